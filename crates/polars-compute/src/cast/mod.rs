@@ -820,6 +820,10 @@ pub fn cast(
             let from = array.as_any().downcast_ref().unwrap();
             Ok(f16_to_f32(from).boxed())
         },
+        (Float32, Float16) => {
+            let from = array.as_any().downcast_ref().unwrap();
+            Ok(f32_to_f16(from).boxed())
+        },
 
         (Float32, UInt8) => primitive_to_primitive_dyn::<f32, u8>(array, to_type, options),
         (Float32, UInt16) => primitive_to_primitive_dyn::<f32, u16>(array, to_type, options),
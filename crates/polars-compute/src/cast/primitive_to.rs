@@ -594,6 +594,11 @@ pub fn f16_to_f32(from: &PrimitiveArray<f16>) -> PrimitiveArray<f32> {
     unary(from, |x| x.to_f32(), ArrowDataType::Float32)
 }
 
+/// Rounds the values to the nearest `f16`. Values that are out of range become infinite.
+pub fn f32_to_f16(from: &PrimitiveArray<f32>) -> PrimitiveArray<f16> {
+    unary(from, f16::from_f32, ArrowDataType::Float16)
+}
+
 /// Returns a [`Utf8Array`] where every element is the utf8 representation of the number.
 pub(super) fn primitive_to_binview<T: NativeType + SerPrimitive>(
     from: &PrimitiveArray<T>,
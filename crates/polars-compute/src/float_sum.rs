@@ -275,3 +275,64 @@ where
         FloatSum::sum(arr.values())
     }
 }
+
+/// Compensated (Kahan-Babuška) summation, which keeps the rounding errors of the additions in a
+/// separate term so that they don't accumulate, unlike in a plain or pairwise sum.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KahanSum<F> {
+    sum: F,
+    compensation: F,
+}
+
+impl<F: Float> KahanSum<F> {
+    pub fn add(&mut self, x: F) {
+        let sum = self.sum + x;
+        // The rounding error of the addition is lost from the smaller operand.
+        let error = if self.sum.abs() >= x.abs() {
+            (self.sum - sum) + x
+        } else {
+            (x - sum) + self.sum
+        };
+        self.compensation = self.compensation + error;
+        self.sum = sum;
+    }
+
+    /// Add the values summed by `other`.
+    pub fn combine(&mut self, other: &Self) {
+        self.add(other.sum);
+        self.compensation = self.compensation + other.compensation;
+    }
+
+    pub fn sum(&self) -> F {
+        // The compensation is meaningless once the sum is infinite or NaN.
+        if self.sum.is_finite() {
+            self.sum + self.compensation
+        } else {
+            self.sum
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kahan_sum() {
+        let mut sum = KahanSum::default();
+        for x in [1.0, 1e100, 1.0, -1e100] {
+            sum.add(x);
+        }
+        assert_eq!(sum.sum(), 2.0);
+
+        let mut tenths = KahanSum::default();
+        (0..10).for_each(|_| tenths.add(0.1));
+        let mut other = KahanSum::default();
+        (0..10).for_each(|_| other.add(0.1));
+        tenths.combine(&other);
+        assert_eq!(tenths.sum(), 2.0);
+
+        sum.add(f64::INFINITY);
+        assert_eq!(sum.sum(), f64::INFINITY);
+    }
+}
@@ -15,13 +15,25 @@ pub struct SumWindow<'a, T, S> {
 impl<T, S> SumWindow<'_, T, S>
 where
     T: NativeType + IsFloat + Sub<Output = T> + NumCast + PartialOrd,
-    S: NativeType + AddAssign + SubAssign + Sub<Output = S> + Add<Output = S> + NumCast,
+    S: NativeType
+        + AddAssign
+        + SubAssign
+        + Sub<Output = S>
+        + Add<Output = S>
+        + NumCast
+        + PartialOrd,
 {
     fn add_finite_kahan(&mut self, val: T) {
         let val: S = NumCast::from(val).unwrap();
-        let y = val - self.err;
-        let new_sum = self.sum + y;
-        self.err = (new_sum - self.sum) - y;
+        let new_sum = self.sum + val;
+        // Kahan-Babuška: the rounding error of the addition is lost from the smaller operand,
+        // which also holds when values cancel out or are removed from the window.
+        let abs = |x: S| if x < S::zeroed() { S::zeroed() - x } else { x };
+        if abs(self.sum) >= abs(val) {
+            self.err += (self.sum - new_sum) + val;
+        } else {
+            self.err += (val - new_sum) + self.sum;
+        }
         self.sum = new_sum;
     }
 
@@ -59,7 +71,13 @@ where
 impl<'a, T, S> RollingAggWindowNoNulls<'a, T> for SumWindow<'a, T, S>
 where
     T: NativeType + IsFloat + Sub<Output = T> + NumCast + PartialOrd,
-    S: NativeType + AddAssign + SubAssign + Sub<Output = S> + Add<Output = S> + NumCast,
+    S: NativeType
+        + AddAssign
+        + SubAssign
+        + Sub<Output = S>
+        + Add<Output = S>
+        + NumCast
+        + PartialOrd,
 {
     fn new(
         slice: &'a [T],
@@ -106,7 +124,7 @@ where
         self.last_start = start;
         self.last_end = end;
         if self.non_finite_count == 0 {
-            NumCast::from(self.sum)
+            NumCast::from(self.sum + self.err)
         } else if self.non_finite_count == self.pos_inf_count {
             Some(T::pos_inf_value())
         } else if self.non_finite_count == self.neg_inf_count {
@@ -230,4 +248,13 @@ mod test {
             )
         );
     }
+
+    #[test]
+    fn test_rolling_sum_compensated() {
+        let values = &[1.0f64, 1e100, 1.0, -1e100, 1.0];
+        let out = rolling_sum(values, 4, 4, false, None, None).unwrap();
+        let out = out.as_any().downcast_ref::<PrimitiveArray<f64>>().unwrap();
+        let out = out.into_iter().map(|v| v.copied()).collect::<Vec<_>>();
+        assert_eq!(out, &[None, None, None, Some(2.0), Some(2.0)]);
+    }
 }
@@ -17,13 +17,25 @@ pub struct SumWindow<'a, T, S> {
 impl<T, S> SumWindow<'_, T, S>
 where
     T: NativeType + IsFloat + Sub<Output = T> + NumCast + PartialOrd,
-    S: NativeType + AddAssign + SubAssign + Sub<Output = S> + Add<Output = S> + NumCast,
+    S: NativeType
+        + AddAssign
+        + SubAssign
+        + Sub<Output = S>
+        + Add<Output = S>
+        + NumCast
+        + PartialOrd,
 {
     fn add_finite_kahan(&mut self, val: T) {
         let val: S = NumCast::from(val).unwrap();
-        let y = val - self.err;
-        let new_sum = self.sum + y;
-        self.err = (new_sum - self.sum) - y;
+        let new_sum = self.sum + val;
+        // Kahan-Babuška: the rounding error of the addition is lost from the smaller operand,
+        // which also holds when values cancel out or are removed from the window.
+        let abs = |x: S| if x < S::zeroed() { S::zeroed() - x } else { x };
+        if abs(self.sum) >= abs(val) {
+            self.err += (self.sum - new_sum) + val;
+        } else {
+            self.err += (val - new_sum) + self.sum;
+        }
         self.sum = new_sum;
     }
 
@@ -61,7 +73,13 @@ where
 impl<'a, T, S> RollingAggWindowNulls<'a, T> for SumWindow<'a, T, S>
 where
     T: NativeType + IsFloat + Sub<Output = T> + NumCast + PartialOrd,
-    S: NativeType + AddAssign + SubAssign + Sub<Output = S> + Add<Output = S> + NumCast,
+    S: NativeType
+        + AddAssign
+        + SubAssign
+        + Sub<Output = S>
+        + Add<Output = S>
+        + NumCast
+        + PartialOrd,
 {
     unsafe fn new(
         slice: &'a [T],
@@ -122,7 +140,7 @@ where
         self.last_start = start;
         self.last_end = end;
         if self.non_finite_count == 0 {
-            NumCast::from(self.sum)
+            NumCast::from(self.sum + self.err)
         } else if self.non_finite_count == self.pos_inf_count {
             Some(T::pos_inf_value())
         } else if self.non_finite_count == self.neg_inf_count {
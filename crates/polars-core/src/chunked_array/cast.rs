@@ -94,6 +94,12 @@ fn cast_impl_inner(
             }
             chunks
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let chunks = cast_chunks(chunks, &DataType::Float32, options)?;
+            let ca = unsafe { Float32Chunked::from_chunks(name, chunks) };
+            return Ok(ca.to_float16().into_series());
+        },
         _ => cast_chunks(chunks, &dtype.to_physical(), options)?,
     };
 
@@ -481,6 +487,8 @@ impl ChunkCast for ListChunked {
                 #[cfg(feature = "dtype-categorical")]
                 polars_ensure!(!matches!(&**child_type, Categorical(_, _)), InvalidOperation: "array of categorical is not yet supported");
 
+                let ca = check_list_widths(&ca, *width, options)?.map_or(ca, Cow::Owned);
+
                 // cast to the physical type to avoid logical chunks.
                 let chunks = cast_chunks(ca.chunks(), &physical_type, options)?;
                 // SAFETY: we just cast so the dtype matches.
@@ -601,6 +609,55 @@ impl ChunkCast for ArrayChunked {
     }
 }
 
+/// Check that the valid rows of `ca` have `width` elements, so that they can be cast to an
+/// array. Rows with a different length raise in a strict cast and become null in a non-strict
+/// cast, in which case the updated `ca` is returned.
+#[cfg(feature = "dtype-array")]
+fn check_list_widths(
+    ca: &ListChunked,
+    width: usize,
+    options: CastOptions,
+) -> PolarsResult<Option<ListChunked>> {
+    use arrow::bitmap::Bitmap;
+    use arrow::compute::utils::combine_validities_and;
+
+    let mut has_mismatch = false;
+    let mut row_offset = 0;
+    for arr in ca.downcast_iter() {
+        for (i, len) in arr.offsets().lengths().enumerate() {
+            if len != width && arr.is_valid(i) {
+                polars_ensure!(
+                    !options.is_strict(),
+                    ComputeError: "not all elements have the specified width {}: row {} has {} elements",
+                    width, row_offset + i, len
+                );
+                has_mismatch = true;
+            }
+        }
+        row_offset += arr.len();
+    }
+    if !has_mismatch {
+        return Ok(None);
+    }
+
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| {
+            let has_width = arr
+                .offsets()
+                .lengths()
+                .map(|len| len == width)
+                .collect::<Bitmap>();
+            let validity = combine_validities_and(arr.validity(), Some(&has_width));
+            arr.clone().with_validity(validity).boxed()
+        })
+        .collect();
+    // SAFETY: only the validity changed.
+    Ok(Some(unsafe {
+        ListChunked::from_chunks_and_dtype_unchecked(ca.name().clone(), chunks, ca.dtype().clone())
+    }))
+}
+
 // Returns inner data type. This is needed because a cast can instantiate the dtype inner
 // values for instance with categoricals
 fn cast_list(
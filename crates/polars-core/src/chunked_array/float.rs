@@ -1,3 +1,4 @@
+use arrow::bitmap::Bitmap;
 use arrow::legacy::kernels::set::set_at_nulls;
 use num_traits::Float;
 use polars_utils::total_ord::{canonical_f32, canonical_f64};
@@ -31,6 +32,25 @@ where
             .map(|arr| set_at_nulls(arr, T::Native::nan()));
         ChunkedArray::from_chunk_iter(self.name().clone(), chunks)
     }
+
+    #[must_use]
+    /// Convert `NaN` values to missing values.
+    ///
+    /// Only the validity is changed, the values are not copied.
+    pub fn nan_to_null(&self) -> Self {
+        let chunks = self.downcast_iter().map(|arr| {
+            let not_nan: Bitmap = arr.values().iter().map(|v| !v.is_nan()).collect();
+            if not_nan.unset_bits() == 0 {
+                return arr.clone();
+            }
+            let validity = match arr.validity() {
+                Some(validity) => validity & &not_nan,
+                None => not_nan,
+            };
+            arr.clone().with_validity(Some(validity))
+        });
+        ChunkedArray::from_chunk_iter(self.name().clone(), chunks)
+    }
 }
 
 pub trait Canonical {
@@ -154,13 +154,23 @@ impl<T: PolarsCategoricalType> CategoricalChunked<T> {
         match &dtype {
             DataType::Categorical(cats, mapping) => {
                 assert!(cats.physical() == T::physical());
-                for opt_s in strings {
+                let mut push = |opt_s: Option<&str>| {
                     cat_ids.push(if let Some(s) = opt_s {
                         T::Native::from_cat(mapping.insert_cat(s)?)
                     } else {
                         T::Native::zero()
                     });
                     validity.push(opt_s.is_some());
+                    PolarsResult::Ok(())
+                };
+                if mapping.is_shared() {
+                    // Insert the new categories at once, instead of syncing each of them with the
+                    // other processes.
+                    let strings = strings.collect::<Vec<_>>();
+                    mapping.insert_cats(strings.iter().flatten().copied())?;
+                    strings.into_iter().try_for_each(push)?;
+                } else {
+                    strings.try_for_each(push)?;
                 }
             },
             DataType::Enum(fcats, mapping) => {
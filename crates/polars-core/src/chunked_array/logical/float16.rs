@@ -0,0 +1,72 @@
+use arrow::types::f16;
+
+use super::*;
+use crate::prelude::*;
+
+/// Half precision floats, stored as their bit representation.
+///
+/// Computations are done in `f32`, the values are only rounded to `f16` when they are stored.
+pub type Float16Chunked = Logical<Float16Type, UInt16Type>;
+
+#[inline]
+fn bits_to_any_value(av: AnyValue<'_>) -> AnyValue<'_> {
+    match av {
+        AnyValue::UInt16(bits) => AnyValue::Float32(f16::from_bits(bits).to_f32()),
+        AnyValue::Null => AnyValue::Null,
+        av => panic!("expected UInt16 bits for Float16, got {av:?}"),
+    }
+}
+
+impl UInt16Chunked {
+    /// Reinterpret the values as the bits of `f16` values.
+    pub fn into_float16(self) -> Float16Chunked {
+        // SAFETY: every bit pattern is a valid f16.
+        unsafe { Float16Chunked::new_logical(self, DataType::Float16) }
+    }
+}
+
+impl Float32Chunked {
+    /// Round the values to the nearest `f16`.
+    pub fn to_float16(&self) -> Float16Chunked {
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| polars_compute::cast::f32_to_f16(arr).transmute::<u16>());
+        UInt16Chunked::from_chunk_iter(self.name().clone(), chunks).into_float16()
+    }
+}
+
+impl Float16Chunked {
+    pub fn to_float32(&self) -> Float32Chunked {
+        let chunks = self
+            .phys
+            .downcast_iter()
+            .map(|arr| polars_compute::cast::f16_to_f32(&arr.clone().transmute::<f16>()));
+        Float32Chunked::from_chunk_iter(self.name().clone(), chunks)
+    }
+}
+
+impl LogicalType for Float16Chunked {
+    fn dtype(&self) -> &'static DataType {
+        &DataType::Float16
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(bits_to_any_value)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        bits_to_any_value(self.phys.get_any_value_unchecked(i))
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Float16 => Ok(self.clone().into_series()),
+            DataType::Float32 => Ok(self.to_float32().into_series()),
+            _ => self.to_float32().cast_with_options(dtype, cast_options),
+        }
+    }
+}
@@ -3,8 +3,8 @@ mod quantile;
 mod var;
 
 use arrow::types::NativeType;
-use num_traits::{Float, One, ToPrimitive, Zero};
-use polars_compute::float_sum;
+use num_traits::{AsPrimitive, Float, NumCast, One, ToPrimitive, Zero};
+use polars_compute::float_sum::{self, KahanSum};
 use polars_compute::min_max::MinMaxKernel;
 use polars_compute::rolling::QuantileMethod;
 use polars_compute::sum::{WrappingSum, wrapping_sum_arr};
@@ -16,6 +16,7 @@ use super::float_sorted_arg_max::{
     float_arg_max_sorted_ascending, float_arg_max_sorted_descending,
 };
 use crate::chunked_array::ChunkedArray;
+use crate::config::{FloatSummation, float_summation};
 use crate::datatypes::{BooleanChunked, PolarsNumericType};
 use crate::prelude::*;
 use crate::series::IsSorted;
@@ -69,6 +70,14 @@ where
     }
 }
 
+/// The compensated sum of `values` in `f64`, which is used instead of the pairwise sum of each
+/// chunk with [`FloatSummation::Kahan`].
+pub(crate) fn kahan_sum<T: NumericNative>(values: impl IntoIterator<Item = T>) -> f64 {
+    let mut sum = KahanSum::default();
+    values.into_iter().for_each(|v| sum.add(v.as_()));
+    sum.sum()
+}
+
 impl<T> ChunkAgg<T::Native> for ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -76,6 +85,9 @@ where
     PrimitiveArray<T::Native>: for<'a> MinMaxKernel<Scalar<'a> = T::Native>,
 {
     fn sum(&self) -> Option<T::Native> {
+        if T::Native::is_float() && float_summation() == FloatSummation::Kahan {
+            return Some(NumCast::from(kahan_sum(self.iter().flatten())).unwrap());
+        }
         Some(
             self.downcast_iter()
                 .map(sum)
@@ -84,6 +96,9 @@ where
     }
 
     fn _sum_as_f64(&self) -> f64 {
+        if T::Native::is_float() && float_summation() == FloatSummation::Kahan {
+            return kahan_sum(self.iter().flatten());
+        }
         self.downcast_iter().map(float_sum::sum_arr_as_f64).sum()
     }
 
@@ -200,18 +215,7 @@ where
 /// Booleans are cast to 1 or 0.
 impl BooleanChunked {
     pub fn sum(&self) -> Option<IdxSize> {
-        Some(if self.is_empty() {
-            0
-        } else {
-            self.downcast_iter()
-                .map(|arr| match arr.validity() {
-                    Some(validity) => {
-                        (arr.len() - (validity & arr.values()).unset_bits()) as IdxSize
-                    },
-                    None => (arr.len() - arr.values().unset_bits()) as IdxSize,
-                })
-                .sum()
-        })
+        Some(self.num_trues() as IdxSize)
     }
 
     pub fn min(&self) -> Option<bool> {
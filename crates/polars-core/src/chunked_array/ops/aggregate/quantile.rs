@@ -56,8 +56,9 @@ fn midpoint_interpol<T: Float>(lower: T, upper: T) -> T {
     }
 }
 
+/// Compute the quantile of `vals`, reordering them in the process.
 // Uses quickselect instead of sorting all data
-fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
+pub fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     vals: &mut [T],
     quantile: f64,
     method: QuantileMethod,
@@ -126,6 +126,12 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let arr = &*(arr as *const dyn Array as *const UInt16Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Float32(arrow::types::f16::from_bits(v).to_f32())
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
@@ -1,3 +1,5 @@
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+
 use super::BooleanChunked;
 
 fn first_true_idx_impl(ca: &BooleanChunked, invert: bool) -> Option<usize> {
@@ -49,6 +51,25 @@ fn first_true_idx_impl(ca: &BooleanChunked, invert: bool) -> Option<usize> {
 }
 
 impl BooleanChunked {
+    /// Get the values as a single packed [`Bitmap`], in which null values are unset.
+    ///
+    /// This doesn't copy if the array consists of a single chunk without null values.
+    pub fn as_bitmap(&self) -> Bitmap {
+        let values = |arr: &arrow::array::BooleanArray| match arr.validity() {
+            None => arr.values().clone(),
+            Some(validity) => arr.values() & validity,
+        };
+        if self.chunks().len() == 1 {
+            return values(self.downcast_as_array());
+        }
+
+        let mut builder = BitmapBuilder::with_capacity(self.len());
+        for arr in self.downcast_iter() {
+            builder.extend_from_bitmap(&values(arr));
+        }
+        builder.freeze()
+    }
+
     pub fn num_trues(&self) -> usize {
         self.downcast_iter()
             .map(|arr| match arr.validity() {
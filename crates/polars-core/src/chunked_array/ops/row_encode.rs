@@ -94,6 +94,8 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => None,
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -208,6 +210,12 @@ pub fn _get_rows_encoded(
             .map_or(Cow::Borrowed(by), Cow::Owned);
         let by = by.propagate_nulls().map_or(by, Cow::Owned);
         let by = by.as_materialized_series();
+        // The bits of Float16 are not ordered like the values.
+        #[cfg(feature = "dtype-f16")]
+        let by = &match by.dtype() {
+            DataType::Float16 => by.cast(&DataType::Float32)?,
+            _ => by.clone(),
+        };
         let arr = by.to_physical_repr().rechunk().chunks()[0].to_boxed();
         let opt = RowEncodingOptions::new_sorted(*desc, *null_last);
         let ctxt = get_row_encoding_context(by.dtype());
@@ -39,9 +39,15 @@ pub fn _arg_bottom_k(
     _broadcast_bools(by_column.len(), &mut sort_options.descending);
     _broadcast_bools(by_column.len(), &mut sort_options.nulls_last);
 
-    // Don't go into row encoding.
-    if by_column.len() == 1 && sort_options.limit.is_some() && !sort_options.maintain_order {
-        return Ok(NoNull::new(by_column[0].arg_sort((&*sort_options).into())));
+    // Don't go into row encoding if the argsort of the column does a partial selection itself.
+    let dtype = by_column[0].dtype();
+    if by_column.len() == 1 && (dtype.is_primitive_numeric() || dtype.is_string()) {
+        let options = SortOptions {
+            limit: Some(k.try_into().unwrap_or(IdxSize::MAX)),
+            ..SortOptions::from(&*sort_options)
+        };
+        let idx = by_column[0].arg_sort(options);
+        return Ok(NoNull::new(idx.slice(0, k)));
     }
 
     let encoded = _get_rows_encoded(
@@ -69,15 +75,17 @@ pub fn _arg_bottom_k(
         }
         &rows
     } else if sort_options.maintain_order {
-        // todo: maybe there is some more efficient method, comparable to select_nth_unstable
+        // Break ties on the index, so that the selection keeps the order of equal rows.
+        let cmp = |a: &CompareRow, b: &CompareRow| a.cmp(b).then(a.idx.cmp(&b.idx));
+        let (lower, _el, _upper) = rows.select_nth_unstable_by(k, cmp);
         if sort_options.multithreaded {
             POOL.install(|| {
-                rows.par_sort();
+                lower.par_sort_unstable_by(cmp);
             })
         } else {
-            rows.sort();
+            lower.sort_unstable_by(cmp);
         }
-        &rows[..k]
+        &*lower
     } else {
         // todo: possible multi threaded `select_nth_unstable`?
         let (lower, _el, _upper) = rows.select_nth_unstable(k);
@@ -15,6 +15,33 @@ where
         options.multithreaded,
     );
 }
+
+// Sort only the first `limit` values, which are selected such that equal values keep the order
+// of their indices, so that the result equals a prefix of the output of `sort_impl`.
+fn sort_limit_impl<T>(
+    vals: &mut [(IdxSize, T)],
+    limit: usize,
+    options: SortOptions,
+) -> &[(IdxSize, T)]
+where
+    T: TotalOrd + Send + Sync,
+{
+    if limit >= vals.len() {
+        sort_impl(vals, options);
+        return vals;
+    }
+
+    let descending = options.descending;
+    let cmp = |a: &(IdxSize, T), b: &(IdxSize, T)| {
+        let ord = a.1.tot_cmp(&b.1);
+        let ord = if descending { ord.reverse() } else { ord };
+        ord.then(a.0.cmp(&b.0))
+    };
+    let (lower, _el, _upper) = vals.select_nth_unstable_by(limit, cmp);
+    sort_by_branch(lower, false, cmp, options.multithreaded);
+    lower
+}
+
 // Compute the indexes after reversing a sorted array, maintaining
 // the order of equal elements, in linear time. Faster than sort_impl
 //  as we avoid allocating extra memory.
@@ -136,20 +163,15 @@ where
     }
 
     let vals = if let Some(limit) = options.limit {
-        let limit = limit as usize;
         // Overwrite output len.
-        len = limit;
-        let out = if limit >= vals.len() {
-            vals.as_mut_slice()
+        len = std::cmp::min(limit as usize, len);
+        // Leading nulls take up part of the output.
+        let limit = if nulls_last {
+            len
         } else {
-            let (lower, _el, _upper) = vals
-                .as_mut_slice()
-                .select_nth_unstable_by(limit, |a, b| a.1.tot_cmp(&b.1));
-            lower
+            len.saturating_sub(nulls_idx.len())
         };
-
-        sort_impl(out, options);
-        out
+        sort_limit_impl(vals.as_mut_slice(), limit, options)
     } else {
         sort_impl(vals.as_mut_slice(), options);
         vals.as_slice()
@@ -168,7 +190,8 @@ where
         idx.extend_from_slice(nulls_idx);
         idx
     } else if options.limit.is_some() {
-        nulls_idx.extend(iter.take(len - nulls_idx.len()));
+        nulls_idx.truncate(len);
+        nulls_idx.extend(iter);
         nulls_idx
     } else {
         let ptr = nulls_idx.as_ptr() as usize;
@@ -231,17 +254,7 @@ where
     }
 
     let vals = if let Some(limit) = options.limit {
-        let limit = limit as usize;
-        let out = if limit >= vals.len() {
-            vals.as_mut_slice()
-        } else {
-            let (lower, _el, _upper) = vals
-                .as_mut_slice()
-                .select_nth_unstable_by(limit, |a, b| a.1.tot_cmp(&b.1));
-            lower
-        };
-        sort_impl(out, options);
-        out
+        sort_limit_impl(vals.as_mut_slice(), limit as usize, options)
     } else {
         sort_impl(vals.as_mut_slice(), options);
         vals.as_slice()
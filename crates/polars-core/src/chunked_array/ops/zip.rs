@@ -31,6 +31,21 @@ where
     Ok(ret.with_name(if_true.name().clone()))
 }
 
+/// Select `if_true` or `if_false` as a whole if the mask of a chunk is all true or all false, so
+/// that the chunks that are not touched by the mask are not copied.
+fn if_then_else_select_chunk<A: Clone>(
+    mask: &Bitmap,
+    if_true: &A,
+    if_false: &A,
+    kernel: impl FnOnce() -> A,
+) -> A {
+    match mask.set_bits() {
+        0 => if_false.clone(),
+        n if n == mask.len() => if_true.clone(),
+        _ => kernel(),
+    }
+}
+
 fn bool_null_to_false(mask: &BooleanArray) -> Bitmap {
     if mask.null_count() == 0 {
         mask.values().clone()
@@ -115,7 +130,12 @@ where
                 .downcast_iter()
                 .zip(if_true_al.downcast_iter())
                 .zip(if_false_al.downcast_iter())
-                .map(|((m, t), f)| IfThenElseKernel::if_then_else(&bool_null_to_false(m), t, f));
+                .map(|((m, t), f)| {
+                    let bm = bool_null_to_false(m);
+                    if_then_else_select_chunk(&bm, t, f, || {
+                        IfThenElseKernel::if_then_else(&bm, t, f)
+                    })
+                });
             ChunkedArray::from_chunk_iter_like(if_true, chunks)
 
         // Broadcast true value.
@@ -128,6 +148,9 @@ where
                     .zip(if_false_al.downcast_iter())
                     .map(|(m, f)| {
                         let bm = bool_null_to_false(m);
+                        if bm.set_bits() == 0 {
+                            return f.clone();
+                        }
                         let t = true_scalar.clone();
                         IfThenElseKernel::if_then_else_broadcast_true(&bm, t, f)
                     });
@@ -147,6 +170,9 @@ where
                         .zip(if_true_al.downcast_iter())
                         .map(|(m, t)| {
                             let bm = bool_null_to_false(m);
+                            if bm.unset_bits() == 0 {
+                                return t.clone();
+                            }
                             let f = false_scalar.clone();
                             IfThenElseKernel::if_then_else_broadcast_false(&bm, t, f)
                         });
@@ -533,3 +559,43 @@ impl ChunkZip<StructType> for StructChunked {
         Ok(out)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{BooleanArray, PrimitiveArray};
+
+    use crate::prelude::*;
+
+    #[test]
+    fn test_zip_with_untouched_chunks() -> PolarsResult<()> {
+        let if_true = Int32Chunked::from_chunk_iter(
+            PlSmallStr::from_static("a"),
+            [
+                PrimitiveArray::from_slice([1, 2]),
+                PrimitiveArray::from_slice([3, 4]),
+            ],
+        );
+        let if_false = Int32Chunked::from_chunk_iter(
+            PlSmallStr::from_static("b"),
+            [
+                PrimitiveArray::from_slice([5, 6]),
+                PrimitiveArray::from_slice([7, 8]),
+            ],
+        );
+        let mask = BooleanChunked::from_chunk_iter(
+            PlSmallStr::from_static("mask"),
+            [
+                BooleanArray::from_slice([false, false]),
+                BooleanArray::from([Some(true), None]),
+            ],
+        );
+
+        let out = if_true.zip_with(&mask, &if_false)?;
+        assert_eq!(Vec::from(&out), &[Some(5), Some(6), Some(3), Some(8)]);
+        // The chunk that is only selected from `if_false` is not copied.
+        let out_values = out.downcast_iter().next().unwrap().values();
+        let false_values = if_false.downcast_iter().next().unwrap().values();
+        assert_eq!(out_values.as_ptr(), false_values.as_ptr());
+        Ok(())
+    }
+}
@@ -1,3 +1,7 @@
+use std::sync::LazyLock;
+
+use polars_utils::relaxed_cell::RelaxedCell;
+
 use crate::POOL;
 
 // Formatting environment variables (typically referenced/set from the python-side Config object)
@@ -29,10 +33,94 @@ pub(crate) const FMT_TABLE_INLINE_COLUMN_DATA_TYPE: &str =
 pub(crate) const FMT_TABLE_ROUNDED_CORNERS: &str = "POLARS_FMT_TABLE_ROUNDED_CORNERS";
 pub(crate) const FMT_TABLE_CELL_LIST_LEN: &str = "POLARS_FMT_TABLE_CELL_LIST_LEN";
 
+/// Settings of a single query that take precedence over their environment variables.
+///
+/// These are passed along with the query to the state of the engine that executes it, so that
+/// concurrent queries in one process can use different settings.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigOverrides {
+    /// Overrides `POLARS_VERBOSE`.
+    pub verbose: Option<bool>,
+    /// Overrides `POLARS_IDEAL_MORSEL_SIZE`, the number of rows per morsel of the streaming engine.
+    pub ideal_morsel_size: Option<usize>,
+    /// Overrides `POLARS_NUM_READERS_PRE_INIT`, the number of files a streaming scan opens ahead
+    /// of the file it is reading.
+    pub scan_readahead: Option<usize>,
+    /// The number of tasks of a streaming join that probe the hash tables at the same time,
+    /// instead of one per pipeline.
+    pub join_probe_parallelism: Option<usize>,
+    /// The number of tasks that encode the data of the streaming Parquet and IPC sinks, instead of
+    /// one per pipeline.
+    pub sink_writer_threads: Option<usize>,
+}
+
+impl ConfigOverrides {
+    /// Whether the query logs verbosely, falling back to `POLARS_VERBOSE`.
+    pub fn verbose(&self) -> bool {
+        self.verbose.unwrap_or_else(verbose)
+    }
+}
+
 pub fn verbose() -> bool {
     std::env::var("POLARS_VERBOSE").as_deref().unwrap_or("") == "1"
 }
 
+/// How integer arithmetic behaves when a result does not fit the dtype.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticOverflow {
+    /// Wrap around, this is the default.
+    Wrap,
+    /// Raise an error.
+    Error,
+    /// Compute in the next wider integer dtype that fits all results.
+    Promote,
+}
+
+pub fn arithmetic_overflow() -> ArithmeticOverflow {
+    match std::env::var("POLARS_ARITHMETIC_OVERFLOW").as_deref() {
+        Ok("error") => ArithmeticOverflow::Error,
+        Ok("promote") => ArithmeticOverflow::Promote,
+        _ => ArithmeticOverflow::Wrap,
+    }
+}
+
+/// How `sum` and `mean` add up floats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FloatSummation {
+    /// Pairwise summation within each chunk, and plain additions within groups and between
+    /// chunks and batches. This is the default.
+    Fast,
+    /// Compensated summation everywhere, which is slower but doesn't drift on large columns.
+    Kahan,
+}
+
+// Read once from `POLARS_FLOAT_SUMMATION`, as it is checked on every sum.
+static FLOAT_SUMMATION: LazyLock<RelaxedCell<u8>> = LazyLock::new(|| {
+    let method = match std::env::var("POLARS_FLOAT_SUMMATION").as_deref() {
+        Ok("kahan") => FloatSummation::Kahan,
+        _ => FloatSummation::Fast,
+    };
+    RelaxedCell::new_u8(method as u8)
+});
+
+pub fn float_summation() -> FloatSummation {
+    match FLOAT_SUMMATION.load() {
+        0 => FloatSummation::Fast,
+        1 => FloatSummation::Kahan,
+        _ => unreachable!(),
+    }
+}
+
+pub fn set_float_summation(method: FloatSummation) {
+    FLOAT_SUMMATION.store(method as u8)
+}
+
+/// Whether NaN join keys match each other when a join doesn't specify it, which they do by default.
+pub fn join_nans_equal() -> bool {
+    std::env::var("POLARS_JOIN_NANS_EQUAL").as_deref() != Ok("0")
+}
+
 pub fn get_engine_affinity() -> String {
     std::env::var("POLARS_ENGINE_AFFINITY").unwrap_or_else(|_| "auto".to_string())
 }
@@ -136,11 +136,15 @@ enum SerializableDataType {
     #[cfg(feature = "dtype-categorical")]
     Enum {
         strings: Series,
+        #[serde(default)]
+        physical: Option<CategoricalPhysical>,
     },
     #[cfg(feature = "dtype-decimal")]
     Decimal(Option<usize>, Option<usize>),
     #[cfg(feature = "object")]
     Object(String),
+    #[cfg(feature = "dtype-f16")]
+    Float16,
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -157,6 +161,8 @@ impl From<&DataType> for SerializableDataType {
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -186,6 +192,7 @@ impl From<&DataType> for SerializableDataType {
                     fcats.categories().clone(),
                 )
                 .into_series(),
+                physical: Some(fcats.physical()),
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
@@ -208,6 +215,8 @@ impl From<SerializableDataType> for DataType {
             Int32 => Self::Int32,
             Int64 => Self::Int64,
             Int128 => Self::Int128,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Self::Float16,
             Float32 => Self::Float32,
             Float64 => Self::Float64,
             String => Self::String,
@@ -239,9 +248,9 @@ impl From<SerializableDataType> for DataType {
                 Self::Categorical(cats, mapping)
             },
             #[cfg(feature = "dtype-categorical")]
-            Enum { strings } => {
+            Enum { strings, physical } => {
                 let ca = strings.str().unwrap();
-                let fcats = FrozenCategories::new(ca.iter().flatten()).unwrap();
+                let fcats = FrozenCategories::with_physical(ca.iter().flatten(), physical).unwrap();
                 let mapping = fcats.mapping().clone();
                 Self::Enum(fcats, mapping)
             },
@@ -318,6 +318,11 @@ impl<'a> AnyValue<'a> {
             (av, DataType::Int32) => AnyValue::Int32(av.extract::<i32>()?),
             (av, DataType::Int64) => AnyValue::Int64(av.extract::<i64>()?),
             (av, DataType::Int128) => AnyValue::Int128(av.extract::<i128>()?),
+            #[cfg(feature = "dtype-f16")]
+            (av, DataType::Float16) => {
+                let v = av.extract::<f32>()?;
+                AnyValue::Float32(arrow::types::f16::from_f32(v).to_f32())
+            },
             (av, DataType::Float32) => AnyValue::Float32(av.extract::<f32>()?),
             (av, DataType::Float64) => AnyValue::Float64(av.extract::<f64>()?),
 
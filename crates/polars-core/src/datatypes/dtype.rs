@@ -98,6 +98,10 @@ pub enum DataType {
     Int32,
     Int64,
     Int128,
+    /// Half precision floating point type, backed by its bits in a 16-bit unsigned integer.
+    /// This is a storage type; compute happens in 32 bits.
+    #[cfg(feature = "dtype-f16")]
+    Float16,
     Float32,
     Float64,
     /// Fixed point decimal type optional precision and non-negative scale.
@@ -377,6 +381,9 @@ impl DataType {
                 _ => false,
             },
 
+            #[cfg(feature = "dtype-f16")]
+            (D::Float16, dt) | (dt, D::Float16) => dt.is_primitive_numeric() || dt.is_string(),
+
             (D::List(from), D::List(to)) => from.can_cast_to(to)?,
             #[cfg(feature = "dtype-array")]
             (D::Array(from, l_width), D::Array(to, r_width)) => {
@@ -419,6 +426,8 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
             #[cfg(feature = "dtype-categorical")]
@@ -628,7 +637,16 @@ impl DataType {
         }
     }
 
-    /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
+    /// Check if this [`DataType`] is a half precision floating point type.
+    pub fn is_float16(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => true,
+            _ => false,
+        }
+    }
+
+    /// Check if this [`DataType`] is a basic floating point type (excludes Float16 and Decimal).
     /// Note, this also includes `Unknown(UnknownKind::Float)`.
     pub fn is_float(&self) -> bool {
         matches!(
@@ -762,6 +780,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MAX),
             UInt32 => Scalar::from(u32::MAX),
             UInt64 => Scalar::from(u64::MAX),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float32(f32::INFINITY)),
             Float32 => Scalar::from(f32::INFINITY),
             Float64 => Scalar::from(f64::INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -784,6 +804,8 @@ impl DataType {
             UInt16 => Scalar::from(u16::MIN),
             UInt32 => Scalar::from(u32::MIN),
             UInt64 => Scalar::from(u64::MIN),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Scalar::new(Float16, AnyValue::Float32(f32::NEG_INFINITY)),
             Float32 => Scalar::from(f32::NEG_INFINITY),
             Float64 => Scalar::from(f64::NEG_INFINITY),
             #[cfg(feature = "dtype-time")]
@@ -813,6 +835,8 @@ impl DataType {
             Int32 => Ok(ArrowDataType::Int32),
             Int64 => Ok(ArrowDataType::Int64),
             Int128 => Ok(ArrowDataType::Int128),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Ok(ArrowDataType::Float16),
             Float32 => Ok(ArrowDataType::Float32),
             Float64 => Ok(ArrowDataType::Float64),
             #[cfg(feature = "dtype-decimal")]
@@ -1013,7 +1037,7 @@ impl DataType {
     }
 
     pub fn is_numeric(&self) -> bool {
-        self.is_integer() || self.is_float() || self.is_decimal()
+        self.is_integer() || self.is_float() || self.is_float16() || self.is_decimal()
     }
 }
 
@@ -1031,6 +1055,8 @@ impl Display for DataType {
             DataType::Int32 => "i32",
             DataType::Int64 => "i64",
             DataType::Int128 => "i128",
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => "f16",
             DataType::Float32 => "f32",
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
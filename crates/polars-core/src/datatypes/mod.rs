@@ -223,6 +223,8 @@ impl_polars_datatype!(BinaryType, DataType::Binary, BinaryViewArray, 'a, &'a [u8
 impl_polars_datatype!(BinaryOffsetType, DataType::BinaryOffset, BinaryArray<i64>, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, FalseT);
 impl_polars_datatype!(BooleanType, DataType::Boolean, BooleanArray, 'a, bool, bool, bool, FalseT);
 
+#[cfg(feature = "dtype-f16")]
+impl_polars_datatype!(Float16Type, DataType::Float16, PrimitiveArray<u16>, 'a, u16, u16, u16, FalseT);
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype!(DecimalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
 impl_polars_datatype!(DatetimeType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
@@ -0,0 +1,66 @@
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+
+/// How to infer the format of the temporal values in a column of strings.
+///
+/// Unlike the per-value inference of `try_parse_dates`, a format is only chosen if it parses
+/// every value, so that ambiguous dates like `01/02/2024` are parsed in the same order as the
+/// unambiguous ones around them. The inference itself is implemented in `polars-time`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DateInferenceOptions {
+    /// The formats to try in order of preference, or the built-in ones if `None`.
+    formats: Option<Vec<PlSmallStr>>,
+    /// The names of the months, starting with January, as they are written in the data.
+    month_names: Option<Vec<PlSmallStr>>,
+    /// The two-digit years below which `%y` is in the 2000s rather than the 1900s.
+    two_digit_year_pivot: Option<u8>,
+}
+
+impl DateInferenceOptions {
+    /// Create the options, where the defaults are the formats that `try_parse_dates` recognizes
+    /// followed by the month-first dates, English month names and chrono's two-digit-year pivot
+    /// of 70.
+    ///
+    /// The `month_names` are matched ASCII case-insensitively and stand in for the English month
+    /// names that `%b` and `%B` parse.
+    pub fn new(
+        formats: Option<Vec<PlSmallStr>>,
+        month_names: Option<Vec<PlSmallStr>>,
+        two_digit_year_pivot: Option<u8>,
+    ) -> PolarsResult<Self> {
+        if let Some(formats) = &formats {
+            polars_ensure!(!formats.is_empty(), InvalidOperation: "`formats` cannot be empty");
+        }
+        if let Some(names) = &month_names {
+            polars_ensure!(
+                names.len() == 12 && names.iter().all(|name| !name.is_empty()),
+                InvalidOperation: "`month_names` must hold 12 non-empty names, got {:?}", names
+            );
+        }
+        if let Some(pivot) = two_digit_year_pivot {
+            polars_ensure!(
+                pivot <= 100,
+                InvalidOperation: "`two_digit_year_pivot` must be at most 100, got {}", pivot
+            );
+        }
+        Ok(Self {
+            formats,
+            month_names,
+            two_digit_year_pivot,
+        })
+    }
+
+    pub fn formats(&self) -> Option<&[PlSmallStr]> {
+        self.formats.as_deref()
+    }
+
+    pub fn month_names(&self) -> Option<&[PlSmallStr]> {
+        self.month_names.as_deref()
+    }
+
+    pub fn two_digit_year_pivot(&self) -> Option<u8> {
+        self.two_digit_year_pivot
+    }
+}
@@ -1,3 +1,5 @@
+pub mod date_inference;
 pub mod time_unit;
 pub mod time_zone;
+pub use date_inference::DateInferenceOptions;
 pub use time_unit::TimeUnit;
@@ -371,6 +371,11 @@ impl Debug for Series {
             DataType::Float64 => {
                 format_array!(f, self.f64().unwrap(), "f64", self.name(), "Series")
             },
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => {
+                let ca = self.f16().unwrap().to_float32();
+                format_array!(f, ca, "f16", self.name(), "Series")
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => format_array!(f, self.date().unwrap(), "date", self.name(), "Series"),
             #[cfg(feature = "dtype-datetime")]
@@ -0,0 +1,240 @@
+use std::borrow::Cow;
+
+use arrow::datatypes::{ArrowSchemaRef, IntegerType};
+use arrow::offset::OffsetsBuffer;
+use arrow::record_batch::RecordBatch;
+
+use crate::prelude::*;
+
+/// How the columns of a [`DataFrame`] are laid out when exported with [`DataFrame::to_arrow`].
+///
+/// Not every Arrow consumer supports every layout, e.g. older consumers don't know the view
+/// types and some only support 32-bit offsets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArrowExportOptions {
+    /// The compatibility level, which determines e.g. whether strings are exported as
+    /// `Utf8View` or `LargeUtf8`.
+    pub compat_level: CompatLevel,
+    /// Export lists, and strings and binaries that are not views, with 64-bit offsets. If
+    /// `false`, they are exported with 32-bit offsets, which fails if the data does not fit.
+    pub large_offsets: bool,
+    /// Export categoricals and enums as dictionaries. If `false`, they are exported as strings.
+    pub categorical_as_dictionary: bool,
+}
+
+impl Default for ArrowExportOptions {
+    fn default() -> Self {
+        Self {
+            compat_level: CompatLevel::newest(),
+            large_offsets: true,
+            categorical_as_dictionary: true,
+        }
+    }
+}
+
+/// `dtype` with all categoricals and enums, also nested ones, replaced by strings.
+fn categoricals_to_string(dtype: &DataType) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(..) | DataType::Enum(..) => DataType::String,
+        DataType::List(inner) => DataType::List(Box::new(categoricals_to_string(inner))),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, width) => {
+            DataType::Array(Box::new(categoricals_to_string(inner)), *width)
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name().clone(), categoricals_to_string(f.dtype())))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+/// `dtype` with all 64-bit offset layouts, also nested ones, replaced by their 32-bit variant.
+fn small_offsets_dtype(dtype: &ArrowDataType) -> ArrowDataType {
+    use ArrowDataType as D;
+    let field = |f: &ArrowField| ArrowField {
+        dtype: small_offsets_dtype(&f.dtype),
+        ..f.clone()
+    };
+    match dtype {
+        D::LargeUtf8 => D::Utf8,
+        D::LargeBinary => D::Binary,
+        D::LargeList(f) | D::List(f) => D::List(Box::new(field(f))),
+        D::FixedSizeList(f, size) => D::FixedSizeList(Box::new(field(f)), *size),
+        D::Struct(fields) => D::Struct(fields.iter().map(field).collect()),
+        D::Dictionary(key, values, is_sorted) => {
+            D::Dictionary(*key, Box::new(small_offsets_dtype(values)), *is_sorted)
+        },
+        dt => dt.clone(),
+    }
+}
+
+fn small_offsets(offsets: &OffsetsBuffer<i64>) -> PolarsResult<OffsetsBuffer<i32>> {
+    offsets.try_into().map_err(|_| {
+        polars_err!(
+            ComputeError: "data does not fit in 32-bit offsets; export it with large offsets"
+        )
+    })
+}
+
+fn dictionary_small_offsets<K: DictionaryKey>(
+    arr: &dyn Array,
+    dtype: ArrowDataType,
+) -> PolarsResult<ArrayRef> {
+    let arr = arr.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = to_small_offsets(arr.values().clone())?;
+    Ok(DictionaryArray::try_new(dtype, arr.keys().clone(), values)?.boxed())
+}
+
+/// Convert all 64-bit offset layouts in `arr`, also nested ones, to their 32-bit variant.
+fn to_small_offsets(arr: ArrayRef) -> PolarsResult<ArrayRef> {
+    use ArrowDataType as D;
+    let dtype = small_offsets_dtype(arr.dtype());
+    if &dtype == arr.dtype() {
+        return Ok(arr);
+    }
+
+    let out = match arr.dtype() {
+        D::LargeUtf8 => {
+            let arr = arr.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            let offsets = small_offsets(arr.offsets())?;
+            Utf8Array::<i32>::new(
+                dtype,
+                offsets,
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        D::LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            let offsets = small_offsets(arr.offsets())?;
+            BinaryArray::<i32>::new(
+                dtype,
+                offsets,
+                arr.values().clone(),
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        D::LargeList(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let offsets = small_offsets(arr.offsets())?;
+            let values = to_small_offsets(arr.values().clone())?;
+            ListArray::<i32>::new(dtype, offsets, values, arr.validity().cloned()).boxed()
+        },
+        D::List(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = to_small_offsets(arr.values().clone())?;
+            ListArray::<i32>::new(
+                dtype,
+                arr.offsets().clone(),
+                values,
+                arr.validity().cloned(),
+            )
+            .boxed()
+        },
+        D::FixedSizeList(..) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = to_small_offsets(arr.values().clone())?;
+            FixedSizeListArray::new(dtype, arr.len(), values, arr.validity().cloned()).boxed()
+        },
+        D::Struct(_) => {
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = arr
+                .values()
+                .iter()
+                .map(|v| to_small_offsets(v.clone()))
+                .collect::<PolarsResult<_>>()?;
+            StructArray::new(dtype, arr.len(), values, arr.validity().cloned()).boxed()
+        },
+        D::Dictionary(key, ..) => match key {
+            IntegerType::UInt8 => dictionary_small_offsets::<u8>(arr.as_ref(), dtype)?,
+            IntegerType::UInt16 => dictionary_small_offsets::<u16>(arr.as_ref(), dtype)?,
+            IntegerType::UInt32 => dictionary_small_offsets::<u32>(arr.as_ref(), dtype)?,
+            _ => polars_bail!(
+                InvalidOperation: "cannot export {:?} with 32-bit offsets", arr.dtype()
+            ),
+        },
+        _ => unreachable!(),
+    };
+    Ok(out)
+}
+
+/// Export the chunks of `s` to Arrow.
+fn export_series(
+    s: &Series,
+    options: &ArrowExportOptions,
+) -> PolarsResult<(ArrowField, Vec<ArrayRef>)> {
+    let s = if options.categorical_as_dictionary {
+        Cow::Borrowed(s)
+    } else {
+        let dtype = categoricals_to_string(s.dtype());
+        if &dtype == s.dtype() {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(s.cast(&dtype)?)
+        }
+    };
+
+    let mut field = s.field().to_arrow(options.compat_level);
+    let mut arrays: Vec<_> = (0..s.chunks().len())
+        .map(|i| s.to_arrow(i, options.compat_level))
+        .collect();
+    if !options.large_offsets {
+        field.dtype = small_offsets_dtype(&field.dtype);
+        arrays = arrays
+            .into_iter()
+            .map(to_small_offsets)
+            .collect::<PolarsResult<_>>()?;
+    }
+    Ok((field, arrays))
+}
+
+impl DataFrame {
+    /// Convert to Arrow record batches, one per chunk.
+    ///
+    /// All columns are exported with `options`, except for the columns in `column_options`
+    /// which are exported with their own options.
+    pub fn to_arrow(
+        &self,
+        options: ArrowExportOptions,
+        column_options: &PlHashMap<PlSmallStr, ArrowExportOptions>,
+    ) -> PolarsResult<Vec<RecordBatch>> {
+        for name in column_options.keys() {
+            self.try_get_column_index(name)?;
+        }
+
+        let mut df = Cow::Borrowed(self);
+        if df.should_rechunk() {
+            df.to_mut().align_chunks_par();
+        }
+
+        let columns = df
+            .get_columns()
+            .iter()
+            .map(|c| {
+                let options = column_options.get(c.name()).unwrap_or(&options);
+                export_series(c.as_materialized_series(), options)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let schema: ArrowSchemaRef = Arc::new(columns.iter().map(|(f, _)| f.clone()).collect());
+        let mut chunks: Vec<_> = columns
+            .into_iter()
+            .map(|(_, arrays)| arrays.into_iter())
+            .collect();
+        let batches = (0..df.first_col_n_chunks())
+            .map(|_| {
+                let arrays: Vec<_> = chunks.iter_mut().map(|c| c.next().unwrap()).collect();
+                let length = arrays.first().map_or(0, |arr| arr.len());
+                RecordBatch::new(length, schema.clone(), arrays)
+            })
+            .collect();
+        Ok(batches)
+    }
+}
@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use arrow::bitmap::BitmapBuilder;
 use arrow::trusted_len::TrustMyLength;
 use num_traits::{Num, NumCast};
+pub use partitioned::PartitionedColumn;
 use polars_compute::rolling::QuantileMethod;
 use polars_error::PolarsResult;
 use polars_utils::aliases::PlSeedableRandomStateQuality;
@@ -12,7 +13,6 @@ pub use scalar::ScalarColumn;
 
 use self::compare_inner::{TotalEqInner, TotalOrdInner};
 use self::gather::check_bounds_ca;
-use self::partitioned::PartitionedColumn;
 use self::series::SeriesColumn;
 use crate::chunked_array::cast::CastOptions;
 use crate::chunked_array::flags::StatisticsFlags;
@@ -103,6 +103,61 @@ impl Column {
         Ok(col)
     }
 
+    /// Create a 64-bit row index of `length` values, starting at `start` and incrementing by
+    /// `step`.
+    ///
+    /// `dtype` must be [`DataType::Int64`] or [`DataType::UInt64`]. Errors if any of the values
+    /// does not fit in `dtype`.
+    pub fn new_stepped_row_index(
+        name: PlSmallStr,
+        start: i64,
+        step: i64,
+        length: usize,
+        dtype: &DataType,
+    ) -> PolarsResult<Column> {
+        polars_ensure!(step != 0, InvalidOperation: "row index `step` must not be zero");
+
+        let (min, max) = match dtype {
+            DataType::Int64 => (i64::MIN as i128, i64::MAX as i128),
+            DataType::UInt64 => (0, u64::MAX as i128),
+            dt => polars_bail!(
+                InvalidOperation: "row index must be of type Int64 or UInt64, got {}", dt
+            ),
+        };
+        if length > 0 {
+            // The values are monotonic, so checking the bounds is sufficient.
+            let first = start as i128;
+            let last = first + (length as i128 - 1) * step as i128;
+            polars_ensure!(
+                (min..=max).contains(&first) && (min..=max).contains(&last),
+                ComputeError:
+                "row index with start {} and step {} overflows {} on dataframe with height {}",
+                start, step, dtype, length
+            );
+        }
+
+        let sorted = if step > 0 {
+            IsSorted::Ascending
+        } else {
+            IsSorted::Descending
+        };
+        // Two's complement arithmetic gives the right result for both types as every value is in
+        // bounds.
+        let values =
+            (0..length as u64).map(|i| (start as u64).wrapping_add(i.wrapping_mul(step as u64)));
+        let series = if dtype == &DataType::Int64 {
+            let mut ca = Int64Chunked::from_iter_values(name, values.map(|v| v as i64));
+            ca.set_sorted_flag(sorted);
+            ca.into_series()
+        } else {
+            let mut ca = UInt64Chunked::from_iter_values(name, values);
+            ca.set_sorted_flag(sorted);
+            ca.into_series()
+        };
+
+        Ok(series.into())
+    }
+
     // # Materialize
     /// Get a reference to a [`Series`] for this [`Column`]
     ///
@@ -1240,6 +1295,15 @@ impl Column {
         }
     }
 
+    /// See [`Series::rechunk_to_chunksize`].
+    pub fn rechunk_to_chunksize(&self, chunk_size: usize) -> PolarsResult<Column> {
+        // @scalar-opt
+        // @partition-opt
+        self.as_materialized_series()
+            .rechunk_to_chunksize(chunk_size)
+            .map(Column::from)
+    }
+
     pub fn explode(&self, skip_empty: bool) -> PolarsResult<Column> {
         self.as_materialized_series()
             .explode(skip_empty)
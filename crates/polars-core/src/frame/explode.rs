@@ -29,13 +29,23 @@ pub struct UnpivotArgsIR {
 }
 
 impl DataFrame {
-    pub fn explode_impl(&self, mut columns: Vec<Column>) -> PolarsResult<DataFrame> {
+    /// Explode `columns`, adding the index of the original row of every exploded row as the
+    /// first column named `index_name`, if set.
+    pub fn explode_impl(
+        &self,
+        mut columns: Vec<Column>,
+        index_name: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
         polars_ensure!(!columns.is_empty(), InvalidOperation: "no columns provided in explode");
         let mut df = self.clone();
         if self.is_empty() {
             for s in &columns {
                 df.with_column(s.as_materialized_series().explode(false)?)?;
             }
+            if let Some(name) = index_name {
+                let row_idx = IdxCa::from_vec(name, Vec::new());
+                df.insert_column(0, row_idx.into_column())?;
+            }
             return Ok(df);
         }
         columns.sort_by(|sa, sb| {
@@ -111,16 +121,20 @@ impl DataFrame {
             // We just created indices that are in bounds.
             let mut df = unsafe { df.take_unchecked(&row_idx) };
             process_column(self, &mut df, exploded.clone())?;
-            PolarsResult::Ok(df)
+            PolarsResult::Ok((df, row_idx))
         };
         let (df, result) = POOL.join(process_first, check_offsets);
-        let mut df = df?;
+        let (mut df, row_idx) = df?;
         result?;
 
         for (exploded, _) in exploded_columns.into_iter().skip(1) {
             process_column(self, &mut df, exploded)?
         }
 
+        if let Some(name) = index_name {
+            df.insert_column(0, row_idx.with_name(name).into_column())?;
+        }
+
         Ok(df)
     }
     /// Explode `DataFrame` to long format by exploding a column with Lists.
@@ -190,7 +204,22 @@ impl DataFrame {
         // We need to sort the column by order of original occurrence. Otherwise the insert by index
         // below will panic
         let columns = self.select_columns(columns)?;
-        self.explode_impl(columns)
+        self.explode_impl(columns, None)
+    }
+
+    /// Explode `DataFrame` to long format like [`DataFrame::explode`], adding the index of the
+    /// original row of every exploded row as the first column named `index_name`.
+    pub fn explode_with_index<I, S>(
+        &self,
+        columns: I,
+        index_name: PlSmallStr,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<PlSmallStr>,
+    {
+        let columns = self.select_columns(columns)?;
+        self.explode_impl(columns, Some(index_name))
     }
 }
 
@@ -1,7 +1,8 @@
 use std::borrow::Cow;
 
+use arrow::bitmap::utils::count_zeros;
+
 use super::*;
-use crate::chunked_array::cast::CastOptions;
 
 pub fn _agg_helper_idx_bool<F>(groups: &GroupsIdx, f: F) -> Series
 where
@@ -159,8 +160,23 @@ impl BooleanChunked {
         }
     }
     pub(crate) unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
-        self.cast_with_options(&IDX_DTYPE, CastOptions::Overflowing)
-            .unwrap()
-            .agg_sum(groups)
+        // Count the set bits of the groups instead of summing the booleans cast to integers.
+        let bitmap = self.as_bitmap();
+        let (bytes, offset, _) = bitmap.as_slice();
+        match groups {
+            GroupsType::Idx(groups) => _agg_helper_idx_no_null::<IdxType, _>(groups, |(_, idx)| {
+                debug_assert!(idx.len() <= self.len());
+                idx.iter()
+                    .map(|i| unsafe { bitmap.get_bit_unchecked(*i as usize) } as IdxSize)
+                    .sum()
+            }),
+            GroupsType::Slice { groups, .. } => {
+                _agg_helper_slice_no_null::<IdxType, _>(groups, |[first, len]| {
+                    debug_assert!(first + len <= self.len() as IdxSize);
+                    let zeros = count_zeros(bytes, offset + first as usize, len as usize);
+                    len - zeros as IdxSize
+                })
+            },
+        }
     }
 }
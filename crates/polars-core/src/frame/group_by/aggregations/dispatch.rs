@@ -138,6 +138,8 @@ impl Series {
             dt if dt.is_primitive_numeric() => apply_method_physical_integer!(s, agg_mean, groups),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => self.cast(&Float64).unwrap().agg_mean(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => self.cast(&Float32).unwrap().agg_mean(groups),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -194,6 +196,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => self.cast(&Float64).unwrap().agg_median(groups),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => self.cast(&Float32).unwrap().agg_median(groups),
             #[cfg(feature = "dtype-datetime")]
             dt @ Datetime(_, _) => self
                 .to_physical_repr()
@@ -247,6 +251,11 @@ impl Series {
 
         use DataType::*;
         match s.dtype() {
+            #[cfg(feature = "dtype-f16")]
+            Float16 => s
+                .cast(&Float32)
+                .unwrap()
+                .agg_quantile(groups, quantile, method),
             Float32 => s.f32().unwrap().agg_quantile(groups, quantile, method),
             Float64 => s.f64().unwrap().agg_quantile(groups, quantile, method),
             dt if dt.is_primitive_numeric() || dt.is_temporal() => {
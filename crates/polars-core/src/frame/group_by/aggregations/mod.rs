@@ -29,6 +29,8 @@ use rayon::prelude::*;
 use crate::chunked_array::cast::CastOptions;
 #[cfg(feature = "object")]
 use crate::chunked_array::object::extension::create_extension;
+use crate::chunked_array::ops::aggregate::kahan_sum;
+use crate::config::{FloatSummation, float_summation};
 use crate::frame::group_by::GroupsIdx;
 #[cfg(feature = "object")]
 use crate::frame::group_by::GroupsIndicator;
@@ -600,10 +602,14 @@ where
                 let ca = self.rechunk();
                 let arr = ca.downcast_iter().next().unwrap();
                 let no_nulls = arr.null_count() == 0;
+                let kahan = T::Native::is_float() && float_summation() == FloatSummation::Kahan;
                 _agg_helper_idx_no_null::<T, _>(groups, |(first, idx)| {
                     debug_assert!(idx.len() <= self.len());
                     if idx.is_empty() {
                         T::Native::zero()
+                    } else if kahan {
+                        let values = idx.iter().filter_map(|&i| arr.get(i as usize));
+                        NumCast::from(kahan_sum(values)).unwrap()
                     } else if idx.len() == 1 {
                         arr.get(first as usize).unwrap_or(T::Native::zero())
                     } else if no_nulls {
@@ -669,6 +675,7 @@ where
                 let ca = self.rechunk();
                 let arr = ca.downcast_iter().next().unwrap();
                 let no_nulls = arr.null_count() == 0;
+                let kahan = float_summation() == FloatSummation::Kahan;
                 _agg_helper_idx::<T, _>(groups, |(first, idx)| {
                     // this can fail due to a bug in lazy code.
                     // here users can create filters in aggregations
@@ -678,6 +685,10 @@ where
                     debug_assert!(idx.len() <= self.len());
                     let out = if idx.is_empty() {
                         None
+                    } else if kahan {
+                        let values = idx.iter().filter_map(|&i| arr.get(i as usize));
+                        let count = values.clone().count();
+                        (count > 0).then(|| kahan_sum(values) / count as f64)
                     } else if idx.len() == 1 {
                         arr.get(first as usize).map(|sum| sum.to_f64().unwrap())
                     } else if no_nulls {
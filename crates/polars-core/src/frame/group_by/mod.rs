@@ -193,6 +193,8 @@ pub struct GroupBy<'a> {
     groups: GroupPositions,
     // columns selected for aggregation
     pub(crate) selected_agg: Option<Vec<PlSmallStr>>,
+    // keys per group, if groups were added that do not occur in `df`
+    expanded_keys: Option<Vec<Column>>,
 }
 
 impl<'a> GroupBy<'a> {
@@ -207,6 +209,7 @@ impl<'a> GroupBy<'a> {
             selected_keys: by,
             groups,
             selected_agg,
+            expanded_keys: None,
         }
     }
 
@@ -250,6 +253,13 @@ impl<'a> GroupBy<'a> {
     }
 
     pub fn keys_sliced(&self, slice: Option<(i64, usize)>) -> Vec<Column> {
+        if let Some(keys) = &self.expanded_keys {
+            return match slice {
+                Some((offset, len)) => keys.iter().map(|k| k.slice(offset, len)).collect(),
+                None => keys.clone(),
+            };
+        }
+
         #[allow(unused_assignments)]
         // needed to keep the lifetimes valid for this scope
         let mut groups_owned = None;
@@ -306,6 +316,76 @@ impl<'a> GroupBy<'a> {
         self.keys_sliced(None)
     }
 
+    /// Add an empty group for every combination of the categories of the `Enum` keys that does
+    /// not occur in the data, like `observed=False` in pandas.
+    ///
+    /// The other keys, including `Categorical` keys, contribute their observed values to the
+    /// combinations, and null keys are not expanded. The added groups are placed after the
+    /// observed groups.
+    #[cfg(feature = "dtype-categorical")]
+    pub fn with_unobserved_categories(mut self) -> PolarsResult<Self> {
+        if !self.selected_keys.iter().any(|k| k.dtype().is_enum()) {
+            return Ok(self);
+        }
+        let mut keys = self.keys();
+
+        let (levels, codes): (Vec<_>, Vec<_>) = keys
+            .iter()
+            .map(|k| key_levels(k.as_materialized_series()))
+            .collect::<PolarsResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+        let n_combinations = levels
+            .iter()
+            .try_fold(1usize, |n, l| n.checked_mul(l.len()))
+            .filter(|&n| n <= IdxSize::MAX as usize)
+            .ok_or_else(
+                || polars_err!(ComputeError: "too many combinations of categories to expand in group_by"),
+            )?;
+
+        let mut observed = vec![false; n_combinations];
+        'groups: for g in 0..self.groups.len() {
+            let mut combination = 0;
+            for (key_codes, key_levels) in codes.iter().zip(&levels) {
+                let Some(code) = key_codes[g] else {
+                    continue 'groups;
+                };
+                combination = combination * key_levels.len() + code as usize;
+            }
+            observed[combination] = true;
+        }
+
+        let mut take = vec![vec![]; keys.len()];
+        for combination in (0..n_combinations).filter(|&c| !observed[c]) {
+            let mut rest = combination;
+            for (idx, key_levels) in take.iter_mut().zip(&levels).rev() {
+                idx.push((rest % key_levels.len()) as IdxSize);
+                rest /= key_levels.len();
+            }
+        }
+        let n_unobserved = take[0].len();
+        if n_unobserved == 0 {
+            return Ok(self);
+        }
+        for ((key, key_levels), idx) in keys.iter_mut().zip(&levels).zip(take) {
+            key.append_owned(key_levels.take_slice(&idx)?.into_column())?;
+        }
+
+        let mut groups = self
+            .groups
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((first, idx)) => (first, idx.clone()),
+                GroupsIndicator::Slice([first, len]) => (first, (first..first + len).collect()),
+            })
+            .collect::<Vec<IdxItem>>();
+        // Like `GroupsIdx::new_empty`, empty groups point to the first row.
+        groups.resize(groups.len() + n_unobserved, (0, IdxVec::new()));
+        self.groups = GroupsType::Idx(groups.into()).into_sliceable();
+        self.expanded_keys = Some(keys);
+        Ok(self)
+    }
+
     fn prepare_agg(&self) -> PolarsResult<(Vec<Column>, Vec<Column>)> {
         let keys = self.keys();
 
@@ -848,13 +928,70 @@ impl<'a> GroupBy<'a> {
             None => self,
             Some((offset, length)) => {
                 self.groups = (self.groups.slice(offset, length)).clone();
-                self.selected_keys = self.keys_sliced(slice);
+                match &mut self.expanded_keys {
+                    Some(keys) => {
+                        *keys = keys.iter().map(|k| k.slice(offset, length)).collect();
+                        self.selected_keys = keys.clone();
+                    },
+                    None => self.selected_keys = self.keys_sliced(slice),
+                }
                 self
             },
         }
     }
 }
 
+/// The distinct non-null values of a key, and the position of the value of every row in them.
+///
+/// The levels of an `Enum` key are all its categories, whether they occur or not. The mapping of
+/// a `Categorical` key is shared with unrelated data, so only its observed categories are used.
+#[cfg(feature = "dtype-categorical")]
+fn key_levels(key: &Series) -> PolarsResult<(Series, Vec<Option<IdxSize>>)> {
+    if key.dtype().is_enum() {
+        let mapping = key.dtype().cat_mapping()?;
+        let n = mapping.num_cats_upper_bound();
+        let mut positions = vec![None; n];
+        let mut ids = vec![];
+        for id in 0..n {
+            if mapping.cat_to_str(id as CatSize).is_some() {
+                positions[id] = Some(ids.len() as IdxSize);
+                ids.push(id as IdxSize);
+            }
+        }
+        let ids = IdxCa::from_vec(key.name().clone(), ids)
+            .into_series()
+            .cast(&key.dtype().cat_physical()?.dtype())?;
+        // SAFETY: the ids are categories of the mapping.
+        let levels = unsafe { ids.from_physical_unchecked(key.dtype())? };
+        let codes = key
+            .to_physical_repr()
+            .cast(&IDX_DTYPE)?
+            .idx()?
+            .iter()
+            .map(|id| id.and_then(|id| positions.get(id as usize).copied().flatten()))
+            .collect();
+        return Ok((levels, codes));
+    }
+
+    let is_valid = key.is_not_null();
+    let mut codes = vec![None; key.len()];
+    let mut firsts = vec![];
+    for g in key.group_tuples(true, true)?.iter() {
+        if is_valid.get(g.first() as usize) != Some(true) {
+            continue;
+        }
+        let code = Some(firsts.len() as IdxSize);
+        firsts.push(g.first());
+        match g {
+            GroupsIndicator::Idx((_, idx)) => idx.iter().for_each(|&i| codes[i as usize] = code),
+            GroupsIndicator::Slice([first, len]) => {
+                codes[first as usize..(first + len) as usize].fill(code)
+            },
+        }
+    }
+    Ok((key.take_slice(&firsts)?, codes))
+}
+
 unsafe fn take_df(df: &DataFrame, g: GroupsIndicator) -> DataFrame {
     match g {
         GroupsIndicator::Idx(idx) => df.take_slice_unchecked(idx.1),
@@ -876,10 +1013,34 @@ pub enum GroupByMethod {
     Groups,
     NUnique,
     Quantile(f64, QuantileMethod),
-    Count { include_nulls: bool },
+    Count {
+        include_nulls: bool,
+    },
     Implode,
     Std(u8),
     Var(u8),
+    #[cfg(feature = "bitwise")]
+    Bitwise(GroupByBitwiseMethod),
+}
+
+#[cfg(feature = "bitwise")]
+#[derive(Copy, Clone, Debug)]
+pub enum GroupByBitwiseMethod {
+    And,
+    Or,
+    Xor,
+}
+
+#[cfg(feature = "bitwise")]
+impl Display for GroupByBitwiseMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::And => "bitwise_and",
+            Self::Or => "bitwise_or",
+            Self::Xor => "bitwise_xor",
+        };
+        write!(f, "{s}")
+    }
 }
 
 impl Display for GroupByMethod {
@@ -902,6 +1063,8 @@ impl Display for GroupByMethod {
             Implode => "list",
             Std(_) => "std",
             Var(_) => "var",
+            #[cfg(feature = "bitwise")]
+            Bitwise(method) => return write!(f, "{method}"),
         };
         write!(f, "{s}")
     }
@@ -927,6 +1090,8 @@ pub fn fmt_group_by_column(name: &str, method: GroupByMethod) -> PlSmallStr {
         Quantile(quantile, _interpol) => format_pl_smallstr!("{name}_quantile_{quantile:.2}"),
         Std(_) => format_pl_smallstr!("{name}_agg_std"),
         Var(_) => format_pl_smallstr!("{name}_agg_var"),
+        #[cfg(feature = "bitwise")]
+        Bitwise(method) => format_pl_smallstr!("{name}_{method}"),
     }
 }
 
@@ -1206,4 +1371,52 @@ mod test {
         let _ = df.group_by(["g"])?.sum()?;
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    #[cfg(feature = "dtype-categorical")]
+    fn test_group_by_unobserved_categories() -> PolarsResult<()> {
+        let dtype = DataType::from_frozen_categories(FrozenCategories::new(["a", "b", "c"])?);
+        let mut df = df![
+            "g" => [Some("c"), Some("a"), Some("c"), None],
+            "h" => [1, 1, 2, 1],
+            "int" => [1, 2, 3, 4]
+        ]?;
+        df.try_apply("g", |s| s.cast(&dtype))?;
+
+        let gb = df.group_by_stable(["g"])?.with_unobserved_categories()?;
+        assert_eq!(
+            gb.keys()[0].cast(&DataType::String)?,
+            Column::new("g".into(), [Some("c"), Some("a"), None, Some("b")])
+        );
+        assert_eq!(
+            gb.get_groups()
+                .group_count()
+                .into_no_null_iter()
+                .collect::<Vec<_>>(),
+            [2, 1, 1, 0]
+        );
+        #[allow(deprecated)]
+        let out = gb.select(["int"]).sum()?;
+        assert_eq!(
+            out.column("int_sum")?,
+            &Column::new("int_sum".into(), [4, 2, 4, 0])
+        );
+
+        // Every combination with the observed values of the other keys is created.
+        let gb = df
+            .group_by_stable(["g", "h"])?
+            .with_unobserved_categories()?;
+        let keys = DataFrame::new(gb.keys())?;
+        assert_eq!(keys.height(), 7);
+        assert_eq!(
+            keys.slice(4, 3).column("g")?.cast(&DataType::String)?,
+            Column::new("g".into(), ["a", "b", "b"])
+        );
+        assert_eq!(
+            keys.slice(4, 3).column("h")?,
+            &Column::new("h".into(), [2, 1, 2])
+        );
+        Ok(())
+    }
 }
@@ -10,6 +10,7 @@ use polars_utils::itertools::Itertools;
 use rayon::prelude::*;
 
 use crate::chunked_array::flags::StatisticsFlags;
+use crate::chunked_array::ops::row_encode::encode_rows_vertical_par_unordered;
 #[cfg(feature = "algorithm_group_by")]
 use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::prelude::*;
@@ -20,8 +21,10 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
+mod arrow_export;
 pub mod builder;
 mod chunks;
+pub use arrow_export::ArrowExportOptions;
 pub use chunks::chunk_df_for_writing;
 pub mod column;
 pub mod explode;
@@ -32,6 +35,7 @@ pub(crate) mod horizontal;
 #[cfg(any(feature = "rows", feature = "object"))]
 pub mod row;
 mod top_k;
+pub mod typed;
 mod upstream_traits;
 mod validation;
 
@@ -64,6 +68,17 @@ pub enum UniqueKeepStrategy {
     Any,
 }
 
+/// Position of a column inserted with [`DataFrame::insert_columns`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InsertPosition {
+    /// Insert at this index of the existing columns.
+    Index(usize),
+    /// Insert directly before the existing column with this name.
+    Before(PlSmallStr),
+    /// Insert directly after the existing column with this name.
+    After(PlSmallStr),
+}
+
 fn ensure_names_unique<T, F>(items: &[T], mut get_name: F) -> PolarsResult<()>
 where
     F: for<'a> FnMut(&'a T) -> &'a str,
@@ -486,6 +501,40 @@ impl DataFrame {
         DataFrame::new(columns)
     }
 
+    /// Add a new column at index 0 that counts the rows with a 64-bit integer.
+    ///
+    /// The index starts at `start` and increments by `step`, which may be negative. `dtype`
+    /// must be [`DataType::Int64`] or [`DataType::UInt64`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// let df1: DataFrame = df!("Name" => ["James", "Mary", "John"])?;
+    ///
+    /// let df2 = df1.with_row_index_stepped("Id".into(), 5_000_000_000, 10, &DataType::Int64)?;
+    /// assert_eq!(
+    ///     df2.column("Id")?.i64()?.to_vec(),
+    ///     &[Some(5_000_000_000), Some(5_000_000_010), Some(5_000_000_020)]
+    /// );
+    ///
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn with_row_index_stepped(
+        &self,
+        name: PlSmallStr,
+        start: i64,
+        step: i64,
+        dtype: &DataType,
+    ) -> PolarsResult<Self> {
+        let mut columns = Vec::with_capacity(self.columns.len() + 1);
+
+        let col = Column::new_stepped_row_index(name, start, step, self.height(), dtype)?;
+        columns.push(col);
+        columns.extend_from_slice(&self.columns);
+        DataFrame::new(columns)
+    }
+
     /// Add a row index column in place.
     ///
     /// # Safety
@@ -592,6 +641,35 @@ impl DataFrame {
         }
     }
 
+    /// Split all columns into chunks of exactly `chunk_size` rows, except for the last chunk which
+    /// holds the remainder.
+    ///
+    /// Afterwards, all columns share the same chunk layout, so [`DataFrame::should_rechunk`]
+    /// returns `false` and binary operations between columns don't need to rechunk.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2, 3, 4, 5], "b" => ["x", "y", "z", "u", "v"])?;
+    /// df.rechunk_to_chunksize(2)?;
+    ///
+    /// let lengths: Vec<_> = df.column("a")?.as_materialized_series().chunk_lengths().collect();
+    /// assert_eq!(lengths, [2, 2, 1]);
+    /// assert!(!df.should_rechunk());
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn rechunk_to_chunksize(&mut self, chunk_size: usize) -> PolarsResult<&mut Self> {
+        polars_ensure!(chunk_size > 0, InvalidOperation: "'chunk_size' must be greater than 0");
+
+        // SAFETY: We never adjust the length or names of the columns.
+        let columns = unsafe { self.get_columns_mut() };
+        for col in columns.iter_mut() {
+            *col = col.rechunk_to_chunksize(chunk_size)?;
+        }
+        Ok(self)
+    }
+
     pub fn _deshare_views_mut(&mut self) {
         // SAFETY: We never adjust the length or names of the columns.
         unsafe {
@@ -1441,6 +1519,108 @@ impl DataFrame {
         self.insert_column_no_name_check(index, column)
     }
 
+    /// Insert multiple columns at once.
+    ///
+    /// All `positions` refer to the columns of the [`DataFrame`] before the insertion, columns
+    /// inserted at the same position keep their relative order. The column vector is rebuilt
+    /// once and no columns are rechunked, which is much cheaper than repeated calls to
+    /// [`DataFrame::insert_column`] when adding many columns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let mut df = df!("a" => [1, 2], "d" => [4, 5])?;
+    /// df.insert_columns(
+    ///     &[InsertPosition::After("a".into()), InsertPosition::Index(1)],
+    ///     [Column::new("b".into(), [2, 3]), Column::new("c".into(), [3, 4])],
+    /// )?;
+    /// assert_eq!(df.get_column_names(), &["a", "b", "c", "d"]);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn insert_columns<I, S>(
+        &mut self,
+        positions: &[InsertPosition],
+        columns: I,
+    ) -> PolarsResult<&mut Self>
+    where
+        I: IntoIterator<Item = S>,
+        S: IntoColumn,
+    {
+        let columns = columns
+            .into_iter()
+            .map(IntoColumn::into_column)
+            .collect::<Vec<_>>();
+        polars_ensure!(
+            positions.len() == columns.len(),
+            ShapeMismatch: "got {} positions for {} columns to insert",
+            positions.len(), columns.len(),
+        );
+        if columns.is_empty() {
+            return Ok(self);
+        }
+
+        ensure_names_unique(&columns, |c| c.name().as_str())?;
+        let schema = self.schema();
+        for c in &columns {
+            polars_ensure!(
+                !schema.contains(c.name()),
+                Duplicate: "column with name {:?} is already present in the DataFrame", c.name()
+            );
+        }
+
+        let height = if self.width() == 0 {
+            columns[0].len()
+        } else {
+            self.height()
+        };
+        for c in &columns {
+            polars_ensure!(
+                c.len() == height,
+                ShapeMismatch: "unable to add a column of length {} to a DataFrame of height {}",
+                c.len(), height,
+            );
+        }
+
+        let mut inserts = positions
+            .iter()
+            .map(|position| {
+                let idx = match position {
+                    InsertPosition::Index(idx) => {
+                        polars_ensure!(
+                            *idx <= self.width(),
+                            OutOfBounds: "insert index {} is out of bounds for a DataFrame of width {}",
+                            idx, self.width(),
+                        );
+                        *idx
+                    },
+                    InsertPosition::Before(name) => self.check_name_to_idx(name)?,
+                    InsertPosition::After(name) => self.check_name_to_idx(name)? + 1,
+                };
+                Ok(idx)
+            })
+            .zip(columns)
+            .map(|(idx, c)| Ok((idx?, c)))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        // Stable, so columns at the same position keep their order.
+        inserts.sort_by_key(|(idx, _)| *idx);
+
+        let mut existing = std::mem::take(&mut self.columns).into_iter();
+        let mut new_columns = Vec::with_capacity(existing.len() + inserts.len());
+        let mut offset = 0;
+        for (idx, column) in inserts {
+            new_columns.extend(existing.by_ref().take(idx - offset));
+            offset = idx;
+            new_columns.push(column);
+        }
+        new_columns.extend(existing);
+
+        self.height = height;
+        self.columns = new_columns;
+        self.clear_schema();
+        Ok(self)
+    }
+
     fn add_column_by_search(&mut self, column: Column) -> PolarsResult<()> {
         if let Some(idx) = self.get_column_index(column.name().as_str()) {
             self.replace_column(idx, column)?;
@@ -3091,6 +3271,58 @@ impl DataFrame {
         Ok(unsafe { DataFrame::new_no_checks(height, columns) })
     }
 
+    /// Split the rows into the rows of which the `subset` occurs once, which
+    /// [`UniqueKeepStrategy::None`] keeps, and a report of the duplicated keys with the number of
+    /// rows they occur in, in a `count` column. Both are computed from a single group-by.
+    ///
+    /// The unique rows keep their order. With `maintain_order` the report is in the order of the
+    /// first occurrence of the keys.
+    #[cfg(feature = "algorithm_group_by")]
+    pub fn unique_with_duplicates(
+        &self,
+        subset: Option<Vec<PlSmallStr>>,
+        maintain_order: bool,
+    ) -> PolarsResult<(DataFrame, DataFrame)> {
+        let names = subset.unwrap_or_else(|| self.get_column_names_owned());
+        let mut df = self.clone();
+        // take on multiple chunks is terrible
+        df.as_single_chunk_par();
+
+        let gb = if maintain_order {
+            df.group_by_stable(names.clone())?
+        } else {
+            df.group_by(names.clone())?
+        };
+        let mut unique_idx = Vec::new();
+        let mut duplicate_idx = Vec::new();
+        let mut counts = Vec::new();
+        for g in gb.get_groups().iter() {
+            let (first, len) = match g {
+                GroupsIndicator::Idx((first, idx)) => (first, idx.len() as IdxSize),
+                GroupsIndicator::Slice([first, len]) => (first, len),
+            };
+            if len == 1 {
+                unique_idx.push(first);
+            } else {
+                duplicate_idx.push(first);
+                counts.push(len);
+            }
+        }
+        if !maintain_order {
+            unique_idx.sort_unstable();
+        }
+
+        let unique_idx = IdxCa::from_vec(PlSmallStr::EMPTY, unique_idx);
+        let duplicate_idx = IdxCa::from_vec(PlSmallStr::EMPTY, duplicate_idx);
+        // SAFETY: the indices are rows of the groups.
+        let unique = unsafe { df.take_unchecked(&unique_idx) };
+        let mut duplicates = unsafe { df.select(names)?.take_unchecked(&duplicate_idx) };
+        duplicates.hstack_mut(&[IdxCa::from_vec(PlSmallStr::from_static("count"), counts)
+            .into_series()
+            .into_column()])?;
+        Ok((unique, duplicates))
+    }
+
     /// Get a mask of all the unique rows in the [`DataFrame`].
     ///
     /// # Example
@@ -3141,6 +3373,68 @@ impl DataFrame {
         ))
     }
 
+    /// Get the columns of `subset`, or all columns if it is `None`.
+    fn n_unique_columns(&self, subset: Option<&[PlSmallStr]>) -> PolarsResult<Vec<Column>> {
+        match subset {
+            Some(names) => self.select_columns(names.iter().cloned()),
+            None => Ok(self.columns.clone()),
+        }
+    }
+
+    /// Count the number of unique rows, or the number of unique combinations of the columns in
+    /// `subset`.
+    ///
+    /// Multiple columns are counted on their row encoding, without combining them into a struct
+    /// column first. Null values count as a distinct value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use polars_core::prelude::*;
+    /// let df: DataFrame = df!("a" => [1, 1, 2],
+    ///                         "b" => ["x", "x", "y"])?;
+    /// assert_eq!(df.n_unique(None)?, 2);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn n_unique(&self, subset: Option<&[PlSmallStr]>) -> PolarsResult<usize> {
+        let columns = self.n_unique_columns(subset)?;
+        if self.height() == 0 {
+            return Ok(0);
+        }
+        match columns.as_slice() {
+            [] => Ok(1),
+            [c] => c.n_unique(),
+            columns => {
+                let rows = encode_rows_vertical_par_unordered(columns)?;
+                let mut set = PlHashSet::with_capacity(rows.len());
+                for arr in rows.downcast_iter() {
+                    set.extend(arr.values_iter());
+                }
+                Ok(set.len())
+            },
+        }
+    }
+
+    /// Estimate the number of unique rows, or the number of unique combinations of the columns in
+    /// `subset`, with the HyperLogLog++ algorithm.
+    ///
+    /// Like [`DataFrame::n_unique`], multiple columns are estimated on their row encoding.
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique(&self, subset: Option<&[PlSmallStr]>) -> PolarsResult<IdxSize> {
+        let columns = self.n_unique_columns(subset)?;
+        if self.height() == 0 {
+            return Ok(0);
+        }
+        match columns.as_slice() {
+            [] => Ok(1),
+            [c] => c.approx_n_unique(),
+            columns => {
+                let rows = encode_rows_vertical_par_unordered(columns)?;
+                Ok(ChunkApproxNUnique::approx_n_unique(&rows))
+            },
+        }
+    }
+
     /// Create a new [`DataFrame`] that shows the null counts per column.
     #[must_use]
     pub fn null_count(&self) -> Self {
@@ -3169,6 +3463,17 @@ impl DataFrame {
         Ok(acc_ca.rechunk().into_owned())
     }
 
+    /// Hash and combine the row values with a hash that is stable across platforms and
+    /// Polars versions, so that it can be persisted. See [`STABLE_ROW_HASH_VERSION`] for the
+    /// version of the algorithm.
+    ///
+    /// [`STABLE_ROW_HASH_VERSION`]: crate::hashing::STABLE_ROW_HASH_VERSION
+    #[cfg(feature = "row_hash")]
+    pub fn hash_rows_stable(&self, seed: u64) -> PolarsResult<UInt64Chunked> {
+        let hashes = crate::hashing::stable::stable_hash_rows(&self.columns, self.height(), seed)?;
+        Ok(UInt64Chunked::from_vec(PlSmallStr::EMPTY, hashes))
+    }
+
     /// Get the supertype of the columns in this DataFrame
     pub fn get_supertype(&self) -> Option<PolarsResult<DataType>> {
         self.columns
@@ -3580,6 +3885,35 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn rechunk_to_chunksize() -> PolarsResult<()> {
+        let mut a = Series::new("a".into(), [1, 2, 3]);
+        a.append(&Series::new("a".into(), [4, 5, 6, 7]))?;
+        let mut df = DataFrame::new(vec![
+            a.into(),
+            Series::new("b".into(), [1, 1, 1, 1, 1, 1, 1]).into(),
+        ])?;
+        assert!(df.should_rechunk());
+
+        df.rechunk_to_chunksize(3)?;
+        assert!(!df.should_rechunk());
+        for c in df.get_columns() {
+            let lengths: Vec<_> = c.as_materialized_series().chunk_lengths().collect();
+            assert_eq!(lengths, [3, 3, 1]);
+        }
+
+        // Aligned inputs are zipped chunk by chunk.
+        let out = (df.column("a")? + df.column("b")?)?;
+        assert_eq!(out.n_chunks(), 3);
+        assert!(
+            out.as_materialized_series()
+                .equals(&Series::new("a".into(), [2, 3, 4, 5, 6, 7, 8]))
+        );
+
+        assert!(df.rechunk_to_chunksize(0).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_duplicate_column() {
         let mut df = df! {
@@ -3684,6 +4018,22 @@ mod test {
         assert!(out.equals(&expected));
     }
 
+    #[test]
+    fn test_unique_with_duplicates() -> PolarsResult<()> {
+        let df = df! {
+            "x" => [1, 2, 3, 2, 1, 2],
+            "y" => ["a", "b", "c", "d", "e", "f"]
+        }?;
+        let (unique, duplicates) = df.unique_with_duplicates(Some(vec!["x".into()]), true)?;
+        assert!(unique.equals(&df! {"x" => [3], "y" => ["c"]}?));
+        let expected = df! {
+            "x" => [1, 2],
+            "count" => [2 as IdxSize, 3]
+        }?;
+        assert!(duplicates.equals(&expected));
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "dtype-i8")]
     fn test_apply_result_schema() {
@@ -3696,4 +4046,80 @@ mod test {
         df.apply("x", |f| f.cast(&DataType::Int8).unwrap()).unwrap();
         assert_ne!(&schema_before, df.schema());
     }
+
+    #[test]
+    fn test_n_unique() -> PolarsResult<()> {
+        let df = df! {
+            "a" => [Some(1), Some(1), None, None, Some(2)],
+            "b" => ["x", "x", "y", "y", "x"],
+            "c" => [1, 2, 3, 3, 1]
+        }?;
+        assert_eq!(df.n_unique(None)?, 4);
+        assert_eq!(df.n_unique(Some(&["a".into(), "b".into()]))?, 3);
+        assert_eq!(df.n_unique(Some(&["b".into()]))?, 2);
+        assert_eq!(df.n_unique(Some(&[]))?, 1);
+        assert_eq!(df.clear().n_unique(None)?, 0);
+        assert!(df.n_unique(Some(&["d".into()])).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_columns() -> PolarsResult<()> {
+        let mut df = df! {
+            "a" => [1, 2],
+            "c" => [3, 4],
+            "e" => [5, 6]
+        }?;
+        df.insert_columns(
+            &[
+                InsertPosition::Index(3),
+                InsertPosition::Before("c".into()),
+                InsertPosition::After("c".into()),
+                InsertPosition::Index(0),
+                InsertPosition::Index(3),
+            ],
+            [
+                Column::new("f".into(), [0, 0]),
+                Column::new("b".into(), [0, 0]),
+                Column::new("d".into(), [0, 0]),
+                Column::new("_".into(), [0, 0]),
+                Column::new("g".into(), [0, 0]),
+            ],
+        )?;
+        assert_eq!(
+            df.get_column_names(),
+            &["_", "a", "b", "c", "d", "e", "f", "g"]
+        );
+        assert_eq!(df.schema().len(), 8);
+
+        let c = || Column::new("x".into(), [0, 0]);
+        assert!(
+            df.insert_columns(&[InsertPosition::Index(9)], [c()])
+                .is_err()
+        );
+        assert!(
+            df.insert_columns(&[InsertPosition::After("z".into())], [c()])
+                .is_err()
+        );
+        assert!(
+            df.insert_columns(&[InsertPosition::Index(0)], [c(), c()])
+                .is_err()
+        );
+        assert!(
+            df.insert_columns(
+                &[InsertPosition::Index(0)],
+                [Column::new("a".into(), [0, 0])]
+            )
+            .is_err()
+        );
+        assert!(
+            df.insert_columns(&[InsertPosition::Index(0)], [Column::new("x".into(), [0])])
+                .is_err()
+        );
+
+        let mut empty = DataFrame::empty();
+        empty.insert_columns(&[InsertPosition::Index(0)], [c()])?;
+        assert_eq!(empty.shape(), (2, 1));
+        Ok(())
+    }
 }
@@ -50,6 +50,12 @@ impl DataFrame {
                 // this requires to support `Object` in Series::iter which we don't yet
                 polars_bail!(InvalidOperation: "Object dtype not supported in 'transpose'")
             },
+            _ if new_height
+                .checked_mul(new_width)
+                .is_some_and(|n| n <= IdxSize::MAX as usize) =>
+            {
+                gather_transpose(cols, dtype, names_out, &mut cols_t)?
+            },
             _ => {
                 let phys_dtype = dtype.to_physical();
                 let mut buffers = (0..new_width)
@@ -66,7 +72,7 @@ impl DataFrame {
                     .collect::<Vec<_>>();
 
                 // this is very expensive. A lot of cache misses here.
-                // This is the part that is performance critical.
+                // Only used if the frame has too many values to be indexed by `IdxSize`.
                 for s in columns {
                     polars_ensure!(s.dtype() == &phys_dtype, ComputeError: "cannot transpose with supertype: {}", dtype);
                     s.iter().zip(buffers.iter_mut()).for_each(|(av, buf)| {
@@ -159,6 +165,49 @@ unsafe fn add_value<T: NumericNative>(
     *el_ptr.add(row_idx) = value;
 }
 
+// Transpose for non-numeric types. All columns are cast to the supertype and stacked into a
+// single contiguous series, from which every output column is gathered with a strided index.
+// This works on the arrays directly, instead of pushing every value as an `AnyValue`.
+fn gather_transpose(
+    cols: &[Column],
+    dtype: &DataType,
+    names_out: &[PlSmallStr],
+    cols_t: &mut Vec<Column>,
+) -> PolarsResult<()> {
+    let new_width = cols[0].len();
+    let new_height = cols.len();
+
+    let columns = POOL.install(|| {
+        cols.par_iter()
+            .map(|c| {
+                // first cast to supertype to ensure units and categories are correct
+                let s = c.as_materialized_series().cast(dtype)?;
+                polars_ensure!(s.dtype() == dtype, ComputeError: "cannot transpose with supertype: {}", dtype);
+                Ok(s)
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let mut stacked = Series::new_empty(PlSmallStr::EMPTY, dtype);
+    for s in columns {
+        stacked.append_owned(s)?;
+    }
+    let stacked = stacked.rechunk();
+
+    let par_iter = names_out.par_iter().enumerate().map(|(col_idx, name)| {
+        let idx = (0..new_height)
+            .map(|row_idx| (row_idx * new_width + col_idx) as IdxSize)
+            .collect::<Vec<_>>();
+        // SAFETY: the caller ensured that all indices fit in `IdxSize` and they are in bounds
+        // as every column has `new_width` values.
+        let mut s = unsafe { stacked.take_slice_unchecked(&idx) };
+        s.rename(name.clone());
+        s.into_column()
+    });
+    POOL.install(|| cols_t.par_extend(par_iter));
+    Ok(())
+}
+
 // This just fills a pre-allocated mutable series vector, which may have a name column.
 // Nothing is returned and the actual DataFrame is constructed above.
 pub(super) fn numeric_transpose<T: PolarsNumericType>(
@@ -306,6 +355,19 @@ mod test {
 
         ]?;
         assert!(out.equals_missing(&expected));
+
+        let mut df = df![
+            "a" => [Some(true), None],
+            "b" => [false, true],
+            "c" => [true, true],
+        ]?;
+        let out = df.transpose(Some("name"), None)?;
+        let expected = df![
+            "name" => ["a", "b", "c"],
+            "column_0" => [Some(true), Some(false), Some(true)],
+            "column_1" => [None, Some(true), Some(true)],
+        ]?;
+        assert!(out.equals_missing(&expected));
         Ok(())
     }
 }
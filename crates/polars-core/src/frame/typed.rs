@@ -0,0 +1,168 @@
+//! Traits to convert between a [`DataFrame`] and a `Vec` of a Rust struct of which each field is a
+//! column. They are usually implemented with the `IntoDataFrame` and `FromDataFrame` derive macros
+//! of the `polars` crate.
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::*;
+
+/// A Rust type of which a `Vec` can be stored in a column, and read back from it.
+pub trait ColumnValue: Sized {
+    /// The values as a [`Series`] of their natural type.
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series;
+
+    /// Read the values of `column`, which is cast to the natural type of `Self` first. Casts that
+    /// don't fit the values are an error.
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>>;
+}
+
+/// Create a [`DataFrame`] with a column for each field of `Self`.
+pub trait IntoDataFrame: Sized {
+    fn into_df(rows: Vec<Self>) -> PolarsResult<DataFrame>;
+}
+
+/// Read each row of a [`DataFrame`] into `Self`, of which the fields are read from the columns
+/// with their name.
+pub trait FromDataFrame: Sized {
+    fn from_df(df: &DataFrame) -> PolarsResult<Vec<Self>>;
+}
+
+fn cast_no_nulls(column: &Column, dtype: &DataType, ty: &str) -> PolarsResult<Series> {
+    polars_ensure!(
+        column.null_count() == 0,
+        ComputeError: "column {:?} contains nulls, which cannot be read into a {ty}; use an Option",
+        column.name()
+    );
+    Ok(column.as_materialized_series().strict_cast(dtype)?)
+}
+
+macro_rules! impl_numeric {
+    ($($native:ty => $ca:ty, $dtype:expr, $downcast:ident);* $(;)?) => {
+        $(
+            impl ColumnValue for $native {
+                fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+                    <$ca>::from_vec(name, values).into_series()
+                }
+
+                fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+                    let s = cast_no_nulls(column, &$dtype, stringify!($native))?;
+                    Ok(s.$downcast()?.into_no_null_iter().collect())
+                }
+            }
+
+            impl ColumnValue for Option<$native> {
+                fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+                    <$ca>::from_slice_options(name, &values).into_series()
+                }
+
+                fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+                    let s = column.as_materialized_series().strict_cast(&$dtype)?;
+                    Ok(s.$downcast()?.into_iter().collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric! {
+    i32 => Int32Chunked, DataType::Int32, i32;
+    i64 => Int64Chunked, DataType::Int64, i64;
+    u32 => UInt32Chunked, DataType::UInt32, u32;
+    u64 => UInt64Chunked, DataType::UInt64, u64;
+    f32 => Float32Chunked, DataType::Float32, f32;
+    f64 => Float64Chunked, DataType::Float64, f64;
+}
+
+#[cfg(feature = "dtype-i8")]
+impl_numeric! {
+    i8 => Int8Chunked, DataType::Int8, i8;
+}
+
+#[cfg(feature = "dtype-i16")]
+impl_numeric! {
+    i16 => Int16Chunked, DataType::Int16, i16;
+}
+
+#[cfg(feature = "dtype-i128")]
+impl_numeric! {
+    i128 => Int128Chunked, DataType::Int128, i128;
+}
+
+#[cfg(feature = "dtype-u8")]
+impl_numeric! {
+    u8 => UInt8Chunked, DataType::UInt8, u8;
+}
+
+#[cfg(feature = "dtype-u16")]
+impl_numeric! {
+    u16 => UInt16Chunked, DataType::UInt16, u16;
+}
+
+impl ColumnValue for bool {
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        BooleanChunked::from_slice(name, &values).into_series()
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        let s = cast_no_nulls(column, &DataType::Boolean, "bool")?;
+        Ok(s.bool()?.into_no_null_iter().collect())
+    }
+}
+
+impl ColumnValue for Option<bool> {
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        BooleanChunked::from_slice_options(name, &values).into_series()
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        let s = column
+            .as_materialized_series()
+            .strict_cast(&DataType::Boolean)?;
+        Ok(s.bool()?.into_iter().collect())
+    }
+}
+
+impl ColumnValue for String {
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        StringChunked::from_slice(name, &values).into_series()
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        let s = cast_no_nulls(column, &DataType::String, "String")?;
+        Ok(s.str()?.into_no_null_iter().map(str::to_string).collect())
+    }
+}
+
+impl ColumnValue for Option<String> {
+    fn into_series(name: PlSmallStr, values: Vec<Self>) -> Series {
+        StringChunked::from_slice_options(name, &values).into_series()
+    }
+
+    fn from_column(column: &Column) -> PolarsResult<Vec<Self>> {
+        let s = column
+            .as_materialized_series()
+            .strict_cast(&DataType::String)?;
+        Ok(s.str()?
+            .into_iter()
+            .map(|v| v.map(str::to_string))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_column_value_roundtrip() -> PolarsResult<()> {
+        let s = <Option<i32>>::into_series("a".into(), vec![Some(1), None]);
+        let c = s.into_column();
+        assert_eq!(<Option<i64>>::from_column(&c)?, [Some(1), None]);
+        assert!(i64::from_column(&c).is_err());
+
+        let c = u32::into_series("a".into(), vec![1, u32::MAX]).into_column();
+        assert_eq!(u64::from_column(&c)?, [1, u32::MAX as u64]);
+        assert!(i32::from_column(&c).is_err());
+        Ok(())
+    }
+}
@@ -1,9 +1,13 @@
 mod identity;
+#[cfg(feature = "row_hash")]
+pub(crate) mod stable;
 pub(crate) mod vector_hasher;
 
 use std::hash::{BuildHasherDefault, Hash, Hasher};
 
 pub use identity::*;
+#[cfg(feature = "row_hash")]
+pub use stable::STABLE_ROW_HASH_VERSION;
 pub use vector_hasher::*;
 
 // We must strike a balance between cache
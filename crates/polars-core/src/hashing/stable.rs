@@ -0,0 +1,212 @@
+//! Row hashes that are stable across platforms and Polars versions.
+//!
+//! Version 1 of the algorithm hashes every value with XXH3-64 using the given seed. A null value
+//! is encoded as the byte `0` and a valid value as the byte `1` followed by:
+//! * the little-endian bytes of the physical value for numeric, decimal and temporal types, with
+//!   `-0.0` hashed as `0.0` and all NaNs hashed as the same NaN;
+//! * a single byte for booleans;
+//! * the bytes of strings and binary values, categoricals and enums are hashed as strings;
+//! * the number of elements as a little-endian `u64` followed by the little-endian hashes of the
+//!   elements for lists and arrays;
+//! * the little-endian row hash of the fields for structs.
+//!
+//! The hash of a row folds the hashes of its values: starting from the seed, every value
+//! replaces the accumulated hash with the hash of the little-endian bytes of the accumulated
+//! hash followed by those of the hash of the value.
+//!
+//! These hashes may be persisted, so the algorithm must never change. A different algorithm
+//! must be added as a new version.
+use arrow::bitmap::Bitmap;
+use rayon::prelude::*;
+use xxhash_rust::xxh3::xxh3_64_with_seed;
+
+use crate::POOL;
+use crate::prelude::*;
+
+/// The version of the algorithm used by [`DataFrame::hash_rows_stable`].
+pub const STABLE_ROW_HASH_VERSION: u32 = 1;
+
+pub(crate) fn stable_hash_rows(
+    columns: &[Column],
+    height: usize,
+    seed: u64,
+) -> PolarsResult<Vec<u64>> {
+    let column_hashes = POOL.install(|| {
+        columns
+            .par_iter()
+            .map(|c| stable_hash_values(c.as_materialized_series(), seed))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let mut hashes = vec![seed; height];
+    for h in &column_hashes {
+        fold_hashes(&mut hashes, h, seed);
+    }
+    Ok(hashes)
+}
+
+fn fold_hashes(acc: &mut [u64], hashes: &[u64], seed: u64) {
+    let mut buf = [0u8; 16];
+    for (a, h) in acc.iter_mut().zip(hashes) {
+        buf[..8].copy_from_slice(&a.to_le_bytes());
+        buf[8..].copy_from_slice(&h.to_le_bytes());
+        *a = xxh3_64_with_seed(&buf, seed);
+    }
+}
+
+fn hash_iter<B: AsRef<[u8]>>(iter: impl Iterator<Item = Option<B>>, seed: u64) -> Vec<u64> {
+    let mut buf = Vec::new();
+    iter.map(|v| {
+        buf.clear();
+        match v {
+            None => buf.push(0),
+            Some(v) => {
+                buf.push(1);
+                buf.extend_from_slice(v.as_ref());
+            },
+        }
+        xxh3_64_with_seed(&buf, seed)
+    })
+    .collect()
+}
+
+/// Hashes the nested values of `ranges` in `inner`, with `validity` of the outer values.
+fn hash_nested(
+    validity: Option<&Bitmap>,
+    ranges: impl Iterator<Item = (usize, usize)>,
+    inner: &[u64],
+    seed: u64,
+) -> Vec<u64> {
+    let mut buf = Vec::new();
+    ranges
+        .enumerate()
+        .map(|(i, (start, end))| {
+            buf.clear();
+            if validity.is_some_and(|v| !v.get_bit(i)) {
+                buf.push(0);
+            } else {
+                buf.push(1);
+                buf.extend_from_slice(&((end - start) as u64).to_le_bytes());
+                for h in &inner[start..end] {
+                    buf.extend_from_slice(&h.to_le_bytes());
+                }
+            }
+            xxh3_64_with_seed(&buf, seed)
+        })
+        .collect()
+}
+
+fn normalize_f32(v: f32) -> f32 {
+    if v.is_nan() {
+        f32::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    }
+}
+
+fn normalize_f64(v: f64) -> f64 {
+    if v.is_nan() {
+        f64::NAN
+    } else if v == 0.0 {
+        0.0
+    } else {
+        v
+    }
+}
+
+fn stable_hash_values(s: &Series, seed: u64) -> PolarsResult<Vec<u64>> {
+    use DataType as D;
+
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        D::Categorical(..) | D::Enum(..) => {
+            return stable_hash_values(&s.cast(&D::String)?, seed);
+        },
+        D::List(_) => {
+            let ca = s.list()?.rechunk();
+            let inner = stable_hash_values(&ca.get_inner(), seed)?;
+            let arr = ca.downcast_as_array();
+            let ranges = arr
+                .offsets()
+                .windows(2)
+                .map(|w| (w[0] as usize, w[1] as usize));
+            return Ok(hash_nested(arr.validity(), ranges, &inner, seed));
+        },
+        #[cfg(feature = "dtype-array")]
+        D::Array(_, width) => {
+            let width = *width;
+            let ca = s.array()?.rechunk();
+            let inner = stable_hash_values(&ca.get_inner(), seed)?;
+            let arr = ca.downcast_as_array();
+            let ranges = (0..arr.len()).map(|i| (i * width, (i + 1) * width));
+            return Ok(hash_nested(arr.validity(), ranges, &inner, seed));
+        },
+        #[cfg(feature = "dtype-struct")]
+        D::Struct(_) => {
+            let ca = s.struct_()?;
+            let fields = ca
+                .fields_as_series()
+                .into_iter()
+                .map(Column::from)
+                .collect::<Vec<_>>();
+            let rows = stable_hash_rows(&fields, ca.len(), seed)?;
+            let validity = ca.rechunk_validity();
+            let mut buf = [1u8; 9];
+            return Ok(rows
+                .iter()
+                .enumerate()
+                .map(|(i, h)| {
+                    if validity.as_ref().is_some_and(|v| !v.get_bit(i)) {
+                        xxh3_64_with_seed(&[0], seed)
+                    } else {
+                        buf[1..].copy_from_slice(&h.to_le_bytes());
+                        xxh3_64_with_seed(&buf, seed)
+                    }
+                })
+                .collect());
+        },
+        _ => {},
+    }
+
+    macro_rules! hash_le_bytes {
+        ($ca:expr) => {
+            hash_iter($ca.iter().map(|v| v.map(|v| v.to_le_bytes())), seed)
+        };
+    }
+
+    let s = s.to_physical_repr();
+    Ok(match s.dtype() {
+        D::Boolean => hash_iter(s.bool()?.iter().map(|v| v.map(|v| [v as u8])), seed),
+        D::UInt8 => hash_le_bytes!(s.u8()?),
+        D::UInt16 => hash_le_bytes!(s.u16()?),
+        D::UInt32 => hash_le_bytes!(s.u32()?),
+        D::UInt64 => hash_le_bytes!(s.u64()?),
+        D::Int8 => hash_le_bytes!(s.i8()?),
+        D::Int16 => hash_le_bytes!(s.i16()?),
+        D::Int32 => hash_le_bytes!(s.i32()?),
+        D::Int64 => hash_le_bytes!(s.i64()?),
+        #[cfg(feature = "dtype-i128")]
+        D::Int128 => hash_le_bytes!(s.i128()?),
+        D::Float32 => hash_iter(
+            s.f32()?
+                .iter()
+                .map(|v| v.map(|v| normalize_f32(v).to_le_bytes())),
+            seed,
+        ),
+        D::Float64 => hash_iter(
+            s.f64()?
+                .iter()
+                .map(|v| v.map(|v| normalize_f64(v).to_le_bytes())),
+            seed,
+        ),
+        D::String => hash_iter(s.str()?.iter(), seed),
+        D::Binary => hash_iter(s.binary()?.iter(), seed),
+        D::BinaryOffset => hash_iter(s.binary_offset()?.iter(), seed),
+        D::Null => vec![xxh3_64_with_seed(&[0], seed); s.len()],
+        dt => polars_bail!(
+            InvalidOperation: "stable row hashes are not supported for dtype {}", dt
+        ),
+    })
+}
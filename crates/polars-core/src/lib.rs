@@ -55,12 +55,17 @@ pub static POOL: LazyLock<ThreadPool> = LazyLock::new(|| {
             std::env::var("POLARS_MAX_THREADS")
                 .map(|s| s.parse::<usize>().expect("integer"))
                 .unwrap_or_else(|_| {
-                    std::thread::available_parallelism()
-                        .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
-                        .get()
+                    polars_utils::affinity::THREAD_AFFINITY
+                        .default_num_threads()
+                        .unwrap_or_else(|| {
+                            std::thread::available_parallelism()
+                                .unwrap_or(std::num::NonZeroUsize::new(1).unwrap())
+                                .get()
+                        })
                 }),
         )
         .thread_name(move |i| format!("{thread_name}-{i}"))
+        .start_handler(|i| polars_utils::affinity::THREAD_AFFINITY.pin_worker(i))
         .build()
         .expect("could not spawn threads")
 });
@@ -10,6 +10,11 @@ pub fn mean_reduce(value: Option<f64>, dtype: DataType) -> Scalar {
             let val = value.map(|m| m as f32);
             Scalar::new(dtype, val.into())
         },
+        #[cfg(feature = "dtype-f16")]
+        DataType::Float16 => {
+            let val = value.map(|m| m as f32);
+            Scalar::new(DataType::Float32, val.into())
+        },
         dt if dt.is_primitive_numeric() || dt.is_decimal() || dt.is_bool() => {
             Scalar::new(DataType::Float64, value.into())
         },
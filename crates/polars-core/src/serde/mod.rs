@@ -1,5 +1,6 @@
 pub mod chunked_array;
 mod df;
+mod rows;
 pub mod series;
 
 #[cfg(test)]
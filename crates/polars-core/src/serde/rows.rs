@@ -0,0 +1,952 @@
+//! Conversion between the rows of a [`DataFrame`] and user types that implement serde's
+//! [`Serialize`] and [`Deserialize`], without going through an [`AnyValue`] per value.
+use std::fmt::{Debug, Display, Formatter};
+
+use arrow::bitmap::Bitmap;
+use polars_error::{PolarsError, PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+use serde::de::value::StrDeserializer;
+use serde::de::{
+    DeserializeOwned, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Visitor,
+};
+use serde::ser::{Impossible, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct};
+use serde::{Deserializer, Serialize, Serializer, forward_to_deserialize_any};
+
+use crate::chunked_array::builder::get_list_builder;
+use crate::prelude::*;
+use crate::utils::try_get_supertype;
+
+/// The error of the (de)serializers, which serde requires to implement its error traits.
+struct Error(PolarsError);
+
+impl Debug for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(polars_err!(ComputeError: "{msg}"))
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(polars_err!(ComputeError: "{msg}"))
+    }
+}
+
+impl From<PolarsError> for Error {
+    fn from(value: PolarsError) -> Self {
+        Self(value)
+    }
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// A column, downcast once to the physical representation the values are read from.
+///
+/// Temporal and decimal values are read as their physical integers, and categoricals as strings.
+struct Reader {
+    values: Values,
+    validity: Option<Bitmap>,
+}
+
+enum Values {
+    Null,
+    Boolean(BooleanChunked),
+    Int32(Int32Chunked),
+    Int64(Int64Chunked),
+    #[cfg(feature = "dtype-i128")]
+    Int128(Int128Chunked),
+    UInt32(UInt32Chunked),
+    UInt64(UInt64Chunked),
+    Float32(Float32Chunked),
+    Float64(Float64Chunked),
+    String(StringChunked),
+    Binary(BinaryChunked),
+    List(ListChunked),
+    /// The fields of a struct column, or the columns of the frame.
+    Struct(Vec<(PlSmallStr, Reader)>),
+}
+
+impl Reader {
+    fn new(s: &Series) -> PolarsResult<Self> {
+        let dtype = s.dtype();
+        let s = if dtype.is_categorical() || dtype.is_enum() {
+            s.cast(&DataType::String)?
+        } else if dtype.is_temporal() || dtype.is_decimal() {
+            s.to_physical_repr().into_owned()
+        } else {
+            s.clone()
+        };
+
+        let values = match s.dtype() {
+            DataType::Null => Values::Null,
+            DataType::Boolean => Values::Boolean(s.bool()?.clone()),
+            DataType::Int8 | DataType::Int16 => {
+                Values::Int32(s.cast(&DataType::Int32)?.i32()?.clone())
+            },
+            DataType::Int32 => Values::Int32(s.i32()?.clone()),
+            DataType::Int64 => Values::Int64(s.i64()?.clone()),
+            #[cfg(feature = "dtype-i128")]
+            DataType::Int128 => Values::Int128(s.i128()?.clone()),
+            DataType::UInt8 | DataType::UInt16 => {
+                Values::UInt32(s.cast(&DataType::UInt32)?.u32()?.clone())
+            },
+            DataType::UInt32 => Values::UInt32(s.u32()?.clone()),
+            DataType::UInt64 => Values::UInt64(s.u64()?.clone()),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => Values::Float32(s.cast(&DataType::Float32)?.f32()?.clone()),
+            DataType::Float32 => Values::Float32(s.f32()?.clone()),
+            DataType::Float64 => Values::Float64(s.f64()?.clone()),
+            DataType::String => Values::String(s.str()?.clone()),
+            DataType::Binary => Values::Binary(s.binary()?.clone()),
+            DataType::BinaryOffset => Values::Binary(s.cast(&DataType::Binary)?.binary()?.clone()),
+            DataType::List(_) => Values::List(s.list()?.clone()),
+            #[cfg(feature = "dtype-array")]
+            DataType::Array(inner, _) => {
+                Values::List(s.cast(&DataType::List(inner.clone()))?.list()?.clone())
+            },
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(_) => Values::Struct(
+                s.struct_()?
+                    .fields_as_series()
+                    .iter()
+                    .map(|field| Ok((field.name().clone(), Self::new(field)?)))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            dt => polars_bail!(InvalidOperation: "cannot deserialize values of type {dt}"),
+        };
+        Ok(Self {
+            values,
+            validity: s.rechunk_validity(),
+        })
+    }
+
+    fn is_null(&self, idx: usize) -> bool {
+        matches!(self.values, Values::Null)
+            || self.validity.as_ref().is_some_and(|v| !v.get_bit(idx))
+    }
+}
+
+/// Deserializes the value at `idx` of a column.
+struct ValueDeserializer<'a> {
+    reader: &'a Reader,
+    idx: usize,
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.reader.is_null(self.idx) {
+            return visitor.visit_unit();
+        }
+        match &self.reader.values {
+            Values::Null => unreachable!(),
+            Values::Boolean(ca) => visitor.visit_bool(ca.get(self.idx).unwrap()),
+            Values::Int32(ca) => visitor.visit_i32(ca.get(self.idx).unwrap()),
+            Values::Int64(ca) => visitor.visit_i64(ca.get(self.idx).unwrap()),
+            #[cfg(feature = "dtype-i128")]
+            Values::Int128(ca) => visitor.visit_i128(ca.get(self.idx).unwrap()),
+            Values::UInt32(ca) => visitor.visit_u32(ca.get(self.idx).unwrap()),
+            Values::UInt64(ca) => visitor.visit_u64(ca.get(self.idx).unwrap()),
+            Values::Float32(ca) => visitor.visit_f32(ca.get(self.idx).unwrap()),
+            Values::Float64(ca) => visitor.visit_f64(ca.get(self.idx).unwrap()),
+            Values::String(ca) => visitor.visit_str(ca.get(self.idx).unwrap()),
+            Values::Binary(ca) => visitor.visit_bytes(ca.get(self.idx).unwrap()),
+            Values::List(ca) => {
+                let values = ca.get_as_series(self.idx).unwrap();
+                let reader = Reader::new(&values)?;
+                visitor.visit_seq(ListAccess {
+                    reader: &reader,
+                    idx: 0,
+                    len: values.len(),
+                })
+            },
+            Values::Struct(fields) => visitor.visit_map(FieldsAccess {
+                fields,
+                idx: self.idx,
+                field: 0,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if self.reader.is_null(self.idx) {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Unit variants are read from strings.
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match &self.reader.values {
+            Values::String(ca) if !self.reader.is_null(self.idx) => {
+                let variant: StrDeserializer<'_, Error> =
+                    ca.get(self.idx).unwrap().into_deserializer();
+                visitor.visit_enum(variant)
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    /// Structs can also be read as tuples, by the position of their fields.
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match &self.reader.values {
+            Values::Struct(fields) if !self.reader.is_null(self.idx) => {
+                visitor.visit_seq(FieldsAccess {
+                    fields,
+                    idx: self.idx,
+                    field: 0,
+                })
+            },
+            _ => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf unit
+        unit_struct map struct identifier ignored_any
+    }
+}
+
+/// The values of a list.
+struct ListAccess<'a> {
+    reader: &'a Reader,
+    idx: usize,
+    len: usize,
+}
+
+impl<'de> SeqAccess<'de> for ListAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.idx == self.len {
+            return Ok(None);
+        }
+        let value = seed.deserialize(ValueDeserializer {
+            reader: self.reader,
+            idx: self.idx,
+        })?;
+        self.idx += 1;
+        Ok(Some(value))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.len - self.idx)
+    }
+}
+
+/// The fields of a struct value, by name or by position.
+struct FieldsAccess<'a> {
+    fields: &'a [(PlSmallStr, Reader)],
+    idx: usize,
+    field: usize,
+}
+
+impl FieldsAccess<'_> {
+    fn next_value<'de, T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value> {
+        let value = seed.deserialize(ValueDeserializer {
+            reader: &self.fields[self.field].1,
+            idx: self.idx,
+        })?;
+        self.field += 1;
+        Ok(value)
+    }
+}
+
+impl<'de> MapAccess<'de> for FieldsAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        let Some((name, _)) = self.fields.get(self.field) else {
+            return Ok(None);
+        };
+        let name: StrDeserializer<'_, Error> = name.as_str().into_deserializer();
+        seed.deserialize(name).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value> {
+        self.next_value(seed)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.field)
+    }
+}
+
+impl<'de> SeqAccess<'de> for FieldsAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        if self.field == self.fields.len() {
+            return Ok(None);
+        }
+        self.next_value(seed).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len() - self.field)
+    }
+}
+
+/// The values of a column that is built from serialized values. The type of the column is
+/// determined by the first value that is not null.
+enum Builder {
+    /// Only nulls so far.
+    Null(usize),
+    Boolean(Vec<Option<bool>>),
+    /// The integers, and the widest integer type that was serialized.
+    Int(Vec<Option<i64>>, DataType),
+    UInt(Vec<Option<u64>>, DataType),
+    Float(Vec<Option<f64>>, DataType),
+    String(Vec<Option<String>>),
+    Binary(Vec<Option<Vec<u8>>>),
+    /// The lengths of the lists, and the builder of their values.
+    List(Vec<Option<usize>>, Box<Builder>),
+    Struct(StructBuilder),
+}
+
+#[derive(Default)]
+struct StructBuilder {
+    fields: Vec<(PlSmallStr, Builder)>,
+    validity: Vec<bool>,
+}
+
+impl StructBuilder {
+    fn push_null(&mut self) {
+        for (_, field) in &mut self.fields {
+            field.push_null();
+        }
+        self.validity.push(false);
+    }
+
+    fn field(&mut self, name: &str) -> &mut Builder {
+        let idx = match self.fields.iter().position(|(n, _)| n.as_str() == name) {
+            Some(idx) => idx,
+            None => {
+                // Fields that are new in this value are null in the values before.
+                let field = Builder::Null(self.validity.len());
+                self.fields.push((name.into(), field));
+                self.fields.len() - 1
+            },
+        };
+        &mut self.fields[idx].1
+    }
+
+    /// Finish a value, in which the fields that were not serialized are null.
+    fn end_value(&mut self) {
+        let len = self.validity.len();
+        for (_, field) in &mut self.fields {
+            if field.len() == len {
+                field.push_null();
+            }
+        }
+        self.validity.push(true);
+    }
+
+    fn finish(self) -> PolarsResult<Vec<Column>> {
+        self.fields
+            .into_iter()
+            .map(|(name, field)| Ok(field.finish(name)?.into_column()))
+            .collect()
+    }
+}
+
+impl Builder {
+    fn len(&self) -> usize {
+        match self {
+            Self::Null(len) => *len,
+            Self::Boolean(v) => v.len(),
+            Self::Int(v, _) => v.len(),
+            Self::UInt(v, _) => v.len(),
+            Self::Float(v, _) => v.len(),
+            Self::String(v) => v.len(),
+            Self::Binary(v) => v.len(),
+            Self::List(lengths, _) => lengths.len(),
+            Self::Struct(builder) => builder.validity.len(),
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Null(_) => "null",
+            Self::Boolean(_) => "boolean",
+            Self::Int(..) => "signed integer",
+            Self::UInt(..) => "unsigned integer",
+            Self::Float(..) => "float",
+            Self::String(_) => "string",
+            Self::Binary(_) => "binary",
+            Self::List(..) => "list",
+            Self::Struct(_) => "struct",
+        }
+    }
+
+    fn push_null(&mut self) {
+        match self {
+            Self::Null(len) => *len += 1,
+            Self::Boolean(v) => v.push(None),
+            Self::Int(v, _) => v.push(None),
+            Self::UInt(v, _) => v.push(None),
+            Self::Float(v, _) => v.push(None),
+            Self::String(v) => v.push(None),
+            Self::Binary(v) => v.push(None),
+            Self::List(lengths, _) => lengths.push(None),
+            Self::Struct(builder) => builder.push_null(),
+        }
+    }
+
+    /// Replace a builder without values with the builder of the type of `value`.
+    fn typed(&mut self, new: fn(usize) -> Self, value: &str) -> Result<&mut Self> {
+        if let Self::Null(len) = self {
+            *self = new(*len);
+        }
+        if std::mem::discriminant(self) != std::mem::discriminant(&new(0)) {
+            return Err(polars_err!(
+                SchemaMismatch: "cannot serialize a {value} into a {} column", self.kind()
+            )
+            .into());
+        }
+        Ok(self)
+    }
+
+    fn push_int(&mut self, v: i64, dtype: DataType) -> Result<()> {
+        let Self::Int(values, current) = self.typed(
+            |len| Self::Int(vec![None; len], DataType::Int8),
+            "signed integer",
+        )?
+        else {
+            unreachable!()
+        };
+        values.push(Some(v));
+        *current = try_get_supertype(current, &dtype)?;
+        Ok(())
+    }
+
+    fn push_uint(&mut self, v: u64, dtype: DataType) -> Result<()> {
+        let Self::UInt(values, current) = self.typed(
+            |len| Self::UInt(vec![None; len], DataType::UInt8),
+            "unsigned integer",
+        )?
+        else {
+            unreachable!()
+        };
+        values.push(Some(v));
+        *current = try_get_supertype(current, &dtype)?;
+        Ok(())
+    }
+
+    fn push_float(&mut self, v: f64, dtype: DataType) -> Result<()> {
+        let Self::Float(values, current) = self.typed(
+            |len| Self::Float(vec![None; len], DataType::Float32),
+            "float",
+        )?
+        else {
+            unreachable!()
+        };
+        values.push(Some(v));
+        *current = try_get_supertype(current, &dtype)?;
+        Ok(())
+    }
+
+    fn push_str(&mut self, v: &str) -> Result<()> {
+        let Self::String(values) = self.typed(|len| Self::String(vec![None; len]), "string")?
+        else {
+            unreachable!()
+        };
+        values.push(Some(v.to_string()));
+        Ok(())
+    }
+
+    fn list(&mut self) -> Result<&mut Self> {
+        self.typed(
+            |len| Self::List(vec![None; len], Box::new(Self::Null(0))),
+            "list",
+        )
+    }
+
+    fn struct_(&mut self) -> Result<&mut StructBuilder> {
+        let Self::Struct(builder) = self.typed(
+            |len| {
+                Self::Struct(StructBuilder {
+                    fields: vec![],
+                    validity: vec![false; len],
+                })
+            },
+            "struct",
+        )?
+        else {
+            unreachable!()
+        };
+        Ok(builder)
+    }
+
+    fn finish(self, name: PlSmallStr) -> PolarsResult<Series> {
+        Ok(match self {
+            Self::Null(len) => Series::full_null(name, len, &DataType::Null),
+            Self::Boolean(v) => BooleanChunked::from_slice_options(name, &v).into_series(),
+            Self::Int(v, dtype) => Int64Chunked::from_slice_options(name, &v)
+                .into_series()
+                .cast(&dtype)?,
+            Self::UInt(v, dtype) => UInt64Chunked::from_slice_options(name, &v)
+                .into_series()
+                .cast(&dtype)?,
+            Self::Float(v, dtype) => Float64Chunked::from_slice_options(name, &v)
+                .into_series()
+                .cast(&dtype)?,
+            Self::String(v) => StringChunked::from_slice_options(name, &v).into_series(),
+            Self::Binary(v) => BinaryChunked::from_slice_options(name, &v).into_series(),
+            Self::List(lengths, values) => {
+                let values = values.finish(PlSmallStr::EMPTY)?;
+                let mut builder =
+                    get_list_builder(values.dtype(), values.len(), lengths.len(), name);
+                let mut offset = 0;
+                for length in lengths {
+                    match length {
+                        Some(length) => {
+                            builder.append_series(&values.slice(offset as i64, length))?;
+                            offset += length;
+                        },
+                        None => builder.append_null(),
+                    }
+                }
+                builder.finish().into_series()
+            },
+            #[cfg(feature = "dtype-struct")]
+            Self::Struct(builder) => {
+                let len = builder.validity.len();
+                let validity = builder
+                    .validity
+                    .contains(&false)
+                    .then(|| Bitmap::from_iter(builder.validity.iter().copied()));
+                let fields = builder.finish()?;
+                StructChunked::from_columns(name, len, &fields)?
+                    .with_outer_validity(validity)
+                    .into_series()
+            },
+            #[cfg(not(feature = "dtype-struct"))]
+            Self::Struct(_) => {
+                polars_bail!(InvalidOperation: "serializing nested structs requires the 'dtype-struct' feature")
+            },
+        })
+    }
+}
+
+/// Serializes a value into a [`Builder`].
+struct ValueSerializer<'a>(&'a mut Builder);
+
+macro_rules! serialize_as {
+    ($($method:ident($ty:ty) => $push:ident($cast:ty, $dtype:expr)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<()> {
+                self.0.$push(v as $cast, $dtype)
+            }
+        )*
+    };
+}
+
+impl<'a> Serializer for ValueSerializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = ListSerializer<'a>;
+    type SerializeTuple = ListSerializer<'a>;
+    type SerializeTupleStruct = ListSerializer<'a>;
+    type SerializeTupleVariant = Impossible<(), Error>;
+    type SerializeMap = Impossible<(), Error>;
+    type SerializeStruct = StructSerializer<'a>;
+    type SerializeStructVariant = Impossible<(), Error>;
+
+    serialize_as! {
+        serialize_i8(i8) => push_int(i64, DataType::Int8),
+        serialize_i16(i16) => push_int(i64, DataType::Int16),
+        serialize_i32(i32) => push_int(i64, DataType::Int32),
+        serialize_i64(i64) => push_int(i64, DataType::Int64),
+        serialize_u8(u8) => push_uint(u64, DataType::UInt8),
+        serialize_u16(u16) => push_uint(u64, DataType::UInt16),
+        serialize_u32(u32) => push_uint(u64, DataType::UInt32),
+        serialize_u64(u64) => push_uint(u64, DataType::UInt64),
+        serialize_f32(f32) => push_float(f64, DataType::Float32),
+        serialize_f64(f64) => push_float(f64, DataType::Float64),
+    }
+
+    fn serialize_bool(self, v: bool) -> Result<()> {
+        let Builder::Boolean(values) = self
+            .0
+            .typed(|len| Builder::Boolean(vec![None; len]), "boolean")?
+        else {
+            unreachable!()
+        };
+        values.push(Some(v));
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<()> {
+        self.0.push_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<()> {
+        self.0.push_str(v)
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<()> {
+        let Builder::Binary(values) = self
+            .0
+            .typed(|len| Builder::Binary(vec![None; len]), "binary value")?
+        else {
+            unreachable!()
+        };
+        values.push(Some(v.to_vec()));
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<()> {
+        self.0.push_null();
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<()> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<()> {
+        self.serialize_none()
+    }
+
+    /// Unit variants are written as strings.
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<()> {
+        self.0.push_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<()> {
+        Err(unsupported(&format!(
+            "enum variant {name}::{variant} with data"
+        )))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<ListSerializer<'a>> {
+        Ok(ListSerializer {
+            builder: self.0.list()?,
+            len: 0,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<ListSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<ListSerializer<'a>> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant> {
+        Err(unsupported(&format!(
+            "enum variant {name}::{variant} with data"
+        )))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap> {
+        Err(unsupported("map"))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<StructSerializer<'a>> {
+        Ok(StructSerializer(self.0.struct_()?))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant> {
+        Err(unsupported(&format!(
+            "enum variant {name}::{variant} with data"
+        )))
+    }
+}
+
+fn unsupported(value: &str) -> Error {
+    polars_err!(InvalidOperation: "cannot serialize a {value} into a column").into()
+}
+
+struct ListSerializer<'a> {
+    builder: &'a mut Builder,
+    len: usize,
+}
+
+impl SerializeSeq for ListSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        let Builder::List(_, values) = self.builder else {
+            unreachable!()
+        };
+        value.serialize(ValueSerializer(values))?;
+        self.len += 1;
+        Ok(())
+    }
+
+    fn end(self) -> Result<()> {
+        let Builder::List(lengths, _) = self.builder else {
+            unreachable!()
+        };
+        lengths.push(Some(self.len));
+        Ok(())
+    }
+}
+
+impl SerializeTuple for ListSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for ListSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<()> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<()> {
+        SerializeSeq::end(self)
+    }
+}
+
+struct StructSerializer<'a>(&'a mut StructBuilder);
+
+impl SerializeStruct for StructSerializer<'_> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<()> {
+        value.serialize(ValueSerializer(self.0.field(key)))
+    }
+
+    fn end(self) -> Result<()> {
+        self.0.end_value();
+        Ok(())
+    }
+}
+
+impl DataFrame {
+    /// Deserialize the rows of this [`DataFrame`] into `T`.
+    ///
+    /// Each row is deserialized as a struct of which the fields are the columns, so the fields of
+    /// `T` are matched to the columns by name, or by position if `T` is a tuple. The columns are
+    /// downcast once, instead of converting each value to an [`AnyValue`].
+    ///
+    /// Nested lists and structs are deserialized as sequences and structs. Temporal and decimal
+    /// values are deserialized as their physical integers, categoricals as strings, and unit
+    /// enum variants from strings.
+    pub fn rows_deserialize<T: DeserializeOwned>(&self) -> PolarsResult<Vec<T>> {
+        let fields = self
+            .get_columns()
+            .iter()
+            .map(|c| Ok((c.name().clone(), Reader::new(c.as_materialized_series())?)))
+            .collect::<PolarsResult<_>>()?;
+        let reader = Reader {
+            values: Values::Struct(fields),
+            validity: None,
+        };
+
+        (0..self.height())
+            .map(|idx| {
+                T::deserialize(ValueDeserializer {
+                    reader: &reader,
+                    idx,
+                })
+                .map_err(|e| e.0)
+            })
+            .collect()
+    }
+
+    /// Create a [`DataFrame`] from rows that serialize as structs, the inverse of
+    /// [`DataFrame::rows_deserialize`].
+    ///
+    /// The fields of the rows become the columns, of which the type is determined by the
+    /// serialized values. Fields that are missing in a row are null. Integers of different
+    /// widths are stored in the widest type that was serialized.
+    pub fn from_serialize<T: Serialize, I: IntoIterator<Item = T>>(
+        rows: I,
+    ) -> PolarsResult<DataFrame> {
+        let mut builder = Builder::Struct(StructBuilder::default());
+        for row in rows {
+            row.serialize(ValueSerializer(&mut builder))
+                .map_err(|e| e.0)?;
+        }
+
+        let Builder::Struct(builder) = builder else {
+            unreachable!()
+        };
+        let height = builder.validity.len();
+        DataFrame::new_with_height(height, builder.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::{Deserialize, Serialize};
+
+    use crate::prelude::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        name: String,
+        value: Option<i32>,
+        weights: Vec<f64>,
+        kind: Kind,
+    }
+
+    #[test]
+    fn test_rows_serde_roundtrip() -> PolarsResult<()> {
+        let rows = vec![
+            Row {
+                name: "a".to_string(),
+                value: Some(1),
+                weights: vec![0.5, 1.5],
+                kind: Kind::Small,
+            },
+            Row {
+                name: "b".to_string(),
+                value: None,
+                weights: vec![],
+                kind: Kind::Large,
+            },
+        ];
+
+        let df = DataFrame::from_serialize(&rows)?;
+        assert_eq!(
+            df.dtypes(),
+            &[
+                DataType::String,
+                DataType::Int32,
+                DataType::List(Box::new(DataType::Float64)),
+                DataType::String
+            ]
+        );
+        assert_eq!(df.rows_deserialize::<Row>()?, rows);
+
+        // Tuples read the columns by position.
+        let tuples = df
+            .select(["name", "value"])?
+            .rows_deserialize::<(String, Option<i64>)>()?;
+        assert_eq!(
+            tuples,
+            [("a".to_string(), Some(1)), ("b".to_string(), None)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_rows_deserialize_errors() -> PolarsResult<()> {
+        let df = df!["name" => ["a"], "value" => [None::<i32>]]?;
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Required {
+            value: i32,
+        }
+        assert!(df.rows_deserialize::<Required>().is_err());
+
+        #[derive(Serialize)]
+        struct Mixed {
+            value: serde_json::Value,
+        }
+        let rows = [
+            Mixed {
+                value: serde_json::json!(1),
+            },
+            Mixed {
+                value: serde_json::json!("a"),
+            },
+        ];
+        assert!(DataFrame::from_serialize(rows).is_err());
+        Ok(())
+    }
+}
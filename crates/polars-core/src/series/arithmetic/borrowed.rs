@@ -1,4 +1,6 @@
+use super::overflow::{OverflowOp, with_overflow_policy};
 use super::*;
+use crate::config::{ArithmeticOverflow, arithmetic_overflow};
 use crate::utils::align_chunks_binary;
 
 pub trait NumOpsDispatchInner: PolarsDataType + Sized {
@@ -298,6 +300,12 @@ pub mod checked {
                     .unwrap()
                     .apply(|opt_v| opt_v.and_then(|v| v.checked_div(rhs.to_i64().unwrap())))
                     .into_series(),
+                #[cfg(feature = "dtype-i128")]
+                Int128 => s
+                    .i128()
+                    .unwrap()
+                    .apply(|opt_v| opt_v.and_then(|v| v.checked_div(rhs.to_i128().unwrap())))
+                    .into_series(),
                 Float32 => s
                     .f32()
                     .unwrap()
@@ -479,24 +487,32 @@ impl Add for &Series {
     type Output = PolarsResult<Series>;
 
     fn add(self, rhs: Self) -> Self::Output {
-        check_lengths(self, rhs)?;
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.add(b))
-            },
-            (DataType::List(_), _) | (_, DataType::List(_)) => {
-                list::NumericListOp::add().execute(self, rhs)
-            },
-            #[cfg(feature = "dtype-array")]
-            (DataType::Array(..), _) | (_, DataType::Array(..)) => {
-                fixed_size_list::NumericFixedSizeListOp::add().execute(self, rhs)
-            },
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
-                lhs.add_to(rhs.as_ref())
-            },
-        }
+        add_with_policy(self, rhs, arithmetic_overflow())
+    }
+}
+
+pub(super) fn add_with_policy(
+    lhs: &Series,
+    rhs: &Series,
+    policy: ArithmeticOverflow,
+) -> PolarsResult<Series> {
+    check_lengths(lhs, rhs)?;
+    match (lhs.dtype(), rhs.dtype()) {
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(_), DataType::Struct(_)) => _struct_arithmetic(lhs, rhs, |a, b| a.add(b)),
+        (DataType::List(_), _) | (_, DataType::List(_)) => {
+            list::NumericListOp::add().execute(lhs, rhs)
+        },
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+            fixed_size_list::NumericFixedSizeListOp::add().execute(lhs, rhs)
+        },
+        _ => {
+            let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs)?;
+            with_overflow_policy(policy, OverflowOp::Add, &lhs, &rhs, |lhs, rhs| {
+                lhs.add_to(rhs)
+            })
+        },
     }
 }
 
@@ -504,24 +520,32 @@ impl Sub for &Series {
     type Output = PolarsResult<Series>;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        check_lengths(self, rhs)?;
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.sub(b))
-            },
-            (DataType::List(_), _) | (_, DataType::List(_)) => {
-                list::NumericListOp::sub().execute(self, rhs)
-            },
-            #[cfg(feature = "dtype-array")]
-            (DataType::Array(..), _) | (_, DataType::Array(..)) => {
-                fixed_size_list::NumericFixedSizeListOp::sub().execute(self, rhs)
-            },
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
-                lhs.subtract(rhs.as_ref())
-            },
-        }
+        sub_with_policy(self, rhs, arithmetic_overflow())
+    }
+}
+
+pub(super) fn sub_with_policy(
+    lhs: &Series,
+    rhs: &Series,
+    policy: ArithmeticOverflow,
+) -> PolarsResult<Series> {
+    check_lengths(lhs, rhs)?;
+    match (lhs.dtype(), rhs.dtype()) {
+        #[cfg(feature = "dtype-struct")]
+        (DataType::Struct(_), DataType::Struct(_)) => _struct_arithmetic(lhs, rhs, |a, b| a.sub(b)),
+        (DataType::List(_), _) | (_, DataType::List(_)) => {
+            list::NumericListOp::sub().execute(lhs, rhs)
+        },
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+            fixed_size_list::NumericFixedSizeListOp::sub().execute(lhs, rhs)
+        },
+        _ => {
+            let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs)?;
+            with_overflow_policy(policy, OverflowOp::Sub, &lhs, &rhs, |lhs, rhs| {
+                lhs.subtract(rhs)
+            })
+        },
     }
 }
 
@@ -534,35 +558,45 @@ impl Mul for &Series {
     /// let out = (&s * &s).unwrap();
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
-        check_lengths(self, rhs)?;
+        mul_with_policy(self, rhs, arithmetic_overflow())
+    }
+}
 
-        use DataType::*;
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (Struct(_), Struct(_)) => _struct_arithmetic(self, rhs, |a, b| a.mul(b)),
-            // temporal lh
-            (Duration(_), _) | (Date, _) | (Datetime(_, _), _) | (Time, _) => self.multiply(rhs),
-            // temporal rhs
-            (_, Date) | (_, Datetime(_, _)) | (_, Time) => {
-                polars_bail!(opq = mul, self.dtype(), rhs.dtype())
-            },
-            (_, Duration(_)) => {
-                // swap order
-                let out = rhs.multiply(self)?;
-                Ok(out.with_name(self.name().clone()))
-            },
-            (DataType::List(_), _) | (_, DataType::List(_)) => {
-                list::NumericListOp::mul().execute(self, rhs)
-            },
-            #[cfg(feature = "dtype-array")]
-            (DataType::Array(..), _) | (_, DataType::Array(..)) => {
-                fixed_size_list::NumericFixedSizeListOp::mul().execute(self, rhs)
-            },
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
-                lhs.multiply(rhs.as_ref())
-            },
-        }
+pub(super) fn mul_with_policy(
+    lhs: &Series,
+    rhs: &Series,
+    policy: ArithmeticOverflow,
+) -> PolarsResult<Series> {
+    check_lengths(lhs, rhs)?;
+
+    use DataType::*;
+    match (lhs.dtype(), rhs.dtype()) {
+        #[cfg(feature = "dtype-struct")]
+        (Struct(_), Struct(_)) => _struct_arithmetic(lhs, rhs, |a, b| a.mul(b)),
+        // temporal lh
+        (Duration(_), _) | (Date, _) | (Datetime(_, _), _) | (Time, _) => lhs.multiply(rhs),
+        // temporal rhs
+        (_, Date) | (_, Datetime(_, _)) | (_, Time) => {
+            polars_bail!(opq = mul, lhs.dtype(), rhs.dtype())
+        },
+        (_, Duration(_)) => {
+            // swap order
+            let out = rhs.multiply(lhs)?;
+            Ok(out.with_name(lhs.name().clone()))
+        },
+        (DataType::List(_), _) | (_, DataType::List(_)) => {
+            list::NumericListOp::mul().execute(lhs, rhs)
+        },
+        #[cfg(feature = "dtype-array")]
+        (DataType::Array(..), _) | (_, DataType::Array(..)) => {
+            fixed_size_list::NumericFixedSizeListOp::mul().execute(lhs, rhs)
+        },
+        _ => {
+            let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs)?;
+            with_overflow_policy(policy, OverflowOp::Mul, &lhs, &rhs, |lhs, rhs| {
+                lhs.multiply(rhs)
+            })
+        },
     }
 }
 
@@ -980,5 +1014,17 @@ mod test {
         );
         let out = s_f64.checked_div_num(0.0f64).unwrap();
         assert_eq!(Vec::from(out.f64().unwrap()), &[None, None, None]);
+
+        #[cfg(feature = "dtype-i128")]
+        {
+            let s_i128 = Series::new("int128".into(), [1i128 << 100, 0, -1]);
+            let out = s_i128.checked_div_num(2).unwrap();
+            assert_eq!(
+                Vec::from(out.i128().unwrap()),
+                &[Some(1i128 << 99), Some(0), Some(0)]
+            );
+            let out = s_i128.checked_div_num(0).unwrap();
+            assert_eq!(Vec::from(out.i128().unwrap()), &[None, None, None]);
+        }
     }
 }
@@ -1,6 +1,7 @@
 mod bitops;
 mod borrowed;
 mod list;
+mod overflow;
 mod owned;
 
 use std::borrow::Cow;
@@ -11,6 +12,7 @@ pub use borrowed::*;
 pub use fixed_size_list::NumericFixedSizeListOp;
 pub use list::NumericListOp;
 use num_traits::{Num, NumCast};
+pub use overflow::promoted_integer_dtype;
 #[cfg(feature = "dtype-array")]
 mod fixed_size_list;
 mod list_utils;
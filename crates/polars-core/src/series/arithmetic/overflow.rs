@@ -0,0 +1,142 @@
+//! Integer overflow handling of [`Series`] arithmetic, see [`ArithmeticOverflow`].
+use std::borrow::Cow;
+
+use num_traits::PrimInt;
+
+use crate::config::ArithmeticOverflow;
+use crate::prelude::*;
+use crate::utils::align_chunks_binary;
+use crate::with_match_physical_integer_polars_type;
+
+#[derive(Clone, Copy, Debug)]
+pub(super) enum OverflowOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl OverflowOp {
+    fn overflows<N: PrimInt>(self, a: N, b: N) -> bool {
+        match self {
+            Self::Add => a.checked_add(&b).is_none(),
+            Self::Sub => a.checked_sub(&b).is_none(),
+            Self::Mul => a.checked_mul(&b).is_none(),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Add => "addition",
+            Self::Sub => "subtraction",
+            Self::Mul => "multiplication",
+        }
+    }
+}
+
+fn ca_overflows<T>(op: OverflowOp, lhs: &ChunkedArray<T>, rhs: &ChunkedArray<T>) -> bool
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt,
+{
+    let any_overflow = |arr: &PrimitiveArray<T::Native>, b: T::Native, swap: bool| {
+        arr.iter().flatten().any(|&a| {
+            if swap {
+                op.overflows(b, a)
+            } else {
+                op.overflows(a, b)
+            }
+        })
+    };
+
+    match (lhs.len(), rhs.len()) {
+        (_, 1) if lhs.len() != 1 => rhs
+            .get(0)
+            .is_some_and(|b| lhs.downcast_iter().any(|arr| any_overflow(arr, b, false))),
+        (1, _) if rhs.len() != 1 => lhs
+            .get(0)
+            .is_some_and(|a| rhs.downcast_iter().any(|arr| any_overflow(arr, a, true))),
+        _ => {
+            let (lhs, rhs) = align_chunks_binary(lhs, rhs);
+            lhs.downcast_iter().zip(rhs.downcast_iter()).any(|(l, r)| {
+                l.iter()
+                    .zip(r.iter())
+                    .any(|(a, b)| matches!((a, b), (Some(&a), Some(&b)) if op.overflows(a, b)))
+            })
+        },
+    }
+}
+
+fn overflows(op: OverflowOp, lhs: &Series, rhs: &Series) -> bool {
+    with_match_physical_integer_polars_type!(lhs.dtype(), |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref();
+        ca_overflows(op, lhs, rhs)
+    })
+}
+
+/// The next wider integer dtype that [`ArithmeticOverflow::Promote`] computes `dtype` arithmetic
+/// in, signed so that unsigned subtractions fit as well.
+pub fn promoted_integer_dtype(dtype: &DataType) -> Option<DataType> {
+    use DataType::*;
+    match dtype {
+        #[cfg(feature = "dtype-i16")]
+        Int8 | UInt8 => Some(Int16),
+        #[cfg(not(feature = "dtype-i16"))]
+        Int8 | UInt8 => Some(Int32),
+        Int16 | UInt16 => Some(Int32),
+        Int32 | UInt32 => Some(Int64),
+        #[cfg(feature = "dtype-i128")]
+        Int64 | UInt64 => Some(Int128),
+        _ => None,
+    }
+}
+
+/// Apply the integer arithmetic `f` to the coerced `lhs` and `rhs`, handling overflow according
+/// to `policy`.
+///
+/// With [`ArithmeticOverflow::Promote`] the output dtype only depends on the input dtypes, so that
+/// it matches the schema of a query: integers are always computed in their
+/// [`promoted_integer_dtype`].
+pub(super) fn with_overflow_policy(
+    policy: ArithmeticOverflow,
+    op: OverflowOp,
+    lhs: &Series,
+    rhs: &Series,
+    f: impl Fn(&Series, &Series) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    if policy == ArithmeticOverflow::Wrap || !lhs.dtype().is_integer() || lhs.dtype() != rhs.dtype()
+    {
+        return f(lhs, rhs);
+    }
+
+    let input_dtype = lhs.dtype().clone();
+    let promoted = match policy {
+        ArithmeticOverflow::Promote => promoted_integer_dtype(lhs.dtype()),
+        _ => None,
+    };
+    let (lhs, rhs) = match &promoted {
+        Some(dtype) => (
+            Cow::Owned(lhs.strict_cast(dtype)?),
+            Cow::Owned(rhs.strict_cast(dtype)?),
+        ),
+        None => (Cow::Borrowed(lhs), Cow::Borrowed(rhs)),
+    };
+
+    if overflows(op, &lhs, &rhs) {
+        match policy {
+            ArithmeticOverflow::Promote if promoted.is_some() => polars_bail!(
+                ComputeError: "integer overflow in {} of {}, promoted from {}",
+                op.name(), lhs.dtype(), input_dtype
+            ),
+            ArithmeticOverflow::Promote => polars_bail!(
+                ComputeError: "integer overflow in {} of {}, and there is no wider integer dtype to promote to",
+                op.name(), lhs.dtype()
+            ),
+            _ => polars_bail!(
+                ComputeError: "integer overflow in {} of {}; set `Config.set_arithmetic_overflow(\"promote\")` to promote to a wider dtype or cast the inputs first",
+                op.name(), lhs.dtype()
+            ),
+        }
+    }
+    f(&lhs, &rhs)
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::config::{ArithmeticOverflow, arithmetic_overflow};
 #[cfg(feature = "performant")]
 use crate::utils::align_chunks_binary_owned_series;
 
@@ -18,10 +19,13 @@ pub fn coerce_lhs_rhs_owned(lhs: Series, rhs: Series) -> PolarsResult<(Series, S
     Ok((left, right))
 }
 
-fn is_eligible(lhs: &DataType, rhs: &DataType) -> bool {
+#[cfg(feature = "performant")]
+fn is_eligible(lhs: &DataType, rhs: &DataType, policy: ArithmeticOverflow) -> bool {
     !lhs.is_logical()
         && lhs.to_physical().is_primitive_numeric()
         && rhs.to_physical().is_primitive_numeric()
+        // Integer overflow is handled by the borrowed implementation.
+        && !(lhs.is_integer() && policy != ArithmeticOverflow::Wrap)
 }
 
 #[cfg(feature = "performant")]
@@ -40,15 +44,16 @@ where
 }
 
 macro_rules! impl_operation {
-    ($operation:ident, $method:ident, $function:expr) => {
+    ($operation:ident, $method:ident, $function:expr, $policy:expr, $borrowed:expr) => {
         impl $operation for Series {
             type Output = PolarsResult<Series>;
 
             fn $method(self, rhs: Self) -> Self::Output {
+                let policy = $policy;
                 #[cfg(feature = "performant")]
                 {
                     // only physical numeric values take the mutable path
-                    if is_eligible(self.dtype(), rhs.dtype()) {
+                    if is_eligible(self.dtype(), rhs.dtype(), policy) {
                         let (lhs, rhs) = coerce_lhs_rhs_owned(self, rhs).unwrap();
                         let (lhs, rhs) = align_chunks_binary_owned_series(lhs, rhs);
                         use DataType::*;
@@ -72,45 +77,58 @@ macro_rules! impl_operation {
                             _ => unreachable!(),
                         })
                     } else {
-                        (&self).$method(&rhs)
+                        $borrowed(&self, &rhs, policy)
                     }
                 }
                 #[cfg(not(feature = "performant"))]
                 {
-                    (&self).$method(&rhs)
+                    $borrowed(&self, &rhs, policy)
                 }
             }
         }
     };
 }
 
-impl_operation!(Add, add, |a, b| a.add(b));
-impl_operation!(Sub, sub, |a, b| a.sub(b));
-impl_operation!(Mul, mul, |a, b| a.mul(b));
-impl_operation!(Div, div, |a, b| a.div(b));
+impl_operation!(
+    Add,
+    add,
+    |a, b| a.add(b),
+    arithmetic_overflow(),
+    add_with_policy
+);
+impl_operation!(
+    Sub,
+    sub,
+    |a, b| a.sub(b),
+    arithmetic_overflow(),
+    sub_with_policy
+);
+impl_operation!(
+    Mul,
+    mul,
+    |a, b| a.mul(b),
+    arithmetic_overflow(),
+    mul_with_policy
+);
+// Division does not check for overflow.
+impl_operation!(
+    Div,
+    div,
+    |a, b| a.div(b),
+    ArithmeticOverflow::Wrap,
+    |lhs: &Series, rhs: &Series, _| lhs.div(rhs)
+);
 
 impl Series {
     pub fn try_add_owned(self, other: Self) -> PolarsResult<Self> {
-        if is_eligible(self.dtype(), other.dtype()) {
-            self + other
-        } else {
-            std::ops::Add::add(&self, &other)
-        }
+        self + other
     }
 
     pub fn try_sub_owned(self, other: Self) -> PolarsResult<Self> {
-        if is_eligible(self.dtype(), other.dtype()) {
-            self - other
-        } else {
-            std::ops::Sub::sub(&self, &other)
-        }
+        self - other
     }
 
     pub fn try_mul_owned(self, other: Self) -> PolarsResult<Self> {
-        if is_eligible(self.dtype(), other.dtype()) {
-            self * other
-        } else {
-            std::ops::Mul::mul(&self, &other)
-        }
+        self * other
     }
 }
@@ -116,6 +116,10 @@ impl Series {
                 })
             },
             Boolean => BooleanChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => UInt16Chunked::from_chunks(name, chunks)
+                .into_float16()
+                .into_series(),
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
@@ -232,6 +236,23 @@ impl Series {
                 "dtype-i128",
                 Ok(Int128Chunked::from_chunks(name, chunks).into_series())
             ),
+            #[cfg(feature = "dtype-f16")]
+            ArrowDataType::Float16 => {
+                let chunks = chunks
+                    .into_iter()
+                    .map(|arr| {
+                        let arr = arr
+                            .as_any()
+                            .downcast_ref::<PrimitiveArray<arrow::types::f16>>()
+                            .unwrap();
+                        arr.clone().transmute::<u16>().boxed()
+                    })
+                    .collect();
+                Ok(UInt16Chunked::from_chunks(name, chunks)
+                    .into_float16()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-f16"))]
             ArrowDataType::Float16 => {
                 let chunks =
                     cast_chunks(&chunks, &DataType::Float32, CastOptions::NonStrict).unwrap();
@@ -0,0 +1,462 @@
+//! Float16 is stored as the bits of the half precision values. Storage operations (slicing,
+//! gathering, filtering, ...) are done on the bits directly. Operations that look at the values
+//! (ordering, hashing, aggregations and arithmetic) are done on `f32`, and the results that are
+//! still Float16 are rounded back.
+use std::cmp::Ordering;
+
+use arrow::types::f16;
+use polars_compute::rolling::QuantileMethod;
+
+use super::*;
+use crate::chunked_array::ops::compare_inner::NullOrderCmp;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Float16Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+/// Compares the `f16` values behind the bits.
+struct Float16Cmp<'a>(&'a UInt16Chunked);
+
+impl Float16Cmp<'_> {
+    #[inline]
+    unsafe fn get(&self, idx: usize) -> Option<f32> {
+        self.0
+            .get_unchecked(idx)
+            .map(|bits| f16::from_bits(bits).to_f32())
+    }
+}
+
+impl TotalEqInner for Float16Cmp<'_> {
+    #[inline]
+    unsafe fn eq_element_unchecked(&self, idx_a: usize, idx_b: usize) -> bool {
+        self.get(idx_a).tot_eq(&self.get(idx_b))
+    }
+}
+
+impl TotalOrdInner for Float16Cmp<'_> {
+    #[inline]
+    unsafe fn cmp_element_unchecked(
+        &self,
+        idx_a: usize,
+        idx_b: usize,
+        nulls_last: bool,
+    ) -> Ordering {
+        self.get(idx_a).null_order_cmp(&self.get(idx_b), nulls_last)
+    }
+}
+
+impl SeriesWrap<Float16Chunked> {
+    fn apply_physical_to_s<F: Fn(&UInt16Chunked) -> UInt16Chunked>(&self, f: F) -> Series {
+        f(self.0.physical()).into_float16().into_series()
+    }
+
+    fn to_f32_series(&self) -> Series {
+        self.0.to_float32().into_series()
+    }
+
+    /// Round a Float32 result back to Float16.
+    fn to_f16_series(s: Series) -> Series {
+        s.f32().unwrap().to_float16().into_series()
+    }
+
+    fn to_f16_scalar(&self, sc: Scalar) -> Scalar {
+        Scalar::new(self.dtype().clone(), sc.value().clone())
+    }
+
+    fn f32_rhs(rhs: &Series) -> PolarsResult<Series> {
+        rhs.cast(&DataType::Float32)
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Float16Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Float16Chunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+
+    fn _get_flags(&self) -> StatisticsFlags {
+        // The order of the bits is not the order of the values.
+        StatisticsFlags::empty()
+    }
+
+    fn _set_flags(&mut self, _flags: StatisticsFlags) {}
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.f16()?;
+        Ok(self
+            .0
+            .physical()
+            .zip_with(mask, other.physical())?
+            .into_float16()
+            .into_series())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        Box::new(Float16Cmp(self.0.physical()))
+    }
+
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        Box::new(Float16Cmp(self.0.physical()))
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.0.to_float32().vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.0.to_float32().vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        Self::to_f16_series(self.0.to_float32().agg_min(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        Self::to_f16_series(self.0.to_float32().agg_max(groups))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.0.to_float32().agg_sum(groups)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0.to_float32().agg_std(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0.to_float32().agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        // we cannot cast and dispatch as the inner type of the list would be incorrect
+        self.0
+            .physical()
+            .agg_list(groups)
+            .cast(&DataType::List(Box::new(self.dtype().clone())))
+            .unwrap()
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_f32_series().subtract(&Self::f32_rhs(rhs)?)
+    }
+
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_f32_series().add_to(&Self::f32_rhs(rhs)?)
+    }
+
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_f32_series().multiply(&Self::f32_rhs(rhs)?)
+    }
+
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_f32_series().divide(&Self::f32_rhs(rhs)?)
+    }
+
+    fn remainder(&self, rhs: &Series) -> PolarsResult<Series> {
+        self.to_f32_series().remainder(&Self::f32_rhs(rhs)?)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.0.to_float32().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.0.to_float32().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Float16Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name)
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.0.physical_mut().shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.slice(offset, length))
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.0.to_float32()._sum_as_f64()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        self.0.to_float32().mean()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.0.to_float32().std(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.0.to_float32().var(ddof)
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.0.to_float32().median()
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let mut other = other.to_physical_repr().into_owned();
+        self.0
+            .physical_mut()
+            .append_owned(std::mem::take(other._get_inner_mut().as_mut()))
+    }
+
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Float16Chunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        let other = other.to_physical_repr();
+        self.0
+            .physical_mut()
+            .extend(other.as_ref().as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .filter(filter)?
+            .into_float16()
+            .into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_float16()
+            .into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_float16()
+            .into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .physical()
+            .take(indices)?
+            .into_float16()
+            .into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0
+            .physical()
+            .take_unchecked(indices)
+            .into_float16()
+            .into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.rechunk().into_owned())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.apply_physical_to_s(|ca| ca.new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self
+            .0
+            .to_float32()
+            .sort_with(options)
+            .to_float16()
+            .into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.to_float32().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.0.to_float32().unique()?.to_float16().into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.0.to_float32().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.0.to_float32().arg_unique()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.apply_physical_to_s(|ca| ca.reverse())
+    }
+
+    fn as_single_ptr(&mut self) -> PolarsResult<usize> {
+        self.0.physical_mut().as_single_ptr()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.apply_physical_to_s(|ca| ca.shift(periods))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.0.to_float32().sum_reduce())
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.to_f16_scalar(self.0.to_float32().max_reduce()))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.to_f16_scalar(self.0.to_float32().min_reduce()))
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.to_f32_series().median_reduce()
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_f32_series().var_reduce(ddof)
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.to_f32_series().std_reduce(ddof)
+    }
+
+    fn quantile_reduce(&self, quantile: f64, method: QuantileMethod) -> PolarsResult<Scalar> {
+        self.to_f32_series().quantile_reduce(quantile, method)
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
@@ -14,6 +14,8 @@ mod datetime;
 mod decimal;
 #[cfg(feature = "dtype-duration")]
 mod duration;
+#[cfg(feature = "dtype-f16")]
+mod float16;
 mod floats;
 mod list;
 pub(crate) mod null;
@@ -157,6 +157,11 @@ impl Series {
                 .clone()
                 .to(self.dtype().to_arrow(CompatLevel::newest()))
                 .to_boxed(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => self.f16().unwrap().physical().downcast_chunks()[chunk_idx]
+                .clone()
+                .transmute::<arrow::types::f16>()
+                .to_boxed(),
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -31,6 +31,7 @@ use std::borrow::Cow;
 use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 
+use arrow::bitmap::Bitmap;
 use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::offset::Offsets;
 pub use from::*;
@@ -245,6 +246,48 @@ impl Series {
         new
     }
 
+    /// Split this [`Series`] into chunks of exactly `chunk_size` rows, except for the last chunk
+    /// which holds the remainder.
+    ///
+    /// Two [`Series`] of the same length that went through this have the same chunk layout, so
+    /// binary kernels can zip their chunks without rechunking. Returns a cheap clone if the chunks
+    /// already have this layout.
+    pub fn rechunk_to_chunksize(&self, chunk_size: usize) -> PolarsResult<Self> {
+        polars_ensure!(chunk_size > 0, InvalidOperation: "'chunk_size' must be greater than 0");
+
+        let len = self.len();
+        if len == 0 {
+            return Ok(self.rechunk());
+        }
+        let n_chunks = self.n_chunks();
+        let is_aligned = self.chunk_lengths().enumerate().all(|(i, l)| {
+            if i + 1 == n_chunks {
+                l > 0 && l <= chunk_size
+            } else {
+                l == chunk_size
+            }
+        });
+        if is_aligned {
+            return Ok(self.clone());
+        }
+
+        let mut out = self.rechunk();
+        let array = out.chunks()[0].clone();
+        let flags = out.get_flags();
+
+        let mut_out = out._get_inner_mut();
+        let chunks = unsafe { mut_out.chunks_mut() };
+        chunks.clear();
+        chunks.extend(
+            (0..len)
+                .step_by(chunk_size)
+                .map(|offset| array.sliced(offset, chunk_size.min(len - offset))),
+        );
+        mut_out.compute_len();
+        mut_out._set_flags(flags);
+        Ok(out)
+    }
+
     pub fn is_sorted_flag(&self) -> IsSorted {
         if self.len() <= 1 {
             return IsSorted::Ascending;
@@ -543,6 +586,12 @@ impl Series {
                 feature_gated!("dtype-duration", Ok(self.clone().into_duration(*tu)))
             },
             (D::Int64, D::Time) => feature_gated!("dtype-time", Ok(self.clone().into_time())),
+            (D::UInt16, D::Float16) => {
+                feature_gated!(
+                    "dtype-f16",
+                    Ok(self.u16()?.clone().into_float16().into_series())
+                )
+            },
 
             (D::List(_), D::List(to)) => unsafe {
                 self.list()
@@ -624,6 +673,14 @@ impl Series {
         }
     }
 
+    /// Get the values of a boolean [`Series`] as a single packed [`Bitmap`], in which null values
+    /// are unset.
+    ///
+    /// This doesn't copy if the [`Series`] consists of a single chunk without null values.
+    pub fn as_bitmap(&self) -> PolarsResult<Bitmap> {
+        Ok(self.bool()?.as_bitmap())
+    }
+
     /// Check if numeric value is NaN (note this is different than missing/ null)
     pub fn is_nan(&self) -> PolarsResult<BooleanChunked> {
         match self.dtype() {
@@ -700,6 +757,7 @@ impl Series {
     /// * Duration -> Int64
     /// * Decimal -> Int128
     /// * Time -> Int64
+    /// * Float16 -> UInt16
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
@@ -726,6 +784,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-f16")]
+            Float16 => Cow::Owned(self.f16().unwrap().phys.clone().into_series()),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
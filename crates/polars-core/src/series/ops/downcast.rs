@@ -68,6 +68,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn try_f16(&self) -> Option<&Float16Chunked> {
+        try_unpack_chunked!(self, DataType::Float16 => Float16Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float32`]
     pub fn try_f32(&self) -> Option<&Float32Chunked> {
         try_unpack_chunked!(self, DataType::Float32 => Float32Chunked)
@@ -248,6 +254,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Float32"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
+    #[cfg(feature = "dtype-f16")]
+    pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
+        self.try_f16()
+            .ok_or_else(|| unpack_chunked_err!(self => "Float16"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float64`]
     pub fn f64(&self) -> PolarsResult<&Float64Chunked> {
         self.try_f64()
@@ -44,6 +44,10 @@ impl Series {
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
                 .into_series(),
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => UInt16Chunked::full_null(name, size)
+                .into_float16()
+                .into_series(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, scale.unwrap_or(0))
@@ -985,6 +985,28 @@ where
     }
 }
 
+/// Slice a single-chunk [`Series`] into chunks of the given lengths.
+#[cfg(feature = "performant")]
+fn series_match_chunks<I>(s: &Series, chunk_lengths: I) -> Series
+where
+    I: Iterator<Item = usize>,
+{
+    debug_assert_eq!(s.n_chunks(), 1);
+    let array = &s.chunks()[0];
+    let mut offset = 0;
+    let chunks = chunk_lengths
+        .map(|len| {
+            let out = array.sliced(offset, len);
+            offset += len;
+            out
+        })
+        .collect();
+    debug_assert_eq!(offset, array.len());
+
+    // SAFETY: We just slice the original chunk, its type does not change.
+    unsafe { Series::from_chunks_and_dtype_unchecked(s.name().clone(), chunks, s.dtype()) }
+}
+
 #[cfg(feature = "performant")]
 pub(crate) fn align_chunks_binary_owned_series(left: Series, right: Series) -> (Series, Series) {
     match (left.chunks().len(), right.chunks().len()) {
@@ -999,6 +1021,12 @@ pub(crate) fn align_chunks_binary_owned_series(left: Series, right: Series) -> (
         {
             (left, right)
         },
+        // Slicing the single chunk is free and keeps the chunks of `left` available for in-place
+        // reuse.
+        (_, 1) if left.len() == right.len() => {
+            let right = series_match_chunks(&right, left.chunk_lengths());
+            (left, right)
+        },
         (_, 1) => (left.rechunk(), right),
         (1, _) => (left, right.rechunk()),
         (_, _) => (left.rechunk(), right.rechunk()),
@@ -1025,6 +1053,12 @@ where
         {
             (left, right)
         },
+        // Slicing the single chunk is free and keeps the chunks of `left` available for in-place
+        // reuse.
+        (_, 1) if left.len() == right.len() => {
+            let right = right.match_chunks(left.chunk_lengths());
+            (left, right)
+        },
         (_, 1) => (left.rechunk().into_owned(), right),
         (1, _) => (left, right.rechunk().into_owned()),
         (_, _) => (left.rechunk().into_owned(), right.rechunk().into_owned()),
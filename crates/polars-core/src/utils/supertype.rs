@@ -40,6 +40,10 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
                 }
             },
         }
+    } else if l.is_float16() && r.is_float() {
+        Some(r.clone())
+    } else if l.is_float() && r.is_float16() {
+        Some(l.clone())
     } else if l.is_signed_integer() && r.is_signed_integer() {
         match (l, r) {
             (Int128, _) | (_, Int128) => Some(Int128),
@@ -92,6 +96,43 @@ pub fn get_numeric_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Opti
     }
 }
 
+/// Returns a supertype that `l` and `r` can be safely upcasted to if it exists, like
+/// [`get_numeric_upcast_supertype_lossless`] but also for the inner types of lists and arrays.
+///
+/// Lists and arrays are upcasted to lists, e.g. `Array(Int32, 3)` and `List(Int64)` to
+/// `List(Int64)`.
+pub fn get_upcast_supertype_lossless(l: &DataType, r: &DataType) -> Option<DataType> {
+    use DataType::*;
+
+    fn inner_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
+        if l == r {
+            Some(l.clone())
+        } else {
+            get_upcast_supertype_lossless(l, r)
+        }
+    }
+
+    match (l, r) {
+        (List(inner_l), List(inner_r)) if inner_l != inner_r => {
+            Some(List(Box::new(inner_supertype(inner_l, inner_r)?)))
+        },
+        #[cfg(feature = "dtype-array")]
+        (Array(inner_l, width_l), Array(inner_r, width_r))
+            if width_l == width_r && inner_l != inner_r =>
+        {
+            Some(Array(
+                Box::new(inner_supertype(inner_l, inner_r)?),
+                *width_l,
+            ))
+        },
+        #[cfg(feature = "dtype-array")]
+        (List(inner_l), Array(inner_r, _)) | (Array(inner_l, _), List(inner_r)) => {
+            Some(List(Box::new(inner_supertype(inner_l, inner_r)?)))
+        },
+        _ => get_numeric_upcast_supertype_lossless(l, r),
+    }
+}
+
 bitflags! {
     #[repr(transparent)]
     #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
@@ -407,7 +448,7 @@ pub fn get_supertype_with_options(
                         }
                     },
                     // numeric vs float|str -> always float|str|decimal
-                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
+                    UnknownKind::Float | UnknownKind::Int(_) if dt.is_float() | dt.is_float16() | dt.is_decimal() => Some(dt.clone()),
                     UnknownKind::Float if dt.is_integer() => Some(Unknown(UnknownKind::Float)),
                     // Materialize float to float or decimal
                     UnknownKind::Float if dt.is_float() | dt.is_decimal() => Some(dt.clone()),
@@ -468,6 +509,11 @@ pub fn get_supertype_with_options(
             (Decimal(_, _), f @ (Float32 | Float64)) => Some(f.clone()),
             #[cfg(feature = "dtype-decimal")]
             (d @ Decimal(_, _), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => Some(d.clone()),
+            // Float16 is only a storage type, so it behaves like Float32 when combined with other numbers.
+            #[cfg(feature = "dtype-f16")]
+            (Float16, dt) if dt.is_numeric() || dt.is_bool() => {
+                inner(&Float32, dt, options).or_else(|| inner(dt, &Float32, options))
+            },
             _ => None,
         }
     }
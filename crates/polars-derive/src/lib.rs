@@ -0,0 +1,169 @@
+//! Derive macros that convert between a `Vec` of a struct and a `DataFrame` with a column for each
+//! field. They are re-exported by the `polars` crate with its `derive` feature, and implement the
+//! `IntoDataFrame` and `FromDataFrame` traits of `polars-core`.
+//!
+//! The fields are stored with the `ColumnValue` implementation of their type, and can be configured
+//! with `#[polars(...)]` attributes:
+//!
+//! * `rename = "name"` - The name of the column, which defaults to the name of the field.
+//! * `dtype = <expr>` - The [`DataType`] the column is cast to when creating a `DataFrame`, e.g.
+//!   `DataType::Date` for an `i32` field of days since the epoch. Reading the column casts it back
+//!   to the type of the field.
+//!
+//! [`DataType`]: https://docs.rs/polars/latest/polars/datatypes/enum.DataType.html
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Expr, Fields, Ident, LitStr, Type, parse_macro_input};
+
+struct Field {
+    ident: Ident,
+    ty: Type,
+    /// The name of the column.
+    name: String,
+    dtype: Option<Expr>,
+}
+
+fn parse_fields(input: &DeriveInput) -> syn::Result<Vec<Field>> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "DataFrames can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &data.fields,
+            "DataFrames can only be derived for structs with named fields",
+        ));
+    };
+
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.clone().unwrap();
+            let mut name = ident.unraw().to_string();
+            let mut dtype = None;
+            for attr in field.attrs.iter().filter(|a| a.path().is_ident("polars")) {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("rename") {
+                        name = meta.value()?.parse::<LitStr>()?.value();
+                        Ok(())
+                    } else if meta.path.is_ident("dtype") {
+                        dtype = Some(meta.value()?.parse::<Expr>()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown polars attribute, expected `rename` or `dtype`"))
+                    }
+                })?;
+            }
+            Ok(Field {
+                ident,
+                ty: field.ty.clone(),
+                name,
+                dtype,
+            })
+        })
+        .collect()
+}
+
+/// The local variables that hold the values of the fields, which can't clash with the names of
+/// the generated code.
+fn field_vars(fields: &[Field]) -> Vec<Ident> {
+    (0..fields.len())
+        .map(|i| format_ident!("__field_{}", i))
+        .collect()
+}
+
+fn expand_into_df(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = parse_fields(input)?;
+    let vars = field_vars(&fields);
+    let idents = fields.iter().map(|f| &f.ident);
+    let columns = fields.iter().zip(&vars).map(|(field, var)| {
+        let Field { ty, name, .. } = field;
+        let cast = field
+            .dtype
+            .as_ref()
+            .map(|dtype| quote!(let series = series.strict_cast(&(#dtype))?;));
+        quote! {{
+            let series =
+                <#ty as ::polars::prelude::ColumnValue>::into_series(#name.into(), #var);
+            #cast
+            ::polars::prelude::IntoColumn::into_column(series)
+        }}
+    });
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::polars::prelude::IntoDataFrame for #ident #ty_generics #where_clause {
+            fn into_df(
+                rows: ::std::vec::Vec<Self>,
+            ) -> ::polars::prelude::PolarsResult<::polars::prelude::DataFrame> {
+                let height = rows.len();
+                #(let mut #vars = ::std::vec::Vec::with_capacity(height);)*
+                for row in rows {
+                    #(#vars.push(row.#idents);)*
+                }
+                let columns = ::std::vec![#(#columns),*];
+                ::polars::prelude::DataFrame::new_with_height(height, columns)
+            }
+        }
+    })
+}
+
+fn expand_from_df(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = parse_fields(input)?;
+    let vars = field_vars(&fields);
+    let reads = fields
+        .iter()
+        .zip(&vars)
+        .map(|(Field { ty, name, .. }, var)| {
+            quote! {
+                let mut #var = <#ty as ::polars::prelude::ColumnValue>::from_column(
+                    df.column(#name)?,
+                )?
+                .into_iter();
+            }
+        });
+    let idents = fields.iter().map(|f| &f.ident);
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::polars::prelude::FromDataFrame for #ident #ty_generics #where_clause {
+            fn from_df(
+                df: &::polars::prelude::DataFrame,
+            ) -> ::polars::prelude::PolarsResult<::std::vec::Vec<Self>> {
+                #(#reads)*
+                ::std::result::Result::Ok(
+                    (0..df.height())
+                        .map(|_| Self {
+                            #(#idents: #vars.next().unwrap(),)*
+                        })
+                        .collect(),
+                )
+            }
+        }
+    })
+}
+
+/// Implements `IntoDataFrame`, which creates a `DataFrame` with a column for each field.
+#[proc_macro_derive(IntoDataFrame, attributes(polars))]
+pub fn derive_into_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_into_df(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements `FromDataFrame`, which reads each field from the column with its name.
+#[proc_macro_derive(FromDataFrame, attributes(polars))]
+pub fn derive_from_dataframe(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_from_df(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -1,5 +1,6 @@
 use std::fmt;
 use std::hash::BuildHasher;
+use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use arrow::array::builder::StaticArrayBuilder;
@@ -10,6 +11,7 @@ use polars_utils::aliases::PlSeedableRandomStateQuality;
 use polars_utils::parma::raw::RawTable;
 
 use super::CatSize;
+use super::shared::SharedLog;
 
 pub struct CategoricalMapping {
     str_to_cat: RawTable<str, CatSize>,
@@ -17,6 +19,7 @@ pub struct CategoricalMapping {
     max_categories: usize,
     upper_bound: AtomicUsize,
     hasher: PlSeedableRandomStateQuality,
+    shared: Option<SharedLog>,
 }
 
 impl CategoricalMapping {
@@ -31,14 +34,41 @@ impl CategoricalMapping {
             max_categories,
             upper_bound: AtomicUsize::new(0),
             hasher,
+            shared: None,
         }
     }
 
+    /// Creates a mapping of which the categories are shared through the file at `path` with the
+    /// mappings of other processes that use the same file, so that they all assign the same ids to
+    /// the same strings. The file is created if it doesn't exist.
+    pub fn with_shared_file(max_categories: usize, path: &Path) -> PolarsResult<Self> {
+        let mut slf = Self::new(max_categories);
+        let shared = SharedLog::open(path)?;
+        shared.sync(&slf)?;
+        slf.shared = Some(shared);
+        Ok(slf)
+    }
+
+    /// Whether the categories are shared with other processes, see
+    /// [`CategoricalMapping::with_shared_file`].
+    pub fn is_shared(&self) -> bool {
+        self.shared.is_some()
+    }
+
+    /// The file through which the categories are shared with other processes, if any.
+    pub fn shared_file(&self) -> Option<&Path> {
+        self.shared.as_ref().map(SharedLog::path)
+    }
+
     #[inline(always)]
     pub fn hasher(&self) -> &PlSeedableRandomStateQuality {
         &self.hasher
     }
 
+    pub(super) fn max_categories(&self) -> usize {
+        self.max_categories
+    }
+
     pub fn set_max_categories(&mut self, max_categories: usize) {
         assert!(max_categories >= self.num_cats_upper_bound());
         self.max_categories = max_categories
@@ -64,9 +94,34 @@ impl CategoricalMapping {
         self.insert_cat_with_hash(s, hash)
     }
 
+    /// Insert all strings into this mapping. A shared mapping appends the new ones to the shared
+    /// file at once, rather than taking the lock of the file for each of them.
+    pub fn insert_cats<'a>(&self, strs: impl IntoIterator<Item = &'a str>) -> PolarsResult<()> {
+        match &self.shared {
+            Some(shared) => {
+                shared.insert_many(self, strs.into_iter().filter(|s| self.get_cat(s).is_none()))
+            },
+            None => strs
+                .into_iter()
+                .try_for_each(|s| self.insert_cat(s).map(|_| ())),
+        }
+    }
+
     /// Same as to_cat, but with the hash pre-computed.
     #[inline(always)]
     pub fn insert_cat_with_hash(&self, s: &str, hash: u64) -> PolarsResult<CatSize> {
+        if let Some(shared) = &self.shared {
+            if let Some(cat) = self.get_cat_with_hash(s, hash) {
+                return Ok(cat);
+            }
+            return shared.insert(self, s);
+        }
+        self.insert_cat_local(s, hash)
+    }
+
+    /// Insert a string into this mapping without appending it to the shared file.
+    #[inline(always)]
+    pub(super) fn insert_cat_local(&self, s: &str, hash: u64) -> PolarsResult<CatSize> {
         self.str_to_cat
             .try_get_or_insert_with(
                 hash,
@@ -91,6 +146,18 @@ impl CategoricalMapping {
     /// None if the string is not in the data structure.
     #[inline(always)]
     pub fn cat_to_str(&self, cat: CatSize) -> Option<&str> {
+        match self.cat_to_str.get(cat as usize) {
+            Some(s) => Some(*s),
+            None if self.shared.is_some() => self.cat_to_str_synced(cat),
+            None => None,
+        }
+    }
+
+    /// Look up a categorical id that another process may have inserted into the shared file.
+    #[cold]
+    fn cat_to_str_synced(&self, cat: CatSize) -> Option<&str> {
+        // An id that can't be synced is as unknown as one that was never inserted.
+        self.shared.as_ref()?.sync(self).ok()?;
         self.cat_to_str.get(cat as usize).copied()
     }
 
@@ -1,5 +1,6 @@
 use std::fmt;
 use std::hash::{BuildHasher, Hash, Hasher};
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::{Arc, LazyLock, Mutex, Weak};
 
@@ -14,6 +15,7 @@ use polars_utils::pl_str::PlSmallStr;
 
 mod catsize;
 mod mapping;
+mod shared;
 
 pub use catsize::{CatNative, CatSize};
 pub use mapping::CategoricalMapping;
@@ -108,6 +110,7 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
     let categories = Arc::new(Categories {
         id: CategoricalId::global(),
         mapping: Mutex::new(Weak::new()),
+        shared_mapping: Mutex::new(None),
     });
     CATEGORIES_REGISTRY
         .lock()
@@ -120,6 +123,8 @@ static GLOBAL_CATEGORIES: LazyLock<Arc<Categories>> = LazyLock::new(|| {
 pub struct Categories {
     id: CategoricalId,
     mapping: Mutex<Weak<CategoricalMapping>>,
+    /// Keeps the mapping alive once it is shared, as a new mapping wouldn't be.
+    shared_mapping: Mutex<Option<Arc<CategoricalMapping>>>,
 }
 
 impl Categories {
@@ -145,6 +150,7 @@ impl Categories {
         let slf = Arc::new(Self {
             id: id.clone(),
             mapping,
+            shared_mapping: Mutex::new(None),
         });
         registry.insert(id, Arc::downgrade(&slf));
         slf
@@ -197,6 +203,37 @@ impl Categories {
         arc
     }
 
+    /// Share the mapping of this Categories object with the ones of other processes through the
+    /// file at `path`, so that they all assign the same ids to the same strings, see
+    /// [`CategoricalMapping::with_shared_file`].
+    ///
+    /// The mapping stays shared for the lifetime of the process. This fails if the mapping is
+    /// already in use, as the ids of its categories could differ from the shared ones.
+    pub fn share(&self, path: &Path) -> PolarsResult<()> {
+        let mut shared = self.shared_mapping.lock().unwrap();
+        if let Some(mapping) = &*shared {
+            polars_ensure!(
+                mapping.shared_file() == Some(path),
+                InvalidOperation: "categories are already shared through {}",
+                mapping.shared_file().unwrap().display()
+            );
+            return Ok(());
+        }
+
+        let mut guard = self.mapping.lock().unwrap();
+        polars_ensure!(
+            guard.strong_count() == 0,
+            InvalidOperation: "cannot share categories while categorical data uses them"
+        );
+        let mapping = Arc::new(CategoricalMapping::with_shared_file(
+            self.id.physical.max_categories(),
+            path,
+        )?);
+        *guard = Arc::downgrade(&mapping);
+        *shared = Some(mapping);
+        Ok(())
+    }
+
     pub fn freeze(&self) -> Arc<FrozenCategories> {
         let mapping = self.mapping();
         let n = mapping.num_cats_upper_bound();
@@ -236,6 +273,16 @@ impl FrozenCategories {
     /// in case these are already known). Returns an error if the categories are not unique.
     /// It is guaranteed that the nth string ends up with category n (0-indexed).
     pub fn new<'a, I: IntoIterator<Item = &'a str>>(strings: I) -> PolarsResult<Arc<Self>> {
+        Self::with_physical(strings, None)
+    }
+
+    /// Same as [`FrozenCategories::new`], but with the given physical type of the category ids
+    /// instead of the smallest one that fits the categories. Returns an error if the categories
+    /// don't fit in `physical`.
+    pub fn with_physical<'a, I: IntoIterator<Item = &'a str>>(
+        strings: I,
+        physical: Option<CategoricalPhysical>,
+    ) -> PolarsResult<Arc<Self>> {
         let strings = strings.into_iter();
         let hasher = *FROZEN_CATEGORIES_HASHER;
         let mut mapping = CategoricalMapping::with_hasher(usize::MAX, hasher);
@@ -254,7 +301,19 @@ impl FrozenCategories {
         let categories = builder.freeze();
         mapping.set_max_categories(categories.len()); // Don't allow any further inserts.
 
-        let physical = CategoricalPhysical::smallest_physical(categories.len())?;
+        let smallest_physical = CategoricalPhysical::smallest_physical(categories.len())?;
+        let physical = match physical {
+            Some(physical) => {
+                polars_ensure!(
+                    categories.len() < physical.max_categories(),
+                    ComputeError: "{} categories do not fit in physical type {}, use {} or wider",
+                    categories.len(), physical.as_str(), smallest_physical.as_str()
+                );
+                physical
+            },
+            None => smallest_physical,
+        };
+
         let mut registry = FROZEN_CATEGORIES_REGISTRY.lock().unwrap();
         let mut last_compared = None; // We have to store the strong reference to avoid a race condition.
         match registry.entry(
@@ -262,7 +321,8 @@ impl FrozenCategories {
             |(hash, weak)| {
                 *hash == combined_hash && {
                     if let Some(frozen_cats) = weak.upgrade() {
-                        let cmp = frozen_cats.categories == categories;
+                        let cmp = frozen_cats.physical == physical
+                            && frozen_cats.categories == categories;
                         last_compared = Some(frozen_cats);
                         cmp
                     } else {
@@ -0,0 +1,147 @@
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use polars_error::{PolarsResult, polars_ensure, polars_err};
+use polars_utils::aliases::PlHashSet;
+
+use super::{CatSize, CategoricalMapping};
+
+struct SharedLogState {
+    /// The process that opened `file`. A forked child shares the open file description with its
+    /// parent, which also shares its locks, so it has to open the file itself.
+    pid: u32,
+    file: File,
+    /// The length of the prefix of the file of which the categories are in the mapping.
+    synced: u64,
+}
+
+/// An append-only file with the categories of a [`CategoricalMapping`] in the order of their ids,
+/// through which the mappings of multiple processes assign the same ids to the same strings.
+///
+/// A category is a little-endian `u32` length followed by its UTF-8 bytes. The file is only
+/// appended to while holding an exclusive lock on it, and a mapping only inserts the categories of
+/// the file, in order, so its ids are the positions of the categories in the file.
+pub(super) struct SharedLog {
+    path: PathBuf,
+    state: Mutex<SharedLogState>,
+}
+
+fn open(path: &Path) -> PolarsResult<File> {
+    OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(path)
+        .map_err(|e| polars_err!(ComputeError: "could not open shared categories {}: {e}", path.display()))
+}
+
+impl SharedLog {
+    pub(super) fn open(path: &Path) -> PolarsResult<Self> {
+        let state = SharedLogState {
+            pid: std::process::id(),
+            file: open(path)?,
+            synced: 0,
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            state: Mutex::new(state),
+        })
+    }
+
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Run `f` while holding a shared or exclusive lock on the file.
+    fn with_lock<T>(
+        &self,
+        exclusive: bool,
+        f: impl FnOnce(&mut SharedLogState) -> PolarsResult<T>,
+    ) -> PolarsResult<T> {
+        let mut state = self.state.lock().unwrap();
+        if state.pid != std::process::id() {
+            state.file = open(&self.path)?;
+            state.pid = std::process::id();
+        }
+        if exclusive {
+            state.file.lock()?;
+        } else {
+            state.file.lock_shared()?;
+        }
+        let out = f(&mut state);
+        state.file.unlock()?;
+        out
+    }
+
+    /// Same as [`SharedLog::sync`], while holding the lock.
+    fn sync_locked(state: &mut SharedLogState, mapping: &CategoricalMapping) -> PolarsResult<()> {
+        let mut buf = Vec::new();
+        state.file.seek(SeekFrom::Start(state.synced))?;
+        state.file.read_to_end(&mut buf)?;
+
+        let mut rest = buf.as_slice();
+        while let Some((len, tail)) = rest.split_first_chunk::<4>() {
+            let len = u32::from_le_bytes(*len) as usize;
+            let s = tail
+                .get(..len)
+                .and_then(|bytes| std::str::from_utf8(bytes).ok())
+                .ok_or_else(|| polars_err!(ComputeError: "shared categories are corrupted"))?;
+            mapping.insert_cat_local(s, mapping.hasher().hash_one(s))?;
+            state.synced += 4 + len as u64;
+            rest = &tail[len..];
+        }
+        Ok(())
+    }
+
+    /// Insert the categories that other processes appended to the file into `mapping`.
+    pub(super) fn sync(&self, mapping: &CategoricalMapping) -> PolarsResult<()> {
+        self.with_lock(false, |state| Self::sync_locked(state, mapping))
+    }
+
+    /// Insert `s` into `mapping`, appending it to the file if no process did so yet.
+    pub(super) fn insert(&self, mapping: &CategoricalMapping, s: &str) -> PolarsResult<CatSize> {
+        self.insert_many(mapping, [s])?;
+        Ok(mapping.get_cat(s).unwrap())
+    }
+
+    /// Insert the strings into `mapping`, appending the ones no process appended yet to the file
+    /// at once, under a single lock.
+    pub(super) fn insert_many<'a>(
+        &self,
+        mapping: &CategoricalMapping,
+        strs: impl IntoIterator<Item = &'a str>,
+    ) -> PolarsResult<()> {
+        self.with_lock(true, |state| {
+            Self::sync_locked(state, mapping)?;
+            // A category must only be appended once, as the ids are the positions in the file.
+            let mut seen = PlHashSet::new();
+            let new = strs
+                .into_iter()
+                .filter(|s| mapping.get_cat(s).is_none() && seen.insert(*s))
+                .collect::<Vec<_>>();
+            if new.is_empty() {
+                return Ok(());
+            }
+            // Check the limit before the categories become visible to other processes.
+            polars_ensure!(
+                mapping.num_cats_upper_bound() + new.len() <= mapping.max_categories(),
+                ComputeError: "attempted to insert more categories than the maximum allowed"
+            );
+            let mut records = Vec::new();
+            for s in &new {
+                let len = u32::try_from(s.len())
+                    .map_err(|_| polars_err!(ComputeError: "category is too long to be shared"))?;
+                records.extend_from_slice(&len.to_le_bytes());
+                records.extend_from_slice(s.as_bytes());
+            }
+            state.file.write_all(&records)?;
+            state.synced += records.len() as u64;
+            for s in new {
+                mapping.insert_cat_local(s, mapping.hasher().hash_one(s))?;
+            }
+            Ok(())
+        })
+    }
+}
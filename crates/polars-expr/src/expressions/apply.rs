@@ -58,7 +58,7 @@ impl ApplyExpr {
     }
 
     #[allow(clippy::ptr_arg)]
-    fn prepare_multiple_inputs<'a>(
+    pub(super) fn prepare_multiple_inputs<'a>(
         &self,
         df: &DataFrame,
         groups: &'a GroupPositions,
@@ -218,7 +218,7 @@ impl ApplyExpr {
         ac.with_values_and_args(c, aggregated, Some(&self.expr), true, self.is_scalar())?;
         Ok(ac)
     }
-    fn apply_multiple_group_aware<'a>(
+    pub(super) fn apply_multiple_group_aware<'a>(
         &self,
         mut acs: Vec<AggregationContext<'a>>,
         df: &DataFrame,
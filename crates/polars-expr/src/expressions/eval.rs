@@ -2,18 +2,20 @@ use std::borrow::Cow;
 use std::sync::{Arc, Mutex};
 
 use arrow::array::{Array, ListArray};
+use arrow::bitmap::Bitmap;
 use polars_core::POOL;
 use polars_core::chunked_array::builder::AnonymousOwnedListBuilder;
 use polars_core::chunked_array::from_iterator_par::ChunkedCollectParIterExt;
 use polars_core::error::{PolarsResult, polars_ensure};
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
-    AnyValue, ChunkCast, ChunkNestingUtils, Column, CompatLevel, DataType, Field, GroupPositions,
-    GroupsType, IntoColumn, ListBuilderTrait, ListChunked,
+    AnyValue, BooleanChunked, ChunkCast, ChunkNestingUtils, Column, CompatLevel, DataType, Field,
+    GroupPositions, GroupsType, IntoColumn, ListBuilderTrait, ListChunked,
 };
 use polars_core::schema::Schema;
 use polars_core::series::Series;
 use polars_core::utils::CustomIterTools;
+use polars_ops::chunked_array::ListNameSpaceImpl;
 use polars_plan::dsl::{EvalVariant, Expr};
 use polars_plan::plans::ExprPushdownGroup;
 use polars_utils::IdxSize;
@@ -37,7 +39,8 @@ pub struct EvalExpr {
     evaluation_is_scalar: bool,
 }
 
-fn offsets_to_groups(offsets: &[i64]) -> Option<GroupPositions> {
+/// Convert list offsets to slice groups. Null lists become empty groups.
+fn offsets_to_groups(offsets: &[i64], validity: Option<&Bitmap>) -> Option<GroupPositions> {
     let mut start = offsets[0];
     let end = *offsets.last().unwrap();
     if IdxSize::try_from(end - start).is_err() {
@@ -46,9 +49,14 @@ fn offsets_to_groups(offsets: &[i64]) -> Option<GroupPositions> {
     let groups = offsets
         .iter()
         .skip(1)
-        .map(|end| {
+        .enumerate()
+        .map(|(i, end)| {
             let offset = start as IdxSize;
-            let len = (*end - start) as IdxSize;
+            let len = if validity.is_some_and(|v| !v.get_bit(i)) {
+                0
+            } else {
+                (*end - start) as IdxSize
+            };
             start = *end;
             [offset, len]
         })
@@ -162,6 +170,11 @@ impl EvalExpr {
     }
 
     fn run_per_sublist(&self, lst: &ListChunked, state: &ExecutionState) -> PolarsResult<Column> {
+        // `list.agg` collects the single value of every sublist in a list first.
+        let output_dtype = match self.variant {
+            EvalVariant::ListAgg => DataType::List(Box::new(self.output_field.dtype.clone())),
+            _ => self.output_field.dtype.clone(),
+        };
         let mut err = None;
         let mut ca: ListChunked = if self.allow_threading {
             let m_err = Mutex::new(None);
@@ -180,7 +193,7 @@ impl EvalExpr {
                             }
                         })
                     })
-                    .collect_ca_with_dtype(PlSmallStr::EMPTY, self.output_field.dtype.clone())
+                    .collect_ca_with_dtype(PlSmallStr::EMPTY, output_dtype.clone())
             });
             err = m_err.into_inner().unwrap();
             ca
@@ -210,10 +223,12 @@ impl EvalExpr {
 
         ca.rename(lst.name().clone());
 
-        if ca.dtype() != self.output_field.dtype() {
-            ca.cast(self.output_field.dtype()).map(Column::from)
-        } else {
-            Ok(ca.into_column())
+        if ca.dtype() != &output_dtype {
+            ca = ca.cast(&output_dtype)?.list()?.clone();
+        }
+        match self.variant {
+            EvalVariant::ListAgg => ca.lst_get(0, true).map(Column::from),
+            _ => Ok(ca.into_column()),
         }
     }
 
@@ -224,7 +239,8 @@ impl EvalExpr {
     ) -> PolarsResult<Column> {
         let lst = lst.rechunk();
         let arr = lst.downcast_as_array();
-        let groups = offsets_to_groups(arr.offsets()).unwrap();
+        let validity = arr.validity().filter(|v| v.unset_bits() > 0);
+        let groups = offsets_to_groups(arr.offsets(), validity).unwrap();
 
         // List elements in a series.
         let values = Series::try_from((PlSmallStr::EMPTY, arr.values().clone())).unwrap();
@@ -238,13 +254,23 @@ impl EvalExpr {
         let mut ac = self
             .evaluation
             .evaluate_on_groups(&df_context, &groups, state)?;
-        let out = match ac.agg_state() {
-            AggState::AggregatedScalar(_) => {
+        let out = match (ac.agg_state(), self.variant) {
+            (AggState::AggregatedScalar(_), EvalVariant::ListAgg) => ac.aggregated(),
+            (AggState::AggregatedScalar(_), _) => {
                 let out = ac.aggregated();
                 out.as_list().into_column()
             },
+            (_, EvalVariant::ListAgg) => ac.aggregated().list()?.lst_get(0, true)?.into_column(),
             _ => ac.aggregated(),
         };
+        let out = match validity {
+            None => out,
+            Some(validity) => {
+                let is_valid = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, validity.clone());
+                let nulls = Column::full_null(PlSmallStr::EMPTY, out.len(), out.dtype());
+                out.zip_with(&is_valid, &nulls)?
+            },
+        };
         Ok(out.with_name(self.output_field.name.clone()).into_column())
     }
 
@@ -261,7 +287,7 @@ impl EvalExpr {
         } && !self.evaluation_is_scalar
         {
             self.run_elementwise_on_values(lst, state)
-        } else if fits_idx_size && lst.null_count() == 0 && self.evaluation_is_scalar {
+        } else if fits_idx_size && self.evaluation_is_scalar {
             self.run_on_group_by_engine(lst, state)
         } else {
             self.run_per_sublist(lst, state)
@@ -343,7 +369,7 @@ impl PhysicalExpr for EvalExpr {
     fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
         let input = self.input.evaluate(df, state)?;
         match self.variant {
-            EvalVariant::List => {
+            EvalVariant::List | EvalVariant::ListAgg => {
                 let lst = input.list()?;
                 self.evaluate_on_list_chunked(lst, state)
             },
@@ -361,7 +387,7 @@ impl PhysicalExpr for EvalExpr {
     ) -> PolarsResult<AggregationContext<'a>> {
         let mut input = self.input.evaluate_on_groups(df, groups, state)?;
         match self.variant {
-            EvalVariant::List => {
+            EvalVariant::List | EvalVariant::ListAgg => {
                 let out = self.evaluate_on_list_chunked(input.get_values().list()?, state)?;
                 input.with_values(out, false, Some(&self.expr))?;
             },
@@ -12,6 +12,7 @@ mod group_iter;
 mod literal;
 #[cfg(feature = "dynamic_group_by")]
 mod rolling;
+mod shift_and_fill;
 mod slice;
 mod sort;
 mod sortby;
@@ -39,6 +40,7 @@ use polars_io::predicates::PhysicalIoExpr;
 use polars_plan::prelude::*;
 #[cfg(feature = "dynamic_group_by")]
 pub(crate) use rolling::RollingExpr;
+pub(crate) use shift_and_fill::*;
 pub(crate) use slice::*;
 pub(crate) use sort::*;
 pub(crate) use sortby::*;
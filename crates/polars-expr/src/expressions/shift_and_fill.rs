@@ -0,0 +1,128 @@
+use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::*;
+
+use super::*;
+use crate::expressions::{AggState, AggregationContext, PhysicalExpr};
+
+/// `shift_and_fill` with a fast path in the group context.
+///
+/// If the fill value is a literal or an aggregation with a single value per group (e.g. the group
+/// mean), the values are shifted within their groups in a single pass over the input, instead of
+/// calling the function on every group.
+pub struct ShiftAndFillExpr {
+    pub(crate) apply: ApplyExpr,
+}
+
+fn extract_n(ac: &AggregationContext<'_>) -> PolarsResult<Option<i64>> {
+    match ac.agg_state() {
+        AggState::Literal(n) if n.len() == 1 => Ok(n.cast(&DataType::Int64)?.i64()?.get(0)),
+        _ => Ok(None),
+    }
+}
+
+/// Shift the values of `ac` within the (non-overlapping) groups, in place.
+fn shift_and_fill_groups(
+    ac: &mut AggregationContext<'_>,
+    n: i64,
+    fill_value: &Column,
+    fill_per_group: bool,
+) -> PolarsResult<Column> {
+    ac.groups();
+    let groups = &ac.groups;
+    let values = ac.get_values();
+    let len = values.len();
+
+    // Rows outside of the groups are taken as is.
+    let mut take: Vec<IdxSize> = (0..len as IdxSize).collect();
+    let mut keep = MutableBitmap::from_len_set(len);
+    let mut fill_take = fill_per_group.then(|| vec![0 as IdxSize; len]);
+    let mut shift_row = |g: usize, row: IdxSize, src: Option<IdxSize>| match src {
+        Some(src) => take[row as usize] = src,
+        None => {
+            keep.set(row as usize, false);
+            if let Some(fill_take) = fill_take.as_mut() {
+                fill_take[row as usize] = g as IdxSize;
+            }
+        },
+    };
+    let src_offset = |j: usize, m: usize| {
+        let src = j as i64 - n;
+        (0..m as i64).contains(&src).then_some(src as usize)
+    };
+    match groups.as_ref().as_ref() {
+        GroupsType::Idx(groups) => {
+            for (g, (_, group)) in groups.iter().enumerate() {
+                for (j, &row) in group.iter().enumerate() {
+                    shift_row(g, row, src_offset(j, group.len()).map(|src| group[src]))
+                }
+            }
+        },
+        GroupsType::Slice { groups, .. } => {
+            for (g, &[first, glen]) in groups.iter().enumerate() {
+                for j in 0..glen as usize {
+                    let src = src_offset(j, glen as usize).map(|src| first + src as IdxSize);
+                    shift_row(g, first + j as IdxSize, src)
+                }
+            }
+        },
+    }
+
+    let shifted = values.take_slice(&take)?;
+    let fill_value = fill_value.cast(values.dtype())?;
+    let fill_value = match fill_take {
+        Some(fill_take) => fill_value.take_slice(&fill_take)?,
+        None => fill_value.new_from_index(0, len),
+    };
+    let keep = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, keep.freeze());
+    shifted.zip_with(&keep, &fill_value)
+}
+
+impl PhysicalExpr for ShiftAndFillExpr {
+    fn as_expression(&self) -> Option<&Expr> {
+        self.apply.as_expression()
+    }
+
+    fn evaluate(&self, df: &DataFrame, state: &ExecutionState) -> PolarsResult<Column> {
+        self.apply.evaluate(df, state)
+    }
+
+    fn evaluate_on_groups<'a>(
+        &self,
+        df: &DataFrame,
+        groups: &'a GroupPositions,
+        state: &ExecutionState,
+    ) -> PolarsResult<AggregationContext<'a>> {
+        let mut acs = self.apply.prepare_multiple_inputs(df, groups, state)?;
+
+        let n = extract_n(&acs[1])?;
+        let fill_per_group = match acs[2].agg_state() {
+            AggState::Literal(s) if s.len() == 1 => Some(false),
+            AggState::AggregatedScalar(s) if s.len() == groups.len() => Some(true),
+            _ => None,
+        };
+        let overlapping = matches!(
+            acs[0].groups().as_ref().as_ref(),
+            GroupsType::Slice { rolling: true, .. }
+        );
+        match (n, fill_per_group) {
+            (Some(n), Some(fill_per_group))
+                if matches!(acs[0].agg_state(), AggState::NotAggregated(_)) && !overlapping =>
+            {
+                let fill_value = acs.pop().unwrap().take();
+                let mut ac = acs.swap_remove(0);
+                let out = shift_and_fill_groups(&mut ac, n, &fill_value, fill_per_group)?;
+                ac.with_values(out, false, self.as_expression())?;
+                Ok(ac)
+            },
+            _ => self.apply.apply_multiple_group_aware(acs, df),
+        }
+    }
+
+    fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
+        self.apply.to_field(input_schema)
+    }
+
+    fn is_scalar(&self) -> bool {
+        false
+    }
+}
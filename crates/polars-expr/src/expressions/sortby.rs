@@ -1,5 +1,6 @@
 use polars_core::POOL;
 use polars_core::chunked_array::from_iterator_par::ChunkedCollectParIterExt;
+use polars_core::chunked_array::ops::sort::arg_bottom_k::_arg_bottom_k;
 use polars_core::prelude::*;
 use polars_utils::idx_vec::IdxVec;
 use rayon::prelude::*;
@@ -211,7 +212,15 @@ impl PhysicalExpr for SortByExpr {
         let (series, sorted_idx) = if self.by.len() == 1 {
             let sorted_idx_f = || {
                 let s_sort_by = self.by[0].evaluate(df, state)?;
-                Ok(s_sort_by.arg_sort(SortOptions::from(&self.sort_options)))
+                let len = s_sort_by.len();
+                let sorted_idx = match self.sort_options.limit {
+                    Some(limit) => {
+                        let mut options = self.sort_options.clone();
+                        _arg_bottom_k(limit as usize, &[s_sort_by], &mut options)?.into_inner()
+                    },
+                    None => s_sort_by.arg_sort(SortOptions::from(&self.sort_options)),
+                };
+                Ok((sorted_idx, len))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         } else {
@@ -265,24 +274,30 @@ impl PhysicalExpr for SortByExpr {
                     }
                 }
 
-                let options = self
+                let mut options = self
                     .sort_options
                     .clone()
                     .with_order_descending_multi(descending)
                     .with_nulls_last_multi(nulls_last);
 
-                s_sort_by[0]
-                    .as_materialized_series()
-                    .arg_sort_multiple(&s_sort_by[1..], &options)
+                let sorted_idx = match options.limit {
+                    Some(limit) => {
+                        _arg_bottom_k(limit as usize, &s_sort_by, &mut options)?.into_inner()
+                    },
+                    None => s_sort_by[0]
+                        .as_materialized_series()
+                        .arg_sort_multiple(&s_sort_by[1..], &options)?,
+                };
+                Ok((sorted_idx, broadcast_length))
             };
             POOL.install(|| rayon::join(series_f, sorted_idx_f))
         };
-        let (sorted_idx, series) = (sorted_idx?, series?);
+        let ((sorted_idx, by_len), series) = (sorted_idx?, series?);
         polars_ensure!(
-            sorted_idx.len() == series.len(),
+            by_len == series.len(),
             expr = self.expr, ShapeMismatch:
             "`sort_by` produced different length ({}) than the Series that has to be sorted ({})",
-            sorted_idx.len(), series.len()
+            by_len, series.len()
         );
 
         // SAFETY: sorted index are within bounds.
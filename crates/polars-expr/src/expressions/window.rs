@@ -2,6 +2,7 @@ use std::fmt::Write;
 
 use arrow::array::PrimitiveArray;
 use arrow::bitmap::Bitmap;
+use polars_core::frame::column::PartitionedColumn;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::utils::_split_offsets;
@@ -527,6 +528,15 @@ impl PhysicalExpr for WindowExpr {
                     // we take the group locations to directly map them to the right place
                     (UpdateGroups::No, Some(out)) => Ok(out.into_column()),
                     (_, _) => {
+                        // if every group is a contiguous run of rows we don't have to repeat
+                        // the group results, we keep them per group and only gather them when
+                        // the column is consumed
+                        if let Some(out) =
+                            partition_by_groups(&out_column, gb.get_groups(), df.height())
+                        {
+                            return Ok(out);
+                        }
+
                         let keys = gb.keys();
 
                         let get_join_tuples = || {
@@ -638,6 +648,63 @@ fn materialize_column(join_opt_ids: &ChunkJoinOptIds, out_column: &Column) -> Co
     }
 }
 
+/// Represent the per-group `values` repeated over the rows of their group as a
+/// [`PartitionedColumn`], if the groups tile the `len` rows in contiguous runs.
+///
+/// This avoids materializing the repeated group results, which is costly for nested values.
+/// Interleaved groups can't be represented by a [`PartitionedColumn`], so `None` is returned
+/// for those and the caller has to materialize the repeated values.
+fn partition_by_groups(values: &Column, groups: &GroupsType, len: usize) -> Option<Column> {
+    if groups.len() != values.len() || groups.is_empty() {
+        return None;
+    }
+
+    // (first, len) of every group, in the order of `values`.
+    let runs: Vec<[IdxSize; 2]> = match groups {
+        GroupsType::Slice { groups, .. } => groups.clone(),
+        GroupsType::Idx(groups) => groups
+            .iter()
+            .map(|(first, idx)| {
+                let contiguous = idx
+                    .iter()
+                    .enumerate()
+                    .all(|(offset, &i)| i == first + offset as IdxSize);
+                contiguous.then(|| [first, idx.len() as IdxSize])
+            })
+            .collect::<Option<_>>()?,
+    };
+
+    let mut order = (0..runs.len() as IdxSize).collect::<Vec<_>>();
+    let sorted = runs.windows(2).all(|w| w[0][0] <= w[1][0]);
+    if !sorted {
+        order.sort_unstable_by_key(|&g| runs[g as usize][0]);
+    }
+
+    let mut ends = Vec::with_capacity(runs.len());
+    let mut end: IdxSize = 0;
+    for &g in &order {
+        let [first, group_len] = runs[g as usize];
+        if first != end {
+            return None;
+        }
+        end += group_len;
+        ends.push(end);
+    }
+    if end as usize != len {
+        return None;
+    }
+
+    let values = if sorted {
+        values.as_materialized_series().clone()
+    } else {
+        let order = IdxCa::from_vec(PlSmallStr::EMPTY, order);
+        // SAFETY: `order` is a permutation of the group indices.
+        unsafe { values.as_materialized_series().take_unchecked(&order) }
+    };
+    let name = values.name().clone();
+    Some(PartitionedColumn::new(name, values, ends.into()).into_column())
+}
+
 /// Simple reducing aggregation can be set by the groups
 fn set_by_groups(
     s: &Column,
@@ -538,10 +538,37 @@ fn create_physical_expr_inner(
             let output_field = expr_arena
                 .get(expression)
                 .to_field(schema, ctxt, expr_arena)?;
+
+            // Bitwise reductions have dedicated group-wise kernels.
+            #[cfg(feature = "bitwise")]
+            if let (Context::Aggregation, IRFunctionExpr::Bitwise(bitwise), [input]) =
+                (ctxt, function, input.as_slice())
+            {
+                let method = match bitwise {
+                    IRBitwiseFunction::And => Some(GroupByBitwiseMethod::And),
+                    IRBitwiseFunction::Or => Some(GroupByBitwiseMethod::Or),
+                    IRBitwiseFunction::Xor => Some(GroupByBitwiseMethod::Xor),
+                    _ => None,
+                };
+                if let Some(method) = method {
+                    let input =
+                        create_physical_expr_inner(input.node(), ctxt, expr_arena, schema, state)?;
+                    let agg_type = AggregationType {
+                        groupby: GroupByMethod::Bitwise(method),
+                        allow_threading: false,
+                    };
+                    return Ok(Arc::new(AggregationExpr::new(
+                        input,
+                        agg_type,
+                        Some(output_field),
+                    )));
+                }
+            }
+
             let input =
                 create_physical_expressions_from_irs(input, ctxt, expr_arena, schema, state)?;
 
-            Ok(Arc::new(ApplyExpr::new(
+            let apply = ApplyExpr::new(
                 input,
                 function.clone().into(),
                 node_to_expr(expression, expr_arena),
@@ -550,7 +577,11 @@ fn create_physical_expr_inner(
                 schema.clone(),
                 output_field,
                 is_scalar,
-            )))
+            );
+            if matches!(function, IRFunctionExpr::ShiftAndFill) {
+                return Ok(Arc::new(ShiftAndFillExpr { apply }));
+            }
+            Ok(Arc::new(apply))
         },
         Slice {
             input,
@@ -1,7 +1,9 @@
 use std::marker::PhantomData;
 
 use num_traits::{AsPrimitive, Zero};
-use polars_core::with_match_physical_numeric_polars_type;
+use polars_compute::float_sum::KahanSum;
+use polars_core::config::{FloatSummation, float_summation};
+use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
 
 use super::*;
 
@@ -10,6 +12,11 @@ pub fn new_mean_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
     use VecGroupedReduction as VGR;
     match dtype {
         Boolean => Box::new(VGR::new(dtype, BoolMeanReducer)),
+        Float32 | Float64 if float_summation() == FloatSummation::Kahan => {
+            with_match_physical_float_polars_type!(dtype, |$T| {
+                Box::new(VGR::new(dtype, KahanMeanReducer::<$T>(PhantomData)))
+            })
+        },
         _ if dtype.is_primitive_numeric() || dtype.is_temporal() => {
             with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
                 Box::new(VGR::new(dtype, NumMeanReducer::<$T>(PhantomData)))
@@ -122,6 +129,59 @@ where
     }
 }
 
+/// Averages floats with the compensated sum of [`KahanSum`], for [`FloatSummation::Kahan`].
+struct KahanMeanReducer<T>(PhantomData<T>);
+impl<T> Clone for KahanMeanReducer<T> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Reducer for KahanMeanReducer<T>
+where
+    T: PolarsFloatType,
+{
+    type Dtype = T;
+    type Value = (KahanSum<f64>, usize);
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        (KahanSum::default(), 0)
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.0.combine(&b.0);
+        a.1 += b.1;
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<T::Native>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.0.add(b.as_());
+            a.1 += 1;
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for x in ca.iter().flatten() {
+            v.0.add(x.as_());
+            v.1 += 1;
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let v = v.into_iter().map(|(s, c)| (s.sum(), c)).collect();
+        Ok(finish_output(v, dtype))
+    }
+}
+
 #[derive(Clone)]
 struct BoolMeanReducer;
 
@@ -28,6 +28,16 @@ pub fn new_min_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         },
         Float32 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float32Type>>::new())),
         Float64 => Box::new(VMGR::new(dtype, NumReducer::<Min<Float64Type>>::new())),
+        #[cfg(all(feature = "dtype-f16", feature = "propagate_nans"))]
+        Float16 if propagate_nans => Box::new(VMGR::new(
+            dtype,
+            Float16Reducer(NumReducer::<NanMin<Float32Type>>::new()),
+        )),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VMGR::new(
+            dtype,
+            Float16Reducer(NumReducer::<Min<Float32Type>>::new()),
+        )),
         Null => Box::new(NullGroupedReduction::default()),
         String | Binary => Box::new(VecGroupedReduction::new(dtype, BinaryMinReducer)),
         _ if dtype.is_integer() || dtype.is_temporal() || dtype.is_enum() => {
@@ -60,6 +70,16 @@ pub fn new_max_reduction(dtype: DataType, propagate_nans: bool) -> Box<dyn Group
         },
         Float32 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float32Type>>::new())),
         Float64 => Box::new(VMGR::new(dtype, NumReducer::<Max<Float64Type>>::new())),
+        #[cfg(all(feature = "dtype-f16", feature = "propagate_nans"))]
+        Float16 if propagate_nans => Box::new(VMGR::new(
+            dtype,
+            Float16Reducer(NumReducer::<NanMax<Float32Type>>::new()),
+        )),
+        #[cfg(feature = "dtype-f16")]
+        Float16 => Box::new(VMGR::new(
+            dtype,
+            Float16Reducer(NumReducer::<Max<Float32Type>>::new()),
+        )),
         Null => Box::new(NullGroupedReduction::default()),
         String | Binary => Box::new(VecGroupedReduction::new(dtype, BinaryMaxReducer)),
         _ if dtype.is_integer() || dtype.is_temporal() || dtype.is_enum() => {
@@ -645,6 +665,53 @@ impl<T: PolarsCategoricalType> Reducer for CatMaxReducer<T> {
     }
 }
 
+/// Reduces `Float16` values in `f32` and rounds the results back to `f16`.
+#[cfg(feature = "dtype-f16")]
+#[derive(Clone)]
+struct Float16Reducer<R>(R);
+
+#[cfg(feature = "dtype-f16")]
+impl<R: Reducer<Dtype = Float32Type>> Reducer for Float16Reducer<R> {
+    type Dtype = Float32Type;
+    type Value = R::Value;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        self.0.init()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float32).unwrap())
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        self.0.combine(a, b)
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f32>, seq_id: u64) {
+        self.0.reduce_one(a, b, seq_id)
+    }
+
+    #[inline(always)]
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &Float32Chunked, seq_id: u64) {
+        self.0.reduce_ca(v, ca, seq_id)
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(dtype == &DataType::Float16);
+        self.0
+            .finish(v, m, &DataType::Float32)?
+            .cast(&DataType::Float16)
+    }
+}
+
 #[derive(Default)]
 pub struct NullGroupedReduction {
     length: usize,
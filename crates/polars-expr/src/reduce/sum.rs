@@ -1,8 +1,10 @@
 use std::borrow::Cow;
 
 use arrow::array::PrimitiveArray;
-use num_traits::Zero;
-use polars_core::with_match_physical_numeric_polars_type;
+use num_traits::{AsPrimitive, NumCast, Zero};
+use polars_compute::float_sum::KahanSum;
+use polars_core::config::{FloatSummation, float_summation};
+use polars_core::{with_match_physical_float_polars_type, with_match_physical_numeric_polars_type};
 use polars_utils::float::IsFloat;
 
 use super::*;
@@ -45,6 +47,11 @@ pub fn new_sum_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
     use VecGroupedReduction as VGR;
     match dtype {
         Boolean => Box::new(VGR::new(dtype, BoolSumReducer)),
+        Float32 | Float64 if float_summation() == FloatSummation::Kahan => {
+            with_match_physical_float_polars_type!(dtype, |$T| {
+                Box::new(VGR::new(dtype, KahanSumReducer::<$T>(PhantomData)))
+            })
+        },
         _ if dtype.is_primitive_numeric() => {
             with_match_physical_numeric_polars_type!(dtype.to_physical(), |$T| {
                 Box::new(VGR::new(dtype, NumSumReducer::<$T>(PhantomData)))
@@ -126,6 +133,60 @@ where
     }
 }
 
+/// Sums floats with [`KahanSum`], for [`FloatSummation::Kahan`].
+struct KahanSumReducer<T>(PhantomData<T>);
+impl<T> Clone for KahanSumReducer<T> {
+    fn clone(&self) -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T> Reducer for KahanSumReducer<T>
+where
+    T: PolarsFloatType,
+{
+    type Dtype = T;
+    type Value = KahanSum<f64>;
+
+    #[inline(always)]
+    fn init(&self) -> Self::Value {
+        KahanSum::default()
+    }
+
+    #[inline(always)]
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.combine(b);
+    }
+
+    #[inline(always)]
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<T::Native>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.add(b.as_());
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for x in ca.iter().flatten() {
+            v.add(x.as_());
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let sums = v
+            .iter()
+            .map(|s| <T::Native as NumCast>::from(s.sum()).unwrap())
+            .collect();
+        let arr = Box::new(PrimitiveArray::<T::Native>::from_vec(sums));
+        Ok(unsafe { Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![arr], dtype) })
+    }
+}
+
 #[derive(Clone)]
 struct BoolSumReducer;
 
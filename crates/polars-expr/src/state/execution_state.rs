@@ -4,7 +4,7 @@ use std::sync::{Mutex, OnceLock, RwLock};
 use std::time::Duration;
 
 use bitflags::bitflags;
-use polars_core::config::verbose;
+use polars_core::config::ConfigOverrides;
 use polars_core::prelude::*;
 use polars_ops::prelude::ChunkJoinOptIds;
 use polars_utils::relaxed_cell::RelaxedCell;
@@ -80,8 +80,7 @@ impl Default for StateFlags {
 }
 
 impl StateFlags {
-    fn init() -> Self {
-        let verbose = verbose();
+    fn init(verbose: bool) -> Self {
         let mut flags: StateFlags = Default::default();
         if verbose {
             flags |= StateFlags::VERBOSE;
@@ -114,23 +113,27 @@ pub struct ExecutionState {
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
     stop: Arc<RelaxedCell<bool>>,
+    /// The settings of the query that override the global configuration.
+    config: Arc<ConfigOverrides>,
 }
 
 impl ExecutionState {
     pub fn new() -> Self {
-        let mut flags: StateFlags = Default::default();
-        if verbose() {
-            flags |= StateFlags::VERBOSE;
-        }
+        Self::with_config(Default::default())
+    }
+
+    /// Create the state of a query that runs with `config` applied.
+    pub fn with_config(config: Arc<ConfigOverrides>) -> Self {
         Self {
             df_cache: Default::default(),
             schema_cache: Default::default(),
             window_cache: Default::default(),
             branch_idx: 0,
-            flags: RelaxedCell::from(StateFlags::init().as_u8()),
+            flags: RelaxedCell::from(StateFlags::init(config.verbose()).as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
             stop: Arc::new(RelaxedCell::from(false)),
+            config,
         }
     }
 
@@ -195,6 +198,7 @@ impl ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            config: self.config.clone(),
         }
     }
 
@@ -268,6 +272,11 @@ impl ExecutionState {
         flags.contains(StateFlags::VERBOSE)
     }
 
+    /// The settings of the query that override the global configuration.
+    pub fn config(&self) -> &Arc<ConfigOverrides> {
+        &self.config
+    }
+
     pub fn remove_cache_window_flag(&mut self) {
         self.set_flags(&|mut flags| {
             flags.remove(StateFlags::CACHE_WINDOW_EXPR);
@@ -308,6 +317,7 @@ impl Clone for ExecutionState {
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
             stop: self.stop.clone(),
+            config: self.config.clone(),
         }
     }
 }
@@ -1,7 +1,10 @@
 use std::io::{Read, Seek};
 
+use arrow::array::{Array, ListArray, PrimitiveArray, StructArray};
+use arrow::datatypes::{ArrowSchemaRef, IntervalUnit};
 use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
+use arrow::types::months_days_ns;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
 
@@ -10,6 +13,11 @@ use crate::shared::{ArrowReader, finish_reader};
 
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
+/// The logical types `decimal`, `date`, `time-*`, `timestamp-*` and `local-timestamp-*`
+/// are read as their Polars counterparts and `uuid` is read as a `String`. Polars has
+/// no calendar interval type, so `duration` is read as a millisecond `Duration`; reading
+/// a duration with a non-zero month component is an error.
+///
 /// [Apache Avro]: https://avro.apache.org
 ///
 /// # Example
@@ -43,11 +51,14 @@ impl<R: Read + Seek> AvroReader<R> {
     }
 
     /// Get arrow schema of the avro File, this is faster than a polars schema.
+    ///
+    /// Logical types without a Polars counterpart are already mapped to the type they are
+    /// read as, see [`AvroReader`].
     pub fn arrow_schema(&mut self) -> PolarsResult<ArrowSchema> {
         let metadata =
             avro::avro_schema::read::read_metadata(&mut self.reader).map_err(to_compute_err)?;
         let schema = read::infer_schema(&metadata.record)?;
-        Ok(schema)
+        Ok(to_polars_schema(&schema))
     }
 
     /// Stop reading when `n` rows are read.
@@ -79,6 +90,113 @@ where
     }
 }
 
+const MILLISECONDS_IN_DAY: i64 = 86_400_000;
+
+fn to_polars_dtype(dtype: &ArrowDataType) -> ArrowDataType {
+    match dtype {
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            ArrowDataType::Duration(ArrowTimeUnit::Millisecond)
+        },
+        ArrowDataType::List(inner) => ArrowDataType::List(Box::new(to_polars_field(inner))),
+        ArrowDataType::Struct(fields) => {
+            ArrowDataType::Struct(fields.iter().map(to_polars_field).collect())
+        },
+        dt => dt.clone(),
+    }
+}
+
+fn to_polars_field(field: &ArrowField) -> ArrowField {
+    let mut field = field.clone();
+    field.dtype = to_polars_dtype(&field.dtype);
+    field
+}
+
+fn to_polars_schema(schema: &ArrowSchema) -> ArrowSchema {
+    schema
+        .iter_values()
+        .map(|field| (field.name.clone(), to_polars_field(field)))
+        .collect()
+}
+
+/// Convert an array deserialized from Avro to the dtype given by [`to_polars_dtype`].
+fn to_polars_array(array: Box<dyn Array>) -> PolarsResult<Box<dyn Array>> {
+    Ok(match array.dtype() {
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            array
+                .iter()
+                .map(|opt_v| {
+                    opt_v
+                        .map(|v| {
+                            polars_ensure!(
+                                v.months() == 0,
+                                ComputeError: "cannot read Avro duration with a month component \
+                                as a Polars Duration, got {} months", v.months()
+                            );
+                            Ok(v.days() as i64 * MILLISECONDS_IN_DAY + v.ns() / 1_000_000)
+                        })
+                        .transpose()
+                })
+                .collect::<PolarsResult<PrimitiveArray<i64>>>()?
+                .to(ArrowDataType::Duration(ArrowTimeUnit::Millisecond))
+                .boxed()
+        },
+        ArrowDataType::List(_) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            ListArray::new(
+                to_polars_dtype(array.dtype()),
+                array.offsets().clone(),
+                to_polars_array(array.values().clone())?,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        ArrowDataType::Struct(_) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let values = array
+                .values()
+                .iter()
+                .map(|values| to_polars_array(values.clone()))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            StructArray::new(
+                to_polars_dtype(array.dtype()),
+                array.len(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        _ => array,
+    })
+}
+
+/// Reads the record batches of an Avro file as types Polars can represent.
+struct PolarsAvroReader<R: Read + Seek> {
+    reader: read::Reader<R>,
+    schema: ArrowSchemaRef,
+}
+
+impl<R> ArrowReader for PolarsAvroReader<R>
+where
+    R: Read + Seek,
+{
+    fn next_record_batch(&mut self) -> PolarsResult<Option<RecordBatch>> {
+        let Some(batch) = self.reader.next_record_batch()? else {
+            return Ok(None);
+        };
+        let height = batch.height();
+        let arrays = batch
+            .into_arrays()
+            .into_iter()
+            .map(to_polars_array)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Some(RecordBatch::new(height, self.schema.clone(), arrays)))
+    }
+}
+
 impl<R> SerReader<R> for AvroReader<R>
 where
     R: Read + Seek,
@@ -118,7 +236,11 @@ where
             (None, schema.clone())
         };
 
-        let avro_reader = avro::read::Reader::new(&mut self.reader, metadata, schema, projection);
+        let avro_reader = PolarsAvroReader {
+            reader: avro::read::Reader::new(&mut self.reader, metadata, schema, projection),
+            schema: Arc::new(to_polars_schema(&projected_schema)),
+        };
+        let projected_schema = avro_reader.schema.clone();
 
         finish_reader(
             avro_reader,
@@ -9,6 +9,14 @@ use polars_core::prelude::*;
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// The Avro schema, as JSON, of the files written by [`AvroWriter`] for a [`DataFrame`] with
+/// `schema`. `name` is the name of the record, see [`AvroWriter::with_name`].
+pub fn avro_schema_json(schema: &Schema, name: &str) -> PolarsResult<String> {
+    let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+    let record = write::to_record(&schema, name.to_string())?;
+    serde_json::to_string(&avro_schema::schema::Schema::Record(record)).map_err(to_compute_err)
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -0,0 +1,281 @@
+//! Reading [BSON] documents, as written by `mongodump`.
+//!
+//! [BSON]: https://bsonspec.org/spec.html
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+use crate::document::{DocumentBuilder, Value};
+use crate::prelude::*;
+
+/// The maximum nesting depth of documents and arrays, which bounds the recursion of the decoder.
+const MAX_DEPTH: usize = 256;
+
+/// Read a file of concatenated BSON documents into a [`DataFrame`].
+///
+/// Every top-level key becomes a column, with its dtype inferred as the supertype of its
+/// values. Embedded documents are read as `Struct`s and arrays as `List`s. ObjectIds are read
+/// as their hexadecimal `String` representation and UTC datetimes as millisecond `Datetime`s
+/// without a time zone. `Decimal128` values and JavaScript code with scope are not supported.
+///
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::bson::BsonReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("collection.bson").expect("file not found");
+///
+///     BsonReader::new(file).finish()
+/// }
+/// ```
+#[must_use]
+pub struct BsonReader<R> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<PlSmallStr>>,
+}
+
+impl<R: Read> BsonReader<R> {
+    /// Stop reading when `n` documents are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<PlSmallStr>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<R: Read> SerReader<R> for BsonReader<R> {
+    fn new(reader: R) -> Self {
+        BsonReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let mut buf = vec![];
+        self.reader.read_to_end(&mut buf)?;
+
+        let mut builder = DocumentBuilder::new(self.columns);
+        let mut cursor = Cursor {
+            buf: &buf,
+            depth: 0,
+        };
+        while !cursor.buf.is_empty() && self.n_rows.is_none_or(|n| builder.height() < n) {
+            builder.push(cursor.document()?)?;
+        }
+        builder.finish(self.rechunk)
+    }
+}
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+    depth: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn bytes(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            len <= self.buf.len(),
+            ComputeError: "BSON data ended unexpectedly, expected {} more bytes", len
+        );
+        let (out, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> PolarsResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn i32(&mut self) -> PolarsResult<i32> {
+        Ok(i32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> PolarsResult<i64> {
+        Ok(i64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn length(&mut self) -> PolarsResult<usize> {
+        let len = self.i32()?;
+        polars_ensure!(len >= 0, ComputeError: "negative length in BSON data");
+        Ok(len as usize)
+    }
+
+    fn utf8(bytes: &[u8]) -> PolarsResult<String> {
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| polars_err!(ComputeError: "BSON data contains invalid utf-8"))
+    }
+
+    fn cstring(&mut self) -> PolarsResult<String> {
+        let end = self
+            .buf
+            .iter()
+            .position(|&b| b == 0)
+            .ok_or_else(|| polars_err!(ComputeError: "unterminated string in BSON data"))?;
+        let s = Self::utf8(&self.buf[..end])?;
+        self.buf = &self.buf[end + 1..];
+        Ok(s)
+    }
+
+    fn string(&mut self) -> PolarsResult<String> {
+        let len = self.length()?;
+        polars_ensure!(len > 0, ComputeError: "invalid string length in BSON data");
+        let bytes = self.bytes(len)?;
+        Self::utf8(&bytes[..len - 1])
+    }
+
+    /// Read a document, including its length prefix.
+    fn document(&mut self) -> PolarsResult<Vec<(PlSmallStr, Value)>> {
+        let len = self.length()?;
+        polars_ensure!(len >= 5, ComputeError: "invalid document length in BSON data");
+        polars_ensure!(
+            self.depth < MAX_DEPTH,
+            ComputeError: "BSON data exceeds the maximum nesting depth of {}", MAX_DEPTH
+        );
+        let mut inner = Cursor {
+            buf: self.bytes(len - 4)?,
+            depth: self.depth + 1,
+        };
+
+        let mut entries = vec![];
+        loop {
+            let element_type = inner.u8()?;
+            if element_type == 0 {
+                break;
+            }
+            let name = inner.cstring()?;
+            entries.push((name.into(), inner.value(element_type)?));
+        }
+        Ok(entries)
+    }
+
+    fn value(&mut self, element_type: u8) -> PolarsResult<Value> {
+        Ok(match element_type {
+            0x01 => Value::Float(f64::from_bits(self.i64()? as u64)),
+            // A string, JavaScript code or a symbol.
+            0x02 | 0x0D | 0x0E => Value::String(self.string()?),
+            0x03 => Value::Document(self.document()?),
+            // Arrays are documents with the indices as keys.
+            0x04 => Value::List(self.document()?.into_iter().map(|(_, v)| v).collect()),
+            0x05 => {
+                let len = self.length()?;
+                let _subtype = self.u8()?;
+                Value::Binary(self.bytes(len)?.to_vec())
+            },
+            // Undefined, null, min key and max key.
+            0x06 | 0x0A | 0xFF | 0x7F => Value::Null,
+            0x07 => {
+                let mut hex = String::with_capacity(24);
+                for byte in self.bytes(12)? {
+                    hex.push_str(&format!("{byte:02x}"));
+                }
+                Value::String(hex)
+            },
+            0x08 => Value::Boolean(self.u8()? != 0),
+            0x09 => Value::Datetime(self.i64()?, TimeUnit::Milliseconds),
+            // A regular expression, read as its pattern.
+            0x0B => {
+                let pattern = self.cstring()?;
+                let _options = self.cstring()?;
+                Value::String(pattern)
+            },
+            0x10 => Value::Int(self.i32()? as i64),
+            0x11 => Value::UInt(self.i64()? as u64),
+            0x12 => Value::Int(self.i64()?),
+            0x13 => polars_bail!(nyi = "reading BSON Decimal128 values"),
+            0x0F => polars_bail!(nyi = "reading BSON JavaScript code with scope"),
+            t => polars_bail!(ComputeError: "unsupported BSON element type 0x{:02x}", t),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn element(element_type: u8, name: &str, value: &[u8], out: &mut Vec<u8>) {
+        out.push(element_type);
+        out.extend_from_slice(name.as_bytes());
+        out.push(0);
+        out.extend_from_slice(value);
+    }
+
+    fn document(elements: &[u8]) -> Vec<u8> {
+        let mut out = ((elements.len() + 5) as i32).to_le_bytes().to_vec();
+        out.extend_from_slice(elements);
+        out.push(0);
+        out
+    }
+
+    #[test]
+    fn test_read_bson() -> PolarsResult<()> {
+        let mut first = vec![];
+        element(0x10, "a", &1i32.to_le_bytes(), &mut first);
+        let mut array = vec![];
+        element(0x12, "0", &2i64.to_le_bytes(), &mut array);
+        element(0x01, "1", &0.5f64.to_le_bytes(), &mut array);
+        element(0x04, "b", &document(&array), &mut first);
+
+        let mut second = vec![];
+        let mut string = 2i32.to_le_bytes().to_vec();
+        string.extend_from_slice(b"x\0");
+        element(0x02, "c", &string, &mut second);
+        element(0x0A, "a", &[], &mut second);
+
+        let mut buf = document(&first);
+        buf.extend(document(&second));
+
+        let df = BsonReader::new(std::io::Cursor::new(&buf)).finish()?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+        assert_eq!(
+            df.dtypes(),
+            vec![
+                DataType::Int64,
+                DataType::List(Box::new(DataType::Float64)),
+                DataType::String
+            ]
+        );
+        assert_eq!(df.column("a")?.null_count(), 1);
+        assert_eq!(df.column("c")?.str()?.get(1), Some("x"));
+
+        let df = BsonReader::new(std::io::Cursor::new(&buf))
+            .with_columns(Some(vec!["c".into()]))
+            .with_n_rows(Some(1))
+            .finish()?;
+        assert_eq!(df.shape(), (1, 1));
+        assert_eq!(df.column("c")?.null_count(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_bson_too_deep() {
+        let mut buf = document(&[]);
+        for _ in 0..MAX_DEPTH + 1 {
+            let mut elements = vec![];
+            element(0x03, "a", &buf, &mut elements);
+            buf = document(&elements);
+        }
+        assert!(
+            BsonReader::new(std::io::Cursor::new(&buf))
+                .finish()
+                .is_err()
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use polars_core::prelude::PlHashMap;
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail, to_compute_err};
+use polars_utils::pl_str::PlSmallStr;
 
 use super::models::{CatalogInfo, NamespaceInfo, TableCredentials, TableInfo};
-use super::schema::schema_to_column_info_list;
-use super::utils::{PageWalker, do_request};
+use super::schema::{schema_to_column_info_list, set_partition_indices};
+use super::utils::{PageWalker, do_request, do_request_opt};
 use crate::catalog::unity::models::{ColumnInfo, DataSourceFormat, TableType};
 use crate::impl_page_walk;
 use crate::utils::decode_json_response;
@@ -61,6 +62,39 @@ impl CatalogClient {
         namespace: &str,
         table_name: &str,
     ) -> PolarsResult<TableInfo> {
+        let bytes =
+            do_request(self.get_table_info_request(catalog_name, namespace, table_name)).await?;
+
+        let out: TableInfo = decode_json_response(&bytes)?;
+
+        Ok(out)
+    }
+
+    /// Returns `None` if the table does not exist.
+    pub async fn try_get_table_info(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> PolarsResult<Option<TableInfo>> {
+        let Some(bytes) =
+            do_request_opt(self.get_table_info_request(catalog_name, namespace, table_name))
+                .await?
+        else {
+            return Ok(None);
+        };
+
+        let out: TableInfo = decode_json_response(&bytes)?;
+
+        Ok(Some(out))
+    }
+
+    fn get_table_info_request(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> reqwest::RequestBuilder {
         let full_table_name = format!(
             "{}.{}.{}",
             catalog_name.replace('/', "%2F"),
@@ -68,19 +102,12 @@ impl CatalogClient {
             table_name.replace('/', "%2F")
         );
 
-        let bytes = do_request(
-            self.http_client
-                .get(format!(
-                    "{}{}{}",
-                    &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_table_name
-                ))
-                .query(&[("full_name", full_table_name)]),
-        )
-        .await?;
-
-        let out: TableInfo = decode_json_response(&bytes)?;
-
-        Ok(out)
+        self.http_client
+            .get(format!(
+                "{}{}{}",
+                &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_table_name
+            ))
+            .query(&[("full_name", full_table_name)])
     }
 
     pub async fn get_table_credentials(
@@ -218,13 +245,14 @@ impl CatalogClient {
         namespace: &str,
         table_name: &str,
         schema: Option<&Schema>,
+        partition_columns: &[PlSmallStr],
         table_type: &TableType,
         data_source_format: Option<&DataSourceFormat>,
         comment: Option<&str>,
         storage_location: Option<&str>,
         properties: &mut (dyn Iterator<Item = (&str, &str)> + Send + Sync),
     ) -> PolarsResult<TableInfo> {
-        let columns = schema.map(schema_to_column_info_list).transpose()?;
+        let columns = columns_with_partitioning(schema, partition_columns)?;
         let columns = columns.as_deref();
 
         let resp = do_request(
@@ -264,6 +292,40 @@ impl CatalogClient {
         }
     }
 
+    /// Updates the owner of an existing table. This is the only table metadata that the
+    /// tables API allows to be changed after creation.
+    pub async fn update_table(
+        &self,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        owner: &str,
+    ) -> PolarsResult<TableInfo> {
+        let full_name = format!(
+            "{}.{}.{}",
+            catalog_name.replace('/', "%2F"),
+            namespace.replace('/', "%2F"),
+            table_name.replace('/', "%2F"),
+        );
+
+        let resp = do_request(
+            self.http_client
+                .patch(format!(
+                    "{}{}{}",
+                    &self.workspace_url, "/api/2.1/unity-catalog/tables/", full_name
+                ))
+                .json(&Body { owner }),
+        )
+        .await?;
+
+        return decode_json_response(&resp);
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            owner: &'a str,
+        }
+    }
+
     pub async fn delete_table(
         &self,
         catalog_name: &str,
@@ -287,6 +349,23 @@ impl CatalogClient {
     }
 }
 
+fn columns_with_partitioning(
+    schema: Option<&Schema>,
+    partition_columns: &[PlSmallStr],
+) -> PolarsResult<Option<Vec<ColumnInfo>>> {
+    let Some(schema) = schema else {
+        if !partition_columns.is_empty() {
+            polars_bail!(ComputeError: "cannot set partition columns without a table schema")
+        }
+        return Ok(None);
+    };
+
+    let mut columns = schema_to_column_info_list(schema)?;
+    set_partition_indices(&mut columns, partition_columns)?;
+
+    Ok(Some(columns))
+}
+
 pub struct CatalogClientBuilder {
     workspace_url: Option<String>,
     bearer_token: Option<String>,
@@ -267,6 +267,26 @@ pub fn schema_to_column_info_list(schema: &Schema) -> PolarsResult<Vec<ColumnInf
         .collect::<PolarsResult<_>>()
 }
 
+/// Sets the `partition_index` of the columns in `partition_columns` to their position in that
+/// list.
+pub fn set_partition_indices(
+    columns: &mut [ColumnInfo],
+    partition_columns: &[PlSmallStr],
+) -> PolarsResult<()> {
+    for (i, name) in partition_columns.iter().enumerate() {
+        let Some(column) = columns.iter_mut().find(|c| &c.name == name) else {
+            polars_bail!(
+                ColumnNotFound:
+                "partition column '{}' not found in the table schema",
+                name
+            )
+        };
+        column.partition_index = Some(i.try_into().unwrap());
+    }
+
+    Ok(())
+}
+
 /// Creates the `type_text` field of the API. Opposite of [`parse_type_text`]
 fn dtype_to_type_text(dtype: &DataType) -> PolarsResult<PlSmallStr> {
     use DataType::*;
@@ -5,7 +5,27 @@ use reqwest::RequestBuilder;
 
 /// Performs the request and attaches the response body to any error messages.
 pub(super) async fn do_request(request: reqwest::RequestBuilder) -> PolarsResult<bytes::Bytes> {
+    do_request_impl(request, false).await.map(Option::unwrap)
+}
+
+/// Performs the request like [`do_request`], but returns `None` if the response status is
+/// `404 Not Found`.
+pub(super) async fn do_request_opt(
+    request: reqwest::RequestBuilder,
+) -> PolarsResult<Option<bytes::Bytes>> {
+    do_request_impl(request, true).await
+}
+
+async fn do_request_impl(
+    request: reqwest::RequestBuilder,
+    allow_not_found: bool,
+) -> PolarsResult<Option<bytes::Bytes>> {
     let resp = request.send().await.map_err(to_compute_err)?;
+
+    if allow_not_found && resp.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
     let opt_err = resp.error_for_status_ref().map(|_| ());
     let resp_bytes = resp.bytes().await.map_err(to_compute_err)?;
 
@@ -21,7 +41,7 @@ pub(super) async fn do_request(request: reqwest::RequestBuilder) -> PolarsResult
         })
     })?;
 
-    Ok(resp_bytes)
+    Ok(Some(resp_bytes))
 }
 
 /// Support for traversing paginated response values that look like:
@@ -0,0 +1,224 @@
+//! Functionality for reading and writing the system clipboard.
+//!
+//! Text copied from spreadsheet software is tab-separated, but text copied from elsewhere often
+//! is not. [`sniff_separator`] guesses the separator and [`parse_spreadsheet_numbers`] converts
+//! the formatted numbers spreadsheets produce (`1,234.5`, `12%`) to numeric columns.
+use polars_core::prelude::*;
+
+/// The separators [`sniff_separator`] considers, in order of preference.
+const SEPARATOR_CANDIDATES: [u8; 4] = [b'\t', b',', b';', b'|'];
+/// The number of lines [`sniff_separator`] looks at.
+const SNIFF_LINES: usize = 20;
+
+fn clipboard_err(e: arboard::Error) -> PolarsError {
+    polars_err!(ComputeError: "clipboard error: {}", e)
+}
+
+/// Get the text currently on the system clipboard.
+pub fn read_clipboard_string() -> PolarsResult<String> {
+    let mut clipboard = arboard::Clipboard::new().map_err(clipboard_err)?;
+    clipboard.get_text().map_err(clipboard_err)
+}
+
+/// Put `text` on the system clipboard.
+pub fn write_clipboard_string(text: &str) -> PolarsResult<()> {
+    let mut clipboard = arboard::Clipboard::new().map_err(clipboard_err)?;
+    clipboard.set_text(text).map_err(clipboard_err)
+}
+
+/// Count the occurrences of `separator` in `line` that are not within double quotes.
+fn count_unquoted(line: &str, separator: u8) -> usize {
+    let mut in_quotes = false;
+    let mut count = 0;
+    for b in line.bytes() {
+        if b == b'"' {
+            in_quotes = !in_quotes;
+        } else if b == separator && !in_quotes {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Guess the field separator of delimited `text`.
+///
+/// The first candidate out of tab, comma, semicolon and pipe that occurs equally often on each of
+/// the first lines is chosen. If no candidate is consistent, the one occurring most often on the
+/// first line is chosen, and tab if there is none at all.
+pub fn sniff_separator(text: &str) -> u8 {
+    let lines = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .take(SNIFF_LINES)
+        .collect::<Vec<_>>();
+    let Some(first) = lines.first() else {
+        return b'\t';
+    };
+
+    let consistent = SEPARATOR_CANDIDATES.into_iter().find(|&sep| {
+        let n = count_unquoted(first, sep);
+        n > 0 && lines.iter().all(|line| count_unquoted(line, sep) == n)
+    });
+    consistent.unwrap_or_else(|| {
+        SEPARATOR_CANDIDATES
+            .into_iter()
+            .map(|sep| (count_unquoted(first, sep), sep))
+            .filter(|(n, _)| *n > 0)
+            // Prefer earlier candidates on ties.
+            .max_by_key(|(n, sep)| {
+                let rank = SEPARATOR_CANDIDATES.iter().position(|c| c == sep).unwrap();
+                (*n, usize::MAX - rank)
+            })
+            .map_or(b'\t', |(_, sep)| sep)
+    })
+}
+
+/// A number as formatted by a spreadsheet.
+struct SpreadsheetNumber {
+    value: f64,
+    is_integer: bool,
+    /// Whether going through [`parse_spreadsheet_number`] changed the textual representation, i.e.
+    /// the CSV reader could not have parsed it.
+    was_formatted: bool,
+}
+
+/// Check whether `digits` is an integer with optional `,` thousands separators.
+fn is_grouped_integer(digits: &str) -> bool {
+    if !digits.contains(',') {
+        return !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit());
+    }
+    let mut groups = digits.split(',');
+    let head = groups.next().unwrap();
+    (1..=3).contains(&head.len())
+        && head.bytes().all(|b| b.is_ascii_digit())
+        && groups.all(|g| g.len() == 3 && g.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Parse a number such as `1,234.5`, `-12%` or `(1,000)`.
+fn parse_spreadsheet_number(s: &str) -> Option<SpreadsheetNumber> {
+    let mut s = s.trim();
+    let mut was_formatted = false;
+
+    let percent = s.ends_with('%');
+    if percent {
+        s = s[..s.len() - 1].trim_end();
+        was_formatted = true;
+    }
+    // Accounting notation for negative numbers.
+    let parenthesized = s.len() > 2 && s.starts_with('(') && s.ends_with(')');
+    let negative = if parenthesized {
+        s = &s[1..s.len() - 1];
+        was_formatted = true;
+        true
+    } else if let Some(rest) = s.strip_prefix('-') {
+        s = rest;
+        true
+    } else {
+        false
+    };
+
+    let (int_part, frac_part) = match s.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (s, None),
+    };
+    if !is_grouped_integer(int_part)
+        || frac_part.is_some_and(|f| f.is_empty() || !f.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+    was_formatted |= int_part.contains(',');
+
+    let mut digits = int_part.replace(',', "");
+    if let Some(frac_part) = frac_part {
+        digits.push('.');
+        digits.push_str(frac_part);
+    }
+    let mut value = digits.parse::<f64>().ok()?;
+    if negative {
+        value = -value;
+    }
+    if percent {
+        value /= 100.0;
+    }
+    Some(SpreadsheetNumber {
+        value,
+        is_integer: frac_part.is_none() && !percent,
+        was_formatted,
+    })
+}
+
+fn parse_spreadsheet_column(ca: &StringChunked) -> Option<Column> {
+    let mut any_formatted = false;
+    let mut all_integer = true;
+    let values = ca
+        .iter()
+        .map(|opt_s| match opt_s {
+            None => Some(None),
+            Some(s) => {
+                let n = parse_spreadsheet_number(s)?;
+                any_formatted |= n.was_formatted;
+                all_integer &= n.is_integer;
+                Some(Some(n.value))
+            },
+        })
+        .collect::<Option<Vec<_>>>()?;
+    if !any_formatted {
+        return None;
+    }
+
+    let name = ca.name().clone();
+    let column = if all_integer && values.iter().flatten().all(|v| v.abs() < 2f64.powi(53)) {
+        Int64Chunked::from_iter_options(name, values.into_iter().map(|v| v.map(|v| v as i64)))
+            .into_column()
+    } else {
+        Float64Chunked::from_iter_options(name, values.into_iter()).into_column()
+    };
+    Some(column)
+}
+
+/// Convert the string columns of `df` that consist of spreadsheet-formatted numbers, such as
+/// `1,234.5` or `12%`, to numeric columns.
+///
+/// Columns become `Int64` if all values are integers, otherwise `Float64`. Percentages are
+/// divided by 100. Columns with any value that is not a number are left as is.
+pub fn parse_spreadsheet_numbers(mut df: DataFrame) -> PolarsResult<DataFrame> {
+    let replacements = df
+        .get_columns()
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| {
+            let ca = c.str().ok()?;
+            parse_spreadsheet_column(ca).map(|c| (i, c))
+        })
+        .collect::<Vec<_>>();
+    for (i, column) in replacements {
+        df.replace_column(i, column)?;
+    }
+    Ok(df)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sniff_separator() {
+        assert_eq!(sniff_separator("a\tb\n\"1,5\"\t2\n"), b'\t');
+        assert_eq!(sniff_separator("a,b,c\n1,2,3\n"), b',');
+        assert_eq!(sniff_separator("a;b\n1,5;2\n"), b';');
+        assert_eq!(sniff_separator("a|b\n1|2|3\n"), b'|');
+        assert_eq!(sniff_separator(""), b'\t');
+    }
+
+    #[test]
+    fn test_parse_spreadsheet_number() {
+        let parse = |s| parse_spreadsheet_number(s).map(|n| (n.value, n.is_integer));
+        assert_eq!(parse("1,234"), Some((1234.0, true)));
+        assert_eq!(parse("-1,234.5"), Some((-1234.5, false)));
+        assert_eq!(parse("(1,000)"), Some((-1000.0, true)));
+        assert_eq!(parse("12.5%"), Some((0.125, false)));
+        assert_eq!(parse("12,34"), None);
+        assert_eq!(parse("1.2.3"), None);
+        assert_eq!(parse("abc"), None);
+    }
+}
@@ -25,7 +25,9 @@ pub mod schema_inference;
 mod splitfields;
 mod utils;
 
-pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+pub use options::{
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, DateInferenceOptions, NullValues,
+};
 pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
@@ -2,6 +2,7 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 
+pub use polars_core::datatypes::DateInferenceOptions;
 use polars_core::datatypes::{DataType, Field};
 use polars_core::schema::{Schema, SchemaRef};
 use polars_error::PolarsResult;
@@ -10,6 +11,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use crate::RowIndex;
+use crate::utils::compression::ArchiveReadOptions;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -42,6 +44,9 @@ pub struct CsvReadOptions {
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
+    /// Glob pattern that selects the members to read if the file is a (compressed) archive.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub archive_member_glob: Option<PlSmallStr>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -57,6 +62,15 @@ pub struct CsvParseOptions {
     pub truncate_ragged_lines: bool,
     pub comment_prefix: Option<CommentPrefix>,
     pub try_parse_dates: bool,
+    /// Infer the formats of the temporal columns with these options if `try_parse_dates` is set,
+    /// rather than inferring the dtype of every value on its own. This requires the `polars-time`
+    /// feature and is ignored without it.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub date_inference: Option<DateInferenceOptions>,
+    /// The formats of the columns to parse into their temporal dtype after reading them as
+    /// strings, by column name. Schema inference sets the formats that `date_inference` chose.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub date_formats: Vec<(PlSmallStr, PlSmallStr)>,
     pub decimal_comma: bool,
 }
 
@@ -88,6 +102,7 @@ impl Default for CsvReadOptions {
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
+            archive_member_glob: None,
         }
     }
 }
@@ -105,6 +120,8 @@ impl Default for CsvParseOptions {
             truncate_ragged_lines: false,
             comment_prefix: None,
             try_parse_dates: false,
+            date_inference: None,
+            date_formats: vec![],
             decimal_comma: false,
         }
     }
@@ -115,6 +132,15 @@ impl CsvReadOptions {
         self.parse_options.clone()
     }
 
+    /// Get the options to read the members of an archive with.
+    pub fn get_archive_options(&self) -> ArchiveReadOptions<'_> {
+        ArchiveReadOptions {
+            member_glob: self.archive_member_glob.as_deref(),
+            has_header: self.has_header,
+            eol_char: self.parse_options.eol_char,
+        }
+    }
+
     pub fn with_path<P: Into<PathBuf>>(mut self, path: Option<P>) -> Self {
         self.path = path.map(|p| p.into());
         self
@@ -247,6 +273,13 @@ impl CsvReadOptions {
         self
     }
 
+    /// Only read the archive members whose path matches this glob pattern if the file is a zip
+    /// or (compressed) tar archive. All members are read by default.
+    pub fn with_archive_member_glob(mut self, archive_member_glob: Option<PlSmallStr>) -> Self {
+        self.archive_member_glob = archive_member_glob;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -323,6 +356,13 @@ impl CsvParseOptions {
         self
     }
 
+    /// Infer the formats of the temporal columns with the given options if `try_parse_dates` is
+    /// set, see [`DateInferenceOptions`].
+    pub fn with_date_inference(mut self, date_inference: Option<DateInferenceOptions>) -> Self {
+        self.date_inference = date_inference;
+        self
+    }
+
     /// Parse floats with a comma as decimal separator.
     pub fn with_decimal_comma(mut self, decimal_comma: bool) -> Self {
         self.decimal_comma = decimal_comma;
@@ -14,7 +14,7 @@ use super::options::{CommentPrefix, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
 use crate::prelude::_csv_read_internal::find_starting_point;
-use crate::utils::compression::maybe_decompress_bytes;
+use crate::utils::compression::{ArchiveReadOptions, maybe_decompress_archive_bytes};
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
@@ -29,6 +29,7 @@ pub fn count_rows(
     skip_lines: usize,
     skip_rows_before_header: usize,
     skip_rows_after_header: usize,
+    archive_member_glob: Option<&str>,
 ) -> PolarsResult<usize> {
     let file = match addr
         .as_local_path()
@@ -46,7 +47,12 @@ pub fn count_rows(
 
     let mmap = MMapSemaphore::new_from_file(&file).unwrap();
     let owned = &mut vec![];
-    let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+    let archive_options = ArchiveReadOptions {
+        member_glob: archive_member_glob,
+        has_header,
+        eol_char,
+    };
+    let reader_bytes = maybe_decompress_archive_bytes(mmap.as_ref(), owned, &archive_options)?;
 
     count_rows_from_slice_par(
         reader_bytes,
@@ -7,6 +7,8 @@ use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, handle_casting_failures};
 #[cfg(feature = "polars-time")]
+use polars_time::chunkedarray::string::DateInference;
+#[cfg(feature = "polars-time")]
 use polars_time::prelude::*;
 use polars_utils::relaxed_cell::RelaxedCell;
 use rayon::prelude::*;
@@ -26,17 +28,32 @@ use crate::RowIndex;
 use crate::csv::read::parser::skip_this_line_naive;
 use crate::mmap::ReaderBytes;
 use crate::predicates::PhysicalIoExpr;
-use crate::utils::compression::SupportedCompression;
+use crate::utils::compression::{
+    ArchiveReadOptions, SupportedCompression, is_archive, maybe_decompress_archive_bytes,
+};
 use crate::utils::update_row_counts2;
 
+#[cfg_attr(not(feature = "polars-time"), allow(unused_variables))]
 pub fn cast_columns(
     df: &mut DataFrame,
     to_cast: &[Field],
     parallel: bool,
     ignore_errors: bool,
+    parse_options: &CsvParseOptions,
 ) -> PolarsResult<()> {
+    #[cfg(feature = "polars-time")]
+    let date_inference = parse_options.date_inference.clone().unwrap_or_default();
     let cast_fn = |c: &Column, fld: &Field| {
+        #[cfg(feature = "polars-time")]
+        let date_format = parse_options
+            .date_formats
+            .iter()
+            .find(|(name, _)| name == fld.name());
         let out = match (c.dtype(), fld.dtype()) {
+            #[cfg(feature = "polars-time")]
+            (DataType::String, dt) if date_format.is_some() => date_inference
+                .parse(c.str().unwrap(), &date_format.unwrap().1, dt)
+                .map(|s| s.into_column()),
             #[cfg(feature = "temporal")]
             (DataType::String, DataType::Date) => c
                 .str()
@@ -137,7 +154,7 @@ impl<'a> CoreReader<'a> {
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         reader_bytes: ReaderBytes<'a>,
-        parse_options: Arc<CsvParseOptions>,
+        mut parse_options: Arc<CsvParseOptions>,
         n_rows: Option<usize>,
         skip_rows: usize,
         skip_lines: usize,
@@ -156,12 +173,25 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
+        archive_member_glob: Option<&str>,
     ) -> PolarsResult<CoreReader<'a>> {
         let separator = parse_options.separator;
 
-        #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
+        // Archives are read completely, as the members have to be located before decompressing.
+        let is_archive = is_archive(&reader_bytes);
+        if is_archive {
+            let archive_options = ArchiveReadOptions {
+                member_glob: archive_member_glob,
+                has_header,
+                eol_char: parse_options.eol_char,
+            };
+            let mut out = vec![];
+            maybe_decompress_archive_bytes(&reader_bytes, &mut out, &archive_options)?;
+            reader_bytes = ReaderBytes::Owned(out.into());
+        }
+
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
             polars_bail!(
                 ComputeError: "cannot read compressed CSV file; \
@@ -172,7 +202,7 @@ impl<'a> CoreReader<'a> {
         // In case the file is compressed this schema inference is wrong and has to be done
         // again after decompression.
         #[cfg(feature = "decompress")]
-        {
+        if !is_archive {
             let total_n_rows =
                 n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
@@ -189,7 +219,7 @@ impl<'a> CoreReader<'a> {
         let mut schema = match schema {
             Some(schema) => schema,
             None => {
-                let (inferred_schema, _, _) = infer_file_schema(
+                let (inferred_schema, _, _, date_formats) = infer_file_schema(
                     &reader_bytes,
                     &parse_options,
                     max_records,
@@ -200,6 +230,9 @@ impl<'a> CoreReader<'a> {
                     skip_rows_after_header,
                     raise_if_empty,
                 )?;
+                if !date_formats.is_empty() {
+                    Arc::make_mut(&mut parse_options).date_formats = date_formats;
+                }
                 Arc::new(inferred_schema)
             },
         };
@@ -214,7 +247,7 @@ impl<'a> CoreReader<'a> {
             }
         }
 
-        prepare_csv_schema(&mut schema, &mut to_cast)?;
+        prepare_csv_schema(&mut schema, &mut to_cast, &parse_options)?;
 
         // Create a null value for every column
         let null_values = parse_options
@@ -312,7 +345,13 @@ impl<'a> CoreReader<'a> {
             starting_point_offset,
         )?;
 
-        cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+        cast_columns(
+            &mut df,
+            &self.to_cast,
+            false,
+            self.ignore_errors,
+            &self.parse_options,
+        )?;
         Ok(df)
     }
 
@@ -235,7 +235,13 @@ impl BatchedCsvReader<'_> {
                         self.starting_point_offset,
                     )?;
 
-                    cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+                    cast_columns(
+                        &mut df,
+                        &self.to_cast,
+                        false,
+                        self.ignore_errors,
+                        &self.parse_options,
+                    )?;
 
                     if let Some(rc) = &self.row_index {
                         unsafe { df.with_row_index_mut(rc.name.clone(), Some(rc.offset)) };
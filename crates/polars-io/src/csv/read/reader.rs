@@ -3,7 +3,7 @@ use std::path::PathBuf;
 
 use polars_core::prelude::*;
 
-use super::options::CsvReadOptions;
+use super::options::{CsvParseOptions, CsvReadOptions};
 use super::read_impl::CoreReader;
 use super::read_impl::batched::to_batched_owned;
 use super::{BatchedCsvReader, OwnedBatchedCsvReader};
@@ -131,6 +131,7 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             self.options.raise_if_empty,
+            self.options.archive_member_glob.as_deref(),
         )
     }
 
@@ -195,11 +196,13 @@ impl<R: MmapBytesReader> CsvReader<R> {
     }
 }
 
-/// Splits datatypes that cannot be natively read into a `fields_to_cast` for
-/// post-read casting.
+/// Splits datatypes that cannot be natively read, and the columns with a format in
+/// `parse_options.date_formats`, into a `fields_to_cast` for post-read casting.
+#[cfg_attr(not(feature = "polars-time"), allow(unused_variables))]
 pub fn prepare_csv_schema(
     schema: &mut SchemaRef,
     fields_to_cast: &mut Vec<Field>,
+    parse_options: &CsvParseOptions,
 ) -> PolarsResult<()> {
     // This branch we check if there are dtypes we cannot parse.
     // We only support a few dtypes in the parser and later cast to the required dtype.
@@ -210,6 +213,19 @@ pub fn prepare_csv_schema(
         .map(|mut fld| {
             use DataType::*;
 
+            #[cfg(feature = "polars-time")]
+            if fld.dtype().is_temporal()
+                && parse_options
+                    .date_formats
+                    .iter()
+                    .any(|(name, _)| name == fld.name())
+            {
+                fields_to_cast.push(fld.clone());
+                fld.coerce(String);
+                changed = true;
+                return PolarsResult::Ok(fld);
+            }
+
             let mut matched = true;
 
             let out = match fld.dtype() {
@@ -2,6 +2,8 @@ use std::borrow::Cow;
 
 use polars_core::prelude::*;
 #[cfg(feature = "polars-time")]
+use polars_time::chunkedarray::string::DateInference;
+#[cfg(feature = "polars-time")]
 use polars_time::chunkedarray::string::infer as date_infer;
 #[cfg(feature = "polars-time")]
 use polars_time::prelude::string::Pattern;
@@ -21,6 +23,7 @@ pub struct SchemaInferenceResult {
     bytes_read: usize,
     bytes_total: usize,
     n_threads: Option<usize>,
+    date_formats: Vec<(PlSmallStr, PlSmallStr)>,
 }
 
 impl SchemaInferenceResult {
@@ -42,7 +45,7 @@ impl SchemaInferenceResult {
 
         let bytes_total = reader_bytes.len();
 
-        let (inferred_schema, rows_read, bytes_read) = infer_file_schema(
+        let (inferred_schema, rows_read, bytes_read, date_formats) = infer_file_schema(
             reader_bytes,
             &parse_options,
             infer_schema_length,
@@ -60,6 +63,7 @@ impl SchemaInferenceResult {
             bytes_read,
             bytes_total,
             n_threads,
+            date_formats,
         };
 
         Ok(this)
@@ -83,6 +87,9 @@ impl CsvReadOptions {
     /// Note: This does not update the schema from the inference result.
     pub fn update_with_inference_result(&mut self, si_result: &SchemaInferenceResult) {
         self.n_threads = si_result.n_threads;
+        if !si_result.date_formats.is_empty() {
+            Arc::make_mut(&mut self.parse_options).date_formats = si_result.date_formats.clone();
+        }
     }
 }
 
@@ -195,7 +202,7 @@ fn infer_file_schema_inner(
     skip_rows_after_header: usize,
     recursion_count: u8,
     raise_if_empty: bool,
-) -> PolarsResult<(Schema, usize, usize)> {
+) -> PolarsResult<(Schema, usize, usize, Vec<(PlSmallStr, PlSmallStr)>)> {
     // keep track so that we can determine the amount of bytes read
     let start_ptr = reader_bytes.as_ptr() as usize;
 
@@ -302,7 +309,7 @@ fn infer_file_schema_inner(
             raise_if_empty,
         );
     } else if !raise_if_empty {
-        return Ok((Schema::default(), 0, 0));
+        return Ok((Schema::default(), 0, 0, vec![]));
     } else {
         polars_bail!(NoData: "empty CSV");
     };
@@ -322,9 +329,23 @@ fn infer_file_schema_inner(
         vec![PlHashSet::with_capacity(4); headers.len()];
     // keep track of columns with nulls
     let mut nulls: Vec<bool> = vec![false; headers.len()];
+    // keep the strings of every column to infer the formats of with `date_inference`
+    #[cfg(feature = "polars-time")]
+    let date_inference = parse_options
+        .date_inference
+        .as_ref()
+        .filter(|_| parse_options.try_parse_dates);
+    #[cfg(feature = "polars-time")]
+    let mut strings: Vec<Vec<String>> = vec![vec![]; headers.len()];
+    #[cfg(feature = "polars-time")]
+    let try_parse_dates = parse_options.try_parse_dates && date_inference.is_none();
+    #[cfg(not(feature = "polars-time"))]
+    let try_parse_dates = parse_options.try_parse_dates;
 
     let mut rows_count = 0;
     let mut fields = Vec::with_capacity(headers.len());
+    #[allow(unused_mut)]
+    let mut date_formats = vec![];
 
     // needed to prevent ownership going into the iterator loop
     let records_ref = &mut lines;
@@ -383,6 +404,8 @@ fn infer_file_schema_inner(
                     headers.push(column_name(i));
                     column_types.push(Default::default());
                     nulls.push(false);
+                    #[cfg(feature = "polars-time")]
+                    strings.push(vec![]);
                 } else {
                     break;
                 }
@@ -400,14 +423,14 @@ fn infer_file_schema_inner(
                 let dtype = match &parse_options.null_values {
                     None => Some(infer_field_schema(
                         &s,
-                        parse_options.try_parse_dates,
+                        try_parse_dates,
                         parse_options.decimal_comma,
                     )),
                     Some(NullValues::AllColumns(names)) => {
                         if !names.iter().any(|nv| nv == s.as_ref()) {
                             Some(infer_field_schema(
                                 &s,
-                                parse_options.try_parse_dates,
+                                try_parse_dates,
                                 parse_options.decimal_comma,
                             ))
                         } else {
@@ -418,7 +441,7 @@ fn infer_file_schema_inner(
                         if s.as_ref() != name.as_str() {
                             Some(infer_field_schema(
                                 &s,
-                                parse_options.try_parse_dates,
+                                try_parse_dates,
                                 parse_options.decimal_comma,
                             ))
                         } else {
@@ -435,7 +458,7 @@ fn infer_file_schema_inner(
                             if null_name.1.as_str() != s.as_ref() {
                                 Some(infer_field_schema(
                                     &s,
-                                    parse_options.try_parse_dates,
+                                    try_parse_dates,
                                     parse_options.decimal_comma,
                                 ))
                             } else {
@@ -444,13 +467,17 @@ fn infer_file_schema_inner(
                         } else {
                             Some(infer_field_schema(
                                 &s,
-                                parse_options.try_parse_dates,
+                                try_parse_dates,
                                 parse_options.decimal_comma,
                             ))
                         }
                     },
                 };
                 if let Some(dtype) = dtype {
+                    #[cfg(feature = "polars-time")]
+                    if date_inference.is_some() && dtype == DataType::String {
+                        strings[i].push(s.to_string());
+                    }
                     unsafe { column_types.get_unchecked_mut(i).insert(dtype) };
                 }
             }
@@ -479,6 +506,18 @@ fn infer_file_schema_inner(
 
         let possibilities = &column_types[i];
         let dtype = finish_infer_field_schema(possibilities);
+        #[cfg(feature = "polars-time")]
+        if let (Some(date_inference), DataType::String) = (date_inference, &dtype) {
+            let values: Vec<&str> = strings[i].iter().map(|s| s.as_str()).collect();
+            if let Some((format, dtype)) = date_inference.infer_format(&values) {
+                if polars_core::config::verbose() {
+                    eprintln!("inferred format '{format}' ({dtype}) for column '{field_name}'");
+                }
+                date_formats.push((field_name.clone(), format));
+                fields.push(Field::new(field_name.clone(), dtype));
+                continue;
+            }
+        }
         fields.push(Field::new(field_name.clone(), dtype));
     }
     // if there is a single line after the header without an eol
@@ -505,7 +544,12 @@ fn infer_file_schema_inner(
         );
     }
 
-    Ok((Schema::from_iter(fields), rows_count, end_ptr - start_ptr))
+    Ok((
+        Schema::from_iter(fields),
+        rows_count,
+        end_ptr - start_ptr,
+        date_formats,
+    ))
 }
 
 /// Infer the schema of a CSV file by reading through the first n rows of the file,
@@ -517,6 +561,7 @@ fn infer_file_schema_inner(
 ///     - inferred schema
 ///     - number of rows used for inference.
 ///     - bytes read
+///     - the formats that `date_inference` chose for the columns it inferred a temporal dtype of
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema(
     reader_bytes: &ReaderBytes,
@@ -528,7 +573,7 @@ pub fn infer_file_schema(
     skip_lines: usize,
     skip_rows_after_header: usize,
     raise_if_empty: bool,
-) -> PolarsResult<(Schema, usize, usize)> {
+) -> PolarsResult<(Schema, usize, usize, Vec<(PlSmallStr, PlSmallStr)>)> {
     if skip_lines > 0 {
         polars_ensure!(skip_rows == 0, InvalidOperation: "only one of 'skip_rows'/'skip_lines' may be set");
         let bytes = skip_lines_naive(reader_bytes, parse_options.eol_char, skip_lines);
@@ -0,0 +1,120 @@
+//! Building a [`DataFrame`] from self-describing documents, as decoded from MessagePack and BSON.
+//!
+//! Every top-level key becomes a column. The dtypes are inferred as the supertype of the
+//! values of each key, nested documents become `Struct`s and arrays become `List`s.
+use polars_core::prelude::*;
+
+pub(crate) enum Value {
+    Null,
+    Boolean(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    String(String),
+    Binary(Vec<u8>),
+    Datetime(i64, TimeUnit),
+    List(Vec<Value>),
+    Document(Vec<(PlSmallStr, Value)>),
+}
+
+impl Value {
+    fn into_any_value(self) -> PolarsResult<AnyValue<'static>> {
+        Ok(match self {
+            Value::Null => AnyValue::Null,
+            Value::Boolean(v) => AnyValue::Boolean(v),
+            Value::Int(v) => AnyValue::Int64(v),
+            Value::UInt(v) => AnyValue::UInt64(v),
+            Value::Float(v) => AnyValue::Float64(v),
+            Value::String(v) => AnyValue::StringOwned(v.into()),
+            Value::Binary(v) => AnyValue::BinaryOwned(v),
+            Value::Datetime(v, tu) => AnyValue::Datetime(v, tu, None),
+            Value::List(values) => {
+                let values = values
+                    .into_iter()
+                    .map(Value::into_any_value)
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                AnyValue::List(Series::from_any_values(PlSmallStr::EMPTY, &values, false)?)
+            },
+            Value::Document(entries) => {
+                let mut values = Vec::with_capacity(entries.len());
+                let mut fields = Vec::with_capacity(entries.len());
+                for (name, value) in entries {
+                    let value = value.into_any_value()?;
+                    fields.push(Field::new(name, value.dtype()));
+                    values.push(value);
+                }
+                AnyValue::StructOwned(Box::new((values, fields)))
+            },
+        })
+    }
+}
+
+/// Collects the top-level entries of documents into columns.
+pub(crate) struct DocumentBuilder {
+    columns: PlIndexMap<PlSmallStr, Vec<AnyValue<'static>>>,
+    projection: Option<Vec<PlSmallStr>>,
+    height: usize,
+}
+
+impl DocumentBuilder {
+    pub(crate) fn new(projection: Option<Vec<PlSmallStr>>) -> Self {
+        Self {
+            columns: PlIndexMap::new(),
+            projection,
+            height: 0,
+        }
+    }
+
+    pub(crate) fn height(&self) -> usize {
+        self.height
+    }
+
+    pub(crate) fn push(&mut self, document: Vec<(PlSmallStr, Value)>) -> PolarsResult<()> {
+        for (name, value) in document {
+            if let Some(projection) = &self.projection {
+                if !projection.contains(&name) {
+                    continue;
+                }
+            }
+            let height = self.height;
+            let column = self
+                .columns
+                .entry(name)
+                .or_insert_with(|| vec![AnyValue::Null; height]);
+            let value = value.into_any_value()?;
+            // The last value wins if a key occurs more than once in a document.
+            if column.len() > height {
+                column[height] = value;
+            } else {
+                column.push(value);
+            }
+        }
+        self.height += 1;
+        for column in self.columns.values_mut() {
+            column.resize(self.height, AnyValue::Null);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn finish(mut self, rechunk: bool) -> PolarsResult<DataFrame> {
+        let names = match self.projection.take() {
+            Some(projection) => projection,
+            None => self.columns.keys().cloned().collect(),
+        };
+        let columns = names
+            .into_iter()
+            .map(|name| {
+                let s = match self.columns.get(&name) {
+                    Some(values) => Series::from_any_values(name, values, false)?,
+                    None => Series::full_null(name, self.height, &DataType::Null),
+                };
+                Ok(Column::from(s))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut df = DataFrame::new_with_height(self.height, columns)?;
+        if rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
@@ -68,7 +68,7 @@ use std::io::Write;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 
-use arrow::array::LIST_VALUES_NAME;
+use arrow::array::{FixedSizeListArray, LIST_VALUES_NAME, ListArray, StructArray};
 use arrow::legacy::conversion::chunk_to_struct;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
@@ -119,6 +119,8 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    pretty: bool,
+    sort_keys: bool,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -126,6 +128,70 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Indent the values of every array and object on their own line. Only supported for
+    /// [`JsonFormat::Json`].
+    pub fn with_pretty(mut self, pretty: bool) -> Self {
+        self.pretty = pretty;
+        self
+    }
+
+    /// Order the keys of every object by name, including the ones of nested structs, instead of
+    /// by the order of the columns and fields.
+    pub fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+}
+
+/// Order the fields of the (nested) structs of `array` by name.
+fn sort_struct_fields(array: &dyn Array) -> ArrayRef {
+    let with_dtype = |field: &ArrowField, dtype: &ArrowDataType| ArrowField {
+        dtype: dtype.clone(),
+        ..field.clone()
+    };
+    match array.dtype() {
+        ArrowDataType::Struct(fields) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let mut fields = fields
+                .iter()
+                .zip(array.values())
+                .map(|(field, values)| {
+                    let values = sort_struct_fields(values.as_ref());
+                    (with_dtype(field, values.dtype()), values)
+                })
+                .collect::<Vec<_>>();
+            fields.sort_by(|(l, _), (r, _)| l.name.cmp(&r.name));
+            let (fields, values) = fields.into_iter().unzip();
+            StructArray::new(
+                ArrowDataType::Struct(fields),
+                array.len(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        ArrowDataType::LargeList(field) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = sort_struct_fields(array.values().as_ref());
+            let dtype = ArrowDataType::LargeList(Box::new(with_dtype(field, values.dtype())));
+            ListArray::new(
+                dtype,
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        },
+        ArrowDataType::FixedSizeList(field, size) => {
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = sort_struct_fields(array.values().as_ref());
+            let dtype =
+                ArrowDataType::FixedSizeList(Box::new(with_dtype(field, values.dtype())), *size);
+            FixedSizeListArray::new(dtype, array.len(), values, array.validity().cloned()).boxed()
+        },
+        _ => array.to_boxed(),
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -138,10 +204,16 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            pretty: false,
+            sort_keys: false,
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.pretty || matches!(self.json_format, JsonFormat::Json),
+            InvalidOperation: "pretty printing is not supported for JSON lines"
+        );
         df.align_chunks_par();
         let fields = df
             .iter()
@@ -151,9 +223,15 @@ where
                 Ok(s.field().to_arrow(CompatLevel::newest()))
             })
             .collect::<PolarsResult<Vec<_>>>()?;
-        let batches = df
-            .iter_chunks(CompatLevel::newest(), false)
-            .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
+        let sort_keys = self.sort_keys;
+        let batches = df.iter_chunks(CompatLevel::newest(), false).map(|chunk| {
+            let array = chunk_to_struct(chunk, fields.clone());
+            Ok(if sort_keys {
+                sort_struct_fields(&array)
+            } else {
+                Box::new(array) as ArrayRef
+            })
+        });
 
         match self.json_format {
             JsonFormat::JsonLines => {
@@ -164,7 +242,12 @@ where
             },
             JsonFormat::Json => {
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                if self.pretty {
+                    let mut writer = polars_json::json::write::PrettyWriter::new(&mut self.buffer);
+                    polars_json::json::write::write(&mut writer, serializer)?;
+                } else {
+                    polars_json::json::write::write(&mut self.buffer, serializer)?;
+                }
             },
         }
 
@@ -9,11 +9,17 @@ extern crate core;
 
 #[cfg(feature = "avro")]
 pub mod avro;
+#[cfg(feature = "bson")]
+pub mod bson;
 #[cfg(feature = "catalog")]
 pub mod catalog;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(any(feature = "bson", feature = "msgpack"))]
+mod document;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
@@ -21,6 +27,8 @@ pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
 pub mod mmap;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
@@ -33,6 +41,10 @@ pub mod path_utils;
 pub mod pl_async;
 pub mod predicates;
 pub mod prelude;
+#[cfg(feature = "protobuf")]
+pub mod protobuf;
+#[cfg(feature = "schema_registry")]
+pub mod schema_registry;
 mod shared;
 pub mod utils;
 
@@ -0,0 +1,359 @@
+//! Reading streams of [MessagePack] maps.
+//!
+//! [MessagePack]: https://github.com/msgpack/msgpack/blob/master/spec.md
+use std::io::Read;
+
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::document::{DocumentBuilder, Value};
+use crate::prelude::*;
+
+/// The extension type of MessagePack timestamps.
+const TIMESTAMP_EXT: i8 = -1;
+
+/// The maximum nesting depth of maps and arrays, which bounds the recursion of the decoder.
+const MAX_DEPTH: usize = 256;
+
+/// Read a stream of concatenated MessagePack maps into a [`DataFrame`].
+///
+/// Every key of the top-level maps becomes a column, with its dtype inferred as the supertype
+/// of its values. Nested maps are read as `Struct`s and arrays as `List`s. Timestamps are read
+/// as nanosecond `Datetime`s without a time zone, other extension types are not supported.
+///
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::msgpack::MsgPackReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("events.msgpack").expect("file not found");
+///
+///     MsgPackReader::new(file).finish()
+/// }
+/// ```
+#[must_use]
+pub struct MsgPackReader<R> {
+    reader: R,
+    rechunk: bool,
+    n_rows: Option<usize>,
+    columns: Option<Vec<PlSmallStr>>,
+}
+
+impl<R: Read> MsgPackReader<R> {
+    /// Stop reading when `n` maps are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<PlSmallStr>>) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<R: Read> SerReader<R> for MsgPackReader<R> {
+    fn new(reader: R) -> Self {
+        MsgPackReader {
+            reader,
+            rechunk: true,
+            n_rows: None,
+            columns: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let mut buf = vec![];
+        self.reader.read_to_end(&mut buf)?;
+
+        let mut builder = DocumentBuilder::new(self.columns);
+        let mut cursor = Cursor {
+            buf: &buf,
+            depth: 0,
+        };
+        while !cursor.buf.is_empty() && self.n_rows.is_none_or(|n| builder.height() < n) {
+            match cursor.value()? {
+                Value::Document(entries) => builder.push(entries)?,
+                _ => polars_bail!(
+                    ComputeError: "expected a map at the top level of the MessagePack stream"
+                ),
+            }
+        }
+        builder.finish(self.rechunk)
+    }
+}
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+    depth: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn bytes(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            len <= self.buf.len(),
+            ComputeError: "MessagePack data ended unexpectedly, expected {} more bytes", len
+        );
+        let (out, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(out)
+    }
+
+    fn array<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    fn u8(&mut self) -> PolarsResult<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> PolarsResult<usize> {
+        Ok(u16::from_be_bytes(self.array()?) as usize)
+    }
+
+    fn u32(&mut self) -> PolarsResult<usize> {
+        Ok(u32::from_be_bytes(self.array()?) as usize)
+    }
+
+    fn string(&mut self, len: usize) -> PolarsResult<String> {
+        String::from_utf8(self.bytes(len)?.to_vec())
+            .map_err(|_| polars_err!(ComputeError: "MessagePack data contains invalid utf-8"))
+    }
+
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> PolarsResult<T>) -> PolarsResult<T> {
+        polars_ensure!(
+            self.depth < MAX_DEPTH,
+            ComputeError: "MessagePack data exceeds the maximum nesting depth of {}", MAX_DEPTH
+        );
+        self.depth += 1;
+        let out = f(self);
+        self.depth -= 1;
+        out
+    }
+
+    fn list(&mut self, len: usize) -> PolarsResult<Value> {
+        self.nested(|c| {
+            // Every value takes at least one byte, so the length is only trusted up to the
+            // remaining input.
+            let mut values = Vec::with_capacity(len.min(c.buf.len()));
+            for _ in 0..len {
+                values.push(c.value()?);
+            }
+            Ok(Value::List(values))
+        })
+    }
+
+    fn map(&mut self, len: usize) -> PolarsResult<Value> {
+        self.nested(|c| c.map_entries(len))
+    }
+
+    fn map_entries(&mut self, len: usize) -> PolarsResult<Value> {
+        // Every entry takes at least two bytes.
+        let mut entries = Vec::with_capacity(len.min(self.buf.len() / 2));
+        for _ in 0..len {
+            let key = match self.value()? {
+                Value::String(key) => key.into(),
+                Value::Int(key) => format_pl_smallstr!("{}", key),
+                Value::UInt(key) => format_pl_smallstr!("{}", key),
+                _ => polars_bail!(
+                    ComputeError: "MessagePack map keys must be strings or integers"
+                ),
+            };
+            entries.push((key, self.value()?));
+        }
+        Ok(Value::Document(entries))
+    }
+
+    fn ext(&mut self, len: usize) -> PolarsResult<Value> {
+        let ext_type = self.u8()? as i8;
+        let data = self.bytes(len)?;
+        polars_ensure!(
+            ext_type == TIMESTAMP_EXT,
+            nyi = "reading MessagePack extension type {}",
+            ext_type
+        );
+        let (secs, nanos) = match data.len() {
+            4 => (u32::from_be_bytes(data.try_into().unwrap()) as i64, 0),
+            8 => {
+                let v = u64::from_be_bytes(data.try_into().unwrap());
+                ((v & 0x3_ffff_ffff) as i64, (v >> 34) as i64)
+            },
+            12 => (
+                i64::from_be_bytes(data[4..].try_into().unwrap()),
+                u32::from_be_bytes(data[..4].try_into().unwrap()) as i64,
+            ),
+            n => polars_bail!(ComputeError: "invalid MessagePack timestamp of {} bytes", n),
+        };
+        let ns = secs
+            .checked_mul(1_000_000_000)
+            .and_then(|ns| ns.checked_add(nanos))
+            .ok_or_else(|| {
+                polars_err!(ComputeError: "MessagePack timestamp is out of range for nanoseconds")
+            })?;
+        Ok(Value::Datetime(ns, TimeUnit::Nanoseconds))
+    }
+
+    fn value(&mut self) -> PolarsResult<Value> {
+        let marker = self.u8()?;
+        Ok(match marker {
+            0x00..=0x7f => Value::Int(marker as i64),
+            0x80..=0x8f => self.map((marker & 0x0f) as usize)?,
+            0x90..=0x9f => self.list((marker & 0x0f) as usize)?,
+            0xa0..=0xbf => Value::String(self.string((marker & 0x1f) as usize)?),
+            0xc0 => Value::Null,
+            0xc2 => Value::Boolean(false),
+            0xc3 => Value::Boolean(true),
+            0xc4 => {
+                let len = self.u8()? as usize;
+                Value::Binary(self.bytes(len)?.to_vec())
+            },
+            0xc5 => {
+                let len = self.u16()?;
+                Value::Binary(self.bytes(len)?.to_vec())
+            },
+            0xc6 => {
+                let len = self.u32()?;
+                Value::Binary(self.bytes(len)?.to_vec())
+            },
+            0xc7 => {
+                let len = self.u8()? as usize;
+                self.ext(len)?
+            },
+            0xc8 => {
+                let len = self.u16()?;
+                self.ext(len)?
+            },
+            0xc9 => {
+                let len = self.u32()?;
+                self.ext(len)?
+            },
+            0xca => Value::Float(f32::from_be_bytes(self.array()?) as f64),
+            0xcb => Value::Float(f64::from_be_bytes(self.array()?)),
+            0xcc => Value::Int(self.u8()? as i64),
+            0xcd => Value::Int(u16::from_be_bytes(self.array()?) as i64),
+            0xce => Value::Int(u32::from_be_bytes(self.array()?) as i64),
+            // Encoders write non-negative integers as unsigned, only use `UInt` if the value
+            // does not fit in an `Int`.
+            0xcf => {
+                let v = u64::from_be_bytes(self.array()?);
+                match i64::try_from(v) {
+                    Ok(v) => Value::Int(v),
+                    Err(_) => Value::UInt(v),
+                }
+            },
+            0xd0 => Value::Int(self.u8()? as i8 as i64),
+            0xd1 => Value::Int(i16::from_be_bytes(self.array()?) as i64),
+            0xd2 => Value::Int(i32::from_be_bytes(self.array()?) as i64),
+            0xd3 => Value::Int(i64::from_be_bytes(self.array()?)),
+            0xd4 => self.ext(1)?,
+            0xd5 => self.ext(2)?,
+            0xd6 => self.ext(4)?,
+            0xd7 => self.ext(8)?,
+            0xd8 => self.ext(16)?,
+            0xd9 => {
+                let len = self.u8()? as usize;
+                Value::String(self.string(len)?)
+            },
+            0xda => {
+                let len = self.u16()?;
+                Value::String(self.string(len)?)
+            },
+            0xdb => {
+                let len = self.u32()?;
+                Value::String(self.string(len)?)
+            },
+            0xdc => {
+                let len = self.u16()?;
+                self.list(len)?
+            },
+            0xdd => {
+                let len = self.u32()?;
+                self.list(len)?
+            },
+            0xde => {
+                let len = self.u16()?;
+                self.map(len)?
+            },
+            0xdf => {
+                let len = self.u32()?;
+                self.map(len)?
+            },
+            0xe0..=0xff => Value::Int(marker as i8 as i64),
+            0xc1 => polars_bail!(ComputeError: "invalid MessagePack marker 0xc1"),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_read_msgpack() -> PolarsResult<()> {
+        let mut buf = vec![];
+        // {"a": 1, "b": [-1, 0.5]}
+        buf.extend([0x82, 0xa1, b'a', 0x01, 0xa1, b'b', 0x92, 0xff, 0xcb]);
+        buf.extend(0.5f64.to_be_bytes());
+        // {"c": {"d": "x"}, "a": nil}
+        buf.extend([
+            0x82, 0xa1, b'c', 0x81, 0xa1, b'd', 0xa1, b'x', 0xa1, b'a', 0xc0,
+        ]);
+        // {"t": timestamp 32}
+        buf.extend([0x81, 0xa1, b't', 0xd6, 0xff, 0, 0, 0, 1]);
+
+        let df = MsgPackReader::new(std::io::Cursor::new(&buf)).finish()?;
+        assert_eq!(df.get_column_names(), &["a", "b", "c", "t"]);
+        assert_eq!(
+            df.dtypes(),
+            vec![
+                DataType::Int64,
+                DataType::List(Box::new(DataType::Float64)),
+                DataType::Struct(vec![Field::new("d".into(), DataType::String)]),
+                DataType::Datetime(TimeUnit::Nanoseconds, None),
+            ]
+        );
+        assert_eq!(df.column("a")?.i64()?.get(0), Some(1));
+        assert_eq!(df.column("a")?.null_count(), 2);
+
+        let df = MsgPackReader::new(std::io::Cursor::new(&buf))
+            .with_columns(Some(vec!["b".into()]))
+            .with_n_rows(Some(2))
+            .finish()?;
+        assert_eq!(df.shape(), (2, 1));
+
+        assert!(
+            MsgPackReader::new(std::io::Cursor::new([0x01]))
+                .finish()
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_msgpack_malformed() {
+        let read = |buf: Vec<u8>| MsgPackReader::new(std::io::Cursor::new(buf)).finish();
+
+        // A map that claims u32::MAX entries.
+        assert!(read(vec![0xdf, 0xff, 0xff, 0xff, 0xff]).is_err());
+        // An array that claims u32::MAX values.
+        assert!(read(vec![0x81, 0xa1, b'a', 0xdd, 0xff, 0xff, 0xff, 0xff]).is_err());
+        // Deeply nested arrays.
+        let mut buf = vec![0x81, 0xa1, b'a'];
+        buf.extend(std::iter::repeat_n(0x91, 100_000));
+        buf.push(0xc0);
+        assert!(read(buf).is_err());
+        // A timestamp 96 that does not fit in nanoseconds.
+        let mut buf = vec![0x81, 0xa1, b't', 0xc7, 12, 0xff, 0, 0, 0, 0];
+        buf.extend(i64::MAX.to_be_bytes());
+        assert!(read(buf).is_err());
+    }
+}
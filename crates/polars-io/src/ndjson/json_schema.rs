@@ -0,0 +1,513 @@
+//! Validation of NDJSON rows against a [JSON Schema](https://json-schema.org), so that rows that
+//! break a data contract are caught while the file is read.
+//!
+//! The validation keywords of draft 2020-12 for single values are supported, together with
+//! `properties`, `required`, `additionalProperties`, `items`, `allOf`, `anyOf`, `oneOf` and `not`.
+//! References (`$ref`) and the other applicators are not supported and give an error when the
+//! schema is compiled. Annotations such as `title` and `format` are ignored.
+use std::borrow::Cow;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use regex::Regex;
+use simd_json::{BorrowedValue as Value, StaticNode};
+
+use super::core::json_lines;
+
+const ANNOTATIONS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$comment",
+    "$defs",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "format",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+];
+
+const TYPE_NAMES: [&str; 7] = [
+    "null", "boolean", "integer", "number", "string", "array", "object",
+];
+
+#[derive(Debug, Clone, PartialEq)]
+enum Literal {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl Literal {
+    fn from_value(value: &Value) -> PolarsResult<Self> {
+        Ok(match value {
+            Value::Static(StaticNode::Null) => Self::Null,
+            Value::Static(StaticNode::Bool(b)) => Self::Bool(*b),
+            Value::String(s) => Self::String(s.to_string()),
+            v => match as_f64(v) {
+                Some(n) => Self::Number(n),
+                None => polars_bail!(
+                    ComputeError: "JSON Schema 'enum' and 'const' only support scalar values, got {}", v
+                ),
+            },
+        })
+    }
+
+    fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Self::Null, Value::Static(StaticNode::Null)) => true,
+            (Self::Bool(a), Value::Static(StaticNode::Bool(b))) => a == b,
+            (Self::String(a), Value::String(b)) => a == b,
+            (Self::Number(a), v) => as_f64(v) == Some(*a),
+            _ => false,
+        }
+    }
+}
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Static(StaticNode::I64(i)) => Some(*i as f64),
+        Value::Static(StaticNode::U64(u)) => Some(*u as f64),
+        Value::Static(StaticNode::F64(f)) => Some(*f),
+        _ => None,
+    }
+}
+
+/// The index of the JSON type of `value` in [`TYPE_NAMES`], where integers are numbers without a
+/// fractional part.
+fn type_of(value: &Value) -> usize {
+    match value {
+        Value::Static(StaticNode::Null) => 0,
+        Value::Static(StaticNode::Bool(_)) => 1,
+        Value::Static(StaticNode::I64(_) | StaticNode::U64(_)) => 2,
+        Value::Static(StaticNode::F64(f)) if f.fract() == 0.0 => 2,
+        Value::Static(StaticNode::F64(_)) => 3,
+        Value::String(_) => 4,
+        Value::Array(_) => 5,
+        Value::Object(_) => 6,
+    }
+}
+
+/// A compiled (sub)schema.
+#[derive(Debug, Default)]
+struct Node {
+    /// The `false` schema, which no value matches.
+    reject_all: bool,
+    types: Option<Vec<usize>>,
+    enum_values: Option<Vec<Literal>>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+    exclusive_maximum: Option<f64>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    items: Option<Box<Node>>,
+    properties: Vec<(String, Node)>,
+    required: Vec<String>,
+    additional_properties: Option<Box<Node>>,
+    all_of: Vec<Node>,
+    any_of: Vec<Node>,
+    one_of: Vec<Node>,
+    not: Option<Box<Node>>,
+}
+
+type ValidationResult = Result<(), (String, String)>;
+
+fn fail(msg: String) -> ValidationResult {
+    Err((String::new(), msg))
+}
+
+fn at(key: &str, result: ValidationResult) -> ValidationResult {
+    result.map_err(|(pointer, msg)| (format!("/{key}{pointer}"), msg))
+}
+
+impl Node {
+    fn compile(schema: &Value) -> PolarsResult<Self> {
+        let object = match schema {
+            Value::Static(StaticNode::Bool(accept)) => {
+                return Ok(Self {
+                    reject_all: !accept,
+                    ..Default::default()
+                });
+            },
+            Value::Object(object) => object,
+            v => {
+                polars_bail!(ComputeError: "a JSON Schema must be an object or a boolean, got {}", v)
+            },
+        };
+
+        let number = |key: &str, v: &Value| {
+            as_f64(v).ok_or_else(
+                || polars_err!(ComputeError: "JSON Schema '{}' must be a number, got {}", key, v),
+            )
+        };
+        let count = |key: &str, v: &Value| match v {
+            Value::Static(StaticNode::I64(i)) if *i >= 0 => Ok(*i as usize),
+            Value::Static(StaticNode::U64(u)) => Ok(*u as usize),
+            v => polars_bail!(
+                ComputeError: "JSON Schema '{}' must be a non-negative integer, got {}", key, v
+            ),
+        };
+        let subschemas = |key: &str, v: &Value| -> PolarsResult<Vec<Self>> {
+            match v {
+                Value::Array(schemas) if !schemas.is_empty() => {
+                    schemas.iter().map(Self::compile).collect()
+                },
+                v => polars_bail!(
+                    ComputeError: "JSON Schema '{}' must be a non-empty array, got {}", key, v
+                ),
+            }
+        };
+
+        let mut node = Self::default();
+        for (key, v) in object.iter() {
+            let key = key.as_ref();
+            match key {
+                "type" => {
+                    let names: Vec<&Value> = match v {
+                        Value::Array(names) => names.iter().collect(),
+                        v => vec![v],
+                    };
+                    let types = names
+                        .into_iter()
+                        .map(|name| {
+                            let tp = match name {
+                                Value::String(name) => {
+                                    TYPE_NAMES.iter().position(|t| *t == name.as_ref())
+                                },
+                                _ => None,
+                            };
+                            tp.ok_or_else(
+                                || polars_err!(ComputeError: "invalid JSON Schema 'type': {}", name),
+                            )
+                        })
+                        .collect::<PolarsResult<_>>()?;
+                    node.types = Some(types);
+                },
+                "enum" => match v {
+                    Value::Array(values) => {
+                        node.enum_values =
+                            Some(values.iter().map(Literal::from_value).collect::<PolarsResult<_>>()?)
+                    },
+                    v => polars_bail!(ComputeError: "JSON Schema 'enum' must be an array, got {}", v),
+                },
+                "const" => node.enum_values = Some(vec![Literal::from_value(v)?]),
+                "minimum" => node.minimum = Some(number(key, v)?),
+                "maximum" => node.maximum = Some(number(key, v)?),
+                "exclusiveMinimum" => node.exclusive_minimum = Some(number(key, v)?),
+                "exclusiveMaximum" => node.exclusive_maximum = Some(number(key, v)?),
+                "minLength" => node.min_length = Some(count(key, v)?),
+                "maxLength" => node.max_length = Some(count(key, v)?),
+                "pattern" => match v {
+                    Value::String(pattern) => {
+                        node.pattern = Some(Regex::new(pattern).map_err(|e| {
+                            polars_err!(ComputeError: "invalid JSON Schema 'pattern': {}", e)
+                        })?)
+                    },
+                    v => polars_bail!(ComputeError: "JSON Schema 'pattern' must be a string, got {}", v),
+                },
+                "minItems" => node.min_items = Some(count(key, v)?),
+                "maxItems" => node.max_items = Some(count(key, v)?),
+                "items" => node.items = Some(Box::new(Self::compile(v)?)),
+                "properties" => match v {
+                    Value::Object(properties) => {
+                        node.properties = properties
+                            .iter()
+                            .map(|(name, schema)| Ok((name.to_string(), Self::compile(schema)?)))
+                            .collect::<PolarsResult<_>>()?
+                    },
+                    v => polars_bail!(
+                        ComputeError: "JSON Schema 'properties' must be an object, got {}", v
+                    ),
+                },
+                "required" => {
+                    node.required = match v {
+                        Value::Array(names) => names
+                            .iter()
+                            .map(|name| match name {
+                                Value::String(name) => Ok(name.to_string()),
+                                v => polars_bail!(
+                                    ComputeError: "JSON Schema 'required' must contain strings, got {}", v
+                                ),
+                            })
+                            .collect::<PolarsResult<_>>()?,
+                        v => polars_bail!(
+                            ComputeError: "JSON Schema 'required' must be an array, got {}", v
+                        ),
+                    }
+                },
+                "additionalProperties" => {
+                    node.additional_properties = Some(Box::new(Self::compile(v)?))
+                },
+                "allOf" => node.all_of = subschemas(key, v)?,
+                "anyOf" => node.any_of = subschemas(key, v)?,
+                "oneOf" => node.one_of = subschemas(key, v)?,
+                "not" => node.not = Some(Box::new(Self::compile(v)?)),
+                key if ANNOTATIONS.contains(&key) => {},
+                key => polars_bail!(ComputeError: "unsupported JSON Schema keyword '{}'", key),
+            }
+        }
+        Ok(node)
+    }
+
+    fn validate(&self, value: &Value) -> ValidationResult {
+        if self.reject_all {
+            return fail("no value is allowed".to_string());
+        }
+
+        let tp = type_of(value);
+        if let Some(types) = &self.types {
+            // An integer is also a number.
+            if !types.iter().any(|t| *t == tp || (*t == 3 && tp == 2)) {
+                let expected: Vec<_> = types.iter().map(|t| TYPE_NAMES[*t]).collect();
+                return fail(format!(
+                    "expected type {}, got {}",
+                    expected.join(" or "),
+                    TYPE_NAMES[tp]
+                ));
+            }
+        }
+        if let Some(values) = &self.enum_values {
+            if !values.iter().any(|l| l.matches(value)) {
+                return fail(format!("{value} is not one of the allowed values"));
+            }
+        }
+
+        if let Some(n) = as_f64(value) {
+            let bounds = [
+                ("minimum", self.minimum.filter(|b| n < *b)),
+                ("maximum", self.maximum.filter(|b| n > *b)),
+                (
+                    "exclusive minimum",
+                    self.exclusive_minimum.filter(|b| n <= *b),
+                ),
+                (
+                    "exclusive maximum",
+                    self.exclusive_maximum.filter(|b| n >= *b),
+                ),
+            ];
+            if let Some((name, Some(bound))) = bounds.into_iter().find(|(_, b)| b.is_some()) {
+                return fail(format!("{value} is outside the {name} of {bound}"));
+            }
+        }
+
+        match value {
+            Value::String(s) => {
+                let len = s.chars().count();
+                if self.min_length.is_some_and(|min| len < min)
+                    || self.max_length.is_some_and(|max| len > max)
+                {
+                    return fail(format!("the length {len} of {value} is out of bounds"));
+                }
+                if let Some(pattern) = self.pattern.as_ref().filter(|p| !p.is_match(s)) {
+                    return fail(format!("{value} does not match '{pattern}'"));
+                }
+            },
+            Value::Array(values) => {
+                let len = values.len();
+                if self.min_items.is_some_and(|min| len < min)
+                    || self.max_items.is_some_and(|max| len > max)
+                {
+                    return fail(format!("the number of items {len} is out of bounds"));
+                }
+                if let Some(items) = &self.items {
+                    for (i, v) in values.iter().enumerate() {
+                        at(&i.to_string(), items.validate(v))?;
+                    }
+                }
+            },
+            Value::Object(object) => {
+                for name in &self.required {
+                    if object.get(name.as_str()).is_none() {
+                        return fail(format!("the required property '{name}' is missing"));
+                    }
+                }
+                for (name, v) in object.iter() {
+                    let name: &str = name.as_ref();
+                    match self.properties.iter().find(|(n, _)| n == name) {
+                        Some((_, schema)) => at(name, schema.validate(v))?,
+                        None => {
+                            if let Some(schema) = &self.additional_properties {
+                                at(name, schema.validate(v)).map_err(|(p, msg)| {
+                                    (p, format!("additional property: {msg}"))
+                                })?;
+                            }
+                        },
+                    }
+                }
+            },
+            _ => {},
+        }
+
+        for schema in &self.all_of {
+            schema.validate(value)?;
+        }
+        if !self.any_of.is_empty() && !self.any_of.iter().any(|s| s.validate(value).is_ok()) {
+            return fail(format!("{value} matches none of the 'anyOf' schemas"));
+        }
+        if !self.one_of.is_empty() {
+            let n = self
+                .one_of
+                .iter()
+                .filter(|s| s.validate(value).is_ok())
+                .count();
+            if n != 1 {
+                return fail(format!(
+                    "{value} matches {n} of the 'oneOf' schemas instead of 1"
+                ));
+            }
+        }
+        if self.not.as_ref().is_some_and(|s| s.validate(value).is_ok()) {
+            return fail(format!("{value} matches the 'not' schema"));
+        }
+        Ok(())
+    }
+}
+
+/// A JSON Schema compiled to validate NDJSON rows with.
+#[derive(Debug)]
+pub struct JsonSchemaValidator {
+    root: Node,
+}
+
+impl JsonSchemaValidator {
+    /// Compile the JSON Schema document `json_schema`.
+    pub fn try_new(json_schema: &str) -> PolarsResult<Self> {
+        let mut bytes = json_schema.as_bytes().to_vec();
+        let schema = simd_json::to_borrowed_value(&mut bytes)
+            .map_err(|e| polars_err!(ComputeError: "invalid JSON Schema: {}", e))?;
+        Ok(Self {
+            root: Node::compile(&schema)?,
+        })
+    }
+
+    /// Validate a single line, returning why it does not match the schema.
+    fn validate_line(&self, line: &[u8], scratch: &mut Vec<u8>) -> Result<(), String> {
+        scratch.clear();
+        scratch.extend_from_slice(line);
+        let value =
+            simd_json::to_borrowed_value(scratch).map_err(|e| format!("invalid JSON: {e}"))?;
+        self.root.validate(&value).map_err(|(pointer, msg)| {
+            if pointer.is_empty() {
+                msg
+            } else {
+                format!("at '{pointer}': {msg}")
+            }
+        })
+    }
+
+    /// Split the lines of `chunk` into the lines that match the schema and those that don't.
+    ///
+    /// Without `keep_rejected`, the first line that does not match raises an error.
+    pub fn split_lines<'a>(
+        &self,
+        chunk: &'a [u8],
+        keep_rejected: bool,
+    ) -> PolarsResult<(Cow<'a, [u8]>, Vec<u8>)> {
+        let mut scratch = vec![];
+        let mut valid: Option<Vec<u8>> = None;
+        let mut rejected = vec![];
+
+        for (i, line) in json_lines(chunk).enumerate() {
+            match self.validate_line(line, &mut scratch) {
+                Ok(()) => {
+                    if let Some(valid) = valid.as_mut() {
+                        valid.extend_from_slice(line);
+                        valid.push(b'\n');
+                    }
+                },
+                Err(msg) => {
+                    polars_ensure!(
+                        keep_rejected,
+                        ComputeError: "row does not match the JSON Schema, {}\n\nRow: {}",
+                        msg, String::from_utf8_lossy(line)
+                    );
+                    // Copy the lines that matched so far.
+                    if valid.is_none() {
+                        valid = Some(
+                            json_lines(chunk)
+                                .take(i)
+                                .flat_map(|l| l.iter().copied().chain([b'\n']))
+                                .collect(),
+                        );
+                    }
+                    rejected.extend_from_slice(line);
+                    rejected.push(b'\n');
+                },
+            }
+        }
+
+        Ok((valid.map_or(Cow::Borrowed(chunk), Cow::Owned), rejected))
+    }
+}
+
+/// Append the rejected `lines` to the file at `path`. Readers that run in parallel can append to
+/// the same file.
+pub fn append_rejected_lines(path: &Path, lines: &[u8]) -> PolarsResult<()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+
+    if lines.is_empty() {
+        return Ok(());
+    }
+    let _guard = LOCK.lock().unwrap();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(lines)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_lines() {
+        let validator = JsonSchemaValidator::try_new(
+            r#"{
+                "type": "object",
+                "properties": {
+                    "a": {"type": ["integer", "null"], "minimum": 0},
+                    "b": {"enum": ["x", "y"]}
+                },
+                "required": ["a"],
+                "additionalProperties": false
+            }"#,
+        )
+        .unwrap();
+
+        let chunk = b"{\"a\": 1, \"b\": \"x\"}\n{\"a\": -1}\n{\"b\": \"y\"}\n{\"a\": null}\n{\"a\": 2, \"c\": 0}\nnope\n";
+        let (valid, rejected) = validator.split_lines(chunk, true).unwrap();
+        assert_eq!(&*valid, b"{\"a\": 1, \"b\": \"x\"}\n{\"a\": null}\n");
+        assert_eq!(
+            rejected,
+            b"{\"a\": -1}\n{\"b\": \"y\"}\n{\"a\": 2, \"c\": 0}\nnope\n"
+        );
+
+        let err = validator.split_lines(chunk, false).unwrap_err().to_string();
+        assert!(
+            err.contains("at '/a': -1 is outside the minimum of 0"),
+            "{err}"
+        );
+
+        // No copy if every line is valid.
+        let (valid, _) = validator.split_lines(b"{\"a\": 1}\n", false).unwrap();
+        assert!(matches!(valid, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_unsupported_keyword() {
+        let err = JsonSchemaValidator::try_new(r##"{"$ref": "#/$defs/a"}"##).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("unsupported JSON Schema keyword '$ref'")
+        );
+    }
+}
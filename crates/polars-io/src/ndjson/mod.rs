@@ -8,6 +8,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 
 pub(crate) mod buffer;
 pub mod core;
+pub mod json_schema;
 
 pub fn infer_schema<R: std::io::BufRead>(
     reader: &mut R,
@@ -6,3 +6,251 @@ pub use polars_parquet::parquet::metadata::FileMetadata;
 pub use polars_parquet::read::statistics::{Statistics as ParquetStatistics, deserialize};
 
 pub type FileMetadataRef = Arc<FileMetadata>;
+
+#[cfg(feature = "dtype-struct")]
+pub use row_groups::row_group_metadata_df;
+
+#[cfg(feature = "dtype-struct")]
+mod row_groups {
+    use polars_core::chunked_array::builder::get_list_builder;
+    use polars_core::prelude::*;
+    use polars_parquet::parquet::compression::Compression;
+    use polars_parquet::parquet::encoding::Encoding;
+    use polars_parquet::read::statistics::deserialize_all;
+    use polars_parquet::read::{ColumnChunkMetadata, PhysicalType, infer_schema};
+
+    use super::FileMetadata;
+
+    /// Returns the metadata of the row groups of a file as a DataFrame with a row per row group
+    /// and the columns:
+    /// * `row_group`, `num_rows`, `total_byte_size`, `compressed_size`: the index, the number of
+    ///   rows and the uncompressed and compressed size in bytes of the row group.
+    /// * `columns`: a list with a struct per column chunk, with its `path`, `physical_type`,
+    ///   `compression`, `encodings`, `num_values`, `compressed_size`, `uncompressed_size` and
+    ///   whether it has a dictionary page, a bloom filter and statistics.
+    /// * `min`, `max`, `null_count`: structs with a field per column with the statistics of the
+    ///   row group. These are typed like the columns, and null if the statistics are missing or
+    ///   the column is nested.
+    pub fn row_group_metadata_df(metadata: &FileMetadata) -> PolarsResult<DataFrame> {
+        let row_groups = metadata.row_groups.as_slice();
+        let height = row_groups.len();
+
+        let mut columns = vec![
+            Column::new(
+                PlSmallStr::from_static("row_group"),
+                (0..height as IdxSize).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                PlSmallStr::from_static("num_rows"),
+                row_groups
+                    .iter()
+                    .map(|rg| rg.num_rows() as u64)
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                PlSmallStr::from_static("total_byte_size"),
+                row_groups
+                    .iter()
+                    .map(|rg| rg.total_byte_size() as u64)
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                PlSmallStr::from_static("compressed_size"),
+                row_groups
+                    .iter()
+                    .map(|rg| rg.compressed_size() as u64)
+                    .collect::<Vec<_>>(),
+            ),
+        ];
+
+        let chunks_dtype = column_chunks_series(&[])?.dtype().clone();
+        let mut chunks = get_list_builder(
+            &chunks_dtype,
+            metadata.schema_descr.columns().len() * height,
+            height,
+            PlSmallStr::from_static("columns"),
+        );
+        for rg in row_groups {
+            chunks.append_series(&column_chunks_series(rg.parquet_columns())?)?;
+        }
+        columns.push(chunks.finish().into_column());
+
+        let schema = infer_schema(metadata)?;
+        let mut min_fields = Vec::with_capacity(schema.len());
+        let mut max_fields = Vec::with_capacity(schema.len());
+        let mut nc_fields = Vec::with_capacity(schema.len());
+
+        for field in schema.iter_values() {
+            // Statistics are only read for columns that consist of a single leaf.
+            let stats = match row_groups
+                .first()
+                .and_then(|rg| rg.columns_idxs_under_root_iter(&field.name))
+            {
+                Some(&[idx]) => deserialize_all(field, row_groups, idx)?,
+                _ => None,
+            };
+
+            let (min, max, nc) = match stats {
+                None => {
+                    let dtype = DataType::from_arrow_field(field);
+                    (
+                        Series::full_null(field.name.clone(), height, &dtype),
+                        Series::full_null(field.name.clone(), height, &dtype),
+                        Series::full_null(field.name.clone(), height, &IDX_DTYPE),
+                    )
+                },
+                Some(stats) => {
+                    let md = field.metadata.as_deref();
+                    // SAFETY: The statistics are deserialized to the dtype of the field.
+                    unsafe {
+                        (
+                            Series::_try_from_arrow_unchecked_with_md(
+                                field.name.clone(),
+                                vec![stats.min_value],
+                                field.dtype(),
+                                md,
+                            )?,
+                            Series::_try_from_arrow_unchecked_with_md(
+                                field.name.clone(),
+                                vec![stats.max_value],
+                                field.dtype(),
+                                md,
+                            )?,
+                            Series::from_arrow(field.name.clone(), stats.null_count.boxed())?,
+                        )
+                    }
+                },
+            };
+
+            min_fields.push(min);
+            max_fields.push(max);
+            nc_fields.push(nc);
+        }
+
+        for (name, fields) in [
+            ("min", min_fields),
+            ("max", max_fields),
+            ("null_count", nc_fields),
+        ] {
+            let ca =
+                StructChunked::from_series(PlSmallStr::from_static(name), height, fields.iter())?;
+            columns.push(ca.into_column());
+        }
+
+        DataFrame::new_with_height(height, columns)
+    }
+
+    fn column_chunks_series(chunks: &[ColumnChunkMetadata]) -> PolarsResult<Series> {
+        let mut encodings = ListStringChunkedBuilder::new(
+            PlSmallStr::from_static("encodings"),
+            chunks.len(),
+            chunks.len() * 2,
+        );
+        for chunk in chunks {
+            let names = chunk
+                .column_encoding()
+                .iter()
+                .map(|&e| Encoding::try_from(e).map(encoding_name))
+                .collect::<Result<Vec<_>, _>>()?;
+            encodings.append_values_iter(names.into_iter());
+        }
+
+        let fields = [
+            StringChunked::from_iter_values(
+                PlSmallStr::from_static("path"),
+                chunks
+                    .iter()
+                    .map(|c| c.descriptor().path_in_schema.join(".")),
+            )
+            .into_series(),
+            StringChunked::from_iter_values(
+                PlSmallStr::from_static("physical_type"),
+                chunks.iter().map(|c| physical_type_name(c.physical_type())),
+            )
+            .into_series(),
+            StringChunked::from_iter_values(
+                PlSmallStr::from_static("compression"),
+                chunks.iter().map(|c| compression_name(c.compression())),
+            )
+            .into_series(),
+            encodings.finish().into_series(),
+            Int64Chunked::from_vec(
+                PlSmallStr::from_static("num_values"),
+                chunks.iter().map(|c| c.num_values()).collect(),
+            )
+            .into_series(),
+            Int64Chunked::from_vec(
+                PlSmallStr::from_static("compressed_size"),
+                chunks.iter().map(|c| c.compressed_size()).collect(),
+            )
+            .into_series(),
+            Int64Chunked::from_vec(
+                PlSmallStr::from_static("uncompressed_size"),
+                chunks.iter().map(|c| c.uncompressed_size()).collect(),
+            )
+            .into_series(),
+            BooleanChunked::from_iter_values(
+                PlSmallStr::from_static("has_dictionary_page"),
+                chunks.iter().map(|c| c.dictionary_page_offset().is_some()),
+            )
+            .into_series(),
+            BooleanChunked::from_iter_values(
+                PlSmallStr::from_static("has_bloom_filter"),
+                chunks
+                    .iter()
+                    .map(|c| c.metadata().bloom_filter_offset.is_some()),
+            )
+            .into_series(),
+            BooleanChunked::from_iter_values(
+                PlSmallStr::from_static("has_statistics"),
+                chunks.iter().map(|c| c.metadata().statistics.is_some()),
+            )
+            .into_series(),
+        ];
+
+        Ok(
+            StructChunked::from_series(PlSmallStr::EMPTY, chunks.len(), fields.iter())?
+                .into_series(),
+        )
+    }
+
+    fn physical_type_name(physical_type: PhysicalType) -> &'static str {
+        match physical_type {
+            PhysicalType::Boolean => "BOOLEAN",
+            PhysicalType::Int32 => "INT32",
+            PhysicalType::Int64 => "INT64",
+            PhysicalType::Int96 => "INT96",
+            PhysicalType::Float => "FLOAT",
+            PhysicalType::Double => "DOUBLE",
+            PhysicalType::ByteArray => "BYTE_ARRAY",
+            PhysicalType::FixedLenByteArray(_) => "FIXED_LEN_BYTE_ARRAY",
+        }
+    }
+
+    fn compression_name(compression: Compression) -> &'static str {
+        match compression {
+            Compression::Uncompressed => "UNCOMPRESSED",
+            Compression::Snappy => "SNAPPY",
+            Compression::Gzip => "GZIP",
+            Compression::Lzo => "LZO",
+            Compression::Brotli => "BROTLI",
+            Compression::Lz4 => "LZ4",
+            Compression::Zstd => "ZSTD",
+            Compression::Lz4Raw => "LZ4_RAW",
+        }
+    }
+
+    fn encoding_name(encoding: Encoding) -> &'static str {
+        match encoding {
+            Encoding::Plain => "PLAIN",
+            Encoding::PlainDictionary => "PLAIN_DICTIONARY",
+            Encoding::Rle => "RLE",
+            Encoding::BitPacked => "BIT_PACKED",
+            Encoding::DeltaBinaryPacked => "DELTA_BINARY_PACKED",
+            Encoding::DeltaLengthByteArray => "DELTA_LENGTH_BYTE_ARRAY",
+            Encoding::DeltaByteArray => "DELTA_BYTE_ARRAY",
+            Encoding::RleDictionary => "RLE_DICTIONARY",
+            Encoding::ByteStreamSplit => "BYTE_STREAM_SPLIT",
+        }
+    }
+}
@@ -7,6 +7,10 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct ParquetOptions {
     pub schema: Option<SchemaRef>,
+    /// Data types to read some of the columns as. The columns are cast while they are decoded,
+    /// so the data type of the file is never materialized for the full file.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub schema_overrides: Option<SchemaRef>,
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
@@ -16,6 +20,7 @@ impl Default for ParquetOptions {
     fn default() -> Self {
         Self {
             schema: None,
+            schema_overrides: None,
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
@@ -31,6 +31,7 @@ fn assert_dtypes(dtype: &ArrowDataType) {
         D::Utf8 | D::Binary | D::LargeUtf8 | D::LargeBinary => unreachable!(),
 
         // These should be cast to Float32
+        #[cfg(not(feature = "dtype-f16"))]
         D::Float16 => unreachable!(),
 
         // This should have been converted to a LargeList
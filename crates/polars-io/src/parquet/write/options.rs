@@ -19,6 +19,13 @@ pub struct ParquetWriteOptions {
     pub statistics: StatisticsOptions,
     /// If `None` will be all written to a single row group.
     pub row_group_size: Option<usize>,
+    /// Target size of a row group in (estimated, uncompressed) bytes. If both this and
+    /// `row_group_size` are set, the smallest resulting row group is used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub row_group_bytes: Option<usize>,
+    /// Start a new row group whenever the value of a key column changes.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub row_group_split: Option<RowGroupSplit>,
     /// if `None` will be 1024^2 bytes
     pub data_page_size: Option<usize>,
     /// Custom file-level key value metadata
@@ -28,6 +35,19 @@ pub struct ParquetWriteOptions {
     pub field_overwrites: Vec<ParquetFieldOverwrites>,
 }
 
+/// Split row groups on the boundaries of a (sorted) key column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct RowGroupSplit {
+    pub column: PlSmallStr,
+    /// Granularity of the key in the physical unit of the column, e.g. `86_400_000_000` to
+    /// split a microsecond `Datetime` column per day. Two rows belong to the same row group if
+    /// their key floor-divided by `every` is equal. If `None`, every distinct value starts a
+    /// new row group.
+    pub every: Option<i64>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
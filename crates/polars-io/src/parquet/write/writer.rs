@@ -1,9 +1,11 @@
+use std::borrow::Cow;
 use std::io::Write;
 use std::sync::Mutex;
 
 use arrow::datatypes::PhysicalType;
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked_optional;
 use polars_parquet::write::{
     ChildWriteOptions, ColumnWriteOptions, CompressionOptions, Encoding, FieldWriteOptions,
     FileWriter, KeyValue, ListLikeFieldWriteOptions, StatisticsOptions, StructFieldWriteOptions,
@@ -12,7 +14,9 @@ use polars_parquet::write::{
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
-use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
+use super::{
+    KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions, RowGroupSplit,
+};
 use crate::prelude::ChildFieldOverwrites;
 use crate::shared::schema_to_arrow_checked;
 
@@ -25,6 +29,8 @@ impl ParquetWriteOptions {
             .with_compression(self.compression)
             .with_statistics(self.statistics)
             .with_row_group_size(self.row_group_size)
+            .with_row_group_bytes(self.row_group_bytes)
+            .with_row_group_split(self.row_group_split.clone())
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
     }
@@ -40,6 +46,10 @@ pub struct ParquetWriter<W> {
     statistics: StatisticsOptions,
     /// if `None` will be 512^2 rows
     row_group_size: Option<usize>,
+    /// Target size of a row group in estimated bytes
+    row_group_bytes: Option<usize>,
+    /// Start a new row group when the key changes
+    row_group_split: Option<RowGroupSplit>,
     /// if `None` will be 1024^2 bytes
     data_page_size: Option<usize>,
     /// Serialize columns in parallel
@@ -65,6 +75,8 @@ where
             compression: ParquetCompression::default().into(),
             statistics: StatisticsOptions::default(),
             row_group_size: None,
+            row_group_bytes: None,
+            row_group_split: None,
             data_page_size: None,
             parallel: true,
             field_overwrites: Vec::new(),
@@ -95,6 +107,21 @@ where
         self
     }
 
+    /// Set the target row group size in bytes, based on the estimated in-memory size of the
+    /// [`DataFrame`]. If a row group size in rows is also set, the smallest of both is used.
+    pub fn with_row_group_bytes(mut self, bytes: Option<usize>) -> Self {
+        self.row_group_bytes = bytes;
+        self
+    }
+
+    /// Start a new row group whenever the key column changes value, e.g. on every new day
+    /// of a sorted timestamp column. This keeps the row group statistics tight, which improves
+    /// pruning when reading the file.
+    pub fn with_row_group_split(mut self, split: Option<RowGroupSplit>) -> Self {
+        self.row_group_split = split;
+        self
+    }
+
     /// Sets the maximum bytes size of a data page. If `None` will be 1024^2 bytes.
     pub fn with_data_page_size(mut self, limit: Option<usize>) -> Self {
         self.data_page_size = limit;
@@ -150,16 +177,80 @@ where
         }
     }
 
+    fn materialize_row_group_size(&self, df: &DataFrame) -> usize {
+        let Some(bytes) = self.row_group_bytes.filter(|_| df.height() > 0) else {
+            return self.row_group_size.unwrap_or(512 * 512);
+        };
+        let row_bytes = df.estimated_size().div_ceil(df.height()).max(1);
+        let rows = (bytes / row_bytes).max(1);
+        self.row_group_size.map_or(rows, |size| size.min(rows))
+    }
+
     /// Write the given DataFrame in the writer `W`.
     /// Returns the total size of the file.
     pub fn finish(self, df: &mut DataFrame) -> PolarsResult<u64> {
-        let chunked_df = chunk_df_for_writing(df, self.row_group_size.unwrap_or(512 * 512))?;
+        let row_group_size = self.materialize_row_group_size(df);
+        let chunked_df = match &self.row_group_split {
+            None => chunk_df_for_writing(df, row_group_size)?,
+            Some(split) => Cow::Owned(split_df_on_key(df, split, row_group_size)?),
+        };
         let mut batched = self.batched(chunked_df.schema())?;
         batched.write_batch(&chunked_df)?;
         batched.finish()
     }
 }
 
+/// Slice `df` into single chunk row groups of at most `row_group_size` rows that never cross a
+/// boundary of the split key.
+fn split_df_on_key(
+    df: &DataFrame,
+    split: &RowGroupSplit,
+    row_group_size: usize,
+) -> PolarsResult<DataFrame> {
+    let key = df.column(&split.column)?.as_materialized_series();
+    let key = match split.every {
+        None => key.to_physical_repr().into_owned(),
+        Some(every) => {
+            polars_ensure!(
+                every > 0,
+                InvalidOperation: "row group split granularity must be positive, got {}", every
+            );
+            let physical = key.to_physical_repr();
+            polars_ensure!(
+                physical.dtype().is_integer(),
+                InvalidOperation: "row group split granularity requires an integer or temporal \
+                key column, got {}", key.dtype()
+            );
+            let physical = physical.cast(&DataType::Int64)?;
+            physical
+                .i64()?
+                .apply_values(|v| v.div_euclid(every))
+                .into_series()
+        },
+    };
+
+    let changed = key.not_equal_missing(&key.shift(1))?;
+    let boundaries = changed
+        .iter()
+        .enumerate()
+        .skip(1)
+        .filter_map(|(i, changed)| changed.unwrap_or(false).then_some(i))
+        .chain(std::iter::once(df.height()));
+
+    let mut row_groups = Vec::new();
+    let mut offset = 0;
+    for end in boundaries {
+        while offset < end {
+            let len = (end - offset).min(row_group_size);
+            let mut row_group = df.slice(offset as i64, len);
+            row_group.as_single_chunk_par();
+            row_groups.push(row_group);
+            offset += len;
+        }
+    }
+    Ok(accumulate_dataframes_vertical_unchecked_optional(row_groups).unwrap_or_else(|| df.clone()))
+}
+
 fn convert_metadata(md: &Option<Vec<MetadataKeyValue>>) -> Vec<KeyValue> {
     md.as_ref()
         .map(|metadata| {
@@ -476,6 +476,58 @@ pub struct ScanIOPredicate {
 }
 
 impl ScanIOPredicate {
+    /// Evaluate the hive predicate on the hive partitions, which have a row per file, to the mask
+    /// of the files that can be skipped.
+    pub fn skip_files_from_hive(&self, hive_df: &DataFrame) -> PolarsResult<Option<Bitmap>> {
+        let Some(predicate) = &self.hive_predicate else {
+            return Ok(None);
+        };
+        let mask = predicate
+            .evaluate_io(hive_df)?
+            .bool()?
+            .rechunk()
+            .into_owned()
+            .downcast_into_iter()
+            .next()
+            .unwrap()
+            .values()
+            .clone();
+
+        // TODO: Optimize to avoid doing this
+        Ok(Some(!&mask))
+    }
+
+    /// Evaluate the skip batch predicate on statistics with a row per file, to the mask of the
+    /// files that can be skipped. Columns without statistics are treated as unknown.
+    pub fn skip_files_from_statistics(&self, stats: &DataFrame) -> PolarsResult<Option<Bitmap>> {
+        let Some(sbp) = &self.skip_batch_predicate else {
+            return Ok(None);
+        };
+
+        let height = stats.height();
+        let mut columns = Vec::with_capacity(1 + self.live_columns.len() * 3);
+
+        columns.push(stats.column("len")?.clone());
+
+        for col in self.live_columns.iter() {
+            let Some(dtype) = sbp.schema().get(col) else {
+                continue;
+            };
+
+            for (suffix, dtype) in [("min", dtype), ("max", dtype), ("nc", &IDX_DTYPE)] {
+                let name = format_pl_smallstr!("{col}_{suffix}");
+                let column = match stats.column(&name) {
+                    Ok(c) => c.strict_cast(dtype)?,
+                    Err(_) => Column::full_null(PlSmallStr::EMPTY, height, dtype),
+                };
+                columns.push(column.with_name(name));
+            }
+        }
+
+        let df = DataFrame::new_with_height(height, columns)?;
+        sbp.evaluate_with_stat_df(&df).map(Some)
+    }
+
     pub fn set_external_constant_columns(&mut self, constant_columns: Vec<(PlSmallStr, Scalar)>) {
         if constant_columns.is_empty() {
             return;
@@ -0,0 +1,335 @@
+//! The parts of a `FileDescriptorSet` that are needed to decode messages.
+//!
+//! The descriptors are protobuf messages themselves, defined in
+//! [`google/protobuf/descriptor.proto`].
+//!
+//! [`google/protobuf/descriptor.proto`]: https://github.com/protocolbuffers/protobuf/blob/main/src/google/protobuf/descriptor.proto
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use super::wire::{Cursor, WireType};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum FieldType {
+    Double,
+    Float,
+    Int64,
+    UInt64,
+    Int32,
+    Fixed64,
+    Fixed32,
+    Bool,
+    String,
+    Message,
+    Bytes,
+    UInt32,
+    Enum,
+    SFixed32,
+    SFixed64,
+    SInt32,
+    SInt64,
+}
+
+impl FieldType {
+    fn from_number(number: u64) -> PolarsResult<Self> {
+        use FieldType::*;
+        Ok(match number {
+            1 => Double,
+            2 => Float,
+            3 => Int64,
+            4 => UInt64,
+            5 => Int32,
+            6 => Fixed64,
+            7 => Fixed32,
+            8 => Bool,
+            9 => String,
+            10 => polars_bail!(nyi = "reading protobuf groups"),
+            11 => Message,
+            12 => Bytes,
+            13 => UInt32,
+            14 => Enum,
+            15 => SFixed32,
+            16 => SFixed64,
+            17 => SInt32,
+            18 => SInt64,
+            n => polars_bail!(ComputeError: "invalid protobuf field type {}", n),
+        })
+    }
+
+    /// Whether values of this type may be packed into a single length-delimited field.
+    pub(super) fn is_packable(&self) -> bool {
+        !matches!(self, Self::String | Self::Bytes | Self::Message)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct FieldDescriptor {
+    pub(super) name: PlSmallStr,
+    pub(super) number: u32,
+    pub(super) field_type: FieldType,
+    /// The fully qualified name of the message or enum type, without the leading dot.
+    pub(super) type_name: PlSmallStr,
+    pub(super) repeated: bool,
+    /// Whether an absent value is null rather than the default value of the type.
+    pub(super) has_presence: bool,
+    in_oneof: bool,
+    proto3_optional: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct MessageDescriptor {
+    pub(super) fields: Vec<FieldDescriptor>,
+    /// Maps field numbers to indices into `fields`.
+    pub(super) field_index: PlHashMap<u32, usize>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub(super) struct EnumDescriptor {
+    pub(super) default: PlSmallStr,
+    pub(super) values: PlHashMap<i32, PlSmallStr>,
+}
+
+/// The message and enum types of a `FileDescriptorSet`, by their fully qualified name.
+#[derive(Clone, Debug, Default)]
+pub struct DescriptorPool {
+    pub(super) messages: PlHashMap<PlSmallStr, MessageDescriptor>,
+    pub(super) enums: PlHashMap<PlSmallStr, EnumDescriptor>,
+}
+
+fn string(cursor: &mut Cursor) -> PolarsResult<PlSmallStr> {
+    let bytes = cursor.len_delimited()?;
+    Ok(std::str::from_utf8(bytes)
+        .map_err(|_| polars_err!(ComputeError: "protobuf descriptor contains invalid utf-8"))?
+        .into())
+}
+
+fn qualify(scope: &str, name: &str) -> PlSmallStr {
+    if scope.is_empty() {
+        name.into()
+    } else {
+        format_pl_smallstr!("{}.{}", scope, name)
+    }
+}
+
+impl DescriptorPool {
+    /// Parse a serialized `FileDescriptorSet`, as written by `protoc --descriptor_set_out`.
+    pub fn try_new(descriptor_set: &[u8]) -> PolarsResult<Self> {
+        let mut pool = Self::default();
+        let mut cursor = Cursor::new(descriptor_set);
+        while !cursor.is_empty() {
+            match cursor.tag()? {
+                (1, WireType::Len) => pool.parse_file(cursor.len_delimited()?)?,
+                (_, wire_type) => cursor.skip(wire_type)?,
+            }
+        }
+        Ok(pool)
+    }
+
+    fn parse_file(&mut self, buf: &[u8]) -> PolarsResult<()> {
+        let mut package = PlSmallStr::EMPTY;
+        let mut messages = vec![];
+        let mut enums = vec![];
+        let mut proto3 = false;
+
+        let mut cursor = Cursor::new(buf);
+        while !cursor.is_empty() {
+            match cursor.tag()? {
+                (2, WireType::Len) => package = string(&mut cursor)?,
+                (4, WireType::Len) => messages.push(cursor.len_delimited()?),
+                (5, WireType::Len) => enums.push(cursor.len_delimited()?),
+                (12, WireType::Len) => proto3 = string(&mut cursor)? == "proto3",
+                (_, wire_type) => cursor.skip(wire_type)?,
+            }
+        }
+
+        for buf in enums {
+            self.parse_enum(buf, &package)?;
+        }
+        for buf in messages {
+            self.parse_message(buf, &package, proto3)?;
+        }
+        Ok(())
+    }
+
+    fn parse_enum(&mut self, buf: &[u8], scope: &str) -> PolarsResult<()> {
+        let mut name = PlSmallStr::EMPTY;
+        let mut descriptor = EnumDescriptor::default();
+
+        let mut cursor = Cursor::new(buf);
+        while !cursor.is_empty() {
+            match cursor.tag()? {
+                (1, WireType::Len) => name = string(&mut cursor)?,
+                (2, WireType::Len) => {
+                    let mut value_name = PlSmallStr::EMPTY;
+                    let mut number = 0;
+                    let mut value = Cursor::new(cursor.len_delimited()?);
+                    while !value.is_empty() {
+                        match value.tag()? {
+                            (1, WireType::Len) => value_name = string(&mut value)?,
+                            (2, WireType::Varint) => number = value.varint()? as i32,
+                            (_, wire_type) => value.skip(wire_type)?,
+                        }
+                    }
+                    // The first value is the default value of the enum.
+                    if descriptor.values.is_empty() {
+                        descriptor.default = value_name.clone();
+                    }
+                    descriptor.values.entry(number).or_insert(value_name);
+                },
+                (_, wire_type) => cursor.skip(wire_type)?,
+            }
+        }
+
+        self.enums.insert(qualify(scope, &name), descriptor);
+        Ok(())
+    }
+
+    fn parse_message(&mut self, buf: &[u8], scope: &str, proto3: bool) -> PolarsResult<()> {
+        let mut name = PlSmallStr::EMPTY;
+        let mut fields = vec![];
+        let mut nested = vec![];
+        let mut enums = vec![];
+
+        let mut cursor = Cursor::new(buf);
+        while !cursor.is_empty() {
+            match cursor.tag()? {
+                (1, WireType::Len) => name = string(&mut cursor)?,
+                (2, WireType::Len) => fields.push(parse_field(cursor.len_delimited()?)?),
+                (3, WireType::Len) => nested.push(cursor.len_delimited()?),
+                (4, WireType::Len) => enums.push(cursor.len_delimited()?),
+                (_, wire_type) => cursor.skip(wire_type)?,
+            }
+        }
+
+        let full_name = qualify(scope, &name);
+        for buf in enums {
+            self.parse_enum(buf, &full_name)?;
+        }
+        for buf in nested {
+            self.parse_message(buf, &full_name, proto3)?;
+        }
+
+        for field in fields.iter_mut() {
+            field.has_presence = !field.repeated
+                && (!proto3
+                    || field.field_type == FieldType::Message
+                    || field.in_oneof
+                    || field.proto3_optional);
+        }
+        let field_index = fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| (field.number, i))
+            .collect();
+        self.messages.insert(
+            full_name,
+            MessageDescriptor {
+                fields,
+                field_index,
+            },
+        );
+        Ok(())
+    }
+
+    /// Get the message type with the given fully qualified name. A leading dot is allowed.
+    pub(super) fn message(&self, name: &str) -> PolarsResult<&MessageDescriptor> {
+        let name = name.strip_prefix('.').unwrap_or(name);
+        self.messages.get(name).ok_or_else(
+            || polars_err!(ComputeError: "protobuf message type '{}' not found in the descriptor set", name),
+        )
+    }
+
+    pub(super) fn enum_type(&self, name: &str) -> PolarsResult<&EnumDescriptor> {
+        self.enums.get(name).ok_or_else(
+            || polars_err!(ComputeError: "protobuf enum type '{}' not found in the descriptor set", name),
+        )
+    }
+
+    /// The Polars dtype of a field of a message.
+    pub(super) fn field_dtype(
+        &self,
+        field: &FieldDescriptor,
+        visiting: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<DataType> {
+        let dtype = match field.field_type {
+            FieldType::Double => DataType::Float64,
+            FieldType::Float => DataType::Float32,
+            FieldType::Int64 | FieldType::SFixed64 | FieldType::SInt64 => DataType::Int64,
+            FieldType::UInt64 | FieldType::Fixed64 => DataType::UInt64,
+            FieldType::Int32 | FieldType::SFixed32 | FieldType::SInt32 => DataType::Int32,
+            FieldType::UInt32 | FieldType::Fixed32 => DataType::UInt32,
+            FieldType::Bool => DataType::Boolean,
+            FieldType::String | FieldType::Enum => DataType::String,
+            FieldType::Bytes => DataType::Binary,
+            FieldType::Message => {
+                DataType::Struct(self.message_fields(&field.type_name, visiting)?)
+            },
+        };
+        Ok(if field.repeated {
+            DataType::List(Box::new(dtype))
+        } else {
+            dtype
+        })
+    }
+
+    /// The Polars fields of the given message type.
+    pub(super) fn message_fields(
+        &self,
+        name: &PlSmallStr,
+        visiting: &mut Vec<PlSmallStr>,
+    ) -> PolarsResult<Vec<Field>> {
+        polars_ensure!(
+            !visiting.contains(name),
+            nyi = "reading recursive protobuf message type '{}'",
+            name
+        );
+        let message = self.message(name)?;
+        visiting.push(name.clone());
+        let fields = message
+            .fields
+            .iter()
+            .map(|field| {
+                Ok(Field::new(
+                    field.name.clone(),
+                    self.field_dtype(field, visiting)?,
+                ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        visiting.pop();
+        Ok(fields)
+    }
+}
+
+fn parse_field(buf: &[u8]) -> PolarsResult<FieldDescriptor> {
+    let mut field = FieldDescriptor {
+        name: PlSmallStr::EMPTY,
+        number: 0,
+        field_type: FieldType::Int32,
+        type_name: PlSmallStr::EMPTY,
+        repeated: false,
+        has_presence: false,
+        in_oneof: false,
+        proto3_optional: false,
+    };
+
+    let mut cursor = Cursor::new(buf);
+    while !cursor.is_empty() {
+        match cursor.tag()? {
+            (1, WireType::Len) => field.name = string(&mut cursor)?,
+            (3, WireType::Varint) => field.number = cursor.varint()? as u32,
+            (4, WireType::Varint) => field.repeated = cursor.varint()? == 3,
+            (5, WireType::Varint) => field.field_type = FieldType::from_number(cursor.varint()?)?,
+            (6, WireType::Len) => {
+                let type_name = string(&mut cursor)?;
+                field.type_name = type_name.strip_prefix('.').unwrap_or(&type_name).into();
+            },
+            (9, WireType::Varint) => {
+                cursor.varint()?;
+                field.in_oneof = true;
+            },
+            (17, WireType::Varint) => field.proto3_optional = cursor.varint()? != 0,
+            (_, wire_type) => cursor.skip(wire_type)?,
+        }
+    }
+    Ok(field)
+}
@@ -0,0 +1,8 @@
+//! Reading length-delimited [protobuf] messages, decoded with the types of a `FileDescriptorSet`.
+//!
+//! [protobuf]: https://protobuf.dev
+mod descriptor;
+mod read;
+mod wire;
+
+pub use read::*;
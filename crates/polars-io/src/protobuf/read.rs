@@ -0,0 +1,483 @@
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+use super::descriptor::{DescriptorPool, FieldDescriptor, FieldType, MessageDescriptor};
+use super::wire::{Cursor, WireType, zigzag32, zigzag64};
+use crate::prelude::*;
+
+/// Read length-delimited protobuf messages into a [`DataFrame`].
+///
+/// The input is a sequence of messages of a single type, each prefixed with its length as a
+/// varint, as written by `writeDelimitedTo` in the protobuf libraries. The message type is
+/// looked up in a serialized `FileDescriptorSet`.
+///
+/// Every field of the message becomes a column. Embedded messages are read as `Struct`s,
+/// repeated fields as `List`s and map fields as a `List` of `Struct`s with a `key` and a
+/// `value` field. Enums are read as the `String` names of their values.
+///
+/// A missing field is null if the field tracks presence and the default value of its type
+/// otherwise, following the rules of the `syntax` of the file it was defined in.
+///
+/// # Example
+/// ```no_run
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::protobuf::ProtobufReader;
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let descriptor_set = std::fs::read("telemetry.desc").expect("file not found");
+///     let file = File::open("telemetry.pb").expect("file not found");
+///
+///     ProtobufReader::new(file)
+///         .with_descriptor_set(descriptor_set)
+///         .with_message_type("telemetry.Event")
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct ProtobufReader<R> {
+    reader: R,
+    rechunk: bool,
+    descriptor_set: Vec<u8>,
+    message_type: PlSmallStr,
+    n_rows: Option<usize>,
+    columns: Option<Vec<PlSmallStr>>,
+}
+
+impl<R: Read> ProtobufReader<R> {
+    /// Set the serialized `FileDescriptorSet` that defines the message type, as written by
+    /// `protoc --include_imports --descriptor_set_out`.
+    pub fn with_descriptor_set(mut self, descriptor_set: Vec<u8>) -> Self {
+        self.descriptor_set = descriptor_set;
+        self
+    }
+
+    /// Set the fully qualified name of the message type, e.g. `my.package.Message`.
+    pub fn with_message_type(mut self, message_type: impl Into<PlSmallStr>) -> Self {
+        self.message_type = message_type.into();
+        self
+    }
+
+    /// Stop reading when `n` messages are read.
+    pub fn with_n_rows(mut self, num_rows: Option<usize>) -> Self {
+        self.n_rows = num_rows;
+        self
+    }
+
+    /// Columns to select/ project
+    pub fn with_columns(mut self, columns: Option<Vec<PlSmallStr>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Get the schema of the messages.
+    pub fn schema(&self) -> PolarsResult<Schema> {
+        let pool = DescriptorPool::try_new(&self.descriptor_set)?;
+        let fields = pool.message_fields(&self.message_type, &mut vec![])?;
+        Ok(Schema::from_iter(fields))
+    }
+}
+
+impl<R: Read> SerReader<R> for ProtobufReader<R> {
+    fn new(reader: R) -> Self {
+        ProtobufReader {
+            reader,
+            rechunk: true,
+            descriptor_set: vec![],
+            message_type: PlSmallStr::EMPTY,
+            n_rows: None,
+            columns: None,
+        }
+    }
+
+    fn set_rechunk(mut self, rechunk: bool) -> Self {
+        self.rechunk = rechunk;
+        self
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let pool = DescriptorPool::try_new(&self.descriptor_set)?;
+        let message = pool.message(&self.message_type)?;
+        let schema = Schema::from_iter(pool.message_fields(&self.message_type, &mut vec![])?);
+
+        let projection = match &self.columns {
+            Some(columns) => columns
+                .iter()
+                .map(|name| {
+                    schema
+                        .index_of(name)
+                        .ok_or_else(|| polars_err!(col_not_found = name))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?,
+            None => (0..message.fields.len()).collect(),
+        };
+
+        let mut buf = vec![];
+        self.reader.read_to_end(&mut buf)?;
+
+        let decoder = Decoder::try_new(&pool, &self.message_type)?;
+        let mut columns = vec![vec![]; message.fields.len()];
+        let mut cursor = Cursor::new(&buf);
+        let mut n_rows = 0;
+        while !cursor.is_empty() && self.n_rows.is_none_or(|n| n_rows < n) {
+            let values = decoder.decode_message(&self.message_type, cursor.len_delimited()?)?;
+            for (column, value) in columns.iter_mut().zip(values) {
+                column.push(value);
+            }
+            n_rows += 1;
+        }
+
+        let columns = projection
+            .into_iter()
+            .map(|i| {
+                let (name, dtype) = schema.get_at_index(i).unwrap();
+                Series::from_any_values_and_dtype(name.clone(), &columns[i], dtype, true)
+                    .map(Column::from)
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut df = DataFrame::new_with_height(n_rows, columns)?;
+        if self.rechunk {
+            df.as_single_chunk_par();
+        }
+        Ok(df)
+    }
+}
+
+struct Decoder<'a> {
+    pool: &'a DescriptorPool,
+    /// The Polars fields of the message types reachable from the root message type.
+    fields: PlHashMap<PlSmallStr, Vec<Field>>,
+}
+
+impl<'a> Decoder<'a> {
+    fn try_new(pool: &'a DescriptorPool, message_type: &str) -> PolarsResult<Self> {
+        let mut decoder = Self {
+            pool,
+            fields: PlHashMap::new(),
+        };
+        decoder.add_message_type(pool.message(message_type)?, message_type)?;
+        Ok(decoder)
+    }
+
+    fn add_message_type(&mut self, message: &MessageDescriptor, name: &str) -> PolarsResult<()> {
+        let name = PlSmallStr::from_str(name.strip_prefix('.').unwrap_or(name));
+        if self.fields.contains_key(&name) {
+            return Ok(());
+        }
+        let fields = self.pool.message_fields(&name, &mut vec![])?;
+        self.fields.insert(name, fields);
+        for field in &message.fields {
+            if field.field_type == FieldType::Message {
+                self.add_message_type(self.pool.message(&field.type_name)?, &field.type_name)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Decode a message into one value per field of its type.
+    fn decode_message(&self, name: &str, buf: &[u8]) -> PolarsResult<Vec<AnyValue<'static>>> {
+        let name = name.strip_prefix('.').unwrap_or(name);
+        let message = self.pool.message(name)?;
+        let fields = &self.fields[name];
+        let mut singular: Vec<Option<AnyValue<'static>>> = vec![None; message.fields.len()];
+        let mut repeated: Vec<Vec<AnyValue<'static>>> = vec![vec![]; message.fields.len()];
+
+        let mut cursor = Cursor::new(buf);
+        while !cursor.is_empty() {
+            let (number, wire_type) = cursor.tag()?;
+            let Some(&i) = message.field_index.get(&number) else {
+                cursor.skip(wire_type)?;
+                continue;
+            };
+            let field = &message.fields[i];
+
+            if field.repeated {
+                if wire_type == WireType::Len && field.field_type.is_packable() {
+                    let mut packed = Cursor::new(cursor.len_delimited()?);
+                    while !packed.is_empty() {
+                        let wire_type = value_wire_type(field.field_type);
+                        repeated[i].push(self.decode_value(field, wire_type, &mut packed)?);
+                    }
+                } else {
+                    repeated[i].push(self.decode_value(field, wire_type, &mut cursor)?);
+                }
+            } else {
+                // The last value wins if a singular field occurs more than once.
+                singular[i] = Some(self.decode_value(field, wire_type, &mut cursor)?);
+            }
+        }
+
+        message
+            .fields
+            .iter()
+            .zip(fields)
+            .zip(singular.into_iter().zip(repeated))
+            .map(|((field, pl_field), (value, values))| {
+                if field.repeated {
+                    let DataType::List(inner) = pl_field.dtype() else {
+                        unreachable!()
+                    };
+                    let s =
+                        Series::from_any_values_and_dtype(PlSmallStr::EMPTY, &values, inner, true)?;
+                    Ok(AnyValue::List(s))
+                } else {
+                    value.map_or_else(|| self.default_value(field), Ok)
+                }
+            })
+            .collect()
+    }
+
+    fn default_value(&self, field: &FieldDescriptor) -> PolarsResult<AnyValue<'static>> {
+        if field.has_presence {
+            return Ok(AnyValue::Null);
+        }
+        Ok(match field.field_type {
+            FieldType::Double => AnyValue::Float64(0.0),
+            FieldType::Float => AnyValue::Float32(0.0),
+            FieldType::Int64 | FieldType::SFixed64 | FieldType::SInt64 => AnyValue::Int64(0),
+            FieldType::UInt64 | FieldType::Fixed64 => AnyValue::UInt64(0),
+            FieldType::Int32 | FieldType::SFixed32 | FieldType::SInt32 => AnyValue::Int32(0),
+            FieldType::UInt32 | FieldType::Fixed32 => AnyValue::UInt32(0),
+            FieldType::Bool => AnyValue::Boolean(false),
+            FieldType::String => AnyValue::StringOwned(PlSmallStr::EMPTY),
+            FieldType::Bytes => AnyValue::BinaryOwned(vec![]),
+            FieldType::Enum => {
+                AnyValue::StringOwned(self.pool.enum_type(&field.type_name)?.default.clone())
+            },
+            FieldType::Message => AnyValue::Null,
+        })
+    }
+
+    fn decode_value(
+        &self,
+        field: &FieldDescriptor,
+        wire_type: WireType,
+        cursor: &mut Cursor,
+    ) -> PolarsResult<AnyValue<'static>> {
+        let expected = value_wire_type(field.field_type);
+        polars_ensure!(
+            wire_type == expected,
+            ComputeError: "protobuf field '{}' has wire type {:?}, expected {:?}",
+            field.name, wire_type, expected
+        );
+
+        Ok(match field.field_type {
+            FieldType::Double => AnyValue::Float64(f64::from_bits(cursor.fixed64()?)),
+            FieldType::Float => AnyValue::Float32(f32::from_bits(cursor.fixed32()?)),
+            FieldType::Int64 => AnyValue::Int64(cursor.varint()? as i64),
+            FieldType::UInt64 => AnyValue::UInt64(cursor.varint()?),
+            FieldType::Int32 => AnyValue::Int32(cursor.varint()? as i32),
+            FieldType::Fixed64 => AnyValue::UInt64(cursor.fixed64()?),
+            FieldType::Fixed32 => AnyValue::UInt32(cursor.fixed32()?),
+            FieldType::Bool => AnyValue::Boolean(cursor.varint()? != 0),
+            FieldType::String => {
+                let bytes = cursor.len_delimited()?;
+                let s = std::str::from_utf8(bytes).map_err(|_| {
+                    polars_err!(ComputeError: "protobuf field '{}' contains invalid utf-8", field.name)
+                })?;
+                AnyValue::StringOwned(s.into())
+            },
+            FieldType::Bytes => AnyValue::BinaryOwned(cursor.len_delimited()?.to_vec()),
+            FieldType::UInt32 => AnyValue::UInt32(cursor.varint()? as u32),
+            FieldType::Enum => {
+                let number = cursor.varint()? as i32;
+                let enum_type = self.pool.enum_type(&field.type_name)?;
+                // Unknown values are kept as their number.
+                let name = match enum_type.values.get(&number) {
+                    Some(name) => name.clone(),
+                    None => number.to_string().into(),
+                };
+                AnyValue::StringOwned(name)
+            },
+            FieldType::SFixed32 => AnyValue::Int32(cursor.fixed32()? as i32),
+            FieldType::SFixed64 => AnyValue::Int64(cursor.fixed64()? as i64),
+            FieldType::SInt32 => AnyValue::Int32(zigzag32(cursor.varint()?)),
+            FieldType::SInt64 => AnyValue::Int64(zigzag64(cursor.varint()?)),
+            FieldType::Message => {
+                let values = self.decode_message(&field.type_name, cursor.len_delimited()?)?;
+                let fields = self.fields[field.type_name.as_str()].clone();
+                AnyValue::StructOwned(Box::new((values, fields)))
+            },
+        })
+    }
+}
+
+/// The wire type of a single value of the given type.
+fn value_wire_type(field_type: FieldType) -> WireType {
+    match field_type {
+        FieldType::Double | FieldType::Fixed64 | FieldType::SFixed64 => WireType::Fixed64,
+        FieldType::Float | FieldType::Fixed32 | FieldType::SFixed32 => WireType::Fixed32,
+        FieldType::String | FieldType::Bytes | FieldType::Message => WireType::Len,
+        _ => WireType::Varint,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn varint(mut v: u64, out: &mut Vec<u8>) {
+        while v >= 0x80 {
+            out.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        out.push(v as u8);
+    }
+
+    fn tag(number: u32, wire_type: u8, out: &mut Vec<u8>) {
+        varint(((number as u64) << 3) | wire_type as u64, out);
+    }
+
+    fn len_field(number: u32, bytes: &[u8], out: &mut Vec<u8>) {
+        tag(number, 2, out);
+        varint(bytes.len() as u64, out);
+        out.extend_from_slice(bytes);
+    }
+
+    fn varint_field(number: u32, v: u64, out: &mut Vec<u8>) {
+        tag(number, 0, out);
+        varint(v, out);
+    }
+
+    fn field_descriptor(name: &str, number: u32, label: u64, ty: u64, type_name: &str) -> Vec<u8> {
+        let mut out = vec![];
+        len_field(1, name.as_bytes(), &mut out);
+        varint_field(3, number as u64, &mut out);
+        varint_field(4, label, &mut out);
+        varint_field(5, ty, &mut out);
+        if !type_name.is_empty() {
+            len_field(6, type_name.as_bytes(), &mut out);
+        }
+        out
+    }
+
+    /// ```proto
+    /// syntax = "proto3";
+    /// package test;
+    /// message Point { sint32 x = 1; sint32 y = 2; }
+    /// enum Kind { UNKNOWN = 0; GPS = 1; }
+    /// message Event {
+    ///   string name = 1;
+    ///   repeated int64 values = 2;
+    ///   Point point = 3;
+    ///   Kind kind = 4;
+    /// }
+    /// ```
+    fn descriptor_set() -> Vec<u8> {
+        let mut point = vec![];
+        len_field(1, b"Point", &mut point);
+        len_field(2, &field_descriptor("x", 1, 1, 17, ""), &mut point);
+        len_field(2, &field_descriptor("y", 2, 1, 17, ""), &mut point);
+
+        let mut kind = vec![];
+        len_field(1, b"Kind", &mut kind);
+        for (name, number) in [("UNKNOWN", 0), ("GPS", 1)] {
+            let mut value = vec![];
+            len_field(1, name.as_bytes(), &mut value);
+            varint_field(2, number, &mut value);
+            len_field(2, &value, &mut kind);
+        }
+
+        let mut event = vec![];
+        len_field(1, b"Event", &mut event);
+        len_field(2, &field_descriptor("name", 1, 1, 9, ""), &mut event);
+        len_field(2, &field_descriptor("values", 2, 3, 3, ""), &mut event);
+        len_field(
+            2,
+            &field_descriptor("point", 3, 1, 11, ".test.Point"),
+            &mut event,
+        );
+        len_field(
+            2,
+            &field_descriptor("kind", 4, 1, 14, ".test.Kind"),
+            &mut event,
+        );
+
+        let mut file = vec![];
+        len_field(2, b"test", &mut file);
+        len_field(4, &point, &mut file);
+        len_field(4, &event, &mut file);
+        len_field(5, &kind, &mut file);
+        len_field(12, b"proto3", &mut file);
+
+        let mut set = vec![];
+        len_field(1, &file, &mut set);
+        set
+    }
+
+    fn messages() -> Vec<u8> {
+        let mut first = vec![];
+        len_field(1, b"a", &mut first);
+        // Packed repeated field.
+        let mut packed = vec![];
+        varint(1, &mut packed);
+        varint(2, &mut packed);
+        len_field(2, &packed, &mut first);
+        let mut point = vec![];
+        varint_field(1, 3, &mut point);
+        len_field(3, &point, &mut first);
+        varint_field(4, 1, &mut first);
+
+        let mut second = vec![];
+        // Unpacked repeated field.
+        varint_field(2, 5, &mut second);
+        // Unknown fields are skipped.
+        varint_field(10, 1, &mut second);
+
+        let mut out = vec![];
+        for message in [first, second] {
+            varint(message.len() as u64, &mut out);
+            out.extend(message);
+        }
+        out
+    }
+
+    #[test]
+    fn test_read_protobuf() -> PolarsResult<()> {
+        let reader = || {
+            ProtobufReader::new(std::io::Cursor::new(messages()))
+                .with_descriptor_set(descriptor_set())
+                .with_message_type(".test.Event")
+        };
+
+        let df = reader().finish()?;
+        let point_fields = vec![
+            Field::new("x".into(), DataType::Int32),
+            Field::new("y".into(), DataType::Int32),
+        ];
+        assert_eq!(
+            df.schema().as_ref(),
+            &Schema::from_iter([
+                Field::new("name".into(), DataType::String),
+                Field::new("values".into(), DataType::List(Box::new(DataType::Int64))),
+                Field::new("point".into(), DataType::Struct(point_fields)),
+                Field::new("kind".into(), DataType::String),
+            ])
+        );
+        assert_eq!(reader().schema()?, **df.schema());
+
+        let name = df.column("name")?.str()?;
+        assert_eq!(name.into_iter().collect::<Vec<_>>(), &[Some("a"), Some("")]);
+        let values = df.column("values")?.list()?;
+        assert_eq!(values.get_as_series(0).unwrap().i64()?.get(1), Some(2));
+        assert_eq!(values.get_as_series(1).unwrap().i64()?.get(0), Some(5));
+        let point = df.column("point")?.struct_()?;
+        assert_eq!(point.field_by_name("x")?.i32()?.get(0), Some(-2));
+        assert_eq!(point.field_by_name("y")?.i32()?.get(0), Some(0));
+        assert_eq!(point.null_count(), 1);
+        let kind = df.column("kind")?.str()?;
+        assert_eq!(
+            kind.into_iter().collect::<Vec<_>>(),
+            &[Some("GPS"), Some("UNKNOWN")]
+        );
+
+        let df = reader()
+            .with_columns(Some(vec!["kind".into()]))
+            .with_n_rows(Some(1))
+            .finish()?;
+        assert_eq!(df.shape(), (1, 1));
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+//! Decoding of the protobuf [wire format].
+//!
+//! [wire format]: https://protobuf.dev/programming-guides/encoding/
+use polars_core::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum WireType {
+    Varint,
+    Fixed64,
+    Len,
+    Fixed32,
+}
+
+/// A cursor over an encoded message.
+pub(super) struct Cursor<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    pub(super) fn new(buf: &'a [u8]) -> Self {
+        Self { buf }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub(super) fn varint(&mut self) -> PolarsResult<u64> {
+        let mut value = 0u64;
+        for (i, &byte) in self.buf.iter().enumerate().take(10) {
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.buf = &self.buf[i + 1..];
+                return Ok(value);
+            }
+        }
+        polars_bail!(ComputeError: "invalid protobuf varint")
+    }
+
+    pub(super) fn bytes(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        polars_ensure!(
+            len <= self.buf.len(),
+            ComputeError: "protobuf data ended unexpectedly, expected {} more bytes", len
+        );
+        let (out, rest) = self.buf.split_at(len);
+        self.buf = rest;
+        Ok(out)
+    }
+
+    pub(super) fn len_delimited(&mut self) -> PolarsResult<&'a [u8]> {
+        let len = self.varint()? as usize;
+        self.bytes(len)
+    }
+
+    pub(super) fn fixed32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    pub(super) fn fixed64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    /// Read the tag of the next field, returning its field number and wire type.
+    pub(super) fn tag(&mut self) -> PolarsResult<(u32, WireType)> {
+        let tag = self.varint()?;
+        let wire_type = match tag & 0x7 {
+            0 => WireType::Varint,
+            1 => WireType::Fixed64,
+            2 => WireType::Len,
+            5 => WireType::Fixed32,
+            3 | 4 => polars_bail!(nyi = "reading protobuf groups"),
+            wt => polars_bail!(ComputeError: "invalid protobuf wire type {}", wt),
+        };
+        Ok(((tag >> 3) as u32, wire_type))
+    }
+
+    pub(super) fn skip(&mut self, wire_type: WireType) -> PolarsResult<()> {
+        match wire_type {
+            WireType::Varint => {
+                self.varint()?;
+            },
+            WireType::Fixed64 => {
+                self.bytes(8)?;
+            },
+            WireType::Len => {
+                self.len_delimited()?;
+            },
+            WireType::Fixed32 => {
+                self.bytes(4)?;
+            },
+        }
+        Ok(())
+    }
+}
+
+pub(super) fn zigzag32(v: u64) -> i32 {
+    let v = v as u32;
+    ((v >> 1) as i32) ^ -((v & 1) as i32)
+}
+
+pub(super) fn zigzag64(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_varint() {
+        let mut cursor = Cursor::new(&[0x96, 0x01, 0x01, 0xff]);
+        assert_eq!(cursor.varint().unwrap(), 150);
+        assert_eq!(cursor.varint().unwrap(), 1);
+        assert!(cursor.varint().is_err());
+    }
+
+    #[test]
+    fn test_zigzag() {
+        assert_eq!(zigzag32(0), 0);
+        assert_eq!(zigzag32(1), -1);
+        assert_eq!(zigzag32(4294967294), i32::MAX);
+        assert_eq!(zigzag64(3), -2);
+        assert_eq!(zigzag64(u64::MAX), i64::MIN);
+    }
+}
@@ -0,0 +1,175 @@
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_utils::error::TruncateErrorDetail;
+use reqwest::StatusCode;
+
+use super::{CompatibilityMode, SchemaFormat, SchemaRegistryOptions};
+use crate::utils::decode_json_response;
+
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+/// The characters that are percent-encoded in the subject, which is a segment of the path of
+/// the request URLs. Only the unreserved characters of RFC 3986 are kept.
+const SUBJECT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// A client for the subject of a schema registry with a Confluent compatible REST API.
+pub struct SchemaRegistryClient {
+    url: String,
+    subject: String,
+    basic_auth: Option<(String, String)>,
+    http_client: reqwest::Client,
+}
+
+/// Performs the request and returns the status and body of the response. Errors if the status
+/// is an error other than one of the `allowed` statuses.
+async fn do_request(
+    request: reqwest::RequestBuilder,
+    allowed: &[StatusCode],
+) -> PolarsResult<(StatusCode, bytes::Bytes)> {
+    let resp = request.send().await.map_err(to_compute_err)?;
+    let status = resp.status();
+    let opt_err = resp.error_for_status_ref().map(|_| ());
+    let resp_bytes = resp.bytes().await.map_err(to_compute_err)?;
+
+    if !allowed.contains(&status) {
+        opt_err.map_err(|e| {
+            to_compute_err(e).wrap_msg(|e| {
+                let body = String::from_utf8_lossy(&resp_bytes);
+
+                format!(
+                    "error: {}, response body: {}",
+                    e,
+                    TruncateErrorDetail(&body)
+                )
+            })
+        })?;
+    }
+
+    Ok((status, resp_bytes))
+}
+
+#[derive(serde::Serialize)]
+struct SchemaBody<'a> {
+    schema: &'a str,
+    #[serde(rename = "schemaType")]
+    schema_type: &'a str,
+}
+
+impl SchemaRegistryClient {
+    pub fn new(options: &SchemaRegistryOptions) -> PolarsResult<Self> {
+        Ok(Self {
+            url: options.url.trim_end_matches('/').to_string(),
+            subject: utf8_percent_encode(&options.subject, SUBJECT_ENCODE_SET).to_string(),
+            basic_auth: options.basic_auth.clone(),
+            http_client: reqwest::ClientBuilder::new()
+                .user_agent("polars")
+                .build()
+                .map_err(to_compute_err)?,
+        })
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self
+            .http_client
+            .request(method, format!("{}{}", self.url, path))
+            .header(reqwest::header::CONTENT_TYPE, CONTENT_TYPE);
+        match &self.basic_auth {
+            Some((username, password)) => request.basic_auth(username, Some(password)),
+            None => request,
+        }
+    }
+
+    /// Set the compatibility mode of the subject.
+    pub async fn set_compatibility(&self, compatibility: CompatibilityMode) -> PolarsResult<()> {
+        do_request(
+            self.request(reqwest::Method::PUT, &format!("/config/{}", self.subject))
+                .json(&Body {
+                    compatibility: compatibility.as_str(),
+                }),
+            &[],
+        )
+        .await?;
+
+        return Ok(());
+
+        #[derive(serde::Serialize)]
+        struct Body<'a> {
+            compatibility: &'a str,
+        }
+    }
+
+    /// Check whether `schema` is compatible with the latest version of the subject.
+    ///
+    /// Returns `None` if it is compatible, or if the subject has no versions yet, and the
+    /// reasons reported by the registry otherwise.
+    pub async fn check_compatibility(
+        &self,
+        schema: &str,
+        format: SchemaFormat,
+    ) -> PolarsResult<Option<Vec<String>>> {
+        let (status, bytes) = do_request(
+            self.request(
+                reqwest::Method::POST,
+                &format!(
+                    "/compatibility/subjects/{}/versions/latest?verbose=true",
+                    self.subject
+                ),
+            )
+            .json(&SchemaBody {
+                schema,
+                schema_type: format.as_str(),
+            }),
+            &[StatusCode::NOT_FOUND],
+        )
+        .await?;
+
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let Response {
+            is_compatible,
+            messages,
+        } = decode_json_response(&bytes)?;
+
+        return Ok((!is_compatible).then_some(messages));
+
+        #[derive(serde::Deserialize)]
+        struct Response {
+            is_compatible: bool,
+            #[serde(default)]
+            messages: Vec<String>,
+        }
+    }
+
+    /// Register `schema` as a new version of the subject, and return its id. Registering a
+    /// schema that is already registered returns the id of the existing schema.
+    pub async fn register(&self, schema: &str, format: SchemaFormat) -> PolarsResult<u32> {
+        let (_, bytes) = do_request(
+            self.request(
+                reqwest::Method::POST,
+                &format!("/subjects/{}/versions", self.subject),
+            )
+            .json(&SchemaBody {
+                schema,
+                schema_type: format.as_str(),
+            }),
+            &[],
+        )
+        .await?;
+
+        let Response { id } = decode_json_response(&bytes)?;
+
+        return Ok(id);
+
+        #[derive(serde::Deserialize)]
+        struct Response {
+            id: u32,
+        }
+    }
+}
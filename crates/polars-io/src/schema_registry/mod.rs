@@ -0,0 +1,156 @@
+//! Registering and validating the schemas of written files against a schema registry with a
+//! [Confluent compatible] REST API.
+//!
+//! [Confluent compatible]: https://docs.confluent.io/platform/current/schema-registry/develop/api.html
+pub mod client;
+
+use polars_core::prelude::*;
+
+use self::client::SchemaRegistryClient;
+use crate::pl_async;
+
+/// The format of the schema that is pushed to the registry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SchemaFormat {
+    Avro,
+    Json,
+}
+
+impl SchemaFormat {
+    /// The `schemaType` of the format in requests to the registry.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Avro => "AVRO",
+            Self::Json => "JSON",
+        }
+    }
+}
+
+/// Which earlier versions of the schema of a subject a new version must be compatible with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CompatibilityMode {
+    Backward,
+    BackwardTransitive,
+    Forward,
+    ForwardTransitive,
+    Full,
+    FullTransitive,
+    None,
+}
+
+impl CompatibilityMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Backward => "BACKWARD",
+            Self::BackwardTransitive => "BACKWARD_TRANSITIVE",
+            Self::Forward => "FORWARD",
+            Self::ForwardTransitive => "FORWARD_TRANSITIVE",
+            Self::Full => "FULL",
+            Self::FullTransitive => "FULL_TRANSITIVE",
+            Self::None => "NONE",
+        }
+    }
+}
+
+/// Where and how to register the schema of a written file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaRegistryOptions {
+    /// The base URL of the registry.
+    pub url: String,
+    /// The subject under which the schema is registered.
+    pub subject: String,
+    /// If set, the compatibility mode of the subject is changed to this mode before the schema
+    /// is validated.
+    pub compatibility: Option<CompatibilityMode>,
+    /// Whether to register the schema as a new version of the subject once the file is written.
+    pub register: bool,
+    /// The user name and password with which to authenticate.
+    pub basic_auth: Option<(String, String)>,
+}
+
+impl SchemaRegistryOptions {
+    pub fn new(url: impl Into<String>, subject: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            subject: subject.into(),
+            compatibility: None,
+            register: true,
+            basic_auth: None,
+        }
+    }
+
+    pub fn with_compatibility(mut self, compatibility: Option<CompatibilityMode>) -> Self {
+        self.compatibility = compatibility;
+        self
+    }
+
+    pub fn with_register(mut self, register: bool) -> Self {
+        self.register = register;
+        self
+    }
+
+    pub fn with_basic_auth(mut self, basic_auth: Option<(String, String)>) -> Self {
+        self.basic_auth = basic_auth;
+        self
+    }
+}
+
+/// Validate the schema document `schema` against the latest version of the subject in the
+/// registry, after setting the compatibility mode of the subject if
+/// [`SchemaRegistryOptions::compatibility`] is set.
+///
+/// Fails with a `SchemaMismatch` error if the registry considers the schema incompatible with
+/// the subject.
+pub async fn validate_schema(
+    options: &SchemaRegistryOptions,
+    schema: &str,
+    format: SchemaFormat,
+) -> PolarsResult<()> {
+    let client = SchemaRegistryClient::new(options)?;
+
+    if let Some(compatibility) = options.compatibility {
+        client.set_compatibility(compatibility).await?;
+    }
+
+    let messages = client.check_compatibility(schema, format).await?;
+    if let Some(messages) = messages {
+        polars_bail!(
+            SchemaMismatch: "schema is incompatible with subject '{}': {}",
+            options.subject, messages.join("; ")
+        );
+    }
+    Ok(())
+}
+
+/// Register the schema document `schema` as a new version of the subject in the registry, and
+/// return its id.
+///
+/// This should only be done once the file is written, so that no version is registered for a
+/// file that failed to be written.
+pub async fn register_schema(
+    options: &SchemaRegistryOptions,
+    schema: &str,
+    format: SchemaFormat,
+) -> PolarsResult<u32> {
+    SchemaRegistryClient::new(options)?
+        .register(schema, format)
+        .await
+}
+
+/// Blocking version of [`validate_schema`].
+pub fn validate_schema_blocking(
+    options: &SchemaRegistryOptions,
+    schema: &str,
+    format: SchemaFormat,
+) -> PolarsResult<()> {
+    pl_async::get_runtime().block_in_place_on(validate_schema(options, schema, format))
+}
+
+/// Blocking version of [`register_schema`].
+pub fn register_schema_blocking(
+    options: &SchemaRegistryOptions,
+    schema: &str,
+    format: SchemaFormat,
+) -> PolarsResult<u32> {
+    pl_async::get_runtime().block_in_place_on(register_schema(options, schema, format))
+}
@@ -0,0 +1,257 @@
+//! Reading the members of zip and tar archives.
+//!
+//! Only regular files are read: directories, links and other special entries are skipped. Zip
+//! members can be stored or deflated, the latter requires the `decompress` feature. Reading
+//! archives requires the `csv` feature, which the CSV and NDJSON readers enable.
+#[cfg(feature = "csv")]
+use std::borrow::Cow;
+#[cfg(feature = "csv")]
+use std::io::{Cursor, Read};
+
+#[cfg(feature = "csv")]
+use polars_core::POOL;
+#[cfg(feature = "csv")]
+use polars_core::prelude::*;
+#[cfg(feature = "csv")]
+use polars_error::to_compute_err;
+#[cfg(feature = "csv")]
+use rayon::prelude::*;
+#[cfg(feature = "csv")]
+use zip::ZipArchive;
+
+const TAR_BLOCK: usize = 512;
+
+const ZIP_LOCAL_HEADER: u32 = 0x04034b50;
+
+/// Represents the archive formats that we can read members from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SupportedArchive {
+    Zip,
+    Tar,
+}
+
+impl SupportedArchive {
+    /// If the given byte slice starts like a supported (uncompressed) archive, return its format.
+    pub fn check(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() >= 4 && bytes[..4] == ZIP_LOCAL_HEADER.to_le_bytes() {
+            Some(Self::Zip)
+        } else if bytes.len() >= TAR_BLOCK && &bytes[257..262] == b"ustar" {
+            Some(Self::Tar)
+        } else {
+            None
+        }
+    }
+}
+
+/// Options that control which archive members are read and how they are combined.
+#[derive(Clone, Copy, Debug)]
+pub struct ArchiveReadOptions<'a> {
+    /// Only read the members whose path matches this glob pattern. All files are read if `None`.
+    pub member_glob: Option<&'a str>,
+    /// Every member starts with a header line. The header is only kept for the first member.
+    pub has_header: bool,
+    pub eol_char: u8,
+}
+
+impl Default for ArchiveReadOptions<'_> {
+    fn default() -> Self {
+        Self {
+            member_glob: None,
+            has_header: false,
+            eol_char: b'\n',
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+/// A member of an archive, identified by its path.
+struct Member<'a> {
+    name: String,
+    source: MemberSource<'a>,
+}
+
+#[cfg(feature = "csv")]
+enum MemberSource<'a> {
+    /// Index of the member in a zip archive.
+    Zip(usize),
+    /// The data of a tar member, which is never compressed within the archive.
+    Tar(&'a [u8]),
+}
+
+#[cfg(feature = "csv")]
+impl Member<'_> {
+    fn read<'a>(&'a self, zip: Option<&ZipArchive<Cursor<&[u8]>>>) -> PolarsResult<Cow<'a, [u8]>> {
+        match self.source {
+            MemberSource::Zip(index) => {
+                // Cloning the archive only clones the shared metadata, so that members can be
+                // inflated in parallel.
+                let mut zip = zip.unwrap().clone();
+                let mut file = zip.by_index(index).map_err(to_compute_err)?;
+                let mut out = Vec::with_capacity(file.size() as usize);
+                file.read_to_end(&mut out).map_err(to_compute_err)?;
+                Ok(Cow::Owned(out))
+            },
+            MemberSource::Tar(data) => Ok(Cow::Borrowed(data)),
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+fn zip_members(zip: &mut ZipArchive<Cursor<&[u8]>>) -> PolarsResult<Vec<Member<'static>>> {
+    let mut members = Vec::with_capacity(zip.len());
+    for index in 0..zip.len() {
+        let file = zip.by_index_raw(index).map_err(to_compute_err)?;
+        if file.is_file() {
+            members.push(Member {
+                name: file.name().to_string(),
+                source: MemberSource::Zip(index),
+            });
+        }
+    }
+    Ok(members)
+}
+
+#[cfg(feature = "csv")]
+fn tar_members(bytes: &[u8]) -> PolarsResult<Vec<Member<'_>>> {
+    let mut archive = tar::Archive::new(bytes);
+    let mut members = vec![];
+    for entry in archive.entries().map_err(to_compute_err)? {
+        let entry = entry.map_err(to_compute_err)?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let start = entry.raw_file_position() as usize;
+        let data = bytes
+            .get(start..start + entry.size() as usize)
+            .ok_or_else(|| polars_err!(ComputeError: "tar archive is truncated"))?;
+        members.push(Member {
+            name: entry
+                .path()
+                .map_err(to_compute_err)?
+                .to_string_lossy()
+                .into_owned(),
+            source: MemberSource::Tar(data),
+        });
+    }
+    Ok(members)
+}
+
+#[cfg(feature = "csv")]
+/// Read the members of an archive and write them to `out`, one after the other.
+///
+/// Members are decompressed in parallel. If `options.has_header` is set, the first line of every
+/// member except the first is dropped.
+pub fn read_archive(
+    archive: SupportedArchive,
+    bytes: &[u8],
+    options: &ArchiveReadOptions,
+    out: &mut Vec<u8>,
+) -> PolarsResult<()> {
+    let mut zip = match archive {
+        SupportedArchive::Zip => Some(ZipArchive::new(Cursor::new(bytes)).map_err(to_compute_err)?),
+        SupportedArchive::Tar => None,
+    };
+    let mut members = match zip.as_mut() {
+        Some(zip) => zip_members(zip)?,
+        None => tar_members(bytes)?,
+    };
+
+    if let Some(member_glob) = options.member_glob {
+        let pattern = glob::Pattern::new(member_glob).map_err(to_compute_err)?;
+        members.retain(|m| pattern.matches(&m.name));
+        polars_ensure!(
+            !members.is_empty(),
+            ComputeError: "no archive member matches the pattern '{}'", member_glob
+        );
+    }
+
+    let contents = POOL.install(|| {
+        members
+            .par_iter()
+            .map(|m| m.read(zip.as_ref()))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    out.reserve(contents.iter().map(|c| c.len() + 1).sum());
+    for (i, content) in contents.iter().enumerate() {
+        let mut content = content.as_ref();
+        if i > 0 && options.has_header {
+            content =
+                memchr::memchr(options.eol_char, content).map_or(&[], |eol| &content[eol + 1..]);
+        }
+        if content.is_empty() {
+            continue;
+        }
+        if out.last().is_some_and(|c| *c != options.eol_char) {
+            out.push(options.eol_char);
+        }
+        out.extend_from_slice(content);
+    }
+    Ok(())
+}
+
+#[cfg(all(test, feature = "csv"))]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn tar_archive(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(vec![]);
+        for (name, content) in entries {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, name, *content).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    fn stored_zip(entries: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(vec![]));
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Stored);
+        writer.add_directory("dir/", options).unwrap();
+        for (name, content) in entries {
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(content).unwrap();
+        }
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_read_tar_members() {
+        let tar = tar_archive(&[
+            ("data/a.csv", b"x,y\n1,2\n"),
+            ("data/b.csv", b"x,y\n3,4"),
+            ("README", b"not data"),
+        ]);
+        assert_eq!(SupportedArchive::check(&tar), Some(SupportedArchive::Tar));
+
+        let options = ArchiveReadOptions {
+            member_glob: Some("*.csv"),
+            has_header: true,
+            ..Default::default()
+        };
+        let mut out = vec![];
+        read_archive(SupportedArchive::Tar, &tar, &options, &mut out).unwrap();
+        assert_eq!(out, b"x,y\n1,2\n3,4");
+    }
+
+    #[test]
+    fn test_read_zip_members() {
+        let zip = stored_zip(&[("a.json", b"{\"a\":1}"), ("b.json", b"{\"a\":2}\n")]);
+        assert_eq!(SupportedArchive::check(&zip), Some(SupportedArchive::Zip));
+
+        let mut out = vec![];
+        read_archive(SupportedArchive::Zip, &zip, &Default::default(), &mut out).unwrap();
+        assert_eq!(out, b"{\"a\":1}\n{\"a\":2}\n");
+
+        let options = ArchiveReadOptions {
+            member_glob: Some("c*"),
+            ..Default::default()
+        };
+        assert!(read_archive(SupportedArchive::Zip, &zip, &options, &mut vec![]).is_err());
+    }
+}
@@ -3,6 +3,10 @@ use std::io::Read;
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
 
+#[cfg(feature = "csv")]
+use super::archive::read_archive;
+pub use super::archive::{ArchiveReadOptions, SupportedArchive};
+
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
     GZIP,
@@ -32,26 +36,41 @@ impl SupportedCompression {
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
 /// An `out` vec must be given for ownership of the decompressed data.
+///
+/// All files of zip and (compressed) tar archives are read, see
+/// [`maybe_decompress_archive_bytes`].
 #[allow(clippy::ptr_arg)]
 pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> PolarsResult<&'a [u8]> {
+    maybe_decompress_archive_bytes(bytes, out, &ArchiveReadOptions::default())
+}
+
+/// Decompress `bytes` if compression is detected, otherwise simply return it.
+/// An `out` vec must be given for ownership of the decompressed data.
+///
+/// If `bytes` (after decompression) is a zip or tar archive, the members that are selected by
+/// `archive_options` are read and concatenated into `out`.
+#[allow(clippy::ptr_arg)]
+pub fn maybe_decompress_archive_bytes<'a>(
+    bytes: &'a [u8],
+    out: &'a mut Vec<u8>,
+    archive_options: &ArchiveReadOptions,
+) -> PolarsResult<&'a [u8]> {
     assert!(out.is_empty());
 
-    if let Some(algo) = SupportedCompression::check(bytes) {
+    if let Some(archive) = SupportedArchive::check(bytes) {
+        feature_gated!("csv", {
+            read_archive(archive, bytes, archive_options, out)?;
+            Ok(out)
+        })
+    } else if let Some(algo) = SupportedCompression::check(bytes) {
         feature_gated!("decompress", {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
-                },
+            decompress_to(algo, bytes, out)?;
+
+            if let Some(archive) = SupportedArchive::check(out) {
+                feature_gated!("csv", {
+                    let decompressed = std::mem::take(out);
+                    read_archive(archive, &decompressed, archive_options, out)?;
+                })
             }
 
             Ok(out)
@@ -60,3 +79,48 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Whether `bytes` is a zip archive or a (compressed) tar archive.
+pub fn is_archive(bytes: &[u8]) -> bool {
+    if SupportedArchive::check(bytes).is_some() {
+        return true;
+    }
+
+    #[cfg(feature = "decompress")]
+    if let Some(algo) = SupportedCompression::check(bytes) {
+        // The tar magic is in the first block, so there is no need to decompress everything.
+        let reader: Box<dyn Read> = match algo {
+            SupportedCompression::GZIP => Box::new(flate2::read::MultiGzDecoder::new(bytes)),
+            SupportedCompression::ZLIB => Box::new(flate2::read::ZlibDecoder::new(bytes)),
+            SupportedCompression::ZSTD => match zstd::Decoder::with_buffer(bytes) {
+                Ok(decoder) => Box::new(decoder),
+                Err(_) => return false,
+            },
+        };
+        let mut first_block = vec![];
+        return reader.take(512).read_to_end(&mut first_block).is_ok()
+            && SupportedArchive::check(&first_block) == Some(SupportedArchive::Tar);
+    }
+
+    false
+}
+
+#[cfg(feature = "decompress")]
+fn decompress_to(algo: SupportedCompression, bytes: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+    match algo {
+        SupportedCompression::GZIP => {
+            flate2::read::MultiGzDecoder::new(bytes)
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
+        },
+        SupportedCompression::ZLIB => {
+            flate2::read::ZlibDecoder::new(bytes)
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
+        },
+        SupportedCompression::ZSTD => {
+            zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
+        },
+    }
+    Ok(())
+}
@@ -1,3 +1,4 @@
+pub mod archive;
 pub mod compression;
 mod other;
 
@@ -7,6 +8,7 @@ pub mod byte_source;
 pub mod file;
 pub mod mkdir;
 pub mod slice;
+pub mod staged_commit;
 pub mod sync_on_close;
 
 pub const URL_ENCODE_CHAR_SET: &percent_encoding::AsciiSet = &percent_encoding::CONTROLS
@@ -0,0 +1,86 @@
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use polars_utils::file::ClosableFile;
+
+use super::file::DynWriteable;
+use super::sync_on_close::SyncOnCloseType;
+
+static STAGING_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// A local file that is written to a hidden staging path in the same directory, and only renamed
+/// to its final path once it is closed.
+///
+/// If it is dropped without being closed, e.g. because the query failed or was cancelled, the
+/// staging file is removed. Readers of the final path thus either see the previous file or the
+/// complete new one, never a partially written file.
+pub struct StagedFile {
+    file: Option<ClosableFile>,
+    staging_path: PathBuf,
+    path: PathBuf,
+}
+
+impl StagedFile {
+    pub fn try_new(path: &Path) -> io::Result<Self> {
+        let file_name = path
+            .file_name()
+            .ok_or_else(|| io::Error::other("path is not a file"))?;
+        let mut staging_name = OsString::from(".");
+        staging_name.push(file_name);
+        staging_name.push(format!(
+            ".{}-{}.staging",
+            std::process::id(),
+            STAGING_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let staging_path = path.with_file_name(staging_name);
+        let file = std::fs::File::create(&staging_path)?;
+
+        Ok(Self {
+            file: Some(file.into()),
+            staging_path,
+            path: path.to_path_buf(),
+        })
+    }
+
+    fn file(&mut self) -> &mut ClosableFile {
+        self.file.as_mut().unwrap()
+    }
+}
+
+impl io::Write for StagedFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file().flush()
+    }
+}
+
+impl DynWriteable for StagedFile {
+    fn as_dyn_write(&self) -> &(dyn io::Write + Send + 'static) {
+        self as _
+    }
+    fn as_mut_dyn_write(&mut self) -> &mut (dyn io::Write + Send + 'static) {
+        self as _
+    }
+    fn close(mut self: Box<Self>) -> io::Result<()> {
+        self.file.take().unwrap().close()?;
+        std::fs::rename(&self.staging_path, &self.path)
+    }
+    fn sync_on_close(&mut self, sync_on_close: SyncOnCloseType) -> io::Result<()> {
+        super::sync_on_close::sync_on_close(sync_on_close, self.file().as_mut())
+    }
+}
+
+impl Drop for StagedFile {
+    fn drop(&mut self) {
+        // Not closed, or the rename failed: the staged data is never committed.
+        if self.staging_path.exists() {
+            drop(self.file.take());
+            let _ = std::fs::remove_file(&self.staging_path);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! APIs to write to JSON
+mod pretty;
 mod serialize;
 mod utf8;
 
@@ -10,6 +11,7 @@ use arrow::io::iterator::StreamingIterator;
 use arrow::record_batch::RecordBatchT;
 pub use fallible_streaming_iterator::*;
 use polars_error::{PolarsError, PolarsResult};
+pub use pretty::PrettyWriter;
 pub(crate) use serialize::new_serializer;
 use serialize::serialize;
 pub use utf8::serialize_to_utf8;
@@ -118,7 +120,8 @@ impl FallibleStreamingIterator for RecordSerializer<'_> {
             if !is_first_row {
                 write!(&mut self.buffer, ",")?;
             }
-            write!(&mut self.buffer, "\"{}\":", f.name)?;
+            utf8::write_str(&mut self.buffer, &f.name)?;
+            write!(&mut self.buffer, ":")?;
 
             self.buffer.extend_from_slice(it.next().unwrap());
             is_first_row = false;
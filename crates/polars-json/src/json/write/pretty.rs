@@ -0,0 +1,96 @@
+use std::io::{Result, Write};
+
+const INDENT: &[u8] = b"  ";
+
+/// A [`Write`] adapter that pretty-prints the compact JSON written to it, indenting every value
+/// of an array or object on its own line. Empty arrays and objects are kept on one line.
+///
+/// The JSON is reformatted as it streams through, so it never has to be in memory as a whole.
+pub struct PrettyWriter<W: Write> {
+    inner: W,
+    buffer: Vec<u8>,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// Whether the last byte opened an array or object, of which the first value isn't indented
+    /// yet as it could be empty.
+    opened: bool,
+}
+
+impl<W: Write> PrettyWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buffer: Vec::new(),
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            opened: false,
+        }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    fn newline(&mut self) {
+        self.buffer.push(b'\n');
+        for _ in 0..self.depth {
+            self.buffer.extend_from_slice(INDENT);
+        }
+    }
+}
+
+impl<W: Write> Write for PrettyWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.buffer.clear();
+        for &b in buf {
+            if self.in_string {
+                self.buffer.push(b);
+                if self.escaped {
+                    self.escaped = false;
+                } else if b == b'\\' {
+                    self.escaped = true;
+                } else if b == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+
+            let closes = matches!(b, b']' | b'}');
+            if self.opened && !closes {
+                self.newline();
+            }
+            match b {
+                b'[' | b'{' => {
+                    self.buffer.push(b);
+                    self.depth += 1;
+                },
+                b']' | b'}' => {
+                    self.depth -= 1;
+                    if !self.opened {
+                        self.newline();
+                    }
+                    self.buffer.push(b);
+                },
+                b',' => {
+                    self.buffer.push(b);
+                    self.newline();
+                },
+                b':' => self.buffer.extend_from_slice(b": "),
+                b'"' => {
+                    self.buffer.push(b);
+                    self.in_string = true;
+                },
+                _ => self.buffer.push(b),
+            }
+            self.opened = matches!(b, b'[' | b'{');
+        }
+        self.inner.write_all(&self.buffer)?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
@@ -192,6 +192,22 @@ fn struct_serializer<'a>(
     //  {"a": 3, "b": c, "c": {"a": 3}},
     // ]
     //
+
+    // The escaped `"name":` of every field, so every row only has to copy the keys.
+    let keys = array
+        .fields()
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let mut key = Vec::with_capacity(field.name.len() + 4);
+            if i > 0 {
+                key.push(b',');
+            }
+            utf8::write_str(&mut key, &field.name).unwrap();
+            key.push(b':');
+            key
+        })
+        .collect::<Vec<_>>();
     let mut serializers = array
         .values()
         .iter()
@@ -199,29 +215,28 @@ fn struct_serializer<'a>(
         .map(|arr| new_serializer(arr, offset, take))
         .collect::<Vec<_>>();
 
-    Box::new(BufStreamingIterator::new(
-        ZipValidity::new_with_validity(0..array.len(), array.validity()),
-        move |maybe, buf| {
-            if maybe.is_some() {
-                let names = array.fields().iter().map(|f| f.name.as_str());
-                serialize_item(
-                    buf,
-                    names.zip(
-                        serializers
-                            .iter_mut()
-                            .map(|serializer| serializer.next().unwrap()),
-                    ),
-                    true,
-                );
-            } else {
-                serializers.iter_mut().for_each(|iter| {
-                    let _ = iter.next();
-                });
-                buf.extend(b"null");
+    let f = move |maybe: Option<usize>, buf: &mut Vec<u8>| {
+        if maybe.is_some() {
+            buf.push(b'{');
+            for (key, serializer) in keys.iter().zip(serializers.iter_mut()) {
+                buf.extend_from_slice(key);
+                buf.extend_from_slice(serializer.next().unwrap());
             }
-        },
-        vec![],
-    ))
+            buf.push(b'}');
+        } else {
+            serializers.iter_mut().for_each(|iter| {
+                let _ = iter.next();
+            });
+            buf.extend(b"null");
+        }
+    };
+
+    materialize_serializer(
+        f,
+        ZipValidity::new_with_validity(0..array.len(), array.validity()),
+        offset,
+        take,
+    )
 }
 
 fn list_serializer<'a, O: Offset>(
@@ -561,28 +576,6 @@ pub(crate) fn new_serializer<'a>(
     }
 }
 
-fn serialize_item<'a>(
-    buffer: &mut Vec<u8>,
-    record: impl Iterator<Item = (&'a str, &'a [u8])>,
-    is_first_row: bool,
-) {
-    if !is_first_row {
-        buffer.push(b',');
-    }
-    buffer.push(b'{');
-    let mut first_item = true;
-    for (key, value) in record {
-        if !first_item {
-            buffer.push(b',');
-        }
-        first_item = false;
-        utf8::write_str(buffer, key).unwrap();
-        buffer.push(b':');
-        buffer.extend(value);
-    }
-    buffer.push(b'}');
-}
-
 /// Serializes `array` to a valid JSON to `buffer`
 /// # Implementation
 /// This operation is CPU-bounded
@@ -86,6 +86,32 @@ where
     polars_core::POOL.install(|| iter.map(|lf| lf.collect()).collect())
 }
 
+/// Replace all values of `expr` by looking them up in a `mapping` of two columns.
+///
+/// The first column of `mapping` holds the values to replace and the second column the values
+/// to replace them with. The mapping is collected once, when the expression is created, and
+/// embedded as literals so that the expression stays serializable. See
+/// [`Expr::replace_strict`] for the meaning of `default` and `return_dtype`.
+#[cfg(feature = "replace")]
+pub fn replace_strict_with_frame(
+    expr: Expr,
+    mut mapping: LazyFrame,
+    default: Option<Expr>,
+    return_dtype: Option<impl Into<DataTypeExpr>>,
+) -> PolarsResult<Expr> {
+    let schema = mapping.collect_schema()?;
+    polars_ensure!(
+        schema.len() == 2,
+        ShapeMismatch: "the mapping of `replace_strict` must have 2 columns, got {}", schema.len()
+    );
+
+    // Both columns come from a single collect, so that they are aligned.
+    let mut columns = mapping.collect()?.take_columns().into_iter();
+    let old = columns.next().unwrap().take_materialized_series();
+    let new = columns.next().unwrap().take_materialized_series();
+    Ok(expr.replace_strict(lit(old), lit(new), default, return_dtype))
+}
+
 #[cfg(test)]
 mod test {
     // used only if feature="diagonal_concat"
@@ -132,4 +158,40 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg(feature = "replace")]
+    fn test_replace_strict_with_frame() -> PolarsResult<()> {
+        let df = df![
+            "a" => [1, 2, 3, 1]
+        ]?;
+        let mapping = df![
+            "from" => [1, 2],
+            "to" => ["x", "y"]
+        ]?;
+
+        let expr = replace_strict_with_frame(
+            col("a"),
+            mapping.clone().lazy(),
+            Some(lit("z")),
+            None::<DataTypeExpr>,
+        )?;
+        let out = df.clone().lazy().select([expr]).collect()?;
+        let expected = df![
+            "a" => ["x", "y", "z", "x"]
+        ]?;
+        assert!(out.equals(&expected));
+
+        let expr = replace_strict_with_frame(col("a"), mapping.lazy(), None, None::<DataTypeExpr>)?;
+        assert!(df.clone().lazy().select([expr]).collect().is_err());
+
+        let mapping = df![
+            "from" => [1, 2]
+        ]?;
+        assert!(
+            replace_strict_with_frame(col("a"), mapping.lazy(), None, None::<DataTypeExpr>)
+                .is_err()
+        );
+        Ok(())
+    }
 }
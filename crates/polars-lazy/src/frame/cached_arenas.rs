@@ -19,6 +19,8 @@ impl LazyFrame {
         lp_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
     ) -> PolarsResult<SchemaRef> {
+        #[cfg(feature = "list_to_struct")]
+        register_list_to_struct_sampler();
         let node = to_alp(
             self.logical_plan.clone(),
             expr_arena,
@@ -42,6 +44,8 @@ impl LazyFrame {
     /// Returns an `Err` if the logical plan has already encountered an error (i.e., if
     /// `self.collect()` would fail), `Ok` otherwise.
     pub fn collect_schema(&mut self) -> PolarsResult<SchemaRef> {
+        #[cfg(feature = "list_to_struct")]
+        register_list_to_struct_sampler();
         let mut cached_arenas = self.cached_arena.lock().unwrap();
 
         match &mut *cached_arenas {
@@ -0,0 +1,105 @@
+//! Debugging of queries by materializing the output of some of their nodes, see
+//! [`LazyFrame::collect_with_data_watch`].
+#[cfg(feature = "ipc")]
+use std::path::PathBuf;
+
+use polars_utils::format_pl_smallstr;
+
+use super::*;
+
+pub type DataWatchFunction = Arc<dyn Fn(DataFrame) -> PolarsResult<()> + Send + Sync>;
+
+/// What happens with the output of a watched node.
+#[derive(Clone)]
+pub enum DataWatchTarget {
+    /// Write the output to an IPC file.
+    #[cfg(feature = "ipc")]
+    Ipc(PathBuf),
+    /// Call the function with the first `n_rows` rows of the output.
+    Callback {
+        n_rows: usize,
+        function: DataWatchFunction,
+    },
+}
+
+impl DataWatchTarget {
+    fn watch(&self, df: &DataFrame) -> PolarsResult<()> {
+        match self {
+            #[cfg(feature = "ipc")]
+            Self::Ipc(path) => {
+                use polars_io::SerWriter;
+                use polars_io::ipc::IpcWriter;
+
+                let file = std::fs::File::create(path)?;
+                IpcWriter::new(file).finish(&mut df.clone())
+            },
+            Self::Callback { n_rows, function } => function(df.head(Some(*n_rows))),
+        }
+    }
+}
+
+/// Pass the output of the nodes with the ids in `watch` through their target. The output of the
+/// nodes is not changed.
+fn insert_watches(
+    lp_top: Node,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    watch: Vec<(usize, DataWatchTarget)>,
+) -> PolarsResult<()> {
+    let ids = IRPlanRef {
+        lp_top,
+        lp_arena,
+        expr_arena,
+    }
+    .node_ids();
+    let nodes = watch
+        .into_iter()
+        .map(|(id, target)| match ids.get_index(id) {
+            Some((node, _)) => Ok((id, *node, target)),
+            None => polars_bail!(InvalidOperation: "the query has no node with id {id}"),
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    for (id, node, target) in nodes {
+        // Keep the node in place so that its parents now read from the watch.
+        let ir = lp_arena.take(node);
+        let input = lp_arena.add(ir);
+        let function = move |df: DataFrame| {
+            target.watch(&df)?;
+            Ok(df)
+        };
+        lp_arena.replace(
+            node,
+            IR::MapFunction {
+                input,
+                function: FunctionIR::Opaque {
+                    function: Arc::new(function),
+                    schema: None,
+                    predicate_pd: false,
+                    projection_pd: false,
+                    streamable: false,
+                    fmt_str: format_pl_smallstr!("DATA WATCH {id}"),
+                },
+            },
+        );
+    }
+    Ok(())
+}
+
+impl LazyFrame {
+    /// Execute the query like [`LazyFrame::collect`], and pass the output of the nodes with the
+    /// ids in `watch` to their [`DataWatchTarget`].
+    ///
+    /// The ids of the nodes are shown by
+    /// [`LazyFrame::describe_optimized_plan_with_node_ids`]. This makes it possible to find the
+    /// stage of a query that produces unexpected values without splitting it up. The query is
+    /// always run by the in-memory engine.
+    pub fn collect_with_data_watch(
+        self,
+        watch: Vec<(usize, DataWatchTarget)>,
+    ) -> PolarsResult<DataFrame> {
+        self._collect_post_opt(|lp_top, lp_arena, expr_arena, _| {
+            insert_watches(lp_top, lp_arena, expr_arena, watch)
+        })
+    }
+}
@@ -0,0 +1,158 @@
+//! Annotation of the optimized plan with the cardinalities and timings of a run on a sample of
+//! its inputs, see [`LazyFrame::explain_sampled`].
+use std::time::Instant;
+
+use polars_utils::format_pl_smallstr;
+
+use super::*;
+
+/// What the run observed of a node: the height of its output and when it was produced.
+type Observation = Option<(usize, Instant)>;
+
+/// Take an evenly spread `fraction` of the rows, which always includes the first row.
+fn sample_rows(df: DataFrame, fraction: f64) -> PolarsResult<DataFrame> {
+    let mask: BooleanChunked = (0..df.height())
+        .map(|i| (i as f64 * fraction).ceil() < ((i + 1) as f64 * fraction).ceil())
+        .collect();
+    df.filter(&mask)
+}
+
+fn opaque(input: Node, function: impl DataFrameUdf + 'static, fmt_str: PlSmallStr) -> IR {
+    IR::MapFunction {
+        input,
+        function: FunctionIR::Opaque {
+            function: Arc::new(function),
+            schema: None,
+            predicate_pd: false,
+            projection_pd: false,
+            streamable: false,
+            fmt_str,
+        },
+    }
+}
+
+/// Sample the output of every scan with `fraction`, and observe the output of every node in the
+/// order of `ids`.
+fn insert_observers(
+    lp_arena: &mut Arena<IR>,
+    ids: &PlIndexMap<Node, usize>,
+    fraction: f64,
+    observations: &Arc<Mutex<Vec<Observation>>>,
+) {
+    for (&node, &id) in ids {
+        let ir = lp_arena.take(node);
+        let mut input = lp_arena.add(ir);
+        if matches!(
+            lp_arena.get(input),
+            IR::Scan { .. } | IR::DataFrameScan { .. } | IR::PythonScan { .. }
+        ) {
+            let sample = move |df: DataFrame| sample_rows(df, fraction);
+            input = lp_arena.add(opaque(input, sample, "SAMPLE".into()));
+        }
+        let observations = observations.clone();
+        let observe = move |df: DataFrame| {
+            observations.lock().unwrap()[id] = Some((df.height(), Instant::now()));
+            Ok(df)
+        };
+        lp_arena.replace(
+            node,
+            opaque(input, observe, format_pl_smallstr!("OBSERVE {id}")),
+        );
+    }
+}
+
+impl LazyFrame {
+    /// Return a String describing the optimized logical plan like [`LazyFrame::explain`], of
+    /// which every node is annotated with the number of rows it produced and the time it took in
+    /// a run of the query on a `fraction` of the rows of each scan.
+    ///
+    /// This shows the actual cardinalities of a complex plan, which are hard to estimate from the
+    /// plan itself. The scans still read all of their input, and the sample takes evenly spread
+    /// rows, so the run is deterministic. The time of a node is measured from the end of its
+    /// slowest input, or from the start of the run for a scan, which makes it approximate if
+    /// nodes run in parallel. The query is always run by the in-memory engine.
+    pub fn explain_sampled(&self, fraction: f64) -> PolarsResult<String> {
+        polars_ensure!(
+            fraction > 0.0 && fraction <= 1.0,
+            InvalidOperation: "`sample` must be a fraction in (0, 1], got {fraction}"
+        );
+
+        let observations = Arc::new(Mutex::new(vec![]));
+        let mut plan = None;
+        let mut start = None;
+        self.clone()
+            ._collect_post_opt(|lp_top, lp_arena, expr_arena, _| {
+                let ids = IRPlanRef {
+                    lp_top,
+                    lp_arena,
+                    expr_arena,
+                }
+                .node_ids();
+                *observations.lock().unwrap() = vec![None; ids.len()];
+                // The observers keep the original nodes in place, so the ids and annotations of
+                // this copy refer to the same nodes.
+                plan = Some((
+                    IRPlan::new(lp_top, lp_arena.clone(), expr_arena.clone()),
+                    ids,
+                ));
+                insert_observers(lp_arena, &plan.as_ref().unwrap().1, fraction, &observations);
+                start = Some(Instant::now());
+                Ok(())
+            })?;
+        let (plan, ids) = plan.unwrap();
+        let start = start.unwrap();
+
+        let observations = observations.lock().unwrap();
+        let mut inputs = vec![];
+        let annotations = ids
+            .iter()
+            .filter_map(|(&node, &id)| {
+                let (rows, end) = observations[id]?;
+                plan.lp_arena.get(node).copy_inputs(&mut inputs);
+                let input_end = inputs
+                    .drain(..)
+                    .filter_map(|input| observations[ids[&input]].map(|(_, end)| end))
+                    .max()
+                    .unwrap_or(start);
+                let time = end.saturating_duration_since(input_end).as_micros();
+                Some((node, format!("[rows: {rows}, time: {time}µs]")))
+            })
+            .collect();
+        Ok(plan
+            .as_ref()
+            .display()
+            .with_annotations(annotations)
+            .to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_explain_sampled() -> PolarsResult<()> {
+        let df = df![
+            "a" => (0..1000).collect::<Vec<i32>>(),
+            "b" => (0..1000).map(|i| i % 4).collect::<Vec<i32>>(),
+        ]?;
+        let explained = df
+            .lazy()
+            .filter(col("b").eq(lit(0)))
+            .group_by([col("b")])
+            .agg([col("a").sum()])
+            .explain_sampled(0.1)?;
+
+        let rows = |keyword: &str| {
+            let line = explained.lines().find(|l| l.contains(keyword)).unwrap();
+            let rows = line.split("[rows: ").nth(1).unwrap();
+            rows[..rows.find(',').unwrap()].parse::<usize>().unwrap()
+        };
+        assert_eq!(rows("AGGREGATE"), 1);
+        assert_eq!(rows("DF "), 100);
+        assert!(!explained.contains("OBSERVE"));
+
+        assert!(DataFrame::empty().lazy().explain_sampled(0.0).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Data quality metrics of the data flowing through a query, see [`LazyFrame::inspect`].
+use polars_core::utils::accumulate_dataframes_vertical;
+
+use super::*;
+
+/// How the summaries of the batches are combined into the summary of all data.
+#[derive(Clone, Copy)]
+enum Combine {
+    Sum,
+    Min,
+    Max,
+}
+
+fn combine_of(expr: &Expr) -> PolarsResult<Combine> {
+    Ok(match expr {
+        Expr::Alias(input, _) | Expr::KeepName(input) | Expr::RenameAlias { expr: input, .. } => {
+            return combine_of(input);
+        },
+        Expr::Len
+        | Expr::Agg(AggExpr::Count(..) | AggExpr::Sum(_))
+        | Expr::Function {
+            function: FunctionExpr::NullCount,
+            ..
+        } => Combine::Sum,
+        Expr::Agg(AggExpr::Min { .. }) => Combine::Min,
+        Expr::Agg(AggExpr::Max { .. }) => Combine::Max,
+        _ => polars_bail!(
+            InvalidOperation: "`inspect` only supports `len`, `count`, `null_count`, `sum`, `min` \
+            and `max`, got {expr}"
+        ),
+    })
+}
+
+/// The metrics of an [`LazyFrame::inspect`] node.
+#[derive(Clone)]
+pub struct InspectHandle {
+    exprs: Vec<Expr>,
+    schema: SchemaRef,
+    combine: Vec<(PlSmallStr, Combine)>,
+    summaries: Arc<Mutex<Vec<DataFrame>>>,
+}
+
+impl InspectHandle {
+    fn summarize(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        df.lazy().select(self.exprs.clone()).collect()
+    }
+
+    /// The metrics of all data that passed through the node so far, as a single row.
+    pub fn report(&self) -> PolarsResult<DataFrame> {
+        let summaries = self.summaries.lock().unwrap();
+        let summaries = if summaries.is_empty() {
+            self.summarize(DataFrame::empty_with_schema(&self.schema))?
+        } else {
+            accumulate_dataframes_vertical(summaries.iter().cloned())?
+        };
+        let exprs = self
+            .combine
+            .iter()
+            .map(|(name, combine)| {
+                let c = col(name.clone());
+                match combine {
+                    Combine::Sum => c.sum(),
+                    Combine::Min => c.min(),
+                    Combine::Max => c.max(),
+                }
+            })
+            .collect::<Vec<_>>();
+        summaries.lazy().select(exprs).collect()
+    }
+
+    /// Forget the data that passed through the node so far.
+    pub fn reset(&self) {
+        self.summaries.lock().unwrap().clear();
+    }
+}
+
+impl LazyFrame {
+    /// Add a node that passes its input through unchanged, and evaluates the summary `exprs` on
+    /// the data that flows through it. The metrics are available from the returned
+    /// [`InspectHandle`] once the query is executed.
+    ///
+    /// The expressions are evaluated per batch, so the node does not need to materialize its
+    /// input in the streaming engine. Only `len`, `count`, `null_count`, `sum`, `min` and `max`
+    /// are supported, as their summaries of the batches can be combined.
+    pub fn inspect(mut self, exprs: Vec<Expr>) -> PolarsResult<(LazyFrame, InspectHandle)> {
+        let schema = self.collect_schema()?;
+        let mut combine = vec![];
+        for expr in &exprs {
+            let expr_combine = combine_of(expr)?;
+            let names = DataFrame::empty_with_schema(&schema)
+                .lazy()
+                .select([expr.clone()])
+                .collect_schema()?;
+            combine.extend(names.iter_names().map(|name| (name.clone(), expr_combine)));
+        }
+
+        let handle = InspectHandle {
+            exprs,
+            schema,
+            combine,
+            summaries: Default::default(),
+        };
+        let inspect = handle.clone();
+        let function = move |df: DataFrame| {
+            let summary = inspect.summarize(df.clone())?;
+            inspect.summaries.lock().unwrap().push(summary);
+            Ok(df)
+        };
+        let lf = self.map(function, OptFlags::NEW_STREAMING, None, Some("INSPECT"));
+        Ok((lf, handle))
+    }
+}
@@ -0,0 +1,94 @@
+//! Joins of the rows of which the `(start, end)` intervals relate, e.g. overlap.
+//!
+//! The relations between two intervals are two inequalities between their bounds, so the join is
+//! planned as a `join_where` on temporary bound columns, which runs as an IEJoin (a sort based
+//! inequality join) instead of a cross join and a filter.
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use crate::prelude::*;
+
+const LEFT_START: &str = "__POLARS_INTERVAL_LEFT_START";
+const LEFT_END: &str = "__POLARS_INTERVAL_LEFT_END";
+const RIGHT_START: &str = "__POLARS_INTERVAL_RIGHT_START";
+const RIGHT_END: &str = "__POLARS_INTERVAL_RIGHT_END";
+
+/// When the interval of a left row matches the interval of a right row in
+/// [`LazyFrame::join_intervals`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum IntervalJoinMode {
+    /// The intervals overlap, i.e. share more than a single point.
+    #[default]
+    Overlap,
+    /// The left interval contains the right interval.
+    Contains,
+    /// The left interval lies within the right interval.
+    Within,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct IntervalJoinOptions {
+    pub mode: IntervalJoinMode,
+    /// The minimum length of the overlap of the intervals for [`IntervalJoinMode::Overlap`], e.g.
+    /// a duration literal for temporal intervals. Touching intervals match if it is zero.
+    pub min_overlap: Option<Expr>,
+    /// Suffix of the columns of the right table of which the name is also in the left table.
+    pub suffix: Option<PlSmallStr>,
+}
+
+impl LazyFrame {
+    /// Join the rows of `self` and `other` of which the intervals relate as given by the mode of
+    /// `options`. An interval is given by its `[start, end]` expressions, which are evaluated on
+    /// its own table, and is treated as half-open, so `start` is part of it and `end` isn't.
+    ///
+    /// This is an inner join of which the output order isn't defined. Rows with a null bound
+    /// don't match.
+    pub fn join_intervals(
+        self,
+        other: LazyFrame,
+        left_on: [Expr; 2],
+        right_on: [Expr; 2],
+        options: IntervalJoinOptions,
+    ) -> LazyFrame {
+        let [left_start, left_end] = left_on;
+        let [right_start, right_end] = right_on;
+        let mut left = self.with_columns([left_start.alias(LEFT_START), left_end.alias(LEFT_END)]);
+        let mut right =
+            other.with_columns([right_start.alias(RIGHT_START), right_end.alias(RIGHT_END)]);
+
+        let predicates = match (options.mode, options.min_overlap) {
+            (IntervalJoinMode::Overlap, None) => vec![
+                col(LEFT_START).lt(col(RIGHT_END)),
+                col(LEFT_END).gt(col(RIGHT_START)),
+            ],
+            (IntervalJoinMode::Overlap, Some(min)) => {
+                // Both intervals have to be at least `min` long, and each has to end at least
+                // `min` after the other starts.
+                let long_enough = |start, end| (col(end) - col(start)).gt_eq(min.clone());
+                left = left.filter(long_enough(LEFT_START, LEFT_END));
+                right = right.filter(long_enough(RIGHT_START, RIGHT_END));
+                vec![
+                    (col(LEFT_START) + min.clone()).lt_eq(col(RIGHT_END)),
+                    (col(LEFT_END) - min).gt_eq(col(RIGHT_START)),
+                ]
+            },
+            (IntervalJoinMode::Contains, _) => vec![
+                col(LEFT_START).lt_eq(col(RIGHT_START)),
+                col(LEFT_END).gt_eq(col(RIGHT_END)),
+            ],
+            (IntervalJoinMode::Within, _) => vec![
+                col(LEFT_START).gt_eq(col(RIGHT_START)),
+                col(LEFT_END).lt_eq(col(RIGHT_END)),
+            ],
+        };
+
+        let mut builder = left.join_builder().with(right);
+        if let Some(suffix) = options.suffix {
+            builder = builder.suffix(suffix);
+        }
+        builder.join_where(predicates).drop(by_name(
+            [LEFT_START, LEFT_END, RIGHT_START, RIGHT_END],
+            true,
+        ))
+    }
+}
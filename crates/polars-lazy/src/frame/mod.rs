@@ -3,20 +3,32 @@
 mod python;
 
 mod cached_arenas;
+mod data_watch;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod explain_sample;
+mod inspect;
+#[cfg(feature = "iejoin")]
+mod interval_join;
+mod params;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod pruned_files;
 
 use std::sync::{Arc, Mutex};
 
 pub use anonymous_scan::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+pub use data_watch::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
+pub use explain_sample::*;
 pub use file_list_reader::*;
+pub use inspect::*;
+#[cfg(feature = "iejoin")]
+pub use interval_join::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
@@ -25,6 +37,7 @@ pub use ndjson::*;
 pub use parquet::*;
 use polars_compute::rolling::QuantileMethod;
 use polars_core::POOL;
+use polars_core::config::ConfigOverrides;
 use polars_core::error::feature_gated;
 use polars_core::prelude::*;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
@@ -39,8 +52,19 @@ use polars_utils::plpath::PlPath;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 
 use crate::frame::cached_arenas::CachedArena;
+use crate::frame::params::CachedPlan;
 use crate::prelude::*;
 
+/// Let the conversion to IR collect a sample of the input of the `list.to_struct` expressions that
+/// infer their number of fields from it, see [`ListToStruct::InferWidth`].
+#[cfg(feature = "list_to_struct")]
+pub(crate) fn register_list_to_struct_sampler() {
+    fn collect_sample(plan: DslPlan) -> PolarsResult<DataFrame> {
+        LazyFrame::from(plan).collect()
+    }
+    polars_plan::plans::LIST_TO_STRUCT_SAMPLER.get_or_init(|| collect_sample);
+}
+
 pub trait IntoLazy {
     fn lazy(self) -> LazyFrame;
 }
@@ -53,6 +77,8 @@ impl IntoLazy for DataFrame {
             logical_plan: lp,
             opt_state: Default::default(),
             cached_arena: Default::default(),
+            cached_plan: Default::default(),
+            config: Default::default(),
         }
     }
 }
@@ -73,6 +99,10 @@ pub struct LazyFrame {
     pub logical_plan: DslPlan,
     pub(crate) opt_state: OptFlags,
     pub(crate) cached_arena: Arc<Mutex<Option<CachedArena>>>,
+    /// The optimized plan of the last [`LazyFrame::collect_with_params`].
+    pub(crate) cached_plan: Arc<Mutex<Option<CachedPlan>>>,
+    /// The settings that override the global configuration when the query is executed.
+    pub(crate) config: Arc<ConfigOverrides>,
 }
 
 impl From<DslPlan> for LazyFrame {
@@ -81,6 +111,8 @@ impl From<DslPlan> for LazyFrame {
             logical_plan: plan,
             opt_state: OptFlags::default(),
             cached_arena: Default::default(),
+            cached_plan: Default::default(),
+            config: Default::default(),
         }
     }
 }
@@ -95,6 +127,8 @@ impl LazyFrame {
             logical_plan,
             opt_state,
             cached_arena,
+            cached_plan: Default::default(),
+            config: Default::default(),
         }
     }
 
@@ -111,6 +145,8 @@ impl LazyFrame {
             logical_plan,
             opt_state,
             cached_arena: Default::default(),
+            cached_plan: Default::default(),
+            config: Default::default(),
         }
     }
 
@@ -125,6 +161,14 @@ impl LazyFrame {
         self
     }
 
+    /// Set the settings that override the global configuration when the query is executed.
+    ///
+    /// The frames derived from this one by further operations use the global configuration again.
+    pub fn with_config(mut self, config: Arc<ConfigOverrides>) -> Self {
+        self.config = config;
+        self
+    }
+
     /// Turn off all optimizations.
     pub fn without_optimizations(self) -> Self {
         self.with_optimizations(OptFlags::from_bits_truncate(0) | OptFlags::TYPE_COERCION)
@@ -148,6 +192,18 @@ impl LazyFrame {
         self
     }
 
+    /// Toggle lookup joins optimization.
+    pub fn with_lookup_joins(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::LOOKUP_JOINS, toggle);
+        self
+    }
+
+    /// Toggle answering aggregations of Parquet scans from file statistics.
+    pub fn with_fast_statistics(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::FAST_STATISTICS, toggle);
+        self
+    }
+
     /// Check if operations are order dependent and unset maintaining_order if
     /// the order would not be observed.
     pub fn with_check_order(mut self, toggle: bool) -> Self {
@@ -234,6 +290,14 @@ impl LazyFrame {
         Ok(self.clone().to_alp_optimized()?.describe())
     }
 
+    /// Return a String describing the optimized logical plan, with the id of every node as used
+    /// by [`LazyFrame::collect_with_data_watch`].
+    ///
+    /// Returns `Err` if optimizing the logical plan fails.
+    pub fn describe_optimized_plan_with_node_ids(&self) -> PolarsResult<String> {
+        Ok(self.clone().to_alp_optimized()?.describe_with_node_ids())
+    }
+
     /// Return a String describing the optimized logical plan in tree format.
     ///
     /// Returns `Err` if optimizing the logical plan fails.
@@ -253,6 +317,59 @@ impl LazyFrame {
         }
     }
 
+    fn to_alp_maybe_optimized(&self, optimized: bool) -> PolarsResult<IRPlan> {
+        if optimized {
+            self.clone().to_alp_optimized()
+        } else {
+            self.clone().to_alp()
+        }
+    }
+
+    /// Return a hash of the logical plan, which is the same for plans that only differ in the
+    /// order of the operands of commutative operators.
+    ///
+    /// If `optimized` is `true`, hashes the optimized plan. The fingerprint is stable across
+    /// processes, but not necessarily across versions of Polars.
+    #[cfg(feature = "cse")]
+    pub fn fingerprint(&self, optimized: bool) -> PolarsResult<u64> {
+        Ok(self.to_alp_maybe_optimized(optimized)?.fingerprint())
+    }
+
+    /// Return the [`fingerprint`](Self::fingerprint) of the logical plan, or `None` if the plan
+    /// calls opaque functions, such as Python UDFs, which the fingerprint does not tell apart.
+    #[cfg(feature = "cse")]
+    pub fn exact_fingerprint(&self, optimized: bool) -> PolarsResult<Option<u64>> {
+        let mut plan = self.to_alp_maybe_optimized(optimized)?;
+        Ok((!plan.has_opaque_functions()).then(|| plan.fingerprint()))
+    }
+
+    /// Return a line based diff between the logical plans of `self` and `other`, or `None` if
+    /// they have the same [`fingerprint`](Self::fingerprint).
+    ///
+    /// Plans that only differ in details that are not shown in their description, such as the
+    /// data of in-memory frames, give a diff without changed lines.
+    #[cfg(feature = "cse")]
+    pub fn diff_plan(&self, other: &LazyFrame, optimized: bool) -> PolarsResult<Option<String>> {
+        let mut a = self.to_alp_maybe_optimized(optimized)?;
+        let mut b = other.to_alp_maybe_optimized(optimized)?;
+        if a.fingerprint() == b.fingerprint() {
+            return Ok(None);
+        }
+        Ok(Some(diff_plan_descriptions(
+            &a.describe_canonical(),
+            &b.describe_canonical(),
+        )))
+    }
+
+    /// Return the lineage of each output column: the source columns and the expressions it derives
+    /// from.
+    ///
+    /// The lineage is taken from the plan as written, before optimizations that could rewrite or
+    /// merge its expressions.
+    pub fn column_lineage(&self) -> PolarsResult<PlIndexMap<PlSmallStr, ColumnLineage>> {
+        Ok(self.clone().to_alp()?.column_lineage())
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -460,6 +577,14 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Replace NaN values in the float columns of the DataFrame with null values.
+    ///
+    /// This only changes the validity of the columns, so it is cheaper than filling the NaN values
+    /// with an arbitrary expression.
+    pub fn nan_to_null(self) -> LazyFrame {
+        self.fill_nan(lit(NULL))
+    }
+
     /// Caches the result into a new LazyFrame.
     ///
     /// This should be used to prevent computations running multiple times.
@@ -469,6 +594,14 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Restrict how common subplan elimination caches this LazyFrame when it occurs more than
+    /// once in a query.
+    ///
+    /// By default, only duplicates that are more expensive to recompute than to cache are cached.
+    pub fn cache_hint(self, hint: CacheHint) -> Self {
+        self.map_private(DslFunction::CacheHint(hint))
+    }
+
     /// Cast named frame columns, resulting in a new LazyFrame with updated dtypes
     pub fn cast(self, dtypes: PlHashMap<&str, DataType>, strict: bool) -> Self {
         let cast_cols: Vec<Expr> = dtypes
@@ -516,6 +649,8 @@ impl LazyFrame {
     }
 
     pub fn to_alp(mut self) -> PolarsResult<IRPlan> {
+        #[cfg(feature = "list_to_struct")]
+        register_list_to_struct_sampler();
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
         let node = to_alp(
             self.logical_plan,
@@ -533,6 +668,8 @@ impl LazyFrame {
         expr_arena: &mut Arena<AExpr>,
         scratch: &mut Vec<Node>,
     ) -> PolarsResult<Node> {
+        #[cfg(feature = "list_to_struct")]
+        register_list_to_struct_sampler();
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
         let new_streaming = self.opt_state.contains(OptFlags::NEW_STREAMING);
@@ -581,6 +718,7 @@ impl LazyFrame {
             Option<std::time::Duration>,
         ) -> PolarsResult<()>,
     {
+        let config = self.config.clone();
         let (mut lp_arena, mut expr_arena) = self.get_arenas();
 
         let mut scratch = vec![];
@@ -614,7 +752,7 @@ impl LazyFrame {
             BUILD_STREAMING_EXECUTOR,
         )?;
 
-        let state = ExecutionState::new();
+        let state = ExecutionState::with_config(config);
         Ok((state, physical_plan, no_file_sink))
     }
 
@@ -684,14 +822,23 @@ impl LazyFrame {
             },
             _ => {},
         }
-        let mut alp_plan = self.clone().to_alp_optimized()?;
+        let alp_plan = self.clone().to_alp_optimized()?;
+        Self::execute_alp_plan(alp_plan, engine, self.config)
+    }
 
+    /// Execute an optimized plan on `engine`.
+    fn execute_alp_plan(
+        mut alp_plan: IRPlan,
+        engine: Engine,
+        config: Arc<ConfigOverrides>,
+    ) -> PolarsResult<DataFrame> {
         match engine {
             Engine::Auto | Engine::Streaming => feature_gated!("new_streaming", {
                 let result = polars_stream::run_query(
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    config,
                 );
                 result.map(|v| v.unwrap_single())
             }),
@@ -705,7 +852,7 @@ impl LazyFrame {
                     &mut alp_plan.expr_arena,
                     BUILD_STREAMING_EXECUTOR,
                 )?;
-                let mut state = ExecutionState::new();
+                let mut state = ExecutionState::with_config(config);
                 physical_plan.execute(&mut state)
             },
         }
@@ -716,10 +863,45 @@ impl LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
             cached_arena: Default::default(),
+            cached_plan: Default::default(),
+            config: Default::default(),
         };
         sink_multiple.explain(true)
     }
 
+    /// Run multiple lazy sinks as one query, so the parts of their plans they share (e.g. the
+    /// scan of a source) are only executed once.
+    ///
+    /// Every plan must end in a file or partitioned sink.
+    pub fn sink_multiple(
+        plans: Vec<DslPlan>,
+        engine: Engine,
+        opt_state: OptFlags,
+    ) -> PolarsResult<()> {
+        for plan in &plans {
+            polars_ensure!(
+                matches!(
+                    plan,
+                    DslPlan::Sink {
+                        payload: SinkType::File { .. } | SinkType::Partition { .. },
+                        ..
+                    }
+                ),
+                InvalidOperation: "sink_multiple expects lazy file sinks, e.g. created with `sink_parquet(..., lazy=True)`"
+            );
+        }
+
+        // Default engine for sink_* is Streaming
+        let engine = match engine {
+            #[cfg(feature = "new_streaming")]
+            Engine::Auto => Engine::Streaming,
+            engine => engine,
+        };
+
+        Self::collect_all_with_engine(plans, engine, opt_state)?;
+        Ok(())
+    }
+
     pub fn collect_all_with_engine(
         plans: Vec<DslPlan>,
         mut engine: Engine,
@@ -742,6 +924,8 @@ impl LazyFrame {
             logical_plan: DslPlan::SinkMultiple { inputs: plans },
             opt_state,
             cached_arena: Default::default(),
+            cached_plan: Default::default(),
+            config: Default::default(),
         };
 
         #[cfg(feature = "new_streaming")]
@@ -769,6 +953,7 @@ impl LazyFrame {
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    Default::default(),
                 );
                 return result.map(|v| v.unwrap_multiple());
             });
@@ -844,6 +1029,16 @@ impl LazyFrame {
         self.collect_with_engine(Engine::InMemory)
     }
 
+    /// Execute the query on the streaming engine only until its first `n` rows are produced, and
+    /// return them.
+    ///
+    /// The operators stop as soon as enough rows are produced, and a group-by only computes the
+    /// final aggregates of as many groups as needed. Operators that need all of their input, such
+    /// as a sort or the aggregation of a group-by, still consume all of it.
+    pub fn peek(self, n: IdxSize) -> PolarsResult<DataFrame> {
+        self.slice(0, n).collect_with_engine(Engine::Streaming)
+    }
+
     // post_opt: A function that is called after optimization. This can be used to modify the IR jit.
     // This version does profiling of the node execution.
     pub fn _profile_post_opt<P>(self, post_opt: P) -> PolarsResult<(DataFrame, DataFrame)>
@@ -1085,6 +1280,7 @@ impl LazyFrame {
                     alp_plan.lp_top,
                     &mut alp_plan.lp_arena,
                     &mut alp_plan.expr_arena,
+                    self.config.clone(),
                 )
             };
 
@@ -1260,6 +1456,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                expand_categories: false,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1272,6 +1469,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: false,
+                expand_categories: false,
             }
         }
     }
@@ -1308,6 +1506,7 @@ impl LazyFrame {
             opt_state,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            expand_categories: false,
             dynamic_options: None,
             rolling_options: Some(options),
         }
@@ -1353,6 +1552,7 @@ impl LazyFrame {
             opt_state,
             keys: group_by.as_ref().to_vec(),
             maintain_order: true,
+            expand_categories: false,
             dynamic_options: Some(options),
             rolling_options: None,
         }
@@ -1374,6 +1574,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                expand_categories: false,
                 dynamic_options: None,
                 rolling_options: None,
             }
@@ -1386,6 +1587,7 @@ impl LazyFrame {
                 opt_state,
                 keys,
                 maintain_order: true,
+                expand_categories: false,
             }
         }
     }
@@ -1575,6 +1777,7 @@ impl LazyFrame {
             suffix,
             slice,
             nulls_equal,
+            nans_equal,
             coalesce,
             maintain_order,
         } = args;
@@ -1591,6 +1794,7 @@ impl LazyFrame {
             .how(how)
             .validate(validation)
             .join_nulls(nulls_equal)
+            .join_nans(nans_equal)
             .coalesce(coalesce)
             .maintain_order(maintain_order);
 
@@ -1683,6 +1887,66 @@ impl LazyFrame {
         )
     }
 
+    /// Replace the values of the rows where `mask` is true with the values of `assignments`,
+    /// and keep the values of the other rows.
+    ///
+    /// Every assignment updates the column of its output name, e.g. `col("a") * lit(2)` updates
+    /// `"a"` and `lit(0).alias("b")` updates `"b"`. This is the same as adding
+    /// `when(mask).then(assignment).otherwise(col(name))` for every assignment, where chunks
+    /// that the mask does not touch are reused without copying them.
+    pub fn update_where<E: AsRef<[Expr]>>(
+        self,
+        mask: Expr,
+        assignments: E,
+    ) -> PolarsResult<LazyFrame> {
+        let exprs = assignments
+            .as_ref()
+            .iter()
+            .map(|assignment| {
+                let name = expr_output_name(assignment)?;
+                Ok(when(mask.clone())
+                    .then(assignment.clone())
+                    .otherwise(col(name.clone()))
+                    .alias(name))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(self.with_columns(exprs))
+    }
+
+    /// Add the features of `feature_set` as columns, see [`FeatureSet`].
+    ///
+    /// The features are added in one [`LazyFrame::with_columns`] per stage of
+    /// [`FeatureSet::stages`]. This fails if a column that the features read is not in the frame,
+    /// or if a feature does not evaluate to the dtype it declares.
+    pub fn with_feature_set(mut self, feature_set: &FeatureSet) -> PolarsResult<LazyFrame> {
+        let schema = self.collect_schema()?;
+        for name in feature_set.input_columns() {
+            polars_ensure!(
+                schema.contains(&name),
+                ColumnNotFound: "column '{}' of feature set '{}' not found in the frame",
+                name, feature_set.name
+            );
+        }
+
+        let mut lf = self;
+        for stage in feature_set.stages()? {
+            lf = lf.with_columns(stage);
+        }
+
+        let schema = lf.collect_schema()?;
+        for feature in feature_set.features() {
+            if let Some(dtype) = &feature.dtype {
+                let out = schema.try_get(&feature.name)?;
+                polars_ensure!(
+                    out == dtype,
+                    SchemaMismatch: "feature '{}' of feature set '{}' has dtype {}, expected {}",
+                    feature.name, feature_set.name, out, dtype
+                );
+            }
+        }
+        Ok(lf)
+    }
+
     /// Match or evolve to a certain schema.
     pub fn match_to_schema(
         self,
@@ -1715,6 +1979,15 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Use the value of this `LazyFrame` as an expression in [`LazyFrame::with_columns`], where
+    /// it is broadcast to the height of the frame. The `LazyFrame` must produce a single column
+    /// with at most one row; no rows give a null.
+    ///
+    /// The `LazyFrame` is part of the query it is used in, so it runs once per collect.
+    pub fn into_scalar_expr(self) -> Expr {
+        Expr::SubPlan(SpecialEq::new(Arc::new(self.logical_plan)), vec![])
+    }
+
     /// Aggregate all the columns as their maximum values.
     ///
     /// Aggregated columns will have the same names as the original columns.
@@ -1798,15 +2071,27 @@ impl LazyFrame {
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
     pub fn explode(self, columns: Selector) -> LazyFrame {
-        self.explode_impl(columns, false)
+        self.explode_impl(columns, false, None)
+    }
+
+    /// Apply explode operation, adding the index of the original row of every exploded row as
+    /// the first column named `index_name`.
+    /// [See eager explode](polars_core::frame::DataFrame::explode_with_index).
+    pub fn explode_with_index(self, columns: Selector, index_name: PlSmallStr) -> LazyFrame {
+        self.explode_impl(columns, false, Some(index_name))
     }
 
     /// Apply explode operation. [See eager explode](polars_core::frame::DataFrame::explode).
-    fn explode_impl(self, columns: Selector, allow_empty: bool) -> LazyFrame {
+    fn explode_impl(
+        self,
+        columns: Selector,
+        allow_empty: bool,
+        index_name: Option<PlSmallStr>,
+    ) -> LazyFrame {
         let opt_state = self.get_opt_state();
         let lp = self
             .get_plan_builder()
-            .explode(columns, allow_empty)
+            .explode(columns, allow_empty, index_name)
             .build();
         Self::from_logical_plan(lp, opt_state)
     }
@@ -1869,6 +2154,20 @@ impl LazyFrame {
         Self::from_logical_plan(lp, opt_state)
     }
 
+    /// Count the number of unique rows, or the number of unique combinations of the columns in
+    /// `subset`, into a single `n_unique` column.
+    ///
+    /// The rows are made unique by a group-by on all selected columns, so this runs on the
+    /// streaming engine without combining the columns into a struct.
+    pub fn n_unique(self, subset: Option<Selector>) -> LazyFrame {
+        let lf = match subset {
+            Some(subset) => self.select([subset.as_expr()]),
+            None => self,
+        };
+        lf.unique_generic(None, UniqueKeepStrategy::Any)
+            .select([len().alias(PlSmallStr::from_static("n_unique"))])
+    }
+
     /// Drop rows containing one or more NaN values.
     ///
     /// `subset` is an optional `Vec` of column names to consider for NaNs; if None, all
@@ -2044,6 +2343,43 @@ impl LazyFrame {
         }
     }
 
+    /// Add a new column at index 0 that counts the rows with a 64-bit integer.
+    ///
+    /// The index starts at `start` and increments by `step`, which may be negative. `dtype`
+    /// must be [`DataType::Int64`] or [`DataType::UInt64`].
+    ///
+    /// The index is computed from a regular row index, so this can be pushed into scans and runs
+    /// on the streaming engine. The index is global: it continues across all morsels and files
+    /// rather than restarting per partition.
+    pub fn with_row_index_stepped<S>(
+        self,
+        name: S,
+        start: i64,
+        step: i64,
+        dtype: DataType,
+    ) -> PolarsResult<LazyFrame>
+    where
+        S: Into<PlSmallStr>,
+    {
+        polars_ensure!(step != 0, InvalidOperation: "row index `step` must not be zero");
+        polars_ensure!(
+            matches!(dtype, DataType::Int64 | DataType::UInt64),
+            InvalidOperation: "row index must be of type Int64 or UInt64, got {}", dtype
+        );
+        let name = name.into();
+
+        // Compute in 128 bits so that, like the eager version, values that do not fit in `dtype`
+        // raise an error in the strict cast instead of wrapping around.
+        #[cfg(feature = "dtype-i128")]
+        let wide = DataType::Int128;
+        #[cfg(not(feature = "dtype-i128"))]
+        let wide = DataType::Int64;
+        let index = (col(name.clone()).cast(wide) * lit(step) + lit(start))
+            .strict_cast(dtype)
+            .alias(name.clone());
+        Ok(self.with_row_index(name, None).with_column(index))
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
@@ -2079,6 +2415,7 @@ pub struct LazyGroupBy {
     opt_state: OptFlags,
     keys: Vec<Expr>,
     maintain_order: bool,
+    expand_categories: bool,
     #[cfg(feature = "dynamic_group_by")]
     dynamic_options: Option<DynamicGroupOptions>,
     #[cfg(feature = "dynamic_group_by")]
@@ -2091,11 +2428,25 @@ impl From<LazyGroupBy> for LazyFrame {
             logical_plan: lgb.logical_plan,
             opt_state: lgb.opt_state,
             cached_arena: Default::default(),
+            cached_plan: Default::default(),
+            config: Default::default(),
         }
     }
 }
 
 impl LazyGroupBy {
+    /// Also create a group for every category of the `Enum` keys that does not occur in the
+    /// data, similar to `observed=False` in pandas.
+    ///
+    /// Aggregations over these groups see no rows, e.g. `len` is zero and `mean` is null. With
+    /// multiple keys, a group is created for every combination of the categories and the
+    /// observed values of the other keys. `Categorical` keys only contribute their observed
+    /// values, as their categories are shared with unrelated data.
+    pub fn expand_categories(mut self, toggle: bool) -> Self {
+        self.expand_categories = toggle;
+        self
+    }
+
     /// Group by and aggregate.
     ///
     /// Select a column with [col] and choose an aggregation.
@@ -2125,6 +2476,7 @@ impl LazyGroupBy {
                 aggs,
                 None,
                 self.maintain_order,
+                self.expand_categories,
                 self.dynamic_options,
                 self.rolling_options,
             )
@@ -2132,7 +2484,13 @@ impl LazyGroupBy {
 
         #[cfg(not(feature = "dynamic_group_by"))]
         let lp = DslBuilder::from(self.logical_plan)
-            .group_by(self.keys, aggs, None, self.maintain_order)
+            .group_by(
+                self.keys,
+                aggs,
+                None,
+                self.maintain_order,
+                self.expand_categories,
+            )
             .build();
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
@@ -2145,8 +2503,11 @@ impl LazyGroupBy {
             .filter_map(|expr| expr_output_name(expr).ok())
             .collect::<Vec<_>>();
 
-        self.agg([all().as_expr().head(n).explode()])
-            .explode_impl(all() - by_name(keys.iter().cloned(), false), true)
+        self.agg([all().as_expr().head(n).explode()]).explode_impl(
+            all() - by_name(keys.iter().cloned(), false),
+            true,
+            None,
+        )
     }
 
     /// Return last n rows of each group
@@ -2157,8 +2518,11 @@ impl LazyGroupBy {
             .filter_map(|expr| expr_output_name(expr).ok())
             .collect::<Vec<_>>();
 
-        self.agg([all().as_expr().tail(n).explode()])
-            .explode_impl(all() - by_name(keys.iter().cloned(), false), true)
+        self.agg([all().as_expr().tail(n).explode()]).explode_impl(
+            all() - by_name(keys.iter().cloned(), false),
+            true,
+            None,
+        )
     }
 
     /// Apply a function over the groups as a new DataFrame.
@@ -2174,10 +2538,14 @@ impl LazyGroupBy {
             dynamic: self.dynamic_options,
             rolling: self.rolling_options,
             slice: None,
+            expand_categories: self.expand_categories,
         };
 
         #[cfg(not(feature = "dynamic_group_by"))]
-        let options = GroupbyOptions { slice: None };
+        let options = GroupbyOptions {
+            slice: None,
+            expand_categories: self.expand_categories,
+        };
 
         let lp = DslPlan::GroupBy {
             input: Arc::new(self.logical_plan),
@@ -2203,8 +2571,10 @@ pub struct JoinBuilder {
     suffix: Option<PlSmallStr>,
     validation: JoinValidation,
     nulls_equal: bool,
+    nans_equal: Option<bool>,
     coalesce: JoinCoalesce,
     maintain_order: MaintainOrderJoin,
+    indicator: Option<PlSmallStr>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -2220,8 +2590,10 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             nulls_equal: false,
+            nans_equal: None,
             coalesce: Default::default(),
             maintain_order: Default::default(),
+            indicator: None,
         }
     }
 
@@ -2286,6 +2658,13 @@ impl JoinBuilder {
         self
     }
 
+    /// Whether NaN values match each other. `None` uses the default of the
+    /// `POLARS_JOIN_NANS_EQUAL` environment variable, under which they do.
+    pub fn join_nans(mut self, nans_equal: Option<bool>) -> Self {
+        self.nans_equal = nans_equal;
+        self
+    }
+
     /// Suffix to add duplicate column names in join.
     /// Defaults to `"_right"` if this method is never called.
     pub fn suffix<S>(mut self, suffix: S) -> Self
@@ -2308,10 +2687,48 @@ impl JoinBuilder {
         self
     }
 
+    /// Add a column with this name that tells whether each row has a match in the left table
+    /// (`"left_only"`), in the right table (`"right_only"`) or in both (`"both"`).
+    ///
+    /// The column is `Categorical` if the `dtype-categorical` feature is enabled, and `String`
+    /// otherwise. A semi join only outputs `"both"` rows and an anti join only `"left_only"` rows.
+    pub fn indicator<S>(mut self, name: S) -> Self
+    where
+        S: Into<PlSmallStr>,
+    {
+        self.indicator = Some(name.into());
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let opt_state = self.lf.opt_state;
-        let other = self.other.expect("'with' not set in join builder");
+        let mut lf = self.lf;
+        let mut other = self.other.expect("'with' not set in join builder");
+
+        // Mark the rows of both tables, so that a missing mark tells which side had no match.
+        const LEFT_MARKER: &str = "__POLARS_JOIN_INDICATOR_LEFT";
+        const RIGHT_MARKER: &str = "__POLARS_JOIN_INDICATOR_RIGHT";
+        let indicator = self.indicator.map(|name| {
+            let indicator = match &self.how {
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Semi => lit("both"),
+                #[cfg(feature = "semi_anti_join")]
+                JoinType::Anti => lit("left_only"),
+                _ => {
+                    lf = lf.with_column(lit(true).alias(LEFT_MARKER));
+                    other = other.with_column(lit(true).alias(RIGHT_MARKER));
+                    when(col(LEFT_MARKER).is_null())
+                        .then(lit("right_only"))
+                        .when(col(RIGHT_MARKER).is_null())
+                        .then(lit("left_only"))
+                        .otherwise(lit("both"))
+                },
+            };
+            #[cfg(feature = "dtype-categorical")]
+            let indicator = indicator.cast(DataType::from_categories(Categories::global()));
+            indicator.alias(name)
+        });
 
         let args = JoinArgs {
             how: self.how,
@@ -2319,12 +2736,12 @@ impl JoinBuilder {
             suffix: self.suffix,
             slice: None,
             nulls_equal: self.nulls_equal,
+            nans_equal: self.nans_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
         };
 
-        let lp = self
-            .lf
+        let lp = lf
             .get_plan_builder()
             .join(
                 other.logical_plan,
@@ -2338,7 +2755,13 @@ impl JoinBuilder {
                 .into(),
             )
             .build();
-        LazyFrame::from_logical_plan(lp, opt_state)
+        let lf = LazyFrame::from_logical_plan(lp, opt_state);
+        match indicator {
+            None => lf,
+            Some(indicator) => lf
+                .with_column(indicator)
+                .drop(by_name([LEFT_MARKER, RIGHT_MARKER], false)),
+        }
     }
 
     // Finish with join predicates
@@ -2410,6 +2833,7 @@ impl JoinBuilder {
             suffix: self.suffix,
             slice: None,
             nulls_equal: self.nulls_equal,
+            nans_equal: self.nans_equal,
             coalesce: self.coalesce,
             maintain_order: self.maintain_order,
         };
@@ -2479,7 +2903,9 @@ mod streaming_dispatch {
             }),
         };
 
-        polars_stream::StreamingQuery::build(node, ir_arena, expr_arena)
+        // The physical plan is built before the state of the query exists, so the parts of an
+        // in-memory query that run on the streaming engine use the global configuration.
+        polars_stream::StreamingQuery::build(node, ir_arena, expr_arena, Default::default())
             .map(Some)
             .map(Mutex::new)
             .map(Arc::new)
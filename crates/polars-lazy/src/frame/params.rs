@@ -0,0 +1,102 @@
+//! Execution of queries with placeholders, see `placeholder`.
+use polars_core::chunked_array::cast::CastOptions;
+
+use super::*;
+
+/// The optimized plan of a query, so that it can be executed with other values for its
+/// placeholders without converting and optimizing it again.
+pub(crate) struct CachedPlan {
+    opt_state: OptFlags,
+    lp_top: Node,
+    lp_arena: Arena<IR>,
+    expr_arena: Arena<AExpr>,
+}
+
+/// Replace every placeholder in `expr_arena` by the literal with its value in `params`.
+fn bind_placeholders(
+    expr_arena: &mut Arena<AExpr>,
+    params: &PlHashMap<PlSmallStr, Scalar>,
+) -> PolarsResult<()> {
+    let mut bound = PlHashSet::new();
+    for node in (0..expr_arena.len()).map(Node) {
+        let AExpr::Function {
+            input,
+            function: IRFunctionExpr::Placeholder { name },
+            ..
+        } = expr_arena.get(node)
+        else {
+            continue;
+        };
+        // The input of a placeholder is a null literal of its type.
+        let AExpr::Literal(LiteralValue::Scalar(null)) = expr_arena.get(input[0].node()) else {
+            unreachable!()
+        };
+        let Some(value) = params.get(name) else {
+            polars_bail!(InvalidOperation: "no value given for placeholder '{name}'")
+        };
+        let value = value
+            .clone()
+            .cast_with_options(null.dtype(), CastOptions::Strict)?;
+        bound.insert(name.clone());
+        expr_arena.replace(node, AExpr::Literal(LiteralValue::Scalar(value)));
+    }
+
+    if let Some(name) = params.keys().find(|name| !bound.contains(*name)) {
+        polars_bail!(InvalidOperation: "the query has no placeholder '{name}'")
+    }
+    Ok(())
+}
+
+impl LazyFrame {
+    /// Execute the query with its placeholders bound to the values in `params`, and collect the
+    /// result into a [`DataFrame`] using `engine`.
+    ///
+    /// The optimized plan is cached, collecting this `LazyFrame` again with other values does not
+    /// convert and optimize the query again. Every placeholder needs a value, and every value in
+    /// `params` is cast to the type of its placeholder.
+    pub fn collect_with_params(
+        mut self,
+        engine: Engine,
+        params: &PlHashMap<PlSmallStr, Scalar>,
+    ) -> PolarsResult<DataFrame> {
+        // Only the streaming engine is run as such, the others run in-memory like `collect`.
+        let engine = match engine {
+            Engine::Streaming => {
+                feature_gated!("new_streaming", self = self.with_new_streaming(true));
+                Engine::Streaming
+            },
+            _ => Engine::InMemory,
+        };
+
+        let mut cached = self.cached_plan.lock().unwrap();
+        if !cached
+            .as_ref()
+            .is_some_and(|plan| plan.opt_state == self.opt_state)
+        {
+            let mut lf = self.clone();
+            if !matches!(lf.logical_plan, DslPlan::Sink { .. }) {
+                lf.logical_plan = DslPlan::Sink {
+                    input: Arc::new(lf.logical_plan),
+                    payload: SinkType::Memory,
+                };
+            }
+            let plan = lf.to_alp_optimized()?;
+            *cached = Some(CachedPlan {
+                opt_state: self.opt_state,
+                lp_top: plan.lp_top,
+                lp_arena: plan.lp_arena,
+                expr_arena: plan.expr_arena,
+            });
+        }
+        let cached_plan = cached.as_ref().unwrap();
+        let mut plan = IRPlan::new(
+            cached_plan.lp_top,
+            cached_plan.lp_arena.clone(),
+            cached_plan.expr_arena.clone(),
+        );
+        drop(cached);
+
+        bind_placeholders(&mut plan.expr_arena, params)?;
+        Self::execute_alp_plan(plan, engine, self.config.clone())
+    }
+}
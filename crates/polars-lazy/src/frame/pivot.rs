@@ -7,10 +7,15 @@
 //! We can do a pivot on an eager `DataFrame` as that is already materialized. The code for the
 //! pivot is here, because we want to be able to pass expressions to the pivot operation.
 //!
+//! The exception is a pivot on an `Enum` column: its categories, and thus the output schema, are
+//! known up front. That pivot is a lazy group-by, see [`LazyFrame::pivot_enum`].
+//!
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_ops::pivot::PivotAgg;
+#[cfg(feature = "dtype-categorical")]
+use polars_utils::format_pl_smallstr;
 
 use crate::physical_plan::exotic::{contains_column_refs, prepare_expression_for_context};
 use crate::prelude::*;
@@ -98,3 +103,81 @@ where
     let agg_expr = agg_expr.map(|ae| PivotAgg(Arc::new(PivotExpr(ae))));
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+#[cfg(feature = "dtype-categorical")]
+impl LazyFrame {
+    /// Pivot on the `Enum` column `on`, with an output column per category and `values` column.
+    ///
+    /// As the categories are known up front, this is a group-by on `index` with an aggregation
+    /// per output column, which runs on the streaming engine if `maintain_order` is not set.
+    /// `agg_expr` is the aggregation, which accesses the values of a category through
+    /// `pl.element()`. If `index` or `values` is not given, all remaining columns are used.
+    pub fn pivot_enum(
+        mut self,
+        on: PlSmallStr,
+        index: Option<Vec<PlSmallStr>>,
+        values: Option<Vec<PlSmallStr>>,
+        agg_expr: Expr,
+        maintain_order: bool,
+        // used as separator/delimiter in generated column names.
+        separator: Option<&str>,
+    ) -> PolarsResult<LazyFrame> {
+        if contains_column_refs(&agg_expr) {
+            polars_bail!(InvalidOperation: "explicit column references are not allowed in aggregate_function");
+        }
+
+        let schema = self.collect_schema()?;
+        let DataType::Enum(categories, _) = schema.try_get(&on)? else {
+            polars_bail!(
+                InvalidOperation: "lazy `pivot` requires the `on` column to be an Enum, got {}",
+                schema.try_get(&on)?
+            )
+        };
+        let remaining = |exclude: &[PlSmallStr]| -> Vec<PlSmallStr> {
+            schema
+                .iter_names()
+                .filter(|name| *name != &on && !exclude.contains(name))
+                .cloned()
+                .collect()
+        };
+        let (index, values) = match (index, values) {
+            (Some(index), Some(values)) => (index, values),
+            (Some(index), None) => {
+                let values = remaining(&index);
+                (index, values)
+            },
+            (None, Some(values)) => (remaining(&values), values),
+            (None, None) => {
+                polars_bail!(InvalidOperation: "`pivot` needs either `index` or `values` to be set")
+            },
+        };
+        polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
+
+        let sep = separator.unwrap_or("_");
+        let mut aggs = Vec::with_capacity(values.len() * categories.categories().len());
+        for value in &values {
+            for category in categories.categories().values_iter() {
+                let element = col(value.clone()).filter(col(on.clone()).eq(lit(category)));
+                let name = if values.len() > 1 {
+                    format_pl_smallstr!("{value}{sep}{category}")
+                } else {
+                    category.into()
+                };
+                let agg = agg_expr.clone().map_expr(|e| match e {
+                    Expr::Column(name) if name.is_empty() => element.clone(),
+                    Expr::Len => element.clone().len(),
+                    e => e,
+                });
+                aggs.push(agg.alias(name));
+            }
+        }
+
+        let keys: Vec<Expr> = index.into_iter().map(col).collect();
+        let group_by = if maintain_order {
+            self.group_by_stable(keys)
+        } else {
+            self.group_by(keys)
+        };
+        Ok(group_by.agg(aggs))
+    }
+}
@@ -0,0 +1,116 @@
+//! The files that the scan of a query reads after they are pruned with its predicates, see
+//! [`LazyFrame::pruned_files`].
+use arrow::bitmap::Bitmap;
+use polars_mem_engine::create_scan_predicate;
+
+use super::*;
+
+/// Get the size of a source in bytes, if it can be known without reading it.
+fn source_bytes(source: ScanSourceRef<'_>) -> Option<u64> {
+    match source {
+        ScanSourceRef::Path(path) => path
+            .as_local_path()
+            .and_then(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len()),
+        ScanSourceRef::File(file) => file.metadata().ok().map(|metadata| metadata.len()),
+        ScanSourceRef::Buffer(buffer) => Some(buffer.len() as u64),
+    }
+}
+
+impl LazyFrame {
+    /// Get the files that the scan of the query reads after they are pruned with the predicates
+    /// that are pushed down to it, without executing the query.
+    ///
+    /// Files are pruned on their hive partitions and on the table statistics of the scan, in the
+    /// same way as when the query runs. Statistics within the files, such as those of Parquet row
+    /// groups, are not read. Returns a frame with a row per file that is read, with its `path`,
+    /// its `estimated_rows` before the predicates are applied and its `estimated_bytes`, which
+    /// are null if they are not known without reading the file.
+    pub fn pruned_files(self) -> PolarsResult<DataFrame> {
+        let mut lp_arena = Arena::with_capacity(16);
+        let mut expr_arena = Arena::with_capacity(16);
+        let root = self.optimize(&mut lp_arena, &mut expr_arena)?;
+
+        let scans: Vec<Node> = lp_arena
+            .iter(root)
+            .filter(|(_, ir)| matches!(ir, IR::Scan { .. }))
+            .map(|(node, _)| node)
+            .collect();
+        polars_ensure!(
+            scans.len() == 1,
+            InvalidOperation: "`pruned_files` requires a query with exactly one file scan, got {}",
+            scans.len()
+        );
+        let IR::Scan {
+            sources,
+            file_info,
+            hive_parts,
+            predicate,
+            unified_scan_args,
+            ..
+        } = lp_arena.get(scans[0])
+        else {
+            unreachable!()
+        };
+
+        let mut skip = Bitmap::new_zeroed(sources.len());
+        if let Some(predicate) = predicate {
+            let hive_df = hive_parts.as_ref().map(|hp| hp.df());
+            let predicate = create_scan_predicate(
+                predicate,
+                &mut expr_arena,
+                &file_info.schema,
+                hive_df.map(|df| df.schema().as_ref()),
+                &mut ExpressionConversionState::new(false),
+                true,  // create_skip_batch_predicate
+                false, // create_column_predicates
+            )?
+            .to_io(None, file_info.schema.clone());
+
+            if let Some(hive_df) = hive_df
+                && let Some(mask) = predicate.skip_files_from_hive(hive_df)?
+            {
+                skip = &skip | &mask;
+            }
+            let hive_is_full_predicate =
+                hive_df.is_some() && predicate.hive_predicate_is_full_predicate;
+            if let Some(table_statistics) = &unified_scan_args.table_statistics
+                && !hive_is_full_predicate
+                && let Some(mask) =
+                    predicate.skip_files_from_statistics(table_statistics.0.as_ref())?
+            {
+                skip = &skip | &mask;
+            }
+        }
+
+        let rows: Vec<Option<u64>> = match &unified_scan_args.table_statistics {
+            Some(table_statistics) => table_statistics
+                .0
+                .column("len")?
+                .strict_cast(&DataType::UInt64)?
+                .u64()?
+                .iter()
+                .collect(),
+            None if sources.len() == 1 => vec![file_info.row_estimation.0.map(|n| n as u64)],
+            None => vec![None; sources.len()],
+        };
+
+        let mut paths = Vec::new();
+        let mut estimated_rows = Vec::new();
+        let mut estimated_bytes = Vec::new();
+        for (i, source) in sources.iter().enumerate() {
+            if skip.get_bit(i) {
+                continue;
+            }
+            paths.push(source.to_include_path_name());
+            estimated_rows.push(rows[i]);
+            estimated_bytes.push(source_bytes(source));
+        }
+
+        DataFrame::new(vec![
+            Column::new(PlSmallStr::from_static("path"), paths),
+            Column::new(PlSmallStr::from_static("estimated_rows"), estimated_rows),
+            Column::new(PlSmallStr::from_static("estimated_bytes"), estimated_bytes),
+        ])
+    }
+}
@@ -13,7 +13,9 @@ pub use polars_ops::prelude::{RankMethod, RankOptions};
 #[cfg(feature = "polars_cloud_client")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::dsl::AnonymousScanOptions;
-pub use polars_plan::plans::{AnonymousScan, AnonymousScanArgs, Literal, LiteralValue, NULL, Null};
+pub use polars_plan::plans::{
+    AnonymousScan, AnonymousScanArgs, CacheHint, Literal, LiteralValue, NULL, Null,
+};
 pub(crate) use polars_plan::prelude::*;
 pub use polars_plan::prelude::{PlanCallback, UnionArgs};
 #[cfg(feature = "rolling_window_by")]
@@ -58,6 +58,8 @@ impl LazyFrame {
                 include_file_paths: None,
                 column_mapping: None,
                 deletion_files: None,
+                table_statistics: None,
+                file_error_policy: FileErrorPolicy::default(),
             },
         )?
         .build()
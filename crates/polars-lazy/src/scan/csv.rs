@@ -4,7 +4,7 @@ use polars_io::csv::read::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
 };
 use polars_io::path_utils::expand_paths;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::maybe_decompress_archive_bytes;
 use polars_io::utils::get_reader_bytes;
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::mmap::MemSlice;
@@ -214,6 +214,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Only read the archive members whose path matches this glob pattern if a file is a zip
+    /// or (compressed) tar archive. All members are read by default.
+    #[must_use]
+    pub fn with_archive_member_glob(mut self, archive_member_glob: Option<PlSmallStr>) -> Self {
+        self.read_options.archive_member_glob = archive_member_glob;
+        self
+    }
+
     /// Truncate lines that are longer than the schema.
     #[must_use]
     pub fn with_truncate_ragged_lines(self, truncate_ragged_lines: bool) -> Self {
@@ -252,7 +260,11 @@ impl LazyCsvReader {
             let parse_options = self.read_options.get_parse_options();
 
             let mut owned = vec![];
-            let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let bytes = maybe_decompress_archive_bytes(
+                bytes.as_ref(),
+                &mut owned,
+                &self.read_options.get_archive_options(),
+            )?;
 
             PolarsResult::Ok(
                 infer_file_schema(
@@ -346,6 +358,8 @@ impl LazyFileListReader for LazyCsvReader {
                 include_file_paths: self.include_file_paths,
                 column_mapping: None,
                 deletion_files: None,
+                table_statistics: None,
+                file_error_policy: FileErrorPolicy::default(),
             },
         )?
         .build()
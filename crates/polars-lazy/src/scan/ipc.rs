@@ -80,6 +80,8 @@ impl LazyFileListReader for LazyIpcReader {
                 include_file_paths,
                 column_mapping: None,
                 deletion_files: None,
+                table_statistics: None,
+                file_error_policy: FileErrorPolicy::default(),
             },
         )?
         .build()
@@ -5,7 +5,8 @@ use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::{HiveOptions, RowIndex};
 use polars_plan::dsl::{
-    CastColumnsPolicy, DslPlan, ExtraColumnsPolicy, FileScanDsl, MissingColumnsPolicy, ScanSources,
+    CastColumnsPolicy, DslPlan, ExtraColumnsPolicy, FileErrorPolicy, FileScanDsl,
+    MissingColumnsPolicy, ScanSources,
 };
 use polars_plan::prelude::{NDJsonReadOptions, UnifiedScanArgs};
 use polars_utils::plpath::PlPath;
@@ -28,6 +29,9 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) archive_member_glob: Option<PlSmallStr>,
+    pub(crate) json_schema: Option<PlSmallStr>,
+    pub(crate) json_schema_rejects: Option<PlPath>,
 }
 
 impl LazyJsonLineReader {
@@ -49,6 +53,9 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            archive_member_glob: None,
+            json_schema: None,
+            json_schema_rejects: None,
         }
     }
 
@@ -69,6 +76,28 @@ impl LazyJsonLineReader {
         self.ignore_errors = ignore_errors;
         self
     }
+
+    /// Only read the archive members whose path matches this glob pattern if a file is a zip
+    /// or (compressed) tar archive. All members are read by default.
+    #[must_use]
+    pub fn with_archive_member_glob(mut self, archive_member_glob: Option<PlSmallStr>) -> Self {
+        self.archive_member_glob = archive_member_glob;
+        self
+    }
+
+    /// Validate every row against this JSON Schema document. Rows that don't match raise an
+    /// error, unless `rejects` is set, in which case they are appended to that local file.
+    #[must_use]
+    pub fn with_json_schema(
+        mut self,
+        json_schema: Option<PlSmallStr>,
+        rejects: Option<PlPath>,
+    ) -> Self {
+        self.json_schema = json_schema;
+        self.json_schema_rejects = rejects;
+        self
+    }
+
     /// Try to stop parsing when `n` rows are parsed. During multithreaded parsing the upper bound `n` cannot
     /// be guaranteed.
     #[must_use]
@@ -141,6 +170,8 @@ impl LazyFileListReader for LazyJsonLineReader {
             include_file_paths: self.include_file_paths,
             column_mapping: None,
             deletion_files: None,
+            table_statistics: None,
+            file_error_policy: FileErrorPolicy::default(),
         };
 
         let options = NDJsonReadOptions {
@@ -151,6 +182,9 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            archive_member_glob: self.archive_member_glob,
+            json_schema: self.json_schema,
+            json_schema_rejects: self.json_schema_rejects,
         };
 
         let scan_type = Box::new(FileScanDsl::NDJson { options });
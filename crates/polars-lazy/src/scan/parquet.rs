@@ -3,6 +3,7 @@ use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParallelStrategy;
 use polars_io::prelude::ParquetOptions;
 use polars_io::{HiveOptions, RowIndex};
+use polars_plan::dsl::table_statistics::TableStatistics;
 use polars_utils::plpath::PlPath;
 use polars_utils::slice_enum::Slice;
 
@@ -17,6 +18,8 @@ pub struct ScanArgsParquet {
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
     pub schema: Option<SchemaRef>,
+    /// Data types to cast some of the columns to while they are decoded.
+    pub schema_overrides: Option<SchemaRef>,
     pub low_memory: bool,
     pub rechunk: bool,
     pub cache: bool,
@@ -24,6 +27,9 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Pre-computed statistics of the files, see [`TableStatistics::from_catalog_df`].
+    pub table_statistics: Option<TableStatistics>,
+    pub file_error_policy: FileErrorPolicy,
 }
 
 impl Default for ScanArgsParquet {
@@ -36,12 +42,15 @@ impl Default for ScanArgsParquet {
             hive_options: Default::default(),
             use_statistics: true,
             schema: None,
+            schema_overrides: None,
             rechunk: false,
             low_memory: false,
             cache: true,
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            table_statistics: None,
+            file_error_policy: FileErrorPolicy::default(),
         }
     }
 }
@@ -66,6 +75,7 @@ impl LazyFileListReader for LazyParquetReader {
     fn finish(self) -> PolarsResult<LazyFrame> {
         let parquet_options = ParquetOptions {
             schema: self.args.schema,
+            schema_overrides: self.args.schema_overrides,
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
@@ -95,6 +105,8 @@ impl LazyFileListReader for LazyParquetReader {
             include_file_paths: self.args.include_file_paths,
             column_mapping: None,
             deletion_files: None,
+            table_statistics: self.args.table_statistics,
+            file_error_policy: self.args.file_error_policy,
         };
 
         let mut lf: LazyFrame =
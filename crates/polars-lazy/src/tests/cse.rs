@@ -128,13 +128,15 @@ fn test_cse_union2_4925() -> PolarsResult<()> {
         "sym" => ["a"],
         "c" => [true],
     ]?
-    .lazy();
+    .lazy()
+    .cache_hint(CacheHint::Always);
 
     let lf2 = df![
         "ts" => [1],
         "d" => [3],
     ]?
-    .lazy();
+    .lazy()
+    .cache_hint(CacheHint::Always);
 
     let args = UnionArgs {
         parallel: false,
@@ -192,7 +194,8 @@ fn test_cse_joins_4954() -> PolarsResult<()> {
     let z = df![
         "a"=> [1],
     ]?
-    .lazy();
+    .lazy()
+    .cache_hint(CacheHint::Always);
 
     let a = x.left_join(z.clone(), col("a"), col("a"));
     let b = y.left_join(z, col("a"), col("a"));
@@ -355,3 +358,47 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cse_cache_hints() -> PolarsResult<()> {
+    let args = UnionArgs {
+        parallel: false,
+        rechunk: false,
+        ..Default::default()
+    };
+
+    // Scanning a DataFrame is cheaper than caching it.
+    let lf = df!["a" => [1, 2, 3]]?.lazy();
+    let q = concat(&[lf.clone(), lf.clone()], args)?;
+    assert_eq!(count_caches(q), 0);
+
+    let lf = lf.cache_hint(CacheHint::Always);
+    let q = concat(&[lf.clone(), lf], args)?;
+    assert_eq!(count_caches(q.clone()), 2);
+
+    // The hints are removed from the optimized plan.
+    let IRPlan {
+        lp_top, lp_arena, ..
+    } = q.to_alp_optimized()?;
+    assert!(lp_arena.iter(lp_top).all(|(_, lp)| !matches!(
+        lp,
+        IR::MapFunction {
+            function: FunctionIR::CacheHint(_),
+            ..
+        }
+    )));
+
+    let lf = scan_foods_ipc().with_column(col("category").str().to_uppercase());
+    let q = concat(&[lf.clone(), lf.clone()], args)?;
+    assert_eq!(count_caches(q), 2);
+
+    let lf = lf.cache_hint(CacheHint::Never);
+    let q = concat(&[lf.clone(), lf], args)?;
+    assert_eq!(count_caches(q.clone()), 0);
+    assert_eq!(
+        q.collect()?.height(),
+        2 * scan_foods_ipc().collect()?.height()
+    );
+
+    Ok(())
+}
@@ -2,8 +2,15 @@ use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
 use super::*;
 
+/// Filter the rows of the input early if at most this fraction of them is still selected after
+/// a conjunct of the predicate.
+const EARLY_FILTER_SELECTIVITY: f64 = 0.25;
+
 pub struct FilterExec {
     pub(crate) predicate: Arc<dyn PhysicalExpr>,
+    /// The terms that `AND` together to form the predicate, if it can be evaluated one term at a
+    /// time. Empty otherwise.
+    conjuncts: Vec<Arc<dyn PhysicalExpr>>,
     pub(crate) input: Box<dyn Executor>,
     // if the predicate contains a window function
     has_window: bool,
@@ -21,12 +28,14 @@ fn column_to_mask(c: &Column) -> PolarsResult<&BooleanChunked> {
 impl FilterExec {
     pub fn new(
         predicate: Arc<dyn PhysicalExpr>,
+        conjuncts: Vec<Arc<dyn PhysicalExpr>>,
         input: Box<dyn Executor>,
         has_window: bool,
         streamable: bool,
     ) -> Self {
         Self {
             predicate,
+            conjuncts,
             input,
             has_window,
             streamable,
@@ -38,6 +47,10 @@ impl FilterExec {
         df: DataFrame,
         state: &mut ExecutionState,
     ) -> PolarsResult<DataFrame> {
+        if !self.conjuncts.is_empty() {
+            return self.filter_by_conjuncts(df, state);
+        }
+
         if self.has_window {
             state.insert_has_window_function_flag()
         }
@@ -51,12 +64,60 @@ impl FilterExec {
         df.filter(column_to_mask(&c)?)
     }
 
+    /// Filter `df` by evaluating the conjuncts of the predicate one at a time.
+    ///
+    /// Once the conjuncts seen so far are selective enough, the rows that are not selected are
+    /// filtered out, so that the remaining conjuncts only run on the surviving rows.
+    fn filter_by_conjuncts(
+        &self,
+        mut df: DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let mut selected: Option<BooleanChunked> = None;
+
+        for (i, conjunct) in self.conjuncts.iter().enumerate() {
+            let c = conjunct.evaluate(&df, state)?;
+            let mask = column_to_mask(&c)?;
+            let mask = match selected.take() {
+                None => mask.clone(),
+                Some(prev) => &prev & mask,
+            };
+
+            if i + 1 == self.conjuncts.len() {
+                return df.filter(&mask);
+            }
+
+            // A broadcasted scalar either keeps or drops all rows.
+            if mask.len() != df.height() {
+                if mask.all() {
+                    continue;
+                }
+                return Ok(df.clear());
+            }
+
+            let n_selected = mask.num_trues();
+            if n_selected == 0 {
+                return Ok(df.clear());
+            }
+            if (n_selected as f64) <= EARLY_FILTER_SELECTIVITY * df.height() as f64 {
+                df = df.filter(&mask)?;
+            } else {
+                selected = Some(mask);
+            }
+        }
+
+        unreachable!()
+    }
+
     fn execute_chunks(
         &mut self,
         chunks: Vec<DataFrame>,
         state: &ExecutionState,
     ) -> PolarsResult<DataFrame> {
         let iter = chunks.into_par_iter().map(|df| {
+            if !self.conjuncts.is_empty() {
+                return self.filter_by_conjuncts(df, state);
+            }
             let c = self.predicate.evaluate(&df, state)?;
 
             // @scalar-opt
@@ -26,6 +26,7 @@ pub struct GroupByExec {
     aggs: Vec<Arc<dyn PhysicalExpr>>,
     apply: Option<Arc<dyn DataFrameUdf>>,
     maintain_order: bool,
+    expand_categories: bool,
     input_schema: SchemaRef,
     slice: Option<(i64, usize)>,
 }
@@ -38,6 +39,7 @@ impl GroupByExec {
         aggs: Vec<Arc<dyn PhysicalExpr>>,
         apply: Option<Arc<dyn DataFrameUdf>>,
         maintain_order: bool,
+        expand_categories: bool,
         input_schema: SchemaRef,
         slice: Option<(i64, usize)>,
     ) -> Self {
@@ -47,6 +49,7 @@ impl GroupByExec {
             aggs,
             apply,
             maintain_order,
+            expand_categories,
             input_schema,
             slice,
         }
@@ -54,6 +57,7 @@ impl GroupByExec {
 }
 
 #[allow(clippy::too_many_arguments)]
+#[cfg_attr(not(feature = "dtype-categorical"), allow(unused_variables))]
 pub(super) fn group_by_helper(
     mut df: DataFrame,
     keys: Vec<Column>,
@@ -61,10 +65,17 @@ pub(super) fn group_by_helper(
     apply: Option<Arc<dyn DataFrameUdf>>,
     state: &ExecutionState,
     maintain_order: bool,
+    expand_categories: bool,
     slice: Option<(i64, usize)>,
 ) -> PolarsResult<DataFrame> {
     df.as_single_chunk_par();
     let gb = df.group_by_with_series(keys, true, maintain_order)?;
+    #[cfg(feature = "dtype-categorical")]
+    let gb = if expand_categories {
+        gb.with_unobserved_categories()?
+    } else {
+        gb
+    };
 
     if let Some(f) = apply {
         return gb.sliced(slice).apply(move |df| f.call_udf(df));
@@ -107,6 +118,7 @@ impl GroupByExec {
             self.apply.take(),
             state,
             self.maintain_order,
+            self.expand_categories,
             self.slice,
         )
     }
@@ -274,6 +274,7 @@ impl PartitionGroupByExec {
                     None,
                     state,
                     self.maintain_order,
+                    false,
                     self.slice,
                 );
             }
@@ -286,6 +286,8 @@ fn create_physical_plan_impl(
                                         .with_compression(options.compression)
                                         .with_statistics(options.statistics)
                                         .with_row_group_size(options.row_group_size)
+                                        .with_row_group_bytes(options.row_group_bytes)
+                                        .with_row_group_split(options.row_group_split.clone())
                                         .with_data_page_size(options.data_page_size)
                                         .with_key_value_metadata(options.key_value_metadata.clone())
                                         .finish(&mut df)?;
@@ -422,15 +424,38 @@ fn create_physical_plan_impl(
             let input_schema = lp_arena.get(input).schema(lp_arena).into_owned();
             let input = recurse!(input, state)?;
             let mut state = ExpressionConversionState::new(true);
-            let predicate = create_physical_expr(
+            let phys_predicate = create_physical_expr(
                 &predicate,
                 Context::Default,
                 expr_arena,
                 &input_schema,
                 &mut state,
             )?;
+
+            // Elementwise conjuncts can be evaluated one after the other, so that the later ones
+            // only have to run on the rows that are still selected.
+            let mut conjuncts = vec![];
+            if streamable && !state.has_windows {
+                let minterms = MintermIter::new(predicate.node(), expr_arena).collect::<Vec<_>>();
+                if minterms.len() > 1 {
+                    conjuncts = minterms
+                        .into_iter()
+                        .map(|node| {
+                            create_physical_expr(
+                                &ExprIR::from_node(node, expr_arena),
+                                Context::Default,
+                                expr_arena,
+                                &input_schema,
+                                &mut ExpressionConversionState::new(true),
+                            )
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                }
+            }
+
             Ok(Box::new(executors::FilterExec::new(
-                predicate,
+                phys_predicate,
+                conjuncts,
                 input,
                 state.has_windows,
                 streamable,
@@ -691,7 +716,8 @@ fn create_physical_plan_impl(
             }
 
             // We first check if we can partition the group_by on the latest moment.
-            let partitionable = partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
+            let partitionable = !options.expand_categories
+                && partitionable_gb(&keys, &aggs, &input_schema, expr_arena, &apply);
             if partitionable {
                 let from_partitioned_ds = lp_arena.iter(input).any(|(_, lp)| {
                     if let Union { options, .. } = lp {
@@ -729,6 +755,7 @@ fn create_physical_plan_impl(
                     phys_aggs,
                     apply,
                     maintain_order,
+                    options.expand_categories,
                     input_schema,
                     options.slice,
                 )))
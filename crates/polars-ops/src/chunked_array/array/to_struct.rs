@@ -17,29 +17,46 @@ pub trait ToStruct: AsArray {
         name_generator: Option<ArrToStructNameGenerator>,
     ) -> PolarsResult<StructChunked> {
         let ca = self.as_array();
-        let n_fields = ca.width();
+        let fields = self.arr_to_cols(name_generator)?;
+        StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())
+    }
+
+    /// Split the arrays into one [`Series`] per element, so that the `i`-th [`Series`] holds the
+    /// `i`-th element of every array. Null arrays give null elements.
+    ///
+    /// The elements are gathered with a stride from the inner values, without materializing
+    /// the arrays.
+    fn arr_to_cols(
+        &self,
+        name_generator: Option<ArrToStructNameGenerator>,
+    ) -> PolarsResult<Vec<Series>> {
+        let ca = self.as_array().rechunk();
+        let width = ca.width();
 
         let name_generator = name_generator
             .as_deref()
             .unwrap_or(&|i| Ok(arr_default_struct_name_gen(i)));
 
-        let fields = POOL.install(|| {
-            (0..n_fields)
+        let values = ca.get_inner();
+        let validity = ca.rechunk_validity();
+
+        POOL.install(|| {
+            (0..width)
                 .into_par_iter()
                 .map(|i| {
-                    ca.array_get(
-                        &Int64Chunked::from_slice(PlSmallStr::EMPTY, &[i as i64]),
-                        true,
-                    )
-                    .and_then(|mut s| {
-                        s.rename(name_generator(i)?.clone());
-                        Ok(s)
-                    })
+                    let idx = (i..values.len())
+                        .step_by(width)
+                        .map(|j| j as IdxSize)
+                        .collect::<Vec<_>>();
+                    let idx = IdxArr::from_vec(idx).with_validity(validity.clone());
+                    let idx = IdxCa::with_chunk(PlSmallStr::EMPTY, idx);
+                    // SAFETY: the indices are in bounds of the inner values.
+                    let mut s = unsafe { values.take_unchecked(&idx) };
+                    s.rename(name_generator(i)?);
+                    Ok(s)
                 })
                 .collect::<PolarsResult<Vec<_>>>()
-        })?;
-
-        StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())
+        })
     }
 }
 
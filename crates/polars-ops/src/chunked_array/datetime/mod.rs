@@ -2,3 +2,7 @@
 mod replace_time_zone;
 #[cfg(feature = "timezones")]
 pub use replace_time_zone::*;
+#[cfg(feature = "timezones")]
+mod time_zone_column;
+#[cfg(feature = "timezones")]
+pub use time_zone_column::*;
@@ -0,0 +1,110 @@
+use arrow::legacy::kernels::convert_to_naive_local;
+use arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+use chrono::{NaiveDateTime, TimeZone as _};
+use chrono_tz::{Tz, UTC};
+use polars_core::chunked_array::ops::arity::broadcast_try_binary_elementwise;
+use polars_core::datatypes::time_zone::parse_time_zone;
+use polars_core::prelude::*;
+
+/// The time zones of a column, parsed once per distinct name.
+#[derive(Default)]
+struct TimeZoneCache {
+    parsed: PlHashMap<PlSmallStr, Tz>,
+}
+
+impl TimeZoneCache {
+    fn get(&mut self, time_zone: &str) -> PolarsResult<Tz> {
+        if let Some(tz) = self.parsed.get(time_zone) {
+            return Ok(*tz);
+        }
+        let tz = parse_time_zone(time_zone)?;
+        self.parsed.insert(time_zone.into(), tz);
+        Ok(tz)
+    }
+}
+
+fn timestamp_conversions(
+    time_unit: TimeUnit,
+) -> (fn(i64) -> NaiveDateTime, fn(NaiveDateTime) -> i64) {
+    match time_unit {
+        TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+        TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+        TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+    }
+}
+
+fn ensure_matching_len(datetime: &DatetimeChunked, time_zones: &StringChunked) -> PolarsResult<()> {
+    polars_ensure!(
+        datetime.len() == time_zones.len() || datetime.len() == 1 || time_zones.len() == 1,
+        ShapeMismatch: "time zone column of length {} does not match datetimes of length {}",
+        time_zones.len(), datetime.len()
+    );
+    Ok(())
+}
+
+/// Convert every datetime to the local time in the time zone of its row.
+///
+/// Time-zone-naive datetimes are interpreted as UTC. As the rows may be in different time zones,
+/// the result is time-zone-naive.
+pub fn convert_time_zone_per_row(
+    datetime: &DatetimeChunked,
+    time_zones: &StringChunked,
+) -> PolarsResult<DatetimeChunked> {
+    ensure_matching_len(datetime, time_zones)?;
+    let time_unit = datetime.time_unit();
+    let (timestamp_to_datetime, datetime_to_timestamp) = timestamp_conversions(time_unit);
+
+    let mut cache = TimeZoneCache::default();
+    let out: Int64Chunked = broadcast_try_binary_elementwise(
+        datetime.physical(),
+        time_zones,
+        |timestamp, time_zone| match (timestamp, time_zone) {
+            (Some(timestamp), Some(time_zone)) => {
+                let tz = cache.get(time_zone)?;
+                let ndt = timestamp_to_datetime(timestamp);
+                let local = tz.from_utc_datetime(&ndt).naive_local();
+                Ok::<_, PolarsError>(Some(datetime_to_timestamp(local)))
+            },
+            _ => Ok(None),
+        },
+    )?;
+    Ok(out.into_datetime(time_unit, None))
+}
+
+/// Localize every time-zone-naive datetime in the time zone of its row, returning the instants
+/// in UTC.
+///
+/// Datetimes that are ambiguous or non-existent in the time zone of their row raise an error.
+pub fn replace_time_zone_per_row(
+    datetime: &DatetimeChunked,
+    time_zones: &StringChunked,
+) -> PolarsResult<DatetimeChunked> {
+    polars_ensure!(
+        datetime.time_zone().is_none(),
+        InvalidOperation: "cannot localize datetimes with time zone {} in a time zone column",
+        datetime.time_zone().as_ref().unwrap()
+    );
+    ensure_matching_len(datetime, time_zones)?;
+    let time_unit = datetime.time_unit();
+    let (timestamp_to_datetime, datetime_to_timestamp) = timestamp_conversions(time_unit);
+
+    let mut cache = TimeZoneCache::default();
+    let out: Int64Chunked = broadcast_try_binary_elementwise(
+        datetime.physical(),
+        time_zones,
+        |timestamp, time_zone| match (timestamp, time_zone) {
+            (Some(timestamp), Some(time_zone)) => {
+                let tz = cache.get(time_zone)?;
+                let ndt = timestamp_to_datetime(timestamp);
+                let utc =
+                    convert_to_naive_local(&UTC, &tz, ndt, Ambiguous::Raise, NonExistent::Raise)?
+                        .expect("we didn't use Ambiguous::Null or NonExistent::Null");
+                Ok::<_, PolarsError>(Some(datetime_to_timestamp(utc)))
+            },
+            _ => Ok(None),
+        },
+    )?;
+    Ok(out.into_datetime(time_unit, Some(TimeZone::UTC)))
+}
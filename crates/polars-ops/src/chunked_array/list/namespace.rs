@@ -1,7 +1,19 @@
 use std::borrow::Cow;
 use std::fmt::Write;
 
+#[cfg(feature = "list_gather")]
+use arrow::array::Array;
+#[cfg(feature = "list_filter")]
+use arrow::array::BooleanArray;
 use arrow::array::ValueSize;
+#[cfg(any(feature = "list_gather", feature = "list_filter"))]
+use arrow::bitmap::Bitmap;
+#[cfg(feature = "list_gather")]
+use arrow::bitmap::BitmapBuilder;
+#[cfg(feature = "list_filter")]
+use arrow::compute::utils::combine_validities_and;
+#[cfg(any(feature = "list_gather", feature = "list_filter"))]
+use arrow::offset::OffsetsBuffer;
 #[cfg(feature = "list_gather")]
 use num_traits::ToPrimitive;
 #[cfg(feature = "list_gather")]
@@ -384,69 +396,53 @@ pub trait ListNameSpaceImpl: AsList {
         unsafe { s.from_physical_unchecked(ca.inner_dtype()) }
     }
 
+    /// Take every `n`-th element of every sublist, starting at `offset`.
+    ///
+    /// `n` and `offset` are either scalars or have one value per sublist.
     #[cfg(feature = "list_gather")]
     fn lst_gather_every(&self, n: &IdxCa, offset: &IdxCa) -> PolarsResult<Series> {
         let list_ca = self.as_list();
-        let out = match (n.len(), offset.len()) {
-            (1, 1) => match (n.get(0), offset.get(0)) {
-                (Some(n), Some(offset)) => list_ca.try_apply_amortized(|s| {
-                    s.as_ref().gather_every(n as usize, offset as usize)
-                })?,
-                _ => ListChunked::full_null_with_dtype(
-                    list_ca.name().clone(),
-                    list_ca.len(),
-                    list_ca.inner_dtype(),
-                ),
-            },
-            (1, len_offset) if len_offset == list_ca.len() => {
-                if let Some(n) = n.get(0) {
-                    list_ca.try_zip_and_apply_amortized(offset, |opt_s, opt_offset| {
-                        match (opt_s, opt_offset) {
-                            (Some(s), Some(offset)) => {
-                                Ok(Some(s.as_ref().gather_every(n as usize, offset as usize)?))
-                            },
-                            _ => Ok(None),
-                        }
-                    })?
-                } else {
-                    ListChunked::full_null_with_dtype(
-                        list_ca.name().clone(),
-                        list_ca.len(),
-                        list_ca.inner_dtype(),
-                    )
-                }
-            },
-            (len_n, 1) if len_n == list_ca.len() => {
-                if let Some(offset) = offset.get(0) {
-                    list_ca.try_zip_and_apply_amortized(n, |opt_s, opt_n| match (opt_s, opt_n) {
-                        (Some(s), Some(n)) => {
-                            Ok(Some(s.as_ref().gather_every(n as usize, offset as usize)?))
-                        },
-                        _ => Ok(None),
-                    })?
-                } else {
-                    ListChunked::full_null_with_dtype(
-                        list_ca.name().clone(),
-                        list_ca.len(),
-                        list_ca.inner_dtype(),
-                    )
-                }
-            },
-            (len_n, len_offset) if len_n == len_offset && len_n == list_ca.len() => list_ca
-                .try_binary_zip_and_apply_amortized(
-                    n,
-                    offset,
-                    |opt_s, opt_n, opt_offset| match (opt_s, opt_n, opt_offset) {
-                        (Some(s), Some(n), Some(offset)) => {
-                            Ok(Some(s.as_ref().gather_every(n as usize, offset as usize)?))
-                        },
-                        _ => Ok(None),
-                    },
-                )?,
-            _ => {
-                polars_bail!(ComputeError: "The lengths of `n` and `offset` should be 1 or equal to the length of list.")
-            },
+        let len = list_ca.len();
+        polars_ensure!(
+            (n.len() == 1 || n.len() == len) && (offset.len() == 1 || offset.len() == len),
+            ComputeError: "The lengths of `n` and `offset` should be 1 or equal to the length of list."
+        );
+
+        let ca = list_ca.rechunk();
+        let arr = ca.downcast_as_array();
+        let n = n.rechunk();
+        let n = n.downcast_as_array();
+        let offset = offset.rechunk();
+        let offset = offset.downcast_as_array();
+        let broadcast = |arr: &IdxArr, i: usize| {
+            let i = if arr.len() == 1 { 0 } else { i };
+            arr.is_valid(i).then(|| arr.value(i))
         };
+
+        let mut idx = Vec::new();
+        let mut offsets = Vec::with_capacity(len + 1);
+        offsets.push(0i64);
+        let mut validity = BitmapBuilder::with_capacity(len);
+        for (i, w) in arr.offsets().windows(2).enumerate() {
+            match (broadcast(n, i), broadcast(offset, i)) {
+                (Some(n), Some(offset)) if arr.is_valid(i) => {
+                    polars_ensure!(n > 0, ComputeError: "cannot perform gather every for `n=0`");
+                    let (start, end) = (w[0] as usize, w[1] as usize);
+                    idx.extend(
+                        (start + offset as usize..end)
+                            .step_by(n as usize)
+                            .map(|j| j as IdxSize),
+                    );
+                    validity.push(true);
+                },
+                _ => validity.push(false),
+            }
+            offsets.push(idx.len() as i64);
+        }
+
+        // SAFETY: the indices are within the sublists.
+        let out =
+            unsafe { gather_inner_unchecked(&ca, idx, offsets, validity.into_opt_validity()) };
         Ok(out.into_series())
     }
 
@@ -564,6 +560,60 @@ pub trait ListNameSpaceImpl: AsList {
         }
     }
 
+    /// Keep the elements of every sublist for which the corresponding element of `mask` is
+    /// `true`.
+    ///
+    /// The sublists of `mask` must have the same lengths as the sublists they filter. Null
+    /// elements of `mask` are treated as `false`, and a null sublist of `mask` gives a null
+    /// sublist.
+    #[cfg(feature = "list_filter")]
+    fn lst_filter(&self, mask: &ListChunked) -> PolarsResult<ListChunked> {
+        let list_ca = self.as_list();
+        polars_ensure!(
+            mask.inner_dtype() == &DataType::Boolean,
+            ComputeError: "filter mask must be a list of booleans, got {}", mask.dtype()
+        );
+        let mask = match (list_ca.len(), mask.len()) {
+            (a, b) if a == b => Cow::Borrowed(mask),
+            (a, 1) => Cow::Owned(mask.new_from_index(0, a)),
+            (a, b) => polars_bail!(length_mismatch = "list.filter", a, b),
+        };
+
+        let ca = list_ca.rechunk();
+        let arr = ca.downcast_as_array();
+        let mask = mask.rechunk();
+        let mask_arr = mask.downcast_as_array();
+        let mask_values = mask_arr
+            .values()
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        let validity = combine_validities_and(arr.validity(), mask_arr.validity());
+
+        let mut idx = Vec::new();
+        let mut offsets = Vec::with_capacity(arr.len() + 1);
+        offsets.push(0i64);
+        let windows = arr.offsets().windows(2).zip(mask_arr.offsets().windows(2));
+        for (i, (w, m)) in windows.enumerate() {
+            if validity.as_ref().is_none_or(|v| v.get_bit(i)) {
+                polars_ensure!(
+                    w[1] - w[0] == m[1] - m[0],
+                    ShapeMismatch: "sublist of length {} in `list.filter` does not match mask of length {}",
+                    w[1] - w[0], m[1] - m[0]
+                );
+                for (j, k) in (w[0] as usize..w[1] as usize).zip(m[0] as usize..m[1] as usize) {
+                    if mask_values.get(k) == Some(true) {
+                        idx.push(j as IdxSize);
+                    }
+                }
+            }
+            offsets.push(idx.len() as i64);
+        }
+
+        // SAFETY: the indices are within the sublists.
+        Ok(unsafe { gather_inner_unchecked(&ca, idx, offsets, validity) })
+    }
+
     #[cfg(feature = "list_drop_nulls")]
     fn lst_drop_nulls(&self) -> ListChunked {
         let list_ca = self.as_list();
@@ -830,6 +880,37 @@ pub trait ListNameSpaceImpl: AsList {
 
 impl ListNameSpaceImpl for ListChunked {}
 
+/// Gather `idx` from the inner values of `ca` and split them into sublists at `offsets`.
+///
+/// # Safety
+/// `ca` must consist of a single chunk and the indices must be in bounds of its inner values.
+#[cfg(any(feature = "list_gather", feature = "list_filter"))]
+unsafe fn gather_inner_unchecked(
+    ca: &ListChunked,
+    idx: Vec<IdxSize>,
+    offsets: Vec<i64>,
+    validity: Option<Bitmap>,
+) -> ListChunked {
+    let idx = IdxCa::from_vec(PlSmallStr::EMPTY, idx);
+    // SAFETY: the indices are in bounds, see the safety requirements.
+    let values = unsafe { ca.get_inner().take_unchecked(&idx) }.rechunk();
+    let values = values.chunks()[0].clone();
+
+    // SAFETY: the offsets are monotonically increasing and end at the length of the values.
+    let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(values.dtype().clone()),
+        offsets,
+        values,
+        validity,
+    );
+
+    // SAFETY: the values have the inner dtype of `ca`.
+    unsafe {
+        ListChunked::from_chunks_and_dtype(ca.name().clone(), vec![arr.boxed()], ca.dtype().clone())
+    }
+}
+
 #[cfg(feature = "list_gather")]
 fn take_series(s: &Series, idx: Series, null_on_oob: bool) -> PolarsResult<Series> {
     let len = s.len();
@@ -39,7 +39,7 @@ pub use list::*;
 use polars_core::prelude::*;
 #[cfg(feature = "repeat_by")]
 pub use repeat_by::*;
-pub use scatter::ChunkedSet;
+pub use scatter::{ChunkedSet, ScatterConflict, dedup_scatter_idx};
 pub use strings::*;
 #[cfg(feature = "top_k")]
 pub use top_k::*;
@@ -6,12 +6,92 @@ use polars_core::utils::arrow::bitmap::MutableBitmap;
 use polars_core::utils::arrow::types::NativeType;
 use polars_utils::index::check_bounds;
 
+/// How an index that is set more than once by a scatter is handled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ScatterConflict {
+    /// The last value for the index is set.
+    #[default]
+    LastWins,
+    /// Raise an error.
+    Error,
+}
+
+/// Sort the indices of a scatter on their index, and drop the indices that are set again later
+/// on according to `conflict`.
+///
+/// Returns `None` if `idx` is already sorted and unique. Otherwise the new indices are returned,
+/// with for every index the position in `idx` of the value to set.
+pub fn dedup_scatter_idx(
+    idx: &[IdxSize],
+    conflict: ScatterConflict,
+) -> PolarsResult<Option<(Vec<IdxSize>, Vec<IdxSize>)>> {
+    if idx.windows(2).all(|w| w[0] < w[1]) {
+        return Ok(None);
+    }
+
+    let mut positions = (0..idx.len() as IdxSize).collect::<Vec<_>>();
+    // The sort is stable, so the last value of an index comes last.
+    positions.sort_by_key(|p| idx[*p as usize]);
+    let mut out_idx: Vec<IdxSize> = Vec::with_capacity(idx.len());
+    let mut out_positions: Vec<IdxSize> = Vec::with_capacity(idx.len());
+    for p in positions {
+        let i = idx[p as usize];
+        if out_idx.last() == Some(&i) {
+            polars_ensure!(
+                conflict == ScatterConflict::LastWins,
+                InvalidOperation: "index {i} is set more than once by the scatter"
+            );
+            *out_positions.last_mut().unwrap() = p;
+        } else {
+            out_idx.push(i);
+            out_positions.push(p);
+        }
+    }
+    Ok(Some((out_idx, out_positions)))
+}
+
 pub trait ChunkedSet<T: Copy> {
     /// Invariant for implementations: if the scatter() fails, typically because
     /// of bad indexes, then self should remain unmodified.
     fn scatter<V>(self, idx: &[IdxSize], values: V) -> PolarsResult<Series>
     where
         V: IntoIterator<Item = Option<T>>;
+
+    /// Apply several batches of indices and values in a single scatter.
+    ///
+    /// This only makes the data mutable once, instead of once per batch, and the data is only
+    /// copied if it is shared. Indices that are set by several batches are handled according to
+    /// `conflict`.
+    fn scatter_batches<'i, I, V>(
+        self,
+        batches: I,
+        conflict: ScatterConflict,
+    ) -> PolarsResult<Series>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = (&'i [IdxSize], V)>,
+        V: IntoIterator<Item = Option<T>>,
+    {
+        let mut idx = vec![];
+        let mut values = vec![];
+        for (batch_idx, batch_values) in batches {
+            let n_values = values.len();
+            values.extend(batch_values);
+            polars_ensure!(
+                values.len() - n_values == batch_idx.len(),
+                ShapeMismatch: "a scatter batch has {} indices but {} values",
+                batch_idx.len(), values.len() - n_values
+            );
+            idx.extend_from_slice(batch_idx);
+        }
+
+        match dedup_scatter_idx(&idx, conflict)? {
+            None => self.scatter(&idx, values),
+            Some((idx, positions)) => {
+                self.scatter(&idx, positions.into_iter().map(|p| values[p as usize]))
+            },
+        }
+    }
 }
 fn check_sorted(idx: &[IdxSize]) -> PolarsResult<()> {
     if idx.is_empty() {
@@ -0,0 +1,356 @@
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+
+/// A line in the Common Log Format, optionally extended with the `referer` and `user_agent`
+/// fields of the Combined Log Format.
+#[derive(Debug, PartialEq, Eq)]
+struct LogLine<'a> {
+    host: Option<&'a str>,
+    ident: Option<&'a str>,
+    user: Option<&'a str>,
+    time: &'a str,
+    method: Option<&'a str>,
+    path: Option<&'a str>,
+    protocol: Option<&'a str>,
+    status: u16,
+    size: Option<u64>,
+    referer: Option<&'a str>,
+    user_agent: Option<&'a str>,
+}
+
+/// `-` denotes a missing value in the log formats.
+fn non_dash(s: &str) -> Option<&str> {
+    (s != "-").then_some(s)
+}
+
+struct Cursor<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Cursor<'a> {
+    fn skip_spaces(&mut self) {
+        self.rest = self.rest.trim_start_matches(' ');
+    }
+
+    fn token(&mut self) -> Option<&'a str> {
+        self.skip_spaces();
+        let end = self.rest.find(' ').unwrap_or(self.rest.len());
+        let (token, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        (!token.is_empty()).then_some(token)
+    }
+
+    fn bracketed(&mut self) -> Option<&'a str> {
+        self.skip_spaces();
+        let inner = self.rest.strip_prefix('[')?;
+        let end = inner.find(']')?;
+        self.rest = &inner[end + 1..];
+        Some(&inner[..end])
+    }
+
+    /// A double quoted value, quotes inside the value are escaped with a backslash.
+    fn quoted(&mut self) -> Option<&'a str> {
+        self.skip_spaces();
+        let inner = self.rest.strip_prefix('"')?;
+        let bytes = inner.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                b'\\' => i += 2,
+                b'"' => {
+                    self.rest = &inner[i + 1..];
+                    return Some(&inner[..i]);
+                },
+                _ => i += 1,
+            }
+        }
+        None
+    }
+}
+
+fn parse_log_line(line: &str) -> Option<LogLine<'_>> {
+    let mut cursor = Cursor { rest: line };
+    let host = non_dash(cursor.token()?);
+    let ident = non_dash(cursor.token()?);
+    let user = non_dash(cursor.token()?);
+    let time = cursor.bracketed()?;
+
+    let request = cursor.quoted()?;
+    let mut request_parts = request.splitn(3, ' ');
+    let method = request_parts.next().and_then(non_dash);
+    let path = request_parts.next();
+    let protocol = request_parts.next();
+
+    let status = cursor.token()?.parse().ok()?;
+    let size = match cursor.token()? {
+        "-" => None,
+        size => Some(size.parse().ok()?),
+    };
+
+    // Combined Log Format.
+    let referer = cursor.quoted().and_then(non_dash);
+    let user_agent = cursor.quoted().and_then(non_dash);
+
+    Some(LogLine {
+        host,
+        ident,
+        user,
+        time,
+        method,
+        path,
+        protocol,
+        status,
+        size,
+        referer,
+        user_agent,
+    })
+}
+
+fn string_field<'a, T>(
+    name: &'static str,
+    rows: &'a [Option<T>],
+    get: impl Fn(&'a T) -> Option<&'a str>,
+) -> Series {
+    StringChunked::from_iter_options(
+        PlSmallStr::from_static(name),
+        rows.iter().map(|row| row.as_ref().and_then(&get)),
+    )
+    .into_series()
+}
+
+fn string_struct_dtype(names: &[&'static str]) -> DataType {
+    DataType::Struct(
+        names
+            .iter()
+            .map(|name| Field::new(PlSmallStr::from_static(name), DataType::String))
+            .collect(),
+    )
+}
+
+/// The output [`DataType`] of [`parse_common_log`].
+pub fn common_log_dtype() -> DataType {
+    DataType::Struct(vec![
+        Field::new(PlSmallStr::from_static("host"), DataType::String),
+        Field::new(PlSmallStr::from_static("ident"), DataType::String),
+        Field::new(PlSmallStr::from_static("user"), DataType::String),
+        Field::new(PlSmallStr::from_static("time"), DataType::String),
+        Field::new(PlSmallStr::from_static("method"), DataType::String),
+        Field::new(PlSmallStr::from_static("path"), DataType::String),
+        Field::new(PlSmallStr::from_static("protocol"), DataType::String),
+        Field::new(PlSmallStr::from_static("status"), DataType::UInt16),
+        Field::new(PlSmallStr::from_static("size"), DataType::UInt64),
+        Field::new(PlSmallStr::from_static("referer"), DataType::String),
+        Field::new(PlSmallStr::from_static("user_agent"), DataType::String),
+    ])
+}
+
+/// Parse lines in the Common (or Combined) Log Format into a struct.
+///
+/// Lines that don't match the format are returned as null.
+pub fn parse_common_log(ca: &StringChunked) -> PolarsResult<StructChunked> {
+    let lines = ca
+        .iter()
+        .map(|opt_s| opt_s.and_then(parse_log_line))
+        .collect::<Vec<_>>();
+
+    let fields = [
+        string_field("host", &lines, |l| l.host),
+        string_field("ident", &lines, |l| l.ident),
+        string_field("user", &lines, |l| l.user),
+        string_field("time", &lines, |l| Some(l.time)),
+        string_field("method", &lines, |l| l.method),
+        string_field("path", &lines, |l| l.path),
+        string_field("protocol", &lines, |l| l.protocol),
+        UInt16Chunked::from_iter_options(
+            PlSmallStr::from_static("status"),
+            lines.iter().map(|line| line.as_ref().map(|l| l.status)),
+        )
+        .into_series(),
+        UInt64Chunked::from_iter_options(
+            PlSmallStr::from_static("size"),
+            lines.iter().map(|line| line.as_ref().and_then(|l| l.size)),
+        )
+        .into_series(),
+        string_field("referer", &lines, |l| l.referer),
+        string_field("user_agent", &lines, |l| l.user_agent),
+    ];
+
+    let validity = Bitmap::from_iter(lines.iter().map(Option::is_some));
+    let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+    Ok(out.with_outer_validity(Some(validity)))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct UserAgent<'a> {
+    browser: Option<&'static str>,
+    browser_version: Option<&'a str>,
+    os: Option<&'static str>,
+    device: &'static str,
+}
+
+/// Browser tokens in order of precedence, most browsers also send the tokens of the
+/// browsers they are derived from.
+const BROWSERS: [(&str, &str); 9] = [
+    ("Edg/", "Edge"),
+    ("EdgA/", "Edge"),
+    ("OPR/", "Opera"),
+    ("SamsungBrowser/", "Samsung Internet"),
+    ("Firefox/", "Firefox"),
+    ("FxiOS/", "Firefox"),
+    ("CriOS/", "Chrome"),
+    ("Chrome/", "Chrome"),
+    ("Version/", "Safari"),
+];
+
+const OPERATING_SYSTEMS: [(&str, &str); 8] = [
+    ("Windows", "Windows"),
+    ("iPhone", "iOS"),
+    ("iPad", "iOS"),
+    ("Android", "Android"),
+    ("CrOS", "Chrome OS"),
+    ("Mac OS X", "macOS"),
+    ("Macintosh", "macOS"),
+    ("Linux", "Linux"),
+];
+
+const BOT_TOKENS: [&str; 4] = ["bot", "crawl", "spider", "slurp"];
+
+fn version_after<'a>(ua: &'a str, token: &str) -> Option<&'a str> {
+    let start = ua.find(token)? + token.len();
+    let rest = &ua[start..];
+    let end = rest
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(rest.len());
+    (end > 0).then(|| &rest[..end])
+}
+
+fn parse_user_agent_str(ua: &str) -> UserAgent<'_> {
+    let lower = ua.to_ascii_lowercase();
+
+    let (browser, browser_version) = BROWSERS
+        .iter()
+        .find(|(token, name)| ua.contains(token) && (*name != "Safari" || ua.contains("Safari/")))
+        .map(|(token, name)| (Some(*name), version_after(ua, token)))
+        .or_else(|| {
+            // Internet Explorer 11 no longer sends the `MSIE` token.
+            if ua.contains("MSIE ") {
+                Some((Some("Internet Explorer"), version_after(ua, "MSIE ")))
+            } else if ua.contains("Trident/") {
+                Some((Some("Internet Explorer"), version_after(ua, "rv:")))
+            } else {
+                None
+            }
+        })
+        .unwrap_or((None, None));
+
+    let os = OPERATING_SYSTEMS
+        .iter()
+        .find(|(token, _)| ua.contains(token))
+        .map(|(_, name)| *name);
+
+    let device = if BOT_TOKENS.iter().any(|token| lower.contains(token)) {
+        "bot"
+    } else if ua.contains("iPad") || lower.contains("tablet") {
+        "tablet"
+    } else if ua.contains("Mobi") || ua.contains("iPhone") {
+        "mobile"
+    } else if ua.contains("Android") {
+        // Android devices without the `Mobile` token are tablets.
+        "tablet"
+    } else {
+        "desktop"
+    };
+
+    UserAgent {
+        browser,
+        browser_version,
+        os,
+        device,
+    }
+}
+
+/// The output [`DataType`] of [`parse_user_agent`].
+pub fn user_agent_dtype() -> DataType {
+    string_struct_dtype(&["browser", "browser_version", "os", "device"])
+}
+
+/// Extract the browser, browser version, operating system and device type from
+/// `User-Agent` header values.
+pub fn parse_user_agent(ca: &StringChunked) -> PolarsResult<StructChunked> {
+    let agents = ca
+        .iter()
+        .map(|opt_s| opt_s.map(parse_user_agent_str))
+        .collect::<Vec<_>>();
+
+    let fields = [
+        string_field("browser", &agents, |ua| ua.browser),
+        string_field("browser_version", &agents, |ua| ua.browser_version),
+        string_field("os", &agents, |ua| ua.os),
+        string_field("device", &agents, |ua| Some(ua.device)),
+    ];
+
+    let out = StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())?;
+    Ok(out.with_outer_validity(ca.rechunk_validity()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_line() {
+        let line = r#"127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /apache_pb.gif HTTP/1.0" 200 2326"#;
+        assert_eq!(
+            parse_log_line(line),
+            Some(LogLine {
+                host: Some("127.0.0.1"),
+                ident: None,
+                user: Some("frank"),
+                time: "10/Oct/2000:13:55:36 -0700",
+                method: Some("GET"),
+                path: Some("/apache_pb.gif"),
+                protocol: Some("HTTP/1.0"),
+                status: 200,
+                size: Some(2326),
+                referer: None,
+                user_agent: None,
+            })
+        );
+
+        let line =
+            r#"::1 - - [10/Oct/2000:13:55:36 +0000] "POST /a\"b HTTP/1.1" 404 - "-" "curl/8.0""#;
+        let parsed = parse_log_line(line).unwrap();
+        assert_eq!(parsed.path, Some(r#"/a\"b"#));
+        assert_eq!(parsed.size, None);
+        assert_eq!(parsed.referer, None);
+        assert_eq!(parsed.user_agent, Some("curl/8.0"));
+
+        assert_eq!(parse_log_line("not a log line"), None);
+        assert_eq!(parse_log_line(r#"h - - [t] "GET / HTTP/1.1" abc 1"#), None);
+    }
+
+    #[test]
+    fn test_parse_user_agent() {
+        let ua = parse_user_agent_str(
+            "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0.0.0 Safari/537.36 Edg/120.0.2210.91",
+        );
+        assert_eq!(ua.browser, Some("Edge"));
+        assert_eq!(ua.browser_version, Some("120.0.2210.91"));
+        assert_eq!(ua.os, Some("Windows"));
+        assert_eq!(ua.device, "desktop");
+
+        let ua = parse_user_agent_str(
+            "Mozilla/5.0 (iPhone; CPU iPhone OS 17_1 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.1 Mobile/15E148 Safari/604.1",
+        );
+        assert_eq!(ua.browser, Some("Safari"));
+        assert_eq!(ua.browser_version, Some("17.1"));
+        assert_eq!(ua.os, Some("iOS"));
+        assert_eq!(ua.device, "mobile");
+
+        let ua = parse_user_agent_str(
+            "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
+        );
+        assert_eq!(ua.browser, None);
+        assert_eq!(ua.device, "bot");
+    }
+}
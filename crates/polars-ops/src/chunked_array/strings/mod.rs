@@ -10,6 +10,8 @@ mod extract;
 mod find_many;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
+#[cfg(feature = "log_parsing")]
+mod log_parsing;
 #[cfg(feature = "strings")]
 mod namespace;
 #[cfg(feature = "string_normalize")]
@@ -26,6 +28,8 @@ mod strip;
 mod substring;
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
+#[cfg(feature = "url")]
+mod url;
 
 #[cfg(feature = "strings")]
 pub use concat::*;
@@ -35,6 +39,8 @@ pub use escape_regex::*;
 pub use find_many::*;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
+#[cfg(feature = "log_parsing")]
+pub use log_parsing::{common_log_dtype, user_agent_dtype};
 #[cfg(feature = "strings")]
 pub use namespace::*;
 #[cfg(feature = "string_normalize")]
@@ -46,6 +52,8 @@ pub use split::*;
 pub use strip::*;
 #[cfg(feature = "strings")]
 pub use substring::{substring_ternary_offsets_value, update_view};
+#[cfg(feature = "url")]
+pub use url::*;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
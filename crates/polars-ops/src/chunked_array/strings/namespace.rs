@@ -302,6 +302,38 @@ pub trait StringNameSpaceImpl: AsString {
         pad::pad_end(ca, length, fill_char)
     }
 
+    /// Pad the start of the string until it is `width` columns wide when displayed.
+    ///
+    /// East Asian wide characters are two columns wide and combining characters have no width.
+    /// Strings that are at least `width` columns wide are returned as-is, unless `truncate` is
+    /// set, in which case characters are removed from their end until they fit.
+    #[cfg(feature = "string_pad")]
+    fn pad_start_display(
+        &self,
+        width: &UInt64Chunked,
+        fill_char: char,
+        truncate: bool,
+    ) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        pad::pad_display(ca, width, fill_char, true, truncate)
+    }
+
+    /// Pad the end of the string until it is `width` columns wide when displayed.
+    ///
+    /// East Asian wide characters are two columns wide and combining characters have no width.
+    /// Strings that are at least `width` columns wide are returned as-is, unless `truncate` is
+    /// set, in which case characters are removed from their end until they fit.
+    #[cfg(feature = "string_pad")]
+    fn pad_end_display(
+        &self,
+        width: &UInt64Chunked,
+        fill_char: char,
+        truncate: bool,
+    ) -> PolarsResult<StringChunked> {
+        let ca = self.as_string();
+        pad::pad_display(ca, width, fill_char, false, truncate)
+    }
+
     /// Pad the start of the string with zeros until it reaches the given length.
     ///
     /// A sign prefix (`-`) is handled by inserting the padding after the sign
@@ -521,6 +553,42 @@ pub trait StringNameSpaceImpl: AsString {
         split_to_struct(ca, by, n + 1, str::split_inclusive, false)
     }
 
+    #[cfg(feature = "dtype-struct")]
+    /// Split `n` times like [`split_exact`](Self::split_exact), keeping the remainder of the
+    /// string in a final `rest` field instead of dropping it.
+    fn split_exact_with_rest(
+        &self,
+        by: &StringChunked,
+        n: usize,
+        inclusive: bool,
+    ) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+
+        let out = if inclusive {
+            split_to_struct(
+                ca,
+                by,
+                n + 1,
+                |s, by| split_with_rest(s, s.split_inclusive(by), n),
+                true,
+            )?
+        } else {
+            split_to_struct(
+                ca,
+                by,
+                n + 1,
+                |s, by| split_with_rest(s, s.split(by), n),
+                true,
+            )?
+        };
+        let mut fields = out.fields_as_series();
+        fields
+            .last_mut()
+            .unwrap()
+            .rename(PlSmallStr::from_static("rest"));
+        StructChunked::from_series(ca.name().clone(), ca.len(), fields.iter())
+    }
+
     #[cfg(feature = "dtype-struct")]
     fn splitn(&self, by: &StringChunked, n: usize) -> PolarsResult<StructChunked> {
         let ca = self.as_string();
@@ -665,6 +733,20 @@ pub trait StringNameSpaceImpl: AsString {
         reverse::reverse(ca)
     }
 
+    /// Parses lines in the Common (or Combined) Log Format into a struct.
+    #[cfg(feature = "log_parsing")]
+    fn str_parse_common_log(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        log_parsing::parse_common_log(ca)
+    }
+
+    /// Extracts the browser, operating system and device type from `User-Agent` values.
+    #[cfg(feature = "log_parsing")]
+    fn str_parse_user_agent(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        log_parsing::parse_user_agent(ca)
+    }
+
     /// Slice the string values.
     ///
     /// Determines a substring starting from `offset` and with length `length` of each of the elements in `array`.
@@ -1,7 +1,8 @@
 use std::fmt::Write;
 
 use polars_core::prelude::arity::broadcast_binary_elementwise;
-use polars_core::prelude::{StringChunked, UInt64Chunked};
+use polars_core::prelude::{PolarsResult, StringChunked, UInt64Chunked, polars_ensure};
+use unicode_width::UnicodeWidthChar;
 
 fn pad_fn<'a>(
     s: Option<&'a str>,
@@ -36,6 +37,56 @@ fn pad_fn<'a>(
     }
 }
 
+/// The number of columns `c` takes up in a terminal, e.g. 2 for East Asian wide characters and
+/// 0 for combining characters.
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+fn pad_display_fn<'a>(
+    s: Option<&'a str>,
+    width: Option<u64>,
+    buf: &mut String,
+    fill_char: char,
+    pad_start: bool,
+    truncate: bool,
+) -> Option<&'a str> {
+    let (Some(s), Some(width)) = (s, width) else {
+        return None;
+    };
+    let width = width as usize;
+
+    let mut s_width = 0;
+    let mut end = s.len();
+    for (i, c) in s.char_indices() {
+        let w = char_width(c);
+        if truncate && s_width + w > width {
+            end = i;
+            break;
+        }
+        s_width += w;
+    }
+    if s_width >= width && end == s.len() {
+        return Some(s);
+    }
+    let s = &s[..end];
+
+    buf.clear();
+    if !pad_start {
+        buf.push_str(s);
+    }
+    for _ in s_width..width {
+        buf.push(fill_char)
+    }
+    if pad_start {
+        buf.push_str(s);
+    }
+    // extend lifetime
+    // lifetime is bound to 'a
+    let slice = buf.as_str();
+    Some(unsafe { std::mem::transmute::<&str, &'a str>(slice) })
+}
+
 fn zfill_fn<'a>(s: Option<&'a str>, length: Option<u64>, buf: &mut String) -> Option<&'a str> {
     if let (Some(s), Some(length)) = (s, length) {
         let s_len = s.len();
@@ -107,3 +158,28 @@ pub(super) fn pad_end<'a>(
         infer(|opt_s, opt_len| pad_fn(opt_s, opt_len, &mut buf, fill_char, false)),
     )
 }
+
+pub(super) fn pad_display<'a>(
+    ca: &'a StringChunked,
+    width: &'a UInt64Chunked,
+    fill_char: char,
+    pad_start: bool,
+    truncate: bool,
+) -> PolarsResult<StringChunked> {
+    polars_ensure!(
+        char_width(fill_char) == 1,
+        InvalidOperation: "fill character {:?} must be one column wide", fill_char
+    );
+    // amortize allocation
+    let mut buf = String::new();
+    fn infer<F: for<'a> FnMut(Option<&'a str>, Option<u64>) -> Option<&'a str>>(f: F) -> F where {
+        f
+    }
+    Ok(broadcast_binary_elementwise(
+        ca,
+        width,
+        infer(|opt_s, opt_width| {
+            pad_display_fn(opt_s, opt_width, &mut buf, fill_char, pad_start, truncate)
+        }),
+    ))
+}
@@ -44,6 +44,45 @@ fn splitn_chars(s: &str, n: usize, keep_remainder: bool) -> SplitNChars<'_> {
     }
 }
 
+/// Yields the first `n` substrings of `parts`, followed by the remainder of `s` starting at the
+/// next substring.
+#[cfg(feature = "dtype-struct")]
+pub struct SplitWithRest<'a, I> {
+    s: &'a str,
+    parts: I,
+    n: Option<usize>,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for SplitWithRest<'a, I> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.n?;
+        let part = self.parts.next()?;
+        if n == 0 {
+            self.n = None;
+            // The parts are substrings of `s`, so the remainder starts where this part starts.
+            let offset = part.as_ptr() as usize - self.s.as_ptr() as usize;
+            Some(&self.s[offset..])
+        } else {
+            self.n = Some(n - 1);
+            Some(part)
+        }
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+pub fn split_with_rest<'a, I>(s: &'a str, parts: I, n: usize) -> SplitWithRest<'a, I>
+where
+    I: Iterator<Item = &'a str>,
+{
+    SplitWithRest {
+        s,
+        parts,
+        n: Some(n),
+    }
+}
+
 /// Splits a string into substrings consisting of single characters.
 fn split_chars(s: &str) -> SplitNChars<'_> {
     SplitNChars {
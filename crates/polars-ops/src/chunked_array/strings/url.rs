@@ -0,0 +1,237 @@
+use std::borrow::Cow;
+
+use arrow::array::{Array, MutablePlString, StructArray, Utf8ViewArray};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use polars_core::prelude::arity::unary_elementwise;
+
+use super::*;
+
+/// Characters that are left untouched when percent-encoding a URL component.
+/// These are the "unreserved" characters of RFC 3986.
+const URL_COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+const URL_FIELDS: [&str; 5] = ["scheme", "host", "path", "query", "fragment"];
+
+/// The components of a URL as defined by RFC 3986, borrowed from the input.
+#[derive(Debug, Default, PartialEq, Eq)]
+struct UrlParts<'a> {
+    scheme: Option<&'a str>,
+    host: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+fn is_scheme(s: &str) -> bool {
+    let mut bytes = s.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+/// Extract the host from an authority component, dropping user info and port.
+fn authority_host(authority: &str) -> Option<&str> {
+    let host_port = match authority.rfind('@') {
+        Some(idx) => &authority[idx + 1..],
+        None => authority,
+    };
+    let host = if host_port.starts_with('[') {
+        // IPv6 literal, keep the brackets.
+        match host_port.find(']') {
+            Some(idx) => &host_port[..=idx],
+            None => host_port,
+        }
+    } else {
+        match host_port.find(':') {
+            Some(idx) => &host_port[..idx],
+            None => host_port,
+        }
+    };
+    (!host.is_empty()).then_some(host)
+}
+
+impl<'a> UrlParts<'a> {
+    /// Split a URL into its components.
+    ///
+    /// This follows the generic syntax of RFC 3986 (appendix B) and never fails; components
+    /// that are not present are returned as `None`.
+    fn parse(url: &'a str) -> Self {
+        let mut rest = url;
+
+        let mut fragment = None;
+        if let Some(idx) = rest.find('#') {
+            fragment = Some(&rest[idx + 1..]);
+            rest = &rest[..idx];
+        }
+
+        let mut query = None;
+        if let Some(idx) = rest.find('?') {
+            query = Some(&rest[idx + 1..]);
+            rest = &rest[..idx];
+        }
+
+        let mut scheme = None;
+        if let Some(idx) = rest.find(':') {
+            let candidate = &rest[..idx];
+            if !candidate.contains('/') && is_scheme(candidate) {
+                scheme = Some(candidate);
+                rest = &rest[idx + 1..];
+            }
+        }
+
+        let mut host = None;
+        if let Some(after) = rest.strip_prefix("//") {
+            let end = after.find('/').unwrap_or(after.len());
+            host = authority_host(&after[..end]);
+            rest = &after[end..];
+        }
+
+        UrlParts {
+            scheme,
+            host,
+            path: rest,
+            query,
+            fragment,
+        }
+    }
+}
+
+/// Get the first value of `key` in a query string, decoded as `application/x-www-form-urlencoded`.
+fn query_value<'a>(query: &'a str, key: &str) -> Option<Cow<'a, str>> {
+    query.split('&').find_map(|pair| {
+        let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+        if form_decode(k).as_deref() == Some(key) {
+            form_decode(v)
+        } else {
+            None
+        }
+    })
+}
+
+fn form_decode(s: &str) -> Option<Cow<'_, str>> {
+    if s.contains('+') {
+        let s = s.replace('+', " ");
+        let decoded = percent_decode_str(&s).decode_utf8().ok()?;
+        Some(Cow::Owned(decoded.into_owned()))
+    } else {
+        percent_decode_str(s).decode_utf8().ok()
+    }
+}
+
+fn url_parse_array(arr: &Utf8ViewArray, dtype: ArrowDataType) -> ArrayRef {
+    let mut builders: [MutablePlString; 5] =
+        std::array::from_fn(|_| MutablePlString::with_capacity(arr.len()));
+
+    for opt_v in arr {
+        match opt_v {
+            Some(s) => {
+                let parts = UrlParts::parse(s);
+                builders[0].push(parts.scheme);
+                builders[1].push(parts.host);
+                builders[2].push(Some(parts.path));
+                builders[3].push(parts.query);
+                builders[4].push(parts.fragment);
+            },
+            None => builders.iter_mut().for_each(|b| b.push_null()),
+        }
+    }
+
+    let values = builders.into_iter().map(|b| b.freeze().boxed()).collect();
+    StructArray::new(dtype, arr.len(), values, arr.validity().cloned()).boxed()
+}
+
+/// The output [`DataType`] of [`UrlNameSpaceImpl::url_parse`].
+pub fn url_parse_dtype() -> DataType {
+    DataType::Struct(
+        URL_FIELDS
+            .iter()
+            .map(|name| Field::new(PlSmallStr::from_static(name), DataType::String))
+            .collect(),
+    )
+}
+
+pub trait UrlNameSpaceImpl: AsString {
+    /// Split URLs into a struct of `scheme`, `host`, `path`, `query` and `fragment`.
+    fn url_parse(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_string();
+        let arrow_dtype = url_parse_dtype().try_to_arrow(CompatLevel::newest())?;
+        let chunks = ca
+            .downcast_iter()
+            .map(|arr| url_parse_array(arr, arrow_dtype.clone()))
+            .collect::<Vec<_>>();
+        let s = Series::try_from((ca.name().clone(), chunks))?;
+        s.struct_().cloned()
+    }
+
+    /// Get the decoded value of the first occurrence of `key` in the query string of URLs.
+    fn url_query_param(&self, key: &str) -> StringChunked {
+        let ca = self.as_string();
+        unary_elementwise(ca, |opt_s| {
+            opt_s.and_then(|s| UrlParts::parse(s).query.and_then(|q| query_value(q, key)))
+        })
+    }
+
+    /// Percent-encode all characters that are not unreserved in a URL component.
+    fn url_encode(&self) -> StringChunked {
+        let ca = self.as_string();
+        unary_elementwise(ca, |opt_s| {
+            opt_s.map(|s| Cow::from(utf8_percent_encode(s, URL_COMPONENT)))
+        })
+    }
+
+    /// Decode percent-encoded sequences. Values that don't decode to valid UTF-8 become null.
+    fn url_decode(&self) -> StringChunked {
+        let ca = self.as_string();
+        unary_elementwise(ca, |opt_s| {
+            opt_s.and_then(|s| percent_decode_str(s).decode_utf8().ok())
+        })
+    }
+}
+
+impl UrlNameSpaceImpl for StringChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_url_parts() {
+        let parts = UrlParts::parse("https://user:pw@example.com:8080/a/b?x=1&y=2#top");
+        assert_eq!(
+            parts,
+            UrlParts {
+                scheme: Some("https"),
+                host: Some("example.com"),
+                path: "/a/b",
+                query: Some("x=1&y=2"),
+                fragment: Some("top"),
+            }
+        );
+
+        let parts = UrlParts::parse("/relative/path?q");
+        assert_eq!(parts.scheme, None);
+        assert_eq!(parts.host, None);
+        assert_eq!(parts.path, "/relative/path");
+        assert_eq!(parts.query, Some("q"));
+
+        let parts = UrlParts::parse("mailto:someone@example.com");
+        assert_eq!(parts.scheme, Some("mailto"));
+        assert_eq!(parts.host, None);
+        assert_eq!(parts.path, "someone@example.com");
+
+        let parts = UrlParts::parse("http://[::1]:80/");
+        assert_eq!(parts.host, Some("[::1]"));
+    }
+
+    #[test]
+    fn test_query_value() {
+        let q = "a=1&b=hello+world&c=%C3%A9&a=2";
+        assert_eq!(query_value(q, "a").as_deref(), Some("1"));
+        assert_eq!(query_value(q, "b").as_deref(), Some("hello world"));
+        assert_eq!(query_value(q, "c").as_deref(), Some("é"));
+        assert_eq!(query_value(q, "d"), None);
+    }
+}
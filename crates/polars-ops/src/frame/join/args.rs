@@ -28,6 +28,11 @@ pub struct JoinArgs {
     pub suffix: Option<PlSmallStr>,
     pub slice: Option<(i64, usize)>,
     pub nulls_equal: bool,
+    /// Whether NaN keys match each other, `None` uses [`polars_core::config::join_nans_equal`].
+    ///
+    /// This is applied when a query plan is converted, the join kernels always match NaN keys.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub nans_equal: Option<bool>,
     pub coalesce: JoinCoalesce,
     pub maintain_order: MaintainOrderJoin,
 }
@@ -126,6 +131,7 @@ impl JoinArgs {
             suffix: None,
             slice: None,
             nulls_equal: false,
+            nans_equal: None,
             coalesce: Default::default(),
             maintain_order: Default::default(),
         }
@@ -79,6 +79,19 @@ pub trait CrossJoin: IntoDf {
 
         _finish_join(l_df, r_df, suffix)
     }
+
+    #[doc(hidden)]
+    /// Creates the Cartesian product from both frames, preserves the order of the right keys.
+    fn _cross_join_right_order(
+        &self,
+        other: &DataFrame,
+        suffix: Option<PlSmallStr>,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<DataFrame> {
+        let (r_df, l_df) = cross_join_dfs(other, self.to_df(), slice, true)?;
+
+        _finish_join(l_df, r_df, suffix)
+    }
 }
 
 impl CrossJoin for DataFrame {}
@@ -1,5 +1,7 @@
 use arrow::array::PrimitiveArray;
-use polars_core::chunked_array::ops::row_encode::encode_rows_unordered;
+use polars_core::chunked_array::ops::row_encode::{
+    encode_rows_unordered, encode_rows_vertical_par_unordered_broadcast_nulls,
+};
 use polars_core::series::BitRepr;
 use polars_core::utils::split;
 use polars_core::with_match_physical_float_polars_type;
@@ -66,20 +68,20 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 )
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_left(rhs, validate, nulls_equal)
             },
             x if x.is_float() => {
@@ -166,20 +168,20 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 }
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_semi_anti(rhs, anti, nulls_equal)?
             },
             x if x.is_float() => {
@@ -289,20 +291,20 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 ))
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_inner(rhs, validate, nulls_equal)
             },
             x if x.is_float() => {
@@ -381,20 +383,20 @@ pub trait SeriesJoin: SeriesSealed + Sized {
                 hash_join_tuples_outer(lhs, rhs, swapped, validate, nulls_equal)
             },
             T::List(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_outer(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-array")]
             T::Array(_, _) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_outer(rhs, validate, nulls_equal)
             },
             #[cfg(feature = "dtype-struct")]
             T::Struct(_) => {
-                let lhs = &encode_rows_unordered(&[lhs.into_owned().into()])?.into_series();
-                let rhs = &encode_rows_unordered(&[rhs.into_owned().into()])?.into_series();
+                let lhs = &prepare_nested_keys(lhs.into_owned(), nulls_equal)?;
+                let rhs = &prepare_nested_keys(rhs.into_owned(), nulls_equal)?;
                 lhs.hash_join_outer(rhs, validate, nulls_equal)
             },
             x if x.is_float() => {
@@ -437,6 +439,18 @@ pub trait SeriesJoin: SeriesSealed + Sized {
 
 impl SeriesJoin for Series {}
 
+/// Row encode a nested key column so that it can be joined as binary. Null keys stay null unless
+/// `nulls_equal`, as the encoding of a null row is a valid value.
+fn prepare_nested_keys(s: Series, nulls_equal: bool) -> PolarsResult<Series> {
+    let keys = [s.into_column()];
+    let encoded = if nulls_equal {
+        encode_rows_unordered(&keys)?
+    } else {
+        encode_rows_vertical_par_unordered_broadcast_nulls(&keys)?
+    };
+    Ok(encoded.into_series())
+}
+
 fn chunks_as_slices<T>(splitted: &[ChunkedArray<T>]) -> Vec<&[T::Native]>
 where
     T: PolarsNumericType,
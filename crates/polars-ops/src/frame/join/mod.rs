@@ -136,6 +136,12 @@ pub trait DataFrameJoinOps: IntoDf {
                 assert!(args.slice.is_none());
                 return fused_cross_filter(left_df, other, args.suffix.clone(), cross_options);
             }
+            if matches!(
+                args.maintain_order,
+                MaintainOrderJoin::Right | MaintainOrderJoin::RightLeft
+            ) {
+                return left_df._cross_join_right_order(other, args.suffix.clone(), args.slice);
+            }
             return left_df.cross_join(other, args.suffix.clone(), args.slice);
         }
 
@@ -1,6 +1,9 @@
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
+mod plot;
+#[cfg(feature = "approx_unique")]
+mod summary;
 
 pub use join::*;
 #[cfg(feature = "to_dummies")]
@@ -122,4 +125,34 @@ pub trait DataFrameOps: IntoDf {
 
         accumulate_dataframes_horizontal(cols)
     }
+
+    /// Summarize every column in a single parallel sweep over the columns.
+    ///
+    /// The result has a row per column with its name, dtype, null count, approximate number of
+    /// unique values, minimum and maximum, and the first `n_samples` values. The minimum, maximum
+    /// and samples are formatted as strings so that columns of all dtypes fit in one frame; they
+    /// are null if they are not supported for the dtype of the column.
+    #[cfg(feature = "approx_unique")]
+    fn summarize(&self, n_samples: usize) -> PolarsResult<DataFrame> {
+        summary::summarize(self.to_df(), n_samples)
+    }
+
+    /// Downsample to `n` rows for a line plot of `y` against `x`, keeping its visual shape.
+    ///
+    /// The rows are selected with the Largest-Triangle-Three-Buckets algorithm, which assumes the
+    /// frame is sorted by `x`. Rows in which `x` or `y` is null or not finite are dropped, and all
+    /// rows are kept if there are at most `n` of them.
+    fn downsample_lttb(&self, x: &str, y: &str, n: usize) -> PolarsResult<DataFrame> {
+        plot::downsample_lttb(self.to_df(), x, y, n)
+    }
+
+    /// Count the points of a scatter plot of `y` against `x` in a grid of `bins` equally wide
+    /// bins along the x- and y-axis that spans the points.
+    ///
+    /// The result has a row per non-empty bin with the center of the bin in the `x` and `y`
+    /// columns, as `Float64`, and the number of points in the `count` column. Rows in which `x` or
+    /// `y` is null or not finite are ignored.
+    fn bin2d(&self, x: &str, y: &str, bins: [usize; 2]) -> PolarsResult<DataFrame> {
+        plot::bin2d(self.to_df(), x, y, bins)
+    }
 }
@@ -0,0 +1,65 @@
+use polars_core::prelude::*;
+
+use crate::series::{finite_points, lttb_indices};
+
+/// Downsample `df` to `n` rows, see
+/// [`DataFrameOps::downsample_lttb`](super::DataFrameOps::downsample_lttb).
+pub(super) fn downsample_lttb(
+    df: &DataFrame,
+    x: &str,
+    y: &str,
+    n: usize,
+) -> PolarsResult<DataFrame> {
+    let x = df.column(x)?.as_materialized_series();
+    let y = df.column(y)?.as_materialized_series();
+    df.take(&lttb_indices(x, y, n)?)
+}
+
+/// Get the start and the width of `bins` equally wide bins spanning `values`.
+fn bin_edges(values: impl Iterator<Item = f64>, bins: usize) -> (f64, f64) {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+        (min.min(v), max.max(v))
+    });
+    (min, (max - min) / bins as f64)
+}
+
+/// Count the points of `df` in a grid, see [`DataFrameOps::bin2d`](super::DataFrameOps::bin2d).
+pub(super) fn bin2d(df: &DataFrame, x: &str, y: &str, bins: [usize; 2]) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        bins[0] > 0 && bins[1] > 0,
+        InvalidOperation: "`bins` must be positive, got {:?}", bins
+    );
+    let points = finite_points(
+        df.column(x)?.as_materialized_series(),
+        df.column(y)?.as_materialized_series(),
+    )?;
+
+    let (x_min, x_width) = bin_edges(points.iter().map(|p| p.1), bins[0]);
+    let (y_min, y_width) = bin_edges(points.iter().map(|p| p.2), bins[1]);
+    let bin = |v: f64, min: f64, width: f64, bins: usize| {
+        if width > 0.0 {
+            (((v - min) / width) as usize).min(bins - 1)
+        } else {
+            0
+        }
+    };
+
+    let mut counts = vec![0 as IdxSize; bins[0] * bins[1]];
+    for &(_, x, y) in &points {
+        counts[bin(x, x_min, x_width, bins[0]) * bins[1] + bin(y, y_min, y_width, bins[1])] += 1;
+    }
+
+    let mut xs = Vec::new();
+    let mut ys = Vec::new();
+    let mut non_empty = Vec::new();
+    for (i, &count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+        xs.push(x_min + ((i / bins[1]) as f64 + 0.5) * x_width);
+        ys.push(y_min + ((i % bins[1]) as f64 + 0.5) * y_width);
+        non_empty.push(count);
+    }
+    DataFrame::new(vec![
+        Column::new(x.into(), xs),
+        Column::new(y.into(), ys),
+        Column::new(PlSmallStr::from_static("count"), non_empty),
+    ])
+}
@@ -0,0 +1,164 @@
+use std::hash::Hash;
+
+use polars_compute::hyperloglogplus::HyperLogLog;
+use polars_core::prelude::*;
+use polars_core::{POOL, with_match_physical_numeric_polars_type};
+use polars_utils::float::IsFloat;
+use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash, TotalOrd};
+use rayon::prelude::*;
+
+struct ColumnSummary {
+    name: String,
+    dtype: String,
+    null_count: IdxSize,
+    n_unique: Option<IdxSize>,
+    min: Option<String>,
+    max: Option<String>,
+    samples: Option<Series>,
+}
+
+fn scalar_to_string(scalar: PolarsResult<Scalar>) -> Option<String> {
+    let scalar = scalar.ok()?;
+    if scalar.is_null() {
+        return None;
+    }
+    let s = scalar
+        .into_series(PlSmallStr::EMPTY)
+        .cast(&DataType::String)
+        .ok()?;
+    s.str().ok()?.get(0).map(str::to_string)
+}
+
+fn value_to_string(s: &Series, idx: Option<usize>) -> Option<String> {
+    let s = s.slice(idx? as i64, 1).cast(&DataType::String).ok()?;
+    s.str().ok()?.get(0).map(str::to_string)
+}
+
+/// The approximate number of unique values and the indices of the minimum and maximum of `ca`,
+/// computed in a single pass over the values. NaN is only the minimum or maximum if all non-null
+/// values are NaN, like in [`Series::min_reduce`] and [`Series::max_reduce`].
+fn fused_stats<T>(ca: &ChunkedArray<T>) -> (IdxSize, Option<usize>, Option<usize>)
+where
+    T: PolarsDataType,
+    for<'a> T::Physical<'a>: TotalHash + TotalEq + TotalOrd + IsFloat + Copy + ToTotalOrd,
+    for<'a> <Option<T::Physical<'a>> as ToTotalOrd>::TotalOrdItem: Hash + Eq,
+{
+    let mut hllp = HyperLogLog::new();
+    let mut min: Option<(usize, T::Physical<'_>)> = None;
+    let mut max: Option<(usize, T::Physical<'_>)> = None;
+    let mut first_nan = None;
+    for (i, item) in ca.iter().enumerate() {
+        hllp.add(&item.to_total_ord());
+        let Some(v) = item else {
+            continue;
+        };
+        if v.is_nan() {
+            first_nan.get_or_insert(i);
+            continue;
+        }
+        if min.is_none_or(|(_, m)| v.tot_lt(&m)) {
+            min = Some((i, v));
+        }
+        if max.is_none_or(|(_, m)| v.tot_gt(&m)) {
+            max = Some((i, v));
+        }
+    }
+    (
+        hllp.count() as IdxSize,
+        min.map(|(i, _)| i).or(first_nan),
+        max.map(|(i, _)| i).or(first_nan),
+    )
+}
+
+/// Run [`fused_stats`] on the physical values of `s`, if they are ordered like the logical ones.
+fn try_fused_stats(s: &Series) -> Option<(IdxSize, Option<usize>, Option<usize>)> {
+    use DataType::*;
+    match s.dtype() {
+        Boolean => Some(fused_stats(s.bool().unwrap())),
+        String => Some(fused_stats(s.str().unwrap())),
+        Binary => Some(fused_stats(s.binary().unwrap())),
+        // The physical values of Float16 are its bits, which are not ordered like the floats.
+        dt if dt.is_float16() => None,
+        dt if dt.is_primitive_numeric() || dt.is_temporal() || dt.is_decimal() => {
+            let phys = s.to_physical_repr();
+            with_match_physical_numeric_polars_type!(phys.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = phys.as_ref().as_ref().as_ref();
+                Some(fused_stats(ca))
+            })
+        },
+        _ => None,
+    }
+}
+
+fn summarize_column(c: &Column, n_samples: usize) -> ColumnSummary {
+    let s = c.as_materialized_series();
+    let (n_unique, min, max) = match try_fused_stats(s) {
+        Some((n_unique, min, max)) => (
+            Some(n_unique),
+            value_to_string(s, min),
+            value_to_string(s, max),
+        ),
+        None => {
+            let orderable = !s.dtype().is_nested() && !s.dtype().is_object();
+            (
+                s.approx_n_unique().ok(),
+                orderable
+                    .then(|| scalar_to_string(s.min_reduce()))
+                    .flatten(),
+                orderable
+                    .then(|| scalar_to_string(s.max_reduce()))
+                    .flatten(),
+            )
+        },
+    };
+    ColumnSummary {
+        name: c.name().to_string(),
+        dtype: c.dtype().to_string(),
+        null_count: c.null_count() as IdxSize,
+        n_unique,
+        min,
+        max,
+        samples: s.head(Some(n_samples)).cast(&DataType::String).ok(),
+    }
+}
+
+/// Summarize all columns of `df`, see [`DataFrameOps::summarize`](super::DataFrameOps::summarize).
+pub(super) fn summarize(df: &DataFrame, n_samples: usize) -> PolarsResult<DataFrame> {
+    let summaries = POOL.install(|| {
+        df.get_columns()
+            .par_iter()
+            .map(|c| summarize_column(c, n_samples))
+            .collect::<Vec<_>>()
+    });
+
+    let n = summaries.len();
+    let mut names = Vec::with_capacity(n);
+    let mut dtypes = Vec::with_capacity(n);
+    let mut null_counts = Vec::with_capacity(n);
+    let mut n_uniques = Vec::with_capacity(n);
+    let mut mins = Vec::with_capacity(n);
+    let mut maxs = Vec::with_capacity(n);
+    let mut samples = Vec::with_capacity(n);
+    for summary in summaries {
+        names.push(summary.name);
+        dtypes.push(summary.dtype);
+        null_counts.push(summary.null_count);
+        n_uniques.push(summary.n_unique);
+        mins.push(summary.min);
+        maxs.push(summary.max);
+        samples.push(summary.samples);
+    }
+
+    // All samples might be missing, make sure the dtype does not depend on that.
+    let samples = Series::new(PlSmallStr::from_static("samples"), samples)
+        .cast(&DataType::List(Box::new(DataType::String)))?;
+    DataFrame::new(vec![
+        Column::new(PlSmallStr::from_static("column"), names),
+        Column::new(PlSmallStr::from_static("dtype"), dtypes),
+        Column::new(PlSmallStr::from_static("null_count"), null_counts),
+        Column::new(PlSmallStr::from_static("n_unique_approx"), n_uniques),
+        Column::new(PlSmallStr::from_static("min"), mins),
+        Column::new(PlSmallStr::from_static("max"), maxs),
+        samples.into_column(),
+    ])
+}
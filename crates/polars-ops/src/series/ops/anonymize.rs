@@ -0,0 +1,122 @@
+use arrow::types::NativeType;
+use polars_core::prelude::*;
+use polars_core::with_match_physical_numeric_polars_type;
+
+/// Context from which the BLAKE3 keys are derived, so that the hashes can't be matched with other
+/// uses of the same salts.
+const KEY_CONTEXT: &str = "polars 2025-10 anon.hash_stable";
+
+/// Hash the values of `s` with keyed BLAKE3, keyed by the salt `salts[salt_id]` of every row.
+///
+/// The values are hashed by their physical bytes, in little endian, except for categoricals, which
+/// are hashed by their strings. The hash only depends on those bytes, the data type's width and
+/// the salt, so it is stable across sessions and Polars versions, and can't be reversed without
+/// the salt. Rotating the salt is done by appending a new salt and switching `salt_id`, which
+/// keeps older salts available to re-identify previously hashed data. `salt_id` of length 1 is
+/// broadcast. Null values or salt ids yield null.
+pub fn hash_stable(
+    s: &Series,
+    salt_id: &Series,
+    salts: &[PlSmallStr],
+) -> PolarsResult<UInt64Chunked> {
+    polars_ensure!(
+        !salts.is_empty(),
+        InvalidOperation: "`hash_stable` requires at least one salt, note that salts are not \
+        serialized with a query"
+    );
+    polars_ensure!(
+        salt_id.len() == 1 || salt_id.len() == s.len(),
+        length_mismatch = "hash_stable",
+        s.len(),
+        salt_id.len()
+    );
+
+    let keys: Vec<[u8; 32]> = salts
+        .iter()
+        .map(|salt| blake3::derive_key(KEY_CONTEXT, salt.as_bytes()))
+        .collect();
+    let salt_id = salt_id.strict_cast(&IDX_DTYPE)?;
+    let salt_id = salt_id.idx()?;
+    if let Some(max) = salt_id.max() {
+        polars_ensure!(
+            (max as usize) < salts.len(),
+            OutOfBounds: "`salt_id` {} is out of bounds for {} salts", max, salts.len()
+        );
+    }
+
+    let out = match s.dtype() {
+        DataType::Binary => hash_values(s.binary()?.iter(), &keys, salt_id),
+        DataType::String => {
+            let values = s.str()?.iter().map(|v| v.map(str::as_bytes));
+            hash_values(values, &keys, salt_id)
+        },
+        dt if dt.is_categorical() || dt.is_enum() => {
+            let s = s.cast(&DataType::String)?;
+            let values = s.str()?.iter().map(|v| v.map(str::as_bytes));
+            hash_values(values, &keys, salt_id)
+        },
+        DataType::Boolean => {
+            let values = s.bool()?.iter().map(|v| v.map(|v| [v as u8]));
+            hash_values(values, &keys, salt_id)
+        },
+        dt if dt.to_physical().is_primitive_numeric() => {
+            let s = s.to_physical_repr();
+            with_match_physical_numeric_polars_type!(s.dtype(), |$T| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                let values = ca.iter().map(|v| v.map(|v| v.to_le_bytes()));
+                hash_values(values, &keys, salt_id)
+            })
+        },
+        dt => polars_bail!(InvalidOperation: "`hash_stable` is not supported for dtype {}", dt),
+    };
+    Ok(out.with_name(s.name().clone()))
+}
+
+fn hash_values<B: AsRef<[u8]>>(
+    values: impl Iterator<Item = Option<B>>,
+    keys: &[[u8; 32]],
+    salt_id: &IdxCa,
+) -> UInt64Chunked {
+    let broadcast = salt_id.len() == 1;
+    values
+        .enumerate()
+        .map(|(i, v)| {
+            let idx = salt_id.get(if broadcast { 0 } else { i })?;
+            let hash = blake3::keyed_hash(&keys[idx as usize], v?.as_ref());
+            Some(u64::from_le_bytes(hash.as_bytes()[..8].try_into().unwrap()))
+        })
+        .collect()
+}
+
+/// Replace the values that occur fewer than `k` times by null.
+pub fn suppress_rare(s: &Series, k: IdxSize) -> PolarsResult<Series> {
+    let groups = s.group_tuples(true, false)?;
+    let mut rare = false;
+    let mut idx: Vec<Option<IdxSize>> = (0..s.len() as IdxSize).map(Some).collect();
+    match &groups {
+        GroupsType::Idx(groups) => {
+            for (_, group) in groups.iter() {
+                if (group.len() as IdxSize) < k {
+                    rare = true;
+                    for &i in group.iter() {
+                        idx[i as usize] = None;
+                    }
+                }
+            }
+        },
+        GroupsType::Slice { groups, .. } => {
+            for &[first, len] in groups.iter() {
+                if len < k {
+                    rare = true;
+                    idx[first as usize..(first + len) as usize].fill(None);
+                }
+            }
+        },
+    }
+
+    if !rare {
+        return Ok(s.clone());
+    }
+    let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx.into_iter());
+    s.take(&idx)
+}
@@ -0,0 +1,195 @@
+use polars_core::prelude::*;
+use rand::distr::weighted::WeightedIndex;
+use rand::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+/// The statistic that is computed on every bootstrap sample.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum BootstrapStatistic {
+    Mean,
+    Median,
+    Sum,
+    Std,
+    Var,
+    Min,
+    Max,
+}
+
+/// How the bootstrap samples are drawn.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum BootstrapMethod {
+    /// Draw single values with replacement, with probabilities proportional to the weights if
+    /// given.
+    Iid,
+    /// Moving block bootstrap: draw runs of `block_size` consecutive values with replacement,
+    /// which keeps the autocorrelation of a series within the blocks.
+    Block { block_size: usize },
+}
+
+impl BootstrapStatistic {
+    fn compute(self, values: &mut [f64]) -> f64 {
+        let n = values.len() as f64;
+        match self {
+            Self::Mean => values.iter().sum::<f64>() / n,
+            Self::Sum => values.iter().sum(),
+            Self::Min => values.iter().copied().fold(f64::INFINITY, f64::min),
+            Self::Max => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            Self::Median => {
+                let mid = values.len() / 2;
+                let (lower, upper, _) = values.select_nth_unstable_by(mid, f64::total_cmp);
+                if values.len() % 2 == 1 {
+                    *upper
+                } else {
+                    let lower_max = lower.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                    (lower_max + *upper) / 2.0
+                }
+            },
+            Self::Std | Self::Var => {
+                if values.len() < 2 {
+                    return f64::NAN;
+                }
+                let mean = values.iter().sum::<f64>() / n;
+                let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+                if self == Self::Std { var.sqrt() } else { var }
+            },
+        }
+    }
+}
+
+/// Linearly interpolated quantile of sorted values.
+fn sorted_quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f64)
+}
+
+/// The fields of the struct returned by [`bootstrap`].
+pub fn bootstrap_output_dtype() -> DataType {
+    DataType::Struct(
+        ["mean", "std", "lower", "upper"]
+            .into_iter()
+            .map(|name| Field::new(name.into(), DataType::Float64))
+            .collect(),
+    )
+}
+
+/// Estimate the sampling distribution of `statistic` over the non-null values of `s` by
+/// resampling them `n_samples` times.
+///
+/// Returns a single-row struct with the mean and standard deviation of the statistic over the
+/// samples, and the `lower` and `upper` bounds of the percentile confidence interval at
+/// `confidence_level`. With `weights`, values are drawn with probabilities proportional to their
+/// weight, rows with a null value or weight are dropped. The samples are drawn from one generator
+/// seeded with `seed`, so the same seed gives the same result for every group.
+pub fn bootstrap(
+    s: &Series,
+    weights: Option<&Series>,
+    n_samples: usize,
+    statistic: BootstrapStatistic,
+    method: BootstrapMethod,
+    confidence_level: f64,
+    seed: Option<u64>,
+) -> PolarsResult<Series> {
+    polars_ensure!(n_samples > 0, InvalidOperation: "`n_samples` must be at least 1");
+    polars_ensure!(
+        confidence_level > 0.0 && confidence_level < 1.0,
+        InvalidOperation: "`confidence_level` must be between 0 and 1, got {confidence_level}"
+    );
+    polars_ensure!(
+        s.dtype().is_primitive_numeric() || s.dtype().is_bool() || s.dtype().is_null(),
+        InvalidOperation: "`bootstrap` requires a numeric input, got {}", s.dtype()
+    );
+    if let Some(weights) = weights {
+        polars_ensure!(
+            weights.len() == s.len(),
+            length_mismatch = "bootstrap",
+            s.len(),
+            weights.len()
+        );
+        polars_ensure!(
+            matches!(method, BootstrapMethod::Iid),
+            InvalidOperation: "`weights` are only supported with the 'iid' bootstrap method"
+        );
+    }
+    if let BootstrapMethod::Block { block_size } = method {
+        polars_ensure!(block_size > 0, InvalidOperation: "`block_size` must be at least 1");
+    }
+
+    let values = s.cast(&DataType::Float64)?;
+    let values = values.f64()?;
+    let (values, weights): (Vec<f64>, Option<Vec<f64>>) = match weights {
+        None => (values.iter().flatten().collect(), None),
+        Some(weights) => {
+            let weights = weights.cast(&DataType::Float64)?;
+            let (values, weights) = values
+                .iter()
+                .zip(weights.f64()?.iter())
+                .filter_map(|(v, w)| Some((v?, w?)))
+                .unzip();
+            (values, Some(weights))
+        },
+    };
+
+    let fields = if values.is_empty() {
+        ["mean", "std", "lower", "upper"].map(|name| Series::new(name.into(), [None::<f64>]))
+    } else {
+        let mut rng = match seed {
+            Some(seed) => SmallRng::seed_from_u64(seed),
+            None => SmallRng::from_os_rng(),
+        };
+        let weighted = weights
+            .map(|w| {
+                WeightedIndex::new(w)
+                    .map_err(|e| polars_err!(InvalidOperation: "invalid bootstrap weights: {}", e))
+            })
+            .transpose()?;
+
+        let n = values.len();
+        let mut sample = Vec::with_capacity(n);
+        let mut stats: Vec<f64> = (0..n_samples)
+            .map(|_| {
+                sample.clear();
+                match (&weighted, method) {
+                    (Some(weighted), _) => {
+                        sample.extend((0..n).map(|_| values[weighted.sample(&mut rng)]))
+                    },
+                    (None, BootstrapMethod::Iid) => {
+                        sample.extend((0..n).map(|_| values[rng.random_range(0..n)]))
+                    },
+                    (None, BootstrapMethod::Block { block_size }) => {
+                        let block_size = block_size.min(n);
+                        while sample.len() < n {
+                            let start = rng.random_range(0..=n - block_size);
+                            let take = block_size.min(n - sample.len());
+                            sample.extend_from_slice(&values[start..start + take]);
+                        }
+                    },
+                }
+                statistic.compute(&mut sample)
+            })
+            .collect();
+
+        stats.sort_unstable_by(f64::total_cmp);
+        let mean = stats.iter().sum::<f64>() / n_samples as f64;
+        let std = BootstrapStatistic::Std.compute(&mut stats);
+        let alpha = (1.0 - confidence_level) / 2.0;
+        [
+            ("mean", mean),
+            ("std", std),
+            ("lower", sorted_quantile(&stats, alpha)),
+            ("upper", sorted_quantile(&stats, 1.0 - alpha)),
+        ]
+        .map(|(name, v)| Series::new(name.into(), [v]))
+    };
+
+    StructChunked::from_series(s.name().clone(), 1, fields.iter()).map(|ca| ca.into_series())
+}
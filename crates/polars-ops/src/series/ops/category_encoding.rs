@@ -0,0 +1,139 @@
+use std::hash::BuildHasher;
+
+use polars_core::prelude::*;
+use polars_utils::aliases::PlFixedStateQuality;
+use polars_utils::hashing::hash_to_partition;
+
+/// The group of every row and the number of groups, groups are numbered in order of appearance.
+fn row_groups(s: &Series) -> PolarsResult<(Vec<IdxSize>, usize)> {
+    let groups = s.group_tuples(true, false)?;
+    let mut out = vec![0 as IdxSize; s.len()];
+    match &groups {
+        GroupsType::Idx(groups) => {
+            for (g, (_, idx)) in groups.iter().enumerate() {
+                for &i in idx.iter() {
+                    out[i as usize] = g as IdxSize;
+                }
+            }
+        },
+        GroupsType::Slice { groups, .. } => {
+            for (g, &[first, len]) in groups.iter().enumerate() {
+                out[first as usize..(first + len) as usize].fill(g as IdxSize);
+            }
+        },
+    }
+    Ok((out, groups.len()))
+}
+
+/// Replace every value by the number of times it occurs, or by the fraction of the rows it
+/// occurs in if `normalize` is set.
+///
+/// Null is counted as a value of its own.
+pub fn frequency_encode(s: &Series, normalize: bool) -> PolarsResult<Series> {
+    let (row_groups, n_groups) = row_groups(s)?;
+    let mut counts = vec![0 as IdxSize; n_groups];
+    for &g in &row_groups {
+        counts[g as usize] += 1;
+    }
+
+    let name = s.name().clone();
+    let counts = row_groups.iter().map(|&g| counts[g as usize]);
+    let out = if normalize {
+        let len = s.len() as f64;
+        Float64Chunked::from_iter_values(name, counts.map(|c| c as f64 / len)).into_series()
+    } else {
+        IdxCa::from_iter_values(name, counts).into_series()
+    };
+    Ok(out)
+}
+
+/// Replace every category by the smoothed mean of the `target` values of its rows.
+///
+/// A category whose rows have `n` non-null targets summing to `sum` is encoded as
+/// `(sum + smoothing * prior) / (n + smoothing)`, where `prior` is the mean of all targets.
+///
+/// To not leak the target of a row into its own encoding, the rows are assigned to one of
+/// `folds` folds by hashing their index with `seed`, and every row is encoded with the targets of
+/// the other folds only. With a single fold, all rows are encoded with all targets. Null is
+/// treated as a category of its own.
+pub fn categorical_target_encode(
+    s: &Series,
+    target: &Series,
+    folds: u32,
+    smoothing: f64,
+    seed: u64,
+) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.len() == target.len(),
+        length_mismatch = "categorical_target_encode",
+        s.len(),
+        target.len()
+    );
+    polars_ensure!(folds > 0, InvalidOperation: "`folds` must be at least 1");
+    polars_ensure!(
+        smoothing >= 0.0,
+        InvalidOperation: "`smoothing` must be non-negative, got {smoothing}"
+    );
+    polars_ensure!(
+        target.dtype().is_primitive_numeric() || target.dtype().is_bool(),
+        InvalidOperation: "`categorical_target_encode` requires a numeric or boolean target, got {}",
+        target.dtype()
+    );
+    let target = target.cast(&DataType::Float64)?;
+    let target = target.f64()?;
+
+    let (row_groups, n_groups) = row_groups(s)?;
+    let folds = folds as usize;
+    let hasher = PlFixedStateQuality::with_seed(seed);
+    let row_folds: Vec<usize> = if folds > 1 {
+        (0..s.len())
+            .map(|i| hash_to_partition(hasher.hash_one(i as u64), folds))
+            .collect()
+    } else {
+        vec![0; s.len()]
+    };
+
+    // The target sum and count of every (category, fold) pair, and of every fold.
+    let mut stats = vec![(0.0, 0usize); n_groups * folds];
+    let mut fold_stats = vec![(0.0, 0usize); folds];
+    for ((&g, &f), y) in row_groups.iter().zip(&row_folds).zip(target.iter()) {
+        if let Some(y) = y {
+            for (sum, n) in [&mut stats[g as usize * folds + f], &mut fold_stats[f]] {
+                *sum += y;
+                *n += 1;
+            }
+        }
+    }
+    let add = |(s1, n1): (f64, usize), &(s2, n2): &(f64, usize)| (s1 + s2, n1 + n2);
+    let total = fold_stats.iter().fold((0.0, 0), add);
+    let category_totals: Vec<_> = stats
+        .chunks_exact(folds)
+        .map(|c| c.iter().fold((0.0, 0), add))
+        .collect();
+
+    let out: Float64Chunked = row_groups
+        .iter()
+        .zip(&row_folds)
+        .map(|(&g, &f)| {
+            let g = g as usize;
+            let ((mut sum, mut n), (mut prior_sum, mut prior_n)) = (category_totals[g], total);
+            if folds > 1 {
+                let (s, c) = stats[g * folds + f];
+                (sum, n) = (sum - s, n - c);
+                let (s, c) = fold_stats[f];
+                (prior_sum, prior_n) = (prior_sum - s, prior_n - c);
+            }
+            if prior_n == 0 {
+                return None;
+            }
+            let prior = prior_sum / prior_n as f64;
+            let denom = n as f64 + smoothing;
+            Some(if denom > 0.0 {
+                (sum + smoothing * prior) / denom
+            } else {
+                prior
+            })
+        })
+        .collect_trusted();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
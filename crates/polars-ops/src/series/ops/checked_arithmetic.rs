@@ -0,0 +1,93 @@
+use num_traits::PrimInt;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_integer_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckedArithmeticOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl CheckedArithmeticOp {
+    fn apply<N: PrimInt>(self, a: N, b: N) -> Option<N> {
+        match self {
+            Self::Add => a.checked_add(&b),
+            Self::Sub => a.checked_sub(&b),
+            Self::Mul => a.checked_mul(&b),
+        }
+    }
+}
+
+fn checked_arithmetic_ca<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    op: CheckedArithmeticOp,
+) -> (ChunkedArray<T>, usize)
+where
+    T: PolarsIntegerType,
+    T::Native: PrimInt,
+{
+    let mut n_overflow = 0;
+    let mut apply = |a: Option<T::Native>, b: Option<T::Native>| {
+        let out = op.apply(a?, b?);
+        n_overflow += out.is_none() as usize;
+        out
+    };
+
+    let out: ChunkedArray<T> = match (lhs.len(), rhs.len()) {
+        (_, 1) => {
+            let b = rhs.get(0);
+            lhs.iter().map(|a| apply(a, b)).collect_trusted()
+        },
+        (1, _) => {
+            let a = lhs.get(0);
+            rhs.iter().map(|b| apply(a, b)).collect_trusted()
+        },
+        _ => lhs
+            .iter()
+            .zip(rhs.iter())
+            .map(|(a, b)| apply(a, b))
+            .collect_trusted(),
+    };
+    (out.with_name(lhs.name().clone()), n_overflow)
+}
+
+/// Integer arithmetic that yields null instead of wrapping around on overflow.
+///
+/// Both inputs are cast to their supertype, which must be an integer type. Returns the result
+/// together with the number of values that overflowed.
+pub fn checked_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: CheckedArithmeticOp,
+) -> PolarsResult<(Series, usize)> {
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        length_mismatch = <&'static str>::from(op),
+        lhs.len(),
+        rhs.len()
+    );
+    let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+    polars_ensure!(
+        dtype.is_integer(),
+        InvalidOperation: "checked arithmetic is only supported for integers, got {} and {}",
+        lhs.dtype(), rhs.dtype()
+    );
+    let lhs = lhs.cast(&dtype)?;
+    let rhs = rhs.cast(&dtype)?;
+
+    with_match_physical_integer_polars_type!(&dtype, |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref();
+        let (out, n_overflow) = checked_arithmetic_ca(lhs, rhs, op);
+        Ok((out.into_series(), n_overflow))
+    })
+}
@@ -1,14 +1,34 @@
 use polars_compute::rolling::QuantileMethod;
 use polars_core::chunked_array::builder::CategoricalChunkedBuilder;
 use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_integer_polars_type;
 use polars_utils::format_pl_smallstr;
 
+/// Whether `cut` and `qcut` compare values of this dtype natively, instead of as `Float64`.
+///
+/// For these dtypes the breakpoints have the dtype of the input as well.
+pub fn is_native_cut_dtype(dtype: &DataType) -> bool {
+    dtype.is_decimal() || dtype.is_temporal()
+}
+
+/// The dtype in which [`cut_with_breaks`] compares values of `dtype` with breaks of
+/// `breaks_dtype`, so that breaks finer than the input, e.g. microsecond breaks of millisecond
+/// datetimes, are not truncated.
+pub fn native_cut_breaks_dtype(dtype: &DataType, breaks_dtype: &DataType) -> DataType {
+    match try_get_supertype(dtype, breaks_dtype) {
+        Ok(supertype) if is_native_cut_dtype(&supertype) => supertype,
+        _ => dtype.clone(),
+    }
+}
+
 fn map_cats(
     s: &Series,
     labels: &[PlSmallStr],
     sorted_breaks: &[f64],
     left_closed: bool,
     include_breaks: bool,
+    missing_label: Option<&str>,
 ) -> PolarsResult<Series> {
     let out_name = PlSmallStr::from_static("category");
 
@@ -42,7 +62,10 @@ fn map_cats(
             })
             .for_each(|idx| match idx {
                 None => {
-                    bld.append_null();
+                    match missing_label {
+                        Some(label) => bld.append_str(label).unwrap(),
+                        None => bld.append_null(),
+                    }
                     brk_vals.append_null();
                 },
                 Some(idx) => unsafe {
@@ -58,16 +81,120 @@ fn map_cats(
             out_name,
             DataType::from_categories(Categories::global()),
             s_iter.map(|opt| {
-                opt.filter(|x| !x.is_nan()).map(|x| {
-                    let pt = sorted_breaks.partition_point(|v| op(&x, v));
-                    unsafe { labels.get_unchecked(pt).as_str() }
-                })
+                opt.filter(|x| !x.is_nan())
+                    .map(|x| {
+                        let pt = sorted_breaks.partition_point(|v| op(&x, v));
+                        unsafe { labels.get_unchecked(pt).as_str() }
+                    })
+                    .or(missing_label)
             }),
         )?
         .into_series())
     }
 }
 
+fn bin_indices<T>(ca: &ChunkedArray<T>, sorted_breaks: &ChunkedArray<T>, left_closed: bool) -> IdxCa
+where
+    T: PolarsIntegerType,
+    T::Native: Ord,
+{
+    let breaks = sorted_breaks.cont_slice().unwrap();
+    ca.iter()
+        .map(|opt| {
+            opt.map(|x| {
+                let pt = if left_closed {
+                    breaks.partition_point(|v| x >= *v)
+                } else {
+                    breaks.partition_point(|v| x > *v)
+                };
+                pt as IdxSize
+            })
+        })
+        .collect_trusted()
+}
+
+/// [`map_cats`] for the dtypes of [`is_native_cut_dtype`], comparing their physical values.
+fn map_cats_native(
+    s: &Series,
+    labels: &[PlSmallStr],
+    sorted_breaks: &Series,
+    left_closed: bool,
+    include_breaks: bool,
+    missing_label: Option<&str>,
+) -> PolarsResult<Series> {
+    let out_name = PlSmallStr::from_static("category");
+
+    let physical = s.to_physical_repr();
+    let breaks_physical = sorted_breaks.to_physical_repr().rechunk();
+    let idx = with_match_physical_integer_polars_type!(physical.dtype(), |$T| {
+        let ca: &ChunkedArray<$T> = physical.as_ref().as_ref();
+        let breaks: &ChunkedArray<$T> = breaks_physical.as_ref().as_ref();
+        bin_indices(ca, breaks, left_closed)
+    });
+
+    let cats = CategoricalChunked::<Categorical32Type>::from_str_iter(
+        out_name.clone(),
+        DataType::from_categories(Categories::global()),
+        idx.iter().map(|opt| {
+            opt.map(|i| unsafe { labels.get_unchecked(i as usize).as_str() })
+                .or(missing_label)
+        }),
+    )?
+    .into_series();
+
+    if !include_breaks {
+        return Ok(cats);
+    }
+
+    // The last bin has no right endpoint.
+    let right_ends = sorted_breaks.extend_constant(AnyValue::Null, 1)?;
+    let brk_vals = right_ends
+        .take(&idx)?
+        .with_name(PlSmallStr::from_static("breakpoint"));
+    let outvals = [brk_vals, cats];
+    Ok(StructChunked::from_series(out_name, outvals[0].len(), outvals.iter())?.into_series())
+}
+
+fn compute_labels_native(breaks: &Series, left_closed: bool) -> PolarsResult<Vec<PlSmallStr>> {
+    // Cast instead of formatting the values for display, which depends on the display config.
+    // This keeps all fractional digits of the dtype.
+    let breaks = breaks.cast(&DataType::String)?;
+    let breaks = breaks
+        .str()?
+        .into_no_null_iter()
+        .map(PlSmallStr::from)
+        .collect::<Vec<_>>();
+    let lo = std::iter::once(PlSmallStr::from_static("-inf")).chain(breaks.iter().cloned());
+    let hi = breaks
+        .iter()
+        .cloned()
+        .chain(std::iter::once(PlSmallStr::from_static("inf")));
+
+    let ret = lo
+        .zip(hi)
+        .map(|(l, h)| {
+            if left_closed {
+                format_pl_smallstr!("[{}, {})", l, h)
+            } else {
+                format_pl_smallstr!("({}, {}]", l, h)
+            }
+        })
+        .collect();
+    Ok(ret)
+}
+
+/// Convert `Float64` breaks to `dtype`, one of [`is_native_cut_dtype`].
+///
+/// Temporal breaks are interpreted as the physical values of `dtype`.
+fn float_breaks_to_native(breaks: &[f64], dtype: &DataType) -> PolarsResult<Series> {
+    let breaks = Series::new(PlSmallStr::EMPTY, breaks);
+    if dtype.is_temporal() {
+        breaks.strict_cast(&dtype.to_physical())?.cast(dtype)
+    } else {
+        breaks.strict_cast(dtype)
+    }
+}
+
 pub fn compute_labels(breaks: &[f64], left_closed: bool) -> PolarsResult<Vec<PlSmallStr>> {
     let lo = std::iter::once(&f64::NEG_INFINITY).chain(breaks.iter());
     let hi = breaks.iter().chain(std::iter::once(&f64::INFINITY));
@@ -91,7 +218,20 @@ pub fn cut(
     labels: Option<Vec<PlSmallStr>>,
     left_closed: bool,
     include_breaks: bool,
+    missing_label: Option<PlSmallStr>,
 ) -> PolarsResult<Series> {
+    if is_native_cut_dtype(s.dtype()) {
+        let breaks = float_breaks_to_native(&breaks, s.dtype())?;
+        return cut_with_breaks(
+            s,
+            &breaks,
+            labels,
+            left_closed,
+            include_breaks,
+            missing_label,
+        );
+    }
+
     // Breaks must be sorted to cut inputs properly.
     polars_ensure!(!breaks.iter().any(|x| x.is_nan()), ComputeError: "breaks cannot be NaN");
     breaks.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
@@ -108,7 +248,63 @@ pub fn cut(
     } else {
         compute_labels(&breaks, left_closed)?
     };
-    map_cats(s, &cut_labels, &breaks, left_closed, include_breaks)
+    map_cats(
+        s,
+        &cut_labels,
+        &breaks,
+        left_closed,
+        include_breaks,
+        missing_label.as_deref(),
+    )
+}
+
+/// Cut with breaks given as a [`Series`], which is cast to the dtype of `s`, or to a finer one of
+/// [`native_cut_breaks_dtype`].
+///
+/// Decimal and temporal inputs are compared natively, see [`is_native_cut_dtype`].
+pub fn cut_with_breaks(
+    s: &Series,
+    breaks: &Series,
+    labels: Option<Vec<PlSmallStr>>,
+    left_closed: bool,
+    include_breaks: bool,
+    missing_label: Option<PlSmallStr>,
+) -> PolarsResult<Series> {
+    if !is_native_cut_dtype(s.dtype()) {
+        let breaks = breaks.strict_cast(&DataType::Float64)?;
+        polars_ensure!(breaks.null_count() == 0, ComputeError: "breaks cannot be null");
+        let breaks = breaks.f64()?.into_no_null_iter().collect();
+        return cut(
+            s,
+            breaks,
+            labels,
+            left_closed,
+            include_breaks,
+            missing_label,
+        );
+    }
+
+    let dtype = native_cut_breaks_dtype(s.dtype(), breaks.dtype());
+    let s = s.strict_cast(&dtype)?;
+    let breaks = breaks.strict_cast(&dtype)?;
+    polars_ensure!(breaks.null_count() == 0, ComputeError: "breaks cannot be null");
+    let breaks = breaks.sort(SortOptions::default())?;
+    polars_ensure!(breaks.n_unique()? == breaks.len(), Duplicate: "breaks are not unique");
+
+    let cut_labels = if let Some(l) = labels {
+        polars_ensure!(l.len() == breaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
+        l
+    } else {
+        compute_labels_native(&breaks, left_closed)?
+    };
+    map_cats_native(
+        &s,
+        &cut_labels,
+        &breaks,
+        left_closed,
+        include_breaks,
+        missing_label.as_deref(),
+    )
 }
 
 pub fn qcut(
@@ -118,20 +314,23 @@ pub fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    missing_label: Option<PlSmallStr>,
 ) -> PolarsResult<Series> {
     polars_ensure!(!probs.iter().any(|x| x.is_nan()), ComputeError: "quantiles cannot be NaN");
 
     if s.null_count() == s.len() {
         // If we only have nulls we don't have any breakpoints.
-        return Ok(Series::full_null(
-            s.name().clone(),
-            s.len(),
-            &DataType::from_categories(Categories::global()),
-        ));
+        let dtype = DataType::from_categories(Categories::global());
+        return match missing_label {
+            Some(label) => Ok(Series::new(s.name().clone(), [label.as_str()])
+                .cast(&dtype)?
+                .new_from_index(0, s.len())),
+            None => Ok(Series::full_null(s.name().clone(), s.len(), &dtype)),
+        };
     }
 
-    let s = s.cast(&DataType::Float64)?;
-    let s2 = s.sort(SortOptions::default())?;
+    let s_float = s.cast(&DataType::Float64)?;
+    let s2 = s_float.sort(SortOptions::default())?;
     let ca = s2.f64()?;
 
     let f = |&p| ca.quantile(p, QuantileMethod::Linear).unwrap().unwrap();
@@ -142,6 +341,24 @@ pub fn qcut(
         polars_ensure!(qbreaks.windows(2).all(|x| x[0] != x[1]), Duplicate: "quantiles are not unique while allow_duplicates=False");
     }
 
+    if is_native_cut_dtype(s.dtype()) {
+        let qbreaks = float_breaks_to_native(&qbreaks, s.dtype())?;
+        let cut_labels = if let Some(l) = labels {
+            polars_ensure!(l.len() == qbreaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
+            l
+        } else {
+            compute_labels_native(&qbreaks, left_closed)?
+        };
+        return map_cats_native(
+            s,
+            &cut_labels,
+            &qbreaks,
+            left_closed,
+            include_breaks,
+            missing_label.as_deref(),
+        );
+    }
+
     let cut_labels = if let Some(l) = labels {
         polars_ensure!(l.len() == qbreaks.len() + 1, ShapeMismatch: "provide len(quantiles) + 1 labels");
         l
@@ -149,7 +366,14 @@ pub fn qcut(
         compute_labels(&qbreaks, left_closed)?
     };
 
-    map_cats(&s, &cut_labels, &qbreaks, left_closed, include_breaks)
+    map_cats(
+        &s_float,
+        &cut_labels,
+        &qbreaks,
+        left_closed,
+        include_breaks,
+        missing_label.as_deref(),
+    )
 }
 
 mod test {
@@ -170,11 +394,11 @@ mod test {
         let left_closed = false;
 
         let include_breaks = false;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, None).unwrap();
         out.cat32().unwrap();
 
         let include_breaks = true;
-        let out = map_cats(&s, labels, breaks, left_closed, include_breaks).unwrap();
+        let out = map_cats(&s, labels, breaks, left_closed, include_breaks, None).unwrap();
         let out = out.struct_().unwrap().fields_as_series()[1].clone();
         out.cat32().unwrap();
     }
@@ -0,0 +1,109 @@
+use either::Either;
+use num_traits::Zero;
+use polars_compute::arithmetic::pl_num::PlNumArithmetic;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+use polars_core::with_match_physical_numeric_polars_type;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use strum_macros::IntoStaticStr;
+
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, IntoStaticStr)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[strum(serialize_all = "lowercase")]
+pub enum DivOrOp {
+    TrueDiv,
+    FloorDiv,
+}
+
+impl DivOrOp {
+    /// The dtype of the result, given the supertype of the inputs.
+    pub fn output_dtype(self, supertype: &DataType) -> DataType {
+        match (self, supertype) {
+            (Self::TrueDiv, DataType::Float32) => DataType::Float32,
+            (Self::TrueDiv, _) | (Self::FloorDiv, DataType::Null) => DataType::Float64,
+            (Self::FloorDiv, dtype) => dtype.clone(),
+        }
+    }
+
+    #[inline(always)]
+    fn apply<N: PlNumArithmetic>(self, a: N, b: N) -> N {
+        match self {
+            // The operands of a true division are cast to floats, for which `legacy_div` is the
+            // true division.
+            Self::TrueDiv => a.legacy_div(b),
+            Self::FloorDiv => a.wrapping_floor_div(b),
+        }
+    }
+}
+
+fn broadcast_iter<T: PolarsNumericType>(
+    ca: &ChunkedArray<T>,
+    len: usize,
+) -> impl Iterator<Item = Option<T::Native>> + '_ {
+    if ca.len() == 1 && len != 1 {
+        Either::Left(std::iter::repeat_n(ca.get(0), len))
+    } else {
+        Either::Right(ca.iter())
+    }
+}
+
+fn div_or_ca<T>(
+    lhs: &ChunkedArray<T>,
+    rhs: &ChunkedArray<T>,
+    default: &ChunkedArray<T>,
+    len: usize,
+    op: DivOrOp,
+) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: PlNumArithmetic,
+{
+    let out: ChunkedArray<T> = broadcast_iter(lhs, len)
+        .zip(broadcast_iter(rhs, len))
+        .zip(broadcast_iter(default, len))
+        .map(|((a, b), default)| {
+            let (a, b) = (a?, b?);
+            if b.is_zero() {
+                default
+            } else {
+                Some(op.apply(a, b))
+            }
+        })
+        .collect();
+    out.with_name(lhs.name().clone())
+}
+
+/// Divide `lhs` by `rhs` in a single pass, yielding the value of `default` where `rhs` is zero.
+///
+/// Null operands yield null. The inputs are cast to their supertype, which must be numeric, and
+/// to a float type for [`DivOrOp::TrueDiv`]. Inputs of length 1 are broadcast.
+pub fn div_or(lhs: &Series, rhs: &Series, default: &Series, op: DivOrOp) -> PolarsResult<Series> {
+    let lens = [lhs.len(), rhs.len(), default.len()];
+    let len = lens.into_iter().find(|&len| len != 1).unwrap_or(1);
+    polars_ensure!(
+        lens.iter().all(|&l| l == len || l == 1),
+        ShapeMismatch: "cannot evaluate `{}_or` on inputs of lengths {}, {} and {}",
+        <&'static str>::from(op), lens[0], lens[1], lens[2]
+    );
+
+    let dtype = try_get_supertype(lhs.dtype(), rhs.dtype())?;
+    let dtype = try_get_supertype(&dtype, default.dtype())?;
+    polars_ensure!(
+        dtype.is_primitive_numeric() || dtype.is_null(),
+        InvalidOperation: "`{}_or` is only supported for numeric types, got {}, {} and {}",
+        <&'static str>::from(op), lhs.dtype(), rhs.dtype(), default.dtype()
+    );
+    let dtype = op.output_dtype(&dtype);
+    let lhs = lhs.cast(&dtype)?;
+    let rhs = rhs.cast(&dtype)?;
+    let default = default.cast(&dtype)?;
+
+    with_match_physical_numeric_polars_type!(&dtype, |$T| {
+        let lhs: &ChunkedArray<$T> = lhs.as_ref().as_ref();
+        let rhs: &ChunkedArray<$T> = rhs.as_ref().as_ref();
+        let default: &ChunkedArray<$T> = default.as_ref().as_ref();
+        Ok(div_or_ca(lhs, rhs, default, len, op).into_series())
+    })
+}
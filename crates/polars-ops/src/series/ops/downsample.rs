@@ -0,0 +1,168 @@
+use arrow::bitmap::MutableBitmap;
+use polars_core::prelude::*;
+
+/// Get the row index and the `x` and `y` values of the rows in which both are finite.
+pub(crate) fn finite_points(x: &Series, y: &Series) -> PolarsResult<Vec<(IdxSize, f64, f64)>> {
+    polars_ensure!(
+        x.len() == y.len(),
+        ShapeMismatch: "cannot downsample series of length {} by series of length {}",
+        y.len(), x.len()
+    );
+    let to_f64 = |s: &Series| {
+        polars_ensure!(
+            s.dtype().is_primitive_numeric() || s.dtype().is_temporal(),
+            InvalidOperation: "cannot downsample series '{}' of dtype {}", s.name(), s.dtype()
+        );
+        s.to_physical_repr().cast(&DataType::Float64)
+    };
+    let xs = to_f64(x)?;
+    let ys = to_f64(y)?;
+    Ok(xs
+        .f64()?
+        .iter()
+        .zip(ys.f64()?.iter())
+        .enumerate()
+        .filter_map(|(i, (x, y))| Some((i as IdxSize, x?, y?)))
+        .filter(|(_, x, y)| x.is_finite() && y.is_finite())
+        .collect())
+}
+
+/// Select `n` points with the Largest-Triangle-Three-Buckets algorithm.
+///
+/// The first and last point are always kept. The points in between are split into `n - 2`
+/// buckets, of which the point is kept that forms the largest triangle with the point kept from
+/// the previous bucket and the average of the next bucket.
+fn lttb(points: &[(IdxSize, f64, f64)], n: usize) -> Vec<IdxSize> {
+    let len = points.len();
+    if n >= len {
+        return points.iter().map(|p| p.0).collect();
+    }
+    if n < 3 {
+        return [0, len - 1][..n].iter().map(|&i| points[i].0).collect();
+    }
+
+    // The start of the bucket `i`, where the last bucket only holds the last point.
+    let bucket_start = |i: usize| (i * (len - 2) / (n - 2) + 1).min(len);
+    let mut out = Vec::with_capacity(n);
+    out.push(points[0].0);
+    let mut prev = 0;
+    for i in 0..n - 2 {
+        let next = &points[bucket_start(i + 1)..bucket_start(i + 2)];
+        let avg_x = next.iter().map(|p| p.1).sum::<f64>() / next.len() as f64;
+        let avg_y = next.iter().map(|p| p.2).sum::<f64>() / next.len() as f64;
+
+        let (_, prev_x, prev_y) = points[prev];
+        let mut max_area = -1.0;
+        let start = bucket_start(i);
+        for (j, &(_, x, y)) in points[start..bucket_start(i + 1)].iter().enumerate() {
+            let area = ((prev_x - avg_x) * (y - prev_y) - (prev_x - x) * (avg_y - prev_y)).abs();
+            if area > max_area {
+                max_area = area;
+                prev = start + j;
+            }
+        }
+        out.push(points[prev].0);
+    }
+    out.push(points[len - 1].0);
+    out
+}
+
+/// Keep the points with the minimum and maximum `y` in each of `n_buckets` equally wide buckets
+/// spanning the `x` values.
+fn minmax(points: &[(IdxSize, f64, f64)], n_buckets: usize) -> Vec<IdxSize> {
+    if n_buckets == 0 {
+        return Vec::new();
+    }
+    if points.len() <= 2 * n_buckets {
+        return points.iter().map(|p| p.0).collect();
+    }
+
+    let (x_min, x_max) = points
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), p| {
+            (min.min(p.1), max.max(p.1))
+        });
+    let width = (x_max - x_min) / n_buckets as f64;
+    // The positions in `points` of the minimum and maximum of every bucket.
+    let mut extrema: Vec<Option<(usize, usize)>> = vec![None; n_buckets];
+    for (i, &(_, x, y)) in points.iter().enumerate() {
+        let bucket = if width > 0.0 {
+            (((x - x_min) / width) as usize).min(n_buckets - 1)
+        } else {
+            0
+        };
+        extrema[bucket] = Some(match extrema[bucket] {
+            None => (i, i),
+            Some((min, max)) => (
+                if y < points[min].2 { i } else { min },
+                if y > points[max].2 { i } else { max },
+            ),
+        });
+    }
+
+    let mut out: Vec<IdxSize> = extrema
+        .into_iter()
+        .flatten()
+        .flat_map(|(min, max)| [points[min].0, points[max].0])
+        .collect();
+    out.sort_unstable();
+    out.dedup();
+    out
+}
+
+/// Get the indices of the `n` points of the line through `y` against `x` that keep its visual
+/// shape, selected with the Largest-Triangle-Three-Buckets algorithm.
+///
+/// The points are assumed to be sorted by `x`. Points of which `x` or `y` is null or not finite
+/// are skipped, and all other points are kept if there are at most `n` of them.
+pub fn lttb_indices(x: &Series, y: &Series, n: usize) -> PolarsResult<IdxCa> {
+    let points = finite_points(x, y)?;
+    Ok(IdxCa::from_vec(PlSmallStr::EMPTY, lttb(&points, n)))
+}
+
+/// Get the sorted indices of the points with the minimum and maximum `y` in each of `n_buckets`
+/// equally wide buckets spanning the `x` values.
+///
+/// Points of which `x` or `y` is null or not finite are skipped, and all other points are kept
+/// if there are at most `2 * n_buckets` of them.
+pub fn minmax_indices(x: &Series, y: &Series, n_buckets: usize) -> PolarsResult<IdxCa> {
+    let points = finite_points(x, y)?;
+    Ok(IdxCa::from_vec(
+        PlSmallStr::EMPTY,
+        minmax(&points, n_buckets),
+    ))
+}
+
+fn indices_to_mask(name: PlSmallStr, len: usize, indices: &IdxCa) -> BooleanChunked {
+    let mut mask = MutableBitmap::from_len_zeroed(len);
+    for i in indices.into_no_null_iter() {
+        mask.set(i as usize, true);
+    }
+    BooleanChunked::from_bitmap(name, mask.freeze())
+}
+
+/// Get a mask of the `n_out` values to keep to downsample the time series of `values` by `by`,
+/// see [`lttb_indices`].
+pub fn downsample_lttb(values: &Series, by: &Series, n_out: usize) -> PolarsResult<BooleanChunked> {
+    let indices = lttb_indices(by, values, n_out)?;
+    Ok(indices_to_mask(
+        values.name().clone(),
+        values.len(),
+        &indices,
+    ))
+}
+
+/// Get a mask of the minimum and maximum values in each of `n_buckets` equally long periods of
+/// the time series of `values` by `by`, see [`minmax_indices`].
+pub fn downsample_minmax(
+    values: &Series,
+    by: &Series,
+    n_buckets: usize,
+) -> PolarsResult<BooleanChunked> {
+    let indices = minmax_indices(by, values, n_buckets)?;
+    Ok(indices_to_mask(
+        values.name().clone(),
+        values.len(),
+        &indices,
+    ))
+}
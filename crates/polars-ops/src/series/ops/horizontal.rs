@@ -1,11 +1,17 @@
 use std::borrow::Cow;
 
+use arrow::bitmap::Bitmap;
+use arrow::compute::utils::combine_validities_and;
+use polars_compute::sum::WrappingAdd;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 use polars_core::series::arithmetic::coerce_lhs_rhs;
 use polars_core::utils::dtypes_to_supertype;
 use polars_core::{POOL, with_match_physical_numeric_polars_type};
-use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{
+    IndexedParallelIterator, IntoParallelIterator, IntoParallelRefIterator, ParallelIterator,
+};
+use rayon::slice::ParallelSliceMut;
 
 fn validate_column_lengths(cs: &[Column]) -> PolarsResult<()> {
     let mut length = 1;
@@ -61,6 +67,41 @@ impl SumMeanHorizontal for DataFrame {
     }
 }
 
+/// A user-defined reduction of columns to one value per row, see [`fold_horizontal`].
+///
+/// Rows are folded one block at a time: the accumulators of the block are initialized, after
+/// which the values of every column in those rows are accumulated into them. Both are passed as
+/// plain slices, so that implementations can be written as simple loops that the compiler
+/// vectorizes.
+pub trait HorizontalFolder: Send + Sync {
+    /// The type that the columns are cast to before they are folded.
+    type Input: PolarsNumericType;
+    /// The type of the folded column.
+    type Output: PolarsNumericType;
+
+    /// Initialize the accumulators of a block of rows.
+    fn init(&self, acc: &mut [<Self::Output as PolarsNumericType>::Native]);
+
+    /// Accumulate the values of the column at index `column` in a block of rows.
+    fn accumulate(
+        &self,
+        acc: &mut [<Self::Output as PolarsNumericType>::Native],
+        column: usize,
+        values: &[<Self::Input as PolarsNumericType>::Native],
+    );
+}
+
+pub trait FoldColumns {
+    /// Fold all columns horizontally with a [`HorizontalFolder`].
+    fn fold_columns<F: HorizontalFolder>(&self, folder: &F) -> PolarsResult<Column>;
+}
+
+impl FoldColumns for DataFrame {
+    fn fold_columns<F: HorizontalFolder>(&self, folder: &F) -> PolarsResult<Column> {
+        fold_horizontal(self.get_columns(), folder)
+    }
+}
+
 fn min_binary<T>(left: &ChunkedArray<T>, right: &ChunkedArray<T>) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -183,6 +224,67 @@ pub fn min_horizontal(columns: &[Column]) -> PolarsResult<Option<Column>> {
     }
 }
 
+/// The number of rows summed at a time by [`sum_horizontal_same_dtype`], small enough for the
+/// partial sums to stay in the cache while going over all columns.
+const SUM_HORIZONTAL_BLOCK_SIZE: usize = 2048;
+
+fn sum_same_dtype_ca<T>(cas: &[&ChunkedArray<T>]) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+    T::Native: WrappingAdd,
+{
+    let cas = cas.iter().map(|ca| ca.rechunk()).collect::<Vec<_>>();
+    let values = cas
+        .iter()
+        .map(|ca| ca.cont_slice().unwrap())
+        .collect::<Vec<_>>();
+
+    let mut out = values[0].to_vec();
+    POOL.install(|| {
+        out.par_chunks_mut(SUM_HORIZONTAL_BLOCK_SIZE)
+            .enumerate()
+            .for_each(|(i, acc)| {
+                let offset = i * SUM_HORIZONTAL_BLOCK_SIZE;
+                let end = offset + acc.len();
+                for v in &values[1..] {
+                    // Simple enough for the compiler to vectorize.
+                    for (a, v) in acc.iter_mut().zip(&v[offset..end]) {
+                        *a = a.wrapping_add(v);
+                    }
+                }
+            })
+    });
+    ChunkedArray::from_vec(cas[0].name().clone(), out)
+}
+
+/// Sum columns of the same numeric dtype without nulls in a single pass over the output.
+///
+/// The generic path materializes a new array for every added column, which dominates for
+/// wide frames.
+fn sum_horizontal_same_dtype(columns: &[&Series]) -> Option<Series> {
+    let dtype = columns[0].dtype();
+    let len = columns[0].len();
+    if !dtype.is_primitive_numeric()
+        || columns
+            .iter()
+            .any(|s| s.dtype() != dtype || s.len() != len || s.null_count() > 0)
+    {
+        return None;
+    }
+
+    let out = with_match_physical_numeric_polars_type!(dtype, |$T| {
+        let cas = columns
+            .iter()
+            .map(|s| {
+                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                ca
+            })
+            .collect::<Vec<_>>();
+        sum_same_dtype_ca(&cas).into_series()
+    });
+    Some(out)
+}
+
 pub fn sum_horizontal(
     columns: &[Column],
     null_strategy: NullStrategy,
@@ -247,6 +349,10 @@ pub fn sum_horizontal(
             .map(Column::from)
             .map(Some),
         _ => {
+            if let Some(out) = sum_horizontal_same_dtype(&non_null_cols) {
+                return Ok(Some(out.into()));
+            }
+
             // the try_reduce_with is a bit slower in parallelism,
             // but I don't think it matters here as we parallelize over columns, not over elements
             let out = POOL.install(|| {
@@ -263,6 +369,106 @@ pub fn sum_horizontal(
     }
 }
 
+/// The number of rows the row-wise statistics compute per task.
+const ROW_WISE_CHUNK_SIZE: usize = 1 << 14;
+
+/// Compute a statistic over the non-null values of every row of numeric `columns`.
+///
+/// The values of a row are gathered in a scratch buffer that is handed to `f`, so there is no
+/// need to transpose or concatenate the columns into lists first.
+fn row_wise_float_statistic(
+    columns: &[Column],
+    fn_name: &str,
+    f: impl Fn(&mut [f64]) -> PolarsResult<Option<f64>> + Sync,
+) -> PolarsResult<Option<Column>> {
+    validate_column_lengths(columns)?;
+    if columns.is_empty() {
+        return Ok(None);
+    }
+    for c in columns {
+        let dtype = c.dtype();
+        polars_ensure!(
+            dtype.is_primitive_numeric() || dtype.is_decimal() || dtype.is_bool() || dtype.is_null(),
+            InvalidOperation: "'{}' expects numeric expressions, found {:?} (dtype={})",
+            fn_name, c.name(), dtype,
+        );
+    }
+
+    let out_dtype = match dtypes_to_supertype(columns.iter().map(|c| c.dtype()))? {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    };
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+    let cas = columns
+        .iter()
+        .map(|c| {
+            let c = c.cast(&DataType::Float64)?;
+            Ok(c.f64()?.rechunk().into_owned())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let arrs = cas
+        .iter()
+        .map(|ca| ca.downcast_as_array())
+        .collect::<Vec<_>>();
+
+    let chunks = POOL.install(|| {
+        (0..len)
+            .into_par_iter()
+            .step_by(ROW_WISE_CHUNK_SIZE)
+            .map(|offset| {
+                let mut values = Vec::with_capacity(arrs.len());
+                (offset..(offset + ROW_WISE_CHUNK_SIZE).min(len))
+                    .map(|row| {
+                        values.clear();
+                        for arr in &arrs {
+                            // Scalar columns are broadcast.
+                            let idx = if arr.len() == 1 { 0 } else { row };
+                            if let Some(v) = arr.get(idx) {
+                                values.push(v);
+                            }
+                        }
+                        f(&mut values)
+                    })
+                    .collect::<PolarsResult<Float64Chunked>>()
+            })
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let mut out = Float64Chunked::full_null(columns[0].name().clone(), 0);
+    for ca in chunks {
+        out.append_owned(ca)?;
+    }
+    out.into_column().cast(&out_dtype).map(Some)
+}
+
+/// Compute the quantile of the non-null values of every row.
+pub fn quantile_horizontal(
+    columns: &[Column],
+    quantile: f64,
+    method: QuantileMethod,
+) -> PolarsResult<Option<Column>> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "quantile should be between 0.0 and 1.0",
+    );
+    row_wise_float_statistic(columns, "quantile_horizontal", |values| {
+        quantile_slice(values, quantile, method)
+    })
+}
+
+/// Compute the standard deviation of the non-null values of every row.
+pub fn std_horizontal(columns: &[Column], ddof: u8) -> PolarsResult<Option<Column>> {
+    row_wise_float_statistic(columns, "std_horizontal", |values| {
+        let n = values.len();
+        if n <= ddof as usize {
+            return Ok(None);
+        }
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let sum_sq = values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>();
+        Ok(Some((sum_sq / (n - ddof as usize) as f64).sqrt()))
+    })
+}
+
 pub fn mean_horizontal(
     columns: &[Column],
     null_strategy: NullStrategy,
@@ -366,6 +572,68 @@ pub fn coalesce_columns(s: &[Column]) -> PolarsResult<Column> {
     Ok(out)
 }
 
+/// The number of rows folded at a time by [`fold_horizontal`].
+const FOLD_HORIZONTAL_BLOCK_SIZE: usize = 2048;
+
+/// Fold columns horizontally with a [`HorizontalFolder`], in parallel over blocks of rows.
+///
+/// The columns are cast to the input type of the folder. The output is null in the rows in which
+/// any of the columns is null; the values that are accumulated for null entries are unspecified.
+pub fn fold_horizontal<F: HorizontalFolder>(
+    columns: &[Column],
+    folder: &F,
+) -> PolarsResult<Column> {
+    polars_ensure!(
+        !columns.is_empty(),
+        ComputeError: "cannot fold zero columns horizontally"
+    );
+    validate_column_lengths(columns)?;
+    let len = columns.iter().map(|c| c.len()).max().unwrap();
+
+    let dtype = <F::Input as PolarsDataType>::get_static_dtype();
+    let series = columns
+        .iter()
+        .map(|c| {
+            let s = if c.len() == len {
+                c.as_materialized_series().strict_cast(&dtype)?
+            } else {
+                c.new_from_index(0, len)
+                    .as_materialized_series()
+                    .strict_cast(&dtype)?
+            };
+            Ok(s.rechunk())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let arrays = series
+        .iter()
+        .map(|s| Ok(s.unpack::<F::Input>()?.downcast_as_array()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let validity = arrays.iter().fold(None, |validity: Option<Bitmap>, arr| {
+        combine_validities_and(validity.as_ref(), arr.validity())
+    });
+    let values = arrays
+        .iter()
+        .map(|arr| arr.values().as_slice())
+        .collect::<Vec<_>>();
+
+    let mut out = vec![<F::Output as PolarsNumericType>::Native::default(); len];
+    POOL.install(|| {
+        out.par_chunks_mut(FOLD_HORIZONTAL_BLOCK_SIZE)
+            .enumerate()
+            .for_each(|(i, acc)| {
+                let offset = i * FOLD_HORIZONTAL_BLOCK_SIZE;
+                folder.init(acc);
+                for (column, values) in values.iter().enumerate() {
+                    folder.accumulate(acc, column, &values[offset..offset + acc.len()]);
+                }
+            })
+    });
+    let out =
+        ChunkedArray::<F::Output>::from_vec_validity(columns[0].name().clone(), out, validity);
+    Ok(out.into_column())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -407,4 +675,42 @@ mod tests {
             &[Some(4), Some(2), Some(6)]
         );
     }
+
+    struct WeightedSum(Vec<f64>);
+
+    impl HorizontalFolder for WeightedSum {
+        type Input = Float64Type;
+        type Output = Float64Type;
+
+        fn init(&self, acc: &mut [f64]) {
+            acc.fill(0.0);
+        }
+
+        fn accumulate(&self, acc: &mut [f64], column: usize, values: &[f64]) {
+            let weight = self.0[column];
+            for (a, v) in acc.iter_mut().zip(values) {
+                *a += weight * v;
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_fold_columns() {
+        let n = FOLD_HORIZONTAL_BLOCK_SIZE + 3;
+        let a = Column::new("a".into(), (0..n as i32).collect::<Vec<_>>());
+        let mut b_values = vec![Some(1.5); n];
+        b_values[n - 1] = None;
+        let b = Column::new("b".into(), b_values);
+        let c = Column::new_scalar("c".into(), Scalar::from(2i64), n);
+
+        let df = DataFrame::new(vec![a, b, c]).unwrap();
+        let out = df.fold_columns(&WeightedSum(vec![1.0, 2.0, -1.0])).unwrap();
+        let out = out.f64().unwrap();
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(out.len(), n);
+        assert_eq!(out.get(0), Some(1.0));
+        assert_eq!(out.get(n - 2), Some((n - 2) as f64 + 1.0));
+        assert_eq!(out.get(n - 1), None);
+    }
 }
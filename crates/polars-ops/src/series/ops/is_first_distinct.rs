@@ -4,6 +4,7 @@ use arrow::array::BooleanArray;
 use arrow::bitmap::MutableBitmap;
 use arrow::legacy::bit_util::*;
 use arrow::legacy::utils::CustomIterTools;
+use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::*;
 use polars_core::with_match_physical_numeric_polars_type;
 use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalHash};
@@ -147,3 +148,31 @@ pub fn is_first_distinct(s: &Series) -> PolarsResult<BooleanChunked> {
     };
     Ok(out)
 }
+
+/// Get a mask of the first occurrence of every distinct row of `columns`.
+///
+/// The columns are row-encoded, so the key doesn't have to be a single (`Struct`) column.
+pub fn is_first_distinct_columns(columns: &[Column]) -> PolarsResult<BooleanChunked> {
+    let [first, rest @ ..] = columns else {
+        polars_bail!(ComputeError: "`is_first_distinct` needs at least one column");
+    };
+    if rest.is_empty() {
+        return is_first_distinct(first.as_materialized_series());
+    }
+    polars_ensure!(
+        rest.iter().all(|c| c.len() == first.len()),
+        ShapeMismatch: "all columns of `is_first_distinct` must have the same length"
+    );
+
+    let rows = _get_rows_encoded_ca_unordered(first.name().clone(), columns)?;
+    let mut unique = PlHashSet::new();
+    let chunks = rows.downcast_iter().map(|arr| -> BooleanArray {
+        arr.values_iter()
+            .map(|v| unique.insert(v))
+            .collect_trusted()
+    });
+    Ok(BooleanChunked::from_chunk_iter(
+        first.name().clone(),
+        chunks,
+    ))
+}
@@ -3,6 +3,7 @@ use std::hash::Hash;
 use arrow::array::BooleanArray;
 use arrow::bitmap::MutableBitmap;
 use arrow::legacy::utils::CustomIterTools;
+use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::*;
 use polars_core::utils::NoNull;
 use polars_core::with_match_physical_numeric_polars_type;
@@ -159,3 +160,30 @@ fn is_last_distinct_list(ca: &ListChunked) -> PolarsResult<BooleanChunked> {
     let arr = BooleanArray::new(ArrowDataType::Boolean, out.into(), None);
     Ok(BooleanChunked::with_chunk(ca.name().clone(), arr))
 }
+
+/// Get a mask of the last occurrence of every distinct row of `columns`.
+///
+/// The columns are row-encoded, so the key doesn't have to be a single (`Struct`) column.
+pub fn is_last_distinct_columns(columns: &[Column]) -> PolarsResult<BooleanChunked> {
+    let [first, rest @ ..] = columns else {
+        polars_bail!(ComputeError: "`is_last_distinct` needs at least one column");
+    };
+    if rest.is_empty() {
+        return is_last_distinct(first.as_materialized_series());
+    }
+    polars_ensure!(
+        rest.iter().all(|c| c.len() == first.len()),
+        ShapeMismatch: "all columns of `is_last_distinct` must have the same length"
+    );
+
+    let rows = _get_rows_encoded_ca_unordered(first.name().clone(), columns)?;
+    let arr = rows.downcast_as_array();
+    let mut unique = PlHashSet::new();
+    Ok(arr
+        .values_iter()
+        .rev()
+        .map(|v| unique.insert(v))
+        .collect_reversed::<NoNull<BooleanChunked>>()
+        .into_inner()
+        .with_name(first.name().clone()))
+}
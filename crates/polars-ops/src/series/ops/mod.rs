@@ -1,9 +1,16 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "anonymize")]
+mod anonymize;
 mod arg_min_max;
 mod bitwise;
+#[cfg(feature = "bootstrap")]
+mod bootstrap;
 #[cfg(feature = "business")]
 mod business;
+#[cfg(feature = "category_encoding")]
+mod category_encoding;
+mod checked_arithmetic;
 mod clip;
 #[cfg(feature = "cum_agg")]
 mod cum_agg;
@@ -11,6 +18,8 @@ mod cum_agg;
 mod cut;
 #[cfg(feature = "diff")]
 mod diff;
+mod div_or;
+mod downsample;
 #[cfg(feature = "ewma")]
 mod ewm;
 #[cfg(feature = "ewma_by")]
@@ -64,14 +73,23 @@ mod search_sorted;
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
+#[cfg(feature = "unique_id")]
+mod unique_id;
 mod various;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
+#[cfg(feature = "anonymize")]
+pub use anonymize::*;
 pub use arg_min_max::ArgAgg;
 pub use bitwise::*;
+#[cfg(feature = "bootstrap")]
+pub use bootstrap::*;
 #[cfg(feature = "business")]
 pub use business::*;
+#[cfg(feature = "category_encoding")]
+pub use category_encoding::*;
+pub use checked_arithmetic::*;
 pub use clip::*;
 #[cfg(feature = "cum_agg")]
 pub use cum_agg::*;
@@ -79,6 +97,8 @@ pub use cum_agg::*;
 pub use cut::*;
 #[cfg(feature = "diff")]
 pub use diff::*;
+pub use div_or::*;
+pub use downsample::*;
 #[cfg(feature = "ewma")]
 pub use ewm::*;
 #[cfg(feature = "ewma_by")]
@@ -138,6 +158,8 @@ pub use search_sorted::*;
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
+#[cfg(feature = "unique_id")]
+pub use unique_id::*;
 pub use various::*;
 mod not;
 
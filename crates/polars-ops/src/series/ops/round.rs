@@ -15,6 +15,101 @@ pub enum RoundMode {
     #[default]
     HalfToEven,
     HalfAwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+}
+
+impl RoundMode {
+    /// Round `x` to an integer.
+    fn round_f64(self, x: f64) -> f64 {
+        match self {
+            Self::HalfToEven => x.round_ties_even(),
+            Self::HalfAwayFromZero => x.round(),
+            Self::Floor => x.floor(),
+            Self::Ceil => x.ceil(),
+        }
+    }
+
+    /// Round `v` to a multiple of `multiplier`, which must be positive.
+    fn round_i128(self, v: i128, multiplier: i128) -> i128 {
+        let floor = v.div_euclid(multiplier) * multiplier;
+        let rem = v - floor;
+        if rem == 0 {
+            return v;
+        }
+        let ceil = floor + multiplier;
+        let round_up = match self {
+            Self::Floor => false,
+            Self::Ceil => true,
+            // Compare `rem` to the distance to the next multiple, as `2 * rem` can overflow.
+            Self::HalfToEven | Self::HalfAwayFromZero => match rem.cmp(&(multiplier - rem)) {
+                std::cmp::Ordering::Less => false,
+                std::cmp::Ordering::Greater => true,
+                std::cmp::Ordering::Equal if self == Self::HalfToEven => {
+                    v.div_euclid(multiplier) % 2 != 0
+                },
+                std::cmp::Ordering::Equal => v >= 0,
+            },
+        };
+        if round_up { ceil } else { floor }
+    }
+}
+
+/// Round `val` to `decimals` decimals.
+fn round_f64(val: f64, decimals: u32, mode: RoundMode) -> f64 {
+    let ret = if decimals == 0 {
+        mode.round_f64(val)
+    } else if decimals >= 326 {
+        // More precise than smallest denormal.
+        val
+    } else if decimals >= 300 {
+        // We're getting into unrepresentable territory for the multiplier
+        // here, split up the 10^n multiplier into 2^n and 5^n.
+        let mul2 = libm::scalbn(1.0, decimals as i32);
+        let invmul2 = 1.0 / mul2; // Still exact for any valid value of decimals.
+        let mul5 = 5.0_f64.powi(decimals as i32);
+        mode.round_f64(val * mul2 * mul5) / mul5 * invmul2
+    } else {
+        let multiplier = 10.0_f64.powi(decimals as i32);
+        mode.round_f64(val * multiplier) / multiplier
+    };
+    if ret.is_finite() {
+        ret
+    } else {
+        // We return the original value which is correct both for overflows and non-finite inputs.
+        val
+    }
+}
+
+/// Round `val` to a multiple of `increment`, where `epsilon` is the relative precision of the
+/// type `val` was originally given in.
+fn round_f64_to_increment(val: f64, increment: f64, mode: RoundMode, epsilon: f64) -> f64 {
+    // Increments like 0.05 aren't exact in binary, but their inverse is an integer, and dividing
+    // by it gives the float closest to the decimal multiple.
+    let inverse = (1.0 / increment).round();
+    let exact_inverse = inverse >= 1.0 && (1.0 / increment - inverse).abs() <= 1e-9 * inverse;
+    let quotient = if exact_inverse {
+        val * inverse
+    } else {
+        val / increment
+    };
+    // Snap quotients that are only off from an integer or a tie by rounding errors, so that e.g.
+    // 0.15 isn't floored to 0.1.
+    let twice = (2.0 * quotient).round();
+    let quotient = if (2.0 * quotient - twice).abs() <= 4.0 * epsilon * twice.abs().max(1.0) {
+        twice / 2.0
+    } else {
+        quotient
+    };
+    let multiple = mode.round_f64(quotient);
+    let ret = if exact_inverse {
+        multiple / inverse
+    } else {
+        multiple * increment
+    };
+    if ret.is_finite() { ret } else { val }
 }
 
 pub trait RoundSeries: SeriesSealed {
@@ -23,146 +118,21 @@ pub trait RoundSeries: SeriesSealed {
         let s = self.as_series();
 
         if let Ok(ca) = s.f32() {
-            match mode {
-                RoundMode::HalfToEven => {
-                    return if decimals == 0 {
-                        let s = ca.apply_values(|val| val.round_ties_even()).into_series();
-                        Ok(s)
-                    } else if decimals >= 326 {
-                        // More precise than smallest denormal.
-                        Ok(s.clone())
-                    } else {
-                        // Note we do the computation on f64 floats to not lose precision
-                        // when the computation is done, we cast to f32
-                        let multiplier = 10.0_f64.powi(decimals as i32);
-                        let s = ca
-                            .apply_values(|val| {
-                                let ret = ((val as f64 * multiplier).round_ties_even() / multiplier)
-                                    as f32;
-                                if ret.is_finite() {
-                                    ret
-                                } else {
-                                    // We return the original value which is correct both for overflows and non-finite inputs.
-                                    val
-                                }
-                            })
-                            .into_series();
-                        Ok(s)
-                    };
-                },
-                RoundMode::HalfAwayFromZero => {
-                    return if decimals == 0 {
-                        let s = ca.apply_values(|val| val.round()).into_series();
-                        Ok(s)
-                    } else if decimals >= 326 {
-                        // More precise than smallest denormal.
-                        Ok(s.clone())
-                    } else {
-                        // Note we do the computation on f64 floats to not lose precision
-                        // when the computation is done, we cast to f32
-                        let multiplier = 10.0_f64.powi(decimals as i32);
-                        let s = ca
-                            .apply_values(|val| {
-                                let ret = ((val as f64 * multiplier).round_ties_even() / multiplier)
-                                    as f32;
-                                if ret.is_finite() {
-                                    ret
-                                } else {
-                                    // We return the original value which is correct both for overflows and non-finite inputs.
-                                    val
-                                }
-                            })
-                            .into_series();
-                        Ok(s)
-                    };
-                },
-            }
+            // Note we do the computation on f64 floats to not lose precision
+            // when the computation is done, we cast to f32
+            let s = ca
+                .apply_values(|val| {
+                    let ret = round_f64(val as f64, decimals, mode) as f32;
+                    if ret.is_finite() { ret } else { val }
+                })
+                .into_series();
+            return Ok(s);
         }
         if let Ok(ca) = s.f64() {
-            match mode {
-                RoundMode::HalfToEven => {
-                    return if decimals == 0 {
-                        let s = ca.apply_values(|val| val.round_ties_even()).into_series();
-                        Ok(s)
-                    } else if decimals >= 326 {
-                        // More precise than smallest denormal.
-                        Ok(s.clone())
-                    } else if decimals >= 300 {
-                        // We're getting into unrepresentable territory for the multiplier
-                        // here, split up the 10^n multiplier into 2^n and 5^n.
-                        let mul2 = libm::scalbn(1.0, decimals as i32);
-                        let invmul2 = 1.0 / mul2; // Still exact for any valid value of decimals.
-                        let mul5 = 5.0_f64.powi(decimals as i32);
-                        let s = ca
-                            .apply_values(|val| {
-                                let ret = (val * mul2 * mul5).round_ties_even() / mul5 * invmul2;
-                                if ret.is_finite() {
-                                    ret
-                                } else {
-                                    // We return the original value which is correct both for overflows and non-finite inputs.
-                                    val
-                                }
-                            })
-                            .into_series();
-                        Ok(s)
-                    } else {
-                        let multiplier = 10.0_f64.powi(decimals as i32);
-                        let s = ca
-                            .apply_values(|val| {
-                                let ret = (val * multiplier).round_ties_even() / multiplier;
-                                if ret.is_finite() {
-                                    ret
-                                } else {
-                                    // We return the original value which is correct both for overflows and non-finite inputs.
-                                    val
-                                }
-                            })
-                            .into_series();
-                        Ok(s)
-                    };
-                },
-                RoundMode::HalfAwayFromZero => {
-                    return if decimals == 0 {
-                        let s = ca.apply_values(|val| val.round()).into_series();
-                        Ok(s)
-                    } else if decimals >= 326 {
-                        // More precise than smallest denormal.
-                        Ok(s.clone())
-                    } else if decimals >= 300 {
-                        // We're getting into unrepresentable territory for the multiplier
-                        // here, split up the 10^n multiplier into 2^n and 5^n.
-                        let mul2 = libm::scalbn(1.0, decimals as i32);
-                        let invmul2 = 1.0 / mul2; // Still exact for any valid value of decimals.
-                        let mul5 = 5.0_f64.powi(decimals as i32);
-                        let s = ca
-                            .apply_values(|val| {
-                                let ret = (val * mul2 * mul5).round() / mul5 * invmul2;
-                                if ret.is_finite() {
-                                    ret
-                                } else {
-                                    // We return the original value which is correct both for overflows and non-finite inputs.
-                                    val
-                                }
-                            })
-                            .into_series();
-                        Ok(s)
-                    } else {
-                        let multiplier = 10.0_f64.powi(decimals as i32);
-                        let s = ca
-                            .apply_values(|val| {
-                                let ret = (val * multiplier).round() / multiplier;
-                                if ret.is_finite() {
-                                    ret
-                                } else {
-                                    // We return the original value which is correct both for overflows and non-finite inputs.
-                                    val
-                                }
-                            })
-                            .into_series();
-                        Ok(s)
-                    };
-                },
-            }
+            let s = ca
+                .apply_values(|val| round_f64(val, decimals, mode))
+                .into_series();
+            return Ok(s);
         }
         #[cfg(feature = "dtype-decimal")]
         if let Some(ca) = s.try_decimal() {
@@ -205,6 +175,9 @@ pub trait RoundSeries: SeriesSealed {
                     };
                     v - rem + round_offset
                 }),
+                RoundMode::Floor | RoundMode::Ceil => ca
+                    .physical()
+                    .apply_values(|v| mode.round_i128(v, multiplier)),
             };
             return Ok(res
                 .into_decimal_unchecked(ca.precision(), scale as usize)
@@ -215,6 +188,61 @@ pub trait RoundSeries: SeriesSealed {
         Ok(s.clone())
     }
 
+    /// Round to the closest multiple of `increment` in the direction of `mode`, e.g. to the next
+    /// multiple of 0.05 with [`RoundMode::Ceil`].
+    ///
+    /// Decimals are rounded exactly, which requires `increment` to be a multiple of their
+    /// precision.
+    fn round_to_increment(&self, increment: f64, mode: RoundMode) -> PolarsResult<Series> {
+        let s = self.as_series();
+        polars_ensure!(
+            increment.is_finite() && increment > 0.0,
+            InvalidOperation: "`increment` must be positive, got {increment}"
+        );
+
+        if let Ok(ca) = s.f32() {
+            let s = ca
+                .apply_values(|val| {
+                    let ret =
+                        round_f64_to_increment(val as f64, increment, mode, f32::EPSILON as f64)
+                            as f32;
+                    if ret.is_finite() { ret } else { val }
+                })
+                .into_series();
+            return Ok(s);
+        }
+        if let Ok(ca) = s.f64() {
+            let s = ca
+                .apply_values(|val| round_f64_to_increment(val, increment, mode, f64::EPSILON))
+                .into_series();
+            return Ok(s);
+        }
+        #[cfg(feature = "dtype-decimal")]
+        if let Some(ca) = s.try_decimal() {
+            let scale = ca.scale();
+            let scaled = increment * 10.0_f64.powi(scale as i32);
+            let multiplier = scaled.round();
+            polars_ensure!(
+                multiplier >= 1.0 && (scaled - multiplier).abs() <= 1e-9 * multiplier,
+                InvalidOperation: "`increment` {increment} is not a multiple of the precision of a \
+                Decimal with scale {scale}"
+            );
+            let multiplier = multiplier as i128;
+            let s = ca
+                .physical()
+                .apply_values(|v| mode.round_i128(v, multiplier))
+                .into_decimal_unchecked(ca.precision(), scale)
+                .into_series();
+            return Ok(s);
+        }
+
+        polars_bail!(
+            InvalidOperation: "`round_to_increment` can only be used on float and decimal columns, \
+            got {}; cast to a float first",
+            s.dtype()
+        )
+    }
+
     fn round_sig_figs(&self, digits: i32) -> PolarsResult<Series> {
         let s = self.as_series();
         polars_ensure!(digits >= 1, InvalidOperation: "digits must be an integer >= 1");
@@ -377,5 +405,36 @@ mod test {
         let out = series.round(2, RoundMode::default()).unwrap();
         let ca = out.f64().unwrap();
         assert_eq!(ca.get(0), Some(1.0));
+
+        let series = Series::new("a".into(), &[0.125f32, -0.125, 0.375]);
+        let out = series.round(2, RoundMode::HalfAwayFromZero).unwrap();
+        let out = out.f32().unwrap().into_no_null_iter().collect::<Vec<_>>();
+        assert_eq!(out, [0.13, -0.13, 0.38]);
+    }
+
+    #[test]
+    fn test_round_to_increment() {
+        let series = Series::new("a".into(), &[0.15, 0.125, -0.125, 1.01, -0.01]);
+        let round = |mode| {
+            let out = series.round_to_increment(0.05, mode).unwrap();
+            out.f64().unwrap().into_no_null_iter().collect::<Vec<_>>()
+        };
+        assert_eq!(round(RoundMode::Floor), [0.15, 0.1, -0.15, 1.0, -0.05]);
+        assert_eq!(round(RoundMode::Ceil), [0.15, 0.15, -0.1, 1.05, 0.0]);
+        assert_eq!(
+            round(RoundMode::HalfAwayFromZero),
+            [0.15, 0.15, -0.15, 1.0, 0.0]
+        );
+        assert_eq!(round(RoundMode::HalfToEven), [0.15, 0.1, -0.1, 1.0, 0.0]);
+
+        for (v, mode, expected) in [
+            (25, RoundMode::HalfToEven, 20),
+            (35, RoundMode::HalfToEven, 40),
+            (-25, RoundMode::HalfAwayFromZero, -30),
+            (-21, RoundMode::Floor, -30),
+            (-29, RoundMode::Ceil, -20),
+        ] {
+            assert_eq!(mode.round_i128(v, 10), expected);
+        }
     }
 }
@@ -6,11 +6,23 @@ use super::*;
 type DummyType = u8;
 #[cfg(feature = "dtype-u8")]
 type DummyCa = UInt8Chunked;
+#[cfg(all(
+    feature = "dtype-u8",
+    feature = "dtype-struct",
+    feature = "dtype-categorical"
+))]
+const DUMMY_DTYPE: DataType = DataType::UInt8;
 
 #[cfg(not(feature = "dtype-u8"))]
 type DummyType = i32;
 #[cfg(not(feature = "dtype-u8"))]
 type DummyCa = Int32Chunked;
+#[cfg(all(
+    not(feature = "dtype-u8"),
+    feature = "dtype-struct",
+    feature = "dtype-categorical"
+))]
+const DUMMY_DTYPE: DataType = DataType::Int32;
 
 pub trait ToDummies {
     fn to_dummies(
@@ -95,3 +107,85 @@ fn sort_columns(mut columns: Vec<Column>) -> Vec<Column> {
     columns.sort_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
     columns
 }
+
+/// The output dtype of [`enum_to_dummies`] for a column `name` of `dtype`.
+///
+/// Only `Enum` columns are supported, as their categories, and thus the output fields, are known
+/// up front.
+#[cfg(all(feature = "dtype-struct", feature = "dtype-categorical"))]
+pub fn enum_dummies_dtype(
+    name: &str,
+    dtype: &DataType,
+    separator: &str,
+    drop_first: bool,
+    sparse: bool,
+) -> PolarsResult<DataType> {
+    let DataType::Enum(fcats, _) = dtype else {
+        polars_bail!(
+            InvalidOperation: "`to_dummies` expression requires an Enum column, got {}; cast to an Enum first, or use `DataFrame.to_dummies`",
+            dtype
+        )
+    };
+    if sparse {
+        return Ok(DataType::List(Box::new(DataType::UInt32)));
+    }
+    let fields = fcats
+        .categories()
+        .values_iter()
+        .skip(drop_first as usize)
+        .map(|cat| Field::new(format_pl_smallstr!("{name}{separator}{cat}"), DUMMY_DTYPE))
+        .collect();
+    Ok(DataType::Struct(fields))
+}
+
+/// One-hot encode an `Enum` column.
+///
+/// Returns a struct with a dummy column per category, or if `sparse` is set, a list per row with
+/// the index of its category. Nulls and, if `drop_first` is set, the first category are
+/// encoded as all zeros or as an empty list.
+#[cfg(all(feature = "dtype-struct", feature = "dtype-categorical"))]
+pub fn enum_to_dummies(
+    s: &Series,
+    separator: &str,
+    drop_first: bool,
+    sparse: bool,
+) -> PolarsResult<Series> {
+    let dtype = enum_dummies_dtype(s.name(), s.dtype(), separator, drop_first, sparse)?;
+    let codes = s.to_physical_repr().cast(&DataType::UInt32)?;
+    let codes = codes.u32()?;
+    // The index of the category among the output columns.
+    let offset = drop_first as u32;
+    let column_idx = |code: Option<u32>| code.and_then(|c| c.checked_sub(offset));
+
+    if sparse {
+        let mut builder = ListPrimitiveChunkedBuilder::<UInt32Type>::new(
+            s.name().clone(),
+            s.len(),
+            s.len(),
+            DataType::UInt32,
+        );
+        for code in codes {
+            match column_idx(code) {
+                Some(idx) => builder.append_slice(&[idx]),
+                None => builder.append_slice(&[]),
+            }
+        }
+        return Ok(builder.finish().into_series());
+    }
+
+    let DataType::Struct(fields) = dtype else {
+        unreachable!()
+    };
+    let mut values = vec![vec![0 as DummyType; s.len()]; fields.len()];
+    for (row, code) in codes.iter().enumerate() {
+        if let Some(idx) = column_idx(code) {
+            values[idx as usize][row] = 1;
+        }
+    }
+    let columns = fields
+        .into_iter()
+        .zip(values)
+        .map(|(field, values)| DummyCa::from_vec(field.name, values).into_series())
+        .collect::<Vec<_>>();
+    Ok(StructChunked::from_series(s.name().clone(), s.len(), columns.iter())?.into_series())
+}
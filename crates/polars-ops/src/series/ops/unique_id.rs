@@ -0,0 +1,130 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use rand::prelude::*;
+
+/// Custom epoch of the snowflake IDs (2020-01-01T00:00:00Z) in milliseconds, which makes the 41
+/// timestamp bits last until 2089.
+pub const SNOWFLAKE_EPOCH_MS: u64 = 1_577_836_800_000;
+/// The largest worker id that fits in the 10 worker bits of a snowflake ID.
+pub const SNOWFLAKE_MAX_WORKER_ID: u16 = (1 << WORKER_BITS) - 1;
+
+const WORKER_BITS: u32 = 10;
+const SEQUENCE_BITS: u32 = 12;
+const SEQUENCE_MASK: u64 = (1 << SEQUENCE_BITS) - 1;
+
+/// The last `timestamp << SEQUENCE_BITS | sequence` handed out in this process.
+///
+/// This is shared by all threads, so IDs are unique and increasing per worker id no matter from
+/// which pipeline they are generated.
+static LAST_SNOWFLAKE: AtomicU64 = AtomicU64::new(0);
+
+const HEX: &[u8; 16] = b"0123456789abcdef";
+
+struct UuidStr([u8; 36]);
+
+impl UuidStr {
+    fn new(mut bytes: [u8; 16]) -> Self {
+        // Version 4 and the RFC 9562 variant.
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        let mut out = [b'-'; 36];
+        let mut pos = 0;
+        for (i, b) in bytes.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                pos += 1;
+            }
+            out[pos] = HEX[(b >> 4) as usize];
+            out[pos + 1] = HEX[(b & 0x0f) as usize];
+            pos += 2;
+        }
+        Self(out)
+    }
+}
+
+impl AsRef<str> for UuidStr {
+    fn as_ref(&self) -> &str {
+        // SAFETY: only ASCII hex digits and dashes are written.
+        unsafe { std::str::from_utf8_unchecked(&self.0) }
+    }
+}
+
+/// Generate `n` random (version 4) UUIDs in their canonical hyphenated form.
+pub fn uuid4(name: PlSmallStr, n: usize) -> StringChunked {
+    let mut rng = SmallRng::from_os_rng();
+    StringChunked::from_iter_values(name, (0..n).map(|_| UuidStr::new(rng.random())))
+}
+
+fn reserve_snowflakes(n: u64) -> u64 {
+    let now_ms = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis() as u64)
+        .saturating_sub(SNOWFLAKE_EPOCH_MS);
+    let now = now_ms << SEQUENCE_BITS;
+
+    // If the sequence of the current millisecond is exhausted, we borrow from the next
+    // millisecond so that the IDs stay unique.
+    let prev = LAST_SNOWFLAKE
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(now.max(last + 1) + n - 1)
+        })
+        .unwrap();
+    now.max(prev + 1)
+}
+
+/// Generate `n` snowflake-style IDs.
+///
+/// The IDs consist of a millisecond timestamp since [`SNOWFLAKE_EPOCH_MS`] (41 bits), the
+/// `worker_id` (10 bits) and a sequence number (12 bits). They are unique and strictly
+/// increasing within a process for a given `worker_id`. Processes that generate IDs
+/// concurrently should use different worker ids.
+pub fn snowflake_ids(name: PlSmallStr, n: usize, worker_id: u16) -> PolarsResult<UInt64Chunked> {
+    polars_ensure!(
+        worker_id <= SNOWFLAKE_MAX_WORKER_ID,
+        InvalidOperation: "`worker_id` must be at most {}, got {}", SNOWFLAKE_MAX_WORKER_ID, worker_id
+    );
+    let start = reserve_snowflakes(n as u64);
+    let worker = (worker_id as u64) << SEQUENCE_BITS;
+    let mut ca = UInt64Chunked::from_iter_values(
+        name,
+        (start..start + n as u64).map(|counter| {
+            let timestamp = counter >> SEQUENCE_BITS;
+            (timestamp << (WORKER_BITS + SEQUENCE_BITS)) | worker | (counter & SEQUENCE_MASK)
+        }),
+    );
+    ca.set_sorted_flag(IsSorted::Ascending);
+    Ok(ca)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_uuid4_format() {
+        let ca = uuid4(PlSmallStr::EMPTY, 16);
+        for s in ca.into_no_null_iter() {
+            assert_eq!(s.len(), 36);
+            assert_eq!(&s[14..15], "4");
+            assert!(matches!(&s[19..20], "8" | "9" | "a" | "b"));
+            assert_eq!(s.matches('-').count(), 4);
+        }
+    }
+
+    #[test]
+    fn test_snowflake_ids_increasing() {
+        let a = snowflake_ids(PlSmallStr::EMPTY, 10_000, 3).unwrap();
+        let b = snowflake_ids(PlSmallStr::EMPTY, 10, 3).unwrap();
+        let ids = a
+            .into_no_null_iter()
+            .chain(b.into_no_null_iter())
+            .collect::<Vec<_>>();
+        assert!(ids.windows(2).all(|w| w[0] < w[1]));
+        assert!(ids.iter().all(|id| (id >> SEQUENCE_BITS) & 0x3ff == 3));
+
+        assert!(snowflake_ids(PlSmallStr::EMPTY, 1, 1024).is_err());
+    }
+}
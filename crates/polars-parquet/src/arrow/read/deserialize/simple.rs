@@ -393,6 +393,33 @@ pub fn page_iter_to_array(
                 ptm,
             )
         },
+        (PhysicalType::FixedLenByteArray(2), Float16) => {
+            let (nested, mut fsb_array, ptm) = PageDecoder::new(
+                &field.name,
+                pages,
+                ArrowDataType::FixedSizeBinary(2),
+                fixed_size_binary::BinaryDecoder { size: 2 },
+                init_nested,
+            )?
+            .collect(filter)?;
+
+            let validity = fsb_array.take_validity();
+            let values = fsb_array
+                .values()
+                .chunks_exact(2)
+                .map(|v| {
+                    // SAFETY: We know that `v` is always of size two.
+                    let le_bytes: [u8; 2] = unsafe { v.try_into().unwrap_unchecked() };
+                    arrow::types::f16::from_le_bytes(le_bytes)
+                })
+                .collect();
+
+            (
+                nested,
+                PrimitiveArray::<arrow::types::f16>::new(dtype, values, validity).to_boxed(),
+                ptm,
+            )
+        },
 
         (PhysicalType::Float, Float32) => PageDecoder::new(
             &field.name,
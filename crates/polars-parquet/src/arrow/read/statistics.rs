@@ -205,6 +205,11 @@ impl ColumnStatistics {
             // some implementations of parquet write arrow's u32 into i64.
             (D::UInt32, PPT::Int64) => rmap!(expect_int64, @prim i64 as u32),
             (D::UInt64, _) => rmap!(expect_int64, @prim i64 as u64),
+            (D::Int128, PPT::FixedLenByteArray(16)) => rmap!(
+                expect_fixedlen,
+                @prim Vec<u8>,
+                |x| convert_i128(&x, 16)
+            ),
 
             (D::Timestamp(time_unit, _), PPT::Int96) => {
                 rmap!(expect_int96, @prim [u32; 3], |x| {
@@ -217,6 +222,10 @@ impl ColumnStatistics {
                 })
             },
 
+            #[cfg(feature = "dtype-f16")]
+            (D::Float16, PPT::FixedLenByteArray(2)) => {
+                rmap!(expect_fixedlen, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+            },
             // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
             (_, PPT::FixedLenByteArray(2))
                 if matches!(
@@ -456,6 +465,12 @@ pub fn deserialize_all(
                 (D::UInt64, _) => {
                     rmap!(expect_int64, MutablePrimitiveArray::<u64>, @prim i64 as u64)
                 },
+                (D::Int128, PPT::FixedLenByteArray(16)) => rmap!(
+                    expect_fixedlen,
+                    MutablePrimitiveArray::<i128>,
+                    @prim Vec<u8>,
+                    |x| convert_i128(&x, 16)
+                ),
 
                 (D::Timestamp(time_unit, _), PPT::Int96) => {
                     rmap!(expect_int96, MutablePrimitiveArray::<i64>, @prim [u32; 3], |x| {
@@ -468,6 +483,10 @@ pub fn deserialize_all(
                     })
                 },
 
+                #[cfg(feature = "dtype-f16")]
+                (D::Float16, PPT::FixedLenByteArray(2)) => {
+                    rmap!(expect_fixedlen, MutablePrimitiveArray::<f16>, @prim Vec<u8>, |v| f16::from_le_bytes([v[0], v[1]]))
+                },
                 // Read Float16, since we don't have a f16 type in Polars we read it to a Float32.
                 (_, PPT::FixedLenByteArray(2))
                     if matches!(logical_type.as_ref(), Some(PrimitiveLogicalType::Float16)) =>
@@ -27,7 +27,7 @@ mod utils;
 
 use arrow::array::*;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, f16, i256};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -241,6 +241,15 @@ pub fn slice_nested_leaf(nested: &[Nested]) -> (usize, usize) {
     out
 }
 
+/// Parquet stores half precision floats as two little endian bytes.
+fn f16_to_fixed_size_binary(array: &PrimitiveArray<f16>) -> FixedSizeBinaryArray {
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(2),
+        array.values().clone().try_transmute().unwrap(),
+        array.validity().cloned(),
+    )
+}
+
 fn decimal_length_from_precision(precision: usize) -> usize {
     // digits = floor(log_10(2^(8*n - 1) - 1))
     // ceil(digits) = log10(2^(8*n - 1) - 1)
@@ -651,6 +660,12 @@ pub fn array_to_page_simple(
 
             fixed_size_binary::array_to_page(array, options, type_, statistics)
         },
+        ArrowDataType::Float16 => {
+            // The byte order of the binary statistics is not the order of the values, so they are
+            // not written.
+            let array = f16_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::Decimal256(precision, _) => {
             let precision = *precision;
             let array = array
@@ -1090,6 +1105,10 @@ fn array_to_page_nested(
             );
             fixed_size_binary::nested_array_to_page(&array, options, type_, nested, statistics)
         },
+        Float16 => {
+            let array = f16_to_fixed_size_binary(array.as_any().downcast_ref().unwrap());
+            fixed_size_binary::nested_array_to_page(&array, options, type_, nested, None)
+        },
         other => polars_bail!(nyi = "Writing nested parquet pages for data type {other:?}"),
     }
     .map(Page::Data)
@@ -0,0 +1,39 @@
+use super::*;
+
+/// Specialized expressions for anonymizing data before it is shared.
+pub struct AnonymizeNameSpace(pub(crate) Expr);
+
+impl AnonymizeNameSpace {
+    /// Hash the values with keyed BLAKE3, keyed by one of `salts`, selected per row by `salt_id`.
+    ///
+    /// The hashes are stable across sessions and Polars versions, unlike [`Expr::hash`]. The salts
+    /// are not serialized with the expression.
+    pub fn hash_stable(self, salt_id: Expr, salts: Vec<PlSmallStr>) -> Expr {
+        self.0
+            .map_binary(AnonymizeFunction::HashStable { salts }, salt_id)
+    }
+
+    /// Replace the values that occur fewer than `k` times by null.
+    pub fn suppress_rare(self, k: IdxSize) -> Expr {
+        self.0.map_unary(AnonymizeFunction::SuppressRare { k })
+    }
+
+    /// Truncate temporal values to the start of their `year`, `quarter`, `month`, `week`, `day`
+    /// or `hour`.
+    #[cfg(feature = "temporal")]
+    pub fn generalize_date(self, level: &str) -> PolarsResult<Expr> {
+        let every = match level {
+            "year" => "1y",
+            "quarter" => "1q",
+            "month" => "1mo",
+            "week" => "1w",
+            "day" => "1d",
+            "hour" => "1h",
+            _ => polars_bail!(
+                InvalidOperation: "invalid `generalize_date` level {:?}, expected one of \
+                'year', 'quarter', 'month', 'week', 'day' or 'hour'", level
+            ),
+        };
+        Ok(self.0.dt().truncate(lit(every)))
+    }
+}
@@ -57,6 +57,40 @@ impl Expr {
         binary_expr(self, Operator::FloorDivide, rhs)
     }
 
+    /// Add `rhs` to `self`, yielding null where the integer result overflows.
+    pub fn add_checked(self, rhs: Self) -> Self {
+        self.map_binary(
+            FunctionExpr::CheckedArithmetic(CheckedArithmeticOp::Add),
+            rhs,
+        )
+    }
+
+    /// Subtract `rhs` from `self`, yielding null where the integer result overflows.
+    pub fn sub_checked(self, rhs: Self) -> Self {
+        self.map_binary(
+            FunctionExpr::CheckedArithmetic(CheckedArithmeticOp::Sub),
+            rhs,
+        )
+    }
+
+    /// Multiply `self` by `rhs`, yielding null where the integer result overflows.
+    pub fn mul_checked(self, rhs: Self) -> Self {
+        self.map_binary(
+            FunctionExpr::CheckedArithmetic(CheckedArithmeticOp::Mul),
+            rhs,
+        )
+    }
+
+    /// Divide `self` by `rhs` in a single pass, yielding `default` where `rhs` is zero.
+    pub fn truediv_or(self, rhs: Self, default: Self) -> Self {
+        self.map_ternary(FunctionExpr::DivOr(DivOrOp::TrueDiv), rhs, default)
+    }
+
+    /// Floor divide `self` by `rhs` in a single pass, yielding `default` where `rhs` is zero.
+    pub fn floordiv_or(self, rhs: Self, default: Self) -> Self {
+        self.map_ternary(FunctionExpr::DivOr(DivOrOp::FloorDiv), rhs, default)
+    }
+
     /// Raise expression to the power `exponent`
     pub fn pow<E: Into<Expr>>(self, exponent: E) -> Self {
         self.map_binary(PowFunction::Generic, exponent.into())
@@ -221,6 +221,7 @@ impl DslBuilder {
         aggs: E,
         apply: Option<(Arc<dyn DataFrameUdf>, SchemaRef)>,
         maintain_order: bool,
+        expand_categories: bool,
         #[cfg(feature = "dynamic_group_by")] dynamic_options: Option<DynamicGroupOptions>,
         #[cfg(feature = "dynamic_group_by")] rolling_options: Option<RollingGroupOptions>,
     ) -> Self {
@@ -231,6 +232,7 @@ impl DslBuilder {
             #[cfg(feature = "dynamic_group_by")]
             rolling: rolling_options,
             slice: None,
+            expand_categories,
         };
 
         DslPlan::GroupBy {
@@ -267,12 +269,18 @@ impl DslBuilder {
         .into()
     }
 
-    pub fn explode(self, columns: Selector, allow_empty: bool) -> Self {
+    pub fn explode(
+        self,
+        columns: Selector,
+        allow_empty: bool,
+        index_name: Option<PlSmallStr>,
+    ) -> Self {
         DslPlan::MapFunction {
             input: Arc::new(self.0),
             function: DslFunction::Explode {
                 columns,
                 allow_empty,
+                index_name,
             },
         }
         .into()
@@ -65,6 +65,17 @@ impl DateLikeNameSpace {
         ))
     }
 
+    /// Convert every datetime to the local time in the time zone of its row in `time_zones`.
+    ///
+    /// As the rows may be in different time zones, the result is time-zone-naive.
+    #[cfg(feature = "timezones")]
+    pub fn convert_time_zone_per_row(self, time_zones: Expr) -> Expr {
+        self.0.map_binary(
+            FunctionExpr::TemporalExpr(TemporalFunction::ConvertTimeZonePerRow),
+            time_zones,
+        )
+    }
+
     /// Get the millennium of a Date/Datetime
     pub fn millennium(self) -> Expr {
         self.0
@@ -281,6 +292,17 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Combine a Date or time-zone-naive Datetime with a Time, localizing the result in the time
+    /// zone of its row in `time_zones` to get a Datetime in UTC.
+    #[cfg(feature = "timezones")]
+    pub fn combine_in_time_zones(self, time: Expr, tu: TimeUnit, time_zones: Expr) -> Expr {
+        self.0.map_ternary(
+            FunctionExpr::TemporalExpr(TemporalFunction::Combine(tu)),
+            time,
+            time_zones,
+        )
+    }
+
     /// Express a Duration in terms of its total number of integer days.
     #[cfg(feature = "dtype-duration")]
     pub fn total_days(self) -> Expr {
@@ -177,6 +177,8 @@ pub enum Expr {
         function: RenameAliasFn,
         expr: Arc<Expr>,
     },
+    /// A scalar expression that is computed once per query, see [`cached`](crate::dsl::cached).
+    Cached(Arc<Expr>),
 }
 
 #[derive(Clone)]
@@ -371,6 +373,7 @@ impl Hash for Expr {
                 variant.hash(state);
             },
             Expr::SubPlan(_, names) => names.hash(state),
+            Expr::Cached(input) => input.hash(state),
             #[cfg(feature = "dtype-struct")]
             Expr::Field(names) => names.hash(state),
         }
@@ -536,12 +539,16 @@ pub enum EvalVariant {
 
     /// `cumulative_eval`
     Cumulative { min_samples: usize },
+
+    /// `list.agg`
+    ListAgg,
 }
 
 impl EvalVariant {
     pub fn to_name(&self) -> &'static str {
         match self {
             Self::List => "list.eval",
+            Self::ListAgg => "list.agg",
             Self::Cumulative { min_samples: _ } => "cumulative_eval",
         }
     }
@@ -549,7 +556,7 @@ impl EvalVariant {
     /// Get the `DataType` of the `pl.element()` value.
     pub fn element_dtype<'a>(&self, dtype: &'a DataType) -> PolarsResult<&'a DataType> {
         match (self, dtype) {
-            (Self::List, DataType::List(inner)) => Ok(inner.as_ref()),
+            (Self::List | Self::ListAgg, DataType::List(inner)) => Ok(inner.as_ref()),
             (Self::Cumulative { min_samples: _ }, dt) => Ok(dt),
             _ => polars_bail!(op = self.to_name(), dtype),
         }
@@ -672,6 +679,14 @@ pub enum RenameAliasFn {
     Python(SpecialEq<Arc<polars_utils::python_function::PythonObject>>),
     #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
     Rust(SpecialEq<Arc<RenameAliasRustFn>>),
+    /// Replace every `{}` in the template by the name.
+    Format(PlSmallStr),
+    #[cfg(feature = "regex")]
+    Replace {
+        pattern: PlSmallStr,
+        value: PlSmallStr,
+        literal: bool,
+    },
 }
 
 impl RenameAliasFn {
@@ -694,6 +709,22 @@ impl RenameAliasFn {
                 }).map_err(|e| polars_err!(ComputeError: "Python function in 'name.map' produced an error: {e}."))?
             },
             Self::Rust(f) => f(name)?,
+            Self::Format(template) => PlSmallStr::from_string(template.replace("{}", name)),
+            #[cfg(feature = "regex")]
+            Self::Replace {
+                pattern,
+                value,
+                literal,
+            } => {
+                if *literal {
+                    PlSmallStr::from_string(name.replace(pattern.as_str(), value))
+                } else {
+                    let re = regex::Regex::new(pattern).map_err(
+                        |e| polars_err!(ComputeError: "invalid regex in 'name.replace': {e}"),
+                    )?;
+                    PlSmallStr::from(re.replace_all(name, value.as_str()).as_ref())
+                }
+            },
         };
         Ok(out)
     }
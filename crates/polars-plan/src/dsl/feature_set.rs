@@ -0,0 +1,178 @@
+#[cfg(feature = "serde")]
+use std::io::{Read, Write};
+
+#[cfg(feature = "serde")]
+use polars_utils::pl_serialize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+use crate::utils::expr_to_leaf_column_names_iter;
+
+#[cfg(feature = "serde")]
+static FEATURE_SET_MAGIC_BYTES: &[u8] = b"FEATURE_SET";
+
+/// A named expression of a [`FeatureSet`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Feature {
+    pub name: PlSmallStr,
+    pub expr: Expr,
+    /// The dtype that the expression must evaluate to, if set.
+    pub dtype: Option<DataType>,
+}
+
+/// A versioned bundle of named expressions that is added to frames as columns, so that they can
+/// be shipped as data.
+///
+/// Features may refer to other features of the set by name, in which case they are evaluated
+/// after the features they refer to. A feature that refers to its own name reads the column of
+/// that name of the frame. Only references by column name are followed, not selectors.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FeatureSet {
+    pub name: PlSmallStr,
+    pub version: u32,
+    features: Vec<Feature>,
+}
+
+impl FeatureSet {
+    /// Create a feature set, which fails if the names of the features are not unique or the
+    /// features refer to each other in a cycle.
+    pub fn new(name: PlSmallStr, version: u32, features: Vec<Feature>) -> PolarsResult<Self> {
+        let feature_set = Self {
+            name,
+            version,
+            features,
+        };
+        feature_set.stages()?;
+        Ok(feature_set)
+    }
+
+    pub fn features(&self) -> &[Feature] {
+        &self.features
+    }
+
+    /// Get the indices of the features that every feature refers to.
+    fn dependencies(&self) -> PolarsResult<Vec<Vec<usize>>> {
+        let mut index = PlHashMap::with_capacity(self.features.len());
+        for (i, feature) in self.features.iter().enumerate() {
+            polars_ensure!(
+                index.insert(feature.name.clone(), i).is_none(),
+                Duplicate: "feature '{}' occurs more than once in feature set '{}'",
+                feature.name, self.name
+            );
+        }
+        Ok(self
+            .features
+            .iter()
+            .enumerate()
+            .map(|(i, feature)| {
+                let mut deps: Vec<usize> = expr_to_leaf_column_names_iter(&feature.expr)
+                    .filter_map(|name| index.get(&name).copied())
+                    .filter(|&dep| dep != i)
+                    .collect();
+                deps.sort_unstable();
+                deps.dedup();
+                deps
+            })
+            .collect())
+    }
+
+    /// Get the columns of the frame that the features read, i.e. the columns they refer to that
+    /// are not features of the set, or that have the name of the feature that refers to them.
+    pub fn input_columns(&self) -> Vec<PlSmallStr> {
+        let names: PlHashSet<&PlSmallStr> = self.features.iter().map(|f| &f.name).collect();
+        let mut out = PlIndexSet::new();
+        for feature in &self.features {
+            out.extend(
+                expr_to_leaf_column_names_iter(&feature.expr)
+                    .filter(|name| name == &feature.name || !names.contains(name)),
+            );
+        }
+        out.into_iter().collect()
+    }
+
+    /// Group the features, aliased to their names, into stages that only refer to the features
+    /// of earlier stages, so that the features of a stage can be evaluated in parallel.
+    pub fn stages(&self) -> PolarsResult<Vec<Vec<Expr>>> {
+        fn stage_of(
+            i: usize,
+            deps: &[Vec<usize>],
+            stages: &mut [Option<usize>],
+            visiting: &mut [bool],
+            feature_set: &FeatureSet,
+        ) -> PolarsResult<usize> {
+            if let Some(stage) = stages[i] {
+                return Ok(stage);
+            }
+            polars_ensure!(
+                !visiting[i],
+                InvalidOperation: "the features of feature set '{}' refer to each other in a cycle \
+                through feature '{}'",
+                feature_set.name, feature_set.features[i].name
+            );
+            visiting[i] = true;
+            let mut stage = 0;
+            for &dep in &deps[i] {
+                stage = stage.max(stage_of(dep, deps, stages, visiting, feature_set)? + 1);
+            }
+            visiting[i] = false;
+            stages[i] = Some(stage);
+            Ok(stage)
+        }
+
+        let deps = self.dependencies()?;
+        let mut stages = vec![None; self.features.len()];
+        let mut visiting = vec![false; self.features.len()];
+        let mut out: Vec<Vec<Expr>> = vec![];
+        for (i, feature) in self.features.iter().enumerate() {
+            let stage = stage_of(i, &deps, &mut stages, &mut visiting, self)?;
+            if out.len() <= stage {
+                out.resize_with(stage + 1, Vec::new);
+            }
+            out[stage].push(feature.expr.clone().alias(feature.name.clone()));
+        }
+        Ok(out)
+    }
+
+    /// Serialize the feature set, prefixed with the DSL version it is compatible with.
+    #[cfg(feature = "serde")]
+    pub fn serialize_versioned<W: Write>(&self, mut writer: W) -> PolarsResult<()> {
+        writer.write_all(FEATURE_SET_MAGIC_BYTES)?;
+        writer.write_all(&DSL_VERSION.0.to_le_bytes())?;
+        writer.write_all(&DSL_VERSION.1.to_le_bytes())?;
+        pl_serialize::SerializeOptions::default().serialize_into_writer::<_, _, true>(writer, self)
+    }
+
+    /// Deserialize a feature set serialized with [`FeatureSet::serialize_versioned`] by a Polars
+    /// version with a compatible DSL version.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_versioned<R: Read>(mut reader: R) -> PolarsResult<Self> {
+        const MAGIC_LEN: usize = FEATURE_SET_MAGIC_BYTES.len();
+        let mut header = [0u8; MAGIC_LEN + 4];
+        reader
+            .read_exact(&mut header)
+            .map_err(|e| polars_err!(ComputeError: "failed to read incoming DSL_VERSION: {e}"))?;
+        polars_ensure!(
+            &header[..MAGIC_LEN] == FEATURE_SET_MAGIC_BYTES,
+            ComputeError: "feature set magic bytes not found"
+        );
+
+        let major = u16::from_le_bytes([header[MAGIC_LEN], header[MAGIC_LEN + 1]]);
+        let minor = u16::from_le_bytes([header[MAGIC_LEN + 2], header[MAGIC_LEN + 3]]);
+        let (major_expected, minor_expected) = DSL_VERSION;
+        polars_ensure!(
+            major == major_expected && minor <= minor_expected,
+            ComputeError: "deserialization failed\n\ngiven DSL_VERSION: {major}.{minor} is not \
+            compatible with this Polars version which uses DSL_VERSION: \
+            {major_expected}.{minor_expected}"
+        );
+
+        let feature_set: Self = pl_serialize::SerializeOptions::default()
+            .deserialize_from_reader::<_, _, true>(reader)
+            .map_err(|e| polars_err!(ComputeError: "deserialization failed\n\nerror: {e}"))?;
+        feature_set.stages()?;
+        Ok(feature_set)
+    }
+}
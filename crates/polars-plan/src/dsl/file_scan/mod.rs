@@ -1,5 +1,6 @@
 use std::hash::Hash;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use deletion::DeletionFilesList;
 use polars_core::schema::iceberg::IcebergSchemaRef;
@@ -18,9 +19,11 @@ use polars_utils::slice_enum::Slice;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use strum_macros::IntoStaticStr;
+use table_statistics::TableStatistics;
 
 use super::*;
 pub mod deletion;
+pub mod table_statistics;
 
 #[cfg(feature = "python")]
 pub mod python_dataset;
@@ -221,6 +224,80 @@ pub enum ColumnMapping {
     Iceberg(IcebergSchemaRef),
 }
 
+/// What happens when a file of a multi-file scan fails to open, e.g. due to a transient network
+/// error.
+///
+/// This applies to the initialization of the readers, i.e. opening the file and reading its
+/// metadata. Errors while the file data is being read still fail the query.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FileErrorPolicy {
+    /// The number of times a file that failed to open is retried, with an exponential backoff
+    /// starting at [`FileErrorPolicy::INITIAL_BACKOFF`].
+    pub retries: usize,
+    /// Skip the files that still fail after the retries instead of failing the query.
+    pub skip: bool,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    #[cfg_attr(feature = "dsl-schema", schemars(skip))]
+    pub skipped: SkippedFiles,
+}
+
+impl FileErrorPolicy {
+    pub const INITIAL_BACKOFF: Duration = Duration::from_millis(100);
+    pub const MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+    /// The delay before retry number `n_retries`, starting at 1.
+    pub fn backoff(n_retries: usize) -> Duration {
+        let exp = n_retries.saturating_sub(1).min(16) as u32;
+        Self::INITIAL_BACKOFF
+            .saturating_mul(1 << exp)
+            .min(Self::MAX_BACKOFF)
+    }
+}
+
+/// The files that were skipped by a scan with [`FileErrorPolicy::skip`], with their errors.
+///
+/// This is shared by all clones, so that the files that were skipped during execution can be
+/// retrieved from the plan that was built.
+#[derive(Debug, Clone, Default)]
+pub struct SkippedFiles(Arc<Mutex<Vec<(PlSmallStr, PolarsError)>>>);
+
+impl SkippedFiles {
+    pub fn push(&self, path: PlSmallStr, err: PolarsError) {
+        self.0.lock().unwrap().push((path, err));
+    }
+
+    /// The skipped files as a frame with a `path` and an `error` column.
+    pub fn to_df(&self) -> PolarsResult<DataFrame> {
+        let skipped = self.0.lock().unwrap();
+        let paths = skipped.iter().map(|(path, _)| path.as_str());
+        let errors = skipped.iter().map(|(_, err)| err.to_string());
+        DataFrame::new(vec![
+            Column::new("path".into(), paths.collect::<Vec<_>>()),
+            Column::new("error".into(), errors.collect::<Vec<_>>()),
+        ])
+    }
+
+    pub fn clear(&self) {
+        self.0.lock().unwrap().clear();
+    }
+}
+
+impl PartialEq for SkippedFiles {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SkippedFiles {}
+
+impl Hash for SkippedFiles {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        Arc::as_ptr(&self.0).hash(state)
+    }
+}
+
 /// Scan arguments shared across different scan types.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -248,6 +325,12 @@ pub struct UnifiedScanArgs {
 
     pub deletion_files: Option<DeletionFilesList>,
     pub column_mapping: Option<ColumnMapping>,
+    /// Pre-computed statistics of the files, used to skip files and count rows without reading
+    /// their metadata.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub table_statistics: Option<TableStatistics>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub file_error_policy: FileErrorPolicy,
 }
 
 impl Default for UnifiedScanArgs {
@@ -268,6 +351,8 @@ impl Default for UnifiedScanArgs {
             include_file_paths: None,
             deletion_files: None,
             column_mapping: None,
+            table_statistics: None,
+            file_error_policy: FileErrorPolicy::default(),
         }
     }
 }
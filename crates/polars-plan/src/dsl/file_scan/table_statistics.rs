@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+/// Pre-computed statistics of the files of a scan, e.g. from an external catalog, with a row per
+/// file in the order of the expanded sources.
+///
+/// The statistics are stored in the layout that is given to skip batch predicates: a `len`
+/// column with the row counts, and `{name}_min`, `{name}_max` and `{name}_nc` (null count)
+/// columns for some of the columns of the files. Null values are unknown.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct TableStatistics(pub Arc<DataFrame>);
+
+impl TableStatistics {
+    /// Creates statistics from a DataFrame with a row per file and the optional columns:
+    /// * `num_rows`: the number of rows of the file.
+    /// * `min`, `max`, `null_count`: structs with a field per column with its minimum, maximum
+    ///   and null count in the file.
+    ///
+    /// A `path` column is allowed but ignored.
+    pub fn from_catalog_df(df: &DataFrame) -> PolarsResult<Self> {
+        let height = df.height();
+        let mut columns = Vec::new();
+
+        columns.push(match df.column("num_rows").ok() {
+            Some(c) => {
+                polars_ensure!(
+                    c.dtype().is_integer(),
+                    SchemaMismatch: "expected an integer `num_rows` column in the file statistics, got {}",
+                    c.dtype()
+                );
+                c.strict_cast(&IDX_DTYPE)?
+            },
+            None => Column::full_null(PlSmallStr::EMPTY, height, &IDX_DTYPE),
+        }
+        .with_name(PlSmallStr::from_static("len")));
+
+        for c in df.get_columns() {
+            let suffix = match c.name().as_str() {
+                "num_rows" | "path" => continue,
+                "min" => "min",
+                "max" => "max",
+                "null_count" => "nc",
+                name => polars_bail!(
+                    ColumnNotFound: "unexpected column '{}' in the file statistics, expected one of \
+                    'path', 'num_rows', 'min', 'max' or 'null_count'", name
+                ),
+            };
+            columns.extend(unnest_statistics(c, suffix)?);
+        }
+
+        let df = DataFrame::new_with_height(height, columns)?;
+        Ok(Self(Arc::new(df)))
+    }
+
+    /// The number of files.
+    pub fn num_files(&self) -> usize {
+        self.0.height()
+    }
+
+    /// The total number of rows of the files, if it is known for all of them.
+    pub fn num_rows(&self) -> Option<IdxSize> {
+        let len = self.0.column("len").ok()?.idx().ok()?;
+        (len.null_count() == 0).then(|| len.sum().unwrap_or(0))
+    }
+}
+
+#[cfg(feature = "dtype-struct")]
+fn unnest_statistics(c: &Column, suffix: &str) -> PolarsResult<Vec<Column>> {
+    let DataType::Struct(_) = c.dtype() else {
+        polars_bail!(
+            SchemaMismatch: "expected a struct `{}` column in the file statistics, got {}",
+            c.name(), c.dtype()
+        )
+    };
+    c.struct_()?
+        .fields_as_series()
+        .into_iter()
+        .map(|s| {
+            let name = format_pl_smallstr!("{}_{suffix}", s.name());
+            let s = if suffix == "nc" {
+                s.strict_cast(&IDX_DTYPE)?
+            } else {
+                s
+            };
+            Ok(s.with_name(name).into_column())
+        })
+        .collect()
+}
+
+#[cfg(not(feature = "dtype-struct"))]
+fn unnest_statistics(_c: &Column, _suffix: &str) -> PolarsResult<Vec<Column>> {
+    polars_bail!(ComputeError: "file statistics require the 'dtype-struct' feature")
+}
+
+impl PartialEq for TableStatistics {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for TableStatistics {}
+
+impl std::hash::Hash for TableStatistics {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (Arc::as_ptr(&self.0) as usize).hash(state)
+    }
+}
+
+impl std::fmt::Display for TableStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n = self.num_files();
+        let s = if n == 1 { "" } else { "s" };
+        write!(f, "table statistics: {n} source{s}")
+    }
+}
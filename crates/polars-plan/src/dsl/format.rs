@@ -170,6 +170,7 @@ impl fmt::Debug for Expr {
                 variant,
             } => match variant {
                 EvalVariant::List => write!(f, "{input:?}.list.eval({evaluation:?})"),
+                EvalVariant::ListAgg => write!(f, "{input:?}.list.agg({evaluation:?})"),
                 EvalVariant::Cumulative { min_samples } => write!(
                     f,
                     "{input:?}.Cumulative_eval({evaluation:?}, min_samples={min_samples}"
@@ -181,6 +182,7 @@ impl fmt::Debug for Expr {
                 length,
             } => write!(f, "{input:?}.slice(offset={offset:?}, length={length:?})",),
             KeepName(e) => write!(f, "{e:?}.name.keep()"),
+            Cached(e) => write!(f, "cached({e:?})"),
             RenameAlias { expr, function } => match function {
                 RenameAliasFn::Prefix(s) => write!(f, "{expr:?}.prefix({s})"),
                 RenameAliasFn::Suffix(s) => write!(f, "{expr:?}.suffix({s})"),
@@ -189,6 +191,11 @@ impl fmt::Debug for Expr {
                 #[cfg(feature = "python")]
                 RenameAliasFn::Python(_) => write!(f, "{expr:?}.rename_alias()"),
                 RenameAliasFn::Rust(_) => write!(f, "{expr:?}.rename_alias()"),
+                RenameAliasFn::Format(s) => write!(f, "{expr:?}.format({s})"),
+                #[cfg(feature = "regex")]
+                RenameAliasFn::Replace { pattern, value, .. } => {
+                    write!(f, "{expr:?}.replace({pattern}, {value})")
+                },
             },
             Selector(s) => fmt::Display::fmt(s, f),
             #[cfg(feature = "dtype-struct")]
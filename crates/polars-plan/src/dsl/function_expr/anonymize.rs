@@ -0,0 +1,45 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum AnonymizeFunction {
+    HashStable {
+        /// The salts are secrets, so they are not serialized or shown in `Debug` output.
+        #[cfg_attr(feature = "serde", serde(skip))]
+        salts: Vec<PlSmallStr>,
+    },
+    SuppressRare {
+        k: IdxSize,
+    },
+}
+
+impl std::fmt::Debug for AnonymizeFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use AnonymizeFunction::*;
+        match self {
+            HashStable { salts } => f
+                .debug_struct("HashStable")
+                .field("salts", &format_args!("<{} redacted>", salts.len()))
+                .finish(),
+            SuppressRare { k } => f.debug_struct("SuppressRare").field("k", k).finish(),
+        }
+    }
+}
+
+impl Display for AnonymizeFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use AnonymizeFunction::*;
+        let s = match self {
+            HashStable { .. } => "hash_stable",
+            SuppressRare { .. } => "suppress_rare",
+        };
+        write!(f, "anon.{s}")
+    }
+}
+
+impl From<AnonymizeFunction> for FunctionExpr {
+    fn from(func: AnonymizeFunction) -> Self {
+        FunctionExpr::Anonymize(func)
+    }
+}
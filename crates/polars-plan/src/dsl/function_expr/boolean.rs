@@ -45,6 +45,12 @@ pub enum BooleanFunction {
     AnyHorizontal,
     // Also bitwise negate
     Not,
+    /// `IsFirstDistinct` over the rows of all inputs.
+    #[cfg(feature = "is_first_distinct")]
+    IsFirstDistinctRows,
+    /// `IsLastDistinct` over the rows of all inputs.
+    #[cfg(feature = "is_last_distinct")]
+    IsLastDistinctRows,
 }
 
 impl Display for BooleanFunction {
@@ -60,9 +66,9 @@ impl Display for BooleanFunction {
             IsNan => "is_nan",
             IsNotNan => "is_not_nan",
             #[cfg(feature = "is_first_distinct")]
-            IsFirstDistinct => "is_first_distinct",
+            IsFirstDistinct | IsFirstDistinctRows => "is_first_distinct",
             #[cfg(feature = "is_last_distinct")]
-            IsLastDistinct => "is_last_distinct",
+            IsLastDistinct | IsLastDistinctRows => "is_last_distinct",
             #[cfg(feature = "is_unique")]
             IsUnique => "is_unique",
             #[cfg(feature = "is_unique")]
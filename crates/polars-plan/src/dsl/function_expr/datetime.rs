@@ -66,6 +66,8 @@ pub enum TemporalFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
     },
+    #[cfg(feature = "timezones")]
+    ConvertTimeZonePerRow,
 }
 
 impl Display for TemporalFunction {
@@ -110,7 +112,7 @@ impl Display for TemporalFunction {
             TotalNanoseconds => "total_nanoseconds",
             ToString(_) => "to_string",
             #[cfg(feature = "timezones")]
-            ConvertTimeZone(_) => "convert_time_zone",
+            ConvertTimeZone(_) | ConvertTimeZonePerRow => "convert_time_zone",
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
@@ -11,7 +11,35 @@ pub enum ListToStruct {
         get_index_name: Option<DslNameGenerator>,
         /// If this is None, it means unbounded.
         max_fields: Option<usize>,
+        /// A format string for the names of the fields, in which `{}` is replaced by the index of
+        /// the field. Takes precedence over `get_index_name`.
+        #[cfg_attr(feature = "serde", serde(default))]
+        name_format: Option<PlSmallStr>,
+        /// Raise an error instead of dropping the elements of lists that are longer than the
+        /// number of fields.
+        #[cfg_attr(feature = "serde", serde(default))]
+        error_on_overflow: bool,
+        /// If `max_fields` is None, infer it from this many rows of the input when the plan is
+        /// converted to IR, so that the schema is known.
+        #[cfg_attr(feature = "serde", serde(default))]
+        infer_sample: Option<usize>,
     },
+    /// Like [`ListToStruct::FixedWidth`], but raise an error for lists that are longer than the
+    /// number of fields.
+    FixedWidthStrict(Arc<[PlSmallStr]>),
+}
+
+#[cfg(feature = "list_to_struct")]
+impl ListToStruct {
+    pub(crate) fn error_on_overflow(&self) -> bool {
+        match self {
+            Self::FixedWidth(_) => false,
+            Self::FixedWidthStrict(_) => true,
+            Self::InferWidth {
+                error_on_overflow, ..
+            } => *error_on_overflow,
+        }
+    }
 }
 
 #[derive(Clone, Eq, PartialEq, Hash, Debug)]
@@ -71,6 +99,8 @@ pub enum ListFunction {
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStruct),
+    #[cfg(feature = "list_filter")]
+    Filter,
 }
 
 impl Display for ListFunction {
@@ -83,6 +113,8 @@ impl Display for ListFunction {
             Contains { nulls_equal: _ } => "contains",
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => "drop_nulls",
+            #[cfg(feature = "list_filter")]
+            Filter => "filter",
             #[cfg(feature = "list_sample")]
             Sample { is_fraction, .. } => {
                 if *is_fraction {
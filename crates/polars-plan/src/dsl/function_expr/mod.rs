@@ -1,3 +1,5 @@
+#[cfg(feature = "anonymize")]
+mod anonymize;
 #[cfg(feature = "dtype-array")]
 mod array;
 mod binary;
@@ -28,6 +30,8 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "url")]
+mod url;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -41,11 +45,14 @@ pub use list::ListFunction;
 pub use list::ListToStruct;
 pub use polars_core::datatypes::ReshapeDimension;
 use polars_core::prelude::*;
+use polars_utils::total_ord::TotalOrdWrap;
 #[cfg(feature = "random")]
 pub use random::RandomMethod;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "anonymize")]
+pub use self::anonymize::AnonymizeFunction;
 pub use self::binary::BinaryFunction;
 #[cfg(feature = "bitwise")]
 pub use self::bitwise::BitwiseFunction;
@@ -69,6 +76,8 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "url")]
+pub use self::url::UrlFunction;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -269,6 +278,8 @@ pub enum FunctionExpr {
         labels: Option<Vec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        missing_label: Option<PlSmallStr>,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -277,6 +288,8 @@ pub enum FunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        missing_label: Option<PlSmallStr>,
     },
     #[cfg(feature = "rle")]
     RLE,
@@ -363,6 +376,67 @@ pub enum FunctionExpr {
     #[cfg(feature = "reinterpret")]
     Reinterpret(bool),
     ExtendConstant,
+    #[cfg(feature = "url")]
+    Url(UrlFunction),
+    #[cfg(feature = "unique_id")]
+    Uuid4,
+    #[cfg(feature = "unique_id")]
+    SnowflakeId {
+        worker_id: u16,
+    },
+    QuantileHorizontal {
+        quantile: TotalOrdWrap<f64>,
+        method: QuantileMethod,
+    },
+    StdHorizontal {
+        ddof: u8,
+    },
+    CheckedArithmetic(CheckedArithmeticOp),
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        separator: Option<PlSmallStr>,
+        drop_first: bool,
+        sparse: bool,
+    },
+    #[cfg(feature = "category_encoding")]
+    CategoricalTargetEncode {
+        folds: u32,
+        smoothing: f64,
+        seed: u64,
+    },
+    #[cfg(feature = "category_encoding")]
+    FrequencyEncode {
+        normalize: bool,
+    },
+    /// A literal whose value is only bound when the query is executed.
+    Placeholder {
+        name: PlSmallStr,
+    },
+    #[cfg(feature = "round_series")]
+    RoundToIncrement {
+        increment: f64,
+        mode: RoundMode,
+    },
+    NanToNull,
+    #[cfg(feature = "downsample")]
+    DownsampleLttb {
+        n_out: usize,
+    },
+    #[cfg(feature = "downsample")]
+    DownsampleMinMax {
+        n_buckets: usize,
+    },
+    #[cfg(feature = "bootstrap")]
+    Bootstrap {
+        n_samples: usize,
+        statistic: BootstrapStatistic,
+        method: BootstrapMethod,
+        confidence_level: f64,
+        seed: Option<u64>,
+    },
+    #[cfg(feature = "anonymize")]
+    Anonymize(AnonymizeFunction),
+    DivOr(DivOrOp),
 }
 
 impl Hash for FunctionExpr {
@@ -385,6 +459,10 @@ impl Hash for FunctionExpr {
             TemporalExpr(f) => f.hash(state),
             #[cfg(feature = "bitwise")]
             Bitwise(f) => f.hash(state),
+            #[cfg(feature = "url")]
+            Url(f) => f.hash(state),
+            #[cfg(feature = "anonymize")]
+            Anonymize(f) => f.hash(state),
 
             // Other expressions
             Boolean(f) => f.hash(state),
@@ -412,6 +490,10 @@ impl Hash for FunctionExpr {
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => {},
+            #[cfg(feature = "downsample")]
+            DownsampleLttb { n_out } => n_out.hash(state),
+            #[cfg(feature = "downsample")]
+            DownsampleMinMax { n_buckets } => n_buckets.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -463,8 +545,13 @@ impl Hash for FunctionExpr {
             SumHorizontal { ignore_nulls } | MeanHorizontal { ignore_nulls } => {
                 ignore_nulls.hash(state)
             },
-            MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse | ArgUnique | ArgMin
-            | ArgMax | Product | Shift | ShiftAndFill => {},
+            QuantileHorizontal { quantile, method } => {
+                quantile.hash(state);
+                method.hash(state);
+            },
+            StdHorizontal { ddof } => ddof.hash(state),
+            MaxHorizontal | MinHorizontal | DropNans | NanToNull | DropNulls | Reverse
+            | ArgUnique | ArgMin | ArgMax | Product | Shift | ShiftAndFill => {},
             Append { upcast } => upcast.hash(state),
             ArgSort {
                 descending,
@@ -478,6 +565,8 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
+            CheckedArithmetic(op) => op.hash(state),
+            DivOr(op) => op.hash(state),
             NullCount => {},
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
@@ -573,6 +662,11 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "round_series")]
             FunctionExpr::RoundSF { digits } => digits.hash(state),
             #[cfg(feature = "round_series")]
+            FunctionExpr::RoundToIncrement { increment, mode } => {
+                increment.to_bits().hash(state);
+                mode.hash(state);
+            },
+            #[cfg(feature = "round_series")]
             FunctionExpr::Floor => {},
             #[cfg(feature = "round_series")]
             Ceil => {},
@@ -589,12 +683,14 @@ impl Hash for FunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                missing_label,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                missing_label.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -607,6 +703,7 @@ impl Hash for FunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                missing_label,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -614,12 +711,47 @@ impl Hash for FunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                missing_label.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+                sparse,
+            } => (separator, drop_first, sparse).hash(state),
+            #[cfg(feature = "category_encoding")]
+            CategoricalTargetEncode {
+                folds,
+                smoothing,
+                seed,
+            } => {
+                folds.hash(state);
+                smoothing.to_bits().hash(state);
+                seed.hash(state);
+            },
+            #[cfg(feature = "category_encoding")]
+            FrequencyEncode { normalize } => normalize.hash(state),
+            #[cfg(feature = "bootstrap")]
+            Bootstrap {
+                n_samples,
+                statistic,
+                method,
+                confidence_level,
+                seed,
+            } => {
+                (n_samples, statistic, method, seed).hash(state);
+                confidence_level.to_bits().hash(state);
+            },
             ToPhysical => {},
+            #[cfg(feature = "unique_id")]
+            Uuid4 => {},
+            #[cfg(feature = "unique_id")]
+            SnowflakeId { worker_id } => worker_id.hash(state),
+            Placeholder { name } => name.hash(state),
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
@@ -673,6 +805,10 @@ impl Display for FunctionExpr {
             TemporalExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => return write!(f, "bitwise_{func}"),
+            #[cfg(feature = "url")]
+            Url(func) => return write!(f, "{func}"),
+            #[cfg(feature = "anonymize")]
+            Anonymize(func) => return write!(f, "{func}"),
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
@@ -681,6 +817,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "abs")]
             Abs => "abs",
             Negate => "negate",
+            CheckedArithmetic(op) => return write!(f, "{}_checked", <&str>::from(op)),
+            DivOr(op) => return write!(f, "{}_or", <&str>::from(op)),
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
@@ -707,6 +845,7 @@ impl Display for FunctionExpr {
             Append { .. } => "upcast",
             ShiftAndFill => "shift_and_fill",
             DropNans => "drop_nans",
+            NanToNull => "nan_to_null",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
             Mode => "mode",
@@ -769,6 +908,10 @@ impl Display for FunctionExpr {
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => "interpolate_by",
+            #[cfg(feature = "downsample")]
+            DownsampleLttb { .. } => "downsample_lttb",
+            #[cfg(feature = "downsample")]
+            DownsampleMinMax { .. } => "downsample_minmax",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -789,6 +932,8 @@ impl Display for FunctionExpr {
             #[cfg(feature = "round_series")]
             RoundSF { .. } => "round_sig_figs",
             #[cfg(feature = "round_series")]
+            RoundToIncrement { .. } => "round_to_increment",
+            #[cfg(feature = "round_series")]
             Floor => "floor",
             #[cfg(feature = "round_series")]
             Ceil => "ceil",
@@ -813,9 +958,22 @@ impl Display for FunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
+            #[cfg(feature = "category_encoding")]
+            CategoricalTargetEncode { .. } => "categorical_target_encode",
+            #[cfg(feature = "category_encoding")]
+            FrequencyEncode { .. } => "frequency_encode",
+            #[cfg(feature = "bootstrap")]
+            Bootstrap { .. } => "bootstrap",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
+            #[cfg(feature = "unique_id")]
+            Uuid4 => "uuid4",
+            #[cfg(feature = "unique_id")]
+            SnowflakeId { .. } => "snowflake_id",
+            Placeholder { .. } => "placeholder",
             SetSortedFlag(_) => "set_sorted",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
@@ -829,6 +987,8 @@ impl Display for FunctionExpr {
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            QuantileHorizontal { .. } => "quantile_horizontal",
+            StdHorizontal { .. } => "std_horizontal",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
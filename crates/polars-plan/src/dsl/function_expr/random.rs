@@ -22,3 +22,14 @@ impl Hash for RandomMethod {
         std::mem::discriminant(self).hash(state)
     }
 }
+
+impl RandomMethod {
+    /// Number of inputs of the function, excluding the group key columns that are appended
+    /// inside a window.
+    pub(crate) fn n_inputs(&self) -> usize {
+        match self {
+            Self::Shuffle => 1,
+            Self::Sample { .. } => 2,
+        }
+    }
+}
@@ -93,6 +93,8 @@ pub enum StringFunction {
     SplitExact {
         n: usize,
         inclusive: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        rest: bool,
     },
     #[cfg(feature = "dtype-struct")]
     SplitN(usize),
@@ -126,6 +128,20 @@ pub enum StringFunction {
     },
     #[cfg(feature = "regex")]
     EscapeRegex,
+    #[cfg(feature = "log_parsing")]
+    ParseCommonLog,
+    #[cfg(feature = "log_parsing")]
+    ParseUserAgent,
+    #[cfg(feature = "string_pad")]
+    PadStartDisplay {
+        fill_char: char,
+        truncate: bool,
+    },
+    #[cfg(feature = "string_pad")]
+    PadEndDisplay {
+        fill_char: char,
+        truncate: bool,
+    },
 }
 
 impl Display for StringFunction {
@@ -161,12 +177,20 @@ impl Display for StringFunction {
             PadEnd { .. } => "pad_end",
             #[cfg(feature = "string_pad")]
             PadStart { .. } => "pad_start",
+            #[cfg(feature = "string_pad")]
+            PadEndDisplay { .. } => "pad_end_display",
+            #[cfg(feature = "string_pad")]
+            PadStartDisplay { .. } => "pad_start_display",
             #[cfg(feature = "regex")]
             Replace { .. } => "replace",
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "log_parsing")]
+            ParseCommonLog => "parse_common_log",
+            #[cfg(feature = "log_parsing")]
+            ParseUserAgent => "parse_user_agent",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
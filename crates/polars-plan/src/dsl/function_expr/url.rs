@@ -0,0 +1,30 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum UrlFunction {
+    Parse,
+    QueryParam(PlSmallStr),
+    Encode,
+    Decode,
+}
+
+impl Display for UrlFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use UrlFunction::*;
+        let s = match self {
+            Parse => "parse",
+            QueryParam(_) => "query_param",
+            Encode => "encode",
+            Decode => "decode",
+        };
+        write!(f, "url.{s}")
+    }
+}
+
+impl From<UrlFunction> for FunctionExpr {
+    fn from(func: UrlFunction) -> Self {
+        FunctionExpr::Url(func)
+    }
+}
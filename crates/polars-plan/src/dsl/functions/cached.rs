@@ -0,0 +1,12 @@
+use super::*;
+
+/// Compute the scalar `expr` only once per query, even if it is used multiple times.
+///
+/// This is allowed in `select`, `with_columns`, `filter` and the aggregations of a `group_by`,
+/// where `expr` is evaluated on the input of the node in a subplan of which the single value is
+/// broadcast to the height of the frame. The subplan is always cached, so it is shared by every
+/// use of `expr` on the same input, also across different parts of the query. `expr` must produce
+/// a single value; no values give a null.
+pub fn cached(expr: Expr) -> Expr {
+    Expr::Cached(Arc::new(expr))
+}
@@ -0,0 +1,27 @@
+use super::*;
+
+/// Get a mask of the first occurrence of every distinct row of `exprs`.
+///
+/// This is like [`Expr::is_first_distinct`] on a `Struct` of `exprs`, without creating it.
+#[cfg(feature = "is_first_distinct")]
+pub fn is_first_distinct_rows<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "`is_first_distinct` needs at least one expression");
+    Ok(Expr::n_ary(
+        FunctionExpr::Boolean(BooleanFunction::IsFirstDistinctRows),
+        exprs,
+    ))
+}
+
+/// Get a mask of the last occurrence of every distinct row of `exprs`.
+///
+/// This is like [`Expr::is_last_distinct`] on a `Struct` of `exprs`, without creating it.
+#[cfg(feature = "is_last_distinct")]
+pub fn is_last_distinct_rows<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "`is_last_distinct` needs at least one expression");
+    Ok(Expr::n_ary(
+        FunctionExpr::Boolean(BooleanFunction::IsLastDistinctRows),
+        exprs,
+    ))
+}
@@ -1,3 +1,5 @@
+use polars_utils::total_ord::TotalOrdWrap;
+
 use super::*;
 
 /// Accumulate over multiple columns horizontally / row wise.
@@ -104,6 +106,25 @@ where
     }
 }
 
+/// Fold columns horizontally / row wise with a user-defined [`HorizontalFolder`].
+///
+/// The folder gets the values of the columns in blocks of rows, see [`fold_horizontal`]. The name
+/// of the resulting column is the name of the first expression.
+pub fn fold_columns<F, E>(folder: F, exprs: E) -> PolarsResult<Expr>
+where
+    F: HorizontalFolder + 'static,
+    E: AsRef<[Expr]>,
+{
+    let exprs = exprs.as_ref();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    let dtype = <F::Output as PolarsDataType>::get_static_dtype();
+    Ok(exprs[0].clone().map_many(
+        move |c| fold_horizontal(c, &folder).map(Some),
+        &exprs[1..],
+        GetOutput::from_type(dtype),
+    ))
+}
+
 /// Create a new column with the bitwise-and of the elements in each row.
 ///
 /// The name of the resulting column will be "all"; use [`alias`](Expr::alias) to choose a different name.
@@ -168,6 +189,35 @@ pub fn mean_horizontal<E: AsRef<[Expr]>>(exprs: E, ignore_nulls: bool) -> Polars
     ))
 }
 
+/// Compute the quantile of the non-null values horizontally across columns.
+pub fn quantile_horizontal<E: AsRef<[Expr]>>(
+    exprs: E,
+    quantile: f64,
+    method: QuantileMethod,
+) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    Ok(Expr::n_ary(
+        FunctionExpr::QuantileHorizontal {
+            quantile: TotalOrdWrap(quantile),
+            method,
+        },
+        exprs,
+    ))
+}
+
+/// Compute the median of the non-null values horizontally across columns.
+pub fn median_horizontal<E: AsRef<[Expr]>>(exprs: E) -> PolarsResult<Expr> {
+    quantile_horizontal(exprs, 0.5, QuantileMethod::Linear)
+}
+
+/// Compute the standard deviation of the non-null values horizontally across columns.
+pub fn std_horizontal<E: AsRef<[Expr]>>(exprs: E, ddof: u8) -> PolarsResult<Expr> {
+    let exprs = exprs.as_ref().to_vec();
+    polars_ensure!(!exprs.is_empty(), ComputeError: "cannot return empty fold because the number of output rows is unknown");
+    Ok(Expr::n_ary(FunctionExpr::StdHorizontal { ddof }, exprs))
+}
+
 /// Folds the expressions from left to right keeping the first non-null values.
 ///
 /// It is an error to provide an empty `exprs`.
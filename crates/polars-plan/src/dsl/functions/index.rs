@@ -5,13 +5,22 @@ use super::*;
 /// That means that the first `Series` will be used to determine the ordering
 /// until duplicates are found. Once duplicates are found, the next `Series` will
 /// be used and so on.
+///
+/// If a `limit` is set in the `sort_options`, only the first `limit` indexes are
+/// returned, which are found with a partial selection instead of a full sort.
 #[cfg(feature = "range")]
 pub fn arg_sort_by<E: AsRef<[Expr]>>(by: E, sort_options: SortMultipleOptions) -> Expr {
     let e = &by.as_ref()[0];
     let name = expr_output_name(e).unwrap();
-    int_range(lit(0 as IdxSize), len().cast(IDX_DTYPE), 1, IDX_DTYPE)
-        .sort_by(by, sort_options)
-        .alias(name)
+    let limit = sort_options.limit;
+    let idx =
+        int_range(lit(0 as IdxSize), len().cast(IDX_DTYPE), 1, IDX_DTYPE).sort_by(by, sort_options);
+    // The limit is only a hint to the sort, so the output is sliced as well.
+    let idx = match limit {
+        Some(limit) => idx.head(Some(limit as usize)),
+        None => idx,
+    };
+    idx.alias(name)
 }
 
 #[cfg(feature = "arg_where")]
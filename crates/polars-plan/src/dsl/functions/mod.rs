@@ -4,14 +4,18 @@
 mod arity;
 #[cfg(feature = "business")]
 mod business;
+mod cached;
 #[cfg(feature = "dtype-struct")]
 mod coerce;
 mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+mod distinct;
 pub(crate) mod horizontal;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+mod placeholder;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -19,18 +23,24 @@ mod selectors;
 mod syntactic_sugar;
 #[cfg(feature = "temporal")]
 mod temporal;
+#[cfg(feature = "unique_id")]
+mod unique_id;
 
 pub use arity::*;
 #[cfg(all(feature = "business", feature = "dtype-date"))]
 pub use business::*;
+pub use cached::*;
 #[cfg(feature = "dtype-struct")]
 pub use coerce::*;
 pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
+#[cfg(any(feature = "is_first_distinct", feature = "is_last_distinct"))]
+pub use distinct::*;
 pub use horizontal::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+pub use placeholder::*;
 #[cfg(all(feature = "range", feature = "temporal"))]
 pub use range::date_range; // This shouldn't be necessary, but clippy complains about dead code
 #[cfg(all(feature = "range", feature = "dtype-time"))]
@@ -42,6 +52,8 @@ pub use selectors::*;
 pub use syntactic_sugar::*;
 #[cfg(feature = "temporal")]
 pub use temporal::*;
+#[cfg(feature = "unique_id")]
+pub use unique_id::*;
 
 #[cfg(feature = "arg_where")]
 use crate::dsl::function_expr::FunctionExpr;
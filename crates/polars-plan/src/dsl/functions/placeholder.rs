@@ -0,0 +1,12 @@
+use super::*;
+
+/// A literal of type `dtype` whose value is only given when the query is executed.
+///
+/// This allows optimizing a query once and executing it with different values, see
+/// `LazyFrame::collect_with_params`. Executing a query with an unbound placeholder fails.
+pub fn placeholder(name: impl Into<PlSmallStr>, dtype: DataType) -> Expr {
+    let name = name.into();
+    Expr::Literal(LiteralValue::Scalar(Scalar::null(dtype)))
+        .map_unary(FunctionExpr::Placeholder { name: name.clone() })
+        .alias(name)
+}
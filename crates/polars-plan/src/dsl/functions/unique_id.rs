@@ -0,0 +1,18 @@
+use super::*;
+
+/// Generate a random (version 4) UUID for every row.
+///
+/// Every evaluation of this expression generates new UUIDs.
+pub fn uuid4() -> Expr {
+    len().map_unary(FunctionExpr::Uuid4).alias("uuid")
+}
+
+/// Generate a snowflake-style `UInt64` ID for every row.
+///
+/// The IDs are unique and increasing within a process for a given `worker_id`, across all
+/// threads and pipelines. `worker_id` must be at most 1023.
+pub fn snowflake_id(worker_id: u16) -> Expr {
+    len()
+        .map_unary(FunctionExpr::SnowflakeId { worker_id })
+        .alias("id")
+}
@@ -156,6 +156,16 @@ impl ListNameSpace {
         )
     }
 
+    /// Keep the elements of every sublist for which the corresponding element of the boolean
+    /// list `mask` is `true`.
+    ///
+    /// The sublists of `mask` must have the same lengths as the sublists they filter.
+    #[cfg(feature = "list_filter")]
+    pub fn filter(self, mask: Expr) -> Expr {
+        self.0
+            .map_binary(FunctionExpr::ListExpr(ListFunction::Filter), mask)
+    }
+
     #[cfg(feature = "list_gather")]
     pub fn gather_every(self, n: Expr, offset: Expr) -> Expr {
         self.0
@@ -304,4 +314,15 @@ impl ListNameSpace {
             variant: EvalVariant::List,
         }
     }
+
+    /// Run an aggregation over the elements of every list, treating each list as a group.
+    ///
+    /// Unlike [`ListNameSpace::eval`], the result is not wrapped in a list.
+    pub fn agg<E: Into<Expr>>(self, other: E) -> Expr {
+        Expr::Eval {
+            expr: Arc::new(self.0),
+            evaluation: Arc::new(other.into()),
+            variant: EvalVariant::ListAgg,
+        }
+    }
 }
@@ -17,6 +17,20 @@ pub enum UpcastOrForbid {
     Forbid,
 }
 
+/// Whether a column may be cast to any data type, not just upcast.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum CastOrForbid {
+    /// Cast to the target data type, raising an error for values that cannot be cast.
+    Strict,
+    /// Cast to the target data type, values that cannot be cast become null.
+    NonStrict,
+    /// Only cast according to the other policies.
+    #[default]
+    Forbid,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -28,4 +42,7 @@ pub struct MatchToSchemaPerColumn {
 
     pub integer_cast: UpcastOrForbid,
     pub float_cast: UpcastOrForbid,
+    /// Takes precedence over `integer_cast` and `float_cast`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cast: CastOrForbid,
 }
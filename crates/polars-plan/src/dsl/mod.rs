@@ -17,10 +17,13 @@ pub mod binary;
 mod bitwise;
 mod builder_dsl;
 pub use builder_dsl::*;
+#[cfg(feature = "anonymize")]
+pub mod anonymize;
 mod datatype_expr;
 #[cfg(feature = "temporal")]
 pub mod dt;
 mod expr;
+mod feature_set;
 mod format;
 mod from;
 pub mod function_expr;
@@ -35,6 +38,8 @@ mod options;
 pub mod python_dsl;
 #[cfg(feature = "random")]
 mod random;
+#[cfg(feature = "serde")]
+mod sandbox;
 mod scan_sources;
 mod selector;
 mod statistics;
@@ -42,7 +47,10 @@ mod statistics;
 pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
+mod text;
 pub mod udf;
+#[cfg(feature = "url")]
+pub mod url;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -53,6 +61,7 @@ pub use arity::*;
 pub use array::*;
 pub use datatype_expr::DataTypeExpr;
 pub use expr::*;
+pub use feature_set::{Feature, FeatureSet};
 pub use function_expr::*;
 pub use functions::*;
 pub use list::*;
@@ -159,6 +168,11 @@ impl Expr {
         self.map_unary(FunctionExpr::DropNans)
     }
 
+    /// Replace NaN values with null values.
+    pub fn nan_to_null(self) -> Self {
+        self.map_unary(FunctionExpr::NanToNull)
+    }
+
     /// Get the number of unique values in the groups.
     pub fn n_unique(self) -> Self {
         AggExpr::NUnique(Arc::new(self)).into()
@@ -669,6 +683,12 @@ impl Expr {
         self.map_unary(FunctionExpr::RoundSF { digits })
     }
 
+    /// Round to a multiple of `increment`, e.g. to the closest 0.05.
+    #[cfg(feature = "round_series")]
+    pub fn round_to_increment(self, increment: f64, mode: RoundMode) -> Self {
+        self.map_unary(FunctionExpr::RoundToIncrement { increment, mode })
+    }
+
     /// Floor underlying floating point array to the lowest integers smaller or equal to the float value.
     #[cfg(feature = "round_series")]
     pub fn floor(self) -> Self {
@@ -851,12 +871,16 @@ impl Expr {
 
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
+        let fill_value = fill_value.into();
+        if matches!(&fill_value, Expr::Literal(LiteralValue::Scalar(sc)) if sc.is_null()) {
+            return self.nan_to_null();
+        }
+
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
         // and that ensure we keep the name of `self`
-
         when(self.clone().is_not_nan().or(self.clone().is_null()))
             .then(self)
-            .otherwise(fill_value.into())
+            .otherwise(fill_value)
     }
     /// Count the values of the Series
     /// or
@@ -1075,6 +1099,20 @@ impl Expr {
         self.map_binary(FunctionExpr::InterpolateBy, by)
     }
 
+    #[cfg(feature = "downsample")]
+    /// Get a mask of the `n_out` values that keep the visual shape of the time series of `self`
+    /// by `by`, selected with the Largest-Triangle-Three-Buckets algorithm.
+    pub fn downsample_lttb(self, by: Expr, n_out: usize) -> Expr {
+        self.map_binary(FunctionExpr::DownsampleLttb { n_out }, by)
+    }
+
+    #[cfg(feature = "downsample")]
+    /// Get a mask of the minimum and maximum values in each of `n_buckets` equally long periods
+    /// of the time series of `self` by `by`.
+    pub fn downsample_minmax(self, by: Expr, n_buckets: usize) -> Expr {
+        self.map_binary(FunctionExpr::DownsampleMinMax { n_buckets }, by)
+    }
+
     #[cfg(feature = "rolling_window")]
     #[allow(clippy::type_complexity)]
     fn finish_rolling(
@@ -1348,15 +1386,43 @@ impl Expr {
         labels: Option<impl IntoVec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        missing_label: Option<PlSmallStr>,
     ) -> Expr {
         self.map_unary(FunctionExpr::Cut {
             breaks,
             labels: labels.map(|x| x.into_vec()),
             left_closed,
             include_breaks,
+            missing_label,
         })
     }
 
+    #[cfg(feature = "cutqcut")]
+    /// Bin continuous values into discrete categories, with `breaks` evaluating to a list of
+    /// break points of the dtype of `self`.
+    ///
+    /// Unlike [`Expr::cut`], Decimal and temporal values are compared without casting to
+    /// `Float64`.
+    pub fn cut_with_breaks(
+        self,
+        breaks: Expr,
+        labels: Option<impl IntoVec<PlSmallStr>>,
+        left_closed: bool,
+        include_breaks: bool,
+        missing_label: Option<PlSmallStr>,
+    ) -> Expr {
+        self.map_binary(
+            FunctionExpr::Cut {
+                breaks: Vec::new(),
+                labels: labels.map(|x| x.into_vec()),
+                left_closed,
+                include_breaks,
+                missing_label,
+            },
+            breaks,
+        )
+    }
+
     #[cfg(feature = "cutqcut")]
     /// Bin continuous values into discrete categories based on their quantiles.
     pub fn qcut(
@@ -1366,6 +1432,7 @@ impl Expr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        missing_label: Option<PlSmallStr>,
     ) -> Expr {
         self.map_unary(FunctionExpr::QCut {
             probs,
@@ -1373,6 +1440,7 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            missing_label,
         })
     }
 
@@ -1385,6 +1453,7 @@ impl Expr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        missing_label: Option<PlSmallStr>,
     ) -> Expr {
         let probs = (1..n_bins).map(|b| b as f64 / n_bins as f64).collect();
         self.map_unary(FunctionExpr::QCut {
@@ -1393,9 +1462,75 @@ impl Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            missing_label,
         })
     }
 
+    #[cfg(feature = "to_dummies")]
+    /// One-hot encode an `Enum` column into a struct with a dummy column per category, or if
+    /// `sparse` is set, into a list with the index of the category of every row.
+    pub fn to_dummies(self, separator: Option<PlSmallStr>, drop_first: bool, sparse: bool) -> Expr {
+        self.map_unary(FunctionExpr::ToDummies {
+            separator,
+            drop_first,
+            sparse,
+        })
+    }
+
+    #[cfg(feature = "category_encoding")]
+    /// Encode every category by the smoothed mean of the `target` values of its rows, computed
+    /// out-of-fold over `folds` folds of hashed row indices to not leak the target of a row.
+    pub fn categorical_target_encode(
+        self,
+        target: Expr,
+        folds: u32,
+        smoothing: f64,
+        seed: u64,
+    ) -> Expr {
+        self.map_binary(
+            FunctionExpr::CategoricalTargetEncode {
+                folds,
+                smoothing,
+                seed,
+            },
+            target,
+        )
+    }
+
+    #[cfg(feature = "bootstrap")]
+    /// Estimate the sampling distribution of `statistic` by resampling the values `n_samples`
+    /// times, returning a struct with its mean, standard deviation and confidence interval.
+    ///
+    /// With `weights`, values are drawn with probabilities proportional to their weight.
+    pub fn bootstrap(
+        self,
+        weights: Option<Expr>,
+        n_samples: usize,
+        statistic: BootstrapStatistic,
+        method: BootstrapMethod,
+        confidence_level: f64,
+        seed: Option<u64>,
+    ) -> Expr {
+        let function = FunctionExpr::Bootstrap {
+            n_samples,
+            statistic,
+            method,
+            confidence_level,
+            seed,
+        };
+        match weights {
+            Some(weights) => self.map_binary(function, weights),
+            None => self.map_unary(function),
+        }
+    }
+
+    #[cfg(feature = "category_encoding")]
+    /// Encode every value by the number of times it occurs, or by its fraction of the rows if
+    /// `normalize` is set.
+    pub fn frequency_encode(self, normalize: bool) -> Expr {
+        self.map_unary(FunctionExpr::FrequencyEncode { normalize })
+    }
+
     #[cfg(feature = "rle")]
     /// Get the lengths of runs of identical values.
     pub fn rle(self) -> Expr {
@@ -1618,6 +1753,18 @@ impl Expr {
         dt::DateLikeNameSpace(self)
     }
 
+    #[cfg(feature = "anonymize")]
+    /// Get the [`anonymize::AnonymizeNameSpace`]
+    pub fn anon(self) -> anonymize::AnonymizeNameSpace {
+        anonymize::AnonymizeNameSpace(self)
+    }
+
+    #[cfg(feature = "url")]
+    /// Get the [`url::UrlNameSpace`]
+    pub fn url(self) -> url::UrlNameSpace {
+        url::UrlNameSpace(self)
+    }
+
     /// Get the [`list::ListNameSpace`]
     pub fn list(self) -> list::ListNameSpace {
         list::ListNameSpace(self)
@@ -61,6 +61,29 @@ impl ExprNameNameSpace {
         }
     }
 
+    /// Format the root column name with a template, in which every `{}` is replaced by the name.
+    pub fn format(self, template: &str) -> Expr {
+        Expr::RenameAlias {
+            expr: Arc::new(self.0),
+            function: RenameAliasFn::Format(template.into()),
+        }
+    }
+
+    /// Replace all matches of a regex `pattern` in the root column name by `value`.
+    ///
+    /// If `literal` is set, the pattern is matched as a literal string.
+    #[cfg(feature = "regex")]
+    pub fn replace(self, pattern: &str, value: &str, literal: bool) -> Expr {
+        Expr::RenameAlias {
+            expr: Arc::new(self.0),
+            function: RenameAliasFn::Replace {
+                pattern: pattern.into(),
+                value: value.into(),
+                literal,
+            },
+        }
+    }
+
     /// Update the root column name to use lowercase characters.
     #[allow(clippy::wrong_self_convention)]
     pub fn to_lowercase(self) -> Expr {
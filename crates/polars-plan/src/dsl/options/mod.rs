@@ -16,6 +16,8 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(feature = "json")]
+use polars_io::utils::compression::ArchiveReadOptions;
 #[cfg(feature = "iejoin")]
 use polars_ops::frame::IEJoinOptions;
 use polars_ops::frame::{CrossJoinFilter, CrossJoinOptions, JoinTypeOptions};
@@ -291,6 +293,9 @@ pub struct GroupbyOptions {
     pub rolling: Option<RollingGroupOptions>,
     /// Take only a slice of the result
     pub slice: Option<(i64, usize)>,
+    /// Also emit a group for the categories of `Enum` keys that do not occur.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub expand_categories: bool,
 }
 
 impl GroupbyOptions {
@@ -435,4 +440,26 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// Glob pattern that selects the members to read if the file is a (compressed) archive.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub archive_member_glob: Option<PlSmallStr>,
+    /// A JSON Schema document that every row must match, see
+    /// [`polars_io::ndjson::json_schema`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub json_schema: Option<PlSmallStr>,
+    /// Append the rows that don't match `json_schema` to this local file, instead of raising an
+    /// error.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub json_schema_rejects: Option<polars_utils::plpath::PlPath>,
+}
+
+#[cfg(feature = "json")]
+impl NDJsonReadOptions {
+    /// Get the options to read the members of an archive with.
+    pub fn get_archive_options(&self) -> ArchiveReadOptions<'_> {
+        ArchiveReadOptions {
+            member_glob: self.archive_member_glob.as_deref(),
+            ..Default::default()
+        }
+    }
 }
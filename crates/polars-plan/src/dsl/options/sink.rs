@@ -31,6 +31,11 @@ pub struct SinkOptions {
 
     /// Recursively create all the directories in the path.
     pub mkdir: bool,
+
+    /// Write local files to a staging path and only move them to the target path when they are
+    /// complete, see [`StagedFile`](polars_io::utils::staged_commit::StagedFile).
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub staged_commit: bool,
 }
 
 impl Default for SinkOptions {
@@ -39,6 +44,7 @@ impl Default for SinkOptions {
             sync_on_close: Default::default(),
             maintain_order: true,
             mkdir: false,
+            staged_commit: false,
         }
     }
 }
@@ -51,6 +57,22 @@ pub enum SinkTarget {
     Dyn(DynSinkTarget),
 }
 
+fn open_path(
+    addr: &PlPath,
+    sink_options: &SinkOptions,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Writeable> {
+    match addr.as_ref().as_local_path() {
+        // Cloud objects are only visible once their upload completes, they need no staging.
+        Some(path) if sink_options.staged_commit => {
+            let path = polars_io::resolve_homedir(&path);
+            let file = polars_io::utils::staged_commit::StagedFile::try_new(&path)?;
+            Ok(Writeable::Dyn(Box::new(file)))
+        },
+        _ => Writeable::try_new(addr.as_ref(), cloud_options),
+    }
+}
+
 impl SinkTarget {
     pub fn open_into_writeable(
         &self,
@@ -63,7 +85,7 @@ impl SinkTarget {
                     polars_io::utils::mkdir::mkdir_recursive(addr.as_ref())?;
                 }
 
-                polars_io::utils::file::Writeable::try_new(addr.as_ref(), cloud_options)
+                open_path(addr, sink_options, cloud_options)
             },
             SinkTarget::Dyn(memory_writer) => Ok(Writeable::Dyn(
                 memory_writer.lock().unwrap().take().unwrap(),
@@ -92,7 +114,7 @@ impl SinkTarget {
                     polars_io::utils::mkdir::tokio_mkdir_recursive(addr.as_ref()).await?;
                 }
 
-                polars_io::utils::file::Writeable::try_new(addr.as_ref(), cloud_options)
+                open_path(addr, sink_options, cloud_options)
             },
             SinkTarget::Dyn(memory_writer) => Ok(Writeable::Dyn(
                 memory_writer.lock().unwrap().take().unwrap(),
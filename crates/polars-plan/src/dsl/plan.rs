@@ -5,6 +5,7 @@ use std::sync::{Arc, Mutex};
 use polars_utils::arena::Node;
 #[cfg(feature = "serde")]
 use polars_utils::pl_serialize;
+use polars_utils::plpath::PlPath;
 use recursive::recursive;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -48,9 +49,18 @@ use super::*;
 // - changing a name, type, or meaning of a field or an enum variant
 // - changing a default value of a field or a default enum variant
 // - restricting the range of allowed values a field can have
-pub static DSL_VERSION: (u16, u16) = (22, 0);
+pub static DSL_VERSION: (u16, u16) = (22, 38);
 static DSL_MAGIC_BYTES: &[u8] = b"DSL_VERSION";
 
+/// The cached IR conversion of a [`DslPlan::Scan`].
+#[derive(Default)]
+pub struct CachedScanIr {
+    pub ir: Option<IR>,
+    /// The scans of which the glob patterns are narrowed with the hive partition values required
+    /// by a filter, by their narrowed patterns. `None` if those patterns don't match any file.
+    pub narrowed: PlHashMap<Vec<PlPath>, Option<Arc<DslPlan>>>,
+}
+
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum DslPlan {
@@ -75,7 +85,7 @@ pub enum DslPlan {
         /// so we cache the IR conversion here, as the path expansion can be quite slow (especially for cloud paths).
         /// We don't have the arena, as this is always a source node.
         #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
-        cached_ir: Arc<Mutex<Option<IR>>>,
+        cached_ir: Arc<Mutex<CachedScanIr>>,
     },
     // we keep track of the projection and selection as it is cheaper to first project and then filter
     /// In memory DataFrame
@@ -293,6 +303,15 @@ impl DslPlan {
         pl_serialize::SerializeOptions::default().serialize_into_writer::<_, _, true>(writer, self)
     }
 
+    /// Deserialize a plan, rejecting plans that do not satisfy the restrictions of `ctx`.
+    #[cfg(feature = "serde")]
+    pub fn deserialize_versioned_with_context<R: Read>(
+        reader: R,
+        ctx: &PlanDeserializationContext,
+    ) -> PolarsResult<Self> {
+        ctx.deserialize_with(|| Self::deserialize_versioned(reader))
+    }
+
     #[cfg(feature = "serde")]
     pub fn deserialize_versioned<R: Read>(mut reader: R) -> PolarsResult<Self> {
         const MAGIC_LEN: usize = DSL_MAGIC_BYTES.len();
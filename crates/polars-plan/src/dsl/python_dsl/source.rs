@@ -3,7 +3,9 @@ use std::sync::Arc;
 use either::Either;
 use polars_core::error::{PolarsResult, polars_err};
 use polars_core::schema::SchemaRef;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::python_function::PythonFunction;
+use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -24,13 +26,25 @@ pub struct PythonOptionsDsl {
 }
 
 impl PythonOptionsDsl {
-    pub fn get_schema(&self) -> PolarsResult<SchemaRef> {
+    /// Get the schema of the source.
+    ///
+    /// A schema function is called with the projected column names if they are given, in which
+    /// case it may return only those columns. Otherwise it is called with `None` and must return
+    /// the full schema.
+    pub fn get_schema(&self, with_columns: Option<&[PlSmallStr]>) -> PolarsResult<SchemaRef> {
         match self.schema_fn.as_ref().expect("should be set").as_ref() {
             Either::Left(func) => Python::with_gil(|py| {
-                let schema = func
-                    .0
-                    .call0(py)
-                    .map_err(|e| polars_err!(ComputeError: "schema callable failed: {}", e))?;
+                let with_columns =
+                    with_columns.map(|cols| cols.iter().map(|c| c.as_str()).collect::<Vec<_>>());
+                let schema = match func.0.call1(py, (with_columns,)) {
+                    // Schema functions in plans that were serialized before they were given the
+                    // projected columns take no arguments.
+                    Err(e) if e.is_instance_of::<PyTypeError>(py) => {
+                        func.0.call0(py).map_err(|_| e)
+                    },
+                    schema => schema,
+                }
+                .map_err(|e| polars_err!(ComputeError: "schema callable failed: {}", e))?;
                 crate::plans::python::python_schema_to_rust(py, schema.into_bound(py))
             }),
             Either::Right(schema) => Ok(schema.clone()),
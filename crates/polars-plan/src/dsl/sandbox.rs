@@ -0,0 +1,396 @@
+use std::path::{Component, PathBuf};
+
+use polars_utils::plpath::{PlPath, PlPathRef};
+use recursive::recursive;
+
+use super::*;
+use crate::dsl::deletion::DeletionFilesList;
+
+/// Restrictions on the plans that are accepted when deserializing, so that plans from untrusted
+/// sources can be deserialized safely.
+///
+/// The default context accepts every plan.
+#[derive(Clone)]
+pub struct PlanDeserializationContext {
+    /// Accept Python objects, e.g. user-defined functions. These are unpickled, which can execute
+    /// arbitrary code.
+    pub allow_python_objects: bool,
+    /// Accept expressions and nodes that run user-defined code, e.g. anonymous functions, Python
+    /// UDFs and FFI plugins, which load a shared library.
+    pub allow_user_functions: bool,
+    /// Only accept plans that read and write paths within these paths, if set.
+    ///
+    /// Local paths are compared after resolving `~`, `.` and `..`, but not symbolic links. Cloud
+    /// URIs are compared with the same scheme only, e.g. `file://` URIs do not match local paths.
+    pub allowed_paths: Option<Vec<PlPath>>,
+}
+
+impl Default for PlanDeserializationContext {
+    fn default() -> Self {
+        Self {
+            allow_python_objects: true,
+            allow_user_functions: true,
+            allowed_paths: None,
+        }
+    }
+}
+
+impl PlanDeserializationContext {
+    /// A context that rejects Python objects, user-defined functions and all paths.
+    pub fn sandboxed() -> Self {
+        Self {
+            allow_python_objects: false,
+            allow_user_functions: false,
+            allowed_paths: Some(Vec::new()),
+        }
+    }
+
+    /// Run `deserialize`, which deserializes a plan, and validate the plan against the context.
+    ///
+    /// Python objects are rejected before they are unpickled.
+    pub fn deserialize_with(
+        &self,
+        deserialize: impl FnOnce() -> PolarsResult<DslPlan>,
+    ) -> PolarsResult<DslPlan> {
+        use polars_utils::pl_serialize::REJECT_PYTHON_OBJECTS;
+
+        let reject = REJECT_PYTHON_OBJECTS.get();
+        REJECT_PYTHON_OBJECTS.set(reject || !self.allow_python_objects);
+        let plan = deserialize();
+        REJECT_PYTHON_OBJECTS.set(reject);
+
+        let plan = plan?;
+        self.validate(&plan)?;
+        Ok(plan)
+    }
+
+    /// Check that `plan` only runs allowed functions, and that all paths read and written by
+    /// `plan` are allowed.
+    pub fn validate(&self, plan: &DslPlan) -> PolarsResult<()> {
+        if self.allow_user_functions && self.allowed_paths.is_none() {
+            return Ok(());
+        }
+        let allowed_paths = self
+            .allowed_paths
+            .as_ref()
+            .map(|paths| {
+                paths
+                    .iter()
+                    .map(|p| NormalizedPath::new(p.as_ref()))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .transpose()?;
+        Validator {
+            allow_user_functions: self.allow_user_functions,
+            allowed_paths,
+        }
+        .check_plan(plan)
+    }
+}
+
+enum NormalizedPath {
+    Local(PathBuf),
+    Cloud(Vec<String>),
+}
+
+impl NormalizedPath {
+    fn new(path: PlPathRef<'_>) -> PolarsResult<Self> {
+        Ok(match path {
+            PlPathRef::Local(p) => {
+                let p = std::path::absolute(polars_io::resolve_homedir(&p))?;
+                let mut out = PathBuf::new();
+                for c in p.components() {
+                    match c {
+                        Component::CurDir => {},
+                        Component::ParentDir => {
+                            out.pop();
+                        },
+                        c => out.push(c),
+                    }
+                }
+                Self::Local(out)
+            },
+            PlPathRef::Cloud(p) => {
+                let mut out: Vec<String> = Vec::new();
+                for segment in p.uri().split('/') {
+                    match segment {
+                        "" | "." => {},
+                        ".." => {
+                            out.pop();
+                        },
+                        s => out.push(s.to_string()),
+                    }
+                }
+                Self::Cloud(out)
+            },
+        })
+    }
+
+    fn starts_with(&self, prefix: &Self) -> bool {
+        match (self, prefix) {
+            (Self::Local(p), Self::Local(prefix)) => p.starts_with(prefix),
+            (Self::Cloud(p), Self::Cloud(prefix)) => p.starts_with(prefix),
+            _ => false,
+        }
+    }
+}
+
+struct Validator {
+    allow_user_functions: bool,
+    allowed_paths: Option<Vec<NormalizedPath>>,
+}
+
+impl Validator {
+    fn check_path(&self, path: PlPathRef<'_>) -> PolarsResult<()> {
+        let Some(allowed) = &self.allowed_paths else {
+            return Ok(());
+        };
+        let normalized = NormalizedPath::new(path)?;
+        polars_ensure!(
+            allowed.iter().any(|prefix| normalized.starts_with(prefix)),
+            InvalidOperation: "path '{}' is outside of the allowed paths", path.to_str()
+        );
+        Ok(())
+    }
+
+    fn check_user_function(&self, name: &str) -> PolarsResult<()> {
+        polars_ensure!(
+            self.allow_user_functions,
+            InvalidOperation: "{} are not allowed in this plan", name
+        );
+        Ok(())
+    }
+
+    fn check_exprs<'a>(&self, exprs: impl IntoIterator<Item = &'a Expr>) -> PolarsResult<()> {
+        exprs.into_iter().try_for_each(|e| self.check_expr(e))
+    }
+
+    #[recursive]
+    fn check_expr(&self, expr: &Expr) -> PolarsResult<()> {
+        for e in expr {
+            match e {
+                Expr::AnonymousFunction { .. } => {
+                    self.check_user_function("anonymous functions")?
+                },
+                #[cfg(feature = "python")]
+                Expr::RenameAlias {
+                    function: RenameAliasFn::Python(_),
+                    ..
+                } => self.check_user_function("Python functions")?,
+                Expr::Function { function, .. } => {
+                    if let Some(name) = user_function_name(function) {
+                        self.check_user_function(name)?;
+                    }
+                    if let Some(dtype) = function_dtype_expr(function) {
+                        self.check_dtype_expr(dtype)?;
+                    }
+                },
+                // The expression iterator does not visit the ordering of windows, subplans and
+                // the expressions of which the data type is used.
+                Expr::Cast { dtype, .. } => self.check_dtype_expr(dtype)?,
+                Expr::Window {
+                    order_by: Some((order_by, _)),
+                    ..
+                } => self.check_expr(order_by)?,
+                Expr::SubPlan(plan, _) => self.check_plan(plan)?,
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    fn check_dtype_expr(&self, dtype: &DataTypeExpr) -> PolarsResult<()> {
+        match dtype {
+            DataTypeExpr::OfExpr(expr) => self.check_expr(expr),
+            DataTypeExpr::Literal(_) | DataTypeExpr::SelfDtype => Ok(()),
+        }
+    }
+
+    #[recursive]
+    fn check_plan(&self, plan: &DslPlan) -> PolarsResult<()> {
+        use DslPlan as DP;
+
+        match plan {
+            #[cfg(feature = "python")]
+            DP::PythonScan { .. } => self.check_user_function("Python scans"),
+            DP::DataFrameScan { .. } => Ok(()),
+            DP::Scan {
+                sources,
+                unified_scan_args,
+                #[cfg(feature = "json")]
+                scan_type,
+                ..
+            } => {
+                for path in sources.as_paths().unwrap_or_default() {
+                    self.check_path(path.as_ref())?;
+                }
+                #[cfg(feature = "json")]
+                if let FileScanDsl::NDJson { options } = scan_type.as_ref() {
+                    if let Some(path) = &options.json_schema_rejects {
+                        self.check_path(path.as_ref())?;
+                    }
+                }
+                if let Some(DeletionFilesList::IcebergPositionDelete(files)) =
+                    &unified_scan_args.deletion_files
+                {
+                    for path in files.values().flat_map(|paths| paths.iter()) {
+                        self.check_path(PlPathRef::new(path))?;
+                    }
+                }
+                Ok(())
+            },
+            DP::Sink { input, payload } => {
+                match payload {
+                    SinkType::Memory => {},
+                    SinkType::File(FileSinkType { target, .. }) => {
+                        if let SinkTarget::Path(path) = target {
+                            self.check_path(path.as_ref())?;
+                        }
+                    },
+                    SinkType::Partition(PartitionSinkType {
+                        base_path,
+                        variant,
+                        per_partition_sort_by,
+                        ..
+                    }) => {
+                        self.check_path(PlPath::as_ref(base_path))?;
+                        if let PartitionVariant::Parted { key_exprs, .. }
+                        | PartitionVariant::ByKey { key_exprs, .. } = variant
+                        {
+                            self.check_exprs(key_exprs)?;
+                        }
+                        for sort_column in per_partition_sort_by.iter().flatten() {
+                            self.check_expr(&sort_column.expr)?;
+                        }
+                    },
+                }
+                self.check_plan(input)
+            },
+            DP::Filter { input, predicate } => {
+                self.check_expr(predicate)?;
+                self.check_plan(input)
+            },
+            DP::Select { expr, input, .. } => {
+                self.check_exprs(expr)?;
+                self.check_plan(input)
+            },
+            DP::GroupBy {
+                input, keys, aggs, ..
+            } => {
+                self.check_exprs(keys.iter().chain(aggs))?;
+                self.check_plan(input)
+            },
+            DP::HStack { input, exprs, .. } => {
+                self.check_exprs(exprs)?;
+                self.check_plan(input)
+            },
+            DP::Sort {
+                input, by_column, ..
+            } => {
+                self.check_exprs(by_column)?;
+                self.check_plan(input)
+            },
+            DP::MapFunction { input, function } => {
+                match function {
+                    #[cfg(feature = "python")]
+                    DslFunction::OpaquePython(_) => self.check_user_function("Python functions")?,
+                    DslFunction::FunctionIR(_) => self.check_user_function("opaque functions")?,
+                    DslFunction::FillNan(e)
+                    | DslFunction::Stats(StatsFunction::Quantile { quantile: e, .. }) => {
+                        self.check_expr(e)?
+                    },
+                    _ => {},
+                }
+                self.check_plan(input)
+            },
+            DP::Cache { input }
+            | DP::MatchToSchema { input, .. }
+            | DP::Distinct { input, .. }
+            | DP::Slice { input, .. } => self.check_plan(input),
+            DP::Join {
+                input_left,
+                input_right,
+                left_on,
+                right_on,
+                predicates,
+                ..
+            } => {
+                self.check_exprs(left_on.iter().chain(right_on).chain(predicates))?;
+                self.check_plan(input_left)?;
+                self.check_plan(input_right)
+            },
+            #[cfg(feature = "merge_sorted")]
+            DP::MergeSorted {
+                input_left,
+                input_right,
+                ..
+            } => {
+                self.check_plan(input_left)?;
+                self.check_plan(input_right)
+            },
+            DP::Union { inputs, .. } | DP::HConcat { inputs, .. } | DP::SinkMultiple { inputs } => {
+                inputs.iter().try_for_each(|input| self.check_plan(input))
+            },
+            DP::ExtContext { input, contexts } => {
+                self.check_plan(input)?;
+                contexts
+                    .iter()
+                    .try_for_each(|context| self.check_plan(context))
+            },
+            DP::IR { dsl, .. } => self.check_plan(dsl),
+        }
+    }
+}
+
+/// The data type of `function` that is given by an expression, if any.
+fn function_dtype_expr(function: &FunctionExpr) -> Option<&DataTypeExpr> {
+    use FunctionExpr as F;
+
+    match function {
+        F::FoldHorizontal { return_dtype, .. } | F::ReduceHorizontal { return_dtype, .. } => {
+            return_dtype.as_ref()
+        },
+        #[cfg(feature = "dtype-struct")]
+        F::CumReduceHorizontal { return_dtype, .. } | F::CumFoldHorizontal { return_dtype, .. } => {
+            return_dtype.as_ref()
+        },
+        #[cfg(feature = "replace")]
+        F::ReplaceStrict { return_dtype } => return_dtype.as_ref(),
+        #[cfg(all(feature = "strings", feature = "extract_jsonpath"))]
+        F::StringExpr(StringFunction::JsonDecode { dtype, .. }) => dtype.as_ref(),
+        #[cfg(all(feature = "strings", feature = "temporal"))]
+        F::StringExpr(StringFunction::Strptime(dtype, _)) => Some(dtype),
+        #[cfg(feature = "binary_encoding")]
+        F::BinaryExpr(BinaryFunction::Reinterpret(dtype, _)) => Some(dtype),
+        #[cfg(feature = "range")]
+        F::Range(RangeFunction::IntRange { dtype, .. } | RangeFunction::IntRanges { dtype }) => {
+            Some(dtype)
+        },
+        _ => None,
+    }
+}
+
+/// The name of the kind of user-defined function that `function` runs, if any.
+fn user_function_name(function: &FunctionExpr) -> Option<&'static str> {
+    use FunctionExpr as F;
+
+    match function {
+        #[cfg(feature = "ffi_plugin")]
+        F::FfiPlugin { .. } => Some("FFI plugins"),
+        F::FoldHorizontal { .. } | F::ReduceHorizontal { .. } => Some("fold and reduce functions"),
+        #[cfg(feature = "dtype-struct")]
+        F::CumReduceHorizontal { .. } | F::CumFoldHorizontal { .. } => {
+            Some("fold and reduce functions")
+        },
+        #[cfg(all(feature = "dtype-struct", feature = "python"))]
+        F::StructExpr(StructFunction::MapFieldNames(_)) => Some("Python functions"),
+        #[cfg(feature = "list_to_struct")]
+        F::ListExpr(ListFunction::ToStruct(ListToStruct::InferWidth {
+            get_index_name: Some(_),
+            ..
+        })) => Some("name generator functions"),
+        #[cfg(all(feature = "dtype-array", feature = "array_to_struct"))]
+        F::ArrayExpr(ArrayFunction::ToStruct(Some(_))) => Some("name generator functions"),
+        _ => None,
+    }
+}
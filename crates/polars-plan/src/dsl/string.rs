@@ -203,6 +203,38 @@ impl StringNameSpace {
             .map_binary(StringFunction::PadEnd { fill_char }, length)
     }
 
+    /// Pad the start of the string until it is `width` columns wide when displayed.
+    ///
+    /// East Asian wide characters are two columns wide and combining characters have no width.
+    /// Strings that are at least `width` columns wide are returned as-is, unless `truncate` is
+    /// set, in which case characters are removed from their end until they fit.
+    #[cfg(feature = "string_pad")]
+    pub fn pad_start_display(self, width: Expr, fill_char: char, truncate: bool) -> Expr {
+        self.0.map_binary(
+            StringFunction::PadStartDisplay {
+                fill_char,
+                truncate,
+            },
+            width,
+        )
+    }
+
+    /// Pad the end of the string until it is `width` columns wide when displayed.
+    ///
+    /// East Asian wide characters are two columns wide and combining characters have no width.
+    /// Strings that are at least `width` columns wide are returned as-is, unless `truncate` is
+    /// set, in which case characters are removed from their end until they fit.
+    #[cfg(feature = "string_pad")]
+    pub fn pad_end_display(self, width: Expr, fill_char: char, truncate: bool) -> Expr {
+        self.0.map_binary(
+            StringFunction::PadEndDisplay {
+                fill_char,
+                truncate,
+            },
+            width,
+        )
+    }
+
     /// Pad the start of the string with zeros until it reaches the given length.
     ///
     /// A sign prefix (`-`) is handled by inserting the padding after the sign
@@ -336,6 +368,7 @@ impl StringNameSpace {
             StringFunction::SplitExact {
                 n,
                 inclusive: false,
+                rest: false,
             },
             by,
         )
@@ -345,8 +378,28 @@ impl StringNameSpace {
     /// Split exactly `n` times by a given substring and keep the substring.
     /// The resulting dtype is [`DataType::Struct`].
     pub fn split_exact_inclusive(self, by: Expr, n: usize) -> Expr {
-        self.0
-            .map_binary(StringFunction::SplitExact { n, inclusive: true }, by)
+        self.0.map_binary(
+            StringFunction::SplitExact {
+                n,
+                inclusive: true,
+                rest: false,
+            },
+            by,
+        )
+    }
+
+    #[cfg(feature = "dtype-struct")]
+    /// Split exactly `n` times by a given substring and keep the remainder of the string in a
+    /// final `rest` field. The resulting dtype is [`DataType::Struct`].
+    pub fn split_exact_with_rest(self, by: Expr, n: usize, inclusive: bool) -> Expr {
+        self.0.map_binary(
+            StringFunction::SplitExact {
+                n,
+                inclusive,
+                rest: true,
+            },
+            by,
+        )
     }
 
     #[cfg(feature = "dtype-struct")]
@@ -389,6 +442,18 @@ impl StringNameSpace {
         self.0.map_unary(StringFunction::Reverse)
     }
 
+    #[cfg(feature = "log_parsing")]
+    /// Parse lines in the Common (or Combined) Log Format into a struct.
+    pub fn parse_common_log(self) -> Expr {
+        self.0.map_unary(StringFunction::ParseCommonLog)
+    }
+
+    #[cfg(feature = "log_parsing")]
+    /// Extract the browser, operating system and device type from `User-Agent` strings.
+    pub fn parse_user_agent(self) -> Expr {
+        self.0.map_unary(StringFunction::ParseUserAgent)
+    }
+
     /// Remove leading and trailing characters, or whitespace if matches is None.
     pub fn strip_chars(self, matches: Expr) -> Expr {
         self.0.map_binary(StringFunction::StripChars, matches)
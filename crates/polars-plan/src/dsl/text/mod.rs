@@ -0,0 +1,38 @@
+//! A human-readable text format for expressions.
+//!
+//! The format uses the syntax of the Python API, e.g.
+//! `(col("a") + 1).sum().over(col("b"), order_by=col("c")).alias("x")`, so that expressions
+//! can be stored in and edited as configuration. It supports:
+//! * `col(name)`, `len()`, `lit(value)`, `lit(value, dtype=dtype)` and
+//!   `when(predicate).then(value).otherwise(value)`;
+//! * integer, float, string, boolean (`True`, `False`) and null (`None`) literals, with
+//!   `float("nan")` and `float("inf")` for the non-finite floats. Literals of other types are
+//!   written as `lit` with the physical value and the dtype;
+//! * the binary operators `==`, `!=`, `<`, `<=`, `>`, `>=`, `+`, `-`, `*`, `/`, `//`, `%`, `&`,
+//!   `|` and `^` with the precedence of Python, and the unary operators `-` and `~`;
+//! * the methods `alias`, `cast`, `sort`, `over`, `filter`, `fill_null`, `eq_missing`,
+//!   `ne_missing`, the aggregations, and several elementwise functions such as `is_null` and
+//!   `abs`.
+//!
+//! Data types are written as in Python, e.g. `Int64`, `Datetime("us", "UTC")` or `List(String)`.
+//!
+//! The text of an expression is stable across versions. An expression that cannot be
+//! represented, e.g. one with a user-defined function, raises an error when converted.
+mod parse;
+mod print;
+
+use super::*;
+
+impl Expr {
+    /// Convert the expression to its text representation.
+    pub fn to_text(&self) -> PolarsResult<String> {
+        let mut out = String::new();
+        print::write_expr(&mut out, self)?;
+        Ok(out)
+    }
+
+    /// Parse an expression from its text representation.
+    pub fn from_text(text: &str) -> PolarsResult<Expr> {
+        parse::parse_expr(text)
+    }
+}
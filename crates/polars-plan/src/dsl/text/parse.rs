@@ -0,0 +1,851 @@
+use std::fmt::{Display, Formatter};
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Int(i128),
+    Float(f64),
+    Punct(&'static str),
+    End,
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Ident(v) => write!(f, "'{v}'"),
+            Token::Str(v) => write!(f, "string {v:?}"),
+            Token::Int(v) => write!(f, "'{v}'"),
+            Token::Float(v) => write!(f, "'{v:?}'"),
+            Token::Punct(v) => write!(f, "'{v}'"),
+            Token::End => write!(f, "end of input"),
+        }
+    }
+}
+
+// Longer punctuation comes first, so that it is matched before its prefixes.
+const PUNCTUATION: &[&str] = &[
+    "==", "!=", "<=", ">=", "//", "<", ">", "+", "-", "*", "/", "%", "&", "|", "^", "~", "(", ")",
+    "[", "]", ",", ".", "=",
+];
+
+fn error_at(pos: usize, msg: impl Display) -> PolarsError {
+    polars_err!(ComputeError: "could not parse expression at position {}: {}", pos, msg)
+}
+
+/// Splits the text into tokens with their byte positions.
+fn tokenize(text: &str) -> PolarsResult<Vec<(Token, usize)>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let start = i;
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let token = if c.is_ascii_alphabetic() || c == b'_' {
+            while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+                i += 1;
+            }
+            Token::Ident(text[start..i].to_string())
+        } else if c.is_ascii_digit() {
+            let mut is_float = false;
+            let digits = |i: &mut usize| {
+                while *i < bytes.len() && bytes[*i].is_ascii_digit() {
+                    *i += 1;
+                }
+            };
+            digits(&mut i);
+            if i + 1 < bytes.len() && bytes[i] == b'.' && bytes[i + 1].is_ascii_digit() {
+                is_float = true;
+                i += 1;
+                digits(&mut i);
+            }
+            if i < bytes.len() && matches!(bytes[i], b'e' | b'E') {
+                let mut j = i + 1;
+                if j < bytes.len() && matches!(bytes[j], b'+' | b'-') {
+                    j += 1;
+                }
+                if j < bytes.len() && bytes[j].is_ascii_digit() {
+                    is_float = true;
+                    i = j;
+                    digits(&mut i);
+                }
+            }
+            let number = &text[start..i];
+            if is_float {
+                Token::Float(number.parse().map_err(|e| error_at(start, e))?)
+            } else {
+                Token::Int(
+                    number
+                        .parse()
+                        .map_err(|_| error_at(start, "integer literal is too large"))?,
+                )
+            }
+        } else if c == b'"' || c == b'\'' {
+            let (s, end) = parse_string(text, start)?;
+            i = end;
+            Token::Str(s)
+        } else {
+            let Some(punct) = PUNCTUATION.iter().find(|p| text[i..].starts_with(**p)) else {
+                let c = text[i..].chars().next().unwrap();
+                return Err(error_at(start, format!("unexpected character {c:?}")));
+            };
+            i += punct.len();
+            Token::Punct(*punct)
+        };
+        tokens.push((token, start));
+    }
+
+    tokens.push((Token::End, text.len()));
+    Ok(tokens)
+}
+
+/// Parses the string literal starting at `start`, returns its value and the position after it.
+fn parse_string(text: &str, start: usize) -> PolarsResult<(String, usize)> {
+    let mut chars = text[start..].char_indices();
+    let (_, quote) = chars.next().unwrap();
+    let mut out = String::new();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            c if c == quote => return Ok((out, start + i + c.len_utf8())),
+            '\\' => {
+                let escaped = match chars.next().map(|(_, c)| c) {
+                    Some('\\') => '\\',
+                    Some('"') => '"',
+                    Some('\'') => '\'',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('0') => '\0',
+                    Some('u') => {
+                        let rest = &text[start + i + 2..];
+                        let code = rest
+                            .strip_prefix('{')
+                            .and_then(|rest| rest.split_once('}'))
+                            .and_then(|(hex, _)| u32::from_str_radix(hex, 16).ok())
+                            .and_then(char::from_u32)
+                            .ok_or_else(|| error_at(start + i, "invalid unicode escape"))?;
+                        // Skip the braces and the hexadecimal digits.
+                        for (_, c) in chars.by_ref() {
+                            if c == '}' {
+                                break;
+                            }
+                        }
+                        code
+                    },
+                    _ => return Err(error_at(start + i, "invalid escape sequence")),
+                };
+                out.push(escaped);
+            },
+            c => out.push(c),
+        }
+    }
+    Err(error_at(start, "unterminated string literal"))
+}
+
+/// A parsed argument of a call.
+enum Value {
+    Expr(Expr),
+    DataType(DataType),
+    List(Vec<Value>),
+}
+
+impl Value {
+    fn into_expr(self) -> PolarsResult<Expr> {
+        match self {
+            Value::Expr(e) => Ok(e),
+            Value::DataType(dt) => {
+                polars_bail!(ComputeError: "expected an expression, got data type {}", dt)
+            },
+            Value::List(_) => polars_bail!(ComputeError: "expected an expression, got a list"),
+        }
+    }
+
+    fn into_exprs(self) -> PolarsResult<Vec<Expr>> {
+        match self {
+            Value::List(values) => values.into_iter().map(Value::into_expr).collect(),
+            v => Ok(vec![v.into_expr()?]),
+        }
+    }
+
+    fn into_dtype(self) -> PolarsResult<DataType> {
+        match self {
+            Value::DataType(dt) => Ok(dt),
+            _ => polars_bail!(ComputeError: "expected a data type"),
+        }
+    }
+
+    fn into_scalar(self) -> PolarsResult<Scalar> {
+        match self.into_expr()? {
+            Expr::Literal(LiteralValue::Scalar(sc)) => Ok(sc),
+            e => polars_bail!(ComputeError: "expected a constant, got {}", e),
+        }
+    }
+
+    fn into_bool(self) -> PolarsResult<bool> {
+        match self.into_scalar()?.value() {
+            AnyValue::Boolean(v) => Ok(*v),
+            v => polars_bail!(ComputeError: "expected a boolean, got {}", v),
+        }
+    }
+
+    fn into_str(self) -> PolarsResult<PlSmallStr> {
+        match self.into_scalar()?.into_value() {
+            AnyValue::StringOwned(v) => Ok(v),
+            v => polars_bail!(ComputeError: "expected a string, got {}", v),
+        }
+    }
+
+    fn into_opt_int(self) -> PolarsResult<Option<i128>> {
+        match self.into_expr()? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(v))) => Ok(Some(v)),
+            Expr::Literal(LiteralValue::Scalar(sc)) if sc.is_null() => Ok(None),
+            e => polars_bail!(ComputeError: "expected an integer, got {}", e),
+        }
+    }
+
+    fn into_int<T: TryFrom<i128>>(self) -> PolarsResult<T> {
+        let v = self
+            .into_opt_int()?
+            .ok_or_else(|| polars_err!(ComputeError: "expected an integer, got None"))?;
+        T::try_from(v).map_err(|_| polars_err!(ComputeError: "integer {} is out of range", v))
+    }
+}
+
+/// The arguments of a call.
+#[derive(Default)]
+struct Args {
+    positional: Vec<Value>,
+    keyword: Vec<(String, Value)>,
+}
+
+impl Args {
+    /// Assigns the arguments to the parameters with the given names, like Python does.
+    fn bind<const N: usize>(self, names: [&str; N]) -> PolarsResult<[Option<Value>; N]> {
+        polars_ensure!(
+            self.positional.len() <= N,
+            ComputeError: "expected at most {} positional arguments, got {}",
+            N, self.positional.len()
+        );
+        let mut out = [const { None }; N];
+        for (slot, v) in out.iter_mut().zip(self.positional) {
+            *slot = Some(v);
+        }
+        for (name, v) in self.keyword {
+            let Some(i) = names.iter().position(|n| *n == name) else {
+                polars_bail!(ComputeError: "unexpected keyword argument '{}'", name);
+            };
+            polars_ensure!(
+                out[i].is_none(),
+                ComputeError: "got multiple values for argument '{}'", name
+            );
+            out[i] = Some(v);
+        }
+        Ok(out)
+    }
+}
+
+fn required(v: Option<Value>, name: &str) -> PolarsResult<Value> {
+    v.ok_or_else(|| polars_err!(ComputeError: "missing required argument '{}'", name))
+}
+
+fn bool_or(v: Option<Value>, default: bool) -> PolarsResult<bool> {
+    v.map_or(Ok(default), Value::into_bool)
+}
+
+/// The maximum nesting depth of expressions and lists, which bounds the recursion of the parser.
+const MAX_DEPTH: usize = 256;
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    depth: usize,
+}
+
+pub(super) fn parse_expr(text: &str) -> PolarsResult<Expr> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        pos: 0,
+        depth: 0,
+    };
+    let e = parser.expr()?;
+    parser.expect(&Token::End)?;
+    Ok(e)
+}
+
+// The binary operators from the lowest to the highest precedence, which is as in Python.
+const BINARY_OPERATORS: &[&[(&str, Operator)]] = &[
+    &[
+        ("==", Operator::Eq),
+        ("!=", Operator::NotEq),
+        ("<", Operator::Lt),
+        ("<=", Operator::LtEq),
+        (">", Operator::Gt),
+        (">=", Operator::GtEq),
+    ],
+    &[("|", Operator::Or)],
+    &[("^", Operator::Xor)],
+    &[("&", Operator::And)],
+    &[("+", Operator::Plus), ("-", Operator::Minus)],
+    &[
+        ("*", Operator::Multiply),
+        ("/", Operator::TrueDivide),
+        ("//", Operator::FloorDivide),
+        ("%", Operator::Modulus),
+    ],
+];
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos].0
+    }
+
+    fn position(&self) -> usize {
+        self.tokens[self.pos].1
+    }
+
+    fn next(&mut self) -> Token {
+        let token = self.tokens[self.pos].0.clone();
+        if token != Token::End {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let found = self.peek() == token;
+        if found {
+            self.next();
+        }
+        found
+    }
+
+    fn expect(&mut self, token: &Token) -> PolarsResult<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(error_at(
+                self.position(),
+                format!("expected {token}, got {}", self.peek()),
+            ))
+        }
+    }
+
+    fn ident(&mut self) -> PolarsResult<String> {
+        match self.peek() {
+            Token::Ident(_) => match self.next() {
+                Token::Ident(name) => Ok(name),
+                _ => unreachable!(),
+            },
+            t => Err(error_at(
+                self.position(),
+                format!("expected a name, got {t}"),
+            )),
+        }
+    }
+
+    /// Whether the next tokens are a call of the method `name`.
+    fn peek_method(&self, name: &str) -> bool {
+        self.peek() == &Token::Punct(".")
+            && matches!(&self.tokens[self.pos + 1].0, Token::Ident(n) if n == name)
+    }
+
+    /// Parses a nested part of the text, erroring if it is nested too deeply.
+    fn nested<T>(&mut self, f: impl FnOnce(&mut Self) -> PolarsResult<T>) -> PolarsResult<T> {
+        if self.depth == MAX_DEPTH {
+            return Err(error_at(
+                self.position(),
+                format!("exceeded the maximum nesting depth of {MAX_DEPTH}"),
+            ));
+        }
+        self.depth += 1;
+        let out = f(self);
+        self.depth -= 1;
+        out
+    }
+
+    fn expr(&mut self) -> PolarsResult<Expr> {
+        self.nested(|p| p.binary(0))
+    }
+
+    fn binary(&mut self, level: usize) -> PolarsResult<Expr> {
+        let Some(operators) = BINARY_OPERATORS.get(level) else {
+            return self.unary();
+        };
+        let mut left = self.binary(level + 1)?;
+        'outer: loop {
+            for (punct, op) in operators.iter() {
+                if self.eat(&Token::Punct(punct)) {
+                    let right = self.binary(level + 1)?;
+                    left = binary_expr(left, *op, right);
+                    continue 'outer;
+                }
+            }
+            return Ok(left);
+        }
+    }
+
+    fn unary(&mut self) -> PolarsResult<Expr> {
+        if self.eat(&Token::Punct("-")) {
+            // Negative numbers are literals.
+            let e = match self.peek() {
+                Token::Int(v) => Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(-v))),
+                Token::Float(v) => Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Float(-v))),
+                _ => return Ok(-self.nested(Self::unary)?),
+            };
+            self.next();
+            self.postfix(e)
+        } else if self.eat(&Token::Punct("~")) {
+            Ok(self.nested(Self::unary)?.not())
+        } else {
+            let e = self.atom()?;
+            self.postfix(e)
+        }
+    }
+
+    fn postfix(&mut self, mut e: Expr) -> PolarsResult<Expr> {
+        while self.eat(&Token::Punct(".")) {
+            let pos = self.position();
+            let name = self.ident()?;
+            let args = self.args()?;
+            e = method(e, &name, args).map_err(|err| error_at(pos, err))?;
+        }
+        Ok(e)
+    }
+
+    fn args(&mut self) -> PolarsResult<Args> {
+        self.expect(&Token::Punct("("))?;
+        let mut args = Args::default();
+        while !self.eat(&Token::Punct(")")) {
+            if let (Token::Ident(name), Some((Token::Punct("="), _))) =
+                (self.peek(), self.tokens.get(self.pos + 1))
+            {
+                let name = name.clone();
+                self.pos += 2;
+                args.keyword.push((name, self.value()?));
+            } else if args.keyword.is_empty() {
+                args.positional.push(self.value()?);
+            } else {
+                return Err(error_at(
+                    self.position(),
+                    "positional argument follows keyword argument",
+                ));
+            }
+            if !self.eat(&Token::Punct(",")) {
+                self.expect(&Token::Punct(")"))?;
+                break;
+            }
+        }
+        Ok(args)
+    }
+
+    fn value(&mut self) -> PolarsResult<Value> {
+        match self.peek() {
+            Token::Punct("[") => {
+                self.next();
+                let mut values = Vec::new();
+                while !self.eat(&Token::Punct("]")) {
+                    values.push(self.nested(Self::value)?);
+                    if !self.eat(&Token::Punct(",")) {
+                        self.expect(&Token::Punct("]"))?;
+                        break;
+                    }
+                }
+                Ok(Value::List(values))
+            },
+            Token::Ident(name)
+                if name.starts_with(|c: char| c.is_ascii_uppercase())
+                    && !matches!(name.as_str(), "True" | "False" | "None") =>
+            {
+                Ok(Value::DataType(self.dtype()?))
+            },
+            _ => Ok(Value::Expr(self.expr()?)),
+        }
+    }
+
+    fn dtype(&mut self) -> PolarsResult<DataType> {
+        use DataType as D;
+
+        let pos = self.position();
+        let name = self.ident()?;
+        let dtype = match name.as_str() {
+            "Boolean" => D::Boolean,
+            "UInt8" => D::UInt8,
+            "UInt16" => D::UInt16,
+            "UInt32" => D::UInt32,
+            "UInt64" => D::UInt64,
+            "Int8" => D::Int8,
+            "Int16" => D::Int16,
+            "Int32" => D::Int32,
+            "Int64" => D::Int64,
+            "Int128" => D::Int128,
+            "Float32" => D::Float32,
+            "Float64" => D::Float64,
+            "String" => D::String,
+            "Binary" => D::Binary,
+            "Date" => D::Date,
+            "Time" => D::Time,
+            "Null" => D::Null,
+            _ => {
+                let args = self.args()?;
+                return parametric_dtype(&name, args).map_err(|err| error_at(pos, err));
+            },
+        };
+        // Simple data types may be instantiated as in Python, e.g. `Int64()`.
+        if self.peek() == &Token::Punct("(") {
+            self.args()?.bind([]).map_err(|err| error_at(pos, err))?;
+        }
+        Ok(dtype)
+    }
+
+    fn atom(&mut self) -> PolarsResult<Expr> {
+        let pos = self.position();
+        match self.next() {
+            Token::Punct("(") => {
+                let e = self.expr()?;
+                self.expect(&Token::Punct(")"))?;
+                Ok(e)
+            },
+            Token::Int(v) => Ok(Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(v)))),
+            Token::Float(v) => Ok(Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Float(v)))),
+            Token::Str(v) => Ok(lit(v)),
+            Token::Ident(name) => match name.as_str() {
+                "True" => Ok(lit(true)),
+                "False" => Ok(lit(false)),
+                "None" => Ok(lit(NULL)),
+                "when" => self.when_then(pos),
+                _ => {
+                    let args = self.args()?;
+                    function(&name, args).map_err(|err| error_at(pos, err))
+                },
+            },
+            t => Err(error_at(pos, format!("unexpected {t}"))),
+        }
+    }
+
+    fn when_then(&mut self, pos: usize) -> PolarsResult<Expr> {
+        let mut branches = Vec::new();
+        let mut condition = self.single_arg("predicate", pos)?;
+        let otherwise = loop {
+            if !self.peek_method("then") {
+                return Err(error_at(self.position(), "expected '.then' after 'when'"));
+            }
+            self.pos += 2;
+            let pos = self.position();
+            branches.push((condition, self.single_arg("statement", pos)?));
+
+            if self.peek_method("when") {
+                self.pos += 2;
+                let pos = self.position();
+                condition = self.single_arg("predicate", pos)?;
+            } else if self.peek_method("otherwise") {
+                self.pos += 2;
+                let pos = self.position();
+                break self.single_arg("statement", pos)?;
+            } else {
+                break lit(NULL);
+            }
+        };
+
+        Ok(branches
+            .into_iter()
+            .rev()
+            .fold(otherwise, |falsy, (predicate, truthy)| {
+                ternary_expr(predicate, truthy, falsy)
+            }))
+    }
+
+    fn single_arg(&mut self, name: &str, pos: usize) -> PolarsResult<Expr> {
+        let [v] = self
+            .args()?
+            .bind([name])
+            .map_err(|err| error_at(pos, err))?;
+        required(v, name)
+            .and_then(Value::into_expr)
+            .map_err(|err| error_at(pos, err))
+    }
+}
+
+fn time_unit(v: Option<Value>) -> PolarsResult<TimeUnit> {
+    let Some(v) = v else {
+        return Ok(TimeUnit::Microseconds);
+    };
+    Ok(match v.into_str()?.as_str() {
+        "ns" => TimeUnit::Nanoseconds,
+        "us" => TimeUnit::Microseconds,
+        "ms" => TimeUnit::Milliseconds,
+        tu => polars_bail!(
+            ComputeError: "invalid time unit '{}', expected one of 'ns', 'us' or 'ms'", tu
+        ),
+    })
+}
+
+fn parametric_dtype(name: &str, args: Args) -> PolarsResult<DataType> {
+    Ok(match name {
+        "Datetime" => {
+            let [tu, tz] = args.bind(["time_unit", "time_zone"])?;
+            let tz = match tz {
+                None => None,
+                Some(tz) => match tz.into_scalar()?.into_value() {
+                    AnyValue::Null => None,
+                    AnyValue::StringOwned(tz) => TimeZone::opt_try_new(Some(tz))?,
+                    v => polars_bail!(ComputeError: "expected a time zone, got {}", v),
+                },
+            };
+            DataType::Datetime(time_unit(tu)?, tz)
+        },
+        "Duration" => {
+            let [tu] = args.bind(["time_unit"])?;
+            DataType::Duration(time_unit(tu)?)
+        },
+        "List" => {
+            let [inner] = args.bind(["inner"])?;
+            DataType::List(Box::new(required(inner, "inner")?.into_dtype()?))
+        },
+        #[cfg(feature = "dtype-array")]
+        "Array" => {
+            let [inner, shape] = args.bind(["inner", "shape"])?;
+            DataType::Array(
+                Box::new(required(inner, "inner")?.into_dtype()?),
+                required(shape, "shape")?.into_int()?,
+            )
+        },
+        #[cfg(feature = "dtype-decimal")]
+        "Decimal" => {
+            let [precision, scale] = args.bind(["precision", "scale"])?;
+            let to_usize = |v: Option<i128>| {
+                v.map(|v| {
+                    usize::try_from(v)
+                        .map_err(|_| polars_err!(ComputeError: "invalid decimal {}", v))
+                })
+                .transpose()
+            };
+            let precision = match precision {
+                None => None,
+                Some(v) => to_usize(v.into_opt_int()?)?,
+            };
+            let scale = match scale {
+                None => Some(0),
+                Some(v) => to_usize(v.into_opt_int()?)?,
+            };
+            DataType::Decimal(precision, scale)
+        },
+        _ => polars_bail!(ComputeError: "unknown data type '{}'", name),
+    })
+}
+
+fn function(name: &str, args: Args) -> PolarsResult<Expr> {
+    Ok(match name {
+        "col" => {
+            let [name] = args.bind(["name"])?;
+            col(required(name, "name")?.into_str()?)
+        },
+        "len" => {
+            args.bind([])?;
+            len()
+        },
+        "lit" => {
+            let [value, dtype] = args.bind(["value", "dtype"])?;
+            let value = required(value, "value")?.into_expr()?;
+            let Expr::Literal(lv) = &value else {
+                polars_bail!(ComputeError: "expected a constant, got {}", value);
+            };
+            let Some(dtype) = dtype else {
+                return Ok(value);
+            };
+            let dtype = dtype.into_dtype()?;
+            let value = match lv {
+                LiteralValue::Dyn(DynLiteralValue::Int(v)) => match i64::try_from(*v) {
+                    Ok(v) => AnyValue::Int64(v),
+                    Err(_) => match u64::try_from(*v) {
+                        Ok(v) => AnyValue::UInt64(v),
+                        Err(_) => AnyValue::Int128(*v),
+                    },
+                },
+                LiteralValue::Dyn(DynLiteralValue::Float(v)) => AnyValue::Float64(*v),
+                LiteralValue::Scalar(sc) => sc.value().clone(),
+                _ => polars_bail!(ComputeError: "expected a constant, got {}", value),
+            };
+            // Decimals are written as a string of their logical value.
+            #[cfg(feature = "dtype-decimal")]
+            if let (DataType::Decimal(precision, Some(scale)), Some(v)) = (&dtype, value.get_str())
+            {
+                let v = arrow::compute::decimal::deserialize_decimal(
+                    v.as_bytes(),
+                    precision.map(|p| p as u8),
+                    *scale as u8,
+                )
+                .ok_or_else(|| polars_err!(ComputeError: "invalid decimal {}", v))?;
+                return Ok(lit(Scalar::new(
+                    dtype.clone(),
+                    AnyValue::Decimal(v, *scale),
+                )));
+            }
+            let scalar = if value.is_null() {
+                Scalar::null(dtype)
+            } else {
+                Scalar::new(value.dtype(), value).cast_with_options(&dtype, CastOptions::Strict)?
+            };
+            lit(scalar)
+        },
+        "float" => {
+            let [value] = args.bind(["value"])?;
+            let value = required(value, "value")?.into_str()?;
+            let v = value
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| polars_err!(ComputeError: "could not convert '{}' to float", value))?;
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Float(v)))
+        },
+        _ => polars_bail!(ComputeError: "unknown function '{}'", name),
+    })
+}
+
+fn method(e: Expr, name: &str, args: Args) -> PolarsResult<Expr> {
+    let no_args: Option<fn(Expr) -> Expr> = match name {
+        "min" => Some(Expr::min),
+        "max" => Some(Expr::max),
+        "nan_min" => Some(Expr::nan_min),
+        "nan_max" => Some(Expr::nan_max),
+        "median" => Some(Expr::median),
+        "n_unique" => Some(Expr::n_unique),
+        "first" => Some(Expr::first),
+        "last" => Some(Expr::last),
+        "mean" => Some(Expr::mean),
+        "implode" => Some(Expr::implode),
+        "count" => Some(Expr::count),
+        "len" => Some(Expr::len),
+        "sum" => Some(Expr::sum),
+        "null_count" => Some(Expr::null_count),
+        "is_null" => Some(Expr::is_null),
+        "is_not_null" => Some(Expr::is_not_null),
+        "is_nan" => Some(Expr::is_nan),
+        "is_not_nan" => Some(Expr::is_not_nan),
+        "is_finite" => Some(Expr::is_finite),
+        "is_infinite" => Some(Expr::is_infinite),
+        "not_" => Some(Expr::not),
+        #[cfg(feature = "abs")]
+        "abs" => Some(Expr::abs),
+        "drop_nulls" => Some(Expr::drop_nulls),
+        "reverse" => Some(Expr::reverse),
+        _ => None,
+    };
+    if let Some(f) = no_args {
+        args.bind([])?;
+        return Ok(f(e));
+    }
+
+    Ok(match name {
+        "alias" => {
+            let [name] = args.bind(["name"])?;
+            e.alias(required(name, "name")?.into_str()?)
+        },
+        "cast" => {
+            let [dtype, strict, wrap_numerical] =
+                args.bind(["dtype", "strict", "wrap_numerical"])?;
+            let dtype = required(dtype, "dtype")?.into_dtype()?;
+            let options = if bool_or(wrap_numerical, false)? {
+                CastOptions::Overflowing
+            } else if bool_or(strict, true)? {
+                CastOptions::Strict
+            } else {
+                CastOptions::NonStrict
+            };
+            e.cast_with_options(dtype, options)
+        },
+        "sort" => {
+            let [descending, nulls_last] = args.bind(["descending", "nulls_last"])?;
+            e.sort(SortOptions {
+                descending: bool_or(descending, false)?,
+                nulls_last: bool_or(nulls_last, false)?,
+                multithreaded: true,
+                maintain_order: false,
+                limit: None,
+            })
+        },
+        "std" | "var" => {
+            let [ddof] = args.bind(["ddof"])?;
+            let ddof = ddof.map_or(Ok(1), Value::into_int)?;
+            if name == "std" {
+                e.std(ddof)
+            } else {
+                e.var(ddof)
+            }
+        },
+        "unique" => {
+            let [maintain_order] = args.bind(["maintain_order"])?;
+            if bool_or(maintain_order, false)? {
+                e.unique_stable()
+            } else {
+                e.unique()
+            }
+        },
+        "any" | "all" => {
+            let [ignore_nulls] = args.bind(["ignore_nulls"])?;
+            let ignore_nulls = bool_or(ignore_nulls, true)?;
+            if name == "any" {
+                e.any(ignore_nulls)
+            } else {
+                e.all(ignore_nulls)
+            }
+        },
+        "fill_null" | "filter" | "eq_missing" | "ne_missing" => {
+            let [other] = args.bind(["value"])?;
+            let other = required(other, "value")?.into_expr()?;
+            match name {
+                "fill_null" => e.fill_null(other),
+                "filter" => e.filter(other),
+                "eq_missing" => e.eq_missing(other),
+                _ => e.neq_missing(other),
+            }
+        },
+        "over" => {
+            let mut args = args;
+            let partition_by = std::mem::take(&mut args.positional)
+                .into_iter()
+                .map(Value::into_exprs)
+                .collect::<PolarsResult<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            let [order_by, descending, nulls_last, mapping_strategy] =
+                args.bind(["order_by", "descending", "nulls_last", "mapping_strategy"])?;
+
+            let order_by = match order_by {
+                None => None,
+                Some(order_by) => Some((
+                    order_by.into_exprs()?,
+                    SortOptions {
+                        descending: bool_or(descending, false)?,
+                        nulls_last: bool_or(nulls_last, false)?,
+                        maintain_order: false,
+                        ..Default::default()
+                    },
+                )),
+            };
+            let mapping = match mapping_strategy {
+                None => WindowMapping::GroupsToRows,
+                Some(v) => match v.into_str()?.as_str() {
+                    "group_to_rows" => WindowMapping::GroupsToRows,
+                    "join" => WindowMapping::Join,
+                    "explode" => WindowMapping::Explode,
+                    v => polars_bail!(
+                        ComputeError: "invalid mapping strategy '{}', expected one of \
+                        'group_to_rows', 'join' or 'explode'", v
+                    ),
+                },
+            };
+            let partition_by = (!partition_by.is_empty()).then_some(partition_by);
+            e.over_with_options(partition_by, order_by, mapping)?
+        },
+        _ => polars_bail!(ComputeError: "unknown method '{}'", name),
+    })
+}
@@ -0,0 +1,488 @@
+use std::fmt::Write;
+
+use super::*;
+
+fn unsupported(what: impl std::fmt::Display) -> PolarsError {
+    polars_err!(InvalidOperation: "cannot convert {} to text", what)
+}
+
+fn py_bool(v: bool) -> &'static str {
+    if v { "True" } else { "False" }
+}
+
+pub(super) fn write_expr(out: &mut String, e: &Expr) -> PolarsResult<()> {
+    match e {
+        Expr::Column(name) => write!(out, "col({:?})", name.as_str()).unwrap(),
+        Expr::Len => out.push_str("len()"),
+        Expr::Literal(lv) => write_literal(out, lv)?,
+        Expr::Alias(input, name) => {
+            write_receiver(out, input)?;
+            write!(out, ".alias({:?})", name.as_str()).unwrap();
+        },
+        Expr::BinaryExpr { left, op, right } => match op {
+            Operator::EqValidity | Operator::NotEqValidity => {
+                write_receiver(out, left)?;
+                out.push_str(if *op == Operator::EqValidity {
+                    ".eq_missing("
+                } else {
+                    ".ne_missing("
+                });
+                write_expr(out, right)?;
+                out.push(')');
+            },
+            _ => {
+                let op = binary_operator(*op)?;
+                write_operand(out, left)?;
+                write!(out, " {op} ").unwrap();
+                write_operand(out, right)?;
+            },
+        },
+        Expr::Cast {
+            expr,
+            dtype,
+            options,
+        } => {
+            let DataTypeExpr::Literal(dtype) = dtype else {
+                return Err(unsupported("a cast to a dynamic data type"));
+            };
+            write_receiver(out, expr)?;
+            out.push_str(".cast(");
+            write_dtype(out, dtype)?;
+            out.push_str(match options {
+                CastOptions::Strict => "",
+                CastOptions::NonStrict => ", strict=False",
+                CastOptions::Overflowing => ", wrap_numerical=True",
+            });
+            out.push(')');
+        },
+        Expr::Sort { expr, options } => {
+            check_sort_options(options)?;
+            write_receiver(out, expr)?;
+            out.push_str(".sort(");
+            write_sort_options(out, options, false);
+            out.push(')');
+        },
+        Expr::Agg(agg) => write_agg(out, agg)?,
+        Expr::Ternary {
+            predicate,
+            truthy,
+            falsy,
+        } => {
+            out.push_str("when(");
+            write_expr(out, predicate)?;
+            out.push_str(").then(");
+            write_expr(out, truthy)?;
+            // Nested ternaries in the otherwise branch are written as a chain.
+            let mut falsy = falsy;
+            while let Expr::Ternary {
+                predicate,
+                truthy,
+                falsy: next,
+            } = falsy.as_ref()
+            {
+                out.push_str(").when(");
+                write_expr(out, predicate)?;
+                out.push_str(").then(");
+                write_expr(out, truthy)?;
+                falsy = next;
+            }
+            out.push_str(").otherwise(");
+            write_expr(out, falsy)?;
+            out.push(')');
+        },
+        Expr::Function { input, function } => write_function(out, input, function)?,
+        Expr::Filter { input, by } => {
+            write_receiver(out, input)?;
+            out.push_str(".filter(");
+            write_expr(out, by)?;
+            out.push(')');
+        },
+        Expr::Window {
+            function,
+            partition_by,
+            order_by,
+            options,
+        } => {
+            let WindowType::Over(mapping) = options else {
+                return Err(unsupported("a rolling window"));
+            };
+            write_receiver(out, function)?;
+            out.push_str(".over(");
+
+            let mut first = true;
+            let mut sep = |out: &mut String| {
+                if !first {
+                    out.push_str(", ");
+                }
+                first = false;
+            };
+
+            // Without partition expressions, `over` partitions by a single literal.
+            let default_partition = order_by.is_some()
+                && matches!(
+                    partition_by.as_slice(),
+                    [Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(1)))]
+                );
+            if !default_partition {
+                for e in partition_by {
+                    sep(out);
+                    write_expr(out, e)?;
+                }
+            }
+            if let Some((e, options)) = order_by {
+                check_sort_options(options)?;
+                sep(out);
+                out.push_str("order_by=");
+                match e.as_ref() {
+                    #[cfg(feature = "dtype-struct")]
+                    Expr::Function {
+                        input,
+                        function: FunctionExpr::AsStruct,
+                    } => {
+                        out.push('[');
+                        for (i, e) in input.iter().enumerate() {
+                            if i > 0 {
+                                out.push_str(", ");
+                            }
+                            write_expr(out, e)?;
+                        }
+                        out.push(']');
+                    },
+                    e => write_expr(out, e)?,
+                }
+                write_sort_options(out, options, true);
+            }
+            match mapping {
+                WindowMapping::GroupsToRows => {},
+                WindowMapping::Join => out.push_str(", mapping_strategy=\"join\""),
+                WindowMapping::Explode => out.push_str(", mapping_strategy=\"explode\""),
+            }
+            out.push(')');
+        },
+        _ => return Err(unsupported(format!("expression {e:?}"))),
+    }
+    Ok(())
+}
+
+/// Writes an expression that is followed by a method call.
+fn write_receiver(out: &mut String, e: &Expr) -> PolarsResult<()> {
+    match e {
+        Expr::Literal(lv) if is_bare_literal(lv) => {
+            out.push_str("lit(");
+            write_literal(out, lv)?;
+            out.push(')');
+        },
+        e if is_operation(e) => {
+            out.push('(');
+            write_expr(out, e)?;
+            out.push(')');
+        },
+        e => write_expr(out, e)?,
+    }
+    Ok(())
+}
+
+/// Writes an operand of a binary operator, nested operations are always parenthesized.
+fn write_operand(out: &mut String, e: &Expr) -> PolarsResult<()> {
+    if is_operation(e) {
+        out.push('(');
+        write_expr(out, e)?;
+        out.push(')');
+        Ok(())
+    } else {
+        write_expr(out, e)
+    }
+}
+
+/// Whether the expression is written with a binary or unary operator.
+fn is_operation(e: &Expr) -> bool {
+    match e {
+        Expr::BinaryExpr { op, .. } => {
+            !matches!(op, Operator::EqValidity | Operator::NotEqValidity)
+        },
+        Expr::Function { input, function } => {
+            input.len() == 1
+                && matches!(
+                    function,
+                    FunctionExpr::Negate | FunctionExpr::Boolean(BooleanFunction::Not)
+                )
+        },
+        _ => false,
+    }
+}
+
+fn binary_operator(op: Operator) -> PolarsResult<&'static str> {
+    use Operator::*;
+    Ok(match op {
+        Eq => "==",
+        NotEq => "!=",
+        Lt => "<",
+        LtEq => "<=",
+        Gt => ">",
+        GtEq => ">=",
+        Plus => "+",
+        Minus => "-",
+        Multiply => "*",
+        TrueDivide => "/",
+        FloorDivide => "//",
+        Modulus => "%",
+        And => "&",
+        Or => "|",
+        Xor => "^",
+        Divide | LogicalAnd | LogicalOr | EqValidity | NotEqValidity => {
+            return Err(unsupported(format!("operator {op:?}")));
+        },
+    })
+}
+
+fn check_sort_options(options: &SortOptions) -> PolarsResult<()> {
+    polars_ensure!(
+        options.multithreaded && !options.maintain_order && options.limit.is_none(),
+        InvalidOperation: "cannot convert sort options {:?} to text", options
+    );
+    Ok(())
+}
+
+fn write_sort_options(out: &mut String, options: &SortOptions, mut sep: bool) {
+    for (name, value) in [
+        ("descending", options.descending),
+        ("nulls_last", options.nulls_last),
+    ] {
+        if value {
+            if sep {
+                out.push_str(", ");
+            }
+            write!(out, "{name}=True").unwrap();
+            sep = true;
+        }
+    }
+}
+
+fn write_agg(out: &mut String, agg: &AggExpr) -> PolarsResult<()> {
+    use AggExpr::*;
+    let (method, ddof) = match agg {
+        Min {
+            propagate_nans: false,
+            ..
+        } => ("min", None),
+        Min {
+            propagate_nans: true,
+            ..
+        } => ("nan_min", None),
+        Max {
+            propagate_nans: false,
+            ..
+        } => ("max", None),
+        Max {
+            propagate_nans: true,
+            ..
+        } => ("nan_max", None),
+        Median(_) => ("median", None),
+        NUnique(_) => ("n_unique", None),
+        First(_) => ("first", None),
+        Last(_) => ("last", None),
+        Mean(_) => ("mean", None),
+        Implode(_) => ("implode", None),
+        Count(_, false) => ("count", None),
+        Count(_, true) => ("len", None),
+        Sum(_) => ("sum", None),
+        Std(_, ddof) => ("std", Some(*ddof)),
+        Var(_, ddof) => ("var", Some(*ddof)),
+        Quantile { .. } | AggGroups(_) => {
+            return Err(unsupported(format!(
+                "aggregation {}",
+                Expr::Agg(agg.clone())
+            )));
+        },
+    };
+    write_receiver(out, agg.as_ref())?;
+    write!(out, ".{method}(").unwrap();
+    match ddof {
+        Some(ddof) if ddof != 1 => write!(out, "ddof={ddof}").unwrap(),
+        _ => {},
+    }
+    out.push(')');
+    Ok(())
+}
+
+fn write_function(out: &mut String, input: &[Expr], function: &FunctionExpr) -> PolarsResult<()> {
+    use FunctionExpr as F;
+
+    if let ([input, value], F::FillNull) = (input, function) {
+        write_receiver(out, input)?;
+        out.push_str(".fill_null(");
+        write_expr(out, value)?;
+        out.push(')');
+        return Ok(());
+    }
+
+    let [input] = input else {
+        return Err(unsupported(format!("function {function}")));
+    };
+    let (method, arg) = match function {
+        F::Negate | F::Boolean(BooleanFunction::Not) => {
+            out.push(if matches!(function, F::Negate) {
+                '-'
+            } else {
+                '~'
+            });
+            return write_receiver(out, input);
+        },
+        F::NullCount => ("null_count", None),
+        F::Boolean(BooleanFunction::IsNull) => ("is_null", None),
+        F::Boolean(BooleanFunction::IsNotNull) => ("is_not_null", None),
+        F::Boolean(BooleanFunction::IsNan) => ("is_nan", None),
+        F::Boolean(BooleanFunction::IsNotNan) => ("is_not_nan", None),
+        F::Boolean(BooleanFunction::IsFinite) => ("is_finite", None),
+        F::Boolean(BooleanFunction::IsInfinite) => ("is_infinite", None),
+        F::Boolean(BooleanFunction::Any { ignore_nulls }) => {
+            ("any", (!ignore_nulls).then_some("ignore_nulls=False"))
+        },
+        F::Boolean(BooleanFunction::All { ignore_nulls }) => {
+            ("all", (!ignore_nulls).then_some("ignore_nulls=False"))
+        },
+        #[cfg(feature = "abs")]
+        F::Abs => ("abs", None),
+        F::DropNulls => ("drop_nulls", None),
+        F::Reverse => ("reverse", None),
+        F::Unique(maintain_order) => ("unique", maintain_order.then_some("maintain_order=True")),
+        _ => return Err(unsupported(format!("function {function}"))),
+    };
+    write_receiver(out, input)?;
+    write!(out, ".{method}({})", arg.unwrap_or_default()).unwrap();
+    Ok(())
+}
+
+/// Whether the literal is written without `lit`.
+fn is_bare_literal(lv: &LiteralValue) -> bool {
+    match lv {
+        LiteralValue::Dyn(_) => true,
+        LiteralValue::Scalar(sc) => matches!(
+            (sc.dtype(), sc.value()),
+            (DataType::Boolean, AnyValue::Boolean(_))
+                | (
+                    DataType::String,
+                    AnyValue::String(_) | AnyValue::StringOwned(_)
+                )
+                | (DataType::Null, AnyValue::Null)
+        ),
+        _ => false,
+    }
+}
+
+fn write_literal(out: &mut String, lv: &LiteralValue) -> PolarsResult<()> {
+    match lv {
+        LiteralValue::Dyn(DynLiteralValue::Int(v)) => write!(out, "{v}").unwrap(),
+        LiteralValue::Dyn(DynLiteralValue::Float(v)) => write_float(out, *v),
+        LiteralValue::Dyn(DynLiteralValue::Str(s)) => write!(out, "{:?}", s.as_str()).unwrap(),
+        LiteralValue::Scalar(sc) => match (sc.dtype(), sc.value()) {
+            (DataType::Boolean, AnyValue::Boolean(v)) => out.push_str(py_bool(*v)),
+            (DataType::String, AnyValue::String(s)) => write!(out, "{s:?}").unwrap(),
+            (DataType::String, AnyValue::StringOwned(s)) => {
+                write!(out, "{:?}", s.as_str()).unwrap()
+            },
+            (DataType::Null, AnyValue::Null) => out.push_str("None"),
+            // The physical integer of a decimal would be rescaled when it is parsed, so decimals
+            // are written as a string of their logical value.
+            #[cfg(feature = "dtype-decimal")]
+            (dtype @ DataType::Decimal(..), AnyValue::Decimal(v, scale)) => {
+                let mut buf = arrow::compute::decimal::DecimalFmtBuffer::new();
+                write!(out, "lit({:?}, dtype=", buf.format(*v, *scale, false)).unwrap();
+                write_dtype(out, dtype)?;
+                out.push(')');
+            },
+            // Other literals are written with their physical value.
+            (dtype, value) => {
+                out.push_str("lit(");
+                if value.is_null() {
+                    out.push_str("None");
+                } else {
+                    match sc.clone().to_physical().value() {
+                        AnyValue::Float32(v) => write_float(out, *v as f64),
+                        AnyValue::Float64(v) => write_float(out, *v),
+                        v if v.dtype().is_integer() => {
+                            write!(out, "{}", v.extract::<i128>().unwrap()).unwrap()
+                        },
+                        _ => return Err(unsupported(format!("literal {value} of {dtype}"))),
+                    }
+                }
+                out.push_str(", dtype=");
+                write_dtype(out, dtype)?;
+                out.push(')');
+            },
+        },
+        _ => return Err(unsupported(format!("literal {lv:?}"))),
+    }
+    Ok(())
+}
+
+fn write_float(out: &mut String, v: f64) {
+    if v.is_nan() {
+        out.push_str("float(\"nan\")");
+    } else if v.is_infinite() {
+        out.push_str(if v > 0.0 {
+            "float(\"inf\")"
+        } else {
+            "float(\"-inf\")"
+        });
+    } else {
+        write!(out, "{v:?}").unwrap();
+    }
+}
+
+fn write_dtype(out: &mut String, dtype: &DataType) -> PolarsResult<()> {
+    use DataType as D;
+    let name = match dtype {
+        D::Boolean => "Boolean",
+        D::UInt8 => "UInt8",
+        D::UInt16 => "UInt16",
+        D::UInt32 => "UInt32",
+        D::UInt64 => "UInt64",
+        D::Int8 => "Int8",
+        D::Int16 => "Int16",
+        D::Int32 => "Int32",
+        D::Int64 => "Int64",
+        D::Int128 => "Int128",
+        D::Float32 => "Float32",
+        D::Float64 => "Float64",
+        D::String => "String",
+        D::Binary => "Binary",
+        D::Date => "Date",
+        D::Time => "Time",
+        D::Null => "Null",
+        D::Datetime(tu, tz) => {
+            write!(out, "Datetime({:?}", tu.to_ascii()).unwrap();
+            if let Some(tz) = tz {
+                write!(out, ", {:?}", tz.as_str()).unwrap();
+            }
+            out.push(')');
+            return Ok(());
+        },
+        D::Duration(tu) => {
+            write!(out, "Duration({:?})", tu.to_ascii()).unwrap();
+            return Ok(());
+        },
+        D::List(inner) => {
+            out.push_str("List(");
+            write_dtype(out, inner)?;
+            out.push(')');
+            return Ok(());
+        },
+        #[cfg(feature = "dtype-array")]
+        D::Array(inner, width) => {
+            out.push_str("Array(");
+            write_dtype(out, inner)?;
+            write!(out, ", {width})").unwrap();
+            return Ok(());
+        },
+        #[cfg(feature = "dtype-decimal")]
+        D::Decimal(precision, scale) => {
+            let opt = |v: &Option<usize>| v.map_or_else(|| "None".to_string(), |v| v.to_string());
+            write!(out, "Decimal({}, {})", opt(precision), opt(scale)).unwrap();
+            return Ok(());
+        },
+        dt => return Err(unsupported(format!("data type {dt}"))),
+    };
+    out.push_str(name);
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use super::*;
+
+/// Specialized expressions for parsing and encoding URLs stored as [`DataType::String`].
+pub struct UrlNameSpace(pub(crate) Expr);
+
+impl UrlNameSpace {
+    /// Split URLs into a struct with the fields `scheme`, `host`, `path`, `query` and `fragment`.
+    pub fn parse(self) -> Expr {
+        self.0.map_unary(UrlFunction::Parse)
+    }
+
+    /// Get the decoded value of the first occurrence of `key` in the query string.
+    pub fn query_param(self, key: PlSmallStr) -> Expr {
+        self.0.map_unary(UrlFunction::QueryParam(key))
+    }
+
+    /// Percent-encode all characters that are not unreserved in a URL component.
+    pub fn encode(self) -> Expr {
+        self.0.map_unary(UrlFunction::Encode)
+    }
+
+    /// Decode percent-encoded sequences; values that are not valid UTF-8 after decoding become null.
+    pub fn decode(self) -> Expr {
+        self.0.map_unary(UrlFunction::Decode)
+    }
+}
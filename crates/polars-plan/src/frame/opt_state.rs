@@ -39,6 +39,12 @@ bitflags! {
         /// Check if operations are order dependent and unset maintaining_order if
         /// the order would not be observed.
         const CHECK_ORDER_OBSERVE = 1 << 16;
+        /// Replace joins with small in-memory tables with unique keys by lookups. This is
+        /// not enabled by default.
+        const LOOKUP_JOINS = 1 << 17;
+        /// Answer null counts, minima and maxima of Parquet scans from the statistics of the
+        /// files. This is not enabled by default.
+        const FAST_STATISTICS = 1 << 18;
     }
 }
 
@@ -59,6 +65,14 @@ impl OptFlags {
         self.contains(OptFlags::COLLAPSE_JOINS)
     }
 
+    pub fn lookup_joins(&self) -> bool {
+        self.contains(OptFlags::LOOKUP_JOINS)
+    }
+
+    pub fn fast_statistics(&self) -> bool {
+        self.contains(OptFlags::FAST_STATISTICS)
+    }
+
     pub fn predicate_pushdown(&self) -> bool {
         self.contains(OptFlags::PREDICATE_PUSHDOWN)
     }
@@ -82,7 +96,11 @@ impl OptFlags {
 
 impl Default for OptFlags {
     fn default() -> Self {
-        Self::from_bits_truncate(u32::MAX) & !Self::NEW_STREAMING & !Self::EAGER
+        Self::from_bits_truncate(u32::MAX)
+            & !Self::NEW_STREAMING
+            & !Self::EAGER
+            & !Self::LOOKUP_JOINS
+            & !Self::FAST_STATISTICS
     }
 }
 
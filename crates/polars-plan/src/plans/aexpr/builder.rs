@@ -282,6 +282,14 @@ impl AExprBuilder {
         )
     }
 
+    pub fn nan_to_null(self, arena: &mut Arena<AExpr>) -> Self {
+        Self::function(
+            vec![self.expr_ir_unnamed()],
+            IRFunctionExpr::NanToNull,
+            arena,
+        )
+    }
+
     pub fn has_no_nulls(self, arena: &mut Arena<AExpr>) -> Self {
         let nc = self.null_count(arena);
         let idx_zero = Self::lit_scalar(Scalar::from(0 as IdxSize), arena);
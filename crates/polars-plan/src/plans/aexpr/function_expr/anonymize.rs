@@ -0,0 +1,86 @@
+use super::*;
+use crate::{map, map_as_slice};
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub enum IRAnonymizeFunction {
+    HashStable {
+        /// The salts are secrets, so they are not serialized or shown in `Debug` output.
+        #[cfg_attr(feature = "ir_serde", serde(skip))]
+        salts: Vec<PlSmallStr>,
+    },
+    SuppressRare {
+        k: IdxSize,
+    },
+}
+
+impl std::fmt::Debug for IRAnonymizeFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRAnonymizeFunction::*;
+        match self {
+            HashStable { salts } => f
+                .debug_struct("HashStable")
+                .field("salts", &format_args!("<{} redacted>", salts.len()))
+                .finish(),
+            SuppressRare { k } => f.debug_struct("SuppressRare").field("k", k).finish(),
+        }
+    }
+}
+
+impl IRAnonymizeFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRAnonymizeFunction::*;
+        match self {
+            HashStable { .. } => mapper.with_dtype(DataType::UInt64),
+            SuppressRare { .. } => mapper.with_same_dtype(),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRAnonymizeFunction::*;
+        match self {
+            HashStable { .. } => FunctionOptions::elementwise(),
+            SuppressRare { .. } => FunctionOptions::length_preserving(),
+        }
+    }
+}
+
+impl Display for IRAnonymizeFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRAnonymizeFunction::*;
+        let s = match self {
+            HashStable { .. } => "hash_stable",
+            SuppressRare { .. } => "suppress_rare",
+        };
+        write!(f, "anon.{s}")
+    }
+}
+
+impl From<IRAnonymizeFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRAnonymizeFunction) -> Self {
+        use IRAnonymizeFunction::*;
+        match func {
+            HashStable { salts } => map_as_slice!(hash_stable, &salts),
+            SuppressRare { k } => map!(suppress_rare, k),
+        }
+    }
+}
+
+impl From<IRAnonymizeFunction> for IRFunctionExpr {
+    fn from(func: IRAnonymizeFunction) -> Self {
+        IRFunctionExpr::Anonymize(func)
+    }
+}
+
+fn hash_stable(s: &[Column], salts: &[PlSmallStr]) -> PolarsResult<Column> {
+    polars_ops::series::hash_stable(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        salts,
+    )
+    .map(|ca| ca.into_column())
+}
+
+fn suppress_rare(s: &Column, k: IdxSize) -> PolarsResult<Column> {
+    polars_ops::series::suppress_rare(s.as_materialized_series(), k).map(Column::from)
+}
@@ -1,4 +1,5 @@
 use std::ops::{BitAnd, BitOr};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use polars_core::POOL;
 use polars_core::utils::SuperTypeFlags;
@@ -162,9 +163,9 @@ impl From<IRBooleanFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             IsNan => map!(is_nan),
             IsNotNan => map!(is_not_nan),
             #[cfg(feature = "is_first_distinct")]
-            IsFirstDistinct => map!(is_first_distinct),
+            IsFirstDistinct => map_as_slice!(is_first_distinct),
             #[cfg(feature = "is_last_distinct")]
-            IsLastDistinct => map!(is_last_distinct),
+            IsLastDistinct => map_as_slice!(is_last_distinct),
             #[cfg(feature = "is_unique")]
             IsUnique => map!(is_unique),
             #[cfg(feature = "is_unique")]
@@ -235,13 +236,13 @@ pub(super) fn is_not_nan(s: &Column) -> PolarsResult<Column> {
 }
 
 #[cfg(feature = "is_first_distinct")]
-fn is_first_distinct(s: &Column) -> PolarsResult<Column> {
-    polars_ops::prelude::is_first_distinct(s.as_materialized_series()).map(|ca| ca.into_column())
+fn is_first_distinct(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::is_first_distinct_columns(s).map(|ca| ca.into_column())
 }
 
 #[cfg(feature = "is_last_distinct")]
-fn is_last_distinct(s: &Column) -> PolarsResult<Column> {
-    polars_ops::prelude::is_last_distinct(s.as_materialized_series()).map(|ca| ca.into_column())
+fn is_last_distinct(s: &[Column]) -> PolarsResult<Column> {
+    polars_ops::prelude::is_last_distinct_columns(s).map(|ca| ca.into_column())
 }
 
 #[cfg(feature = "is_unique")]
@@ -303,46 +304,58 @@ fn not(s: &Column) -> PolarsResult<Column> {
     polars_ops::series::negate_bitwise(s.as_materialized_series()).map(Column::from)
 }
 
-// We shouldn't hit these often only on very wide dataframes where we don't reduce to & expressions.
+/// Fold the columns with a Kleene `op`. Once a partial result equals `absorbing` in every row,
+/// the remaining columns can no longer change the result and are skipped.
+fn bool_horizontal(
+    s: &[Column],
+    absorbing: bool,
+    op: impl Fn(&BooleanChunked, &BooleanChunked) -> BooleanChunked + Sync,
+) -> PolarsResult<Column> {
+    // Cast all columns up front so that invalid inputs raise even if they would be skipped.
+    let columns = POOL.install(|| {
+        s.par_iter()
+            .map(|c| c.cast(&DataType::Boolean))
+            .collect::<PolarsResult<Vec<_>>>()
+    })?;
+
+    let saturated = AtomicBool::new(false);
+    let out = POOL.install(|| {
+        columns
+            .par_iter()
+            .try_fold(
+                || BooleanChunked::new(PlSmallStr::EMPTY, &[!absorbing]),
+                |acc, b| {
+                    if saturated.load(Ordering::Relaxed) {
+                        return PolarsResult::Ok(acc);
+                    }
+                    let acc = op(&acc, b.bool()?);
+                    if acc.null_count() == 0 && if absorbing { acc.all() } else { !acc.any() } {
+                        saturated.store(true, Ordering::Relaxed);
+                    }
+                    Ok(acc)
+                },
+            )
+            .try_reduce(
+                || BooleanChunked::new(PlSmallStr::EMPTY, &[!absorbing]),
+                |a, b| Ok(op(&a, &b)),
+            )
+    })?;
+
+    let out = if saturated.load(Ordering::Relaxed) {
+        let len = s.iter().map(|c| c.len()).max().unwrap();
+        BooleanChunked::full(PlSmallStr::EMPTY, absorbing, len)
+    } else {
+        out
+    };
+    Ok(out.with_name(s[0].name().clone()).into_column())
+}
+
+// We shouldn't hit these often only on very wide dataframes where we don't reduce to | expressions.
 fn any_horizontal(s: &[Column]) -> PolarsResult<Column> {
-    let out = POOL
-        .install(|| {
-            s.par_iter()
-                .try_fold(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, &[false]),
-                    |acc, b| {
-                        let b = b.cast(&DataType::Boolean)?;
-                        let b = b.bool()?;
-                        PolarsResult::Ok((&acc).bitor(b))
-                    },
-                )
-                .try_reduce(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, [false]),
-                    |a, b| Ok(a.bitor(b)),
-                )
-        })?
-        .with_name(s[0].name().clone());
-    Ok(out.into_column())
+    bool_horizontal(s, true, |a, b| a.bitor(b))
 }
 
 // We shouldn't hit these often only on very wide dataframes where we don't reduce to & expressions.
 fn all_horizontal(s: &[Column]) -> PolarsResult<Column> {
-    let out = POOL
-        .install(|| {
-            s.par_iter()
-                .try_fold(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, &[true]),
-                    |acc, b| {
-                        let b = b.cast(&DataType::Boolean)?;
-                        let b = b.bool()?;
-                        PolarsResult::Ok((&acc).bitand(b))
-                    },
-                )
-                .try_reduce(
-                    || BooleanChunked::new(PlSmallStr::EMPTY, [true]),
-                    |a, b| Ok(a.bitand(b)),
-                )
-        })?
-        .with_name(s[0].name().clone());
-    Ok(out.into_column())
+    bool_horizontal(s, false, |a, b| a.bitand(b))
 }
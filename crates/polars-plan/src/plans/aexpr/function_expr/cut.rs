@@ -1,19 +1,43 @@
 use polars_core::prelude::*;
 
 pub(crate) fn cut(
-    s: &Column,
+    s: &[Column],
     breaks: Vec<f64>,
     labels: Option<Vec<PlSmallStr>>,
     left_closed: bool,
     include_breaks: bool,
+    missing_label: Option<PlSmallStr>,
 ) -> PolarsResult<Column> {
-    polars_ops::prelude::cut(
-        s.as_materialized_series(),
-        breaks,
-        labels,
-        left_closed,
-        include_breaks,
-    )
+    let series = s[0].as_materialized_series();
+    match s.get(1) {
+        // Breaks given as an expression, in the dtype of the input.
+        Some(breaks) => {
+            let breaks = breaks.list()?;
+            polars_ensure!(
+                breaks.len() == 1,
+                ComputeError: "`breaks` must be a single list of breaks, got {} lists", breaks.len()
+            );
+            let breaks = breaks
+                .get_as_series(0)
+                .unwrap_or_else(|| Series::new_empty(PlSmallStr::EMPTY, series.dtype()));
+            polars_ops::prelude::cut_with_breaks(
+                series,
+                &breaks,
+                labels,
+                left_closed,
+                include_breaks,
+                missing_label,
+            )
+        },
+        None => polars_ops::prelude::cut(
+            series,
+            breaks,
+            labels,
+            left_closed,
+            include_breaks,
+            missing_label,
+        ),
+    }
     .map(Column::from)
 }
 
@@ -24,6 +48,7 @@ pub(crate) fn qcut(
     left_closed: bool,
     allow_duplicates: bool,
     include_breaks: bool,
+    missing_label: Option<PlSmallStr>,
 ) -> PolarsResult<Column> {
     polars_ops::prelude::qcut(
         s.as_materialized_series(),
@@ -32,6 +57,7 @@ pub(crate) fn qcut(
         left_closed,
         allow_duplicates,
         include_breaks,
+        missing_label,
     )
     .map(Column::from)
 }
@@ -76,6 +76,8 @@ pub enum IRTemporalFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
     },
+    #[cfg(feature = "timezones")]
+    ConvertTimeZonePerRow,
 }
 
 impl IRTemporalFunction {
@@ -103,6 +105,11 @@ impl IRTemporalFunction {
                 DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, Some(tz.clone()))),
                 dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
             }),
+            #[cfg(feature = "timezones")]
+            ConvertTimeZonePerRow => mapper.try_map_dtype(|dt| match dt {
+                DataType::Datetime(tu, _) => Ok(DataType::Datetime(*tu, None)),
+                dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
+            }),
             TimeStamp(_) => mapper.with_dtype(DataType::Int64),
             IsLeapYear => mapper.with_dtype(DataType::Boolean),
             Time => mapper.with_dtype(DataType::Time),
@@ -135,6 +142,10 @@ impl IRTemporalFunction {
                 PlSmallStr::from_static("datetime"),
                 DataType::Datetime(*time_unit, time_zone.clone()),
             )),
+            // With a time zone column, the combined datetimes are localized to UTC instants.
+            Combine(tu) if mapper.get_fields_lens() == 3 => {
+                mapper.with_dtype(DataType::Datetime(*tu, Some(TimeZone::UTC)))
+            },
             Combine(tu) => mapper.try_map_dtype(|dt| match dt {
                 DataType::Datetime(_, tz) => Ok(DataType::Datetime(*tu, tz.clone())),
                 DataType::Date => Ok(DataType::Datetime(*tu, None)),
@@ -181,7 +192,7 @@ impl IRTemporalFunction {
             | T::TotalMicroseconds
             | T::TotalNanoseconds => FunctionOptions::elementwise(),
             #[cfg(feature = "timezones")]
-            T::ConvertTimeZone(_) => FunctionOptions::elementwise(),
+            T::ConvertTimeZone(_) | T::ConvertTimeZonePerRow => FunctionOptions::elementwise(),
             #[cfg(feature = "month_start")]
             T::MonthStart => FunctionOptions::elementwise(),
             #[cfg(feature = "month_end")]
@@ -247,7 +258,7 @@ impl Display for IRTemporalFunction {
             TotalNanoseconds => "total_nanoseconds",
             ToString(_) => "to_string",
             #[cfg(feature = "timezones")]
-            ConvertTimeZone(_) => "convert_time_zone",
+            ConvertTimeZone(_) | ConvertTimeZonePerRow => "convert_time_zone",
             CastTimeUnit(_) => "cast_time_unit",
             WithTimeUnit(_) => "with_time_unit",
             TimeStamp(tu) => return write!(f, "dt.timestamp({tu})"),
@@ -470,6 +481,20 @@ pub(super) fn to_string(s: &Column, format: &str) -> PolarsResult<Column> {
     TemporalMethods::to_string(s.as_materialized_series(), format).map(Column::from)
 }
 
+#[cfg(feature = "timezones")]
+pub(super) fn convert_time_zone_per_row(s: &[Column]) -> PolarsResult<Column> {
+    let datetime = &s[0];
+    let time_zones = &s[1];
+    match datetime.dtype() {
+        DataType::Datetime(_, _) => Ok(polars_ops::prelude::convert_time_zone_per_row(
+            datetime.datetime()?,
+            time_zones.cast(&DataType::String)?.str()?,
+        )?
+        .into_column()),
+        dtype => polars_bail!(ComputeError: "expected Datetime, got {}", dtype),
+    }
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn convert_time_zone(s: &Column, time_zone: &TimeZone) -> PolarsResult<Column> {
     match s.dtype() {
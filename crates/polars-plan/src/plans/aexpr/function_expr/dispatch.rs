@@ -3,6 +3,32 @@ use polars_ops::series::NullStrategy;
 
 use super::*;
 
+pub(super) fn checked_arithmetic(s: &[Column], op: CheckedArithmeticOp) -> PolarsResult<Column> {
+    let (out, n_overflow) = polars_ops::series::checked_arithmetic(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        op,
+    )?;
+    if n_overflow > 0 {
+        let name = <&str>::from(op);
+        let len = out.len();
+        polars_warn!(
+            "{n_overflow} of {len} values overflowed in `{name}_checked` and were set to null"
+        );
+    }
+    Ok(out.into())
+}
+
+pub(super) fn div_or(s: &[Column], op: DivOrOp) -> PolarsResult<Column> {
+    polars_ops::series::div_or(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+        op,
+    )
+    .map(Column::from)
+}
+
 pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(s.reverse())
 }
@@ -47,10 +73,120 @@ pub(super) fn interpolate_by(s: &[Column]) -> PolarsResult<Column> {
     polars_ops::prelude::interpolate_by(&s[0], by, by_is_sorted)
 }
 
+#[cfg(feature = "downsample")]
+pub(super) fn downsample_lttb(s: &[Column], n_out: usize) -> PolarsResult<Column> {
+    polars_ops::prelude::downsample_lttb(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        n_out,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "downsample")]
+pub(super) fn downsample_minmax(s: &[Column], n_buckets: usize) -> PolarsResult<Column> {
+    polars_ops::prelude::downsample_minmax(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        n_buckets,
+    )
+    .map(Column::from)
+}
+
 pub(super) fn to_physical(s: &Column) -> PolarsResult<Column> {
     Ok(s.to_physical_repr())
 }
 
+#[cfg(feature = "to_dummies")]
+pub(super) fn to_dummies(
+    s: &Column,
+    separator: Option<PlSmallStr>,
+    drop_first: bool,
+    sparse: bool,
+) -> PolarsResult<Column> {
+    polars_ops::prelude::enum_to_dummies(
+        s.as_materialized_series(),
+        separator.as_deref().unwrap_or("_"),
+        drop_first,
+        sparse,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "category_encoding")]
+pub(super) fn categorical_target_encode(
+    s: &[Column],
+    folds: u32,
+    smoothing: f64,
+    seed: u64,
+) -> PolarsResult<Column> {
+    polars_ops::prelude::categorical_target_encode(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        folds,
+        smoothing,
+        seed,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "category_encoding")]
+pub(super) fn frequency_encode(s: &Column, normalize: bool) -> PolarsResult<Column> {
+    polars_ops::prelude::frequency_encode(s.as_materialized_series(), normalize).map(Column::from)
+}
+
+#[cfg(feature = "bootstrap")]
+pub(super) fn bootstrap(
+    s: &[Column],
+    n_samples: usize,
+    statistic: BootstrapStatistic,
+    method: BootstrapMethod,
+    confidence_level: f64,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    polars_ops::series::bootstrap(
+        s[0].as_materialized_series(),
+        s.get(1).map(|w| w.as_materialized_series()),
+        n_samples,
+        statistic,
+        method,
+        confidence_level,
+        seed,
+    )
+    .map(Column::from)
+}
+
+#[cfg(feature = "unique_id")]
+fn unique_id_len(s: &Column) -> PolarsResult<usize> {
+    polars_ensure!(
+        s.len() == 1,
+        ComputeError: "the number of IDs to generate must be a single value, got {} values", s.len()
+    );
+    let n = s.strict_cast(&IDX_DTYPE)?;
+    Ok(n.idx()?.get(0).unwrap_or(0) as usize)
+}
+
+#[cfg(feature = "unique_id")]
+pub(super) fn uuid4(s: &Column) -> PolarsResult<Column> {
+    let n = unique_id_len(s)?;
+    Ok(polars_ops::series::uuid4(PlSmallStr::from_static("uuid"), n).into_column())
+}
+
+#[cfg(feature = "unique_id")]
+pub(super) fn snowflake_id(s: &Column, worker_id: u16) -> PolarsResult<Column> {
+    let n = unique_id_len(s)?;
+    Ok(
+        polars_ops::series::snowflake_ids(PlSmallStr::from_static("id"), n, worker_id)?
+            .into_column(),
+    )
+}
+
+pub(super) fn placeholder(_s: &Column, name: &PlSmallStr) -> PolarsResult<Column> {
+    polars_bail!(
+        InvalidOperation: "placeholder '{name}' is not bound; pass its value with `collect(params=...)`"
+    )
+}
+
 pub(super) fn set_sorted_flag(s: &Column, sorted: IsSorted) -> PolarsResult<Column> {
     let mut s = s.clone();
     s.set_sorted_flag(sorted);
@@ -130,6 +266,18 @@ pub(super) fn mean_horizontal(
     polars_ops::prelude::mean_horizontal(s, null_strategy)
 }
 
+pub(super) fn quantile_horizontal(
+    s: &mut [Column],
+    quantile: f64,
+    method: QuantileMethod,
+) -> PolarsResult<Option<Column>> {
+    polars_ops::prelude::quantile_horizontal(s, quantile, method)
+}
+
+pub(super) fn std_horizontal(s: &mut [Column], ddof: u8) -> PolarsResult<Option<Column>> {
+    polars_ops::prelude::std_horizontal(s, ddof)
+}
+
 pub(super) fn drop_nulls(s: &Column) -> PolarsResult<Column> {
     Ok(s.drop_nulls())
 }
@@ -61,6 +61,8 @@ pub enum IRListFunction {
     ToArray(usize),
     #[cfg(feature = "list_to_struct")]
     ToStruct(ListToStruct),
+    #[cfg(feature = "list_filter")]
+    Filter,
 }
 
 impl IRListFunction {
@@ -72,6 +74,8 @@ impl IRListFunction {
             Contains { nulls_equal: _ } => mapper.with_dtype(DataType::Boolean),
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => mapper.with_same_dtype(),
+            #[cfg(feature = "list_filter")]
+            Filter => mapper.with_same_dtype(),
             #[cfg(feature = "list_sample")]
             Sample { .. } => mapper.with_same_dtype(),
             Slice => mapper.with_same_dtype(),
@@ -138,21 +142,25 @@ impl IRListFunction {
                 let inner_dtype = inner_dtype.as_ref();
 
                 match args {
-                    ListToStruct::FixedWidth(names) => Ok(DataType::Struct(
-                        names
-                            .iter()
-                            .map(|x| Field::new(x.clone(), inner_dtype.clone()))
-                            .collect::<Vec<_>>(),
-                    )),
+                    ListToStruct::FixedWidth(names) | ListToStruct::FixedWidthStrict(names) => {
+                        Ok(DataType::Struct(
+                            names
+                                .iter()
+                                .map(|x| Field::new(x.clone(), inner_dtype.clone()))
+                                .collect::<Vec<_>>(),
+                        ))
+                    },
                     ListToStruct::InferWidth {
                         get_index_name,
+                        name_format,
                         max_fields: Some(max_fields),
                         ..
                     } => (0..*max_fields)
                         .map(|i| {
-                            let name = match get_index_name {
-                                None => _default_struct_name_gen(i),
-                                Some(ng) => PlSmallStr::from_string(ng.call(i)?),
+                            let name = match (name_format, get_index_name) {
+                                (Some(name_format), _) => format_field_name(name_format, i),
+                                (None, None) => _default_struct_name_gen(i),
+                                (None, Some(ng)) => PlSmallStr::from_string(ng.call(i)?),
                             };
                             Ok(Field::new(name, inner_dtype.as_ref().clone()))
                         })
@@ -187,6 +195,8 @@ impl IRListFunction {
             L::Diff { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "list_drop_nulls")]
             L::DropNulls => FunctionOptions::elementwise(),
+            #[cfg(feature = "list_filter")]
+            L::Filter => FunctionOptions::elementwise(),
             #[cfg(feature = "list_count")]
             L::CountMatches => FunctionOptions::elementwise(),
             L::Sum
@@ -212,7 +222,9 @@ impl IRListFunction {
             #[cfg(feature = "dtype-array")]
             L::ToArray(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "list_to_struct")]
-            L::ToStruct(ListToStruct::FixedWidth(_)) => FunctionOptions::elementwise(),
+            L::ToStruct(ListToStruct::FixedWidth(_) | ListToStruct::FixedWidthStrict(_)) => {
+                FunctionOptions::elementwise()
+            },
             #[cfg(feature = "list_to_struct")]
             L::ToStruct(ListToStruct::InferWidth { .. }) => FunctionOptions::length_preserving(),
         }
@@ -238,6 +250,8 @@ impl Display for IRListFunction {
             Contains { nulls_equal: _ } => "contains",
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => "drop_nulls",
+            #[cfg(feature = "list_filter")]
+            Filter => "filter",
             #[cfg(feature = "list_sample")]
             Sample { is_fraction, .. } => {
                 if *is_fraction {
@@ -302,6 +316,8 @@ impl From<IRListFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Contains { nulls_equal } => map_as_slice!(contains, nulls_equal),
             #[cfg(feature = "list_drop_nulls")]
             DropNulls => map!(drop_nulls),
+            #[cfg(feature = "list_filter")]
+            Filter => map_as_slice!(filter),
             #[cfg(feature = "list_sample")]
             Sample {
                 is_fraction,
@@ -686,6 +702,13 @@ pub(super) fn gather_every(args: &[Column]) -> PolarsResult<Column> {
         .map(Column::from)
 }
 
+#[cfg(feature = "list_filter")]
+pub(super) fn filter(args: &[Column]) -> PolarsResult<Column> {
+    let ca = args[0].list()?;
+    let mask = args[1].list()?;
+    ca.lst_filter(mask).map(Column::from)
+}
+
 #[cfg(feature = "list_count")]
 pub(super) fn count_matches(args: &[Column]) -> PolarsResult<Column> {
     let s = &args[0];
@@ -810,19 +833,35 @@ pub(super) fn to_array(s: &Column, width: usize) -> PolarsResult<Column> {
     s.cast(&array_dtype)
 }
 
+/// The name of the field at `idx` according to the `name_format` of [`ListToStruct::InferWidth`].
+#[cfg(feature = "list_to_struct")]
+fn format_field_name(name_format: &str, idx: usize) -> PlSmallStr {
+    PlSmallStr::from_string(name_format.replace("{}", &idx.to_string()))
+}
+
 #[cfg(feature = "list_to_struct")]
 pub(super) fn to_struct(s: &Column, args: &ListToStruct) -> PolarsResult<Column> {
+    let error_on_overflow = args.error_on_overflow();
     let args = args.clone();
     let args = match args {
-        ListToStruct::FixedWidth(strs) => ListToStructArgs::FixedWidth(strs),
+        ListToStruct::FixedWidth(names) | ListToStruct::FixedWidthStrict(names) => {
+            ListToStructArgs::FixedWidth(names)
+        },
         ListToStruct::InferWidth {
             infer_field_strategy,
             get_index_name,
+            name_format,
             max_fields,
+            ..
         } => {
-            let get_index_name = get_index_name.map(|f| {
-                NameGenerator(Arc::new(move |i| f.call(i).map(PlSmallStr::from)) as Arc<_>)
-            });
+            let get_index_name = match name_format {
+                Some(name_format) => Some(NameGenerator::from_func(move |i| {
+                    Ok(format_field_name(&name_format, i))
+                })),
+                None => get_index_name.map(|f| {
+                    NameGenerator(Arc::new(move |i| f.call(i).map(PlSmallStr::from)) as Arc<_>)
+                }),
+            };
 
             ListToStructArgs::InferWidth {
                 infer_field_strategy,
@@ -831,7 +870,20 @@ pub(super) fn to_struct(s: &Column, args: &ListToStruct) -> PolarsResult<Column>
             }
         },
     };
-    Ok(s.list()?.to_struct(&args)?.into_column())
+    let ca = s.list()?;
+    let out = ca.to_struct(&args)?;
+
+    if error_on_overflow {
+        let n_fields = out.struct_fields().len();
+        if let Some(max_len) = ca.lst_lengths().max() {
+            polars_ensure!(
+                max_len as usize <= n_fields,
+                InvalidOperation: "list.to_struct: found a list with {} elements, but the struct has {} fields",
+                max_len, n_fields
+            );
+        }
+    }
+    Ok(out.into_column())
 }
 
 pub(super) fn n_unique(s: &Column) -> PolarsResult<Column> {
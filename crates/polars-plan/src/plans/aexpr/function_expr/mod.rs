@@ -1,5 +1,7 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "anonymize")]
+mod anonymize;
 #[cfg(feature = "arg_where")]
 mod arg_where;
 #[cfg(feature = "dtype-array")]
@@ -75,6 +77,8 @@ mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
 mod unique;
+#[cfg(feature = "url")]
+mod url;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -91,10 +95,13 @@ use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 use polars_core::series::ops::NullBehavior;
 use polars_core::utils::SuperTypeFlags;
+use polars_utils::total_ord::TotalOrdWrap;
 #[cfg(feature = "random")]
 pub use random::IRRandomMethod;
 use schema::FieldsMapper;
 
+#[cfg(feature = "anonymize")]
+pub use self::anonymize::IRAnonymizeFunction;
 pub use self::binary::IRBinaryFunction;
 #[cfg(feature = "bitwise")]
 pub use self::bitwise::IRBitwiseFunction;
@@ -118,6 +125,8 @@ pub use self::strings::IRStringFunction;
 pub use self::struct_::IRStructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::IRTrigonometricFunction;
+#[cfg(feature = "url")]
+pub use self::url::IRUrlFunction;
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -319,6 +328,7 @@ pub enum IRFunctionExpr {
         labels: Option<Vec<PlSmallStr>>,
         left_closed: bool,
         include_breaks: bool,
+        missing_label: Option<PlSmallStr>,
     },
     #[cfg(feature = "cutqcut")]
     QCut {
@@ -327,6 +337,7 @@ pub enum IRFunctionExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        missing_label: Option<PlSmallStr>,
     },
     #[cfg(feature = "rle")]
     RLE,
@@ -413,6 +424,66 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "reinterpret")]
     Reinterpret(bool),
     ExtendConstant,
+    #[cfg(feature = "url")]
+    Url(IRUrlFunction),
+    #[cfg(feature = "unique_id")]
+    Uuid4,
+    #[cfg(feature = "unique_id")]
+    SnowflakeId {
+        worker_id: u16,
+    },
+    QuantileHorizontal {
+        quantile: TotalOrdWrap<f64>,
+        method: QuantileMethod,
+    },
+    StdHorizontal {
+        ddof: u8,
+    },
+    CheckedArithmetic(CheckedArithmeticOp),
+    #[cfg(feature = "to_dummies")]
+    ToDummies {
+        separator: Option<PlSmallStr>,
+        drop_first: bool,
+        sparse: bool,
+    },
+    #[cfg(feature = "category_encoding")]
+    CategoricalTargetEncode {
+        folds: u32,
+        smoothing: f64,
+        seed: u64,
+    },
+    #[cfg(feature = "category_encoding")]
+    FrequencyEncode {
+        normalize: bool,
+    },
+    Placeholder {
+        name: PlSmallStr,
+    },
+    #[cfg(feature = "round_series")]
+    RoundToIncrement {
+        increment: f64,
+        mode: RoundMode,
+    },
+    NanToNull,
+    #[cfg(feature = "downsample")]
+    DownsampleLttb {
+        n_out: usize,
+    },
+    #[cfg(feature = "downsample")]
+    DownsampleMinMax {
+        n_buckets: usize,
+    },
+    #[cfg(feature = "bootstrap")]
+    Bootstrap {
+        n_samples: usize,
+        statistic: BootstrapStatistic,
+        method: BootstrapMethod,
+        confidence_level: f64,
+        seed: Option<u64>,
+    },
+    #[cfg(feature = "anonymize")]
+    Anonymize(IRAnonymizeFunction),
+    DivOr(DivOrOp),
 }
 
 impl Hash for IRFunctionExpr {
@@ -435,6 +506,10 @@ impl Hash for IRFunctionExpr {
             TemporalExpr(f) => f.hash(state),
             #[cfg(feature = "bitwise")]
             Bitwise(f) => f.hash(state),
+            #[cfg(feature = "url")]
+            Url(f) => f.hash(state),
+            #[cfg(feature = "anonymize")]
+            Anonymize(f) => f.hash(state),
 
             // Other expressions
             Boolean(f) => f.hash(state),
@@ -464,6 +539,10 @@ impl Hash for IRFunctionExpr {
             Interpolate(f) => f.hash(state),
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => {},
+            #[cfg(feature = "downsample")]
+            DownsampleLttb { n_out } => n_out.hash(state),
+            #[cfg(feature = "downsample")]
+            DownsampleMinMax { n_buckets } => n_buckets.hash(state),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
                 flags: _,
@@ -516,8 +595,13 @@ impl Hash for IRFunctionExpr {
             SumHorizontal { ignore_nulls } | MeanHorizontal { ignore_nulls } => {
                 ignore_nulls.hash(state)
             },
-            MaxHorizontal | MinHorizontal | DropNans | DropNulls | Reverse | ArgUnique | ArgMin
-            | ArgMax | Product | Shift | ShiftAndFill => {},
+            QuantileHorizontal { quantile, method } => {
+                quantile.hash(state);
+                method.hash(state);
+            },
+            StdHorizontal { ddof } => ddof.hash(state),
+            MaxHorizontal | MinHorizontal | DropNans | NanToNull | DropNulls | Reverse
+            | ArgUnique | ArgMin | ArgMax | Product | Shift | ShiftAndFill => {},
             Append { upcast } => {
                 upcast.hash(state);
             },
@@ -533,6 +617,8 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "abs")]
             Abs => {},
             Negate => {},
+            CheckedArithmetic(op) => op.hash(state),
+            DivOr(op) => op.hash(state),
             NullCount => {},
             #[cfg(feature = "arg_where")]
             ArgWhere => {},
@@ -628,6 +714,11 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "round_series")]
             IRFunctionExpr::RoundSF { digits } => digits.hash(state),
             #[cfg(feature = "round_series")]
+            IRFunctionExpr::RoundToIncrement { increment, mode } => {
+                increment.to_bits().hash(state);
+                mode.hash(state);
+            },
+            #[cfg(feature = "round_series")]
             IRFunctionExpr::Floor => {},
             #[cfg(feature = "round_series")]
             Ceil => {},
@@ -644,12 +735,14 @@ impl Hash for IRFunctionExpr {
                 labels,
                 left_closed,
                 include_breaks,
+                missing_label,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(breaks);
                 slice.hash(state);
                 labels.hash(state);
                 left_closed.hash(state);
                 include_breaks.hash(state);
+                missing_label.hash(state);
             },
             #[cfg(feature = "dtype-array")]
             Reshape(dims) => dims.hash(state),
@@ -662,6 +755,7 @@ impl Hash for IRFunctionExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                missing_label,
             } => {
                 let slice = bytemuck::cast_slice::<_, u64>(probs);
                 slice.hash(state);
@@ -669,12 +763,47 @@ impl Hash for IRFunctionExpr {
                 left_closed.hash(state);
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
+                missing_label.hash(state);
             },
             #[cfg(feature = "rle")]
             RLE => {},
             #[cfg(feature = "rle")]
             RLEID => {},
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+                sparse,
+            } => (separator, drop_first, sparse).hash(state),
+            #[cfg(feature = "category_encoding")]
+            CategoricalTargetEncode {
+                folds,
+                smoothing,
+                seed,
+            } => {
+                folds.hash(state);
+                smoothing.to_bits().hash(state);
+                seed.hash(state);
+            },
+            #[cfg(feature = "category_encoding")]
+            FrequencyEncode { normalize } => normalize.hash(state),
+            #[cfg(feature = "bootstrap")]
+            Bootstrap {
+                n_samples,
+                statistic,
+                method,
+                confidence_level,
+                seed,
+            } => {
+                (n_samples, statistic, method, seed).hash(state);
+                confidence_level.to_bits().hash(state);
+            },
             ToPhysical => {},
+            #[cfg(feature = "unique_id")]
+            Uuid4 => {},
+            #[cfg(feature = "unique_id")]
+            SnowflakeId { worker_id } => worker_id.hash(state),
+            Placeholder { name } => name.hash(state),
             SetSortedFlag(is_sorted) => is_sorted.hash(state),
             #[cfg(feature = "ewma")]
             EwmMean { options } => options.hash(state),
@@ -728,6 +857,10 @@ impl Display for IRFunctionExpr {
             TemporalExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => return write!(f, "bitwise_{func}"),
+            #[cfg(feature = "url")]
+            Url(func) => return write!(f, "{func}"),
+            #[cfg(feature = "anonymize")]
+            Anonymize(func) => return write!(f, "{func}"),
 
             // Other expressions
             Boolean(func) => return write!(f, "{func}"),
@@ -736,6 +869,8 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "abs")]
             Abs => "abs",
             Negate => "negate",
+            CheckedArithmetic(op) => return write!(f, "{}_checked", <&str>::from(op)),
+            DivOr(op) => return write!(f, "{}_or", <&str>::from(op)),
             NullCount => "null_count",
             Pow(func) => return write!(f, "{func}"),
             #[cfg(feature = "row_hash")]
@@ -762,6 +897,7 @@ impl Display for IRFunctionExpr {
             Append { .. } => "append",
             ShiftAndFill => "shift_and_fill",
             DropNans => "drop_nans",
+            NanToNull => "nan_to_null",
             DropNulls => "drop_nulls",
             #[cfg(feature = "mode")]
             Mode => "mode",
@@ -824,6 +960,10 @@ impl Display for IRFunctionExpr {
             Interpolate(_) => "interpolate",
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => "interpolate_by",
+            #[cfg(feature = "downsample")]
+            DownsampleLttb { .. } => "downsample_lttb",
+            #[cfg(feature = "downsample")]
+            DownsampleMinMax { .. } => "downsample_minmax",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -844,6 +984,8 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "round_series")]
             RoundSF { .. } => "round_sig_figs",
             #[cfg(feature = "round_series")]
+            RoundToIncrement { .. } => "round_to_increment",
+            #[cfg(feature = "round_series")]
             Floor => "floor",
             #[cfg(feature = "round_series")]
             Ceil => "ceil",
@@ -870,9 +1012,22 @@ impl Display for IRFunctionExpr {
             RLE => "rle",
             #[cfg(feature = "rle")]
             RLEID => "rle_id",
+            #[cfg(feature = "to_dummies")]
+            ToDummies { .. } => "to_dummies",
+            #[cfg(feature = "category_encoding")]
+            CategoricalTargetEncode { .. } => "categorical_target_encode",
+            #[cfg(feature = "category_encoding")]
+            FrequencyEncode { .. } => "frequency_encode",
+            #[cfg(feature = "bootstrap")]
+            Bootstrap { .. } => "bootstrap",
             ToPhysical => "to_physical",
             #[cfg(feature = "random")]
             Random { method, .. } => method.into(),
+            #[cfg(feature = "unique_id")]
+            Uuid4 => "uuid4",
+            #[cfg(feature = "unique_id")]
+            SnowflakeId { .. } => "snowflake_id",
+            Placeholder { .. } => "placeholder",
             SetSortedFlag(_) => "set_sorted",
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin { lib, symbol, .. } => return write!(f, "{lib}:{symbol}"),
@@ -888,6 +1043,8 @@ impl Display for IRFunctionExpr {
             MinHorizontal => "min_horizontal",
             SumHorizontal { .. } => "sum_horizontal",
             MeanHorizontal { .. } => "mean_horizontal",
+            QuantileHorizontal { .. } => "quantile_horizontal",
+            StdHorizontal { .. } => "std_horizontal",
             #[cfg(feature = "ewma")]
             EwmMean { .. } => "ewm_mean",
             #[cfg(feature = "ewma_by")]
@@ -1013,6 +1170,10 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             TemporalExpr(func) => func.into(),
             #[cfg(feature = "bitwise")]
             Bitwise(func) => func.into(),
+            #[cfg(feature = "url")]
+            Url(func) => func.into(),
+            #[cfg(feature = "anonymize")]
+            Anonymize(func) => func.into(),
 
             // Other expressions
             Boolean(func) => func.into(),
@@ -1021,6 +1182,8 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "abs")]
             Abs => map!(abs::abs),
             Negate => map!(dispatch::negate),
+            CheckedArithmetic(op) => map_as_slice!(dispatch::checked_arithmetic, op),
+            DivOr(op) => map_as_slice!(dispatch::div_or, op),
             NullCount => {
                 let f = |s: &mut [Column]| {
                     let s = &s[0];
@@ -1136,6 +1299,7 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map_as_slice!(shift_and_fill::shift_and_fill)
             },
             DropNans => map_owned!(nan::drop_nans),
+            NanToNull => map_owned!(nan::nan_to_null),
             DropNulls => map!(dispatch::drop_nulls),
             #[cfg(feature = "round_series")]
             Clip { has_min, has_max } => {
@@ -1211,6 +1375,12 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             InterpolateBy => {
                 map_as_slice!(dispatch::interpolate_by)
             },
+            #[cfg(feature = "downsample")]
+            DownsampleLttb { n_out } => map_as_slice!(dispatch::downsample_lttb, n_out),
+            #[cfg(feature = "downsample")]
+            DownsampleMinMax { n_buckets } => {
+                map_as_slice!(dispatch::downsample_minmax, n_buckets)
+            },
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
             #[cfg(feature = "log")]
@@ -1225,6 +1395,10 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             #[cfg(feature = "round_series")]
             RoundSF { digits } => map!(round::round_sig_figs, digits),
             #[cfg(feature = "round_series")]
+            RoundToIncrement { increment, mode } => {
+                map!(round::round_to_increment, increment, mode)
+            },
+            #[cfg(feature = "round_series")]
             Floor => map!(round::floor),
             #[cfg(feature = "round_series")]
             Ceil => map!(round::ceil),
@@ -1249,12 +1423,14 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 labels,
                 left_closed,
                 include_breaks,
-            } => map!(
+                missing_label,
+            } => map_as_slice!(
                 cut::cut,
                 breaks.clone(),
                 labels.clone(),
                 left_closed,
-                include_breaks
+                include_breaks,
+                missing_label.clone()
             ),
             #[cfg(feature = "cutqcut")]
             QCut {
@@ -1263,24 +1439,55 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                missing_label,
             } => map!(
                 cut::qcut,
                 probs.clone(),
                 labels.clone(),
                 left_closed,
                 allow_duplicates,
-                include_breaks
+                include_breaks,
+                missing_label.clone()
             ),
             #[cfg(feature = "rle")]
             RLE => map!(rle),
             #[cfg(feature = "rle")]
             RLEID => map!(rle_id),
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+                sparse,
+            } => map!(dispatch::to_dummies, separator.clone(), drop_first, sparse),
+            #[cfg(feature = "category_encoding")]
+            CategoricalTargetEncode {
+                folds,
+                smoothing,
+                seed,
+            } => map_as_slice!(dispatch::categorical_target_encode, folds, smoothing, seed),
+            #[cfg(feature = "category_encoding")]
+            FrequencyEncode { normalize } => map!(dispatch::frequency_encode, normalize),
+            #[cfg(feature = "bootstrap")]
+            Bootstrap {
+                n_samples,
+                statistic,
+                method,
+                confidence_level,
+                seed,
+            } => map_as_slice!(
+                dispatch::bootstrap,
+                n_samples,
+                statistic,
+                method,
+                confidence_level,
+                seed
+            ),
             ToPhysical => map!(dispatch::to_physical),
             #[cfg(feature = "random")]
             Random { method, seed } => {
                 use IRRandomMethod::*;
                 match method {
-                    Shuffle => map!(random::shuffle, seed),
+                    Shuffle => map_as_slice!(random::shuffle, seed),
                     Sample {
                         is_fraction,
                         with_replacement,
@@ -1294,6 +1501,11 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                     },
                 }
             },
+            #[cfg(feature = "unique_id")]
+            Uuid4 => map!(dispatch::uuid4),
+            #[cfg(feature = "unique_id")]
+            SnowflakeId { worker_id } => map!(dispatch::snowflake_id, worker_id),
+            Placeholder { name } => map!(dispatch::placeholder, &name),
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
@@ -1359,6 +1571,10 @@ impl From<IRFunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             MinHorizontal => wrap!(dispatch::min_horizontal),
             SumHorizontal { ignore_nulls } => wrap!(dispatch::sum_horizontal, ignore_nulls),
             MeanHorizontal { ignore_nulls } => wrap!(dispatch::mean_horizontal, ignore_nulls),
+            QuantileHorizontal { quantile, method } => {
+                wrap!(dispatch::quantile_horizontal, quantile.0, method)
+            },
+            StdHorizontal { ddof } => wrap!(dispatch::std_horizontal, ddof),
             #[cfg(feature = "ewma")]
             EwmMean { options } => map!(ewm::ewm_mean, options),
             #[cfg(feature = "ewma_by")]
@@ -1403,6 +1619,10 @@ impl IRFunctionExpr {
             F::TemporalExpr(e) => e.function_options(),
             #[cfg(feature = "bitwise")]
             F::Bitwise(e) => e.function_options(),
+            #[cfg(feature = "url")]
+            F::Url(e) => e.function_options(),
+            #[cfg(feature = "anonymize")]
+            F::Anonymize(e) => e.function_options(),
             F::Boolean(e) => e.function_options(),
             #[cfg(feature = "business")]
             F::Business(e) => e.function_options(),
@@ -1412,6 +1632,9 @@ impl IRFunctionExpr {
             #[cfg(feature = "abs")]
             F::Abs => FunctionOptions::elementwise(),
             F::Negate => FunctionOptions::elementwise(),
+            F::CheckedArithmetic(_) | F::DivOr(_) => {
+                FunctionOptions::elementwise().with_supertyping(Default::default())
+            },
             #[cfg(feature = "hist")]
             F::Hist { .. } => FunctionOptions::groupwise(),
             F::NullCount => FunctionOptions::aggregation(),
@@ -1446,6 +1669,7 @@ impl IRFunctionExpr {
             F::ShiftAndFill => FunctionOptions::length_preserving(),
             F::Shift => FunctionOptions::length_preserving(),
             F::DropNans => FunctionOptions::row_separable(),
+            F::NanToNull => FunctionOptions::elementwise(),
             F::DropNulls => FunctionOptions::row_separable()
                 .with_flags(|f| f | FunctionFlags::ALLOW_EMPTY_INPUTS),
             #[cfg(feature = "mode")]
@@ -1506,15 +1730,21 @@ impl IRFunctionExpr {
             F::Interpolate(_) => FunctionOptions::length_preserving(),
             #[cfg(feature = "interpolate_by")]
             F::InterpolateBy => FunctionOptions::length_preserving(),
+            #[cfg(feature = "downsample")]
+            F::DownsampleLttb { .. } | F::DownsampleMinMax { .. } => {
+                FunctionOptions::length_preserving()
+            },
             #[cfg(feature = "log")]
             F::Log { .. } | F::Log1p | F::Exp => FunctionOptions::elementwise(),
             #[cfg(feature = "log")]
             F::Entropy { .. } => FunctionOptions::aggregation(),
             F::Unique(_) => FunctionOptions::groupwise(),
             #[cfg(feature = "round_series")]
-            F::Round { .. } | F::RoundSF { .. } | F::Floor | F::Ceil => {
-                FunctionOptions::elementwise()
-            },
+            F::Round { .. }
+            | F::RoundSF { .. }
+            | F::RoundToIncrement { .. }
+            | F::Floor
+            | F::Ceil => FunctionOptions::elementwise(),
             F::UpperBound | F::LowerBound => FunctionOptions::aggregation(),
             #[cfg(feature = "fused")]
             F::Fused(_) => FunctionOptions::elementwise(),
@@ -1534,6 +1764,16 @@ impl IRFunctionExpr {
             F::RLE => FunctionOptions::groupwise(),
             #[cfg(feature = "rle")]
             F::RLEID => FunctionOptions::length_preserving(),
+            #[cfg(feature = "to_dummies")]
+            F::ToDummies { .. } => {
+                FunctionOptions::elementwise().with_flags(|f| f | FunctionFlags::PASS_NAME_TO_APPLY)
+            },
+            #[cfg(feature = "category_encoding")]
+            F::CategoricalTargetEncode { .. } | F::FrequencyEncode { .. } => {
+                FunctionOptions::length_preserving()
+            },
+            #[cfg(feature = "bootstrap")]
+            F::Bootstrap { .. } => FunctionOptions::aggregation(),
             F::ToPhysical => FunctionOptions::elementwise(),
             #[cfg(feature = "random")]
             F::Random {
@@ -1545,13 +1785,21 @@ impl IRFunctionExpr {
                 method: IRRandomMethod::Shuffle,
                 ..
             } => FunctionOptions::length_preserving(),
+            #[cfg(feature = "unique_id")]
+            F::Uuid4 | F::SnowflakeId { .. } => {
+                FunctionOptions::row_separable().with_flags(|f| f | FunctionFlags::ALLOW_RENAME)
+            },
+            F::Placeholder { .. } => FunctionOptions::elementwise(),
             F::SetSortedFlag(_) => FunctionOptions::elementwise(),
             #[cfg(feature = "ffi_plugin")]
             F::FfiPlugin { flags, .. } => *flags,
             F::MaxHorizontal | F::MinHorizontal => FunctionOptions::elementwise().with_flags(|f| {
                 f | FunctionFlags::INPUT_WILDCARD_EXPANSION | FunctionFlags::ALLOW_RENAME
             }),
-            F::MeanHorizontal { .. } | F::SumHorizontal { .. } => FunctionOptions::elementwise()
+            F::MeanHorizontal { .. }
+            | F::SumHorizontal { .. }
+            | F::QuantileHorizontal { .. }
+            | F::StdHorizontal { .. } => FunctionOptions::elementwise()
                 .with_flags(|f| f | FunctionFlags::INPUT_WILDCARD_EXPANSION),
 
             F::FoldHorizontal { returns_scalar, .. }
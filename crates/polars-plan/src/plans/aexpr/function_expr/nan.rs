@@ -15,3 +15,11 @@ pub(super) fn drop_nans(s: Column) -> PolarsResult<Column> {
         _ => Ok(s),
     }
 }
+
+pub(super) fn nan_to_null(s: Column) -> PolarsResult<Column> {
+    match s.dtype() {
+        DataType::Float32 => Ok(s.f32()?.nan_to_null().into_column()),
+        DataType::Float64 => Ok(s.f64()?.nan_to_null().into_column()),
+        _ => Ok(s),
+    }
+}
@@ -1,4 +1,7 @@
+use std::hash::BuildHasher;
+
 use polars_core::prelude::DataType::Float64;
+use polars_utils::aliases::PlFixedStateQuality;
 use strum_macros::IntoStaticStr;
 
 use super::*;
@@ -21,8 +24,25 @@ impl Hash for IRRandomMethod {
     }
 }
 
-pub(super) fn shuffle(s: &Column, seed: Option<u64>) -> PolarsResult<Column> {
-    Ok(s.shuffle(seed))
+/// Mix the seed with the group key.
+///
+/// Inside a window the key columns are passed as trailing inputs, so that every group gets its
+/// own seed that doesn't depend on the order in which the groups are evaluated.
+fn seed_by_key(seed: Option<u64>, keys: &[Column]) -> PolarsResult<Option<u64>> {
+    let Some(mut seed) = seed else {
+        return Ok(None);
+    };
+    for key in keys {
+        if !key.is_empty() {
+            seed = PlFixedStateQuality::default().hash_one((seed, key.get(0)?));
+        }
+    }
+    Ok(Some(seed))
+}
+
+pub(super) fn shuffle(s: &[Column], seed: Option<u64>) -> PolarsResult<Column> {
+    let seed = seed_by_key(seed, &s[1..])?;
+    Ok(s[0].shuffle(seed))
 }
 
 pub(super) fn sample_frac(
@@ -33,6 +53,7 @@ pub(super) fn sample_frac(
 ) -> PolarsResult<Column> {
     let src = &s[0];
     let frac_s = &s[1];
+    let seed = seed_by_key(seed, &s[2..])?;
 
     polars_ensure!(
         frac_s.len() == 1,
@@ -56,6 +77,7 @@ pub(super) fn sample_n(
 ) -> PolarsResult<Column> {
     let src = &s[0];
     let n_s = &s[1];
+    let seed = seed_by_key(seed, &s[2..])?;
 
     polars_ensure!(
         n_s.len() == 1,
@@ -10,6 +10,14 @@ pub(super) fn round_sig_figs(c: &Column, digits: i32) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(|s| s.round_sig_figs(digits))
 }
 
+pub(super) fn round_to_increment(
+    c: &Column,
+    increment: f64,
+    mode: RoundMode,
+) -> PolarsResult<Column> {
+    c.try_apply_unary_elementwise(|s| s.round_to_increment(increment, mode))
+}
+
 pub(super) fn floor(c: &Column) -> PolarsResult<Column> {
     c.try_apply_unary_elementwise(Series::floor)
 }
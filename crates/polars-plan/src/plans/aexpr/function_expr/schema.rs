@@ -2,6 +2,20 @@ use polars_core::utils::materialize_dyn_int;
 
 use super::*;
 
+/// The dtype of the breakpoints of `cut` and `qcut`.
+#[cfg(feature = "cutqcut")]
+fn cut_breaks_dtype(fields: &[Field]) -> DataType {
+    let dtype = fields[0].dtype();
+    if !polars_ops::prelude::is_native_cut_dtype(dtype) {
+        return DataType::Float64;
+    }
+    // Breaks given as a list expression.
+    match fields.get(1).and_then(|f| f.dtype().inner_dtype()) {
+        Some(breaks_dtype) => polars_ops::prelude::native_cut_breaks_dtype(dtype, breaks_dtype),
+        None => dtype.clone(),
+    }
+}
+
 impl IRFunctionExpr {
     pub(crate) fn get_field(
         &self,
@@ -28,6 +42,10 @@ impl IRFunctionExpr {
             TemporalExpr(fun) => fun.get_field(mapper),
             #[cfg(feature = "bitwise")]
             Bitwise(fun) => fun.get_field(mapper),
+            #[cfg(feature = "url")]
+            Url(fun) => fun.get_field(mapper),
+            #[cfg(feature = "anonymize")]
+            Anonymize(fun) => fun.get_field(mapper),
 
             // Other expressions
             Boolean(func) => func.get_field(mapper),
@@ -36,6 +54,12 @@ impl IRFunctionExpr {
             #[cfg(feature = "abs")]
             Abs => mapper.with_same_dtype(),
             Negate => mapper.with_same_dtype(),
+            CheckedArithmetic(_) => mapper.map_to_supertype(),
+            DivOr(op) => {
+                let mut field = mapper.map_to_supertype()?;
+                field.coerce(op.output_dtype(field.dtype()));
+                Ok(field)
+            },
             NullCount => mapper.with_dtype(IDX_DTYPE),
             Pow(pow_function) => match pow_function {
                 IRPowFunction::Generic => mapper.pow_dtype(),
@@ -90,6 +114,7 @@ impl IRFunctionExpr {
             },
             ShiftAndFill => mapper.with_same_dtype(),
             DropNans => mapper.with_same_dtype(),
+            NanToNull => mapper.with_same_dtype(),
             DropNulls => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
             Clip { .. } => mapper.with_same_dtype(),
@@ -216,6 +241,10 @@ impl IRFunctionExpr {
             },
             #[cfg(feature = "interpolate_by")]
             InterpolateBy => mapper.map_numeric_to_float_dtype(),
+            #[cfg(feature = "downsample")]
+            DownsampleLttb { .. } | DownsampleMinMax { .. } => {
+                mapper.with_dtype(DataType::Boolean)
+            },
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
@@ -243,7 +272,9 @@ impl IRFunctionExpr {
             Entropy { .. } | Log { .. } | Log1p | Exp => mapper.map_to_float_dtype(),
             Unique(_) => mapper.with_same_dtype(),
             #[cfg(feature = "round_series")]
-            Round { .. } | RoundSF { .. } | Floor | Ceil => mapper.with_same_dtype(),
+            Round { .. } | RoundSF { .. } | RoundToIncrement { .. } | Floor | Ceil => {
+                mapper.with_same_dtype()
+            },
             UpperBound | LowerBound => mapper.with_same_dtype(),
             #[cfg(feature = "fused")]
             Fused(_) => mapper.map_to_supertype(),
@@ -263,7 +294,7 @@ impl IRFunctionExpr {
                 ..
             } => {
                 let struct_dt = DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
+                    Field::new(PlSmallStr::from_static("breakpoint"), cut_breaks_dtype(fields)),
                     Field::new(
                         PlSmallStr::from_static("category"),
                         DataType::from_categories(Categories::global()),
@@ -322,7 +353,7 @@ impl IRFunctionExpr {
                 ..
             } => {
                 let struct_dt = DataType::Struct(vec![
-                    Field::new(PlSmallStr::from_static("breakpoint"), DataType::Float64),
+                    Field::new(PlSmallStr::from_static("breakpoint"), cut_breaks_dtype(fields)),
                     Field::new(
                         PlSmallStr::from_static("category"),
                         DataType::from_categories(Categories::global()),
@@ -339,9 +370,39 @@ impl IRFunctionExpr {
             }),
             #[cfg(feature = "rle")]
             RLEID => mapper.with_dtype(IDX_DTYPE),
+            #[cfg(feature = "to_dummies")]
+            ToDummies {
+                separator,
+                drop_first,
+                sparse,
+            } => mapper.try_map_field(|field| {
+                let dtype = polars_ops::prelude::enum_dummies_dtype(
+                    field.name(),
+                    field.dtype(),
+                    separator.as_deref().unwrap_or("_"),
+                    *drop_first,
+                    *sparse,
+                )?;
+                Ok(Field::new(field.name().clone(), dtype))
+            }),
+            #[cfg(feature = "category_encoding")]
+            CategoricalTargetEncode { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "category_encoding")]
+            FrequencyEncode { normalize } => mapper.with_dtype(if *normalize {
+                DataType::Float64
+            } else {
+                IDX_DTYPE
+            }),
+            #[cfg(feature = "bootstrap")]
+            Bootstrap { .. } => mapper.with_dtype(polars_ops::series::bootstrap_output_dtype()),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
             Random { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "unique_id")]
+            Uuid4 => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "unique_id")]
+            SnowflakeId { .. } => mapper.with_dtype(DataType::UInt64),
+            Placeholder { .. } => mapper.with_same_dtype(),
             SetSortedFlag(_) => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
@@ -385,7 +446,7 @@ impl IRFunctionExpr {
                     f
                 })
             },
-            MeanHorizontal { .. } => {
+            MeanHorizontal { .. } | QuantileHorizontal { .. } | StdHorizontal { .. } => {
                 mapper.map_to_supertype().map(|mut f| {
                     match f.dtype {
                         dt @ DataType::Float32 => { f.dtype = dt; },
@@ -502,6 +563,8 @@ impl<'a> FieldsMapper<'a> {
             #[cfg(feature = "dtype-time")]
             dt @ DataType::Time => dt.clone(),
             DataType::Float32 => DataType::Float32,
+            #[cfg(feature = "dtype-f16")]
+            DataType::Float16 => DataType::Float32,
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => DataType::Float64,
             DataType::Boolean => DataType::Float64,
@@ -529,6 +592,8 @@ impl<'a> FieldsMapper<'a> {
         self.map_dtype(|dt| {
             let should_coerce = match dt {
                 DataType::Float32 => false,
+                #[cfg(feature = "dtype-f16")]
+                DataType::Float16 => return DataType::Float32,
                 #[cfg(feature = "dtype-decimal")]
                 DataType::Decimal(..) => true,
                 DataType::Boolean => true,
@@ -104,6 +104,7 @@ pub enum IRStringFunction {
     SplitExact {
         n: usize,
         inclusive: bool,
+        rest: bool,
     },
     #[cfg(feature = "dtype-struct")]
     SplitN(usize),
@@ -138,6 +139,20 @@ pub enum IRStringFunction {
     },
     #[cfg(feature = "regex")]
     EscapeRegex,
+    #[cfg(feature = "log_parsing")]
+    ParseCommonLog,
+    #[cfg(feature = "log_parsing")]
+    ParseUserAgent,
+    #[cfg(feature = "string_pad")]
+    PadStartDisplay {
+        fill_char: char,
+        truncate: bool,
+    },
+    #[cfg(feature = "string_pad")]
+    PadEndDisplay {
+        fill_char: char,
+        truncate: bool,
+    },
 }
 
 impl IRStringFunction {
@@ -170,6 +185,14 @@ impl IRStringFunction {
             Normalize { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "string_reverse")]
             Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "log_parsing")]
+            ParseCommonLog => {
+                mapper.with_dtype(polars_ops::chunked_array::strings::common_log_dtype())
+            },
+            #[cfg(feature = "log_parsing")]
+            ParseUserAgent => {
+                mapper.with_dtype(polars_ops::chunked_array::strings::user_agent_dtype())
+            },
             #[cfg(feature = "temporal")]
             Strptime(dtype, options) => match dtype {
                 #[cfg(feature = "dtype-datetime")]
@@ -202,11 +225,22 @@ impl IRStringFunction {
             Uppercase | Lowercase | StripChars | StripCharsStart | StripCharsEnd | StripPrefix
             | StripSuffix | Slice | Head | Tail => mapper.with_same_dtype(),
             #[cfg(feature = "string_pad")]
-            PadStart { .. } | PadEnd { .. } | ZFill => mapper.with_same_dtype(),
+            PadStart { .. }
+            | PadEnd { .. }
+            | PadStartDisplay { .. }
+            | PadEndDisplay { .. }
+            | ZFill => mapper.with_same_dtype(),
             #[cfg(feature = "dtype-struct")]
-            SplitExact { n, .. } => mapper.with_dtype(DataType::Struct(
+            SplitExact { n, rest, .. } => mapper.with_dtype(DataType::Struct(
                 (0..n + 1)
-                    .map(|i| Field::new(format_pl_smallstr!("field_{i}"), DataType::String))
+                    .map(|i| {
+                        let name = if *rest && i == *n {
+                            PlSmallStr::from_static("rest")
+                        } else {
+                            format_pl_smallstr!("field_{i}")
+                        };
+                        Field::new(name, DataType::String)
+                    })
                     .collect(),
             )),
             #[cfg(feature = "dtype-struct")]
@@ -267,6 +301,8 @@ impl IRStringFunction {
             S::Normalize { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "string_reverse")]
             S::Reverse => FunctionOptions::elementwise(),
+            #[cfg(feature = "log_parsing")]
+            S::ParseCommonLog | S::ParseUserAgent => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
             S::Strptime(_, options) if options.format.is_some() => FunctionOptions::elementwise(),
             #[cfg(feature = "temporal")]
@@ -290,7 +326,11 @@ impl IRStringFunction {
             | S::Tail => FunctionOptions::elementwise(),
             S::Slice => FunctionOptions::elementwise(),
             #[cfg(feature = "string_pad")]
-            S::PadStart { .. } | S::PadEnd { .. } | S::ZFill => FunctionOptions::elementwise(),
+            S::PadStart { .. }
+            | S::PadEnd { .. }
+            | S::PadStartDisplay { .. }
+            | S::PadEndDisplay { .. }
+            | S::ZFill => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-struct")]
             S::SplitExact { .. } => FunctionOptions::elementwise(),
             #[cfg(feature = "dtype-struct")]
@@ -342,12 +382,20 @@ impl Display for IRStringFunction {
             PadEnd { .. } => "pad_end",
             #[cfg(feature = "string_pad")]
             PadStart { .. } => "pad_start",
+            #[cfg(feature = "string_pad")]
+            PadEndDisplay { .. } => "pad_end_display",
+            #[cfg(feature = "string_pad")]
+            PadStartDisplay { .. } => "pad_start_display",
             #[cfg(feature = "regex")]
             Replace { .. } => "replace",
             #[cfg(feature = "string_normalize")]
             Normalize { .. } => "normalize",
             #[cfg(feature = "string_reverse")]
             Reverse => "reverse",
+            #[cfg(feature = "log_parsing")]
+            ParseCommonLog => "parse_common_log",
+            #[cfg(feature = "log_parsing")]
+            ParseUserAgent => "parse_user_agent",
             #[cfg(feature = "string_encoding")]
             HexEncode => "hex_encode",
             #[cfg(feature = "binary_encoding")]
@@ -436,6 +484,20 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map_as_slice!(strings::pad_start, fill_char)
             },
             #[cfg(feature = "string_pad")]
+            PadEndDisplay {
+                fill_char,
+                truncate,
+            } => {
+                map_as_slice!(strings::pad_display, fill_char, false, truncate)
+            },
+            #[cfg(feature = "string_pad")]
+            PadStartDisplay {
+                fill_char,
+                truncate,
+            } => {
+                map_as_slice!(strings::pad_display, fill_char, true, truncate)
+            },
+            #[cfg(feature = "string_pad")]
             ZFill => {
                 map_as_slice!(strings::zfill)
             },
@@ -447,7 +509,9 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 map_as_slice!(strings::split, inclusive)
             },
             #[cfg(feature = "dtype-struct")]
-            SplitExact { n, inclusive } => map_as_slice!(strings::split_exact, n, inclusive),
+            SplitExact { n, inclusive, rest } => {
+                map_as_slice!(strings::split_exact, n, inclusive, rest)
+            },
             #[cfg(feature = "dtype-struct")]
             SplitN(n) => map_as_slice!(strings::splitn, n),
             #[cfg(feature = "concat_str")]
@@ -466,6 +530,10 @@ impl From<IRStringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Normalize { form } => map!(strings::normalize, form.clone()),
             #[cfg(feature = "string_reverse")]
             Reverse => map!(strings::reverse),
+            #[cfg(feature = "log_parsing")]
+            ParseCommonLog => map!(strings::parse_common_log),
+            #[cfg(feature = "log_parsing")]
+            ParseUserAgent => map!(strings::parse_user_agent),
             Uppercase => map!(uppercase),
             Lowercase => map!(lowercase),
             #[cfg(feature = "nightly")]
@@ -683,6 +751,29 @@ pub(super) fn pad_end(s: &[Column], fill_char: char) -> PolarsResult<Column> {
     Ok(ca.pad_end(length, fill_char).into_column())
 }
 
+#[cfg(feature = "string_pad")]
+pub(super) fn pad_display(
+    s: &[Column],
+    fill_char: char,
+    pad_start: bool,
+    truncate: bool,
+) -> PolarsResult<Column> {
+    let s1 = s[0].as_materialized_series();
+    let width = &s[1];
+    polars_ensure!(
+        s1.len() == 1 || width.len() == 1 || s1.len() == width.len(),
+        ShapeMismatch: "cannot pad with 'width' array of length {}", width.len()
+    );
+    let width = width.as_materialized_series().u64()?;
+    let ca = s1.str()?;
+    let out = if pad_start {
+        ca.pad_start_display(width, fill_char, truncate)?
+    } else {
+        ca.pad_end_display(width, fill_char, truncate)?
+    };
+    Ok(out.into_column())
+}
+
 #[cfg(feature = "string_pad")]
 pub(super) fn zfill(s: &[Column]) -> PolarsResult<Column> {
     let s1 = s[0].as_materialized_series();
@@ -795,11 +886,19 @@ pub(super) fn strptime(
 }
 
 #[cfg(feature = "dtype-struct")]
-pub(super) fn split_exact(s: &[Column], n: usize, inclusive: bool) -> PolarsResult<Column> {
+pub(super) fn split_exact(
+    s: &[Column],
+    n: usize,
+    inclusive: bool,
+    rest: bool,
+) -> PolarsResult<Column> {
     let ca = s[0].str()?;
     let by = s[1].str()?;
 
-    if inclusive {
+    if rest {
+        ca.split_exact_with_rest(by, n, inclusive)
+            .map(|ca| ca.into_column())
+    } else if inclusive {
         ca.split_exact_inclusive(by, n).map(|ca| ca.into_column())
     } else {
         ca.split_exact(by, n).map(|ca| ca.into_column())
@@ -1127,6 +1226,18 @@ pub(super) fn reverse(s: &Column) -> PolarsResult<Column> {
     Ok(ca.str_reverse().into_column())
 }
 
+#[cfg(feature = "log_parsing")]
+pub(super) fn parse_common_log(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_parse_common_log()?.into_column())
+}
+
+#[cfg(feature = "log_parsing")]
+pub(super) fn parse_user_agent(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.str_parse_user_agent()?.into_column())
+}
+
 #[cfg(feature = "string_to_integer")]
 pub(super) fn to_integer(
     s: &[Column],
@@ -45,6 +45,8 @@ impl From<IRTemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             TimeStamp(tu) => map!(datetime::timestamp, tu),
             #[cfg(feature = "timezones")]
             ConvertTimeZone(tz) => map!(datetime::convert_time_zone, &tz),
+            #[cfg(feature = "timezones")]
+            ConvertTimeZonePerRow => map_as_slice!(datetime::convert_time_zone_per_row),
             WithTimeUnit(tu) => map!(datetime::with_time_unit, tu),
             CastTimeUnit(tu) => map!(datetime::cast_time_unit, tu),
             Truncate => {
@@ -199,6 +201,19 @@ pub(super) fn combine(s: &[Column], tu: TimeUnit) -> PolarsResult<Column> {
 
     let duration = time.cast(&DataType::Duration(tu))?;
     let result_naive = datetime + duration;
+    #[cfg(feature = "timezones")]
+    if let Some(time_zones) = s.get(2) {
+        polars_ensure!(
+            tz.is_none(),
+            InvalidOperation: "cannot combine datetimes with time zone {} in a time zone column",
+            tz.unwrap()
+        );
+        return Ok(polars_ops::prelude::replace_time_zone_per_row(
+            result_naive?.datetime().unwrap(),
+            time_zones.cast(&DataType::String)?.str()?,
+        )?
+        .into_column());
+    }
     match tz {
         #[cfg(feature = "timezones")]
         Some(tz) => Ok(polars_ops::prelude::replace_time_zone(
@@ -0,0 +1,76 @@
+use super::*;
+use crate::map;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+pub enum IRUrlFunction {
+    Parse,
+    QueryParam(PlSmallStr),
+    Encode,
+    Decode,
+}
+
+impl IRUrlFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRUrlFunction::*;
+        match self {
+            Parse => mapper.with_dtype(polars_ops::chunked_array::strings::url_parse_dtype()),
+            QueryParam(_) | Encode | Decode => mapper.with_dtype(DataType::String),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        FunctionOptions::elementwise()
+    }
+}
+
+impl Display for IRUrlFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRUrlFunction::*;
+        let s = match self {
+            Parse => "parse",
+            QueryParam(_) => "query_param",
+            Encode => "encode",
+            Decode => "decode",
+        };
+        write!(f, "url.{s}")
+    }
+}
+
+impl From<IRUrlFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
+    fn from(func: IRUrlFunction) -> Self {
+        use IRUrlFunction::*;
+        match func {
+            Parse => map!(parse),
+            QueryParam(key) => map!(query_param, key.as_str()),
+            Encode => map!(encode),
+            Decode => map!(decode),
+        }
+    }
+}
+
+impl From<IRUrlFunction> for IRFunctionExpr {
+    fn from(func: IRUrlFunction) -> Self {
+        IRFunctionExpr::Url(func)
+    }
+}
+
+fn parse(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    ca.url_parse().map(|ca| ca.into_column())
+}
+
+fn query_param(s: &Column, key: &str) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.url_query_param(key).into_column())
+}
+
+fn encode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.url_encode().into_column())
+}
+
+fn decode(s: &Column) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(ca.url_decode().into_column())
+}
@@ -24,7 +24,7 @@ impl AExpr {
             Literal(v) => v.is_scalar(),
 
             Eval { variant, .. } => match variant {
-                EvalVariant::List => true,
+                EvalVariant::List | EvalVariant::ListAgg => true,
                 EvalVariant::Cumulative { min_samples: _ } => false,
             },
 
@@ -2,6 +2,8 @@
 use polars_core::chunked_array::arithmetic::{
     _get_decimal_scale_add_sub, _get_decimal_scale_div, _get_decimal_scale_mul,
 };
+use polars_core::config::{ArithmeticOverflow, arithmetic_overflow};
+use polars_core::series::arithmetic::promoted_integer_dtype;
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
 
@@ -168,6 +170,16 @@ impl AExpr {
                         Field::new(out_name.clone(), Boolean)
                     },
                     Operator::TrueDivide => get_truediv_field(*left, *right, ctx)?,
+                    Operator::Plus | Operator::Minus | Operator::Multiply => {
+                        let mut field = get_arithmetic_field(*left, *right, *op, ctx)?;
+                        // Series arithmetic computes integers in a wider dtype in this mode.
+                        if arithmetic_overflow() == ArithmeticOverflow::Promote {
+                            if let Some(dtype) = promoted_integer_dtype(field.dtype()) {
+                                field.coerce(dtype);
+                            }
+                        }
+                        field
+                    },
                     _ => get_arithmetic_field(*left, *right, *op, ctx)?,
                 };
 
@@ -199,6 +211,8 @@ impl AExpr {
                         let dt = match field.dtype() {
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-f16")]
+                            Float16 => Some(Float32),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -324,7 +338,7 @@ impl AExpr {
 
                 output_field.dtype = match variant {
                     EvalVariant::List => DataType::List(Box::new(output_field.dtype)),
-                    EvalVariant::Cumulative { .. } => output_field.dtype,
+                    EvalVariant::ListAgg | EvalVariant::Cumulative { .. } => output_field.dtype,
                 };
                 output_field.name = field.name;
 
@@ -83,6 +83,8 @@ fn function_input_wildcard_expansion(function: &FunctionExpr) -> FunctionExpansi
             | F::ReduceHorizontal { .. }
             | F::SumHorizontal { .. }
             | F::MeanHorizontal { .. }
+            | F::QuantileHorizontal { .. }
+            | F::StdHorizontal { .. }
     );
     let mut allow_empty_inputs = matches!(
         function,
@@ -113,6 +115,14 @@ fn function_input_wildcard_expansion(function: &FunctionExpr) -> FunctionExpansi
             F::StringExpr(StringFunction::ConcatHorizontal { .. })
         );
     }
+    #[cfg(feature = "is_first_distinct")]
+    {
+        expand_into_inputs |= matches!(function, F::Boolean(BooleanFunction::IsFirstDistinctRows));
+    }
+    #[cfg(feature = "is_last_distinct")]
+    {
+        expand_into_inputs |= matches!(function, F::Boolean(BooleanFunction::IsLastDistinctRows));
+    }
 
     FunctionExpansionFlags {
         expand_into_input: expand_into_inputs,
@@ -724,6 +734,16 @@ fn expand_expression_rec(
                 |e| Expr::KeepName(Arc::new(e)),
             )?
         },
+        Expr::Cached(expr) => {
+            _ = expand_single(
+                expr.as_ref(),
+                ignored_selector_columns,
+                schema,
+                out,
+                opt_flags,
+                |e| Expr::Cached(Arc::new(e)),
+            )?
+        },
         Expr::Len => out.push(Expr::Len),
         Expr::AnonymousFunction {
             input,
@@ -380,6 +380,8 @@ pub(super) fn to_aexpr_impl(
             order_by,
             options,
         } => {
+            #[cfg(feature = "random")]
+            let function = seed_random_by_partition(function, &partition_by)?;
             let (function, output_name) = recurse_arc!(function)?;
             let order_by = if let Some((e, options)) = order_by {
                 Some((recurse_arc!(e)?.0, options))
@@ -452,6 +454,12 @@ pub(super) fn to_aexpr_impl(
 
             match variant {
                 EvalVariant::List => {},
+                EvalVariant::ListAgg => {
+                    polars_ensure!(
+                        is_scalar_ae(evaluation, ctx.arena),
+                        InvalidOperation: "`list.agg` is not allowed with non-scalar output, use `list.eval` instead"
+                    )
+                },
                 EvalVariant::Cumulative { .. } => {
                     polars_ensure!(
                         is_scalar_ae(evaluation, ctx.arena),
@@ -521,9 +529,62 @@ pub(super) fn to_aexpr_impl(
             )
         },
 
+        Expr::Cached(_) => polars_bail!(
+            InvalidOperation:
+            "'cached' is only allowed in 'select', 'with_columns', 'filter' and 'group_by().agg'"
+        ),
         e @ Expr::SubPlan { .. } | e @ Expr::Selector(_) => {
             polars_bail!(InvalidOperation: "'Expr: {}' not allowed in this context/location", e)
         },
     };
     Ok((ctx.arena.add(v), output_name))
 }
+
+/// Append the partition keys as inputs to the seeded random functions of a window, so that every
+/// group derives its own seed from its key instead of all groups sharing the same seed.
+#[cfg(feature = "random")]
+fn seed_random_by_partition(function: Arc<Expr>, partition_by: &[Expr]) -> PolarsResult<Arc<Expr>> {
+    use crate::plans::visitor::{RewriteRecursion, RewritingVisitor, TreeWalker};
+
+    fn is_seeded_random(e: &Expr) -> bool {
+        matches!(
+            e,
+            Expr::Function { input, function: FunctionExpr::Random { method, seed: Some(_) } }
+                if input.len() == method.n_inputs()
+        )
+    }
+
+    struct SeedByPartition<'a> {
+        partition_by: &'a [Expr],
+    }
+
+    impl RewritingVisitor for SeedByPartition<'_> {
+        type Node = Expr;
+        type Arena = ();
+
+        fn pre_visit(&mut self, node: &Expr, _arena: &mut ()) -> PolarsResult<RewriteRecursion> {
+            Ok(match node {
+                // Nested windows are seeded by their own partition.
+                Expr::Window { .. } => RewriteRecursion::Stop,
+                _ => RewriteRecursion::MutateAndContinue,
+            })
+        }
+
+        fn mutate(&mut self, mut node: Expr, _arena: &mut ()) -> PolarsResult<Expr> {
+            if is_seeded_random(&node) {
+                let Expr::Function { input, .. } = &mut node else {
+                    unreachable!()
+                };
+                input.extend(self.partition_by.iter().cloned());
+            }
+            Ok(node)
+        }
+    }
+
+    if partition_by.is_empty() || !function.into_iter().any(is_seeded_random) {
+        return Ok(function);
+    }
+    Arc::unwrap_or_clone(function)
+        .rewrite(&mut SeedByPartition { partition_by }, &mut ())
+        .map(Arc::new)
+}
@@ -144,6 +144,8 @@ pub(super) fn convert_functions(
                 L::Contains { nulls_equal } => IL::Contains { nulls_equal },
                 #[cfg(feature = "list_drop_nulls")]
                 L::DropNulls => IL::DropNulls,
+                #[cfg(feature = "list_filter")]
+                L::Filter => IL::Filter,
                 #[cfg(feature = "list_sample")]
                 L::Sample {
                     is_fraction,
@@ -248,10 +250,30 @@ pub(super) fn convert_functions(
                 S::Normalize { form } => IS::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 S::Reverse => IS::Reverse,
+                #[cfg(feature = "log_parsing")]
+                S::ParseCommonLog => IS::ParseCommonLog,
+                #[cfg(feature = "log_parsing")]
+                S::ParseUserAgent => IS::ParseUserAgent,
                 #[cfg(feature = "string_pad")]
                 S::PadStart { fill_char } => IS::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
                 S::PadEnd { fill_char } => IS::PadEnd { fill_char },
+                #[cfg(feature = "string_pad")]
+                S::PadStartDisplay {
+                    fill_char,
+                    truncate,
+                } => IS::PadStartDisplay {
+                    fill_char,
+                    truncate,
+                },
+                #[cfg(feature = "string_pad")]
+                S::PadEndDisplay {
+                    fill_char,
+                    truncate,
+                } => IS::PadEndDisplay {
+                    fill_char,
+                    truncate,
+                },
                 S::Slice => IS::Slice,
                 S::Head => IS::Head,
                 S::Tail => IS::Tail,
@@ -270,7 +292,7 @@ pub(super) fn convert_functions(
                 S::StripPrefix => IS::StripPrefix,
                 S::StripSuffix => IS::StripSuffix,
                 #[cfg(feature = "dtype-struct")]
-                S::SplitExact { n, inclusive } => IS::SplitExact { n, inclusive },
+                S::SplitExact { n, inclusive, rest } => IS::SplitExact { n, inclusive, rest },
                 #[cfg(feature = "dtype-struct")]
                 S::SplitN(v) => IS::SplitN(v),
                 #[cfg(feature = "temporal")]
@@ -389,6 +411,8 @@ pub(super) fn convert_functions(
                 T::WithTimeUnit(time_unit) => IT::WithTimeUnit(time_unit),
                 #[cfg(feature = "timezones")]
                 T::ConvertTimeZone(time_zone) => IT::ConvertTimeZone(time_zone),
+                #[cfg(feature = "timezones")]
+                T::ConvertTimeZonePerRow => IT::ConvertTimeZonePerRow,
                 T::TimeStamp(time_unit) => IT::TimeStamp(time_unit),
                 T::Truncate => IT::Truncate,
                 #[cfg(feature = "offset_by")]
@@ -429,6 +453,24 @@ pub(super) fn convert_functions(
             BitwiseFunction::Or => IRBitwiseFunction::Or,
             BitwiseFunction::Xor => IRBitwiseFunction::Xor,
         }),
+        #[cfg(feature = "anonymize")]
+        F::Anonymize(anonymize_function) => {
+            use {AnonymizeFunction as A, IRAnonymizeFunction as IA};
+            I::Anonymize(match anonymize_function {
+                A::HashStable { salts } => IA::HashStable { salts },
+                A::SuppressRare { k } => IA::SuppressRare { k },
+            })
+        },
+        #[cfg(feature = "url")]
+        F::Url(url_function) => {
+            use {IRUrlFunction as IU, UrlFunction as U};
+            I::Url(match url_function {
+                U::Parse => IU::Parse,
+                U::QueryParam(key) => IU::QueryParam(key),
+                U::Encode => IU::Encode,
+                U::Decode => IU::Decode,
+            })
+        },
         F::Boolean(boolean_function) => {
             use {BooleanFunction as B, IRBooleanFunction as IB};
             I::Boolean(match boolean_function {
@@ -441,9 +483,9 @@ pub(super) fn convert_functions(
                 B::IsNan => IB::IsNan,
                 B::IsNotNan => IB::IsNotNan,
                 #[cfg(feature = "is_first_distinct")]
-                B::IsFirstDistinct => IB::IsFirstDistinct,
+                B::IsFirstDistinct | B::IsFirstDistinctRows => IB::IsFirstDistinct,
                 #[cfg(feature = "is_last_distinct")]
-                B::IsLastDistinct => IB::IsLastDistinct,
+                B::IsLastDistinct | B::IsLastDistinctRows => IB::IsLastDistinct,
                 #[cfg(feature = "is_unique")]
                 B::IsUnique => IB::IsUnique,
                 #[cfg(feature = "is_unique")]
@@ -553,6 +595,8 @@ pub(super) fn convert_functions(
         #[cfg(feature = "abs")]
         F::Abs => I::Abs,
         F::Negate => I::Negate,
+        F::CheckedArithmetic(op) => I::CheckedArithmetic(op),
+        F::DivOr(op) => I::DivOr(op),
         #[cfg(feature = "hist")]
         F::Hist {
             bin_count,
@@ -739,6 +783,7 @@ pub(super) fn convert_functions(
         },
         F::Shift => I::Shift,
         F::DropNans => I::DropNans,
+        F::NanToNull => I::NanToNull,
         F::DropNulls => I::DropNulls,
         #[cfg(feature = "mode")]
         F::Mode => I::Mode,
@@ -816,6 +861,10 @@ pub(super) fn convert_functions(
         F::Interpolate(interpolation_method) => I::Interpolate(interpolation_method),
         #[cfg(feature = "interpolate_by")]
         F::InterpolateBy => I::InterpolateBy,
+        #[cfg(feature = "downsample")]
+        F::DownsampleLttb { n_out } => I::DownsampleLttb { n_out },
+        #[cfg(feature = "downsample")]
+        F::DownsampleMinMax { n_buckets } => I::DownsampleMinMax { n_buckets },
         #[cfg(feature = "log")]
         F::Entropy { base, normalize } => I::Entropy { base, normalize },
         #[cfg(feature = "log")]
@@ -830,6 +879,8 @@ pub(super) fn convert_functions(
         #[cfg(feature = "round_series")]
         F::RoundSF { digits } => I::RoundSF { digits },
         #[cfg(feature = "round_series")]
+        F::RoundToIncrement { increment, mode } => I::RoundToIncrement { increment, mode },
+        #[cfg(feature = "round_series")]
         F::Floor => I::Floor,
         #[cfg(feature = "round_series")]
         F::Ceil => I::Ceil,
@@ -858,11 +909,13 @@ pub(super) fn convert_functions(
             labels,
             left_closed,
             include_breaks,
+            missing_label,
         } => I::Cut {
             breaks,
             labels,
             left_closed,
             include_breaks,
+            missing_label,
         },
         #[cfg(feature = "cutqcut")]
         F::QCut {
@@ -871,17 +924,55 @@ pub(super) fn convert_functions(
             left_closed,
             allow_duplicates,
             include_breaks,
+            missing_label,
         } => I::QCut {
             probs,
             labels,
             left_closed,
             allow_duplicates,
             include_breaks,
+            missing_label,
         },
         #[cfg(feature = "rle")]
         F::RLE => I::RLE,
         #[cfg(feature = "rle")]
         F::RLEID => I::RLEID,
+        #[cfg(feature = "to_dummies")]
+        F::ToDummies {
+            separator,
+            drop_first,
+            sparse,
+        } => I::ToDummies {
+            separator,
+            drop_first,
+            sparse,
+        },
+        #[cfg(feature = "category_encoding")]
+        F::CategoricalTargetEncode {
+            folds,
+            smoothing,
+            seed,
+        } => I::CategoricalTargetEncode {
+            folds,
+            smoothing,
+            seed,
+        },
+        #[cfg(feature = "category_encoding")]
+        F::FrequencyEncode { normalize } => I::FrequencyEncode { normalize },
+        #[cfg(feature = "bootstrap")]
+        F::Bootstrap {
+            n_samples,
+            statistic,
+            method,
+            confidence_level,
+            seed,
+        } => I::Bootstrap {
+            n_samples,
+            statistic,
+            method,
+            confidence_level,
+            seed,
+        },
         F::ToPhysical => I::ToPhysical,
         #[cfg(feature = "random")]
         F::Random { method, seed } => {
@@ -902,6 +993,11 @@ pub(super) fn convert_functions(
                 seed,
             }
         },
+        #[cfg(feature = "unique_id")]
+        F::Uuid4 => I::Uuid4,
+        #[cfg(feature = "unique_id")]
+        F::SnowflakeId { worker_id } => I::SnowflakeId { worker_id },
+        F::Placeholder { name } => I::Placeholder { name },
         F::SetSortedFlag(is_sorted) => I::SetSortedFlag(is_sorted),
         #[cfg(feature = "ffi_plugin")]
         F::FfiPlugin {
@@ -961,6 +1057,8 @@ pub(super) fn convert_functions(
         F::MinHorizontal => I::MinHorizontal,
         F::SumHorizontal { ignore_nulls } => I::SumHorizontal { ignore_nulls },
         F::MeanHorizontal { ignore_nulls } => I::MeanHorizontal { ignore_nulls },
+        F::QuantileHorizontal { quantile, method } => I::QuantileHorizontal { quantile, method },
+        F::StdHorizontal { ddof } => I::StdHorizontal { ddof },
         #[cfg(feature = "ewma")]
         F::EwmMean { options } => I::EwmMean { options },
         #[cfg(feature = "ewma_by")]
@@ -0,0 +1,263 @@
+//! Narrowing of the glob patterns of hive partitioned scans with the partition values required by
+//! a filter, so that the files of other partitions are not listed.
+use polars_utils::plpath::PlPath;
+
+use super::*;
+
+/// Collect the `(column, value)` pairs that `predicate` requires, i.e. equalities of a column
+/// and a string or integer literal that are combined with `and`.
+fn required_values(predicate: &Expr, out: &mut Vec<(PlSmallStr, String)>) {
+    match predicate {
+        Expr::BinaryExpr {
+            left,
+            op: Operator::And | Operator::LogicalAnd,
+            right,
+        } => {
+            required_values(left, out);
+            required_values(right, out);
+        },
+        Expr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::AllHorizontal),
+        } => {
+            for e in input {
+                required_values(e, out);
+            }
+        },
+        Expr::BinaryExpr {
+            left,
+            op: Operator::Eq,
+            right,
+        } => {
+            let (name, lv) = match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(name), Expr::Literal(lv))
+                | (Expr::Literal(lv), Expr::Column(name)) => (name, lv),
+                _ => return,
+            };
+
+            let value = if let Some(s) = lv.extract_str() {
+                s.to_string()
+            } else if let Some(av) = lv.to_any_value().filter(|av| av.dtype().is_integer()) {
+                av.to_string()
+            } else {
+                return;
+            };
+
+            // Values with other characters are escaped in the paths, or would be read as globs.
+            if !value.is_empty()
+                && value
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b"-_.".contains(&b))
+            {
+                out.push((name.clone(), value));
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Replace the `{column}=*` directories of `pattern` with the required values. Returns `None` if
+/// the pattern doesn't change, or if it would no longer be a glob, as that changes from where the
+/// hive partitions are parsed.
+fn narrow_pattern(pattern: &str, values: &[(PlSmallStr, String)]) -> Option<String> {
+    let (dirs, file) = pattern.rsplit_once('/')?;
+    let mut changed = false;
+    let dirs = dirs
+        .split('/')
+        .map(|dir| {
+            let value = dir
+                .strip_suffix("=*")
+                .and_then(|key| values.iter().find(|(name, _)| name == key));
+            match value {
+                Some((name, value)) => {
+                    changed = true;
+                    format!("{name}={value}")
+                },
+                None => dir.to_string(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let out = format!("{}/{file}", dirs.join("/"));
+    (changed && polars_io::path_utils::get_glob_start_idx(out.as_bytes()).is_some()).then_some(out)
+}
+
+/// The names of the `{column}=*` directories of `pattern`, of which the hive partition values are
+/// inferred from the listed paths.
+fn glob_partitions(pattern: &str) -> impl Iterator<Item = &str> {
+    let dirs = pattern.rsplit_once('/').map_or("", |(dirs, _)| dirs);
+    dirs.split('/').filter_map(|dir| dir.strip_suffix("=*"))
+}
+
+/// Narrow the glob patterns of a hive partitioned scan directly below a filter with the partition
+/// values that the filter requires, e.g. `s3://bucket/year=*/*.parquet` becomes
+/// `s3://bucket/year=2024/*.parquet` for `col("year") == 2024`. The filter is still applied to the
+/// scan.
+///
+/// Integer partition values can be written in other forms (e.g. `month=01`), so the original
+/// patterns are used if the narrowed patterns don't match any file. The narrowed scans are kept in
+/// the `cached_ir` of the original scan, so that repeatedly collecting the same `LazyFrame`
+/// doesn't list the files again.
+pub(super) fn narrow_hive_scan(
+    input: Arc<DslPlan>,
+    predicate: &Expr,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<Arc<DslPlan>> {
+    let DslPlan::Scan {
+        sources: ScanSources::Paths(paths),
+        unified_scan_args,
+        scan_type,
+        cached_ir,
+    } = input.as_ref()
+    else {
+        return Ok(input);
+    };
+
+    let is_hive_file_scan = match scan_type.as_ref() {
+        #[cfg(feature = "parquet")]
+        FileScanDsl::Parquet { .. } => true,
+        #[cfg(feature = "ipc")]
+        FileScanDsl::Ipc { .. } => true,
+        _ => false,
+    };
+
+    // Table statistics and deletion files are given per file of the original patterns, and the row
+    // index and slice are applied before the filter, so they count the rows of all files.
+    if !is_hive_file_scan
+        || !ctxt.opt_flags.predicate_pushdown()
+        || !unified_scan_args.glob
+        || unified_scan_args.hive_options.enabled != Some(true)
+        || unified_scan_args.table_statistics.is_some()
+        || unified_scan_args.deletion_files.is_some()
+        || unified_scan_args.row_index.is_some()
+        || unified_scan_args.pre_slice.is_some()
+    {
+        return Ok(input);
+    }
+
+    // Only the files of the narrowed patterns are listed, so the schemas must be given instead of
+    // inferred from the first file and the partition values of the paths.
+    let hive_schema = unified_scan_args.hive_options.schema.as_deref();
+    let schemas_given = unified_scan_args.schema.is_some()
+        && paths.iter().all(|path| {
+            glob_partitions(path.to_str())
+                .all(|name| hive_schema.is_some_and(|schema| schema.contains(name)))
+        });
+    if !schemas_given {
+        return Ok(input);
+    }
+
+    let mut values = vec![];
+    required_values(predicate, &mut values);
+    if values.is_empty() {
+        return Ok(input);
+    }
+
+    let mut changed = false;
+    let patterns = paths
+        .iter()
+        .map(|path| match narrow_pattern(path.to_str(), &values) {
+            Some(pattern) => {
+                changed = true;
+                PlPath::new(&pattern)
+            },
+            None => path.clone(),
+        })
+        .collect::<Vec<_>>();
+    if !changed {
+        return Ok(input);
+    }
+
+    let mut cached = cached_ir.lock().unwrap();
+    let scan = match cached.narrowed.get(&patterns) {
+        Some(scan) => scan.clone(),
+        None => {
+            let mut narrowed_scan_args = unified_scan_args.clone();
+            let sources = ScanSources::Paths(patterns.clone().into())
+                .expand_paths_with_hive_update(
+                    &mut narrowed_scan_args,
+                    unified_scan_args.cloud_options.as_ref(),
+                )?;
+
+            if ctxt.verbose {
+                eprintln!(
+                    "narrowed hive partitioned scan with filter: {} patterns matched {} files",
+                    patterns.len(),
+                    sources.len()
+                );
+            }
+
+            // The sources are already expanded.
+            narrowed_scan_args.glob = false;
+            let scan = (!sources.is_empty()).then(|| {
+                Arc::new(DslPlan::Scan {
+                    sources,
+                    unified_scan_args: narrowed_scan_args,
+                    scan_type: scan_type.clone(),
+                    cached_ir: Default::default(),
+                })
+            });
+            cached.narrowed.insert(patterns, scan.clone());
+            scan
+        },
+    };
+    drop(cached);
+
+    Ok(scan.unwrap_or(input))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_narrow_pattern() {
+        let values = [("year".into(), "2024".to_string())];
+
+        assert_eq!(
+            narrow_pattern("s3://bucket/year=*/month=*/*.parquet", &values).as_deref(),
+            Some("s3://bucket/year=2024/month=*/*.parquet")
+        );
+        // The file name is not a partition.
+        assert_eq!(narrow_pattern("s3://bucket/data/year=*", &values), None);
+        // It would no longer be a glob.
+        assert_eq!(
+            narrow_pattern("s3://bucket/year=*/a.parquet", &values),
+            None
+        );
+        assert_eq!(
+            narrow_pattern("s3://bucket/month=*/*.parquet", &values),
+            None
+        );
+    }
+
+    #[test]
+    fn test_glob_partitions() {
+        let names = glob_partitions("s3://bucket/year=*/region=eu/month=*/*.parquet");
+        assert_eq!(names.collect::<Vec<_>>(), ["year", "month"]);
+    }
+
+    #[test]
+    fn test_required_values() {
+        let predicate = col("year")
+            .eq(lit(2024))
+            .and(lit("eu").eq(col("region")))
+            .and(col("month").gt(lit(6)))
+            .and(col("name").eq(lit("a/b")));
+
+        let mut values = vec![];
+        required_values(&predicate, &mut values);
+        assert_eq!(
+            values,
+            [
+                ("year".into(), "2024".to_string()),
+                ("region".into(), "eu".to_string())
+            ]
+        );
+
+        // Alternatives are not required.
+        let mut values = vec![];
+        required_values(&col("year").eq(lit(2024)).or(col("a")), &mut values);
+        assert!(values.is_empty());
+    }
+}
@@ -2,7 +2,9 @@ use arrow::legacy::error::PolarsResult;
 use either::Either;
 use polars_core::chunked_array::cast::CastOptions;
 use polars_core::error::feature_gated;
-use polars_core::utils::{get_numeric_upcast_supertype_lossless, try_get_supertype};
+use polars_core::utils::{
+    get_numeric_upcast_supertype_lossless, get_upcast_supertype_lossless, try_get_supertype,
+};
 use polars_utils::format_pl_smallstr;
 use polars_utils::itertools::Itertools;
 
@@ -271,7 +273,7 @@ pub fn resolve_join(
         let ltype = get_dtype!(lnode, &schema_left)?;
         let rtype = get_dtype!(rnode, &schema_right)?;
 
-        if let Some(dtype) = get_numeric_upcast_supertype_lossless(&ltype, &rtype) {
+        if let Some(dtype) = get_upcast_supertype_lossless(&ltype, &rtype) {
             // We use overflowing cast to allow better optimization as we are casting to a known
             // lossless supertype.
             //
@@ -318,6 +320,29 @@ pub fn resolve_join(
         }
     }
 
+    // NaN keys that must not match are turned into null keys, which never match as long as
+    // `nulls_equal` is not set. The joined columns themselves keep their NaN values.
+    let how = &options.args.how;
+    let nans_equal = options
+        .args
+        .nans_equal
+        .unwrap_or_else(polars_core::config::join_nans_equal);
+    if !nans_equal && (how.is_equi() || how.is_semi_anti()) {
+        polars_ensure!(
+            !options.args.nulls_equal,
+            InvalidOperation: "'nans_equal=False' cannot be combined with 'nulls_equal=True', as NaN keys would match null keys"
+        );
+        for (lnode, rnode) in left_on.iter_mut().zip(right_on.iter_mut()) {
+            let dtype = get_dtype!(lnode, &schema_left)?;
+            if matches!(dtype, DataType::Float32 | DataType::Float64) {
+                let l = AExprBuilder::new_from_node(lnode.node()).nan_to_null(ctxt.expr_arena);
+                let r = AExprBuilder::new_from_node(rnode.node()).nan_to_null(ctxt.expr_arena);
+                lnode.set_node(l.node());
+                rnode.set_node(r.node());
+            }
+        }
+    }
+
     // Every expression must be elementwise so that we are
     // guaranteed the keys for a join are all the same length.
 
@@ -0,0 +1,81 @@
+//! Resolve the number of fields of the `list.to_struct` expressions that infer it from a sample of
+//! the input, see [`ListToStruct::InferWidth`], so that the schema of the node is known.
+use std::sync::OnceLock;
+
+use super::*;
+
+/// Collects a plan. This is for `polars-lazy` to inject, as plans can't be executed here.
+pub static LIST_TO_STRUCT_SAMPLER: OnceLock<fn(DslPlan) -> PolarsResult<DataFrame>> =
+    OnceLock::new();
+
+fn is_sampled(e: &Expr) -> bool {
+    matches!(
+        e,
+        Expr::Function {
+            function: FunctionExpr::ListExpr(ListFunction::ToStruct(ListToStruct::InferWidth {
+                max_fields: None,
+                infer_sample: Some(_),
+                ..
+            })),
+            ..
+        }
+    )
+}
+
+pub(super) fn has_sampled_width(exprs: &[Expr]) -> bool {
+    exprs.iter().any(|e| has_expr(e, is_sampled))
+}
+
+/// Set `max_fields` of the sampled `list.to_struct` expressions in `exprs` by collecting the first
+/// rows of `input`. If no fields can be inferred from the sample, the number of fields stays
+/// unknown until the query runs.
+pub(super) fn infer_widths(exprs: Vec<Expr>, input: &DslPlan) -> PolarsResult<Vec<Expr>> {
+    let infer = |e: Expr| match e {
+        Expr::Function {
+            input: fn_input,
+            function:
+                FunctionExpr::ListExpr(ListFunction::ToStruct(ListToStruct::InferWidth {
+                    infer_field_strategy,
+                    get_index_name,
+                    max_fields: None,
+                    name_format,
+                    error_on_overflow,
+                    infer_sample: Some(n),
+                })),
+        } => {
+            let args = |max_fields, error_on_overflow| ListToStruct::InferWidth {
+                infer_field_strategy: infer_field_strategy.clone(),
+                get_index_name: get_index_name.clone(),
+                max_fields,
+                name_format: name_format.clone(),
+                error_on_overflow,
+                infer_sample: None,
+            };
+            let collect = LIST_TO_STRUCT_SAMPLER.get().ok_or_else(
+                || polars_err!(ComputeError: "no engine to sample the input of 'list.to_struct'"),
+            )?;
+            let sample = DslBuilder::from(input.clone())
+                .slice(0, n.try_into().unwrap_or(IdxSize::MAX))
+                .project(
+                    vec![fn_input[0].clone().list().to_struct(args(None, false))],
+                    Default::default(),
+                )
+                .build();
+            let df = collect(sample)
+                .map_err(|e| e.context("failed to sample the input of 'list.to_struct'".into()))?;
+            let max_fields = match df.get_columns()[0].dtype() {
+                DataType::Struct(fields) if !fields.is_empty() => Some(fields.len()),
+                _ => None,
+            };
+            Ok(Expr::Function {
+                input: fn_input,
+                function: FunctionExpr::ListExpr(ListFunction::ToStruct(args(
+                    max_fields,
+                    error_on_overflow,
+                ))),
+            })
+        },
+        e => Ok(e),
+    };
+    exprs.into_iter().map(|e| e.try_map_expr(&infer)).collect()
+}
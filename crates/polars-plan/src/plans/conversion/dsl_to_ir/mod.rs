@@ -15,12 +15,19 @@ mod concat;
 mod expr_expansion;
 mod expr_to_ir;
 mod functions;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+mod hive_listing;
 mod join;
+#[cfg(feature = "list_to_struct")]
+mod list_to_struct;
+mod scalar_subquery;
 mod scans;
 mod utils;
 pub use expr_expansion::{is_regex_projection, prepare_projection};
 pub use expr_to_ir::{ExprToIRContext, to_expr_ir};
 use expr_to_ir::{to_expr_ir_materialized_lit, to_expr_irs};
+#[cfg(feature = "list_to_struct")]
+pub use list_to_struct::LIST_TO_STRUCT_SAMPLER;
 use utils::DslConversionContext;
 
 macro_rules! failed_here {
@@ -86,6 +93,27 @@ pub fn to_alp(
     }
 }
 
+#[cfg(feature = "python")]
+fn python_scan_to_ir(
+    mut options: crate::dsl::python_dsl::PythonOptionsDsl,
+    with_columns: Option<&[PlSmallStr]>,
+) -> PolarsResult<IR> {
+    let scan_fn = options.scan_fn.take();
+    let schema = options.get_schema(with_columns)?;
+    Ok(IR::PythonScan {
+        options: PythonOptions {
+            scan_fn,
+            schema,
+            python_source: options.python_source,
+            validate_schema: options.validate_schema,
+            output_schema: Default::default(),
+            with_columns: Default::default(),
+            n_rows: Default::default(),
+            predicate: Default::default(),
+        },
+    })
+}
+
 fn run_conversion(lp: IR, ctxt: &mut DslConversionContext, name: &str) -> PolarsResult<Node> {
     let lp_node = ctxt.lp_arena.add(lp);
     ctxt.conversion_optimizer
@@ -110,22 +138,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             cached_ir,
         } => scans::dsl_to_ir(sources, unified_scan_args, scan_type, cached_ir, ctxt)?,
         #[cfg(feature = "python")]
-        DslPlan::PythonScan { mut options } => {
-            let scan_fn = options.scan_fn.take();
-            let schema = options.get_schema()?;
-            IR::PythonScan {
-                options: PythonOptions {
-                    scan_fn,
-                    schema,
-                    python_source: options.python_source,
-                    validate_schema: options.validate_schema,
-                    output_schema: Default::default(),
-                    with_columns: Default::default(),
-                    n_rows: Default::default(),
-                    predicate: Default::default(),
-                },
-            }
-        },
+        DslPlan::PythonScan { options } => python_scan_to_ir(options, None)?,
         DslPlan::Union { inputs, args } => {
             let mut inputs = inputs
                 .into_iter()
@@ -177,7 +190,31 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 options,
             }
         },
+        DslPlan::Filter { input, predicate }
+            if has_expr(&predicate, |e| matches!(e, Expr::Cached(_))) =>
+        {
+            let (mut predicate, input, tmp_names) =
+                scalar_subquery::cached_to_columns(vec![predicate], input)
+                    .map_err(|e| e.context(failed_here!(filter)))?;
+            let lp = DslBuilder::from(Arc::unwrap_or_clone(input))
+                .filter(predicate.pop().unwrap())
+                .drop(by_name(tmp_names, true))
+                .build();
+            return to_alp_impl(lp, ctxt);
+        },
+        #[cfg(feature = "list_to_struct")]
+        DslPlan::Filter { input, predicate }
+            if list_to_struct::has_sampled_width(std::slice::from_ref(&predicate)) =>
+        {
+            let predicate = list_to_struct::infer_widths(vec![predicate], &input)
+                .map_err(|e| e.context(failed_here!(filter)))?
+                .pop()
+                .unwrap();
+            return to_alp_impl(DslPlan::Filter { input, predicate }, ctxt);
+        },
         DslPlan::Filter { input, predicate } => {
+            #[cfg(any(feature = "parquet", feature = "ipc"))]
+            let input = hive_listing::narrow_hive_scan(input, &predicate, ctxt)?;
             let mut input =
                 to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(filter)))?;
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
@@ -287,13 +324,54 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             schema,
             output_schema: None,
         },
+        DslPlan::Select {
+            expr,
+            input,
+            options,
+        } if expr
+            .iter()
+            .any(|e| has_expr(e, |e| matches!(e, Expr::Cached(_)))) =>
+        {
+            let (expr, input, _) = scalar_subquery::cached_to_columns(expr, input)
+                .map_err(|e| e.context(failed_here!(select)))?;
+            let lp = DslPlan::Select {
+                expr,
+                input,
+                options,
+            };
+            return to_alp_impl(lp, ctxt);
+        },
+        #[cfg(feature = "list_to_struct")]
+        DslPlan::Select {
+            expr,
+            input,
+            options,
+        } if list_to_struct::has_sampled_width(&expr) => {
+            let expr = list_to_struct::infer_widths(expr, &input)
+                .map_err(|e| e.context(failed_here!(select)))?;
+            let lp = DslPlan::Select {
+                expr,
+                input,
+                options,
+            };
+            return to_alp_impl(lp, ctxt);
+        },
         DslPlan::Select {
             expr,
             input,
             options,
         } => {
-            let input =
-                to_alp_impl(owned(input), ctxt).map_err(|e| e.context(failed_here!(select)))?;
+            let input = match owned(input) {
+                // Only request the selected columns from the schema function of Python sources.
+                #[cfg(feature = "python")]
+                DslPlan::PythonScan { options } => {
+                    let with_columns = utils::projected_column_names(&expr);
+                    let ir = python_scan_to_ir(options, with_columns.as_deref())
+                        .map_err(|e| e.context(failed_here!(select)))?;
+                    ctxt.lp_arena.add(ir)
+                },
+                input => to_alp_impl(input, ctxt).map_err(|e| e.context(failed_here!(select)))?,
+            };
             let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
             let (exprs, schema) = prepare_projection(expr, &input_schema, ctxt.opt_flags)
                 .map_err(|e| e.context(failed_here!(select)))?;
@@ -443,6 +521,50 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 cache_hits: crate::constants::UNLIMITED_CACHE,
             }
         },
+        DslPlan::GroupBy {
+            input,
+            keys,
+            aggs,
+            apply,
+            maintain_order,
+            options,
+        } if aggs
+            .iter()
+            .any(|e| has_expr(e, |e| matches!(e, Expr::Cached(_)))) =>
+        {
+            let (aggs, input, _) = scalar_subquery::cached_to_columns(aggs, input)
+                .map_err(|e| e.context(failed_here!(group_by)))?;
+            let lp = DslPlan::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply,
+                maintain_order,
+                options,
+            };
+            return to_alp_impl(lp, ctxt);
+        },
+        #[cfg(feature = "list_to_struct")]
+        DslPlan::GroupBy {
+            input,
+            keys,
+            aggs,
+            apply,
+            maintain_order,
+            options,
+        } if list_to_struct::has_sampled_width(&aggs) => {
+            let aggs = list_to_struct::infer_widths(aggs, &input)
+                .map_err(|e| e.context(failed_here!(group_by)))?;
+            let lp = DslPlan::GroupBy {
+                input,
+                keys,
+                aggs,
+                apply,
+                maintain_order,
+                options,
+            };
+            return to_alp_impl(lp, ctxt);
+        },
         DslPlan::GroupBy {
             input,
             keys,
@@ -514,26 +636,99 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             .map_err(|e| e.context(failed_here!(join)))
             .map(|t| t.0);
         },
+        #[cfg(feature = "list_to_struct")]
         DslPlan::HStack {
             input,
             exprs,
             options,
-        } => {
-            let input = to_alp_impl(owned(input), ctxt)
+        } if list_to_struct::has_sampled_width(&exprs) => {
+            let exprs = list_to_struct::infer_widths(exprs, &input)
                 .map_err(|e| e.context(failed_here!(with_columns)))?;
-            let (exprs, schema) =
-                resolve_with_columns(exprs, input, ctxt.lp_arena, ctxt.expr_arena, ctxt.opt_flags)
-                    .map_err(|e| e.context(failed_here!(with_columns)))?;
-
-            ctxt.conversion_optimizer
-                .fill_scratch(&exprs, ctxt.expr_arena);
-            let lp = IR::HStack {
+            let lp = DslPlan::HStack {
                 input,
                 exprs,
-                schema,
                 options,
             };
-            return run_conversion(lp, ctxt, "with_columns");
+            return to_alp_impl(lp, ctxt);
+        },
+        DslPlan::HStack {
+            input,
+            exprs,
+            options,
+        } => {
+            let exprs = if exprs
+                .iter()
+                .any(|e| has_expr(e, |e| matches!(e, Expr::Cached(_))))
+            {
+                scalar_subquery::cached_to_subqueries(exprs, &input)
+            } else {
+                exprs
+            };
+            let mut input = to_alp_impl(owned(input), ctxt)
+                .map_err(|e| e.context(failed_here!(with_columns)))?;
+            let (exprs, tmp_names) = if exprs
+                .iter()
+                .any(|e| has_expr(e, |e| matches!(e, Expr::SubPlan(..))))
+            {
+                let (exprs, joined, tmp_names) =
+                    scalar_subquery::join_scalar_subqueries(exprs, input, ctxt)
+                        .map_err(|e| e.context(failed_here!(with_columns)))?;
+                input = joined;
+                (exprs, tmp_names)
+            } else {
+                (exprs, vec![])
+            };
+            let input_schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena).into_owned();
+            let (stages, output_names) = with_columns_stages(exprs, &input_schema, ctxt.opt_flags)
+                .map_err(|e| e.context(failed_here!(with_columns)))?;
+
+            for exprs in stages {
+                let (exprs, schema) = resolve_with_columns(
+                    exprs,
+                    input,
+                    ctxt.lp_arena,
+                    ctxt.expr_arena,
+                    ctxt.opt_flags,
+                )
+                .map_err(|e| e.context(failed_here!(with_columns)))?;
+
+                ctxt.conversion_optimizer
+                    .fill_scratch(&exprs, ctxt.expr_arena);
+                let lp = IR::HStack {
+                    input,
+                    exprs,
+                    schema,
+                    options,
+                };
+                input = run_conversion(lp, ctxt, "with_columns")?;
+            }
+
+            // Restore the order in which the new columns were given, and remove the columns of
+            // the scalar subqueries.
+            if output_names.is_some() || !tmp_names.is_empty() {
+                let schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
+                let names: Vec<&PlSmallStr> = match &output_names {
+                    Some(output_names) => input_schema
+                        .iter_names()
+                        .chain(
+                            output_names
+                                .iter()
+                                .filter(|name| !input_schema.contains(name)),
+                        )
+                        .collect(),
+                    None => schema.iter_names().collect(),
+                };
+                let columns = names
+                    .into_iter()
+                    .filter(|name| !tmp_names.contains(name))
+                    .map(|name| (name.clone(), schema.get(name).unwrap().clone()))
+                    .collect::<Schema>();
+                input = ctxt.lp_arena.add(IR::SimpleProjection {
+                    input,
+                    columns: Arc::new(columns),
+                });
+            }
+            return Ok(input);
         },
         DslPlan::MatchToSchema {
             input,
@@ -573,6 +768,17 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                         used_input_columns += 1;
                         exprs.push(Expr::Column(column.clone()))
                     },
+                    Some(_) if per_column.cast != CastOrForbid::Forbid => {
+                        let options = if per_column.cast == CastOrForbid::Strict {
+                            CastOptions::Strict
+                        } else {
+                            CastOptions::NonStrict
+                        };
+                        used_input_columns += 1;
+                        exprs.push(
+                            Expr::Column(column.clone()).cast_with_options(dtype.clone(), options),
+                        );
+                    },
                     Some(input_dtype) => {
                         let from_dtype = input_dtype;
                         let to_dtype = dtype;
@@ -603,7 +809,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             }
 
             // Report the error for missing columns
-            if let Some(lst) = found_missing_columns.first() {
+            if !found_missing_columns.is_empty() {
                 use std::fmt::Write;
                 let mut formatted = String::new();
                 write!(&mut formatted, "\"{}\"", found_missing_columns[0]).unwrap();
@@ -611,7 +817,6 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     write!(&mut formatted, ", \"{c}\"").unwrap();
                 }
 
-                write!(&mut formatted, "\"{lst}\"").unwrap();
                 polars_bail!(SchemaMismatch: "missing columns in `match_to_schema`: {formatted}");
             }
 
@@ -691,6 +896,7 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                 DslFunction::Explode {
                     columns,
                     allow_empty,
+                    index_name,
                 } => {
                     let columns = columns.into_columns(&input_schema, &Default::default())?;
                     polars_ensure!(!columns.is_empty() || allow_empty, InvalidOperation: "no columns provided in explode");
@@ -699,11 +905,19 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
                     }
                     let function = FunctionIR::Explode {
                         columns: columns.into_iter().collect(),
+                        index_name,
                         schema: Default::default(),
                     };
                     let ir = IR::MapFunction { input, function };
                     return Ok(ctxt.lp_arena.add(ir));
                 },
+                // The hint is only used by common subplan elimination.
+                DslFunction::CacheHint(_)
+                    if !cfg!(feature = "cse")
+                        || !ctxt.opt_flags.contains(OptFlags::COMM_SUBPLAN_ELIM) =>
+                {
+                    return Ok(input);
+                },
                 DslFunction::FillNan(fill_value) => {
                     let exprs = input_schema
                         .iter()
@@ -1084,6 +1298,80 @@ fn resolve_with_columns(
     Ok((eirs, Arc::new(output_schema)))
 }
 
+/// Split the expressions of a `with_columns` into stages, such that expressions referring to
+/// columns that are created by other expressions of the same call run after those.
+///
+/// Columns of the input take precedence, so this only applies to queries that would otherwise
+/// fail on a missing column. If there is more than one stage, the output names of the
+/// expressions are returned as well.
+fn with_columns_stages(
+    exprs: Vec<Expr>,
+    input_schema: &Schema,
+    opt_flags: &mut OptFlags,
+) -> PolarsResult<(Vec<Vec<Expr>>, Option<Vec<PlSmallStr>>)> {
+    let expanded = rewrite_projections(exprs.clone(), &PlHashSet::new(), input_schema, opt_flags)?;
+    let Ok(names) = expanded
+        .iter()
+        .map(expr_output_name)
+        .collect::<PolarsResult<Vec<_>>>()
+    else {
+        return Ok((vec![exprs], None));
+    };
+    let producers = names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| (name, i))
+        .collect::<PlHashMap<_, _>>();
+    // Duplicate names are reported by `resolve_with_columns`.
+    if producers.len() != names.len() {
+        return Ok((vec![exprs], None));
+    }
+
+    let dependencies = expanded
+        .iter()
+        .enumerate()
+        .map(|(i, e)| {
+            expr_to_leaf_column_names_iter(e)
+                .filter(|name| !input_schema.contains(name))
+                .filter_map(|name| producers.get(&name).copied())
+                .filter(|j| *j != i)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    if dependencies.iter().all(|d| d.is_empty()) {
+        return Ok((vec![exprs], None));
+    }
+
+    // Every expression runs in the stage after the last stage of its dependencies.
+    let mut stages: Vec<Option<usize>> = vec![None; expanded.len()];
+    while stages.iter().any(Option::is_none) {
+        let mut progress = false;
+        for i in 0..expanded.len() {
+            if stages[i].is_some() {
+                continue;
+            }
+            let stage = dependencies[i]
+                .iter()
+                .try_fold(0, |stage, j| Some(stage.max(stages[*j]? + 1)));
+            if stage.is_some() {
+                stages[i] = stage;
+                progress = true;
+            }
+        }
+        polars_ensure!(
+            progress,
+            InvalidOperation: "the expressions passed to `LazyFrame.with_columns` depend on each other in a cycle"
+        );
+    }
+
+    let n_stages = stages.iter().flatten().max().unwrap() + 1;
+    let mut out = vec![vec![]; n_stages];
+    for (e, stage) in expanded.into_iter().zip(stages) {
+        out[stage.unwrap()].push(e);
+    }
+    Ok((out, Some(names)))
+}
+
 fn resolve_group_by(
     input: Node,
     keys: Vec<Expr>,
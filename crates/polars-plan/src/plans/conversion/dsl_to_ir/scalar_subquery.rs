@@ -0,0 +1,186 @@
+//! Scalar subqueries, i.e. [`Expr::SubPlan`]s in a `with_columns` of which the single value is
+//! broadcast to the height of the frame. [`Expr::Cached`] is rewritten into a scalar subquery on
+//! the input of the `with_columns`, and in other nodes into a temporary column that such a
+//! `with_columns` adds to their input.
+use either::Either;
+use polars_ops::frame::MaintainOrderJoin;
+use polars_utils::format_pl_smallstr;
+
+use super::*;
+use crate::constants::POLARS_TMP_PREFIX;
+
+/// Convert the subplan of a scalar subquery, and make it produce exactly one row in the column
+/// `tmp_name`. No rows become a null, more rows are an error. Returns the node and the name of the
+/// column of the subplan.
+fn to_scalar_subplan(
+    plan: Arc<DslPlan>,
+    tmp_name: PlSmallStr,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<(Node, PlSmallStr)> {
+    let input = to_alp_impl(Arc::unwrap_or_clone(plan), ctxt)
+        .map_err(|e| e.context(failed_here!(scalar subquery)))?;
+    let schema = ctxt.lp_arena.get(input).schema(ctxt.lp_arena);
+    polars_ensure!(
+        schema.len() == 1,
+        InvalidOperation: "a scalar subquery must produce a single column, got {}", schema.len()
+    );
+    let (name, dtype) = schema.get_at_index(0).unwrap();
+    let name = name.clone();
+
+    let expr = ExprIR::new(
+        ctxt.expr_arena.add(AExpr::Column(name.clone())),
+        OutputName::Alias(tmp_name.clone()),
+    );
+    let input = ctxt.lp_arena.add(IR::Select {
+        input,
+        expr: vec![expr],
+        schema: Arc::new(Schema::from_iter([(tmp_name, dtype.clone())])),
+        options: ProjectionOptions::default(),
+    });
+
+    let function = |df: DataFrame| match df.height() {
+        0 => Ok(DataFrame::full_null(df.schema(), 1)),
+        1 => Ok(df),
+        n => polars_bail!(ComputeError: "a scalar subquery must produce at most one row, got {n}"),
+    };
+    let node = ctxt.lp_arena.add(IR::MapFunction {
+        input,
+        function: FunctionIR::Opaque {
+            function: Arc::new(function),
+            schema: None,
+            predicate_pd: false,
+            projection_pd: false,
+            streamable: false,
+            fmt_str: PlSmallStr::from_static("SCALAR SUBQUERY"),
+        },
+    });
+    Ok((node, name))
+}
+
+/// Replace the [`Expr::Cached`] in `exprs` by scalar subqueries that evaluate them on `input`.
+/// The subqueries are always cached, so that equal cached expressions on equal inputs are only
+/// computed once, also if they occur in different parts of the query.
+pub(super) fn cached_to_subqueries(exprs: Vec<Expr>, input: &Arc<DslPlan>) -> Vec<Expr> {
+    let mut subqueries = PlHashMap::new();
+    exprs
+        .into_iter()
+        .map(|expr| {
+            expr.map_expr(|e| {
+                let Expr::Cached(expr) = e else {
+                    return e;
+                };
+                subqueries
+                    .entry(expr)
+                    .or_insert_with_key(|expr| {
+                        let plan = DslPlan::Select {
+                            expr: vec![expr.as_ref().clone()],
+                            input: input.clone(),
+                            options: Default::default(),
+                        };
+                        let plan = DslPlan::MapFunction {
+                            input: Arc::new(plan),
+                            function: DslFunction::CacheHint(CacheHint::Always),
+                        };
+                        Expr::SubPlan(SpecialEq::new(Arc::new(plan)), vec![])
+                    })
+                    .clone()
+            })
+        })
+        .collect()
+}
+
+/// Replace the [`Expr::Cached`] in `exprs` by the first value of temporary columns, which are
+/// added to `input` by a `with_columns` of the cached expressions. This allows them in nodes that
+/// don't support scalar subqueries, such as `select`, `filter` and `group_by`.
+///
+/// Returns the rewritten expressions, the new input and the names of the temporary columns, which
+/// have to be removed if the node keeps the columns of its input.
+pub(super) fn cached_to_columns(
+    exprs: Vec<Expr>,
+    input: Arc<DslPlan>,
+) -> PolarsResult<(Vec<Expr>, Arc<DslPlan>, Vec<PlSmallStr>)> {
+    let mut columns = PlIndexMap::new();
+    let exprs = exprs
+        .into_iter()
+        .map(|expr| {
+            expr.try_map_expr(|e| {
+                let Expr::Cached(expr) = e else {
+                    return Ok(e);
+                };
+                let name = expr_output_name(&expr)?;
+                let n = columns.len();
+                let tmp_name = columns
+                    .entry(expr)
+                    .or_insert_with(|| format_pl_smallstr!("{POLARS_TMP_PREFIX}CACHED_{n}"));
+                Ok(Expr::Column(tmp_name.clone()).first().alias(name))
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let (cached, tmp_names): (Vec<_>, Vec<_>) = columns
+        .into_iter()
+        .map(|(expr, tmp_name)| (Expr::Cached(expr).alias(tmp_name.clone()), tmp_name))
+        .unzip();
+    let input = DslPlan::HStack {
+        input,
+        exprs: cached,
+        options: ProjectionOptions::default(),
+    };
+    Ok((exprs, Arc::new(input), tmp_names))
+}
+
+/// Replace the scalar subqueries in `exprs` with temporary columns, which are added to `input`
+/// with a cross join. The subplans are inputs of the query, so they run once per collect.
+///
+/// Returns the rewritten expressions, the new input and the names of the temporary columns, which have to be removed after
+/// the expressions are evaluated.
+pub(super) fn join_scalar_subqueries(
+    exprs: Vec<Expr>,
+    mut input: Node,
+    ctxt: &mut DslConversionContext,
+) -> PolarsResult<(Vec<Expr>, Node, Vec<PlSmallStr>)> {
+    let mut tmp_names = vec![];
+    // The same subquery can occur multiple times, which is only joined once.
+    let mut joined = PlHashMap::new();
+    let exprs = exprs
+        .into_iter()
+        .map(|expr| {
+            expr.try_map_expr(|e| {
+                let Expr::SubPlan(plan, _) = e else {
+                    return Ok(e);
+                };
+                let key = Arc::as_ptr(&plan).addr();
+                if let Some(e) = joined.get(&key) {
+                    return Ok(e.clone());
+                }
+                let tmp_name =
+                    format_pl_smallstr!("{POLARS_TMP_PREFIX}SUBQUERY_{}", tmp_names.len());
+                let (subplan, name) = to_scalar_subplan(plan.into_inner(), tmp_name.clone(), ctxt)?;
+
+                let options = JoinOptions {
+                    args: JoinArgs {
+                        maintain_order: MaintainOrderJoin::Left,
+                        ..JoinArgs::new(JoinType::Cross)
+                    },
+                    ..Default::default()
+                };
+                input = join::resolve_join(
+                    Either::Right(input),
+                    Either::Right(subplan),
+                    vec![],
+                    vec![],
+                    vec![],
+                    JoinOptionsIR::from(options),
+                    ctxt,
+                )?
+                .0;
+
+                tmp_names.push(tmp_name.clone());
+                let e = Expr::Column(tmp_name).alias(name);
+                joined.insert(key, e.clone());
+                Ok(e)
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok((exprs, input, tmp_names))
+}
@@ -4,7 +4,7 @@ use polars_io::cloud::CloudOptions;
 #[cfg(feature = "cloud")]
 use polars_io::pl_async::get_runtime;
 use polars_io::prelude::*;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::maybe_decompress_archive_bytes;
 
 use super::*;
 
@@ -12,12 +12,13 @@ pub(super) fn dsl_to_ir(
     sources: ScanSources,
     mut unified_scan_args_box: Box<UnifiedScanArgs>,
     scan_type: Box<FileScanDsl>,
-    cached_ir: Arc<Mutex<Option<IR>>>,
+    cached_ir: Arc<Mutex<CachedScanIr>>,
     ctxt: &mut DslConversionContext,
 ) -> PolarsResult<IR> {
     // Note that the first metadata can still end up being `None` later if the files were
     // filtered from predicate pushdown.
-    let mut cached_ir = cached_ir.lock().unwrap();
+    let mut cached = cached_ir.lock().unwrap();
+    let cached_ir = &mut cached.ir;
 
     if cached_ir.is_none() {
         let cloud_options = unified_scan_args_box.cloud_options.clone();
@@ -62,6 +63,15 @@ pub(super) fn dsl_to_ir(
             FileScanDsl::Anonymous { .. } => sources,
         };
 
+        if let Some(table_statistics) = &unified_scan_args.table_statistics {
+            polars_ensure!(
+                table_statistics.num_files() == sources.len(),
+                InvalidOperation:
+                "table statistics were given for {} files, but the scan has {} files",
+                table_statistics.num_files(), sources.len()
+            );
+        }
+
         // For cloud we must deduplicate files. Serialization/deserialization leads to Arc's losing there
         // sharing.
         let (mut file_info, scan_type_ir) = ctxt.cache_file_info.get_or_insert(
@@ -72,6 +82,15 @@ pub(super) fn dsl_to_ir(
             ctxt.verbose,
         )?;
 
+        if let Some(num_rows) = unified_scan_args
+            .table_statistics
+            .as_ref()
+            .and_then(|s| s.num_rows())
+        {
+            let num_rows = num_rows as usize;
+            file_info.row_estimation = (Some(num_rows), num_rows);
+        }
+
         if unified_scan_args.hive_options.enabled.is_none() {
             // We expect this to be `Some(_)` after this point. If it hasn't been auto-enabled
             // we explicitly set it to disabled.
@@ -263,6 +282,29 @@ pub(super) fn parquet_file_info(
     Ok((file_info, metadata))
 }
 
+/// Replace the data types of the columns in `schema_overrides`. The reader casts these columns
+/// while decoding them.
+#[cfg(feature = "parquet")]
+pub(super) fn apply_schema_overrides(
+    file_info: &mut FileInfo,
+    schema_overrides: &Schema,
+) -> PolarsResult<()> {
+    let schema = Arc::make_mut(&mut file_info.schema);
+
+    for (name, dtype) in schema_overrides.iter() {
+        let Some(file_dtype) = schema.get_mut(name) else {
+            polars_bail!(
+                ColumnNotFound:
+                "column '{}' of `schema_overrides` not found in the parquet schema",
+                name
+            );
+        };
+        *file_dtype = dtype.clone();
+    }
+
+    Ok(())
+}
+
 // TODO! return metadata arced
 #[cfg(feature = "ipc")]
 pub(super) fn ipc_file_info(
@@ -361,7 +403,11 @@ pub fn csv_file_info(
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let mut reader = std::io::Cursor::new(maybe_decompress_archive_bytes(
+            &memslice,
+            owned,
+            &csv_options.get_archive_options(),
+        )?);
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -479,7 +525,11 @@ pub fn ndjson_file_info(
         schema.clone()
     } else {
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let mut reader = std::io::Cursor::new(maybe_decompress_archive_bytes(
+            &memslice,
+            owned,
+            &ndjson_options.get_archive_options(),
+        )?);
 
         Arc::new(polars_io::ndjson::infer_schema(
             &mut reader,
@@ -510,6 +560,7 @@ enum CachedSourceKey {
     ParquetIpc {
         first_path: PlPath,
         schema_overwrite: Option<SchemaRef>,
+        schema_overrides: Option<SchemaRef>,
     },
     CsvJson {
         paths: Arc<[PlPath]>,
@@ -534,7 +585,9 @@ impl SourcesToFileInfo {
         Ok(match scan_type {
             #[cfg(feature = "parquet")]
             FileScanDsl::Parquet { options } => {
-                if let Some(schema) = &options.schema {
+                let schema_overrides = options.schema_overrides.clone();
+
+                let (mut file_info, scan_ir) = if let Some(schema) = &options.schema {
                     // We were passed a schema, we don't have to call `parquet_file_info`,
                     // but this does mean we don't have `row_estimation` and `first_metadata`.
                     (
@@ -559,7 +612,13 @@ impl SourcesToFileInfo {
                     .map_err(|e| e.context(failed_here!(parquet scan)))?;
 
                     (file_info, FileScanIR::Parquet { options, metadata })
+                };
+
+                if let Some(schema_overrides) = schema_overrides {
+                    scans::apply_schema_overrides(&mut file_info, &schema_overrides)?;
                 }
+
+                (file_info, scan_ir)
             },
             #[cfg(feature = "ipc")]
             FileScanDsl::Ipc { options } => {
@@ -673,6 +732,7 @@ impl SourcesToFileInfo {
                 let key = CachedSourceKey::ParquetIpc {
                     first_path: paths[0].clone(),
                     schema_overwrite: options.schema.clone(),
+                    schema_overrides: options.schema_overrides.clone(),
                 };
 
                 let v = self.inner.get(&key);
@@ -683,6 +743,7 @@ impl SourcesToFileInfo {
                 let key = CachedSourceKey::ParquetIpc {
                     first_path: paths[0].clone(),
                     schema_overwrite: None,
+                    schema_overrides: None,
                 };
 
                 let v = self.inner.get(&key);
@@ -28,6 +28,33 @@ pub(super) fn expand_expressions(
     )
 }
 
+/// Get the columns used by `exprs` if they can be determined without the input schema. Returns
+/// `None` if any of the expressions selects columns by a pattern or dtype, or if no columns are
+/// used at all.
+#[cfg(feature = "python")]
+pub(super) fn projected_column_names(exprs: &[Expr]) -> Option<Vec<PlSmallStr>> {
+    let mut names = PlIndexSet::new();
+    for expr in exprs {
+        for e in expr {
+            match e {
+                Expr::Column(name) if is_regex_projection(name) || name.as_str() == "*" => {
+                    return None;
+                },
+                Expr::Column(name) => {
+                    names.insert(name.clone());
+                },
+                Expr::Selector(_)
+                | Expr::Field(_)
+                | Expr::Eval { .. }
+                | Expr::SubPlan(..)
+                | Expr::AnonymousFunction { .. } => return None,
+                _ => {},
+            }
+        }
+    }
+    (!names.is_empty()).then(|| names.into_iter().collect())
+}
+
 pub(super) fn empty_df() -> IR {
     IR::DataFrameScan {
         df: Arc::new(Default::default()),
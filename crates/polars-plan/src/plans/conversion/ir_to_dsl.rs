@@ -324,6 +324,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IL::Contains { nulls_equal } => L::Contains { nulls_equal },
                 #[cfg(feature = "list_drop_nulls")]
                 IL::DropNulls => L::DropNulls,
+                #[cfg(feature = "list_filter")]
+                IL::Filter => L::Filter,
                 #[cfg(feature = "list_sample")]
                 IL::Sample {
                     is_fraction,
@@ -425,10 +427,30 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Normalize { form } => B::Normalize { form },
                 #[cfg(feature = "string_reverse")]
                 IB::Reverse => B::Reverse,
+                #[cfg(feature = "log_parsing")]
+                IB::ParseCommonLog => B::ParseCommonLog,
+                #[cfg(feature = "log_parsing")]
+                IB::ParseUserAgent => B::ParseUserAgent,
                 #[cfg(feature = "string_pad")]
                 IB::PadStart { fill_char } => B::PadStart { fill_char },
                 #[cfg(feature = "string_pad")]
                 IB::PadEnd { fill_char } => B::PadEnd { fill_char },
+                #[cfg(feature = "string_pad")]
+                IB::PadStartDisplay {
+                    fill_char,
+                    truncate,
+                } => B::PadStartDisplay {
+                    fill_char,
+                    truncate,
+                },
+                #[cfg(feature = "string_pad")]
+                IB::PadEndDisplay {
+                    fill_char,
+                    truncate,
+                } => B::PadEndDisplay {
+                    fill_char,
+                    truncate,
+                },
                 IB::Slice => B::Slice,
                 IB::Head => B::Head,
                 IB::Tail => B::Tail,
@@ -447,7 +469,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::StripPrefix => B::StripPrefix,
                 IB::StripSuffix => B::StripSuffix,
                 #[cfg(feature = "dtype-struct")]
-                IB::SplitExact { n, inclusive } => B::SplitExact { n, inclusive },
+                IB::SplitExact { n, inclusive, rest } => B::SplitExact { n, inclusive, rest },
                 #[cfg(feature = "dtype-struct")]
                 IB::SplitN(n) => B::SplitN(n),
                 #[cfg(feature = "temporal")]
@@ -554,6 +576,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::WithTimeUnit(time_unit) => B::WithTimeUnit(time_unit),
                 #[cfg(feature = "timezones")]
                 IB::ConvertTimeZone(time_zone) => B::ConvertTimeZone(time_zone),
+                #[cfg(feature = "timezones")]
+                IB::ConvertTimeZonePerRow => B::ConvertTimeZonePerRow,
                 IB::TimeStamp(time_unit) => B::TimeStamp(time_unit),
                 IB::Truncate => B::Truncate,
                 #[cfg(feature = "offset_by")]
@@ -597,6 +621,24 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IB::Xor => B::Xor,
             })
         },
+        #[cfg(feature = "anonymize")]
+        IF::Anonymize(f) => {
+            use {AnonymizeFunction as A, IRAnonymizeFunction as IA};
+            F::Anonymize(match f {
+                IA::HashStable { salts } => A::HashStable { salts },
+                IA::SuppressRare { k } => A::SuppressRare { k },
+            })
+        },
+        #[cfg(feature = "url")]
+        IF::Url(f) => {
+            use {IRUrlFunction as IU, UrlFunction as U};
+            F::Url(match f {
+                IU::Parse => U::Parse,
+                IU::QueryParam(key) => U::QueryParam(key),
+                IU::Encode => U::Encode,
+                IU::Decode => U::Decode,
+            })
+        },
         IF::Boolean(f) => {
             use {BooleanFunction as B, IRBooleanFunction as IB};
             F::Boolean(match f {
@@ -667,6 +709,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "abs")]
         IF::Abs => F::Abs,
         IF::Negate => F::Negate,
+        IF::CheckedArithmetic(op) => F::CheckedArithmetic(op),
+        IF::DivOr(op) => F::DivOr(op),
         #[cfg(feature = "hist")]
         IF::Hist {
             bin_count,
@@ -825,6 +869,7 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::ShiftAndFill => F::ShiftAndFill,
         IF::Shift => F::Shift,
         IF::DropNans => F::DropNans,
+        IF::NanToNull => F::NanToNull,
         IF::DropNulls => F::DropNulls,
         #[cfg(feature = "mode")]
         IF::Mode => F::Mode,
@@ -895,6 +940,10 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::Interpolate(m) => F::Interpolate(m),
         #[cfg(feature = "interpolate_by")]
         IF::InterpolateBy => F::InterpolateBy,
+        #[cfg(feature = "downsample")]
+        IF::DownsampleLttb { n_out } => F::DownsampleLttb { n_out },
+        #[cfg(feature = "downsample")]
+        IF::DownsampleMinMax { n_buckets } => F::DownsampleMinMax { n_buckets },
         #[cfg(feature = "log")]
         IF::Entropy { base, normalize } => F::Entropy { base, normalize },
         #[cfg(feature = "log")]
@@ -909,6 +958,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         #[cfg(feature = "round_series")]
         IF::RoundSF { digits } => F::RoundSF { digits },
         #[cfg(feature = "round_series")]
+        IF::RoundToIncrement { increment, mode } => F::RoundToIncrement { increment, mode },
+        #[cfg(feature = "round_series")]
         IF::Floor => F::Floor,
         #[cfg(feature = "round_series")]
         IF::Ceil => F::Ceil,
@@ -950,11 +1001,13 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             labels,
             left_closed,
             include_breaks,
+            missing_label,
         } => F::Cut {
             breaks,
             labels,
             left_closed,
             include_breaks,
+            missing_label,
         },
         #[cfg(feature = "cutqcut")]
         IF::QCut {
@@ -963,17 +1016,55 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
             left_closed,
             allow_duplicates,
             include_breaks,
+            missing_label,
         } => F::QCut {
             probs,
             labels,
             left_closed,
             allow_duplicates,
             include_breaks,
+            missing_label,
         },
         #[cfg(feature = "rle")]
         IF::RLE => F::RLE,
         #[cfg(feature = "rle")]
         IF::RLEID => F::RLEID,
+        #[cfg(feature = "to_dummies")]
+        IF::ToDummies {
+            separator,
+            drop_first,
+            sparse,
+        } => F::ToDummies {
+            separator,
+            drop_first,
+            sparse,
+        },
+        #[cfg(feature = "category_encoding")]
+        IF::CategoricalTargetEncode {
+            folds,
+            smoothing,
+            seed,
+        } => F::CategoricalTargetEncode {
+            folds,
+            smoothing,
+            seed,
+        },
+        #[cfg(feature = "category_encoding")]
+        IF::FrequencyEncode { normalize } => F::FrequencyEncode { normalize },
+        #[cfg(feature = "bootstrap")]
+        IF::Bootstrap {
+            n_samples,
+            statistic,
+            method,
+            confidence_level,
+            seed,
+        } => F::Bootstrap {
+            n_samples,
+            statistic,
+            method,
+            confidence_level,
+            seed,
+        },
         IF::ToPhysical => F::ToPhysical,
         #[cfg(feature = "random")]
         IF::Random { method, seed } => {
@@ -994,6 +1085,11 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 seed,
             }
         },
+        #[cfg(feature = "unique_id")]
+        IF::Uuid4 => F::Uuid4,
+        #[cfg(feature = "unique_id")]
+        IF::SnowflakeId { worker_id } => F::SnowflakeId { worker_id },
+        IF::Placeholder { name } => F::Placeholder { name },
         IF::SetSortedFlag(s) => F::SetSortedFlag(s),
         #[cfg(feature = "ffi_plugin")]
         IF::FfiPlugin {
@@ -1053,6 +1149,8 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
         IF::MinHorizontal => F::MinHorizontal,
         IF::SumHorizontal { ignore_nulls } => F::SumHorizontal { ignore_nulls },
         IF::MeanHorizontal { ignore_nulls } => F::MeanHorizontal { ignore_nulls },
+        IF::QuantileHorizontal { quantile, method } => F::QuantileHorizontal { quantile, method },
+        IF::StdHorizontal { ddof } => F::StdHorizontal { ddof },
         #[cfg(feature = "ewma")]
         IF::EwmMean { options } => F::EwmMean { options },
         #[cfg(feature = "ewma_by")]
@@ -158,6 +158,24 @@ pub(super) fn process_binary(
                 right: node_right,
             }));
         },
+        #[cfg(feature = "dtype-f16")]
+        (l, r) if l.is_float16() || r.is_float16() => {
+            // Float16 is a storage type, binary expressions on it are computed in Float32.
+            let mut float32 = |node, dtype: &DataType| {
+                if dtype.is_float16() {
+                    expr_arena.add(AExpr::Cast {
+                        expr: node,
+                        dtype: DataType::Float32,
+                        options: CastOptions::NonStrict,
+                    })
+                } else {
+                    node
+                }
+            };
+            let left = float32(node_left, &type_left);
+            let right = float32(node_right, &type_right);
+            return Ok(Some(AExpr::BinaryExpr { left, op, right }));
+        },
         _ => {
             unpack!(early_escape(&type_left, &type_right));
         },
@@ -352,6 +352,59 @@ impl OptimizationRule for TypeCoercionRule {
                     options,
                 })
             },
+            // Float16 is a storage type, functions that look at the values compute in Float32.
+            #[cfg(feature = "dtype-f16")]
+            AExpr::Function {
+                ref function,
+                ref input,
+                options,
+            } if !is_float16_storage_function(function)
+                && input.iter().any(|e| {
+                    e.dtype(schema, Context::Default, expr_arena)
+                        .is_ok_and(|dt| dt.is_float16())
+                }) =>
+            {
+                let function = function.clone();
+                let mut input = input.clone();
+                for e in input.iter_mut() {
+                    let dtype = e.dtype(schema, Context::Default, expr_arena)?.clone();
+                    if dtype.is_float16() {
+                        cast_expr_ir(
+                            e,
+                            &dtype,
+                            &DataType::Float32,
+                            expr_arena,
+                            CastOptions::NonStrict,
+                        )?;
+                    }
+                }
+                Some(AExpr::Function {
+                    function,
+                    input,
+                    options,
+                })
+            },
+            #[cfg(feature = "dtype-f16")]
+            AExpr::Agg(
+                ref agg @ (IRAggExpr::Sum(input)
+                | IRAggExpr::Mean(input)
+                | IRAggExpr::Median(input)
+                | IRAggExpr::Std(input, _)
+                | IRAggExpr::Var(input, _)
+                | IRAggExpr::Quantile { expr: input, .. }),
+            ) => {
+                let (_, dtype) = unpack!(get_aexpr_and_type(expr_arena, input, schema));
+                if !dtype.is_float16() {
+                    return Ok(None);
+                }
+                let mut agg = agg.clone();
+                agg.set_input(expr_arena.add(AExpr::Cast {
+                    expr: input,
+                    dtype: DataType::Float32,
+                    options: CastOptions::NonStrict,
+                }));
+                Some(AExpr::Agg(agg))
+            },
             // generic type coercion of any function.
             AExpr::Function {
                 // only for `DataType::Unknown` as it still has to be set.
@@ -597,6 +650,8 @@ See https://github.com/pola-rs/polars/issues/22149 for more information."
                     ref function @ IRFunctionExpr::StringExpr(
                         IRStringFunction::PadStart { .. }
                         | IRStringFunction::PadEnd { .. }
+                        | IRStringFunction::PadStartDisplay { .. }
+                        | IRStringFunction::PadEndDisplay { .. }
                         | IRStringFunction::ZFill,
                     ),
                 ref input,
@@ -957,6 +1012,35 @@ fn check_cast(from: &DataType, to: &DataType) -> PolarsResult<()> {
     Ok(())
 }
 
+/// Functions that only move Float16 values around, without looking at them.
+#[cfg(feature = "dtype-f16")]
+fn is_float16_storage_function(function: &IRFunctionExpr) -> bool {
+    use IRFunctionExpr as F;
+    match function {
+        F::NullCount
+        | F::FillNull
+        | F::Append { .. }
+        | F::Shift
+        | F::ShiftAndFill
+        | F::DropNulls
+        | F::Repeat
+        | F::Reverse
+        | F::Coalesce
+        | F::ToPhysical
+        | F::SetSortedFlag(_)
+        | F::GatherEvery { .. }
+        | F::ExtendConstant
+        | F::ListExpr(_) => true,
+        #[cfg(feature = "dtype-array")]
+        F::ArrayExpr(_) | F::Reshape(_) => true,
+        #[cfg(feature = "repeat_by")]
+        F::RepeatBy => true,
+        #[cfg(feature = "dtype-struct")]
+        F::AsStruct | F::StructExpr(_) => true,
+        _ => false,
+    }
+}
+
 fn early_escape(type_self: &DataType, type_other: &DataType) -> Option<()> {
     match (type_self, type_other) {
         (lhs, rhs) if lhs == rhs => None,
@@ -15,6 +15,8 @@ use polars_io::cloud::CloudOptions;
 use polars_io::parquet::read::ParquetReader;
 #[cfg(all(feature = "parquet", feature = "async"))]
 use polars_io::pl_async::{get_runtime, with_concurrency_budget};
+#[cfg(feature = "json")]
+use polars_io::utils::compression::ArchiveReadOptions;
 use polars_utils::plpath::PlPath;
 
 use super::*;
@@ -56,7 +58,9 @@ pub fn count_rows(
                 metadata.as_deref(),
             ),
             #[cfg(feature = "json")]
-            FileScanIR::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            FileScanIR::NDJson { options } => {
+                count_rows_ndjson(sources, cloud_options, &options.get_archive_options())
+            },
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset { .. } => unreachable!(),
             FileScanIR::Anonymous { .. } => {
@@ -92,6 +96,7 @@ fn count_all_rows_csv(
                 options.skip_lines,
                 options.skip_rows,
                 options.skip_rows_after_header,
+                options.archive_member_glob.as_deref(),
             ),
             _ => {
                 let memslice = source.to_memslice()?;
@@ -210,9 +215,10 @@ async fn count_rows_cloud_ipc(
 pub(super) fn count_rows_ndjson(
     sources: &ScanSources,
     cloud_options: Option<&CloudOptions>,
+    archive_options: &ArchiveReadOptions,
 ) -> PolarsResult<usize> {
     use polars_core::config;
-    use polars_io::utils::compression::maybe_decompress_bytes;
+    use polars_io::utils::compression::maybe_decompress_archive_bytes;
 
     if sources.is_empty() {
         return Ok(0);
@@ -248,7 +254,7 @@ pub(super) fn count_rows_ndjson(
 
             let owned = &mut vec![];
             let reader = polars_io::ndjson::core::JsonLineReader::new(std::io::Cursor::new(
-                maybe_decompress_bytes(&memslice[..], owned)?,
+                maybe_decompress_archive_bytes(&memslice[..], owned, archive_options)?,
             ));
             reader.count()
         })
@@ -18,6 +18,26 @@ pub struct OpaquePythonUdf {
     pub validate_output: bool,
 }
 
+/// Whether common subplan elimination may cache a sub-plan.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum CacheHint {
+    /// Cache the sub-plan if it occurs more than once, regardless of its cost.
+    Always,
+    /// Never cache the sub-plan or any part of it.
+    Never,
+}
+
+impl Display for CacheHint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Always => write!(f, "always"),
+            Self::Never => write!(f, "never"),
+        }
+    }
+}
+
 // Except for Opaque functions, this only has the DSL name of the function.
 #[derive(Clone, IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -34,6 +54,8 @@ pub enum DslFunction {
     Explode {
         columns: Selector,
         allow_empty: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        index_name: Option<PlSmallStr>,
     },
     #[cfg(feature = "pivot")]
     Unpivot {
@@ -51,6 +73,7 @@ pub enum DslFunction {
     // Function that is already converted to IR.
     #[cfg_attr(any(feature = "serde", feature = "dsl-schema"), serde(skip))]
     FunctionIR(FunctionIR),
+    CacheHint(CacheHint),
 }
 
 #[derive(Clone)]
@@ -119,6 +142,7 @@ impl DslFunction {
             },
             #[cfg(feature = "python")]
             DslFunction::OpaquePython(inner) => FunctionIR::OpaquePython(inner),
+            DslFunction::CacheHint(hint) => FunctionIR::CacheHint(hint),
             DslFunction::Stats(_)
             | DslFunction::FillNan(_)
             | DslFunction::Rename { .. }
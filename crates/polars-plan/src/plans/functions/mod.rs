@@ -3,6 +3,8 @@ mod dsl;
 #[cfg(feature = "python")]
 mod python_udf;
 mod schema;
+#[cfg(feature = "parquet")]
+mod statistics;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -16,6 +18,10 @@ use polars_io::cloud::CloudOptions;
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "parquet")]
+pub(crate) use statistics::read_metadata;
+#[cfg(feature = "parquet")]
+pub use statistics::{StatisticsAgg, StatisticsAggKind};
 use strum_macros::IntoStaticStr;
 
 #[cfg(feature = "python")]
@@ -50,6 +56,8 @@ pub enum FunctionIR {
     Rechunk,
     Explode {
         columns: Arc<[PlSmallStr]>,
+        /// Name of the column with the index of the original row of every exploded row.
+        index_name: Option<PlSmallStr>,
         #[cfg_attr(feature = "ir_serde", serde(skip))]
         schema: CachedSchema,
     },
@@ -71,6 +79,21 @@ pub enum FunctionIR {
         // used for formatting
         fmt_str: PlSmallStr,
     },
+    /// Aggregations of a Parquet scan that are answered from the statistics of the files.
+    #[cfg(feature = "parquet")]
+    FastStatistics {
+        sources: ScanSources,
+        cloud_options: Option<CloudOptions>,
+        /// The scanned columns.
+        columns: SchemaRef,
+        aggs: Arc<[StatisticsAgg]>,
+        /// The output schema, with a column per aggregation.
+        schema: SchemaRef,
+        allow_missing_columns: bool,
+    },
+    /// Restricts how common subplan elimination caches the input. This is a no-op, and it is
+    /// removed after common subplan elimination.
+    CacheHint(CacheHint),
 }
 
 impl Eq for FunctionIR {}
@@ -80,6 +103,7 @@ impl PartialEq for FunctionIR {
         use FunctionIR::*;
         match (self, other) {
             (Rechunk, Rechunk) => true,
+            (CacheHint(l), CacheHint(r)) => l == r,
             (
                 FastCount {
                     sources: srcs_l, ..
@@ -88,7 +112,31 @@ impl PartialEq for FunctionIR {
                     sources: srcs_r, ..
                 },
             ) => srcs_l == srcs_r,
-            (Explode { columns: l, .. }, Explode { columns: r, .. }) => l == r,
+            #[cfg(feature = "parquet")]
+            (
+                FastStatistics {
+                    sources: srcs_l,
+                    aggs: l,
+                    ..
+                },
+                FastStatistics {
+                    sources: srcs_r,
+                    aggs: r,
+                    ..
+                },
+            ) => srcs_l == srcs_r && l == r,
+            (
+                Explode {
+                    columns: l,
+                    index_name: l_index,
+                    ..
+                },
+                Explode {
+                    columns: r,
+                    index_name: r_index,
+                    ..
+                },
+            ) => l == r && l_index == r_index,
             #[cfg(feature = "pivot")]
             (Unpivot { args: l, .. }, Unpivot { args: r, .. }) => l == r,
             (RowIndex { name: l, .. }, RowIndex { name: r, .. }) => l == r,
@@ -115,9 +163,31 @@ impl Hash for FunctionIR {
                 cloud_options.hash(state);
                 alias.hash(state);
             },
+            #[cfg(feature = "parquet")]
+            FunctionIR::FastStatistics {
+                sources,
+                cloud_options,
+                columns: _,
+                aggs,
+                schema: _,
+                allow_missing_columns,
+            } => {
+                sources.hash(state);
+                cloud_options.hash(state);
+                aggs.hash(state);
+                allow_missing_columns.hash(state);
+            },
             FunctionIR::Unnest { columns } => columns.hash(state),
             FunctionIR::Rechunk => {},
-            FunctionIR::Explode { columns, schema: _ } => columns.hash(state),
+            FunctionIR::CacheHint(hint) => hint.hash(state),
+            FunctionIR::Explode {
+                columns,
+                index_name,
+                schema: _,
+            } => {
+                columns.hash(state);
+                index_name.hash(state);
+            },
             #[cfg(feature = "pivot")]
             FunctionIR::Unpivot { args, schema: _ } => args.hash(state),
             FunctionIR::RowIndex {
@@ -138,7 +208,11 @@ impl FunctionIR {
         use FunctionIR::*;
         match self {
             Rechunk => false,
-            FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            FastCount { .. } | Unnest { .. } | CacheHint(_) => true,
+            // The row indices would restart on every batch.
+            Explode { index_name, .. } => index_name.is_none(),
+            #[cfg(feature = "parquet")]
+            FastStatistics { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             Opaque { streamable, .. } => *streamable,
@@ -167,8 +241,12 @@ impl FunctionIR {
             OpaquePython(OpaquePythonUdf { predicate_pd, .. }) => *predicate_pd,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
-            Rechunk | Unnest { .. } | Explode { .. } => true,
+            Rechunk | Unnest { .. } | CacheHint(_) => true,
+            // Filtering the input would change the row indices.
+            Explode { index_name, .. } => index_name.is_none(),
             RowIndex { .. } | FastCount { .. } => false,
+            #[cfg(feature = "parquet")]
+            FastStatistics { .. } => false,
         }
     }
 
@@ -178,7 +256,9 @@ impl FunctionIR {
             Opaque { projection_pd, .. } => *projection_pd,
             #[cfg(feature = "python")]
             OpaquePython(OpaquePythonUdf { projection_pd, .. }) => *projection_pd,
-            Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } => true,
+            Rechunk | FastCount { .. } | Unnest { .. } | Explode { .. } | CacheHint(_) => true,
+            #[cfg(feature = "parquet")]
+            FastStatistics { .. } => true,
             #[cfg(feature = "pivot")]
             Unpivot { .. } => true,
             RowIndex { .. } => true,
@@ -211,14 +291,38 @@ impl FunctionIR {
                 cloud_options,
                 alias,
             } => count::count_rows(sources, scan_type, cloud_options.as_ref(), alias.clone()),
+            #[cfg(feature = "parquet")]
+            FastStatistics {
+                sources,
+                cloud_options,
+                columns,
+                aggs,
+                schema,
+                allow_missing_columns,
+            } => statistics::aggregate_statistics(
+                sources,
+                cloud_options.as_ref(),
+                columns,
+                aggs,
+                schema,
+                *allow_missing_columns,
+            ),
             Rechunk => {
                 df.as_single_chunk_par();
                 Ok(df)
             },
+            CacheHint(_) => Ok(df),
             Unnest { columns: _columns } => {
                 feature_gated!("dtype-struct", df.unnest(_columns.iter().cloned()))
             },
-            Explode { columns, .. } => df.explode(columns.iter().cloned()),
+            Explode {
+                columns,
+                index_name,
+                ..
+            } => match index_name {
+                Some(name) => df.explode_with_index(columns.iter().cloned(), name.clone()),
+                None => df.explode(columns.iter().cloned()),
+            },
             #[cfg(feature = "pivot")]
             Unpivot { args, .. } => {
                 use polars_ops::pivot::UnpivotDF;
@@ -241,6 +345,7 @@ impl Display for FunctionIR {
         use FunctionIR::*;
         match self {
             Opaque { fmt_str, .. } => write!(f, "{fmt_str}"),
+            CacheHint(hint) => write!(f, "CACHE HINT: {hint}"),
             Unnest { columns } => {
                 write!(f, "UNNEST by:")?;
                 let columns = columns.as_ref();
@@ -262,6 +367,26 @@ impl Display for FunctionIR {
                     ScanSourcesDisplay(sources)
                 )
             },
+            #[cfg(feature = "parquet")]
+            FastStatistics {
+                sources,
+                aggs,
+                schema,
+                ..
+            } => {
+                write!(
+                    f,
+                    "FAST STATISTICS (parquet) {} [",
+                    ScanSourcesDisplay(sources)
+                )?;
+                for (i, (agg, name)) in aggs.iter().zip(schema.iter_names()).enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{agg} as \"{name}\"")?;
+                }
+                write!(f, "]")
+            },
             v => {
                 let s: &str = v.into();
                 write!(f, "{s}")
@@ -47,7 +47,9 @@ impl FunctionIR {
                 schema.insert_at_index(0, name, IDX_DTYPE)?;
                 Ok(Cow::Owned(Arc::new(schema)))
             },
-            Rechunk => Ok(Cow::Borrowed(input_schema)),
+            #[cfg(feature = "parquet")]
+            FastStatistics { schema, .. } => Ok(Cow::Owned(schema.clone())),
+            Rechunk | CacheHint(_) => Ok(Cow::Borrowed(input_schema)),
             Unnest { columns: _columns } => {
                 #[cfg(feature = "dtype-struct")]
                 {
@@ -87,7 +89,11 @@ impl FunctionIR {
                 input_schema,
                 name.clone(),
             ))),
-            Explode { schema, columns } => explode_schema(schema, input_schema, columns),
+            Explode {
+                schema,
+                columns,
+                index_name,
+            } => explode_schema(schema, input_schema, columns, index_name.as_ref()),
             #[cfg(feature = "pivot")]
             Unpivot { schema, args } => unpivot_schema(args, schema, input_schema),
         }
@@ -114,6 +120,7 @@ fn explode_schema<'a>(
     cached_schema: &CachedSchema,
     schema: &'a Schema,
     columns: &[PlSmallStr],
+    index_name: Option<&PlSmallStr>,
 ) -> PolarsResult<Cow<'a, SchemaRef>> {
     let mut guard = cached_schema.lock().unwrap();
     if let Some(schema) = &*guard {
@@ -136,6 +143,13 @@ fn explode_schema<'a>(
 
         PolarsResult::Ok(())
     })?;
+    if let Some(name) = index_name {
+        polars_ensure!(
+            !schema.contains(name),
+            Duplicate: "column with name '{}' already exists", name
+        );
+        schema.insert_at_index(0, name.clone(), IDX_DTYPE)?;
+    }
     let schema = Arc::new(schema);
     *guard = Some(schema.clone());
     Ok(Cow::Owned(schema))
@@ -0,0 +1,228 @@
+use std::io::Cursor;
+
+use arrow::datatypes::Field as ArrowField;
+use polars_io::SerReader;
+use polars_io::parquet::metadata::FileMetadataRef;
+use polars_io::parquet::read::ParquetReader;
+#[cfg(feature = "cloud")]
+use polars_io::pl_async::get_runtime;
+use polars_parquet::read::statistics::deserialize_all;
+use polars_parquet::read::{PhysicalType, infer_schema};
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// An aggregation that can be answered from the statistics of a Parquet file.
+#[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum StatisticsAggKind {
+    NullCount,
+    Min,
+    Max,
+}
+
+#[cfg_attr(feature = "ir_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct StatisticsAgg {
+    pub column: PlSmallStr,
+    pub kind: StatisticsAggKind,
+}
+
+impl Display for StatisticsAgg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let kind = match self.kind {
+            StatisticsAggKind::NullCount => "null_count",
+            StatisticsAggKind::Min => "min",
+            StatisticsAggKind::Max => "max",
+        };
+        write!(f, "col(\"{}\").{kind}()", self.column)
+    }
+}
+
+/// Computes the aggregations from the row group statistics of the files.
+///
+/// The statistics of a column are only used if they are present for all row groups of a file.
+/// Otherwise the column is read from that file.
+pub fn aggregate_statistics(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+    columns: &Schema,
+    aggs: &[StatisticsAgg],
+    schema: &Schema,
+    allow_missing_columns: bool,
+) -> PolarsResult<DataFrame> {
+    // The values of the columns in all files, these are the minima and maxima of the row groups
+    // if their statistics are exact.
+    let mut mins: Vec<Series> = columns
+        .iter()
+        .map(|(name, dtype)| Series::new_empty(name.clone(), dtype))
+        .collect();
+    let mut maxs = mins.clone();
+    let mut null_counts: Vec<IdxSize> = vec![0; columns.len()];
+
+    for source in sources.iter() {
+        let metadata = read_metadata(source, cloud_options)?;
+        let file_schema = infer_schema(&metadata)?;
+
+        let mut to_read = Vec::new();
+        for (i, (name, dtype)) in columns.iter().enumerate() {
+            let Some(field) = file_schema.get(name) else {
+                polars_ensure!(
+                    allow_missing_columns,
+                    ColumnNotFound: "did not find column {} in file {}",
+                    name, source.to_include_path_name()
+                );
+                null_counts[i] += metadata.num_rows as IdxSize;
+                continue;
+            };
+
+            match exact_statistics(field, &metadata)? {
+                Some((min, max, null_count)) => {
+                    mins[i].append_owned(min.strict_cast(dtype)?)?;
+                    maxs[i].append_owned(max.strict_cast(dtype)?)?;
+                    null_counts[i] += null_count;
+                },
+                None => to_read.push(i),
+            }
+        }
+
+        if to_read.is_empty() {
+            continue;
+        }
+
+        let names = to_read
+            .iter()
+            .map(|&i| columns.get_at_index(i).unwrap().0.to_string())
+            .collect();
+        let mut reader = ParquetReader::new(Cursor::new(read_file(source, cloud_options)?))
+            .with_columns(Some(names));
+        reader.set_metadata(metadata);
+        let df = reader.finish()?;
+
+        for i in to_read {
+            let (name, dtype) = columns.get_at_index(i).unwrap();
+            let s = df
+                .column(name)?
+                .as_materialized_series()
+                .strict_cast(dtype)?;
+            null_counts[i] += s.null_count() as IdxSize;
+            mins[i].append(&s)?;
+            maxs[i].append_owned(s)?;
+        }
+    }
+
+    let out = aggs
+        .iter()
+        .zip(schema.iter_names())
+        .map(|(agg, name)| {
+            let i = columns.index_of(&agg.column).unwrap();
+            Ok(match agg.kind {
+                StatisticsAggKind::NullCount => Column::new(name.clone(), [null_counts[i]]),
+                StatisticsAggKind::Min => mins[i].min_reduce()?.into_column(name.clone()),
+                StatisticsAggKind::Max => maxs[i].max_reduce()?.into_column(name.clone()),
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new_with_height(1, out)
+}
+
+/// Returns the minima, maxima and total null count of the row groups of a column, if the
+/// statistics are present for all row groups.
+fn exact_statistics(
+    field: &ArrowField,
+    metadata: &FileMetadataRef,
+) -> PolarsResult<Option<(Series, Series, IdxSize)>> {
+    let row_groups = metadata.row_groups.as_slice();
+    let Some(first) = row_groups.first() else {
+        let dtype = DataType::from_arrow_field(field);
+        let empty = Series::new_empty(field.name.clone(), &dtype);
+        return Ok(Some((empty.clone(), empty, 0)));
+    };
+
+    let Some(&[idx]) = first.columns_idxs_under_root_iter(&field.name) else {
+        return Ok(None);
+    };
+    // The sort order of INT96 timestamps is undefined, so their statistics cannot be trusted.
+    if first.parquet_columns()[idx].physical_type() == PhysicalType::Int96 {
+        return Ok(None);
+    }
+    let Some(stats) = deserialize_all(field, row_groups, idx)? else {
+        return Ok(None);
+    };
+
+    let mut null_count = 0;
+    for (i, rg) in row_groups.iter().enumerate() {
+        let Some(nc) = stats.null_count.get(i) else {
+            return Ok(None);
+        };
+        // Only row groups that are all null may lack a minimum and maximum.
+        let all_null = nc as usize == rg.num_rows();
+        if !all_null && (stats.min_value.is_null(i) || stats.max_value.is_null(i)) {
+            return Ok(None);
+        }
+        null_count += nc;
+    }
+
+    let md = field.metadata.as_deref();
+    // SAFETY: The statistics are deserialized to the dtype of the field.
+    let (min, max) = unsafe {
+        (
+            Series::_try_from_arrow_unchecked_with_md(
+                field.name.clone(),
+                vec![stats.min_value],
+                field.dtype(),
+                md,
+            )?,
+            Series::_try_from_arrow_unchecked_with_md(
+                field.name.clone(),
+                vec![stats.max_value],
+                field.dtype(),
+                md,
+            )?,
+        )
+    };
+    Ok(Some((min, max, null_count)))
+}
+
+pub(crate) fn read_metadata(
+    source: ScanSourceRef<'_>,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<FileMetadataRef> {
+    match source {
+        ScanSourceRef::Path(path) if path.is_cloud_url() => feature_gated!("cloud", {
+            use polars_io::prelude::ParquetObjectStore;
+
+            get_runtime().block_on(async {
+                let mut reader =
+                    ParquetObjectStore::from_uri(path.to_str(), cloud_options, None).await?;
+                reader.get_metadata().await.cloned()
+            })
+        }),
+        _ => ParquetReader::new(Cursor::new(source.to_memslice()?))
+            .get_metadata()
+            .cloned(),
+    }
+}
+
+fn read_file(
+    source: ScanSourceRef<'_>,
+    #[allow(unused)] cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<MemSlice> {
+    match source {
+        ScanSourceRef::Path(path) if path.is_cloud_url() => feature_gated!("cloud", {
+            use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+
+            get_runtime().block_on(async {
+                let byte_source = source
+                    .to_dyn_byte_source(&DynByteSourceBuilder::ObjectStore, cloud_options)
+                    .await?;
+                let size = byte_source.get_size().await?;
+                byte_source.get_range(0..size).await
+            })
+        }),
+        _ => source.to_memslice(),
+    }
+}
@@ -0,0 +1,273 @@
+//! Canonical descriptions, fingerprints and diffs of plans.
+use std::fmt::Write;
+#[cfg(feature = "cse")]
+use std::hash::{BuildHasher, Hash, Hasher};
+
+#[cfg(feature = "cse")]
+use polars_core::hashing::columns_to_hashes;
+#[cfg(feature = "cse")]
+use polars_utils::aliases::{
+    PlFixedStateQuality, PlSeedableRandomStateQuality, SeedableFromU64SeedExt,
+};
+use recursive::recursive;
+
+use super::*;
+
+/// Whether the operands of `op` can be swapped without changing the result.
+fn is_commutative(op: Operator) -> bool {
+    use Operator as O;
+    matches!(
+        op,
+        O::Eq
+            | O::EqValidity
+            | O::NotEq
+            | O::NotEqValidity
+            | O::Multiply
+            | O::And
+            | O::Or
+            | O::Xor
+            | O::LogicalAnd
+            | O::LogicalOr
+    )
+}
+
+/// Whether chains of `op` can additionally be regrouped without changing the result.
+fn is_associative(op: Operator) -> bool {
+    use Operator as O;
+    matches!(op, O::And | O::Or | O::Xor | O::LogicalAnd | O::LogicalOr)
+}
+
+fn collect_operands(node: Node, op: Operator, arena: &Arena<AExpr>, out: &mut Vec<Node>) {
+    match arena.get(node) {
+        AExpr::BinaryExpr {
+            left,
+            op: node_op,
+            right,
+        } if *node_op == op => {
+            collect_operands(*left, op, arena, out);
+            collect_operands(*right, op, arena, out);
+        },
+        _ => out.push(node),
+    }
+}
+
+/// Rewrite `node` and its inputs such that the operands of commutative operators are in a
+/// canonical order.
+#[recursive]
+fn canonicalize_expr(node: Node, arena: &mut Arena<AExpr>, done: &mut PlHashSet<Node>) {
+    if !done.insert(node) {
+        return;
+    }
+    let mut inputs = vec![];
+    arena.get(node).inputs_rev(&mut inputs);
+    for input in inputs {
+        canonicalize_expr(input, arena, done);
+    }
+
+    let AExpr::BinaryExpr { left, op, right } = arena.get(node) else {
+        return;
+    };
+    let (left, op, right) = (*left, *op, *right);
+    if !is_commutative(op) {
+        return;
+    }
+    let mut operands = vec![];
+    if is_associative(op) {
+        collect_operands(left, op, arena, &mut operands);
+        collect_operands(right, op, arena, &mut operands);
+    } else {
+        operands.extend([left, right]);
+    }
+
+    let mut operands: Vec<_> = operands
+        .into_iter()
+        .map(|node| (ExprIRDisplay::display_node(node, arena).to_string(), node))
+        .collect();
+    operands.sort_by(|a, b| a.0.cmp(&b.0));
+    let mut operands = operands.into_iter().map(|(_, node)| node);
+    let mut left = operands.next().unwrap();
+    let mut right = operands.next().unwrap();
+    for next in operands {
+        left = arena.add(AExpr::BinaryExpr { left, op, right });
+        right = next;
+    }
+    arena.replace(node, AExpr::BinaryExpr { left, op, right });
+}
+
+/// Replace the ids of the caches in `desc` by the order in which they first appear.
+fn renumber_caches(desc: &str) -> String {
+    const PREFIX: &str = "CACHE[id: ";
+    let mut ids = PlHashMap::new();
+    let mut out = String::with_capacity(desc.len());
+    let mut rest = desc;
+    while let Some(i) = rest.find(PREFIX) {
+        let (head, tail) = rest.split_at(i + PREFIX.len());
+        out.push_str(head);
+        let (id, tail) = tail.split_at(tail.find(',').unwrap_or(tail.len()));
+        let n = ids.len();
+        write!(out, "{}", ids.entry(id).or_insert(n)).unwrap();
+        rest = tail;
+    }
+    out.push_str(rest);
+    out
+}
+
+impl IRPlan {
+    /// Put the operands of commutative operators in a canonical order.
+    fn canonicalize(&mut self) {
+        let mut done = PlHashSet::new();
+        for node in (0..self.expr_arena.len()).map(Node) {
+            canonicalize_expr(node, &mut self.expr_arena, &mut done);
+        }
+    }
+
+    /// Describe the plan such that plans that only differ in the order of the operands of
+    /// commutative operators, or in the ids of their caches, have the same description.
+    pub fn describe_canonical(&mut self) -> String {
+        self.canonicalize();
+        renumber_caches(&self.describe())
+    }
+
+    /// A hash of the plan, which is the same for plans that only differ in the order of the
+    /// operands of commutative operators, or in the ids of their caches.
+    ///
+    /// The whole plan is hashed, not its description, so in-memory data and other details that
+    /// are not shown by [`IRPlan::describe_canonical`] are taken into account. The fingerprint
+    /// is the same across processes and machines, but not necessarily across versions of Polars.
+    #[cfg(feature = "cse")]
+    pub fn fingerprint(&mut self) -> u64 {
+        self.canonicalize();
+        let mut state = PlFixedStateQuality::with_seed(0).build_hasher();
+        hash_plan(
+            self.lp_top,
+            &self.lp_arena,
+            &self.expr_arena,
+            &mut PlHashMap::new(),
+            &mut state,
+        );
+        state.finish()
+    }
+
+    /// Whether the plan calls opaque functions, such as Python UDFs or IO plugins. These are not
+    /// part of the [`IRPlan::fingerprint`], plans that only differ in them have the same
+    /// fingerprint.
+    #[cfg(feature = "cse")]
+    pub fn has_opaque_functions(&self) -> bool {
+        let mut exprs = vec![];
+        self.lp_arena.iter(self.lp_top).any(|(_, ir)| {
+            exprs.clear();
+            ir.copy_exprs(&mut exprs);
+            is_opaque(ir)
+                || exprs.iter().any(|e| {
+                    self.expr_arena
+                        .iter(e.node())
+                        .any(|(_, ae)| matches!(ae, AExpr::AnonymousFunction { .. }))
+                })
+        })
+    }
+}
+
+#[cfg(feature = "cse")]
+fn is_opaque(ir: &IR) -> bool {
+    match ir {
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. }
+        | IR::MapFunction {
+            function: FunctionIR::OpaquePython(_),
+            ..
+        } => true,
+        IR::MapFunction {
+            function: FunctionIR::Opaque { .. },
+            ..
+        } => true,
+        _ => false,
+    }
+}
+
+/// Hash the plan rooted at `node`, renumbering the ids of caches in the order they are first
+/// visited.
+#[cfg(feature = "cse")]
+#[recursive]
+fn hash_plan<H: Hasher>(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+    cache_ids: &mut PlHashMap<UniqueId, usize>,
+    state: &mut H,
+) {
+    let ir = lp_arena.get(node);
+    match ir {
+        // In-memory frames are hashed by value instead of by pointer.
+        IR::DataFrameScan {
+            df, output_schema, ..
+        } => {
+            std::mem::discriminant(ir).hash(state);
+            df.height().hash(state);
+            if df.width() > 0 {
+                let mut hashes = vec![];
+                if columns_to_hashes(
+                    df.get_columns(),
+                    Some(PlSeedableRandomStateQuality::seed_from_u64(0)),
+                    &mut hashes,
+                )
+                .is_ok()
+                {
+                    hashes.hash(state);
+                }
+            }
+            df.schema().hash(state);
+            output_schema.hash(state);
+        },
+        IR::Cache { id, cache_hits, .. } => {
+            std::mem::discriminant(ir).hash(state);
+            let n = cache_ids.len();
+            cache_ids.entry(*id).or_insert(n).hash(state);
+            cache_hits.hash(state);
+        },
+        _ => IRNode::new(node)
+            .hashable_and_cmp(lp_arena, expr_arena)
+            .hash(state),
+    }
+    for &input in ir.get_inputs().iter() {
+        hash_plan(input, lp_arena, expr_arena, cache_ids, state);
+    }
+}
+
+/// A line based diff between the plan descriptions `a` and `b`, see
+/// [`IRPlan::describe_canonical`].
+///
+/// Lines only in `a` are prefixed with `- `, lines only in `b` with `+ ` and lines in both
+/// with two spaces.
+pub fn diff_plan_descriptions(a: &str, b: &str) -> String {
+    let a: Vec<_> = a.lines().collect();
+    let b: Vec<_> = b.lines().collect();
+
+    // `lcs[i][j]` is the length of the longest common subsequence of `a[i..]` and `b[j..]`.
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            writeln!(out, "  {}", a[i]).unwrap();
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            writeln!(out, "- {}", a[i]).unwrap();
+            i += 1;
+        } else {
+            writeln!(out, "+ {}", b[j]).unwrap();
+            j += 1;
+        }
+    }
+    out
+}
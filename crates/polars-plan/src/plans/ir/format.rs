@@ -8,12 +8,15 @@ use recursive::recursive;
 
 use self::ir::dot::ScanSourcesDisplay;
 use crate::dsl::deletion::DeletionFilesList;
+use crate::dsl::table_statistics::TableStatistics;
 use crate::prelude::*;
 
 const INDENT_INCREMENT: usize = 2;
 
 pub struct IRDisplay<'a> {
     lp: IRPlanRef<'a>,
+    node_ids: Option<Arc<PlIndexMap<Node, usize>>>,
+    annotations: Option<Arc<PlHashMap<Node, String>>>,
 }
 
 #[derive(Clone, Copy)]
@@ -74,6 +77,7 @@ fn write_scan(
     pre_slice: Option<Slice>,
     row_index: Option<&RowIndex>,
     deletion_files: Option<&DeletionFilesList>,
+    table_statistics: Option<&TableStatistics>,
 ) -> fmt::Result {
     write!(
         f,
@@ -107,12 +111,31 @@ fn write_scan(
     if let Some(deletion_files) = deletion_files {
         write!(f, "\n{deletion_files}")?;
     }
+    if let Some(table_statistics) = table_statistics {
+        write!(f, "\n{table_statistics}")?;
+    }
     Ok(())
 }
 
 impl<'a> IRDisplay<'a> {
     pub fn new(lp: IRPlanRef<'a>) -> Self {
-        Self { lp }
+        Self {
+            lp,
+            node_ids: None,
+            annotations: None,
+        }
+    }
+
+    /// Prefix every node with its id, see [`IRPlanRef::node_ids`].
+    pub fn with_node_ids(mut self) -> Self {
+        self.node_ids = Some(Arc::new(self.lp.node_ids()));
+        self
+    }
+
+    /// Append the annotation of a node to its first line, e.g. statistics of an execution.
+    pub fn with_annotations(mut self, annotations: PlHashMap<Node, String>) -> Self {
+        self.annotations = Some(Arc::new(annotations));
+        self
     }
 
     fn root(&self) -> &IR {
@@ -122,6 +145,8 @@ impl<'a> IRDisplay<'a> {
     fn with_root(&self, root: Node) -> Self {
         Self {
             lp: self.lp.with_root(root),
+            node_ids: self.node_ids.clone(),
+            annotations: self.annotations.clone(),
         }
     }
 
@@ -140,6 +165,42 @@ impl<'a> IRDisplay<'a> {
         }
     }
 
+    /// Write the header of the current node with `header`, with the id of the node after the
+    /// indentation and its annotation at the end of the first line. Only the header is buffered,
+    /// so that formatting stays linear in the size of the plan.
+    fn write_header(
+        &self,
+        f: &mut Formatter,
+        header: impl FnOnce(&mut dyn fmt::Write) -> fmt::Result,
+    ) -> fmt::Result {
+        let id = self
+            .node_ids
+            .as_ref()
+            .and_then(|ids| ids.get(&self.lp.lp_top));
+        let annotation = self
+            .annotations
+            .as_ref()
+            .and_then(|annotations| annotations.get(&self.lp.lp_top));
+        if id.is_none() && annotation.is_none() {
+            return header(f);
+        }
+
+        let mut buf = String::new();
+        header(&mut buf)?;
+        let node = buf.trim_start_matches(' ');
+        let pad = &buf[..buf.len() - node.len()];
+        let (first_line, rest) = node.split_at(node.find('\n').unwrap_or(node.len()));
+        write!(f, "{pad}")?;
+        if let Some(id) = id {
+            write!(f, "[{id}] ")?;
+        }
+        write!(f, "{first_line}")?;
+        if let Some(annotation) = annotation {
+            write!(f, "  {annotation}")?;
+        }
+        write!(f, "{rest}")
+    }
+
     #[recursive]
     fn _format(&self, f: &mut Formatter, indent: usize) -> fmt::Result {
         if indent != 0 {
@@ -154,7 +215,9 @@ impl<'a> IRDisplay<'a> {
         let output_schema = output_schema.as_ref();
         match ir_node {
             Union { inputs, options } => {
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)
+                })?;
                 let name = if let Some(slice) = options.slice {
                     format!("SLICED UNION: {slice:?}")
                 } else {
@@ -174,7 +237,9 @@ impl<'a> IRDisplay<'a> {
             },
             HConcat { inputs, .. } => {
                 let sub_sub_indent = sub_indent + INDENT_INCREMENT;
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)
+                })?;
                 for (i, plan) in inputs.iter().enumerate() {
                     write!(f, "\n{:sub_indent$}PLAN {i}:", "")?;
                     self.with_root(*plan)._format(f, sub_sub_indent)?;
@@ -182,7 +247,9 @@ impl<'a> IRDisplay<'a> {
                 write!(f, "\n{:indent$}END HCONCAT", "")
             },
             GroupBy { input, .. } => {
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)
+                })?;
                 write!(f, "\n{:sub_indent$}FROM", "")?;
                 self.with_root(*input)._format(f, sub_indent)?;
                 Ok(())
@@ -202,7 +269,9 @@ impl<'a> IRDisplay<'a> {
                 if let Some(JoinTypeOptionsIR::Cross { predicate }) = &options.options {
                     let predicate = self.display_expr(predicate);
                     let name = "NESTED LOOP";
-                    write!(f, "{:indent$}{name} JOIN ON {predicate}:", "")?;
+                    self.write_header(f, |f| {
+                        write!(f, "{:indent$}{name} JOIN ON {predicate}:", "")
+                    })?;
                     write!(f, "\n{:indent$}LEFT PLAN:", "")?;
                     self.with_root(*input_left)._format(f, sub_indent)?;
                     write!(f, "\n{:indent$}RIGHT PLAN:", "")?;
@@ -210,7 +279,7 @@ impl<'a> IRDisplay<'a> {
                     write!(f, "\n{:indent$}END {name} JOIN", "")
                 } else {
                     let how = &options.args.how;
-                    write!(f, "{:indent$}{how} JOIN:", "")?;
+                    self.write_header(f, |f| write!(f, "{:indent$}{how} JOIN:", ""))?;
                     write!(f, "\n{:indent$}LEFT PLAN ON: {left_on}", "")?;
                     self.with_root(*input_left)._format(f, sub_indent)?;
                     write!(f, "\n{:indent$}RIGHT PLAN ON: {right_on}", "")?;
@@ -219,11 +288,15 @@ impl<'a> IRDisplay<'a> {
                 }
             },
             MapFunction { input, .. } => {
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)
+                })?;
                 self.with_root(*input)._format(f, sub_indent)
             },
             SinkMultiple { inputs } => {
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)
+                })?;
 
                 // 3 levels of indentation
                 // - 0 => SINK_MULTIPLE ... END SINK_MULTIPLE
@@ -242,8 +315,10 @@ impl<'a> IRDisplay<'a> {
                 input_right,
                 key: _,
             } => {
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
-                write!(f, ":")?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                    write!(f, ":")
+                })?;
 
                 write!(f, "\n{:indent$}LEFT PLAN:", "")?;
                 self.with_root(*input_left)._format(f, sub_indent)?;
@@ -252,7 +327,9 @@ impl<'a> IRDisplay<'a> {
                 write!(f, "\n{:indent$}END MERGE_SORTED", "")
             },
             ir_node => {
-                write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)?;
+                self.write_header(f, |f| {
+                    write_ir_non_recursive(f, ir_node, self.lp.expr_arena, output_schema, indent)
+                })?;
                 for input in ir_node.get_inputs().iter() {
                     self.with_root(*input)._format(f, sub_indent)?;
                 }
@@ -522,6 +599,7 @@ impl Display for ExprIRDisplay<'_> {
                 let evaluation = self.with_root(evaluation);
                 match variant {
                     EvalVariant::List => write!(f, "{expr}.list.eval({evaluation})"),
+                    EvalVariant::ListAgg => write!(f, "{expr}.list.agg({evaluation})"),
                     EvalVariant::Cumulative { min_samples } => write!(
                         f,
                         "{expr}.cumulative_eval({evaluation}, min_samples={min_samples})"
@@ -682,6 +760,7 @@ pub fn write_ir_non_recursive(
                     .map(|len| polars_utils::slice_enum::Slice::Positive { offset: 0, len }),
                 None,
                 None,
+                None,
             )
         },
         IR::Slice {
@@ -728,6 +807,7 @@ pub fn write_ir_non_recursive(
                 unified_scan_args.pre_slice.clone(),
                 unified_scan_args.row_index.as_ref(),
                 unified_scan_args.deletion_files.as_ref(),
+                unified_scan_args.table_statistics.as_ref(),
             )
         },
         IR::DataFrameScan {
@@ -0,0 +1,235 @@
+//! The lineage of the columns of a plan: the source columns and expressions they derive from.
+use recursive::recursive;
+
+use super::*;
+use crate::utils::aexpr_to_leaf_names_iter;
+
+/// A column of a source of a plan.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SourceColumn {
+    /// The path of the scanned file, or `"DF"` for a DataFrame in memory.
+    pub source: PlSmallStr,
+    pub column: PlSmallStr,
+}
+
+/// What a column of a plan derives from, see [`IRPlan::column_lineage`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnLineage {
+    /// The source columns of which the values flow into the column.
+    pub sources: PlIndexSet<SourceColumn>,
+    /// The expressions that compute the column, starting at the ones closest to the sources.
+    pub expressions: PlIndexSet<String>,
+}
+
+impl ColumnLineage {
+    fn extend(&mut self, other: &Self) {
+        self.sources.extend(other.sources.iter().cloned());
+        self.expressions.extend(other.expressions.iter().cloned());
+    }
+}
+
+type Lineages = PlIndexMap<PlSmallStr, ColumnLineage>;
+
+fn source_lineages(schema: &Schema, sources: &[PlSmallStr]) -> Lineages {
+    schema
+        .iter_names()
+        .map(|name| {
+            let sources = sources
+                .iter()
+                .map(|source| SourceColumn {
+                    source: source.clone(),
+                    column: name.clone(),
+                })
+                .collect();
+            let lineage = ColumnLineage {
+                sources,
+                expressions: PlIndexSet::default(),
+            };
+            (name.clone(), lineage)
+        })
+        .collect()
+}
+
+fn expr_lineage(expr: &ExprIR, input: &Lineages, expr_arena: &Arena<AExpr>) -> ColumnLineage {
+    let mut lineage = ColumnLineage::default();
+    for name in aexpr_to_leaf_names_iter(expr.node(), expr_arena) {
+        if let Some(input_lineage) = input.get(&name) {
+            lineage.extend(input_lineage);
+        }
+    }
+    if !matches!(expr_arena.get(expr.node()), AExpr::Column(_)) {
+        lineage
+            .expressions
+            .insert(expr.display(expr_arena).to_string());
+    }
+    lineage
+}
+
+/// Complete the lineage of the columns in `schema` with the ones that aren't `computed` by the
+/// node, which pass through from its inputs. A column that none of the inputs has is created by
+/// the node without an expression, e.g. the boundaries of dynamic groups, and derives from all
+/// columns of the inputs.
+fn complete_lineages(schema: &Schema, mut computed: Lineages, inputs: &[Lineages]) -> Lineages {
+    schema
+        .iter_names()
+        .map(|name| {
+            if let Some(lineage) = computed.swap_remove(name) {
+                return (name.clone(), lineage);
+            }
+            let mut lineage = ColumnLineage::default();
+            let mut passes_through = false;
+            for input_lineage in inputs.iter().filter_map(|input| input.get(name)) {
+                lineage.extend(input_lineage);
+                passes_through = true;
+            }
+            if !passes_through {
+                for input_lineage in inputs.iter().flat_map(|input| input.values()) {
+                    lineage.extend(input_lineage);
+                }
+            }
+            (name.clone(), lineage)
+        })
+        .collect()
+}
+
+struct LineageVisitor<'a> {
+    lp_arena: &'a Arena<IR>,
+    expr_arena: &'a Arena<AExpr>,
+    /// The lineages of the nodes visited so far, as the inputs of caches are shared.
+    visited: PlHashMap<Node, Lineages>,
+}
+
+impl LineageVisitor<'_> {
+    #[recursive]
+    fn visit(&mut self, node: Node) -> Lineages {
+        if let Some(lineages) = self.visited.get(&node) {
+            return lineages.clone();
+        }
+
+        let (lp_arena, expr_arena) = (self.lp_arena, self.expr_arena);
+        let ir = lp_arena.get(node);
+        let schema = ir.schema(lp_arena).into_owned();
+        let mut input_nodes = vec![];
+        ir.copy_inputs(&mut input_nodes);
+        let inputs = input_nodes
+            .into_iter()
+            .map(|input| self.visit(input))
+            .collect::<Vec<_>>();
+        let exprs_lineages = |exprs: &[ExprIR]| -> Lineages {
+            let computed = exprs
+                .iter()
+                .map(|e| {
+                    (
+                        e.output_name().clone(),
+                        expr_lineage(e, &inputs[0], expr_arena),
+                    )
+                })
+                .collect();
+            complete_lineages(&schema, computed, &inputs)
+        };
+
+        let lineages = match ir {
+            IR::Scan { sources, .. } => {
+                let sources = if sources.is_empty() {
+                    vec![PlSmallStr::from_static("ANONYMOUS SCAN")]
+                } else {
+                    sources
+                        .iter()
+                        .map(|source| source.to_include_path_name().into())
+                        .collect()
+                };
+                source_lineages(&schema, &sources)
+            },
+            IR::DataFrameScan { .. } => source_lineages(&schema, &["DF".into()]),
+            #[cfg(feature = "python")]
+            IR::PythonScan { .. } => source_lineages(&schema, &["PYTHON SCAN".into()]),
+            IR::Select { expr, .. } => exprs_lineages(expr),
+            IR::HStack { exprs, .. } => exprs_lineages(exprs),
+            IR::GroupBy { keys, aggs, .. } => {
+                exprs_lineages(&[keys.as_slice(), aggs.as_slice()].concat())
+            },
+            IR::Join {
+                left_on,
+                right_on,
+                options,
+                ..
+            } => {
+                let (left, right) = (&inputs[0], &inputs[1]);
+                let suffix = options.args.suffix().as_str();
+                schema
+                    .iter_names()
+                    .map(|name| {
+                        let mut lineage = match left.get(name) {
+                            Some(lineage) => lineage.clone(),
+                            // The right columns of which the name is also in the left table get
+                            // a suffix.
+                            None => name
+                                .strip_suffix(suffix)
+                                .filter(|stripped| left.contains_key(*stripped))
+                                .and_then(|stripped| right.get(stripped))
+                                .or_else(|| right.get(name))
+                                .cloned()
+                                .unwrap_or_default(),
+                        };
+                        // A coalesced key takes its values from both tables.
+                        if options.args.should_coalesce() {
+                            for (l, r) in left_on.iter().zip(right_on) {
+                                if l.output_name() == name {
+                                    lineage.extend(&expr_lineage(r, right, expr_arena));
+                                }
+                            }
+                        }
+                        (name.clone(), lineage)
+                    })
+                    .collect()
+            },
+            _ => complete_lineages(&schema, Lineages::default(), &inputs),
+        };
+        self.visited.insert(node, lineages.clone());
+        lineages
+    }
+}
+
+impl IRPlan {
+    /// The lineage of each output column of the plan: the source columns of which the values flow
+    /// into it, and the expressions that compute it.
+    ///
+    /// Filters, sorts and other nodes that only select rows don't add to the lineage.
+    pub fn column_lineage(&self) -> PlIndexMap<PlSmallStr, ColumnLineage> {
+        LineageVisitor {
+            lp_arena: &self.lp_arena,
+            expr_arena: &self.expr_arena,
+            visited: PlHashMap::new(),
+        }
+        .visit(self.lp_top)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_complete_lineages() {
+        let lineage = |column: &str| ColumnLineage {
+            sources: [SourceColumn {
+                source: "DF".into(),
+                column: column.into(),
+            }]
+            .into_iter()
+            .collect(),
+            expressions: PlIndexSet::default(),
+        };
+        let input: Lineages = [("a".into(), lineage("a")), ("b".into(), lineage("b"))]
+            .into_iter()
+            .collect();
+        let schema = Schema::from_iter([
+            Field::new("b".into(), DataType::Int32),
+            Field::new("c".into(), DataType::Int32),
+        ]);
+
+        let out = complete_lineages(&schema, Lineages::default(), &[input]);
+        assert_eq!(out["b"], lineage("b"));
+        assert_eq!(out["c"].sources.len(), 2);
+    }
+}
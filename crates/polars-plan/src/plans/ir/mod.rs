@@ -1,6 +1,8 @@
 mod dot;
+mod fingerprint;
 mod format;
 mod inputs;
+mod lineage;
 mod schema;
 pub(crate) mod tree_format;
 
@@ -8,7 +10,9 @@ use std::borrow::Cow;
 use std::fmt;
 
 pub use dot::{EscapeLabel, IRDotDisplay, PathsDisplay, ScanSourcesDisplay};
+pub use fingerprint::diff_plan_descriptions;
 pub use format::{ExprIRDisplay, IRDisplay, write_group_by, write_ir_non_recursive};
+pub use lineage::{ColumnLineage, SourceColumn};
 use polars_core::prelude::*;
 use polars_utils::idx_vec::UnitVec;
 use polars_utils::unique_id::UniqueId;
@@ -189,6 +193,10 @@ impl IRPlan {
         self.as_ref().describe()
     }
 
+    pub fn describe_with_node_ids(&self) -> String {
+        self.as_ref().describe_with_node_ids()
+    }
+
     pub fn describe_tree_format(&self) -> String {
         self.as_ref().describe_tree_format()
     }
@@ -227,6 +235,28 @@ impl<'a> IRPlanRef<'a> {
         self.display().to_string()
     }
 
+    /// Describe the plan with the id of every node, see [`IRPlanRef::node_ids`].
+    pub fn describe_with_node_ids(self) -> String {
+        self.display().with_node_ids().to_string()
+    }
+
+    /// Number the nodes of the plan in the order in which they are displayed, starting with 0 for
+    /// the root. A node that is the input of several nodes keeps the id of its first occurrence.
+    pub fn node_ids(self) -> PlIndexMap<Node, usize> {
+        let mut ids = PlIndexMap::new();
+        let mut stack = vec![self.lp_top];
+        let mut inputs = vec![];
+        while let Some(node) = stack.pop() {
+            if ids.contains_key(&node) {
+                continue;
+            }
+            ids.insert(node, ids.len());
+            self.lp_arena.get(node).copy_inputs(&mut inputs);
+            stack.extend(inputs.drain(..).rev());
+        }
+        ids
+    }
+
     pub fn describe_tree_format(self) -> String {
         let mut visitor = tree_format::TreeFmtVisitor::default();
         tree_format::TreeFmtNode::root_logical_plan(self).traverse(&mut visitor);
@@ -100,6 +100,7 @@ macro_rules! push_expr {
                 $push($c, input);
             },
             KeepName(e) => $push($c, e),
+            Cached(e) => $push($c, e),
             RenameAlias { expr, .. } => $push($c, expr),
             SubPlan { .. } => {},
             // pass
@@ -316,8 +316,11 @@ fn insert_fitting_join(
 
             (eq_left_on, eq_right_on, remaining_predicates)
         },
+        // The IEJoin does not keep the order of its inputs.
         #[cfg(feature = "iejoin")]
-        _ if !ie_op.is_empty() => {
+        _ if !ie_op.is_empty()
+            && matches!(options.args.maintain_order, MaintainOrderJoin::None) =>
+        {
             // We can only IE join up to 2 operators
 
             let operator1 = ie_op[0];
@@ -34,6 +34,8 @@ pub(super) struct MemberCollector {
     pub(crate) has_sort: bool,
     pub(crate) has_group_by: bool,
     #[cfg(feature = "cse")]
+    pub(crate) has_cache_hint: bool,
+    #[cfg(feature = "cse")]
     scans: UniqueScans,
 }
 
@@ -49,6 +51,8 @@ impl MemberCollector {
             has_sort: false,
             has_group_by: false,
             #[cfg(feature = "cse")]
+            has_cache_hint: false,
+            #[cfg(feature = "cse")]
             scans: UniqueScans::default(),
         }
     }
@@ -83,6 +87,11 @@ impl MemberCollector {
                 DataFrameScan { .. } => {
                     self.scans.insert(_node, lp_arena, _expr_arena);
                 },
+                #[cfg(feature = "cse")]
+                MapFunction {
+                    function: FunctionIR::CacheHint(_),
+                    ..
+                } => self.has_cache_hint = true,
                 _ => {},
             }
         }
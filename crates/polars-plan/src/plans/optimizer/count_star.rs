@@ -42,6 +42,20 @@ impl OptimizationRule for CountStar {
             use_fast_file_count,
         )
         .map(|count_star_expr| {
+            if let Some(row_count) = count_star_expr.row_count {
+                let column_name = count_star_expr
+                    .alias
+                    .unwrap_or(PlSmallStr::from_static(crate::constants::LEN));
+                let df = DataFrame::new(vec![Column::new(column_name, [row_count])]).unwrap();
+                let alp = IR::DataFrameScan {
+                    schema: df.schema().clone(),
+                    df: Arc::new(df),
+                    output_schema: None,
+                };
+                lp_arena.replace(count_star_expr.node, alp.clone());
+                return alp;
+            }
+
             // MapFunction needs a leaf node, hence we create a dummy placeholder node
             let placeholder = IR::DataFrameScan {
                 df: Arc::new(Default::default()),
@@ -76,6 +90,8 @@ struct CountStarExpr {
     scan_type: Box<FileScanIR>,
     // Column Alias
     alias: Option<PlSmallStr>,
+    // Row count that is known without reading the files
+    row_count: Option<IdxSize>,
 }
 
 // Visit the logical plan and return CountStarExpr with the expr information gathered
@@ -97,6 +113,7 @@ fn visit_logical_plan_for_scan_paths(
             let mut scan_type: Option<Box<FileScanIR>> = None;
             let mut cloud_options = None;
             let mut sources = None;
+            let mut row_count = Some(0);
 
             for input in inputs {
                 match visit_logical_plan_for_scan_paths(
@@ -125,6 +142,8 @@ fn visit_logical_plan_for_scan_paths(
                             _ => return None,
                         }
 
+                        row_count = row_count.zip(expr.row_count).map(|(a, b)| a + b);
+
                         // Take the first Some(_) cloud option
                         // TODO: Should check the cloud types are the same.
                         cloud_options = cloud_options.or(expr.cloud_options);
@@ -154,14 +173,46 @@ fn visit_logical_plan_for_scan_paths(
                 cloud_options,
                 node,
                 alias: None,
+                row_count,
             })
         },
         IR::Scan {
             scan_type,
             sources,
             unified_scan_args,
+            predicate,
             ..
         } => {
+            // Only the rows that match the JSON Schema are counted, which requires reading them.
+            #[cfg(feature = "json")]
+            if let FileScanIR::NDJson { options } = scan_type.as_ref() {
+                if options.json_schema.is_some() {
+                    return None;
+                }
+            }
+
+            // Pre-computed statistics give the row count without reading the files.
+            let row_count = unified_scan_args
+                .table_statistics
+                .as_ref()
+                .and_then(|s| s.num_rows())
+                .filter(|_| {
+                    predicate.is_none()
+                        && unified_scan_args.pre_slice.is_none()
+                        && unified_scan_args.deletion_files.is_none()
+                        && use_fast_file_count != Some(false)
+                });
+            if row_count.is_some() {
+                return Some(CountStarExpr {
+                    sources: sources.clone(),
+                    scan_type: scan_type.clone(),
+                    cloud_options: unified_scan_args.cloud_options.clone(),
+                    node,
+                    alias: None,
+                    row_count,
+                });
+            }
+
             // New-streaming is generally on par for all except CSV (see https://github.com/pola-rs/polars/pull/22363).
             // In the future we can potentially remove the dedicated count codepaths.
 
@@ -178,6 +229,7 @@ fn visit_logical_plan_for_scan_paths(
                     cloud_options: unified_scan_args.cloud_options.clone(),
                     node,
                     alias: None,
+                    row_count: None,
                 })
             } else {
                 None
@@ -399,6 +399,12 @@ impl ExprIdentifierVisitor<'_> {
                 function: IRFunctionExpr::Random { .. },
                 ..
             } => REFUSE_NO_MEMBER,
+            // Every evaluation must produce new IDs.
+            #[cfg(feature = "unique_id")]
+            AExpr::Function {
+                function: IRFunctionExpr::Uuid4 | IRFunctionExpr::SnowflakeId { .. },
+                ..
+            } => REFUSE_NO_MEMBER,
             #[cfg(feature = "rolling_window")]
             AExpr::Function {
                 function: IRFunctionExpr::RollingExpr { .. },
@@ -138,14 +138,50 @@ impl<V> Default for IdentifierMap<V> {
 
 /// Identifier maps to Expr Node and count.
 type SubPlanCount = IdentifierMap<(Node, u32)>;
-/// (post_visit_idx, identifier);
-type IdentifierArray = Vec<(usize, Identifier)>;
+/// (post_visit_idx, identifier, recompute cost);
+type IdentifierArray = Vec<(usize, Identifier, u32)>;
+
+/// The cost of materializing the output of a sub-plan in a cache, in the units of
+/// [`recompute_cost`].
+const CACHE_COST: u32 = 1;
+
+/// A rough estimate of the cost of recomputing `lp`, excluding its inputs.
+fn recompute_cost(lp: &IR) -> u32 {
+    match lp {
+        // These don't compute anything, or are cheap views of their inputs.
+        IR::DataFrameScan { .. }
+        | IR::SimpleProjection { .. }
+        | IR::Slice { .. }
+        | IR::Cache { .. }
+        | IR::Union { .. }
+        | IR::HConcat { .. }
+        | IR::MapFunction {
+            function: FunctionIR::CacheHint(_),
+            ..
+        } => 0,
+        // These read from outside of memory, or combine or reorder the rows of their inputs.
+        IR::Scan { .. }
+        | IR::GroupBy { .. }
+        | IR::Join { .. }
+        | IR::Sort { .. }
+        | IR::Distinct { .. } => 8,
+        #[cfg(feature = "python")]
+        IR::PythonScan { .. } => 8,
+        _ => 2,
+    }
+}
+
+/// Whether caching a sub-plan that occurs `count` times saves more than it costs.
+fn is_worth_caching(cost: u32, count: u32) -> bool {
+    cost.saturating_mul(count - 1) > CACHE_COST
+}
 
 /// See Expr based CSE for explanations.
 enum VisitRecord {
     /// Entered a new plan node
     Entered(usize),
-    SubPlanId(Identifier),
+    /// The identifier and the recompute cost of a sub-plan.
+    SubPlanId(Identifier, u32),
 }
 
 struct LpIdentifierVisitor<'a> {
@@ -173,14 +209,16 @@ impl LpIdentifierVisitor<'_> {
         }
     }
 
-    fn pop_until_entered(&mut self) -> (usize, Identifier) {
+    fn pop_until_entered(&mut self) -> (usize, Identifier, u32) {
         let mut id = Identifier::new();
+        let mut cost = 0u32;
 
         while let Some(item) = self.visit_stack.pop() {
             match item {
-                VisitRecord::Entered(idx) => return (idx, id),
-                VisitRecord::SubPlanId(s) => {
+                VisitRecord::Entered(idx) => return (idx, id, cost),
+                VisitRecord::SubPlanId(s, input_cost) => {
                     id.combine(&s);
+                    cost = cost.saturating_add(input_cost);
                 },
             }
         }
@@ -212,7 +250,7 @@ impl Visitor for LpIdentifierVisitor<'_> {
             .push(VisitRecord::Entered(self.pre_visit_idx));
         self.pre_visit_idx += 1;
 
-        self.identifier_array.push((0, Identifier::new()));
+        self.identifier_array.push((0, Identifier::new(), 0));
 
         if skip_children(node.to_alp(&arena.0)) {
             Ok(VisitRecursion::Skip)
@@ -228,17 +266,19 @@ impl Visitor for LpIdentifierVisitor<'_> {
     ) -> PolarsResult<VisitRecursion> {
         self.post_visit_idx += 1;
 
-        let (pre_visit_idx, sub_plan_id) = self.pop_until_entered();
+        let (pre_visit_idx, sub_plan_id, inputs_cost) = self.pop_until_entered();
 
         // Create the Id of this node.
         let id: Identifier = sub_plan_id.add_alp_node(node, &arena.0, &arena.1);
+        let cost = inputs_cost.saturating_add(recompute_cost(node.to_alp(&arena.0)));
 
         // Store the created id.
-        self.identifier_array[pre_visit_idx] = (self.post_visit_idx, id.clone());
+        self.identifier_array[pre_visit_idx] = (self.post_visit_idx, id.clone(), cost);
 
         // We popped until entered, push this Id on the stack so the trail
         // is available for the parent plan.
-        self.visit_stack.push(VisitRecord::SubPlanId(id.clone()));
+        self.visit_stack
+            .push(VisitRecord::SubPlanId(id.clone(), cost));
 
         let (_, sp_count) = self
             .sp_count
@@ -295,7 +335,7 @@ impl RewritingVisitor for CommonSubPlanRewriter<'_> {
             return Ok(RewriteRecursion::Stop);
         }
 
-        let id = &self.identifier_array[self.visited_idx].1;
+        let (post_visit_idx, id, cost) = &self.identifier_array[self.visited_idx];
 
         // Id placeholder not overwritten, so we can skip this sub-expression.
         if !id.is_valid() {
@@ -303,16 +343,36 @@ impl RewritingVisitor for CommonSubPlanRewriter<'_> {
             return Ok(RewriteRecursion::NoMutateAndContinue);
         }
 
+        let hint = match lp_node.to_alp(&arena.0) {
+            IR::MapFunction {
+                function: FunctionIR::CacheHint(hint),
+                ..
+            } => Some(*hint),
+            _ => None,
+        };
+        if hint == Some(CacheHint::Never) {
+            // Don't cache this sub-plan or any of its inputs.
+            let post_visit_idx = *post_visit_idx;
+            self.visited_idx += 1;
+            while self.visited_idx < self.identifier_array.len()
+                && post_visit_idx > self.identifier_array[self.visited_idx].0
+            {
+                self.visited_idx += 1;
+            }
+            return Ok(RewriteRecursion::Stop);
+        }
+
         let Some((_, count)) = self.sp_count.get(id, &arena.0, &arena.1) else {
             self.visited_idx += 1;
             return Ok(RewriteRecursion::NoMutateAndContinue);
         };
 
-        if *count > 1 {
+        if *count > 1 && (hint == Some(CacheHint::Always) || is_worth_caching(*cost, *count)) {
             // Rewrite this sub-plan, don't visit its children
             Ok(RewriteRecursion::MutateAndStop)
         }
-        // Never mutate if count <= 1. The post-visit will search for the node, and not be able to find it
+        // Never mutate if count <= 1. The post-visit will search for the node, and not be able to find it.
+        // Sub-plans that are cheaper to recompute than to cache are not mutated either.
         else {
             // Don't traverse the children.
             if skip_children(lp_node.to_alp(&arena.0)) {
@@ -330,7 +390,7 @@ impl RewritingVisitor for CommonSubPlanRewriter<'_> {
         mut node: Self::Node,
         arena: &mut Self::Arena,
     ) -> PolarsResult<Self::Node> {
-        let (post_visit_count, id) = &self.identifier_array[self.visited_idx];
+        let (post_visit_count, id, _) = &self.identifier_array[self.visited_idx];
         self.visited_idx += 1;
 
         if *post_visit_count < self.max_post_visit_idx {
@@ -407,3 +467,19 @@ pub(crate) fn prune_unused_caches(lp_arena: &mut Arena<IR>, cid2c: CacheId2Cache
         }
     }
 }
+
+/// Remove the [`CacheHint`] nodes, which are only used by common subplan elimination.
+pub(crate) fn remove_cache_hints(root: Node, lp_arena: &mut Arena<IR>) {
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        while let IR::MapFunction {
+            input,
+            function: FunctionIR::CacheHint(_),
+        } = lp_arena.get(node)
+        {
+            let input = lp_arena.get(*input).clone();
+            lp_arena.replace(node, input);
+        }
+        lp_arena.get(node).copy_inputs(&mut stack);
+    }
+}
@@ -3,7 +3,7 @@ mod cse_lp;
 
 pub(super) use cse_expr::CommonSubExprOptimizer;
 pub use cse_expr::NaiveExprMerger;
-pub(super) use cse_lp::{elim_cmn_subplans, prune_unused_caches};
+pub(super) use cse_lp::{elim_cmn_subplans, prune_unused_caches, remove_cache_hints};
 
 use super::*;
 
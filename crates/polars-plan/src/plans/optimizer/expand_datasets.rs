@@ -131,6 +131,8 @@ impl OptimizationRule for ExpandDatasets {
                                 include_file_paths: _include_file_paths @ None,
                                 deletion_files,
                                 column_mapping,
+                                table_statistics,
+                                file_error_policy: _,
                             } = *resolved_unified_scan_args
                             else {
                                 panic!(
@@ -147,6 +149,7 @@ impl OptimizationRule for ExpandDatasets {
                             unified_scan_args.extra_columns_policy = extra_columns_policy;
                             unified_scan_args.deletion_files = deletion_files;
                             unified_scan_args.column_mapping = column_mapping;
+                            unified_scan_args.table_statistics = table_statistics;
 
                             *sources = resolved_sources;
                             *scan_type = Box::new(match *resolved_scan_type {
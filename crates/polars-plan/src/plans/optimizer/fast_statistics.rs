@@ -0,0 +1,149 @@
+use super::*;
+
+pub(super) struct FastStatistics;
+
+impl FastStatistics {
+    pub(super) fn new() -> Self {
+        Self
+    }
+}
+
+impl OptimizationRule for FastStatistics {
+    // Replace select null_count/min/max of columns from a Parquet scan with a specialized map
+    // function that answers them from the statistics of the files.
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        mut node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        // New-streaming always puts a sink on top.
+        if let IR::Sink { input, .. } = lp_arena.get(node) {
+            node = *input;
+        }
+
+        let IR::Select { input, expr, .. } = lp_arena.get(node) else {
+            return Ok(None);
+        };
+        let IR::Scan {
+            sources,
+            file_info,
+            hive_parts,
+            predicate,
+            scan_type,
+            unified_scan_args,
+            ..
+        } = lp_arena.get(*input)
+        else {
+            return Ok(None);
+        };
+
+        // The statistics are of the whole files, so only unfiltered scans of their physical
+        // columns can use them.
+        if !matches!(scan_type.as_ref(), FileScanIR::Parquet { .. })
+            || predicate.is_some()
+            || unified_scan_args.pre_slice.is_some()
+            || unified_scan_args.row_index.is_some()
+            || unified_scan_args.include_file_paths.is_some()
+            || unified_scan_args.deletion_files.is_some()
+            || unified_scan_args.column_mapping.is_some()
+            || expr.is_empty()
+        {
+            return Ok(None);
+        }
+
+        let mut columns = Schema::with_capacity(expr.len());
+        let mut aggs = Vec::with_capacity(expr.len());
+        let mut schema = Schema::with_capacity(expr.len());
+
+        for e in expr {
+            let Some(agg) = statistics_agg(e, expr_arena) else {
+                return Ok(None);
+            };
+            let Some(dtype) = file_info.schema.get(&agg.column) else {
+                return Ok(None);
+            };
+            if hive_parts
+                .as_ref()
+                .is_some_and(|hive_parts| hive_parts.schema().contains(&agg.column))
+                || !has_exact_statistics(dtype, agg.kind)
+            {
+                return Ok(None);
+            }
+
+            let output_dtype = match agg.kind {
+                StatisticsAggKind::NullCount => IDX_DTYPE,
+                StatisticsAggKind::Min | StatisticsAggKind::Max => dtype.clone(),
+            };
+            // Duplicate output names are left to raise in the select.
+            if schema
+                .insert(e.output_name().clone(), output_dtype)
+                .is_some()
+            {
+                return Ok(None);
+            }
+            columns.insert(agg.column.clone(), dtype.clone());
+            aggs.push(agg);
+        }
+
+        let function = FunctionIR::FastStatistics {
+            sources: sources.clone(),
+            cloud_options: unified_scan_args.cloud_options.clone(),
+            columns: Arc::new(columns),
+            aggs: aggs.into(),
+            schema: Arc::new(schema),
+            allow_missing_columns: matches!(
+                unified_scan_args.missing_columns_policy,
+                MissingColumnsPolicy::Insert
+            ),
+        };
+
+        // MapFunction needs a leaf node, hence we create a dummy placeholder node
+        let placeholder = IR::DataFrameScan {
+            df: Arc::new(Default::default()),
+            schema: Arc::new(Default::default()),
+            output_schema: None,
+        };
+        let placeholder_node = lp_arena.add(placeholder);
+
+        let alp = IR::MapFunction {
+            input: placeholder_node,
+            function,
+        };
+        lp_arena.replace(node, alp.clone());
+        Ok(Some(alp))
+    }
+}
+
+fn statistics_agg(e: &ExprIR, expr_arena: &Arena<AExpr>) -> Option<StatisticsAgg> {
+    let (kind, input) = match expr_arena.get(e.node()) {
+        AExpr::Agg(IRAggExpr::Min { input, .. }) => (StatisticsAggKind::Min, *input),
+        AExpr::Agg(IRAggExpr::Max { input, .. }) => (StatisticsAggKind::Max, *input),
+        AExpr::Function {
+            input,
+            function: IRFunctionExpr::NullCount,
+            ..
+        } if input.len() == 1 => (StatisticsAggKind::NullCount, input[0].node()),
+        _ => return None,
+    };
+    match expr_arena.get(input) {
+        AExpr::Column(column) => Some(StatisticsAgg {
+            column: column.clone(),
+            kind,
+        }),
+        _ => None,
+    }
+}
+
+/// Whether the statistics of a column of `dtype` give the exact result of the aggregation.
+///
+/// Writers may truncate the minima and maxima of binary columns, and floats have an ambiguous
+/// ordering of NaNs and zeros, so only the null counts are used for these.
+fn has_exact_statistics(dtype: &DataType, kind: StatisticsAggKind) -> bool {
+    match kind {
+        StatisticsAggKind::NullCount => !dtype.is_nested() && !dtype.is_object(),
+        StatisticsAggKind::Min | StatisticsAggKind::Max => {
+            dtype.is_integer() || dtype.is_bool() || dtype.is_temporal()
+        },
+    }
+}
@@ -0,0 +1,92 @@
+use polars_core::scalar::Scalar;
+
+use super::stack_opt::OptimizeExprContext;
+use super::*;
+
+/// Rewrites aggregations over common expression shapes to a single aggregation, so that they are
+/// computed by the grouped aggregation kernels instead of materializing the intermediate per-group
+/// results, e.g. the new groups that a filter creates in a group-by.
+///
+/// * `x.unique().len()` -> `x.n_unique()`
+/// * `x.is_not_null().sum()` -> `x.count()`
+/// * `x.filter(m).len()` -> `m.sum()`
+/// * `x.filter(m).count()` -> `(m & x.is_not_null()).sum()`
+/// * `x.filter(m).agg()` -> `when(m).then(x).agg()` for aggregations that ignore nulls
+pub struct FusedAggregation {}
+
+/// Whether `node` has the length of the frame. This holds for elementwise expressions over
+/// columns, which lets the mask of a filter be applied as a `when/then`.
+fn has_frame_length(node: Node, expr_arena: &Arena<AExpr>) -> bool {
+    is_elementwise_rec(node, expr_arena)
+        && !is_scalar_ae(node, expr_arena)
+        && aexpr_to_leaf_names_iter(node, expr_arena).next().is_some()
+}
+
+impl OptimizationRule for FusedAggregation {
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        schema: &Schema,
+        _ctx: OptimizeExprContext,
+    ) -> PolarsResult<Option<AExpr>> {
+        let AExpr::Agg(agg) = expr_arena.get(expr_node) else {
+            return Ok(None);
+        };
+        let agg = agg.clone();
+        let input = expr_arena.get(agg.get_input().first()).clone();
+
+        match (agg, input) {
+            (
+                IRAggExpr::Count(_, true),
+                AExpr::Function {
+                    input,
+                    function: IRFunctionExpr::Unique(_),
+                    ..
+                },
+            ) => Ok(Some(AExpr::Agg(IRAggExpr::NUnique(input[0].node())))),
+            (
+                IRAggExpr::Sum(_),
+                AExpr::Function {
+                    input,
+                    function: IRFunctionExpr::Boolean(IRBooleanFunction::IsNotNull),
+                    ..
+                },
+            ) => Ok(Some(AExpr::Agg(IRAggExpr::Count(input[0].node(), false)))),
+            (mut agg, AExpr::Filter { input: x, by: mask }) => {
+                if !has_frame_length(x, expr_arena) || !has_frame_length(mask, expr_arena) {
+                    return Ok(None);
+                }
+
+                let out = match agg {
+                    IRAggExpr::Count(_, true) => IRAggExpr::Sum(mask),
+                    IRAggExpr::Count(_, false) => {
+                        let is_not_null = AExprBuilder::new_from_node(x).is_not_null(expr_arena);
+                        let mask = AExprBuilder::new_from_node(mask).and(is_not_null, expr_arena);
+                        IRAggExpr::Sum(mask.node())
+                    },
+                    // The filtered out values become nulls, which these aggregations ignore.
+                    IRAggExpr::Sum(_)
+                    | IRAggExpr::Min { .. }
+                    | IRAggExpr::Max { .. }
+                    | IRAggExpr::Mean(_)
+                    | IRAggExpr::Median(_)
+                    | IRAggExpr::Std(..)
+                    | IRAggExpr::Var(..) => {
+                        let dtype =
+                            expr_arena
+                                .get(x)
+                                .get_type(schema, Context::Default, expr_arena)?;
+                        let null = AExprBuilder::lit_scalar(Scalar::null(dtype), expr_arena);
+                        let masked = AExprBuilder::new_from_node(mask).ternary(x, null, expr_arena);
+                        agg.set_input(masked.node());
+                        agg
+                    },
+                    _ => return Ok(None),
+                };
+                Ok(Some(AExpr::Agg(out)))
+            },
+            _ => Ok(None),
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Optimization that replaces joins with a tiny in-memory dimension table by a lookup.
+//!
+//! For example, `fact.join(dim, on='key', how='left')`, where `dim` is a small `DataFrame` with
+//! unique keys, is rewritten to
+//! `fact.with_columns(pl.col.key.replace_strict(dim['key'], dim['value'], default=None))`.
+//! This gathers the values directly instead of building and probing a hash table.
+use polars_ops::frame::{JoinType, JoinValidation, MaintainOrderJoin};
+
+use super::*;
+
+/// The maximum number of rows of a dimension table that is turned into a lookup.
+const MAX_LOOKUP_ROWS: usize = 1024;
+
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        if let Some(ir) = try_lookup(current, lp_arena, expr_arena) {
+            lp_arena.replace(current, ir);
+        }
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+}
+
+fn list_lit(s: Series, expr_arena: &mut Arena<AExpr>) -> ExprIR {
+    let dtype = DataType::List(Box::new(s.dtype().clone()));
+    let scalar = Scalar::new(dtype, AnyValue::List(s));
+    ExprIR::from_node(
+        expr_arena.add(AExpr::Literal(LiteralValue::Scalar(scalar))),
+        expr_arena,
+    )
+}
+
+fn try_lookup(node: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) -> Option<IR> {
+    let IR::Join {
+        input_left,
+        input_right,
+        schema,
+        left_on,
+        right_on,
+        options,
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+
+    let args = &options.args;
+    #[cfg_attr(not(feature = "is_in"), allow(unused_variables))]
+    let is_inner = match args.how {
+        JoinType::Left => false,
+        #[cfg(feature = "is_in")]
+        JoinType::Inner => true,
+        _ => return None,
+    };
+    // The lookup keeps the order of the left input and has no way to slice or match nulls.
+    if options.options.is_some()
+        || args.slice.is_some()
+        || args.nulls_equal
+        || !args.should_coalesce()
+        || !matches!(
+            args.validation,
+            JoinValidation::ManyToMany | JoinValidation::ManyToOne
+        )
+        || !matches!(
+            args.maintain_order,
+            MaintainOrderJoin::None | MaintainOrderJoin::Left | MaintainOrderJoin::LeftRight
+        )
+    {
+        return None;
+    }
+
+    let ([left_key], [right_key]) = (left_on.as_slice(), right_on.as_slice()) else {
+        return None;
+    };
+    let (AExpr::Column(left_name), AExpr::Column(right_name)) = (
+        expr_arena.get(left_key.node()),
+        expr_arena.get(right_key.node()),
+    ) else {
+        return None;
+    };
+
+    let IR::DataFrameScan {
+        df,
+        schema: df_schema,
+        output_schema,
+    } = lp_arena.get(*input_right)
+    else {
+        return None;
+    };
+    if df.height() > MAX_LOOKUP_ROWS {
+        return None;
+    }
+    let right_schema = output_schema.as_ref().unwrap_or(df_schema);
+    let left_schema = lp_arena.get(*input_left).schema(lp_arena);
+
+    let key_dtype = left_schema.get(left_name)?;
+    if right_schema.get(right_name)? != key_dtype
+        || !(key_dtype.is_integer() || key_dtype.is_string() || key_dtype.is_bool())
+    {
+        return None;
+    }
+
+    // The output of the join is the left input followed by the non-key columns of the right
+    // input, which may have been suffixed.
+    let value_names = right_schema
+        .iter_names()
+        .filter(|name| *name != right_name)
+        .collect::<Vec<_>>();
+    if schema.len() != left_schema.len() + value_names.len()
+        || schema
+            .iter_names()
+            .zip(left_schema.iter_names())
+            .any(|(l, r)| l != r)
+    {
+        return None;
+    }
+
+    // Null keys never match, so they can be dropped from the dimension table.
+    let dim = df.filter(&df.column(right_name).ok()?.is_not_null()).ok()?;
+    let keys = dim
+        .column(right_name)
+        .ok()?
+        .as_materialized_series()
+        .clone();
+    if keys.n_unique().ok()? != keys.len() {
+        return None;
+    }
+
+    let input_left = *input_left;
+    let left_name = left_name.clone();
+    let schema = schema.clone();
+    let left_len = left_schema.len();
+    let values = value_names
+        .iter()
+        .map(|name| Some(dim.column(name).ok()?.as_materialized_series().clone()))
+        .collect::<Option<Vec<_>>>()?;
+
+    let key_column = |expr_arena: &mut Arena<AExpr>| {
+        ExprIR::from_node(expr_arena.add(AExpr::Column(left_name.clone())), expr_arena)
+    };
+
+    // An inner join drops the rows without a match.
+    #[cfg(feature = "is_in")]
+    let input = if is_inner {
+        let function = IRFunctionExpr::Boolean(IRBooleanFunction::IsIn { nulls_equal: false });
+        let predicate = AExpr::Function {
+            input: vec![key_column(expr_arena), list_lit(keys.clone(), expr_arena)],
+            options: function.function_options(),
+            function,
+        };
+        let predicate = ExprIR::from_node(expr_arena.add(predicate), expr_arena);
+        lp_arena.add(IR::Filter {
+            input: input_left,
+            predicate,
+        })
+    } else {
+        input_left
+    };
+    #[cfg(not(feature = "is_in"))]
+    let input = input_left;
+
+    let exprs = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, values)| {
+            let (name, dtype) = schema.get_at_index(left_len + i).unwrap();
+            let function = IRFunctionExpr::ReplaceStrict {
+                return_dtype: Some(dtype.clone()),
+            };
+            let default = expr_arena.add(AExpr::Literal(LiteralValue::Scalar(Scalar::null(
+                dtype.clone(),
+            ))));
+            let lookup = AExpr::Function {
+                input: vec![
+                    key_column(expr_arena),
+                    list_lit(keys.clone(), expr_arena),
+                    list_lit(values, expr_arena),
+                    ExprIR::from_node(default, expr_arena),
+                ],
+                options: function.function_options(),
+                function,
+            };
+            ExprIR::new(expr_arena.add(lookup), OutputName::Alias(name.clone()))
+        })
+        .collect();
+
+    Some(IR::HStack {
+        input,
+        exprs,
+        schema,
+        options: ProjectionOptions {
+            duplicate_check: false,
+            ..Default::default()
+        },
+    })
+}
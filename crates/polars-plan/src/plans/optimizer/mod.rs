@@ -12,18 +12,27 @@ mod collect_members;
 mod count_star;
 #[cfg(feature = "cse")]
 mod cse;
+#[cfg(feature = "parquet")]
+mod fast_statistics;
 mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
+mod fused_agg;
 mod join_utils;
 pub(crate) use join_utils::ExprOrigin;
 mod expand_datasets;
+#[cfg(feature = "replace")]
+mod lookup_joins;
 mod predicate_pushdown;
 mod projection_pushdown;
+#[cfg(feature = "cse")]
+mod reuse_sort_keys;
 mod set_order;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
+#[cfg(feature = "parquet")]
+mod sorted_heads;
 mod stack_opt;
 
 use collapse_and_project::SimpleProjectionAndCollapse;
@@ -48,6 +57,8 @@ use crate::plans::optimizer::count_star::CountStar;
 use crate::plans::optimizer::cse::CommonSubExprOptimizer;
 #[cfg(feature = "cse")]
 use crate::plans::optimizer::cse::prune_unused_caches;
+#[cfg(feature = "parquet")]
+use crate::plans::optimizer::fast_statistics::FastStatistics;
 use crate::plans::optimizer::predicate_pushdown::ExprEval;
 #[cfg(feature = "cse")]
 use crate::plans::visitor::*;
@@ -134,12 +145,13 @@ pub fn optimize(
     if opt_flags.simplify_expr() {
         #[cfg(feature = "fused")]
         rules.push(Box::new(fused::FusedArithmetic {}));
+        rules.push(Box::new(fused_agg::FusedAggregation {}));
     }
 
     #[cfg(feature = "cse")]
     let _cse_plan_changed = if comm_subplan_elim {
         let members = get_or_init_members!();
-        if (members.has_sink_multiple || members.has_joins_or_unions)
+        let changed = if (members.has_sink_multiple || members.has_joins_or_unions)
             && members.has_duplicate_scans()
             && !members.has_cache
         {
@@ -156,13 +168,24 @@ pub fn optimize(
             changed
         } else {
             false
+        };
+        // The hints have served their purpose.
+        if members.has_cache_hint {
+            cse::remove_cache_hints(lp_top, lp_arena);
         }
+        changed
     } else {
         false
     };
     #[cfg(not(feature = "cse"))]
     let _cse_plan_changed = false;
 
+    // Must run before projection pushdown, which may remove the reused columns.
+    #[cfg(feature = "cse")]
+    if comm_subexpr_elim && get_or_init_members!().has_sort {
+        reuse_sort_keys::optimize(lp_top, lp_arena, expr_arena);
+    }
+
     // Should be run before predicate pushdown.
     if opt_flags.projection_pushdown() {
         let mut projection_pushdown_opt = ProjectionPushDown::new();
@@ -174,6 +197,11 @@ pub fn optimize(
             let mut count_star_opt = CountStar::new();
             count_star_opt.optimize_plan(lp_arena, expr_arena, lp_top)?;
         }
+
+        #[cfg(feature = "parquet")]
+        if opt_flags.fast_statistics() {
+            FastStatistics::new().optimize_plan(lp_arena, expr_arena, lp_top)?;
+        }
     }
 
     if opt_flags.predicate_pushdown() {
@@ -192,6 +220,12 @@ pub fn optimize(
         collapse_joins::optimize(lp_top, lp_arena, expr_arena, opt_flags.new_streaming());
     }
 
+    // Make sure it is after projection pushdown, so the dimension tables are projected.
+    #[cfg(feature = "replace")]
+    if opt_flags.lookup_joins() && get_or_init_members!().has_joins_or_unions {
+        lookup_joins::optimize(lp_top, lp_arena, expr_arena);
+    }
+
     // Make sure its before slice pushdown.
     if opt_flags.fast_projection() {
         rules.push(Box::new(SimpleProjectionAndCollapse::new(
@@ -231,6 +265,12 @@ pub fn optimize(
         rules.push(Box::new(FlattenUnionRule {}));
     }
 
+    // Must run after slice pushdown, which puts the limit in the sort.
+    #[cfg(feature = "parquet")]
+    if opt_flags.slice_pushdown() {
+        rules.push(Box::new(sorted_heads::SortedHeads));
+    }
+
     // Note: ExpandDatasets must run after slice and predicate pushdown.
     rules.push(Box::new(expand_datasets::ExpandDatasets {}) as Box<dyn OptimizationRule>);
 
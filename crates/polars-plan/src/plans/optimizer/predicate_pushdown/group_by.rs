@@ -23,7 +23,7 @@ pub(super) fn process_group_by(
     let no_push = false;
 
     // Don't pushdown predicates on these cases.
-    if apply.is_some() || no_push || options.slice.is_some() {
+    if apply.is_some() || no_push || options.slice.is_some() || options.expand_categories {
         let lp = GroupBy {
             input,
             keys,
@@ -17,13 +17,23 @@ pub(super) fn process_functions(
 ) -> PolarsResult<IR> {
     use FunctionIR::*;
     match function {
-        Explode { columns, .. } => {
+        Explode {
+            columns,
+            index_name,
+            ..
+        } => {
             columns
                 .iter()
                 .for_each(|name| add_str_to_accumulated(name.clone(), &mut ctx, expr_arena));
-            proj_pd.pushdown_and_assign(input, ctx, lp_arena, expr_arena)?;
+            if index_name.is_some() {
+                // The index column is created by the explode, so it is not pushed down.
+                proj_pd
+                    .pushdown_and_assign_check_schema(input, ctx, lp_arena, expr_arena, false)?;
+            } else {
+                proj_pd.pushdown_and_assign(input, ctx, lp_arena, expr_arena)?;
+            }
             Ok(IRBuilder::new(input, expr_arena, lp_arena)
-                .explode(columns.clone())
+                .explode(columns.clone(), index_name.clone())
                 .build())
         },
         #[cfg(feature = "pivot")]
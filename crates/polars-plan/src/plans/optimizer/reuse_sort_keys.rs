@@ -0,0 +1,53 @@
+//! Optimization that sorts by the columns of an input `with_columns` instead of by sort keys that
+//! are identical to the expressions of those columns, so that such keys are not computed twice.
+//!
+//! For example, `lf.with_columns(k=expr).sort(expr)` is rewritten to
+//! `lf.with_columns(k=expr).sort('k')`.
+use super::*;
+
+pub fn optimize(root: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let mut ir_stack = Vec::with_capacity(16);
+    ir_stack.push(root);
+
+    while let Some(current) = ir_stack.pop() {
+        reuse_sort_keys(current, lp_arena, expr_arena);
+        lp_arena.get(current).copy_inputs(&mut ir_stack);
+    }
+}
+
+fn reuse_sort_keys(node: Node, lp_arena: &mut Arena<IR>, expr_arena: &mut Arena<AExpr>) {
+    let IR::Sort {
+        input, by_column, ..
+    } = lp_arena.get(node)
+    else {
+        return;
+    };
+    let IR::HStack { exprs, .. } = lp_arena.get(*input) else {
+        return;
+    };
+
+    // A key can only be replaced if it reads the same columns as the `with_columns`.
+    let written: PlHashSet<&PlSmallStr> = exprs.iter().map(|e| e.output_name()).collect();
+    let replacements = by_column
+        .iter()
+        .enumerate()
+        .filter(|(_, key)| !matches!(expr_arena.get(key.node()), AExpr::Column(_)))
+        .filter(|(_, key)| {
+            !aexpr_to_leaf_names_iter(key.node(), expr_arena).any(|name| written.contains(&name))
+        })
+        .filter_map(|(i, key)| {
+            let key = AexprNode::new(key.node()).hashable_and_cmp(expr_arena);
+            exprs
+                .iter()
+                .find(|e| AexprNode::new(e.node()).hashable_and_cmp(expr_arena) == key)
+                .map(|e| (i, e.output_name().clone()))
+        })
+        .collect::<Vec<_>>();
+
+    for (i, name) in replacements {
+        let key = name_to_expr_ir(name, expr_arena);
+        if let IR::Sort { by_column, .. } = lp_arena.get_mut(node) {
+            by_column[i] = key;
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use std::ops::Range;
+
+use polars_core::POOL;
+use polars_io::parquet::metadata::FileMetadataRef;
+use polars_parquet::read::infer_schema;
+use polars_parquet::read::statistics::deserialize_all;
+use polars_utils::slice_enum::Slice;
+use rayon::prelude::*;
+
+use super::*;
+use crate::plans::functions::read_metadata;
+
+/// Only reads the heads of the Parquet files that are sorted on the sort column under a sort with
+/// a limit, e.g. "the latest N events" of a time partitioned dataset.
+///
+/// The scan is split into a union of a scan per sorted file, which is sliced to the rows that can
+/// be in the result, and scans of the other files. The sliced scans only fetch the row groups that
+/// overlap with their slice. To not read the metadata of all files of unsorted datasets, this is
+/// only done if the metadata of the first file says it is sorted.
+pub(super) struct SortedHeads;
+
+impl OptimizationRule for SortedHeads {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> PolarsResult<Option<IR>> {
+        let IR::Sort {
+            input,
+            by_column,
+            slice: Some((offset, len)),
+            sort_options,
+        } = lp_arena.get(node)
+        else {
+            return Ok(None);
+        };
+        let [by] = by_column.as_slice() else {
+            return Ok(None);
+        };
+        let AExpr::Column(column) = expr_arena.get(by.node()) else {
+            return Ok(None);
+        };
+        if *offset < 0 {
+            return Ok(None);
+        }
+        let order = RequestedOrder {
+            descending: sort_options.descending[0],
+            nulls_last: sort_options.nulls_last[0],
+            maintain_order: sort_options.maintain_order,
+        };
+        let n = (*offset as usize).saturating_add(*len);
+        let input = *input;
+        let IR::Scan {
+            sources,
+            file_info,
+            hive_parts,
+            predicate,
+            output_schema: _,
+            scan_type,
+            unified_scan_args,
+        } = lp_arena.get(input)
+        else {
+            return Ok(None);
+        };
+
+        let FileScanIR::Parquet {
+            options,
+            metadata: Some(first_metadata),
+        } = scan_type.as_ref()
+        else {
+            return Ok(None);
+        };
+        if matches!(sources, ScanSources::Files(_))
+            || hive_parts.is_some()
+            || predicate.is_some()
+            || unified_scan_args.pre_slice.is_some()
+            || unified_scan_args.row_index.is_some()
+            || unified_scan_args.include_file_paths.is_some()
+            || unified_scan_args.deletion_files.is_some()
+            || unified_scan_args.column_mapping.is_some()
+            || unified_scan_args.file_error_policy.skip
+            || !matches!(
+                unified_scan_args.missing_columns_policy,
+                MissingColumnsPolicy::Raise
+            )
+        {
+            return Ok(None);
+        }
+        let Some(dtype) = file_info.schema.get(column) else {
+            return Ok(None);
+        };
+        if file_order(first_metadata, column, dtype, order)?.is_none() {
+            return Ok(None);
+        }
+        let sources = sources.clone();
+        let options = options.clone();
+        let dtype = dtype.clone();
+
+        let cloud_options = unified_scan_args.cloud_options.as_ref();
+        let mut metadatas = POOL.install(|| {
+            (1..sources.len())
+                .into_par_iter()
+                .map(|i| read_metadata(sources.at(i), cloud_options))
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        metadatas.insert(0, first_metadata.clone());
+        let heads = metadatas
+            .iter()
+            .map(|metadata| {
+                let num_rows = metadata.num_rows;
+                let len = n.min(num_rows);
+                Ok(match file_order(metadata, column, &dtype, order)? {
+                    Some(FileOrder::Same) => Some(0..len),
+                    Some(FileOrder::Reversed) => Some(num_rows - len..num_rows),
+                    None => None,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        // Consecutive unsorted files are scanned together. The files stay in order, so that the
+        // sort can maintain the order of equal rows.
+        let mut inputs = Vec::new();
+        let mut total_rows = 0;
+        let mut start = 0;
+        while start < sources.len() {
+            let (files, pre_slice, num_rows) = match &heads[start] {
+                Some(rows) => (
+                    start..start + 1,
+                    Some(Slice::Positive {
+                        offset: rows.start,
+                        len: rows.len(),
+                    }),
+                    rows.len(),
+                ),
+                None => {
+                    let end = (start..sources.len())
+                        .find(|&i| heads[i].is_some())
+                        .unwrap_or(sources.len());
+                    let num_rows = metadatas[start..end].iter().map(|md| md.num_rows).sum();
+                    (start..end, None, num_rows)
+                },
+            };
+
+            let mut scan = lp_arena.get(input).clone();
+            let IR::Scan {
+                sources: scan_sources,
+                file_info,
+                scan_type,
+                unified_scan_args,
+                ..
+            } = &mut scan
+            else {
+                unreachable!()
+            };
+            *scan_sources = select_sources(&sources, files.clone());
+            file_info.row_estimation = (Some(num_rows), num_rows);
+            *scan_type = Box::new(FileScanIR::Parquet {
+                options: options.clone(),
+                metadata: Some(metadatas[files.start].clone()),
+            });
+            unified_scan_args.pre_slice = pre_slice;
+
+            inputs.push(lp_arena.add(scan));
+            total_rows += num_rows;
+            start = files.end;
+        }
+
+        let scan = if let [scan] = inputs.as_slice() {
+            lp_arena.take(*scan)
+        } else {
+            IR::Union {
+                inputs,
+                options: UnionOptions {
+                    rows: (Some(total_rows), total_rows),
+                    parallel: true,
+                    maintain_order: order.maintain_order,
+                    ..Default::default()
+                },
+            }
+        };
+        lp_arena.replace(input, scan);
+        Ok(Some(lp_arena.get(node).clone()))
+    }
+}
+
+fn select_sources(sources: &ScanSources, files: Range<usize>) -> ScanSources {
+    match sources {
+        ScanSources::Paths(paths) => ScanSources::Paths(paths[files].into()),
+        ScanSources::Buffers(buffers) => ScanSources::Buffers(buffers[files].into()),
+        ScanSources::Files(_) => unreachable!(),
+    }
+}
+
+#[derive(Clone, Copy)]
+struct RequestedOrder {
+    descending: bool,
+    nulls_last: bool,
+    maintain_order: bool,
+}
+
+/// How the rows of a file are ordered relative to the requested sort order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileOrder {
+    Same,
+    Reversed,
+}
+
+fn file_order(
+    metadata: &FileMetadataRef,
+    column: &str,
+    dtype: &DataType,
+    order: RequestedOrder,
+) -> PolarsResult<Option<FileOrder>> {
+    let Some((file_descending, file_nulls_first)) = sort_order(metadata, column, dtype)? else {
+        return Ok(None);
+    };
+    // Files with multiple row groups are only considered sorted without nulls, so the position
+    // of the nulls only matters for a single row group.
+    let nulls_match =
+        |nulls_first: bool| metadata.row_groups.len() > 1 || file_nulls_first == nulls_first;
+    // The tail of a file that is sorted the other way holds the last of the equal rows instead of
+    // the first, so it can't be used if the order of equal rows must be maintained.
+    let file_order = if file_descending == order.descending && nulls_match(!order.nulls_last) {
+        Some(FileOrder::Same)
+    } else if file_descending != order.descending
+        && nulls_match(order.nulls_last)
+        && !order.maintain_order
+    {
+        Some(FileOrder::Reversed)
+    } else {
+        None
+    };
+    Ok(file_order)
+}
+
+/// Returns whether the file is sorted descending and whether the nulls come first, if the file is
+/// sorted on `column`.
+///
+/// This requires all row groups to declare `column` as their first sorting column, in the same
+/// direction. If there are multiple row groups, their statistics must show that they don't overlap
+/// and the column may not contain nulls. The column must be stored as `dtype`, as a cast could
+/// change its order.
+fn sort_order(
+    metadata: &FileMetadataRef,
+    column: &str,
+    dtype: &DataType,
+) -> PolarsResult<Option<(bool, bool)>> {
+    let row_groups = metadata.row_groups.as_slice();
+    let Some(first) = row_groups.first() else {
+        return Ok(None);
+    };
+    let Some(&[idx]) = first.columns_idxs_under_root_iter(column) else {
+        return Ok(None);
+    };
+    let Some(sorting) = first.sorting_columns().and_then(|s| s.first()) else {
+        return Ok(None);
+    };
+    let (descending, nulls_first) = (sorting.descending, sorting.nulls_first);
+    let all_declared = row_groups.iter().all(|rg| {
+        rg.sorting_columns()
+            .and_then(|s| s.first())
+            .is_some_and(|s| {
+                s.column_idx as usize == idx
+                    && s.descending == descending
+                    && s.nulls_first == nulls_first
+            })
+    });
+    if !all_declared {
+        return Ok(None);
+    }
+    let file_schema = infer_schema(metadata)?;
+    let Some(field) = file_schema.get(column) else {
+        return Ok(None);
+    };
+    if DataType::from_arrow_field(field) != *dtype {
+        return Ok(None);
+    }
+    if row_groups.len() == 1 {
+        return Ok(Some((descending, nulls_first)));
+    }
+
+    // The sorting columns only hold within a row group, so check from the statistics that the
+    // row groups follow each other.
+    if !(dtype.is_integer() || dtype.is_temporal()) {
+        return Ok(None);
+    }
+    let Some(stats) = deserialize_all(field, row_groups, idx)? else {
+        return Ok(None);
+    };
+    if (0..row_groups.len()).any(|i| stats.null_count.get(i) != Some(0)) {
+        return Ok(None);
+    }
+    let md = field.metadata.as_deref();
+    // SAFETY: The statistics are deserialized to the dtype of the field.
+    let (min, max) = unsafe {
+        (
+            Series::_try_from_arrow_unchecked_with_md(
+                field.name.clone(),
+                vec![stats.min_value],
+                field.dtype(),
+                md,
+            )?,
+            Series::_try_from_arrow_unchecked_with_md(
+                field.name.clone(),
+                vec![stats.max_value],
+                field.dtype(),
+                md,
+            )?,
+        )
+    };
+    if min.has_nulls() || max.has_nulls() {
+        return Ok(None);
+    }
+    let n = row_groups.len() - 1;
+    let in_order = if descending {
+        min.slice(0, n).gt_eq(&max.slice(1, n))?
+    } else {
+        max.slice(0, n).lt_eq(&min.slice(1, n))?
+    };
+    Ok(in_order.all().then_some((descending, nulls_first)))
+}
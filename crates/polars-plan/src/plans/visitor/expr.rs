@@ -80,6 +80,7 @@ impl TreeWalker for Expr {
             },
             Slice { input, offset, length } => Slice { input: am(input, &mut f)?, offset: am(offset, &mut f)?, length: am(length, f)? },
             KeepName(expr) => KeepName(am(expr, f)?),
+            Cached(expr) => Cached(am(expr, f)?),
             Len => Len,
             RenameAlias { function, expr } => RenameAlias { function, expr: am(expr, f)? },
             AnonymousFunction { input, function, output_type, options, fmt_str } => {
@@ -165,6 +165,30 @@ impl PyCatalogClient {
         table_info_to_pyobject(py, table_info).map(|x| x.into())
     }
 
+    #[pyo3(signature = (catalog_name, namespace, table_name))]
+    pub fn try_get_table_info(
+        &self,
+        py: Python<'_>,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+    ) -> PyResult<PyObject> {
+        let table_info = py
+            .enter_polars(|| {
+                pl_async::get_runtime().block_in_place_on(self.client().try_get_table_info(
+                    catalog_name,
+                    namespace,
+                    table_name,
+                ))
+            })
+            .map_err(to_py_err)?;
+
+        match table_info {
+            Some(table_info) => table_info_to_pyobject(py, table_info).map(|x| x.into()),
+            None => Ok(PyNone::get(py).as_any().clone().unbind()),
+        }
+    }
+
     #[pyo3(signature = (table_id, write))]
     pub fn get_table_credentials(
         &self,
@@ -343,8 +367,8 @@ impl PyCatalogClient {
     }
 
     #[pyo3(signature = (
-        catalog_name, namespace, table_name, schema, table_type, data_source_format, comment,
-        storage_root, properties
+        catalog_name, namespace, table_name, schema, partition_by, table_type, data_source_format,
+        comment, storage_root, properties
     ))]
     pub fn create_table(
         &self,
@@ -353,6 +377,7 @@ impl PyCatalogClient {
         namespace: &str,
         table_name: &str,
         schema: Option<Wrap<Schema>>,
+        partition_by: Vec<Wrap<PlSmallStr>>,
         table_type: &str,
         data_source_format: Option<&str>,
         comment: Option<&str>,
@@ -367,6 +392,7 @@ impl PyCatalogClient {
                         namespace,
                         table_name,
                         schema.as_ref().map(|x| &x.0),
+                        &partition_by.into_iter().map(|x| x.0).collect::<Vec<_>>(),
                         &TableType::from_str(table_type)
                             .map_err(|e| PyValueError::new_err(e.to_string()))?,
                         data_source_format
@@ -385,6 +411,29 @@ impl PyCatalogClient {
         table_info_to_pyobject(py, table_info).map(|x| x.into())
     }
 
+    #[pyo3(signature = (catalog_name, namespace, table_name, owner))]
+    pub fn update_table(
+        &self,
+        py: Python<'_>,
+        catalog_name: &str,
+        namespace: &str,
+        table_name: &str,
+        owner: &str,
+    ) -> PyResult<PyObject> {
+        let table_info = py.allow_threads(|| {
+            pl_async::get_runtime()
+                .block_in_place_on(self.client().update_table(
+                    catalog_name,
+                    namespace,
+                    table_name,
+                    owner,
+                ))
+                .map_err(to_py_err)
+        })?;
+
+        table_info_to_pyobject(py, table_info).map(|x| x.into())
+    }
+
     #[pyo3(signature = (catalog_name, namespace, table_name))]
     pub fn delete_table(
         &self,
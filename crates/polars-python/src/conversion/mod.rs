@@ -233,6 +233,10 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let class = pl.getattr(intern!(py, "Int128"))?;
                 class.call0()
             },
+            DataType::Float16 => {
+                let class = pl.getattr(intern!(py, "Float16"))?;
+                class.call0()
+            },
             DataType::Float32 => {
                 let class = pl.getattr(intern!(py, "Float32"))?;
                 class.call0()
@@ -294,7 +298,7 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                 let kwargs = [("categories", categories)];
                 categorical_class.call((), Some(&kwargs.into_py_dict(py)?))
             },
-            DataType::Enum(_, mapping) => {
+            DataType::Enum(fcats, mapping) => {
                 let categories = unsafe {
                     StringChunked::from_chunks(
                         PlSmallStr::from_static("category"),
@@ -302,8 +306,17 @@ impl<'py> IntoPyObject<'py> for &Wrap<DataType> {
                     )
                 };
                 let class = pl.getattr(intern!(py, "Enum"))?;
+                // Only pass the physical type if it isn't the one that is chosen by default.
+                let physical = (CategoricalPhysical::smallest_physical(categories.len()).ok()
+                    != Some(fcats.physical()))
+                .then(|| match fcats.physical() {
+                    CategoricalPhysical::U8 => Wrap(DataType::UInt8),
+                    CategoricalPhysical::U16 => Wrap(DataType::UInt16),
+                    CategoricalPhysical::U32 => Wrap(DataType::UInt32),
+                });
                 let series = to_series(py, categories.into_series().into())?;
-                class.call1((series,))
+                let kwargs = [("physical", physical)];
+                class.call((series,), Some(&kwargs.into_py_dict(py)?))
             },
             DataType::Time => pl.getattr(intern!(py, "Time")),
             DataType::Struct(fields) => {
@@ -371,6 +384,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                     "UInt16" => DataType::UInt16,
                     "UInt32" => DataType::UInt32,
                     "UInt64" => DataType::UInt64,
+                    "Float16" => DataType::Float16,
                     "Float32" => DataType::Float32,
                     "Float64" => DataType::Float64,
                     "Boolean" => DataType::Boolean,
@@ -406,6 +420,7 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
             "UInt16" => DataType::UInt16,
             "UInt32" => DataType::UInt32,
             "UInt64" => DataType::UInt64,
+            "Float16" => DataType::Float16,
             "Float32" => DataType::Float32,
             "Float64" => DataType::Float64,
             "Boolean" => DataType::Boolean,
@@ -423,8 +438,21 @@ impl<'py> FromPyObject<'py> for Wrap<DataType> {
                 let ca = s.str().map_err(PyPolarsErr::from)?;
                 let categories = ca.downcast_iter().next().unwrap().clone();
                 assert!(!categories.has_nulls());
+                let physical = ob
+                    .getattr(intern!(py, "physical"))?
+                    .extract::<Option<Wrap<DataType>>>()?
+                    .map(|dtype| match dtype.0 {
+                        DataType::UInt8 => Ok(CategoricalPhysical::U8),
+                        DataType::UInt16 => Ok(CategoricalPhysical::U16),
+                        DataType::UInt32 => Ok(CategoricalPhysical::U32),
+                        dt => Err(PyTypeError::new_err(format!(
+                            "Enum physical must be one of pl.UInt(8|16|32), got {dt}"
+                        ))),
+                    })
+                    .transpose()?;
                 DataType::from_frozen_categories(
-                    FrozenCategories::new(categories.values_iter()).unwrap(),
+                    FrozenCategories::with_physical(categories.values_iter(), physical)
+                        .map_err(PyPolarsErr::from)?,
                 )
             },
             "Date" => DataType::Date,
@@ -905,14 +933,25 @@ impl<'py> FromPyObject<'py> for Wrap<ClosedWindow> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<TradingCalendar> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let (open, close, week_mask, holidays, early_closes) = ob.extract()?;
+        let calendar = TradingCalendar::new(open, close, week_mask, holidays, early_closes)
+            .map_err(PyPolarsErr::from)?;
+        Ok(Wrap(calendar))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<RoundMode> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "half_to_even" => RoundMode::HalfToEven,
             "half_away_from_zero" => RoundMode::HalfAwayFromZero,
+            "floor" => RoundMode::Floor,
+            "ceil" => RoundMode::Ceil,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`mode` must be one of {{'half_to_even', 'half_away_from_zero'}}, got {v}",
+                    "`mode` must be one of {{'half_to_even', 'half_away_from_zero', 'floor', 'ceil'}}, got {v}",
                 )));
             },
         };
@@ -1122,6 +1161,26 @@ impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<BootstrapStatistic> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "mean" => BootstrapStatistic::Mean,
+            "median" => BootstrapStatistic::Median,
+            "sum" => BootstrapStatistic::Sum,
+            "std" => BootstrapStatistic::Std,
+            "var" => BootstrapStatistic::Var,
+            "min" => BootstrapStatistic::Min,
+            "max" => BootstrapStatistic::Max,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "bootstrap `statistic` must be one of {{'mean', 'median', 'sum', 'std', 'var', 'min', 'max'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<Roll> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1171,6 +1230,21 @@ impl<'py> FromPyObject<'py> for Wrap<UniqueKeepStrategy> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<CacheHint> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "always" => CacheHint::Always,
+            "never" => CacheHint::Never,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`hint` must be one of {{'always', 'never'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "search_sorted")]
 impl<'py> FromPyObject<'py> for Wrap<SearchSortedSide> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1238,6 +1312,21 @@ impl<'py> FromPyObject<'py> for Wrap<JoinValidation> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<ScatterConflict> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "last" => ScatterConflict::LastWins,
+            "raise" => ScatterConflict::Error,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`on_conflict` must be one of {{'last', 'raise'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<MaintainOrderJoin> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -1256,6 +1345,23 @@ impl<'py> FromPyObject<'py> for Wrap<MaintainOrderJoin> {
     }
 }
 
+#[cfg(feature = "iejoin")]
+impl<'py> FromPyObject<'py> for Wrap<IntervalJoinMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "overlap" => IntervalJoinMode::Overlap,
+            "contains" => IntervalJoinMode::Contains,
+            "within" => IntervalJoinMode::Within,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`mode` must be one of {{'overlap', 'contains', 'within'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "csv")]
 impl<'py> FromPyObject<'py> for Wrap<QuoteStyle> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
@@ -1587,6 +1693,61 @@ impl<'py> FromPyObject<'py> for Wrap<Option<TimeZone>> {
     }
 }
 
+impl<'py> FromPyObject<'py> for Wrap<CastOrForbid> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "strict" => CastOrForbid::Strict,
+            "non-strict" => CastOrForbid::NonStrict,
+            "forbid" => CastOrForbid::Forbid,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`cast` must be one of {{'strict', 'non-strict', 'forbid'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "schema_registry")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::schema_registry::SchemaFormat> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::schema_registry::SchemaFormat;
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "avro" => SchemaFormat::Avro,
+            "json" => SchemaFormat::Json,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`format` must be one of {{'avro', 'json'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
+#[cfg(feature = "schema_registry")]
+impl<'py> FromPyObject<'py> for Wrap<polars_io::schema_registry::CompatibilityMode> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        use polars_io::schema_registry::CompatibilityMode;
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "backward" => CompatibilityMode::Backward,
+            "backward_transitive" => CompatibilityMode::BackwardTransitive,
+            "forward" => CompatibilityMode::Forward,
+            "forward_transitive" => CompatibilityMode::ForwardTransitive,
+            "full" => CompatibilityMode::Full,
+            "full_transitive" => CompatibilityMode::FullTransitive,
+            "none" => CompatibilityMode::None,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`compatibility` must be one of {{'backward', 'backward_transitive', 'forward', 'forward_transitive', 'full', 'full_transitive', 'none'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<UpcastOrForbid> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
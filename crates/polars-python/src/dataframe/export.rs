@@ -74,20 +74,49 @@ impl PyDataFrame {
     }
 
     #[allow(clippy::wrong_self_convention)]
+    #[pyo3(signature = (compat_level, large_offsets, categorical_as_dictionary, column_options))]
     pub fn to_arrow(
         &mut self,
         py: Python<'_>,
         compat_level: PyCompatLevel,
+        large_offsets: bool,
+        categorical_as_dictionary: bool,
+        column_options: Vec<(String, PyCompatLevel, bool, bool)>,
     ) -> PyResult<Vec<PyObject>> {
         py.enter_polars_ok(|| self.df.align_chunks_par())?;
         let pyarrow = py.import("pyarrow")?;
 
-        let rbs = self
-            .df
-            .iter_chunks(compat_level.0, true)
-            .map(|rb| interop::arrow::to_py::to_py_rb(&rb, py, &pyarrow))
-            .collect::<PyResult<_>>()?;
-        Ok(rbs)
+        let options = ArrowExportOptions {
+            compat_level: compat_level.0,
+            large_offsets,
+            categorical_as_dictionary,
+        };
+        if column_options.is_empty() && large_offsets && categorical_as_dictionary {
+            let rbs = self
+                .df
+                .iter_chunks(compat_level.0, true)
+                .map(|rb| interop::arrow::to_py::to_py_rb(&rb, py, &pyarrow))
+                .collect::<PyResult<_>>()?;
+            return Ok(rbs);
+        }
+
+        let column_options = column_options
+            .into_iter()
+            .map(
+                |(name, compat_level, large_offsets, categorical_as_dictionary)| {
+                    let options = ArrowExportOptions {
+                        compat_level: compat_level.0,
+                        large_offsets,
+                        categorical_as_dictionary,
+                    };
+                    (name.into(), options)
+                },
+            )
+            .collect();
+        py.enter_polars(|| self.df.to_arrow(options, &column_options))?
+            .iter()
+            .map(|rb| interop::arrow::to_py::to_py_rb(rb, py, &pyarrow))
+            .collect()
     }
 
     /// Create a `Vec` of PyArrow RecordBatch instances.
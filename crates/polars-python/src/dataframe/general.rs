@@ -324,6 +324,20 @@ impl PyDataFrame {
         py.enter_polars_df(|| self.df.with_row_index(name.into(), offset))
     }
 
+    pub fn with_row_index_stepped(
+        &self,
+        py: Python<'_>,
+        name: &str,
+        start: i64,
+        step: i64,
+        dtype: Wrap<DataType>,
+    ) -> PyResult<Self> {
+        py.enter_polars_df(|| {
+            self.df
+                .with_row_index_stepped(name.into(), start, step, &dtype.0)
+        })
+    }
+
     pub fn _to_metadata(&self) -> Self {
         Self {
             df: self.df._to_metadata(),
@@ -445,6 +459,19 @@ impl PyDataFrame {
         Ok(unsafe { std::mem::transmute::<Vec<DataFrame>, Vec<PyDataFrame>>(out) })
     }
 
+    #[pyo3(signature = (maintain_order, subset=None))]
+    pub fn unique_with_duplicates(
+        &self,
+        py: Python<'_>,
+        maintain_order: bool,
+        subset: Option<Vec<String>>,
+    ) -> PyResult<(Self, Self)> {
+        let subset = subset.map(|v| v.into_iter().map(PlSmallStr::from).collect());
+        let (unique, duplicates) =
+            py.enter_polars(|| self.df.unique_with_duplicates(subset, maintain_order))?;
+        Ok((unique.into(), duplicates.into()))
+    }
+
     pub fn lazy(&self) -> PyLazyFrame {
         self.df.clone().lazy().into()
     }
@@ -473,6 +500,28 @@ impl PyDataFrame {
         py.enter_polars_df(|| Ok(self.df.null_count()))
     }
 
+    #[cfg(feature = "approx_unique")]
+    pub fn summarize(&self, py: Python, n_samples: usize) -> PyResult<Self> {
+        py.enter_polars_df(|| self.df.summarize(n_samples))
+    }
+
+    pub fn n_unique(&self, py: Python) -> PyResult<usize> {
+        py.enter_polars(|| self.df.n_unique(None))
+    }
+
+    #[cfg(feature = "approx_unique")]
+    pub fn approx_n_unique(&self, py: Python) -> PyResult<IdxSize> {
+        py.enter_polars(|| self.df.approx_n_unique(None))
+    }
+
+    pub fn downsample_lttb(&self, py: Python, x: &str, y: &str, n: usize) -> PyResult<Self> {
+        py.enter_polars_df(|| self.df.downsample_lttb(x, y, n))
+    }
+
+    pub fn bin2d(&self, py: Python, x: &str, y: &str, bins: (usize, usize)) -> PyResult<Self> {
+        py.enter_polars_df(|| self.df.bin2d(x, y, [bins.0, bins.1]))
+    }
+
     #[pyo3(signature = (lambda, output_type, inference_size))]
     pub fn map_rows(
         &mut self,
@@ -523,6 +572,10 @@ impl PyDataFrame {
         py.enter_polars_series(|| self.df.hash_rows(Some(hb)))
     }
 
+    pub fn hash_rows_stable(&self, py: Python<'_>, seed: u64) -> PyResult<PySeries> {
+        py.enter_polars_series(|| self.df.hash_rows_stable(seed))
+    }
+
     #[pyo3(signature = (keep_names_as, column_names))]
     pub fn transpose(
         &mut self,
@@ -316,14 +316,91 @@ impl PyDataFrame {
         })
     }
 
+    #[staticmethod]
+    #[cfg(feature = "protobuf")]
+    #[pyo3(signature = (py_f, descriptor_set, message_type, columns, n_rows))]
+    pub fn read_protobuf(
+        py: Python<'_>,
+        py_f: PyObject,
+        descriptor_set: Vec<u8>,
+        message_type: &str,
+        columns: Option<Vec<String>>,
+        n_rows: Option<usize>,
+    ) -> PyResult<Self> {
+        use polars_io::protobuf::ProtobufReader;
+
+        let file = get_file_like(py_f, false)?;
+        let message_type = PlSmallStr::from_str(message_type);
+        let columns = columns.map(|columns| columns.into_iter().map(Into::into).collect());
+        py.enter_polars_df(move || {
+            ProtobufReader::new(file)
+                .with_descriptor_set(descriptor_set)
+                .with_message_type(message_type)
+                .with_columns(columns)
+                .with_n_rows(n_rows)
+                .finish()
+        })
+    }
+
+    #[staticmethod]
+    #[cfg(feature = "msgpack")]
+    #[pyo3(signature = (py_f, columns, n_rows))]
+    pub fn read_msgpack(
+        py: Python<'_>,
+        py_f: PyObject,
+        columns: Option<Vec<String>>,
+        n_rows: Option<usize>,
+    ) -> PyResult<Self> {
+        use polars_io::msgpack::MsgPackReader;
+
+        let file = get_file_like(py_f, false)?;
+        let columns = columns.map(|columns| columns.into_iter().map(Into::into).collect());
+        py.enter_polars_df(move || {
+            MsgPackReader::new(file)
+                .with_columns(columns)
+                .with_n_rows(n_rows)
+                .finish()
+        })
+    }
+
+    #[staticmethod]
+    #[cfg(feature = "bson")]
+    #[pyo3(signature = (py_f, columns, n_rows))]
+    pub fn read_bson(
+        py: Python<'_>,
+        py_f: PyObject,
+        columns: Option<Vec<String>>,
+        n_rows: Option<usize>,
+    ) -> PyResult<Self> {
+        use polars_io::bson::BsonReader;
+
+        let file = get_file_like(py_f, false)?;
+        let columns = columns.map(|columns| columns.into_iter().map(Into::into).collect());
+        py.enter_polars_df(move || {
+            BsonReader::new(file)
+                .with_columns(columns)
+                .with_n_rows(n_rows)
+                .finish()
+        })
+    }
+
     #[cfg(feature = "json")]
-    pub fn write_json(&mut self, py: Python<'_>, py_f: PyObject) -> PyResult<()> {
+    #[pyo3(signature = (py_f, pretty, sort_keys))]
+    pub fn write_json(
+        &mut self,
+        py: Python<'_>,
+        py_f: PyObject,
+        pretty: bool,
+        sort_keys: bool,
+    ) -> PyResult<()> {
         let file = BufWriter::new(get_file_like(py_f, true)?);
         py.enter_polars(|| {
             // TODO: Cloud support
 
             JsonWriter::new(file)
                 .with_json_format(JsonFormat::Json)
+                .with_pretty(pretty)
+                .with_sort_keys(sort_keys)
                 .finish(&mut self.df)
         })
     }
@@ -0,0 +1,31 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+use crate::error::PyPolarsErr;
+
+#[pymethods]
+impl PyExpr {
+    fn anon_hash_stable(&self, salt_id: PyExpr, salts: Vec<String>) -> Self {
+        let salts = salts.into_iter().map(PlSmallStr::from).collect();
+        self.inner
+            .clone()
+            .anon()
+            .hash_stable(salt_id.inner, salts)
+            .into()
+    }
+
+    fn anon_suppress_rare(&self, k: IdxSize) -> Self {
+        self.inner.clone().anon().suppress_rare(k).into()
+    }
+
+    fn anon_generalize_date(&self, level: &str) -> PyResult<Self> {
+        let expr = self
+            .inner
+            .clone()
+            .anon()
+            .generalize_date(level)
+            .map_err(PyPolarsErr::from)?;
+        Ok(expr.into())
+    }
+}
@@ -62,6 +62,15 @@ impl PyExpr {
             .into())
     }
 
+    #[cfg(feature = "timezones")]
+    fn dt_convert_time_zone_per_row(&self, time_zones: Self) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .convert_time_zone_per_row(time_zones.inner)
+            .into()
+    }
+
     fn dt_cast_time_unit(&self, time_unit: Wrap<TimeUnit>) -> Self {
         self.inner.clone().dt().cast_time_unit(time_unit.0).into()
     }
@@ -147,6 +156,21 @@ impl PyExpr {
             .combine(time.inner, time_unit.0)
             .into()
     }
+
+    #[cfg(feature = "timezones")]
+    fn dt_combine_in_time_zones(
+        &self,
+        time: Self,
+        time_unit: Wrap<TimeUnit>,
+        time_zones: Self,
+    ) -> Self {
+        self.inner
+            .clone()
+            .dt()
+            .combine_in_time_zones(time.inner, time_unit.0, time_zones.inner)
+            .into()
+    }
+
     fn dt_millennium(&self) -> Self {
         self.inner.clone().dt().millennium().into()
     }
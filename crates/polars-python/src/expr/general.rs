@@ -9,15 +9,16 @@ use polars_plan::plans::predicates::aexpr_to_skip_batch_predicate;
 use polars_plan::plans::{ExprToIRContext, node_to_expr, to_expr_ir};
 use polars_utils::arena::Arena;
 use pyo3::class::basic::CompareOp;
+use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 
 use super::datatype::PyDataTypeExpr;
 use super::selector::PySelector;
-use crate::PyExpr;
 use crate::conversion::{Wrap, parse_fill_null_strategy};
 use crate::error::PyPolarsErr;
 use crate::map::lazy::map_single;
 use crate::utils::EnterPolarsExt;
+use crate::{PyExpr, PyLazyFrame};
 
 #[pymethods]
 impl PyExpr {
@@ -163,7 +164,7 @@ impl PyExpr {
             .into()
     }
 
-    #[pyo3(signature = (breaks, labels, left_closed, include_breaks))]
+    #[pyo3(signature = (breaks, labels, left_closed, include_breaks, *, missing_label=None))]
     #[cfg(feature = "cutqcut")]
     fn cut(
         &self,
@@ -171,13 +172,41 @@ impl PyExpr {
         labels: Option<Vec<String>>,
         left_closed: bool,
         include_breaks: bool,
+        missing_label: Option<String>,
     ) -> Self {
         self.inner
             .clone()
-            .cut(breaks, labels, left_closed, include_breaks)
+            .cut(
+                breaks,
+                labels,
+                left_closed,
+                include_breaks,
+                missing_label.map(Into::into),
+            )
             .into()
     }
-    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (breaks, labels, left_closed, include_breaks, *, missing_label=None))]
+    #[cfg(feature = "cutqcut")]
+    fn cut_with_breaks(
+        &self,
+        breaks: PyExpr,
+        labels: Option<Vec<String>>,
+        left_closed: bool,
+        include_breaks: bool,
+        missing_label: Option<String>,
+    ) -> Self {
+        self.inner
+            .clone()
+            .cut_with_breaks(
+                breaks.inner,
+                labels,
+                left_closed,
+                include_breaks,
+                missing_label.map(Into::into),
+            )
+            .into()
+    }
+    #[pyo3(signature = (probs, labels, left_closed, allow_duplicates, include_breaks, *, missing_label=None))]
     #[cfg(feature = "cutqcut")]
     fn qcut(
         &self,
@@ -186,13 +215,21 @@ impl PyExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        missing_label: Option<String>,
     ) -> Self {
         self.inner
             .clone()
-            .qcut(probs, labels, left_closed, allow_duplicates, include_breaks)
+            .qcut(
+                probs,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                missing_label.map(Into::into),
+            )
             .into()
     }
-    #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks))]
+    #[pyo3(signature = (n_bins, labels, left_closed, allow_duplicates, include_breaks, *, missing_label=None))]
     #[cfg(feature = "cutqcut")]
     fn qcut_uniform(
         &self,
@@ -201,6 +238,7 @@ impl PyExpr {
         left_closed: bool,
         allow_duplicates: bool,
         include_breaks: bool,
+        missing_label: Option<String>,
     ) -> Self {
         self.inner
             .clone()
@@ -210,10 +248,81 @@ impl PyExpr {
                 left_closed,
                 allow_duplicates,
                 include_breaks,
+                missing_label.map(Into::into),
             )
             .into()
     }
 
+    #[pyo3(signature = (separator, drop_first, sparse))]
+    fn to_dummies(&self, separator: Option<String>, drop_first: bool, sparse: bool) -> Self {
+        self.inner
+            .clone()
+            .to_dummies(separator.map(Into::into), drop_first, sparse)
+            .into()
+    }
+
+    #[pyo3(signature = (target, folds, smoothing, seed))]
+    fn categorical_target_encode(
+        &self,
+        target: PyExpr,
+        folds: u32,
+        smoothing: f64,
+        seed: u64,
+    ) -> Self {
+        self.inner
+            .clone()
+            .categorical_target_encode(target.inner, folds, smoothing, seed)
+            .into()
+    }
+
+    fn frequency_encode(&self, normalize: bool) -> Self {
+        self.inner.clone().frequency_encode(normalize).into()
+    }
+
+    #[pyo3(signature = (weights, n_samples, statistic, method, block_size, confidence_level, seed))]
+    fn bootstrap(
+        &self,
+        weights: Option<PyExpr>,
+        n_samples: usize,
+        statistic: Wrap<BootstrapStatistic>,
+        method: &str,
+        block_size: Option<usize>,
+        confidence_level: f64,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        let method = match (method, block_size) {
+            ("iid", None) => BootstrapMethod::Iid,
+            ("block", Some(block_size)) => BootstrapMethod::Block { block_size },
+            ("iid", Some(_)) => {
+                return Err(PyValueError::new_err(
+                    "`block_size` is only supported with the 'block' bootstrap method",
+                ));
+            },
+            ("block", None) => {
+                return Err(PyValueError::new_err(
+                    "the 'block' bootstrap method requires a `block_size`",
+                ));
+            },
+            (v, _) => {
+                return Err(PyValueError::new_err(format!(
+                    "bootstrap `method` must be one of {{'iid', 'block'}}, got {v}",
+                )));
+            },
+        };
+        Ok(self
+            .inner
+            .clone()
+            .bootstrap(
+                weights.map(|w| w.inner),
+                n_samples,
+                statistic.0,
+                method,
+                confidence_level,
+                seed,
+            )
+            .into())
+    }
+
     #[cfg(feature = "rle")]
     fn rle(&self) -> Self {
         self.inner.clone().rle().into()
@@ -468,6 +577,13 @@ impl PyExpr {
         self.clone().inner.round_sig_figs(digits).into()
     }
 
+    fn round_to_increment(&self, increment: f64, mode: Wrap<RoundMode>) -> Self {
+        self.inner
+            .clone()
+            .round_to_increment(increment, mode.0)
+            .into()
+    }
+
     fn floor(&self) -> Self {
         self.inner.clone().floor().into()
     }
@@ -660,6 +776,32 @@ impl PyExpr {
         self.inner.clone().pow(exponent.inner).into()
     }
 
+    fn add_checked(&self, other: Self) -> Self {
+        self.inner.clone().add_checked(other.inner).into()
+    }
+
+    fn sub_checked(&self, other: Self) -> Self {
+        self.inner.clone().sub_checked(other.inner).into()
+    }
+
+    fn mul_checked(&self, other: Self) -> Self {
+        self.inner.clone().mul_checked(other.inner).into()
+    }
+
+    fn truediv_or(&self, other: Self, default: Self) -> Self {
+        self.inner
+            .clone()
+            .truediv_or(other.inner, default.inner)
+            .into()
+    }
+
+    fn floordiv_or(&self, other: Self, default: Self) -> Self {
+        self.inner
+            .clone()
+            .floordiv_or(other.inner, default.inner)
+            .into()
+    }
+
     fn sqrt(&self) -> Self {
         self.inner.clone().sqrt().into()
     }
@@ -733,6 +875,15 @@ impl PyExpr {
     fn interpolate_by(&self, by: PyExpr) -> Self {
         self.inner.clone().interpolate_by(by.inner).into()
     }
+    fn downsample_lttb(&self, by: PyExpr, n_out: usize) -> Self {
+        self.inner.clone().downsample_lttb(by.inner, n_out).into()
+    }
+    fn downsample_minmax(&self, by: PyExpr, n_buckets: usize) -> Self {
+        self.inner
+            .clone()
+            .downsample_minmax(by.inner, n_buckets)
+            .into()
+    }
 
     fn lower_bound(&self) -> Self {
         self.inner.clone().lower_bound().into()
@@ -920,6 +1071,25 @@ impl PyExpr {
             .into()
     }
 
+    #[pyo3(signature = (mapping, default=None, return_dtype=None))]
+    fn replace_strict_with_frame(
+        &self,
+        py: Python<'_>,
+        mapping: PyLazyFrame,
+        default: Option<PyExpr>,
+        return_dtype: Option<PyDataTypeExpr>,
+    ) -> PyResult<Self> {
+        let expr = py.enter_polars(|| {
+            dsl::replace_strict_with_frame(
+                self.inner.clone(),
+                mapping.ldf,
+                default.map(|e| e.inner),
+                return_dtype.map(|dt| dt.inner),
+            )
+        })?;
+        Ok(expr.into())
+    }
+
     #[cfg(feature = "hist")]
     #[pyo3(signature = (bins, bin_count, include_category, include_breakpoint))]
     fn hist(
@@ -50,6 +50,10 @@ impl PyExpr {
         self.inner.clone().list().eval(expr.inner).into()
     }
 
+    fn list_agg(&self, expr: PyExpr) -> Self {
+        self.inner.clone().list().agg(expr.inner).into()
+    }
+
     #[cfg(feature = "list_filter")]
     fn list_filter(&self, predicate: PyExpr) -> Self {
         self.inner
@@ -59,6 +63,11 @@ impl PyExpr {
             .into()
     }
 
+    #[cfg(feature = "list_filter")]
+    fn list_filter_mask(&self, mask: PyExpr) -> Self {
+        self.inner.clone().list().filter(mask.inner).into()
+    }
+
     fn list_get(&self, index: PyExpr, null_on_oob: bool) -> Self {
         self.inner
             .clone()
@@ -199,12 +208,15 @@ impl PyExpr {
         self.inner.clone().list().to_array(width).into()
     }
 
-    #[pyo3(signature = (width_strat, name_gen, upper_bound))]
+    #[pyo3(signature = (width_strat, name_gen, name_format, upper_bound, error_on_overflow, infer_sample))]
     fn list_to_struct(
         &self,
         width_strat: Wrap<ListToStructWidthStrategy>,
         name_gen: Option<PyObject>,
+        name_format: Option<Wrap<PlSmallStr>>,
         upper_bound: Option<usize>,
+        error_on_overflow: bool,
+        infer_sample: Option<usize>,
     ) -> PyResult<Self> {
         let name_gen = name_gen.map(|lambda| PlanCallback::new_python(PythonObject(lambda)));
 
@@ -216,22 +228,32 @@ impl PyExpr {
                 infer_field_strategy: width_strat.0,
                 get_index_name: name_gen,
                 max_fields: upper_bound,
+                name_format: name_format.map(|f| f.0),
+                error_on_overflow,
+                infer_sample,
             })
             .into())
     }
 
-    #[pyo3(signature = (names))]
-    fn list_to_struct_fixed_width(&self, names: Bound<'_, PySequence>) -> PyResult<Self> {
+    #[pyo3(signature = (names, error_on_overflow))]
+    fn list_to_struct_fixed_width(
+        &self,
+        names: Bound<'_, PySequence>,
+        error_on_overflow: bool,
+    ) -> PyResult<Self> {
+        let names = names
+            .try_iter()?
+            .map(|x| Ok(x?.extract::<Wrap<PlSmallStr>>()?.0))
+            .collect::<PyResult<Arc<[_]>>>()?;
         Ok(self
             .inner
             .clone()
             .list()
-            .to_struct(ListToStruct::FixedWidth(
-                names
-                    .try_iter()?
-                    .map(|x| Ok(x?.extract::<Wrap<PlSmallStr>>()?.0))
-                    .collect::<PyResult<Arc<[_]>>>()?,
-            ))
+            .to_struct(if error_on_overflow {
+                ListToStruct::FixedWidthStrict(names)
+            } else {
+                ListToStruct::FixedWidth(names)
+            })
             .into())
     }
 
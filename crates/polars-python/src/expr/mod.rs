@@ -1,4 +1,6 @@
 #[cfg(feature = "pymethods")]
+mod anonymize;
+#[cfg(feature = "pymethods")]
 mod array;
 #[cfg(feature = "pymethods")]
 mod binary;
@@ -26,6 +28,8 @@ mod serde;
 mod string;
 #[cfg(feature = "pymethods")]
 mod r#struct;
+#[cfg(feature = "pymethods")]
+mod url;
 
 use std::mem::ManuallyDrop;
 
@@ -25,6 +25,19 @@ impl PyExpr {
         self.inner.clone().name().suffix(suffix).into()
     }
 
+    fn name_format(&self, template: &str) -> Self {
+        self.inner.clone().name().format(template).into()
+    }
+
+    #[cfg(feature = "regex")]
+    fn name_replace(&self, pattern: &str, value: &str, literal: bool) -> Self {
+        self.inner
+            .clone()
+            .name()
+            .replace(pattern, value, literal)
+            .into()
+    }
+
     fn name_to_lowercase(&self) -> Self {
         self.inner.clone().name().to_lowercase().into()
     }
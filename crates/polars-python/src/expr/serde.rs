@@ -88,4 +88,17 @@ impl PyExpr {
         })?;
         Ok(inner.into())
     }
+
+    /// Convert into the text representation.
+    fn to_text(&self) -> PyResult<String> {
+        let text = self.inner.to_text().map_err(PyPolarsErr::from)?;
+        Ok(text)
+    }
+
+    /// Parse an Expr from the text representation.
+    #[staticmethod]
+    fn from_text(text: &str) -> PyResult<PyExpr> {
+        let expr = Expr::from_text(text).map_err(PyPolarsErr::from)?;
+        Ok(expr.into())
+    }
 }
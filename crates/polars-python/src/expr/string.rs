@@ -165,6 +165,14 @@ impl PyExpr {
         self.inner.clone().str().reverse().into()
     }
 
+    fn str_parse_common_log(&self) -> Self {
+        self.inner.clone().str().parse_common_log().into()
+    }
+
+    fn str_parse_user_agent(&self) -> Self {
+        self.inner.clone().str().parse_user_agent().into()
+    }
+
     fn str_pad_start(&self, length: PyExpr, fill_char: char) -> Self {
         self.inner
             .clone()
@@ -181,6 +189,22 @@ impl PyExpr {
             .into()
     }
 
+    fn str_pad_start_display(&self, width: PyExpr, fill_char: char, truncate: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .pad_start_display(width.inner, fill_char, truncate)
+            .into()
+    }
+
+    fn str_pad_end_display(&self, width: PyExpr, fill_char: char, truncate: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .pad_end_display(width.inner, fill_char, truncate)
+            .into()
+    }
+
     fn str_zfill(&self, length: PyExpr) -> Self {
         self.inner.clone().str().zfill(length.inner).into()
     }
@@ -309,6 +333,14 @@ impl PyExpr {
             .into()
     }
 
+    fn str_split_exact_with_rest(&self, by: Self, n: usize, inclusive: bool) -> Self {
+        self.inner
+            .clone()
+            .str()
+            .split_exact_with_rest(by.inner, n, inclusive)
+            .into()
+    }
+
     fn str_splitn(&self, by: Self, n: usize) -> Self {
         self.inner.clone().str().splitn(by.inner, n).into()
     }
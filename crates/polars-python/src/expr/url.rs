@@ -0,0 +1,22 @@
+use pyo3::prelude::*;
+
+use crate::PyExpr;
+
+#[pymethods]
+impl PyExpr {
+    fn url_parse(&self) -> Self {
+        self.inner.clone().url().parse().into()
+    }
+
+    fn url_query_param(&self, key: &str) -> Self {
+        self.inner.clone().url().query_param(key.into()).into()
+    }
+
+    fn url_encode(&self) -> Self {
+        self.inner.clone().url().encode().into()
+    }
+
+    fn url_decode(&self) -> Self {
+        self.inner.clone().url().decode().into()
+    }
+}
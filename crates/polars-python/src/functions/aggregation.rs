@@ -1,7 +1,9 @@
 use polars::lazy::dsl;
+use polars::prelude::QuantileMethod;
 use pyo3::prelude::*;
 
 use crate::PyExpr;
+use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
 use crate::expr::ToExprs;
 
@@ -46,3 +48,22 @@ pub fn mean_horizontal(exprs: Vec<PyExpr>, ignore_nulls: bool) -> PyResult<PyExp
     let e = dsl::mean_horizontal(exprs, ignore_nulls).map_err(PyPolarsErr::from)?;
     Ok(e.into())
 }
+
+#[pyfunction]
+pub fn quantile_horizontal(
+    exprs: Vec<PyExpr>,
+    quantile: f64,
+    interpolation: Wrap<QuantileMethod>,
+) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e =
+        dsl::quantile_horizontal(exprs, quantile, interpolation.0).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn std_horizontal(exprs: Vec<PyExpr>, ddof: u8) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::std_horizontal(exprs, ddof).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
@@ -5,9 +5,18 @@ use polars::prelude::ArrowSchema;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+#[cfg(any(feature = "clipboard", feature = "parquet"))]
+use crate::PyDataFrame;
 use crate::conversion::Wrap;
 use crate::error::PyPolarsErr;
 use crate::file::{EitherRustPythonFile, get_either_file};
+#[cfg(any(
+    feature = "avro",
+    feature = "clipboard",
+    feature = "parquet",
+    feature = "schema_registry"
+))]
+use crate::utils::EnterPolarsExt;
 
 #[cfg(feature = "ipc")]
 #[pyfunction]
@@ -46,6 +55,87 @@ pub fn read_parquet_metadata(py: Python, py_f: PyObject) -> PyResult<Bound<PyDic
     Ok(dict)
 }
 
+#[cfg(feature = "parquet")]
+#[pyfunction]
+pub fn read_parquet_row_group_metadata(py: Python, py_f: PyObject) -> PyResult<PyDataFrame> {
+    use polars_io::parquet::metadata::row_group_metadata_df;
+    use polars_parquet::read::read_metadata;
+
+    let metadata = match get_either_file(py_f, false)? {
+        EitherRustPythonFile::Rust(r) => {
+            read_metadata(&mut BufReader::new(r)).map_err(PyPolarsErr::from)?
+        },
+        EitherRustPythonFile::Py(mut r) => read_metadata(&mut r).map_err(PyPolarsErr::from)?,
+    };
+
+    py.enter_polars_df(|| row_group_metadata_df(&metadata))
+}
+
+#[cfg(feature = "avro")]
+#[pyfunction]
+pub fn read_avro_schema(py: Python<'_>, py_f: PyObject) -> PyResult<Wrap<polars::prelude::Schema>> {
+    use polars::io::SerReader;
+    use polars::io::avro::AvroReader;
+
+    let file = crate::file::get_file_like(py_f, false)?;
+    let schema = py.enter_polars(move || AvroReader::new(file).schema())?;
+    Ok(Wrap(schema))
+}
+
+#[cfg(feature = "avro")]
+#[pyfunction]
+pub fn avro_schema_json(schema: Wrap<polars::prelude::Schema>, name: &str) -> PyResult<String> {
+    let out = polars::io::avro::avro_schema_json(&schema.0, name).map_err(PyPolarsErr::from)?;
+    Ok(out)
+}
+
+#[cfg(feature = "schema_registry")]
+fn schema_registry_options(
+    url: String,
+    subject: String,
+    compatibility: Option<Wrap<polars_io::schema_registry::CompatibilityMode>>,
+    basic_auth: Option<(String, String)>,
+) -> polars_io::schema_registry::SchemaRegistryOptions {
+    polars_io::schema_registry::SchemaRegistryOptions::new(url, subject)
+        .with_compatibility(compatibility.map(|c| c.0))
+        .with_basic_auth(basic_auth)
+}
+
+#[cfg(feature = "schema_registry")]
+#[pyfunction]
+#[pyo3(signature = (schema, format, url, subject, compatibility, basic_auth))]
+pub fn validate_schema_registry(
+    py: Python<'_>,
+    schema: String,
+    format: Wrap<polars_io::schema_registry::SchemaFormat>,
+    url: String,
+    subject: String,
+    compatibility: Option<Wrap<polars_io::schema_registry::CompatibilityMode>>,
+    basic_auth: Option<(String, String)>,
+) -> PyResult<()> {
+    let options = schema_registry_options(url, subject, compatibility, basic_auth);
+    py.enter_polars(|| {
+        polars_io::schema_registry::validate_schema_blocking(&options, &schema, format.0)
+    })
+}
+
+#[cfg(feature = "schema_registry")]
+#[pyfunction]
+#[pyo3(signature = (schema, format, url, subject, basic_auth))]
+pub fn register_schema_registry(
+    py: Python<'_>,
+    schema: String,
+    format: Wrap<polars_io::schema_registry::SchemaFormat>,
+    url: String,
+    subject: String,
+    basic_auth: Option<(String, String)>,
+) -> PyResult<u32> {
+    let options = schema_registry_options(url, subject, None, basic_auth);
+    py.enter_polars(|| {
+        polars_io::schema_registry::register_schema_blocking(&options, &schema, format.0)
+    })
+}
+
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn fields_to_pydict(schema: &ArrowSchema, dict: &Bound<'_, PyDict>) -> PyResult<()> {
     for field in schema.iter_values() {
@@ -57,24 +147,24 @@ fn fields_to_pydict(schema: &ArrowSchema, dict: &Bound<'_, PyDict>) -> PyResult<
 
 #[cfg(feature = "clipboard")]
 #[pyfunction]
-pub fn read_clipboard_string() -> PyResult<String> {
-    use arboard;
-    let mut clipboard =
-        arboard::Clipboard::new().map_err(|e| PyPolarsErr::Other(format!("{e}")))?;
-    let result = clipboard
-        .get_text()
-        .map_err(|e| PyPolarsErr::Other(format!("{e}")))?;
-    Ok(result)
+pub fn read_clipboard_string(py: Python<'_>) -> PyResult<String> {
+    py.enter_polars(polars_io::clipboard::read_clipboard_string)
 }
 
 #[cfg(feature = "clipboard")]
 #[pyfunction]
-pub fn write_clipboard_string(s: &str) -> PyResult<()> {
-    use arboard;
-    let mut clipboard =
-        arboard::Clipboard::new().map_err(|e| PyPolarsErr::Other(format!("{e}")))?;
-    clipboard
-        .set_text(s)
-        .map_err(|e| PyPolarsErr::Other(format!("{e}")))?;
-    Ok(())
+pub fn write_clipboard_string(py: Python<'_>, s: &str) -> PyResult<()> {
+    py.enter_polars(|| polars_io::clipboard::write_clipboard_string(s))
+}
+
+#[cfg(feature = "clipboard")]
+#[pyfunction]
+pub fn sniff_clipboard_separator(text: &str) -> String {
+    (polars_io::clipboard::sniff_separator(text) as char).to_string()
+}
+
+#[cfg(feature = "clipboard")]
+#[pyfunction]
+pub fn parse_spreadsheet_numbers(py: Python<'_>, df: PyDataFrame) -> PyResult<PyDataFrame> {
+    py.enter_polars_df(|| polars_io::clipboard::parse_spreadsheet_numbers(df.df))
 }
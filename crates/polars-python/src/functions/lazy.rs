@@ -69,6 +69,7 @@ pub fn arg_sort_by(
     nulls_last: Vec<bool>,
     multithreaded: bool,
     maintain_order: bool,
+    limit: Option<IdxSize>,
 ) -> PyExpr {
     let by = by.into_iter().map(|e| e.inner).collect::<Vec<Expr>>();
     dsl::arg_sort_by(
@@ -78,7 +79,7 @@ pub fn arg_sort_by(
             nulls_last,
             multithreaded,
             maintain_order,
-            limit: None,
+            limit,
         },
     )
     .into()
@@ -110,6 +111,20 @@ pub fn coalesce(exprs: Vec<PyExpr>) -> PyExpr {
     dsl::coalesce(&exprs).into()
 }
 
+#[pyfunction]
+pub fn is_first_distinct(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::is_first_distinct_rows(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
+#[pyfunction]
+pub fn is_last_distinct(exprs: Vec<PyExpr>) -> PyResult<PyExpr> {
+    let exprs = exprs.to_exprs();
+    let e = dsl::is_last_distinct_rows(exprs).map_err(PyPolarsErr::from)?;
+    Ok(e.into())
+}
+
 #[pyfunction]
 pub fn col(name: &str) -> PyExpr {
     dsl::col(name).into()
@@ -132,6 +147,18 @@ pub fn collect_all(
     Ok(dfs.into_iter().map(Into::into).collect())
 }
 
+#[pyfunction]
+pub fn sink_multiple(
+    lfs: Vec<PyLazyFrame>,
+    engine: Wrap<Engine>,
+    optflags: PyOptFlags,
+    py: Python<'_>,
+) -> PyResult<()> {
+    let plans = lfs_to_plans(lfs);
+    py.enter_polars(|| LazyFrame::sink_multiple(plans, engine.0, optflags.inner))?;
+    Ok(())
+}
+
 #[pyfunction]
 pub fn explain_all(lfs: Vec<PyLazyFrame>, optflags: PyOptFlags, py: Python) -> PyResult<String> {
     let plans = lfs_to_plans(lfs);
@@ -146,26 +173,32 @@ pub fn collect_all_with_callback(
     optflags: PyOptFlags,
     lambda: PyObject,
     py: Python<'_>,
-) {
+) -> PyResult<()> {
     let plans = lfs.into_iter().map(|lf| lf.ldf.logical_plan).collect();
-    let result = py
-        .enter_polars(|| LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner))
-        .map(|dfs| {
-            dfs.into_iter()
-                .map(Into::into)
-                .collect::<Vec<PyDataFrame>>()
+    py.enter_polars_ok(|| {
+        // Collect in the background, so that an asyncio event loop calling this is not blocked
+        // until all queries are finished.
+        polars_core::POOL.spawn(move || {
+            let result = LazyFrame::collect_all_with_engine(plans, engine.0, optflags.inner)
+                .map(|dfs| {
+                    dfs.into_iter()
+                        .map(Into::into)
+                        .collect::<Vec<PyDataFrame>>()
+                })
+                .map_err(PyPolarsErr::from);
+
+            Python::with_gil(|py| match result {
+                Ok(dfs) => {
+                    lambda.call1(py, (dfs,)).map_err(|err| err.restore(py)).ok();
+                },
+                Err(err) => {
+                    lambda
+                        .call1(py, (PyErr::from(err),))
+                        .map_err(|err| err.restore(py))
+                        .ok();
+                },
+            });
         });
-
-    Python::with_gil(|py| match result {
-        Ok(dfs) => {
-            lambda.call1(py, (dfs,)).map_err(|err| err.restore(py)).ok();
-        },
-        Err(err) => {
-            lambda
-                .call1(py, (PyErr::from(err),))
-                .map_err(|err| err.restore(py))
-                .ok();
-        },
     })
 }
 
@@ -228,6 +261,16 @@ pub fn len() -> PyExpr {
     dsl::len().into()
 }
 
+#[pyfunction]
+pub fn uuid4() -> PyExpr {
+    dsl::uuid4().into()
+}
+
+#[pyfunction]
+pub fn snowflake_id(worker_id: u16) -> PyExpr {
+    dsl::snowflake_id(worker_id).into()
+}
+
 #[pyfunction]
 pub fn cov(a: PyExpr, b: PyExpr, ddof: u8) -> PyExpr {
     dsl::cov(a.inner, b.inner, ddof).into()
@@ -490,11 +533,21 @@ pub fn map_mul(
     map::lazy::map_mul(&pyexpr, py, lambda, output_type, map_groups, returns_scalar)
 }
 
+#[pyfunction]
+pub fn cached(expr: PyExpr) -> PyExpr {
+    dsl::cached(expr.inner).into()
+}
+
 #[pyfunction]
 pub fn pearson_corr(a: PyExpr, b: PyExpr) -> PyExpr {
     dsl::pearson_corr(a.inner, b.inner).into()
 }
 
+#[pyfunction]
+pub fn placeholder(name: &str, dtype: Wrap<DataType>) -> PyExpr {
+    dsl::placeholder(name, dtype.0).into()
+}
+
 #[pyfunction]
 pub fn reduce(
     lambda: PyObject,
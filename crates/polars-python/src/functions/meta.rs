@@ -1,4 +1,5 @@
 use polars_core::POOL;
+use polars_core::config::FloatSummation;
 use polars_core::fmt::FloatFmt;
 use polars_core::prelude::IDX_DTYPE;
 use pyo3::exceptions::PyValueError;
@@ -95,3 +96,28 @@ pub fn get_trim_decimal_zeros() -> PyResult<Option<bool>> {
     use polars_core::fmt::get_trim_decimal_zeros;
     Ok(Some(get_trim_decimal_zeros()))
 }
+
+#[pyfunction]
+#[pyo3(signature = (method=None))]
+pub fn set_float_summation(method: Option<&str>) -> PyResult<()> {
+    let method = match method {
+        None | Some("fast") => FloatSummation::Fast,
+        Some("kahan") => FloatSummation::Kahan,
+        Some(e) => {
+            return Err(PyValueError::new_err(format!(
+                "method must be one of {{'fast', 'kahan'}}, got {e}",
+            )));
+        },
+    };
+    polars_core::config::set_float_summation(method);
+    Ok(())
+}
+
+#[pyfunction]
+pub fn get_float_summation() -> PyResult<Option<String>> {
+    let method = match polars_core::config::float_summation() {
+        FloatSummation::Fast => "fast",
+        FloatSummation::Kahan => "kahan",
+    };
+    Ok(Some(method.to_string()))
+}
@@ -1,5 +1,10 @@
+use std::path::PathBuf;
+
+use polars_dtype::categorical::Categories;
 use pyo3::prelude::*;
 
+use crate::error::PyPolarsErr;
+
 #[pyfunction]
 pub fn enable_string_cache() {
     // The string cache no longer exists.
@@ -16,6 +21,14 @@ pub fn using_string_cache() -> bool {
     true
 }
 
+#[pyfunction]
+pub fn enable_shared_string_cache(path: PathBuf) -> PyResult<()> {
+    Categories::global()
+        .share(&path)
+        .map_err(PyPolarsErr::from)?;
+    Ok(())
+}
+
 #[pyclass]
 pub struct PyStringCacheHolder;
 
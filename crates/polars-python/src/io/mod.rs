@@ -1,18 +1,21 @@
 use std::sync::Arc;
 
 use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::table_statistics::TableStatistics;
 use polars::prelude::{
-    CastColumnsPolicy, ColumnMapping, ExtraColumnsPolicy, MissingColumnsPolicy, PlSmallStr, Schema,
-    UnifiedScanArgs,
+    CastColumnsPolicy, ColumnMapping, ExtraColumnsPolicy, FileErrorPolicy, MissingColumnsPolicy,
+    PlSmallStr, Schema, UnifiedScanArgs,
 };
 use polars_io::{HiveOptions, RowIndex};
 use polars_utils::IdxSize;
 use polars_utils::plpath::PlPathRef;
 use polars_utils::slice_enum::Slice;
-use pyo3::types::PyAnyMethods;
-use pyo3::{Bound, FromPyObject, PyObject, PyResult};
+use pyo3::prelude::*;
 
+use crate::PyDataFrame;
+use crate::error::PyPolarsErr;
 use crate::prelude::Wrap;
+use crate::utils::EnterPolarsExt;
 
 /// Interface to `class ScanOptions` on the Python side
 pub struct PyScanOptions<'py>(Bound<'py, pyo3::PyAny>);
@@ -48,6 +51,8 @@ impl PyScanOptions<'_> {
             retries: usize,
             deletion_files: Option<Wrap<DeletionFilesList>>,
             column_mapping: Option<Wrap<ColumnMapping>>,
+            table_statistics: Option<PyDataFrame>,
+            file_errors: Option<PyFileErrorPolicy>,
         }
 
         let Extract {
@@ -68,6 +73,8 @@ impl PyScanOptions<'_> {
             retries,
             deletion_files,
             column_mapping,
+            table_statistics,
+            file_errors,
         } = self.0.extract()?;
 
         let cloud_options = storage_options;
@@ -114,6 +121,11 @@ impl PyScanOptions<'_> {
             try_parse_dates: try_parse_hive_dates,
         };
 
+        let table_statistics = table_statistics
+            .map(|df| TableStatistics::from_catalog_df(&df.df))
+            .transpose()
+            .map_err(PyPolarsErr::from)?;
+
         let unified_scan_args = UnifiedScanArgs {
             // Schema is currently still stored inside the options per scan type, but we do eventually
             // want to put it here instead.
@@ -132,8 +144,41 @@ impl PyScanOptions<'_> {
             include_file_paths: include_file_paths.map(|x| x.0),
             deletion_files: DeletionFilesList::filter_empty(deletion_files.map(|x| x.0)),
             column_mapping: column_mapping.map(|x| x.0),
+            table_statistics,
+            file_error_policy: file_errors.map(|x| x.policy).unwrap_or_default(),
         };
 
         Ok(unified_scan_args)
     }
 }
+
+/// Interface to `class ScanFileErrors` on the Python side
+#[pyclass]
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct PyFileErrorPolicy {
+    pub policy: FileErrorPolicy,
+}
+
+#[pymethods]
+impl PyFileErrorPolicy {
+    #[new]
+    fn new(retries: usize, skip: bool) -> Self {
+        Self {
+            policy: FileErrorPolicy {
+                retries,
+                skip,
+                skipped: Default::default(),
+            },
+        }
+    }
+
+    fn skipped(&self, py: Python) -> PyResult<PyDataFrame> {
+        let out = py.enter_polars(|| self.policy.skipped.to_df())?;
+        Ok(out.into())
+    }
+
+    fn clear_skipped(&self) {
+        self.policy.skipped.clear()
+    }
+}
@@ -0,0 +1,89 @@
+use std::io::{BufReader, BufWriter};
+
+use polars::prelude::*;
+use pyo3::prelude::*;
+
+use super::PyLazyFrame;
+use crate::PyExpr;
+use crate::error::PyPolarsErr;
+use crate::file::get_file_like;
+use crate::prelude::Wrap;
+use crate::utils::EnterPolarsExt;
+
+#[pymethods]
+impl PyLazyFrame {
+    fn with_feature_set(&self, py: Python, feature_set: &PyFeatureSet) -> PyResult<Self> {
+        let ldf = py.enter_polars(|| self.ldf.clone().with_feature_set(&feature_set.inner))?;
+        Ok(ldf.into())
+    }
+}
+
+#[pyclass]
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct PyFeatureSet {
+    pub inner: FeatureSet,
+}
+
+#[pymethods]
+impl PyFeatureSet {
+    #[new]
+    fn new(
+        name: &str,
+        version: u32,
+        features: Vec<(String, PyExpr, Option<Wrap<DataType>>)>,
+    ) -> PyResult<Self> {
+        let features = features
+            .into_iter()
+            .map(|(name, expr, dtype)| Feature {
+                name: name.into(),
+                expr: expr.inner,
+                dtype: dtype.map(|dtype| dtype.0),
+            })
+            .collect();
+        let inner = FeatureSet::new(name.into(), version, features).map_err(PyPolarsErr::from)?;
+        Ok(Self { inner })
+    }
+
+    fn name(&self) -> &str {
+        self.inner.name.as_str()
+    }
+
+    fn version(&self) -> u32 {
+        self.inner.version
+    }
+
+    fn features<'py>(
+        &self,
+        py: Python<'py>,
+    ) -> PyResult<Vec<(&str, PyExpr, Option<Bound<'py, PyAny>>)>> {
+        self.inner
+            .features()
+            .iter()
+            .map(|feature| {
+                let dtype = feature
+                    .dtype
+                    .as_ref()
+                    .map(|dtype| Wrap(dtype.clone()).into_pyobject(py))
+                    .transpose()?;
+                Ok((feature.name.as_str(), feature.expr.clone().into(), dtype))
+            })
+            .collect()
+    }
+
+    /// Serialize into binary data.
+    fn serialize_binary(&self, py: Python<'_>, py_f: PyObject) -> PyResult<()> {
+        let file = get_file_like(py_f, true)?;
+        let writer = BufWriter::new(file);
+        py.enter_polars(|| self.inner.serialize_versioned(writer))
+    }
+
+    /// Deserialize a file-like object containing binary data into a feature set.
+    #[staticmethod]
+    fn deserialize_binary(py: Python<'_>, py_f: PyObject) -> PyResult<Self> {
+        let file = get_file_like(py_f, false)?;
+        let reader = BufReader::new(file);
+        let inner = py.enter_polars(|| FeatureSet::deserialize_versioned(reader))?;
+        Ok(Self { inner })
+    }
+}
@@ -4,10 +4,11 @@ use std::num::NonZeroUsize;
 use either::Either;
 use polars::io::{HiveOptions, RowIndex};
 use polars::time::*;
+use polars_core::config::ConfigOverrides;
 use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
 use polars_parquet::arrow::write::StatisticsOptions;
-use polars_plan::dsl::ScanSources;
+use polars_plan::dsl::{DslPlan, ScanSources};
 use polars_plan::plans::{AExpr, IR};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::python_function::PythonObject;
@@ -28,6 +29,29 @@ use crate::prelude::*;
 use crate::utils::{EnterPolarsExt, to_py_err};
 use crate::{PyDataFrame, PyExpr, PyLazyGroupBy};
 
+/// The settings passed to a collect call as keyword arguments, that override the global
+/// configuration.
+fn config_overrides(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<Arc<ConfigOverrides>> {
+    fn get<'py, T: FromPyObject<'py>>(
+        kwargs: &Bound<'py, PyDict>,
+        key: &str,
+    ) -> PyResult<Option<T>> {
+        kwargs.get_item(key)?.map(|v| v.extract()).transpose()
+    }
+
+    let Some(kwargs) = kwargs else {
+        return Ok(Default::default());
+    };
+    let overrides = ConfigOverrides {
+        verbose: get(kwargs, "verbose")?,
+        ideal_morsel_size: get(kwargs, "ideal_morsel_size")?,
+        scan_readahead: get(kwargs, "scan_readahead")?,
+        join_probe_parallelism: get(kwargs, "join_probe_parallelism")?,
+        sink_writer_threads: get(kwargs, "sink_writer_threads")?,
+    };
+    Ok(Arc::new(overrides))
+}
+
 fn pyobject_to_first_path_and_scan_sources(
     obj: PyObject,
 ) -> PyResult<(Option<PlPath>, ScanSources)> {
@@ -78,7 +102,8 @@ impl PyLazyFrame {
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (
         source, sources, infer_schema_length, schema, schema_overrides, batch_size, n_rows, low_memory, rechunk,
-        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, retries, file_cache_ttl
+        row_index, ignore_errors, include_file_paths, cloud_options, credential_provider, retries, file_cache_ttl,
+        archive_member_glob, json_schema, json_schema_rejects
     ))]
     fn new_from_ndjson(
         source: Option<PyObject>,
@@ -97,6 +122,9 @@ impl PyLazyFrame {
         credential_provider: Option<PyObject>,
         retries: usize,
         file_cache_ttl: Option<u64>,
+        archive_member_glob: Option<String>,
+        json_schema: Option<String>,
+        json_schema_rejects: Option<String>,
     ) -> PyResult<Self> {
         use cloud::credential_provider::PlCredentialProvider;
         let row_index = row_index.map(|(name, offset)| RowIndex {
@@ -142,6 +170,11 @@ impl PyLazyFrame {
             .with_row_index(row_index)
             .with_ignore_errors(ignore_errors)
             .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_archive_member_glob(archive_member_glob.map(|x| x.into()))
+            .with_json_schema(
+                json_schema.map(|x| x.into()),
+                json_schema_rejects.map(|x| polars_utils::plpath::PlPath::new(&x)),
+            )
             .finish()
             .map_err(PyPolarsErr::from)?;
 
@@ -153,8 +186,8 @@ impl PyLazyFrame {
     #[pyo3(signature = (source, sources, separator, has_header, ignore_errors, skip_rows, skip_lines, n_rows, cache, overwrite_dtype,
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
-        encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths
+        encoding, row_index, try_parse_dates, date_inference, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
+        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths, archive_member_glob
     )
     )]
     fn new_from_csv(
@@ -180,6 +213,7 @@ impl PyLazyFrame {
         encoding: Wrap<CsvEncoding>,
         row_index: Option<(String, IdxSize)>,
         try_parse_dates: bool,
+        date_inference: Option<(Option<Vec<String>>, Option<Vec<String>>, Option<u8>)>,
         eol_char: &str,
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
@@ -191,6 +225,7 @@ impl PyLazyFrame {
         retries: usize,
         file_cache_ttl: Option<u64>,
         include_file_paths: Option<String>,
+        archive_member_glob: Option<String>,
     ) -> PyResult<Self> {
         #[cfg(feature = "cloud")]
         use cloud::credential_provider::PlCredentialProvider;
@@ -221,6 +256,19 @@ impl PyLazyFrame {
                 .collect::<Schema>()
         });
 
+        let to_names =
+            |names: Vec<String>| -> Vec<PlSmallStr> { names.into_iter().map(Into::into).collect() };
+        let date_inference = date_inference
+            .map(|(formats, month_names, two_digit_year_pivot)| {
+                DateInferenceOptions::new(
+                    formats.map(to_names),
+                    month_names.map(to_names),
+                    two_digit_year_pivot,
+                )
+            })
+            .transpose()
+            .map_err(PyPolarsErr::from)?;
+
         let sources = sources.0;
         let (first_path, sources) = match source {
             None => (sources.first_path().map(|p| p.into_owned()), sources),
@@ -266,13 +314,15 @@ impl PyLazyFrame {
             .with_encoding(encoding.0)
             .with_row_index(row_index)
             .with_try_parse_dates(try_parse_dates)
+            .map_parse_options(|opts| opts.with_date_inference(date_inference.clone()))
             .with_null_values(null_values)
             .with_missing_is_null(!missing_utf8_is_empty_string)
             .with_truncate_ragged_lines(truncate_ragged_lines)
             .with_decimal_comma(decimal_comma)
             .with_glob(glob)
             .with_raise_if_empty(raise_if_empty)
-            .with_include_file_paths(include_file_paths.map(|x| x.into()));
+            .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_archive_member_glob(archive_member_glob.map(|x| x.into()));
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -303,11 +353,12 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (
-        sources, schema, scan_options, parallel, low_memory, use_statistics
+        sources, schema, schema_overrides, scan_options, parallel, low_memory, use_statistics
     ))]
     fn new_from_parquet(
         sources: Wrap<ScanSources>,
         schema: Option<Wrap<Schema>>,
+        schema_overrides: Option<Wrap<Schema>>,
         scan_options: PyScanOptions,
         parallel: Wrap<ParallelStrategy>,
         low_memory: bool,
@@ -319,6 +370,7 @@ impl PyLazyFrame {
 
         let options = ParquetOptions {
             schema: schema.map(|x| Arc::new(x.0)),
+            schema_overrides: schema_overrides.map(|x| Arc::new(x.0)),
             parallel,
             low_memory,
             use_statistics,
@@ -495,6 +547,14 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.describe_optimized_plan())
     }
 
+    fn describe_optimized_plan_with_node_ids(&self, py: Python) -> PyResult<String> {
+        py.enter_polars(|| self.ldf.describe_optimized_plan_with_node_ids())
+    }
+
+    fn describe_optimized_plan_sampled(&self, py: Python, fraction: f64) -> PyResult<String> {
+        py.enter_polars(|| self.ldf.explain_sampled(fraction))
+    }
+
     fn describe_plan_tree(&self, py: Python) -> PyResult<String> {
         py.enter_polars(|| self.ldf.describe_plan_tree())
     }
@@ -503,6 +563,48 @@ impl PyLazyFrame {
         py.enter_polars(|| self.ldf.describe_optimized_plan_tree())
     }
 
+    #[cfg(feature = "cse")]
+    fn fingerprint(&self, py: Python, optimized: bool) -> PyResult<u64> {
+        py.enter_polars(|| self.ldf.fingerprint(optimized))
+    }
+
+    #[cfg(feature = "cse")]
+    fn exact_fingerprint(&self, py: Python, optimized: bool) -> PyResult<Option<u64>> {
+        py.enter_polars(|| self.ldf.exact_fingerprint(optimized))
+    }
+
+    #[cfg(feature = "cse")]
+    fn diff_plan(&self, py: Python, other: &Self, optimized: bool) -> PyResult<Option<String>> {
+        py.enter_polars(|| self.ldf.diff_plan(&other.ldf, optimized))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn column_lineage(
+        &self,
+        py: Python,
+    ) -> PyResult<Vec<(String, Vec<(String, String)>, Vec<String>)>> {
+        let lineage = py.enter_polars(|| self.ldf.column_lineage())?;
+        Ok(lineage
+            .into_iter()
+            .map(|(name, lineage)| {
+                let sources = lineage
+                    .sources
+                    .into_iter()
+                    .map(|s| (s.source.into_string(), s.column.into_string()))
+                    .collect();
+                (
+                    name.into_string(),
+                    sources,
+                    lineage.expressions.into_iter().collect(),
+                )
+            })
+            .collect())
+    }
+
+    fn pruned_files(&self, py: Python) -> PyResult<PyDataFrame> {
+        py.enter_polars_df(|| self.ldf.clone().pruned_files())
+    }
+
     fn to_dot(&self, py: Python<'_>, optimized: bool) -> PyResult<String> {
         py.enter_polars(|| self.ldf.to_dot(optimized))
     }
@@ -627,6 +729,11 @@ impl PyLazyFrame {
         ldf.cache().into()
     }
 
+    fn cache_hint(&self, hint: Wrap<CacheHint>) -> Self {
+        let ldf = self.ldf.clone();
+        ldf.cache_hint(hint.0).into()
+    }
+
     #[pyo3(signature = (optflags))]
     fn with_optimizations(&self, optflags: PyOptFlags) -> Self {
         let ldf = self.ldf.clone();
@@ -652,15 +759,17 @@ impl PyLazyFrame {
         Ok((df.into(), time_df.into()))
     }
 
-    #[pyo3(signature = (engine, lambda_post_opt=None))]
+    #[pyo3(signature = (engine, lambda_post_opt=None, **overrides))]
     fn collect(
         &self,
         py: Python<'_>,
         engine: Wrap<Engine>,
         lambda_post_opt: Option<PyObject>,
+        overrides: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<PyDataFrame> {
+        let overrides = config_overrides(overrides)?;
         py.enter_polars_df(|| {
-            let ldf = self.ldf.clone();
+            let ldf = self.ldf.clone().with_config(overrides);
             if let Some(lambda) = lambda_post_opt {
                 ldf._collect_post_opt(|root, lp_arena, expr_arena, _| {
                     post_opt_callback(&lambda, root, lp_arena, expr_arena, None)
@@ -671,15 +780,85 @@ impl PyLazyFrame {
         })
     }
 
-    #[pyo3(signature = (engine, lambda))]
+    fn peek(&self, py: Python<'_>, n: IdxSize) -> PyResult<PyDataFrame> {
+        py.enter_polars_df(|| self.ldf.clone().peek(n))
+    }
+
+    #[pyo3(signature = (engine, params, **overrides))]
+    fn collect_with_params(
+        &self,
+        py: Python<'_>,
+        engine: Wrap<Engine>,
+        params: Vec<(String, Wrap<AnyValue<'static>>)>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<PyDataFrame> {
+        let overrides = config_overrides(overrides)?;
+        let params = params
+            .into_iter()
+            .map(|(name, Wrap(value))| (name.into(), Scalar::new(value.dtype(), value)))
+            .collect::<PlHashMap<_, _>>();
+        py.enter_polars_df(|| {
+            let ldf = self.ldf.clone().with_config(overrides);
+            ldf.collect_with_params(engine.0, &params)
+        })
+    }
+
+    #[pyo3(signature = (watch, n_rows, **overrides))]
+    fn collect_with_data_watch(
+        &self,
+        py: Python<'_>,
+        watch: Vec<(usize, PyObject)>,
+        n_rows: usize,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<PyDataFrame> {
+        let overrides = config_overrides(overrides)?;
+        let watch = watch
+            .into_iter()
+            .map(|(id, target)| {
+                #[cfg(feature = "ipc")]
+                if let Ok(path) = target.extract::<PyBackedStr>(py) {
+                    let path = std::path::PathBuf::from(&*path);
+                    return Ok((id, DataWatchTarget::Ipc(path)));
+                }
+                if !target.bind(py).is_callable() {
+                    return Err(PyTypeError::new_err(format!(
+                        "expected a path or a callable to watch node {id}"
+                    )));
+                }
+                let function = move |df: DataFrame| {
+                    Python::with_gil(|py| {
+                        target
+                            .call1(py, (PyDataFrame::new(df),))
+                            .map(|_| ())
+                            .map_err(
+                                |e| polars_err!(ComputeError: "data watch callback failed: {}", e),
+                            )
+                    })
+                };
+                let target = DataWatchTarget::Callback {
+                    n_rows,
+                    function: Arc::new(function),
+                };
+                Ok((id, target))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        py.enter_polars_df(|| {
+            let ldf = self.ldf.clone().with_config(overrides);
+            ldf.collect_with_data_watch(watch)
+        })
+    }
+
+    #[pyo3(signature = (engine, lambda, **overrides))]
     fn collect_with_callback(
         &self,
         py: Python<'_>,
         engine: Wrap<Engine>,
         lambda: PyObject,
+        overrides: Option<&Bound<'_, PyDict>>,
     ) -> PyResult<()> {
+        let overrides = config_overrides(overrides)?;
         py.enter_polars_ok(|| {
-            let ldf = self.ldf.clone();
+            let ldf = self.ldf.clone().with_config(overrides);
 
             polars_core::POOL.spawn(move || {
                 let result = ldf
@@ -706,6 +885,7 @@ impl PyLazyFrame {
     #[pyo3(signature = (
         target, compression, compression_level, statistics, row_group_size, data_page_size,
         cloud_options, credential_provider, retries, sink_options, metadata, field_overwrites,
+        row_group_bytes=None, split_row_groups_on=None, split_row_groups_every=None,
     ))]
     fn sink_parquet(
         &self,
@@ -722,13 +902,22 @@ impl PyLazyFrame {
         sink_options: Wrap<SinkOptions>,
         metadata: Wrap<Option<KeyValueMetadata>>,
         field_overwrites: Vec<Wrap<ParquetFieldOverwrites>>,
+        row_group_bytes: Option<usize>,
+        split_row_groups_on: Option<String>,
+        split_row_groups_every: Option<i64>,
     ) -> PyResult<PyLazyFrame> {
         let compression = parse_parquet_compression(compression, compression_level)?;
+        let row_group_split = split_row_groups_on.map(|column| RowGroupSplit {
+            column: column.into(),
+            every: split_row_groups_every,
+        });
 
         let options = ParquetWriteOptions {
             compression,
             statistics: statistics.0,
             row_group_size,
+            row_group_bytes,
+            row_group_split,
             data_page_size,
             key_value_metadata: metadata.0,
             field_overwrites: field_overwrites.into_iter().map(|f| f.0).collect(),
@@ -990,26 +1179,32 @@ impl PyLazyFrame {
         ldf.remove(predicate.inner).into()
     }
 
-    fn select(&mut self, exprs: Vec<PyExpr>) -> Self {
+    fn select(&mut self, py: Python, exprs: Vec<PyExpr>) -> PyResult<Self> {
         let ldf = self.ldf.clone();
         let exprs = exprs.to_exprs();
-        ldf.select(exprs).into()
+        py.enter_polars_ok(|| ldf.select(exprs).into())
     }
 
-    fn select_seq(&mut self, exprs: Vec<PyExpr>) -> Self {
+    fn select_seq(&mut self, py: Python, exprs: Vec<PyExpr>) -> PyResult<Self> {
         let ldf = self.ldf.clone();
         let exprs = exprs.to_exprs();
-        ldf.select_seq(exprs).into()
+        py.enter_polars_ok(|| ldf.select_seq(exprs).into())
     }
 
-    fn group_by(&mut self, by: Vec<PyExpr>, maintain_order: bool) -> PyLazyGroupBy {
+    fn group_by(
+        &mut self,
+        by: Vec<PyExpr>,
+        maintain_order: bool,
+        expand_categories: bool,
+    ) -> PyLazyGroupBy {
         let ldf = self.ldf.clone();
         let by = by.to_exprs();
         let lazy_gb = if maintain_order {
             ldf.group_by_stable(by)
         } else {
             ldf.group_by(by)
-        };
+        }
+        .expand_categories(expand_categories);
 
         PyLazyGroupBy { lgb: Some(lazy_gb) }
     }
@@ -1053,6 +1248,7 @@ impl PyLazyFrame {
         closed: Wrap<ClosedWindow>,
         group_by: Vec<PyExpr>,
         start_by: Wrap<StartBy>,
+        calendar: Option<Wrap<TradingCalendar>>,
     ) -> PyResult<PyLazyGroupBy> {
         let closed_window = closed.0;
         let group_by = group_by
@@ -1071,6 +1267,7 @@ impl PyLazyFrame {
                 include_boundaries,
                 closed_window,
                 start_by: start_by.0,
+                calendar: calendar.map(|c| c.0),
                 ..Default::default()
             },
         );
@@ -1083,6 +1280,10 @@ impl PyLazyFrame {
         self.ldf.clone().with_context(contexts).into()
     }
 
+    fn into_scalar_expr(&self) -> PyExpr {
+        self.ldf.clone().into_scalar_expr().into()
+    }
+
     #[cfg(feature = "asof_join")]
     #[pyo3(signature = (other, left_on, right_on, left_by, right_by, allow_parallel, force_parallel, suffix, strategy, tolerance, tolerance_str, coalesce, allow_eq, check_sortedness))]
     fn join_asof(
@@ -1137,7 +1338,7 @@ impl PyLazyFrame {
             .into())
     }
 
-    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None))]
+    #[pyo3(signature = (other, left_on, right_on, allow_parallel, force_parallel, nulls_equal, how, suffix, validate, maintain_order, coalesce=None, indicator=None, nans_equal=None))]
     fn join(
         &self,
         other: Self,
@@ -1151,6 +1352,8 @@ impl PyLazyFrame {
         validate: Wrap<JoinValidation>,
         maintain_order: Wrap<MaintainOrderJoin>,
         coalesce: Option<bool>,
+        indicator: Option<String>,
+        nans_equal: Option<bool>,
     ) -> PyResult<Self> {
         let coalesce = match coalesce {
             None => JoinCoalesce::JoinSpecific,
@@ -1168,7 +1371,7 @@ impl PyLazyFrame {
             .map(|pyexpr| pyexpr.inner)
             .collect::<Vec<_>>();
 
-        Ok(ldf
+        let builder = ldf
             .join_builder()
             .with(other)
             .left_on(left_on)
@@ -1176,16 +1379,26 @@ impl PyLazyFrame {
             .allow_parallel(allow_parallel)
             .force_parallel(force_parallel)
             .join_nulls(nulls_equal)
+            .join_nans(nans_equal)
             .how(how.0)
             .suffix(suffix)
             .validate(validate.0)
             .coalesce(coalesce)
-            .maintain_order(maintain_order.0)
-            .finish()
-            .into())
+            .maintain_order(maintain_order.0);
+        let builder = match indicator {
+            Some(name) => builder.indicator(name),
+            None => builder,
+        };
+        Ok(builder.finish().into())
     }
 
-    fn join_where(&self, other: Self, predicates: Vec<PyExpr>, suffix: String) -> PyResult<Self> {
+    fn join_where(
+        &self,
+        other: Self,
+        predicates: Vec<PyExpr>,
+        suffix: String,
+        maintain_order: Wrap<MaintainOrderJoin>,
+    ) -> PyResult<Self> {
         let ldf = self.ldf.clone();
         let other = other.ldf;
 
@@ -1195,18 +1408,55 @@ impl PyLazyFrame {
             .join_builder()
             .with(other)
             .suffix(suffix)
+            .maintain_order(maintain_order.0)
             .join_where(predicates)
             .into())
     }
 
-    fn with_columns(&mut self, exprs: Vec<PyExpr>) -> Self {
+    #[cfg(feature = "iejoin")]
+    fn join_intervals(
+        &self,
+        other: Self,
+        left_on: [PyExpr; 2],
+        right_on: [PyExpr; 2],
+        mode: Wrap<IntervalJoinMode>,
+        min_overlap: Option<PyExpr>,
+        suffix: String,
+    ) -> Self {
+        let options = IntervalJoinOptions {
+            mode: mode.0,
+            min_overlap: min_overlap.map(|e| e.inner),
+            suffix: Some(suffix.into()),
+        };
+        self.ldf
+            .clone()
+            .join_intervals(
+                other.ldf,
+                left_on.map(|e| e.inner),
+                right_on.map(|e| e.inner),
+                options,
+            )
+            .into()
+    }
+
+    fn with_columns(&mut self, py: Python, exprs: Vec<PyExpr>) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let exprs = exprs.to_exprs();
+        py.enter_polars_ok(|| ldf.with_columns(exprs).into())
+    }
+
+    fn with_columns_seq(&mut self, py: Python, exprs: Vec<PyExpr>) -> PyResult<Self> {
         let ldf = self.ldf.clone();
-        ldf.with_columns(exprs.to_exprs()).into()
+        let exprs = exprs.to_exprs();
+        py.enter_polars_ok(|| ldf.with_columns_seq(exprs).into())
     }
 
-    fn with_columns_seq(&mut self, exprs: Vec<PyExpr>) -> Self {
+    fn update_where(&self, mask: PyExpr, assignments: Vec<PyExpr>) -> PyResult<Self> {
         let ldf = self.ldf.clone();
-        ldf.with_columns_seq(exprs.to_exprs()).into()
+        let ldf = ldf
+            .update_where(mask.inner, assignments.to_exprs())
+            .map_err(PyPolarsErr::from)?;
+        Ok(ldf.into())
     }
 
     fn match_to_schema<'py>(
@@ -1218,6 +1468,7 @@ impl PyLazyFrame {
         extra_struct_fields: &Bound<'py, PyAny>,
         integer_cast: &Bound<'py, PyAny>,
         float_cast: &Bound<'py, PyAny>,
+        cast: &Bound<'py, PyAny>,
     ) -> PyResult<Self> {
         fn parse_missing_columns<'py>(
             schema: &Schema,
@@ -1308,11 +1559,32 @@ impl PyLazyFrame {
             Ok(out)
         }
 
+        fn parse_any_cast<'py>(
+            schema: &Schema,
+            cast: &Bound<'py, PyAny>,
+        ) -> PyResult<Vec<CastOrForbid>> {
+            let mut out = Vec::with_capacity(schema.len());
+            if let Ok(policy) = cast.extract::<Wrap<CastOrForbid>>() {
+                out.extend(std::iter::repeat_n(policy.0, schema.len()));
+            } else if let Ok(dict) = cast.downcast::<PyDict>() {
+                out.extend(std::iter::repeat_n(CastOrForbid::Forbid, schema.len()));
+                for (key, value) in dict.iter() {
+                    let key = key.extract::<String>()?;
+                    let value = value.extract::<Wrap<CastOrForbid>>()?;
+                    out[schema.try_index_of(&key).map_err(to_py_err)?] = value.0;
+                }
+            } else {
+                return Err(PyTypeError::new_err("Invalid value for `cast`"));
+            }
+            Ok(out)
+        }
+
         let missing_columns = parse_missing_columns(&schema.0, missing_columns)?;
         let missing_struct_fields = parse_missing_struct_fields(&schema.0, missing_struct_fields)?;
         let extra_struct_fields = parse_extra_struct_fields(&schema.0, extra_struct_fields)?;
         let integer_cast = parse_cast(&schema.0, integer_cast)?;
         let float_cast = parse_cast(&schema.0, float_cast)?;
+        let cast = parse_any_cast(&schema.0, cast)?;
 
         let per_column = (0..schema.0.len())
             .map(|i| MatchToSchemaPerColumn {
@@ -1321,6 +1593,7 @@ impl PyLazyFrame {
                 extra_struct_fields: extra_struct_fields[i],
                 integer_cast: integer_cast[i],
                 float_cast: float_cast[i],
+                cast: cast[i],
             })
             .collect();
 
@@ -1403,8 +1676,14 @@ impl PyLazyFrame {
         out.into()
     }
 
-    fn explode(&self, subset: PySelector) -> Self {
-        self.ldf.clone().explode(subset.inner).into()
+    #[pyo3(signature = (subset, keep_index_as=None))]
+    fn explode(&self, subset: PySelector, keep_index_as: Option<&str>) -> Self {
+        let ldf = self.ldf.clone();
+        match keep_index_as {
+            Some(name) => ldf.explode_with_index(subset.inner, name.into()),
+            None => ldf.explode(subset.inner),
+        }
+        .into()
     }
 
     fn null_count(&self) -> Self {
@@ -1428,6 +1707,10 @@ impl PyLazyFrame {
         .into()
     }
 
+    fn n_unique(&self, subset: Option<PySelector>) -> Self {
+        self.ldf.clone().n_unique(subset.map(|e| e.inner)).into()
+    }
+
     fn drop_nans(&self, subset: Option<PySelector>) -> Self {
         self.ldf.clone().drop_nans(subset.map(|e| e.inner)).into()
     }
@@ -1467,13 +1750,53 @@ impl PyLazyFrame {
         ldf.unpivot(args).into()
     }
 
+    #[cfg(feature = "pivot")]
+    #[pyo3(signature = (on, index, values, aggregate_expr, maintain_order, separator))]
+    fn pivot_enum(
+        &self,
+        on: String,
+        index: Option<Vec<String>>,
+        values: Option<Vec<String>>,
+        aggregate_expr: PyExpr,
+        maintain_order: bool,
+        separator: Option<&str>,
+    ) -> PyResult<Self> {
+        let to_names = |names: Vec<String>| names.into_iter().map(|s| s.into()).collect();
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .pivot_enum(
+                on.into(),
+                index.map(to_names),
+                values.map(to_names),
+                aggregate_expr.inner,
+                maintain_order,
+                separator,
+            )
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
     #[pyo3(signature = (name, offset=None))]
     fn with_row_index(&self, name: &str, offset: Option<IdxSize>) -> Self {
         let ldf = self.ldf.clone();
         ldf.with_row_index(name, offset).into()
     }
 
-    #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output))]
+    fn with_row_index_stepped(
+        &self,
+        name: &str,
+        start: i64,
+        step: i64,
+        dtype: Wrap<DataType>,
+    ) -> PyResult<Self> {
+        let ldf = self.ldf.clone();
+        let out = ldf
+            .with_row_index_stepped(name, start, step, dtype.0)
+            .map_err(PyPolarsErr::from)?;
+        Ok(out.into())
+    }
+
+    #[pyo3(signature = (lambda, predicate_pushdown, projection_pushdown, slice_pushdown, streamable, schema, validate_output, scan_level=false))]
     fn map_batches(
         &self,
         lambda: PyObject,
@@ -1483,14 +1806,30 @@ impl PyLazyFrame {
         streamable: bool,
         schema: Option<Wrap<Schema>>,
         validate_output: bool,
-    ) -> Self {
+        scan_level: bool,
+    ) -> PyResult<Self> {
+        // A scan-level function is attached directly to the scan, so that it runs on the morsels
+        // of the scan inside the streaming pipeline.
+        if scan_level
+            && !matches!(
+                self.ldf.logical_plan,
+                DslPlan::Scan { .. } | DslPlan::PythonScan { .. }
+            )
+        {
+            return Err(PyPolarsErr::from(polars_err!(
+                InvalidOperation: "`map_batches` with `scan_level=True` must be applied directly to a scan"
+            ))
+            .into());
+        }
+
         let mut opt = OptFlags::default();
         opt.set(OptFlags::PREDICATE_PUSHDOWN, predicate_pushdown);
         opt.set(OptFlags::PROJECTION_PUSHDOWN, projection_pushdown);
         opt.set(OptFlags::SLICE_PUSHDOWN, slice_pushdown);
-        opt.set(OptFlags::NEW_STREAMING, streamable);
+        opt.set(OptFlags::NEW_STREAMING, streamable || scan_level);
 
-        self.ldf
+        Ok(self
+            .ldf
             .clone()
             .map_python(
                 lambda.into(),
@@ -1498,7 +1837,7 @@ impl PyLazyFrame {
                 schema.map(|s| Arc::new(s.0)),
                 validate_output,
             )
-            .into()
+            .into())
     }
 
     fn drop(&self, columns: PySelector) -> Self {
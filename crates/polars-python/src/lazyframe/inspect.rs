@@ -0,0 +1,39 @@
+use polars::prelude::*;
+use pyo3::prelude::*;
+
+use super::PyLazyFrame;
+use crate::expr::ToExprs;
+use crate::utils::EnterPolarsExt;
+use crate::{PyDataFrame, PyExpr};
+
+#[pymethods]
+impl PyLazyFrame {
+    fn inspect_metrics(
+        &self,
+        py: Python,
+        exprs: Vec<PyExpr>,
+    ) -> PyResult<(PyLazyFrame, PyInspectHandle)> {
+        let exprs = exprs.to_exprs();
+        let (ldf, handle) = py.enter_polars(|| self.ldf.clone().inspect(exprs))?;
+        Ok((ldf.into(), PyInspectHandle { handle }))
+    }
+}
+
+#[pyclass]
+#[repr(transparent)]
+#[derive(Clone)]
+pub struct PyInspectHandle {
+    pub handle: InspectHandle,
+}
+
+#[pymethods]
+impl PyInspectHandle {
+    pub fn report(&self, py: Python) -> PyResult<PyDataFrame> {
+        let out = py.enter_polars(|| self.handle.report())?;
+        Ok(out.into())
+    }
+
+    pub fn reset(&self, py: Python) -> PyResult<()> {
+        py.enter_polars_ok(|| self.handle.reset())
+    }
+}
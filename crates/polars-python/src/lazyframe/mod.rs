@@ -1,6 +1,8 @@
 mod exitable;
+mod feature_set;
 #[cfg(feature = "pymethods")]
 mod general;
+mod inspect;
 mod optflags;
 #[cfg(feature = "pymethods")]
 mod serde;
@@ -10,6 +12,8 @@ pub mod visitor;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::PyInProcessQuery;
+pub use feature_set::PyFeatureSet;
+pub use inspect::PyInspectHandle;
 use polars::prelude::{Engine, LazyFrame, OptFlags};
 use pyo3::exceptions::PyValueError;
 use pyo3::pybacked::PyBackedStr;
@@ -56,6 +56,8 @@ flag_getter_setters! {
     (COMM_SUBPLAN_ELIM, get_comm_subplan_elim, set_comm_subplan_elim, clear=true)
     (COMM_SUBEXPR_ELIM, get_comm_subexpr_elim, set_comm_subexpr_elim, clear=true)
     (COLLAPSE_JOINS, get_collapse_joins, set_collapse_joins, clear=true)
+    (LOOKUP_JOINS, get_lookup_joins, set_lookup_joins, clear=true)
+    (FAST_STATISTICS, get_fast_statistics, set_fast_statistics, clear=true)
     (CHECK_ORDER_OBSERVE, get_check_order_observe, set_check_order_observe, clear=true)
     (FAST_PROJECTION, get_fast_projection, set_fast_projection, clear=true)
 
@@ -35,18 +35,39 @@ impl PyLazyFrame {
 
     /// Deserialize a file-like object containing binary data into a LazyFrame.
     #[staticmethod]
-    fn deserialize_binary(py: Python<'_>, py_f: PyObject) -> PyResult<Self> {
+    #[pyo3(signature = (
+        py_f, allow_python_objects=true, allow_user_functions=true, allowed_paths=None
+    ))]
+    fn deserialize_binary(
+        py: Python<'_>,
+        py_f: PyObject,
+        allow_python_objects: bool,
+        allow_user_functions: bool,
+        allowed_paths: Option<Vec<Wrap<PlPath>>>,
+    ) -> PyResult<Self> {
         let file = get_file_like(py_f, false)?;
         let reader = BufReader::new(file);
 
-        let lp: DslPlan = py.enter_polars(|| DslPlan::deserialize_versioned(reader))?;
+        let ctx =
+            deserialization_context(allow_python_objects, allow_user_functions, allowed_paths);
+        let lp: DslPlan =
+            py.enter_polars(|| DslPlan::deserialize_versioned_with_context(reader, &ctx))?;
         Ok(LazyFrame::from(lp).into())
     }
 
     /// Deserialize a file-like object containing JSON string data into a LazyFrame.
     #[staticmethod]
     #[cfg(feature = "json")]
-    fn deserialize_json(py: Python<'_>, py_f: PyObject) -> PyResult<Self> {
+    #[pyo3(signature = (
+        py_f, allow_python_objects=true, allow_user_functions=true, allowed_paths=None
+    ))]
+    fn deserialize_json(
+        py: Python<'_>,
+        py_f: PyObject,
+        allow_python_objects: bool,
+        allow_user_functions: bool,
+        allowed_paths: Option<Vec<Wrap<PlPath>>>,
+    ) -> PyResult<Self> {
         // it is faster to first read to memory and then parse: https://github.com/serde-rs/json/issues/160
         // so don't bother with files.
         let mut json = String::new();
@@ -62,10 +83,26 @@ impl PyLazyFrame {
         // in this scope.
         let json = unsafe { std::mem::transmute::<&'_ str, &'static str>(json.as_str()) };
 
+        let ctx =
+            deserialization_context(allow_python_objects, allow_user_functions, allowed_paths);
         let lp = py.enter_polars(|| {
-            serde_json::from_str::<DslPlan>(json)
-                .map_err(|err| ComputeError::new_err(err.to_string()))
+            ctx.deserialize_with(|| {
+                serde_json::from_str::<DslPlan>(json)
+                    .map_err(|err| polars_err!(ComputeError: "{err}"))
+            })
         })?;
         Ok(LazyFrame::from(lp).into())
     }
 }
+
+fn deserialization_context(
+    allow_python_objects: bool,
+    allow_user_functions: bool,
+    allowed_paths: Option<Vec<Wrap<PlPath>>>,
+) -> PlanDeserializationContext {
+    PlanDeserializationContext {
+        allow_python_objects,
+        allow_user_functions,
+        allowed_paths: allowed_paths.map(|paths| paths.into_iter().map(|p| p.0).collect()),
+    }
+}
@@ -218,9 +218,9 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
-        if parsed.len() != 3 {
+        if parsed.len() != 4 {
             return Err(PyValueError::new_err(
-                "`sink_options` must be a dictionary with the exactly 3 field.",
+                "`sink_options` must be a dictionary with the exactly 4 field.",
             ));
         }
 
@@ -240,10 +240,17 @@ impl<'py> FromPyObject<'py> for Wrap<SinkOptions> {
             .ok_or_else(|| PyValueError::new_err("`sink_options` must contain `mkdir` field"))?;
         let mkdir = mkdir.extract::<bool>()?;
 
+        let staged_commit =
+            PyDictMethods::get_item(&parsed, "staged_commit")?.ok_or_else(|| {
+                PyValueError::new_err("`sink_options` must contain `staged_commit` field")
+            })?;
+        let staged_commit = staged_commit.extract::<bool>()?;
+
         Ok(Wrap(SinkOptions {
             sync_on_close,
             maintain_order,
             mkdir,
+            staged_commit,
         }))
     }
 }
@@ -2,10 +2,11 @@ use std::sync::{Arc, Mutex};
 
 use polars::prelude::PolarsError;
 use polars::prelude::python_dsl::PythonScanSource;
-use polars_plan::plans::{Context, ExprToIRContext, IR, to_expr_ir};
+use polars_plan::plans::{ArenaExprIter, Context, ExprToIRContext, IR, to_expr_ir};
 use polars_plan::prelude::expr_ir::ExprIR;
 use polars_plan::prelude::{AExpr, PythonOptions};
 use polars_utils::arena::{Arena, Node};
+use pyo3::exceptions::PyNotImplementedError;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 
@@ -43,6 +44,9 @@ impl From<&ExprIR> for PyExprIR {
 
 type Version = (u16, u16);
 
+/// `(path, kind, plan node name, message)`, see `NodeTraverser::unsupported_operations`.
+type UnsupportedOperation = (Vec<usize>, &'static str, &'static str, String);
+
 #[pyclass]
 pub struct NodeTraverser {
     root: Node,
@@ -58,7 +62,7 @@ impl NodeTraverser {
     // Increment major on breaking changes to the IR (e.g. renaming
     // fields, reordering tuples), minor on backwards compatible
     // changes (e.g. exposing a new expression node).
-    const VERSION: Version = (9, 0);
+    const VERSION: Version = (9, 1);
 
     pub fn new(root: Node, lp_arena: Arena<IR>, expr_arena: Arena<AExpr>) -> Self {
         Self {
@@ -188,6 +192,55 @@ impl NodeTraverser {
         expr_nodes::into_py(py, expr)
     }
 
+    /// Get the plan nodes and expressions below the current node that cannot be viewed.
+    ///
+    /// Every operation is a `(path, kind, name, message)` tuple. The `path` holds the input
+    /// indices that lead from the current node to the plan node, `kind` is `"node"` or
+    /// `"expression"`, `name` is the name of the plan node and `message` describes the
+    /// operation.
+    fn unsupported_operations(&self, py: Python<'_>) -> PyResult<Vec<UnsupportedOperation>> {
+        let lp_arena = self.lp_arena.lock().unwrap();
+        let expr_arena = self.expr_arena.lock().unwrap();
+
+        let not_implemented = |result: PyResult<PyObject>| match result {
+            Err(err) if err.is_instance_of::<PyNotImplementedError>(py) => {
+                Ok(Some(err.value(py).to_string()))
+            },
+            Err(err) => Err(err),
+            Ok(_) => Ok(None),
+        };
+
+        let mut out = vec![];
+        let mut stack = vec![(self.root, vec![])];
+        let mut inputs = vec![];
+        let mut exprs = vec![];
+        while let Some((node, path)) = stack.pop() {
+            let ir = lp_arena.get(node);
+            if let Some(message) = not_implemented(nodes::into_py(py, ir))? {
+                out.push((path.clone(), "node", ir.name(), message));
+            }
+
+            exprs.clear();
+            ir.copy_exprs(&mut exprs);
+            for e in &exprs {
+                for (_, ae) in expr_arena.iter(e.node()) {
+                    if let Some(message) = not_implemented(expr_nodes::into_py(py, ae))? {
+                        out.push((path.clone(), "expression", ir.name(), message));
+                    }
+                }
+            }
+
+            inputs.clear();
+            ir.copy_inputs(&mut inputs);
+            for (i, input) in inputs.iter().enumerate().rev() {
+                let mut path = path.clone();
+                path.push(i);
+                stack.push((*input, path));
+            }
+        }
+        Ok(out)
+    }
+
     /// Add some expressions to the arena and return their new node ids as well
     /// as the total number of nodes in the arena.
     fn add_expressions(&mut self, expressions: Vec<PyExpr>) -> PyResult<(Vec<usize>, usize)> {
@@ -250,6 +250,7 @@ pub enum PyTemporalFunction {
     CastTimeUnit,
     WithTimeUnit,
     ConvertTimeZone,
+    ConvertTimeZonePerRow,
     TimeStamp,
     Truncate,
     OffsetBy,
@@ -535,6 +536,11 @@ impl PyGroupbyOptions {
         self.inner.slice
     }
 
+    #[getter]
+    fn expand_categories(&self) -> bool {
+        self.inner.expand_categories
+    }
+
     #[getter]
     fn dynamic(&self) -> Option<PyDynamicGroupOptions> {
         self.inner
@@ -762,6 +768,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::Bitwise(_) => {
                     return Err(PyNotImplementedError::new_err("bitwise expr"));
                 },
+                IRFunctionExpr::Url(_) => {
+                    return Err(PyNotImplementedError::new_err("url expr"));
+                },
+                IRFunctionExpr::Anonymize(_) => {
+                    return Err(PyNotImplementedError::new_err("anon expr"));
+                },
                 IRFunctionExpr::StringExpr(strfun) => match strfun {
                     IRStringFunction::ConcatHorizontal {
                         delimiter,
@@ -870,7 +882,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::StripSuffix => {
                         (PyStringFunction::StripSuffix,).into_py_any(py)
                     },
-                    IRStringFunction::SplitExact { n, inclusive } => {
+                    IRStringFunction::SplitExact { rest: true, .. } => {
+                        return Err(PyNotImplementedError::new_err("split_exact with rest"));
+                    },
+                    IRStringFunction::SplitExact { n, inclusive, .. } => {
                         (PyStringFunction::SplitExact, n, inclusive).into_py_any(py)
                     },
                     IRStringFunction::SplitN(n) => (PyStringFunction::SplitN, n).into_py_any(py),
@@ -908,6 +923,16 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRStringFunction::FindMany { .. } => {
                         return Err(PyNotImplementedError::new_err("find_many"));
                     },
+                    IRStringFunction::ParseCommonLog => {
+                        return Err(PyNotImplementedError::new_err("parse_common_log"));
+                    },
+                    IRStringFunction::ParseUserAgent => {
+                        return Err(PyNotImplementedError::new_err("parse_user_agent"));
+                    },
+                    IRStringFunction::PadStartDisplay { .. }
+                    | IRStringFunction::PadEndDisplay { .. } => {
+                        return Err(PyNotImplementedError::new_err("pad_display"));
+                    },
                     #[cfg(feature = "regex")]
                     IRStringFunction::EscapeRegex => {
                         (PyStringFunction::EscapeRegex,).into_py_any(py)
@@ -1005,6 +1030,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     IRTemporalFunction::ConvertTimeZone(time_zone) => {
                         (PyTemporalFunction::ConvertTimeZone, time_zone.as_str()).into_py_any(py)
                     },
+                    #[cfg(feature = "timezones")]
+                    IRTemporalFunction::ConvertTimeZonePerRow => {
+                        (PyTemporalFunction::ConvertTimeZonePerRow,).into_py_any(py)
+                    },
                     IRTemporalFunction::TimeStamp(time_unit) => {
                         (PyTemporalFunction::TimeStamp, Wrap(*time_unit)).into_py_any(py)
                     },
@@ -1179,6 +1208,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::ShiftAndFill => ("shift_and_fill",).into_py_any(py),
                 IRFunctionExpr::Shift => ("shift",).into_py_any(py),
                 IRFunctionExpr::DropNans => ("drop_nans",).into_py_any(py),
+                IRFunctionExpr::NanToNull => ("nan_to_null",).into_py_any(py),
                 IRFunctionExpr::DropNulls => ("drop_nulls",).into_py_any(py),
                 IRFunctionExpr::Mode => ("mode",).into_py_any(py),
                 IRFunctionExpr::Skew(bias) => ("skew", bias).into_py_any(py),
@@ -1251,6 +1281,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 )
                     .into_py_any(py),
                 IRFunctionExpr::InterpolateBy => ("interpolate_by",).into_py_any(py),
+                IRFunctionExpr::DownsampleLttb { n_out } => {
+                    ("downsample_lttb", n_out).into_py_any(py)
+                },
+                IRFunctionExpr::DownsampleMinMax { n_buckets } => {
+                    ("downsample_minmax", n_buckets).into_py_any(py)
+                },
                 IRFunctionExpr::Entropy { base, normalize } => {
                     ("entropy", base, normalize).into_py_any(py)
                 },
@@ -1264,6 +1300,9 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     ("round", decimals, Into::<&str>::into(mode)).into_py_any(py)
                 },
                 IRFunctionExpr::RoundSF { digits } => ("round_sig_figs", digits).into_py_any(py),
+                IRFunctionExpr::RoundToIncrement { increment, mode } => {
+                    ("round_to_increment", increment, Into::<&str>::into(mode)).into_py_any(py)
+                },
                 IRFunctionExpr::Floor => ("floor",).into_py_any(py),
                 IRFunctionExpr::Ceil => ("ceil",).into_py_any(py),
                 IRFunctionExpr::UpperBound => ("upper_bound",).into_py_any(py),
@@ -1287,10 +1326,29 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 IRFunctionExpr::RLE => ("rle",).into_py_any(py),
                 #[cfg(feature = "rle")]
                 IRFunctionExpr::RLEID => ("rle_id",).into_py_any(py),
+                IRFunctionExpr::ToDummies { .. } => {
+                    return Err(PyNotImplementedError::new_err("to_dummies"));
+                },
+                IRFunctionExpr::CategoricalTargetEncode { .. } => {
+                    return Err(PyNotImplementedError::new_err("categorical_target_encode"));
+                },
+                IRFunctionExpr::FrequencyEncode { .. } => {
+                    return Err(PyNotImplementedError::new_err("frequency_encode"));
+                },
+                IRFunctionExpr::Bootstrap { .. } => {
+                    return Err(PyNotImplementedError::new_err("bootstrap"));
+                },
                 IRFunctionExpr::ToPhysical => ("to_physical",).into_py_any(py),
                 IRFunctionExpr::Random { .. } => {
                     return Err(PyNotImplementedError::new_err("random"));
                 },
+                IRFunctionExpr::Uuid4 => return Err(PyNotImplementedError::new_err("uuid4")),
+                IRFunctionExpr::SnowflakeId { .. } => {
+                    return Err(PyNotImplementedError::new_err("snowflake_id"));
+                },
+                IRFunctionExpr::Placeholder { .. } => {
+                    return Err(PyNotImplementedError::new_err("placeholder"));
+                },
                 IRFunctionExpr::SetSortedFlag(sorted) => (
                     "set_sorted",
                     match sorted {
@@ -1324,6 +1382,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     ("mean_horizontal", ignore_nulls).into_py_any(py)
                 },
                 IRFunctionExpr::MinHorizontal => ("min_horizontal",).into_py_any(py),
+                IRFunctionExpr::QuantileHorizontal { .. } => {
+                    Err(PyNotImplementedError::new_err("quantile_horizontal"))
+                },
+                IRFunctionExpr::StdHorizontal { .. } => {
+                    Err(PyNotImplementedError::new_err("std_horizontal"))
+                },
                 IRFunctionExpr::EwmMean { options: _ } => {
                     return Err(PyNotImplementedError::new_err("ewm mean"));
                 },
@@ -1339,6 +1403,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     ("replace_strict",).into_py_any(py)
                 },
                 IRFunctionExpr::Negate => ("negate",).into_py_any(py),
+                IRFunctionExpr::CheckedArithmetic(op) => {
+                    ("checked_arithmetic", <&str>::from(op)).into_py_any(py)
+                },
+                IRFunctionExpr::DivOr(op) => ("div_or", <&str>::from(op)).into_py_any(py),
                 IRFunctionExpr::FillNullWithStrategy(strategy) => {
                     let (strategy_str, py_limit): (&str, PyObject) = match strategy {
                         FillNullStrategy::Forward(limit) => {
@@ -618,7 +618,16 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
                 )
                     .into_py_any(py)?,
                 FunctionIR::Rechunk => ("rechunk",).into_py_any(py)?,
-                FunctionIR::Explode { columns, schema: _ } => (
+                FunctionIR::CacheHint(hint) => ("cache_hint", hint.to_string()).into_py_any(py)?,
+                FunctionIR::Explode {
+                    index_name: Some(_),
+                    ..
+                } => return Err(PyNotImplementedError::new_err("explode with index")),
+                FunctionIR::Explode {
+                    columns,
+                    index_name: None,
+                    schema: _,
+                } => (
                     "explode",
                     columns.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
                 )
@@ -666,6 +675,12 @@ pub(crate) fn into_py(py: Python<'_>, plan: &IR) -> PyResult<PyObject> {
 
                     ("fast_count", sources, scan_type, alias).into_py_any(py)?
                 },
+                #[cfg(feature = "parquet")]
+                FunctionIR::FastStatistics { .. } => {
+                    return Err(PyNotImplementedError::new_err(
+                        "fast statistics mapfunction",
+                    ));
+                },
             },
         }
         .into_py_any(py),
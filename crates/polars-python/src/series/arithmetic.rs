@@ -23,12 +23,33 @@ impl PySeries {
     }
 }
 
+/// Whether integer arithmetic on `s` has to detect overflow, which is only implemented for
+/// arithmetic between series.
+fn checks_overflow(s: &Series) -> bool {
+    s.dtype().is_integer()
+        && polars_core::config::arithmetic_overflow()
+            != polars_core::config::ArithmeticOverflow::Wrap
+}
+
+fn scalar_series<T>(s: &Series, value: T) -> PolarsResult<Series>
+where
+    Series: NamedFrom<[T; 1], [T]>,
+{
+    Series::new(s.name().clone(), [value]).cast(s.dtype())
+}
+
 macro_rules! impl_arithmetic {
     ($name:ident, $type:ty, $operand:tt) => {
         #[pymethods]
         impl PySeries {
             fn $name(&self, py: Python<'_>, other: $type) -> PyResult<Self> {
-                py.enter_polars_series(|| Ok({&self.series $operand other}))
+                py.enter_polars_series(|| {
+                    if checks_overflow(&self.series) {
+                        let other = scalar_series(&self.series, other)?;
+                        return &self.series $operand &other;
+                    }
+                    Ok({&self.series $operand other})
+                })
             }
         }
     };
@@ -90,63 +111,69 @@ impl_arithmetic!(rem_f32, f32, %);
 impl_arithmetic!(rem_f64, f64, %);
 
 macro_rules! impl_rhs_arithmetic {
-    ($name:ident, $type:ty, $operand:ident) => {
+    ($name:ident, $type:ty, $operand:ident, $trait:ident) => {
         #[pymethods]
         impl PySeries {
             fn $name(&self, py: Python<'_>, other: $type) -> PyResult<Self> {
-                py.enter_polars_series(|| Ok(other.$operand(&self.series)))
+                py.enter_polars_series(|| {
+                    if checks_overflow(&self.series) {
+                        let other = scalar_series(&self.series, other)?;
+                        return std::ops::$trait::$operand(&other, &self.series);
+                    }
+                    Ok(other.$operand(&self.series))
+                })
             }
         }
     };
 }
 
-impl_rhs_arithmetic!(add_u8_rhs, u8, add);
-impl_rhs_arithmetic!(add_u16_rhs, u16, add);
-impl_rhs_arithmetic!(add_u32_rhs, u32, add);
-impl_rhs_arithmetic!(add_u64_rhs, u64, add);
-impl_rhs_arithmetic!(add_i8_rhs, i8, add);
-impl_rhs_arithmetic!(add_i16_rhs, i16, add);
-impl_rhs_arithmetic!(add_i32_rhs, i32, add);
-impl_rhs_arithmetic!(add_i64_rhs, i64, add);
-impl_rhs_arithmetic!(add_f32_rhs, f32, add);
-impl_rhs_arithmetic!(add_f64_rhs, f64, add);
-impl_rhs_arithmetic!(sub_u8_rhs, u8, sub);
-impl_rhs_arithmetic!(sub_u16_rhs, u16, sub);
-impl_rhs_arithmetic!(sub_u32_rhs, u32, sub);
-impl_rhs_arithmetic!(sub_u64_rhs, u64, sub);
-impl_rhs_arithmetic!(sub_i8_rhs, i8, sub);
-impl_rhs_arithmetic!(sub_i16_rhs, i16, sub);
-impl_rhs_arithmetic!(sub_i32_rhs, i32, sub);
-impl_rhs_arithmetic!(sub_i64_rhs, i64, sub);
-impl_rhs_arithmetic!(sub_f32_rhs, f32, sub);
-impl_rhs_arithmetic!(sub_f64_rhs, f64, sub);
-impl_rhs_arithmetic!(div_u8_rhs, u8, div);
-impl_rhs_arithmetic!(div_u16_rhs, u16, div);
-impl_rhs_arithmetic!(div_u32_rhs, u32, div);
-impl_rhs_arithmetic!(div_u64_rhs, u64, div);
-impl_rhs_arithmetic!(div_i8_rhs, i8, div);
-impl_rhs_arithmetic!(div_i16_rhs, i16, div);
-impl_rhs_arithmetic!(div_i32_rhs, i32, div);
-impl_rhs_arithmetic!(div_i64_rhs, i64, div);
-impl_rhs_arithmetic!(div_f32_rhs, f32, div);
-impl_rhs_arithmetic!(div_f64_rhs, f64, div);
-impl_rhs_arithmetic!(mul_u8_rhs, u8, mul);
-impl_rhs_arithmetic!(mul_u16_rhs, u16, mul);
-impl_rhs_arithmetic!(mul_u32_rhs, u32, mul);
-impl_rhs_arithmetic!(mul_u64_rhs, u64, mul);
-impl_rhs_arithmetic!(mul_i8_rhs, i8, mul);
-impl_rhs_arithmetic!(mul_i16_rhs, i16, mul);
-impl_rhs_arithmetic!(mul_i32_rhs, i32, mul);
-impl_rhs_arithmetic!(mul_i64_rhs, i64, mul);
-impl_rhs_arithmetic!(mul_f32_rhs, f32, mul);
-impl_rhs_arithmetic!(mul_f64_rhs, f64, mul);
-impl_rhs_arithmetic!(rem_u8_rhs, u8, rem);
-impl_rhs_arithmetic!(rem_u16_rhs, u16, rem);
-impl_rhs_arithmetic!(rem_u32_rhs, u32, rem);
-impl_rhs_arithmetic!(rem_u64_rhs, u64, rem);
-impl_rhs_arithmetic!(rem_i8_rhs, i8, rem);
-impl_rhs_arithmetic!(rem_i16_rhs, i16, rem);
-impl_rhs_arithmetic!(rem_i32_rhs, i32, rem);
-impl_rhs_arithmetic!(rem_i64_rhs, i64, rem);
-impl_rhs_arithmetic!(rem_f32_rhs, f32, rem);
-impl_rhs_arithmetic!(rem_f64_rhs, f64, rem);
+impl_rhs_arithmetic!(add_u8_rhs, u8, add, Add);
+impl_rhs_arithmetic!(add_u16_rhs, u16, add, Add);
+impl_rhs_arithmetic!(add_u32_rhs, u32, add, Add);
+impl_rhs_arithmetic!(add_u64_rhs, u64, add, Add);
+impl_rhs_arithmetic!(add_i8_rhs, i8, add, Add);
+impl_rhs_arithmetic!(add_i16_rhs, i16, add, Add);
+impl_rhs_arithmetic!(add_i32_rhs, i32, add, Add);
+impl_rhs_arithmetic!(add_i64_rhs, i64, add, Add);
+impl_rhs_arithmetic!(add_f32_rhs, f32, add, Add);
+impl_rhs_arithmetic!(add_f64_rhs, f64, add, Add);
+impl_rhs_arithmetic!(sub_u8_rhs, u8, sub, Sub);
+impl_rhs_arithmetic!(sub_u16_rhs, u16, sub, Sub);
+impl_rhs_arithmetic!(sub_u32_rhs, u32, sub, Sub);
+impl_rhs_arithmetic!(sub_u64_rhs, u64, sub, Sub);
+impl_rhs_arithmetic!(sub_i8_rhs, i8, sub, Sub);
+impl_rhs_arithmetic!(sub_i16_rhs, i16, sub, Sub);
+impl_rhs_arithmetic!(sub_i32_rhs, i32, sub, Sub);
+impl_rhs_arithmetic!(sub_i64_rhs, i64, sub, Sub);
+impl_rhs_arithmetic!(sub_f32_rhs, f32, sub, Sub);
+impl_rhs_arithmetic!(sub_f64_rhs, f64, sub, Sub);
+impl_rhs_arithmetic!(div_u8_rhs, u8, div, Div);
+impl_rhs_arithmetic!(div_u16_rhs, u16, div, Div);
+impl_rhs_arithmetic!(div_u32_rhs, u32, div, Div);
+impl_rhs_arithmetic!(div_u64_rhs, u64, div, Div);
+impl_rhs_arithmetic!(div_i8_rhs, i8, div, Div);
+impl_rhs_arithmetic!(div_i16_rhs, i16, div, Div);
+impl_rhs_arithmetic!(div_i32_rhs, i32, div, Div);
+impl_rhs_arithmetic!(div_i64_rhs, i64, div, Div);
+impl_rhs_arithmetic!(div_f32_rhs, f32, div, Div);
+impl_rhs_arithmetic!(div_f64_rhs, f64, div, Div);
+impl_rhs_arithmetic!(mul_u8_rhs, u8, mul, Mul);
+impl_rhs_arithmetic!(mul_u16_rhs, u16, mul, Mul);
+impl_rhs_arithmetic!(mul_u32_rhs, u32, mul, Mul);
+impl_rhs_arithmetic!(mul_u64_rhs, u64, mul, Mul);
+impl_rhs_arithmetic!(mul_i8_rhs, i8, mul, Mul);
+impl_rhs_arithmetic!(mul_i16_rhs, i16, mul, Mul);
+impl_rhs_arithmetic!(mul_i32_rhs, i32, mul, Mul);
+impl_rhs_arithmetic!(mul_i64_rhs, i64, mul, Mul);
+impl_rhs_arithmetic!(mul_f32_rhs, f32, mul, Mul);
+impl_rhs_arithmetic!(mul_f64_rhs, f64, mul, Mul);
+impl_rhs_arithmetic!(rem_u8_rhs, u8, rem, Rem);
+impl_rhs_arithmetic!(rem_u16_rhs, u16, rem, Rem);
+impl_rhs_arithmetic!(rem_u32_rhs, u32, rem, Rem);
+impl_rhs_arithmetic!(rem_u64_rhs, u64, rem, Rem);
+impl_rhs_arithmetic!(rem_i8_rhs, i8, rem, Rem);
+impl_rhs_arithmetic!(rem_i16_rhs, i16, rem, Rem);
+impl_rhs_arithmetic!(rem_i32_rhs, i32, rem, Rem);
+impl_rhs_arithmetic!(rem_i64_rhs, i64, rem, Rem);
+impl_rhs_arithmetic!(rem_f32_rhs, f32, rem, Rem);
+impl_rhs_arithmetic!(rem_f64_rhs, f64, rem, Rem);
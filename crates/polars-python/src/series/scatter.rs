@@ -3,6 +3,7 @@ use polars::prelude::*;
 use pyo3::prelude::*;
 
 use super::PySeries;
+use crate::conversion::Wrap;
 use crate::utils::EnterPolarsExt;
 
 #[pymethods]
@@ -26,6 +27,88 @@ impl PySeries {
             }
         })
     }
+
+    fn scatter_batches(
+        &mut self,
+        py: Python<'_>,
+        batches: Vec<(PySeries, PySeries)>,
+        on_conflict: Wrap<ScatterConflict>,
+    ) -> PyResult<()> {
+        let s = std::mem::take(&mut self.series);
+        py.enter_polars(|| {
+            let result = scatter_batches(s, batches, on_conflict.0);
+            match result {
+                Ok(out) => {
+                    self.series = out;
+                    Ok(())
+                },
+                Err((s, e)) => {
+                    // Restore original series:
+                    self.series = s;
+                    Err(e)
+                },
+            }
+        })
+    }
+}
+
+/// Combine the batches into a single scatter, so that the data is only made mutable once.
+fn scatter_batches(
+    s: Series,
+    batches: Vec<(PySeries, PySeries)>,
+    conflict: ScatterConflict,
+) -> Result<Series, (Series, PolarsError)> {
+    let mut all_idx = IdxCa::from_vec(s.name().clone(), vec![]);
+    let mut all_values: Option<Series> = None;
+    for (idx, values) in batches {
+        let result = (|| -> PolarsResult<()> {
+            let idx = polars_ops::prelude::convert_to_unsigned_index(&idx.series, s.len())?;
+            let mut values = values.series.strict_cast(s.dtype())?;
+            // Broadcast values input
+            if values.len() == 1 && idx.len() > 1 {
+                values = values.new_from_index(0, idx.len());
+            }
+            polars_ensure!(
+                values.len() == idx.len(),
+                ShapeMismatch: "a scatter batch has {} indices but {} values",
+                idx.len(), values.len()
+            );
+            all_idx.append(&idx)?;
+            match &mut all_values {
+                Some(all_values) => {
+                    all_values.append(&values)?;
+                },
+                None => all_values = Some(values),
+            }
+            Ok(())
+        })();
+        if let Err(err) = result {
+            return Err((s, err));
+        }
+    }
+    let Some(mut values) = all_values else {
+        return Ok(s);
+    };
+
+    if all_idx.null_count() > 0 {
+        return Err((
+            s,
+            PolarsError::ComputeError("index values should not be null".into()),
+        ));
+    }
+    let all_idx = all_idx.rechunk();
+    let idx = match dedup_scatter_idx(all_idx.cont_slice().unwrap(), conflict) {
+        Ok(None) => all_idx.into_owned(),
+        Ok(Some((idx, positions))) => {
+            values = match values.take_slice(&positions) {
+                Ok(values) => values,
+                Err(err) => return Err((s, err)),
+            };
+            IdxCa::from_vec(s.name().clone(), idx)
+        },
+        Err(err) => return Err((s, err)),
+    };
+    scatter(s, &idx.into_series(), &values)
 }
 
 fn scatter(mut s: Series, idx: &Series, values: &Series) -> Result<Series, (Series, PolarsError)> {
@@ -112,6 +195,11 @@ fn scatter_impl(
             let values = values.i64()?;
             ca.scatter(idx, values)
         },
+        DataType::Int128 => {
+            let ca: &mut ChunkedArray<Int128Type> = mutable_s.as_mut();
+            let values = values.i128()?;
+            ca.scatter(idx, values)
+        },
         DataType::UInt8 => {
             let ca: &mut ChunkedArray<UInt8Type> = mutable_s.as_mut();
             let values = values.u8()?;
@@ -1,8 +1,8 @@
-use polars::sql::SQLContext;
+use polars::sql::{SQLContext, TablePolicy};
 use pyo3::prelude::*;
 
-use crate::PyLazyFrame;
 use crate::error::PyPolarsErr;
+use crate::{PyExpr, PyLazyFrame};
 
 #[pyclass(unsendable)]
 #[repr(transparent)]
@@ -45,4 +45,43 @@ impl PySQLContext {
     pub fn unregister(&mut self, name: &str) {
         self.context.unregister(name)
     }
+
+    #[pyo3(signature = (name, row_filter, column_masks))]
+    pub fn register_policy(
+        &mut self,
+        name: &str,
+        row_filter: Option<PyExpr>,
+        column_masks: Vec<(String, PyExpr)>,
+    ) {
+        let mut policy = TablePolicy::new();
+        if let Some(row_filter) = row_filter {
+            policy = policy.with_row_filter(row_filter.inner);
+        }
+        for (column, mask) in column_masks {
+            policy = policy.with_column_mask(column, mask.inner);
+        }
+        self.context.register_policy(name, policy)
+    }
+
+    pub fn unregister_policy(&mut self, name: &str) {
+        self.context.unregister_policy(name)
+    }
+
+    pub fn register_function(&mut self, name: &str, function: PyObject) {
+        self.context.register_function(name, move |args| {
+            Python::with_gil(|py| {
+                let args = args.into_iter().map(PyExpr::from).collect::<Vec<_>>();
+                let out = function.call1(py, (args,))?;
+                Ok(out.extract::<PyExpr>(py)?.inner)
+            })
+        })
+    }
+
+    pub fn unregister_function(&mut self, name: &str) {
+        self.context.unregister_function(name)
+    }
+
+    pub fn get_functions(&self) -> Vec<String> {
+        self.context.get_functions()
+    }
 }
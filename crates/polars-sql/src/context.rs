@@ -18,7 +18,7 @@ use sqlparser::ast::{
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
 
-use crate::function_registry::{DefaultFunctionRegistry, FunctionRegistry};
+use crate::function_registry::{DefaultFunctionRegistry, FunctionFactory, FunctionRegistry};
 use crate::sql_expr::{
     parse_sql_array, parse_sql_expr, resolve_compound_identifier, to_sql_interface_err,
 };
@@ -52,11 +52,66 @@ impl SelectModifiers {
     }
 }
 
+/// A policy restricting the rows and column values of a table that queries can see.
+///
+/// The policy is applied whenever the table is read by a query, before any other part of the
+/// query is evaluated, so that the hidden rows and values are not visible to it.
+#[derive(Clone, Debug, Default)]
+pub struct TablePolicy {
+    row_filter: Option<Expr>,
+    column_masks: Vec<(PlSmallStr, Expr)>,
+}
+
+impl TablePolicy {
+    /// Create a new policy that does not restrict the table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only show the rows for which `predicate` is true.
+    ///
+    /// Calling this again combines the predicates with `AND`.
+    pub fn with_row_filter(mut self, predicate: Expr) -> Self {
+        self.row_filter = Some(match self.row_filter {
+            Some(filter) => filter.and(predicate),
+            None => predicate,
+        });
+        self
+    }
+
+    /// Replace the values of column `name` with the result of `mask`.
+    ///
+    /// The mask is evaluated on the unmasked columns of the filtered rows.
+    pub fn with_column_mask(mut self, name: impl Into<PlSmallStr>, mask: Expr) -> Self {
+        let name = name.into();
+        self.column_masks.retain(|(n, _)| *n != name);
+        self.column_masks.push((name, mask));
+        self
+    }
+
+    fn apply(&self, mut lf: LazyFrame) -> LazyFrame {
+        if let Some(predicate) = &self.row_filter {
+            lf = lf.filter(predicate.clone());
+        }
+        if !self.column_masks.is_empty() {
+            lf = lf.with_columns(
+                self.column_masks
+                    .iter()
+                    .map(|(name, mask)| mask.clone().alias(name.clone()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+        lf
+    }
+}
+
 /// The SQLContext is the main entry point for executing SQL queries.
 #[derive(Clone)]
 pub struct SQLContext {
     pub(crate) table_map: PlHashMap<String, LazyFrame>,
+    pub(crate) table_policies: PlHashMap<String, TablePolicy>,
     pub(crate) function_registry: Arc<dyn FunctionRegistry>,
+    pub(crate) function_factories: PlHashMap<String, FunctionFactory>,
     pub(crate) lp_arena: Arena<IR>,
     pub(crate) expr_arena: Arena<AExpr>,
 
@@ -69,7 +124,9 @@ impl Default for SQLContext {
     fn default() -> Self {
         Self {
             function_registry: Arc::new(DefaultFunctionRegistry {}),
+            function_factories: Default::default(),
             table_map: Default::default(),
+            table_policies: Default::default(),
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
@@ -118,10 +175,92 @@ impl SQLContext {
     }
 
     /// Unregister a [`LazyFrame`] table from the [`SQLContext`].
+    ///
+    /// The policy of the table, if any, is kept.
     pub fn unregister(&mut self, name: &str) {
         self.table_map.remove(&name.to_owned());
     }
 
+    /// Register a [`TablePolicy`] for the table `name`, replacing any existing policy.
+    ///
+    /// Every query that reads the table sees it with the policy applied. The policy is kept
+    /// when the table is unregistered or registered again.
+    /// ```rust
+    /// # use polars_sql::{SQLContext, TablePolicy};
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// let df = df! {
+    ///    "tenant" => [1, 2, 1],
+    ///    "secret" => ["a", "b", "c"],
+    /// }.unwrap().lazy();
+    ///
+    /// ctx.register("df", df);
+    /// ctx.register_policy(
+    ///     "df",
+    ///     TablePolicy::new()
+    ///         .with_row_filter(col("tenant").eq(lit(1)))
+    ///         .with_column_mask("secret", lit(NULL).cast(DataType::String)),
+    /// );
+    /// let out = ctx.execute("SELECT * FROM df").unwrap().collect().unwrap();
+    /// assert_eq!(out.height(), 2);
+    /// assert_eq!(out.column("secret").unwrap().null_count(), 2);
+    /// # }
+    ///```
+    pub fn register_policy(&mut self, name: &str, policy: TablePolicy) {
+        self.table_policies.insert(name.to_owned(), policy);
+    }
+
+    /// Unregister the [`TablePolicy`] of the table `name`.
+    pub fn unregister_policy(&mut self, name: &str) {
+        self.table_policies.remove(name);
+    }
+
+    /// Register a function that SQL queries on this context can call by `name`, replacing any
+    /// function registered under the same name.
+    ///
+    /// A call is planned by passing the expressions of its arguments to `function`, so the
+    /// schema of the result is resolved like that of any other expression. Aggregations in the
+    /// returned expression aggregate the groups of a `GROUP BY`. Names are case-insensitive and
+    /// take precedence over the built-in functions and the function registry.
+    /// ```rust
+    /// # use polars_sql::SQLContext;
+    /// # use polars_core::prelude::*;
+    /// # use polars_lazy::prelude::*;
+    /// # fn main() {
+    ///
+    /// let mut ctx = SQLContext::new();
+    /// ctx.register("df", df! { "a" => [1, 2, 3] }.unwrap().lazy());
+    /// ctx.register_function("sum_squares", |args: Vec<Expr>| {
+    ///     Ok((args[0].clone() * args[0].clone()).sum())
+    /// });
+    /// let out = ctx.execute("SELECT SUM_SQUARES(a) AS s FROM df").unwrap().collect().unwrap();
+    /// assert_eq!(out.column("s").unwrap().i32().unwrap().get(0), Some(14));
+    /// # }
+    ///```
+    pub fn register_function<F>(&mut self, name: &str, function: F)
+    where
+        F: Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync + 'static,
+    {
+        self.function_factories
+            .insert(name.to_lowercase(), Arc::new(function));
+    }
+
+    /// Unregister the function registered by [`SQLContext::register_function`] under `name`.
+    pub fn unregister_function(&mut self, name: &str) {
+        self.function_factories.remove(&name.to_lowercase());
+    }
+
+    /// Get the names of all functions registered by [`SQLContext::register_function`], in
+    /// sorted order.
+    pub fn get_functions(&self) -> Vec<String> {
+        let mut functions = Vec::from_iter(self.function_factories.keys().cloned());
+        functions.sort_unstable();
+        functions
+    }
+
     /// Execute a SQL query, returning a [`LazyFrame`].
     /// ```rust
     /// # use polars_sql::SQLContext;
@@ -223,17 +362,26 @@ impl SQLContext {
     }
 
     pub(super) fn get_table_from_current_scope(&self, name: &str) -> Option<LazyFrame> {
-        let table = self.table_map.get(name).cloned();
+        let table = self.get_registered_table(name);
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
                 self.table_aliases
                     .borrow()
                     .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                    .and_then(|alias| self.get_registered_table(alias))
             })
     }
 
+    /// Get a table from the table map, with its policy applied.
+    fn get_registered_table(&self, name: &str) -> Option<LazyFrame> {
+        let lf = self.table_map.get(name).cloned()?;
+        Some(match self.table_policies.get(name) {
+            Some(policy) => policy.apply(lf),
+            None => lf,
+        })
+    }
+
     fn expr_or_ordinal(
         &mut self,
         e: &SQLExpr,
@@ -856,6 +1004,7 @@ impl SQLContext {
                                 suffix: None,
                                 slice: None,
                                 nulls_equal: false,
+                                nans_equal: None,
                                 coalesce: Default::default(),
                                 maintain_order: polars_ops::frame::MaintainOrderJoin::Left,
                             },
@@ -1313,8 +1462,11 @@ impl SQLContext {
 
         for mut e in projections {
             // `Len` represents COUNT(*) so we treat as an aggregation here.
-            let is_agg_or_window = has_expr(e, |e| {
-                matches!(e, Expr::Agg(_) | Expr::Len | Expr::Window { .. })
+            let is_agg_or_window = has_expr(e, |e| match e {
+                Expr::Agg(_) | Expr::Len | Expr::Window { .. } => true,
+                // A registered function that maps the groups to a scalar.
+                Expr::AnonymousFunction { options, .. } => options.returns_scalar(),
+                _ => false,
             });
 
             let mut is_function_under_alias = false;
@@ -1,8 +1,16 @@
 //! This module defines a FunctionRegistry for supported SQL functions and UDFs.
 
+use std::sync::Arc;
+
 use polars_error::{PolarsResult, polars_bail};
+use polars_plan::prelude::Expr;
 use polars_plan::prelude::udf::UserDefinedFunction;
 pub use polars_plan::prelude::{Context, FunctionOptions};
+
+/// A function that builds the expression of a SQL function call from the expressions of its
+/// arguments; see [`SQLContext::register_function`](crate::SQLContext::register_function).
+pub type FunctionFactory = Arc<dyn Fn(Vec<Expr>) -> PolarsResult<Expr> + Send + Sync>;
+
 /// A registry that holds user defined functions.
 pub trait FunctionRegistry: Send + Sync {
     /// Register a function.
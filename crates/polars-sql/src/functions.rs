@@ -691,6 +691,13 @@ pub(crate) enum PolarsSQLFunctions {
     // ----
     // Column selection
     // ----
+    /// SQL 'columns' function; selects the columns of which the name matches a regex.
+    /// The columns are resolved against the schema of the input frame, so scans only
+    /// read the matching columns.
+    /// ```sql
+    /// SELECT COLUMNS('^price_') FROM df;
+    /// SELECT MAX(COLUMNS('_count$')) FROM df;
+    /// ```
     Columns,
 
     // ----
@@ -814,6 +821,9 @@ impl PolarsSQLFunctions {
 impl PolarsSQLFunctions {
     fn try_from_sql(function: &'_ SQLFunction, ctx: &'_ SQLContext) -> PolarsResult<Self> {
         let function_name = function.name.0[0].value.to_lowercase();
+        if ctx.function_factories.contains_key(&function_name) {
+            return Ok(Self::Udf(function_name));
+        }
         Ok(match function_name.as_str() {
             // ----
             // Bitwise functions
@@ -1530,14 +1540,16 @@ impl SQLFunctionVisitor<'_> {
                                 did you mean COLUMNS(*)?"
                             )
                         };
-                        let pat = match pat {
+                        let regex = match pat {
                             _ if pat.starts_with('^') && pat.ends_with('$') => pat.to_string(),
                             _ if pat.starts_with('^') => format!("{pat}.*$"),
                             _ if pat.ends_with('$') => format!("^.*{pat}"),
                             _ => format!("^.*{pat}.*$"),
                         };
+                        let rx = polars_utils::regex_cache::compile_regex(&regex).map_err(|_| {
+                            polars_err!(SQLSyntax: "COLUMNS expects a valid regex; found {:?}", pat)
+                        })?;
                         if let Some(active_schema) = &active_schema {
-                            let rx = polars_utils::regex_cache::compile_regex(&pat).unwrap();
                             let col_names = active_schema
                                 .iter_names()
                                 .filter(|name| rx.is_match(name))
@@ -1550,7 +1562,7 @@ impl SQLFunctionVisitor<'_> {
                                 cols(col_names).as_expr()
                             })
                         } else {
-                            Ok(col(pat.as_str()))
+                            Ok(col(regex.as_str()))
                         }
                     },
                     Expr::Selector(s) => Ok(s.as_expr()),
@@ -1577,6 +1589,9 @@ impl SQLFunctionVisitor<'_> {
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
+        if let Some(factory) = self.ctx.function_factories.get(func_name) {
+            return factory(args);
+        }
         Ok(self
             .ctx
             .function_registry
@@ -9,5 +9,5 @@ mod sql_expr;
 mod table_functions;
 mod types;
 
-pub use context::SQLContext;
+pub use context::{SQLContext, TablePolicy};
 pub use sql_expr::sql_expr;
@@ -1,6 +1,6 @@
 use polars_core::prelude::*;
 use polars_lazy::prelude::IntoLazy;
-use polars_plan::prelude::{GetOutput, UserDefinedFunction};
+use polars_plan::prelude::{Expr, GetOutput, UserDefinedFunction, lit};
 use polars_sql::SQLContext;
 use polars_sql::function_registry::FunctionRegistry;
 
@@ -108,3 +108,40 @@ fn test_udfs() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_register_function() -> PolarsResult<()> {
+    let mut ctx = SQLContext::new();
+    ctx.register(
+        "foo",
+        df! {
+            "k" => &["x", "y", "x"],
+            "v" => &[1, 2, 3],
+        }?
+        .lazy(),
+    );
+    ctx.register_function("Plus_One", |args| match <[Expr; 1]>::try_from(args) {
+        Ok([e]) => Ok(e + lit(1)),
+        Err(_) => polars_bail!(SQLSyntax: "PLUS_ONE expects 1 argument"),
+    });
+    ctx.register_function("total", |args| Ok(args[0].clone().sum()));
+    assert_eq!(ctx.get_functions(), ["plus_one", "total"]);
+
+    let res = ctx
+        .execute("SELECT k, TOTAL(plus_one(v)) AS t FROM foo GROUP BY k ORDER BY k")?
+        .collect()?;
+    let expected = df! {
+        "k" => &["x", "y"],
+        "t" => &[6, 3],
+    }?;
+    assert!(expected.equals_missing(&res));
+
+    assert!(matches!(
+        ctx.execute("SELECT plus_one(k, v) FROM foo"),
+        Err(PolarsError::SQLSyntax(_))
+    ));
+
+    ctx.unregister_function("PLUS_ONE");
+    assert!(ctx.execute("SELECT plus_one(v) FROM foo").is_err());
+    Ok(())
+}
@@ -16,6 +16,7 @@ use crossbeam_deque::{Injector, Steal, Stealer, Worker as WorkQueue};
 use crossbeam_utils::CachePadded;
 use park_group::ParkGroup;
 use parking_lot::Mutex;
+use polars_utils::affinity::THREAD_AFFINITY;
 use polars_utils::relaxed_cell::RelaxedCell;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
@@ -264,16 +265,21 @@ impl Executor {
         GLOBAL_SCHEDULER.get_or_init(|| {
             let mut n_threads = NUM_EXECUTOR_THREADS.load();
             if n_threads == 0 {
-                n_threads = std::thread::available_parallelism()
-                    .map(|n| n.get())
-                    .unwrap_or(4);
+                n_threads = THREAD_AFFINITY.default_num_threads().unwrap_or_else(|| {
+                    std::thread::available_parallelism()
+                        .map(|n| n.get())
+                        .unwrap_or(4)
+                });
             }
 
             let thread_task_lists = (0..n_threads)
                 .map(|t| {
                     std::thread::Builder::new()
                         .name(format!("async-executor-{t}"))
-                        .spawn(move || Self::global().runner(t))
+                        .spawn(move || {
+                            THREAD_AFFINITY.pin_worker(t);
+                            Self::global().runner(t)
+                        })
                         .unwrap();
 
                     let high_prio_tasks = WorkQueue::new_lifo();
@@ -1,6 +1,7 @@
 use std::sync::Arc;
 
 use polars_core::POOL;
+use polars_core::config::ConfigOverrides;
 use polars_core::frame::DataFrame;
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
@@ -10,6 +11,7 @@ use slotmap::{SecondaryMap, SparseSecondaryMap};
 
 use crate::async_executor;
 use crate::graph::{Graph, GraphNode, GraphNodeKey, LogicalPipeKey, PortState};
+use crate::morsel::get_ideal_morsel_size;
 use crate::pipe::PhysicalPipe;
 
 #[derive(Clone)]
@@ -17,7 +19,8 @@ pub struct StreamingExecutionState {
     // The number of parallel pipelines we have within each stream.
     pub num_pipelines: usize,
 
-    // The ExecutionState passed to any non-streaming operations.
+    // The ExecutionState passed to any non-streaming operations. It also holds the settings of
+    // the query that override the global configuration.
     pub in_memory_exec_state: ExecutionState,
 }
 
@@ -30,6 +33,23 @@ impl Default for StreamingExecutionState {
     }
 }
 
+impl StreamingExecutionState {
+    pub fn config(&self) -> &ConfigOverrides {
+        self.in_memory_exec_state.config()
+    }
+
+    pub fn verbose(&self) -> bool {
+        self.in_memory_exec_state.verbose()
+    }
+
+    /// The number of rows the nodes aim to put in a morsel.
+    pub fn ideal_morsel_size(&self) -> usize {
+        self.config()
+            .ideal_morsel_size
+            .unwrap_or_else(get_ideal_morsel_size)
+    }
+}
+
 /// Finds all runnable pipeline blockers in the graph, that is, nodes which:
 ///  - Only have blocked output ports.
 ///  - Have at least one ready input port connected to a ready output port.
@@ -268,6 +288,7 @@ fn run_subgraph(
 
 pub fn execute_graph(
     graph: &mut Graph,
+    config: Arc<ConfigOverrides>,
 ) -> PolarsResult<SparseSecondaryMap<GraphNodeKey, DataFrame>> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -275,7 +296,7 @@ pub fn execute_graph(
 
     let state = StreamingExecutionState {
         num_pipelines,
-        in_memory_exec_state: ExecutionState::default(),
+        in_memory_exec_state: ExecutionState::with_config(config),
     };
 
     // Ensure everything is properly connected.
@@ -292,12 +313,12 @@ pub fn execute_graph(
 
     let mut pipe_seq_offsets = SecondaryMap::new();
     loop {
-        if polars_core::config::verbose() {
+        if state.verbose() {
             eprintln!("polars-stream: updating graph state");
         }
         graph.update_all_states(&state)?;
         let (nodes, pipes) = find_runnable_subgraph(graph);
-        if polars_core::config::verbose() {
+        if state.verbose() {
             for node in &nodes {
                 eprintln!(
                     "polars-stream: running {} in subgraph",
@@ -309,7 +330,7 @@ pub fn execute_graph(
             break;
         }
         run_subgraph(graph, &nodes, &pipes, &mut pipe_seq_offsets, &state)?;
-        if polars_core::config::verbose() {
+        if state.verbose() {
             eprintln!("polars-stream: done running graph phase");
         }
     }
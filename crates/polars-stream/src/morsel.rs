@@ -2,6 +2,8 @@ use std::future::Future;
 use std::sync::{Arc, OnceLock};
 
 use polars_core::frame::DataFrame;
+use polars_core::prelude::BooleanChunked;
+use polars_error::PolarsResult;
 use polars_utils::relaxed_cell::RelaxedCell;
 
 use crate::async_primitives::wait_group::WaitToken;
@@ -90,6 +92,12 @@ pub struct Morsel {
 
     /// Used to notify someone when this morsel is consumed, to provide backpressure.
     consume_token: Option<WaitToken>,
+
+    /// The rows of `df` that are still selected, if a filter deferred its compaction.
+    ///
+    /// Morsels with a selection are only sent to nodes that know how to honor it, all other
+    /// nodes see compacted morsels.
+    selection: Option<BooleanChunked>,
 }
 
 impl Morsel {
@@ -99,26 +107,63 @@ impl Morsel {
             seq,
             source_token,
             consume_token: None,
+            selection: None,
         }
     }
 
     #[allow(unused)]
     pub fn into_inner(self) -> (DataFrame, MorselSeq, SourceToken, Option<WaitToken>) {
+        debug_assert!(self.selection.is_none());
         (self.df, self.seq, self.source_token, self.consume_token)
     }
 
     pub fn into_df(self) -> DataFrame {
+        debug_assert!(self.selection.is_none());
         self.df
     }
 
     pub fn df(&self) -> &DataFrame {
+        debug_assert!(self.selection.is_none());
         &self.df
     }
 
     pub fn df_mut(&mut self) -> &mut DataFrame {
+        debug_assert!(self.selection.is_none());
         &mut self.df
     }
 
+    /// The uncompacted data of this morsel, the rows that are not in the selection still have
+    /// to be dropped.
+    pub fn uncompacted_df(&self) -> &DataFrame {
+        &self.df
+    }
+
+    pub fn selection(&self) -> Option<&BooleanChunked> {
+        self.selection.as_ref()
+    }
+
+    pub fn set_selection(&mut self, selection: Option<BooleanChunked>) {
+        debug_assert!(
+            selection
+                .as_ref()
+                .is_none_or(|s| s.len() == self.df.height() && s.null_count() == 0)
+        );
+        self.selection = selection;
+    }
+
+    pub fn take_selection(&mut self) -> Option<BooleanChunked> {
+        self.selection.take()
+    }
+
+    /// Drop the rows that are not in the selection.
+    pub fn compact(&mut self) -> PolarsResult<()> {
+        if let Some(selection) = self.selection.take() {
+            // We already parallelize, call the sequential filter.
+            self.df = self.df._filter_seq(&selection)?;
+        }
+        Ok(())
+    }
+
     pub fn seq(&self) -> MorselSeq {
         self.seq
     }
@@ -0,0 +1,405 @@
+use std::sync::Arc;
+
+use polars_core::POOL;
+use polars_core::chunked_array::ops::row_encode::encode_rows_vertical_par_unordered;
+use polars_core::prelude::{
+    BooleanChunked, ChunkSort, Column, DataType, Int64Chunked, IntoColumn, NewChunkedArray,
+    PlHashMap, PlRandomState, SortOptions,
+};
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::hash_keys::HashKeys;
+use polars_plan::plans::DataFrameUdf;
+use polars_utils::IdxSize;
+use polars_utils::hashing::HashPartitioner;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::{Receiver, Sender, connector};
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+const LB_NAME: &str = "_lower_boundary";
+const UB_NAME: &str = "_upper_boundary";
+
+/// The physical values of a time column, cast to `dtype` first.
+fn physical_times(column: &Column, dtype: &DataType) -> PolarsResult<Int64Chunked> {
+    let physical = column.cast(dtype)?.to_physical_repr();
+    Ok(physical.cast(&DataType::Int64)?.i64()?.clone())
+}
+
+/// The rows of a partition that can still contribute to windows that weren't output yet, and the
+/// windows that were output.
+struct PartitionState {
+    /// The rows, with their evaluated keys.
+    buffer: Vec<(DataFrame, DataFrame)>,
+    buffered_rows: usize,
+    /// The number of buffered rows at which the complete windows are aggregated.
+    flush_at: usize,
+    /// The lower bound of the first window of each key that wasn't output yet. Windows before it
+    /// are complete and were output already.
+    cutoffs: PlHashMap<Vec<u8>, i64>,
+    out: Vec<DataFrame>,
+}
+
+impl PartitionState {
+    fn new(flush_at: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            buffered_rows: 0,
+            flush_at,
+            cutoffs: PlHashMap::default(),
+            out: Vec::new(),
+        }
+    }
+
+    /// Aggregate the windows of the buffered rows with `map`, and output the complete ones. All
+    /// windows are complete when the input is done.
+    ///
+    /// A window is complete once a row of its key after its upper bound was seen, as the rows of
+    /// each key are sorted by time. The rows from the lower bound of the first incomplete window
+    /// onwards are kept, and the windows starting before it are dropped when they're aggregated
+    /// again, as they were output already.
+    fn flush(
+        &mut self,
+        map: &dyn DataFrameUdf,
+        num_keys: usize,
+        index_column: &PlSmallStr,
+        input_done: bool,
+    ) -> PolarsResult<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let (dfs, keys): (Vec<_>, Vec<_>) = core::mem::take(&mut self.buffer).into_iter().unzip();
+        let df = accumulate_dataframes_vertical_unchecked(dfs);
+        let keys = accumulate_dataframes_vertical_unchecked(keys);
+        self.buffered_rows = 0;
+
+        let out = map.call_udf(df.clone())?;
+        let out_keys = encode_rows_vertical_par_unordered(&out.get_columns()[..num_keys])?;
+        let bounds_dtype = out.column(LB_NAME)?.dtype().clone();
+        let lower = physical_times(out.column(LB_NAME)?, &bounds_dtype)?;
+        let upper = physical_times(out.column(UB_NAME)?, &bounds_dtype)?;
+
+        let times = physical_times(df.column(index_column)?, &bounds_dtype)?;
+        let encoded = encode_rows_vertical_par_unordered(keys.get_columns())?;
+        let mut last_times = PlHashMap::with_capacity(self.cutoffs.len());
+        if !input_done {
+            for (key, t) in encoded.into_no_null_iter().zip(times.into_no_null_iter()) {
+                let last = last_times.entry(key).or_insert(t);
+                *last = t.max(*last);
+            }
+        }
+
+        let mut cutoffs = PlHashMap::with_capacity(self.cutoffs.len());
+        let mut output = Vec::with_capacity(out.height());
+        let windows = lower.into_no_null_iter().zip(upper.into_no_null_iter());
+        for (key, (lower, upper)) in out_keys.into_no_null_iter().zip(windows) {
+            if self.cutoffs.get(key).is_some_and(|cutoff| lower < *cutoff) {
+                output.push(false);
+            } else if input_done || last_times[key] > upper {
+                output.push(true);
+            } else {
+                // The windows of a key are ordered by their lower bound.
+                cutoffs.entry_ref(key).or_insert(lower);
+                output.push(false);
+            }
+        }
+        self.out
+            .push(out.filter(&BooleanChunked::from_slice(PlSmallStr::EMPTY, &output))?);
+        if input_done {
+            return Ok(());
+        }
+
+        let keep: BooleanChunked = encoded
+            .into_no_null_iter()
+            .zip(times.into_no_null_iter())
+            .map(|(key, t)| cutoffs.get(key).is_some_and(|cutoff| t >= *cutoff))
+            .collect();
+        let df = df.filter(&keep)?;
+        self.buffered_rows = df.height();
+        self.buffer.push((df, keys.filter(&keep)?));
+        self.cutoffs = cutoffs;
+        // Flush less often when many rows are kept, so that they aren't aggregated over and over.
+        self.flush_at = self.flush_at.max(2 * self.buffered_rows);
+        Ok(())
+    }
+}
+
+struct DynamicGroupBySinkState {
+    key_selectors: Vec<StreamExpr>,
+    random_state: PlRandomState,
+    partitioner: HashPartitioner,
+    partitions: Vec<PartitionState>,
+}
+
+impl DynamicGroupBySinkState {
+    #[allow(clippy::too_many_arguments)]
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        mut recv: Receiver<Morsel>,
+        maps: &'env [Arc<dyn DataFrameUdf>],
+        num_keys: usize,
+        index_column: &'env PlSmallStr,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        let (mut senders, receivers): (Vec<Sender<(DataFrame, DataFrame)>>, Vec<_>) =
+            (0..self.partitions.len()).map(|_| connector()).unzip();
+
+        // The morsels are received in order, so the rows of each key arrive sorted by time in the
+        // task of their partition.
+        let key_selectors = &self.key_selectors;
+        let random_state = &self.random_state;
+        let partitioner = self.partitioner.clone();
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            let mut idxs_per_p: Vec<Vec<IdxSize>> = vec![Vec::new(); senders.len()];
+            while let Ok(morsel) = recv.recv().await {
+                let mut df = morsel.into_df();
+                let mut key_columns = Vec::new();
+                for selector in key_selectors {
+                    let s = selector.evaluate(&df, &state.in_memory_exec_state).await?;
+                    key_columns.push(s.into_column());
+                }
+                let mut keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
+                let hash_keys = HashKeys::from_df(&keys, *random_state, true, false);
+
+                idxs_per_p.iter_mut().for_each(Vec::clear);
+                hash_keys.gen_idxs_per_partition(&partitioner, &mut idxs_per_p, &mut [], true);
+                // For gathers.
+                df.rechunk_mut();
+                keys.rechunk_mut();
+                for (sender, idxs) in senders.iter_mut().zip(&idxs_per_p) {
+                    if !idxs.is_empty() {
+                        // SAFETY: the indices were generated from the rows of the morsel.
+                        let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+                        let p_keys = unsafe { keys.take_slice_unchecked_impl(idxs, false) };
+                        if sender.send((p_df, p_keys)).await.is_err() {
+                            return Ok(());
+                        }
+                    }
+                }
+            }
+            Ok(())
+        }));
+
+        for ((mut recv, partition), map) in
+            receivers.into_iter().zip(&mut self.partitions).zip(maps)
+        {
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                while let Ok((df, keys)) = recv.recv().await {
+                    partition.buffered_rows += df.height();
+                    partition.buffer.push((df, keys));
+                    if partition.buffered_rows >= partition.flush_at {
+                        partition.flush(map.as_ref(), num_keys, index_column, false)?;
+                    }
+                }
+                Ok(())
+            }));
+        }
+    }
+
+    /// Aggregate the remaining windows of each partition, and combine the results.
+    fn finalize(
+        &mut self,
+        maps: &[Arc<dyn DataFrameUdf>],
+        input_schema: &Schema,
+        num_keys: usize,
+        index_column: &PlSmallStr,
+    ) -> PolarsResult<DataFrame> {
+        let dfs = POOL.install(|| {
+            core::mem::take(&mut self.partitions)
+                .into_par_iter()
+                .zip(maps)
+                .with_max_len(1)
+                .map(|(mut partition, map)| {
+                    partition.flush(map.as_ref(), num_keys, index_column, true)?;
+                    PolarsResult::Ok(partition.out)
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        let dfs = dfs
+            .into_iter()
+            .flatten()
+            .filter(|df| df.height() > 0)
+            .collect::<Vec<_>>();
+
+        match dfs.len() {
+            0 => maps[0].call_udf(DataFrame::empty_with_schema(input_schema)),
+            1 => Ok(dfs.into_iter().next().unwrap()),
+            _ => {
+                // The in-memory engine outputs the windows ordered by the encoded keys, restore
+                // that order over the partitions. The windows of each key are already in order.
+                let df = accumulate_dataframes_vertical_unchecked(dfs);
+                let encoded = encode_rows_vertical_par_unordered(&df.get_columns()[..num_keys])?;
+                let idx = encoded.arg_sort(SortOptions {
+                    maintain_order: true,
+                    ..Default::default()
+                });
+                // SAFETY: the indices are in bounds as they're a permutation of the rows.
+                Ok(unsafe { df.take_unchecked(&idx) })
+            },
+        }
+    }
+}
+
+enum DynamicGroupByState {
+    Sink(DynamicGroupBySinkState),
+    Source(InMemorySourceNode),
+    Done,
+}
+
+/// A `group_by_dynamic` with `group_by` keys, which partitions the rows by the hash of their keys
+/// and groups the partitions in parallel, instead of sorting all rows by their keys.
+///
+/// When the windows lie on a fixed grid, each partition aggregates its complete windows as the
+/// rows come in, so that it only has to keep the rows of the windows that are still open.
+pub struct DynamicGroupByNode {
+    state: DynamicGroupByState,
+    input_schema: Arc<Schema>,
+    num_keys: usize,
+    index_column: PlSmallStr,
+    /// Whether the boundaries of the windows are part of the output. They're always computed.
+    include_boundaries: bool,
+    /// The in-memory `group_by_dynamic` of each partition.
+    maps: Vec<Arc<dyn DataFrameUdf>>,
+}
+
+impl DynamicGroupByNode {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        maps: Vec<Arc<dyn DataFrameUdf>>,
+        index_column: PlSmallStr,
+        include_boundaries: bool,
+        incremental: bool,
+        random_state: PlRandomState,
+    ) -> Self {
+        let num_keys = key_selectors.len();
+        let num_partitions = maps.len();
+        let partitioner = HashPartitioner::new(num_partitions, 0);
+        let flush_at = if incremental {
+            std::env::var("POLARS_DYNAMIC_GROUP_BY_FLUSH_SIZE")
+                .map(|sz| sz.parse::<usize>().unwrap())
+                .unwrap_or_else(|_| get_ideal_morsel_size() * 16)
+        } else {
+            usize::MAX
+        };
+        Self {
+            state: DynamicGroupByState::Sink(DynamicGroupBySinkState {
+                key_selectors,
+                random_state,
+                partitioner,
+                partitions: (0..num_partitions)
+                    .map(|_| PartitionState::new(flush_at))
+                    .collect(),
+            }),
+            input_schema,
+            num_keys,
+            index_column,
+            include_boundaries,
+            maps,
+        }
+    }
+}
+
+impl ComputeNode for DynamicGroupByNode {
+    fn name(&self) -> &str {
+        "dynamic-group-by"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = DynamicGroupByState::Done;
+            },
+            // Input is done, transition to being a source.
+            DynamicGroupByState::Sink(sink) if matches!(recv[0], PortState::Done) => {
+                let mut df = sink.finalize(
+                    &self.maps,
+                    &self.input_schema,
+                    self.num_keys,
+                    &self.index_column,
+                )?;
+                if !self.include_boundaries {
+                    df = df.drop_many([LB_NAME, UB_NAME]);
+                }
+                let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::new(0));
+                self.state = DynamicGroupByState::Source(source);
+            },
+            // Defer to source node implementation.
+            DynamicGroupByState::Source(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = DynamicGroupByState::Done;
+                }
+            },
+            // Nothing to change.
+            DynamicGroupByState::Done | DynamicGroupByState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            DynamicGroupByState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            DynamicGroupByState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            DynamicGroupByState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, DynamicGroupByState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            DynamicGroupByState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().serial(),
+                    &self.maps,
+                    self.num_keys,
+                    &self.index_column,
+                    state,
+                    join_handles,
+                )
+            },
+            DynamicGroupByState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            DynamicGroupByState::Done => unreachable!(),
+        }
+    }
+}
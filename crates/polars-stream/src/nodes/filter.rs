@@ -1,15 +1,62 @@
-use polars_error::polars_err;
+use polars_core::prelude::{BooleanChunked, ChunkFillNullValue, NewChunkedArray};
+use polars_error::{PolarsError, polars_ensure, polars_err};
 
 use super::compute_node_prelude::*;
 use crate::expression::StreamExpr;
 
+/// Whether `err` can be caused by the values of rows that a deferred filter already removed, such
+/// as a failing strict cast. Only these errors are retried on the compacted rows.
+pub(crate) fn is_value_error(err: &PolarsError) -> bool {
+    match err {
+        PolarsError::ComputeError(_)
+        | PolarsError::InvalidOperation(_)
+        | PolarsError::OutOfBounds(_) => true,
+        PolarsError::Context { error, .. } => is_value_error(error),
+        _ => false,
+    }
+}
+
 pub struct FilterNode {
     predicate: StreamExpr,
+    /// Attach the selected rows to the morsels instead of compacting them. This may only be set
+    /// if the receiving node honors the selection.
+    defer_compaction: bool,
 }
 
 impl FilterNode {
-    pub fn new(predicate: StreamExpr) -> Self {
-        Self { predicate }
+    pub fn new(predicate: StreamExpr, defer_compaction: bool) -> Self {
+        Self {
+            predicate,
+            defer_compaction,
+        }
+    }
+
+    /// Evaluate the predicate into a selection of the same height as `df` without nulls.
+    async fn evaluate_selection(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<BooleanChunked> {
+        let mask = self.predicate.evaluate(df, state).await?;
+        let mask = mask.bool().map_err(|_| {
+            polars_err!(
+                ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
+            )
+        })?;
+
+        if mask.len() == 1 && df.height() != 1 {
+            return Ok(BooleanChunked::full(
+                mask.name().clone(),
+                mask.get(0) == Some(true),
+                df.height(),
+            ));
+        }
+        polars_ensure!(
+            mask.len() == df.height(),
+            ShapeMismatch: "filter's length: {} differs from that of the series: {}",
+            mask.len(), df.height()
+        );
+        mask.fill_null_with_values(false)
     }
 }
 
@@ -43,22 +90,76 @@ impl ComputeNode for FilterNode {
 
         for (mut recv, mut send) in receivers.into_iter().zip(senders) {
             let slf = &*self;
+            let exec_state = &state.in_memory_exec_state;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
-                while let Ok(morsel) = recv.recv().await {
+                while let Ok(mut morsel) = recv.recv().await {
+                    let selection = match morsel.take_selection() {
+                        None if !slf.defer_compaction => None,
+                        None => Some(
+                            slf.evaluate_selection(morsel.uncompacted_df(), exec_state)
+                                .await?,
+                        ),
+                        Some(prev) => {
+                            match slf
+                                .evaluate_selection(morsel.uncompacted_df(), exec_state)
+                                .await
+                            {
+                                Ok(selection) => Some(&selection & &prev),
+                                // The rows that are no longer selected might contain values the
+                                // predicate fails on, retry on the compacted rows so we raise the
+                                // same errors as an eager filter would.
+                                Err(err) if is_value_error(&err) => {
+                                    morsel.set_selection(Some(prev));
+                                    morsel.compact()?;
+                                    if slf.defer_compaction {
+                                        Some(
+                                            slf.evaluate_selection(
+                                                morsel.uncompacted_df(),
+                                                exec_state,
+                                            )
+                                            .await?,
+                                        )
+                                    } else {
+                                        None
+                                    }
+                                },
+                                Err(err) => return Err(err),
+                            }
+                        },
+                    };
 
-                    let morsel = morsel.async_try_map(|df| async move {
-                        let mask = slf.predicate.evaluate(&df, &state.in_memory_exec_state).await?;
-                        let mask = mask.bool().map_err(|_| {
-                            polars_err!(
-                                ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
-                            )
-                        })?;
+                    let morsel = match selection {
+                        None => {
+                            morsel
+                                .async_try_map(|df| async move {
+                                    let mask = slf.predicate.evaluate(&df, exec_state).await?;
+                                    let mask = mask.bool().map_err(|_| {
+                                        polars_err!(
+                                            ComputeError: "filter predicate must be of type `Boolean`, got `{}`", mask.dtype()
+                                        )
+                                    })?;
 
-                        // We already parallelize, call the sequential filter.
-                        df._filter_seq(mask)
-                    }).await?;
+                                    // We already parallelize, call the sequential filter.
+                                    df._filter_seq(mask)
+                                })
+                                .await?
+                        },
+                        Some(selection) => {
+                            let selected = selection.num_trues();
+                            if selected == 0 {
+                                continue;
+                            }
+                            if selected < selection.len() {
+                                morsel.set_selection(Some(selection));
+                                if !slf.defer_compaction {
+                                    morsel.compact()?;
+                                }
+                            }
+                            morsel
+                        },
+                    };
 
-                    if morsel.df().height() == 0 {
+                    if morsel.uncompacted_df().height() == 0 {
                         continue;
                     }
 
@@ -20,7 +20,6 @@ use super::compute_node_prelude::*;
 use crate::async_executor;
 use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
-use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
 
 #[cfg(debug_assertions)]
@@ -126,6 +125,7 @@ impl GroupBySinkState {
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let ideal_morsel_size = state.ideal_morsel_size();
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
@@ -196,7 +196,7 @@ impl GroupBySinkState {
                     }
 
                     // If we have too many evicted rows, flush them.
-                    if local.hot_grouper.num_evictions() >= get_ideal_morsel_size() {
+                    if local.hot_grouper.num_evictions() >= ideal_morsel_size {
                         local.flush_evictions(&partitioner);
                     }
                 }
@@ -453,6 +453,8 @@ pub struct GroupByNode {
     state: GroupByState,
     key_schema: Arc<Schema>,
     output_schema: Arc<Schema>,
+    /// Only this many groups, in any order, have to be produced.
+    limit: Option<usize>,
 }
 
 impl GroupByNode {
@@ -466,6 +468,7 @@ impl GroupByNode {
         output_schema: Arc<Schema>,
         random_state: PlRandomState,
         num_pipelines: usize,
+        limit: Option<usize>,
     ) -> Self {
         let hot_table_size = std::env::var("POLARS_HOT_TABLE_SIZE")
             .map(|sz| sz.parse::<usize>().unwrap())
@@ -502,6 +505,7 @@ impl GroupByNode {
             }),
             key_schema,
             output_schema,
+            limit,
         }
     }
 }
@@ -532,7 +536,20 @@ impl ComputeNode for GroupByNode {
                 else {
                     unreachable!()
                 };
-                let partitions = sink.combine_locals()?;
+                let mut partitions = sink.combine_locals()?;
+                if let Some(limit) = self.limit {
+                    // Every partition has complete groups, so only finalize enough partitions.
+                    let mut num_groups = 0;
+                    let num_partitions = partitions
+                        .iter()
+                        .take_while(|p| {
+                            let done = num_groups >= limit;
+                            num_groups += p.grouper.num_groups() as usize;
+                            !done
+                        })
+                        .count();
+                    partitions.truncate(num_partitions.max(1));
+                }
                 let dfs = POOL.install(|| {
                     partitions
                         .into_par_iter()
@@ -3,7 +3,7 @@ use std::sync::atomic::{AtomicU64, Ordering};
 
 use super::compute_node_prelude::*;
 use crate::async_primitives::wait_group::WaitGroup;
-use crate::morsel::{MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{MorselSeq, SourceToken};
 
 pub struct InMemorySourceNode {
     source: Option<Arc<DataFrame>>,
@@ -39,7 +39,7 @@ impl ComputeNode for InMemorySourceNode {
 
         if self.morsel_size == 0 {
             let len = self.source.as_ref().unwrap().height();
-            let ideal_morsel_count = (len / get_ideal_morsel_size()).max(1);
+            let ideal_morsel_count = (len / state.ideal_morsel_size()).max(1);
             let morsel_count = ideal_morsel_count.next_multiple_of(state.num_pipelines);
             self.morsel_size = len.div_ceil(morsel_count).max(1);
             self.seq = AtomicU64::new(0);
@@ -18,7 +18,7 @@ use polars_utils::priority::Priority;
 
 use super::{
     DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_SINK_LINEARIZER_BUFFER_SIZE, SinkInputPort,
-    SinkNode, buffer_and_distribute_columns_task,
+    SinkNode, buffer_and_distribute_columns_task, num_encode_tasks,
 };
 use crate::async_executor::spawn;
 use crate::async_primitives::connector::{Receiver, connector};
@@ -74,12 +74,13 @@ impl SinkNode for IpcSinkNode {
         state: &StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
+        let num_encode_tasks = num_encode_tasks(state);
         // Buffer task -> Encode tasks
         let (dist_tx, dist_rxs) =
-            distributor_channel(state.num_pipelines, *DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE);
+            distributor_channel(num_encode_tasks, *DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE);
         // Encode tasks -> Collect task
         let (mut lin_rx, lin_txs) =
-            Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
+            Linearizer::new(num_encode_tasks, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
         let (mut io_tx, mut io_rx) = connector::<(Vec<EncodedData>, EncodedData)>();
 
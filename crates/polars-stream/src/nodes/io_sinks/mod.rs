@@ -2,7 +2,6 @@ use std::sync::{Arc, LazyLock, Mutex};
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use polars_core::config;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::Column;
 use polars_core::schema::SchemaRef;
@@ -45,6 +44,15 @@ static DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE: LazyLock<usize> = LazyLock::new(||
         .unwrap_or(1)
 });
 
+/// The number of tasks that encode the columns distributed by
+/// [`buffer_and_distribute_columns_task`].
+fn num_encode_tasks(state: &StreamingExecutionState) -> usize {
+    state
+        .config()
+        .sink_writer_threads
+        .unwrap_or(state.num_pipelines)
+}
+
 pub enum SinkInputPort {
     Serial(Receiver<Morsel>),
     Parallel(Vec<Receiver<Morsel>>),
@@ -278,6 +286,7 @@ impl ComputeNode for SinkComputeNode {
         assert!(send_ports.is_empty());
 
         let name = self.name().to_string();
+        let verbose = state.verbose();
         let started = self.started.get_or_insert_with(|| {
             let (tx, rx) = connector();
             let mut join_handles = Vec::new();
@@ -310,7 +319,7 @@ impl ComputeNode for SinkComputeNode {
                     return Ok(());
                 }
 
-                if config::verbose() {
+                if verbose {
                     eprintln!("[{name}]: Last data sent.");
                 }
             }
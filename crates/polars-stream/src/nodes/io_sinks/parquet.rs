@@ -4,7 +4,7 @@ use std::sync::{Arc, Mutex};
 
 use polars_core::prelude::{ArrowSchema, CompatLevel};
 use polars_core::schema::SchemaRef;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::write::BatchedWriter;
 use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
@@ -22,7 +22,7 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use super::metrics::WriteMetrics;
 use super::{
     DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_SINK_LINEARIZER_BUFFER_SIZE, SinkInputPort,
-    SinkNode, buffer_and_distribute_columns_task,
+    SinkNode, buffer_and_distribute_columns_task, num_encode_tasks,
 };
 use crate::async_executor::spawn;
 use crate::async_primitives::connector::{Receiver, connector};
@@ -57,6 +57,11 @@ impl ParquetSinkNode {
         cloud_options: Option<CloudOptions>,
         collect_metrics: bool,
     ) -> PolarsResult<Self> {
+        polars_ensure!(
+            write_options.row_group_bytes.is_none() && write_options.row_group_split.is_none(),
+            InvalidOperation: "`row_group_bytes` and `split_row_groups_on` are not supported \
+            by the streaming engine, use the in-memory engine instead"
+        );
         let schema = schema_to_arrow_checked(&input_schema, CompatLevel::newest(), "parquet")?;
         let column_options: Vec<ColumnWriteOptions> =
             get_column_write_options(&schema, &write_options.field_overwrites);
@@ -105,12 +110,13 @@ impl SinkNode for ParquetSinkNode {
         state: &StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
+        let num_encode_tasks = num_encode_tasks(state);
         // Buffer task -> Encode tasks
         let (dist_tx, dist_rxs) =
-            distributor_channel(state.num_pipelines, *DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE);
+            distributor_channel(num_encode_tasks, *DEFAULT_SINK_DISTRIBUTOR_BUFFER_SIZE);
         // Encode tasks -> Collect task
         let (mut lin_rx, lin_txs) =
-            Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
+            Linearizer::new(num_encode_tasks, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
         let (mut io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
 
@@ -3,7 +3,6 @@ use std::sync::{Arc, OnceLock};
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use polars_core::config;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{Column, PlHashSet, PlIndexMap, row_encode};
 use polars_core::schema::SchemaRef;
@@ -226,7 +225,7 @@ impl SinkNode for PartitionByKeySinkNode {
                 },
             }
 
-            let verbose = config::verbose();
+            let verbose = state.verbose();
             let mut file_idx = 0;
             let mut open_partitions: PlIndexMap<Buffer<u8>, OpenPartition> = PlIndexMap::default();
 
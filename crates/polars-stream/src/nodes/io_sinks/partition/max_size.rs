@@ -2,7 +2,6 @@ use std::sync::{Arc, Mutex};
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use polars_core::config;
 use polars_core::prelude::Column;
 use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_ensure};
@@ -144,7 +143,7 @@ impl SinkNode for MaxSizePartitionSinkNode {
                 node: Box<dyn SinkNode + Send + Sync>,
             }
 
-            let verbose = config::verbose();
+            let verbose = state.verbose();
             let mut file_idx = 0;
             let mut current_sink_opt = None;
 
@@ -2,7 +2,6 @@ use std::sync::{Arc, Mutex};
 
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
-use polars_core::config;
 use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
 use polars_core::prelude::{AnyValue, Column, IntoColumn, PlHashSet};
 use polars_core::schema::SchemaRef;
@@ -155,7 +154,7 @@ impl SinkNode for PartedPartitionSinkNode {
                 node: Box<dyn SinkNode + Send + Sync>,
             }
 
-            let verbose = config::verbose();
+            let verbose = state.verbose();
             let mut file_idx = 0;
             let mut current_sink_opt: Option<CurrentSink> = None;
             let mut lengths = Vec::new();
@@ -127,6 +127,7 @@ impl FileReader for BatchFnReader {
             predicate: None,
             cast_columns_policy: _,
             num_pipelines: _,
+            ideal_morsel_size: _,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -15,7 +15,7 @@ use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
     CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::maybe_decompress_archive_bytes;
 use polars_io::utils::slice::SplitSlicePosition;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
@@ -139,6 +139,7 @@ impl FileReader for CsvFileReader {
             predicate: None,
             cast_columns_policy: _,
             num_pipelines,
+            ideal_morsel_size: _,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -411,7 +412,11 @@ impl CsvFileReader {
     /// Panics if `self.cached_bytes` is None.
     fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
         let mut out = vec![];
-        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
+        maybe_decompress_archive_bytes(
+            self.cached_bytes.as_deref().unwrap(),
+            &mut out,
+            &self.options.get_archive_options(),
+        )?;
 
         if !out.is_empty() {
             self.cached_bytes = Some(MemSlice::from_vec(out));
@@ -606,9 +611,8 @@ impl ChunkReader {
         alt_count_lines: Option<Arc<CountLinesWithComments>>,
     ) -> PolarsResult<Self> {
         let mut fields_to_cast: Vec<Field> = options.fields_to_cast.clone();
-        prepare_csv_schema(&mut reader_schema, &mut fields_to_cast)?;
-
         let parse_options = options.parse_options.clone();
+        prepare_csv_schema(&mut reader_schema, &mut fields_to_cast, &parse_options)?;
 
         // Logic from `CoreReader::new()`
 
@@ -701,7 +705,13 @@ impl ChunkReader {
             df = df.slice(i64::try_from(slice.0).unwrap(), slice.1);
         }
 
-        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        cast_columns(
+            &mut df,
+            &self.fields_to_cast,
+            false,
+            self.ignore_errors,
+            &self.parse_options,
+        )?;
 
         if let Some(ri) = &self.row_index {
             assert!(n_lines_is_correct);
@@ -25,7 +25,7 @@ use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_positio
 use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
 use crate::async_primitives::distributor_channel::distributor_channel;
 use crate::async_primitives::linearizer::Linearizer;
-use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::{
     FileReader, FileReaderCallbacks,
@@ -132,10 +132,10 @@ fn slice_take(slice: &mut Range<usize>, n: usize) -> Range<usize> {
     rng
 }
 
-fn get_max_morsel_size() -> usize {
+fn get_max_morsel_size(ideal_morsel_size: usize) -> usize {
     std::env::var("POLARS_STREAMING_IPC_SOURCE_MAX_MORSEL_SIZE")
         .map_or_else(
-            |_| get_ideal_morsel_size(),
+            |_| ideal_morsel_size,
             |v| {
                 v.parse::<usize>().expect(
                     "POLARS_STREAMING_IPC_SOURCE_MAX_MORSEL_SIZE does not contain valid size",
@@ -202,6 +202,7 @@ impl FileReader for IpcFileReader {
             predicate: None,
             cast_columns_policy: _,
             num_pipelines,
+            ideal_morsel_size,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -310,7 +311,7 @@ impl FileReader for IpcFileReader {
             projection_indices.map(|indices| prepare_projection(&file_metadata.schema, indices));
 
         // Split size for morsels.
-        let max_morsel_size = get_max_morsel_size();
+        let max_morsel_size = get_max_morsel_size(ideal_morsel_size);
 
         let metadata = file_metadata;
 
@@ -479,7 +480,7 @@ impl FileReader for IpcFileReader {
             }
 
             // Batch completion parameters
-            let batch_size_limit = get_ideal_morsel_size();
+            let batch_size_limit = ideal_morsel_size;
             let sliced_batch_size_limit = slice.len().div_ceil(num_pipelines);
             let batch_block_limit = metadata.blocks.len().div_ceil(num_pipelines);
 
@@ -54,6 +54,7 @@ impl DeletionFilesProvider {
                                 (PlSmallStr::from_static("file_path"), DataType::String),
                                 (PlSmallStr::from_static("pos"), DataType::Int64),
                             ]))),
+                            schema_overrides: None,
 
                             parallel: polars_io::prelude::ParallelStrategy::Auto,
                             low_memory: false,
@@ -74,6 +75,7 @@ impl DeletionFilesProvider {
         scan_source_idx: usize,
         cloud_options: Option<Arc<CloudOptions>>,
         num_pipelines: usize,
+        ideal_morsel_size: usize,
         verbose: bool,
     ) -> Option<RowDeletionsInit> {
         match self {
@@ -145,6 +147,7 @@ impl DeletionFilesProvider {
                                     predicate: None,
                                     cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
                                     num_pipelines,
+                                    ideal_morsel_size,
                                     callbacks: FileReaderCallbacks {
                                         file_schema_tx: None,
                                         n_rows_in_file_tx: None,
@@ -10,45 +10,50 @@ impl MultiScanTaskInitializer {
     ///
     /// TODO: Move logic here, rename to `evaluate_on_constant_columns`.
     pub fn initialize_predicate(&self) -> PolarsResult<(Option<Bitmap>, Option<&ScanIOPredicate>)> {
-        if let Some(predicate) = &self.config.predicate {
-            if let Some(hive_parts) = self.config.hive_parts.as_ref() {
-                let mut skip_files_mask = None;
-
-                if let Some(predicate) = &predicate.hive_predicate {
-                    let mask = predicate
-                        .evaluate_io(hive_parts.df())?
-                        .bool()?
-                        .rechunk()
-                        .into_owned()
-                        .downcast_into_iter()
-                        .next()
-                        .unwrap()
-                        .values()
-                        .clone();
-
-                    // TODO: Optimize to avoid doing this
-                    let mask = !&mask;
-
-                    if self.config.verbose {
-                        eprintln!(
-                            "[MultiScan]: Predicate pushdown allows skipping {} / {} files",
-                            mask.set_bits(),
-                            mask.len()
-                        );
-                    }
-
-                    skip_files_mask = Some(mask);
+        let Some(predicate) = &self.config.predicate else {
+            return Ok((None, None));
+        };
+
+        let mut skip_files_mask = None;
+        let mut need_pred_for_inner_readers = true;
+
+        if let Some(hive_parts) = self.config.hive_parts.as_ref() {
+            if let Some(mask) = predicate.skip_files_from_hive(hive_parts.df())? {
+                if self.config.verbose {
+                    eprintln!(
+                        "[MultiScan]: Predicate pushdown allows skipping {} / {} files",
+                        mask.set_bits(),
+                        mask.len()
+                    );
                 }
 
-                let need_pred_for_inner_readers = !predicate.hive_predicate_is_full_predicate;
+                skip_files_mask = Some(mask);
+            }
+
+            need_pred_for_inner_readers = !predicate.hive_predicate_is_full_predicate;
+        }
 
-                return Ok((
-                    skip_files_mask,
-                    need_pred_for_inner_readers.then_some(predicate),
-                ));
+        if let Some(table_statistics) = &self.config.table_statistics
+            && need_pred_for_inner_readers
+            && let Some(mask) = predicate.skip_files_from_statistics(table_statistics.0.as_ref())?
+        {
+            if self.config.verbose {
+                eprintln!(
+                    "[MultiScan]: Table statistics allow skipping {} / {} files",
+                    mask.set_bits(),
+                    mask.len()
+                );
             }
+
+            skip_files_mask = Some(match skip_files_mask {
+                Some(hive_mask) => &hive_mask | &mask,
+                None => mask,
+            });
         }
 
-        Ok((None, self.config.predicate.as_ref()))
+        Ok((
+            skip_files_mask,
+            need_pred_for_inner_readers.then_some(predicate),
+        ))
     }
 }
@@ -89,6 +89,7 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
 
     let deletion_files_provider = DeletionFilesProvider::new(config.deletion_files.clone());
     let num_pipelines = config.num_pipelines();
+    let ideal_morsel_size = config.ideal_morsel_size();
 
     let mut initialized_readers =
         VecDeque::with_capacity(config.sources.len().min(num_pipelines.saturating_add(4)));
@@ -129,6 +130,7 @@ async fn resolve_negative_slice(config: &MultiFileReaderConfig) -> PolarsResult<
                     scan_source_idx,
                     cloud_options,
                     num_pipelines,
+                    ideal_morsel_size,
                     verbose,
                 );
 
@@ -5,6 +5,7 @@ pub mod post_apply_pipeline;
 pub mod reader_interface;
 pub mod reader_pipelines;
 pub mod row_counter;
+pub mod small_files;
 
 use std::sync::atomic::AtomicUsize;
 use std::sync::{Arc, Mutex};
@@ -17,13 +18,17 @@ use polars_io::cloud::CloudOptions;
 use polars_io::predicates::ScanIOPredicate;
 use polars_io::{RowIndex, pl_async};
 use polars_plan::dsl::deletion::DeletionFilesList;
-use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSources};
+use polars_plan::dsl::table_statistics::TableStatistics;
+use polars_plan::dsl::{
+    CastColumnsPolicy, ExtraColumnsPolicy, FileErrorPolicy, MissingColumnsPolicy, ScanSources,
+};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::slice_enum::Slice;
 use reader_interface::builder::FileReaderBuilder;
 use reader_interface::capabilities::ReaderCapabilities;
+use small_files::SmallFileCompaction;
 
 use crate::async_executor::{self, AbortOnDropHandle, TaskPriority};
 use crate::async_primitives::connector;
@@ -57,8 +62,12 @@ pub struct MultiFileReaderConfig {
     pub extra_columns_policy: ExtraColumnsPolicy,
     pub cast_columns_policy: CastColumnsPolicy,
     pub deletion_files: Option<DeletionFilesList>,
+    pub table_statistics: Option<TableStatistics>,
+    pub file_error_policy: FileErrorPolicy,
 
     pub num_pipelines: AtomicUsize,
+    /// Number of rows the readers aim to put in a morsel.
+    pub ideal_morsel_size: AtomicUsize,
     /// Number of readers to initialize concurrently. e.g. Parquet will want to fetch metadata in this
     /// step.
     pub n_readers_pre_init: AtomicUsize,
@@ -73,6 +82,11 @@ impl MultiFileReaderConfig {
             .load(std::sync::atomic::Ordering::Relaxed)
     }
 
+    fn ideal_morsel_size(&self) -> usize {
+        self.ideal_morsel_size
+            .load(std::sync::atomic::Ordering::Relaxed)
+    }
+
     fn n_readers_pre_init(&self) -> usize {
         self.n_readers_pre_init
             .load(std::sync::atomic::Ordering::Relaxed)
@@ -176,12 +190,15 @@ impl ComputeNode for MultiFileReader {
 
         let phase_morsel_tx = send_ports[0].take().unwrap().serial();
         let num_pipelines = state.num_pipelines;
+        let ideal_morsel_size = state.ideal_morsel_size();
+        let scan_readahead = state.config().scan_readahead;
         let verbose = self.verbose;
 
         join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
             use MultiScanState::*;
 
-            self.state.initialize(num_pipelines);
+            self.state
+                .initialize(num_pipelines, ideal_morsel_size, scan_readahead);
             self.state.refresh(verbose).await?;
 
             match &mut self.state {
@@ -277,7 +294,12 @@ impl MultiScanState {
     }
 
     /// Initialize state if not yet initialized.
-    fn initialize(&mut self, num_pipelines: usize) {
+    fn initialize(
+        &mut self,
+        num_pipelines: usize,
+        ideal_morsel_size: usize,
+        scan_readahead: Option<usize>,
+    ) {
         use MultiScanState::*;
 
         let slf = std::mem::replace(self, Finished);
@@ -291,8 +313,12 @@ impl MultiScanState {
             .num_pipelines
             .store(num_pipelines, std::sync::atomic::Ordering::Relaxed);
 
+        config
+            .ideal_morsel_size
+            .store(ideal_morsel_size, std::sync::atomic::Ordering::Relaxed);
+
         config.n_readers_pre_init.store(
-            calc_n_readers_pre_init(num_pipelines, &config),
+            calc_n_readers_pre_init(num_pipelines, scan_readahead, &config),
             std::sync::atomic::Ordering::Relaxed,
         );
 
@@ -315,7 +341,15 @@ impl MultiScanState {
     }
 }
 
-fn calc_n_readers_pre_init(num_pipelines: usize, config: &MultiFileReaderConfig) -> usize {
+fn calc_n_readers_pre_init(
+    num_pipelines: usize,
+    scan_readahead: Option<usize>,
+    config: &MultiFileReaderConfig,
+) -> usize {
+    if let Some(v) = scan_readahead {
+        return v;
+    }
+
     if let Ok(v) = std::env::var("POLARS_NUM_READERS_PRE_INIT").map(|x| {
         x.parse::<usize>()
             .ok()
@@ -331,12 +365,18 @@ fn calc_n_readers_pre_init(num_pipelines: usize, config: &MultiFileReaderConfig)
         Some(Slice::Negative { .. }) | None => usize::MAX,
     };
 
+    // With small-file compaction the whole file is fetched during initialization, so we
+    // initialize as many readers ahead as we will be reading concurrently.
+    let n_ahead = if SmallFileCompaction::new_opt(config).is_some() {
+        SmallFileCompaction::max_concurrent_scans(num_pipelines, config.sources.len())
+    } else {
+        num_pipelines.saturating_add(3).min(128)
+    };
+
     // Set this generously high, there are users who scan 10,000's of small files from the cloud.
-    num_pipelines
-        .saturating_add(3)
+    n_ahead
         .min(max_files_with_slice)
         .min(config.sources.len().max(1))
-        .min(128)
 }
 
 fn calc_max_concurrent_scans(num_pipelines: usize, config: &MultiFileReaderConfig) -> usize {
@@ -349,5 +389,9 @@ fn calc_max_concurrent_scans(num_pipelines: usize, config: &MultiFileReaderConfi
         return v;
     }
 
+    if SmallFileCompaction::new_opt(config).is_some() {
+        return SmallFileCompaction::max_concurrent_scans(num_pipelines, config.sources.len());
+    }
+
     num_pipelines.min(config.sources.len().max(1)).min(128)
 }
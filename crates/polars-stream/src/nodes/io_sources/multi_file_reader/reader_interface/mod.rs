@@ -16,6 +16,7 @@ use polars_utils::slice_enum::Slice;
 
 use crate::async_executor::JoinHandle;
 use crate::async_primitives::connector;
+use crate::morsel::get_ideal_morsel_size;
 
 /// Interface to read a single file
 #[async_trait]
@@ -132,6 +133,8 @@ pub struct BeginReadArgs {
     pub cast_columns_policy: CastColumnsPolicy,
 
     pub num_pipelines: usize,
+    /// Number of rows the reader aims to put in a morsel.
+    pub ideal_morsel_size: usize,
     pub callbacks: FileReaderCallbacks,
     // TODO
     // We could introduce dynamic `Option<Box<dyn Any>>` for the reader to use. That would help
@@ -149,6 +152,7 @@ impl Default for BeginReadArgs {
             // TODO: Use less restrictive default
             cast_columns_policy: CastColumnsPolicy::ERROR_ON_MISMATCH,
             num_pipelines: 1,
+            ideal_morsel_size: get_ideal_morsel_size(),
             callbacks: FileReaderCallbacks::default(),
         }
     }
@@ -8,9 +8,11 @@ use polars_core::prelude::{AnyValue, DataType, PlHashMap};
 use polars_core::scalar::Scalar;
 use polars_core::schema::SchemaRef;
 use polars_error::PolarsResult;
-use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy, ScanSource};
+use polars_io::{RowIndex, pl_async};
+use polars_plan::dsl::{
+    CastColumnsPolicy, ExtraColumnsPolicy, FileErrorPolicy, MissingColumnsPolicy, ScanSource,
+};
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_utils::IdxSize;
 use polars_utils::slice_enum::Slice;
@@ -37,6 +39,7 @@ use crate::nodes::io_sources::multi_file_reader::reader_interface::{
     BeginReadArgs, FileReader, FileReaderCallbacks,
 };
 use crate::nodes::io_sources::multi_file_reader::row_counter::RowCounter;
+use crate::nodes::io_sources::multi_file_reader::small_files::SmallFileCompaction;
 
 impl MultiScanTaskInitializer {
     /// Generic reader pipeline that should work for all file types and configurations
@@ -48,6 +51,7 @@ impl MultiScanTaskInitializer {
     ) -> PolarsResult<JoinHandle<PolarsResult<()>>> {
         let verbose = self.config.verbose;
         let num_pipelines = self.config.num_pipelines();
+        let ideal_morsel_size = self.config.ideal_morsel_size();
         let reader_capabilities = self.config.reader_capabilities();
 
         // Row index should only be pushed if we have a predicate or negative slice as there is a
@@ -140,6 +144,16 @@ impl MultiScanTaskInitializer {
         let has_row_index_or_slice = extra_ops.has_row_index_or_slice();
 
         let config = self.config.clone();
+        let small_file_compaction = SmallFileCompaction::new_opt(&self.config);
+
+        if verbose {
+            if let Some(small_file_compaction) = &small_file_compaction {
+                eprintln!(
+                    "[MultiScanTaskInitializer]: Small-file compaction: max_file_size: {}",
+                    small_file_compaction.max_file_size
+                )
+            }
+        }
 
         // Buffered initialization stream. This concurrently calls `FileReader::initialize()`,
         // allowing for e.g. concurrent Parquet metadata fetch.
@@ -200,12 +214,13 @@ impl MultiScanTaskInitializer {
                     let sources = config.sources.clone();
                     let deletion_files_provider = deletion_files_provider.clone();
                     let initialized_row_deletions = initialized_row_deletions.clone();
+                    let file_error_policy = config.file_error_policy.clone();
 
                     let maybe_initialized = initialized_readers.pop_front();
                     let scan_source = sources.get(scan_source_idx).unwrap().into_owned();
 
                     AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
-                        let (scan_source, reader, n_rows_in_file) = async {
+                        let Some((scan_source, reader, n_rows_in_file, is_compacted)) = async {
                             if verbose {
                                 eprintln!("[MultiScan]: Initialize source {scan_source_idx}");
                             }
@@ -213,28 +228,98 @@ impl MultiScanTaskInitializer {
                             let scan_source = scan_source?;
 
                             if let Some((reader, n_rows_in_file)) = maybe_initialized {
-                                return PolarsResult::Ok((
+                                return PolarsResult::Ok(Some((
                                     scan_source,
                                     reader,
                                     Some(n_rows_in_file),
-                                ));
+                                    false,
+                                )));
                             }
 
-                            let mut reader = file_reader_builder.build_file_reader(
-                                scan_source.clone(),
-                                cloud_options.clone(),
-                                scan_source_idx,
-                            );
-
-                            reader.initialize().await?;
-                            let opt_n_rows = reader
-                                .fast_n_rows_in_file()
-                                .await?
-                                .map(|num_phys_rows| RowCounter::new(num_phys_rows, 0));
-
-                            PolarsResult::Ok((scan_source, reader, opt_n_rows))
+                            let mut n_retries = 0;
+
+                            loop {
+                                let result = async {
+                                    let compacted_source = match &small_file_compaction {
+                                        Some(v) => {
+                                            v.try_fetch_full(&scan_source, cloud_options.as_deref())
+                                                .await?
+                                        },
+                                        None => None,
+                                    };
+
+                                    let is_compacted = compacted_source.is_some();
+
+                                    if verbose && is_compacted {
+                                        eprintln!(
+                                            "[MultiScan]: Fetched source {scan_source_idx} in full"
+                                        );
+                                    }
+
+                                    let mut reader = file_reader_builder.build_file_reader(
+                                        compacted_source.unwrap_or_else(|| scan_source.clone()),
+                                        cloud_options.clone(),
+                                        scan_source_idx,
+                                    );
+
+                                    reader.initialize().await?;
+                                    let opt_n_rows = reader
+                                        .fast_n_rows_in_file()
+                                        .await?
+                                        .map(|num_phys_rows| RowCounter::new(num_phys_rows, 0));
+
+                                    PolarsResult::Ok((reader, opt_n_rows, is_compacted))
+                                }
+                                .await;
+
+                                match result {
+                                    Ok((reader, opt_n_rows, is_compacted)) => {
+                                        return Ok(Some((
+                                            scan_source,
+                                            reader,
+                                            opt_n_rows,
+                                            is_compacted,
+                                        )));
+                                    },
+                                    Err(e) if n_retries < file_error_policy.retries => {
+                                        n_retries += 1;
+                                        let backoff = FileErrorPolicy::backoff(n_retries);
+
+                                        if verbose {
+                                            eprintln!(
+                                                "[MultiScan]: Retry source {scan_source_idx} \
+                                                ({n_retries} / {}) in {backoff:?}: {e}",
+                                                file_error_policy.retries
+                                            );
+                                        }
+
+                                        // The timer needs the tokio runtime.
+                                        let _ = pl_async::get_runtime()
+                                            .spawn(tokio::time::sleep(backoff))
+                                            .await;
+                                    },
+                                    Err(e) if file_error_policy.skip => {
+                                        if verbose {
+                                            eprintln!(
+                                                "[MultiScan]: Skip source {scan_source_idx}: {e}"
+                                            );
+                                        }
+
+                                        let path = scan_source
+                                            .as_scan_source_ref()
+                                            .to_include_path_name()
+                                            .into();
+                                        file_error_policy.skipped.push(path, e);
+                                        return Ok(None);
+                                    },
+                                    Err(e) => return Err(e),
+                                }
+                            }
                         }
-                        .await?;
+                        .await?
+                        else {
+                            return Ok(None);
+                        };
 
                         let row_deletions: Option<RowDeletionsInit> = initialized_row_deletions
                             .get(&scan_source_idx)
@@ -244,17 +329,19 @@ impl MultiScanTaskInitializer {
                                     scan_source_idx,
                                     cloud_options,
                                     num_pipelines,
+                                    ideal_morsel_size,
                                     verbose,
                                 )
                             });
 
-                        Ok(InitializedReaderState {
+                        Ok(Some(InitializedReaderState {
                             scan_source_idx,
                             scan_source,
                             reader,
                             n_rows_in_file,
                             row_deletions,
-                        })
+                            is_compacted,
+                        }))
                     }))
                 })
                 .buffered(
@@ -262,6 +349,8 @@ impl MultiScanTaskInitializer {
                         .n_readers_pre_init()
                         .min(self.config.sources.len()),
                 )
+                // Skipped files don't have a reader.
+                .filter_map(|x| std::future::ready(x.transpose()))
         };
 
         let sources = self.config.sources.clone();
@@ -296,6 +385,7 @@ impl MultiScanTaskInitializer {
                     verbose,
                 },
                 num_pipelines,
+                ideal_morsel_size,
                 verbose,
             }
             .run(),
@@ -335,6 +425,7 @@ struct ReaderStarter {
     extra_ops: ExtraOperations,
     constant_args: StartReaderArgsConstant,
     num_pipelines: usize,
+    ideal_morsel_size: usize,
     verbose: bool,
 }
 
@@ -350,6 +441,7 @@ impl ReaderStarter {
             extra_ops,
             constant_args,
             num_pipelines,
+            ideal_morsel_size,
             verbose,
         } = self;
 
@@ -399,6 +491,7 @@ impl ReaderStarter {
                 mut reader,
                 mut n_rows_in_file,
                 row_deletions,
+                is_compacted,
             }) = readers_init_iter.next().await.transpose()?
             else {
                 if verbose {
@@ -644,7 +737,10 @@ impl ReaderStarter {
                 pre_slice,
                 predicate,
                 cast_columns_policy: extra_ops_post.cast_columns_policy.clone(),
-                num_pipelines,
+                // Compacted files are small, decode them on a single pipeline and instead read
+                // more files concurrently.
+                num_pipelines: if is_compacted { 1 } else { num_pipelines },
+                ideal_morsel_size,
                 callbacks,
             };
 
@@ -733,6 +829,8 @@ struct InitializedReaderState {
     reader: Box<dyn FileReader>,
     n_rows_in_file: Option<RowCounter>,
     row_deletions: Option<RowDeletionsInit>,
+    /// The full file was fetched into memory by small-file compaction.
+    is_compacted: bool,
 }
 
 /// Constant over the file list.
@@ -0,0 +1,90 @@
+//! Small-file compaction reader mode.
+//!
+//! When a scan spans many tiny cloud files, the per-file overhead (separate metadata / footer and
+//! column range requests, and a reader that spreads a few rows over all pipelines) dominates the
+//! scan. In this mode, files at or below a size threshold are fetched with a single range request
+//! covering the whole file, and are decoded by one pipeline each, with more files being decoded
+//! concurrently instead.
+
+use polars_error::PolarsResult;
+use polars_io::cloud::CloudOptions;
+use polars_io::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+use polars_plan::dsl::ScanSource;
+
+use super::MultiFileReaderConfig;
+
+/// Files up to this size are fetched in full.
+const DEFAULT_MAX_FILE_SIZE: usize = 1024 * 1024;
+/// Minimum number of sources before the mode is enabled by default.
+const DEFAULT_MIN_SOURCES: usize = 64;
+
+#[derive(Debug, Clone, Copy)]
+pub struct SmallFileCompaction {
+    /// Files at or below this size (in bytes) are fetched in a single request.
+    pub max_file_size: usize,
+}
+
+impl SmallFileCompaction {
+    /// Returns `Some` if small-file compaction should be used for this scan.
+    ///
+    /// This can be configured with `POLARS_SMALL_FILE_COMPACTION_THRESHOLD` (in bytes), where `0`
+    /// disables the mode. If unset, the mode is used for cloud scans of at least
+    /// [`DEFAULT_MIN_SOURCES`] files.
+    pub fn new_opt(config: &MultiFileReaderConfig) -> Option<Self> {
+        if !config.sources.is_cloud_url() {
+            return None;
+        }
+
+        if let Ok(v) = std::env::var("POLARS_SMALL_FILE_COMPACTION_THRESHOLD") {
+            let max_file_size = v.parse::<usize>().unwrap_or_else(|_| {
+                panic!("invalid value for POLARS_SMALL_FILE_COMPACTION_THRESHOLD: {v}")
+            });
+
+            return (max_file_size > 0).then_some(Self { max_file_size });
+        }
+
+        (config.sources.len() >= DEFAULT_MIN_SOURCES).then_some(Self {
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+        })
+    }
+
+    /// Fetch the full contents of `scan_source` if it is small enough. Returns `None` if the
+    /// source is not a cloud path or is larger than the threshold, in which case the source
+    /// should be read as normal.
+    pub async fn try_fetch_full(
+        &self,
+        scan_source: &ScanSource,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Option<ScanSource>> {
+        let ScanSource::Path(path) = scan_source else {
+            return Ok(None);
+        };
+
+        if !path.is_cloud_url() {
+            return Ok(None);
+        }
+
+        let byte_source = DynByteSourceBuilder::ObjectStore
+            .try_build_from_path(path.to_str(), cloud_options)
+            .await?;
+
+        let size = byte_source.get_size().await?;
+
+        if size > self.max_file_size {
+            return Ok(None);
+        }
+
+        let bytes = byte_source.get_range(0..size).await?;
+
+        Ok(Some(ScanSource::Buffer(bytes)))
+    }
+
+    /// Concurrency for reading small files. As every file is decoded by a single pipeline, we
+    /// allow for more files to be in flight than there are pipelines.
+    pub fn max_concurrent_scans(num_pipelines: usize, n_sources: usize) -> usize {
+        num_pipelines
+            .saturating_mul(4)
+            .min(n_sources.max(1))
+            .min(512)
+    }
+}
@@ -17,7 +17,13 @@ impl FileReaderBuilder for Arc<polars_plan::dsl::NDJsonReadOptions> {
     fn reader_capabilities(&self) -> ReaderCapabilities {
         use ReaderCapabilities as RC;
 
-        RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        // The reader applies the row index and slice to the lines of the file, which would count
+        // the rows that don't match the JSON Schema.
+        if self.json_schema.is_some() {
+            RC::empty()
+        } else {
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
     }
 
     fn build_file_reader(
@@ -1,6 +1,10 @@
+use std::borrow::Cow;
+use std::path::PathBuf;
+
 use polars_core::schema::SchemaRef;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_io::ndjson;
+use polars_io::ndjson::json_schema::{JsonSchemaValidator, append_rejected_lines};
 use polars_io::prelude::parse_ndjson;
 use polars_plan::dsl::NDJsonReadOptions;
 
@@ -11,6 +15,8 @@ use crate::nodes::compute_node_prelude::*;
 pub(super) struct ChunkReader {
     projected_schema: SchemaRef,
     ignore_errors: bool,
+    json_schema: Option<JsonSchemaValidator>,
+    json_schema_rejects: Option<PathBuf>,
 }
 
 impl ChunkReader {
@@ -19,18 +25,62 @@ impl ChunkReader {
         projected_schema: &SchemaRef,
     ) -> PolarsResult<Self> {
         let projected_schema = projected_schema.clone();
+        let json_schema = options
+            .json_schema
+            .as_deref()
+            .map(JsonSchemaValidator::try_new)
+            .transpose()?;
+        let json_schema_rejects = options
+            .json_schema_rejects
+            .as_ref()
+            .map(|path| {
+                polars_ensure!(
+                    path.is_local(),
+                    ComputeError: "the rejected rows of a JSON Schema can only be written to a local file"
+                );
+                Ok(path.as_ref().as_local_path().unwrap().to_path_buf())
+            })
+            .transpose()?;
 
         Ok(Self {
             projected_schema,
             ignore_errors: options.ignore_errors,
+            json_schema,
+            json_schema_rejects,
         })
     }
 
+    /// The lines of `chunk` that match the JSON Schema, if one is set. The other lines are
+    /// appended to the rejects file, or raise an error.
+    fn valid_lines<'a>(&self, chunk: &'a [u8]) -> PolarsResult<Cow<'a, [u8]>> {
+        let Some(json_schema) = &self.json_schema else {
+            return Ok(Cow::Borrowed(chunk));
+        };
+        let rejects = self.json_schema_rejects.as_deref();
+        let (valid, rejected) = json_schema.split_lines(chunk, rejects.is_some())?;
+        if let Some(path) = rejects {
+            append_rejected_lines(path, &rejected)?;
+        }
+        Ok(valid)
+    }
+
     pub(super) fn read_chunk(&self, chunk: &[u8]) -> PolarsResult<DataFrame> {
+        let chunk = self.valid_lines(chunk)?;
         if self.projected_schema.is_empty() {
-            Ok(DataFrame::empty_with_height(ndjson::count_rows(chunk)))
+            Ok(DataFrame::empty_with_height(ndjson::count_rows(&chunk)))
         } else {
-            parse_ndjson(chunk, None, &self.projected_schema, self.ignore_errors)
+            parse_ndjson(&chunk, None, &self.projected_schema, self.ignore_errors)
+        }
+    }
+
+    /// Count the rows of `chunk`, without the rows that don't match the JSON Schema.
+    pub(super) fn count_rows(&self, chunk: &[u8]) -> PolarsResult<usize> {
+        match &self.json_schema {
+            None => Ok(ndjson::count_rows(chunk)),
+            Some(json_schema) => {
+                let (valid, _) = json_schema.split_lines(chunk, true)?;
+                Ok(ndjson::count_rows(&valid))
+            },
         }
     }
 }
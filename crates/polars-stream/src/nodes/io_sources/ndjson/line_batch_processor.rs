@@ -2,7 +2,6 @@ use std::cmp::Reverse;
 use std::sync::Arc;
 
 use polars_error::PolarsResult;
-use polars_io::ndjson;
 use polars_utils::mmap::MemSlice;
 use polars_utils::priority::Priority;
 
@@ -81,7 +80,7 @@ impl LineBatchProcessor {
                 chunk_idx: _,
             }) = line_batch_rx.recv().await
             {
-                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(bytes));
+                n_rows_processed = n_rows_processed.saturating_add(chunk_reader.count_rows(bytes)?);
             }
         }
 
@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::maybe_decompress_archive_bytes;
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -65,6 +65,7 @@ impl FileReader for NDJsonFileReader {
             pre_slice,
 
             num_pipelines,
+            ideal_morsel_size,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -229,6 +230,7 @@ impl FileReader for NDJsonFileReader {
                     // The correct row index offset can only be known after total row count is
                     // available. This is handled by the MorselStreamReverser.
                     row_index: row_index.take().map(|x| (x, total_row_count_rx.unwrap())),
+                    ideal_morsel_size,
                     verbose,
                 }
                 .run(),
@@ -448,7 +450,11 @@ impl NDJsonFileReader {
 
             let memslice = {
                 let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
+                maybe_decompress_archive_bytes(
+                    &source,
+                    &mut out,
+                    &self.options.get_archive_options(),
+                )?;
 
                 if out.is_empty() {
                     source
@@ -12,7 +12,7 @@ use polars_utils::priority::Priority;
 use crate::async_executor;
 use crate::async_executor::AbortOnDropHandle;
 use crate::async_primitives::linearizer::Linearizer;
-use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
 
 /// Outputs a stream of morsels in reverse order from which they were received.
@@ -27,6 +27,8 @@ pub struct MorselStreamReverser {
     /// Slice from right to left.
     pub offset_len_rtl: (usize, usize),
     pub row_index: Option<(RowIndex, tokio::sync::oneshot::Receiver<usize>)>,
+    /// Number of rows to put in an output morsel.
+    pub ideal_morsel_size: usize,
     pub verbose: bool,
 }
 
@@ -37,6 +39,7 @@ impl MorselStreamReverser {
             morsel_senders,
             offset_len_rtl,
             row_index,
+            ideal_morsel_size,
             verbose,
         } = self;
 
@@ -141,7 +144,7 @@ impl MorselStreamReverser {
         };
 
         let combined_df = Arc::new(combined_df);
-        let chunk_size = get_ideal_morsel_size();
+        let chunk_size = ideal_morsel_size;
         let n_chunks = combined_df.height().div_ceil(chunk_size);
         let num_pipelines = morsel_senders.len();
         let n_tasks = num_pipelines.min(n_chunks);
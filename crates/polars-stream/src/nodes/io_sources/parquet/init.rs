@@ -20,7 +20,7 @@ use super::row_group_data_fetch::RowGroupDataFetcher;
 use super::row_group_decode::RowGroupDecoder;
 use super::{AsyncTaskData, ParquetReadImpl};
 use crate::async_executor;
-use crate::morsel::{Morsel, SourceToken, get_ideal_morsel_size};
+use crate::morsel::{Morsel, SourceToken};
 use crate::nodes::io_sources::multi_file_reader::extra_ops::cast_columns::CastColumns;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::io_sources::parquet::PredicateApplyMode;
@@ -36,6 +36,7 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
     reader_schema: &ArrowSchemaRef,
     mut row_index: Option<RowIndex>,
     cast_columns: Option<(CastColumns, SchemaRef)>,
+    schema_overrides: Option<SchemaRef>,
     verbose: bool,
 ) -> PolarsResult<Option<Bitmap>> {
     if !use_statistics {
@@ -97,9 +98,15 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
             let max_name = format_pl_smallstr!("{c}_max");
             let nc_name = format_pl_smallstr!("{c}_nc");
 
-            let (min, max, nc) = match stat {
+            // The statistics are in the data type of the file, which may not be ordered the same
+            // way as the dtype that the column is read as.
+            let dtype_override = schema_overrides.as_ref().and_then(|schema| schema.get(c));
+
+            let (min, max, nc) = match stat.filter(|_| dtype_override.is_none()) {
                 None => {
-                    let dtype = DataType::from_arrow_field(field.as_ref());
+                    let dtype = dtype_override
+                        .cloned()
+                        .unwrap_or_else(|| DataType::from_arrow_field(field.as_ref()));
 
                     (
                         Column::full_null(min_name, num_row_groups, &dtype),
@@ -190,7 +197,7 @@ impl ParquetReadImpl {
         let row_group_decoder = self.init_row_group_decoder();
         let row_group_decoder = Arc::new(row_group_decoder);
 
-        let ideal_morsel_size = get_ideal_morsel_size();
+        let ideal_morsel_size = self.config.ideal_morsel_size;
 
         if verbose {
             eprintln!("[ParquetFileReader]: ideal_morsel_size: {ideal_morsel_size}");
@@ -206,6 +213,7 @@ impl ParquetReadImpl {
 
         let row_index = self.row_index.clone();
         let live_filter_columns_cast = self.live_filter_columns_cast.take();
+        let schema_overrides = self.options.schema_overrides.clone();
 
         let prefetch_task = AbortOnDropHandle(io_runtime.spawn(async move {
             polars_ensure!(
@@ -270,6 +278,7 @@ impl ParquetReadImpl {
                 &reader_schema,
                 row_index,
                 live_filter_columns_cast,
+                schema_overrides,
                 verbose,
             )
             .await?;
@@ -438,6 +447,7 @@ impl ParquetReadImpl {
             predicate_arrow_field_indices,
             non_predicate_arrow_field_indices,
             min_values_per_thread,
+            schema_overrides: self.options.schema_overrides.clone(),
         }
     }
 }
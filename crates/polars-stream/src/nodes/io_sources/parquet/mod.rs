@@ -136,6 +136,7 @@ impl FileReader for ParquetFileReader {
             mut predicate,
             cast_columns_policy,
             num_pipelines,
+            ideal_morsel_size,
             callbacks:
                 FileReaderCallbacks {
                     file_schema_tx,
@@ -264,6 +265,7 @@ impl FileReader for ParquetFileReader {
             metadata: file_metadata.clone(),
             config: io_sources::parquet::Config {
                 num_pipelines,
+                ideal_morsel_size,
                 row_group_prefetch_size,
                 min_values_per_thread,
             },
@@ -311,7 +313,18 @@ impl ParquetFileReader {
         } = self.init_data.as_mut().unwrap();
 
         if file_schema_pl.is_none() {
-            *file_schema_pl = Some(Arc::new(Schema::from_arrow_schema(file_schema.as_ref())))
+            let mut schema = Schema::from_arrow_schema(file_schema.as_ref());
+
+            // The overridden columns are cast while they are decoded.
+            if let Some(schema_overrides) = &self.config.schema_overrides {
+                for (name, dtype) in schema_overrides.iter() {
+                    if let Some(file_dtype) = schema.get_mut(name) {
+                        *file_dtype = dtype.clone();
+                    }
+                }
+            }
+
+            *file_schema_pl = Some(Arc::new(schema))
         }
 
         file_schema_pl.clone().unwrap()
@@ -355,6 +368,8 @@ struct ParquetReadImpl {
 #[derive(Debug)]
 struct Config {
     num_pipelines: usize,
+    /// Number of rows to put in a morsel.
+    ideal_morsel_size: usize,
     /// Number of row groups to pre-fetch concurrently, this can be across files
     row_group_prefetch_size: usize,
     /// Minimum number of values for a parallel spawned task to process to amortize
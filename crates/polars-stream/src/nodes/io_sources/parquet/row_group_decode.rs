@@ -1,10 +1,12 @@
 use std::ops::Deref;
 use std::sync::Arc;
 
+use polars_core::chunked_array::cast::CastOptions;
 use polars_core::frame::DataFrame;
 use polars_core::prelude::{
     ArrowField, ArrowSchema, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn,
 };
+use polars_core::schema::{Schema, SchemaRef};
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
@@ -34,6 +36,8 @@ pub(super) struct RowGroupDecoder {
     /// Indices into `projected_arrow_schema. This must be sorted.
     pub(super) non_predicate_arrow_field_indices: Arc<Vec<usize>>,
     pub(super) min_values_per_thread: usize,
+    /// Data types that columns are cast to directly after decoding.
+    pub(super) schema_overrides: Option<SchemaRef>,
 }
 
 impl RowGroupDecoder {
@@ -168,6 +172,7 @@ impl RowGroupDecoder {
                     row_group_data,
                     filter.clone(),
                     expected_num_rows,
+                    self.schema_overrides.as_deref(),
                 )
             }) {
                 out_vec.push(s?.0)
@@ -182,6 +187,7 @@ impl RowGroupDecoder {
         let task_handles = {
             let projected_arrow_schema = projected_arrow_schema.clone();
             let filter = filter.clone();
+            let schema_overrides = self.schema_overrides.clone();
 
             parallelize_first_to_local(
                 (0..projected_arrow_schema.len())
@@ -190,6 +196,7 @@ impl RowGroupDecoder {
                         let row_group_data = row_group_data_2.clone();
                         let projected_arrow_schema = projected_arrow_schema.clone();
                         let filter = filter.clone();
+                        let schema_overrides = schema_overrides.clone();
 
                         async move {
                             // This is exact as we have already taken out the remainder.
@@ -206,6 +213,7 @@ impl RowGroupDecoder {
                                         &row_group_data,
                                         filter.clone(),
                                         expected_num_rows,
+                                        schema_overrides.as_deref(),
                                     )
                                 })
                                 .collect::<PolarsResult<Vec<_>>>()
@@ -222,22 +230,34 @@ impl RowGroupDecoder {
     }
 }
 
+/// Cast a decoded column to its data type in `schema_overrides`, if it has one.
+fn cast_to_override(column: Column, schema_overrides: Option<&Schema>) -> PolarsResult<Column> {
+    match schema_overrides.and_then(|schema| schema.get(column.name())) {
+        Some(dtype) if dtype != column.dtype() => {
+            column.cast_with_options(dtype, CastOptions::Strict)
+        },
+        _ => Ok(column),
+    }
+}
+
 fn decode_column(
     arrow_field: &ArrowField,
     row_group_data: &RowGroupData,
     filter: Option<polars_parquet::read::Filter>,
     expected_num_rows: usize,
+    schema_overrides: Option<&Schema>,
 ) -> PolarsResult<(Column, Bitmap)> {
     let Some(iter) = row_group_data
         .row_group_metadata
         .columns_under_root_iter(&arrow_field.name)
     else {
+        let column = Column::full_null(
+            arrow_field.name.clone(),
+            expected_num_rows,
+            &DataType::from_arrow_field(arrow_field),
+        );
         return Ok((
-            Column::full_null(
-                arrow_field.name.clone(),
-                expected_num_rows,
-                &DataType::from_arrow_field(arrow_field),
-            ),
+            cast_to_override(column, schema_overrides)?,
             Bitmap::default(),
         ));
     };
@@ -280,7 +300,10 @@ fn decode_column(
 
     // TODO: Also load in the metadata.
 
-    Ok((series.into_column(), pred_true_mask))
+    Ok((
+        cast_to_override(series.into_column(), schema_overrides)?,
+        pred_true_mask,
+    ))
 }
 
 /// # Safety
@@ -359,6 +382,7 @@ fn decode_column_in_filter(
     column_predicates: &ColumnPredicates,
     row_group_data: &RowGroupData,
     projection_height: usize,
+    schema_overrides: Option<&Schema>,
 ) -> PolarsResult<(Column, Bitmap)> {
     let mut filter = None;
     let mut constant = None;
@@ -383,7 +407,13 @@ fn decode_column_in_filter(
             }));
         }
     }
-    let (mut c, m) = decode_column(arrow_field, row_group_data, filter, projection_height)?;
+    let (mut c, m) = decode_column(
+        arrow_field,
+        row_group_data,
+        filter,
+        projection_height,
+        schema_overrides,
+    )?;
 
     if let Some(constant) = constant {
         c = Column::new_scalar(c.name().clone(), constant.clone(), m.set_bits());
@@ -438,7 +468,14 @@ impl RowGroupDecoder {
                 .predicate_arrow_field_indices
                 .iter()
                 .map(|&i| self.projected_arrow_schema.get_at_index(i).unwrap())
-                .all(|(_, arrow_field)| !arrow_field.dtype().is_nested());
+                // Column predicates are evaluated on the data type of the file.
+                .all(|(_, arrow_field)| {
+                    !arrow_field.dtype().is_nested()
+                        && !self
+                            .schema_overrides
+                            .as_ref()
+                            .is_some_and(|schema| schema.contains(&arrow_field.name))
+                });
 
         let cols_per_thread = (self
             .predicate_arrow_field_indices
@@ -449,6 +486,7 @@ impl RowGroupDecoder {
             let predicate_arrow_field_indices = self.predicate_arrow_field_indices.clone();
             let projected_arrow_schema = self.projected_arrow_schema.clone();
             let row_group_data = row_group_data.clone();
+            let schema_overrides = self.schema_overrides.clone();
 
             parallelize_first_to_local(
                 (0..self.predicate_arrow_field_indices.len())
@@ -458,6 +496,7 @@ impl RowGroupDecoder {
                         let predicate_arrow_field_indices = predicate_arrow_field_indices.clone();
                         let projected_arrow_schema = projected_arrow_schema.clone();
                         let column_predicates = scan_predicate.column_predicates.clone();
+                        let schema_overrides = schema_overrides.clone();
 
                         async move {
                             (offset
@@ -475,6 +514,7 @@ impl RowGroupDecoder {
                                         column_predicates.as_ref(),
                                         row_group_data.as_ref(),
                                         projection_height,
+                                        schema_overrides.as_deref(),
                                     )
                                 })
                                 .collect::<PolarsResult<Vec<_>>>()
@@ -584,6 +624,7 @@ impl RowGroupDecoder {
             let projected_arrow_schema = self.projected_arrow_schema.clone();
             let row_group_data = row_group_data.clone();
             let prefilter_setting = *prefilter_setting;
+            let schema_overrides = self.schema_overrides.clone();
 
             parallelize_first_to_local((0..non_predicate_len).step_by(cols_per_thread).map(
                 move |offset| {
@@ -593,6 +634,7 @@ impl RowGroupDecoder {
                     let projected_arrow_schema = projected_arrow_schema.clone();
                     let mask = mask.clone();
                     let mask_bitmap = mask_bitmap.clone();
+                    let schema_overrides = schema_overrides.clone();
                     let max_col = offset
                         .saturating_add(cols_per_thread)
                         .min(non_predicate_len);
@@ -612,6 +654,7 @@ impl RowGroupDecoder {
                                     &mask,
                                     &mask_bitmap,
                                     expected_num_rows,
+                                    schema_overrides.as_deref(),
                                 )
                             })
                             .collect::<PolarsResult<Vec<_>>>()
@@ -642,16 +685,18 @@ fn decode_column_prefiltered(
     mask: &BooleanChunked,
     mask_bitmap: &Bitmap,
     expected_num_rows: usize,
+    schema_overrides: Option<&Schema>,
 ) -> PolarsResult<Column> {
     let Some(iter) = row_group_data
         .row_group_metadata
         .columns_under_root_iter(&arrow_field.name)
     else {
-        return Ok(Column::full_null(
+        let column = Column::full_null(
             arrow_field.name.clone(),
             expected_num_rows,
             &DataType::from_arrow_field(arrow_field),
-        ));
+        );
+        return cast_to_override(column, schema_overrides);
     };
 
     let columns_to_deserialize = iter
@@ -697,7 +742,7 @@ fn decode_column_prefiltered(
 
     assert_eq!(series.len(), expected_num_rows);
 
-    Ok(series.into_column())
+    cast_to_override(series.into_column(), schema_overrides)
 }
 
 mod tests {
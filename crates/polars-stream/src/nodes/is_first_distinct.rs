@@ -0,0 +1,83 @@
+use polars_core::prelude::row_encode::_get_rows_encoded_ca_unordered;
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compute_node_prelude::*;
+
+/// A node that computes whether each row of its input is the first occurrence of that row,
+/// as a single boolean column. The rows that were seen are kept in their row encoding.
+pub struct IsFirstDistinctNode {
+    name: PlSmallStr,
+    seen: PlHashSet<Vec<u8>>,
+}
+
+impl IsFirstDistinctNode {
+    pub fn new(name: PlSmallStr) -> Self {
+        Self {
+            name,
+            seen: PlHashSet::new(),
+        }
+    }
+
+    fn mask(&mut self, df: DataFrame) -> PolarsResult<DataFrame> {
+        let height = df.height();
+        let rows = _get_rows_encoded_ca_unordered(self.name.clone(), df.get_columns())?;
+        let mask = BooleanChunked::from_iter_values(
+            self.name.clone(),
+            rows.downcast_iter()
+                .flat_map(|arr| arr.values_iter())
+                .map(|row| {
+                    if self.seen.contains(row) {
+                        false
+                    } else {
+                        self.seen.insert(row.to_vec());
+                        true
+                    }
+                }),
+        );
+        DataFrame::new_with_height(height, vec![mask.into_column()])
+    }
+}
+
+impl ComputeNode for IsFirstDistinctNode {
+    fn name(&self) -> &str {
+        "is-first-distinct"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+        recv.swap_with_slice(send);
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        _state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        let mut recv = recv_ports[0].take().unwrap().serial();
+        let mut send = send_ports[0].take().unwrap().serial();
+
+        // Whether a row is the first occurrence depends on all rows before it, so the morsels
+        // are processed in order.
+        join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+            while let Ok(morsel) = recv.recv().await {
+                let morsel = morsel.try_map(|df| self.mask(df))?;
+                if send.send(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            Ok(())
+        }));
+    }
+}
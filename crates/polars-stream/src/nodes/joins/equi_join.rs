@@ -4,10 +4,10 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use polars_core::POOL;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
-use polars_core::{POOL, config};
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::idx_table::{IdxTable, new_idx_table};
 use polars_io::pl_async::get_runtime;
@@ -22,13 +22,14 @@ use polars_utils::relaxed_cell::RelaxedCell;
 use polars_utils::sparse_init_vec::SparseInitVec;
 use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
+use tokio::sync::{Semaphore, SemaphorePermit};
 
 use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
 use crate::async_executor;
 use crate::async_primitives::connector::{Receiver, Sender};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
-use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::morsel::SourceToken;
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
 
@@ -304,7 +305,7 @@ impl SampleState {
             return Ok(None);
         }
 
-        if config::verbose() {
+        if state.verbose() {
             eprintln!(
                 "choosing build side, sample lengths are: {} vs. {}",
                 self.left_len, self.right_len
@@ -324,7 +325,7 @@ impl SampleState {
                 params,
                 &state.in_memory_exec_state,
             )?;
-            if config::verbose() {
+            if state.verbose() {
                 eprintln!(
                     "estimated cardinalities are: {left_cardinality} vs. {right_cardinality}"
                 );
@@ -349,7 +350,7 @@ impl SampleState {
             },
         };
 
-        if config::verbose() {
+        if state.verbose() {
             eprintln!(
                 "build side chosen: {}",
                 if left_is_build { "left" } else { "right" }
@@ -588,6 +589,7 @@ impl BuildState {
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            probe_permits: None,
         }
     }
 
@@ -722,6 +724,7 @@ impl BuildState {
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            probe_permits: None,
         }
     }
 }
@@ -739,6 +742,18 @@ struct ProbeState {
 
     // For unordered joins we relabel output morsels to speed up the linearizer.
     unordered_morsel_seq: AtomicU64,
+
+    // Limits the number of probe tasks computing at the same time, if configured.
+    probe_permits: Option<Semaphore>,
+}
+
+/// Waits until the task may probe, if the number of probing tasks is limited. The permit must be
+/// released while sending, as the receiver may wait for a morsel of a task without a permit.
+async fn acquire_probe_permit(permits: Option<&Semaphore>) -> Option<SemaphorePermit<'_>> {
+    match permits {
+        Some(permits) => Some(permits.acquire().await.unwrap()),
+        None => None,
+    }
 }
 
 impl ProbeState {
@@ -748,6 +763,7 @@ impl ProbeState {
         mut send: Sender<Morsel>,
         partitions: &[ProbeTable],
         unordered_morsel_seq: &AtomicU64,
+        probe_permits: Option<&Semaphore>,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
         state: &StreamingExecutionState,
@@ -760,7 +776,7 @@ impl ProbeState {
         let mut probe_match = Vec::new();
         let mut max_seq = MorselSeq::default();
 
-        let probe_limit = get_ideal_morsel_size() as IdxSize;
+        let probe_limit = state.ideal_morsel_size() as IdxSize;
         let mark_matches = params.emit_unmatched_build();
         let emit_unmatched = params.emit_unmatched_probe();
 
@@ -793,6 +809,7 @@ impl ProbeState {
                 continue;
             }
 
+            let mut permit = acquire_probe_permit(probe_permits).await;
             let hash_keys =
                 select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
             let mut payload = select_payload(df, payload_selector);
@@ -892,9 +909,11 @@ impl ProbeState {
                                 let out_len = probe_match.len();
                                 probe_match.clear();
                                 let out_morsel = new_morsel(&mut build_out, &mut probe_out);
+                                drop(permit.take());
                                 if send.send(out_morsel).await.is_err() {
                                     return Ok(max_seq);
                                 }
+                                permit = acquire_probe_permit(probe_permits).await;
                                 if probe_group_end != probe_partitions.len() {
                                     // We had enough matches to need a mid-partition flush, let's assume there are a lot of
                                     // matches and just do a large reserve.
@@ -963,9 +982,11 @@ impl ProbeState {
                                 let out_len = probe_match.len();
                                 probe_match.clear();
                                 let out_morsel = new_morsel(&mut build_out, &mut probe_out);
+                                drop(permit.take());
                                 if send.send(out_morsel).await.is_err() {
                                     return Ok(max_seq);
                                 }
+                                permit = acquire_probe_permit(probe_permits).await;
                                 // We had enough matches to need a mid-partition flush, let's assume there are a lot of
                                 // matches and just do a large reserve.
                                 let old_est = probe_limit as usize + max_match_per_key_est;
@@ -982,12 +1003,14 @@ impl ProbeState {
                     probe_out.gather_extend(&payload, &probe_match, ShareStrategy::Always);
                     probe_match.clear();
                     let out_morsel = new_morsel(&mut build_out, &mut probe_out);
+                    drop(permit.take());
                     if send.send(out_morsel).await.is_err() {
                         return Ok(max_seq);
                     }
                 }
             }
 
+            drop(permit);
             drop(wait_token);
 
             // Move selectivity estimate a bit towards latest value. Allows rapid changes at first.
@@ -1091,13 +1114,14 @@ impl EmitUnmatchedState {
         mut send: Sender<Morsel>,
         params: &EquiJoinParams,
         num_pipelines: usize,
+        ideal_morsel_size: usize,
     ) -> PolarsResult<()> {
         let total_len: usize = self
             .partitions
             .iter()
             .map(|p| p.hash_table.num_keys() as usize)
             .sum();
-        let ideal_morsel_count = (total_len / get_ideal_morsel_size()).max(1);
+        let ideal_morsel_count = (total_len / ideal_morsel_size).max(1);
         let morsel_count = ideal_morsel_count.next_multiple_of(num_pipelines);
         let morsel_size = total_len.div_ceil(morsel_count).max(1);
 
@@ -1490,6 +1514,8 @@ impl ComputeNode for EquiJoinNode {
                     )
                     .unwrap();
 
+                probe_state.probe_permits =
+                    state.config().join_probe_parallelism.map(Semaphore::new);
                 let partitioner = HashPartitioner::new(state.num_pipelines, 0);
                 let probe_tasks = receivers
                     .into_iter()
@@ -1502,6 +1528,7 @@ impl ComputeNode for EquiJoinNode {
                                 send,
                                 &probe_state.table_per_partition,
                                 &probe_state.unordered_morsel_seq,
+                                probe_state.probe_permits.as_ref(),
                                 partitioner.clone(),
                                 &self.params,
                                 state,
@@ -1524,7 +1551,12 @@ impl ComputeNode for EquiJoinNode {
                 let send = send_ports[0].take().unwrap().serial();
                 join_handles.push(scope.spawn_task(
                     TaskPriority::Low,
-                    emit_state.emit_unmatched(send, &self.params, state.num_pipelines),
+                    emit_state.emit_unmatched(
+                        send,
+                        &self.params,
+                        state.num_pipelines,
+                        state.ideal_morsel_size(),
+                    ),
                 ));
             },
             EquiJoinState::EmitUnmatchedBuildInOrder(src_node) => {
@@ -9,7 +9,7 @@ use polars_utils::pl_str::PlSmallStr;
 use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
 use crate::async_primitives::connector::Receiver;
 use crate::async_primitives::distributor_channel::distributor_channel;
-use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::morsel::SourceToken;
 use crate::nodes::compute_node_prelude::*;
 
 pub struct MergeSortedNode {
@@ -228,7 +228,7 @@ impl ComputeNode for MergeSortedNode {
         scope: &'s TaskScope<'s, 'env>,
         recv_ports: &mut [Option<RecvPort<'_>>],
         send_ports: &mut [Option<SendPort<'_>>],
-        _state: &'s StreamingExecutionState,
+        state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
         assert_eq!(recv_ports.len(), 2);
@@ -451,7 +451,7 @@ impl ComputeNode for MergeSortedNode {
                 // Task that actually merges the two dataframes. Since this merge might be very
                 // expensive, this is split over several tasks.
                 join_handles.extend(dist_recv.into_iter().zip(send).map(|(mut recv, mut send)| {
-                    let ideal_morsel_size = get_ideal_morsel_size();
+                    let ideal_morsel_size = state.ideal_morsel_size();
                     scope.spawn_task(TaskPriority::High, async move {
                         while let Ok((left, right)) = recv.recv().await {
                             // When we are flushing the buffer, we will just send one morsel from
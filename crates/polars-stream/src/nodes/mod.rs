@@ -1,3 +1,5 @@
+#[cfg(feature = "dynamic_group_by")]
+pub mod dynamic_group_by;
 pub mod dynamic_slice;
 pub mod filter;
 pub mod group_by;
@@ -7,6 +9,8 @@ pub mod in_memory_source;
 pub mod input_independent_select;
 pub mod io_sinks;
 pub mod io_sources;
+#[cfg(feature = "is_first_distinct")]
+pub mod is_first_distinct;
 pub mod joins;
 pub mod map;
 #[cfg(feature = "merge_sorted")]
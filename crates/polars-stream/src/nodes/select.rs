@@ -4,6 +4,7 @@ use polars_core::prelude::IntoColumn;
 use polars_core::schema::Schema;
 
 use super::compute_node_prelude::*;
+use super::filter::is_value_error;
 use crate::expression::StreamExpr;
 
 pub struct SelectNode {
@@ -20,6 +21,26 @@ impl SelectNode {
             extend_original,
         }
     }
+
+    async fn select(
+        &self,
+        df: DataFrame,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let mut selected = Vec::new();
+        for selector in self.selectors.iter() {
+            let s = selector.evaluate(&df, &state.in_memory_exec_state).await?;
+            selected.push(s.into_column());
+        }
+
+        if self.extend_original {
+            let mut out = df;
+            out._add_columns(selected, &self.schema)?;
+            Ok(out)
+        } else {
+            DataFrame::new_with_broadcast(selected)
+        }
+    }
 }
 
 impl ComputeNode for SelectNode {
@@ -57,20 +78,27 @@ impl ComputeNode for SelectNode {
         for (mut recv, mut send) in receivers.into_iter().zip(senders) {
             let slf = &*self;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
-                while let Ok(morsel) = recv.recv().await {
+                while let Ok(mut morsel) = recv.recv().await {
+                    let selection = morsel.take_selection();
                     let (df, seq, source_token, consume_token) = morsel.into_inner();
-                    let mut selected = Vec::new();
-                    for selector in slf.selectors.iter() {
-                        let s = selector.evaluate(&df, &state.in_memory_exec_state).await?;
-                        selected.push(s.into_column());
-                    }
-
-                    let ret = if slf.extend_original {
-                        let mut out = df;
-                        out._add_columns(selected, &slf.schema)?;
-                        out
-                    } else {
-                        DataFrame::new_with_broadcast(selected)?
+                    let ret = match selection {
+                        None => slf.select(df, state).await?,
+                        // The selectors are elementwise, so we can evaluate them before dropping
+                        // the unselected rows and only compact the selected columns.
+                        Some(selection) => match slf
+                            .select(df.clone(), state)
+                            .await
+                            .and_then(|out| out._filter_seq(&selection))
+                        {
+                            Ok(out) => out,
+                            // The unselected rows might contain values the selectors fail on,
+                            // retry on the compacted rows so we raise the same errors as an
+                            // eager filter would.
+                            Err(err) if is_value_error(&err) => {
+                                slf.select(df._filter_seq(&selection)?, state).await?
+                            },
+                            Err(err) => return Err(err),
+                        },
                     };
 
                     let mut morsel = Morsel::new(ret, seq, source_token);
@@ -38,6 +38,10 @@ impl NodeStyle {
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "is_first_distinct")]
+            K::IsFirstDistinct { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "dynamic_group_by")]
+            K::DynamicGroupBy { .. } => Self::MemoryIntensive,
             _ => Self::Generic,
         }
     }
@@ -203,6 +207,10 @@ fn visualize_plan_rec(
             format!("with-row-index\\nname: {name}\\noffset: {offset:?}"),
             from_ref(input),
         ),
+        #[cfg(feature = "is_first_distinct")]
+        PhysNodeKind::IsFirstDistinct { input, name } => {
+            (format!("is-first-distinct\\nname: {name}"), from_ref(input))
+        },
         PhysNodeKind::InputIndependentSelect { selectors } => (
             format!(
                 "input-independent-select\\n{}",
@@ -345,6 +353,8 @@ fn visualize_plan_rec(
             missing_columns_policy: _,
             extra_columns_policy: _,
             deletion_files,
+            table_statistics,
+            file_error_policy,
             file_schema: _,
         } => {
             let mut out = format!("multi-scan[{}]", file_reader_builder.reader_name());
@@ -403,9 +413,23 @@ fn visualize_plan_rec(
                 write!(f, "\n{deletion_files}").unwrap();
             }
 
+            if let Some(table_statistics) = table_statistics {
+                write!(f, "\n{table_statistics}").unwrap();
+            }
+
+            if file_error_policy.retries > 0 {
+                write!(f, "\nfile retries: {}", file_error_policy.retries).unwrap();
+            }
+
+            if file_error_policy.skip {
+                write!(f, "\nskip failed files").unwrap();
+            }
+
             (out, &[][..])
         },
-        PhysNodeKind::GroupBy { input, key, aggs } => (
+        PhysNodeKind::GroupBy {
+            input, key, aggs, ..
+        } => (
             format!(
                 "group-by\\nkey:\\n{}\\naggs:\\n{}",
                 fmt_exprs_to_label(key, expr_arena, FormatExprStyle::Select),
@@ -413,6 +437,17 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy {
+            input, keys, aggs, ..
+        } => (
+            format!(
+                "dynamic-group-by\\nkey:\\n{}\\naggs:\\n{}",
+                fmt_exprs_to_label(keys, expr_arena, FormatExprStyle::Select),
+                fmt_exprs_to_label(aggs, expr_arena, FormatExprStyle::Select)
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::InMemoryJoin {
             input_left,
             input_right,
@@ -716,6 +716,7 @@ fn lower_exprs_with_ctx(
                         suffix: None,
                         slice: None,
                         nulls_equal,
+                        nans_equal: None,
                         coalesce: Default::default(),
                         maintain_order: Default::default(),
                     },
@@ -732,6 +733,34 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(left_col_expr);
             },
 
+            #[cfg(feature = "is_first_distinct")]
+            AExpr::Function {
+                input: ref inner_exprs,
+                function: IRFunctionExpr::Boolean(IRBooleanFunction::IsFirstDistinct),
+                options: _,
+            } => {
+                // Select the keys under unique names, so that the node only sees the key columns.
+                let out_name = unique_column_name();
+                let inner_nodes = inner_exprs.iter().map(|e| e.node()).collect_vec();
+                let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &inner_nodes, ctx)?;
+                let key_exprs = trans_exprs
+                    .into_iter()
+                    .map(|e| ExprIR::new(e, OutputName::Alias(unique_column_name())))
+                    .collect_vec();
+                let key_stream = build_select_stream_with_ctx(trans_input, &key_exprs, ctx)?;
+
+                let output_schema = Schema::from_iter([(out_name.clone(), DataType::Boolean)]);
+                let node_kind = PhysNodeKind::IsFirstDistinct {
+                    input: key_stream,
+                    name: out_name.clone(),
+                };
+                let node_key = ctx
+                    .phys_sm
+                    .insert(PhysNode::new(Arc::new(output_schema), node_kind));
+                input_streams.insert(PhysStream::first(node_key));
+                transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+            },
+
             // Lower arbitrary elementwise functions.
             ref node @ AExpr::Function {
                 input: ref inner_exprs,
@@ -812,7 +841,7 @@ fn lower_exprs_with_ctx(
                 evaluation,
                 variant,
             } => match variant {
-                EvalVariant::List => {
+                EvalVariant::List | EvalVariant::ListAgg => {
                     let (trans_input, trans_expr) = lower_exprs_with_ctx(input, &[inner], ctx)?;
                     let eval_expr = AExpr::Eval {
                         expr: trans_expr[0],
@@ -906,7 +935,7 @@ fn lower_exprs_with_ctx(
                             )
                         })
                         .collect(),
-                    slice: None,
+                    slice: sort_options.limit.map(|limit| (0, limit as usize)),
                     sort_options,
                 };
                 let output_schema = ctx.phys_sm[select_stream.node].output_schema.clone();
@@ -408,7 +408,7 @@ fn try_build_streaming_group_by(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> Option<PolarsResult<PhysStream>> {
-    if apply.is_some() || maintain_order {
+    if apply.is_some() || maintain_order || options.expand_categories {
         return None; // TODO
     }
 
@@ -504,6 +504,12 @@ fn try_build_streaming_group_by(
             input: pre_select,
             key: trans_keys,
             aggs: trans_agg_exprs,
+            // The output order is not maintained, so any groups make up the head of the output.
+            limit: options.slice.and_then(|(offset, len)| {
+                usize::try_from(offset)
+                    .ok()
+                    .map(|offset| offset.saturating_add(len))
+            }),
         },
     ));
 
@@ -523,6 +529,54 @@ fn try_build_streaming_group_by(
     Some(out)
 }
 
+/// Tries to build a `group_by_dynamic` with `group_by` keys, of which the rows are partitioned by
+/// their keys so that each partition can be grouped on its own.
+#[cfg(feature = "dynamic_group_by")]
+#[allow(clippy::too_many_arguments)]
+fn try_build_dynamic_group_by(
+    input: PhysStream,
+    keys: &[ExprIR],
+    aggs: &[ExprIR],
+    output_schema: Arc<Schema>,
+    options: &GroupbyOptions,
+    apply: Option<&Arc<dyn DataFrameUdf>>,
+    expr_arena: &Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+    expr_cache: &mut ExprCache,
+) -> Option<PhysStream> {
+    if options.dynamic.is_none() || keys.is_empty() || apply.is_some() {
+        return None;
+    }
+    // The keys are evaluated on each morsel to partition it.
+    if !keys
+        .iter()
+        .all(|key| is_elementwise_rec_cached(key.node(), expr_arena, expr_cache))
+    {
+        return None;
+    }
+
+    // The slice is over the windows of all partitions.
+    let partition_options = GroupbyOptions {
+        slice: None,
+        ..options.clone()
+    };
+    let node = phys_sm.insert(PhysNode::new(
+        output_schema,
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            keys: keys.to_vec(),
+            aggs: aggs.to_vec(),
+            options: Arc::new(partition_options),
+        },
+    ));
+    let stream = PhysStream::first(node);
+    Some(if let Some((offset, len)) = options.slice {
+        build_slice_stream(stream, offset, len, phys_sm)
+    } else {
+        stream
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn build_group_by_stream(
     input: PhysStream,
@@ -550,33 +604,48 @@ pub fn build_group_by_stream(
         ctx,
     );
     if let Some(stream) = streaming {
-        stream
-    } else {
-        let format_str = ctx.prepare_visualization.then(|| {
-            let mut buffer = String::new();
-            write_group_by(
-                &mut buffer,
-                0,
-                expr_arena,
-                keys,
-                aggs,
-                apply.as_deref(),
-                maintain_order,
-            )
-            .unwrap();
-            buffer
-        });
-        build_group_by_fallback(
-            input,
+        return stream;
+    }
+
+    #[cfg(feature = "dynamic_group_by")]
+    if let Some(stream) = try_build_dynamic_group_by(
+        input,
+        keys,
+        aggs,
+        output_schema.clone(),
+        &options,
+        apply.as_ref(),
+        expr_arena,
+        phys_sm,
+        expr_cache,
+    ) {
+        return Ok(stream);
+    }
+
+    let format_str = ctx.prepare_visualization.then(|| {
+        let mut buffer = String::new();
+        write_group_by(
+            &mut buffer,
+            0,
+            expr_arena,
             keys,
             aggs,
-            output_schema,
+            apply.as_deref(),
             maintain_order,
-            options,
-            apply,
-            expr_arena,
-            phys_sm,
-            format_str,
         )
-    }
+        .unwrap();
+        buffer
+    });
+    build_group_by_fallback(
+        input,
+        keys,
+        aggs,
+        output_schema,
+        maintain_order,
+        options,
+        apply,
+        expr_arena,
+        phys_sm,
+        format_str,
+    )
 }
@@ -616,6 +616,8 @@ pub fn lower_ir(
                         deletion_files: DeletionFilesList::filter_empty(
                             unified_scan_args.deletion_files,
                         ),
+                        table_statistics: unified_scan_args.table_statistics,
+                        file_error_policy: unified_scan_args.file_error_policy,
                         file_schema,
                     };
 
@@ -8,9 +8,11 @@ use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_ops::frame::JoinArgs;
 use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::dsl::table_statistics::TableStatistics;
 use polars_plan::dsl::{
-    CastColumnsPolicy, JoinTypeOptionsIR, MissingColumnsPolicy, PartitionTargetCallback,
-    PartitionVariantIR, ScanSources, SinkFinishCallback, SinkOptions, SinkTarget, SortColumnIR,
+    CastColumnsPolicy, FileErrorPolicy, JoinTypeOptionsIR, MissingColumnsPolicy,
+    PartitionTargetCallback, PartitionVariantIR, ScanSources, SinkFinishCallback, SinkOptions,
+    SinkTarget, SortColumnIR,
 };
 use polars_plan::plans::hive::HivePartitionsDf;
 use polars_plan::plans::{AExpr, DataFrameUdf, IR};
@@ -26,6 +28,8 @@ mod to_graph;
 pub use fmt::visualize_plan;
 use polars_plan::dsl::ExtraColumnsPolicy;
 use polars_plan::prelude::FileType;
+#[cfg(feature = "dynamic_group_by")]
+use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::plpath::PlPath;
@@ -104,6 +108,13 @@ pub enum PhysNodeKind {
         offset: Option<IdxSize>,
     },
 
+    /// Whether each row of the input is the first occurrence of that row.
+    #[cfg(feature = "is_first_distinct")]
+    IsFirstDistinct {
+        input: PhysStream,
+        name: PlSmallStr,
+    },
+
     InputIndependentSelect {
         selectors: Vec<ExprIR>,
     },
@@ -231,6 +242,8 @@ pub enum PhysNodeKind {
         extra_columns_policy: ExtraColumnsPolicy,
 
         deletion_files: Option<DeletionFilesList>,
+        table_statistics: Option<TableStatistics>,
+        file_error_policy: FileErrorPolicy,
 
         /// Schema of columns contained in the file. Does not contain external columns (e.g. hive / row_index).
         file_schema: SchemaRef,
@@ -246,6 +259,18 @@ pub enum PhysNodeKind {
         key: Vec<ExprIR>,
         // Must be a 'simple' expression, a singular column feeding into a single aggregate, or Len.
         aggs: Vec<ExprIR>,
+        /// Only this many groups, in any order, have to be produced.
+        limit: Option<usize>,
+    },
+
+    /// A `group_by_dynamic` with `group_by` keys, of which the windows are computed per partition
+    /// of the keys.
+    #[cfg(feature = "dynamic_group_by")]
+    DynamicGroupBy {
+        input: PhysStream,
+        keys: Vec<ExprIR>,
+        aggs: Vec<ExprIR>,
+        options: Arc<GroupbyOptions>,
     },
 
     EquiJoin {
@@ -334,6 +359,18 @@ fn visit_node_inputs_mut(
                 visit(input);
             },
 
+            #[cfg(feature = "is_first_distinct")]
+            PhysNodeKind::IsFirstDistinct { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
+            #[cfg(feature = "dynamic_group_by")]
+            PhysNodeKind::DynamicGroupBy { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
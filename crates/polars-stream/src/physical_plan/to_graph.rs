@@ -2,9 +2,10 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 
 use parking_lot::Mutex;
-use polars_core::prelude::PlRandomState;
+use polars_core::POOL;
+use polars_core::config::ConfigOverrides;
+use polars_core::prelude::{PlHashSet, PlRandomState};
 use polars_core::schema::Schema;
-use polars_core::{POOL, config};
 use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_expr::groups::new_hash_grouper;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr};
@@ -12,8 +13,10 @@ use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
 use polars_plan::dsl::{JoinOptionsIR, PartitionVariantIR, ScanSources};
+#[cfg(feature = "dynamic_group_by")]
+use polars_plan::plans::DataFrameUdf;
 use polars_plan::plans::expr_ir::ExprIR;
-use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
+use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR, is_elementwise_rec};
 use polars_plan::prelude::{FileType, FunctionFlags};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::format_pl_smallstr;
@@ -23,7 +26,7 @@ use polars_utils::plpath::PlPath;
 use recursive::recursive;
 use slotmap::{SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 use crate::execute::StreamingExecutionState;
 use crate::expression::StreamExpr;
 use crate::graph::{Graph, GraphNodeKey};
@@ -46,6 +49,32 @@ fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
     })
 }
 
+fn has_udf(node: Node, arena: &Arena<AExpr>) -> bool {
+    arena
+        .iter(node)
+        .any(|(_n, ae)| matches!(ae, AExpr::AnonymousFunction { .. }))
+}
+
+/// Let the filter producing `input` (if any) defer its compaction to the consumer of `input`,
+/// which evaluates `exprs` on the uncompacted rows.
+///
+/// Every stream has a single consumer (multiple consumers go through a multiplexer), so only
+/// the consumer has to honor the selection. All other nodes see compacted morsels. UDFs are never
+/// evaluated on the rows a filter removed, they can be expensive or have side effects.
+fn defer_filter_compaction(
+    input: &PhysStream,
+    exprs: &[ExprIR],
+    ctx: &mut GraphConversionContext<'_>,
+) {
+    if ctx.lazy_filter
+        && !exprs.iter().any(|e| has_udf(e.node(), ctx.expr_arena))
+        && matches!(ctx.phys_sm[input.node].kind, PhysNodeKind::Filter { .. })
+        && !ctx.phys_to_graph.contains_key(input.node)
+    {
+        ctx.deferred_filters.insert(input.node);
+    }
+}
+
 fn create_stream_expr(
     expr_ir: &ExprIR,
     ctx: &mut GraphConversionContext<'_>,
@@ -69,12 +98,20 @@ struct GraphConversionContext<'a> {
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
     expr_conversion_state: ExpressionConversionState,
     num_pipelines: usize,
+    /// The settings of the query that override the global configuration.
+    config: &'a ConfigOverrides,
+    /// Whether filters may hand a selection to their consumer instead of compacting the morsels,
+    /// this is opt-in through `POLARS_STREAMING_LAZY_FILTER=1`.
+    lazy_filter: bool,
+    /// The filters whose consumer honors the selection of incoming morsels.
+    deferred_filters: PlHashSet<PhysNodeKey>,
 }
 
 pub fn physical_plan_to_graph(
     root: PhysNodeKey,
     phys_sm: &SlotMap<PhysNodeKey, PhysNode>,
     expr_arena: &mut Arena<AExpr>,
+    config: &ConfigOverrides,
 ) -> PolarsResult<(Graph, SecondaryMap<PhysNodeKey, GraphNodeKey>)> {
     // Get the number of threads from the rayon thread-pool as that respects our config.
     let num_pipelines = POOL.current_num_threads();
@@ -85,6 +122,9 @@ pub fn physical_plan_to_graph(
         phys_to_graph: SecondaryMap::with_capacity(phys_sm.len()),
         expr_conversion_state: ExpressionConversionState::new(false),
         num_pipelines,
+        config,
+        lazy_filter: std::env::var("POLARS_STREAMING_LAZY_FILTER").as_deref() == Ok("1"),
+        deferred_filters: PlHashSet::new(),
     };
 
     to_graph_rec(root, &mut ctx)?;
@@ -166,9 +206,12 @@ fn to_graph_rec<'a>(
         Filter { predicate, input } => {
             let input_schema = &ctx.phys_sm[input.node].output_schema;
             let phys_predicate_expr = create_stream_expr(predicate, ctx, input_schema)?;
+            // A filter combines the selection of its input with its own predicate.
+            defer_filter_compaction(input, std::slice::from_ref(predicate), ctx);
             let input_key = to_graph_rec(input.node, ctx)?;
+            let defer_compaction = ctx.deferred_filters.contains(&phys_node_key);
             ctx.graph.add_node(
-                nodes::filter::FilterNode::new(phys_predicate_expr),
+                nodes::filter::FilterNode::new(phys_predicate_expr, defer_compaction),
                 [(input_key, input.port)],
             )
         },
@@ -183,6 +226,15 @@ fn to_graph_rec<'a>(
                 .iter()
                 .map(|selector| create_stream_expr(selector, ctx, input_schema))
                 .collect::<PolarsResult<_>>()?;
+            // Elementwise selectors can be evaluated on the uncompacted rows, after which only
+            // the selected columns have to be compacted.
+            if !*extend_original
+                && selectors
+                    .iter()
+                    .all(|e| is_elementwise_rec(e.node(), ctx.expr_arena))
+            {
+                defer_filter_compaction(input, selectors, ctx);
+            }
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
                 nodes::select::SelectNode::new(
@@ -206,6 +258,15 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "is_first_distinct")]
+        IsFirstDistinct { input, name } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::is_first_distinct::IsFirstDistinctNode::new(name.clone()),
+                [(input_key, input.port)],
+            )
+        },
+
         InputIndependentSelect { selectors } => {
             let empty_schema = Default::default();
             let phys_selectors = selectors
@@ -537,6 +598,8 @@ fn to_graph_rec<'a>(
             cast_columns_policy,
             include_file_paths,
             deletion_files,
+            table_statistics,
+            file_error_policy,
             file_schema,
         } => {
             let hive_parts = hive_parts.clone();
@@ -575,8 +638,10 @@ fn to_graph_rec<'a>(
             let extra_columns_policy = *extra_columns_policy;
             let cast_columns_policy = cast_columns_policy.clone();
             let deletion_files = deletion_files.clone();
+            let table_statistics = table_statistics.clone();
+            let file_error_policy = file_error_policy.clone();
 
-            let verbose = config::verbose();
+            let verbose = ctx.config.verbose();
 
             ctx.graph.add_node(
                 nodes::io_sources::multi_file_reader::MultiFileReader::new(Arc::new(
@@ -596,8 +661,11 @@ fn to_graph_rec<'a>(
                         extra_columns_policy,
                         cast_columns_policy,
                         deletion_files,
+                        table_statistics,
+                        file_error_policy,
                         // Initialized later
                         num_pipelines: AtomicUsize::new(0),
+                        ideal_morsel_size: AtomicUsize::new(0),
                         n_readers_pre_init: AtomicUsize::new(0),
                         max_concurrent_scans: AtomicUsize::new(0),
                         verbose,
@@ -607,7 +675,12 @@ fn to_graph_rec<'a>(
             )
         },
 
-        GroupBy { input, key, aggs } => {
+        GroupBy {
+            input,
+            key,
+            aggs,
+            limit,
+        } => {
             let input_key = to_graph_rec(input.node, ctx)?;
 
             let input_schema = &ctx.phys_sm[input.node].output_schema;
@@ -641,6 +714,86 @@ fn to_graph_rec<'a>(
                     node.output_schema.clone(),
                     PlRandomState::default(),
                     ctx.num_pipelines,
+                    *limit,
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
+        #[cfg(feature = "dynamic_group_by")]
+        DynamicGroupBy {
+            input,
+            keys,
+            aggs,
+            options,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let key_selectors = keys
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, &input_schema))
+                .try_collect_vec()?;
+
+            // The partitions always compute the boundaries of the windows, to know which windows
+            // are complete.
+            let dynamic = options.dynamic.as_ref().unwrap();
+            let mut partition_schema = node.output_schema.as_ref().clone();
+            if !dynamic.include_boundaries {
+                let dtype = input_schema.try_get(&dynamic.index_column)?;
+                let at = keys.len();
+                partition_schema.insert_at_index(at, "_lower_boundary".into(), dtype.clone())?;
+                partition_schema.insert_at_index(
+                    at + 1,
+                    "_upper_boundary".into(),
+                    dtype.clone(),
+                )?;
+            }
+            let partition_schema = Arc::new(partition_schema);
+            let mut partition_options = options.as_ref().clone();
+            if let Some(dynamic) = &mut partition_options.dynamic {
+                dynamic.include_boundaries = true;
+            }
+            let partition_options = Arc::new(partition_options);
+
+            // Every partition gets its own executor, so they can run in parallel.
+            let maps = (0..ctx.num_pipelines)
+                .map(|_| {
+                    let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                    let mut lp_arena = Arena::default();
+                    let df_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
+                    let group_by_node = lp_arena.add(IR::GroupBy {
+                        input: df_node,
+                        keys: keys.clone(),
+                        aggs: aggs.clone(),
+                        schema: partition_schema.clone(),
+                        maintain_order: false,
+                        options: partition_options.clone(),
+                        apply: None,
+                    });
+                    let executor = Mutex::new(create_physical_plan(
+                        group_by_node,
+                        &mut lp_arena,
+                        ctx.expr_arena,
+                        None,
+                    )?);
+                    let map: Arc<dyn DataFrameUdf> = Arc::new(move |df| {
+                        lmdf.set_materialized_dataframe(df);
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    });
+                    PolarsResult::Ok(map)
+                })
+                .try_collect_vec()?;
+
+            let input_key = to_graph_rec(input.node, ctx)?;
+            ctx.graph.add_node(
+                nodes::dynamic_group_by::DynamicGroupByNode::new(
+                    input_schema,
+                    key_selectors,
+                    maps,
+                    dynamic.index_column.clone(),
+                    dynamic.include_boundaries,
+                    dynamic.has_fixed_windows(),
+                    PlRandomState::default(),
                 ),
                 [(input_key, input.port)],
             )
@@ -888,7 +1041,11 @@ fn to_graph_rec<'a>(
                 S::Pyarrow => todo!(),
                 S::Cuda => todo!(),
                 S::IOPlugin => {
-                    let batch_size = Some(get_ideal_morsel_size());
+                    let batch_size = Some(
+                        ctx.config
+                            .ideal_morsel_size
+                            .unwrap_or_else(get_ideal_morsel_size),
+                    );
                     let output_schema = output_schema.clone();
 
                     let with_columns = with_columns.map(|x| {
@@ -991,7 +1148,9 @@ fn to_graph_rec<'a>(
                 },
             };
 
-            use polars_plan::dsl::{CastColumnsPolicy, ExtraColumnsPolicy, MissingColumnsPolicy};
+            use polars_plan::dsl::{
+                CastColumnsPolicy, ExtraColumnsPolicy, FileErrorPolicy, MissingColumnsPolicy,
+            };
 
             use crate::nodes::io_sources::batch::builder::BatchFnReaderBuilder;
             use crate::nodes::io_sources::batch::{BatchFnReader, GetBatchState};
@@ -1003,7 +1162,7 @@ fn to_graph_rec<'a>(
                 // retrieve the schema from the first morsel.
                 output_schema: validate_schema.then(|| output_schema.clone()),
                 get_batch_state: Some(GetBatchState::from(get_batch_fn)),
-                verbose: config::verbose(),
+                verbose: ctx.config.verbose(),
             };
 
             // Note: This will potentially override the output schema if `validate_schema` is `false`.
@@ -1029,7 +1188,9 @@ fn to_graph_rec<'a>(
             let extra_columns_policy = ExtraColumnsPolicy::Ignore;
             let cast_columns_policy = CastColumnsPolicy::ERROR_ON_MISMATCH;
             let deletion_files = None;
-            let verbose = config::verbose();
+            let table_statistics = None;
+            let file_error_policy = FileErrorPolicy::default();
+            let verbose = ctx.config.verbose();
 
             ctx.graph.add_node(
                 nodes::io_sources::multi_file_reader::MultiFileReader::new(Arc::new(
@@ -1049,8 +1210,11 @@ fn to_graph_rec<'a>(
                         extra_columns_policy,
                         cast_columns_policy,
                         deletion_files,
+                        table_statistics,
+                        file_error_policy,
                         // Initialized later
                         num_pipelines: AtomicUsize::new(0),
+                        ideal_morsel_size: AtomicUsize::new(0),
                         n_readers_pre_init: AtomicUsize::new(0),
                         max_concurrent_scans: AtomicUsize::new(0),
                         verbose,
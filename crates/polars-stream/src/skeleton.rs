@@ -2,6 +2,7 @@
 use std::cmp::Reverse;
 
 use polars_core::POOL;
+use polars_core::config::ConfigOverrides;
 use polars_core::prelude::*;
 use polars_expr::planner::{ExpressionConversionState, create_physical_expr, get_expr_depth_limit};
 use polars_plan::plans::{Context, IR, IRPlan};
@@ -28,8 +29,9 @@ pub fn run_query(
     node: Node,
     ir_arena: &mut Arena<IR>,
     expr_arena: &mut Arena<AExpr>,
+    config: Arc<ConfigOverrides>,
 ) -> PolarsResult<QueryResult> {
-    StreamingQuery::build(node, ir_arena, expr_arena)?.execute()
+    StreamingQuery::build(node, ir_arena, expr_arena, config)?.execute()
 }
 
 /// Visualizes the physical plan as a dot graph.
@@ -57,6 +59,7 @@ pub struct StreamingQuery {
     root_phys_node: PhysNodeKey,
     phys_sm: SlotMap<PhysNodeKey, PhysNode>,
     phys_to_graph: SecondaryMap<PhysNodeKey, GraphNodeKey>,
+    config: Arc<ConfigOverrides>,
 }
 
 impl StreamingQuery {
@@ -64,6 +67,7 @@ impl StreamingQuery {
         node: Node,
         ir_arena: &mut Arena<IR>,
         expr_arena: &mut Arena<AExpr>,
+        config: Arc<ConfigOverrides>,
     ) -> PolarsResult<Self> {
         if let Ok(visual_path) = std::env::var("POLARS_VISUALIZE_IR") {
             let plan = IRPlan {
@@ -91,8 +95,12 @@ impl StreamingQuery {
             std::fs::write(visual_path, visualization).unwrap();
         }
 
-        let (mut graph, phys_to_graph) =
-            crate::physical_plan::physical_plan_to_graph(root_phys_node, &phys_sm, expr_arena)?;
+        let (mut graph, phys_to_graph) = crate::physical_plan::physical_plan_to_graph(
+            root_phys_node,
+            &phys_sm,
+            expr_arena,
+            &config,
+        )?;
 
         let top_ir = ir_arena.get(node).clone();
 
@@ -102,6 +110,7 @@ impl StreamingQuery {
             root_phys_node,
             phys_sm,
             phys_to_graph,
+            config,
         };
 
         Ok(out)
@@ -114,10 +123,11 @@ impl StreamingQuery {
             root_phys_node,
             phys_sm,
             phys_to_graph,
+            config,
         } = self;
 
         crate::async_executor::clear_task_wait_statistics();
-        let mut results = crate::execute::execute_graph(&mut graph)?;
+        let mut results = crate::execute::execute_graph(&mut graph, config)?;
 
         if std::env::var("POLARS_TRACK_WAIT_STATS").as_deref() == Ok("1") {
             let mut stats = crate::async_executor::get_task_wait_statistics();
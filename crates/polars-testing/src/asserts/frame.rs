@@ -180,6 +180,26 @@ mod tests {
         assert_dataframe_equal!(&df1, &df2, options);
     }
 
+    #[test]
+    #[should_panic(expected = "dtypes do not match")]
+    fn test_dataframe_column_order_ignored_dtype_mismatch() {
+        let df1 = DataFrame::new(vec![
+            Series::new("col1".into(), &[1, 2, 3]).into(),
+            Series::new("col2".into(), &[1.0, 2.0, 3.0]).into(),
+        ])
+        .unwrap();
+
+        let df2 = DataFrame::new(vec![
+            Series::new("col2".into(), &[1, 2, 3]).into(),
+            Series::new("col1".into(), &[1.0, 2.0, 3.0]).into(),
+        ])
+        .unwrap();
+
+        let options =
+            crate::asserts::DataFrameEqualOptions::default().with_check_column_order(false);
+        assert_dataframe_equal!(&df1, &df2, options);
+    }
+
     #[test]
     #[should_panic(expected = "columns mismatch: [\"col3\"] in left, but not in right")]
     fn test_dataframe_left_has_extra_column() {
@@ -626,4 +646,35 @@ mod tests {
 
         assert_dataframe_equal!(&df1, &df2);
     }
+
+    // Testing the non-asserting comparison
+    #[test]
+    fn test_dataframe_equal_fn() {
+        use crate::asserts::{DataFrameEqualOptions, dataframe_equal};
+
+        let df1 = DataFrame::new(vec![
+            Series::new("col1".into(), &[1.0000001, 2.0000002]).into(),
+            Series::new("col2".into(), &["a", "b"]).into(),
+        ])
+        .unwrap();
+
+        let df2 = DataFrame::new(vec![
+            Series::new("col2".into(), &["a", "b"]).into(),
+            Series::new("col1".into(), &[1.0, 2.0]).into(),
+        ])
+        .unwrap();
+
+        let options = DataFrameEqualOptions::default().with_check_column_order(false);
+        assert!(dataframe_equal(&df1, &df2, options));
+        assert!(!dataframe_equal(
+            &df1,
+            &df2,
+            DataFrameEqualOptions::default()
+        ));
+
+        let options = DataFrameEqualOptions::default()
+            .with_check_column_order(false)
+            .with_check_exact(true);
+        assert!(!dataframe_equal(&df1, &df2, options));
+    }
 }
@@ -4,4 +4,5 @@ mod utils;
 
 pub use utils::{
     DataFrameEqualOptions, SeriesEqualOptions, assert_dataframe_equal, assert_series_equal,
+    dataframe_equal, series_equal,
 };
@@ -647,4 +647,19 @@ mod tests {
 
         assert_series_equal!(&s1, &s2);
     }
+
+    // Testing the non-asserting comparison
+    #[test]
+    fn test_series_equal_fn() {
+        use crate::asserts::{SeriesEqualOptions, series_equal};
+
+        let s1 = Series::new("".into(), &[1.0, 2.0, 3.0]);
+        let s2 = Series::new("".into(), &[1.0, 2.0, 3.01]);
+
+        assert!(!series_equal(&s1, &s2, SeriesEqualOptions::default()));
+        let options = SeriesEqualOptions::default()
+            .with_check_exact(false)
+            .with_atol(0.1);
+        assert!(series_equal(&s1, &s2, options));
+    }
 }
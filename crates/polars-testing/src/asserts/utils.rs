@@ -571,6 +571,14 @@ pub fn assert_series_equal(
     )
 }
 
+/// Returns whether two Series are equal according to `options`.
+///
+/// This is the non-asserting counterpart of [`assert_series_equal`], e.g. to compare Series with
+/// a tolerance for floating point values in a condition.
+pub fn series_equal(left: &Series, right: &Series, options: SeriesEqualOptions) -> bool {
+    assert_series_equal(left, right, options).is_ok()
+}
+
 /// Configuration options for comparing DataFrame equality.
 ///
 /// Controls the behavior of DataFrame equality comparisons by specifying
@@ -690,8 +698,8 @@ impl DataFrameEqualOptions {
 ///    - Reports columns present in right but missing in left
 /// 3. **Column order validation**: If `check_column_order` is true, verifies columns appear in the same sequence
 /// 4. **Data type validation**: If `check_dtypes` is true, ensures corresponding columns have matching data types
-///    - When `check_column_order` is false, compares data type sets for equality
-///    - When `check_column_order` is true, performs more precise type checking
+///    - When `check_column_order` is false, compares the data types of the columns with the same name
+///    - When `check_column_order` is true, compares the data types of the columns in the same position
 ///
 fn assert_dataframe_schema_equal(
     left: &DataFrame,
@@ -769,14 +777,17 @@ fn assert_dataframe_schema_equal(
                 ));
             }
         } else {
-            let left_dtypes: PlHashSet<DataType> = left.dtypes().into_iter().collect();
-            let right_dtypes: PlHashSet<DataType> = right.dtypes().into_iter().collect();
-            if left_dtypes != right_dtypes {
+            let right_dtypes_by_left: Vec<_> = ordered_left_cols
+                .iter()
+                .map(|col| right_schema.get(col).unwrap().clone())
+                .collect();
+            let left_dtypes_ordered = left.dtypes();
+            if left_dtypes_ordered != right_dtypes_by_left {
                 return Err(polars_err!(
                     assertion_error = "DataFrames",
                     "dtypes do not match",
-                    format!("{:?}", left_dtypes),
-                    format!("{:?}", right_dtypes)
+                    format!("{:?}", left_dtypes_ordered),
+                    format!("{:?}", right_dtypes_by_left)
                 ));
             }
         }
@@ -891,3 +902,16 @@ pub fn assert_dataframe_equal(
 
     Ok(())
 }
+
+/// Returns whether two DataFrames are equal according to `options`.
+///
+/// This is the non-asserting counterpart of [`assert_dataframe_equal`], e.g. to compare
+/// DataFrames regardless of their column order, or with a tolerance for floating point values,
+/// in a condition.
+pub fn dataframe_equal(
+    left: &DataFrame,
+    right: &DataFrame,
+    options: DataFrameEqualOptions,
+) -> bool {
+    assert_dataframe_equal(left, right, options).is_ok()
+}
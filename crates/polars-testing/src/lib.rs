@@ -1 +1,3 @@
 pub mod asserts;
+#[cfg(feature = "proptest")]
+pub mod proptest;
@@ -0,0 +1,421 @@
+//! [`proptest`] strategies that generate random DataFrames.
+//!
+//! These can be used to fuzz code that consumes Polars DataFrames, for example:
+//!
+//! ```
+//! use polars_core::prelude::*;
+//! use polars_testing::proptest::{ColumnStrategyOptions, DataFrameStrategy};
+//! use proptest::prelude::*;
+//!
+//! let schema = Schema::from_iter([
+//!     Field::new("id".into(), DataType::UInt32),
+//!     Field::new("value".into(), DataType::Float64),
+//! ]);
+//! let strategy = DataFrameStrategy::new(schema)
+//!     .unwrap()
+//!     .with_height(0..=100)
+//!     .with_n_chunks(1..=4)
+//!     .with_column_options("id", ColumnStrategyOptions::default().with_null_ratio(0.0));
+//!
+//! proptest!(|(df in strategy)| {
+//!     prop_assert_eq!(df.column("id").unwrap().null_count(), 0);
+//! });
+//! ```
+use std::ops::RangeInclusive;
+
+use polars_core::prelude::*;
+use proptest::prelude::*;
+use proptest::strategy::NewTree;
+use proptest::test_runner::TestRunner;
+
+/// Constraints on the values that are generated for a column.
+#[derive(Clone, Debug)]
+pub struct ColumnStrategyOptions {
+    /// The probability that a value is null.
+    pub null_ratio: f64,
+    /// The range of the values of integer columns, clamped to the range of their data type.
+    /// If `None`, the full range of the data type is used, for `UInt64` up to `i64::MAX`.
+    pub int_range: Option<RangeInclusive<i64>>,
+    /// The range of the values of float columns.
+    pub float_range: RangeInclusive<f64>,
+    /// The range of the lengths of string and binary values.
+    pub len_range: RangeInclusive<usize>,
+    /// Whether, and in which direction, the values are sorted. Nulls are sorted first.
+    pub sorted: IsSorted,
+}
+
+impl Default for ColumnStrategyOptions {
+    /// Creates a new `ColumnStrategyOptions` with default settings.
+    ///
+    /// Default configuration:
+    /// - 10% of the values are null
+    /// - Integers span the range of their data type, floats the range `-1e6..=1e6`
+    /// - Strings and binary values have a length of at most 8
+    /// - Values are not sorted
+    fn default() -> Self {
+        Self {
+            null_ratio: 0.1,
+            int_range: None,
+            float_range: -1e6..=1e6,
+            len_range: 0..=8,
+            sorted: IsSorted::Not,
+        }
+    }
+}
+
+impl ColumnStrategyOptions {
+    /// Creates a new `ColumnStrategyOptions` with default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the probability that a value is null.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not in `0.0..=1.0`.
+    pub fn with_null_ratio(mut self, value: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&value),
+            "null ratio must be in 0.0..=1.0, got {value}"
+        );
+        self.null_ratio = value;
+        self
+    }
+
+    /// Sets the range of the values of integer columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty.
+    pub fn with_int_range(mut self, value: RangeInclusive<i64>) -> Self {
+        assert!(!value.is_empty(), "integer range must not be empty");
+        self.int_range = Some(value);
+        self
+    }
+
+    /// Sets the range of the values of float columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty or not finite.
+    pub fn with_float_range(mut self, value: RangeInclusive<f64>) -> Self {
+        assert!(
+            value.start().is_finite() && value.end().is_finite() && !value.is_empty(),
+            "float range must be finite and not empty"
+        );
+        self.float_range = value;
+        self
+    }
+
+    /// Sets the range of the lengths of string and binary values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty.
+    pub fn with_len_range(mut self, value: RangeInclusive<usize>) -> Self {
+        assert!(!value.is_empty(), "length range must not be empty");
+        self.len_range = value;
+        self
+    }
+
+    /// Sets whether, and in which direction, the values are sorted.
+    pub fn with_sorted(mut self, value: IsSorted) -> Self {
+        self.sorted = value;
+        self
+    }
+}
+
+/// A strategy that generates DataFrames with a given schema.
+///
+/// The height, the number of chunks and the values of every column are random within the
+/// configured constraints. Chunks are never empty, so the number of chunks is at most the height.
+/// A DataFrame with an empty schema always has a height of 0.
+#[derive(Clone, Debug)]
+pub struct DataFrameStrategy {
+    schema: Schema,
+    height: RangeInclusive<usize>,
+    n_chunks: RangeInclusive<usize>,
+    default_options: ColumnStrategyOptions,
+    column_options: PlHashMap<PlSmallStr, ColumnStrategyOptions>,
+}
+
+fn is_supported(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::Boolean | DataType::String | DataType::Binary | DataType::Categorical(..)
+    ) || int_bounds(dtype).is_some()
+        || dtype.is_float()
+}
+
+/// The range of the values of an integer data type that fit in an `i64`.
+fn int_bounds(dtype: &DataType) -> Option<(i64, i64)> {
+    let bounds = match dtype {
+        DataType::Int8 => (i8::MIN as i64, i8::MAX as i64),
+        DataType::Int16 => (i16::MIN as i64, i16::MAX as i64),
+        DataType::Int32 => (i32::MIN as i64, i32::MAX as i64),
+        DataType::Int64 => (i64::MIN, i64::MAX),
+        DataType::UInt8 => (0, u8::MAX as i64),
+        DataType::UInt16 => (0, u16::MAX as i64),
+        DataType::UInt32 => (0, u32::MAX as i64),
+        DataType::UInt64 => (0, i64::MAX),
+        _ => return None,
+    };
+    Some(bounds)
+}
+
+fn nullable_vec<S: Strategy>(
+    values: S,
+    null_ratio: f64,
+    height: usize,
+) -> impl Strategy<Value = Vec<Option<S::Value>>> {
+    proptest::collection::vec(
+        (proptest::bool::weighted(null_ratio), values)
+            .prop_map(|(is_null, value)| (!is_null).then_some(value)),
+        height,
+    )
+}
+
+fn column_strategy(
+    name: PlSmallStr,
+    dtype: DataType,
+    options: ColumnStrategyOptions,
+    height: usize,
+) -> BoxedStrategy<Column> {
+    let null_ratio = options.null_ratio;
+    let values = if let Some((min, max)) = int_bounds(&dtype) {
+        let range = match &options.int_range {
+            Some(range) => range.start().clamp(&min, &max)..=range.end().clamp(&min, &max),
+            None => min..=max,
+        };
+        nullable_vec(range, null_ratio, height)
+            .prop_map(|v| Series::new(PlSmallStr::EMPTY, v))
+            .boxed()
+    } else if dtype.is_float() {
+        nullable_vec(options.float_range.clone(), null_ratio, height)
+            .prop_map(|v| Series::new(PlSmallStr::EMPTY, v))
+            .boxed()
+    } else {
+        match &dtype {
+            DataType::Boolean => nullable_vec(any::<bool>(), null_ratio, height)
+                .prop_map(|v| Series::new(PlSmallStr::EMPTY, v))
+                .boxed(),
+            DataType::Binary => {
+                let value = proptest::collection::vec(any::<u8>(), options.len_range.clone());
+                nullable_vec(value, null_ratio, height)
+                    .prop_map(|v| Series::new(PlSmallStr::EMPTY, v))
+                    .boxed()
+            },
+            _ => {
+                let value = proptest::collection::vec(
+                    proptest::char::range('a', 'z'),
+                    options.len_range.clone(),
+                )
+                .prop_map(String::from_iter);
+                nullable_vec(value, null_ratio, height)
+                    .prop_map(|v| Series::new(PlSmallStr::EMPTY, v))
+                    .boxed()
+            },
+        }
+    };
+
+    values
+        .prop_map(move |s| {
+            let mut s = s.strict_cast(&dtype).unwrap();
+            if options.sorted != IsSorted::Not {
+                let descending = options.sorted == IsSorted::Descending;
+                s = s
+                    .sort(SortOptions::default().with_order_descending(descending))
+                    .unwrap();
+            }
+            s.with_name(name.clone()).into_column()
+        })
+        .boxed()
+}
+
+/// Split `df` into chunks that start at the increasing `offsets`.
+fn split_chunks(df: DataFrame, offsets: Vec<usize>) -> DataFrame {
+    let height = df.height();
+    let mut out = df.slice(0, offsets.first().copied().unwrap_or(height));
+    let ends = offsets.iter().skip(1).copied().chain([height]);
+    for (start, end) in offsets.iter().copied().zip(ends) {
+        out.vstack_mut(&df.slice(start as i64, end - start))
+            .unwrap();
+    }
+    out
+}
+
+impl DataFrameStrategy {
+    /// Creates a strategy that generates DataFrames with `schema`.
+    ///
+    /// Supports boolean, integer (except 128-bit), float, string, binary and categorical columns.
+    pub fn new(schema: Schema) -> PolarsResult<Self> {
+        for (name, dtype) in schema.iter() {
+            polars_ensure!(
+                is_supported(dtype),
+                InvalidOperation: "cannot generate values of type {} for column '{}'", dtype, name
+            );
+        }
+        Ok(Self {
+            schema,
+            height: 0..=10,
+            n_chunks: 1..=1,
+            default_options: ColumnStrategyOptions::default(),
+            column_options: PlHashMap::new(),
+        })
+    }
+
+    /// Sets the range of the heights of the DataFrames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty.
+    pub fn with_height(mut self, value: RangeInclusive<usize>) -> Self {
+        assert!(!value.is_empty(), "height range must not be empty");
+        self.height = value;
+        self
+    }
+
+    /// Sets the range of the number of chunks of the DataFrames.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is empty.
+    pub fn with_n_chunks(mut self, value: RangeInclusive<usize>) -> Self {
+        assert!(!value.is_empty(), "chunk count range must not be empty");
+        self.n_chunks = value;
+        self
+    }
+
+    /// Sets the options of the columns without options of their own.
+    pub fn with_default_column_options(mut self, value: ColumnStrategyOptions) -> Self {
+        self.default_options = value;
+        self
+    }
+
+    /// Sets the options of the column `name`. Options for columns that are not in the schema are
+    /// ignored.
+    pub fn with_column_options(
+        mut self,
+        name: impl Into<PlSmallStr>,
+        value: ColumnStrategyOptions,
+    ) -> Self {
+        self.column_options.insert(name.into(), value);
+        self
+    }
+
+    fn to_boxed(&self) -> BoxedStrategy<DataFrame> {
+        let this = self.clone();
+        (self.height.clone(), self.n_chunks.clone())
+            .prop_flat_map(move |(height, n_chunks)| {
+                let height = if this.schema.is_empty() { 0 } else { height };
+                let columns: Vec<_> = this
+                    .schema
+                    .iter()
+                    .map(|(name, dtype)| {
+                        let options = this
+                            .column_options
+                            .get(name)
+                            .unwrap_or(&this.default_options);
+                        column_strategy(name.clone(), dtype.clone(), options.clone(), height)
+                    })
+                    .collect();
+                // Every chunk but the first starts at a distinct offset, so none are empty.
+                let n_splits = n_chunks.saturating_sub(1).min(height.saturating_sub(1));
+                let offsets =
+                    proptest::sample::subsequence((1..height).collect::<Vec<_>>(), n_splits);
+                (columns, offsets)
+            })
+            .prop_map(|(columns, offsets)| split_chunks(DataFrame::new(columns).unwrap(), offsets))
+            .boxed()
+    }
+}
+
+impl Strategy for DataFrameStrategy {
+    type Tree = <BoxedStrategy<DataFrame> as Strategy>::Tree;
+    type Value = DataFrame;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        self.to_boxed().new_tree(runner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> Schema {
+        Schema::from_iter([
+            Field::new("a".into(), DataType::Int8),
+            Field::new("b".into(), DataType::Float64),
+            Field::new("c".into(), DataType::String),
+            Field::new("d".into(), DataType::Boolean),
+        ])
+    }
+
+    #[test]
+    fn test_dataframe_strategy_unsupported_dtype() {
+        let schema = Schema::from_iter([Field::new(
+            "a".into(),
+            DataType::List(Box::new(DataType::Int32)),
+        )]);
+        assert!(DataFrameStrategy::new(schema).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn test_dataframe_strategy_constraints(
+            df in DataFrameStrategy::new(schema())
+                .unwrap()
+                .with_height(5..=20)
+                .with_n_chunks(2..=3)
+                .with_column_options(
+                    "a",
+                    ColumnStrategyOptions::default()
+                        .with_null_ratio(0.0)
+                        .with_int_range(-3..=1000)
+                        .with_sorted(IsSorted::Descending),
+                )
+                .with_column_options(
+                    "b",
+                    ColumnStrategyOptions::default()
+                        .with_null_ratio(1.0),
+                )
+        ) {
+            prop_assert_eq!(df.schema().as_ref(), &schema());
+            prop_assert!((5..=20).contains(&df.height()));
+            prop_assert!((2..=3).contains(&df.first_col_n_chunks()));
+
+            let a = df.column("a").unwrap().i8().unwrap();
+            prop_assert_eq!(a.null_count(), 0);
+            prop_assert!(a.min().unwrap() >= -3);
+            let values: Vec<_> = a.into_no_null_iter().collect();
+            prop_assert!(values.is_sorted_by(|x, y| x >= y));
+
+            prop_assert_eq!(df.column("b").unwrap().null_count(), df.height());
+        }
+
+        #[test]
+        fn test_dataframe_strategy_empty_schema(
+            df in DataFrameStrategy::new(Schema::default())
+                .unwrap()
+                .with_height(1..=10)
+                .with_n_chunks(1..=3)
+        ) {
+            prop_assert_eq!(df.height(), 0);
+            prop_assert_eq!(df.width(), 0);
+        }
+
+        #[test]
+        fn test_dataframe_strategy_int_range_outside_dtype(
+            df in DataFrameStrategy::new(Schema::from_iter([Field::new("a".into(), DataType::UInt8)]))
+                .unwrap()
+                .with_default_column_options(
+                    ColumnStrategyOptions::default().with_int_range(-10..=-5),
+                )
+        ) {
+            let a = df.column("a").unwrap().u8().unwrap();
+            prop_assert!(a.into_iter().flatten().all(|v| v == 0));
+        }
+    }
+}
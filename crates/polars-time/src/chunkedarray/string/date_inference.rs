@@ -0,0 +1,201 @@
+use std::borrow::Cow;
+
+use arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+use chrono::format::{Item, Numeric, StrftimeItems};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime};
+use polars_core::chunked_array::temporal::time_to_time64ns;
+use polars_core::prelude::arity::unary_elementwise;
+use polars_core::prelude::*;
+
+use super::patterns;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The temporal types a format can parse into, in the order they are tried.
+#[derive(Clone, Copy, PartialEq)]
+enum Temporal {
+    DatetimeTz,
+    Datetime,
+    Date,
+    Time,
+}
+
+impl Temporal {
+    const ALL: [Self; 4] = [Self::DatetimeTz, Self::Datetime, Self::Date, Self::Time];
+
+    fn dtype(self) -> DataType {
+        match self {
+            Self::DatetimeTz => DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+            Self::Datetime => DataType::Datetime(TimeUnit::Microseconds, None),
+            Self::Date => DataType::Date,
+            Self::Time => DataType::Time,
+        }
+    }
+}
+
+fn has_two_digit_year(format: &str) -> bool {
+    StrftimeItems::new(format).any(|item| matches!(item, Item::Numeric(Numeric::YearMod100, _)))
+}
+
+/// The inference of the formats of temporal values in columns of strings with
+/// [`DateInferenceOptions`].
+pub trait DateInference {
+    /// Get the first candidate format that parses all `values`, and the dtype it parses them
+    /// into, or `None` if there are no values or no format parses all of them.
+    fn infer_format(&self, values: &[&str]) -> Option<(PlSmallStr, DataType)>;
+
+    /// Parse `ca` into `dtype` with a format returned by [`Self::infer_format`], where the values
+    /// that it cannot parse become null.
+    fn parse(&self, ca: &StringChunked, format: &str, dtype: &DataType) -> PolarsResult<Series>;
+}
+
+impl DateInference for DateInferenceOptions {
+    fn infer_format(&self, values: &[&str]) -> Option<(PlSmallStr, DataType)> {
+        let (first, rest) = values.split_first()?;
+        candidates(self).into_iter().find_map(|format| {
+            let temporal = Temporal::ALL
+                .into_iter()
+                .find(|&temporal| parse_value(self, first, format, temporal).is_some())?;
+            rest.iter()
+                .all(|val| parse_value(self, val, format, temporal).is_some())
+                .then(|| (format.into(), temporal.dtype()))
+        })
+    }
+
+    fn parse(&self, ca: &StringChunked, format: &str, dtype: &DataType) -> PolarsResult<Series> {
+        let temporal = match dtype {
+            DataType::Datetime(_, Some(_)) => Temporal::DatetimeTz,
+            DataType::Datetime(_, None) => Temporal::Datetime,
+            DataType::Date => Temporal::Date,
+            DataType::Time => Temporal::Time,
+            dt => polars_bail!(InvalidOperation: "cannot parse strings with a format into {}", dt),
+        };
+        let physical: Int64Chunked =
+            unary_elementwise(ca, |val| parse_value(self, val?, format, temporal));
+        let out = match temporal {
+            Temporal::DatetimeTz => physical
+                .into_datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+                .into_series(),
+            Temporal::Datetime => physical
+                .into_datetime(TimeUnit::Microseconds, None)
+                .into_series(),
+            Temporal::Date => physical.cast(&DataType::Int32)?.cast(&DataType::Date)?,
+            Temporal::Time => physical.into_time().into_series(),
+        };
+        out.cast(dtype)
+    }
+}
+
+fn candidates(options: &DateInferenceOptions) -> Vec<&str> {
+    match options.formats() {
+        Some(formats) => formats.iter().map(|format| format.as_str()).collect(),
+        None => [
+            patterns::DATE_Y_M_D,
+            patterns::DATE_D_M_Y,
+            patterns::DATE_M_D_Y,
+            patterns::DATETIME_Y_M_D,
+            patterns::DATETIME_D_M_Y,
+            patterns::DATETIME_Y_M_D_Z,
+            patterns::TIME_H_M_S,
+        ]
+        .concat(),
+    }
+}
+
+/// Replace the localized month names in `val` by the English ones.
+fn localize<'a>(options: &DateInferenceOptions, val: &'a str) -> Cow<'a, str> {
+    let Some(names) = options.month_names() else {
+        return Cow::Borrowed(val);
+    };
+    let bytes = val.as_bytes();
+    let mut out = String::new();
+    let mut copied = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let month = names
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| {
+                bytes[i..]
+                    .get(..name.len())
+                    .is_some_and(|b| b.eq_ignore_ascii_case(name.as_bytes()))
+            })
+            .max_by_key(|(_, name)| name.len());
+        match month {
+            Some((month, name)) => {
+                out.push_str(&val[copied..i]);
+                out.push_str(MONTH_NAMES[month]);
+                i += name.len();
+                copied = i;
+            },
+            None => i += 1,
+        }
+    }
+    if copied == 0 {
+        return Cow::Borrowed(val);
+    }
+    out.push_str(&val[copied..]);
+    Cow::Owned(out)
+}
+
+/// Move the year of `value` to the century the pivot puts its last two digits in.
+fn apply_pivot<T: Datelike>(
+    options: &DateInferenceOptions,
+    value: T,
+    two_digit_year: bool,
+) -> Option<T> {
+    match options.two_digit_year_pivot() {
+        Some(pivot) if two_digit_year => {
+            let year = value.year().rem_euclid(100);
+            let century = if year < pivot as i32 { 2000 } else { 1900 };
+            value.with_year(century + year)
+        },
+        _ => Some(value),
+    }
+}
+
+/// Parse `val` with `format` into the physical value of `temporal`.
+fn parse_value(
+    options: &DateInferenceOptions,
+    val: &str,
+    format: &str,
+    temporal: Temporal,
+) -> Option<i64> {
+    let val = localize(options, val);
+    let two_digit_year = has_two_digit_year(format);
+    match temporal {
+        Temporal::DatetimeTz => {
+            let dt = DateTime::<FixedOffset>::parse_from_str(&val, format).ok()?;
+            let dt = apply_pivot(options, dt, two_digit_year)?;
+            Some(datetime_to_timestamp_us(dt.naive_utc()))
+        },
+        Temporal::Datetime => {
+            let dt = NaiveDateTime::parse_from_str(&val, format).ok()?;
+            let dt = apply_pivot(options, dt, two_digit_year)?;
+            Some(datetime_to_timestamp_us(dt))
+        },
+        Temporal::Date => {
+            let date = NaiveDate::parse_from_str(&val, format).ok()?;
+            let date = apply_pivot(options, date, two_digit_year)?;
+            let ms = datetime_to_timestamp_ms(date.and_time(NaiveTime::default()));
+            Some(ms / MILLISECONDS_IN_DAY)
+        },
+        Temporal::Time => {
+            let time = NaiveTime::parse_from_str(&val, format).ok()?;
+            Some(time_to_time64ns(&time))
+        },
+    }
+}
@@ -1,5 +1,7 @@
+mod date_inference;
 pub mod infer;
 use chrono::DateTime;
+pub use date_inference::DateInference;
 mod patterns;
 mod strptime;
 use chrono::ParseError;
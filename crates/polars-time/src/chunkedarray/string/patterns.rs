@@ -7,6 +7,12 @@ pub(super) static DATE_D_M_Y: &[&str] = &[
     "%d.%m.%Y", // 31.12.2021
 ];
 
+pub(super) static DATE_M_D_Y: &[&str] = &[
+    "%m-%d-%Y", // 12-31-2021
+    "%m/%d/%Y", // 12/31/2021
+    "%m.%d.%Y", // 12.31.2021
+];
+
 pub(super) static DATE_Y_M_D: &[&str] = &[
     "%Y-%m-%d", // 2021-12-31
     "%Y/%m/%d", // 2021/12/31
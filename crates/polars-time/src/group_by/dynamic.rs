@@ -12,6 +12,7 @@ use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
+use crate::windows::sessions::group_by_sessions;
 
 #[repr(transparent)]
 struct Wrap<T>(pub T);
@@ -34,6 +35,9 @@ pub struct DynamicGroupOptions {
     pub include_boundaries: bool,
     pub closed_window: ClosedWindow,
     pub start_by: StartBy,
+    /// Align the windows to the sessions of this calendar instead of to the clock.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub calendar: Option<TradingCalendar>,
 }
 
 impl Default for DynamicGroupOptions {
@@ -47,10 +51,19 @@ impl Default for DynamicGroupOptions {
             include_boundaries: false,
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
+            calendar: None,
         }
     }
 }
 
+impl DynamicGroupOptions {
+    /// Whether the windows lie on a grid that doesn't depend on the data, so that the windows of
+    /// the rows from a point in time onwards don't depend on the rows before it.
+    pub fn has_fixed_windows(&self) -> bool {
+        self.start_by == StartBy::WindowBound && self.calendar.is_none()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -207,6 +220,10 @@ impl Wrap<&DataFrame> {
         ensure_duration_matches_dtype(options.every, time_type, "every")?;
         ensure_duration_matches_dtype(options.offset, time_type, "offset")?;
         ensure_duration_matches_dtype(options.period, time_type, "period")?;
+        polars_ensure!(
+            options.calendar.is_none() || matches!(time_type, DataType::Date | DataType::Datetime(_, _)),
+            InvalidOperation: "a trading calendar requires a Date or Datetime index column, got {}", time_type
+        );
 
         use DataType::*;
         let (dt, tu) = match time_type {
@@ -309,10 +326,21 @@ impl Wrap<&DataFrame> {
                 _ => unreachable!(),
             };
 
-        let groups = if group_by.is_none() {
-            let vals = dt.physical().downcast_iter().next().unwrap();
-            let ts = vals.values().as_slice();
-            let (groups, lower, upper) = group_by_windows(
+        let windows = |ts: &[i64]| match &options.calendar {
+            Some(calendar) => group_by_sessions(
+                calendar,
+                options.every,
+                options.period,
+                options.offset,
+                matches!(time_type, DataType::Date),
+                ts,
+                options.closed_window,
+                tu,
+                tz,
+                include_lower_bound,
+                include_upper_bound,
+            ),
+            None => group_by_windows(
                 w,
                 ts,
                 options.closed_window,
@@ -321,7 +349,13 @@ impl Wrap<&DataFrame> {
                 include_lower_bound,
                 include_upper_bound,
                 options.start_by,
-            )?;
+            ),
+        };
+
+        let groups = if group_by.is_none() {
+            let vals = dt.physical().downcast_iter().next().unwrap();
+            let ts = vals.values().as_slice();
+            let (groups, lower, upper) = windows(ts)?;
             update_bounds(lower, upper);
             PolarsResult::Ok(GroupsType::Slice {
                 groups,
@@ -340,16 +374,7 @@ impl Wrap<&DataFrame> {
                 let values = &ts[start..end];
                 check_sortedness_slice(values)?;
 
-                let (groups, lower, upper) = group_by_windows(
-                    w,
-                    values,
-                    options.closed_window,
-                    tu,
-                    tz,
-                    include_lower_bound,
-                    include_upper_bound,
-                    options.start_by,
-                )?;
+                let (groups, lower, upper) = windows(values)?;
 
                 PolarsResult::Ok((
                     groups
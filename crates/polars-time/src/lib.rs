@@ -50,4 +50,5 @@ pub use upsample::*;
 pub use utils::known_timezones;
 pub use windows::duration::Duration;
 pub use windows::group_by::ClosedWindow;
+pub use windows::sessions::TradingCalendar;
 pub use windows::window::Window;
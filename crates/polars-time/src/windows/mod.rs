@@ -8,6 +8,7 @@ pub(crate) mod bounds;
 pub(crate) mod calendar;
 pub(crate) mod duration;
 pub(crate) mod group_by;
+pub(crate) mod sessions;
 #[cfg(test)]
 mod test;
 pub(crate) mod window;
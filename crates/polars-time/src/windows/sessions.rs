@@ -0,0 +1,279 @@
+#[cfg(feature = "timezones")]
+use arrow::legacy::kernels::{Ambiguous, NonExistent};
+use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "timezones")]
+use arrow::temporal_conversions::timestamp_ns_to_datetime;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::calendar::{NS_DAY, NS_MICROSECOND, NS_MILLISECOND};
+use crate::prelude::*;
+#[cfg(feature = "timezones")]
+use crate::utils::{try_localize_datetime, unlocalize_datetime};
+
+/// The trading sessions of an exchange, which `group_by_dynamic` aligns its windows to.
+///
+/// A session runs from `open` to `close` on each day of the week in `week_mask` that isn't a
+/// holiday, except on the days in `early_closes`, of which the session closes at the given time.
+/// The times are wall clock times in the time zone of the index column.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct TradingCalendar {
+    /// Start of the sessions, in nanoseconds since midnight.
+    open: i64,
+    /// End of the regular sessions, in nanoseconds since midnight.
+    close: i64,
+    /// The days of the week, starting with Monday, that have a session.
+    week_mask: [bool; 7],
+    /// Sorted days since the epoch without a session.
+    holidays: Vec<i32>,
+    /// Days since the epoch of which the session closes early, sorted by day.
+    early_closes: Vec<(i32, i64)>,
+}
+
+impl TradingCalendar {
+    pub fn new(
+        open: i64,
+        close: i64,
+        week_mask: [bool; 7],
+        mut holidays: Vec<i32>,
+        mut early_closes: Vec<(i32, i64)>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            0 <= open && open < close && close <= NS_DAY,
+            InvalidOperation: "the open of a trading session must be before its close on the same day"
+        );
+        polars_ensure!(
+            week_mask.iter().any(|&b| b),
+            InvalidOperation: "`week_mask` of a trading calendar must contain at least one day"
+        );
+        holidays.sort_unstable();
+        holidays.dedup();
+        early_closes.sort_unstable();
+        early_closes.dedup_by_key(|(day, _)| *day);
+        polars_ensure!(
+            early_closes.iter().all(|&(_, t)| open < t && t <= close),
+            InvalidOperation: "an early close must be after the open of the session"
+        );
+        Ok(Self {
+            open,
+            close,
+            week_mask,
+            holidays,
+            early_closes,
+        })
+    }
+
+    /// The open and close of the session on `day` in nanoseconds since midnight, if it's a
+    /// trading day.
+    pub fn session(&self, day: i32) -> Option<(i64, i64)> {
+        // 1970-01-01 was a Thursday.
+        let weekday = (day as i64 + 3).rem_euclid(7) as usize;
+        if !self.week_mask[weekday] || self.holidays.binary_search(&day).is_ok() {
+            return None;
+        }
+        let close = match self.early_closes.binary_search_by_key(&day, |(d, _)| *d) {
+            Ok(i) => self.early_closes[i].1,
+            Err(_) => self.close,
+        };
+        Some((self.open, close))
+    }
+
+    /// The trading days from `day` onwards.
+    fn sessions_from(&self, day: i32) -> impl Iterator<Item = (i32, (i64, i64))> + '_ {
+        (day..).filter_map(|day| self.session(day).map(|s| (day, s)))
+    }
+}
+
+/// Converts between the timestamps of a column and nanoseconds since the epoch in wall clock
+/// time.
+struct LocalClock<'a> {
+    ns_per_unit: i64,
+    tz: Option<&'a Tz>,
+}
+
+impl LocalClock<'_> {
+    fn to_local(&self, t: i64) -> i64 {
+        let t = t * self.ns_per_unit;
+        match self.tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => {
+                datetime_to_timestamp_ns(unlocalize_datetime(timestamp_ns_to_datetime(t), tz))
+            },
+            _ => t,
+        }
+    }
+
+    fn from_local(&self, t: i64) -> PolarsResult<i64> {
+        let t = match self.tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => datetime_to_timestamp_ns(
+                try_localize_datetime(
+                    timestamp_ns_to_datetime(t),
+                    tz,
+                    Ambiguous::Raise,
+                    NonExistent::Raise,
+                )?
+                .expect("we didn't use Ambiguous::Null or NonExistent::Null"),
+            ),
+            _ => t,
+        };
+        Ok(t.div_euclid(self.ns_per_unit))
+    }
+}
+
+/// The length of a window in a trading calendar.
+enum SessionSpan {
+    /// A number of whole sessions.
+    Sessions(usize),
+    /// A duration within a session, in nanoseconds.
+    Intraday(i64),
+}
+
+impl SessionSpan {
+    fn new(duration: Duration, name: &str) -> PolarsResult<Self> {
+        polars_ensure!(
+            !duration.negative() && !duration.is_zero(),
+            InvalidOperation: "`{name}` must be positive with a trading calendar"
+        );
+        if duration.days_only() {
+            Ok(Self::Sessions(duration.days() as usize))
+        } else if duration.months() == 0 && duration.weeks() == 0 && duration.days() == 0 {
+            Ok(Self::Intraday(duration.nanoseconds()))
+        } else {
+            polars_bail!(
+                InvalidOperation:
+                "`{name}` must be a number of days (trading sessions) or a duration within a day \
+                with a trading calendar, got {duration}"
+            )
+        }
+    }
+}
+
+/// Group the sorted `time` values by windows that are aligned to the sessions of `calendar`.
+///
+/// If `every` (and `period`) is a number of days the windows span that many sessions, from the
+/// open of the first to the close of the last, or over the whole trading days if `whole_days` is
+/// set. Otherwise the windows start at the open of each session, shifted by `offset`, and are cut
+/// off at its close. Windows without values are skipped, and values outside of the sessions
+/// aren't in any window.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn group_by_sessions(
+    calendar: &TradingCalendar,
+    every: Duration,
+    period: Duration,
+    offset: Duration,
+    whole_days: bool,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    tu: TimeUnit,
+    tz: &Option<TimeZone>,
+    include_lower_bound: bool,
+    include_upper_bound: bool,
+) -> PolarsResult<(GroupsSlice, Vec<i64>, Vec<i64>)> {
+    let every = SessionSpan::new(every, "every")?;
+    let period = SessionSpan::new(period, "period")?;
+
+    #[cfg(feature = "timezones")]
+    let tz = tz.as_ref().and_then(|tz| tz.parse::<Tz>().ok());
+    #[cfg(not(feature = "timezones"))]
+    let tz: Option<Tz> = {
+        let _ = tz;
+        None
+    };
+    let clock = LocalClock {
+        ns_per_unit: match tu {
+            TimeUnit::Nanoseconds => 1,
+            TimeUnit::Microseconds => NS_MICROSECOND,
+            TimeUnit::Milliseconds => NS_MILLISECOND,
+        },
+        tz: tz.as_ref(),
+    };
+    let first_day = clock.to_local(time[0]).div_euclid(NS_DAY) as i32;
+    let last_day = clock.to_local(time[time.len() - 1]).div_euclid(NS_DAY) as i32;
+    let at = |day: i32, t: i64| clock.from_local(day as i64 * NS_DAY + t);
+
+    // The bounds of the windows in order of their start.
+    let mut windows = vec![];
+    match (every, period) {
+        (SessionSpan::Sessions(every), SessionSpan::Sessions(period)) => {
+            polars_ensure!(
+                offset.is_zero(),
+                InvalidOperation: "`offset` is not supported for windows of whole trading sessions"
+            );
+            let n_sessions = calendar
+                .sessions_from(first_day)
+                .take_while(|(day, _)| *day <= last_day)
+                .count();
+            let sessions = calendar
+                .sessions_from(first_day)
+                .take(n_sessions + period.saturating_sub(every))
+                .collect::<Vec<_>>();
+            for i in (0..n_sessions).step_by(every) {
+                let (first, (open, _)) = sessions[i];
+                let (last, (_, close)) = sessions[(i + period).min(sessions.len()) - 1];
+                let (open, close) = if whole_days {
+                    (0, NS_DAY)
+                } else {
+                    (open, close)
+                };
+                windows.push(Bounds::new(at(first, open)?, at(last, close)?));
+            }
+        },
+        (SessionSpan::Intraday(every), SessionSpan::Intraday(period)) => {
+            polars_ensure!(
+                !whole_days,
+                InvalidOperation: "windows within a trading session require a Datetime index column"
+            );
+            polars_ensure!(
+                offset.months() == 0 && offset.weeks() == 0 && offset.days() == 0,
+                InvalidOperation: "`offset` must be a duration within a day with a trading calendar"
+            );
+            let offset = if offset.negative() {
+                -offset.nanoseconds()
+            } else {
+                offset.nanoseconds()
+            };
+            for (day, (open, close)) in calendar
+                .sessions_from(first_day)
+                .take_while(|(day, _)| *day <= last_day)
+            {
+                let mut start = open + offset;
+                while start < close {
+                    let lower = start.max(open);
+                    let upper = (start + period).min(close);
+                    if lower < upper {
+                        windows.push(Bounds::new(at(day, lower)?, at(day, upper)?));
+                    }
+                    start += every;
+                }
+            }
+        },
+        _ => polars_bail!(
+            InvalidOperation:
+            "`every` and `period` must both be a number of days or both be a duration within a day \
+            with a trading calendar"
+        ),
+    }
+
+    let mut groups = Vec::with_capacity(windows.len());
+    let mut lower_bound = vec![];
+    let mut upper_bound = vec![];
+    for bounds in windows {
+        let start = time.partition_point(|&t| !bounds.is_member_entry(t, closed_window));
+        let end = time.partition_point(|&t| bounds.is_member_exit(t, closed_window));
+        if start >= end {
+            continue;
+        }
+        if include_lower_bound {
+            lower_bound.push(bounds.start);
+        }
+        if include_upper_bound {
+            upper_bound.push(bounds.stop);
+        }
+        groups.push([start as IdxSize, (end - start) as IdxSize]);
+    }
+    Ok((groups, lower_bound, upper_bound))
+}
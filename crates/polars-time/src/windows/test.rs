@@ -942,3 +942,74 @@ fn test_group_by_windows_offsets_3776() {
     .unwrap();
     assert_eq!(groups, [[0, 1], [1, 1], [2, 1]]);
 }
+
+#[test]
+fn test_group_by_sessions() {
+    use crate::windows::calendar::{NS_HOUR, NS_MINUTE};
+    use crate::windows::sessions::group_by_sessions;
+
+    let ns = |d: NaiveDate, h: u32, m: u32| {
+        d.and_hms_opt(h, m, 0)
+            .unwrap()
+            .and_utc()
+            .timestamp_nanos_opt()
+            .unwrap()
+    };
+    let days = |d: NaiveDate| (d - DateTime::UNIX_EPOCH.date_naive()).num_days() as i32;
+    let thanksgiving = NaiveDate::from_ymd_opt(2024, 11, 28).unwrap();
+    let friday = NaiveDate::from_ymd_opt(2024, 11, 29).unwrap();
+    let monday = NaiveDate::from_ymd_opt(2024, 12, 2).unwrap();
+
+    let calendar = TradingCalendar::new(
+        (9 * 60 + 30) * NS_MINUTE,
+        16 * NS_HOUR,
+        [true, true, true, true, true, false, false],
+        vec![days(thanksgiving)],
+        vec![(days(friday), 13 * NS_HOUR)],
+    )
+    .unwrap();
+    let ts = [
+        ns(thanksgiving, 10, 0),
+        ns(friday, 9, 30),
+        ns(friday, 11, 0),
+        ns(friday, 12, 59),
+        // After the early close.
+        ns(friday, 13, 30),
+        // Before the open.
+        ns(monday, 9, 0),
+        ns(monday, 15, 59),
+    ];
+
+    let group = |every: &str| {
+        group_by_sessions(
+            &calendar,
+            Duration::parse(every),
+            Duration::parse(every),
+            Duration::parse("0ns"),
+            false,
+            &ts,
+            ClosedWindow::Left,
+            TimeUnit::Nanoseconds,
+            &None,
+            true,
+            true,
+        )
+        .unwrap()
+    };
+
+    let (groups, lower, upper) = group("2h");
+    assert_eq!(groups, [[1, 2], [3, 1], [6, 1]]);
+    assert_eq!(
+        lower,
+        [ns(friday, 9, 30), ns(friday, 11, 30), ns(monday, 15, 30)]
+    );
+    assert_eq!(
+        upper,
+        [ns(friday, 11, 30), ns(friday, 13, 0), ns(monday, 16, 0)]
+    );
+
+    let (groups, lower, upper) = group("1d");
+    assert_eq!(groups, [[1, 3], [6, 1]]);
+    assert_eq!(lower, [ns(friday, 9, 30), ns(monday, 9, 30)]);
+    assert_eq!(upper, [ns(friday, 13, 0), ns(monday, 16, 0)]);
+}
@@ -0,0 +1,155 @@
+//! Pinning of the Polars worker threads to a set of cores.
+//!
+//! The cores are configured with either of these environment variables, read on startup:
+//! * `POLARS_CPU_AFFINITY` -> a list of cores such as `0-7,16-23`.
+//! * `POLARS_NUMA_NODE` -> a NUMA node, whose cores are used. Memory is then also preferably
+//!   allocated on that node by the worker threads.
+//!
+//! This is only supported on Linux, on other platforms no threads are pinned.
+use std::sync::LazyLock;
+
+/// The cores and NUMA node configured for the worker threads.
+#[derive(Debug, Default)]
+pub struct ThreadAffinity {
+    cpus: Vec<usize>,
+    numa_node: Option<usize>,
+}
+
+pub static THREAD_AFFINITY: LazyLock<ThreadAffinity> = LazyLock::new(|| {
+    if let Ok(list) = std::env::var("POLARS_CPU_AFFINITY") {
+        let cpus =
+            parse_cpu_list(&list).unwrap_or_else(|e| panic!("invalid POLARS_CPU_AFFINITY: {e}"));
+        return ThreadAffinity {
+            cpus,
+            numa_node: None,
+        };
+    }
+    if let Ok(node) = std::env::var("POLARS_NUMA_NODE") {
+        let node = node
+            .parse::<usize>()
+            .unwrap_or_else(|_| panic!("invalid POLARS_NUMA_NODE: expected an integer"));
+        let list = std::fs::read_to_string(format!("/sys/devices/system/node/node{node}/cpulist"))
+            .unwrap_or_else(|e| panic!("invalid POLARS_NUMA_NODE: cannot read its cores: {e}"));
+        let cpus = parse_cpu_list(list.trim())
+            .unwrap_or_else(|e| panic!("invalid POLARS_NUMA_NODE: cannot read its cores: {e}"));
+        return ThreadAffinity {
+            cpus,
+            numa_node: Some(node),
+        };
+    }
+    ThreadAffinity::default()
+});
+
+/// Parse a list of cores in the format of `taskset --cpu-list`, e.g. `0-3,8,10-11`.
+pub fn parse_cpu_list(list: &str) -> Result<Vec<usize>, String> {
+    let mut cpus = Vec::new();
+    for part in list.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let parse = |s: &str| {
+            s.trim()
+                .parse::<usize>()
+                .map_err(|_| format!("'{part}' is not a core or a range of cores"))
+        };
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(format!("'{part}' is an empty range of cores"));
+                }
+                cpus.extend(start..=end);
+            },
+            None => cpus.push(parse(part)?),
+        }
+    }
+    if cpus.is_empty() {
+        return Err("no cores given".to_string());
+    }
+    cpus.sort_unstable();
+    cpus.dedup();
+    Ok(cpus)
+}
+
+impl ThreadAffinity {
+    /// The configured cores, empty if the threads are not pinned.
+    pub fn cpus(&self) -> &[usize] {
+        &self.cpus
+    }
+
+    /// The number of worker threads to use if it is not set explicitly.
+    pub fn default_num_threads(&self) -> Option<usize> {
+        (!self.cpus.is_empty()).then_some(self.cpus.len())
+    }
+
+    /// Pin the calling thread, the `i`-th worker of its pool, to its core.
+    ///
+    /// Failing to pin is not an error, the thread then runs unpinned.
+    pub fn pin_worker(&self, i: usize) {
+        if self.cpus.is_empty() {
+            return;
+        }
+        let cpu = self.cpus[i % self.cpus.len()];
+        #[cfg(target_os = "linux")]
+        unsafe {
+            // `libc::cpu_set_t` only holds 1024 cores, so build a mask that fits any core.
+            let mask = bit_mask(cpu);
+            libc::sched_setaffinity(
+                0,
+                mask.len() * size_of::<libc::c_ulong>(),
+                mask.as_ptr().cast::<libc::cpu_set_t>(),
+            );
+
+            if let Some(node) = self.numa_node {
+                // Prefer, but don't require, memory of the node for allocations of this thread.
+                const MPOL_PREFERRED: libc::c_long = 1;
+                let mask = bit_mask(node);
+                // The kernel only reads `maxnode - 1` bits of the mask.
+                let max_node = mask.len() * 8 * size_of::<libc::c_ulong>() + 1;
+                libc::syscall(
+                    libc::SYS_set_mempolicy,
+                    MPOL_PREFERRED,
+                    mask.as_ptr(),
+                    max_node as libc::c_ulong,
+                );
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        let _ = cpu;
+    }
+}
+
+/// A mask of `c_ulong` words in which only bit `bit` is set, as the kernel expects for sets of
+/// cores and NUMA nodes.
+#[cfg(target_os = "linux")]
+fn bit_mask(bit: usize) -> Vec<libc::c_ulong> {
+    let bits = 8 * size_of::<libc::c_ulong>();
+    let mut mask = vec![0 as libc::c_ulong; bit / bits + 1];
+    mask[bit / bits] |= 1 << (bit % bits);
+    mask
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_list() {
+        assert_eq!(
+            parse_cpu_list("0-3,8, 10-11"),
+            Ok(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpu_list("3,1,3"), Ok(vec![1, 3]));
+        assert!(parse_cpu_list("").is_err());
+        assert!(parse_cpu_list("4-2").is_err());
+        assert!(parse_cpu_list("a").is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_bit_mask() {
+        let bits = 8 * size_of::<libc::c_ulong>();
+        assert_eq!(bit_mask(0), vec![1]);
+        let mask = bit_mask(2000);
+        assert_eq!(mask.len(), 2000 / bits + 1);
+        assert_eq!(mask[2000 / bits], 1 << (2000 % bits));
+        assert!(mask[..2000 / bits].iter().all(|w| *w == 0));
+    }
+}
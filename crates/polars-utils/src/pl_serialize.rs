@@ -217,6 +217,8 @@ where
 
 thread_local! {
     pub static USE_CLOUDPICKLE: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+    /// Whether deserializing Python objects raises an error instead of unpickling them.
+    pub static REJECT_PYTHON_OBJECTS: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
 }
 
 #[cfg(feature = "python")]
@@ -267,6 +269,11 @@ pub fn python_object_deserialize(buf: &[u8]) -> PolarsResult<pyo3::Py<pyo3::PyAn
 
     use crate::python_function::PYTHON3_VERSION;
 
+    polars_ensure!(
+        !REJECT_PYTHON_OBJECTS.get(),
+        InvalidOperation: "deserializing Python objects, such as user-defined functions, is not allowed"
+    );
+
     // Handle pickle metadata
     let use_cloudpickle = buf[0] != 0;
     if use_cloudpickle {
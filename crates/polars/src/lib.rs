@@ -178,6 +178,8 @@
 //!     - `regex` - Use regexes in [column selection]
 //!     - `dot_diagram` - Create dot diagrams from lazy logical plans.
 //! * `sql` - Pass SQL queries to Polars.
+//! * `asserts` - Compare [`DataFrame`]s and [`Series`] with a tolerance for floats, regardless of
+//!   their column order, or with categoricals as strings, e.g. in integration tests.
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
@@ -220,6 +222,8 @@
 //!     - `sort_multiple` - Allow sorting a [`DataFrame`] on multiple columns
 //!     - `rows` - Create [`DataFrame`] from rows and extract rows from [`DataFrame`]s.
 //!       Also activates `pivot` and `transpose` operations
+//!     - `derive` - `#[derive(IntoDataFrame, FromDataFrame)]` to convert between a `Vec` of a struct
+//!       and a [`DataFrame`] with a column for each field.
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
@@ -256,6 +260,7 @@
 //!     - `diff` - [`diff`] operation.
 //!     - `pct_change` - Compute change percentages.
 //!     - `unique_counts` - Count unique values in expressions.
+//!     - `unique_id` - Generate UUIDs and snowflake-style IDs.
 //!     - `log` - Logarithms for [`Series`].
 //!     - `list_to_struct` - Convert [`List`] to [`Struct`] dtypes.
 //!     - `list_count` - Count elements in lists.
@@ -271,6 +276,9 @@
 //!     - `extract_groups` - Extract multiple regex groups from strings.
 //!     - `cov` - Covariance and correlation functions.
 //!     - `find_many` - Find/replace multiple string patterns at once.
+//!     - `url` - Parse URLs and percent-encode/decode strings.
+//!     - `anonymize` - Stable salted hashing and rare value suppression.
+//!     - `log_parsing` - Parse web server log lines and `User-Agent` strings.
 //! * [`DataFrame`] pretty printing
 //!     - `fmt` - Activate [`DataFrame`] formatting
 //!
@@ -394,6 +402,8 @@
 //! * `POLARS_FMT_STR_LEN` -> maximum number of characters printed per string value.
 //! * `POLARS_TABLE_WIDTH` -> width of the tables used during DataFrame formatting.
 //! * `POLARS_MAX_THREADS` -> maximum number of threads used to initialize thread pool (on startup).
+//! * `POLARS_CPU_AFFINITY` -> list of cores, e.g. `0-7,16-23`, to pin the threads of the thread pool to (on startup, Linux only).
+//! * `POLARS_NUMA_NODE` -> NUMA node to pin the threads of the thread pool to and to prefer memory of (on startup, Linux only).
 //! * `POLARS_VERBOSE` -> print logging info to stderr.
 //! * `POLARS_NO_PARTITION` -> polars may choose to partition the group_by operation, based on data
 //!   cardinality. Setting this env var will turn partitioned group_by's off.
@@ -425,6 +435,8 @@ pub use polars_core::{
 pub use polars_io as io;
 #[cfg(feature = "lazy")]
 pub use polars_lazy as lazy;
+#[cfg(feature = "asserts")]
+pub use polars_testing::{assert_dataframe_equal, assert_series_equal, asserts};
 #[cfg(feature = "temporal")]
 pub use polars_time as time;
 #[doc(hidden)]
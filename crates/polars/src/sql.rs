@@ -1,2 +1,2 @@
 pub use polars_sql::function_registry::*;
-pub use polars_sql::{SQLContext, keywords, sql_expr};
+pub use polars_sql::{SQLContext, TablePolicy, keywords, sql_expr};
@@ -0,0 +1,43 @@
+use super::*;
+
+#[derive(Debug, PartialEq, IntoDataFrame, FromDataFrame)]
+struct Row {
+    name: String,
+    #[polars(rename = "count")]
+    n: Option<u32>,
+    #[polars(dtype = DataType::Date)]
+    day: i32,
+}
+
+#[test]
+fn test_derive_dataframe_roundtrip() -> PolarsResult<()> {
+    let rows = vec![
+        Row {
+            name: "a".to_string(),
+            n: Some(1),
+            day: 0,
+        },
+        Row {
+            name: "b".to_string(),
+            n: None,
+            day: 365,
+        },
+    ];
+
+    let df = Row::into_df(rows)?;
+    assert_eq!(df.get_column_names_str(), ["name", "count", "day"]);
+    assert_eq!(df.column("count")?.dtype(), &DataType::UInt32);
+    assert_eq!(df.column("day")?.dtype(), &DataType::Date);
+
+    let rows = Row::from_df(&df)?;
+    assert_eq!(rows[1].n, None);
+    assert_eq!(rows[1].day, 365);
+    assert!(Row::into_df(rows)?.equals_missing(&df));
+
+    // Nulls can only be read into options.
+    let df = df
+        .lazy()
+        .with_column(lit(NULL).cast(DataType::String).alias("name"));
+    assert!(Row::from_df(&df.collect()?).is_err());
+    Ok(())
+}
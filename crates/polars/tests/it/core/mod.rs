@@ -1,4 +1,6 @@
 mod date_like;
+#[cfg(feature = "derive")]
+mod derive;
 mod group_by;
 mod joins;
 mod list;
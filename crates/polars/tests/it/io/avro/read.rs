@@ -375,3 +375,82 @@ fn test_list() -> PolarsResult<()> {
     assert_eq!(result, expected);
     Ok(())
 }
+
+fn write_logical(duration: Duration) -> Result<Vec<u8>, Box<apache_avro::Error>> {
+    let raw_schema = r#"
+    {
+        "type": "record",
+        "name": "test",
+        "fields": [
+            {"name": "decimal", "type": {
+                "type": "bytes",
+                "logicalType": "decimal",
+                "precision": 10,
+                "scale": 2
+            }},
+            {"name": "local_ts", "type": {
+                "type": "long",
+                "logicalType": "local-timestamp-millis"
+            }},
+            {"name": "duration", "type": {
+                "type": "fixed",
+                "name": "duration",
+                "size": 12,
+                "logicalType": "duration"
+            }}
+        ]
+    }
+"#;
+    let avro = AvroSchema::parse_str(raw_schema).unwrap();
+    let mut writer = Writer::new(&avro, Vec::new());
+
+    let mut record = Record::new(writer.schema()).unwrap();
+    record.put("decimal", Value::Decimal(vec![0x7b].into()));
+    record.put("local_ts", Value::LocalTimestampMillis(1_000));
+    record.put("duration", Value::Duration(duration));
+    writer.append(record)?;
+    writer.into_inner().map_err(Box::new)
+}
+
+#[test]
+fn read_logical_types_into_polars() -> PolarsResult<()> {
+    use polars::io::SerReader;
+    use polars::io::avro::AvroReader;
+    use polars::prelude::{DataType, TimeUnit};
+
+    let duration = Duration::new(Months::new(0), Days::new(1), Millis::new(5));
+    let avro = write_logical(duration).unwrap();
+    let df = AvroReader::new(std::io::Cursor::new(avro)).finish()?;
+
+    assert_eq!(
+        df.dtypes(),
+        vec![
+            DataType::Decimal(Some(10), Some(2)),
+            DataType::Datetime(TimeUnit::Milliseconds, None),
+            DataType::Duration(TimeUnit::Milliseconds),
+        ]
+    );
+    let physical = df
+        .select_columns(["local_ts", "duration"])?
+        .into_iter()
+        .map(|c| c.cast(&DataType::Int64).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(physical[0].i64()?.get(0), Some(1_000));
+    assert_eq!(physical[1].i64()?.get(0), Some(86_400_005));
+    assert_eq!(
+        df.column("decimal")?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .get(0),
+        Some(1.23)
+    );
+
+    let duration = Duration::new(Months::new(1), Days::new(0), Millis::new(0));
+    let avro = write_logical(duration).unwrap();
+    assert!(
+        AvroReader::new(std::io::Cursor::new(avro))
+            .finish()
+            .is_err()
+    );
+    Ok(())
+}
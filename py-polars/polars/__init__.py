@@ -46,7 +46,7 @@ from polars.convert import (
     from_torch,
     json_normalize,
 )
-from polars.dataframe import DataFrame
+from polars.dataframe import CompressedFrame, DataFrame
 from polars.datatype_expr import DataTypeExpr
 from polars.datatypes import (
     Array,
@@ -61,6 +61,7 @@ from polars.datatypes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -95,9 +96,11 @@ from polars.functions import (
     arg_sort_by,
     arg_where,
     business_day_count,
+    cached,
     coalesce,
     col,
     collect_all,
+    collect_all_as_completed,
     collect_all_async,
     concat,
     concat_arr,
@@ -133,6 +136,8 @@ from polars.functions import (
     implode,
     int_range,
     int_ranges,
+    is_first_distinct,
+    is_last_distinct,
     last,
     len,
     linear_space,
@@ -145,12 +150,15 @@ from polars.functions import (
     mean,
     mean_horizontal,
     median,
+    median_horizontal,
     min,
     min_horizontal,
     n_unique,
     nth,
     ones,
+    placeholder,
     quantile,
+    quantile_horizontal,
     reduce,
     repeat,
     rolling_corr,
@@ -159,8 +167,11 @@ from polars.functions import (
     select,
     self_dtype,
     set_random_seed,
+    sink_multiple,
+    snowflake_id,
     sql_expr,
     std,
+    std_horizontal,
     struct,
     sum,
     sum_horizontal,
@@ -168,6 +179,7 @@ from polars.functions import (
     time,
     time_range,
     time_ranges,
+    uuid4,
     var,
     when,
     zeros,
@@ -175,14 +187,20 @@ from polars.functions import (
 from polars.interchange import CompatLevel
 from polars.io import (
     BasePartitionContext,
+    DateInference,
+    InferredSchema,
     KeyedPartition,
     KeyedPartitionContext,
     PartitionByKey,
     PartitionMaxSize,
     PartitionParted,
     ScanCastOptions,
+    ScanFileErrors,
+    SharedMemoryFrame,
     defer,
+    infer_schema,
     read_avro,
+    read_bson,
     read_clipboard,
     read_csv,
     read_csv_batched,
@@ -194,11 +212,15 @@ from polars.io import (
     read_ipc_schema,
     read_ipc_stream,
     read_json,
+    read_msgpack,
     read_ndjson,
     read_ods,
     read_parquet,
     read_parquet_metadata,
+    read_parquet_row_group_metadata,
     read_parquet_schema,
+    read_protobuf,
+    scan_avro,
     scan_csv,
     scan_delta,
     scan_iceberg,
@@ -215,7 +237,15 @@ from polars.io.cloud import (
     CredentialProviderFunctionReturn,
     CredentialProviderGCP,
 )
-from polars.lazyframe import GPUEngine, LazyFrame, QueryOptFlags
+from polars.lazyframe import (
+    FeatureSet,
+    GPUEngine,
+    GPUFallbackReport,
+    GPUUnsupportedOperation,
+    LazyFrame,
+    QueryOptFlags,
+    StreamingEngine,
+)
 from polars.meta import (
     build_info,
     get_index_type,
@@ -223,15 +253,23 @@ from polars.meta import (
     thread_pool_size,
     threadpool_size,
 )
+from polars.result_cache import (
+    clear_result_cache,
+    disable_result_cache,
+    enable_result_cache,
+    using_result_cache,
+)
 from polars.schema import Schema
 from polars.series import Series
 from polars.sql import SQLContext, sql
 from polars.string_cache import (
+    SharedStringCache,
     StringCache,
     disable_string_cache,
     enable_string_cache,
     using_string_cache,
 )
+from polars.trading_calendar import TradingCalendar
 
 __version__: str = _get_polars_version()
 del _get_polars_version
@@ -244,11 +282,17 @@ __all__ = [
     "selectors",
     # core classes
     "DataFrame",
+    "CompressedFrame",
     "Expr",
     "LazyFrame",
     "Series",
     # Engine configuration
     "GPUEngine",
+    "GPUFallbackReport",
+    "GPUUnsupportedOperation",
+    "StreamingEngine",
+    # feature sets
+    "FeatureSet",
     # schema
     "Schema",
     # datatype_expr
@@ -266,6 +310,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int8",
@@ -287,6 +332,7 @@ __all__ = [
     "Utf8",
     # polars.io
     "defer",
+    "DateInference",
     "KeyedPartition",
     "BasePartitionContext",
     "KeyedPartitionContext",
@@ -294,7 +340,12 @@ __all__ = [
     "PartitionMaxSize",
     "PartitionParted",
     "ScanCastOptions",
+    "ScanFileErrors",
+    "SharedMemoryFrame",
+    "InferredSchema",
+    "infer_schema",
     "read_avro",
+    "read_bson",
     "read_clipboard",
     "read_csv",
     "read_csv_batched",
@@ -306,11 +357,15 @@ __all__ = [
     "read_ipc_schema",
     "read_ipc_stream",
     "read_json",
+    "read_msgpack",
     "read_ndjson",
     "read_ods",
     "read_parquet",
     "read_parquet_metadata",
+    "read_parquet_row_group_metadata",
     "read_parquet_schema",
+    "read_protobuf",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -326,13 +381,21 @@ __all__ = [
     "CredentialProviderFunction",
     "CredentialProviderFunctionReturn",
     "CredentialProviderGCP",
+    # polars.result_cache
+    "clear_result_cache",
+    "disable_result_cache",
+    "enable_result_cache",
+    "using_result_cache",
     # polars.stringcache
+    "SharedStringCache",
     "StringCache",
     "disable_string_cache",
     "enable_string_cache",
     "using_string_cache",
     # polars.config
     "Config",
+    # polars.trading_calendar
+    "TradingCalendar",
     # polars.functions.whenthen
     "when",
     # polars.functions
@@ -363,8 +426,11 @@ __all__ = [
     "max",
     "max_horizontal",
     "mean_horizontal",
+    "median_horizontal",
     "min",
     "min_horizontal",
+    "quantile_horizontal",
+    "std_horizontal",
     "sum",
     "sum_horizontal",
     # polars.functions.lazy
@@ -373,9 +439,11 @@ __all__ = [
     "arctan2",
     "arctan2d",
     "arg_sort_by",
+    "cached",
     "coalesce",
     "col",
     "collect_all",
+    "collect_all_as_completed",
     "collect_all_async",
     "concat_arr",
     "concat_list",
@@ -401,6 +469,8 @@ __all__ = [
     "implode",
     "int_range",
     "int_ranges",
+    "is_first_distinct",
+    "is_last_distinct",
     "last",
     "linear_space",
     "linear_spaces",
@@ -411,12 +481,14 @@ __all__ = [
     "median",
     "n_unique",
     "nth",
+    "placeholder",
     "quantile",
     "reduce",
     "rolling_corr",
     "rolling_cov",
     "row_index",
     "select",
+    "sink_multiple",
     "std",
     "struct",
     "tail",
@@ -426,6 +498,9 @@ __all__ = [
     "len",
     # polars.functions.random
     "set_random_seed",
+    # polars.functions.unique_id
+    "snowflake_id",
+    "uuid4",
     # polars.convert
     "from_arrow",
     "from_dataframe",
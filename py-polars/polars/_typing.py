@@ -29,7 +29,7 @@ if TYPE_CHECKING:
     from polars.dependencies import pandas as pd
     from polars.dependencies import pyarrow as pa
     from polars.dependencies import torch
-    from polars.lazyframe.engine_config import GPUEngine
+    from polars.lazyframe.engine_config import GPUEngine, StreamingEngine
     from polars.selectors import Selector
 
     with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -103,6 +103,11 @@ ColumnNameOrSelector: TypeAlias = Union[str, SelectorType]
 # The following all have an equivalent Rust enum with the same name
 Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
+BootstrapMethod: TypeAlias = Literal["iid", "block"]
+BootstrapStatistic: TypeAlias = Literal[
+    "mean", "median", "sum", "std", "var", "min", "max"
+]
+CacheHint: TypeAlias = Literal["always", "never"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
 CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
@@ -115,6 +120,7 @@ FillNullStrategy: TypeAlias = Literal[
 ]
 FloatFmt: TypeAlias = Literal["full", "mixed"]
 IndexOrder: TypeAlias = Literal["c", "fortran"]
+IntervalJoinMode: TypeAlias = Literal["overlap", "contains", "within"]
 IpcCompression: TypeAlias = Literal["uncompressed", "lz4", "zstd"]
 JoinValidation: TypeAlias = Literal["m:m", "m:1", "1:m", "1:1"]
 Label: TypeAlias = Literal["left", "right", "datapoint"]
@@ -137,7 +143,7 @@ QuantileMethod: TypeAlias = Literal[
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
-RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero"]
+RoundMode: TypeAlias = Literal["half_to_even", "half_away_from_zero", "floor", "ceil"]
 SerializationFormat: TypeAlias = Literal["binary", "json"]
 Endianness: TypeAlias = Literal["little", "big"]
 SizeUnit: TypeAlias = Literal[
@@ -315,7 +321,7 @@ MultiColSelector: TypeAlias = Union[MultiIndexSelector, MultiNameSelector, Boole
 
 # LazyFrame engine selection
 EngineType: TypeAlias = Union[
-    Literal["auto", "in-memory", "streaming", "gpu"], "GPUEngine"
+    Literal["auto", "in-memory", "streaming", "gpu"], "GPUEngine", "StreamingEngine"
 ]
 
 PlanStage: TypeAlias = Literal["ir", "physical"]
@@ -361,6 +367,8 @@ __all__ = [
     "AsofJoinStrategy",
     "AvroCompression",
     "BooleanMask",
+    "BootstrapMethod",
+    "BootstrapStatistic",
     "BufferInfo",
     "CategoricalOrdering",
     "ClosedInterval",
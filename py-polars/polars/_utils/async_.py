@@ -75,9 +75,13 @@ class _AioDataFrameResult(Awaitable[T], Generic[T]):
     __slots__ = ("loop", "result")
 
     def __init__(self) -> None:
-        from asyncio import get_event_loop
+        from asyncio import get_event_loop, get_running_loop
 
-        self.loop = get_event_loop()
+        try:
+            # Resolve the results on the loop that awaits them.
+            self.loop = get_running_loop()
+        except RuntimeError:
+            self.loop = get_event_loop()
         self.result: Future[T] = self.loop.create_future()
 
     def __await__(self) -> Generator[Any, None, T]:
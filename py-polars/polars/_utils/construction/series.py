@@ -34,6 +34,7 @@ from polars.datatypes import (
     Decimal,
     Duration,
     Enum,
+    Float16,
     List,
     Null,
     Object,
@@ -157,6 +158,7 @@ def sequence_to_pyseries(
             constructor, name, values, dtype, strict=strict
         )
         if dtype in (
+            Float16,
             Date,
             Datetime,
             Duration,
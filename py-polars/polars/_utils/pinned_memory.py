@@ -0,0 +1,32 @@
+from __future__ import annotations
+
+from polars.dependencies import import_optional
+from polars.dependencies import numpy as np
+from polars.dependencies import pyarrow as pa
+
+
+def to_pinned_memory(table: pa.Table) -> pa.Table:
+    """
+    Move the buffers of an Arrow Table into pinned (page-locked) host memory.
+
+    The table is written as an Arrow IPC stream into a single buffer that is taken
+    from the pinned memory pool of CuPy, and read back zero-copy, so that every
+    array of the result points into that buffer. The memory returns to the pool
+    once the result and all arrays taken from it are dropped, which makes repeated
+    exports cheap.
+    """
+    cupy = import_optional(
+        "cupy",
+        err_prefix="exporting to pinned memory requires",
+        err_suffix="to be installed",
+    )
+    sink = pa.MockOutputStream()
+    with pa.ipc.new_stream(sink, table.schema) as writer:
+        writer.write_table(table)
+    size = sink.size()
+
+    memory = cupy.cuda.alloc_pinned_memory(size)
+    buffer = pa.py_buffer(np.frombuffer(memory, dtype=np.uint8, count=size))
+    with pa.ipc.new_stream(pa.FixedSizeBufferWriter(buffer), table.schema) as writer:
+        writer.write_table(table)
+    return pa.ipc.open_stream(buffer).read_all()
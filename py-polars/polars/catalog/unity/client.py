@@ -4,6 +4,7 @@ import contextlib
 import importlib
 import os
 import sys
+import uuid
 from typing import TYPE_CHECKING, Any, Literal
 
 from polars._utils.unstable import issue_unstable_warning
@@ -14,9 +15,10 @@ from polars.catalog.unity.models import (
     NamespaceInfo,
     TableInfo,
 )
+from polars.exceptions import SchemaError
 
 if TYPE_CHECKING:
-    from collections.abc import Generator
+    from collections.abc import Generator, Sequence
     from datetime import datetime
 
     import deltalake
@@ -524,6 +526,7 @@ class Catalog:
         comment: str | None = None,
         storage_root: str | None = None,
         properties: dict[str, str] | None = None,
+        partition_by: Sequence[str] | None = None,
     ) -> TableInfo:
         """
         Create a table in the catalog.
@@ -552,12 +555,15 @@ class Catalog:
             Base location at which to store the table.
         properties
             Extra key-value metadata to store.
+        partition_by
+            Names of the columns of `schema` by which the table is partitioned.
         """
         return self._client.create_table(
             catalog_name=catalog_name,
             namespace=namespace,
             table_name=table_name,
             schema=schema,
+            partition_by=list(partition_by or []),
             table_type=table_type,
             data_source_format=data_source_format,
             comment=comment,
@@ -565,6 +571,226 @@ class Catalog:
             properties=list((properties or {}).items()),
         )
 
+    def update_table(
+        self,
+        catalog_name: str,
+        namespace: str,
+        table_name: str,
+        *,
+        owner: str,
+    ) -> TableInfo:
+        """
+        Update the owner of a table in the catalog.
+
+        The schema, partitioning and location of a table cannot be changed once it
+        has been created.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        catalog_name
+            Name of the catalog.
+        namespace
+            Name of the namespace (unity schema).
+        table_name
+            Name of the table.
+        owner
+            Name of the new owner of the table.
+        """
+        return self._client.update_table(
+            catalog_name=catalog_name,
+            namespace=namespace,
+            table_name=table_name,
+            owner=owner,
+        )
+
+    def sink_table(
+        self,
+        lf: LazyFrame,
+        catalog_name: str,
+        namespace: str,
+        table_name: str,
+        *,
+        storage_location: str | None = None,
+        partition_by: str | Sequence[str] | None = None,
+        comment: str | None = None,
+        properties: dict[str, str] | None = None,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+    ) -> TableInfo:
+        """
+        Write a LazyFrame to a Parquet catalog table and register it in the catalog.
+
+        The data is written to new Parquet files in the location of the table, with
+        a hive directory per partition if the table is partitioned. Once the write
+        has completed, the table is created as an external Parquet table at
+        `storage_location` if it does not exist yet. Otherwise the schema of the
+        data must match the schema of the existing table, which is checked before
+        anything is written.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        lf
+            LazyFrame to write.
+        catalog_name
+            Name of the catalog.
+        namespace
+            Name of the namespace (unity schema).
+        table_name
+            Name of the table.
+        storage_location
+            Base location of the table. This is required if the table does not
+            exist yet, and must match the location of the table otherwise.
+        partition_by
+            Names of the columns by which the table is partitioned. Defaults to the
+            partition columns of the existing table, and must match them otherwise.
+        comment
+            Leaves a comment about the table. This can only be given if the table
+            does not exist yet.
+        properties
+            Extra key-value metadata to store. This can only be given if the table
+            does not exist yet.
+        storage_options
+            Options that indicate how to connect to a cloud provider, see
+            :meth:`write_table`.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials, see :meth:`write_table`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        TableInfo
+            The metadata of the registered table.
+        """
+        from polars.io.partition import PartitionByKey
+
+        full_name = f"{catalog_name}.{namespace}.{table_name}"
+
+        if isinstance(partition_by, str):
+            partition_by = [partition_by]
+
+        schema = lf.collect_schema()
+        table_info = self._client.try_get_table_info(
+            catalog_name, namespace, table_name
+        )
+
+        if table_info is None:
+            if storage_location is None:
+                msg = (
+                    f"sink_table: table {full_name} does not exist, a "
+                    "storage_location is required to create it"
+                )
+                raise ValueError(msg)
+        else:
+            location, data_source_format = _extract_location_and_data_format(
+                table_info, "sink table"
+            )
+
+            if data_source_format != "PARQUET":
+                msg = (
+                    f"sink_table: table format of {full_name} "
+                    f"({data_source_format}) is unsupported."
+                )
+                raise NotImplementedError(msg)
+
+            if storage_location is not None and (
+                storage_location.rstrip("/") != location.rstrip("/")
+            ):
+                msg = (
+                    f"sink_table: storage_location ({storage_location}) does not "
+                    f"match the location of table {full_name} ({location})"
+                )
+                raise ValueError(msg)
+
+            storage_location = location
+
+            if comment is not None or properties is not None:
+                msg = (
+                    f"sink_table: comment and properties cannot be set on the "
+                    f"existing table {full_name}"
+                )
+                raise ValueError(msg)
+
+            partition_columns = [
+                c for c in table_info.columns or [] if c.partition_index is not None
+            ]
+            partition_columns.sort(key=lambda c: c.partition_index or 0)
+            table_partition_by = [c.name for c in partition_columns]
+
+            if partition_by is None:
+                partition_by = table_partition_by
+            elif list(partition_by) != table_partition_by:
+                msg = (
+                    f"sink_table: partition_by ({list(partition_by)}) does not match "
+                    f"the partition columns of table {full_name} "
+                    f"({table_partition_by})"
+                )
+                raise ValueError(msg)
+
+            table_schema = table_info.get_polars_schema()
+            if table_schema is not None and schema != table_schema:
+                msg = (
+                    f"sink_table: schema of the data ({schema}) does not match the "
+                    f"schema of table {full_name} ({table_schema})"
+                )
+                raise SchemaError(msg)
+
+            credential_provider, storage_options = self._init_credentials(  # type: ignore[assignment]
+                credential_provider,
+                storage_options,
+                table_info,
+                write=True,
+                caller_name="Catalog.sink_table",
+            )
+
+        base_path = storage_location.rstrip("/")
+        file_name = uuid.uuid4().hex
+
+        lf.sink_parquet(
+            PartitionByKey(
+                base_path,
+                file_path=lambda ctx: (
+                    f"{ctx.hive_dirs()}/{file_name}-{ctx.in_part_idx}.parquet"
+                ),
+                by=list(partition_by),
+                include_key=False,
+            )
+            if partition_by
+            else f"{base_path}/{file_name}.parquet",
+            storage_options=storage_options,
+            credential_provider=credential_provider,
+            mkdir=True,
+        )
+
+        if table_info is None:
+            return self.create_table(
+                catalog_name,
+                namespace,
+                table_name,
+                schema=schema,
+                table_type="EXTERNAL",
+                data_source_format="PARQUET",
+                comment=comment,
+                storage_root=storage_location,
+                properties=properties,
+                partition_by=partition_by,
+            )
+
+        return table_info
+
     def delete_table(
         self,
         catalog_name: str,
@@ -56,6 +56,7 @@ TableFormatNames: TypeAlias = Literal[
 # and/or unstable settings that should not be saved or reset with the Config vars.
 _POLARS_CFG_ENV_VARS = {
     "POLARS_WARN_UNSTABLE",
+    "POLARS_ARITHMETIC_OVERFLOW",
     "POLARS_FMT_MAX_COLS",
     "POLARS_FMT_MAX_ROWS",
     "POLARS_FMT_NUM_DECIMAL",
@@ -73,6 +74,7 @@ _POLARS_CFG_ENV_VARS = {
     "POLARS_FMT_TABLE_HIDE_DATAFRAME_SHAPE_INFORMATION",
     "POLARS_FMT_TABLE_INLINE_COLUMN_DATA_TYPE",
     "POLARS_FMT_TABLE_ROUNDED_CORNERS",
+    "POLARS_JOIN_NANS_EQUAL",
     "POLARS_STREAMING_CHUNK_SIZE",
     "POLARS_TABLE_WIDTH",
     "POLARS_VERBOSE",
@@ -89,6 +91,7 @@ with contextlib.suppress(ImportError, NameError):
     _POLARS_CFG_DIRECT_VARS = {
         "set_fmt_float": plr.get_float_fmt,
         "set_float_precision": plr.get_float_precision,
+        "set_float_summation": plr.get_float_summation,
         "set_thousands_separator": plr.get_thousands_separator,
         "set_decimal_separator": plr.get_decimal_separator,
         "set_trim_decimal_zeros": plr.get_trim_decimal_zeros,
@@ -98,14 +101,17 @@ with contextlib.suppress(ImportError, NameError):
 class ConfigParameters(TypedDict, total=False):
     """Parameters supported by the polars Config."""
 
+    arithmetic_overflow: Literal["wrap", "error", "promote"] | None
     ascii_tables: bool | None
     auto_structify: bool | None
     decimal_separator: str | None
     thousands_separator: str | bool | None
     float_precision: int | None
+    float_summation: Literal["fast", "kahan"] | None
     fmt_float: FloatFmt | None
     fmt_str_lengths: int | None
     fmt_table_cell_list_len: int | None
+    join_nans_equal: bool | None
     streaming_chunk_size: int | None
     tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -123,14 +129,17 @@ class ConfigParameters(TypedDict, total=False):
     verbose: bool | None
     expr_depth_warning: int
 
+    set_arithmetic_overflow: Literal["wrap", "error", "promote"] | None
     set_ascii_tables: bool | None
     set_auto_structify: bool | None
     set_decimal_separator: str | None
     set_thousands_separator: str | bool | None
     set_float_precision: int | None
+    set_float_summation: Literal["fast", "kahan"] | None
     set_fmt_float: FloatFmt | None
     set_fmt_str_lengths: int | None
     set_fmt_table_cell_list_len: int | None
+    set_join_nans_equal: bool | None
     set_streaming_chunk_size: int | None
     set_tbl_cell_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
     set_tbl_cell_numeric_alignment: Literal["LEFT", "CENTER", "RIGHT"] | None
@@ -483,6 +492,56 @@ class Config(contextlib.ContextDecorator):
 
         return config_state
 
+    @classmethod
+    def set_arithmetic_overflow(
+        cls, policy: Literal["wrap", "error", "promote"] | None = "wrap"
+    ) -> type[Config]:
+        """
+        Set how integer addition, subtraction and multiplication handle overflow.
+
+        Parameters
+        ----------
+        policy : {'wrap', 'error', 'promote'}
+            * "wrap": results that do not fit the dtype wrap around (default).
+            * "error": raise a :class:`~polars.exceptions.ComputeError` if any
+              result does not fit.
+            * "promote": always compute in the next wider integer dtype, for
+              instance `Int32` becomes `Int64`, and raise a
+              :class:`~polars.exceptions.ComputeError` if a result does not fit
+              that either. Unsigned dtypes are promoted to signed ones, so that
+              subtractions fit as well.
+
+        Notes
+        -----
+        Detecting overflow requires an additional pass over the data, which makes
+        integer arithmetic slower when this is set to "error" or "promote". The
+        result dtype of "promote" only depends on the input dtypes, so the schema
+        of a query matches its result.
+
+        Examples
+        --------
+        >>> s = pl.Series([100, 120], dtype=pl.Int8)
+        >>> with pl.Config(arithmetic_overflow="promote"):
+        ...     s * 2
+        shape: (2,)
+        Series: '' [i16]
+        [
+            200
+            240
+        ]
+        >>> with pl.Config(arithmetic_overflow="error"):
+        ...     s * 2  # doctest: +SKIP
+        ComputeError: integer overflow in multiplication of i8; ...
+        """
+        if policy is None:
+            os.environ.pop("POLARS_ARITHMETIC_OVERFLOW", None)
+        elif policy not in ("wrap", "error", "promote"):
+            msg = f"invalid `policy`: {policy!r}, expected 'wrap', 'error' or 'promote'"
+            raise ValueError(msg)
+        else:
+            os.environ["POLARS_ARITHMETIC_OVERFLOW"] = policy
+        return cls
+
     @classmethod
     def set_ascii_tables(cls, active: bool | None = True) -> type[Config]:
         """
@@ -727,6 +786,48 @@ class Config(contextlib.ContextDecorator):
         plr.set_float_precision(precision)
         return cls
 
+    @classmethod
+    def set_float_summation(
+        cls, method: Literal["fast", "kahan"] | None = "fast"
+    ) -> type[Config]:
+        """
+        Set how `sum` and `mean` add up floats.
+
+        Parameters
+        ----------
+        method : {'fast', 'kahan'}
+            * "fast": sum each chunk of a column pairwise, and add the values of
+              groups and the sums of chunks one after the other (default).
+            * "kahan": use compensated (Kahan) summation everywhere, including in
+              `group_by`, `over` and the streaming engine, so that the rounding
+              errors of the additions do not accumulate.
+
+        Notes
+        -----
+        Compensated summation is slower, but the result of summing a large float
+        column does not drift with the number of values or the way it is chunked.
+        Rolling sums are always compensated.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"g": [1] * 11, "x": [1e16] + [1.0] * 10})
+        >>> with pl.Config(float_summation="kahan"):
+        ...     df.group_by("g").agg(pl.col("x").sum() - 1e16)
+        shape: (1, 2)
+        ┌─────┬──────┐
+        │ g   ┆ x    │
+        │ --- ┆ ---  │
+        │ i64 ┆ f64  │
+        ╞═════╪══════╡
+        │ 1   ┆ 10.0 │
+        └─────┴──────┘
+        """
+        if method not in (None, "fast", "kahan"):
+            msg = f"invalid `method`: {method!r}, expected 'fast' or 'kahan'"
+            raise ValueError(msg)
+        plr.set_float_summation(method)
+        return cls
+
     @classmethod
     def set_fmt_float(cls, fmt: FloatFmt | None = "mixed") -> type[Config]:
         """
@@ -872,6 +973,48 @@ class Config(contextlib.ContextDecorator):
             os.environ["POLARS_FMT_TABLE_CELL_LIST_LEN"] = str(n)
         return cls
 
+    @classmethod
+    def set_join_nans_equal(cls, active: bool | None = True) -> type[Config]:
+        """
+        Set whether NaN join keys match each other by default.
+
+        Parameters
+        ----------
+        active : bool
+            * True: NaN keys match each other, like any other float value (default).
+            * False: NaN keys never match, as NaN compares unequal to itself.
+
+        Notes
+        -----
+        This is the default of the `nans_equal` parameter of `DataFrame.join` and
+        `LazyFrame.join`, which applies to the float join keys of equi-joins only.
+        The joined columns keep their NaN values either way. Other operations, such
+        as `unique` and `group_by`, always treat NaN values as equal to each other;
+        to treat them as missing data there instead, use `DataFrame.nan_to_null`
+        first.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [1.0, float("nan")], "a": [1, 2]})
+        >>> other = pl.DataFrame({"x": [1.0, float("nan")], "b": ["p", "q"]})
+        >>> with pl.Config(join_nans_equal=False):
+        ...     df.join(other, on="x", how="left")
+        shape: (2, 3)
+        ┌─────┬─────┬──────┐
+        │ x   ┆ a   ┆ b    │
+        │ --- ┆ --- ┆ ---  │
+        │ f64 ┆ i64 ┆ str  │
+        ╞═════╪═════╪══════╡
+        │ 1.0 ┆ 1   ┆ p    │
+        │ NaN ┆ 2   ┆ null │
+        └─────┴─────┴──────┘
+        """
+        if active is None:
+            os.environ.pop("POLARS_JOIN_NANS_EQUAL", None)
+        else:
+            os.environ["POLARS_JOIN_NANS_EQUAL"] = str(int(active))
+        return cls
+
     @classmethod
     def set_streaming_chunk_size(cls, size: int | None) -> type[Config]:
         """
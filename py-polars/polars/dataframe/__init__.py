@@ -1,5 +1,7 @@
+from polars.dataframe.compressed import CompressedFrame
 from polars.dataframe.frame import DataFrame
 
 __all__ = [
+    "CompressedFrame",
     "DataFrame",
 ]
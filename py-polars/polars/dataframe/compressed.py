@@ -0,0 +1,200 @@
+from __future__ import annotations
+
+from datetime import timedelta
+from io import BytesIO
+from time import monotonic
+from typing import TYPE_CHECKING
+
+from polars._utils.various import scale_bytes
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import DataFrame, Series
+    from polars._typing import SizeUnit
+    from polars.schema import Schema
+
+
+class CompressedFrame:
+    """
+    A DataFrame of which the columns are held compressed in memory.
+
+    Returned by :meth:`DataFrame.compress_in_memory`.
+
+    Every column is held as an Arrow IPC buffer in which every chunk is compressed
+    with LZ4. A column is decompressed when it is accessed, and the decompressed
+    column is kept until it has not been accessed for `cold_after` seconds, after
+    which only the compressed buffer remains. Cold columns are released at the next
+    access of the frame, or with :meth:`release`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+    >>> cf = df.compress_in_memory()
+    >>> cf["a"]
+    shape: (3,)
+    Series: 'a' [i64]
+    [
+        1
+        2
+        3
+    ]
+    >>> cf.decompressed_columns
+    ['a']
+    >>> cf.release()
+    >>> cf.decompressed_columns
+    []
+    """
+
+    _buffers: dict[str, bytes]
+    _schema: Schema
+    _height: int
+    _cold_after: float | None
+    _decompressed: dict[str, tuple[Series, float]]
+
+    def __init__(self) -> None:
+        msg = "use `DataFrame.compress_in_memory` to create a CompressedFrame"
+        raise TypeError(msg)
+
+    @classmethod
+    def _from_frame(
+        cls, df: DataFrame, cold_after: float | timedelta | None
+    ) -> CompressedFrame:
+        if isinstance(cold_after, timedelta):
+            cold_after = cold_after.total_seconds()
+        if cold_after is not None and cold_after < 0:
+            msg = f"`cold_after` must be non-negative, got {cold_after!r}"
+            raise ValueError(msg)
+
+        self = cls.__new__(cls)
+        self._buffers = {}
+        for s in df.iter_columns():
+            buf = BytesIO()
+            s.to_frame().write_ipc(buf, compression="lz4")
+            self._buffers[s.name] = buf.getvalue()
+        self._schema = df.schema
+        self._height = df.height
+        self._cold_after = cold_after
+        self._decompressed = {}
+        return self
+
+    def __repr__(self) -> str:
+        return (
+            f"<CompressedFrame of shape {self.shape} with "
+            f"{len(self._decompressed)} decompressed column(s)>"
+        )
+
+    def __len__(self) -> int:
+        return self._height
+
+    def __contains__(self, name: str) -> bool:
+        return name in self._buffers
+
+    def __getitem__(self, name: str) -> Series:
+        return self.get_column(name)
+
+    @property
+    def columns(self) -> list[str]:
+        """The names of the columns."""
+        return self._schema.names()
+
+    @property
+    def schema(self) -> Schema:
+        """The schema of the frame."""
+        return self._schema
+
+    @property
+    def height(self) -> int:
+        """The number of rows."""
+        return self._height
+
+    @property
+    def width(self) -> int:
+        """The number of columns."""
+        return len(self._buffers)
+
+    @property
+    def shape(self) -> tuple[int, int]:
+        """The number of rows and columns."""
+        return (self._height, self.width)
+
+    @property
+    def decompressed_columns(self) -> list[str]:
+        """The names of the columns that are currently held decompressed."""
+        self._release_cold()
+        return [name for name in self.columns if name in self._decompressed]
+
+    def _release_cold(self) -> None:
+        if self._cold_after is None:
+            return
+        now = monotonic()
+        self._decompressed = {
+            name: entry
+            for name, entry in self._decompressed.items()
+            if now - entry[1] < self._cold_after
+        }
+
+    def get_column(self, name: str) -> Series:
+        """
+        Get a column, decompressing it if it is not held decompressed.
+
+        Parameters
+        ----------
+        name
+            The name of the column.
+        """
+        self._release_cold()
+        if name in self._decompressed:
+            s = self._decompressed[name][0]
+        else:
+            try:
+                buf = self._buffers[name]
+            except KeyError:
+                from polars.exceptions import ColumnNotFoundError
+
+                msg = f"column {name!r} not found in the CompressedFrame"
+                raise ColumnNotFoundError(msg) from None
+            from polars.io.ipc import read_ipc
+
+            s = read_ipc(BytesIO(buf), rechunk=False).to_series()
+        if self._cold_after != 0:
+            self._decompressed[name] = (s, monotonic())
+        return s
+
+    def decompress(self, columns: Sequence[str] | None = None) -> DataFrame:
+        """
+        Get the frame, or a selection of its columns, as a DataFrame.
+
+        Parameters
+        ----------
+        columns
+            The names of the columns to get. Defaults to all columns.
+        """
+        from polars import DataFrame
+
+        names = self.columns if columns is None else list(columns)
+        return DataFrame([self.get_column(name) for name in names])
+
+    def release(self) -> None:
+        """Release all decompressed columns, keeping only the compressed buffers."""
+        self._decompressed = {}
+
+    def estimated_size(self, unit: SizeUnit = "b") -> int | float:
+        """
+        Return an estimation of the memory that the frame uses.
+
+        This is the size of the compressed buffers plus the estimated size of the
+        columns that are currently held decompressed.
+
+        Parameters
+        ----------
+        unit : {'b', 'kb', 'mb', 'gb', 'tb'}
+            Scale the returned size to the given unit.
+        """
+        sz = sum(len(buf) for buf in self._buffers.values())
+        sz += sum(s.estimated_size() for s, _ in self._decompressed.values())
+        return scale_bytes(sz, unit)
@@ -48,7 +48,6 @@ from polars._utils.deprecation import (
     issue_deprecation_warning,
 )
 from polars._utils.getitem import get_df_item_by_key
-from polars._utils.parse import parse_into_expression
 from polars._utils.pycapsule import is_pycapsule, pycapsule_to_frame
 from polars._utils.serde import serialize_polars_object
 from polars._utils.unstable import issue_unstable_warning, unstable
@@ -62,7 +61,7 @@ from polars._utils.various import (
     scale_bytes,
     warn_null_comparison,
 )
-from polars._utils.wrap import wrap_expr, wrap_ldf, wrap_s
+from polars._utils.wrap import wrap_ldf, wrap_s
 from polars.dataframe._html import NotebookFormatter
 from polars.dataframe.group_by import DynamicGroupBy, GroupBy, RollingGroupBy
 from polars.dataframe.plotting import DataFramePlot
@@ -113,6 +112,7 @@ from polars.selectors import _expand_selector_dicts, _expand_selectors
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyDataFrame
+    from polars.polars import avro_schema_json as _avro_schema_json
     from polars.polars import dtype_str_repr as _dtype_str_repr
     from polars.polars import write_clipboard_string as _write_clipboard_string
 
@@ -131,7 +131,7 @@ if TYPE_CHECKING:
     from xlsxwriter import Workbook
     from xlsxwriter.worksheet import Worksheet
 
-    from polars import DataType, Expr, LazyFrame, Series
+    from polars import DataType, Expr, LazyFrame, Series, TradingCalendar
     from polars._typing import (
         AsofJoinStrategy,
         AvroCompression,
@@ -149,6 +149,7 @@ if TYPE_CHECKING:
         FillNullStrategy,
         FrameInitTypes,
         IndexOrder,
+        IntervalJoinMode,
         IntoExpr,
         IntoExprColumn,
         IpcCompression,
@@ -165,6 +166,7 @@ if TYPE_CHECKING:
         PartitioningScheme,
         PivotAgg,
         PolarsDataType,
+        PolarsIntegerType,
         PythonDataType,
         QuantileMethod,
         RowTotalsDefinition,
@@ -182,6 +184,9 @@ if TYPE_CHECKING:
     from polars._utils.various import NoDefault
     from polars.interchange.dataframe import PolarsDataFrame
     from polars.io.cloud import CredentialProviderFunction
+    from polars.dataframe.compressed import CompressedFrame
+    from polars.io.shared_memory import SharedMemoryFrame
+    from polars.lazyframe.feature_set import FeatureSet
     from polars.ml.torch import PolarsDataset
 
     if sys.version_info >= (3, 10):
@@ -661,6 +666,10 @@ class DataFrame:
           is shorthand for
           `alt.Chart(df).mark_attr(tooltip=True).encode(**kwargs).interactive()`
 
+        To chart millions of points without transferring them all to the browser,
+        `df.plot.downsample_lttb` and `df.plot.bin2d` reduce the frame to the rows
+        of a line plot or the counts of a heatmap first.
+
         For configuration, we suggest reading
         `Chart Configuration <https://altair-viz.github.io/altair-tutorial/notebooks/08-Configuration.html>`_.
         For example, you can:
@@ -1699,7 +1708,15 @@ class DataFrame:
         return s.get_index_signed(row)
 
     @deprecate_renamed_parameter("future", "compat_level", version="1.1")
-    def to_arrow(self, *, compat_level: CompatLevel | None = None) -> pa.Table:
+    def to_arrow(
+        self,
+        *,
+        compat_level: CompatLevel | None = None,
+        large_offsets: bool = True,
+        categorical_as_dictionary: bool = True,
+        column_options: Mapping[str, Mapping[str, Any]] | None = None,
+        pinned_memory: bool = False,
+    ) -> pa.Table:
         """
         Collect the underlying arrow arrays in an Arrow Table.
 
@@ -1716,6 +1733,24 @@ class DataFrame:
         compat_level
             Use a specific compatibility level
             when exporting Polars' internal data structures.
+        large_offsets
+            Export lists, and strings and binaries that are not views, with 64-bit
+            offsets (e.g. `large_list` and `large_string`). If set to `False`, they
+            are exported with 32-bit offsets (e.g. `list` and `string`), which raises
+            an error if the data does not fit.
+        categorical_as_dictionary
+            Export Categorical and Enum columns as dictionaries. If set to `False`,
+            they are exported as strings.
+        column_options
+            Override the options above for specific columns, given as a mapping of
+            column name to a mapping of option name to value. Options that are not
+            given for a column fall back to the ones of the whole frame.
+        pinned_memory
+            Copy the exported buffers into pinned (page-locked) host memory, which
+            can be transferred to a GPU faster than pageable memory. The memory is
+            taken from the pinned memory pool of CuPy, which must be installed, and
+            returns to it when the table is dropped, so repeatedly feeding exports
+            into GPU libraries does not allocate new pinned memory every time.
 
         Examples
         --------
@@ -1729,17 +1764,58 @@ class DataFrame:
         ----
         foo: [[1,2,3,4,5,6]]
         bar: [["a","b","c","d","e","f"]]
+
+        Export the strings with 32-bit offsets, for consumers that do not support
+        `large_string`.
+
+        >>> df.to_arrow(column_options={"bar": {"large_offsets": False}})
+        pyarrow.Table
+        foo: int64
+        bar: string
+        ----
+        foo: [[1,2,3,4,5,6]]
+        bar: [["a","b","c","d","e","f"]]
         """
         if not self.width:  # 0x0 dataframe, cannot infer schema from batches
             return pa.table({})
 
-        if compat_level is None:
-            compat_level = False  # type: ignore[assignment]
-        elif isinstance(compat_level, CompatLevel):
-            compat_level = compat_level._version  # type: ignore[attr-defined]
+        def compat_version(compat_level: CompatLevel | None) -> Any:
+            if compat_level is None:
+                return False
+            elif isinstance(compat_level, CompatLevel):
+                return compat_level._version  # type: ignore[attr-defined]
+            return compat_level
+
+        per_column = []
+        for name, options in (column_options or {}).items():
+            if unknown := set(options) - {
+                "compat_level",
+                "large_offsets",
+                "categorical_as_dictionary",
+            }:
+                msg = f"unknown export options for column {name!r}: {sorted(unknown)}"
+                raise ValueError(msg)
+            per_column.append(
+                (
+                    name,
+                    compat_version(options.get("compat_level", compat_level)),
+                    options.get("large_offsets", large_offsets),
+                    options.get("categorical_as_dictionary", categorical_as_dictionary),
+                )
+            )
+
+        record_batches = self._df.to_arrow(
+            compat_version(compat_level),
+            large_offsets,
+            categorical_as_dictionary,
+            per_column,
+        )
+        table = pa.Table.from_batches(record_batches)
+        if pinned_memory:
+            from polars._utils.pinned_memory import to_pinned_memory
 
-        record_batches = self._df.to_arrow(compat_level)
-        return pa.Table.from_batches(record_batches)
+            return to_pinned_memory(table)
+        return table
 
     @overload
     def to_dict(self, *, as_series: Literal[True] = ...) -> dict[str, Series]: ...
@@ -2789,20 +2865,43 @@ class DataFrame:
         return serialize_polars_object(serializer, file, format)
 
     @overload
-    def write_json(self, file: None = ...) -> str: ...
+    def write_json(
+        self, file: None = ..., *, pretty: bool = ..., sort_keys: bool = ...
+    ) -> str: ...
 
     @overload
-    def write_json(self, file: IOBase | str | Path) -> None: ...
+    def write_json(
+        self,
+        file: IOBase | str | Path,
+        *,
+        pretty: bool = ...,
+        sort_keys: bool = ...,
+    ) -> None: ...
 
-    def write_json(self, file: IOBase | str | Path | None = None) -> str | None:
+    def write_json(
+        self,
+        file: IOBase | str | Path | None = None,
+        *,
+        pretty: bool = False,
+        sort_keys: bool = False,
+    ) -> str | None:
         """
         Serialize to JSON representation.
 
+        The rows are written as an array of objects. The output is streamed to the
+        file chunk by chunk, so the whole JSON is never held in memory at once.
+
         Parameters
         ----------
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        pretty
+            Indent every value of an array or object on its own line, by two spaces
+            per level of nesting.
+        sort_keys
+            Order the keys of every object by name, including the fields of nested
+            structs, instead of by the order of the columns and fields.
 
         See Also
         --------
@@ -2818,11 +2917,18 @@ class DataFrame:
         ... )
         >>> df.write_json()
         '[{"foo":1,"bar":6},{"foo":2,"bar":7},{"foo":3,"bar":8}]'
+        >>> print(df.head(1).write_json(pretty=True, sort_keys=True))
+        [
+          {
+            "bar": 6,
+            "foo": 1
+          }
+        ]
         """
 
         def write_json_to_string() -> str:
             with BytesIO() as buf:
-                self._df.write_json(buf)
+                self._df.write_json(buf, pretty, sort_keys)
                 json_bytes = buf.getvalue()
             return json_bytes.decode("utf8")
 
@@ -2834,20 +2940,30 @@ class DataFrame:
             return None
         elif isinstance(file, (str, Path)):
             file = normalize_filepath(file)
-            self._df.write_json(file)
+            self._df.write_json(file, pretty, sort_keys)
             return None
         else:
-            self._df.write_json(file)
+            self._df.write_json(file, pretty, sort_keys)
             return None
 
     @overload
-    def write_ndjson(self, file: None = None) -> str: ...
+    def write_ndjson(
+        self, file: None = None, *, schema_registry: dict[str, Any] | None = ...
+    ) -> str: ...
 
     @overload
-    def write_ndjson(self, file: str | Path | IO[bytes] | IO[str]) -> None: ...
+    def write_ndjson(
+        self,
+        file: str | Path | IO[bytes] | IO[str],
+        *,
+        schema_registry: dict[str, Any] | None = ...,
+    ) -> None: ...
 
     def write_ndjson(
-        self, file: str | Path | IO[bytes] | IO[str] | None = None
+        self,
+        file: str | Path | IO[bytes] | IO[str] | None = None,
+        *,
+        schema_registry: dict[str, Any] | None = None,
     ) -> str | None:
         r"""
         Serialize to newline delimited JSON representation.
@@ -2857,6 +2973,15 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        schema_registry
+            Validate the JSON Schema of the rows, see :meth:`Schema.to_json_schema`,
+            against a schema registry with a Confluent compatible REST API before
+            writing, and register it. See :meth:`DataFrame.write_avro` for the
+            accepted keys.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -2885,6 +3010,7 @@ class DataFrame:
 
         self.lazy().sink_ndjson(
             target,
+            schema_registry=schema_registry,
             optimizations=QueryOptFlags._eager(),
             engine=engine,
         )
@@ -3142,6 +3268,8 @@ class DataFrame:
         file: str | Path | IO[bytes],
         compression: AvroCompression = "uncompressed",
         name: str = "",
+        *,
+        schema_registry: dict[str, Any] | None = None,
     ) -> None:
         """
         Write to Apache Avro file.
@@ -3154,6 +3282,26 @@ class DataFrame:
             Compression method. Defaults to "uncompressed".
         name
             Schema name. Defaults to empty string.
+        schema_registry
+            Validate the Avro schema of the file against a schema registry with a
+            Confluent compatible REST API before writing, and register it once the
+            file is written. Writing fails, without creating the file, if the registry
+            considers the schema incompatible with the latest version of the subject.
+            Accepts the keys:
+
+            * `url`: The base URL of the registry.
+            * `subject`: The subject under which the schema is registered.
+            * `compatibility`: If given, first set the compatibility mode of the
+              subject to one of `'backward'`, `'backward_transitive'`,
+              `'forward'`, `'forward_transitive'`, `'full'`,
+              `'full_transitive'` or `'none'`.
+            * `register`: Whether to register the schema as a new version of the
+              subject, or to only validate it. Defaults to `True`.
+            * `basic_auth`: A `(username, password)` tuple to authenticate with.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -3168,6 +3316,18 @@ class DataFrame:
         ... )
         >>> path: pathlib.Path = dirpath / "new_file.avro"
         >>> df.write_avro(path)
+
+        Register the schema of the file with a schema registry before writing.
+
+        >>> df.write_avro(
+        ...     path,
+        ...     name="example",
+        ...     schema_registry={
+        ...         "url": "http://localhost:8081",
+        ...         "subject": "example-value",
+        ...         "compatibility": "backward",
+        ...     },
+        ... )  # doctest: +SKIP
         """
         if compression is None:
             compression = "uncompressed"
@@ -3175,9 +3335,20 @@ class DataFrame:
             file = normalize_filepath(file)
         if name is None:
             name = ""
+        if schema_registry is not None:
+            from polars.io._schema_registry import (
+                register_schema_registry,
+                validate_schema_registry,
+            )
+
+            avro_schema = _avro_schema_json(self.schema, name)
+            validate_schema_registry(schema_registry, avro_schema, "avro")
 
         self._df.write_avro(file, compression, name)
 
+        if schema_registry is not None:
+            register_schema_registry(schema_registry, avro_schema, "avro")
+
     def write_excel(
         self,
         workbook: str | Workbook | IO[bytes] | Path | None = None,
@@ -3933,6 +4104,116 @@ class DataFrame:
         self._df.write_ipc_stream(file, compression, compat_level)
         return file if return_bytes else None  # type: ignore[return-value]
 
+    @unstable()
+    def write_shared_memory(
+        self,
+        name: str | None = None,
+        *,
+        compat_level: CompatLevel | None = None,
+    ) -> SharedMemoryFrame:
+        """
+        Write to a named shared-memory segment, so that other processes can map it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name of the segment. Defaults to a new unique name.
+        compat_level
+            Use a specific compatibility level
+            when exporting Polars' internal data structures.
+
+        Returns
+        -------
+        SharedMemoryFrame
+            A handle to the segment, which can be pickled and read in other
+            processes without copying the data.
+
+        See Also
+        --------
+        LazyFrame.sink_shared_memory
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3]})
+        >>> handle = df.write_shared_memory()
+        >>> handle.read()
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ 3   │
+        └─────┘
+        >>> handle.unlink()
+        """
+        from polars.io.shared_memory import _new_shared_memory_frame
+
+        handle = _new_shared_memory_frame(name)
+        try:
+            self.write_ipc(
+                handle.path, compression="uncompressed", compat_level=compat_level
+            )
+        except BaseException:
+            handle.path.unlink(missing_ok=True)
+            raise
+        return handle
+
+    @unstable()
+    def compress_in_memory(
+        self, *, cold_after: float | timedelta | None = 60.0
+    ) -> CompressedFrame:
+        """
+        Hold the columns of this DataFrame compressed in memory.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every chunk of every column is compressed with LZ4, and a column is only
+        decompressed when it is accessed. This lets large interim frames fit in
+        memory, at the CPU cost of decompressing the columns that are used.
+
+        Parameters
+        ----------
+        cold_after
+            The number of seconds after which a decompressed column that is not
+            accessed is released again, keeping only its compressed data. If `None`,
+            decompressed columns are kept until :meth:`CompressedFrame.release` is
+            called. If 0, decompressed columns are never kept.
+
+        Returns
+        -------
+        CompressedFrame
+            The compressed frame, which does not reference this DataFrame.
+
+        Examples
+        --------
+        >>> from datetime import timedelta
+        >>> df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]})
+        >>> cf = df.compress_in_memory(cold_after=timedelta(minutes=5))
+        >>> cf.decompress(["b"])
+        shape: (3, 1)
+        ┌─────┐
+        │ b   │
+        │ --- │
+        │ str │
+        ╞═════╡
+        │ x   │
+        │ y   │
+        │ z   │
+        └─────┘
+        """
+        from polars.dataframe.compressed import CompressedFrame
+
+        return CompressedFrame._from_frame(self, cold_after)
+
     def write_parquet(
         self,
         file: str | Path | IO[bytes],
@@ -3941,6 +4222,9 @@ class DataFrame:
         compression_level: int | None = None,
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
+        row_group_bytes: int | None = None,
+        split_row_groups_on: str | None = None,
+        split_row_groups_every: int | timedelta | None = None,
         data_page_size: int | None = None,
         use_pyarrow: bool = False,
         pyarrow_options: dict[str, Any] | None = None,
@@ -3993,6 +4277,19 @@ class DataFrame:
               - "null_count": number of null values in column (default: `True`)
         row_group_size
             Size of the row groups in number of rows. Defaults to 512^2 rows.
+        row_group_bytes
+            Target size of the row groups in bytes, based on the estimated
+            (uncompressed) in-memory size of the DataFrame. If `row_group_size` is
+            also set, the smallest of both is used.
+        split_row_groups_on
+            Name of a (sorted) column on whose value changes a new row group is
+            started. This keeps the statistics of every row group tight, which
+            improves pruning when reading a time-partitioned file.
+        split_row_groups_every
+            Granularity of `split_row_groups_on`, e.g. `timedelta(days=1)` to start a
+            new row group on every new (UTC) day. Integers are interpreted in the
+            physical unit of the column. If not set, every distinct value starts a
+            new row group.
         data_page_size
             Size of the data page in bytes. Defaults to 1024^2 bytes.
         use_pyarrow
@@ -4071,6 +4368,22 @@ class DataFrame:
         ...     use_pyarrow=True,
         ...     pyarrow_options={"partition_cols": ["watermark"]},
         ... )
+
+        Start a new row group for every day of a sorted timestamp column, so that
+        readers filtering on a day only touch the matching row groups.
+
+        >>> from datetime import datetime, timedelta
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "ts": pl.datetime_range(
+        ...             datetime(2024, 1, 1), datetime(2024, 1, 3), "6h", eager=True
+        ...         ),
+        ...     }
+        ... ).with_row_index()
+        >>> path: pathlib.Path = dirpath / "daily_row_groups.parquet"
+        >>> df.write_parquet(
+        ...     path, split_row_groups_on="ts", split_row_groups_every=timedelta(days=1)
+        ... )
         """
         if compression is None:
             compression = "uncompressed"
@@ -4089,6 +4402,9 @@ class DataFrame:
             if metadata is not None:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `metadata`"
                 raise ValueError(msg)
+            if row_group_bytes is not None or split_row_groups_on is not None:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `row_group_bytes` or `split_row_groups_on`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -4152,6 +4468,9 @@ class DataFrame:
             compression_level=compression_level,
             statistics=statistics,
             row_group_size=row_group_size,
+            row_group_bytes=row_group_bytes,
+            split_row_groups_on=split_row_groups_on,
+            split_row_groups_every=split_row_groups_every,
             data_page_size=data_page_size,
             storage_options=storage_options,
             credential_provider=credential_provider,
@@ -6499,7 +6818,130 @@ class DataFrame:
         """
         return function(self, *args, **kwargs)
 
-    def with_row_index(self, name: str = "index", offset: int = 0) -> DataFrame:
+    @unstable()
+    def split(
+        self,
+        fractions: Sequence[float],
+        *,
+        stratify_by: str | Sequence[str] | None = None,
+        shuffle: bool = True,
+        seed: int | None = None,
+    ) -> list[DataFrame]:
+        """
+        Split the rows into disjoint frames, e.g. a train and test set.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        fractions
+            The fraction of rows that goes to every split. Must sum to 1.
+        stratify_by
+            Column(s) by which to stratify, so that every split has the same
+            proportions of the values of these columns.
+        shuffle
+            Shuffle the rows before splitting. If `False`, the first rows (of every
+            stratum) go to the first split.
+        seed
+            Seed that determines the shuffling of the rows. If set to None (default),
+            a random seed is used.
+
+        See Also
+        --------
+        k_fold
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": range(10), "y": [0, 1] * 5})
+        >>> train, test = df.split([0.8, 0.2], shuffle=False)
+        >>> test
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ x   ┆ y   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 8   ┆ 0   │
+        │ 9   ┆ 1   │
+        └─────┴─────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return F.collect_all(
+            self.lazy().split(
+                fractions, stratify_by=stratify_by, shuffle=shuffle, seed=seed
+            ),
+            optimizations=QueryOptFlags._eager(),
+        )
+
+    @unstable()
+    def k_fold(
+        self,
+        k: int,
+        *,
+        group_by: str | Sequence[str] | None = None,
+        shuffle: bool = True,
+        seed: int | None = None,
+    ) -> list[tuple[DataFrame, DataFrame]]:
+        """
+        Split the rows into `k` folds for cross-validation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        k
+            Number of folds.
+        group_by
+            Column(s) of which the rows with equal values are always assigned to the
+            same fold, e.g. all measurements of the same patient.
+        shuffle
+            Shuffle the rows (or groups) before assigning them to the folds. If
+            `False`, the folds are assigned round-robin in the order of the rows.
+        seed
+            Seed that determines the shuffling of the rows. If set to None (default),
+            a random seed is used.
+
+        Returns
+        -------
+        list of tuple of DataFrame
+            For every fold a `(train, test)` pair, where `test` holds the rows of
+            the fold and `train` all other rows.
+
+        See Also
+        --------
+        split
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": range(6), "patient": [1, 1, 2, 2, 3, 3]})
+        >>> for train, test in df.k_fold(3, group_by="patient", shuffle=False):
+        ...     print(test["patient"].to_list())
+        [1, 1]
+        [2, 2]
+        [3, 3]
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        folds = self.lazy().k_fold(k, group_by=group_by, shuffle=shuffle, seed=seed)
+        dfs = F.collect_all(
+            [lf for fold in folds for lf in fold],
+            optimizations=QueryOptFlags._eager(),
+        )
+        return list(zip(dfs[::2], dfs[1::2]))
+
+    def with_row_index(
+        self,
+        name: str = "index",
+        offset: int = 0,
+        *,
+        step: int = 1,
+        dtype: PolarsIntegerType | None = None,
+    ) -> DataFrame:
         """
         Add a row index as the first column in the DataFrame.
 
@@ -6508,12 +6950,22 @@ class DataFrame:
         name
             Name of the index column.
         offset
-            Start the index at this offset. Cannot be negative.
+            Start the index at this offset. Cannot be negative, unless `dtype` is
+            `Int64`.
+        step
+            Increment the index by this value for every row. Can be negative, but
+            not zero.
+        dtype
+            Data type of the index column, either `Int64` or `UInt64`. Setting
+            this, or a `step` other than `1`, creates a 64-bit index regardless of
+            the index size of the Polars build. Defaults to `Int64` if only `step`
+            is given.
 
         Notes
         -----
         The resulting column does not have any special properties. It is a regular
-        column of type `UInt32` (or `UInt64` in `polars-u64-idx`).
+        column of type `UInt32` (or `UInt64` in `polars-u64-idx`), unless `step` or
+        `dtype` is set.
 
         Examples
         --------
@@ -6546,6 +6998,21 @@ class DataFrame:
         │ 1002 ┆ 5   ┆ 6   │
         └──────┴─────┴─────┘
 
+        A 64-bit index can start beyond the maximum index value and count in steps,
+        which is useful to generate surrogate keys.
+
+        >>> df.with_row_index("id", offset=5_000_000_000, step=10)
+        shape: (3, 3)
+        ┌────────────┬─────┬─────┐
+        │ id         ┆ a   ┆ b   │
+        │ ---        ┆ --- ┆ --- │
+        │ i64        ┆ i64 ┆ i64 │
+        ╞════════════╪═════╪═════╡
+        │ 5000000000 ┆ 1   ┆ 2   │
+        │ 5000000010 ┆ 3   ┆ 4   │
+        │ 5000000020 ┆ 5   ┆ 6   │
+        └────────────┴─────┴─────┘
+
         An index column can also be created using the expressions :func:`int_range`
         and :func:`len`.
 
@@ -6564,6 +7031,12 @@ class DataFrame:
         │ 2     ┆ 5   ┆ 6   │
         └───────┴─────┴─────┘
         """
+        if step != 1 or dtype is not None:
+            if dtype is None:
+                dtype = Int64
+            return self._from_pydf(
+                self._df.with_row_index_stepped(name, offset, step, dtype)
+            )
         try:
             return self._from_pydf(self._df.with_row_index(name, offset))
         except OverflowError:
@@ -6616,6 +7089,7 @@ class DataFrame:
         self,
         *by: IntoExpr | Iterable[IntoExpr],
         maintain_order: bool = False,
+        expand_categories: bool = False,
         **named_by: IntoExpr,
     ) -> GroupBy:
         """
@@ -6635,6 +7109,14 @@ class DataFrame:
             .. note::
                 Within each group, the order of rows is always preserved, regardless
                 of this argument.
+        expand_categories
+            Also create a group for every category of `Enum` keys that does not occur
+            in the data, similar to `observed=False` in pandas. Aggregations over these
+            groups see no rows. With multiple keys, a group is created for every
+            combination of the categories and the observed values of the other keys.
+            `Categorical` keys only contribute their observed values, as their
+            categories are shared with unrelated data. Setting this to `True` blocks the
+            possibility to run on the streaming engine.
         **named_by
             Additional columns to group by, specified as keyword arguments.
             The columns will be renamed to the keyword used.
@@ -6759,7 +7241,13 @@ class DataFrame:
                     f"    group_by({value!r})"
                 )
                 raise TypeError(msg)
-        return GroupBy(self, *by, **named_by, maintain_order=maintain_order)
+        return GroupBy(
+            self,
+            *by,
+            **named_by,
+            maintain_order=maintain_order,
+            expand_categories=expand_categories,
+        )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
     def rolling(
@@ -6931,6 +7419,7 @@ class DataFrame:
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        calendar: TradingCalendar | None = None,
     ) -> DynamicGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -7004,6 +7493,21 @@ class DataFrame:
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        calendar
+            Align the windows to the sessions of this :class:`TradingCalendar`
+            instead of to the clock, in which case `start_by` has no effect:
+
+            * If `every` is a number of days, like `'1d'`, each window spans that
+              many sessions (skipping holidays and weekends), from the open of the
+              first to the close of the last. The same goes for `period`.
+            * Otherwise each session is split in windows of `every` starting at the
+              open (shifted by `offset`), of which the last one ends at the close.
+
+            Rows outside of the sessions are not in any window.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Returns
         -------
@@ -7236,6 +7740,7 @@ class DataFrame:
             closed=closed,
             group_by=group_by,
             start_by=start_by,
+            calendar=calendar,
         )
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -7705,8 +8210,10 @@ class DataFrame:
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
         nulls_equal: bool = False,
+        nans_equal: bool | None = None,
         coalesce: bool | None = None,
         maintain_order: MaintainOrderJoin | None = None,
+        indicator: bool | str = False,
     ) -> DataFrame:
         """
         Join in SQL-like fashion.
@@ -7741,7 +8248,8 @@ class DataFrame:
                    table.
                * - **anti**
                  - Returns rows from the left table that have no match in the right
-                   table.
+                   table. Duplicate rows of the left table are all kept, like the
+                   `"left_only"` rows of a full join with `indicator=True`.
 
         left_on
             Name(s) of the left join column(s).
@@ -7770,6 +8278,12 @@ class DataFrame:
 
         nulls_equal
             Join on null values. By default null values will never produce matches.
+        nans_equal
+            Whether NaN keys match each other. If False, NaN keys never produce
+            matches, while the joined columns keep their NaN values; this cannot be
+            combined with `nulls_equal=True`. If None (default), NaN keys match unless
+            `Config.set_join_nans_equal` says otherwise. This only applies to the
+            join keys; `group_by` and `unique` always treat NaN values as equal.
         coalesce
             Coalescing behavior (merging of join columns).
 
@@ -7808,6 +8322,12 @@ class DataFrame:
                * - **right_left**
                  - First preserves the order of the right DataFrame, then the left.
 
+        indicator
+            Add a `Categorical` column that tells whether each row has a match in the
+            left table (`"left_only"`), in the right table (`"right_only"`) or in both
+            (`"both"`), like `pandas.merge(indicator=True)`. The column is named
+            `"_merge"`, or the given string.
+
         See Also
         --------
         join_asof
@@ -7934,8 +8454,10 @@ class DataFrame:
                 suffix=suffix,
                 validate=validate,
                 nulls_equal=nulls_equal,
+                nans_equal=nans_equal,
                 coalesce=coalesce,
                 maintain_order=maintain_order,
+                indicator=indicator,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -7946,6 +8468,7 @@ class DataFrame:
         other: DataFrame,
         *predicates: Expr | Iterable[Expr],
         suffix: str = "_right",
+        maintain_order: MaintainOrderJoin | None = None,
     ) -> DataFrame:
         """
         Perform a join based on one or multiple (in)equality predicates.
@@ -7955,7 +8478,8 @@ class DataFrame:
         multiple times in the result.
 
         .. note::
-            The row order of the input DataFrames is not preserved.
+            The row order of the input DataFrames is not preserved, unless
+            `maintain_order` is set.
 
         .. warning::
             This functionality is experimental. It may be
@@ -7971,6 +8495,25 @@ class DataFrame:
             be applied in the predicate.
         suffix
             Suffix to append to columns with a duplicate name.
+        maintain_order : {'none', 'left', 'right', 'left_right', 'right_left'}
+            Which DataFrame row order to preserve, if any.
+            Preserving an order prevents the use of the specialized inequality join,
+            which can be considerably slower.
+
+            .. list-table ::
+               :header-rows: 0
+
+               * - **none**
+                 - *(Default)* No specific ordering is desired. The ordering might
+                   differ across Polars versions or even between different runs.
+               * - **left**
+                 - Preserves the order of the left DataFrame.
+               * - **right**
+                 - Preserves the order of the right DataFrame.
+               * - **left_right**
+                 - First preserves the order of the left DataFrame, then the right.
+               * - **right_left**
+                 - First preserves the order of the right DataFrame, then the left.
 
         Examples
         --------
@@ -8040,6 +8583,116 @@ class DataFrame:
                 other.lazy(),
                 *predicates,
                 suffix=suffix,
+                maintain_order=maintain_order,
+            )
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
+    def join_intervals(
+        self,
+        other: DataFrame,
+        *,
+        on: Sequence[str | Expr] | None = None,
+        left_on: Sequence[str | Expr] | None = None,
+        right_on: Sequence[str | Expr] | None = None,
+        mode: IntervalJoinMode = "overlap",
+        min_overlap: int | float | timedelta | None = None,
+        suffix: str = "_right",
+    ) -> DataFrame:
+        """
+        Join the rows of which the `(start, end)` intervals overlap.
+
+        The intervals are half-open: they contain their start, but not their end.
+        Rather than pairing every row with every other row, this is planned as an
+        inequality join on the bounds. Rows with a null bound are not matched.
+
+        .. note::
+            The row order of the input DataFrames is not preserved.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            DataFrame to join with.
+        on
+            The start and end of the intervals of both tables, as column names or
+            expressions.
+        left_on
+            The start and end of the intervals of the left table.
+        right_on
+            The start and end of the intervals of the right table.
+        mode : {'overlap', 'contains', 'within'}
+            When two intervals match.
+
+            * 'overlap': The intervals have an overlap of nonzero length.
+            * 'contains': The left interval contains the right interval.
+            * 'within': The left interval lies within the right interval.
+        min_overlap
+            With `mode='overlap'`, the minimum length of the overlap, e.g. a
+            `timedelta` for temporal intervals. Intervals that only touch match if
+            this is zero.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+
+        Examples
+        --------
+        >>> genes = pl.DataFrame(
+        ...     {"gene": ["a", "b", "c"], "start": [0, 40, 90], "end": [50, 80, 120]}
+        ... )
+        >>> reads = pl.DataFrame({"read": [1, 2], "from": [45, 100], "to": [60, 110]})
+        >>> genes.join_intervals(
+        ...     reads, left_on=["start", "end"], right_on=["from", "to"]
+        ... ).sort("gene")
+        shape: (3, 6)
+        ┌──────┬───────┬─────┬──────┬──────┬─────┐
+        │ gene ┆ start ┆ end ┆ read ┆ from ┆ to  │
+        │ ---  ┆ ---   ┆ --- ┆ ---  ┆ ---  ┆ --- │
+        │ str  ┆ i64   ┆ i64 ┆ i64  ┆ i64  ┆ i64 │
+        ╞══════╪═══════╪═════╪══════╪══════╪═════╡
+        │ a    ┆ 0     ┆ 50  ┆ 1    ┆ 45   ┆ 60  │
+        │ b    ┆ 40    ┆ 80  ┆ 1    ┆ 45   ┆ 60  │
+        │ c    ┆ 90    ┆ 120 ┆ 2    ┆ 100  ┆ 110 │
+        └──────┴───────┴─────┴──────┴──────┴─────┘
+
+        Find the reads that lie entirely within a gene.
+
+        >>> genes.join_intervals(
+        ...     reads,
+        ...     left_on=["start", "end"],
+        ...     right_on=["from", "to"],
+        ...     mode="contains",
+        ... ).select("gene", "read").sort("gene")
+        shape: (2, 2)
+        ┌──────┬──────┐
+        │ gene ┆ read │
+        │ ---  ┆ ---  │
+        │ str  ┆ i64  │
+        ╞══════╪══════╡
+        │ b    ┆ 1    │
+        │ c    ┆ 2    │
+        └──────┴──────┘
+        """
+        require_same_type(self, other)
+
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .join_intervals(
+                other.lazy(),
+                on=on,
+                left_on=left_on,
+                right_on=right_on,
+                mode=mode,
+                min_overlap=min_overlap,
+                suffix=suffix,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -8890,10 +9543,47 @@ class DataFrame:
 
         return self.lazy().fill_nan(value).collect(optimizations=QueryOptFlags._eager())
 
+    def nan_to_null(self) -> DataFrame:
+        """
+        Replace floating point NaN values with null values.
+
+        This only changes which values are valid, so the float columns are not copied.
+        Operations such as `unique`, `group_by` and `join` then treat the NaN values
+        like any other missing data.
+
+        See Also
+        --------
+        fill_nan
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1.5, float("nan"), float("nan")],
+        ...         "b": [1, 2, 3],
+        ...     }
+        ... )
+        >>> df.nan_to_null()
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ a    ┆ b   │
+        │ ---  ┆ --- │
+        │ f64  ┆ i64 │
+        ╞══════╪═════╡
+        │ 1.5  ┆ 1   │
+        │ null ┆ 2   │
+        │ null ┆ 3   │
+        └──────┴─────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return self.lazy().nan_to_null().collect(optimizations=QueryOptFlags._eager())
+
     def explode(
         self,
         columns: ColumnNameOrSelector | Iterable[ColumnNameOrSelector],
         *more_columns: ColumnNameOrSelector,
+        keep_index_as: str | None = None,
     ) -> DataFrame:
         """
         Explode the dataframe to long format by exploding the given columns.
@@ -8905,6 +9595,10 @@ class DataFrame:
             columns being exploded must be of the `List` or `Array` data type.
         *more_columns
             Additional names of columns to explode, specified as positional arguments.
+        keep_index_as
+            If set, add a first column with this name holding the index of the
+            original row of every exploded row. This is cheaper than adding a row
+            index before exploding, and can be used to re-aggregate the rows later.
 
         Returns
         -------
@@ -8951,7 +9645,7 @@ class DataFrame:
 
         return (
             self.lazy()
-            .explode(columns, *more_columns)
+            .explode(columns, *more_columns, keep_index_as=keep_index_as)
             .collect(optimizations=QueryOptFlags._eager())
         )
 
@@ -8970,7 +9664,8 @@ class DataFrame:
         """
         Create a spreadsheet-style pivot table as a DataFrame.
 
-        Only available in eager mode. See "Examples" section below for how to do a
+        Only available in eager mode, unless `on` is an `Enum` column, see
+        :meth:`LazyFrame.pivot`. See "Examples" section below for how to do a
         "lazy pivot" if you know the unique column values in advance.
 
         .. versionchanged:: 1.0.0
@@ -9655,6 +10350,74 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def shift_by(self, period: str | timedelta, *, on: str) -> DataFrame:
+        """
+        Shift the values of all columns forward by a length of time.
+
+        Every row gets the values that the other columns had `period` before the time
+        in the `on` column, or null if there is no row that early.
+
+        Parameters
+        ----------
+        period
+            Length of time to shift values forward by, as a duration string (e.g.
+            `"1h"` or `"1mo"`, see `Expr.dt.offset_by`) or a timedelta. If it is
+            negative, the values come from after the row instead.
+        on
+            Name of a Date or Datetime column, which must be sorted in ascending order.
+            It is not shifted itself.
+
+        Notes
+        -----
+        This is the equivalent of `shift(freq=...)` in pandas followed by aligning the
+        result to the original index. The row where the values come from is found
+        with a binary search in `on`, so no join is needed. As with a backward
+        `join_asof`, it is the last row at or before `t - period`, so a gap in the time
+        series repeats the last value before it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        shift
+        join_asof
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "ts": [
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 1),
+        ...             datetime(2024, 1, 1, 3),
+        ...         ],
+        ...         "a": [1, 2, 3],
+        ...     }
+        ... )
+        >>> df.shift_by("1h", on="ts")
+        shape: (3, 2)
+        ┌─────────────────────┬──────┐
+        │ ts                  ┆ a    │
+        │ ---                 ┆ ---  │
+        │ datetime[μs]        ┆ i64  │
+        ╞═════════════════════╪══════╡
+        │ 2024-01-01 00:00:00 ┆ null │
+        │ 2024-01-01 01:00:00 ┆ 1    │
+        │ 2024-01-01 03:00:00 ┆ 2    │
+        └─────────────────────┴──────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .shift_by(period, on=on)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     def is_duplicated(self) -> Series:
         """
         Get a mask of all duplicated rows in this DataFrame.
@@ -9917,6 +10680,10 @@ class DataFrame:
         Creating a new DataFrame using this method does not create a new copy of
         existing data.
 
+        Expressions may refer to columns that are created by other expressions in the
+        same call, as long as the input has no column with that name. Such expressions
+        are evaluated after the expressions they depend on.
+
         Examples
         --------
         Pass an expression to add it as a new column.
@@ -10062,6 +10829,119 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
+    @unstable()
+    def update_where(
+        self,
+        predicate: IntoExpr,
+        *assignments: IntoExpr | Iterable[IntoExpr],
+        **named_assignments: IntoExpr,
+    ) -> DataFrame:
+        """
+        Update the values of the rows where the predicate is true.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every assignment replaces the values of the column with its output name in the
+        selected rows, and the other rows keep their values. This is a shorthand for
+        `pl.when(predicate).then(assignment).otherwise(pl.col(name))` for every
+        assignment, which does not copy the chunks that the predicate does not select.
+
+        Parameters
+        ----------
+        predicate
+            Boolean expression that selects the rows to update.
+        *assignments
+            Values of the columns to update, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_assignments
+            Values of the columns to update, specified as keyword arguments. The
+            keyword is the name of the column to update.
+
+        See Also
+        --------
+        with_columns
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "z", "w"]})
+        >>> df.update_where(pl.col("a") > 2, pl.col("a") * 10, b="big")
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 1   ┆ x   │
+        │ 2   ┆ y   │
+        │ 30  ┆ big │
+        │ 40  ┆ big │
+        └─────┴─────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .update_where(predicate, *assignments, **named_assignments)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
+    def with_feature_set(self, feature_set: FeatureSet) -> DataFrame:
+        """
+        Add the features of a feature set as columns.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The features that do not refer to each other are evaluated together in one
+        :meth:`with_columns`, after the features they refer to, so the new columns
+        are in the order in which they are evaluated.
+
+        Parameters
+        ----------
+        feature_set
+            The :class:`FeatureSet` to add.
+
+        Raises
+        ------
+        ColumnNotFoundError
+            If a column that the features read is not in the frame.
+        SchemaError
+            If a feature does not evaluate to the dtype it declares.
+
+        See Also
+        --------
+        FeatureSet
+
+        Examples
+        --------
+        >>> fs = pl.FeatureSet(
+        ...     "sizes",
+        ...     {"area": pl.col("w") * pl.col("h"), "w": pl.col("w").cast(pl.Int32)},
+        ...     dtypes={"area": pl.Int64},
+        ... )
+        >>> pl.DataFrame({"w": [2, 3], "h": [4, 5]}).with_feature_set(fs)
+        shape: (2, 3)
+        ┌─────┬─────┬──────┐
+        │ w   ┆ h   ┆ area │
+        │ --- ┆ --- ┆ ---  │
+        │ i32 ┆ i64 ┆ i64  │
+        ╞═════╪═════╪══════╡
+        │ 2   ┆ 4   ┆ 8    │
+        │ 3   ┆ 5   ┆ 15   │
+        └─────┴─────┴──────┘
+        """
+        from polars.lazyframe.opt_flags import QueryOptFlags
+
+        return (
+            self.lazy()
+            .with_feature_set(feature_set)
+            .collect(optimizations=QueryOptFlags._eager())
+        )
+
     @overload
     def n_chunks(self, strategy: Literal["first"] = ...) -> int: ...
 
@@ -10703,15 +11583,92 @@ class DataFrame:
             .collect(optimizations=QueryOptFlags._eager())
         )
 
-    def n_unique(self, subset: str | Expr | Sequence[str | Expr] | None = None) -> int:
+    def unique_with_duplicates(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+        *,
+        maintain_order: bool = False,
+    ) -> tuple[DataFrame, DataFrame]:
+        """
+        Split the rows into unique rows and a report of the duplicated keys.
+
+        The unique rows are the rows that `unique(keep="none")` returns. The report
+        has a row for each key that occurs more than once, with the number of rows it
+        occurs in. Both are computed in a single pass, instead of two group-bys.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s), to consider when identifying
+            duplicate rows. If set to `None` (default), use all columns.
+        maintain_order
+            Order the report by the first occurrence of the keys. The unique rows
+            always keep the order of the DataFrame.
+
+        Returns
+        -------
+        tuple of (DataFrame, DataFrame)
+            The unique rows, and the duplicated keys with their `count`.
+
+        See Also
+        --------
+        unique
+        is_duplicated
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": [1, 2, 3, 2, 1, 2],
+        ...         "value": ["a", "b", "c", "d", "e", "f"],
+        ...     }
+        ... )
+        >>> unique, duplicates = df.unique_with_duplicates("id", maintain_order=True)
+        >>> unique
+        shape: (1, 2)
+        ┌─────┬───────┐
+        │ id  ┆ value │
+        │ --- ┆ ---   │
+        │ i64 ┆ str   │
+        ╞═════╪═══════╡
+        │ 3   ┆ c     │
+        └─────┴───────┘
+        >>> duplicates
+        shape: (2, 2)
+        ┌─────┬───────┐
+        │ id  ┆ count │
+        │ --- ┆ ---   │
+        │ i64 ┆ u32   │
+        ╞═════╪═══════╡
+        │ 1   ┆ 2     │
+        │ 2   ┆ 3     │
+        └─────┴───────┘
+        """
+        if subset is not None:
+            subset = _expand_selectors(self, subset)
+        unique, duplicates = self._df.unique_with_duplicates(maintain_order, subset)
+        return wrap_df(unique), wrap_df(duplicates)
+
+    def n_unique(
+        self,
+        subset: str | Expr | Sequence[str | Expr] | None = None,
+        *,
+        approximate: bool = False,
+    ) -> int:
         """
         Return the number of unique rows, or the number of unique row-subsets.
 
+        Multiple columns are counted on their row encoding, without combining them
+        into a struct column first.
+
         Parameters
         ----------
         subset
             One or more columns/expressions that define what to count;
             omit to return the count of unique rows.
+        approximate
+            Estimate the count with the HyperLogLog++ algorithm instead, which is
+            faster and uses less memory on keys of many columns.
 
         Notes
         -----
@@ -10759,25 +11716,56 @@ class DataFrame:
         ...     ],
         ... )
         3
+
+        Approximate count of the unique rows.
+
+        >>> df.n_unique(approximate=True)
+        5
         """
-        if isinstance(subset, str):
-            expr = F.col(subset)
-        elif isinstance(subset, pl.Expr):
-            expr = subset
-        elif isinstance(subset, Sequence) and len(subset) == 1:
-            expr = wrap_expr(parse_into_expression(subset[0]))
-        else:
-            struct_fields = F.all() if (subset is None) else subset
-            expr = F.struct(struct_fields)
+        df = self if subset is None else self.select(subset)
+        if approximate:
+            return df._df.approx_n_unique()
+        return df._df.n_unique()
 
-        from polars.lazyframe.opt_flags import QueryOptFlags
+    @unstable()
+    def k_anonymity(self, quasi_identifiers: str | Sequence[str]) -> int:
+        """
+        Return the k-anonymity of the frame with respect to the given columns.
 
-        df = (
-            self.lazy()
-            .select(expr.n_unique())
-            .collect(optimizations=QueryOptFlags._eager())
-        )
-        return 0 if df.is_empty() else df.row(0)[0]
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is the size of the smallest group of rows sharing the same values
+        for all `quasi_identifiers`: every row is indistinguishable from at
+        least `k - 1` other rows on those columns. Returns 0 for an empty frame.
+
+        Parameters
+        ----------
+        quasi_identifiers
+            Column(s) that could identify an individual when combined with
+            external data, such as a postal code, birth date or gender.
+
+        See Also
+        --------
+        Expr.anon.suppress_rare
+        Expr.anon.generalize_date
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "zip": ["1011", "1011", "1011", "2022", "2022"],
+        ...         "gender": ["f", "f", "m", "m", "m"],
+        ...     }
+        ... )
+        >>> df.k_anonymity(["zip", "gender"])
+        1
+        >>> df.k_anonymity("zip")
+        2
+        """
+        k = self.select(F.len().over(quasi_identifiers).min()).item()
+        return 0 if k is None else k
 
     @deprecated(
         "`DataFrame.approx_n_unique` is deprecated; "
@@ -10850,6 +11838,48 @@ class DataFrame:
         """
         return self._from_pydf(self._df.null_count())
 
+    @unstable()
+    def summarize(self, n_samples: int = 5) -> DataFrame:
+        """
+        Summarize every column in a single parallel sweep.
+
+        The result has a row per column with its name, dtype, null count, approximate
+        number of unique values, minimum and maximum, and the first `n_samples`
+        values. The minimum, maximum and samples are formatted as strings so that
+        columns of all dtypes fit in one frame; they are null if they are not
+        supported for the dtype of the column.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        n_samples
+            The number of values to include in the samples of each column.
+
+        See Also
+        --------
+        describe
+        glimpse
+        null_count
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [1, 2, 2], "b": ["x", None, "z"]})
+        >>> df.summarize()
+        shape: (2, 7)
+        ┌────────┬───────┬────────────┬─────────────────┬─────┬─────┬──────────────────┐
+        │ column ┆ dtype ┆ null_count ┆ n_unique_approx ┆ min ┆ max ┆ samples          │
+        │ ---    ┆ ---   ┆ ---        ┆ ---             ┆ --- ┆ --- ┆ ---              │
+        │ str    ┆ str   ┆ u32        ┆ u32             ┆ str ┆ str ┆ list[str]        │
+        ╞════════╪═══════╪════════════╪═════════════════╪═════╪═════╪══════════════════╡
+        │ a      ┆ i64   ┆ 0          ┆ 2               ┆ 1   ┆ 2   ┆ ["1", "2", "2"]  │
+        │ b      ┆ str   ┆ 1          ┆ 3               ┆ x   ┆ z   ┆ ["x", null, "z"] │
+        └────────┴───────┴────────────┴─────────────────┴─────┴─────┴──────────────────┘
+        """
+        return self._from_pydf(self._df.summarize(n_samples))
+
     def sample(
         self,
         n: int | Series | None = None,
@@ -11625,6 +12655,8 @@ class DataFrame:
         seed_1: int | None = None,
         seed_2: int | None = None,
         seed_3: int | None = None,
+        *,
+        stable: bool = False,
     ) -> Series:
         """
         Hash and combine the rows in this DataFrame.
@@ -11641,12 +12673,20 @@ class DataFrame:
             Random seed parameter. Defaults to `seed` if not set.
         seed_3
             Random seed parameter. Defaults to `seed` if not set.
+        stable
+            Use a versioned hash algorithm that gives the same results on all
+            platforms and Polars versions, so that the hashes can be persisted and
+            compared, e.g. to detect changed rows. Only `seed` is used as the seed.
 
         Notes
         -----
-        This implementation of `hash_rows` does not guarantee stable results
-        across different Polars versions. Its stability is only guaranteed within a
-        single version.
+        Without `stable=True`, this implementation of `hash_rows` does not guarantee
+        stable results across different Polars versions or platforms. Its stability is
+        only guaranteed within a single version.
+
+        The stable hashes use version 1 of an algorithm based on XXH3-64. The
+        algorithm of a version never changes. Stable hashes are not supported for
+        columns of type `Object`.
 
         Examples
         --------
@@ -11666,6 +12706,15 @@ class DataFrame:
             2047317070637311557
         ]
         """
+        if stable:
+            if seed_1 is not None or seed_2 is not None or seed_3 is not None:
+                msg = (
+                    "`seed_1`, `seed_2` and `seed_3` cannot be used with"
+                    " `stable=True`"
+                )
+                raise ValueError(msg)
+            return wrap_s(self._df.hash_rows_stable(seed))
+
         k0 = seed
         k1 = seed_1 if seed_1 is not None else seed
         k2 = seed_2 if seed_2 is not None else seed
@@ -12197,6 +13246,8 @@ class DataFrame:
         | Mapping[str, Literal["upcast", "forbid"]] = "forbid",
         float_cast: Literal["upcast", "forbid"]
         | Mapping[str, Literal["upcast", "forbid"]] = "forbid",
+        cast: Literal["strict", "non-strict", "forbid"]
+        | Mapping[str, Literal["strict", "non-strict", "forbid"]] = "forbid",
     ) -> DataFrame:
         """
         Match or evolve the schema of a LazyFrame into a specific schema.
@@ -12232,6 +13283,11 @@ class DataFrame:
         float_cast
             Forbid of upcast for float columns from the input to the respective column
             in `schema`.
+        cast
+            Cast columns from the input to any data type of the respective column in
+            `schema`, not just upcast. With `"strict"`, values that cannot be cast
+            raise an error; with `"non-strict"` they become null. This takes
+            precedence over `integer_cast` and `float_cast`.
 
         Examples
         --------
@@ -12341,6 +13397,7 @@ class DataFrame:
                 extra_struct_fields=extra_struct_fields,
                 integer_cast=integer_cast,
                 float_cast=float_cast,
+                cast=cast,
             )
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -11,7 +11,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
     from datetime import timedelta
 
-    from polars import DataFrame
+    from polars import DataFrame, TradingCalendar
     from polars._typing import (
         ClosedInterval,
         IntoExpr,
@@ -40,6 +40,7 @@ class GroupBy:
         df: DataFrame,
         *by: IntoExpr | Iterable[IntoExpr],
         maintain_order: bool,
+        expand_categories: bool = False,
         **named_by: IntoExpr,
     ) -> None:
         """
@@ -57,6 +58,9 @@ class GroupBy:
         maintain_order
             Ensure that the order of the groups is consistent with the input data.
             This is slower than a default group by.
+        expand_categories
+            Also create a group for every category of `Enum` keys that does not occur
+            in the data.
         **named_by
             Additional column(s) to group by, specified as keyword arguments.
             The columns will be named as the keyword used.
@@ -65,6 +69,7 @@ class GroupBy:
         self.by = by
         self.named_by = named_by
         self.maintain_order = maintain_order
+        self.expand_categories = expand_categories
 
     def __iter__(self) -> Self:
         """
@@ -106,7 +111,12 @@ class GroupBy:
         temp_col = "__POLARS_GB_GROUP_INDICES"
         groups_df = (
             self.df.lazy()
-            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .group_by(
+                *self.by,
+                **self.named_by,
+                maintain_order=self.maintain_order,
+                expand_categories=self.expand_categories,
+            )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(optimizations=QueryOptFlags.none())
         )
@@ -236,7 +246,12 @@ class GroupBy:
 
         return (
             self.df.lazy()
-            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .group_by(
+                *self.by,
+                **self.named_by,
+                maintain_order=self.maintain_order,
+                expand_categories=self.expand_categories,
+            )
             .agg(*aggs, **named_aggs)
             .collect(optimizations=QueryOptFlags.none())
         )
@@ -308,6 +323,9 @@ class GroupBy:
         if not all(isinstance(c, str) for c in self.by):
             msg = "cannot call `map_groups` when grouping by an expression"
             raise TypeError(msg)
+        if self.expand_categories:
+            msg = "cannot call `map_groups` with `expand_categories=True`"
+            raise TypeError(msg)
 
         return self.df.__class__._from_pydf(
             self.df._df.group_by_map_groups(
@@ -364,7 +382,12 @@ class GroupBy:
 
         return (
             self.df.lazy()
-            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .group_by(
+                *self.by,
+                **self.named_by,
+                maintain_order=self.maintain_order,
+                expand_categories=self.expand_categories,
+            )
             .head(n)
             .collect(optimizations=QueryOptFlags._eager())
         )
@@ -418,7 +441,12 @@ class GroupBy:
 
         return (
             self.df.lazy()
-            .group_by(*self.by, **self.named_by, maintain_order=self.maintain_order)
+            .group_by(
+                *self.by,
+                **self.named_by,
+                maintain_order=self.maintain_order,
+                expand_categories=self.expand_categories,
+            )
             .tail(n)
             .collect(optimizations=QueryOptFlags.none())
         )
@@ -443,6 +471,43 @@ class GroupBy:
         """
         return self.agg(F.all())
 
+    def indices(self, name: str | None = None) -> DataFrame:
+        """
+        Return the row indices of each group.
+
+        The groups are not materialized, which makes this a cheap way to hand the
+        grouping over to other libraries (e.g. for cross-validation splits). Use
+        :meth:`DataFrame.to_arrow` to get the result as Arrow data.
+
+        Parameters
+        ----------
+        name
+            Assign a name to the resulting column; if unset, defaults to "indices".
+
+        Returns
+        -------
+        DataFrame
+            The group keys and a column of data type `List(UInt32)` (`List(UInt64)`
+            for the 64-bit index build of Polars) with the row indices of each
+            group.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["one", "two", "one", "two"], "b": [1, 2, 3, 4]})
+        >>> df.group_by("a", maintain_order=True).indices()
+        shape: (2, 2)
+        ┌─────┬───────────┐
+        │ a   ┆ indices   │
+        │ --- ┆ ---       │
+        │ str ┆ list[u32] │
+        ╞═════╪═══════════╡
+        │ one ┆ [0, 2]    │
+        │ two ┆ [1, 3]    │
+        └─────┴───────────┘
+        """
+        indices = F.first().agg_groups().alias("indices" if name is None else name)
+        return self.agg(indices)
+
     def len(self, name: str | None = None) -> DataFrame:
         """
         Return the number of rows in each group.
@@ -929,6 +994,7 @@ class DynamicGroupBy:
         label: Label,
         group_by: IntoExpr | Iterable[IntoExpr] | None,
         start_by: StartBy,
+        calendar: TradingCalendar | None,
     ) -> None:
         every = parse_as_duration_string(every)
         period = parse_as_duration_string(period)
@@ -944,6 +1010,7 @@ class DynamicGroupBy:
         self.closed = closed
         self.group_by = group_by
         self.start_by = start_by
+        self.calendar = calendar
 
     def __iter__(self) -> Self:
         from polars.lazyframe.opt_flags import QueryOptFlags
@@ -961,6 +1028,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                calendar=self.calendar,
             )
             .agg(F.first().agg_groups().alias(temp_col))
             .collect(optimizations=QueryOptFlags.none())
@@ -1014,6 +1082,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                calendar=self.calendar,
             )
             .agg(*aggs, **named_aggs)
             .collect(optimizations=QueryOptFlags.none())
@@ -1061,6 +1130,7 @@ class DynamicGroupBy:
                 closed=self.closed,
                 group_by=self.group_by,
                 start_by=self.start_by,
+                calendar=self.calendar,
             )
             .map_groups(function, schema)
             .collect(optimizations=QueryOptFlags.none())
@@ -35,8 +35,90 @@ class DataFramePlot:
     """DataFrame.plot namespace."""
 
     def __init__(self, df: DataFrame) -> None:
+        self._df = df
         self._chart = alt.Chart(df)
 
+    def downsample_lttb(self, x: str, y: str, n: int) -> DataFrame:
+        """
+        Downsample to `n` rows for a line plot of `y` against `x`.
+
+        The rows are selected with the Largest-Triangle-Three-Buckets algorithm, which
+        keeps the visual shape of the line, so that charts of millions of points can
+        be drawn from a frame of a few thousand rows. The frame is assumed to be
+        sorted by `x`.
+
+        Rows in which `x` or `y` is null or not finite are dropped, and all rows are
+        kept if there are at most `n` of them.
+
+        Parameters
+        ----------
+        x
+            Column with the x-coordinates, of a numeric or temporal dtype.
+        y
+            Column with the y-coordinates, of a numeric or temporal dtype.
+        n
+            Number of rows to keep.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": range(6), "y": [0, 1, 0, 5, 0, 1]})
+        >>> df.plot.downsample_lttb("x", "y", 3)
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ x   ┆ y   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 0   ┆ 0   │
+        │ 3   ┆ 5   │
+        │ 5   ┆ 1   │
+        └─────┴─────┘
+        """
+        return self._df._from_pydf(self._df._df.downsample_lttb(x, y, n))
+
+    def bin2d(self, x: str, y: str, bins: int | tuple[int, int] = 100) -> DataFrame:
+        """
+        Count the points of a scatter plot of `y` against `x` in a grid of bins.
+
+        The bins are equally wide and span the points, so that a heatmap of millions
+        of points can be drawn from a frame with a row per non-empty bin. The result
+        has the center of the bin in the `x` and `y` columns, as `Float64`, and the
+        number of points in the bin in the `count` column.
+
+        Rows in which `x` or `y` is null or not finite are ignored.
+
+        Parameters
+        ----------
+        x
+            Column with the x-coordinates, of a numeric or temporal dtype.
+        y
+            Column with the y-coordinates, of a numeric or temporal dtype.
+        bins
+            Number of bins along both axes, or a tuple with the number of bins along
+            the x- and y-axis.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [0, 1, 1, 4], "y": [0, 0, 1, 2]})
+        >>> df.plot.bin2d("x", "y", bins=2)
+        shape: (3, 3)
+        ┌─────┬─────┬───────┐
+        │ x   ┆ y   ┆ count │
+        │ --- ┆ --- ┆ ---   │
+        │ f64 ┆ f64 ┆ u32   │
+        ╞═════╪═════╪═══════╡
+        │ 1.0 ┆ 0.5 ┆ 2     │
+        │ 1.0 ┆ 1.5 ┆ 1     │
+        │ 3.0 ┆ 1.5 ┆ 1     │
+        └─────┴─────┴───────┘
+        >>> df.plot.bin2d("x", "y", bins=2).plot.rect(
+        ...     x="x:O", y="y:O", color="count"
+        ... )  # doctest: +SKIP
+        """
+        if isinstance(bins, int):
+            bins = (bins, bins)
+        return self._df._from_pydf(self._df._df.bin2d(x, y, bins))
+
     def bar(
         self,
         x: X | None = None,
@@ -17,6 +17,7 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -75,6 +76,7 @@ __all__ = [
     "Duration",
     "Enum",
     "Field",
+    "Float16",
     "Float32",
     "Float64",
     "Int16",
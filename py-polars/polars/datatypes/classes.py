@@ -388,6 +388,15 @@ class UInt64(UnsignedIntegerType):
     """64-bit unsigned integer type."""
 
 
+class Float16(FloatType):
+    """
+    16-bit floating point type.
+
+    Values are stored in half precision, but computations are done in 32-bit
+    floating point and produce :class:`Float32` results.
+    """
+
+
 class Float32(FloatType):
     """32-bit floating point type."""
 
@@ -644,6 +653,10 @@ class Categories:
     categories to strings) if they have the same name, namespace and physical backing
     type, even if they are created in separate calls to `Categories`.
 
+    The physical type of named categories is not widened automatically; adding more
+    categories than it can hold raises an error. Cast to a `Categorical` with
+    wider categories to change it.
+
     .. warning::
         This functionality is currently considered **unstable**. It may be
         changed at any point without it being considered a breaking change.
@@ -818,6 +831,11 @@ class Enum(DataType):
     categories
         The categories in the dataset; must be a unique set of strings, or an
         existing Python string-valued enum.
+    physical
+        The physical type used to represent the categories, one of `pl.UInt8`,
+        `pl.UInt16` or `pl.UInt32`. By default the smallest type that fits the
+        categories is used, so Enums with more categories can use a wider type.
+        Cast to an Enum with a different `physical` to change it.
 
     Examples
     --------
@@ -831,11 +849,31 @@ class Enum(DataType):
     >>> from http import HTTPMethod
     >>> pl.Enum(HTTPMethod)
     Enum(categories=['CONNECT', 'DELETE', 'GET', 'HEAD', 'OPTIONS', 'PATCH', 'POST', 'PUT', 'TRACE'])
+
+    Use a fixed physical type, regardless of the number of categories:
+
+    >>> pl.Enum(["north", "south"], physical=pl.UInt32)
+    Enum(categories=['north', 'south'], physical=pl.UInt32)
     """  # noqa: W505
 
     categories: Series
+    physical: PolarsDataType | None
+
+    def __init__(
+        self,
+        categories: Series | Iterable[str] | type[enum.Enum],
+        *,
+        physical: PolarsDataType | None = None,
+    ) -> None:
+        if physical is not None and physical not in (
+            pldt.UInt8,
+            pldt.UInt16,
+            pldt.UInt32,
+        ):
+            msg = "Enum physical must be one of pl.UInt(8|16|32)"
+            raise TypeError(msg)
+        self.physical = physical
 
-    def __init__(self, categories: Series | Iterable[str] | type[enum.Enum]) -> None:
         if isclass(categories) and issubclass(categories, enum.Enum):
             for enum_subclass in (enum.Flag, enum.IntEnum):
                 if issubclass(categories, enum_subclass):
@@ -853,6 +891,12 @@ class Enum(DataType):
         if categories.is_empty():
             self.categories = pl.Series(name="category", dtype=String)
             return
+        if physical is not None and categories.len() >= _max_categories(physical):
+            msg = (
+                f"{categories.len()} Enum categories do not fit in physical type "
+                f"{physical}"
+            )
+            raise ValueError(msg)
 
         if categories.has_nulls():
             msg = "Enum categories must not contain null values"
@@ -874,7 +918,10 @@ class Enum(DataType):
         if type(other) is DataTypeClass and issubclass(other, Enum):
             return True
         elif isinstance(other, Enum):
-            return self.categories.equals(other.categories)
+            return (
+                self._effective_physical() == other._effective_physical()
+                and self.categories.equals(other.categories)
+            )
         else:
             return False
 
@@ -883,7 +930,21 @@ class Enum(DataType):
 
     def __repr__(self) -> str:
         class_name = self.__class__.__name__
-        return f"{class_name}(categories={self.categories.to_list()!r})"
+        if self.physical is None:
+            return f"{class_name}(categories={self.categories.to_list()!r})"
+        return (
+            f"{class_name}(categories={self.categories.to_list()!r}, "
+            f"physical=pl.{self.physical})"
+        )
+
+    def _effective_physical(self) -> PolarsDataType:
+        """The physical type, with the default one resolved."""
+        if self.physical is not None:
+            return self.physical
+        for dtype in (pldt.UInt8, pldt.UInt16):
+            if self.categories.len() < _max_categories(dtype):
+                return dtype
+        return pldt.UInt32
 
     def union(self, other: Enum) -> Enum:
         """Union of two Enums."""
@@ -894,6 +955,15 @@ class Enum(DataType):
     __or__ = union
 
 
+def _max_categories(physical: PolarsDataType) -> int:
+    """The exclusive upper bound of the number of categories of a physical type."""
+    if physical == pldt.UInt8:
+        return 2**8 - 1
+    elif physical == pldt.UInt16:
+        return 2**16 - 1
+    return 2**32 - 1
+
+
 class Object(ObjectType):
     """Data type for wrapping arbitrary Python objects."""
 
@@ -24,6 +24,7 @@ if not _DOCUMENTING:
     _POLARS_TYPE_TO_CONSTRUCTOR: dict[
         PolarsDataType, Callable[[str, Sequence[Any], bool], PySeries]
     ] = {
+        dt.Float16: PySeries.new_opt_f32,
         dt.Float32: PySeries.new_opt_f32,
         dt.Float64: PySeries.new_opt_f64,
         dt.Int8: PySeries.new_opt_i8,
@@ -22,6 +22,7 @@ from polars.datatypes.classes import (
     Duration,
     Enum,
     Field,
+    Float16,
     Float32,
     Float64,
     Int8,
@@ -148,6 +149,7 @@ class _DataTypeMappings:
             Datetime: "datetime",
             Decimal: "decimal",
             Duration: "duration",
+            Float16: "f16",
             Float32: "f32",
             Float64: "f64",
             Int128: "i128",
@@ -177,6 +179,7 @@ class _DataTypeMappings:
             Datetime: datetime,
             Decimal: PyDecimal,
             Duration: timedelta,
+            Float16: float,
             Float32: float,
             Float64: float,
             Int128: int,
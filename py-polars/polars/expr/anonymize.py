@@ -0,0 +1,157 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING, Literal
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.unstable import unstable
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import Expr
+    from polars._typing import IntoExpr
+
+
+class ExprAnonymizeNameSpace:
+    """Namespace for anonymization related expressions."""
+
+    _accessor = "anon"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def hash_stable(self, salts: str | Sequence[str], salt_id: IntoExpr = 0) -> Expr:
+        """
+        Hash the values with a salt, in a way that is stable across sessions.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The values are hashed with keyed BLAKE3, using a key derived from the
+        salt, so the hashes can't be reversed or linked to the values without
+        the salt. Unlike :meth:`Expr.hash`, the result only depends on the value
+        and the salt, and does not change between Polars versions or sessions.
+        Values are hashed by their physical representation, so equal values of
+        different data types, such as `12` and `"12"`, have different hashes.
+        Categorical values are hashed by their string.
+
+        The salts are secrets, so they are not shown in the query plan and are
+        not serialized with the expression.
+
+        To rotate salts, append the new salt to `salts` and point `salt_id` to
+        it. Data hashed with an older salt can still be matched by selecting
+        that salt's index.
+
+        Parameters
+        ----------
+        salts
+            The salts to choose from.
+        salt_id
+            Index into `salts` of the salt to use. Accepts expression input,
+            which allows a different salt per row.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt64`.
+
+        See Also
+        --------
+        Expr.hash
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "email": ["ann@example.com", "bob@example.com", None],
+        ...         "salt_id": [0, 1, 1],
+        ...     }
+        ... )
+        >>> df.select(
+        ...     pl.col("email").anon.hash_stable(["2023-salt", "2024-salt"], "salt_id")
+        ... )  # doctest: +IGNORE_RESULT
+        """
+        if isinstance(salts, str):
+            salts = [salts]
+        salt_id_pyexpr = parse_into_expression(salt_id)
+        return wrap_expr(self._pyexpr.anon_hash_stable(salt_id_pyexpr, list(salts)))
+
+    @unstable()
+    def suppress_rare(self, k: int) -> Expr:
+        """
+        Replace values that occur fewer than `k` times by null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is a building block for k-anonymity: after suppression, every
+        remaining value is shared by at least `k` rows.
+
+        Parameters
+        ----------
+        k
+            Minimum number of occurrences of a value for it to be kept.
+
+        See Also
+        --------
+        DataFrame.k_anonymity
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"zip": ["1011", "1011", "2022", "1011", "3033"]})
+        >>> df.with_columns(pl.col("zip").anon.suppress_rare(2))
+        shape: (5, 1)
+        ┌──────┐
+        │ zip  │
+        │ ---  │
+        │ str  │
+        ╞══════╡
+        │ 1011 │
+        │ 1011 │
+        │ null │
+        │ 1011 │
+        │ null │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.anon_suppress_rare(k))
+
+    @unstable()
+    def generalize_date(
+        self, level: Literal["year", "quarter", "month", "week", "day", "hour"]
+    ) -> Expr:
+        """
+        Generalize temporal values to the start of their year, quarter, month, etc.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        level : {'year', 'quarter', 'month', 'week', 'day', 'hour'}
+            The precision to keep.
+
+        See Also
+        --------
+        Expr.dt.truncate
+
+        Examples
+        --------
+        >>> from datetime import date
+        >>> df = pl.DataFrame({"birth": [date(1990, 5, 17), date(1985, 11, 2)]})
+        >>> df.with_columns(pl.col("birth").anon.generalize_date("quarter"))
+        shape: (2, 1)
+        ┌────────────┐
+        │ birth      │
+        │ ---        │
+        │ date       │
+        ╞════════════╡
+        │ 1990-04-01 │
+        │ 1985-10-01 │
+        └────────────┘
+        """
+        return wrap_expr(self._pyexpr.anon_generalize_date(level))
@@ -914,6 +914,44 @@ class ExprArrayNameSpace:
             pyexpr = self._pyexpr.arr_to_struct(fields)
             return wrap_expr(pyexpr)
 
+    def to_cols(
+        self, fields: Sequence[str] | Callable[[int], str] | None = None
+    ) -> Expr:
+        """
+        Split the arrays into one column per element.
+
+        The `i`-th column holds the `i`-th element of every array. This is the inverse
+        of :func:`concat_arr`.
+
+        Parameters
+        ----------
+        fields
+            The names of the columns, given as a list of names that are assigned by
+            index or a function that maps the index to a name. If not set, the
+            columns are named `field_0, field_1 .. field_n`.
+
+        See Also
+        --------
+        polars.concat_arr
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"n": [[0, 1, 2], [3, 4, 5]]}, schema={"n": pl.Array(pl.Int8, 3)}
+        ... )
+        >>> df.select(pl.col("n").arr.to_cols(["x", "y", "z"]))
+        shape: (2, 3)
+        ┌─────┬─────┬─────┐
+        │ x   ┆ y   ┆ z   │
+        │ --- ┆ --- ┆ --- │
+        │ i8  ┆ i8  ┆ i8  │
+        ╞═════╪═════╪═════╡
+        │ 0   ┆ 1   ┆ 2   │
+        │ 3   ┆ 4   ┆ 5   │
+        └─────┴─────┴─────┘
+        """
+        return self.to_struct(fields).struct.unnest()
+
     def shift(self, n: int | IntoExprColumn = 1) -> Expr:
         """
         Shift array values by the given number of indices.
@@ -491,7 +491,13 @@ class ExprDateTimeNameSpace:
             )
         )
 
-    def combine(self, time: dt.time | Expr, time_unit: TimeUnit = "us") -> Expr:
+    def combine(
+        self,
+        time: dt.time | Expr,
+        time_unit: TimeUnit = "us",
+        *,
+        time_zone: str | Expr | None = None,
+    ) -> Expr:
         """
         Create a naive Datetime from an existing Date/Datetime expression and a Time.
 
@@ -504,6 +510,16 @@ class ExprDateTimeNameSpace:
             A python time literal or polars expression/column that resolves to a time.
         time_unit : {'ns', 'us', 'ms'}
             Unit of time.
+        time_zone
+            Time zone in which to interpret the combined local datetimes, either as a
+            single time zone name or as an expression of time zone names (one per
+            row). If set, the result is a Datetime in UTC. The underlying expression
+            must then be a Date or a time-zone-naive Datetime.
+
+        Notes
+        -----
+        When `time_zone` is set, local datetimes that are ambiguous or non-existent
+        in their time zone (e.g. due to daylight saving time) raise an error.
 
         Examples
         --------
@@ -544,12 +560,38 @@ class ExprDateTimeNameSpace:
         │ 2022-12-31 01:02:03.456 ┆ 2022-10-10 01:02:03.456 ┆ 2022-10-10 04:05:06 │
         │ 2023-07-05 07:08:09.101 ┆ 2022-07-05 07:08:09.101 ┆ 2022-07-05 04:05:06 │
         └─────────────────────────┴─────────────────────────┴─────────────────────┘
+
+        Combine local dates and times recorded in different time zones into UTC
+        instants:
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "dt": [date(2024, 1, 1), date(2024, 7, 1)],
+        ...         "tm": [time(9), time(9)],
+        ...         "tz": ["Europe/Amsterdam", "America/New_York"],
+        ...     }
+        ... )
+        >>> df.select(pl.col("dt").dt.combine(pl.col("tm"), time_zone=pl.col("tz")))
+        shape: (2, 1)
+        ┌─────────────────────────┐
+        │ dt                      │
+        │ ---                     │
+        │ datetime[μs, UTC]       │
+        ╞═════════════════════════╡
+        │ 2024-01-01 08:00:00 UTC │
+        │ 2024-07-01 13:00:00 UTC │
+        └─────────────────────────┘
         """
         if not isinstance(time, (dt.time, pl.Expr)):
             msg = f"expected 'time' to be a Python time or Polars expression, found {qualified_type_name(time)!r}"
             raise TypeError(msg)
         time = parse_into_expression(time)
-        return wrap_expr(self._pyexpr.dt_combine(time, time_unit))
+        if time_zone is None:
+            return wrap_expr(self._pyexpr.dt_combine(time, time_unit))
+        time_zone = parse_into_expression(time_zone, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.dt_combine_in_time_zones(time, time_unit, time_zone)
+        )
 
     def to_string(self, format: str | None = None) -> Expr:
         """
@@ -1877,20 +1919,26 @@ class ExprDateTimeNameSpace:
         """
         return wrap_expr(self._pyexpr.dt_cast_time_unit(time_unit))
 
-    def convert_time_zone(self, time_zone: str) -> Expr:
+    def convert_time_zone(self, time_zone: str | Expr) -> Expr:
         """
         Convert to given time zone for an expression of type Datetime.
 
         Parameters
         ----------
         time_zone
-            Time zone for the `Datetime` expression.
+            Time zone for the `Datetime` expression. An expression of time zone names
+            converts every row to the time zone of that row instead.
 
         Notes
         -----
         If converting from a time-zone-naive datetime, then conversion will happen
         as if converting from UTC, regardless of your system's time zone.
 
+        As a single `Datetime` column cannot hold several time zones, converting to
+        an expression of time zones returns the local time in each row's time zone
+        as a time-zone-naive `Datetime`. Every distinct time zone name is only
+        parsed once.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -1923,7 +1971,30 @@ class ExprDateTimeNameSpace:
         │ 2020-04-01 00:00:00 UTC ┆ 2020-04-01 01:00:00 BST     │
         │ 2020-05-01 00:00:00 UTC ┆ 2020-05-01 01:00:00 BST     │
         └─────────────────────────┴─────────────────────────────┘
-        """
+
+        Convert every row to its own time zone:
+
+        >>> df = df.with_columns(
+        ...     tz=pl.Series(["Europe/London", "Asia/Tokyo", "America/Chicago"])
+        ... )
+        >>> df.select(
+        ...     pl.col("date"),
+        ...     local=pl.col("date").dt.convert_time_zone(pl.col("tz")),
+        ... )
+        shape: (3, 2)
+        ┌─────────────────────────┬─────────────────────┐
+        │ date                    ┆ local               │
+        │ ---                     ┆ ---                 │
+        │ datetime[μs, UTC]       ┆ datetime[μs]        │
+        ╞═════════════════════════╪═════════════════════╡
+        │ 2020-03-01 00:00:00 UTC ┆ 2020-03-01 00:00:00 │
+        │ 2020-04-01 00:00:00 UTC ┆ 2020-04-01 09:00:00 │
+        │ 2020-05-01 00:00:00 UTC ┆ 2020-04-30 19:00:00 │
+        └─────────────────────────┴─────────────────────┘
+        """
+        if not isinstance(time_zone, str):
+            time_zone = parse_into_expression(time_zone)
+            return wrap_expr(self._pyexpr.dt_convert_time_zone_per_row(time_zone))
         return wrap_expr(self._pyexpr.dt_convert_time_zone(time_zone))
 
     def replace_time_zone(
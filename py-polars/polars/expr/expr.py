@@ -54,6 +54,7 @@ from polars.exceptions import (
     OutOfBoundsError,
     PolarsInefficientMapWarning,
 )
+from polars.expr.anonymize import ExprAnonymizeNameSpace
 from polars.expr.array import ExprArrayNameSpace
 from polars.expr.binary import ExprBinaryNameSpace
 from polars.expr.categorical import ExprCatNameSpace
@@ -63,6 +64,7 @@ from polars.expr.meta import ExprMetaNameSpace
 from polars.expr.name import ExprNameNameSpace
 from polars.expr.string import ExprStringNameSpace
 from polars.expr.struct import ExprStructNameSpace
+from polars.expr.url import ExprUrlNameSpace
 from polars.meta import thread_pool_size
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -77,6 +79,8 @@ if TYPE_CHECKING:
 
     from polars import DataFrame, LazyFrame, Series
     from polars._typing import (
+        BootstrapMethod,
+        BootstrapStatistic,
         ClosedInterval,
         FillNullStrategy,
         InterpolationMethod,
@@ -133,6 +137,8 @@ class Expr:
         "str",
         "bin",
         "struct",
+        "url",
+        "anon",
     }
 
     @classmethod
@@ -426,6 +432,34 @@ class Expr:
 
         return cls._from_pyexpr(deserializer(source))
 
+    @classmethod
+    def from_text(cls, text: str) -> Expr:
+        """
+        Parse an expression from its text representation.
+
+        The text uses the syntax of the Python API, for example
+        `col("a").cast(Int64).sum().alias("total")`. Unlike :meth:`deserialize`,
+        this never executes code, so it can be used to load expressions from
+        configuration files.
+
+        Parameters
+        ----------
+        text
+            The text representation of the expression, as returned by
+            :meth:`Expr.meta.to_text`.
+
+        See Also
+        --------
+        Expr.meta.to_text
+
+        Examples
+        --------
+        >>> expr = pl.Expr.from_text('(col("foo") * 2).sum().over(col("bar"))')
+        >>> expr.meta.eq((pl.col("foo") * 2).sum().over("bar"))
+        True
+        """
+        return cls._from_pyexpr(PyExpr.from_text(text))
+
     def to_physical(self) -> Expr:
         """
         Cast to physical representation of the logical dtype.
@@ -1647,13 +1681,17 @@ class Expr:
         ----------
         decimals
             Number of decimals to round by.
-        mode : {'half_to_even', 'half_away_from_zero'}
+        mode : {'half_to_even', 'half_away_from_zero', 'floor', 'ceil'}
             RoundMode.
 
             * *half_to_even*
                 round to the nearest even number
             * *half_away_from_zero*
                 round to the nearest number away from zero
+            * *floor*
+                round towards negative infinity
+            * *ceil*
+                round towards positive infinity
 
         Examples
         --------
@@ -1726,6 +1764,46 @@ class Expr:
         """
         return wrap_expr(self._pyexpr.round_sig_figs(digits))
 
+    def round_to_increment(
+        self, increment: float, mode: RoundMode = "half_to_even"
+    ) -> Expr:
+        """
+        Round to a multiple of `increment`, e.g. to the nearest 0.05.
+
+        Decimal data is rounded exactly, for which `increment` has to be a multiple
+        of the precision of its scale. Float data is rounded to the float closest to
+        the multiple, so that e.g. increments of 0.05 aren't thrown off by 0.05 not
+        being exact in binary.
+
+        Parameters
+        ----------
+        increment
+            The positive number to round to a multiple of.
+        mode : {'half_to_even', 'half_away_from_zero', 'floor', 'ceil'}
+            RoundMode, see :meth:`round`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"price": [1.01, 1.03, 1.075, 2.12]})
+        >>> df.with_columns(
+        ...     nearest=pl.col("price").round_to_increment(0.05),
+        ...     floor=pl.col("price").round_to_increment(0.05, mode="floor"),
+        ...     ceil=pl.col("price").round_to_increment(0.05, mode="ceil"),
+        ... )
+        shape: (4, 4)
+        ┌───────┬─────────┬───────┬──────┐
+        │ price ┆ nearest ┆ floor ┆ ceil │
+        │ ---   ┆ ---     ┆ ---   ┆ ---  │
+        │ f64   ┆ f64     ┆ f64   ┆ f64  │
+        ╞═══════╪═════════╪═══════╪══════╡
+        │ 1.01  ┆ 1.0     ┆ 1.0   ┆ 1.05 │
+        │ 1.03  ┆ 1.05    ┆ 1.0   ┆ 1.05 │
+        │ 1.075 ┆ 1.1     ┆ 1.05  ┆ 1.1  │
+        │ 2.12  ┆ 2.1     ┆ 2.1   ┆ 2.15 │
+        └───────┴─────────┴───────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.round_to_increment(increment, mode))
+
     def dot(self, other: Expr | str) -> Expr:
         """
         Compute the dot/inner product between two Expressions.
@@ -2697,7 +2775,9 @@ class Expr:
             Number of indices to shift forward. If a negative value is passed, values
             are shifted in the opposite direction instead.
         fill_value
-            Fill the resulting null values with this scalar value.
+            Fill the resulting null values with this scalar value. This may also be
+            an expression producing a single value, which is evaluated per group in
+            a group by or window context.
 
         Notes
         -----
@@ -2755,6 +2835,24 @@ class Expr:
         │ 3   ┆ 100   │
         │ 4   ┆ 100   │
         └─────┴───────┘
+
+        In a window context, the fill value can be computed per group.
+
+        >>> df = pl.DataFrame({"g": ["x", "x", "y", "y"], "a": [1, 2, 3, 5]})
+        >>> df.with_columns(
+        ...     shift=pl.col("a").shift(fill_value=pl.col("a").max()).over("g")
+        ... )
+        shape: (4, 3)
+        ┌─────┬─────┬───────┐
+        │ g   ┆ a   ┆ shift │
+        │ --- ┆ --- ┆ ---   │
+        │ str ┆ i64 ┆ i64   │
+        ╞═════╪═════╪═══════╡
+        │ x   ┆ 1   ┆ 2     │
+        │ x   ┆ 2   ┆ 1     │
+        │ y   ┆ 3   ┆ 5     │
+        │ y   ┆ 5   ┆ 3     │
+        └─────┴─────┴───────┘
         """
         if fill_value is not None:
             fill_value = parse_into_expression(fill_value, str_as_lit=True)
@@ -3477,7 +3575,10 @@ class Expr:
                 the same elements before aggregation as after.
             - join
                 Join the groups as 'List<group_dtype>' to the row positions.
-                warning: this can be memory intensive.
+                warning: this can be memory intensive. If every group is a
+                contiguous run of rows, e.g. because the frame is sorted by the
+                partition keys, every group result is stored once instead of once
+                per row. Interleaved groups still repeat the group results.
             - explode
                 Explodes the grouped data into new rows, similar to the results of
                 `group_by` + `agg` + `explode`. Sorting of the given groups is required
@@ -3938,11 +4039,12 @@ class Expr:
     @unstable()
     def cut(
         self,
-        breaks: Sequence[float],
+        breaks: Sequence[NumericLiteral] | Sequence[TemporalLiteral],
         *,
         labels: Sequence[str] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        missing_label: str | None = None,
     ) -> Expr:
         """
         Bin continuous values into discrete categories.
@@ -3954,7 +4056,9 @@ class Expr:
         Parameters
         ----------
         breaks
-            List of unique cut points.
+            List of unique cut points. For Decimal and temporal data, these may be
+            values of the same type, e.g. :class:`datetime.datetime`, which are
+            compared without casting to :class:`Float64`.
         labels
             Names of the categories. The number of labels must be equal to the number
             of cut points plus one.
@@ -3964,6 +4068,9 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        missing_label
+            Label of the category for null and NaN values. By default these are
+            mapped to null.
 
         Returns
         -------
@@ -4014,7 +4121,26 @@ class Expr:
         │ 2   ┆ inf        ┆ (1, inf]   │
         └─────┴────────────┴────────────┘
         """
-        return wrap_expr(self._pyexpr.cut(breaks, labels, left_closed, include_breaks))
+        if all(isinstance(b, (int, float)) for b in breaks):
+            return wrap_expr(
+                self._pyexpr.cut(
+                    breaks,
+                    labels,
+                    left_closed,
+                    include_breaks,
+                    missing_label=missing_label,
+                )
+            )
+        breaks_expr = F.lit(pl.Series(breaks).implode())
+        return wrap_expr(
+            self._pyexpr.cut_with_breaks(
+                breaks_expr._pyexpr,
+                labels,
+                left_closed,
+                include_breaks,
+                missing_label=missing_label,
+            )
+        )
 
     @unstable()
     def qcut(
@@ -4025,6 +4151,7 @@ class Expr:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        missing_label: str | None = None,
     ) -> Expr:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -4051,6 +4178,9 @@ class Expr:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        missing_label
+            Label of the category for null and NaN values. By default these are
+            mapped to null.
 
         Returns
         -------
@@ -4124,15 +4254,248 @@ class Expr:
         """
         if isinstance(quantiles, int):
             pyexpr = self._pyexpr.qcut_uniform(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                missing_label=missing_label,
             )
         else:
             pyexpr = self._pyexpr.qcut(
-                quantiles, labels, left_closed, allow_duplicates, include_breaks
+                quantiles,
+                labels,
+                left_closed,
+                allow_duplicates,
+                include_breaks,
+                missing_label=missing_label,
             )
 
         return wrap_expr(pyexpr)
 
+    @unstable()
+    def categorical_target_encode(
+        self,
+        target: IntoExpr,
+        *,
+        folds: int = 5,
+        smoothing: float = 1.0,
+        seed: int = 0,
+    ) -> Expr:
+        """
+        Encode every category by the smoothed mean of the target values of its rows.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A category whose rows have `n` non-null targets summing to `sum` is encoded
+        as `(sum + smoothing * prior) / (n + smoothing)`, where `prior` is the mean
+        of all targets. Null is treated as a category of its own.
+
+        To not leak the target of a row into its own encoding, the rows are
+        assigned to `folds` folds by hashing their index with `seed`, and every row
+        is encoded using the targets of the rows in the other folds only. The fold
+        assignment is deterministic, so the same `seed` gives the same encoding.
+
+        Parameters
+        ----------
+        target
+            The numeric or boolean target to encode the categories with. Accepts
+            expression input. Strings are parsed as column names.
+        folds
+            Number of folds the rows are split into. With a single fold, every row
+            is encoded using all targets, including its own.
+        smoothing
+            Weight of the prior mean, in number of rows. Larger values pull the
+            encoding of rare categories more strongly towards the prior.
+        seed
+            Seed used to assign the rows to folds.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Float64`.
+
+        See Also
+        --------
+        frequency_encode
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "city": ["NY", "SF", "NY", "LA", "NY", "SF"],
+        ...         "y": [1, 0, 1, 0, 0, 1],
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     encoded=pl.col("city").categorical_target_encode("y", folds=1)
+        ... )
+        shape: (6, 3)
+        ┌──────┬─────┬─────────┐
+        │ city ┆ y   ┆ encoded │
+        │ ---  ┆ --- ┆ ---     │
+        │ str  ┆ i64 ┆ f64     │
+        ╞══════╪═════╪═════════╡
+        │ NY   ┆ 1   ┆ 0.625   │
+        │ SF   ┆ 0   ┆ 0.5     │
+        │ NY   ┆ 1   ┆ 0.625   │
+        │ LA   ┆ 0   ┆ 0.25    │
+        │ NY   ┆ 0   ┆ 0.625   │
+        │ SF   ┆ 1   ┆ 0.5     │
+        └──────┴─────┴─────────┘
+        """
+        target_pyexpr = parse_into_expression(target)
+        return wrap_expr(
+            self._pyexpr.categorical_target_encode(
+                target_pyexpr, folds, smoothing, seed
+            )
+        )
+
+    @unstable()
+    def frequency_encode(self, *, normalize: bool = False) -> Expr:
+        """
+        Encode every value by the number of times it occurs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Null is counted as a value of its own.
+
+        Parameters
+        ----------
+        normalize
+            Encode every value by the fraction of the rows it occurs in instead.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`UInt32`, or :class:`Float64` if
+            `normalize` is set.
+
+        See Also
+        --------
+        categorical_target_encode
+        value_counts
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"city": ["NY", "SF", "NY", "LA", "NY", "SF"]})
+        >>> df.with_columns(
+        ...     freq=pl.col("city").frequency_encode(),
+        ...     frac=pl.col("city").frequency_encode(normalize=True),
+        ... )
+        shape: (6, 3)
+        ┌──────┬──────┬──────────┐
+        │ city ┆ freq ┆ frac     │
+        │ ---  ┆ ---  ┆ ---      │
+        │ str  ┆ u32  ┆ f64      │
+        ╞══════╪══════╪══════════╡
+        │ NY   ┆ 3    ┆ 0.5      │
+        │ SF   ┆ 2    ┆ 0.333333 │
+        │ NY   ┆ 3    ┆ 0.5      │
+        │ LA   ┆ 1    ┆ 0.166667 │
+        │ NY   ┆ 3    ┆ 0.5      │
+        │ SF   ┆ 2    ┆ 0.333333 │
+        └──────┴──────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.frequency_encode(normalize))
+
+    @unstable()
+    def to_dummies(
+        self,
+        *,
+        separator: str = "_",
+        drop_first: bool = False,
+        sparse: bool = False,
+    ) -> Expr:
+        """
+        Convert an Enum column into dummy/indicator variables.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The column must be of data type :class:`Enum`, whose categories determine
+        the output schema before the query runs. Cast a :class:`Categorical` or
+        :class:`String` column to an :class:`Enum` first.
+
+        Parameters
+        ----------
+        separator
+            Separator/delimiter used when generating the names of the dummy columns.
+        drop_first
+            Remove the first category from the variable being encoded.
+        sparse
+            Instead of a dummy column per category, return a single column with a
+            list per row holding the index of its category among the encoded
+            categories.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with a :class:`UInt8` field per
+            category, or if `sparse` is set, of data type :class:`List` of
+            :class:`UInt32`. Null values are encoded with all fields set to zero, or
+            an empty list.
+
+        See Also
+        --------
+        DataFrame.to_dummies
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"color": ["red", "green", None, "red"]},
+        ...     schema={"color": pl.Enum(["red", "green", "blue"])},
+        ... )
+        >>> df.select(pl.col("color").to_dummies()).unnest("color")
+        shape: (4, 3)
+        ┌───────────┬─────────────┬────────────┐
+        │ color_red ┆ color_green ┆ color_blue │
+        │ ---       ┆ ---         ┆ ---        │
+        │ u8        ┆ u8          ┆ u8         │
+        ╞═══════════╪═════════════╪════════════╡
+        │ 1         ┆ 0           ┆ 0          │
+        │ 0         ┆ 1           ┆ 0          │
+        │ 0         ┆ 0           ┆ 0          │
+        │ 1         ┆ 0           ┆ 0          │
+        └───────────┴─────────────┴────────────┘
+
+        Drop the first category.
+
+        >>> df.select(pl.col("color").to_dummies(drop_first=True)).unnest("color")
+        shape: (4, 2)
+        ┌─────────────┬────────────┐
+        │ color_green ┆ color_blue │
+        │ ---         ┆ ---        │
+        │ u8          ┆ u8         │
+        ╞═════════════╪════════════╡
+        │ 0           ┆ 0          │
+        │ 1           ┆ 0          │
+        │ 0           ┆ 0          │
+        │ 0           ┆ 0          │
+        └─────────────┴────────────┘
+
+        Get the index of the category of every row instead.
+
+        >>> df.select(pl.col("color").to_dummies(sparse=True))
+        shape: (4, 1)
+        ┌───────────┐
+        │ color     │
+        │ ---       │
+        │ list[u32] │
+        ╞═══════════╡
+        │ [0]       │
+        │ [1]       │
+        │ []        │
+        │ [0]       │
+        └───────────┘
+        """
+        return wrap_expr(self._pyexpr.to_dummies(separator, drop_first, sparse))
+
     def rle(self) -> Expr:
         """
         Compress the column data using run-length encoding.
@@ -5417,6 +5780,93 @@ Consider using {self}.implode() instead"""
         """
         return self.__add__(other)
 
+    @unstable()
+    def add_checked(self, other: IntoExpr) -> Expr:
+        """
+        Add `other`, yielding null where the integer result overflows.
+
+        Unlike `expr + other`, which silently wraps around on overflow, results that
+        do not fit the integer dtype become null. A warning reports the number of
+        values that overflowed.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Integer literal or expression value. Both sides are cast to their
+            supertype, which must be an integer type.
+
+        See Also
+        --------
+        sub_checked
+        mul_checked
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [1, 100, 127]}, schema={"x": pl.Int8})
+        >>> df.with_columns(pl.col("x").add_checked(27).alias("x+27"))
+        shape: (3, 2)
+        ┌─────┬──────┐
+        │ x   ┆ x+27 │
+        │ --- ┆ ---  │
+        │ i8  ┆ i8   │
+        ╞═════╪══════╡
+        │ 1   ┆ 28   │
+        │ 100 ┆ 127  │
+        │ 127 ┆ null │
+        └─────┴──────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.add_checked(other))
+
+    @unstable()
+    def floordiv_or(self, other: IntoExpr, default: IntoExpr = None) -> Expr:
+        """
+        Floor divide by `other`, yielding `default` where `other` is zero.
+
+        Unlike `pl.when(other != 0).then(expr // other).otherwise(default)`, the
+        division and the check for zero are done in a single pass over the data.
+        Where `expr` or `other` is null, the result is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Numeric literal or expression value.
+        default
+            Numeric literal or expression value to yield where `other` is zero.
+            Defaults to null. All inputs are cast to their supertype.
+
+        See Also
+        --------
+        floordiv
+        truediv_or
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [7, -7, 5], "b": [2, 2, 0]})
+        >>> df.with_columns(pl.col("a").floordiv_or(pl.col("b"), -1).alias("a//b"))
+        shape: (3, 3)
+        ┌─────┬─────┬──────┐
+        │ a   ┆ b   ┆ a//b │
+        │ --- ┆ --- ┆ ---  │
+        │ i64 ┆ i64 ┆ i64  │
+        ╞═════╪═════╪══════╡
+        │ 7   ┆ 2   ┆ 3    │
+        │ -7  ┆ 2   ┆ -4   │
+        │ 5   ┆ 0   ┆ -1   │
+        └─────┴─────┴──────┘
+        """
+        other = parse_into_expression(other)
+        default = parse_into_expression(default)
+        return wrap_expr(self._pyexpr.floordiv_or(other, default))
+
     def floordiv(self, other: Any) -> Expr:
         """
         Method equivalent of integer division operator `expr // other`.
@@ -5563,6 +6013,48 @@ Consider using {self}.implode() instead"""
         """
         return self.__mul__(other)
 
+    @unstable()
+    def mul_checked(self, other: IntoExpr) -> Expr:
+        """
+        Multiply by `other`, yielding null where the integer result overflows.
+
+        Unlike `expr * other`, which silently wraps around on overflow, results that
+        do not fit the integer dtype become null. A warning reports the number of
+        values that overflowed.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Integer literal or expression value. Both sides are cast to their
+            supertype, which must be an integer type.
+
+        See Also
+        --------
+        add_checked
+        sub_checked
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [2, 60, 64]}, schema={"x": pl.Int8})
+        >>> df.with_columns(pl.col("x").mul_checked(2).alias("x*2"))
+        shape: (3, 2)
+        ┌─────┬──────┐
+        │ x   ┆ x*2  │
+        │ --- ┆ ---  │
+        │ i8  ┆ i8   │
+        ╞═════╪══════╡
+        │ 2   ┆ 4    │
+        │ 60  ┆ 120  │
+        │ 64  ┆ null │
+        └─────┴──────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.mul_checked(other))
+
     def sub(self, other: Any) -> Expr:
         """
         Method equivalent of subtraction operator `expr - other`.
@@ -5594,6 +6086,48 @@ Consider using {self}.implode() instead"""
         """
         return self.__sub__(other)
 
+    @unstable()
+    def sub_checked(self, other: IntoExpr) -> Expr:
+        """
+        Subtract `other`, yielding null where the integer result overflows.
+
+        Unlike `expr - other`, which silently wraps around on overflow, results that
+        do not fit the integer dtype become null. A warning reports the number of
+        values that overflowed.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Integer literal or expression value. Both sides are cast to their
+            supertype, which must be an integer type.
+
+        See Also
+        --------
+        add_checked
+        mul_checked
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"x": [0, 5, 10]}, schema={"x": pl.UInt8})
+        >>> df.with_columns(pl.col("x").sub_checked(5).alias("x-5"))
+        shape: (3, 2)
+        ┌─────┬──────┐
+        │ x   ┆ x-5  │
+        │ --- ┆ ---  │
+        │ u8  ┆ u8   │
+        ╞═════╪══════╡
+        │ 0   ┆ null │
+        │ 5   ┆ 0    │
+        │ 10  ┆ 5    │
+        └─────┴──────┘
+        """
+        other = parse_into_expression(other)
+        return wrap_expr(self._pyexpr.sub_checked(other))
+
     def neg(self) -> Expr:
         """
         Method equivalent of unary minus operator `-expr`.
@@ -5660,6 +6194,56 @@ Consider using {self}.implode() instead"""
         """
         return self.__truediv__(other)
 
+    @unstable()
+    def truediv_or(self, other: IntoExpr, default: IntoExpr = None) -> Expr:
+        """
+        Divide by `other`, yielding `default` where `other` is zero.
+
+        Unlike `pl.when(other != 0).then(expr / other).otherwise(default)`, the
+        division and the check for zero are done in a single pass over the data,
+        instead of yielding infinity or NaN where `other` is zero. Where `expr` or
+        `other` is null, the result is null.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            Numeric literal or expression value.
+        default
+            Numeric literal or expression value to yield where `other` is zero.
+            Defaults to null. All inputs are cast to a float supertype.
+
+        See Also
+        --------
+        truediv
+        floordiv_or
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [6, 1, 0, None], "b": [3, 0, 0, 2]})
+        >>> df.with_columns(
+        ...     pl.col("a").truediv_or(pl.col("b")).alias("a/b"),
+        ...     pl.col("a").truediv_or(pl.col("b"), 0.0).alias("a/b or 0"),
+        ... )
+        shape: (4, 4)
+        ┌──────┬─────┬──────┬──────────┐
+        │ a    ┆ b   ┆ a/b  ┆ a/b or 0 │
+        │ ---  ┆ --- ┆ ---  ┆ ---      │
+        │ i64  ┆ i64 ┆ f64  ┆ f64      │
+        ╞══════╪═════╪══════╪══════════╡
+        │ 6    ┆ 3   ┆ 2.0  ┆ 2.0      │
+        │ 1    ┆ 0   ┆ null ┆ 0.0      │
+        │ 0    ┆ 0   ┆ null ┆ 0.0      │
+        │ null ┆ 2   ┆ null ┆ null     │
+        └──────┴─────┴──────┴──────────┘
+        """
+        other = parse_into_expression(other)
+        default = parse_into_expression(default)
+        return wrap_expr(self._pyexpr.truediv_or(other, default))
+
     def pow(self, exponent: IntoExprColumn | int | float) -> Expr:
         """
         Method equivalent of exponentiation operator `expr ** exponent`.
@@ -6267,6 +6851,108 @@ Consider using {self}.implode() instead"""
         by = parse_into_expression(by)
         return wrap_expr(self._pyexpr.interpolate_by(by))
 
+    @unstable()
+    def downsample_lttb(self, by: IntoExpr, n_out: int) -> Expr:
+        """
+        Get a mask of the values to keep to downsample a time series to `n_out` values.
+
+        The values are selected with the Largest-Triangle-Three-Buckets algorithm, which
+        keeps the visual shape of the line of the values against `by`. The first and
+        last value are always kept. Filter on the mask to render a long signal with
+        few points, per signal by combining it with :meth:`over`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column with the time (or other x-coordinate) of the values, of a numeric or
+            temporal dtype. The values are assumed to be sorted by it.
+        n_out
+            Number of values to keep. Values of which the time or the value itself is
+            null or not finite are never kept, and all other values are kept if there
+            are at most `n_out` of them.
+
+        See Also
+        --------
+        downsample_minmax
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "id": ["a"] * 5 + ["b"] * 5,
+        ...         "t": [0, 1, 2, 3, 4] * 2,
+        ...         "v": [0, 2, 1, 5, 3, 4, 0, 3, 3, 1],
+        ...     }
+        ... )
+        >>> df.filter(pl.col("v").downsample_lttb("t", 3).over("id"))
+        shape: (6, 3)
+        ┌─────┬─────┬─────┐
+        │ id  ┆ t   ┆ v   │
+        │ --- ┆ --- ┆ --- │
+        │ str ┆ i64 ┆ i64 │
+        ╞═════╪═════╪═════╡
+        │ a   ┆ 0   ┆ 0   │
+        │ a   ┆ 3   ┆ 5   │
+        │ a   ┆ 4   ┆ 3   │
+        │ b   ┆ 0   ┆ 4   │
+        │ b   ┆ 1   ┆ 0   │
+        │ b   ┆ 4   ┆ 1   │
+        └─────┴─────┴─────┘
+        """
+        by = parse_into_expression(by)
+        return wrap_expr(self._pyexpr.downsample_lttb(by, n_out))
+
+    @unstable()
+    def downsample_minmax(self, by: IntoExpr, n_buckets: int) -> Expr:
+        """
+        Get a mask of the minimum and maximum value in each period of a time series.
+
+        The range of `by` is split into `n_buckets` equally long periods, of which the
+        (first) minimum and maximum value are kept, so that no peak of the signal is
+        lost. Filter on the mask to render a long signal with at most `2 * n_buckets`
+        points, per signal by combining it with :meth:`over`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Column with the time (or other x-coordinate) of the values, of a numeric or
+            temporal dtype.
+        n_buckets
+            Number of periods. Values of which the time or the value itself is null or
+            not finite are never kept, and all other values are kept if there are at
+            most `2 * n_buckets` of them.
+
+        See Also
+        --------
+        downsample_lttb
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"t": range(8), "v": [3, 1, 4, 1, 5, 9, 2, 6]})
+        >>> df.filter(pl.col("v").downsample_minmax("t", 2))
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ t   ┆ v   │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 1   ┆ 1   │
+        │ 2   ┆ 4   │
+        │ 5   ┆ 9   │
+        │ 6   ┆ 2   │
+        └─────┴─────┘
+        """
+        by = parse_into_expression(by)
+        return wrap_expr(self._pyexpr.downsample_minmax(by, n_buckets))
+
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def rolling_min_by(
@@ -9513,6 +10199,10 @@ Consider using {self}.implode() instead"""
             Seed for the random number generator. If set to None (default), a
             random seed is generated each time the shuffle is called.
 
+            Inside :meth:`over`, the seed is combined with the hash of the group key,
+            so that every group is shuffled differently, but reproducibly and
+            independent of the order of the groups.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -9557,6 +10247,10 @@ Consider using {self}.implode() instead"""
             Seed for the random number generator. If set to None (default), a
             random seed is generated for each sample operation.
 
+            Inside :meth:`over`, the seed is combined with the hash of the group key,
+            so that every group is sampled differently, but reproducibly and
+            independent of the order of the groups.
+
         Examples
         --------
         >>> df = pl.DataFrame({"a": [1, 2, 3]})
@@ -10213,6 +10907,97 @@ Consider using {self}.implode() instead"""
         """
         return wrap_expr(self._pyexpr.entropy(base, normalize))
 
+    @unstable()
+    def bootstrap(
+        self,
+        n_samples: int = 1000,
+        statistic: BootstrapStatistic = "mean",
+        *,
+        method: BootstrapMethod = "iid",
+        block_size: int | None = None,
+        weights: IntoExpr | None = None,
+        confidence_level: float = 0.95,
+        seed: int | None = None,
+    ) -> Expr:
+        """
+        Estimate the sampling distribution of a statistic by bootstrap resampling.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The non-null values are resampled `n_samples` times, and `statistic` is
+        computed on every sample. The result is a struct with the `mean` and `std`
+        of the statistic over the samples, and the `lower` and `upper` bounds of
+        its percentile confidence interval. In a group-by context, every group is
+        resampled independently.
+
+        Parameters
+        ----------
+        n_samples
+            Number of bootstrap samples to draw.
+        statistic : {'mean', 'median', 'sum', 'std', 'var', 'min', 'max'}
+            The statistic to compute on every sample.
+        method : {'iid', 'block'}
+            How the samples are drawn.
+
+            - 'iid': draw single values with replacement.
+            - 'block': moving block bootstrap, draw runs of `block_size`
+              consecutive values with replacement. This keeps the
+              autocorrelation of ordered data within the blocks.
+        block_size
+            Length of the blocks for the 'block' method.
+        weights
+            Draw values with probabilities proportional to these weights. Only
+            supported with the 'iid' method. Rows with a null weight are dropped.
+            Accepts expression input. Strings are parsed as column names.
+        confidence_level
+            Confidence level of the percentile interval, between 0 and 1.
+        seed
+            Seed for the random number generator. If set to None (default), a
+            random seed is generated for every evaluation. With a seed, every
+            group is resampled with the same random stream.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with :class:`Float64` fields
+            `mean`, `std`, `lower` and `upper`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "variant": ["a", "a", "a", "b", "b", "b"],
+        ...         "value": [1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+        ...     }
+        ... )
+        >>> df.group_by("variant", maintain_order=True).agg(
+        ...     pl.col("value").bootstrap(100, seed=0)
+        ... ).unnest("value")  # doctest: +IGNORE_RESULT
+        shape: (2, 5)
+        ┌─────────┬──────────┬──────────┬──────────┬──────────┐
+        │ variant ┆ mean     ┆ std      ┆ lower    ┆ upper    │
+        │ ---     ┆ ---      ┆ ---      ┆ ---      ┆ ---      │
+        │ str     ┆ f64      ┆ f64      ┆ f64      ┆ f64      │
+        ╞═════════╪══════════╪══════════╪══════════╪══════════╡
+        │ a       ┆ 2.013333 ┆ 0.468463 ┆ 1.333333 ┆ 2.666667 │
+        │ b       ┆ 5.013333 ┆ 0.468463 ┆ 4.333333 ┆ 5.666667 │
+        └─────────┴──────────┴──────────┴──────────┴──────────┘
+        """
+        weights_pyexpr = None if weights is None else parse_into_expression(weights)
+        return wrap_expr(
+            self._pyexpr.bootstrap(
+                weights_pyexpr,
+                n_samples,
+                statistic,
+                method,
+                block_size,
+                confidence_level,
+                seed,
+            )
+        )
+
     @unstable()
     @deprecate_renamed_parameter("min_periods", "min_samples", version="1.21.0")
     def cumulative_eval(self, expr: Expr, *, min_samples: int = 1) -> Expr:
@@ -10579,7 +11364,7 @@ Consider using {self}.implode() instead"""
 
     def replace_strict(
         self,
-        old: IntoExpr | Sequence[Any] | Mapping[Any, Any],
+        old: IntoExpr | Sequence[Any] | Mapping[Any, Any] | DataFrame | LazyFrame,
         new: IntoExpr | Sequence[Any] | NoDefault = no_default,
         *,
         default: IntoExpr | NoDefault = no_default,
@@ -10596,11 +11381,15 @@ Consider using {self}.implode() instead"""
             other non-expression inputs are parsed as literals.
             Also accepts a mapping of values to their replacement as syntactic sugar for
             `replace_strict(old=Series(mapping.keys()), new=Series(mapping.values()))`.
+            Also accepts a DataFrame or LazyFrame with two columns, holding the values
+            to replace and their replacements. A LazyFrame is collected once, when
+            the expression is created.
         new
             Value or sequence of values to replace by.
             Accepts expression input. Sequences are parsed as Series,
             other non-expression inputs are parsed as literals.
             Length must match the length of `old` or have length 1.
+            Must not be given if `old` is a DataFrame or LazyFrame.
         default
             Set values that were not replaced to this value. If no default is specified,
             (default), an error is raised if any values were not replaced.
@@ -10748,7 +11537,44 @@ Consider using {self}.implode() instead"""
         │ 2   ┆ 5.0 ┆ 5.0      │
         │ 3   ┆ 1.0 ┆ 10.0     │
         └─────┴─────┴──────────┘
+
+        The mapping can also be given as a frame of two columns, for example to look up
+        values in a table that is read lazily.
+
+        >>> lookup = pl.LazyFrame({"key": [1, 2], "value": ["low", "mid"]})
+        >>> df.with_columns(
+        ...     replaced=pl.col("a").replace_strict(lookup, default=pl.lit("high"))
+        ... )
+        shape: (4, 3)
+        ┌─────┬─────┬──────────┐
+        │ a   ┆ b   ┆ replaced │
+        │ --- ┆ --- ┆ ---      │
+        │ i64 ┆ f64 ┆ str      │
+        ╞═════╪═════╪══════════╡
+        │ 1   ┆ 1.5 ┆ low      │
+        │ 2   ┆ 2.5 ┆ mid      │
+        │ 2   ┆ 5.0 ┆ mid      │
+        │ 3   ┆ 1.0 ┆ high     │
+        └─────┴─────┴──────────┘
         """  # noqa: W505
+        dtype: pl.DataTypeExpr | None = None
+        if return_dtype is not None:
+            dtype = parse_into_datatype_expr(return_dtype)._pydatatype_expr
+
+        default = (
+            None
+            if default is no_default
+            else parse_into_expression(default, str_as_lit=True)
+        )
+
+        if isinstance(old, (pl.DataFrame, pl.LazyFrame)):
+            if new is not no_default:
+                msg = "`new` argument must not be given if `old` argument is a frame"
+                raise TypeError(msg)
+            return wrap_expr(
+                self._pyexpr.replace_strict_with_frame(old.lazy()._ldf, default, dtype)
+            )
+
         if new is no_default:
             if not isinstance(old, Mapping):
                 msg = (
@@ -10761,16 +11587,6 @@ Consider using {self}.implode() instead"""
         old = parse_into_expression(old, str_as_lit=True)  # type: ignore[arg-type]
         new = parse_into_expression(new, str_as_lit=True)  # type: ignore[arg-type]
 
-        dtype: pl.DataTypeExpr | None = None
-        if return_dtype is not None:
-            dtype = parse_into_datatype_expr(return_dtype)._pydatatype_expr
-
-        default = (
-            None
-            if default is no_default
-            else parse_into_expression(default, str_as_lit=True)
-        )
-
         return wrap_expr(self._pyexpr.replace_strict(old, new, default, dtype))
 
     def bitwise_count_ones(self) -> Expr:
@@ -11037,6 +11853,24 @@ Consider using {self}.implode() instead"""
         """Create an object namespace of all datetime related methods."""
         return ExprDateTimeNameSpace(self)
 
+    @property
+    def url(self) -> ExprUrlNameSpace:
+        """
+        Create an object namespace of all URL related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprUrlNameSpace(self)
+
+    @property
+    def anon(self) -> ExprAnonymizeNameSpace:
+        """
+        Create an object namespace of all anonymization related methods.
+
+        See the individual method pages for full details.
+        """
+        return ExprAnonymizeNameSpace(self)
+
     # Keep the `list` and `str` properties below at the end of the definition of Expr,
     # as to not confuse mypy with the type annotation `str` and `list`
 
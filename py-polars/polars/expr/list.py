@@ -1104,9 +1104,11 @@ class ExprListNameSpace:
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
-        fields: Sequence[str] | Callable[[int], str] | None = None,
+        fields: Sequence[str] | Callable[[int], str] | str | None = None,
         upper_bound: int | None = None,
         *,
+        error_on_overflow: bool = False,
+        infer_sample: int | None = None,
         _eager: bool = False,
     ) -> Expr:
         """
@@ -1123,8 +1125,11 @@ class ExprListNameSpace:
         fields
             If the name and number of the desired fields is known in advance
             a list of field names can be given, which will be assigned by index.
-            Otherwise, to dynamically assign field names, a custom function can be
-            used; if neither are set, fields will be `field_0, field_1 .. field_n`.
+            Otherwise, to dynamically assign field names, a custom function or a
+            format string in which `{}` is replaced by the index of the field (e.g.
+            `"n{}"`) can be given; if neither are set, fields will be
+            `field_0, field_1 .. field_n`. A format string is evaluated in Rust, so it
+            is faster than a function and the expression remains serializable.
         upper_bound
             A polars `LazyFrame` needs to know the schema at all times, so the
             caller must provide an upper bound of the number of struct fields that
@@ -1135,6 +1140,17 @@ class ExprListNameSpace:
             When operating on a `DataFrame`, the schema does not need to be
             tracked or pre-determined, as the result will be eagerly evaluated,
             so you can leave this parameter unset.
+        error_on_overflow
+            Raise an error if a sublist has more elements than the struct has fields,
+            instead of silently dropping the elements that do not fit. Use this to
+            detect an `upper_bound`, or a list of `fields`, that is too small.
+        infer_sample
+            If `upper_bound` is not set, infer the number of struct fields from this
+            many rows of the input of the `select`, `with_columns`, `filter` or
+            `group_by().agg` when the schema of the query is resolved, e.g. by
+            `collect` or `collect_schema`, so that the schema is known. The rows are
+            collected, so this can be expensive. Lists that are longer than those in
+            the sample are truncated, unless `error_on_overflow` is set.
 
         Notes
         -----
@@ -1194,19 +1210,46 @@ class ExprListNameSpace:
         ...     named=True
         ... )
         [{'n': {'one': 0, 'two': 1}}, {'n': {'one': 2, 'two': 3}}]
+
+        Convert list to struct with field names from a format string, in lazy mode
+        with an upper bound on the number of fields:
+
+        >>> lf = pl.LazyFrame({"n": [[0, 1], [2, 3]]})
+        >>> lf.select(
+        ...     pl.col("n").list.to_struct(fields="n{}", upper_bound=2)
+        ... ).collect_schema()
+        Schema({'n': Struct({'n0': Int64, 'n1': Int64})})
         """
+        name_format = None
+        if isinstance(fields, str):
+            if "{}" not in fields:
+                msg = (
+                    "format string for the field names must contain '{}',"
+                    f" got {fields!r}"
+                )
+                raise ValueError(msg)
+            name_format, fields = fields, None
+
         if isinstance(fields, Sequence):
-            pyexpr = self._pyexpr.list_to_struct_fixed_width(fields)
+            pyexpr = self._pyexpr.list_to_struct_fixed_width(fields, error_on_overflow)
             return wrap_expr(pyexpr)
         else:
-            if not _eager:
+            if not _eager and upper_bound is None and infer_sample is None:
                 msg = (
-                    "`to_struct()` should be passed a list of field names to avoid "
-                    "query errors in subsequent operations (e.g. <struct operation> "
-                    "not supported for dtype Unknown)"
+                    "`to_struct()` should be passed a list of field names, an "
+                    "`upper_bound` or an `infer_sample` to avoid query errors in "
+                    "subsequent operations "
+                    "(e.g. <struct operation> not supported for dtype Unknown)"
                 )
                 warnings.warn(msg, stacklevel=find_stacklevel())
-            pyexpr = self._pyexpr.list_to_struct(n_field_strategy, fields, upper_bound)
+            pyexpr = self._pyexpr.list_to_struct(
+                n_field_strategy,
+                fields,
+                name_format,
+                upper_bound,
+                error_on_overflow,
+                infer_sample,
+            )
             return wrap_expr(pyexpr)
 
     def eval(self, expr: Expr, *, parallel: bool = False) -> Expr:
@@ -1244,9 +1287,47 @@ class ExprListNameSpace:
         """
         return wrap_expr(self._pyexpr.list_eval(expr._pyexpr, parallel))
 
-    def filter(self, predicate: Expr) -> Expr:
+    def agg(self, expr: Expr) -> Expr:
+        """
+        Run an aggregation expression against the lists' elements.
+
+        Every list is treated as a group, so the aggregation runs on the group-by
+        engine instead of on every list separately. Unlike :meth:`eval`, the result
+        is not wrapped in a list. Null lists give a null result.
+
+        Parameters
+        ----------
+        expr
+            Expression to run, which must produce a single value per list. Select
+            the elements with `pl.element()`.
+
+        See Also
+        --------
+        eval
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": [[1, 8, 3], [4, 5], None]})
+        >>> df.with_columns(
+        ...     q=pl.col("a").list.agg(pl.element().quantile(0.9)),
+        ...     n_big=pl.col("a").list.agg((pl.element() > 3).sum()),
+        ... )
+        shape: (3, 3)
+        ┌───────────┬──────┬───────┐
+        │ a         ┆ q    ┆ n_big │
+        │ ---       ┆ ---  ┆ ---   │
+        │ list[i64] ┆ f64  ┆ u32   │
+        ╞═══════════╪══════╪═══════╡
+        │ [1, 8, 3] ┆ 8.0  ┆ 1     │
+        │ [4, 5]    ┆ 5.0  ┆ 2     │
+        │ null      ┆ null ┆ null  │
+        └───────────┴──────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.list_agg(expr._pyexpr))
+
+    def filter(self, predicate: Expr | Series) -> Expr:
         """
-        Filter elements in each list by a boolean expression.
+        Filter elements in each list by a boolean expression or a boolean list.
 
         Parameters
         ----------
@@ -1254,6 +1335,11 @@ class ExprListNameSpace:
             A boolean expression that is evaluated per list element.
             You can refer to the current element with `pl.element()`.
 
+            If `predicate` does not refer to `pl.element()`, it must instead be a
+            `List(Boolean)` column with lists of the same lengths as the lists being
+            filtered. The elements are kept where the mask is `true`, and a null mask
+            element drops the element.
+
         Examples
         --------
         >>> import polars as pl
@@ -1271,7 +1357,34 @@ class ExprListNameSpace:
         │ 8   ┆ 5   ┆ [8]       │
         │ 3   ┆ 2   ┆ [2]       │
         └─────┴─────┴───────────┘
-        """
+
+        Filter the lists by the aligned lists of another column.
+
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "values": [[1, 2, 3], [4, 5], [6]],
+        ...         "keep": [[True, False, True], [False, True], [False]],
+        ...     }
+        ... )
+        >>> df.with_columns(kept=pl.col("values").list.filter(pl.col("keep")))
+        shape: (3, 3)
+        ┌───────────┬─────────────────────┬───────────┐
+        │ values    ┆ keep                ┆ kept      │
+        │ ---       ┆ ---                 ┆ ---       │
+        │ list[i64] ┆ list[bool]          ┆ list[i64] │
+        ╞═══════════╪═════════════════════╪═══════════╡
+        │ [1, 2, 3] ┆ [true, false, true] ┆ [1, 3]    │
+        │ [4, 5]    ┆ [false, true]       ┆ [5]       │
+        │ [6]       ┆ [false]             ┆ []        │
+        └───────────┴─────────────────────┴───────────┘
+        """
+        if isinstance(predicate, pl.Series):
+            mask = parse_into_expression(predicate)
+            return wrap_expr(self._pyexpr.list_filter_mask(mask))
+
+        root_names = predicate.meta.root_names()
+        if root_names and "" not in root_names:
+            return wrap_expr(self._pyexpr.list_filter_mask(predicate._pyexpr))
         return wrap_expr(self._pyexpr.list_filter(predicate._pyexpr))
 
     def set_union(self, other: IntoExpr | Collection[Any]) -> Expr:
@@ -351,6 +351,31 @@ class ExprMetaNameSpace:
 
         return serialize_polars_object(serializer, file, format)
 
+    def to_text(self) -> str:
+        """
+        Convert this expression to a human-readable text representation.
+
+        The text uses the syntax of the Python API and can be edited and parsed
+        back into an expression with :meth:`Expr.from_text`. Unlike
+        :meth:`serialize`, the format is stable across Polars versions.
+
+        Only a subset of the expressions can be represented as text, e.g. columns,
+        literals, arithmetic, comparisons, casts, aggregations, `when/then/otherwise`
+        and window functions. Other expressions, such as those with Python UDFs,
+        raise an error.
+
+        See Also
+        --------
+        Expr.from_text
+
+        Examples
+        --------
+        >>> expr = (pl.col("foo") * 2).sum().over("bar").alias("baz")
+        >>> expr.meta.to_text()
+        '(col("foo") * 2).sum().over(col("bar")).alias("baz")'
+        """
+        return self._pyexpr.to_text()
+
     @overload
     def write_json(self, file: None = ...) -> str: ...
 
@@ -207,6 +207,97 @@ class ExprNameNameSpace:
         """
         return wrap_expr(self._pyexpr.name_suffix(suffix))
 
+    def format(self, template: str) -> Expr:
+        """
+        Format the root column name of the expression with a template.
+
+        Parameters
+        ----------
+        template
+            Template for the new name, in which every `{}` is replaced by the root
+            column name.
+
+        Notes
+        -----
+        This will undo any previous renaming operations on the expression.
+
+        Due to implementation constraints, this method can only be called as the last
+        expression in a chain. Only one name operation per expression will work.
+        Consider using `.name.map` for advanced renaming.
+
+        See Also
+        --------
+        prefix
+        suffix
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "a": [1, 2, 3],
+        ...         "b": [4, 5, 6],
+        ...     }
+        ... )
+        >>> df.select(pl.all().max().name.format("max({})"))
+        shape: (1, 2)
+        ┌────────┬────────┐
+        │ max(a) ┆ max(b) │
+        │ ---    ┆ ---    │
+        │ i64    ┆ i64    │
+        ╞════════╪════════╡
+        │ 3      ┆ 6      │
+        └────────┴────────┘
+        """
+        return wrap_expr(self._pyexpr.name_format(template))
+
+    def replace(self, pattern: str, value: str, *, literal: bool = False) -> Expr:
+        r"""
+        Replace all matches of a regex pattern in the root column name.
+
+        Parameters
+        ----------
+        pattern
+            A valid regular expression pattern, compatible with the `regex crate
+            <https://docs.rs/regex/latest/regex/>`_.
+        value
+            String that will replace the matches. Capture groups can be referred to
+            with `$1` or `${name}`.
+        literal
+            Treat `pattern` as a literal string, not as a regular expression.
+
+        Notes
+        -----
+        This will undo any previous renaming operations on the expression.
+
+        Due to implementation constraints, this method can only be called as the last
+        expression in a chain. Only one name operation per expression will work.
+        Consider using `.name.map` for advanced renaming.
+
+        See Also
+        --------
+        map
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "price_2023": [1.0, 2.0],
+        ...         "price_2024": [1.5, 2.5],
+        ...     }
+        ... )
+        >>> df.select(pl.all().name.replace(r"^price_(\d+)$", "p$1"))
+        shape: (2, 2)
+        ┌───────┬───────┐
+        │ p2023 ┆ p2024 │
+        │ ---   ┆ ---   │
+        │ f64   ┆ f64   │
+        ╞═══════╪═══════╡
+        │ 1.0   ┆ 1.5   │
+        │ 2.0   ┆ 2.5   │
+        └───────┴───────┘
+        """
+        return wrap_expr(self._pyexpr.name_replace(pattern, value, literal))
+
     def to_lowercase(self) -> Expr:
         """
         Make the root column name lowercase.
@@ -900,6 +900,114 @@ class ExprStringNameSpace:
             raise TypeError(msg)
         return wrap_expr(self._pyexpr.str_pad_end(length, fill_char))
 
+    def pad_start_display(
+        self,
+        width: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        truncate: bool = False,
+    ) -> Expr:
+        """
+        Pad the start of the string until it is the given width when displayed.
+
+        Unlike :func:`pad_start`, which counts characters, this counts the columns
+        that the string takes up in a terminal or in a fixed-width font: East Asian
+        wide and fullwidth characters are two columns wide and combining characters
+        have no width. Use this to align columns of fixed-width reports.
+
+        Parameters
+        ----------
+        width
+            Pad the string until it is this many columns wide. Strings that are at
+            least this wide are returned as-is, unless `truncate` is set. Can be int
+            or expression.
+        fill_char
+            The character to pad the string with. Must be one column wide.
+        truncate
+            Remove characters from the end of strings that are wider than `width`
+            until they fit. A wide character that does not fit completely is
+            replaced with `fill_char`.
+
+        See Also
+        --------
+        pad_start
+        pad_end_display
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["ab", "日本", "日本語です"]})
+        >>> df.select(pl.col("a").str.pad_start_display(6, "*")).to_series().to_list()
+        ["****ab", "**日本", "日本語です"]
+        >>> df.select(
+        ...     pl.col("a").str.pad_start_display(6, "*", truncate=True)
+        ... ).to_series().to_list()
+        ["****ab", "**日本", "日本語"]
+        """
+        width = parse_into_expression(width)
+        if not isinstance(fill_char, str):
+            msg = (
+                f'"pad_start_display" expects a `str`, given a '
+                f"{qualified_type_name(fill_char)!r}"
+            )
+            raise TypeError(msg)
+        return wrap_expr(
+            self._pyexpr.str_pad_start_display(width, fill_char, truncate)
+        )
+
+    def pad_end_display(
+        self,
+        width: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        truncate: bool = False,
+    ) -> Expr:
+        """
+        Pad the end of the string until it is the given width when displayed.
+
+        Unlike :func:`pad_end`, which counts characters, this counts the columns
+        that the string takes up in a terminal or in a fixed-width font: East Asian
+        wide and fullwidth characters are two columns wide and combining characters
+        have no width. Use this to align columns of fixed-width reports.
+
+        Parameters
+        ----------
+        width
+            Pad the string until it is this many columns wide. Strings that are at
+            least this wide are returned as-is, unless `truncate` is set. Can be int
+            or expression.
+        fill_char
+            The character to pad the string with. Must be one column wide.
+        truncate
+            Remove characters from the end of strings that are wider than `width`
+            until they fit. A wide character that does not fit completely is
+            replaced with `fill_char`.
+
+        See Also
+        --------
+        pad_end
+        pad_start_display
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"a": ["ab", "日本", "日本語です"]})
+        >>> df.select(pl.col("a").str.pad_end_display(6, "*")).to_series().to_list()
+        ["ab****", "日本**", "日本語です"]
+        >>> df.select(
+        ...     pl.col("a").str.pad_end_display(6, "*", truncate=True)
+        ... ).to_series().to_list()
+        ["ab****", "日本**", "日本語"]
+        """
+        width = parse_into_expression(width)
+        if not isinstance(fill_char, str):
+            msg = (
+                f'"pad_end_display" expects a `str`, given a '
+                f"{qualified_type_name(fill_char)!r}"
+            )
+            raise TypeError(msg)
+        return wrap_expr(
+            self._pyexpr.str_pad_end_display(width, fill_char, truncate)
+        )
+
     def zfill(self, length: int | IntoExprColumn) -> Expr:
         """
         Pad the start of the string with zeros until it reaches the given length.
@@ -1790,7 +1898,9 @@ class ExprStringNameSpace:
             return wrap_expr(self._pyexpr.str_split_inclusive(by))
         return wrap_expr(self._pyexpr.str_split(by))
 
-    def split_exact(self, by: IntoExpr, n: int, *, inclusive: bool = False) -> Expr:
+    def split_exact(
+        self, by: IntoExpr, n: int, *, inclusive: bool = False, rest: bool = False
+    ) -> Expr:
         """
         Split the string by a substring using `n` splits.
 
@@ -1806,6 +1916,9 @@ class ExprStringNameSpace:
             Number of splits to make.
         inclusive
             If True, include the split character/string in the results.
+        rest
+            If True, the last field is named `rest` and holds the remainder of the
+            string after `n` splits, instead of only the part up to the next split.
 
         Returns
         -------
@@ -1852,8 +1965,25 @@ class ExprStringNameSpace:
         │ c    ┆ c          ┆ null        │
         │ d_4  ┆ d          ┆ 4           │
         └──────┴────────────┴─────────────┘
+
+        Keep the remainder of the string after the first split with `rest=True`.
+
+        >>> df = pl.DataFrame({"x": ["a_1_x", "b_2", "c"]})
+        >>> df.select(pl.col("x").str.split_exact("_", 1, rest=True)).unnest("x")
+        shape: (3, 2)
+        ┌─────────┬──────┐
+        │ field_0 ┆ rest │
+        │ ---     ┆ ---  │
+        │ str     ┆ str  │
+        ╞═════════╪══════╡
+        │ a       ┆ 1_x  │
+        │ b       ┆ 2    │
+        │ c       ┆ null │
+        └─────────┴──────┘
         """
         by = parse_into_expression(by, str_as_lit=True)
+        if rest:
+            return wrap_expr(self._pyexpr.str_split_exact_with_rest(by, n, inclusive))
         if inclusive:
             return wrap_expr(self._pyexpr.str_split_exact_inclusive(by, n))
         return wrap_expr(self._pyexpr.str_split_exact(by, n))
@@ -2181,6 +2311,96 @@ class ExprStringNameSpace:
         """
         return wrap_expr(self._pyexpr.str_reverse())
 
+    @unstable()
+    def parse_common_log(self) -> Expr:
+        """
+        Parse web server log lines in the Common Log Format into a struct.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Lines in the Combined Log Format are supported as well, the trailing
+        `referer` and `user_agent` fields are null for lines that don't have them.
+        Fields logged as `-` are returned as null, as are lines that don't match
+        the format.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `host`, `ident`,
+            `user`, `time`, `method`, `path`, `protocol`, `status` (:class:`UInt16`),
+            `size` (:class:`UInt64`), `referer` and `user_agent`.
+
+        Notes
+        -----
+        The `time` field is returned as written in the log. It can be converted
+        with `str.to_datetime("%d/%b/%Y:%H:%M:%S %z")`.
+
+        Examples
+        --------
+        >>> log = '::1 - - [10/Oct/2000:13:55:36 -0700] "GET / HTTP/1.1" 200 512'
+        >>> df = pl.DataFrame({"log": [log, "not a log line"]})
+        >>> df.select(pl.col("log").str.parse_common_log()).unnest("log").select(
+        ...     "host", "time", "method", "path", "status", "size"
+        ... )
+        shape: (2, 6)
+        ┌──────┬────────────────────────────┬────────┬──────┬────────┬──────┐
+        │ host ┆ time                       ┆ method ┆ path ┆ status ┆ size │
+        │ ---  ┆ ---                        ┆ ---    ┆ ---  ┆ ---    ┆ ---  │
+        │ str  ┆ str                        ┆ str    ┆ str  ┆ u16    ┆ u64  │
+        ╞══════╪════════════════════════════╪════════╪══════╪════════╪══════╡
+        │ ::1  ┆ 10/Oct/2000:13:55:36 -0700 ┆ GET    ┆ /    ┆ 200    ┆ 512  │
+        │ null ┆ null                       ┆ null   ┆ null ┆ null   ┆ null │
+        └──────┴────────────────────────────┴────────┴──────┴────────┴──────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_common_log())
+
+    @unstable()
+    def parse_user_agent(self) -> Expr:
+        """
+        Extract the browser, operating system and device type from user agents.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The values of `User-Agent` HTTP headers are matched against the tokens of
+        common browsers and operating systems. Values that can't be identified are
+        returned as null. The `device` is one of `"desktop"`, `"mobile"`,
+        `"tablet"` or `"bot"`.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `browser`,
+            `browser_version`, `os` and `device`, all of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "ua": [
+        ...             "Mozilla/5.0 (Android 14; Mobile) Firefox/128.0",
+        ...             "Mozilla/5.0 (Windows NT 10.0) Chrome/126.0.0.0",
+        ...             "Googlebot/2.1 (+http://www.google.com/bot.html)",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("ua").str.parse_user_agent()).unnest("ua")
+        shape: (3, 4)
+        ┌─────────┬─────────────────┬─────────┬─────────┐
+        │ browser ┆ browser_version ┆ os      ┆ device  │
+        │ ---     ┆ ---             ┆ ---     ┆ ---     │
+        │ str     ┆ str             ┆ str     ┆ str     │
+        ╞═════════╪═════════════════╪═════════╪═════════╡
+        │ Firefox ┆ 128.0           ┆ Android ┆ mobile  │
+        │ Chrome  ┆ 126.0.0.0       ┆ Windows ┆ desktop │
+        │ null    ┆ null            ┆ null    ┆ bot     │
+        └─────────┴─────────────────┴─────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.str_parse_user_agent())
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Expr:
@@ -0,0 +1,169 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.various import qualified_type_name
+from polars._utils.wrap import wrap_expr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+class ExprUrlNameSpace:
+    """Namespace for URL related expressions."""
+
+    _accessor = "url"
+
+    def __init__(self, expr: Expr) -> None:
+        self._pyexpr = expr._pyexpr
+
+    @unstable()
+    def parse(self) -> Expr:
+        """
+        Split URLs into their components.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The URLs are split following the generic syntax of RFC 3986. User
+        information and the port are not part of the returned `host`. Components
+        that are not present in a URL are returned as null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`Struct` with the fields `scheme`,
+            `host`, `path`, `query` and `fragment`, all of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "url": [
+        ...             "https://pola.rs/posts?page=2#top",
+        ...             "ftp://files.example.com/pub",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.select(pl.col("url").url.parse()).unnest("url")
+        shape: (2, 5)
+        ┌────────┬───────────────────┬────────┬────────┬──────────┐
+        │ scheme ┆ host              ┆ path   ┆ query  ┆ fragment │
+        │ ---    ┆ ---               ┆ ---    ┆ ---    ┆ ---      │
+        │ str    ┆ str               ┆ str    ┆ str    ┆ str      │
+        ╞════════╪═══════════════════╪════════╪════════╪══════════╡
+        │ https  ┆ pola.rs           ┆ /posts ┆ page=2 ┆ top      │
+        │ ftp    ┆ files.example.com ┆ /pub   ┆ null   ┆ null     │
+        └────────┴───────────────────┴────────┴────────┴──────────┘
+        """
+        return wrap_expr(self._pyexpr.url_parse())
+
+    @unstable()
+    def query_param(self, key: str) -> Expr:
+        """
+        Get the value of a query string parameter.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The value of the first occurrence of `key` is returned, decoded as
+        `application/x-www-form-urlencoded` (`+` is decoded as a space).
+
+        Parameters
+        ----------
+        key
+            Name of the query parameter.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {"url": ["https://pola.rs/?page=2&q=a+b", "https://pola.rs/?q=x"]}
+        ... )
+        >>> df.with_columns(page=pl.col("url").url.query_param("page"))
+        shape: (2, 2)
+        ┌───────────────────────────────┬──────┐
+        │ url                           ┆ page │
+        │ ---                           ┆ ---  │
+        │ str                           ┆ str  │
+        ╞═══════════════════════════════╪══════╡
+        │ https://pola.rs/?page=2&q=a+b ┆ 2    │
+        │ https://pola.rs/?q=x          ┆ null │
+        └───────────────────────────────┴──────┘
+        """
+        if not isinstance(key, str):
+            msg = f'"query_param" expects a `str`, given a {qualified_type_name(key)!r}'
+            raise TypeError(msg)
+        return wrap_expr(self._pyexpr.url_query_param(key))
+
+    @unstable()
+    def encode(self) -> Expr:
+        """
+        Percent-encode the strings as a URL component.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        All characters except the unreserved characters of RFC 3986
+        (`A-Z`, `a-z`, `0-9`, `-`, `_`, `.` and `~`) are encoded.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"raw": ["a b&c", "café"]})
+        >>> df.with_columns(encoded=pl.col("raw").url.encode())
+        shape: (2, 2)
+        ┌───────┬───────────┐
+        │ raw   ┆ encoded   │
+        │ ---   ┆ ---       │
+        │ str   ┆ str       │
+        ╞═══════╪═══════════╡
+        │ a b&c ┆ a%20b%26c │
+        │ café  ┆ caf%C3%A9 │
+        └───────┴───────────┘
+        """
+        return wrap_expr(self._pyexpr.url_encode())
+
+    @unstable()
+    def decode(self) -> Expr:
+        """
+        Decode percent-encoded strings.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Values that are not valid UTF-8 after decoding are returned as null.
+
+        Returns
+        -------
+        Expr
+            Expression of data type :class:`String`.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"encoded": ["a%20b%26c", "caf%C3%A9"]})
+        >>> df.with_columns(decoded=pl.col("encoded").url.decode())
+        shape: (2, 2)
+        ┌───────────┬─────────┐
+        │ encoded   ┆ decoded │
+        │ ---       ┆ ---     │
+        │ str       ┆ str     │
+        ╞═══════════╪═════════╡
+        │ a%20b%26c ┆ a b&c   │
+        │ caf%C3%A9 ┆ café    │
+        └───────────┴─────────┘
+        """
+        return wrap_expr(self._pyexpr.url_decode())
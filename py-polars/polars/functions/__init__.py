@@ -8,8 +8,11 @@ from polars.functions.aggregation import (
     max,
     max_horizontal,
     mean_horizontal,
+    median_horizontal,
     min,
     min_horizontal,
+    quantile_horizontal,
+    std_horizontal,
     sum,
     sum_horizontal,
 )
@@ -35,8 +38,10 @@ from polars.functions.lazy import (
     arctan2d,
     arg_sort_by,
     arg_where,
+    cached,
     coalesce,
     collect_all,
+    collect_all_as_completed,
     collect_all_async,
     corr,
     count,
@@ -54,6 +59,8 @@ from polars.functions.lazy import (
     groups,
     head,
     implode,
+    is_first_distinct,
+    is_last_distinct,
     last,
     map_batches,
     map_groups,
@@ -67,13 +74,14 @@ from polars.functions.lazy import (
     rolling_cov,
     row_index,
     select,
+    sink_multiple,
     sql_expr,
     std,
     tail,
     var,
 )
 from polars.functions.len import len
-from polars.functions.lit import lit
+from polars.functions.lit import lit, placeholder
 from polars.functions.random import set_random_seed
 from polars.functions.range import (
     arange,
@@ -89,6 +97,7 @@ from polars.functions.range import (
     time_ranges,
 )
 from polars.functions.repeat import ones, repeat, zeros
+from polars.functions.unique_id import snowflake_id, uuid4
 from polars.functions.whenthen import when
 
 __all__ = [
@@ -103,7 +112,10 @@ __all__ = [
     "any_horizontal",
     "cum_sum_horizontal",
     "max_horizontal",
+    "median_horizontal",
     "min_horizontal",
+    "quantile_horizontal",
+    "std_horizontal",
     "sum_horizontal",
     # polars.functions.datatype
     "dtype_of",
@@ -129,9 +141,11 @@ __all__ = [
     "arctan2d",
     "arg_sort_by",
     "business_day_count",
+    "cached",
     "coalesce",
     "col",
     "collect_all",
+    "collect_all_as_completed",
     "collect_all_async",
     "concat_arr",
     "concat_list",
@@ -157,6 +171,8 @@ __all__ = [
     "implode",
     "int_range",
     "int_ranges",
+    "is_first_distinct",
+    "is_last_distinct",
     "last",
     "linear_space",
     "linear_spaces",
@@ -168,6 +184,7 @@ __all__ = [
     "median",
     "n_unique",
     "nth",
+    "placeholder",
     "quantile",
     "reduce",
     "rolling_corr",
@@ -175,6 +192,7 @@ __all__ = [
     "row_index",
     "select",
     "set_random_seed",
+    "sink_multiple",
     "std",
     "struct",
     "tail",
@@ -182,6 +200,9 @@ __all__ = [
     "var",
     # polars.functions.len
     "len",
+    # polars.functions.unique_id
+    "snowflake_id",
+    "uuid4",
     # polars.functions.whenthen
     "when",
     "sql_expr",
@@ -4,7 +4,10 @@ from polars.functions.aggregation.horizontal import (
     cum_sum_horizontal,
     max_horizontal,
     mean_horizontal,
+    median_horizontal,
     min_horizontal,
+    quantile_horizontal,
+    std_horizontal,
     sum_horizontal,
 )
 from polars.functions.aggregation.vertical import (
@@ -26,8 +29,11 @@ __all__ = [
     "max",
     "max_horizontal",
     "mean_horizontal",
+    "median_horizontal",
     "min",
     "min_horizontal",
+    "quantile_horizontal",
+    "std_horizontal",
     "sum",
     "sum_horizontal",
 ]
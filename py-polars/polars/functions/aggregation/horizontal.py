@@ -14,7 +14,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
 
     from polars import Expr
-    from polars._typing import IntoExpr
+    from polars._typing import IntoExpr, QuantileMethod
 
 
 def all_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
@@ -257,6 +257,130 @@ def mean_horizontal(
     return wrap_expr(plr.mean_horizontal(pyexprs, ignore_nulls))
 
 
+def median_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
+    """
+    Compute the median of all values horizontally across columns.
+
+    Null values are ignored. Rows without any non-null value produce a null.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, 8, 3],
+    ...         "b": [4, 5, None],
+    ...         "c": [2, 9, 6],
+    ...     }
+    ... )
+    >>> df.with_columns(median=pl.median_horizontal(pl.all()))
+    shape: (3, 4)
+    ┌─────┬──────┬─────┬────────┐
+    │ a   ┆ b    ┆ c   ┆ median │
+    │ --- ┆ ---  ┆ --- ┆ ---    │
+    │ i64 ┆ i64  ┆ i64 ┆ f64    │
+    ╞═════╪══════╪═════╪════════╡
+    │ 1   ┆ 4    ┆ 2   ┆ 2.0    │
+    │ 8   ┆ 5    ┆ 9   ┆ 8.0    │
+    │ 3   ┆ null ┆ 6   ┆ 4.5    │
+    └─────┴──────┴─────┴────────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.quantile_horizontal(pyexprs, 0.5, "linear"))
+
+
+def quantile_horizontal(
+    *exprs: IntoExpr | Iterable[IntoExpr],
+    quantile: float,
+    interpolation: QuantileMethod = "nearest",
+) -> Expr:
+    """
+    Compute a quantile of all values horizontally across columns.
+
+    Null values are ignored. Rows without any non-null value produce a null.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+    quantile
+        Quantile between 0.0 and 1.0.
+    interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+        Interpolation method.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [1, 8, 3],
+    ...         "b": [4, 5, None],
+    ...         "c": [2, 9, 6],
+    ...     }
+    ... )
+    >>> df.with_columns(
+    ...     q=pl.quantile_horizontal(pl.all(), quantile=0.75, interpolation="linear")
+    ... )
+    shape: (3, 4)
+    ┌─────┬──────┬─────┬──────┐
+    │ a   ┆ b    ┆ c   ┆ q    │
+    │ --- ┆ ---  ┆ --- ┆ ---  │
+    │ i64 ┆ i64  ┆ i64 ┆ f64  │
+    ╞═════╪══════╪═════╪══════╡
+    │ 1   ┆ 4    ┆ 2   ┆ 3.0  │
+    │ 8   ┆ 5    ┆ 9   ┆ 8.5  │
+    │ 3   ┆ null ┆ 6   ┆ 5.25 │
+    └─────┴──────┴─────┴──────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.quantile_horizontal(pyexprs, quantile, interpolation))
+
+
+def std_horizontal(*exprs: IntoExpr | Iterable[IntoExpr], ddof: int = 1) -> Expr:
+    """
+    Compute the standard deviation of all values horizontally across columns.
+
+    Null values are ignored. Rows with at most `ddof` non-null values produce a null.
+
+    Parameters
+    ----------
+    *exprs
+        Column(s) to use in the aggregation. Accepts expression input. Strings are
+        parsed as column names, other non-expression inputs are parsed as literals.
+    ddof
+        "Delta Degrees of Freedom": the divisor used in the calculation is N - ddof,
+        where N represents the number of non-null values in a row.
+
+    Examples
+    --------
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "a": [2, 8, 1],
+    ...         "b": [4, 5, None],
+    ...         "c": [6, 2, 1],
+    ...     }
+    ... )
+    >>> df.with_columns(std=pl.std_horizontal(pl.all()))
+    shape: (3, 4)
+    ┌─────┬──────┬─────┬─────┐
+    │ a   ┆ b    ┆ c   ┆ std │
+    │ --- ┆ ---  ┆ --- ┆ --- │
+    │ i64 ┆ i64  ┆ i64 ┆ f64 │
+    ╞═════╪══════╪═════╪═════╡
+    │ 2   ┆ 4    ┆ 6   ┆ 2.0 │
+    │ 8   ┆ 5    ┆ 2   ┆ 3.0 │
+    │ 1   ┆ null ┆ 1   ┆ 0.0 │
+    └─────┴──────┴─────┴─────┘
+    """
+    pyexprs = parse_into_list_of_expressions(*exprs)
+    return wrap_expr(plr.std_horizontal(pyexprs, ddof))
+
+
 def cum_sum_horizontal(*exprs: IntoExpr | Iterable[IntoExpr]) -> Expr:
     """
     Cumulatively sum all values horizontally across columns.
@@ -524,6 +524,10 @@ def concat_arr(exprs: IntoExpr | Iterable[IntoExpr], *more_exprs: IntoExpr) -> E
         Additional columns to concatenate into a single array column, specified as
         positional arguments.
 
+    See Also
+    --------
+    Expr.arr.to_cols
+
     Examples
     --------
     Concatenate 2 array columns:
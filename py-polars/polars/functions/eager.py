@@ -20,7 +20,7 @@ if TYPE_CHECKING:
     from collections.abc import Iterable
 
     from polars import DataFrame, Expr, LazyFrame, Series
-    from polars._typing import FrameType, JoinStrategy, PolarsType
+    from polars._typing import FillNullStrategy, FrameType, JoinStrategy, PolarsType
 
 
 def concat(
@@ -346,6 +346,7 @@ def align_frames(
     how: JoinStrategy = "full",
     select: str | Expr | Sequence[str | Expr] | None = None,
     descending: bool | Sequence[bool] = False,
+    fill_strategy: FillNullStrategy | Sequence[FillNullStrategy | None] | None = None,
 ) -> list[FrameType]:
     r"""
     Align a sequence of frames using common values from one or more columns as a key.
@@ -362,7 +363,8 @@ def align_frames(
 
     Note that this function does not result in a joined frame - you receive the same
     number of frames back that you passed in, but each is now aligned by key and has
-    the same number of rows.
+    the same number of rows. LazyFrames are aligned lazily, and the first key column
+    of every aligned frame is flagged as sorted.
 
     Parameters
     ----------
@@ -380,6 +382,14 @@ def align_frames(
         By default the row alignment values are determined using a full outer join
         strategy across all frames; if you know that the first frame contains all
         required keys, you can set `how="left"` for a large performance increase.
+    fill_strategy : {None, 'forward', 'backward', 'min', 'max', 'mean', 'zero', 'one'}
+        Strategy with which the nulls of the non-key columns are filled after the
+        frames are aligned; can be a single strategy for all frames or a list with a
+        strategy (or `None` to not fill) for each frame. When aligning on multiple
+        columns, the nulls are filled within the groups of all but the last of them,
+        so that e.g. values are carried forward in time per entity when aligning on
+        an entity and a time column. Note that nulls that were in the frames before
+        they were aligned are filled as well.
 
     Examples
     --------
@@ -479,6 +489,30 @@ def align_frames(
     ├╌╌╌╌╌╌╌┤
     │ 47.0  │
     └───────┘
+
+    Align frames on multiple columns, carrying the values of the second frame
+    forward per id:
+
+    >>> obs = pl.DataFrame({"id": [1, 1, 2], "t": [1, 2, 1], "x": [1.0, 2.0, 3.0]})
+    >>> feat = pl.DataFrame({"id": [1, 2, 2], "t": [1, 1, 2], "f": [10, 20, 30]})
+    >>> _, feat = pl.align_frames(
+    ...     obs, feat, on=["id", "t"], fill_strategy=[None, "forward"]
+    ... )
+    >>> feat
+    shape: (4, 3)
+    ┌─────┬─────┬─────┐
+    │ id  ┆ t   ┆ f   │
+    │ --- ┆ --- ┆ --- │
+    │ i64 ┆ i64 ┆ i64 │
+    ╞═════╪═════╪═════╡
+    │ 1   ┆ 1   ┆ 10  │
+    ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
+    │ 1   ┆ 2   ┆ 10  │
+    ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
+    │ 2   ┆ 1   ┆ 20  │
+    ├╌╌╌╌╌┼╌╌╌╌╌┼╌╌╌╌╌┤
+    │ 2   ┆ 2   ┆ 30  │
+    └─────┴─────┴─────┘
     """  # noqa: W505
     if not frames:
         return []
@@ -498,6 +532,18 @@ def align_frames(
     on = [on] if (isinstance(on, str) or not isinstance(on, Sequence)) else on
     align_on = [(c.meta.output_name() if isinstance(c, pl.Expr) else c) for c in on]
 
+    if fill_strategy is None or isinstance(fill_strategy, str):
+        fill_strategies = [fill_strategy] * len(frames)
+    else:
+        fill_strategies = list(fill_strategy)
+        if len(fill_strategies) != len(frames):
+            msg = (
+                f"`fill_strategy` has {len(fill_strategies)} strategies, "
+                f"but {len(frames)} frames were given"
+            )
+            raise ValueError(msg)
+    first_descending = descending if isinstance(descending, bool) else descending[0]
+
     # create aligned master frame (this is the most expensive part; after
     # we just select out the columns representing the component frames)
     idx_frames = [(idx, frame.lazy()) for idx, frame in enumerate(frames)]  # type: ignore[union-attr]
@@ -514,7 +560,12 @@ def align_frames(
             F.col(f"{c}{sfx}").alias(c) if f"{c}{sfx}" in aligned_cols else F.col(c)
             for c in lf.collect_schema()
         ]
-        f = alignment_frame.select(*df_cols)
+        f = alignment_frame.select(*df_cols).with_columns(
+            F.col(align_on[0]).set_sorted(descending=first_descending)
+        )
+        if (strategy := fill_strategies[idx]) is not None:
+            fill = F.all().exclude(align_on).fill_null(strategy=strategy)
+            f = f.with_columns(fill.over(align_on[:-1]) if len(align_on) > 1 else fill)
         if select is not None:
             f = f.select(select)
         aligned_frames.append(f)
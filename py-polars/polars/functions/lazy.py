@@ -33,7 +33,13 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     import sys
-    from collections.abc import Awaitable, Collection, Iterable, Sequence
+    from collections.abc import (
+        AsyncIterator,
+        Awaitable,
+        Collection,
+        Iterable,
+        Sequence,
+    )
     from typing import Literal
 
     from polars import DataFrame, Expr, LazyFrame, Series
@@ -1769,6 +1775,7 @@ def arg_sort_by(
     nulls_last: bool | Sequence[bool] = False,
     multithreaded: bool = True,
     maintain_order: bool = False,
+    limit: int | None = None,
 ) -> Expr:
     """
     Return the row indices that would sort the column(s).
@@ -1789,6 +1796,9 @@ def arg_sort_by(
         Sort using multiple threads.
     maintain_order
         Whether the order should be maintained if elements are equal.
+    limit
+        Only return the first `limit` row indices. These are found with a partial
+        selection instead of sorting all rows, which is faster for small limits.
 
     See Also
     --------
@@ -1835,6 +1845,19 @@ def arg_sort_by(
     │ 3   │
     └─────┘
 
+    Only compute the row indices of the first rows.
+
+    >>> df.select(pl.arg_sort_by("b", "a", limit=2))
+    shape: (2, 1)
+    ┌─────┐
+    │ b   │
+    │ --- │
+    │ u32 │
+    ╞═════╡
+    │ 3   │
+    │ 1   │
+    └─────┘
+
     Use gather to apply the arg sort to other columns.
 
     >>> df.select(pl.col("c").gather(pl.arg_sort_by("a")))
@@ -1854,7 +1877,9 @@ def arg_sort_by(
     descending = extend_bool(descending, len(exprs), "descending", "exprs")
     nulls_last = extend_bool(nulls_last, len(exprs), "nulls_last", "exprs")
     return wrap_expr(
-        plr.arg_sort_by(exprs, descending, nulls_last, multithreaded, maintain_order)
+        plr.arg_sort_by(
+            exprs, descending, nulls_last, multithreaded, maintain_order, limit
+        )
     )
 
 
@@ -1982,6 +2007,7 @@ def collect_all_async(
     gevent: Literal[True],
     engine: EngineType = "auto",
     optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    max_concurrency: None = None,
 ) -> _GeventDataFrameResult[list[DataFrame]]: ...
 
 
@@ -1992,6 +2018,7 @@ def collect_all_async(
     gevent: Literal[False] = False,
     engine: EngineType = "auto",
     optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    max_concurrency: int | None = None,
 ) -> Awaitable[list[DataFrame]]: ...
 
 
@@ -2003,6 +2030,7 @@ def collect_all_async(
     gevent: bool = False,
     engine: EngineType = "auto",
     optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    max_concurrency: int | None = None,
 ) -> Awaitable[list[DataFrame]] | _GeventDataFrameResult[list[DataFrame]]:
     """
     Collect multiple LazyFrames at the same time asynchronously in thread pool.
@@ -2042,10 +2070,18 @@ def collect_all_async(
         .. note::
            The GPU engine does not support async, or running in the
            background. If either are enabled, then GPU execution is switched off.
+    max_concurrency
+        Collect the LazyFrames as separate queries, of which at most
+        `max_concurrency` run at the same time, like
+        :func:`polars.collect_all_as_completed`. This bounds the number of open
+        files and requests when each LazyFrame reads a small (cloud) file.
+        The DataFrames are still returned in the order of `lazy_frames`.
+        Not supported together with `gevent=True`.
 
     See Also
     --------
     polars.collect_all : Collect multiple LazyFrames at the same time.
+    polars.collect_all_as_completed : Yield the results as they finish.
     LazyFrame.collect_async : To collect single frame.
 
     Notes
@@ -2053,6 +2089,11 @@ def collect_all_async(
     In case of error `set_exception` is used on
     `asyncio.Future`/`gevent.event.AsyncResult` and will be reraised by them.
 
+    Only the collection runs in the background. Building the LazyFrames, such
+    as the file listing and schema resolution of :func:`polars.scan_parquet` on
+    cloud storage, and eager reads such as :func:`polars.read_database` still
+    block the calling thread. Use :func:`asyncio.to_thread` for those.
+
     Returns
     -------
     If `gevent=False` (default) then returns awaitable.
@@ -2063,6 +2104,19 @@ def collect_all_async(
     if engine == "streaming":
         issue_unstable_warning("streaming mode is considered unstable.")
 
+    if max_concurrency is not None:
+        if gevent:
+            msg = "`max_concurrency` is not supported together with `gevent=True`"
+            raise ValueError(msg)
+        return _gather_ordered(
+            collect_all_as_completed(
+                lazy_frames,
+                max_concurrency=max_concurrency,
+                engine=engine,
+                optimizations=optimizations,
+            )
+        )
+
     result: (
         _GeventDataFrameResult[list[DataFrame]] | _AioDataFrameResult[list[DataFrame]]
     ) = _GeventDataFrameResult() if gevent else _AioDataFrameResult()
@@ -2073,6 +2127,128 @@ def collect_all_async(
     return result
 
 
+async def _gather_ordered(
+    results: AsyncIterator[tuple[int, DataFrame]],
+) -> list[DataFrame]:
+    """Collect the `(index, DataFrame)` pairs of `results` into an ordered list."""
+    out = {idx: df async for idx, df in results}
+    return [out[idx] for idx in range(len(out))]
+
+
+@unstable()
+async def collect_all_as_completed(
+    lazy_frames: Iterable[LazyFrame],
+    *,
+    max_concurrency: int | None = None,
+    priority: Sequence[int] | None = None,
+    engine: EngineType = "auto",
+    optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+) -> AsyncIterator[tuple[int, DataFrame]]:
+    """
+    Collect multiple LazyFrames and yield the results as they finish.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Unlike :func:`polars.collect_all`, the LazyFrames are collected as separate
+    queries, of which at most `max_concurrency` run at the same time. This limits
+    how many queries are in flight at once, e.g. in batch reporting jobs. It does
+    not limit the resources of a single query: every query still uses the full
+    thread pool, and no common subplans are shared between the queries.
+
+    Parameters
+    ----------
+    lazy_frames
+        A list of LazyFrames to collect.
+    max_concurrency
+        The maximum number of queries that run at the same time. By default all
+        queries are started at once.
+    priority
+        A priority for every LazyFrame. Queries with a higher priority are
+        started first, queries with the same priority are started in the order
+        they are given.
+    engine
+        Select the engine used to process the queries, optional.
+        See :func:`polars.collect_all` for the available engines.
+    optimizations
+        The optimization passes done during query optimization.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+    See Also
+    --------
+    polars.collect_all_async : Collect multiple LazyFrames at the same time.
+
+    Notes
+    -----
+    If a query fails, its exception is raised by the iterator and the queries
+    that have not started yet are skipped. Queries that are already running
+    cannot be interrupted; they run to completion and their results are
+    discarded.
+
+    Returns
+    -------
+    An async iterator of `(index, DataFrame)` tuples, in the order in which the
+    queries finish. The index is the position of the LazyFrame in `lazy_frames`.
+
+    Examples
+    --------
+    >>> import asyncio
+    >>> lfs = [pl.LazyFrame({"a": [i]}) for i in range(3)]
+    >>> async def main() -> list[int]:
+    ...     results = {}
+    ...     async for i, df in pl.collect_all_as_completed(lfs, max_concurrency=2):
+    ...         results[i] = df.item()
+    ...     return [results[i] for i in range(3)]
+    >>> asyncio.run(main())
+    [0, 1, 2]
+    """
+    import asyncio
+
+    lfs = list(lazy_frames)
+    if max_concurrency is None:
+        max_concurrency = max(len(lfs), 1)
+    elif max_concurrency < 1:
+        msg = f"`max_concurrency` must be at least 1, got {max_concurrency}"
+        raise ValueError(msg)
+
+    order: Iterable[int] = range(len(lfs))
+    if priority is not None:
+        if len(priority) != len(lfs):
+            msg = (
+                f"`priority` must have the same length as `lazy_frames`, "
+                f"got {len(priority)} and {len(lfs)}"
+            )
+            raise ValueError(msg)
+        # `sorted` is stable, so queries with the same priority keep their order.
+        order = sorted(order, key=lambda i: -priority[i])
+
+    pending = iter(order)
+    running: dict[asyncio.Future[DataFrame], int] = {}
+
+    def start_next() -> None:
+        if (idx := next(pending, None)) is not None:
+            result = lfs[idx].collect_async(engine=engine, optimizations=optimizations)
+            running[asyncio.ensure_future(result)] = idx
+
+    for _ in range(max_concurrency):
+        start_next()
+
+    try:
+        while running:
+            done, _ = await asyncio.wait(running, return_when=asyncio.FIRST_COMPLETED)
+            for future in sorted(done, key=running.__getitem__):
+                idx = running.pop(future)
+                start_next()
+                yield idx, future.result()
+    finally:
+        for future in running:
+            future.cancel()
+
+
 @unstable()
 def explain_all(
     lazy_frames: Iterable[LazyFrame],
@@ -2104,6 +2280,60 @@ def explain_all(
     return plr.explain_all(lfs, optimizations._pyoptflags)
 
 
+@unstable()
+def sink_multiple(
+    lazy_sinks: Iterable[LazyFrame],
+    *,
+    engine: EngineType = "auto",
+    optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+) -> None:
+    """
+    Run multiple lazy sinks in a single query.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The sinks are combined into one plan, on which Common Subplan Elimination is
+    applied. A source shared by the sinks, e.g. a scan with different
+    post-processing per sink, is read only once.
+
+    Parameters
+    ----------
+    lazy_sinks
+        The sinks to run, created with the `sink_*` methods of a LazyFrame with
+        `lazy=True`, e.g. :meth:`LazyFrame.sink_parquet`.
+    engine
+        Select the engine used to process the query, optional.
+        If set to `"auto"` (default), the query is run using the polars
+        streaming engine.
+    optimizations
+        The optimization passes done during query optimization.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+    See Also
+    --------
+    collect_all : Collect multiple LazyFrames at the same time.
+
+    Examples
+    --------
+    Write the raw data and a summary of it, while scanning the source once.
+
+    >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+    >>> pl.sink_multiple(
+    ...     [
+    ...         lf.sink_parquet("raw.parquet", lazy=True),
+    ...         lf.group_by("key").agg(pl.len()).sink_csv("summary.csv", lazy=True),
+    ...     ]
+    ... )  # doctest: +SKIP
+    """
+    lfs = [lf._ldf for lf in lazy_sinks]
+    plr.sink_multiple(lfs, engine, optimizations._pyoptflags)
+
+
 @overload
 def select(
     *exprs: IntoExpr | Iterable[IntoExpr],
@@ -2339,6 +2569,106 @@ def coalesce(
         return wrap_expr(plr.coalesce(exprs))
 
 
+def is_first_distinct(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+) -> Expr:
+    """
+    Get a mask of the first occurrence of every distinct row of the given columns.
+
+    This is equivalent to calling :meth:`Expr.is_first_distinct` on a struct of the
+    columns, without creating the struct.
+
+    Parameters
+    ----------
+    exprs
+        Column(s) that make up a row. Accepts expression input. Strings are parsed as
+        column names, other non-expression inputs are parsed as literals.
+    *more_exprs
+        Additional columns, specified as positional arguments.
+
+    See Also
+    --------
+    is_last_distinct
+    Expr.is_first_distinct
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 1, 2, 1], "b": ["x", "x", "y", "z"]})
+    >>> df.with_columns(first=pl.is_first_distinct("a", "b"))
+    shape: (4, 3)
+    ┌─────┬─────┬───────┐
+    │ a   ┆ b   ┆ first │
+    │ --- ┆ --- ┆ ---   │
+    │ i64 ┆ str ┆ bool  │
+    ╞═════╪═════╪═══════╡
+    │ 1   ┆ x   ┆ true  │
+    │ 1   ┆ x   ┆ false │
+    │ 2   ┆ y   ┆ true  │
+    │ 1   ┆ z   ┆ true  │
+    └─────┴─────┴───────┘
+
+    The columns can also be given as expressions.
+
+    >>> df.filter(pl.is_first_distinct(pl.col("a") > 1, pl.col("b") == "x"))
+    shape: (3, 2)
+    ┌─────┬─────┐
+    │ a   ┆ b   │
+    │ --- ┆ --- │
+    │ i64 ┆ str │
+    ╞═════╪═════╡
+    │ 1   ┆ x   │
+    │ 2   ┆ y   │
+    │ 1   ┆ z   │
+    └─────┴─────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    return wrap_expr(plr.is_first_distinct(exprs))
+
+
+def is_last_distinct(
+    exprs: IntoExpr | Iterable[IntoExpr],
+    *more_exprs: IntoExpr,
+) -> Expr:
+    """
+    Get a mask of the last occurrence of every distinct row of the given columns.
+
+    This is equivalent to calling :meth:`Expr.is_last_distinct` on a struct of the
+    columns, without creating the struct.
+
+    Parameters
+    ----------
+    exprs
+        Column(s) that make up a row. Accepts expression input. Strings are parsed as
+        column names, other non-expression inputs are parsed as literals.
+    *more_exprs
+        Additional columns, specified as positional arguments.
+
+    See Also
+    --------
+    is_first_distinct
+    Expr.is_last_distinct
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 1, 2, 1], "b": ["x", "x", "y", "z"]})
+    >>> df.with_columns(last=pl.is_last_distinct("a", "b"))
+    shape: (4, 3)
+    ┌─────┬─────┬───────┐
+    │ a   ┆ b   ┆ last  │
+    │ --- ┆ --- ┆ ---   │
+    │ i64 ┆ str ┆ bool  │
+    ╞═════╪═════╪═══════╡
+    │ 1   ┆ x   ┆ false │
+    │ 1   ┆ x   ┆ true  │
+    │ 2   ┆ y   ┆ true  │
+    │ 1   ┆ z   ┆ true  │
+    └─────┴─────┴───────┘
+    """
+    exprs = parse_into_list_of_expressions(exprs, *more_exprs)
+    return wrap_expr(plr.is_last_distinct(exprs))
+
+
 @overload
 def from_epoch(column: str | Expr, time_unit: EpochTimeUnit = ...) -> Expr: ...
 
@@ -2631,3 +2961,57 @@ def row_index(name: str = "index") -> pl.Expr:
         F.len(),
         dtype=get_index_type(),
     ).alias(name)
+
+
+@unstable()
+def cached(expr: IntoExpr) -> Expr:
+    """
+    Compute a scalar expression only once per query, even if it is used repeatedly.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    This is allowed in :meth:`LazyFrame.select`, :meth:`LazyFrame.with_columns`,
+    :meth:`LazyFrame.filter` and the aggregations of :meth:`LazyFrame.group_by`;
+    anywhere else it raises an :class:`InvalidOperationError`. The expression is
+    evaluated on the input of that operation in a separate, cached subplan, of
+    which the single value is broadcast to the height of the frame. The subplan is
+    shared by every use of the same cached expression on the same input, also
+    across different parts of the query, such as the branches of a concatenation.
+    This avoids repeated full passes over the data for normalization constants.
+
+    Parameters
+    ----------
+    expr
+        The expression to cache. It must produce a single value; no values give a
+        null.
+
+    See Also
+    --------
+    LazyFrame.to_scalar_expr
+
+    Notes
+    -----
+    Sharing the result across different parts of the query relies on common
+    subplan elimination; see :class:`QueryOptFlags`.
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"x": [1, 2, 3, 6]})
+    >>> lf.with_columns(
+    ...     x_centered=pl.col("x") - pl.cached(pl.col("x").mean())
+    ... ).collect()
+    shape: (4, 2)
+    ┌─────┬────────────┐
+    │ x   ┆ x_centered │
+    │ --- ┆ ---        │
+    │ i64 ┆ f64        │
+    ╞═════╪════════════╡
+    │ 1   ┆ -2.0       │
+    │ 2   ┆ -1.0       │
+    │ 3   ┆ 0.0        │
+    │ 6   ┆ 3.0        │
+    └─────┴────────────┘
+    """
+    return wrap_expr(plr.cached(parse_into_expression(expr)))
@@ -7,6 +7,7 @@ from typing import TYPE_CHECKING, Any
 from zoneinfo import ZoneInfo
 
 import polars._reexport as pl
+from polars._utils.unstable import unstable
 from polars._utils.wrap import wrap_expr
 from polars.datatypes import Date, Datetime, Duration
 from polars.dependencies import (
@@ -200,3 +201,56 @@ def lit(
         item = value
 
     return wrap_expr(plr.lit(item, allow_object, is_scalar=True))
+
+
+@unstable()
+def placeholder(name: str, dtype: PolarsDataType) -> Expr:
+    """
+    Return an expression representing a literal whose value is given at collect time.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    A query with placeholders is optimized once. Collecting it with
+    `collect(params=...)` binds the placeholders to the given values, without
+    optimizing the query again, so the same query can be executed with different
+    values. Collecting a query with a placeholder without a value raises an error.
+
+    Parameters
+    ----------
+    name
+        The name of the placeholder, which is also the name of the expression.
+    dtype
+        The data type of the placeholder. Values given for the placeholder are
+        cast to this type.
+
+    See Also
+    --------
+    LazyFrame.collect
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4]})
+    >>> query = lf.filter(pl.col("a") > pl.placeholder("min_a", pl.Int64))
+    >>> query.collect(params={"min_a": 2})
+    shape: (2, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 3   │
+    │ 4   │
+    └─────┘
+    >>> query.collect(params={"min_a": 3})
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 4   │
+    └─────┘
+    """
+    return wrap_expr(plr.placeholder(name, dtype))
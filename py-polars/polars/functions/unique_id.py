@@ -0,0 +1,113 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.various import qualified_type_name
+from polars._utils.wrap import wrap_expr
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    import polars.polars as plr
+
+if TYPE_CHECKING:
+    from polars import Expr
+
+
+@unstable()
+def uuid4() -> Expr:
+    """
+    Generate a random (version 4) UUID for every row.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The UUIDs are returned in their canonical hyphenated form. New UUIDs are
+    generated every time the expression is evaluated.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`String`.
+
+    See Also
+    --------
+    snowflake_id
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 3]})
+    >>> df.with_columns(id=pl.uuid4()).select(
+    ...     pl.col("id").str.len_chars().alias("len"),
+    ...     pl.col("id").n_unique().alias("n_unique"),
+    ... )
+    shape: (3, 2)
+    ┌─────┬──────────┐
+    │ len ┆ n_unique │
+    │ --- ┆ ---      │
+    │ u32 ┆ u32      │
+    ╞═════╪══════════╡
+    │ 36  ┆ 3        │
+    │ 36  ┆ 3        │
+    │ 36  ┆ 3        │
+    └─────┴──────────┘
+    """
+    return wrap_expr(plr.uuid4())
+
+
+@unstable()
+def snowflake_id(worker_id: int = 0) -> Expr:
+    """
+    Generate a snowflake-style ID for every row.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    An ID consists of a millisecond timestamp since 2020-01-01 (41 bits), the
+    `worker_id` (10 bits) and a sequence number (12 bits). Within a process the
+    IDs are unique and strictly increasing for a given `worker_id`, also across
+    queries that run concurrently. Give every process that generates IDs at the
+    same time a different `worker_id`.
+
+    Parameters
+    ----------
+    worker_id
+        Identifier of the generating process, between 0 and 1023.
+
+    Returns
+    -------
+    Expr
+        Expression of data type :class:`UInt64`.
+
+    See Also
+    --------
+    uuid4
+
+    Examples
+    --------
+    >>> df = pl.DataFrame({"a": [1, 2, 3]})
+    >>> df.with_columns(id=pl.snowflake_id(worker_id=7)).select(
+    ...     worker=(pl.col("id") // 2**12) % 2**10,
+    ...     increasing=pl.col("id").diff() > 0,
+    ... )
+    shape: (3, 2)
+    ┌────────┬────────────┐
+    │ worker ┆ increasing │
+    │ ---    ┆ ---        │
+    │ u64    ┆ bool       │
+    ╞════════╪════════════╡
+    │ 7      ┆ null       │
+    │ 7      ┆ true       │
+    │ 7      ┆ true       │
+    └────────┴────────────┘
+    """
+    if not isinstance(worker_id, int):
+        type_name = qualified_type_name(worker_id)
+        msg = f'"snowflake_id" expects an `int`, given a {type_name!r}'
+        raise TypeError(msg)
+    if not 0 <= worker_id <= 1023:
+        msg = f"`worker_id` must be between 0 and 1023, got {worker_id}"
+        raise ValueError(msg)
+    return wrap_expr(plr.snowflake_id(worker_id))
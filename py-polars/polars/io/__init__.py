@@ -1,17 +1,20 @@
 """Functions for reading data."""
 
-from polars.io.avro import read_avro
+from polars.io.avro import read_avro, scan_avro
+from polars.io.bson import read_bson
 from polars.io.clipboard import read_clipboard
-from polars.io.csv import read_csv, read_csv_batched, scan_csv
+from polars.io.csv import DateInference, read_csv, read_csv_batched, scan_csv
 from polars.io.database import read_database, read_database_uri
 from polars.io.delta import read_delta, scan_delta
 from polars.io.iceberg import scan_iceberg
 from polars.io.ipc import read_ipc, read_ipc_schema, read_ipc_stream, scan_ipc
 from polars.io.json import read_json
+from polars.io.msgpack import read_msgpack
 from polars.io.ndjson import read_ndjson, scan_ndjson
 from polars.io.parquet import (
     read_parquet,
     read_parquet_metadata,
+    read_parquet_row_group_metadata,
     read_parquet_schema,
     scan_parquet,
 )
@@ -24,12 +27,18 @@ from polars.io.partition import (
     PartitionParted,
 )
 from polars.io.plugins import _defer as defer
+from polars.io.protobuf import read_protobuf
 from polars.io.pyarrow_dataset import scan_pyarrow_dataset
-from polars.io.scan_options import ScanCastOptions
+from polars.io.scan_options import ScanCastOptions, ScanFileErrors
+from polars.io.schema_inference import InferredSchema, infer_schema
+from polars.io.shared_memory import SharedMemoryFrame
 from polars.io.spreadsheet import read_excel, read_ods
 
 __all__ = [
     "defer",
+    "DateInference",
+    "infer_schema",
+    "InferredSchema",
     "PartitionByKey",
     "PartitionMaxSize",
     "PartitionParted",
@@ -37,6 +46,7 @@ __all__ = [
     "BasePartitionContext",
     "KeyedPartitionContext",
     "read_avro",
+    "read_bson",
     "read_clipboard",
     "read_csv",
     "read_csv_batched",
@@ -48,11 +58,15 @@ __all__ = [
     "read_ipc_schema",
     "read_ipc_stream",
     "read_json",
+    "read_msgpack",
     "read_ndjson",
     "read_ods",
     "read_parquet",
     "read_parquet_metadata",
+    "read_parquet_row_group_metadata",
     "read_parquet_schema",
+    "read_protobuf",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
@@ -61,4 +75,6 @@ __all__ = [
     "scan_parquet",
     "scan_pyarrow_dataset",
     "ScanCastOptions",
+    "ScanFileErrors",
+    "SharedMemoryFrame",
 ]
@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+import contextlib
+from typing import Any, Literal
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import register_schema_registry as _register_schema_registry
+    from polars.polars import validate_schema_registry as _validate_schema_registry
+
+_KEYS = {"url", "subject", "compatibility", "register", "basic_auth"}
+
+
+def _check_keys(schema_registry: dict[str, Any]) -> None:
+    unknown = set(schema_registry) - _KEYS
+    if unknown:
+        msg = (
+            f"unknown `schema_registry` keys: {sorted(unknown)!r},"
+            f" expected a subset of {sorted(_KEYS)!r}"
+        )
+        raise ValueError(msg)
+    for key in ("url", "subject"):
+        if key not in schema_registry:
+            msg = f"`schema_registry` is missing the required key {key!r}"
+            raise ValueError(msg)
+
+
+def _basic_auth(schema_registry: dict[str, Any]) -> tuple[str, str] | None:
+    basic_auth = schema_registry.get("basic_auth")
+    if basic_auth is None:
+        return None
+    username, password = basic_auth
+    return username, password
+
+
+def validate_schema_registry(
+    schema_registry: dict[str, Any],
+    schema: str,
+    format: Literal["avro", "json"],
+) -> None:
+    """
+    Validate a schema document against a schema registry before writing.
+
+    Parameters
+    ----------
+    schema_registry
+        The `schema_registry` argument of the writing function, see
+        :meth:`DataFrame.write_avro`.
+    schema
+        The Avro or JSON schema document of the written rows.
+    format : {'avro', 'json'}
+        The format of `schema`.
+    """
+    _check_keys(schema_registry)
+    compatibility = schema_registry.get("compatibility")
+    _validate_schema_registry(
+        schema,
+        format,
+        url=schema_registry["url"],
+        subject=schema_registry["subject"],
+        compatibility=compatibility.lower() if compatibility is not None else None,
+        basic_auth=_basic_auth(schema_registry),
+    )
+
+
+def register_schema_registry(
+    schema_registry: dict[str, Any],
+    schema: str,
+    format: Literal["avro", "json"],
+) -> int | None:
+    """
+    Register a schema document with a schema registry once the file is written.
+
+    Parameters
+    ----------
+    schema_registry
+        The `schema_registry` argument of the writing function, see
+        :meth:`DataFrame.write_avro`.
+    schema
+        The Avro or JSON schema document of the written rows.
+    format : {'avro', 'json'}
+        The format of `schema`.
+
+    Returns
+    -------
+    int or None
+        The id of the registered schema, or `None` if `register` is `False`.
+    """
+    if not schema_registry.get("register", True):
+        return None
+    return _register_schema_registry(
+        schema,
+        format,
+        url=schema_registry["url"],
+        subject=schema_registry["subject"],
+        basic_auth=_basic_auth(schema_registry),
+    )
@@ -4,15 +4,19 @@ import contextlib
 from pathlib import Path
 from typing import IO, TYPE_CHECKING
 
+from polars._utils.unstable import unstable
 from polars._utils.various import normalize_filepath
 from polars._utils.wrap import wrap_df
 from polars.io._utils import parse_columns_arg
+from polars.io.plugins import register_io_source
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
-    from polars.polars import PyDataFrame
+    from polars.polars import PyDataFrame, read_avro_schema
 
 if TYPE_CHECKING:
-    from polars import DataFrame
+    from collections.abc import Iterator
+
+    from polars import DataFrame, Expr, LazyFrame
 
 
 def read_avro(
@@ -40,6 +44,17 @@ def read_avro(
     Returns
     -------
     DataFrame
+
+    See Also
+    --------
+    scan_avro
+
+    Notes
+    -----
+    Avro logical types are read as the matching Polars data type; `uuid` is read as
+    a `String`. Polars has no calendar interval type, so `duration` is read as a
+    millisecond `Duration`; a duration with a non-zero month component raises an
+    error.
     """
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source)
@@ -47,3 +62,84 @@ def read_avro(
 
     pydf = PyDataFrame.read_avro(source, column_names, projection, n_rows)
     return wrap_df(pydf)
+
+
+@unstable()
+def scan_avro(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    n_rows: int | None = None,
+) -> LazyFrame:
+    """
+    Lazily read from an Apache Avro file.
+
+    This allows the query optimizer to push down projections and slices to the
+    reader; only the selected columns are decoded.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance). File-like objects are read into memory
+        when this function is called.
+    n_rows
+        Stop reading from Apache Avro file after reading `n_rows`.
+
+    Returns
+    -------
+    LazyFrame
+
+    See Also
+    --------
+    read_avro
+
+    Examples
+    --------
+    >>> import io
+    >>> buf = io.BytesIO()
+    >>> pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", "z"]}).write_avro(buf)
+    >>> pl.scan_avro(buf.getvalue()).filter(pl.col("a") > 1).select("b").collect()
+    shape: (2, 1)
+    ┌─────┐
+    │ b   │
+    │ --- │
+    │ str │
+    ╞═════╡
+    │ y   │
+    │ z   │
+    └─────┘
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+    elif not isinstance(source, bytes):
+        source = source.read()
+
+    def io_source(
+        with_columns: list[str] | None,
+        predicate: Expr | None,
+        n_rows: int | None,
+        batch_size: int | None,
+    ) -> Iterator[DataFrame]:
+        df = read_avro(
+            source,
+            columns=with_columns,
+            # The slice applies to the filtered rows.
+            n_rows=n_rows if predicate is None else None,
+        )
+        if predicate is not None:
+            df = df.filter(predicate)
+            if n_rows is not None:
+                df = df.head(n_rows)
+        yield df
+
+    lf = register_io_source(
+        io_source=io_source, schema=lambda: read_avro_schema(source)
+    )
+    if n_rows is not None:
+        lf = lf.head(n_rows)
+    return lf
@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import IO, TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.various import normalize_filepath
+from polars._utils.wrap import wrap_df
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyDataFrame
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+
+
+@unstable()
+def read_bson(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    columns: list[str] | None = None,
+    n_rows: int | None = None,
+) -> DataFrame:
+    """
+    Read a file of concatenated BSON documents, as written by `mongodump`.
+
+    Every top-level key becomes a column, with its data type inferred as the supertype
+    of its values. Embedded documents are read as `Struct` columns and arrays as
+    `List` columns. ObjectIds are read as their hexadecimal string representation and
+    UTC datetimes as millisecond `Datetime` values without a time zone. `Decimal128`
+    values and JavaScript code with scope are not supported.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance).
+    columns
+        Names of the keys to read. Keys that do not occur in any document are read as a
+        column of nulls.
+    n_rows
+        Stop reading after reading `n_rows` documents.
+
+    Returns
+    -------
+    DataFrame
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+
+    pydf = PyDataFrame.read_bson(source, columns, n_rows)
+    return wrap_df(pydf)
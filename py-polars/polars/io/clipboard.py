@@ -4,16 +4,20 @@ import contextlib
 from io import StringIO
 from typing import TYPE_CHECKING, Any
 
+from polars._utils.wrap import wrap_df
 from polars.io.csv.functions import read_csv
 
 with contextlib.suppress(ImportError):
+    import polars.polars as plr
     from polars.polars import read_clipboard_string as _read_clipboard_string
 
 if TYPE_CHECKING:
     from polars import DataFrame
 
 
-def read_clipboard(separator: str = "\t", **kwargs: Any) -> DataFrame:
+def read_clipboard(
+    separator: str | None = "\t", *, parse_numbers: bool = False, **kwargs: Any
+) -> DataFrame:
     """
     Read text from clipboard and pass to `read_csv`.
 
@@ -22,7 +26,13 @@ def read_clipboard(separator: str = "\t", **kwargs: Any) -> DataFrame:
     Parameters
     ----------
     separator
-        Single byte character to use as separator parsing csv from clipboard.
+        Single byte character to use as separator parsing csv from clipboard. If set
+        to `None`, the separator is guessed from the text on the clipboard, out of
+        tab, comma, semicolon and pipe.
+    parse_numbers
+        Convert string columns holding numbers as formatted by spreadsheet software,
+        such as `1,234.5`, `(1,000)` or `12%`, to numeric columns. Percentages are
+        divided by 100.
     kwargs
         Additional arguments passed to `read_csv`.
 
@@ -32,5 +42,10 @@ def read_clipboard(separator: str = "\t", **kwargs: Any) -> DataFrame:
     DataFrame.write_clipboard : Write a DataFrame to the clipboard.
     """
     csv_string: str = _read_clipboard_string()
+    if separator is None:
+        separator = plr.sniff_clipboard_separator(csv_string)
     io_string = StringIO(csv_string)
-    return read_csv(source=io_string, separator=separator, **kwargs)
+    df = read_csv(source=io_string, separator=separator, **kwargs)
+    if parse_numbers:
+        df = wrap_df(plr.parse_spreadsheet_numbers(df._df))
+    return df
@@ -1,8 +1,10 @@
 from polars.io.csv.batched_reader import BatchedCsvReader
+from polars.io.csv.date_inference import DateInference
 from polars.io.csv.functions import read_csv, read_csv_batched, scan_csv
 
 __all__ = [
     "BatchedCsvReader",
+    "DateInference",
     "read_csv",
     "read_csv_batched",
     "scan_csv",
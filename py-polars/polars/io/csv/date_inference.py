@@ -0,0 +1,94 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import issue_unstable_warning
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+
+class DateInference:
+    """How to infer the formats of the temporal columns of a CSV source."""
+
+    def __init__(
+        self,
+        formats: Sequence[str] | None = None,
+        *,
+        month_names: Sequence[str] | None = None,
+        two_digit_year_pivot: int | None = None,
+    ) -> None:
+        """
+        Infer the format of every column of strings from all of its sampled values.
+
+        Pass it as `try_parse_dates` to :func:`scan_csv`. The first format that
+        parses every non-null sampled value of a column is chosen, so that ambiguous
+        dates like `01/02/2024` are read in the same day/month order as the
+        unambiguous dates of the same column. Run with `POLARS_VERBOSE=1` to log the
+        format chosen per column.
+
+        .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        formats
+            The `chrono` format strings to try, in order of preference. Defaults to
+            the formats that `try_parse_dates=True` recognizes, followed by
+            month-first dates. The dtype is Date, Datetime or Time depending on the
+            fields of the format, and a Datetime in UTC if it has an offset.
+        month_names
+            The names of the twelve months, starting with January, as they are
+            written in the data, to parse with `%b` or `%B`. They are matched
+            case-insensitively for ASCII letters.
+        two_digit_year_pivot
+            Two-digit years (`%y`) below this pivot are in the 2000s, the other ones
+            in the 1900s. Defaults to 70.
+
+        Examples
+        --------
+        >>> source = b"day\\n01/02/2024\\n01/13/2024\\n"
+        >>> pl.scan_csv(source, try_parse_dates=pl.DateInference()).collect()
+        shape: (2, 1)
+        ┌────────────┐
+        │ day        │
+        │ ---        │
+        │ date       │
+        ╞════════════╡
+        │ 2024-01-02 │
+        │ 2024-01-13 │
+        └────────────┘
+        >>> pl.scan_csv(
+        ...     b"day\\n1 janvier 24\\n1 mars 60\\n",
+        ...     try_parse_dates=pl.DateInference(
+        ...         ["%d %B %y"],
+        ...         month_names=[
+        ...             "janvier", "février", "mars", "avril", "mai", "juin",
+        ...             "juillet", "août", "septembre", "octobre", "novembre",
+        ...             "décembre",
+        ...         ],
+        ...         two_digit_year_pivot=50,
+        ...     ),
+        ... ).collect()
+        shape: (2, 1)
+        ┌────────────┐
+        │ day        │
+        │ ---        │
+        │ date       │
+        ╞════════════╡
+        │ 2024-01-01 │
+        │ 1960-03-01 │
+        └────────────┘
+        """
+        issue_unstable_warning("DateInference is considered unstable.")
+
+        self.formats = None if formats is None else list(formats)
+        self.month_names = None if month_names is None else list(month_names)
+        self.two_digit_year_pivot = two_digit_year_pivot
+
+    def __repr__(self) -> str:
+        return (
+            f"DateInference({self.formats!r}, month_names={self.month_names!r}, "
+            f"two_digit_year_pivot={self.two_digit_year_pivot!r})"
+        )
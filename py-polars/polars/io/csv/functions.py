@@ -30,6 +30,7 @@ from polars.io.cloud.credential_provider._builder import (
 )
 from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
 from polars.io.csv.batched_reader import BatchedCsvReader
+from polars.io.csv.date_inference import DateInference
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyDataFrame, PyLazyFrame
@@ -1089,7 +1090,7 @@ def scan_csv(
     skip_rows_after_header: int = 0,
     row_index_name: str | None = None,
     row_index_offset: int = 0,
-    try_parse_dates: bool = False,
+    try_parse_dates: bool | DateInference = False,
     eol_char: str = "\n",
     new_columns: Sequence[str] | None = None,
     raise_if_empty: bool = True,
@@ -1101,6 +1102,7 @@ def scan_csv(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    archive_member_glob: str | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1199,6 +1201,10 @@ def scan_csv(
         Try to automatically parse dates. Most ISO8601-like formats
         can be inferred, as well as a handful of others. If this does not succeed,
         the column remains of data type `pl.String`.
+
+        Pass a :class:`DateInference` to choose one format per column that parses
+        all of its sampled values, from configurable candidate formats, month names
+        and two-digit-year pivot.
     eol_char
         Single byte end of line character (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
@@ -1246,6 +1252,16 @@ def scan_csv(
         (which defaults to 1 hour) if not given.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    archive_member_glob
+        Glob pattern that selects the files to read if a source is a zip or
+        (compressed) tar archive, e.g. `"*.csv"`. All files are read by default.
+        The files are decompressed in parallel and read as if they were
+        concatenated, so they must all have the same columns. If `has_header` is
+        set, every file is expected to start with a header.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     Returns
     -------
@@ -1388,6 +1404,7 @@ def scan_csv(
         credential_provider=credential_provider_builder,
         file_cache_ttl=file_cache_ttl,
         include_file_paths=include_file_paths,
+        archive_member_glob=archive_member_glob,
     )
 
 
@@ -1423,7 +1440,7 @@ def _scan_csv_impl(
     skip_rows_after_header: int = 0,
     row_index_name: str | None = None,
     row_index_offset: int = 0,
-    try_parse_dates: bool = False,
+    try_parse_dates: bool | DateInference = False,
     eol_char: str = "\n",
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = True,
@@ -1434,6 +1451,7 @@ def _scan_csv_impl(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    archive_member_glob: str | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if schema_overrides is not None:
@@ -1445,6 +1463,15 @@ def _scan_csv_impl(
             dtype_list.append((k, parse_into_dtype(v)))
     processed_null_values = _process_null_values(null_values)
 
+    date_inference = None
+    if isinstance(try_parse_dates, DateInference):
+        date_inference = (
+            try_parse_dates.formats,
+            try_parse_dates.month_names,
+            try_parse_dates.two_digit_year_pivot,
+        )
+        try_parse_dates = True
+
     if isinstance(source, list):
         sources = source
         source = None  # type: ignore[assignment]
@@ -1480,6 +1507,7 @@ def _scan_csv_impl(
         encoding=encoding,
         row_index=parse_row_index_args(row_index_name, row_index_offset),
         try_parse_dates=try_parse_dates,
+        date_inference=date_inference,
         eol_char=eol_char,
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
@@ -1491,5 +1519,6 @@ def _scan_csv_impl(
         retries=retries,
         file_cache_ttl=file_cache_ttl,
         include_file_paths=include_file_paths,
+        archive_member_glob=archive_member_glob,
     )
     return wrap_ldf(pylf)
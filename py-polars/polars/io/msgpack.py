@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import IO, TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.various import normalize_filepath
+from polars._utils.wrap import wrap_df
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyDataFrame
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+
+
+@unstable()
+def read_msgpack(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    columns: list[str] | None = None,
+    n_rows: int | None = None,
+) -> DataFrame:
+    r"""
+    Read a stream of MessagePack maps into a DataFrame.
+
+    Every key of the top-level maps becomes a column, with its data type inferred as
+    the supertype of its values. Nested maps are read as `Struct` columns and arrays
+    as `List` columns. Timestamps are read as nanosecond `Datetime` values without a
+    time zone; other extension types are not supported.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance).
+    columns
+        Names of the keys to read. Keys that do not occur in any map are read as a
+        column of nulls.
+    n_rows
+        Stop reading after reading `n_rows` maps.
+
+    Returns
+    -------
+    DataFrame
+
+    Examples
+    --------
+    >>> import io
+    >>> buf = io.BytesIO(b"\x82\xa1a\x01\xa1b\xa1x\x81\xa1a\x02")
+    >>> pl.read_msgpack(buf)
+    shape: (2, 2)
+    ┌─────┬──────┐
+    │ a   ┆ b    │
+    │ --- ┆ ---  │
+    │ i64 ┆ str  │
+    ╞═════╪══════╡
+    │ 1   ┆ x    │
+    │ 2   ┆ null │
+    └─────┴──────┘
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+
+    pydf = PyDataFrame.read_msgpack(source, columns, n_rows)
+    return wrap_df(pydf)
@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import contextlib
+import json
 from pathlib import Path
 from typing import IO, TYPE_CHECKING, Any, Literal
 
@@ -48,6 +49,9 @@ def read_ndjson(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    archive_member_glob: str | None = None,
+    json_schema: dict[str, Any] | str | Path | None = None,
+    json_schema_rejects: str | Path | None = None,
 ) -> DataFrame:
     r"""
     Read into a DataFrame from a newline delimited JSON file.
@@ -120,6 +124,36 @@ def read_ndjson(
         (which defaults to 1 hour) if not given.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    archive_member_glob
+        Glob pattern that selects the files to read if a source is a zip or
+        (compressed) tar archive, e.g. `"*.jsonl"`. All files are read by default.
+        The files are decompressed in parallel and read as if they were
+        concatenated.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    json_schema
+        A JSON Schema that every row must match, as a dict or the path of a JSON
+        file, e.g. one written by :meth:`LazyFrame.sink_ndjson`. Rows are validated
+        while they are read, before they are parsed into the `schema`. Rows that
+        don't match raise an error, unless `json_schema_rejects` is given.
+
+        The validation keywords for values of draft 2020-12 are supported, together
+        with `properties`, `required`, `additionalProperties`, `items`, `allOf`,
+        `anyOf`, `oneOf` and `not`. Annotations such as `format` are ignored, other
+        keywords such as `$ref` raise an error.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    json_schema_rejects
+        Append the lines of the rows that don't match `json_schema` to this local
+        file, and leave them out of the result.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
     See Also
     --------
@@ -167,6 +201,9 @@ def read_ndjson(
         row_index_offset=row_index_offset,
         ignore_errors=ignore_errors,
         include_file_paths=include_file_paths,
+        archive_member_glob=archive_member_glob,
+        json_schema=json_schema,
+        json_schema_rejects=json_schema_rejects,
         retries=retries,
         storage_options=storage_options,
         credential_provider=credential_provider_builder,  # type: ignore[arg-type]
@@ -204,6 +241,9 @@ def scan_ndjson(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    archive_member_glob: str | None = None,
+    json_schema: dict[str, Any] | str | Path | None = None,
+    json_schema_rejects: str | Path | None = None,
 ) -> LazyFrame:
     """
     Lazily read from a newline delimited JSON file or multiple files via glob patterns.
@@ -280,6 +320,36 @@ def scan_ndjson(
         (which defaults to 1 hour) if not given.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    archive_member_glob
+        Glob pattern that selects the files to read if a source is a zip or
+        (compressed) tar archive, e.g. `"*.jsonl"`. All files are read by default.
+        The files are decompressed in parallel and read as if they were
+        concatenated.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    json_schema
+        A JSON Schema that every row must match, as a dict or the path of a JSON
+        file, e.g. one written by :meth:`LazyFrame.sink_ndjson`. Rows are validated
+        while they are read, before they are parsed into the `schema`. Rows that
+        don't match raise an error, unless `json_schema_rejects` is given.
+
+        The validation keywords for values of draft 2020-12 are supported, together
+        with `properties`, `required`, `additionalProperties`, `items`, `allOf`,
+        `anyOf`, `oneOf` and `not`. Annotations such as `format` are ignored, other
+        keywords such as `$ref` raise an error.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    json_schema_rejects
+        Append the lines of the rows that don't match `json_schema` to this local
+        file, and leave them out of the result.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
     """
     sources: list[str] | list[Path] | list[IO[str]] | list[IO[bytes]] = []
     if isinstance(source, (str, Path)):
@@ -299,6 +369,13 @@ def scan_ndjson(
         msg = "'infer_schema_length' should be positive"
         raise ValueError(msg)
 
+    if isinstance(json_schema, dict):
+        json_schema = json.dumps(json_schema)
+    elif json_schema is not None:
+        json_schema = Path(normalize_filepath(json_schema)).read_text()
+    if json_schema_rejects is not None:
+        json_schema_rejects = normalize_filepath(json_schema_rejects)
+
     credential_provider_builder = _init_credential_provider_builder(
         credential_provider, source, storage_options, "scan_ndjson"
     )
@@ -324,6 +401,9 @@ def scan_ndjson(
         row_index=parse_row_index_args(row_index_name, row_index_offset),
         ignore_errors=ignore_errors,
         include_file_paths=include_file_paths,
+        archive_member_glob=archive_member_glob,
+        json_schema=json_schema,
+        json_schema_rejects=json_schema_rejects,
         retries=retries,
         cloud_options=storage_options,
         credential_provider=credential_provider_builder,
@@ -4,6 +4,7 @@ from polars.io.parquet.field_overwrites import (
 from polars.io.parquet.functions import (
     read_parquet,
     read_parquet_metadata,
+    read_parquet_row_group_metadata,
     read_parquet_schema,
     scan_parquet,
 )
@@ -12,6 +13,7 @@ __all__ = [
     "ParquetFieldOverwrites",
     "read_parquet",
     "read_parquet_metadata",
+    "read_parquet_row_group_metadata",
     "read_parquet_schema",
     "scan_parquet",
 ]
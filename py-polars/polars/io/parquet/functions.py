@@ -18,7 +18,7 @@ from polars._utils.various import (
     is_path_or_str_sequence,
     normalize_filepath,
 )
-from polars._utils.wrap import wrap_ldf
+from polars._utils.wrap import wrap_df, wrap_ldf
 from polars.convert import from_arrow
 from polars.dependencies import import_optional
 from polars.io._utils import (
@@ -32,6 +32,9 @@ from polars.io.scan_options._options import ScanOptions
 with contextlib.suppress(ImportError):
     from polars.polars import PyLazyFrame
     from polars.polars import read_parquet_metadata as _read_parquet_metadata
+    from polars.polars import (
+        read_parquet_row_group_metadata as _read_parquet_row_group_metadata,
+    )
 
 if TYPE_CHECKING:
     from typing import Literal
@@ -45,7 +48,7 @@ if TYPE_CHECKING:
         SchemaDict,
     )
     from polars.io.cloud import CredentialProviderFunction
-    from polars.io.scan_options import ScanCastOptions
+    from polars.io.scan_options import ScanCastOptions, ScanFileErrors
 
 
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
@@ -397,6 +400,60 @@ def read_parquet_metadata(source: str | Path | IO[bytes] | bytes) -> dict[str, s
     return _read_parquet_metadata(source)
 
 
+def read_parquet_row_group_metadata(
+    source: str | Path | IO[bytes] | bytes,
+) -> DataFrame:
+    """
+    Get the metadata of the row groups of a Parquet file without reading data.
+
+    .. warning::
+        This functionality is considered **experimental**. It may be removed or
+        changed at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance). For file-like objects, the stream position
+        may not be updated accordingly after reading.
+
+    Returns
+    -------
+    DataFrame
+        A DataFrame with a row per row group and the columns:
+
+        * `row_group`: The index of the row group.
+        * `num_rows`: The number of rows.
+        * `total_byte_size`, `compressed_size`: The uncompressed and compressed
+          size of the row group in bytes.
+        * `columns`: A list with a struct per column chunk, with its `path`,
+          `physical_type`, `compression`, `encodings`, `num_values`,
+          `compressed_size` and `uncompressed_size`, and whether it has a
+          dictionary page (`has_dictionary_page`), a bloom filter
+          (`has_bloom_filter`) and statistics (`has_statistics`).
+        * `min`, `max`, `null_count`: Structs with a field per column with the
+          statistics of the row group, with the data types of the columns. These
+          are null if the statistics are missing or the column is nested.
+
+    Examples
+    --------
+    Find the column chunks that are not compressed.
+
+    >>> (
+    ...     pl.read_parquet_row_group_metadata("path/to/file.parquet")
+    ...     .select("row_group", "columns")
+    ...     .explode("columns")
+    ...     .unnest("columns")
+    ...     .filter(pl.col("compression") == "UNCOMPRESSED")
+    ... )  # doctest: +SKIP
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source, check_not_directory=False)
+
+    return wrap_df(_read_parquet_row_group_metadata(source))
+
+
 @deprecate_renamed_parameter("row_count_name", "row_index_name", version="0.20.4")
 @deprecate_renamed_parameter("row_count_offset", "row_index_offset", version="0.20.4")
 def scan_parquet(
@@ -410,6 +467,7 @@ def scan_parquet(
     hive_partitioning: bool | None = None,
     glob: bool = True,
     schema: SchemaDict | None = None,
+    schema_overrides: SchemaDict | None = None,
     hive_schema: SchemaDict | None = None,
     try_parse_hive_dates: bool = True,
     rechunk: bool = False,
@@ -423,6 +481,8 @@ def scan_parquet(
     allow_missing_columns: bool | None = None,
     extra_columns: Literal["ignore", "raise"] = "raise",
     cast_options: ScanCastOptions | None = None,
+    file_statistics: DataFrame | None = None,
+    file_errors: ScanFileErrors | None = None,
     _column_mapping: ColumnMapping | None = None,
     _deletion_files: DeletionFiles | None = None,
 ) -> LazyFrame:
@@ -476,7 +536,12 @@ def scan_parquet(
         can be skipped from reading.
     hive_partitioning
         Infer statistics and schema from hive partitioned URL and use them
-        to prune reads.
+        to prune reads. If the source is a glob pattern with partition directories
+        such as `year=*/month=*`, a filter directly on the scan that requires a
+        partition value, e.g. `pl.col("year") == 2024`, is pushed into the glob so
+        that the files of other partitions are not listed. This requires `schema`
+        and a `hive_schema` with the partition columns of the glob, and is not done
+        with a row index or `n_rows`.
     glob
         Expand path given via globbing rules.
     schema
@@ -484,6 +549,15 @@ def scan_parquet(
         datatypes in the file(s). If there are extra columns that are not in the
         file(s), consider also passing `missing_columns='insert'`.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    schema_overrides
+        Read some of the columns as other data types, e.g. `{"ts": pl.Datetime("ms")}`
+        for `Int96` timestamps or `{"name": pl.Categorical}` for strings. The columns
+        are cast while they are decoded, so that the data type of the file is not
+        materialized for the full file. Values that can't be cast raise an error.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -557,6 +631,32 @@ def scan_parquet(
         Configuration for column type-casting during scans. Useful for datasets
         containing files that have differing schemas.
 
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    file_statistics
+        Pre-computed statistics of the files, e.g. from a table catalog, with a row
+        per file in the order of the (expanded) sources. These are used to skip
+        files and to count rows without reading the file metadata. The
+        following columns are accepted, all of them optional:
+
+        * `path`: The path of the file. This is not used.
+        * `num_rows`: The number of rows of the file.
+        * `min`, `max`: Structs with the minimum and maximum value of some of
+          the columns of the file.
+        * `null_count`: A struct with the number of nulls of some of the columns
+          of the file.
+
+        Null values are treated as unknown.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+    file_errors
+        Retry the files that fail to open, and optionally skip them instead of
+        failing the query, see :class:`ScanFileErrors`. The skipped files are
+        reported by the passed options after the query is executed.
+
         .. warning::
             This functionality is considered **unstable**. It may be changed
             at any point without it being considered a breaking change.
@@ -587,6 +687,12 @@ def scan_parquet(
         msg = "the `schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if schema_overrides is not None:
+        msg = (
+            "the `schema_overrides` parameter of `scan_parquet` is considered unstable."
+        )
+        issue_unstable_warning(msg)
+
     if hive_schema is not None:
         msg = "the `hive_schema` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
@@ -595,6 +701,12 @@ def scan_parquet(
         msg = "The `cast_options` parameter of `scan_parquet` is considered unstable."
         issue_unstable_warning(msg)
 
+    if file_statistics is not None:
+        msg = (
+            "The `file_statistics` parameter of `scan_parquet` is considered unstable."
+        )
+        issue_unstable_warning(msg)
+
     if allow_missing_columns is not None:
         issue_deprecation_warning(
             "the parameter `allow_missing_columns` for `scan_parquet` is deprecated. "
@@ -627,6 +739,7 @@ def scan_parquet(
     pylf = PyLazyFrame.new_from_parquet(
         sources=sources,
         schema=schema,
+        schema_overrides=schema_overrides,
         parallel=parallel,
         low_memory=low_memory,
         use_statistics=use_statistics,
@@ -654,6 +767,10 @@ def scan_parquet(
             retries=retries,
             deletion_files=_deletion_files,
             column_mapping=_column_mapping,
+            table_statistics=(
+                file_statistics._df if file_statistics is not None else None
+            ),
+            file_errors=file_errors._inner if file_errors is not None else None,
         ),
     )
 
@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import inspect
 import os
 import sys
 from collections.abc import Iterator
@@ -22,7 +23,9 @@ def register_io_source(
         [list[str] | None, Expr | None, int | None, int | None], Iterator[DataFrame]
     ],
     *,
-    schema: Callable[[], SchemaDict] | SchemaDict,
+    schema: Callable[[], SchemaDict]
+    | Callable[[list[str] | None], SchemaDict]
+    | SchemaDict,
     validate_schema: bool = False,
 ) -> LazyFrame:
     """
@@ -58,6 +61,13 @@ def register_io_source(
     schema
         Schema or function that when called produces the schema that the reader
         will produce before projection pushdown.
+
+        If the function accepts an argument, it is called with the names of the
+        selected columns if the query directly selects a fixed set of columns
+        from the source (e.g. `lf.select("a", "b")`), and may then return the
+        schema of only those columns. This avoids computing the full schema if
+        that is expensive. Otherwise, it is called with `None` and must return
+        the full schema.
     validate_schema
         Whether the engine should validate if the batches generated match
         the given schema. It's an implementation error if this isn't
@@ -91,11 +101,37 @@ def register_io_source(
             with_columns, parsed_predicate, n_rows, batch_size
         ), parsed_predicate_success
 
+    if callable(schema):
+        schema = _projected_schema_fn(schema)
+
     return pl.LazyFrame._scan_python_function(
         schema=schema, scan_fn=wrap, pyarrow=False, validate_schema=validate_schema
     )
 
 
+def _projected_schema_fn(
+    schema_fn: Callable[[], SchemaDict] | Callable[[list[str] | None], SchemaDict],
+) -> Callable[[list[str] | None], SchemaDict]:
+    """Ensure the schema function accepts the projected columns as argument."""
+    # Only functions with a required positional parameter are given the columns, so
+    # that e.g. `def schema(refresh=False)` is still called without arguments.
+    try:
+        n_required = sum(
+            p.kind in (p.POSITIONAL_ONLY, p.POSITIONAL_OR_KEYWORD)
+            and p.default is p.empty
+            for p in inspect.signature(schema_fn).parameters.values()
+        )
+    except (TypeError, ValueError):
+        n_required = 0
+    if n_required > 0:
+        return schema_fn  # type: ignore[return-value]
+
+    def full_schema_fn(with_columns: list[str] | None) -> SchemaDict:
+        return schema_fn()  # type: ignore[call-arg]
+
+    return full_schema_fn
+
+
 @unstable()
 def _defer(
     function: Callable[[], DataFrame],
@@ -0,0 +1,71 @@
+from __future__ import annotations
+
+import contextlib
+from pathlib import Path
+from typing import IO, TYPE_CHECKING
+
+from polars._utils.unstable import unstable
+from polars._utils.various import normalize_filepath
+from polars._utils.wrap import wrap_df
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyDataFrame
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+
+
+@unstable()
+def read_protobuf(
+    source: str | Path | IO[bytes] | bytes,
+    descriptor_set: str | Path | bytes,
+    message_type: str,
+    *,
+    columns: list[str] | None = None,
+    n_rows: int | None = None,
+) -> DataFrame:
+    """
+    Read length-delimited protobuf messages into a DataFrame.
+
+    The source holds a sequence of messages of a single type, each prefixed with its
+    length as a varint, as written by `writeDelimitedTo` in the protobuf libraries.
+
+    Every field of the message becomes a column. Embedded messages are read as
+    `Struct` columns, repeated fields as `List` columns and map fields as a `List` of
+    `Struct` values with a `key` and a `value` field. Enums are read as the names of
+    their values. A missing field is null if it tracks presence, and the default
+    value of its type otherwise.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object (by "file-like object" we refer to objects
+        that have a `read()` method, such as a file handler like the builtin `open`
+        function, or a `BytesIO` instance).
+    descriptor_set
+        Path to, or the contents of, a serialized `FileDescriptorSet` that defines the
+        message type, as written by `protoc --include_imports --descriptor_set_out`.
+    message_type
+        Fully qualified name of the message type, e.g. `"my.package.Message"`.
+    columns
+        Names of the fields to read.
+    n_rows
+        Stop reading after reading `n_rows` messages.
+
+    Returns
+    -------
+    DataFrame
+    """
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+    if not isinstance(descriptor_set, bytes):
+        descriptor_set = Path(normalize_filepath(descriptor_set)).read_bytes()
+
+    pydf = PyDataFrame.read_protobuf(
+        source, descriptor_set, message_type, columns, n_rows
+    )
+    return wrap_df(pydf)
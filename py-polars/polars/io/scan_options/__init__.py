@@ -1,5 +1,7 @@
 from polars.io.scan_options.cast_options import ScanCastOptions
+from polars.io.scan_options.file_errors import ScanFileErrors
 
 __all__ = [
     "ScanCastOptions",
+    "ScanFileErrors",
 ]
@@ -6,6 +6,7 @@ if TYPE_CHECKING:
     from polars._typing import ColumnMapping, DeletionFiles, SchemaDict
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
     from polars.io.scan_options.cast_options import ScanCastOptions
+    from polars.polars import PyDataFrame, PyFileErrorPolicy
 
 from dataclasses import dataclass
 
@@ -46,3 +47,5 @@ class ScanOptions:
 
     column_mapping: ColumnMapping | None = None
     deletion_files: DeletionFiles | None = None
+    table_statistics: PyDataFrame | None = None
+    file_errors: PyFileErrorPolicy | None = None
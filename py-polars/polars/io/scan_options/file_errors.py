@@ -0,0 +1,77 @@
+from __future__ import annotations
+
+import contextlib
+from typing import TYPE_CHECKING
+
+from polars._utils.unstable import issue_unstable_warning
+from polars._utils.wrap import wrap_df
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyFileErrorPolicy
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+
+
+class ScanFileErrors:
+    """Handling of files that fail to open during a scan."""
+
+    def __init__(self, *, retries: int = 0, skip: bool = False) -> None:
+        """
+        Handling of files that fail to open during a scan.
+
+        .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        This applies to opening a file and reading its metadata, which is where
+        transient errors (e.g. a dropped connection) of scans over many files
+        usually happen. Errors while the data of a file is read still fail the
+        query.
+
+        Parameters
+        ----------
+        retries
+            The number of times a file that fails to open is retried, in addition
+            to the retries of the individual cloud requests. The retries wait with
+            an exponential backoff, starting at 100 milliseconds.
+        skip
+            Skip the files that still fail after the retries instead of failing
+            the query. The skipped files are reported by :meth:`skipped`.
+
+        Examples
+        --------
+        >>> file_errors = pl.ScanFileErrors(retries=2, skip=True)
+        >>> df = pl.scan_parquet(
+        ...     "s3://bucket/*.parquet", file_errors=file_errors
+        ... ).collect()  # doctest: +SKIP
+        >>> file_errors.skipped()  # doctest: +SKIP
+        shape: (1, 2)
+        ┌──────────────────────────┬─────────────────────────────────┐
+        │ path                     ┆ error                           │
+        │ ---                      ┆ ---                             │
+        │ str                      ┆ str                             │
+        ╞══════════════════════════╪═════════════════════════════════╡
+        │ s3://bucket/0042.parquet ┆ Generic S3 error: error sendin… │
+        └──────────────────────────┴─────────────────────────────────┘
+        """
+        issue_unstable_warning("ScanFileErrors is considered unstable.")
+
+        if retries < 0:
+            msg = f"`retries` must be non-negative, got {retries}"
+            raise ValueError(msg)
+
+        self._inner = PyFileErrorPolicy(retries, skip)
+
+    def skipped(self) -> DataFrame:
+        """
+        Get the files that were skipped, with a `path` and an `error` column.
+
+        The files of all executions of the scans that use these options are
+        included, until :meth:`clear` is called.
+        """
+        return wrap_df(self._inner.skipped())
+
+    def clear(self) -> None:
+        """Forget the files that were skipped so far."""
+        self._inner.clear_skipped()
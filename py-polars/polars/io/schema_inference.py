@@ -0,0 +1,209 @@
+from __future__ import annotations
+
+from pathlib import Path
+from typing import IO, TYPE_CHECKING, Any, Literal
+
+from polars import datatypes as dt
+from polars._utils.unstable import unstable
+from polars._utils.various import normalize_filepath
+from polars.exceptions import ComputeError
+from polars.io.csv.functions import read_csv
+from polars.io.ndjson import read_ndjson
+from polars.io.parquet.functions import read_parquet_schema
+from polars.schema import Schema
+
+if TYPE_CHECKING:
+    from polars import Series
+    from polars._typing import PolarsDataType
+
+InferSchemaFormat = Literal["csv", "ndjson", "parquet"]
+
+_FORMAT_BY_SUFFIX: dict[str, InferSchemaFormat] = {
+    ".csv": "csv",
+    ".tsv": "csv",
+    ".ndjson": "ndjson",
+    ".jsonl": "ndjson",
+    ".parquet": "parquet",
+}
+
+# From the most to the least specific, strings can always be parsed.
+_STRING_CANDIDATES: list[PolarsDataType] = [
+    dt.Boolean,
+    dt.Int64,
+    dt.Float64,
+    dt.Date,
+    dt.Datetime,
+]
+
+
+class InferredSchema:
+    """
+    A recommended schema inferred from a sample of a source.
+
+    Returned by :func:`infer_schema`.
+
+    Attributes
+    ----------
+    schema
+        The recommended schema. Pass it as `schema` or `schema_overrides` to the
+        corresponding `read_*` or `scan_*` function to pin down the dtypes.
+    notes
+        A description per column of how its dtype was decided.
+    n_sampled_rows
+        The number of rows that were sampled, `None` if the schema was read from
+        metadata.
+    """
+
+    def __init__(
+        self, schema: Schema, notes: dict[str, str], n_sampled_rows: int | None
+    ) -> None:
+        self.schema = schema
+        self.notes = notes
+        self.n_sampled_rows = n_sampled_rows
+
+    def __repr__(self) -> str:
+        lines = [f"InferredSchema(n_sampled_rows={self.n_sampled_rows})"]
+        lines.extend(
+            f"  {name}: {dtype} ({self.notes[name]})"
+            for name, dtype in self.schema.items()
+        )
+        return "\n".join(lines)
+
+
+def _count_parsed(s: Series, dtype: PolarsDataType) -> int:
+    """Count the values of a string column that can be parsed as `dtype`."""
+    if dtype == dt.Boolean:
+        parsed = (
+            s.str.strip_chars()
+            .str.to_lowercase()
+            .replace_strict(
+                {"true": True, "false": False}, default=None, return_dtype=dt.Boolean
+            )
+        )
+    elif dtype in (dt.Date, dt.Datetime):
+        try:
+            parsed = s.str.strptime(dtype, strict=False)  # type: ignore[arg-type]
+        except ComputeError:
+            # No format could be inferred from the first value.
+            return 0
+    else:
+        parsed = s.str.strip_chars().cast(dtype, strict=False)
+    return parsed.len() - parsed.null_count()
+
+
+def _infer_string_column(s: Series, threshold: float) -> tuple[PolarsDataType, str]:
+    n_values = s.len() - s.null_count()
+    if n_values == 0:
+        return dt.String, "all sampled values are null"
+
+    near_miss = None
+    for dtype in _STRING_CANDIDATES:
+        n_parsed = _count_parsed(s, dtype)
+        if n_parsed == n_values:
+            note = f"parsed as {dtype} in all {n_values} non-null sampled values"
+            if near_miss is not None:
+                note = f"{note}; {near_miss}"
+            return dtype, note
+        fraction = n_parsed / n_values
+        if near_miss is None and fraction >= threshold:
+            near_miss = (
+                f"parsed as {dtype} in {fraction:.1%} of non-null sampled values"
+            )
+
+    return dt.String, near_miss or "no narrower dtype fits all sampled values"
+
+
+@unstable()
+def infer_schema(
+    source: str | Path | IO[bytes] | bytes,
+    *,
+    format: InferSchemaFormat | None = None,
+    sample_size: int = 10_000,
+    threshold: float = 0.99,
+    **kwargs: Any,
+) -> InferredSchema:
+    """
+    Recommend a schema for a CSV, NDJSON or Parquet source by sampling it.
+
+    The first `sample_size` rows are read and every column is assigned the most
+    specific dtype that all of its non-null sampled values can be parsed as. The
+    notes explain the decision per column, including near misses such as
+    "parsed as Int64 in 99.7% of non-null sampled values", to help decide on the
+    explicit schema to use for production scans.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    source
+        Path to a file or a file-like object.
+    format : {'csv', 'ndjson', 'parquet'}
+        The format of the source. By default, this is determined from the file
+        extension of the path.
+    sample_size
+        The number of rows to sample. Parquet sources are not sampled, their schema
+        is read from the file metadata.
+    threshold
+        Mention the narrowest dtype that at least this fraction of the non-null
+        sampled values of a column can be parsed as in its note, if it is not the
+        recommended dtype.
+    **kwargs
+        Additional arguments passed to the `read_*` function of the format, for
+        instance `separator` for CSV sources.
+
+    Examples
+    --------
+    >>> source = b"a,b,c\\n1,2020-01-01,x\\n2,2020-01-02,1.5\\n"
+    >>> pl.infer_schema(source, format="csv")
+    InferredSchema(n_sampled_rows=2)
+      a: Int64 (parsed as Int64 in all 2 non-null sampled values)
+      b: Date (parsed as Date in all 2 non-null sampled values)
+      c: String (no narrower dtype fits all sampled values)
+    >>> pl.infer_schema(source, format="csv", threshold=0.5).notes["c"]
+    'parsed as Float64 in 50.0% of non-null sampled values'
+    """
+    if sample_size < 1:
+        msg = f"`sample_size` must be at least 1, got {sample_size}"
+        raise ValueError(msg)
+    if not 0.0 <= threshold <= 1.0:
+        msg = f"`threshold` must be between 0.0 and 1.0, got {threshold}"
+        raise ValueError(msg)
+
+    if isinstance(source, (str, Path)):
+        source = normalize_filepath(source)
+    if format is None:
+        suffix = Path(source).suffix.lower() if isinstance(source, str) else ""
+        if suffix not in _FORMAT_BY_SUFFIX:
+            msg = "could not determine the format of the source, please pass `format`"
+            raise ValueError(msg)
+        format = _FORMAT_BY_SUFFIX[suffix]
+
+    schema = Schema()
+    notes = {}
+    if format == "parquet":
+        for name, dtype in read_parquet_schema(source).items():
+            schema[name] = dtype
+            notes[name] = "read from the file metadata"
+        return InferredSchema(schema, notes, n_sampled_rows=None)
+
+    if format == "csv":
+        df = read_csv(source, n_rows=sample_size, infer_schema=False, **kwargs)
+    elif format == "ndjson":
+        df = read_ndjson(
+            source, n_rows=sample_size, infer_schema_length=None, **kwargs
+        )
+    else:
+        msg = f"`format` must be one of {{'csv', 'ndjson', 'parquet'}}, got {format!r}"
+        raise ValueError(msg)
+
+    for s in df.iter_columns():
+        if s.dtype == dt.String:
+            dtype, note = _infer_string_column(s, threshold)
+        else:
+            dtype = s.dtype
+            note = f"JSON values are {dtype} in all {df.height} sampled rows"
+        schema[s.name] = dtype
+        notes[s.name] = note
+    return InferredSchema(schema, notes, n_sampled_rows=df.height)
@@ -0,0 +1,114 @@
+from __future__ import annotations
+
+import os
+import tempfile
+import uuid
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from polars.io.ipc import read_ipc
+
+if TYPE_CHECKING:
+    from collections.abc import Sequence
+
+    from polars import DataFrame
+
+
+def _segment_dir() -> Path:
+    shm = Path("/dev/shm")
+    return shm if shm.is_dir() else Path(tempfile.gettempdir())
+
+
+def _check_segment_name(name: str) -> None:
+    if (
+        not name
+        or name in (".", "..")
+        or os.sep in name
+        or (os.altsep is not None and os.altsep in name)
+    ):
+        msg = f"invalid shared-memory segment name: {name!r}"
+        raise ValueError(msg)
+
+
+class SharedMemoryFrame:
+    """
+    A handle to a DataFrame in a named shared-memory segment.
+
+    Returned by :meth:`DataFrame.write_shared_memory` and
+    :meth:`LazyFrame.sink_shared_memory`.
+
+    The segment holds the data as an uncompressed Arrow IPC file. Every process
+    that reads the handle memory maps the same segment instead of copying the data,
+    and the handle only pickles its name, so it can be sent to
+    :mod:`multiprocessing` workers cheaply.
+
+    On Linux, the segment is a file in `/dev/shm`. On other platforms, it is a file
+    in the temporary directory. The segment outlives the handle and the process that
+    created it; call :meth:`unlink` once the data is no longer needed.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name of an existing segment.
+
+    Examples
+    --------
+    >>> from concurrent.futures import ProcessPoolExecutor
+    >>> def total(handle: pl.SharedMemoryFrame) -> int:
+    ...     return handle.read()["a"].sum()
+    >>> handle = pl.DataFrame({"a": [1, 2, 3]}).write_shared_memory()
+    >>> with ProcessPoolExecutor() as pool:  # doctest: +SKIP
+    ...     list(pool.map(total, [handle, handle]))
+    [6, 6]
+    >>> handle.unlink()
+    """
+
+    def __init__(self, name: str) -> None:
+        _check_segment_name(name)
+        self.name = name
+
+    def __repr__(self) -> str:
+        return f"SharedMemoryFrame({self.name!r})"
+
+    @property
+    def path(self) -> Path:
+        """The path of the segment."""
+        return _segment_dir() / self.name
+
+    def read(self, columns: Sequence[int] | Sequence[str] | None = None) -> DataFrame:
+        """
+        Map the DataFrame in the segment into this process.
+
+        The DataFrame references the segment without copying it.
+
+        Parameters
+        ----------
+        columns
+            Columns to select. Accepts a list of column indices (starting at zero)
+            or a list of column names.
+        """
+        return read_ipc(self.path, columns=columns, memory_map=True)
+
+    def unlink(self) -> None:
+        """
+        Remove the segment.
+
+        DataFrames that were already read from the segment stay valid on platforms
+        that support removing mapped files.
+        """
+        self.path.unlink()
+
+
+def _new_shared_memory_frame(name: str | None) -> SharedMemoryFrame:
+    handle = SharedMemoryFrame(name or f"polars-{uuid.uuid4().hex}")
+    # Claim the name atomically, so concurrent writers cannot share a segment.
+    try:
+        handle.path.touch(exist_ok=False)
+    except FileExistsError:
+        msg = f"shared-memory segment {handle.name!r} already exists"
+        raise FileExistsError(msg) from None
+    return handle
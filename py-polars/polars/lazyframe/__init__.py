@@ -1,9 +1,19 @@
-from polars.lazyframe.engine_config import GPUEngine
+from polars.lazyframe.engine_config import (
+    GPUEngine,
+    GPUFallbackReport,
+    GPUUnsupportedOperation,
+    StreamingEngine,
+)
+from polars.lazyframe.feature_set import FeatureSet
 from polars.lazyframe.frame import LazyFrame
 from polars.lazyframe.opt_flags import QueryOptFlags
 
 __all__ = [
+    "FeatureSet",
     "GPUEngine",
+    "GPUFallbackReport",
+    "GPUUnsupportedOperation",
     "LazyFrame",
     "QueryOptFlags",
+    "StreamingEngine",
 ]
@@ -1,9 +1,9 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Literal, NamedTuple
 
 if TYPE_CHECKING:
-    from collections.abc import Mapping
+    from collections.abc import Mapping, Sequence
 
     from rmm.mr import DeviceMemoryResource  # type: ignore[import-not-found]
 
@@ -59,3 +59,130 @@ class GPUEngine:
         # Avoids need for changes in cudf-polars
         kwargs["raise_on_fail"] = raise_on_fail
         self.config = kwargs
+
+
+class GPUUnsupportedOperation(NamedTuple):
+    """An operation that makes the GPU engine fall back to the CPU engine."""
+
+    path: tuple[int, ...]
+    """
+    The input indices that lead from the root of the optimized query plan to the
+    plan node of the operation.
+
+    Empty for operations that the GPU engine did not attribute to a plan node.
+    """
+    kind: Literal["node", "expression", "engine"]
+    """
+    Whether the operation is a plan node or an expression that Polars cannot pass
+    to the GPU engine, or an operation that the GPU engine rejected.
+    """
+    node: str | None
+    """The name of the plan node of the operation."""
+    message: str
+    """A description of the operation."""
+
+
+class GPUFallbackReport:
+    """
+    The operations in a query that make the GPU engine fall back to the CPU engine.
+
+    Returned by :meth:`LazyFrame.gpu_fallback_report`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    operations: list[GPUUnsupportedOperation]
+    """The unsupported operations, in the order of the query plan."""
+
+    def __init__(self, operations: Sequence[GPUUnsupportedOperation]) -> None:
+        self.operations = list(operations)
+
+    def __repr__(self) -> str:
+        if not self.operations:
+            return "GPUFallbackReport(supported)"
+        lines = ["GPUFallbackReport("]
+        for op in self.operations:
+            path = "/".join(map(str, op.path)) or "-"
+            node = f" in {op.node}" if op.node is not None else ""
+            lines.append(f"  [{path}] {op.kind}{node}: {op.message}")
+        lines.append(")")
+        return "\n".join(lines)
+
+    @property
+    def supported(self) -> bool:
+        """Whether the GPU engine can run the query without falling back."""
+        return not self.operations
+
+
+class StreamingEngine:
+    """
+    Configuration options for the streaming engine.
+
+    Use this to tune the concurrency of individual operators, instead of the size of
+    the global thread pool which all operators share. IO-heavy and CPU-heavy queries
+    often need different trade-offs. Options that are not set use the defaults of the
+    engine, which scale with the number of threads.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    scan_readahead
+        The number of files a scan opens ahead of the file it is reading. Higher
+        values hide the latency of scanning many files, e.g. from cloud storage.
+    join_probe_parallelism
+        The number of tasks of an equi-join that probe the hash tables at the same
+        time.
+    sink_writer_threads
+        The number of tasks that encode the data of Parquet and IPC sinks.
+
+    Examples
+    --------
+    >>> lf = pl.LazyFrame({"a": [1, 2, 3]})
+    >>> lf.select(pl.col("a").sum()).collect(
+    ...     engine=pl.StreamingEngine(join_probe_parallelism=2)
+    ... )  # doctest: +SKIP
+    """
+
+    scan_readahead: int | None
+    """The number of files a scan opens ahead of the file it is reading."""
+    join_probe_parallelism: int | None
+    """The number of tasks of a join that probe at the same time."""
+    sink_writer_threads: int | None
+    """The number of tasks that encode the data of a sink."""
+
+    def __init__(
+        self,
+        *,
+        scan_readahead: int | None = None,
+        join_probe_parallelism: int | None = None,
+        sink_writer_threads: int | None = None,
+    ) -> None:
+        for name, value in (
+            ("scan_readahead", scan_readahead),
+            ("join_probe_parallelism", join_probe_parallelism),
+            ("sink_writer_threads", sink_writer_threads),
+        ):
+            if value is not None and value < 1:
+                msg = f"`{name}` must be >= 1, got {value}"
+                raise ValueError(msg)
+        self.scan_readahead = scan_readahead
+        self.join_probe_parallelism = join_probe_parallelism
+        self.sink_writer_threads = sink_writer_threads
+
+    def __repr__(self) -> str:
+        options = ", ".join(f"{k}={v}" for k, v in self._overrides().items())
+        return f"StreamingEngine({options})"
+
+    def _overrides(self) -> dict[str, int]:
+        """The options that are set, as arguments of the engine."""
+        options = {
+            "scan_readahead": self.scan_readahead,
+            "join_probe_parallelism": self.join_probe_parallelism,
+            "sink_writer_threads": self.sink_writer_threads,
+        }
+        return {k: v for k, v in options.items() if v is not None}
@@ -0,0 +1,192 @@
+from __future__ import annotations
+
+import contextlib
+from io import BytesIO, StringIO
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from polars._utils.parse import parse_into_expression
+from polars._utils.serde import serialize_polars_object
+from polars._utils.unstable import issue_unstable_warning
+from polars._utils.various import normalize_filepath
+from polars._utils.wrap import wrap_expr
+from polars.datatypes import parse_into_dtype
+
+with contextlib.suppress(ImportError):  # Module not available when building docs
+    from polars.polars import PyFeatureSet
+
+if TYPE_CHECKING:
+    from collections.abc import Mapping
+    from io import IOBase
+
+    from polars import Expr
+    from polars._typing import IntoExpr, PolarsDataType
+
+
+class FeatureSet:
+    """
+    A versioned bundle of named expressions to add to frames as columns.
+
+    A feature set can be serialized, so that features can be shipped as data and
+    applied with :meth:`DataFrame.with_feature_set` or
+    :meth:`LazyFrame.with_feature_set`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name of the feature set.
+    features
+        The expressions of the features, by their names. Features can refer to
+        other features of the set by name, in which case they are evaluated after
+        the features they refer to. A feature that refers to its own name reads the
+        column of that name of the frame. Only references by column name are
+        followed, not selectors.
+    version
+        The version of the feature set.
+    dtypes
+        The dtypes that features must evaluate to, by the names of the features.
+
+    Examples
+    --------
+    >>> import io
+    >>> fs = pl.FeatureSet(
+    ...     "orders",
+    ...     {
+    ...         "total": pl.col("price") * pl.col("quantity"),
+    ...         "is_large": pl.col("total") > 100,
+    ...     },
+    ...     version=2,
+    ...     dtypes={"is_large": pl.Boolean},
+    ... )
+    >>> fs = pl.FeatureSet.deserialize(io.BytesIO(fs.serialize()))
+    >>> df = pl.DataFrame({"price": [10.0, 60.0], "quantity": [3, 2]})
+    >>> df.with_feature_set(fs)
+    shape: (2, 4)
+    ┌───────┬──────────┬───────┬──────────┐
+    │ price ┆ quantity ┆ total ┆ is_large │
+    │ ---   ┆ ---      ┆ ---   ┆ ---      │
+    │ f64   ┆ i64      ┆ f64   ┆ bool     │
+    ╞═══════╪══════════╪═══════╪══════════╡
+    │ 10.0  ┆ 3        ┆ 30.0  ┆ false    │
+    │ 60.0  ┆ 2        ┆ 120.0 ┆ true     │
+    └───────┴──────────┴───────┴──────────┘
+    """
+
+    _fs: PyFeatureSet
+
+    def __init__(
+        self,
+        name: str,
+        features: Mapping[str, IntoExpr],
+        *,
+        version: int = 1,
+        dtypes: Mapping[str, PolarsDataType] | None = None,
+    ) -> None:
+        issue_unstable_warning("`FeatureSet` is considered unstable.")
+
+        dtypes = dict(dtypes or {})
+        if unknown := set(dtypes) - set(features):
+            msg = f"`dtypes` contains names that are not features: {sorted(unknown)!r}"
+            raise ValueError(msg)
+        self._fs = PyFeatureSet(
+            name,
+            version,
+            [
+                (
+                    feature,
+                    parse_into_expression(expr),
+                    parse_into_dtype(dtypes[feature]) if feature in dtypes else None,
+                )
+                for feature, expr in features.items()
+            ],
+        )
+
+    @classmethod
+    def _from_pyfs(cls, pyfs: PyFeatureSet) -> FeatureSet:
+        fs = cls.__new__(cls)
+        fs._fs = pyfs
+        return fs
+
+    def __repr__(self) -> str:
+        return (
+            f"FeatureSet({self.name!r}, version={self.version}, "
+            f"features={list(self.features)!r})"
+        )
+
+    @property
+    def name(self) -> str:
+        """The name of the feature set."""
+        return self._fs.name()
+
+    @property
+    def version(self) -> int:
+        """The version of the feature set."""
+        return self._fs.version()
+
+    @property
+    def features(self) -> dict[str, Expr]:
+        """The expressions of the features, by their names."""
+        return {name: wrap_expr(expr) for name, expr, _ in self._fs.features()}
+
+    @property
+    def dtypes(self) -> dict[str, PolarsDataType]:
+        """The dtypes that features must evaluate to, by the names of the features."""
+        return {
+            name: dtype
+            for name, _, dtype in self._fs.features()
+            if dtype is not None
+        }
+
+    def serialize(self, file: IOBase | str | Path | None = None) -> bytes | None:
+        """
+        Serialize the feature set to a file or bytes.
+
+        Parameters
+        ----------
+        file
+            File path to which the result should be written. If set to `None`
+            (default), the output is returned as bytes instead.
+
+        See Also
+        --------
+        FeatureSet.deserialize
+
+        Notes
+        -----
+        A feature set can only be deserialized by Polars versions that can
+        deserialize the expressions of the Polars version that serialized it.
+        """
+        return serialize_polars_object(self._fs.serialize_binary, file, "binary")
+
+    @classmethod
+    def deserialize(cls, source: str | Path | IOBase) -> FeatureSet:
+        """
+        Read a feature set from a file.
+
+        Parameters
+        ----------
+        source
+            Path to a file or a file-like object (by file-like object, we refer to
+            objects that have a `read()` method, such as a file handler (e.g.
+            via builtin `open` function) or `BytesIO`).
+
+        Warnings
+        --------
+        This function uses :mod:`pickle` if the features contain Python UDFs,
+        and as such inherits the security implications. Deserializing can execute
+        arbitrary code, so it should only be attempted on trusted data.
+
+        See Also
+        --------
+        FeatureSet.serialize
+        """
+        if isinstance(source, StringIO):
+            source = BytesIO(source.getvalue().encode())
+        elif isinstance(source, (str, Path)):
+            source = normalize_filepath(source)
+
+        return cls._from_pyfs(PyFeatureSet.deserialize_binary(source))
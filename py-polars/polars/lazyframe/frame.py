@@ -3,10 +3,12 @@ from __future__ import annotations
 import contextlib
 import io
 import os
+import tempfile
 import warnings
 from collections.abc import Collection, Iterable, Mapping
 from datetime import date, datetime, time, timedelta
 from functools import lru_cache, partial, reduce
+from itertools import accumulate
 from io import BytesIO, StringIO
 from operator import and_
 from pathlib import Path
@@ -28,7 +30,11 @@ from polars._typing import (
     PartitioningScheme,
 )
 from polars._utils.async_ import _AioDataFrameResult, _GeventDataFrameResult
-from polars._utils.convert import negate_duration_string, parse_as_duration_string
+from polars._utils.convert import (
+    negate_duration_string,
+    parse_as_duration_string,
+    timedelta_to_int,
+)
 from polars._utils.deprecation import (
     deprecate_renamed_parameter,
     deprecate_streaming_parameter,
@@ -74,9 +80,11 @@ from polars.datatypes import (
     Int32,
     Int64,
     Int128,
+    List,
     Null,
     Object,
     String,
+    Struct,
     Time,
     UInt8,
     UInt16,
@@ -97,12 +105,19 @@ from polars.dependencies import polars_cloud as pc
 from polars.dependencies import pyarrow as pa
 from polars.exceptions import PerformanceWarning
 from polars.interchange.protocol import CompatLevel
-from polars.lazyframe.engine_config import GPUEngine
+from polars.lazyframe.engine_config import (
+    GPUEngine,
+    GPUFallbackReport,
+    GPUUnsupportedOperation,
+    StreamingEngine,
+)
 from polars.lazyframe.group_by import LazyGroupBy
 from polars.lazyframe.in_process import InProcessQuery
+from polars.lazyframe.inspect import InspectMetrics
 from polars.lazyframe.opt_flags import DEFAULT_QUERY_OPT_FLAGS, forward_old_opt_flags
+from polars.result_cache import _collect_cached
 from polars.schema import Schema
-from polars.selectors import by_dtype, expand_selector
+from polars.selectors import _expand_selectors, by_dtype, expand_selector
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
     from polars.polars import PyLazyFrame, get_engine_affinity
@@ -113,14 +128,16 @@ if TYPE_CHECKING:
     from io import IOBase
     from typing import IO, Literal
 
+    from polars.lazyframe.feature_set import FeatureSet
     from polars.lazyframe.opt_flags import QueryOptFlags
 
     with contextlib.suppress(ImportError):  # Module not available when building docs
         from polars.polars import PyExpr, PyPartitioning, PySelector
 
-    from polars import DataFrame, DataType, Expr
+    from polars import DataFrame, DataType, Expr, TradingCalendar
     from polars._typing import (
         AsofJoinStrategy,
+        CacheHint,
         ClosedInterval,
         ColumnNameOrSelector,
         CsvQuoteStyle,
@@ -128,6 +145,7 @@ if TYPE_CHECKING:
         ExplainFormat,
         FillNullStrategy,
         FrameInitTypes,
+        IntervalJoinMode,
         IntoExpr,
         IntoExprColumn,
         IpcCompression,
@@ -137,8 +155,10 @@ if TYPE_CHECKING:
         MaintainOrderJoin,
         Orientation,
         ParquetMetadata,
+        PivotAgg,
         PlanStage,
         PolarsDataType,
+        PolarsIntegerType,
         PythonDataType,
         QuantileMethod,
         SchemaDefinition,
@@ -151,6 +171,7 @@ if TYPE_CHECKING:
     from polars.dependencies import numpy as np
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.parquet import ParquetFieldOverwrites
+    from polars.io.shared_memory import SharedMemoryFrame
 
     if sys.version_info >= (3, 10):
         from typing import Concatenate, ParamSpec
@@ -171,10 +192,42 @@ if TYPE_CHECKING:
     P = ParamSpec("P")
 
 
+_SPLIT_COLUMN = "__POLARS_SPLIT"
+
+
 def _select_engine(engine: EngineType) -> EngineType:
     return get_engine_affinity() if engine == "auto" else engine
 
 
+def _split_engine_config(engine: EngineType) -> tuple[EngineType, dict[str, Any]]:
+    """Split a :class:`StreamingEngine` into the engine and its options."""
+    if isinstance(engine, StreamingEngine):
+        return "streaming", engine._overrides()
+    return engine, {}
+
+
+def _config_overrides(config: Mapping[str, Any] | None) -> dict[str, Any]:
+    """Translate the `config` of a collect call to the arguments of the engine."""
+    if not config:
+        return {}
+    unknown = set(config) - {"verbose", "streaming_chunk_size"}
+    if unknown:
+        msg = (
+            f"unsupported `config` option(s) {sorted(unknown)!r}; "
+            "only 'verbose' and 'streaming_chunk_size' can be set per query"
+        )
+        raise ValueError(msg)
+    overrides: dict[str, Any] = {}
+    if (verbose := config.get("verbose")) is not None:
+        overrides["verbose"] = bool(verbose)
+    if (size := config.get("streaming_chunk_size")) is not None:
+        if size < 1:
+            msg = f"number of rows per chunk must be >= 1, got {size}"
+            raise ValueError(msg)
+        overrides["ideal_morsel_size"] = size
+    return overrides
+
+
 def _to_sink_target(
     path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
 ) -> str | Path | IO[bytes] | IO[str] | PyPartitioning:
@@ -192,6 +245,32 @@ def _to_sink_target(
         raise TypeError(msg)
 
 
+def _timedelta_to_physical(td: timedelta, dtype: DataType) -> int:
+    """Express a timedelta in the physical unit of a temporal data type."""
+    if dtype == Date:
+        if td % timedelta(days=1):
+            msg = f"granularity of a Date column must be whole days, got {td!r}"
+            raise ValueError(msg)
+        return td.days
+    elif dtype == Time:
+        return timedelta_to_int(td, "ns")
+    elif isinstance(dtype, (Datetime, Duration)):
+        return timedelta_to_int(td, dtype.time_unit)
+    else:
+        msg = f"a timedelta granularity requires a temporal column, got {dtype}"
+        raise TypeError(msg)
+
+
+def _to_watch_target(target: Any) -> Any:
+    if isinstance(target, (str, Path)):
+        return normalize_filepath(target)
+    elif callable(target):
+        return lambda df: target(wrap_df(df))
+    else:
+        # Raises a `TypeError` when collecting.
+        return target
+
+
 def _gpu_engine_callback(
     engine: EngineType,
     *,
@@ -433,7 +512,7 @@ class LazyFrame:
     @classmethod
     def _scan_python_function(
         cls,
-        schema: pa.schema | SchemaDict | Callable[[], SchemaDict],
+        schema: pa.schema | SchemaDict | Callable[[list[str] | None], SchemaDict],
         scan_fn: Any,
         *,
         pyarrow: bool = False,
@@ -459,7 +538,13 @@ class LazyFrame:
 
     @classmethod
     def deserialize(
-        cls, source: str | Path | IOBase, *, format: SerializationFormat = "binary"
+        cls,
+        source: str | Path | IOBase,
+        *,
+        format: SerializationFormat = "binary",
+        allow_python_objects: bool = True,
+        allow_user_functions: bool = True,
+        allowed_paths: Sequence[str | Path] | None = None,
     ) -> LazyFrame:
         """
         Read a logical plan from a file to construct a LazyFrame.
@@ -475,12 +560,26 @@ class LazyFrame:
 
             - `"binary"`: Deserialize from binary format (bytes). This is the default.
             - `"json"`: Deserialize from JSON format (string).
+        allow_python_objects
+            Whether to accept plans containing Python objects, such as UDFs. If
+            False, such plans raise an error before any Python object is unpickled.
+        allow_user_functions
+            Whether to accept plans that run user-defined code, such as Python UDFs,
+            anonymous functions and plugins. Plugins load a shared library, so they
+            can execute arbitrary code even if `allow_python_objects=False`.
+        allowed_paths
+            If set, only accept plans that scan and sink paths within these paths
+            or URIs. Local paths are compared after resolving `~`, `.` and `..`,
+            but not symbolic links.
 
         Warnings
         --------
         This function uses :mod:`pickle` if the logical plan contains Python UDFs,
         and as such inherits the security implications. Deserializing can execute
-        arbitrary code, so it should only be attempted on trusted data.
+        arbitrary code, so it should only be attempted on trusted data, or with
+        `allow_python_objects=False` and `allow_user_functions=False`. Plans from
+        untrusted sources should also set `allowed_paths` to prevent them from
+        reading or writing arbitrary files.
 
         See Also
         --------
@@ -519,7 +618,14 @@ class LazyFrame:
             msg = f"`format` must be one of {{'binary', 'json'}}, got {format!r}"
             raise ValueError(msg)
 
-        return cls._from_pyldf(deserializer(source))
+        return cls._from_pyldf(
+            deserializer(
+                source,
+                allow_python_objects,
+                allow_user_functions,
+                None if allowed_paths is None else list(allowed_paths),
+            )
+        )
 
     @property
     def columns(self) -> list[str]:
@@ -1167,6 +1273,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         tree_format: bool | None = None,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        node_ids: bool = False,
+        sample: float | None = None,
     ) -> str:
         """
         Create a string representation of the query plan.
@@ -1261,6 +1369,26 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
             .. deprecated:: 0.20.30
                 Use `format="tree"` instead.
+        node_ids
+            Prefix every node of the optimized plan with its id, which can be passed
+            to the `watch` parameter of :meth:`collect`. Only supported for the
+            plain format of the optimized plan.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        sample
+            Run the query on this fraction of the rows of every scan, and annotate each
+            node of the optimized plan with the number of rows it produced and the time
+            it took (in microseconds). This reveals the actual cardinalities of the
+            plan, which are hard to predict for complex queries. The sample consists of
+            evenly spread rows and is always run on the in-memory engine; the scans
+            still read all of their input. Only supported for the plain format of the
+            optimized plan.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -1274,6 +1402,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         >>> lf.group_by("a", maintain_order=True).agg(pl.all().sum()).sort(
         ...     "a"
         ... ).explain()  # doctest: +SKIP
+
+        Show how many rows each node produces on 10% of the data:
+
+        >>> lf.filter(pl.col("b") > 2).group_by("a").agg(pl.col("c").sum()).explain(
+        ...     sample=0.1
+        ... )  # doctest: +SKIP
         """
         if tree_format is not None:
             issue_deprecation_warning(
@@ -1289,10 +1423,26 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         if engine == "streaming":
             issue_unstable_warning("streaming mode is considered unstable.")
 
+        if node_ids:
+            issue_unstable_warning("node ids in `explain` are considered unstable.")
+            if not optimized or format != "plain":
+                msg = "`node_ids` is only supported for the plain optimized plan"
+                raise ValueError(msg)
+
+        if sample is not None:
+            issue_unstable_warning("sampled runs in `explain` are considered unstable.")
+            if not optimized or format != "plain" or node_ids:
+                msg = "`sample` is only supported for the plain optimized plan"
+                raise ValueError(msg)
+
         if optimized:
             optimizations = optimizations.__copy__()
             optimizations._pyoptflags.streaming = engine == "streaming"
             ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+            if node_ids:
+                return ldf.describe_optimized_plan_with_node_ids()
+            if sample is not None:
+                return ldf.describe_optimized_plan_sampled(sample)
             if format == "tree":
                 return ldf.describe_optimized_plan_tree()
             else:
@@ -1303,6 +1453,204 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         else:
             return self._ldf.describe_plan()
 
+    @unstable()
+    def fingerprint(
+        self,
+        *,
+        optimized: bool = True,
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> int:
+        """
+        Return a hash of the query plan.
+
+        Plans that only differ in the order of the operands of commutative operators,
+        e.g. `a & b` and `b & a`, have the same fingerprint. This makes it possible to
+        assert that a change in the code that builds a query did not alter its plan.
+
+        The whole plan is hashed, including the data of in-memory frames, so plans
+        with the same description can still have different fingerprints.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        optimized
+            Hash the optimized plan instead of the naive plan.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Notes
+        -----
+        The fingerprint is the same across processes and machines, but it is not
+        guaranteed to be the same across versions of Polars.
+
+        See Also
+        --------
+        diff_plan
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": [True, False, True]})
+        >>> q1 = lf.filter((pl.col("a") > 1) & pl.col("b"))
+        >>> q2 = lf.filter(pl.col("b") & (pl.col("a") > 1))
+        >>> q1.fingerprint() == q2.fingerprint()
+        True
+        >>> q1.fingerprint() == lf.filter(pl.col("a") > 2).fingerprint()
+        False
+        """
+        return self._plan_ldf(optimized, optimizations).fingerprint(optimized)
+
+    @unstable()
+    def diff_plan(
+        self,
+        other: LazyFrame,
+        *,
+        optimized: bool = True,
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> str | None:
+        """
+        Return a line based diff between the query plans of two LazyFrames.
+
+        Lines only in the plan of this LazyFrame are prefixed with `- `, lines only in
+        the plan of `other` with `+ `. Returns `None` if both plans have the same
+        :meth:`fingerprint`. Plans that only differ in details that are not part of
+        their description, such as the data of in-memory frames, give a diff without
+        changed lines.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        other
+            LazyFrame to compare the plan with.
+        optimized
+            Compare the optimized plans instead of the naive plans.
+        optimizations
+            The optimization passes done during query optimization.
+
+        See Also
+        --------
+        fingerprint
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+        >>> lf.select("a").diff_plan(lf.select("a")) is None
+        True
+        >>> print(lf.select("a").diff_plan(lf.select("b")))  # doctest: +SKIP
+        - DF ["a", "b"]; PROJECT["a"] 1/2 COLUMNS
+        + DF ["a", "b"]; PROJECT["b"] 1/2 COLUMNS
+        """
+        return self._plan_ldf(optimized, optimizations).diff_plan(
+            other._plan_ldf(optimized, optimizations), optimized
+        )
+
+    @unstable()
+    def column_lineage(self) -> DataFrame:
+        """
+        Return the source columns and expressions each output column derives from.
+
+        The result has a row per output column of the query, with the columns of the
+        sources of which the values flow into it, and the expressions that compute it
+        in the order they are applied. A source is the path of a scanned file, or `DF`
+        for a DataFrame in memory. Filters, sorts and other operations that only
+        select rows are not part of the lineage.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Notes
+        -----
+        The lineage is taken from the naive plan, so the expressions are shown as
+        written instead of as rewritten by the optimizer.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+        >>> lf.select(pl.col("a"), (pl.col("a") + pl.col("b")).alias("d")).filter(
+        ...     pl.col("c") > 5
+        ... ).column_lineage()  # doctest: +SKIP
+        shape: (2, 3)
+        ┌────────┬─────────────────────────────┬─────────────────────────────────┐
+        │ column ┆ sources                     ┆ expressions                     │
+        │ ---    ┆ ---                         ┆ ---                             │
+        │ str    ┆ list[struct[2]]             ┆ list[str]                       │
+        ╞════════╪═════════════════════════════╪═════════════════════════════════╡
+        │ a      ┆ [{"DF","a"}]                ┆ []                              │
+        │ d      ┆ [{"DF","a"}, {"DF","b"}]    ┆ ["[(col("a")) + (col("b"))].al… │
+        └────────┴─────────────────────────────┴─────────────────────────────────┘
+        """
+        lineage = self._ldf.column_lineage()
+        return pl.DataFrame(
+            [
+                (
+                    name,
+                    [{"source": src, "column": col} for src, col in sources],
+                    expressions,
+                )
+                for name, sources, expressions in lineage
+            ],
+            schema={
+                "column": String,
+                "sources": List(Struct({"source": String, "column": String})),
+                "expressions": List(String),
+            },
+            orient="row",
+        )
+
+    @unstable()
+    def pruned_files(self) -> DataFrame:
+        """
+        Return the files that the scan of the query reads, without executing it.
+
+        The files are pruned with the predicates that are pushed down to the scan, on
+        their hive partitions and on the `file_statistics` of the scan, in the same way
+        as when the query is executed. This allows an external scheduler to plan the
+        work of a query before running it. Statistics within the files, such as those
+        of Parquet row groups, are not read.
+
+        The result has a row per file that is read, with its `path`, its
+        `estimated_rows` before the predicates are applied and its
+        `estimated_bytes`. The estimates are null if they are not known without
+        reading the file, e.g. the size of a file in cloud storage.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Raises
+        ------
+        InvalidOperationError
+            If the query does not have exactly one file scan.
+
+        Examples
+        --------
+        >>> pl.DataFrame({"year": [2023, 2024], "v": [1, 2]}).write_parquet(
+        ...     "data", partition_by="year"
+        ... )  # doctest: +SKIP
+        >>> lf = pl.scan_parquet("data", hive_partitioning=True)  # doctest: +SKIP
+        >>> lf.filter(pl.col("year") == 2024).pruned_files()  # doctest: +SKIP
+        shape: (1, 3)
+        ┌──────────────────────────────┬────────────────┬─────────────────┐
+        │ path                         ┆ estimated_rows ┆ estimated_bytes │
+        │ ---                          ┆ ---            ┆ ---             │
+        │ str                          ┆ u64            ┆ u64             │
+        ╞══════════════════════════════╪════════════════╪═════════════════╡
+        │ data/year=2024/00000000.parq ┆ null           ┆ 520             │
+        └──────────────────────────────┴────────────────┴─────────────────┘
+        """
+        return wrap_df(self._ldf.pruned_files())
+
+    def _plan_ldf(self, optimized: bool, optimizations: QueryOptFlags) -> PyLazyFrame:
+        if optimized:
+            return self._ldf.with_optimizations(optimizations._pyoptflags)
+        return self._ldf
+
     @deprecate_streaming_parameter()
     @forward_old_opt_flags()
     def show_graph(
@@ -1487,6 +1835,56 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             inspect, predicate_pushdown=True, projection_pushdown=True
         )
 
+    @unstable()
+    def inspect_metrics(
+        self, *exprs: IntoExpr | Iterable[IntoExpr]
+    ) -> tuple[LazyFrame, InspectMetrics]:
+        """
+        Compute metrics of the data that flows through this node of the query.
+
+        The data is passed on unchanged, and the metric expressions are evaluated on
+        it while the query runs. The metrics are reported by the returned
+        :class:`~polars.lazyframe.inspect.InspectMetrics` after collecting. They are
+        computed per batch, so the streaming engine does not need to materialize
+        the data to compute them. This makes them useful for data quality checks
+        inside a pipeline.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        *exprs
+            The metrics to compute. Only :func:`len`, :meth:`Expr.count`,
+            :meth:`Expr.null_count`, :meth:`Expr.sum`, :meth:`Expr.min` and
+            :meth:`Expr.max` are supported, optionally with an alias.
+
+        Returns
+        -------
+        tuple of LazyFrame and InspectMetrics
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, None, 3], "b": ["x", "y", None]})
+        >>> lf, metrics = lf.inspect_metrics(
+        ...     pl.len(), pl.all().null_count().name.suffix("_nulls"), pl.col("a").max()
+        ... )
+        >>> df = lf.filter(pl.col("a") > 1).collect()
+        >>> metrics.report()
+        shape: (1, 4)
+        ┌─────┬─────────┬─────────┬─────┐
+        │ len ┆ a_nulls ┆ b_nulls ┆ a   │
+        │ --- ┆ ---     ┆ ---     ┆ --- │
+        │ u32 ┆ u32     ┆ u32     ┆ i64 │
+        ╞═════╪═════════╪═════════╪═════╡
+        │ 3   ┆ 1       ┆ 1       ┆ 3   │
+        └─────┴─────────┴─────────┴─────┘
+        """
+        pyexprs = parse_into_list_of_expressions(*exprs)
+        ldf, handle = self._ldf.inspect_metrics(pyexprs)
+        return self._from_pyldf(ldf), InspectMetrics(handle)
+
     def sort(
         self,
         by: IntoExpr | Iterable[IntoExpr],
@@ -1847,6 +2245,77 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         reverse = extend_bool(reverse, len(by), "reverse", "by")
         return self._from_pyldf(self._ldf.bottom_k(k, by=by, reverse=reverse))
 
+    @unstable()
+    def gpu_fallback_report(
+        self,
+        *,
+        engine: GPUEngine | None = None,
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> GPUFallbackReport:
+        """
+        Report the operations that make the GPU engine fall back to the CPU engine.
+
+        Collecting with `engine="gpu"` only warns when the query cannot run on the
+        GPU. This lists every unsupported operation in the optimized query plan
+        instead, so the query can be rewritten to run on the GPU entirely. The query
+        is not executed.
+
+        The plan nodes and expressions that Polars cannot pass to GPU engines are
+        reported first. If there are none and `cudf_polars` is installed, the
+        operations that it rejects are reported instead.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        engine
+            The :class:`~.GPUEngine` configuration to check the query against.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [[1, 2], [3]]})
+        >>> report = lf.select(pl.col("a").list.sum()).gpu_fallback_report()
+        >>> report.supported
+        False
+        >>> op = report.operations[0]
+        >>> op.kind, op.node, op.message
+        ('expression', 'projection', 'list expr')
+        """
+        ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        operations = [
+            GPUUnsupportedOperation(tuple(path), kind, node, message)
+            for path, kind, node, message in ldf.visit().unsupported_operations()
+        ]
+        if operations:
+            return GPUFallbackReport(operations)
+
+        try:
+            cudf_polars = import_optional("cudf_polars")
+        except ModuleNotFoundError:
+            return GPUFallbackReport(operations)
+
+        if engine is None:
+            engine = GPUEngine()
+        config = GPUEngine(
+            device=engine.device,
+            memory_resource=engine.memory_resource,
+            **{**engine.config, "raise_on_fail": True},
+        )
+        try:
+            # Translating the plan does not execute it.
+            cudf_polars.execute_with_cudf(ldf.visit(), None, config=config)
+        except NotImplementedError as exc:
+            # `cudf_polars` passes the individual errors after the message.
+            errors = exc.args[1] if len(exc.args) > 1 else exc.args
+            operations.extend(
+                GPUUnsupportedOperation((), "engine", None, str(err)) for err in errors
+            )
+        return GPUFallbackReport(operations)
+
     @forward_old_opt_flags()
     def profile(
         self,
@@ -2080,6 +2549,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         background: Literal[True],
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        config: Mapping[str, Any] | None = None,
+        params: Mapping[str, Any] | None = None,
+        watch: Mapping[int, str | Path | Callable[[DataFrame], None]] | None = None,
     ) -> InProcessQuery: ...
 
     @overload
@@ -2099,6 +2571,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         background: Literal[False] = False,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        config: Mapping[str, Any] | None = None,
+        params: Mapping[str, Any] | None = None,
+        watch: Mapping[int, str | Path | Callable[[DataFrame], None]] | None = None,
     ) -> DataFrame: ...
 
     @deprecate_streaming_parameter()
@@ -2119,6 +2594,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         engine: EngineType = "auto",
         background: bool = False,
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        config: Mapping[str, Any] | None = None,
+        params: Mapping[str, Any] | None = None,
+        watch: Mapping[int, str | Path | Callable[[DataFrame], None]] | None = None,
         **_kwargs: Any,
     ) -> DataFrame | InProcessQuery:
         """
@@ -2202,6 +2680,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. note::
                The GPU engine does not support streaming, or running in the
                background. If either are enabled, then GPU execution is switched off.
+
+            The concurrency of the operators of the streaming engine can be
+            tuned by providing a :class:`~.StreamingEngine` object.
         background
             Run the query in the background and get a handle to the query.
             This handle can be used to fetch the result or cancel the query.
@@ -2212,6 +2693,42 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         optimizations
             The optimization passes done during query optimization.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        config
+            Configuration options that apply to this query only, taking precedence
+            over the global :class:`Config` (and its environment variables) without
+            modifying it. This allows concurrent queries in one process to use
+            different settings. Supported options are `verbose` and
+            `streaming_chunk_size`, see :meth:`Config.set_verbose` and
+            :meth:`Config.set_streaming_chunk_size`.
+            The options are passed to the engine along with the query, so they
+            apply to the engine's own operations; the file readers and writers it
+            calls still log according to the global setting of `verbose`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        params
+            The values of the placeholders in the query, by name, see
+            :func:`polars.placeholder`. The optimized query is cached, so collecting
+            the same LazyFrame again with other values does not optimize it again.
+            Every placeholder needs a value. Not supported in background mode, and
+            the GPU engine is not used for a query with `params`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        watch
+            Debug the query by materializing the output of some of its nodes, given
+            by their id in `explain(node_ids=True)` with the same `optimizations`.
+            The output of a node is written to an IPC file if it maps to a path, or
+            its first 10 rows are passed to a callable. This shows which stage of the
+            query produces unexpected values without splitting it up. The query is
+            run by the in-memory engine, and `watch` can't be combined with
+            `background` or `params`.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
@@ -2302,7 +2819,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 error_msg = f"collect() got an unexpected keyword argument '{k}'"
                 raise TypeError(error_msg)
 
-        engine = _select_engine(engine)
+        engine, engine_overrides = _split_engine_config(_select_engine(engine))
 
         new_streaming = (
             _kwargs.get("new_streaming", False) or get_engine_affinity() == "streaming"
@@ -2325,14 +2842,49 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         if isinstance(engine, GPUEngine):
             engine = "gpu"
 
+        overrides = _config_overrides(config)
+        if overrides:
+            issue_unstable_warning("per-query `config` is considered unstable.")
+        overrides.update(engine_overrides)
+
         ldf = self._ldf.with_optimizations(optimizations._pyoptflags)
+        if watch is not None:
+            issue_unstable_warning("`watch` is considered unstable.")
+            if background or params is not None:
+                msg = "`watch` can't be combined with `background` or `params`"
+                raise ValueError(msg)
+            watch_list = [
+                (node, _to_watch_target(target)) for node, target in watch.items()
+            ]
+            return wrap_df(ldf.collect_with_data_watch(watch_list, 10, **overrides))
+
         if background:
             issue_unstable_warning("background mode is considered unstable.")
+            if overrides:
+                msg = (
+                    "`config` and `StreamingEngine` options are not supported in "
+                    "background mode"
+                )
+                raise ValueError(msg)
+            if params is not None:
+                msg = "`params` is not supported in background mode"
+                raise ValueError(msg)
             return InProcessQuery(ldf.collect_concurrently())
 
+        if params is not None:
+            issue_unstable_warning("query `params` are considered unstable.")
+            params_list = list(params.items())
+            return wrap_df(ldf.collect_with_params(engine, params_list, **overrides))
+
         # Only for testing purposes
         callback = _kwargs.get("post_opt_callback", callback)
-        return wrap_df(ldf.collect(engine, callback))
+        if callback is None:
+            return _collect_cached(
+                self,
+                f"{engine}{sorted(overrides.items())}{optimizations}",
+                lambda: wrap_df(ldf.collect(engine, callback, **overrides)),
+            )
+        return wrap_df(ldf.collect(engine, callback, **overrides))
 
     @overload
     def collect_async(
@@ -2341,6 +2893,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         gevent: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        config: Mapping[str, Any] | None = None,
     ) -> _GeventDataFrameResult[DataFrame]: ...
 
     @overload
@@ -2350,6 +2903,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         gevent: Literal[False] = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        config: Mapping[str, Any] | None = None,
     ) -> Awaitable[DataFrame]: ...
 
     @deprecate_streaming_parameter()
@@ -2359,6 +2913,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         gevent: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+        config: Mapping[str, Any] | None = None,
     ) -> Awaitable[DataFrame] | _GeventDataFrameResult[DataFrame]:
         """
         Collect DataFrame asynchronously in thread pool.
@@ -2396,6 +2951,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
+        config
+            Configuration options that apply to this query only, see
+            :meth:`LazyFrame.collect`.
 
         Returns
         -------
@@ -2442,7 +3000,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ c   ┆ 6   ┆ 1   │
         └─────┴─────┴─────┘
         """
-        engine = _select_engine(engine)
+        engine, engine_overrides = _split_engine_config(_select_engine(engine))
 
         if engine == "streaming":
             issue_unstable_warning("streaming mode is considered unstable.")
@@ -2452,40 +3010,181 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         result: _GeventDataFrameResult[DataFrame] | _AioDataFrameResult[DataFrame] = (
             _GeventDataFrameResult() if gevent else _AioDataFrameResult()
         )
-        ldf.collect_with_callback(engine, result._callback)
+        overrides = {**_config_overrides(config), **engine_overrides}
+        ldf.collect_with_callback(engine, result._callback, **overrides)
         return result
 
-    def collect_schema(self) -> Schema:
+    @unstable()
+    def collect_partitioned(
+        self,
+        by: IntoExpr | Iterable[IntoExpr] | None = None,
+        *,
+        n_shards: int | None = None,
+        max_shard_size: int | None = None,
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> list[DataFrame]:
         """
-        Resolve the schema of this LazyFrame.
+        Collect into multiple DataFrames in a single pass.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The query runs once on the streaming engine, which writes the rows of every
+        shard to Arrow IPC files in a temporary directory as they are produced (see
+        :class:`polars.io.partition.PartitionByKey` and
+        :class:`polars.io.partition.PartitionMaxSize`). The files are read back and
+        removed afterwards, so the rows are never held in memory twice.
+
+        Parameters
+        ----------
+        by
+            Column(s) to shard by. Rows are assigned to one of `n_shards` shards by
+            the hash of these columns, so all rows with the same key end up in the
+            same shard. If not given, the rows are distributed round-robin over the
+            `n_shards` shards, which gives shards of (nearly) equal size.
+        n_shards
+            Number of DataFrames to return.
+        max_shard_size
+            Instead of `n_shards`, split the rows in order into shards of this many
+            rows. The last shard may be smaller.
+        optimizations
+            The optimization passes done during query optimization.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        list of DataFrame
+            The shards. With `n_shards` some of them may be empty. The order of the
+            rows within a shard is maintained.
+
+        See Also
+        --------
+        DataFrame.partition_by
 
         Examples
         --------
-        Determine the schema.
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4, 5], "b": ["x", "y", "x", "z", "y"]})
+        >>> [df["a"].to_list() for df in lf.collect_partitioned(n_shards=2)]
+        [[1, 3, 5], [2, 4]]
 
-        >>> lf = pl.LazyFrame(
-        ...     {
-        ...         "foo": [1, 2, 3],
-        ...         "bar": [6.0, 7.0, 8.0],
-        ...         "ham": ["a", "b", "c"],
-        ...     }
-        ... )
-        >>> lf.collect_schema()
-        Schema({'foo': Int64, 'bar': Float64, 'ham': String})
+        Shard by the hash of a column.
 
-        Access various properties of the schema.
+        >>> shards = lf.collect_partitioned("b", n_shards=2)
+        >>> keys = [set(df["b"]) for df in shards]
+        >>> sorted(keys[0] | keys[1]), keys[0].isdisjoint(keys[1])
+        (['x', 'y', 'z'], True)
 
-        >>> schema = lf.collect_schema()
-        >>> schema["bar"]
-        Float64
-        >>> schema.names()
-        ['foo', 'bar', 'ham']
-        >>> schema.dtypes()
-        [Int64, Float64, String]
-        >>> schema.len()
-        3
+        Shard by size.
+
+        >>> [df["a"].to_list() for df in lf.collect_partitioned(max_shard_size=2)]
+        [[1, 2], [3, 4], [5]]
         """
-        return Schema(self._ldf.collect_schema(), check_dtypes=False)
+        from polars.io.ipc import read_ipc
+        from polars.io.partition import PartitionByKey, PartitionMaxSize
+
+        def read_files(files: Iterable[Path]) -> list[DataFrame]:
+            return [
+                read_ipc(f, memory_map=False, rechunk=False)
+                for f in sorted(files, key=lambda f: int(f.stem))
+            ]
+
+        if (n_shards is None) == (max_shard_size is None):
+            msg = "exactly one of `n_shards` and `max_shard_size` must be given"
+            raise ValueError(msg)
+
+        if max_shard_size is not None:
+            if by is not None:
+                msg = "`by` cannot be combined with `max_shard_size`"
+                raise ValueError(msg)
+            if not isinstance(max_shard_size, int) or max_shard_size < 1:
+                msg = (
+                    "`max_shard_size` must be a positive integer, "
+                    f"got {max_shard_size!r}"
+                )
+                raise ValueError(msg)
+            with tempfile.TemporaryDirectory(prefix="polars-shards-") as base_path:
+                self.sink_ipc(
+                    PartitionMaxSize(
+                        base_path,
+                        file_path=lambda ctx: f"{ctx.file_idx}.ipc",
+                        max_size=max_shard_size,
+                    ),
+                    engine="streaming",
+                    optimizations=optimizations,
+                )
+                return read_files(Path(base_path).glob("*.ipc"))
+
+        if not isinstance(n_shards, int) or n_shards < 1:
+            msg = f"`n_shards` must be a positive integer, got {n_shards!r}"
+            raise ValueError(msg)
+
+        lf = self
+        index_name = "__POLARS_SHARD_INDEX"
+        with tempfile.TemporaryDirectory(prefix="polars-shards-") as base_path:
+            if by is None:
+                lf = lf.with_row_index(index_name)
+                shard = F.col(index_name) % n_shards
+            else:
+                shard = F.struct(by).hash(seed=0) % n_shards
+            lf.sink_ipc(
+                PartitionByKey(
+                    base_path,
+                    file_path=lambda ctx: (
+                        f"{ctx.keys[0].str_value}/{ctx.in_part_idx}.ipc"
+                    ),
+                    by=shard.alias("__POLARS_SHARD"),
+                    include_key=False,
+                ),
+                mkdir=True,
+                engine="streaming",
+                optimizations=optimizations,
+            )
+
+            empty = pl.DataFrame(schema=lf.collect_schema())
+            shards = []
+            for i in range(n_shards):
+                dfs = read_files(Path(base_path, str(i)).glob("*.ipc"))
+                shards.append(F.concat(dfs, rechunk=False) if dfs else empty.clear())
+
+        if by is None:
+            shards = [df.drop(index_name) for df in shards]
+        return shards
+
+    def collect_schema(self) -> Schema:
+        """
+        Resolve the schema of this LazyFrame.
+
+        Examples
+        --------
+        Determine the schema.
+
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "foo": [1, 2, 3],
+        ...         "bar": [6.0, 7.0, 8.0],
+        ...         "ham": ["a", "b", "c"],
+        ...     }
+        ... )
+        >>> lf.collect_schema()
+        Schema({'foo': Int64, 'bar': Float64, 'ham': String})
+
+        Access various properties of the schema.
+
+        >>> schema = lf.collect_schema()
+        >>> schema["bar"]
+        Float64
+        >>> schema.names()
+        ['foo', 'bar', 'ham']
+        >>> schema.dtypes()
+        [Int64, Float64, String]
+        >>> schema.len()
+        3
+        """
+        return Schema(self._ldf.collect_schema(), check_dtypes=False)
 
     @overload
     def sink_parquet(
@@ -2496,6 +3195,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression_level: int | None = None,
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
+        row_group_bytes: int | None = None,
+        split_row_groups_on: str | None = None,
+        split_row_groups_every: int | timedelta | None = None,
         data_page_size: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
@@ -2505,6 +3207,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: Literal[False] = ...,
         field_overwrites: ParquetFieldOverwrites
         | Sequence[ParquetFieldOverwrites]
@@ -2524,6 +3227,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression_level: int | None = None,
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
+        row_group_bytes: int | None = None,
+        split_row_groups_on: str | None = None,
+        split_row_groups_every: int | timedelta | None = None,
         data_page_size: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
@@ -2533,6 +3239,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: Literal[True],
         field_overwrites: ParquetFieldOverwrites
         | Sequence[ParquetFieldOverwrites]
@@ -2551,6 +3258,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         compression_level: int | None = None,
         statistics: bool | str | dict[str, bool] = True,
         row_group_size: int | None = None,
+        row_group_bytes: int | None = None,
+        split_row_groups_on: str | None = None,
+        split_row_groups_every: int | timedelta | None = None,
         data_page_size: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
@@ -2561,6 +3271,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         sync_on_close: SyncOnCloseMethod | None = None,
         metadata: ParquetMetadata | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: bool = False,
         field_overwrites: ParquetFieldOverwrites
         | Sequence[ParquetFieldOverwrites]
@@ -2612,6 +3323,23 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             If None (default), the chunks of the `DataFrame` are
             used. Writing in smaller chunks may reduce memory pressure and improve
             writing speeds.
+        row_group_bytes
+            Target size of the row groups in bytes, based on the estimated
+            (uncompressed) in-memory size of the data. If `row_group_size` is also
+            set, the smallest of both is used.
+
+            Only supported by the in-memory engine.
+        split_row_groups_on
+            Name of a (sorted) column on whose value changes a new row group is
+            started. This keeps the statistics of every row group tight, which
+            improves pruning when reading the file.
+
+            Only supported by the in-memory engine.
+        split_row_groups_every
+            Granularity of `split_row_groups_on`, e.g. `timedelta(days=1)` to start a
+            new row group on every new (UTC) day. Integers are interpreted in the
+            physical unit of the column. If not set, every distinct value starts a
+            new row group.
         data_page_size
             Size limit of individual data pages.
             If not set defaults to 1024 * 1024 bytes
@@ -2666,6 +3394,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        staged_commit: bool
+            Write local files to a hidden staging file next to the target, and only
+            rename it to the target once it is complete. If the query fails, the
+            staging file is removed and an existing file at the target is left as is.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
@@ -2747,11 +3483,22 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             # Handle empty dict input
             storage_options = None
 
+        if split_row_groups_every is not None:
+            if split_row_groups_on is None:
+                msg = "`split_row_groups_every` requires `split_row_groups_on` to be set"
+                raise ValueError(msg)
+            if isinstance(split_row_groups_every, timedelta):
+                dtype = self.collect_schema()[split_row_groups_on]
+                split_row_groups_every = _timedelta_to_physical(
+                    split_row_groups_every, dtype
+                )
+
         target = _to_sink_target(path)
         sink_options = {
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "staged_commit": staged_commit,
         }
 
         if isinstance(metadata, dict):
@@ -2803,6 +3550,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             sink_options=sink_options,
             metadata=metadata,
             field_overwrites=field_overwrites_dicts,
+            row_group_bytes=row_group_bytes,
+            split_row_groups_on=split_row_groups_on,
+            split_row_groups_every=split_row_groups_every,
         )
 
         if not lazy:
@@ -2827,6 +3577,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -2847,6 +3598,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -2866,6 +3618,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -2929,6 +3682,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        staged_commit: bool
+            Write local files to a hidden staging file next to the target, and only
+            rename it to the target once it is complete. If the query fails, the
+            staging file is removed and an existing file at the target is left as is.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
@@ -2989,6 +3750,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "staged_commit": staged_commit,
         }
 
         if compat_level is None:
@@ -3016,6 +3778,80 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf)
 
+    @unstable()
+    def sink_shared_memory(
+        self,
+        name: str | None = None,
+        *,
+        compat_level: CompatLevel | None = None,
+        maintain_order: bool = True,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> SharedMemoryFrame:
+        """
+        Evaluate the query into a named shared-memory segment.
+
+        The result is written directly into the segment, so that other processes
+        can map it without a copy being made in this process first.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            The name of the segment. Defaults to a new unique name.
+        compat_level
+            Use a specific compatibility level
+            when exporting Polars' internal data structures.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+        optimizations
+            The optimization passes done during query optimization.
+
+        Returns
+        -------
+        SharedMemoryFrame
+            A handle to the segment, which can be pickled and read in other
+            processes without copying the data.
+
+        See Also
+        --------
+        DataFrame.write_shared_memory
+
+        Examples
+        --------
+        >>> lf = pl.scan_parquet("/path/to/my_file.parquet")  # doctest: +SKIP
+        >>> handle = lf.sink_shared_memory()  # doctest: +SKIP
+        >>> handle.read()  # doctest: +SKIP
+        """
+        from polars.io.shared_memory import _new_shared_memory_frame
+
+        handle = _new_shared_memory_frame(name)
+        try:
+            self.sink_ipc(
+                handle.path,
+                compression="uncompressed",
+                compat_level=compat_level,
+                maintain_order=maintain_order,
+                engine=engine,
+                optimizations=optimizations,
+            )
+        except BaseException:
+            handle.path.unlink(missing_ok=True)
+            raise
+        return handle
+
     @overload
     def sink_csv(
         self,
@@ -3043,6 +3879,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3075,6 +3912,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3106,6 +3944,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3217,6 +4056,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        staged_commit: bool
+            Write local files to a hidden staging file next to the target, and only
+            rename it to the target once it is complete. If the query fails, the
+            staging file is removed and an existing file at the target is left as is.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed at any
                 point without it being considered a breaking change.
@@ -3280,6 +4127,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "staged_commit": staged_commit,
         }
 
         ldf = self._ldf.sink_csv(
@@ -3324,6 +4172,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
+        json_schema: str | Path | None = None,
+        schema_registry: dict[str, Any] | None = None,
         lazy: Literal[False] = ...,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3342,6 +4193,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
+        json_schema: str | Path | None = None,
+        schema_registry: dict[str, Any] | None = None,
         lazy: Literal[True],
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3359,6 +4213,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         retries: int = 2,
         sync_on_close: SyncOnCloseMethod | None = None,
         mkdir: bool = False,
+        staged_commit: bool = False,
+        json_schema: str | Path | None = None,
+        schema_registry: dict[str, Any] | None = None,
         lazy: bool = False,
         engine: EngineType = "auto",
         optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
@@ -3416,6 +4273,34 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         mkdir: bool
             Recursively create all the directories in the path.
 
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        staged_commit: bool
+            Write local files to a hidden staging file next to the target, and only
+            rename it to the target once it is complete. If the query fails, the
+            staging file is removed and an existing file at the target is left as is.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        json_schema
+            Write a JSON Schema of the rows to this path after the rows have been
+            written, see :meth:`Schema.to_json_schema`. Not supported with
+            `lazy=True`. The rows can be validated against it when they are read
+            back with the `json_schema` parameter of :func:`scan_ndjson`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        schema_registry
+            Validate the JSON Schema of the rows, see :meth:`Schema.to_json_schema`,
+            against a schema registry with a Confluent compatible REST API before
+            writing, and register it once the rows are written. Fails before anything
+            is written if the registry considers the schema incompatible with the
+            latest version of the subject. See :meth:`DataFrame.write_avro` for the
+            accepted keys. Not supported with `lazy=True`.
+
             .. warning::
                 This functionality is considered **unstable**. It may be changed
                 at any point without it being considered a breaking change.
@@ -3468,11 +4353,28 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             # Handle empty dict input
             storage_options = None
 
+        if json_schema is not None and lazy:
+            msg = "`json_schema` is not supported with `lazy=True`"
+            raise ValueError(msg)
+
+        if schema_registry is not None:
+            if lazy:
+                msg = "`schema_registry` is not supported with `lazy=True`"
+                raise ValueError(msg)
+
+            import json
+
+            from polars.io._schema_registry import validate_schema_registry
+
+            registry_schema = json.dumps(self.collect_schema().to_json_schema())
+            validate_schema_registry(schema_registry, registry_schema, "json")
+
         target = _to_sink_target(path)
         sink_options = {
             "sync_on_close": sync_on_close or "none",
             "maintain_order": maintain_order,
             "mkdir": mkdir,
+            "staged_commit": staged_commit,
         }
 
         ldf = self._ldf.sink_json(
@@ -3487,6 +4389,22 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             ldf = ldf.with_optimizations(optimizations._pyoptflags)
             ldf = LazyFrame._from_pyldf(ldf)
             ldf.collect(engine=engine)
+
+            if json_schema is not None:
+                import json
+
+                # Only written once the rows are, so that it never describes a
+                # file that failed to be written.
+                json_schema = Path(normalize_filepath(json_schema))
+                if mkdir:
+                    json_schema.parent.mkdir(parents=True, exist_ok=True)
+                with json_schema.open("w") as f:
+                    json.dump(self.collect_schema().to_json_schema(), f, indent=2)
+
+            if schema_registry is not None:
+                from polars.io._schema_registry import register_schema_registry
+
+                register_schema_registry(schema_registry, registry_schema, "json")
             return None
         return LazyFrame._from_pyldf(ldf)
 
@@ -3521,6 +4439,51 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self.head(n_rows).collect(**kwargs)
 
+    @unstable()
+    def peek(self, n: int = 5) -> DataFrame:
+        """
+        Run the query only until its first `n` rows are produced, and return them.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is meant to cheaply explore large queries. The query runs on the
+        streaming engine, of which the operators stop as soon as enough rows are
+        produced. A group-by only computes the final aggregates of as many groups as
+        needed, which are not necessarily the first groups in the order of
+        appearance. Operators that need all of their input, such as a sort or the
+        aggregation of a group-by, still consume all of it.
+
+        The result is the same as `lf.head(n).collect(engine="streaming")`.
+
+        Parameters
+        ----------
+        n
+            Number of rows to return.
+
+        See Also
+        --------
+        head
+        collect
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": range(1_000_000)})
+        >>> lf.filter(pl.col("a") % 3 == 0).peek(3)
+        shape: (3, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 0   │
+        │ 3   │
+        │ 6   │
+        └─────┘
+        """
+        return wrap_df(self._ldf.peek(n))
+
     def lazy(self) -> LazyFrame:
         """
         Return lazy representation, i.e. itself.
@@ -3554,6 +4517,45 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self._from_pyldf(self._ldf.cache())
 
+    @unstable()
+    def cache_hint(self, hint: CacheHint) -> LazyFrame:
+        """
+        Restrict how the optimizer caches this LazyFrame if it occurs more than once.
+
+        By default, common subplan elimination only caches the duplicates of a query
+        that are more expensive to recompute than to keep in memory.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        hint : {'always', 'never'}
+            * 'always': Cache this LazyFrame if it occurs more than once, regardless
+              of its cost.
+            * 'never': Never cache this LazyFrame or any part of it.
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3]}).cache_hint("always")
+        >>> pl.concat([lf, lf]).collect()
+        shape: (6, 1)
+        ┌─────┐
+        │ a   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ 3   │
+        │ 1   │
+        │ 2   │
+        │ 3   │
+        └─────┘
+        """
+        return self._from_pyldf(self._ldf.cache_hint(hint))
+
     def cast(
         self,
         dtypes: (
@@ -4272,6 +5274,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         *by: IntoExpr | Iterable[IntoExpr],
         maintain_order: bool = False,
+        expand_categories: bool = False,
         **named_by: IntoExpr,
     ) -> LazyGroupBy:
         """
@@ -4287,6 +5290,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             This is slower than a default group by.
             Setting this to `True` blocks the possibility
             to run on the streaming engine.
+        expand_categories
+            Also create a group for every category of `Enum` keys that does not occur
+            in the data, similar to `observed=False` in pandas. Aggregations over these
+            groups see no rows. With multiple keys, a group is created for every
+            combination of the categories and the observed values of the other keys.
+            `Categorical` keys only contribute their observed values, as their
+            categories are shared with unrelated data. Setting this to `True` blocks the
+            possibility to run on the streaming engine.
         **named_by
             Additional columns to group by, specified as keyword arguments.
             The columns will be renamed to the keyword used.
@@ -4361,6 +5372,29 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ b   ┆ 1   ┆ 3.0 │
         │ c   ┆ 1   ┆ 1.0 │
         └─────┴─────┴─────┘
+
+        Set `expand_categories=True` to also create groups for the categories that
+        do not occur in the data. These come after the observed groups.
+
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "size": pl.Series(["S", "L", "S"], dtype=pl.Enum(["S", "M", "L"])),
+        ...         "n": [1, 2, 3],
+        ...     }
+        ... )
+        >>> lf.group_by("size", maintain_order=True, expand_categories=True).agg(
+        ...     pl.col("n").sum()
+        ... ).collect()
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ size ┆ n   │
+        │ ---  ┆ --- │
+        │ enum ┆ i64 │
+        ╞══════╪═════╡
+        │ S    ┆ 4   │
+        │ L    ┆ 2   │
+        │ M    ┆ 0   │
+        └──────┴─────┘
         """
         for value in named_by.values():
             if not isinstance(value, (str, pl.Expr, pl.Series)):
@@ -4373,7 +5407,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 )
                 raise TypeError(msg)
         exprs = parse_into_list_of_expressions(*by, **named_by)
-        lgb = self._ldf.group_by(exprs, maintain_order)
+        lgb = self._ldf.group_by(exprs, maintain_order, expand_categories)
         return LazyGroupBy(lgb)
 
     @deprecate_renamed_parameter("by", "group_by", version="0.20.14")
@@ -4528,6 +5562,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         label: Label = "left",
         group_by: IntoExpr | Iterable[IntoExpr] | None = None,
         start_by: StartBy = "window",
+        calendar: TradingCalendar | None = None,
     ) -> LazyGroupBy:
         """
         Group based on a time value (or index value of type Int32, Int64).
@@ -4601,6 +5636,21 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
               The resulting window is then shifted back until the earliest datapoint
               is in or in front of it.
+        calendar
+            Align the windows to the sessions of this :class:`TradingCalendar`
+            instead of to the clock, in which case `start_by` has no effect:
+
+            * If `every` is a number of days, like `'1d'`, each window spans that
+              many sessions (skipping holidays and weekends), from the open of the
+              first to the close of the last. The same goes for `period`.
+            * Otherwise each session is split in windows of `every` starting at the
+              open (shifted by `offset`), of which the last one ends at the close.
+
+            Rows outside of the sessions are not in any window.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Returns
         -------
@@ -4837,6 +5887,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         offset = parse_as_duration_string(offset)
         every = parse_as_duration_string(every)
 
+        if calendar is not None:
+            issue_unstable_warning("`calendar` is considered unstable.")
+
         pyexprs_by = (
             parse_into_list_of_expressions(group_by) if group_by is not None else []
         )
@@ -4850,6 +5903,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             closed,
             pyexprs_by,
             start_by,
+            calendar._to_tuple() if calendar is not None else None,
         )
         return LazyGroupBy(lgb)
 
@@ -5236,8 +6290,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         suffix: str = "_right",
         validate: JoinValidation = "m:m",
         nulls_equal: bool = False,
+        nans_equal: bool | None = None,
         coalesce: bool | None = None,
         maintain_order: MaintainOrderJoin | None = None,
+        indicator: bool | str = False,
         allow_parallel: bool = True,
         force_parallel: bool = False,
     ) -> LazyFrame:
@@ -5274,7 +6330,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                    table.
                * - **anti**
                  - Returns rows from the left table that have no match in the right
-                   table.
+                   table. Duplicate rows of the left table are all kept, like the
+                   `"left_only"` rows of a full join with `indicator=True`.
 
         left_on
             Join column of the left DataFrame.
@@ -5302,6 +6359,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 This is currently not supported by the streaming engine.
         nulls_equal
             Join on null values. By default null values will never produce matches.
+        nans_equal
+            Whether NaN keys match each other. If False, NaN keys never produce
+            matches, while the joined columns keep their NaN values; this cannot be
+            combined with `nulls_equal=True`. If None (default), NaN keys match unless
+            `Config.set_join_nans_equal` says otherwise. This only applies to the
+            join keys; `group_by` and `unique` always treat NaN values as equal.
         coalesce
             Coalescing behavior (merging of join columns).
 
@@ -5340,6 +6403,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                * - **right_left**
                  - First preserves the order of the right DataFrame, then the left.
 
+        indicator
+            Add a `Categorical` column that tells whether each row has a match in the
+            left table (`"left_only"`), in the right table (`"right_only"`) or in both
+            (`"both"`), like `pandas.merge(indicator=True)`. The column is named
+            `"_merge"`, or the given string.
         allow_parallel
             Allow the physical plan to optionally evaluate the computation of both
             DataFrames up to the join in parallel.
@@ -5419,6 +6487,19 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 3   ┆ 8.0 ┆ c   │
         └─────┴─────┴─────┘
 
+        >>> lf.join(other_lf, on="ham", how="full", indicator=True).collect()
+        shape: (4, 6)
+        ┌──────┬──────┬──────┬───────┬───────────┬────────────┐
+        │ foo  ┆ bar  ┆ ham  ┆ apple ┆ ham_right ┆ _merge     │
+        │ ---  ┆ ---  ┆ ---  ┆ ---   ┆ ---       ┆ ---        │
+        │ i64  ┆ f64  ┆ str  ┆ str   ┆ str       ┆ cat        │
+        ╞══════╪══════╪══════╪═══════╪═══════════╪════════════╡
+        │ 1    ┆ 6.0  ┆ a    ┆ x     ┆ a         ┆ both       │
+        │ 2    ┆ 7.0  ┆ b    ┆ y     ┆ b         ┆ both       │
+        │ null ┆ null ┆ null ┆ z     ┆ d         ┆ right_only │
+        │ 3    ┆ 8.0  ┆ c    ┆ null  ┆ null      ┆ left_only  │
+        └──────┴──────┴──────┴───────┴───────────┴────────────┘
+
         >>> lf.join(other_lf, how="cross").collect()
         shape: (9, 5)
         ┌─────┬─────┬─────┬───────┬───────────┐
@@ -5441,6 +6522,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         if maintain_order is None:
             maintain_order = "none"
+        if indicator is True:
+            indicator_name: str | None = "_merge"
+        else:
+            indicator_name = indicator or None
 
         uses_on = on is not None
         uses_left_on = left_on is not None
@@ -5482,6 +6567,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                     suffix,
                     validate,
                     maintain_order,
+                    None,
+                    indicator_name,
+                    nans_equal,
                 )
             )
 
@@ -5509,6 +6597,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 validate,
                 maintain_order,
                 coalesce,
+                indicator_name,
+                nans_equal,
             )
         )
 
@@ -5518,6 +6608,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         other: LazyFrame,
         *predicates: Expr | Iterable[Expr],
         suffix: str = "_right",
+        maintain_order: MaintainOrderJoin | None = None,
     ) -> LazyFrame:
         """
         Perform a join based on one or multiple (in)equality predicates.
@@ -5527,7 +6618,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         multiple times in the result.
 
         .. note::
-            The row order of the input DataFrames is not preserved.
+            The row order of the input DataFrames is not preserved, unless
+            `maintain_order` is set.
 
         .. warning::
             This functionality is experimental. It may be
@@ -5543,6 +6635,25 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             be applied in the predicate.
         suffix
             Suffix to append to columns with a duplicate name.
+        maintain_order : {'none', 'left', 'right', 'left_right', 'right_left'}
+            Which DataFrame row order to preserve, if any.
+            Preserving an order prevents the use of the specialized inequality join,
+            which can be considerably slower.
+
+            .. list-table ::
+               :header-rows: 0
+
+               * - **none**
+                 - *(Default)* No specific ordering is desired. The ordering might
+                   differ across Polars versions or even between different runs.
+               * - **left**
+                 - Preserves the order of the left DataFrame.
+               * - **right**
+                 - Preserves the order of the right DataFrame.
+               * - **left_right**
+                 - First preserves the order of the left DataFrame, then the right.
+               * - **right_left**
+                 - First preserves the order of the right DataFrame, then the left.
 
         Examples
         --------
@@ -5605,36 +6716,162 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         require_same_type(self, other)
 
         pyexprs = parse_into_list_of_expressions(*predicates)
+        if maintain_order is None:
+            maintain_order = "none"
 
         return self._from_pyldf(
             self._ldf.join_where(
                 other._ldf,
                 pyexprs,
                 suffix,
+                maintain_order,
             )
         )
 
-    def with_columns(
+    def join_intervals(
         self,
-        *exprs: IntoExpr | Iterable[IntoExpr],
-        **named_exprs: IntoExpr,
+        other: LazyFrame,
+        *,
+        on: Sequence[str | Expr] | None = None,
+        left_on: Sequence[str | Expr] | None = None,
+        right_on: Sequence[str | Expr] | None = None,
+        mode: IntervalJoinMode = "overlap",
+        min_overlap: int | float | timedelta | None = None,
+        suffix: str = "_right",
     ) -> LazyFrame:
         """
-        Add columns to this LazyFrame.
+        Join the rows of which the `(start, end)` intervals overlap.
 
-        Added columns will replace existing columns with the same name.
+        Every interval is treated as half-open, so it contains its start but not its
+        end. This performs an inner join on the inequalities between the bounds of
+        the intervals, which is faster than a cross join followed by a filter. Rows
+        with a null bound are not matched.
+
+        .. note::
+            The row order of the input LazyFrames is not preserved.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
 
         Parameters
         ----------
-        *exprs
-            Column(s) to add, specified as positional arguments.
-            Accepts expression input. Strings are parsed as column names, other
-            non-expression inputs are parsed as literals.
-        **named_exprs
-            Additional columns to add, specified as keyword arguments.
-            The columns will be renamed to the keyword used.
-
-        Returns
+        other
+            LazyFrame to join with.
+        on
+            The start and end of the intervals of both tables, as column names or
+            expressions.
+        left_on
+            The start and end of the intervals of the left table.
+        right_on
+            The start and end of the intervals of the right table.
+        mode : {'overlap', 'contains', 'within'}
+            When two intervals match.
+
+            * 'overlap': The intervals have an overlap of nonzero length.
+            * 'contains': The left interval contains the right interval.
+            * 'within': The left interval lies within the right interval.
+        min_overlap
+            With `mode='overlap'`, the minimum length of the overlap, e.g. a
+            `timedelta` for temporal intervals. Intervals that only touch match if
+            this is zero.
+        suffix
+            Suffix to append to columns with a duplicate name.
+
+        See Also
+        --------
+        join_where
+
+        Examples
+        --------
+        >>> from datetime import datetime, timedelta
+        >>> meetings = pl.LazyFrame(
+        ...     {
+        ...         "meeting": ["standup", "review"],
+        ...         "start": [datetime(2025, 1, 6, 9), datetime(2025, 1, 6, 14)],
+        ...         "end": [datetime(2025, 1, 6, 10), datetime(2025, 1, 6, 16)],
+        ...     }
+        ... )
+        >>> absences = pl.LazyFrame(
+        ...     {
+        ...         "person": ["ann", "bob"],
+        ...         "start": [datetime(2025, 1, 6, 8), datetime(2025, 1, 6, 15)],
+        ...         "end": [datetime(2025, 1, 6, 9, 30), datetime(2025, 1, 6, 18)],
+        ...     }
+        ... )
+        >>> meetings.join_intervals(absences, on=["start", "end"]).select(
+        ...     "meeting", "person"
+        ... ).sort("meeting").collect()
+        shape: (2, 2)
+        ┌─────────┬────────┐
+        │ meeting ┆ person │
+        │ ---     ┆ ---    │
+        │ str     ┆ str    │
+        ╞═════════╪════════╡
+        │ review  ┆ bob    │
+        │ standup ┆ ann    │
+        └─────────┴────────┘
+
+        Only match absences that overlap a meeting by at least an hour.
+
+        >>> meetings.join_intervals(
+        ...     absences, on=["start", "end"], min_overlap=timedelta(hours=1)
+        ... ).select("meeting", "person").collect()
+        shape: (1, 2)
+        ┌─────────┬────────┐
+        │ meeting ┆ person │
+        │ ---     ┆ ---    │
+        │ str     ┆ str    │
+        ╞═════════╪════════╡
+        │ review  ┆ bob    │
+        └─────────┴────────┘
+        """
+        require_same_type(self, other)
+
+        if on is not None:
+            if left_on is not None or right_on is not None:
+                msg = "cannot use `on` together with `left_on` or `right_on`"
+                raise ValueError(msg)
+            left_on = right_on = on
+        elif left_on is None or right_on is None:
+            msg = "either `on` or both `left_on` and `right_on` must be given"
+            raise ValueError(msg)
+        if len(left_on) != 2 or len(right_on) != 2:
+            msg = "the intervals must be given by their start and end"
+            raise ValueError(msg)
+
+        return self._from_pyldf(
+            self._ldf.join_intervals(
+                other._ldf,
+                [parse_into_expression(e) for e in left_on],
+                [parse_into_expression(e) for e in right_on],
+                mode,
+                None if min_overlap is None else F.lit(min_overlap)._pyexpr,
+                suffix,
+            )
+        )
+
+    def with_columns(
+        self,
+        *exprs: IntoExpr | Iterable[IntoExpr],
+        **named_exprs: IntoExpr,
+    ) -> LazyFrame:
+        """
+        Add columns to this LazyFrame.
+
+        Added columns will replace existing columns with the same name.
+
+        Parameters
+        ----------
+        *exprs
+            Column(s) to add, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_exprs
+            Additional columns to add, specified as keyword arguments.
+            The columns will be renamed to the keyword used.
+
+        Returns
         -------
         LazyFrame
             A new LazyFrame with the columns added.
@@ -5644,6 +6881,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         Creating a new LazyFrame using this method does not create a new copy of
         existing data.
 
+        Expressions may refer to columns that are created by other expressions in the
+        same call, as long as the input has no column with that name. Such expressions
+        are evaluated after the expressions they depend on.
+
         Examples
         --------
         Pass an expression to add it as a new column.
@@ -5779,6 +7020,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         See Also
         --------
         with_columns
+
+        Notes
+        -----
+        As with :meth:`with_columns`, expressions may refer to columns that are created
+        by other expressions in the same call.
         """
         structify = bool(int(os.environ.get("POLARS_AUTO_STRUCTIFY", 0)))
 
@@ -5787,6 +7033,104 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         )
         return self._from_pyldf(self._ldf.with_columns_seq(pyexprs))
 
+    @unstable()
+    def update_where(
+        self,
+        predicate: IntoExpr,
+        *assignments: IntoExpr | Iterable[IntoExpr],
+        **named_assignments: IntoExpr,
+    ) -> LazyFrame:
+        """
+        Update the values of the rows where the predicate is true.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Every assignment replaces the values of the column with its output name in the
+        selected rows, and the other rows keep their values. This is a shorthand for
+        `pl.when(predicate).then(assignment).otherwise(pl.col(name))` for every
+        assignment, which does not copy the chunks that the predicate does not select.
+
+        Parameters
+        ----------
+        predicate
+            Boolean expression that selects the rows to update.
+        *assignments
+            Values of the columns to update, specified as positional arguments.
+            Accepts expression input. Strings are parsed as column names, other
+            non-expression inputs are parsed as literals.
+        **named_assignments
+            Values of the columns to update, specified as keyword arguments. The
+            keyword is the name of the column to update.
+
+        See Also
+        --------
+        with_columns
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "z", "w"]})
+        >>> lf.update_where(pl.col("a") > 2, pl.col("a") * 10, b="big").collect()
+        shape: (4, 2)
+        ┌─────┬─────┐
+        │ a   ┆ b   │
+        │ --- ┆ --- │
+        │ i64 ┆ str │
+        ╞═════╪═════╡
+        │ 1   ┆ x   │
+        │ 2   ┆ y   │
+        │ 30  ┆ big │
+        │ 40  ┆ big │
+        └─────┴─────┘
+        """
+        mask = parse_into_expression(predicate)
+        pyexprs = parse_into_list_of_expressions(*assignments, **named_assignments)
+        return self._from_pyldf(self._ldf.update_where(mask, pyexprs))
+
+    def with_feature_set(self, feature_set: FeatureSet) -> LazyFrame:
+        """
+        Add the features of a feature set as columns.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The features that do not refer to each other are evaluated together in one
+        :meth:`with_columns`, after the features they refer to, so the new columns
+        are in the order in which they are evaluated.
+
+        Parameters
+        ----------
+        feature_set
+            The :class:`FeatureSet` to add.
+
+        Raises
+        ------
+        ColumnNotFoundError
+            If a column that the features read is not in the frame.
+        SchemaError
+            If a feature does not evaluate to the dtype it declares.
+
+        Examples
+        --------
+        >>> fs = pl.FeatureSet(
+        ...     "scores",
+        ...     {"z": (pl.col("x") - pl.col("mean")) / 2, "mean": pl.col("x").mean()},
+        ... )
+        >>> pl.LazyFrame({"x": [1.0, 3.0]}).with_feature_set(fs).collect()
+        shape: (2, 3)
+        ┌─────┬──────┬──────┐
+        │ x   ┆ mean ┆ z    │
+        │ --- ┆ ---  ┆ ---  │
+        │ f64 ┆ f64  ┆ f64  │
+        ╞═════╪══════╪══════╡
+        │ 1.0 ┆ 2.0  ┆ -0.5 │
+        │ 3.0 ┆ 2.0  ┆ 0.5  │
+        └─────┴──────┴──────┘
+        """
+        return self._from_pyldf(self._ldf.with_feature_set(feature_set._fs))
+
     @deprecated(
         "`LazyFrame.with_context` is deprecated; "
         "use `pl.concat(..., how='horizontal')` instead."
@@ -5853,6 +7197,40 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         return self._from_pyldf(self._ldf.with_context([lf._ldf for lf in other]))
 
+    @unstable()
+    def to_scalar_expr(self) -> Expr:
+        """
+        Use the value of this LazyFrame as an expression in `with_columns`.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The LazyFrame must produce a single column with at most one row, of which
+        the value is broadcast to the height of the frame; no rows give a null. The
+        LazyFrame becomes part of the query it is used in, so it runs once per
+        collect of that query.
+
+        Examples
+        --------
+        Normalize by a total that is computed from another table:
+
+        >>> sales = pl.LazyFrame({"region": ["eu", "us"], "amount": [30, 70]})
+        >>> targets = pl.LazyFrame({"target": [80, 120]})
+        >>> total = targets.select(pl.col("target").sum()).to_scalar_expr()
+        >>> sales.with_columns(share=pl.col("amount") / total).collect()
+        shape: (2, 3)
+        ┌────────┬────────┬───────┐
+        │ region ┆ amount ┆ share │
+        │ ---    ┆ ---    ┆ ---   │
+        │ str    ┆ i64    ┆ f64   │
+        ╞════════╪════════╪═══════╡
+        │ eu     ┆ 30     ┆ 0.15  │
+        │ us     ┆ 70     ┆ 0.35  │
+        └────────┴────────┴───────┘
+        """
+        return wrap_expr(self._ldf.into_scalar_expr())
+
     def drop(
         self,
         *columns: ColumnNameOrSelector | Iterable[ColumnNameOrSelector],
@@ -6096,6 +7474,74 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         n = parse_into_expression(n)
         return self._from_pyldf(self._ldf.shift(n, fill_value))
 
+    @unstable()
+    def shift_by(self, period: str | timedelta, *, on: str) -> LazyFrame:
+        """
+        Shift the values of all columns forward by a length of time.
+
+        Every row gets the values that the other columns had `period` before the time
+        in the `on` column, or null if there is no row that early.
+
+        Parameters
+        ----------
+        period
+            Length of time to shift values forward by, as a duration string (e.g.
+            `"1h"` or `"1mo"`, see `Expr.dt.offset_by`) or a timedelta. If it is
+            negative, the values come from after the row instead.
+        on
+            Name of a Date or Datetime column, which must be sorted in ascending order.
+            It is not shifted itself.
+
+        Notes
+        -----
+        This is the equivalent of `shift(freq=...)` in pandas followed by aligning the
+        result to the original index. The row where the values come from is found
+        with a binary search in `on`, so no join is needed. As with a backward
+        `join_asof`, it is the last row at or before `t - period`, so a gap in the time
+        series repeats the last value before it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        See Also
+        --------
+        shift
+        join_asof
+
+        Examples
+        --------
+        >>> from datetime import datetime
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "ts": [
+        ...             datetime(2024, 1, 1, 0),
+        ...             datetime(2024, 1, 1, 1),
+        ...             datetime(2024, 1, 1, 3),
+        ...         ],
+        ...         "a": [1, 2, 3],
+        ...     }
+        ... )
+        >>> lf.shift_by("1h", on="ts").collect()
+        shape: (3, 2)
+        ┌─────────────────────┬──────┐
+        │ ts                  ┆ a    │
+        │ ---                 ┆ ---  │
+        │ datetime[μs]        ┆ i64  │
+        ╞═════════════════════╪══════╡
+        │ 2024-01-01 00:00:00 ┆ null │
+        │ 2024-01-01 01:00:00 ┆ 1    │
+        │ 2024-01-01 03:00:00 ┆ 2    │
+        └─────────────────────┴──────┘
+        """
+        ts = F.col(on)
+        period = parse_as_duration_string(period)
+        start = ts.dt.offset_by(negate_duration_string(period))
+        # The number of rows at or before the start, of which the last one is taken.
+        n_before = ts.search_sorted(start, side="right").cast(Int64)
+        index = F.when(ts.is_not_null() & (n_before > 0)).then(n_before - 1)
+        return self.with_columns(F.exclude(on).gather(index))
+
     def slice(self, offset: int, length: int | None = None) -> LazyFrame:
         """
         Get a slice of this DataFrame.
@@ -6345,7 +7791,176 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         """
         return self.select(F.all().approx_n_unique())
 
-    def with_row_index(self, name: str = "index", offset: int = 0) -> LazyFrame:
+    @unstable()
+    def split(
+        self,
+        fractions: Sequence[float],
+        *,
+        stratify_by: str | Sequence[str] | None = None,
+        shuffle: bool = True,
+        seed: int | None = None,
+    ) -> list[LazyFrame]:
+        """
+        Split the rows into disjoint frames, e.g. a train and test set.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The rows are assigned to a split in a single pass, which is cached and shared
+        by all returned frames.
+
+        Parameters
+        ----------
+        fractions
+            The fraction of rows that goes to every split. Must sum to 1.
+        stratify_by
+            Column(s) by which to stratify, so that every split has the same
+            proportions of the values of these columns.
+        shuffle
+            Shuffle the rows before splitting. If `False`, the first rows (of every
+            stratum) go to the first split.
+        seed
+            Seed that determines the shuffling of the rows. If set to None (default),
+            a random seed is drawn once, so the returned frames are always disjoint.
+
+        See Also
+        --------
+        k_fold
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"x": range(10), "y": [0, 1] * 5})
+        >>> train, test = lf.split([0.8, 0.2], stratify_by="y", seed=42)
+        >>> test.collect().height
+        2
+        >>> test.collect()["y"].sort().to_list()
+        [0, 1]
+        """
+        if isinstance(fractions, (int, float)) or len(fractions) < 2:
+            msg = f"`fractions` must contain at least two fractions, got {fractions!r}"
+            raise ValueError(msg)
+        if any(f <= 0 for f in fractions) or abs(sum(fractions) - 1) > 1e-9:
+            msg = f"`fractions` must be positive and sum to 1, got {fractions!r}"
+            raise ValueError(msg)
+
+        position = F.col(_SPLIT_COLUMN).rank("ordinal") - 1
+        n = F.len()
+        if stratify_by is not None:
+            position = position.over(stratify_by)
+            n = n.over(stratify_by)
+
+        # Round away the floating point error of the cumulative sum.
+        boundaries = [round(b, 12) for b in accumulate(fractions)][:-1]
+        fraction = position / n
+        assignment = F.sum_horizontal([fraction >= b for b in boundaries])
+
+        lf = self._with_split_assignment(shuffle, seed, assignment)
+        split = F.col(_SPLIT_COLUMN)
+        return [
+            lf.filter(split == i).drop(_SPLIT_COLUMN) for i in range(len(fractions))
+        ]
+
+    @unstable()
+    def k_fold(
+        self,
+        k: int,
+        *,
+        group_by: str | Sequence[str] | None = None,
+        shuffle: bool = True,
+        seed: int | None = None,
+    ) -> list[tuple[LazyFrame, LazyFrame]]:
+        """
+        Split the rows into `k` folds for cross-validation.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The rows are assigned to a fold in a single pass, which is cached and shared
+        by all returned frames.
+
+        Parameters
+        ----------
+        k
+            Number of folds.
+        group_by
+            Column(s) of which the rows with equal values are always assigned to the
+            same fold, e.g. all measurements of the same patient.
+        shuffle
+            Shuffle the rows (or groups) before assigning them to the folds. If
+            `False`, the folds are assigned round-robin in the order of the rows.
+        seed
+            Seed that determines the shuffling of the rows. If set to None (default),
+            a random seed is drawn once, so the returned folds are always disjoint.
+
+        Returns
+        -------
+        list of tuple of LazyFrame
+            For every fold a `(train, test)` pair, where `test` holds the rows of
+            the fold and `train` all other rows.
+
+        See Also
+        --------
+        split
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame({"x": range(6)})
+        >>> folds = lf.k_fold(3, shuffle=False)
+        >>> [test.collect()["x"].to_list() for _, test in folds]
+        [[0, 3], [1, 4], [2, 5]]
+        """
+        if k < 2:
+            msg = f"`k` must be at least 2, got {k}"
+            raise ValueError(msg)
+
+        if group_by is None:
+            position = F.col(_SPLIT_COLUMN).rank("ordinal") - 1
+        else:
+            # Number the groups by their first row, in the (shuffled) row order.
+            position = F.col(_SPLIT_COLUMN).min().over(group_by).rank("dense") - 1
+
+        lf = self._with_split_assignment(shuffle, seed, position % k)
+        fold = F.col(_SPLIT_COLUMN)
+        return [
+            (
+                lf.filter(fold != i).drop(_SPLIT_COLUMN),
+                lf.filter(fold == i).drop(_SPLIT_COLUMN),
+            )
+            for i in range(k)
+        ]
+
+    def _with_split_assignment(
+        self, shuffle: bool, seed: int | None, assignment: Expr
+    ) -> LazyFrame:
+        """
+        Add the split or fold of every row in a cached column.
+
+        `assignment` is computed from a temporary sort key in the same column, which is
+        the row index, or a hash of it if `shuffle` is set.
+        """
+        key = F.int_range(F.len(), dtype=UInt64)
+        if shuffle:
+            if seed is None:
+                import random
+
+                seed = random.getrandbits(63)
+            key = key.hash(seed)
+        return (
+            self.with_columns(key.alias(_SPLIT_COLUMN))
+            .with_columns(assignment.alias(_SPLIT_COLUMN))
+            .cache()
+        )
+
+    def with_row_index(
+        self,
+        name: str = "index",
+        offset: int = 0,
+        *,
+        step: int = 1,
+        dtype: PolarsIntegerType | None = None,
+    ) -> LazyFrame:
         """
         Add a row index as the first column in the LazyFrame.
 
@@ -6354,7 +7969,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         name
             Name of the index column.
         offset
-            Start the index at this offset. Cannot be negative.
+            Start the index at this offset. Cannot be negative, unless `dtype` is
+            `Int64`.
+        step
+            Increment the index by this value for every row. Can be negative, but
+            not zero.
+        dtype
+            Data type of the index column, either `Int64` or `UInt64`. Setting
+            this, or a `step` other than `1`, creates a 64-bit index regardless of
+            the index size of the Polars build. Defaults to `Int64` if only `step`
+            is given.
 
         Warnings
         --------
@@ -6364,7 +7988,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         Notes
         -----
         The resulting column does not have any special properties. It is a regular
-        column of type `UInt32` (or `UInt64` in `polars-u64-idx`).
+        column of type `UInt32` (or `UInt64` in `polars-u64-idx`), unless `step` or
+        `dtype` is set.
+
+        The index is global, also on the streaming engine: it continues across all
+        morsels and files instead of restarting per partition.
 
         Examples
         --------
@@ -6397,6 +8025,21 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 1002 ┆ 5   ┆ 6   │
         └──────┴─────┴─────┘
 
+        A 64-bit index can start beyond the maximum index value and count in steps,
+        which is useful to generate surrogate keys.
+
+        >>> lf.with_row_index("id", offset=5_000_000_000, step=10).collect()
+        shape: (3, 3)
+        ┌────────────┬─────┬─────┐
+        │ id         ┆ a   ┆ b   │
+        │ ---        ┆ --- ┆ --- │
+        │ i64        ┆ i64 ┆ i64 │
+        ╞════════════╪═════╪═════╡
+        │ 5000000000 ┆ 1   ┆ 2   │
+        │ 5000000010 ┆ 3   ┆ 4   │
+        │ 5000000020 ┆ 5   ┆ 6   │
+        └────────────┴─────┴─────┘
+
         An index column can also be created using the expressions :func:`int_range`
         and :func:`len`.
 
@@ -6415,6 +8058,12 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 2     ┆ 5   ┆ 6   │
         └───────┴─────┴─────┘
         """
+        if step != 1 or dtype is not None:
+            if dtype is None:
+                dtype = Int64
+            return self._from_pyldf(
+                self._ldf.with_row_index_stepped(name, offset, step, dtype)
+            )
         try:
             return self._from_pyldf(self._ldf.with_row_index(name, offset))
         except OverflowError:
@@ -6693,6 +8342,40 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             value = F.lit(value)
         return self._from_pyldf(self._ldf.fill_nan(value._pyexpr))
 
+    def nan_to_null(self) -> LazyFrame:
+        """
+        Replace floating point NaN values with null values.
+
+        This only changes which values are valid, so the float columns are not copied.
+        Operations such as `unique`, `group_by` and `join` then treat the NaN values
+        like any other missing data.
+
+        See Also
+        --------
+        fill_nan
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": [1.5, float("nan"), float("nan")],
+        ...         "b": [1, 2, 3],
+        ...     }
+        ... )
+        >>> lf.nan_to_null().collect()
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ a    ┆ b   │
+        │ ---  ┆ --- │
+        │ f64  ┆ i64 │
+        ╞══════╪═════╡
+        │ 1.5  ┆ 1   │
+        │ null ┆ 2   │
+        │ null ┆ 3   │
+        └──────┴─────┘
+        """
+        return self.fill_nan(None)
+
     def std(self, ddof: int = 1) -> LazyFrame:
         """
         Aggregate the columns in the LazyFrame to their standard deviation value.
@@ -6958,6 +8641,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         columns: ColumnNameOrSelector | Iterable[ColumnNameOrSelector],
         *more_columns: ColumnNameOrSelector,
+        keep_index_as: str | None = None,
     ) -> LazyFrame:
         """
         Explode the DataFrame to long format by exploding the given columns.
@@ -6969,6 +8653,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             columns being exploded must be of the `List` or `Array` data type.
         *more_columns
             Additional names of columns to explode, specified as positional arguments.
+        keep_index_as
+            If set, add a first column with this name holding the index of the
+            original row of every exploded row. This is cheaper than adding a row
+            index before exploding, and can be used to re-aggregate the rows later.
 
         Examples
         --------
@@ -6994,11 +8682,32 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ c       ┆ 7       │
         │ c       ┆ 8       │
         └─────────┴─────────┘
+
+        Keep the index of the original row of every exploded row:
+
+        >>> lf.explode("numbers", keep_index_as="row").collect()
+        shape: (8, 3)
+        ┌─────┬─────────┬─────────┐
+        │ row ┆ letters ┆ numbers │
+        │ --- ┆ ---     ┆ ---     │
+        │ u32 ┆ str     ┆ i64     │
+        ╞═════╪═════════╪═════════╡
+        │ 0   ┆ a       ┆ 1       │
+        │ 1   ┆ a       ┆ 2       │
+        │ 1   ┆ a       ┆ 3       │
+        │ 2   ┆ b       ┆ 4       │
+        │ 2   ┆ b       ┆ 5       │
+        │ 3   ┆ c       ┆ 6       │
+        │ 3   ┆ c       ┆ 7       │
+        │ 3   ┆ c       ┆ 8       │
+        └─────┴─────────┴─────────┘
         """
         subset = parse_list_into_selector(columns) | parse_list_into_selector(  # type: ignore[arg-type]
             more_columns
         )
-        return self._from_pyldf(self._ldf.explode(subset=subset._pyselector))
+        return self._from_pyldf(
+            self._ldf.explode(subset=subset._pyselector, keep_index_as=keep_index_as)
+        )
 
     def unique(
         self,
@@ -7090,6 +8799,55 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             selector_subset = parse_list_into_selector(subset)._pyselector
         return self._from_pyldf(self._ldf.unique(maintain_order, selector_subset, keep))
 
+    def n_unique(
+        self,
+        subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
+    ) -> LazyFrame:
+        """
+        Count the number of unique rows, or the number of unique row-subsets.
+
+        The count is computed with a group-by on the columns, which runs on the
+        streaming engine, without combining them into a struct column first.
+
+        Parameters
+        ----------
+        subset
+            Column name(s) or selector(s) that define what to count; omit to count
+            the unique rows.
+
+        Returns
+        -------
+        LazyFrame
+            A LazyFrame with a single `n_unique` column.
+
+        See Also
+        --------
+        DataFrame.n_unique
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "a": [1, 1, 2, 3],
+        ...         "b": ["x", "x", "x", "y"],
+        ...         "c": [0.5, 1.0, 1.0, 3.0],
+        ...     }
+        ... )
+        >>> lf.n_unique(["a", "b"]).collect()
+        shape: (1, 1)
+        ┌──────────┐
+        │ n_unique │
+        │ ---      │
+        │ u32      │
+        ╞══════════╡
+        │ 3        │
+        └──────────┘
+        """
+        selector_subset: PySelector | None = None
+        if subset is not None:
+            selector_subset = parse_list_into_selector(subset)._pyselector
+        return self._from_pyldf(self._ldf.n_unique(selector_subset))
+
     def drop_nans(
         self,
         subset: ColumnNameOrSelector | Collection[ColumnNameOrSelector] | None = None,
@@ -7263,6 +9021,132 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             selector_subset = parse_list_into_selector(subset)._pyselector
         return self._from_pyldf(self._ldf.drop_nulls(subset=selector_subset))
 
+    @unstable()
+    def pivot(
+        self,
+        on: str,
+        *,
+        index: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
+        values: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
+        aggregate_function: PivotAgg | Expr,
+        maintain_order: bool = False,
+        separator: str = "_",
+    ) -> LazyFrame:
+        """
+        Create a spreadsheet-style pivot table on an `Enum` column.
+
+        The output columns of a pivot are given by the unique values of `on`, which
+        in general are only known once the data is materialized. The categories of an
+        `Enum` are known up front, so a pivot on an `Enum` column can be lazy. Every
+        category gets a column, also if it does not occur in the data.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        on
+            The `Enum` column whose categories will be used as the new columns of the
+            output LazyFrame.
+        index
+            The column(s) that remain from the input to the output. The output
+            LazyFrame will have one row for each unique combination of the `index`'s
+            values. If None, all remaining columns not specified on `on` and `values`
+            will be used. At least one of `index` and `values` must be specified.
+        values
+            The existing column(s) of values which will be moved under the new columns
+            from index. These are the values on which the aggregation will be
+            computed. If None, all remaining columns not specified on `on` and `index`
+            will be used. At least one of `index` and `values` must be specified.
+        aggregate_function
+            Choose from:
+
+            - A predefined aggregate function string, one of
+              {'min', 'max', 'first', 'last', 'sum', 'mean', 'median', 'len'}
+            - An expression to do the aggregation. The expression can only access
+              data from the respective 'values' columns as generated by pivot,
+              through `pl.element()`.
+
+            As in :meth:`DataFrame.pivot`, a cell without any rows is the aggregate of
+            no values, e.g. `0` for `'sum'` and null for `'first'`.
+        maintain_order
+            Ensure the values of `index` are sorted by discovery order. The pivot can
+            only run on the streaming engine if this is not set.
+        separator
+            Used as separator/delimiter in generated column names in case of multiple
+            `values` columns.
+
+        See Also
+        --------
+        DataFrame.pivot
+
+        Examples
+        --------
+        >>> lf = pl.LazyFrame(
+        ...     {
+        ...         "name": ["Cady", "Cady", "Karen"],
+        ...         "subject": ["maths", "physics", "maths"],
+        ...         "score": [98, 99, 61],
+        ...     },
+        ...     schema_overrides={"subject": pl.Enum(["maths", "physics"])},
+        ... )
+        >>> lf.pivot(
+        ...     "subject",
+        ...     index="name",
+        ...     values="score",
+        ...     aggregate_function="first",
+        ...     maintain_order=True,
+        ... ).collect()
+        shape: (2, 3)
+        ┌───────┬───────┬─────────┐
+        │ name  ┆ maths ┆ physics │
+        │ ---   ┆ ---   ┆ ---     │
+        │ str   ┆ i64   ┆ i64     │
+        ╞═══════╪═══════╪═════════╡
+        │ Cady  ┆ 98    ┆ 99      │
+        │ Karen ┆ 61    ┆ null    │
+        └───────┴───────┴─────────┘
+        """
+        if values is not None:
+            values = _expand_selectors(self, values)
+        if index is not None:
+            index = _expand_selectors(self, index)
+
+        if isinstance(aggregate_function, str):
+            if aggregate_function == "first":
+                aggregate_expr = F.element().first()
+            elif aggregate_function == "sum":
+                aggregate_expr = F.element().sum()
+            elif aggregate_function == "max":
+                aggregate_expr = F.element().max()
+            elif aggregate_function == "min":
+                aggregate_expr = F.element().min()
+            elif aggregate_function == "mean":
+                aggregate_expr = F.element().mean()
+            elif aggregate_function == "median":
+                aggregate_expr = F.element().median()
+            elif aggregate_function == "last":
+                aggregate_expr = F.element().last()
+            elif aggregate_function == "len":
+                aggregate_expr = F.len()
+            else:
+                msg = f"invalid input for `aggregate_function` argument: {aggregate_function!r}"
+                raise ValueError(msg)
+        else:
+            aggregate_expr = aggregate_function
+
+        return self._from_pyldf(
+            self._ldf.pivot_enum(
+                on,
+                index,
+                values,
+                aggregate_expr._pyexpr,
+                maintain_order,
+                separator,
+            )
+        )
+
     def unpivot(
         self,
         on: ColumnNameOrSelector | Sequence[ColumnNameOrSelector] | None = None,
@@ -7361,6 +9245,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         schema: None | SchemaDict = None,
         validate_output_schema: bool = True,
         streamable: bool = False,
+        scan_level: bool = False,
     ) -> LazyFrame:
         """
         Apply a custom function.
@@ -7392,6 +9277,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             streaming engine. That means that the function must produce the same result
             when it is executed in batches or when it is be executed on the full
             dataset.
+        scan_level
+            Attach the function directly to the scan this `LazyFrame` was created
+            from. With the streaming engine the function is then called on every
+            batch coming out of the scan, holding the GIL only for the duration of
+            the call, so custom decoding does not require collecting the scan first.
+            Implies `streamable=True`. Raises if this `LazyFrame` is not a scan.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Warnings
         --------
@@ -7431,6 +9326,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ -2      ┆ 199998 │
         └─────────┴────────┘
         """
+        if scan_level:
+            issue_unstable_warning("`scan_level` is considered unstable.")
         if no_optimizations:
             predicate_pushdown = False
             projection_pushdown = False
@@ -7445,6 +9342,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 streamable=streamable,
                 schema=schema,
                 validate_output=validate_output_schema,
+                scan_level=scan_level,
             )
         )
 
@@ -8028,6 +9926,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         | Mapping[str, Literal["upcast", "forbid"]] = "forbid",
         float_cast: Literal["upcast", "forbid"]
         | Mapping[str, Literal["upcast", "forbid"]] = "forbid",
+        cast: Literal["strict", "non-strict", "forbid"]
+        | Mapping[str, Literal["strict", "non-strict", "forbid"]] = "forbid",
     ) -> LazyFrame:
         """
         Match or evolve the schema of a LazyFrame into a specific schema.
@@ -8063,6 +9963,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         float_cast
             Forbid of upcast for float columns from the input to the respective column
             in `schema`.
+        cast
+            Cast columns from the input to any data type of the respective column in
+            `schema`, not just upcast. With `"strict"`, values that cannot be cast
+            raise an error; with `"non-strict"` they become null. This takes
+            precedence over `integer_cast` and `float_cast`.
 
         Examples
         --------
@@ -8167,6 +10072,27 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         │ 2   ┆ 2.0 │
         │ 3   ┆ 3.0 │
         └─────┴─────┘
+
+        Casting columns to arbitrary data types
+
+        >>> (
+        ...     pl.LazyFrame({"a": ["1", "2", "x"], "b": [1, 2, 3]})
+        ...     .match_to_schema(
+        ...         {"a": pl.Int64, "b": pl.String},
+        ...         cast={"a": "non-strict", "b": "strict"},
+        ...     )
+        ...     .collect()
+        ... )
+        shape: (3, 2)
+        ┌──────┬─────┐
+        │ a    ┆ b   │
+        │ ---  ┆ --- │
+        │ i64  ┆ str │
+        ╞══════╪═════╡
+        │ 1    ┆ 1   │
+        │ 2    ┆ 2   │
+        │ null ┆ 3   │
+        └──────┴─────┘
         """
         from polars import Expr
 
@@ -8205,6 +10131,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
                 extra_struct_fields=extra_struct_fields,
                 integer_cast=integer_cast,
                 float_cast=float_cast,
+                cast=cast,
             )
         )
 
@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.wrap import wrap_df
+
+if TYPE_CHECKING:
+    from polars import DataFrame
+    from polars.polars import PyInspectHandle
+
+
+class InspectMetrics:
+    """
+    Metrics of the data that flows through a node of a query.
+
+    This is returned by :meth:`LazyFrame.inspect_metrics`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+
+    def __init__(self, handle: PyInspectHandle) -> None:
+        self._inner = handle
+
+    def report(self) -> DataFrame:
+        """
+        Get the metrics of all data that passed through the node so far.
+
+        The metrics are a single row, with a column for every output of the metric
+        expressions. If no data passed through the node, the metrics are those of an
+        empty frame.
+        """
+        return wrap_df(self._inner.report())
+
+    def reset(self) -> None:
+        """Forget the data that passed through the node so far."""
+        self._inner.reset()
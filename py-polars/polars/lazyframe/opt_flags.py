@@ -41,6 +41,8 @@ class QueryOptFlags:
         comm_subexpr_elim: None | bool = None,
         cluster_with_columns: None | bool = None,
         collapse_joins: None | bool = None,
+        lookup_joins: None | bool = None,
+        fast_statistics: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
     ) -> None:
@@ -54,6 +56,8 @@ class QueryOptFlags:
             comm_subexpr_elim=comm_subexpr_elim,
             cluster_with_columns=cluster_with_columns,
             collapse_joins=collapse_joins,
+            lookup_joins=lookup_joins,
+            fast_statistics=fast_statistics,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
         )
@@ -75,6 +79,8 @@ class QueryOptFlags:
         comm_subexpr_elim: None | bool = None,
         cluster_with_columns: None | bool = None,
         collapse_joins: None | bool = None,
+        lookup_joins: None | bool = None,
+        fast_statistics: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
     ) -> QueryOptFlags:
@@ -90,6 +96,8 @@ class QueryOptFlags:
             comm_subexpr_elim=comm_subexpr_elim,
             cluster_with_columns=cluster_with_columns,
             collapse_joins=collapse_joins,
+            lookup_joins=lookup_joins,
+            fast_statistics=fast_statistics,
             check_order_observe=check_order_observe,
             fast_projection=fast_projection,
         )
@@ -105,6 +113,8 @@ class QueryOptFlags:
         comm_subexpr_elim: None | bool = None,
         cluster_with_columns: None | bool = None,
         collapse_joins: None | bool = None,
+        lookup_joins: None | bool = None,
+        fast_statistics: None | bool = None,
         check_order_observe: None | bool = None,
         fast_projection: None | bool = None,
     ) -> QueryOptFlags:
@@ -125,6 +135,10 @@ class QueryOptFlags:
             self.cluster_with_columns = cluster_with_columns
         if collapse_joins is not None:
             self.collapse_joins = collapse_joins
+        if lookup_joins is not None:
+            self.lookup_joins = lookup_joins
+        if fast_statistics is not None:
+            self.fast_statistics = fast_statistics
         if check_order_observe is not None:
             self.check_order_observe = check_order_observe
         if fast_projection is not None:
@@ -223,6 +237,33 @@ class QueryOptFlags:
     def collapse_joins(self, value: bool) -> None:
         self._pyoptflags.collapse_joins = value
 
+    @property
+    def lookup_joins(self) -> bool:
+        """
+        Replace joins with small in-memory tables with unique keys by lookups.
+
+        This is not enabled by default.
+        """
+        return self._pyoptflags.lookup_joins
+
+    @lookup_joins.setter
+    def lookup_joins(self, value: bool) -> None:
+        self._pyoptflags.lookup_joins = value
+
+    @property
+    def fast_statistics(self) -> bool:
+        """
+        Answer null counts, minima and maxima of Parquet scans from file statistics.
+
+        Files without the required statistics are read instead. This is not enabled
+        by default.
+        """
+        return self._pyoptflags.fast_statistics
+
+    @fast_statistics.setter
+    def fast_statistics(self, value: bool) -> None:
+        self._pyoptflags.fast_statistics = value
+
     @property
     def check_order_observe(self) -> bool:
         """Do not maintain order if the order would not be observed."""
@@ -255,6 +296,8 @@ QueryOptFlags {{
     comm_subexpr_elim: {self.comm_subexpr_elim}
     cluster_with_columns: {self.cluster_with_columns}
     collapse_joins: {self.collapse_joins}
+    lookup_joins: {self.lookup_joins}
+    fast_statistics: {self.fast_statistics}
     check_order_observe: {self.check_order_observe}
     fast_projection: {self.fast_projection}
 
@@ -31,6 +31,12 @@ def thread_pool_size() -> int:
     PySpark UDF or similar context. Otherwise, it is strongly recommended not to
     override this value as it will be set automatically by the engine.
 
+    On Linux, the worker threads can be pinned to cores by setting the
+    `POLARS_CPU_AFFINITY` environment variable to a list of cores such as
+    `0-7,16-23`, or to the cores of a NUMA node by setting `POLARS_NUMA_NODE` to
+    its index. Memory is then also preferably allocated on that node. Unless
+    `POLARS_MAX_THREADS` is set, the thread pool size is the number of these cores.
+
     Examples
     --------
     >>> pl.thread_pool_size()  # doctest: +SKIP
@@ -0,0 +1,299 @@
+from __future__ import annotations
+
+import hashlib
+import threading
+import time
+import warnings
+from datetime import timedelta
+from pathlib import Path
+from typing import TYPE_CHECKING, Callable
+
+from polars._utils.unstable import unstable
+from polars._utils.various import find_stacklevel, normalize_filepath
+from polars.exceptions import ComputeError, PolarsError
+
+if TYPE_CHECKING:
+    from polars import DataFrame, LazyFrame
+
+
+__all__ = [
+    "clear_result_cache",
+    "disable_result_cache",
+    "enable_result_cache",
+    "using_result_cache",
+]
+
+
+class _ResultCache:
+    """The collected frames, by the fingerprint of their plan."""
+
+    def __init__(self, ttl: float | None, directory: Path | None) -> None:
+        self.ttl = ttl
+        self.directory = directory
+        # The frames and the time at which they were collected.
+        self.frames: dict[str, tuple[DataFrame, float]] = {}
+        self.lock = threading.Lock()
+
+    def _path(self, key: str) -> Path:
+        assert self.directory is not None
+        return self.directory / f"{key}.ipc"
+
+    def _is_expired(self, created: float) -> bool:
+        return self.ttl is not None and time.time() - created > self.ttl
+
+    def get(self, key: str) -> DataFrame | None:
+        from polars.io.ipc import read_ipc
+
+        if self.directory is None:
+            with self.lock:
+                entry = self.frames.get(key)
+                if entry is None:
+                    return None
+                if self._is_expired(entry[1]):
+                    del self.frames[key]
+                    return None
+                return entry[0].clone()
+
+        path = self._path(key)
+        try:
+            if self._is_expired(path.stat().st_mtime):
+                path.unlink(missing_ok=True)
+                return None
+            return read_ipc(path, memory_map=False)
+        except (OSError, ComputeError):
+            return None
+
+    def put(self, key: str, df: DataFrame) -> None:
+        if self.directory is None:
+            with self.lock:
+                self.frames[key] = (df.clone(), time.time())
+            return
+
+        # Write to a temporary file first, so that concurrent readers never see a
+        # partially written frame.
+        path = self._path(key)
+        tmp_path = path.with_suffix(f".{threading.get_ident()}.tmp")
+        try:
+            df.write_ipc(tmp_path)
+            tmp_path.replace(path)
+        except (OSError, PolarsError) as e:
+            # The result is still returned, it just isn't cached.
+            tmp_path.unlink(missing_ok=True)
+            msg = f"could not store the result in the result cache: {e}"
+            warnings.warn(msg, UserWarning, stacklevel=find_stacklevel())
+
+    def remove(self, plan: str) -> None:
+        """Remove the results of a plan, collected with any settings."""
+        if self.directory is None:
+            with self.lock:
+                for key in [k for k in self.frames if k.startswith(f"{plan}-")]:
+                    del self.frames[key]
+        else:
+            for path in self.directory.glob(f"{plan}-*.ipc"):
+                path.unlink(missing_ok=True)
+
+    def clear(self) -> None:
+        if self.directory is None:
+            with self.lock:
+                self.frames.clear()
+        else:
+            for path in self.directory.glob("*.ipc"):
+                path.unlink(missing_ok=True)
+
+
+_RESULT_CACHE: _ResultCache | None = None
+
+
+def _fingerprint(lf: LazyFrame) -> str | None:
+    """
+    The :meth:`LazyFrame.fingerprint` of the naive plan of `lf`.
+
+    Returns `None` if the plan calls Python functions, which are not part of the
+    fingerprint.
+    """
+    try:
+        plan = lf._ldf.exact_fingerprint(False)
+    except (AttributeError, PolarsError):
+        # Built without fingerprints, or the plan is invalid and collecting raises.
+        return None
+    return None if plan is None else f"{plan:016x}"
+
+
+def _collect_cached(
+    lf: LazyFrame, settings: str, collect: Callable[[], DataFrame]
+) -> DataFrame:
+    """
+    Return the cached result of `lf`, or collect and cache it.
+
+    The `settings` that `lf` is collected with (e.g. the engine and the optimization
+    flags) are part of the key, as they can change the result.
+    """
+    cache = _RESULT_CACHE
+    if cache is None or (plan := _fingerprint(lf)) is None:
+        return collect()
+    key = f"{plan}-{hashlib.sha256(settings.encode()).hexdigest()[:16]}"
+
+    if (df := cache.get(key)) is not None:
+        return df
+
+    df = collect()
+    cache.put(key, df)
+    return df
+
+
+@unstable()
+def enable_result_cache(
+    *,
+    ttl: int | timedelta | None = None,
+    directory: str | Path | None = None,
+) -> None:
+    """
+    Enable the global result cache of :meth:`LazyFrame.collect`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    While the cache is enabled, the results of collected LazyFrames are stored by
+    the fingerprint of their query plan, and collecting an identical query plan
+    returns the stored result instead of running the query again. This is aimed at
+    interactive sessions, e.g. notebooks in which the same cells are re-run.
+
+    Parameters
+    ----------
+    ttl
+        The time after which a stored result expires, as a number of seconds or a
+        timedelta. By default results don't expire.
+    directory
+        Store the results as IPC files in this directory instead of in memory. The
+        results are then shared by processes that use the same directory, and
+        remain available after a restart.
+
+    Notes
+    -----
+    Results are keyed by the :meth:`LazyFrame.fingerprint` of the query plan, so the
+    cache does not know if the data of the scanned files changes. Use `ttl` or
+    :func:`clear_result_cache` to make sure such changes are picked up.
+
+    Query plans that call Python functions, e.g. UDFs or IO plugins, are not cached.
+    In-memory data in the query plan is hashed as part of the fingerprint.
+
+    Only :meth:`LazyFrame.collect` uses the cache, and not when it is called with
+    `background`, `params` or `watch`.
+
+    Enabling the cache again replaces it, which drops the results stored in memory.
+
+    See Also
+    --------
+    disable_result_cache
+    clear_result_cache
+
+    Examples
+    --------
+    >>> pl.enable_result_cache(ttl=600)
+    >>> lf = pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a").sum())
+    >>> lf.collect()  # runs the query
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 6   │
+    └─────┘
+    >>> lf.collect()  # returns the stored result
+    shape: (1, 1)
+    ┌─────┐
+    │ a   │
+    │ --- │
+    │ i64 │
+    ╞═════╡
+    │ 6   │
+    └─────┘
+    >>> pl.disable_result_cache()
+    """
+    global _RESULT_CACHE
+
+    if isinstance(ttl, timedelta):
+        ttl = ttl.total_seconds()
+    if ttl is not None and ttl < 0:
+        msg = f"`ttl` must be non-negative, got {ttl}"
+        raise ValueError(msg)
+
+    path = None
+    if directory is not None:
+        path = Path(normalize_filepath(directory, check_not_directory=False))
+        path.mkdir(parents=True, exist_ok=True)
+
+    _RESULT_CACHE = _ResultCache(ttl, path)
+
+
+@unstable()
+def disable_result_cache() -> None:
+    """
+    Disable the global result cache of :meth:`LazyFrame.collect`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    The results stored in memory are dropped. Results stored in a directory are
+    kept, and are used again if the cache is enabled with the same directory.
+
+    See Also
+    --------
+    enable_result_cache
+    """
+    global _RESULT_CACHE
+    _RESULT_CACHE = None
+
+
+@unstable()
+def clear_result_cache(*lazy_frames: LazyFrame) -> None:
+    """
+    Remove results from the global result cache of :meth:`LazyFrame.collect`.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    *lazy_frames
+        Remove the results of these LazyFrames. By default all results are removed.
+
+    See Also
+    --------
+    enable_result_cache
+
+    Examples
+    --------
+    >>> pl.enable_result_cache()
+    >>> lf = pl.LazyFrame({"a": [1, 2, 3]})
+    >>> _ = lf.collect()
+    >>> pl.clear_result_cache(lf)  # the next collect runs the query again
+    >>> pl.disable_result_cache()
+    """
+    cache = _RESULT_CACHE
+    if cache is None:
+        return
+
+    if not lazy_frames:
+        cache.clear()
+        return
+
+    for lf in lazy_frames:
+        if (plan := _fingerprint(lf)) is not None:
+            cache.remove(plan)
+
+
+@unstable()
+def using_result_cache() -> bool:
+    """
+    Check whether the global result cache of :meth:`LazyFrame.collect` is enabled.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+    """
+    return _RESULT_CACHE is not None
@@ -3,10 +3,32 @@ from __future__ import annotations
 import sys
 from collections import OrderedDict
 from collections.abc import Mapping
-from typing import TYPE_CHECKING, Literal, Union, overload
+from typing import TYPE_CHECKING, Any, Literal, Union, overload
 
 from polars._typing import PythonDataType
-from polars.datatypes import DataType, DataTypeClass, is_polars_dtype
+from polars.datatypes import (
+    Array,
+    Boolean,
+    Categorical,
+    DataType,
+    DataTypeClass,
+    Date,
+    Enum,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    Int128,
+    List,
+    Null,
+    String,
+    Struct,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    is_polars_dtype,
+)
 from polars.datatypes._parse import parse_into_dtype
 
 if TYPE_CHECKING:
@@ -49,6 +71,66 @@ def _check_dtype(tp: DataType | DataTypeClass) -> DataType:
     return tp  # type: ignore[return-value]
 
 
+_INTEGER_BITS: dict[DataTypeClass, int] = {
+    Int8: 8,
+    Int16: 16,
+    Int32: 32,
+    Int64: 64,
+    Int128: 128,
+    UInt8: 8,
+    UInt16: 16,
+    UInt32: 32,
+    UInt64: 64,
+}
+
+
+def _dtype_to_json_schema(dtype: DataType) -> dict[str, Any]:
+    # Mirrors how the NDJSON writer serializes the values of each dtype.
+    json_type: str
+    out: dict[str, Any] = {}
+    if isinstance(dtype, Boolean):
+        json_type = "boolean"
+    elif dtype.is_integer():
+        json_type = "integer"
+        bits = _INTEGER_BITS[dtype.base_type()]
+        if dtype.is_signed_integer():
+            out["minimum"] = -(2 ** (bits - 1))
+            out["maximum"] = 2 ** (bits - 1) - 1
+        else:
+            out["minimum"] = 0
+            out["maximum"] = 2**bits - 1
+    elif dtype.is_float():
+        json_type = "number"
+    elif isinstance(dtype, Enum):
+        json_type = "string"
+        out["enum"] = [*dtype.categories, None]
+    elif isinstance(dtype, (String, Categorical)) or dtype.is_decimal():
+        json_type = "string"
+    elif dtype.is_temporal():
+        json_type = "string"
+        if isinstance(dtype, Date):
+            out["format"] = "date"
+    elif isinstance(dtype, (List, Array)):
+        json_type = "array"
+        out["items"] = _dtype_to_json_schema(dtype.inner)  # type: ignore[arg-type]
+        if isinstance(dtype, Array):
+            out["minItems"] = out["maxItems"] = dtype.size
+    elif isinstance(dtype, Struct):
+        json_type = "object"
+        out["properties"] = {
+            field.name: _dtype_to_json_schema(field.dtype)  # type: ignore[arg-type]
+            for field in dtype.fields
+        }
+    elif isinstance(dtype, Null):
+        return {"type": "null"}
+    else:
+        msg = f"cannot convert dtype {dtype} to a JSON Schema"
+        raise TypeError(msg)
+
+    # Every value can be null.
+    return {"type": [json_type, "null"], **out}
+
+
 class Schema(BaseSchema):
     """
     Ordered mapping of column names to their data type.
@@ -214,3 +296,30 @@ class Schema(BaseSchema):
         {'x': <class 'int'>, 'y':  <class 'str'>, 'z': <class 'datetime.timedelta'>}
         """
         return {name: tp.to_python() for name, tp in self.items()}
+
+    def to_json_schema(self) -> dict[str, Any]:
+        """
+        Return a JSON Schema describing rows of this schema written as JSON.
+
+        The JSON Schema follows draft 2020-12 and describes the rows as they are
+        written by :meth:`DataFrame.write_ndjson` and :meth:`LazyFrame.sink_ndjson`.
+
+        Examples
+        --------
+        >>> s = pl.Schema({"x": pl.UInt8(), "y": pl.List(pl.String)})
+        >>> s.to_json_schema()  # doctest: +NORMALIZE_WHITESPACE
+        {'$schema': 'https://json-schema.org/draft/2020-12/schema',
+         'type': 'object',
+         'properties': {'x': {'type': ['integer', 'null'], 'minimum': 0,
+         'maximum': 255},
+         'y': {'type': ['array', 'null'], 'items': {'type': ['string', 'null']}}},
+         'required': ['x', 'y']}
+        """
+        return {
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                name: _dtype_to_json_schema(tp) for name, tp in self.items()
+            },
+            "required": self.names(),
+        }
@@ -9,7 +9,7 @@ from polars.series.utils import expr_dispatch
 if TYPE_CHECKING:
     from collections.abc import Sequence
 
-    from polars import Series
+    from polars import DataFrame, Series
     from polars._typing import IntoExpr, IntoExprColumn
     from polars.expr.expr import Expr
     from polars.polars import PySeries
@@ -736,6 +736,41 @@ class ArrayNameSpace:
         s = wrap_s(self._s)
         return s.to_frame().select(F.col(s.name).arr.to_struct(fields)).to_series()
 
+    def to_cols(
+        self,
+        fields: Callable[[int], str] | Sequence[str] | None = None,
+    ) -> DataFrame:
+        """
+        Split the arrays into a DataFrame with one column per element.
+
+        The `i`-th column holds the `i`-th element of every array. This is the inverse
+        of :func:`concat_arr`.
+
+        Parameters
+        ----------
+        fields
+            The names of the columns, given as a list of names that are assigned by
+            index or a function that maps the index to a name. If not set, the
+            columns are named `field_0, field_1 .. field_n`.
+
+        Examples
+        --------
+        >>> s = pl.Series("n", [[0, 1], [2, 3], [4, 5]], dtype=pl.Array(pl.Int64, 2))
+        >>> s.arr.to_cols(lambda idx: f"n{idx}")
+        shape: (3, 2)
+        ┌─────┬─────┐
+        │ n0  ┆ n1  │
+        │ --- ┆ --- │
+        │ i64 ┆ i64 │
+        ╞═════╪═════╡
+        │ 0   ┆ 1   │
+        │ 2   ┆ 3   │
+        │ 4   ┆ 5   │
+        └─────┴─────┘
+        """
+        s = wrap_s(self._s)
+        return s.to_frame().select(F.col(s.name).arr.to_cols(fields))
+
     def shift(self, n: int | IntoExprColumn = 1) -> Series:
         """
         Shift array values by the given number of indices.
@@ -1327,20 +1327,24 @@ class DateTimeNameSpace:
         ]
         """
 
-    def convert_time_zone(self, time_zone: str) -> Series:
+    def convert_time_zone(self, time_zone: str | Series) -> Series:
         """
         Convert to given time zone for a Series of type Datetime.
 
         Parameters
         ----------
         time_zone
-            Time zone for the `Datetime` Series.
+            Time zone for the `Datetime` Series. A Series of time zone names converts
+            every element to the time zone at the same position instead.
 
         Notes
         -----
         If converting from a time-zone-naive datetime, then conversion will happen
         as if converting from UTC, regardless of your system's time zone.
 
+        Converting to a Series of time zones returns the local time in each
+        element's time zone as a time-zone-naive `Datetime`.
+
         Examples
         --------
         >>> from datetime import datetime
@@ -2008,7 +2012,13 @@ class DateTimeNameSpace:
         ]
         """
 
-    def combine(self, time: dt.time | Series, time_unit: TimeUnit = "us") -> Series:
+    def combine(
+        self,
+        time: dt.time | Series,
+        time_unit: TimeUnit = "us",
+        *,
+        time_zone: str | Series | None = None,
+    ) -> Series:
         """
         Create a naive Datetime from an existing Date/Datetime expression and a Time.
 
@@ -2021,6 +2031,11 @@ class DateTimeNameSpace:
             A python time literal or Series of the same length as this Series.
         time_unit : {'ns', 'us', 'ms'}
             Unit of time.
+        time_zone
+            Time zone in which to interpret the combined local datetimes, either as a
+            single time zone name or as a Series of time zone names. If set, the result
+            is a Datetime in UTC, and local datetimes that are ambiguous or
+            non-existent in their time zone raise an error.
 
         Examples
         --------
@@ -868,7 +868,9 @@ class ListNameSpace:
     def to_struct(
         self,
         n_field_strategy: ListToStructWidthStrategy = "first_non_null",
-        fields: Callable[[int], str] | Sequence[str] | None = None,
+        fields: Callable[[int], str] | Sequence[str] | str | None = None,
+        *,
+        error_on_overflow: bool = False,
     ) -> Series:
         """
         Convert the series of type `List` to a series of type `Struct`.
@@ -884,8 +886,13 @@ class ListNameSpace:
         fields
             If the name and number of the desired fields is known in advance
             a list of field names can be given, which will be assigned by index.
-            Otherwise, to dynamically assign field names, a custom function can be
-            used; if neither are set, fields will be `field_0, field_1 .. field_n`.
+            Otherwise, to dynamically assign field names, a custom function or a
+            format string in which `{}` is replaced by the index of the field (e.g.
+            `"n{}"`) can be given; if neither are set, fields will be
+            `field_0, field_1 .. field_n`.
+        error_on_overflow
+            Raise an error if a sublist has more elements than the struct has fields,
+            instead of silently dropping the elements that do not fit.
 
         Examples
         --------
@@ -931,6 +938,7 @@ class ListNameSpace:
                     # in lazy mode) there is no need to determine/track the schema.
                     n_field_strategy,
                     fields,
+                    error_on_overflow=error_on_overflow,
                     _eager=True,
                 )
             )
@@ -966,7 +974,33 @@ class ListNameSpace:
         ]
         """
 
-    def filter(self, predicate: Expr) -> Series:
+    def agg(self, expr: Expr) -> Series:
+        """
+        Run an aggregation expression against the lists' elements.
+
+        Every list is treated as a group. Unlike :meth:`eval`, the result is not
+        wrapped in a list.
+
+        Parameters
+        ----------
+        expr
+            Expression to run, which must produce a single value per list. Select
+            the elements with `pl.element()`.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [[1, 4], [8, 5], [3, 2]])
+        >>> s.list.agg(pl.element().max() - pl.element().min())
+        shape: (3,)
+        Series: 'a' [i64]
+        [
+            3
+            3
+            1
+        ]
+        """
+
+    def filter(self, predicate: Expr | Series) -> Series:
         """
         Filter elements in each list by a boolean expression, returning a new Series of lists.
 
@@ -976,6 +1010,9 @@ class ListNameSpace:
             A boolean expression evaluated on each list element.
             Use `pl.element()` to refer to the current element.
 
+            Alternatively, a Series of boolean lists with the same lengths as the lists
+            being filtered. The elements are kept where the mask is `true`.
+
         Examples
         --------
         >>> import polars as pl
@@ -988,6 +1025,15 @@ class ListNameSpace:
             [8]
             [2]
         ]
+
+        >>> s.list.filter(pl.Series([[True, False], [False, False], [True, True]]))
+        shape: (3,)
+        Series: 'a' [list[i64]]
+        [
+            [1]
+            []
+            [3, 2]
+        ]
         """  # noqa: W505
 
     def set_union(self, other: Series | Collection[Any]) -> Series:
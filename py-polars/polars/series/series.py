@@ -2322,11 +2322,12 @@ class Series:
     @unstable()
     def cut(
         self,
-        breaks: Sequence[float],
+        breaks: Sequence[NumericLiteral] | Sequence[TemporalLiteral],
         *,
         labels: Sequence[str] | None = None,
         left_closed: bool = False,
         include_breaks: bool = False,
+        missing_label: str | None = None,
     ) -> Series:
         """
         Bin continuous values into discrete categories.
@@ -2338,7 +2339,9 @@ class Series:
         Parameters
         ----------
         breaks
-            List of unique cut points.
+            List of unique cut points. For Decimal and temporal data, these may be
+            values of the same type, e.g. :class:`datetime.datetime`, which are
+            compared without casting to :class:`Float64`.
         labels
             Names of the categories. The number of labels must be equal to the number
             of cut points plus one.
@@ -2348,6 +2351,9 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        missing_label
+            Label of the category for null and NaN values. By default these are
+            mapped to null.
 
         Returns
         -------
@@ -2402,6 +2408,7 @@ class Series:
         left_closed: bool = False,
         allow_duplicates: bool = False,
         include_breaks: bool = False,
+        missing_label: str | None = None,
     ) -> Series:
         """
         Bin continuous values into discrete categories based on their quantiles.
@@ -2428,6 +2435,9 @@ class Series:
             Include a column with the right endpoint of the bin each observation falls
             in. This will change the data type of the output from a
             :class:`Categorical` to a :class:`Struct`.
+        missing_label
+            Label of the category for null and NaN values. By default these are
+            mapped to null.
 
         Returns
         -------
@@ -2487,6 +2497,110 @@ class Series:
         └─────┴────────────┴────────────┘
         """
 
+    @unstable()
+    def categorical_target_encode(
+        self,
+        target: Series,
+        *,
+        folds: int = 5,
+        smoothing: float = 1.0,
+        seed: int = 0,
+    ) -> Series:
+        """
+        Encode every category by the smoothed mean of the target values of its rows.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        A category whose rows have `n` non-null targets summing to `sum` is encoded
+        as `(sum + smoothing * prior) / (n + smoothing)`, where `prior` is the mean
+        of all targets. Null is treated as a category of its own.
+
+        To not leak the target of a row into its own encoding, the rows are
+        assigned to `folds` folds by hashing their index with `seed`, and every row
+        is encoded using the targets of the rows in the other folds only.
+
+        Parameters
+        ----------
+        target
+            The numeric or boolean target to encode the categories with.
+        folds
+            Number of folds the rows are split into. With a single fold, every row
+            is encoded using all targets, including its own.
+        smoothing
+            Weight of the prior mean, in number of rows.
+        seed
+            Seed used to assign the rows to folds.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Float64`.
+
+        See Also
+        --------
+        frequency_encode
+
+        Examples
+        --------
+        >>> s = pl.Series("city", ["NY", "SF", "NY", "LA", "NY", "SF"])
+        >>> s.categorical_target_encode(pl.Series([1, 0, 1, 0, 0, 1]), folds=1)
+        shape: (6,)
+        Series: 'city' [f64]
+        [
+            0.625
+            0.5
+            0.625
+            0.25
+            0.625
+            0.5
+        ]
+        """
+
+    @unstable()
+    def frequency_encode(self, *, normalize: bool = False) -> Series:
+        """
+        Encode every value by the number of times it occurs.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Null is counted as a value of its own.
+
+        Parameters
+        ----------
+        normalize
+            Encode every value by the fraction of the rows it occurs in instead.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`UInt32`, or :class:`Float64` if `normalize`
+            is set.
+
+        See Also
+        --------
+        categorical_target_encode
+        value_counts
+
+        Examples
+        --------
+        >>> s = pl.Series("city", ["NY", "SF", "NY", "LA", "NY", "SF"])
+        >>> s.frequency_encode()
+        shape: (6,)
+        Series: 'city' [u32]
+        [
+            3
+            2
+            3
+            1
+            3
+            2
+        ]
+        """
+
     def rle(self) -> Series:
         """
         Compress the Series data using run-length encoding.
@@ -4785,7 +4899,9 @@ class Series:
         return tensor
 
     @deprecate_renamed_parameter("future", "compat_level", version="1.1")
-    def to_arrow(self, *, compat_level: CompatLevel | None = None) -> pa.Array:
+    def to_arrow(
+        self, *, compat_level: CompatLevel | None = None, pinned_memory: bool = False
+    ) -> pa.Array:
         """
         Return the underlying Arrow array.
 
@@ -4799,6 +4915,9 @@ class Series:
         compat_level
             Use a specific compatibility level
             when exporting Polars' internal data structures.
+        pinned_memory
+            Copy the exported buffers into pinned (page-locked) host memory from the
+            pinned memory pool of CuPy, see :meth:`DataFrame.to_arrow`.
 
         Examples
         --------
@@ -4816,7 +4935,13 @@ class Series:
             compat_level = False  # type: ignore[assignment]
         elif isinstance(compat_level, CompatLevel):
             compat_level = compat_level._version  # type: ignore[attr-defined]
-        return self._s.to_arrow(compat_level)
+        array = self._s.to_arrow(compat_level)
+        if pinned_memory:
+            from polars._utils.pinned_memory import to_pinned_memory
+
+            table = pa.table({self.name: array})
+            return to_pinned_memory(table).column(0).chunk(0)
+        return array
 
     def to_pandas(
         self, *, use_pyarrow_extension_array: bool = False, **kwargs: Any
@@ -5091,6 +5216,70 @@ class Series:
         self._s.scatter(indices._s, values._s)
         return self
 
+    @unstable()
+    def scatter_batches(
+        self,
+        batches: Iterable[
+            tuple[
+                Series | Iterable[int] | int | np.ndarray[Any, Any],
+                Series | Iterable[PythonLiteral] | PythonLiteral | None,
+            ]
+        ],
+        *,
+        on_conflict: Literal["last", "raise"] = "last",
+    ) -> Series:
+        """
+        Set values at the index locations of several batches at once.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        This is equivalent to calling :meth:`scatter` for every batch, but the data
+        is only updated once, which is faster if there are many batches.
+
+        Parameters
+        ----------
+        batches
+            Pairs of index locations and replacement values, like the arguments of
+            :meth:`scatter`.
+        on_conflict : {'last', 'raise'}
+            What to do with an index location that is set more than once.
+
+            - 'last': set the last value of the location.
+            - 'raise': raise an error.
+
+        Examples
+        --------
+        >>> s = pl.Series("a", [1, 2, 3, 4])
+        >>> s.scatter_batches([([0, 1], [10, 20]), (1, None), ([3], 40)])
+        shape: (4,)
+        Series: 'a' [i64]
+        [
+                10
+                null
+                3
+                40
+        ]
+        """
+        pybatches = []
+        for indices, values in batches:
+            if not isinstance(indices, Iterable):
+                index: Any = indices  # Workaround for older NumPy versions
+                indices = [index]
+            indices = Series(values=indices)
+            if indices.is_empty():
+                continue
+
+            if not isinstance(values, Series):
+                if not isinstance(values, Iterable) or isinstance(values, str):
+                    values = [values]
+                values = Series(values=values)
+            pybatches.append((indices._s, values._s))
+
+        self._s.scatter_batches(pybatches, on_conflict)
+        return self
+
     def index_of(self, element: IntoExpr) -> int | None:
         """
         Get the index of the first occurrence of a value, or ``None`` if it's not found.
@@ -5362,7 +5551,7 @@ class Series:
         ----------
         decimals
             Number of decimals to round by.
-        mode : {'half_to_even', 'half_away_from_zero'}
+        mode : {'half_to_even', 'half_away_from_zero', 'floor', 'ceil'}
             Rounding mode.
 
         Examples
@@ -5415,6 +5604,36 @@ class Series:
         ]
         """
 
+    def round_to_increment(
+        self, increment: float, mode: RoundMode = "half_to_even"
+    ) -> Series:
+        """
+        Round to a multiple of `increment`, e.g. to the nearest 0.05.
+
+        Decimal data is rounded exactly, for which `increment` has to be a multiple
+        of the precision of its scale.
+
+        Parameters
+        ----------
+        increment
+            The positive number to round to a multiple of.
+        mode : {'half_to_even', 'half_away_from_zero', 'floor', 'ceil'}
+            Rounding mode.
+
+        Examples
+        --------
+        >>> s = pl.Series([1.01, 1.03, 1.075, 2.12])
+        >>> s.round_to_increment(0.05, mode="ceil")
+        shape: (4,)
+        Series: '' [f64]
+        [
+                1.05
+                1.05
+                1.1
+                2.15
+        ]
+        """
+
     def dot(self, other: Series | ArrayLike) -> int | float | None:
         """
         Compute the dot/inner product between two Series.
@@ -6734,6 +6953,70 @@ class Series:
         ]
         """
 
+    def downsample_lttb(self, by: IntoExpr, n_out: int) -> Series:
+        """
+        Get a mask of the values to keep to downsample a time series to `n_out` values.
+
+        The values are selected with the Largest-Triangle-Three-Buckets algorithm, which
+        keeps the visual shape of the line of the values against `by`. The first and
+        last value are always kept.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Time (or other x-coordinate) of the values, of a numeric or temporal dtype.
+            The values are assumed to be sorted by it.
+        n_out
+            Number of values to keep. Values of which the time or the value itself is
+            null or not finite are never kept, and all other values are kept if there
+            are at most `n_out` of them.
+
+        Examples
+        --------
+        >>> s = pl.Series("v", [0, 2, 1, 5, 3])
+        >>> s.downsample_lttb(pl.Series([0, 1, 2, 3, 4]), 3)
+        shape: (5,)
+        Series: 'v' [bool]
+        [
+            true
+            false
+            false
+            true
+            true
+        ]
+        """
+
+    def downsample_minmax(self, by: IntoExpr, n_buckets: int) -> Series:
+        """
+        Get a mask of the minimum and maximum value in each period of a time series.
+
+        The range of `by` is split into `n_buckets` equally long periods, of which the
+        (first) minimum and maximum value are kept.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        by
+            Time (or other x-coordinate) of the values, of a numeric or temporal dtype.
+        n_buckets
+            Number of periods. Values of which the time or the value itself is null or
+            not finite are never kept, and all other values are kept if there are at
+            most `2 * n_buckets` of them.
+
+        Examples
+        --------
+        >>> s = pl.Series("v", [3, 1, 4, 1, 5, 9, 2, 6])
+        >>> s.downsample_minmax(pl.Series(range(8)), 2).to_list()
+        [False, True, True, False, False, True, True, False]
+        """
+
     def abs(self) -> Series:
         """
         Compute absolute values.
@@ -2,9 +2,11 @@ from __future__ import annotations
 
 from typing import TYPE_CHECKING
 
+from polars import functions as F
 from polars._utils.deprecation import deprecate_nonkeyword_arguments, deprecated
 from polars._utils.unstable import unstable
 from polars._utils.various import no_default
+from polars._utils.wrap import wrap_s
 from polars.datatypes import Int64
 from polars.datatypes.constants import N_INFER_DEFAULT
 from polars.series.utils import expr_dispatch
@@ -980,7 +982,15 @@ class StringNameSpace:
             Series of data type `List(String)`.
         """
 
-    def split_exact(self, by: IntoExpr, n: int, *, inclusive: bool = False) -> Series:
+    def split_exact(
+        self,
+        by: IntoExpr,
+        n: int | None,
+        *,
+        inclusive: bool = False,
+        rest: bool = False,
+        infer_schema_length: int | None = N_INFER_DEFAULT,
+    ) -> Series:
         """
         Split the string by a substring using `n` splits.
 
@@ -993,9 +1003,16 @@ class StringNameSpace:
         by
             Substring to split by.
         n
-            Number of splits to make.
+            Number of splits to make. If set to `None`, this is the maximum number
+            of splits that can be made in the first `infer_schema_length` values.
         inclusive
             If True, include the split character/string in the results.
+        rest
+            If True, the last field is named `rest` and holds the remainder of the
+            string after `n` splits, instead of only the part up to the next split.
+        infer_schema_length
+            The number of values to inspect if `n` is `None`. If set to `None`, all
+            values are inspected.
 
         Examples
         --------
@@ -1033,12 +1050,42 @@ class StringNameSpace:
         │ d          ┆ 4           │
         └────────────┴─────────────┘
 
+        Infer the number of splits from the data.
+
+        >>> pl.Series("x", ["a,b", "c,d,e", None]).str.split_exact(",", None)
+        shape: (3,)
+        Series: 'x' [struct[3]]
+        [
+                {"a","b",null}
+                {"c","d","e"}
+                {null,null,null}
+        ]
+
         Returns
         -------
         Series
             Series of data type :class:`Struct` with fields of data type
             :class:`String`.
         """
+        s = wrap_s(self._s)
+        if n is None:
+            if not isinstance(by, str):
+                msg = "`n` can only be inferred if `by` is a string"
+                raise TypeError(msg)
+            sample = s if infer_schema_length is None else s.head(infer_schema_length)
+            if by:
+                counts = sample.str.count_matches(by, literal=True)
+            else:
+                counts = sample.str.len_chars() - 1
+            max_count = counts.max()
+            n = max(max_count, 0) if isinstance(max_count, int) else 0
+        return (
+            s.to_frame()
+            .select(
+                F.col(s.name).str.split_exact(by, n, inclusive=inclusive, rest=rest)
+            )
+            .to_series()
+        )
 
     def splitn(self, by: IntoExpr, n: int) -> Series:
         """
@@ -1511,6 +1558,116 @@ class StringNameSpace:
         ]
         """
 
+    def pad_start_display(
+        self,
+        width: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        truncate: bool = False,
+    ) -> Series:
+        """
+        Pad the start of the string until it is the given width when displayed.
+
+        Unlike :func:`pad_start`, which counts characters, this counts the columns
+        that the string takes up in a terminal or in a fixed-width font: East Asian
+        wide and fullwidth characters are two columns wide and combining characters
+        have no width. Use this to align columns of fixed-width reports.
+
+        Parameters
+        ----------
+        width
+            Pad the string until it is this many columns wide. Strings that are at
+            least this wide are returned as-is, unless `truncate` is set.
+        fill_char
+            The character to pad the string with. Must be one column wide.
+        truncate
+            Remove characters from the end of strings that are wider than `width`
+            until they fit. A wide character that does not fit completely is
+            replaced with `fill_char`.
+
+        See Also
+        --------
+        pad_start
+        pad_end_display
+
+        Examples
+        --------
+        >>> s = pl.Series(["ab", "日本", "日本語です", None])
+        >>> s.str.pad_start_display(6, "*")
+        shape: (4,)
+        Series: '' [str]
+        [
+            "****ab"
+            "**日本"
+            "日本語です"
+            null
+        ]
+        >>> s.str.pad_start_display(6, "*", truncate=True)
+        shape: (4,)
+        Series: '' [str]
+        [
+            "****ab"
+            "**日本"
+            "日本語"
+            null
+        ]
+        """
+
+    def pad_end_display(
+        self,
+        width: int | IntoExprColumn,
+        fill_char: str = " ",
+        *,
+        truncate: bool = False,
+    ) -> Series:
+        """
+        Pad the end of the string until it is the given width when displayed.
+
+        Unlike :func:`pad_end`, which counts characters, this counts the columns
+        that the string takes up in a terminal or in a fixed-width font: East Asian
+        wide and fullwidth characters are two columns wide and combining characters
+        have no width. Use this to align columns of fixed-width reports.
+
+        Parameters
+        ----------
+        width
+            Pad the string until it is this many columns wide. Strings that are at
+            least this wide are returned as-is, unless `truncate` is set.
+        fill_char
+            The character to pad the string with. Must be one column wide.
+        truncate
+            Remove characters from the end of strings that are wider than `width`
+            until they fit. A wide character that does not fit completely is
+            replaced with `fill_char`.
+
+        See Also
+        --------
+        pad_end
+        pad_start_display
+
+        Examples
+        --------
+        >>> s = pl.Series(["ab", "日本", "日本語です", None])
+        >>> s.str.pad_end_display(6, "*")
+        shape: (4,)
+        Series: '' [str]
+        [
+            "ab****"
+            "日本**"
+            "日本語です"
+            null
+        ]
+        >>> s.str.pad_end_display(6, "*", truncate=True)
+        shape: (4,)
+        Series: '' [str]
+        [
+            "ab****"
+            "日本**"
+            "日本語"
+            null
+        ]
+        """
+
     def zfill(self, length: int | IntoExprColumn) -> Series:
         """
         Pad the start of the string with zeros until it reaches the given length.
@@ -1626,6 +1783,70 @@ class StringNameSpace:
         ]
         """
 
+    @unstable()
+    def parse_common_log(self) -> Series:
+        """
+        Parse web server log lines in the Common Log Format into a struct.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Lines in the Combined Log Format are supported as well, the trailing
+        `referer` and `user_agent` fields are null for lines that don't have them.
+        Fields logged as `-` are returned as null, as are lines that don't match
+        the format.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with the fields `host`, `ident`,
+            `user`, `time`, `method`, `path`, `protocol`, `status` (:class:`UInt16`),
+            `size` (:class:`UInt64`), `referer` and `user_agent`.
+
+        Examples
+        --------
+        >>> log = '::1 - - [10/Oct/2000:13:55:36 -0700] "GET / HTTP/1.1" 200 512'
+        >>> s = pl.Series("log", [log])
+        >>> s.str.parse_common_log().struct.field("status")
+        shape: (1,)
+        Series: 'status' [u16]
+        [
+            200
+        ]
+        """
+
+    @unstable()
+    def parse_user_agent(self) -> Series:
+        """
+        Extract the browser, operating system and device type from user agents.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        The values of `User-Agent` HTTP headers are matched against the tokens of
+        common browsers and operating systems. Values that can't be identified are
+        returned as null. The `device` is one of `"desktop"`, `"mobile"`,
+        `"tablet"` or `"bot"`.
+
+        Returns
+        -------
+        Series
+            Series of data type :class:`Struct` with the fields `browser`,
+            `browser_version`, `os` and `device`, all of data type :class:`String`.
+
+        Examples
+        --------
+        >>> s = pl.Series("ua", ["Mozilla/5.0 (Android 14; Mobile) Firefox/128.0"])
+        >>> s.str.parse_user_agent().struct.field("browser")
+        shape: (1,)
+        Series: 'browser' [str]
+        [
+            "Firefox"
+        ]
+        """
+
     def slice(
         self, offset: int | IntoExprColumn, length: int | IntoExprColumn | None = None
     ) -> Series:
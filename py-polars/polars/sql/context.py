@@ -15,12 +15,14 @@ from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.pycapsule import is_pycapsule
 from polars._utils.unstable import issue_unstable_warning
 from polars._utils.various import _get_stack_locals, qualified_type_name
-from polars._utils.wrap import wrap_ldf
+from polars._utils.wrap import wrap_expr, wrap_ldf
 from polars.convert import from_arrow, from_pandas
 from polars.dataframe import DataFrame
 from polars.dependencies import _check_for_pandas, _check_for_pyarrow
 from polars.dependencies import pandas as pd
 from polars.dependencies import pyarrow as pa
+from polars.expr import Expr
+from polars.functions import map_batches, map_groups, sql_expr
 from polars.lazyframe import LazyFrame
 from polars.series import Series
 
@@ -33,6 +35,9 @@ if TYPE_CHECKING:
     from types import TracebackType
     from typing import Any, Final, Literal
 
+    from polars._typing import PolarsDataType
+    from polars.polars import PyExpr
+
     if sys.version_info >= (3, 10):
         from typing import TypeAlias
     else:
@@ -576,6 +581,284 @@ class SQLContext(Generic[FrameType]):
             self.register(name, frame)
         return self
 
+    def register_policy(
+        self,
+        name: str,
+        *,
+        row_filter: str | Expr | None = None,
+        column_masks: Mapping[str, str | Expr] | None = None,
+    ) -> Self:
+        """
+        Register a policy restricting the rows and values of a table that queries see.
+
+        The policy is applied every time a query reads the table, before any other
+        part of the query, so that the hidden rows and values are not visible to it.
+        This can be used to serve restricted views of the same tables to different
+        users. Registering a new policy for the table replaces the existing one.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            Name of the table; it does not need to be registered yet, and the policy
+            is kept if the table is unregistered or registered again.
+        row_filter
+            Only show the rows for which this predicate is true. A string is parsed
+            as a SQL expression.
+        column_masks
+            A `{column:mask, ...}` mapping of expressions that replace the values of
+            the columns; they are evaluated on the unmasked columns of the filtered
+            rows. Strings are parsed as SQL expressions.
+
+        See Also
+        --------
+        unregister_policy
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "tenant": ["x", "y", "x"],
+        ...         "email": ["a@x.com", "b@y.com", "c@x.com"],
+        ...         "value": [1, 2, 3],
+        ...     }
+        ... )
+        >>> ctx = pl.SQLContext(data=df)
+        >>> ctx.register_policy(
+        ...     "data",
+        ...     row_filter="tenant = 'x'",
+        ...     column_masks={"email": pl.lit("***")},
+        ... ).execute("SELECT * FROM data", eager=True)
+        shape: (2, 3)
+        ┌────────┬───────┬───────┐
+        │ tenant ┆ email ┆ value │
+        │ ---    ┆ ---   ┆ ---   │
+        │ str    ┆ str   ┆ i64   │
+        ╞════════╪═══════╪═══════╡
+        │ x      ┆ ***   ┆ 1     │
+        │ x      ┆ ***   ┆ 3     │
+        └────────┴───────┴───────┘
+        """
+        issue_unstable_warning("`SQLContext.register_policy` is considered unstable.")
+
+        def _to_expr(e: str | Expr) -> Expr:
+            return sql_expr(e) if isinstance(e, str) else e
+
+        self._ctxt.register_policy(
+            name,
+            None if row_filter is None else _to_expr(row_filter)._pyexpr,
+            [
+                (column, _to_expr(mask)._pyexpr)
+                for column, mask in (column_masks or {}).items()
+            ],
+        )
+        return self
+
+    def unregister_policy(self, names: str | Collection[str]) -> Self:
+        """
+        Unregister the policies of one or more tables by name.
+
+        The tables themselves remain registered.
+
+        Parameters
+        ----------
+        names
+            Names of the tables whose policies to unregister.
+
+        See Also
+        --------
+        register_policy
+
+        Examples
+        --------
+        >>> ctx = pl.SQLContext(data=pl.DataFrame({"x": [1, 2, 3]}))
+        >>> ctx.register_policy("data", row_filter="x > 1").execute(
+        ...     "SELECT COUNT(*) AS n FROM data", eager=True
+        ... ).item()
+        2
+        >>> ctx.unregister_policy("data").execute("SELECT * FROM data", eager=True)
+        shape: (3, 1)
+        ┌─────┐
+        │ x   │
+        │ --- │
+        │ i64 │
+        ╞═════╡
+        │ 1   │
+        │ 2   │
+        │ 3   │
+        └─────┘
+        """
+        if isinstance(names, str):
+            names = [names]
+        for nm in names:
+            self._ctxt.unregister_policy(nm)
+        return self
+
+    def register_function(
+        self,
+        name: str,
+        function: Callable[..., Any],
+        *,
+        return_dtype: PolarsDataType | None = None,
+        returns_scalar: bool = False,
+    ) -> Self:
+        """
+        Register a function that queries on this context can call by name.
+
+        By default `function` is an expression factory: it is called with the
+        expressions of the arguments of a call when the query is planned, and must
+        return an expression, of which the schema is resolved as usual. Aggregations
+        in it aggregate the groups of a `GROUP BY` clause.
+
+        If `return_dtype` is given, `function` is instead a Python UDF that is called
+        with the arguments as Series when the query runs; the declared dtype is used
+        to plan the query without calling it.
+
+        Function names are case-insensitive, take precedence over the built-in SQL
+        functions, and are only known to this context. Registering a function with
+        the name of an existing one replaces it.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        name
+            Name by which SQL queries call the function.
+        function
+            The expression factory, or the Python UDF if `return_dtype` is given.
+        return_dtype
+            The dtype that the Python UDF returns.
+        returns_scalar
+            Whether the Python UDF is an aggregate that returns a single value for
+            each group (or for the frame if there is no `GROUP BY`), rather than a
+            value for each row. Requires `return_dtype`.
+
+        See Also
+        --------
+        unregister_function
+        functions
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"k": ["x", "y", "x"], "v": [1, 2, 3]})
+        >>> ctx = pl.SQLContext(data=df)
+
+        Register an expression factory, and call it like a built-in function:
+
+        >>> ctx.register_function("double", lambda e: e * 2).execute(
+        ...     "SELECT k, SUM(DOUBLE(v)) AS s FROM data GROUP BY k ORDER BY k",
+        ...     eager=True,
+        ... )
+        shape: (2, 2)
+        ┌─────┬─────┐
+        │ k   ┆ s   │
+        │ --- ┆ --- │
+        │ str ┆ i64 │
+        ╞═════╪═════╡
+        │ x   ┆ 8   │
+        │ y   ┆ 4   │
+        └─────┴─────┘
+
+        Register a Python aggregate UDF, declaring the dtype that it returns:
+
+        >>> ctx.register_function(
+        ...     "spread",
+        ...     lambda s: s.max() - s.min(),
+        ...     return_dtype=pl.Int64,
+        ...     returns_scalar=True,
+        ... ).execute(
+        ...     "SELECT k, SPREAD(v) AS spread FROM data GROUP BY k ORDER BY k",
+        ...     eager=True,
+        ... )
+        shape: (2, 2)
+        ┌─────┬────────┐
+        │ k   ┆ spread │
+        │ --- ┆ ---    │
+        │ str ┆ i64    │
+        ╞═════╪════════╡
+        │ x   ┆ 2      │
+        │ y   ┆ 0      │
+        └─────┴────────┘
+        """
+        issue_unstable_warning("`SQLContext.register_function` is considered unstable.")
+
+        if return_dtype is None:
+            if returns_scalar:
+                msg = "`returns_scalar` requires a `return_dtype`"
+                raise ValueError(msg)
+
+            def _factory(args: list[PyExpr]) -> PyExpr:
+                expr = function(*(wrap_expr(arg) for arg in args))
+                if not isinstance(expr, Expr):
+                    msg = (
+                        f"SQL function {name!r} must return an expression, "
+                        f"got {qualified_type_name(expr)!r}"
+                    )
+                    raise TypeError(msg)
+                return expr._pyexpr
+
+        else:
+
+            def _udf(series: list[Series]) -> Any:
+                return function(*series)
+
+            def _factory(args: list[PyExpr]) -> PyExpr:
+                exprs = [wrap_expr(arg) for arg in args]
+                if returns_scalar:
+                    expr = map_groups(exprs, _udf, return_dtype, returns_scalar=True)
+                else:
+                    expr = map_batches(exprs, _udf, return_dtype)
+                return expr._pyexpr
+
+        self._ctxt.register_function(name, _factory)
+        return self
+
+    def unregister_function(self, names: str | Collection[str]) -> Self:
+        """
+        Unregister one or more functions registered with `register_function`.
+
+        Parameters
+        ----------
+        names
+            Names of the functions to unregister.
+
+        See Also
+        --------
+        register_function
+
+        Examples
+        --------
+        >>> ctx = pl.SQLContext().register_function("inc", lambda e: e + 1)
+        >>> ctx.functions()
+        ['inc']
+        >>> ctx.unregister_function("inc").functions()
+        []
+        """
+        if isinstance(names, str):
+            names = [names]
+        for nm in names:
+            self._ctxt.unregister_function(nm)
+        return self
+
+    def functions(self) -> list[str]:
+        """
+        Return a list of the names of the functions registered with `register_function`.
+
+        Names are returned in lowercase, in sorted order.
+
+        Examples
+        --------
+        >>> ctx = pl.SQLContext()
+        >>> ctx.register_function("Inc", lambda e: e + 1).functions()
+        ['inc']
+        """
+        return self._ctxt.get_functions()
+
     def unregister(self, names: str | Collection[str]) -> Self:
         """
         Unregister one or more eager/lazy frames by name.
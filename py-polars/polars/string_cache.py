@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 import contextlib
+import uuid
 from typing import TYPE_CHECKING
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -9,6 +10,7 @@ with contextlib.suppress(ImportError):  # Module not available when building doc
 
 if TYPE_CHECKING:
     import sys
+    from pathlib import Path
     from types import TracebackType
 
     if sys.version_info >= (3, 11):
@@ -18,6 +20,7 @@ if TYPE_CHECKING:
 
 
 __all__ = [
+    "SharedStringCache",
     "StringCache",
     "disable_string_cache",
     "enable_string_cache",
@@ -88,6 +91,91 @@ class StringCache(contextlib.ContextDecorator):
         del self._string_cache
 
 
+class SharedStringCache:
+    """
+    A handle to a string cache in a named shared-memory segment.
+
+    Every process that enables the same shared string cache gives the same
+    categories of :class:`Categorical` columns the same physical value, so worker
+    processes that operate on partitions of the same dataset produce compatible
+    columns. A process can send the physical values of a column to another one, for
+    example through a :class:`SharedMemoryFrame`, which casts them back to
+    `Categorical` without converting them to and from strings.
+
+    The segment holds the categories in the order of their physical values, and
+    grows as the processes add categories to it. Once enabled, a process uses the
+    shared string cache until it exits. The handle only pickles its name, so it can
+    be sent to :mod:`multiprocessing` workers, which should enable it before they
+    create categorical data, e.g. in the initializer of a pool.
+
+    On Linux, the segment is a file in `/dev/shm`. On other platforms, it is a file
+    in the temporary directory. The segment outlives the processes that use it;
+    call :meth:`unlink` once they are done.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    name
+        The name of the segment. A new segment with a random name is used if it is
+        not given.
+
+    Examples
+    --------
+    >>> from concurrent.futures import ProcessPoolExecutor
+    >>> def encode(strings: list[str]) -> list[int]:
+    ...     s = pl.Series(strings, dtype=pl.Categorical)
+    ...     return s.to_physical().to_list()
+    >>> cache = pl.SharedStringCache()
+    >>> with ProcessPoolExecutor(initializer=cache.enable) as pool:  # doctest: +SKIP
+    ...     a, b = pool.map(encode, [["x", "y"], ["y", "x"]])
+    >>> a == b[::-1]  # doctest: +SKIP
+    True
+    >>> cache.unlink()  # doctest: +SKIP
+    """
+
+    def __init__(self, name: str | None = None) -> None:
+        from polars.io.shared_memory import _check_segment_name
+
+        if name is None:
+            name = f"polars-string-cache-{uuid.uuid4().hex}"
+        _check_segment_name(name)
+        self.name = name
+
+    def __repr__(self) -> str:
+        return f"SharedStringCache({self.name!r})"
+
+    @property
+    def path(self) -> Path:
+        """The path of the segment."""
+        from polars.io.shared_memory import _segment_dir
+
+        return _segment_dir() / self.name
+
+    def enable(self) -> None:
+        """
+        Use the shared string cache in this process.
+
+        The segment is created if it does not exist. This raises an error if
+        categorical data that uses the local string cache is alive, as its physical
+        values could differ from the shared ones, or if another shared string cache
+        is already enabled.
+        """
+        plr.enable_shared_string_cache(self.path)
+
+    def unlink(self) -> None:
+        """
+        Remove the segment.
+
+        This should only be done once all processes that use the shared string
+        cache are done, as a process that enables it afterwards starts with a new,
+        empty segment.
+        """
+        self.path.unlink(missing_ok=True)
+
+
 def enable_string_cache() -> None:
     """
     Enable the global string cache.
@@ -0,0 +1,121 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from polars._utils.convert import date_to_int, time_to_int
+
+if TYPE_CHECKING:
+    from collections.abc import Iterable, Mapping
+    from datetime import date, time
+
+__all__ = ["TradingCalendar"]
+
+
+class TradingCalendar:
+    """
+    The trading sessions of an exchange.
+
+    Pass a calendar to :meth:`DataFrame.group_by_dynamic` or
+    :meth:`LazyFrame.group_by_dynamic` to align the windows to the sessions instead
+    of to the clock. A session runs from `open` to `close` on each day in
+    `week_mask` that isn't one of the `holidays`, and closes early on the days in
+    `early_closes`. The times are wall clock times in the time zone of the index
+    column.
+
+    .. warning::
+        This functionality is considered **unstable**. It may be changed
+        at any point without it being considered a breaking change.
+
+    Parameters
+    ----------
+    open
+        Time of day at which the sessions open.
+    close
+        Time of day at which the regular sessions close.
+    week_mask
+        Which days of the week have a session, starting with Monday. By default,
+        Monday to Friday do.
+    holidays
+        Dates on which there is no session.
+    early_closes
+        The dates on which the session closes early (e.g. half-days), mapped to the
+        time of the close.
+
+    Examples
+    --------
+    >>> from datetime import date, datetime, time
+    >>> calendar = pl.TradingCalendar(
+    ...     open=time(9, 30),
+    ...     close=time(16),
+    ...     holidays=[date(2024, 11, 28)],
+    ...     early_closes={date(2024, 11, 29): time(13)},
+    ... )
+    >>> df = pl.DataFrame(
+    ...     {
+    ...         "time": [
+    ...             datetime(2024, 11, 27, 10),
+    ...             datetime(2024, 11, 27, 15, 30),
+    ...             datetime(2024, 11, 29, 9, 45),
+    ...             datetime(2024, 11, 29, 12, 30),
+    ...             datetime(2024, 11, 29, 14),
+    ...             datetime(2024, 12, 2, 11),
+    ...         ],
+    ...         "price": [100, 101, 102, 103, 104, 105],
+    ...     }
+    ... )
+
+    Take the closing price of each session, of which the last trade on the
+    half-day after Thanksgiving is after the early close:
+
+    >>> df.group_by_dynamic(
+    ...     "time", every="1d", label="right", calendar=calendar
+    ... ).agg(pl.col("price").last())
+    shape: (3, 2)
+    ┌─────────────────────┬───────┐
+    │ time                ┆ price │
+    │ ---                 ┆ ---   │
+    │ datetime[μs]        ┆ i64   │
+    ╞═════════════════════╪═══════╡
+    │ 2024-11-27 16:00:00 ┆ 101   │
+    │ 2024-11-29 13:00:00 ┆ 103   │
+    │ 2024-12-02 16:00:00 ┆ 105   │
+    └─────────────────────┴───────┘
+    """
+
+    def __init__(
+        self,
+        open: time,
+        close: time,
+        *,
+        week_mask: Iterable[bool] = (True, True, True, True, True, False, False),
+        holidays: Iterable[date] = (),
+        early_closes: Mapping[date, time] | None = None,
+    ) -> None:
+        mask = tuple(week_mask)
+        if len(mask) != 7:
+            msg = f"`week_mask` must contain 7 days, got {len(mask)}"
+            raise ValueError(msg)
+
+        self.open = open
+        self.close = close
+        self.week_mask = mask
+        self.holidays = tuple(sorted(set(holidays)))
+        self.early_closes = dict(sorted((early_closes or {}).items()))
+
+    def __repr__(self) -> str:
+        return (
+            f"TradingCalendar(open={self.open!r}, close={self.close!r}, "
+            f"week_mask={self.week_mask!r}, holidays={self.holidays!r}, "
+            f"early_closes={self.early_closes!r})"
+        )
+
+    def _to_tuple(
+        self,
+    ) -> tuple[int, int, tuple[bool, ...], list[int], list[tuple[int, int]]]:
+        return (
+            time_to_int(self.open),
+            time_to_int(self.close),
+            self.week_mask,
+            [date_to_int(d) for d in self.holidays],
+            [(date_to_int(d), time_to_int(t)) for d, t in self.early_closes.items()],
+        )
@@ -20,9 +20,12 @@ use polars_python::expr::datatype::PyDataTypeExpr;
 use polars_python::expr::selector::PySelector;
 use polars_python::expr::PyExpr;
 use polars_python::functions::PyStringCacheHolder;
+use polars_python::io::PyFileErrorPolicy;
 #[cfg(not(target_arch = "wasm32"))]
 use polars_python::lazyframe::PyInProcessQuery;
-use polars_python::lazyframe::{PyLazyFrame, PyOptFlags, PyPartitioning};
+use polars_python::lazyframe::{
+    PyFeatureSet, PyInspectHandle, PyLazyFrame, PyOptFlags, PyPartitioning,
+};
 use polars_python::lazygroupby::PyLazyGroupBy;
 use polars_python::series::PySeries;
 #[cfg(feature = "sql")]
@@ -99,6 +102,9 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_class::<PyOptFlags>().unwrap();
     #[cfg(not(target_arch = "wasm32"))]
     m.add_class::<PyInProcessQuery>().unwrap();
+    m.add_class::<PyInspectHandle>().unwrap();
+    m.add_class::<PyFeatureSet>().unwrap();
+    m.add_class::<PyFileErrorPolicy>().unwrap();
     m.add_class::<PyLazyGroupBy>().unwrap();
     m.add_class::<PyExpr>().unwrap();
     m.add_class::<PyDataTypeExpr>().unwrap();
@@ -168,6 +174,10 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::mean_horizontal))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::quantile_horizontal))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::std_horizontal))
+        .unwrap();
 
     // Functions - lazy
     m.add_wrapped(wrap_pyfunction!(functions::arg_sort_by))
@@ -176,14 +186,21 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::as_struct))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::cached)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::coalesce))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::is_first_distinct))
+        .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::is_last_distinct))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::field)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::col)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::collect_all))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::explain_all))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::sink_multiple))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::collect_all_with_callback))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cols)).unwrap();
@@ -196,6 +213,9 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::concat_str))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::len)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::uuid4)).unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::snowflake_id))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cov)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::cum_fold))
         .unwrap();
@@ -218,6 +238,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(functions::map_mul)).unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::pearson_corr))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::placeholder))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_corr))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::rolling_cov))
@@ -245,12 +267,33 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     #[cfg(feature = "parquet")]
     m.add_wrapped(wrap_pyfunction!(functions::read_parquet_metadata))
         .unwrap();
+    #[cfg(feature = "parquet")]
+    m.add_wrapped(wrap_pyfunction!(functions::read_parquet_row_group_metadata))
+        .unwrap();
+    #[cfg(feature = "avro")]
+    m.add_wrapped(wrap_pyfunction!(functions::read_avro_schema))
+        .unwrap();
+    #[cfg(feature = "avro")]
+    m.add_wrapped(wrap_pyfunction!(functions::avro_schema_json))
+        .unwrap();
+    #[cfg(feature = "schema_registry")]
+    m.add_wrapped(wrap_pyfunction!(functions::validate_schema_registry))
+        .unwrap();
+    #[cfg(feature = "schema_registry")]
+    m.add_wrapped(wrap_pyfunction!(functions::register_schema_registry))
+        .unwrap();
     #[cfg(feature = "clipboard")]
     m.add_wrapped(wrap_pyfunction!(functions::read_clipboard_string))
         .unwrap();
     #[cfg(feature = "clipboard")]
     m.add_wrapped(wrap_pyfunction!(functions::write_clipboard_string))
         .unwrap();
+    #[cfg(feature = "clipboard")]
+    m.add_wrapped(wrap_pyfunction!(functions::sniff_clipboard_separator))
+        .unwrap();
+    #[cfg(feature = "clipboard")]
+    m.add_wrapped(wrap_pyfunction!(functions::parse_spreadsheet_numbers))
+        .unwrap();
     #[cfg(feature = "catalog")]
     m.add_class::<PyCatalogClient>().unwrap();
 
@@ -265,6 +308,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::using_string_cache))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::enable_shared_string_cache))
+        .unwrap();
 
     // Numeric formatting
     m.add_wrapped(wrap_pyfunction!(functions::get_thousands_separator))
@@ -275,6 +320,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::get_float_precision))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::get_float_summation))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::get_decimal_separator))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::get_trim_decimal_zeros))
@@ -283,6 +330,8 @@ fn polars(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::set_float_precision))
         .unwrap();
+    m.add_wrapped(wrap_pyfunction!(functions::set_float_summation))
+        .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::set_decimal_separator))
         .unwrap();
     m.add_wrapped(wrap_pyfunction!(functions::set_trim_decimal_zeros))
@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+from datetime import timedelta
+
+import pytest
+
+import polars as pl
+import polars.dataframe.compressed
+from polars.exceptions import ColumnNotFoundError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_compress_in_memory_roundtrip() -> None:
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": [1, 2], "b": ["x", None], "c": [[1.0], []]}),
+            pl.DataFrame({"a": [3, 4], "b": ["y", "z"], "c": [None, [2.0, 3.0]]}),
+        ],
+        rechunk=False,
+    )
+    cf = df.compress_in_memory()
+    assert cf.shape == (4, 3)
+    assert len(cf) == 4
+    assert cf.columns == ["a", "b", "c"]
+    assert cf.schema == df.schema
+    assert "b" in cf
+    assert "d" not in cf
+
+    assert cf.decompressed_columns == []
+    assert_series_equal(cf["b"], df["b"])
+    assert cf.get_column("a").n_chunks() == 2
+    assert cf.decompressed_columns == ["a", "b"]
+    assert_frame_equal(cf.decompress(["c", "a"]), df.select("c", "a"))
+    assert_frame_equal(cf.decompress(), df)
+
+    with pytest.raises(ColumnNotFoundError, match="'d'"):
+        cf["d"]
+
+
+def test_compress_in_memory_cold_after(monkeypatch: pytest.MonkeyPatch) -> None:
+    now = [0.0]
+    monkeypatch.setattr(polars.dataframe.compressed, "monotonic", lambda: now[0])
+    df = pl.DataFrame({"a": [1, 2] * 500, "b": ["x"] * 1000})
+
+    cf = df.compress_in_memory(cold_after=timedelta(seconds=10))
+    cf["a"]
+    now[0] = 5.0
+    cf["b"]
+    now[0] = 12.0
+    assert cf.decompressed_columns == ["b"]
+    cf["b"]
+    now[0] = 20.0
+    assert cf.decompressed_columns == ["b"]
+    cf.release()
+    assert cf.decompressed_columns == []
+
+    cf = df.compress_in_memory(cold_after=None)
+    cf["a"]
+    now[0] = 1e9
+    assert cf.decompressed_columns == ["a"]
+
+    cf = df.compress_in_memory(cold_after=0)
+    assert_series_equal(cf["a"], df["a"])
+    assert cf.decompressed_columns == []
+    assert cf.estimated_size() < df.estimated_size()
+
+
+def test_compress_in_memory_invalid() -> None:
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.DataFrame({"a": [1]}).compress_in_memory(cold_after=-1)
+    with pytest.raises(TypeError, match="compress_in_memory"):
+        pl.CompressedFrame()
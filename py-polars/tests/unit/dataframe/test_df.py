@@ -1779,6 +1779,63 @@ def test_reproducible_hash_with_seeds() -> None:
     assert_series_equal(expected, result, check_names=False, check_exact=True)
 
 
+def test_hash_rows_stable() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 3, 3],
+            "b": [0.0, float("nan"), -0.0, None],
+            "c": ["x", "y", None, "x"],
+            "d": [[1, 2], None, [], [1, 2]],
+            "e": [{"x": 1}, {"x": None}, None, {"x": 1}],
+        }
+    )
+    h = df.hash_rows(stable=True)
+    assert h.dtype == pl.UInt64
+    assert h.n_unique() == 4
+
+    # The hashes do not depend on the memory layout or representation of the values.
+    chunked = pl.concat([df.head(2), df.tail(2)], rechunk=False)
+    assert_series_equal(chunked.hash_rows(stable=True), h)
+    assert_series_equal(
+        df.with_columns(pl.col("c").cast(pl.Categorical)).hash_rows(stable=True), h
+    )
+    assert_series_equal(df.slice(2).hash_rows(stable=True), h.slice(2))
+    df_signed = df.with_columns(b=pl.Series([-0.0, float("-nan"), 0.0, None]))
+    assert_series_equal(df_signed.hash_rows(stable=True), h)
+
+    assert (df.hash_rows(seed=1, stable=True) != h).all()
+    # Nulls hash differently from zeros and empty values.
+    other = pl.DataFrame({"a": [None, 0], "c": [None, ""], "d": [None, []]})
+    assert other.hash_rows(stable=True).n_unique() == 2
+    for name in other.columns:
+        assert other.select(name).hash_rows(stable=True).n_unique() == 2
+
+    with pytest.raises(ValueError, match="cannot be used with `stable=True`"):
+        df.hash_rows(1, 2, stable=True)
+
+
+def test_hash_rows_stable_golden() -> None:
+    # These hashes may be persisted, so they must never change.
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 3],
+            "b": [0.5, float("nan"), -0.0],
+            "c": ["x", None, ""],
+            "d": [[1, 2], None, []],
+        }
+    )
+    assert df.hash_rows(stable=True).to_list() == [
+        1158662250162245499,
+        10339265439387947431,
+        1874826894371040287,
+    ]
+    assert df.hash_rows(seed=42, stable=True).to_list() == [
+        3573876256693867703,
+        6848743684190569494,
+        14507031387627325850,
+    ]
+
+
 @pytest.mark.slow
 @pytest.mark.parametrize(
     "e",
@@ -1984,6 +2041,65 @@ def test_with_row_index_bad_offset_lazy() -> None:
         lf.with_row_index(offset=2**32)
 
 
+@pytest.mark.parametrize("lazy", [False, True])
+def test_with_row_index_stepped(lazy: bool) -> None:
+    df = pl.DataFrame({"a": [1, 1, 3]})
+    frame = df.lazy() if lazy else df
+
+    out = frame.with_row_index("id", offset=2**40, step=5)
+    if isinstance(out, pl.LazyFrame):
+        assert out.collect_schema() == pl.Schema({"id": pl.Int64, "a": pl.Int64})
+        out = out.collect()
+    expected = pl.DataFrame(
+        {"id": [2**40, 2**40 + 5, 2**40 + 10], "a": [1, 1, 3]},
+        schema={"id": pl.Int64, "a": pl.Int64},
+    )
+    assert_frame_equal(out, expected)
+
+    out = frame.with_row_index("id", offset=-1, step=-2)
+    if isinstance(out, pl.LazyFrame):
+        out = out.collect()
+    assert out["id"].to_list() == [-1, -3, -5]
+
+    out = frame.with_row_index("id", offset=10, dtype=pl.UInt64)
+    if isinstance(out, pl.LazyFrame):
+        out = out.collect()
+    assert out["id"].dtype == pl.UInt64
+    assert out["id"].to_list() == [10, 11, 12]
+
+
+@pytest.mark.parametrize("lazy", [False, True])
+def test_with_row_index_stepped_invalid(lazy: bool) -> None:
+    df = pl.DataFrame({"a": [1, 1, 3]})
+    frame = df.lazy() if lazy else df
+
+    with pytest.raises(InvalidOperationError, match="must not be zero"):
+        frame.with_row_index(step=0)
+    with pytest.raises(InvalidOperationError, match="Int64 or UInt64"):
+        frame.with_row_index(dtype=pl.Int32)
+
+
+@pytest.mark.parametrize("lazy", [False, True])
+def test_with_row_index_stepped_overflow(lazy: bool) -> None:
+    df = pl.DataFrame({"a": [1, 1, 3]})
+    # The eager version checks the bounds up front, the lazy one in a strict cast.
+    exc = (InvalidOperationError, ComputeError) if lazy else ComputeError
+
+    def with_row_index(**kwargs: Any) -> pl.DataFrame:
+        if lazy:
+            return df.lazy().with_row_index(**kwargs).collect()
+        return df.with_row_index(**kwargs)
+
+    with pytest.raises(exc):
+        with_row_index(offset=1, step=-1, dtype=pl.UInt64)
+    with pytest.raises(exc):
+        with_row_index(offset=2**63 - 2, step=1)
+
+    # Values beyond the Int64 range are valid for UInt64.
+    out = with_row_index(offset=2**63 - 2, step=2**62, dtype=pl.UInt64)
+    assert out["index"].to_list() == [2**63 - 2, 2**63 + 2**62 - 2, 2**64 - 2]
+
+
 def test_with_row_count_deprecated() -> None:
     df = pl.DataFrame({"a": [1, 1, 3], "b": [1.0, 2.0, 2.0]})
 
@@ -2196,6 +2312,27 @@ def test_fill_nan() -> None:
     assert df.fill_nan(2.0).dtypes == [pl.Float64, pl.Datetime]
 
 
+def test_nan_to_null() -> None:
+    nan = float("nan")
+    df = pl.DataFrame(
+        {
+            "a": [1.0, nan, None],
+            "b": pl.Series([nan, 2.0, nan], dtype=pl.Float32),
+            "c": [1, 2, 3],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1.0, None, None],
+            "b": pl.Series([None, 2.0, None], dtype=pl.Float32),
+            "c": [1, 2, 3],
+        }
+    )
+    assert_frame_equal(df.nan_to_null(), expected)
+    assert_frame_equal(df.lazy().nan_to_null().collect(), expected)
+    assert df.nan_to_null()["a"].n_unique() == 2
+
+
 #
 def test_forward_fill() -> None:
     df = pl.DataFrame({"a": [1.0, None, 3.0]})
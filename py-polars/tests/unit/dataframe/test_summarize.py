@@ -0,0 +1,51 @@
+from __future__ import annotations
+
+from datetime import date
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_summarize() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3, None, 1, 2],
+            "b": ["x", "y", None, None],
+            "c": [date(2020, 1, 2), date(2020, 1, 1), None, date(2021, 1, 1)],
+            "d": [[1], [2, 3], [], None],
+        }
+    )
+    out = df.summarize(n_samples=2)
+    expected = pl.DataFrame(
+        {
+            "column": ["a", "b", "c", "d"],
+            "dtype": ["i64", "str", "date", "list[i64]"],
+            "null_count": [1, 2, 1, 1],
+            "min": ["1", "x", "2020-01-01", None],
+            "max": ["3", "y", "2021-01-01", None],
+            "samples": [["3", None], ["x", "y"], ["2020-01-02", "2020-01-01"], None],
+        },
+        schema_overrides={"null_count": pl.get_index_type()},
+    )
+    assert_frame_equal(out.drop("n_unique_approx"), expected)
+    assert out["n_unique_approx"].to_list()[:3] == [4, 3, 4]
+
+
+def test_summarize_empty() -> None:
+    out = pl.DataFrame().summarize()
+    assert out.height == 0
+    assert out.schema["samples"] == pl.List(pl.String)
+
+
+def test_summarize_nan() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [float("nan"), 2.0, None, -1.0],
+            "b": [float("nan"), None, float("nan"), None],
+            "c": [True, False, None, True],
+        }
+    )
+    out = df.summarize(n_samples=0)
+    # NaNs are ignored by min and max unless there are no other values.
+    assert out["min"].to_list() == ["-1.0", "NaN", "false"]
+    assert out["max"].to_list() == ["2.0", "NaN", "true"]
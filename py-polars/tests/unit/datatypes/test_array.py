@@ -27,6 +27,32 @@ def test_cast_list_array() -> None:
         s.cast(pl.Array(pl.Int64, 2))
 
 
+def test_cast_list_array_wrong_width() -> None:
+    s = pl.Series([[1, 2], [3], None, [4, 5, 6], [7, 8]])
+
+    with pytest.raises(
+        ComputeError,
+        match=r"not all elements have the specified width 2: row 1 has 1 elements",
+    ):
+        s.cast(pl.Array(pl.Int64, 2))
+
+    out = s.cast(pl.Array(pl.Int64, 2), strict=False)
+    assert out.dtype == pl.Array(pl.Int64, 2)
+    assert out.to_list() == [[1, 2], None, None, None, [7, 8]]
+
+    out = s.cast(pl.Array(pl.Float64, 2), strict=False)
+    assert out.to_list() == [[1.0, 2.0], None, None, None, [7.0, 8.0]]
+
+
+def test_concat_list_array_relaxed() -> None:
+    a = pl.DataFrame({"x": [[1.0, 2.0, 3.0]]}, schema={"x": pl.Array(pl.Float64, 3)})
+    b = pl.DataFrame({"x": [[4.0]]}, schema={"x": pl.List(pl.Float64)})
+
+    out = pl.concat([a, b], how="vertical_relaxed")
+    assert out.schema == pl.Schema({"x": pl.List(pl.Float64)})
+    assert out["x"].to_list() == [[1.0, 2.0, 3.0], [4.0]]
+
+
 def test_array_in_group_by_iter() -> None:
     df = pl.DataFrame(
         [
@@ -31,6 +31,33 @@ def test_bool_sum_empty() -> None:
     assert pl.Series([], dtype=pl.Boolean).sum() == 0
 
 
+def test_bool_group_by_sum() -> None:
+    values = [True, None, True, False, True, None, False, True, True, None]
+    a = pl.Series("a", values[:4])
+    df = pl.DataFrame(
+        {
+            "g": [0, 0, 0, 1, 1, 1, 2, 2, 3, 3],
+            # Multiple chunks, of which one has an offset.
+            "a": pl.concat([a, pl.Series("a", values)[4:]], rechunk=False),
+        }
+    )
+    expected = pl.DataFrame(
+        {"g": [0, 1, 2, 3], "a": [2, 1, 1, 1]},
+        schema_overrides={"a": pl.get_index_type()},
+    )
+
+    # Slice groups.
+    result = (
+        df.set_sorted("g").group_by("g", maintain_order=True).agg(pl.col("a").sum())
+    )
+    assert_frame_equal(result, expected)
+
+    # Index groups.
+    df = df.reverse()
+    result = df.group_by("g").agg((pl.col("a") & pl.lit(True)).sum()).sort("g")
+    assert_frame_equal(result, expected)
+
+
 def test_bool_min_max() -> None:
     assert pl.Series([None, True]).min()
     assert not pl.Series([None, True, False]).min()
@@ -4,10 +4,11 @@ import io
 import itertools
 import operator
 from dataclasses import dataclass
+from decimal import ROUND_CEILING, ROUND_FLOOR, ROUND_HALF_EVEN, ROUND_HALF_UP
 from decimal import Decimal as D
 from math import ceil, floor
 from random import choice, randrange, seed
-from typing import Any, Callable, NamedTuple
+from typing import TYPE_CHECKING, Any, Callable, NamedTuple
 
 import pyarrow as pa
 import pytest
@@ -15,6 +16,9 @@ import pytest
 import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 
+if TYPE_CHECKING:
+    from polars._typing import RoundMode
+
 
 @pytest.fixture(scope="module")
 def permutations_int_dec_none() -> list[tuple[D | int | None, ...]]:
@@ -572,6 +576,33 @@ def test_decimal_round() -> None:
 
         assert_series_equal(got_s, expected_s)
 
+    tenths = D("0.1")
+    floor_s = pl.Series("a", [v.quantize(tenths, ROUND_FLOOR) for v in values], dtype)
+    ceil_s = pl.Series("a", [v.quantize(tenths, ROUND_CEILING) for v in values], dtype)
+    assert_series_equal(i_s.round(1, mode="floor"), floor_s)
+    assert_series_equal(i_s.round(1, mode="ceil"), ceil_s)
+
+
+@pytest.mark.parametrize(
+    ("mode", "rounding"),
+    [
+        ("half_to_even", ROUND_HALF_EVEN),
+        ("half_away_from_zero", ROUND_HALF_UP),
+        ("floor", ROUND_FLOOR),
+        ("ceil", ROUND_CEILING),
+    ],
+)
+def test_decimal_round_to_increment(mode: RoundMode, rounding: str) -> None:
+    values = [D(v) / 1000 for v in range(-300, 300)]
+    s = pl.Series("a", values, pl.Decimal(6, 3))
+
+    # Rounding to a multiple of 0.05 is rounding twice the value to 0.1.
+    expected = [(2 * v).quantize(D("0.1"), rounding=rounding) / 2 for v in values]
+    assert_series_equal(
+        s.round_to_increment(0.05, mode=mode),
+        pl.Series("a", expected, pl.Decimal(6, 3)),
+    )
+
 
 def test_decimal_arithmetic_schema() -> None:
     q = pl.LazyFrame({"x": [1.0]}, schema={"x": pl.Decimal(15, 2)})
@@ -687,3 +687,35 @@ def test_read_enum_from_csv() -> None:
     read = pl.read_csv(f, schema=schema)
     assert read.schema == schema
     assert_frame_equal(df.cast(schema), read)  # type: ignore[arg-type]
+
+
+def test_enum_physical() -> None:
+    # By default the smallest physical type that fits the categories is used.
+    assert pl.Series(["a"], dtype=pl.Enum(["a"])).to_physical().dtype == pl.UInt8
+    categories = [str(i) for i in range(300)]
+    assert pl.Series(["a"], dtype=pl.Enum(["a", *categories])).to_physical().dtype == (
+        pl.UInt16
+    )
+
+    dtype = pl.Enum(["a", "b"], physical=pl.UInt32)
+    s = pl.Series(["b", "a", None], dtype=dtype)
+    assert s.dtype == dtype
+    assert s.dtype != pl.Enum(["a", "b"])
+    assert s.to_physical().dtype == pl.UInt32
+    assert repr(dtype) == "Enum(categories=['a', 'b'], physical=pl.UInt32)"
+
+    # Changing the physical type keeps the values.
+    out = s.cast(pl.Enum(["a", "b"]))
+    assert out.to_physical().dtype == pl.UInt8
+    assert out.to_list() == ["b", "a", None]
+
+    # The physical type is kept through serialization.
+    lf = pl.LazyFrame({"a": ["a"]}).cast({"a": dtype})
+    assert pl.LazyFrame.deserialize(io.BytesIO(lf.serialize())).collect_schema() == {
+        "a": dtype
+    }
+
+    with pytest.raises(ValueError, match="do not fit in physical type UInt8"):
+        pl.Enum(categories, physical=pl.UInt8)
+    with pytest.raises(TypeError, match="Enum physical must be one of"):
+        pl.Enum(["a"], physical=pl.Int32)
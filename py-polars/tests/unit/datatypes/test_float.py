@@ -1,3 +1,5 @@
+import io
+
 import pyarrow as pa
 import pytest
 
@@ -309,3 +311,89 @@ def test_arrow_float16_read_empty_20946() -> None:
     df = pl.from_arrow(table)
     assert df.shape == (0, 1)
     assert df.schema == pl.Schema([("float_column", pl.Float32)])  # type: ignore[union-attr]
+
+
+def test_float16_storage() -> None:
+    s = pl.Series("a", [1.0, 0.1, None, 70000.0], dtype=pl.Float16)
+    assert s.dtype == pl.Float16
+    assert s.to_list() == [1.0, 0.0999755859375, None, float("inf")]
+    assert_series_equal(
+        s.cast(pl.Float32),
+        pl.Series("a", [1.0, 0.0999755859375, None, float("inf")], dtype=pl.Float32),
+    )
+    assert s.to_arrow().type == pa.float16()
+
+
+def test_float16_compute_in_float32() -> None:
+    s = pl.Series("a", [1.5, 2.5, None], dtype=pl.Float16)
+    assert (s + s).dtype == pl.Float32
+    assert (s + s).to_list() == [3.0, 5.0, None]
+    assert (s * 2).dtype == pl.Float32
+
+    df = pl.DataFrame({"a": s})
+    out = df.select(
+        pl.col("a").sum().alias("sum"),
+        pl.col("a").mean().alias("mean"),
+        pl.col("a").min().alias("min"),
+        pl.col("a").sqrt().alias("sqrt"),
+    )
+    assert out.schema == {
+        "sum": pl.Float32,
+        "mean": pl.Float32,
+        "min": pl.Float16,
+        "sqrt": pl.Float32,
+    }
+    assert out.row(0)[:3] == (4.0, 2.0, 1.5)
+
+
+def test_float16_min_max_streaming() -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 1, 2, 2, 2],
+            "a": pl.Series([1.5, None, -2.0, float("nan"), 0.5], dtype=pl.Float16),
+        }
+    )
+    out = (
+        lf.group_by("g")
+        .agg(
+            pl.col("a").min().alias("min"),
+            pl.col("a").max().alias("max"),
+            pl.col("a").nan_max().alias("nan_max"),
+        )
+        .sort("g")
+        .collect(engine="streaming")
+    )
+    assert out.schema == {
+        "g": pl.Int64,
+        "min": pl.Float16,
+        "max": pl.Float16,
+        "nan_max": pl.Float16,
+    }
+    assert out["min"].to_list() == [1.5, -2.0]
+    assert out["max"].to_list() == [1.5, 0.5]
+    assert out["nan_max"].is_nan().to_list() == [False, True]
+
+    out = lf.select(
+        pl.col("a").min().alias("min"), pl.col("a").max().alias("max")
+    ).collect(engine="streaming")
+    assert out.schema == {"min": pl.Float16, "max": pl.Float16}
+    assert out.row(0) == (-2.0, 1.5)
+
+
+def test_float16_storage_ops_keep_dtype() -> None:
+    s = pl.Series("a", [3.0, None, 1.0], dtype=pl.Float16)
+    assert s.fill_null(0.0).dtype == pl.Float16
+    assert s.reverse().dtype == pl.Float16
+    assert s.sort().to_list() == [None, 1.0, 3.0]
+    assert s.head(1).dtype == pl.Float16
+
+
+@pytest.mark.parametrize("fmt", ["parquet", "ipc"])
+def test_float16_roundtrip(fmt: str) -> None:
+    df = pl.DataFrame({"a": pl.Series([0.5, None, -2.0], dtype=pl.Float16)})
+    f = io.BytesIO()
+    getattr(df, f"write_{fmt}")(f)
+    f.seek(0)
+    out = getattr(pl, f"read_{fmt}")(f)
+    assert out.schema == {"a": pl.Float16}
+    assert_series_equal(out["a"], df["a"])
@@ -1,4 +1,12 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
 import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
 
 
 def test_integer_float_functions() -> None:
@@ -29,3 +37,54 @@ def test_compare_zero_with_uint64_16798() -> None:
     df = pl.Series("a", [(1 << 63), 0], dtype=pl.UInt64).to_frame()
     assert df.select(pl.col("a") >= 0).item(0, 0)
     assert df.select(pl.col("a") == 0).item(0, 0) is False
+
+
+BIG = 2**100
+
+
+def test_int128_arithmetic() -> None:
+    s = pl.Series("a", [BIG, -BIG, None], dtype=pl.Int128)
+    assert (-s).to_list() == [-BIG, BIG, None]
+    assert (s + 1).to_list() == [BIG + 1, -BIG + 1, None]
+    assert (s * 2).dtype == pl.Int128
+    assert (s // 3).to_list() == [BIG // 3, -BIG // 3, None]
+    assert (s > 0).to_list() == [True, False, None]
+    assert (s == BIG).to_list() == [True, False, None]
+
+
+def test_int128_set_and_scatter() -> None:
+    s = pl.Series("a", [1, 2, 3], dtype=pl.Int128)
+    assert s.set(s == 2, BIG).to_list() == [1, BIG, 3]
+    assert s.scatter(0, -BIG).to_list() == [-BIG, 2, 3]
+
+
+def test_int128_group_by_keys() -> None:
+    df = pl.DataFrame(
+        {
+            "k": pl.Series([BIG, BIG + 1, BIG, BIG + 1], dtype=pl.Int128),
+            "v": [1, 2, 3, 4],
+        }
+    )
+    out = df.group_by("k", maintain_order=True).agg(pl.col("v").sum())
+    assert out.to_dict(as_series=False) == {"k": [BIG, BIG + 1], "v": [4, 6]}
+
+    out = df.group_by("k", "v", maintain_order=True).len()
+    assert out["k"].to_list() == [BIG, BIG + 1, BIG, BIG + 1]
+
+
+def test_int128_parquet_roundtrip(tmp_path: Path) -> None:
+    path = tmp_path / "int128.parquet"
+    df = pl.DataFrame({"a": pl.Series([BIG, -BIG, None, 5], dtype=pl.Int128)})
+    df.write_parquet(path, statistics=True)
+    assert_frame_equal(pl.read_parquet(path), df)
+
+    out = pl.scan_parquet(path).filter(pl.col("a") > 10).collect()
+    assert_series_equal(out["a"], pl.Series("a", [BIG], dtype=pl.Int128))
+
+
+def test_int128_to_python() -> None:
+    s = pl.Series([BIG, -BIG])
+    assert s.dtype == pl.Int128
+    assert s.to_list() == [BIG, -BIG]
+    assert s.item(0) == BIG
+    assert pl.select(pl.lit(BIG)).item() == BIG
@@ -1,9 +1,11 @@
 import io
+from datetime import date
+from decimal import Decimal
 
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ComputeError, InvalidOperationError
 
 
 @pytest.mark.parametrize(
@@ -69,3 +71,65 @@ def test_expr_write_json_from_json_deprecated() -> None:
         round_tripped = pl.Expr.from_json(json)
 
     assert round_tripped.meta == expr
+
+
+@pytest.mark.parametrize(
+    "expr",
+    [
+        pl.col("foo"),
+        pl.len(),
+        pl.lit(1) + pl.lit(-2.5),
+        pl.col("a") - pl.col("b") * 3,
+        ((pl.col("a") > 1) & ~pl.col("b").is_null()) | (pl.col("c") == 'x\n"y"'),
+        -pl.col("a").abs(),
+        pl.col("a").cast(pl.Int32, strict=False).sum().alias("b"),
+        pl.col("a").cast(pl.Datetime("ms", "UTC")),
+        pl.col("a").cast(pl.List(pl.Array(pl.Int8, 2))),
+        pl.col("a").std(ddof=0) // pl.col("b").var(),
+        pl.col("a").fill_null(pl.lit(None)).unique(maintain_order=True),
+        pl.col("a").sort(descending=True, nulls_last=True).first(),
+        pl.col("a").any(ignore_nulls=False),
+        pl.col("a").eq_missing(None),
+        pl.when(pl.col("a") > 0).then(1).when(pl.col("a") < 0).then(-1).otherwise(0),
+        pl.when(pl.col("a").is_finite()).then(float("inf")),
+        pl.col("a").sum().over("b", "c", mapping_strategy="join"),
+        pl.col("a").first().over(order_by="b", descending=True),
+        pl.col("a").filter(pl.col("b") % 2 == 0).mean(),
+        pl.col("a") + pl.lit(date(2020, 1, 1)),
+        pl.lit(5, dtype=pl.Int16),
+        pl.lit(Decimal("-12.05")),
+    ],
+)
+def test_expr_text_roundtrip(expr: pl.Expr) -> None:
+    text = expr.meta.to_text()
+    assert pl.Expr.from_text(text).meta == expr
+
+
+def test_expr_text_format() -> None:
+    expr = (pl.col("a") + 1).cast(pl.Int64).sum().over("b").alias("c")
+    text = '(col("a") + 1).cast(Int64).sum().over(col("b")).alias("c")'
+    assert expr.meta.to_text() == text
+    assert pl.lit(float("nan")).meta.to_text() == 'float("nan")'
+    assert pl.lit(date(2020, 1, 1)).meta.to_text() == "lit(18262, dtype=Date)"
+
+    # Decimals are written with their logical value.
+    assert pl.lit(Decimal("1.25")).meta.to_text().startswith('lit("1.25", dtype=')
+    expr = pl.Expr.from_text('lit("1.25", dtype=Decimal(10, 2))')
+    assert pl.select(expr).item() == Decimal("1.25")
+
+    # Hand-written text follows the operator precedence of Python.
+    expr = pl.Expr.from_text("col('a') > 1 | col('b')")
+    assert expr.meta == (pl.col("a") > 1 | pl.col("b"))
+
+
+def test_expr_text_errors() -> None:
+    with pytest.raises(InvalidOperationError, match="cannot convert"):
+        pl.col("a").map_batches(lambda s: s).meta.to_text()
+    with pytest.raises(ComputeError, match="unknown method 'foo'"):
+        pl.Expr.from_text('col("a").foo()')
+    with pytest.raises(ComputeError, match="position 10: unexpected end of input"):
+        pl.Expr.from_text('col("a") +')
+    with pytest.raises(ComputeError, match="maximum nesting depth"):
+        pl.Expr.from_text("(" * 10_000 + "1" + ")" * 10_000)
+    with pytest.raises(ComputeError, match="maximum nesting depth"):
+        pl.Expr.from_text("-" * 10_000 + "col('a')")
@@ -393,6 +393,43 @@ def test_align_frames_with_nulls() -> None:
     )
 
 
+def test_align_frames_fill_strategy() -> None:
+    df1 = pl.DataFrame({"id": [1, 1, 2], "t": [1, 2, 1], "x": [1.0, None, 3.0]})
+    df2 = pl.DataFrame({"id": [2, 1, 2], "t": [2, 1, 1], "y": [30, 10, 20]})
+    df3 = pl.DataFrame({"id": [1], "t": [3], "z": [5]})
+
+    a1, a2, a3 = pl.align_frames(
+        df1.lazy(),
+        df2.lazy(),
+        df3.lazy(),
+        on=["id", "t"],
+        fill_strategy=["zero", "forward", "forward"],
+    )
+    assert isinstance(a1, pl.LazyFrame)
+    a1, a2, a3 = pl.collect_all([a1, a2, a3])
+    assert a1.to_dict(as_series=False) == {
+        "id": [1, 1, 1, 2, 2],
+        "t": [1, 2, 3, 1, 2],
+        "x": [1.0, 0.0, 0.0, 3.0, 0.0],
+    }
+    assert a2["y"].to_list() == [10, 10, 10, 20, 30]
+    # values are not carried forward from one id to the next
+    assert a3["z"].to_list() == [None, None, 5, None, None]
+    for df in (a1, a2, a3):
+        assert df["id"].flags["SORTED_ASC"]
+
+    df = pl.DataFrame({"id": [1, 2, 3], "x": [1.0, None, 3.0]})
+    a1, a2 = pl.align_frames(
+        df, df, on="id", descending=True, fill_strategy=["backward", None]
+    )
+    assert a1["x"].to_list() == [3.0, 1.0, 1.0]
+    assert a2["x"].to_list() == [3.0, None, 1.0]
+    assert a1["id"].flags["SORTED_DESC"]
+
+    with pytest.raises(ValueError, match="has 1 strategies, but 2 frames were given"):
+        pl.align_frames(df1, df2, on="id", fill_strategy=["forward"])
+
+
 def test_align_frames_duplicate_key() -> None:
     # setup some test frames with duplicate key/alignment values
     df1 = pl.DataFrame({"x": ["a", "a", "a", "e"], "y": [1, 2, 4, 5]})
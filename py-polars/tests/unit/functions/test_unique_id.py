@@ -0,0 +1,47 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+
+def test_uuid4() -> None:
+    df = pl.DataFrame({"a": range(1000)}).with_columns(id=pl.uuid4())
+    assert df.schema["id"] == pl.String
+    assert df["id"].n_unique() == 1000
+    assert df["id"].str.contains(
+        r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}$"
+    ).all()
+
+
+def test_unique_id_lazy_schema() -> None:
+    lf = pl.LazyFrame({"a": [1, 2]}).with_columns(pl.uuid4(), pl.snowflake_id())
+    schema = pl.Schema({"a": pl.Int64, "uuid": pl.String, "id": pl.UInt64})
+    assert lf.collect_schema() == schema
+    assert lf.collect().schema == schema
+
+
+def test_snowflake_id() -> None:
+    df = pl.DataFrame({"a": range(10_000)}).with_columns(id=pl.snowflake_id(5))
+    assert df.schema["id"] == pl.UInt64
+    ids = df["id"]
+    assert (ids.diff().drop_nulls() > 0).all()
+    assert ((ids // 2**12) % 2**10 == 5).all()
+
+    # IDs keep increasing across evaluations.
+    more = pl.select(pl.repeat(0, 3)).with_columns(id=pl.snowflake_id(5))["id"]
+    assert more.min() > ids.max()  # type: ignore[operator]
+
+
+def test_snowflake_id_not_cse() -> None:
+    df = pl.LazyFrame({"a": [1, 2, 3]}).select(
+        x=pl.snowflake_id(), y=pl.snowflake_id()
+    )
+    out = df.collect(optimizations=pl.QueryOptFlags(comm_subexpr_elim=True))
+    assert (out["x"] != out["y"]).all()
+
+
+@pytest.mark.parametrize("worker_id", [-1, 1024])
+def test_snowflake_id_invalid_worker_id(worker_id: int) -> None:
+    with pytest.raises(ValueError, match="between 0 and 1023"):
+        pl.snowflake_id(worker_id)
@@ -928,6 +928,58 @@ def test_to_arrow_empty_chunks_20627() -> None:
     assert df.to_arrow().shape == (1, 1)
 
 
+def test_to_arrow_export_options() -> None:
+    df = pl.DataFrame(
+        {
+            "s": ["a", "b"],
+            "l": [["x"], ["y", "z"]],
+            "c": pl.Series(["x", "y"], dtype=pl.Categorical),
+        }
+    )
+
+    schema = df.to_arrow().schema
+    assert schema.field("s").type == pa.large_string()
+    assert schema.field("l").type == pa.large_list(pa.large_string())
+    assert schema.field("c").type.value_type == pa.large_string()
+
+    tbl = df.to_arrow(large_offsets=False)
+    assert tbl.schema.field("s").type == pa.string()
+    assert tbl.schema.field("l").type == pa.list_(pa.string())
+    assert pa.types.is_dictionary(tbl.schema.field("c").type)
+    assert tbl.schema.field("c").type.value_type == pa.string()
+    assert_frame_equal(pl.from_arrow(tbl), df)  # type: ignore[arg-type]
+
+    tbl = df.to_arrow(categorical_as_dictionary=False)
+    assert tbl.schema.field("c").type == pa.large_string()
+    assert tbl.column("c").to_pylist() == ["x", "y"]
+
+
+def test_to_arrow_column_options() -> None:
+    df = pl.DataFrame(
+        {
+            "s": ["a", "b"],
+            "c": pl.Series(["x", "y"], dtype=pl.Categorical),
+        }
+    )
+    tbl = df.to_arrow(
+        column_options={
+            "s": {"large_offsets": False},
+            "c": {
+                "compat_level": CompatLevel.newest(),
+                "categorical_as_dictionary": False,
+            },
+        },
+    )
+    assert tbl.schema.field("s").type == pa.string()
+    assert tbl.schema.field("c").type == pa.string_view()
+    assert tbl.to_pydict() == {"s": ["a", "b"], "c": ["x", "y"]}
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        df.to_arrow(column_options={"missing": {"large_offsets": False}})
+    with pytest.raises(ValueError, match="unknown export options"):
+        df.to_arrow(column_options={"s": {"large_offset": False}})
+
+
 def test_from_arrow_recorbatch() -> None:
     n_legs = pa.array([2, 2, 4, 4, 5, 100])
     animals = pa.array(
@@ -0,0 +1,66 @@
+from __future__ import annotations
+
+from types import ModuleType, SimpleNamespace
+from typing import Any
+
+import pyarrow as pa
+import pytest
+
+import polars as pl
+from tests.unit.conftest import mock_module_import
+
+
+class MockedCupyModule(ModuleType):
+    """Mock CuPy module, of which the pinned memory is plain host memory."""
+
+    def __init__(self, name: str) -> None:
+        super().__init__(name)
+        self.allocations: list[bytearray] = []
+        self.cuda = SimpleNamespace(alloc_pinned_memory=self._alloc_pinned_memory)
+
+    def _alloc_pinned_memory(self, size: int) -> bytearray:
+        memory = bytearray(size)
+        self.allocations.append(memory)
+        return memory
+
+
+def assert_in_memory(array: pa.Array, memory: bytearray) -> None:
+    start = pa.py_buffer(memory).address
+    for buffer in array.buffers():
+        if buffer is not None:
+            assert start <= buffer.address
+            assert buffer.address + buffer.size <= start + len(memory)
+
+
+def test_to_arrow_pinned_memory() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, None, 3],
+            "b": ["x", "y", None],
+            "c": [[1.0], [], None],
+            "d": pl.Series(["p", "q", "p"], dtype=pl.Categorical),
+        }
+    )
+    cupy = MockedCupyModule("cupy")
+    with mock_module_import("cupy", cupy, replace_if_exists=True):
+        result = df.to_arrow(pinned_memory=True)
+        series_result = df["a"].to_arrow(pinned_memory=True)
+
+    assert result.equals(df.to_arrow())
+    assert series_result.equals(df["a"].to_arrow())
+
+    assert len(cupy.allocations) == 2
+    for column in result.columns:
+        (chunk,) = column.chunks
+        assert_in_memory(chunk, cupy.allocations[0])
+    assert_in_memory(series_result, cupy.allocations[1])
+
+
+def test_to_arrow_pinned_memory_requires_cupy() -> None:
+    # A module of `None` makes its import fail.
+    missing: Any = None
+    with (
+        mock_module_import("cupy", missing, replace_if_exists=True),
+        pytest.raises(ModuleNotFoundError, match="requires 'cupy'"),
+    ):
+        pl.DataFrame({"a": [1]}).to_arrow(pinned_memory=True)
@@ -1,6 +1,18 @@
+from __future__ import annotations
+
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import TYPE_CHECKING, Any
+
 import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+    from pathlib import Path
 
 
 def test_catalog_require_https() -> None:
@@ -9,3 +21,152 @@ def test_catalog_require_https() -> None:
 
     pl.Catalog("https://")
     pl.Catalog("http://", require_https=False)
+
+
+class FakeUnityCatalog:
+    """Stores the tables that are created and updated through the tables API."""
+
+    def __init__(self) -> None:
+        self.tables: dict[str, dict[str, Any]] = {}
+        self.requests: list[tuple[str, str]] = []
+        self.patches: list[dict[str, Any]] = []
+        self.url = ""
+
+
+@pytest.fixture
+def unity_catalog() -> Iterator[FakeUnityCatalog]:
+    state = FakeUnityCatalog()
+    prefix = "/api/2.1/unity-catalog/tables"
+
+    class Handler(BaseHTTPRequestHandler):
+        def log_message(self, *args: Any) -> None:
+            pass
+
+        def _respond(self, status: int, body: dict[str, Any]) -> None:
+            data = json.dumps(body).encode()
+            self.send_response(status)
+            self.send_header("Content-Type", "application/json")
+            self.send_header("Content-Length", str(len(data)))
+            self.end_headers()
+            self.wfile.write(data)
+
+        def _body(self) -> dict[str, Any]:
+            length = int(self.headers["Content-Length"])
+            return json.loads(self.rfile.read(length))  # type: ignore[no-any-return]
+
+        def _full_name(self) -> str:
+            return self.path.split("?")[0].removeprefix(f"{prefix}/")
+
+        def do_GET(self) -> None:
+            state.requests.append(("GET", self._full_name()))
+            table = state.tables.get(self._full_name())
+            if table is None:
+                self._respond(404, {"error_code": "TABLE_DOES_NOT_EXIST"})
+            else:
+                self._respond(200, table)
+
+        def do_POST(self) -> None:
+            body = self._body()
+            full_name = f"{body['catalog_name']}.{body['schema_name']}.{body['name']}"
+            state.requests.append(("POST", full_name))
+            state.tables[full_name] = {
+                **body,
+                "table_id": full_name,
+                "created_at": None,
+                "created_by": None,
+                "updated_at": None,
+                "updated_by": None,
+            }
+            self._respond(200, state.tables[full_name])
+
+        def do_PATCH(self) -> None:
+            state.requests.append(("PATCH", self._full_name()))
+            table = state.tables[self._full_name()]
+            body = self._body()
+            state.patches.append(body)
+            table.update(body)
+            self._respond(200, table)
+
+    server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    state.url = f"http://127.0.0.1:{server.server_address[1]}"
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield state
+    server.shutdown()
+    server.server_close()
+
+
+def test_catalog_sink_table(unity_catalog: FakeUnityCatalog, tmp_path: Path) -> None:
+    catalog = pl.Catalog(unity_catalog.url, bearer_token=None, require_https=False)
+    location = str(tmp_path / "t")
+
+    lf = pl.LazyFrame({"a": [1, 2, 3], "p": ["x", "y", "x"]})
+    table_info = catalog.sink_table(
+        lf,
+        "c",
+        "ns",
+        "t",
+        storage_location=location,
+        partition_by="p",
+        properties={"k": "v"},
+        credential_provider=None,
+    )
+    assert unity_catalog.requests == [("GET", "c.ns.t"), ("POST", "c.ns.t")]
+    assert table_info.table_type == "EXTERNAL"
+    assert table_info.data_source_format == "PARQUET"
+    assert table_info.storage_location == location
+    assert table_info.properties == {"k": "v"}
+    assert table_info.get_polars_schema() == pl.Schema({"a": pl.Int64, "p": pl.String})
+    assert [c.partition_index for c in table_info.columns or []] == [None, 0]
+    assert sorted(p.name for p in (tmp_path / "t").iterdir()) == ["p=x", "p=y"]
+
+    # Appending to the existing table keeps its location and partitioning.
+    unity_catalog.requests.clear()
+    lf = pl.LazyFrame({"a": [4], "p": ["y"]})
+    table_info = catalog.sink_table(lf, "c", "ns", "t", credential_provider=None)
+    assert unity_catalog.requests == [("GET", "c.ns.t")]
+    assert table_info.get_polars_schema() == pl.Schema({"a": pl.Int64, "p": pl.String})
+
+    [file] = (tmp_path / "t" / "p=x").iterdir()
+    assert_frame_equal(pl.read_parquet(file), pl.DataFrame({"a": [1, 3]}))
+    assert len(list((tmp_path / "t" / "p=y").iterdir())) == 2
+
+    table_info = catalog.update_table("c", "ns", "t", owner="me")
+    assert unity_catalog.requests[-1] == ("PATCH", "c.ns.t")
+    assert unity_catalog.patches == [{"owner": "me"}]
+
+
+def test_catalog_sink_table_invalid(
+    unity_catalog: FakeUnityCatalog, tmp_path: Path
+) -> None:
+    catalog = pl.Catalog(unity_catalog.url, bearer_token=None, require_https=False)
+    lf = pl.LazyFrame({"a": [1]})
+
+    with pytest.raises(ValueError, match="a storage_location is required"):
+        catalog.sink_table(lf, "c", "ns", "t")
+
+    catalog.sink_table(
+        lf, "c", "ns", "t", storage_location=str(tmp_path), credential_provider=None
+    )
+    with pytest.raises(ValueError, match="does not match the location"):
+        catalog.sink_table(lf, "c", "ns", "t", storage_location="s3://bucket/t")
+
+    with pytest.raises(ValueError, match="does not match the partition columns"):
+        catalog.sink_table(
+            lf.with_columns(p=pl.lit("x")),
+            "c",
+            "ns",
+            "t",
+            partition_by="p",
+            credential_provider=None,
+        )
+
+    with pytest.raises(ValueError, match="cannot be set on the existing table"):
+        catalog.sink_table(lf, "c", "ns", "t", comment="c", credential_provider=None)
+
+    # The schema is checked before anything is written.
+    with pytest.raises(pl.exceptions.SchemaError, match="does not match the schema"):
+        catalog.sink_table(
+            lf.with_columns(b=True), "c", "ns", "t", credential_provider=None
+        )
+    assert len(list(tmp_path.iterdir())) == 1
@@ -1,7 +1,10 @@
 from __future__ import annotations
 
 import io
-from typing import TYPE_CHECKING
+from datetime import date, datetime
+from decimal import Decimal as D
+from typing import TYPE_CHECKING, Any
+from unittest.mock import patch
 
 import pytest
 
@@ -89,3 +92,62 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+def test_read_logical_types() -> None:
+    df = pl.DataFrame(
+        {
+            "dec": pl.Series([D("1.23"), None], dtype=pl.Decimal(10, 2)),
+            "ts": pl.Series([datetime(2020, 1, 1, 12), None], dtype=pl.Datetime("ms")),
+            "date": [date(2020, 1, 1), None],
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_avro(f)
+    f.seek(0)
+
+    assert_frame_equal(pl.read_avro(f), df)
+    assert pl.scan_avro(f.getvalue()).collect_schema() == df.schema
+
+
+def test_scan_avro() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [True, False, True], "c": ["a", "b", "c"]})
+
+    f = io.BytesIO()
+    df.write_avro(f)
+    f.seek(0)
+
+    lf = pl.scan_avro(f)
+    assert lf.collect_schema() == df.schema
+    assert_frame_equal(lf.collect(), df)
+    assert_frame_equal(
+        lf.filter(pl.col("a") > 1).select("c").collect(),
+        pl.DataFrame({"c": ["b", "c"]}),
+    )
+    assert_frame_equal(lf.head(2).collect(), df.head(2))
+    assert_frame_equal(
+        lf.filter(pl.col("b")).head(1).collect(),
+        pl.DataFrame({"a": [1], "b": [True], "c": ["a"]}),
+    )
+    assert_frame_equal(pl.scan_avro(f.getvalue(), n_rows=1).collect(), df.head(1))
+
+
+@pytest.mark.write_disk
+def test_scan_avro_projection_pushdown(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": [True, False, True], "c": ["a", "b", "c"]})
+    file_path = tmp_path / "small.avro"
+    df.write_avro(file_path)
+
+    seen = []
+    read_avro = pl.io.avro.read_avro
+
+    def spy(*args: Any, **kwargs: Any) -> pl.DataFrame:
+        seen.append(kwargs["columns"])
+        return read_avro(*args, **kwargs)
+
+    with patch("polars.io.avro.read_avro", spy):
+        out = pl.scan_avro(file_path).select("c", "a").collect()
+
+    assert_frame_equal(out, df.select("c", "a"))
+    assert sorted(seen[0]) == ["a", "c"]
@@ -0,0 +1,82 @@
+from __future__ import annotations
+
+import io
+import struct
+from datetime import datetime
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def _element(ty: int, name: str, value: bytes) -> bytes:
+    return bytes([ty]) + name.encode() + b"\x00" + value
+
+
+def _document(*elements: bytes) -> bytes:
+    body = b"".join(elements)
+    return struct.pack("<i", len(body) + 5) + body + b"\x00"
+
+
+def _string(s: str) -> bytes:
+    return struct.pack("<i", len(s) + 1) + s.encode() + b"\x00"
+
+
+@pytest.fixture
+def data() -> bytes:
+    array = _document(
+        _element(0x12, "0", struct.pack("<q", 2)),
+        _element(0x01, "1", struct.pack("<d", 0.5)),
+    )
+    return _document(
+        _element(0x10, "a", struct.pack("<i", 1)),
+        _element(0x04, "b", array),
+    ) + _document(
+        _element(0x03, "c", _document(_element(0x02, "d", _string("x")))),
+        _element(0x0A, "a", b""),
+    )
+
+
+def test_read_bson(data: bytes) -> None:
+    df = pl.read_bson(io.BytesIO(data))
+    expected = pl.DataFrame(
+        {
+            "a": [1, None],
+            "b": [[2.0, 0.5], None],
+            "c": [None, {"d": "x"}],
+        }
+    )
+    assert_frame_equal(df, expected)
+
+
+def test_read_bson_columns_n_rows(data: bytes, tmp_path: Path) -> None:
+    path = tmp_path / "data.bson"
+    path.write_bytes(data)
+
+    df = pl.read_bson(path, columns=["c", "a"], n_rows=1)
+    expected = pl.DataFrame(
+        {"c": [None], "a": [1]},
+        schema={"c": pl.Null, "a": pl.Int64},
+    )
+    assert_frame_equal(df, expected)
+
+
+def test_read_bson_object_id_datetime() -> None:
+    data = _document(
+        _element(0x07, "_id", bytes(range(12))),
+        _element(0x09, "t", struct.pack("<q", 1_000)),
+    )
+    df = pl.read_bson(io.BytesIO(data))
+    assert df.schema == {"_id": pl.String, "t": pl.Datetime("ms")}
+    assert df.row(0) == ("000102030405060708090a0b", datetime(1970, 1, 1, 0, 0, 1))
+
+
+def test_read_bson_decimal128_unsupported() -> None:
+    data = _document(_element(0x13, "x", bytes(16)))
+    with pytest.raises(pl.exceptions.ComputeError, match="Decimal128"):
+        pl.read_bson(io.BytesIO(data))
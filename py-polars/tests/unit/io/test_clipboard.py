@@ -0,0 +1,52 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+import polars.io.clipboard
+from polars.testing import assert_frame_equal
+
+
+@pytest.mark.parametrize(
+    ("text", "separator"),
+    [
+        ("a\tb\n1,234\t2\n", "\t"),
+        ("a,b\n1,2\n", ","),
+        ('a;b\n"x;y";2\n', ";"),
+        ("", "\t"),
+    ],
+)
+def test_read_clipboard_sniff_separator(
+    text: str, separator: str, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setattr(polars.io.clipboard, "_read_clipboard_string", lambda: text)
+    from polars.polars import sniff_clipboard_separator
+
+    assert sniff_clipboard_separator(text) == separator
+    if text:
+        assert pl.read_clipboard(separator=None).columns == ["a", "b"]
+
+
+def test_read_clipboard_spreadsheet_numbers(monkeypatch: pytest.MonkeyPatch) -> None:
+    text = (
+        "name\tamount\tshare\tcode\n"
+        "x\t1,234\t12.5%\t1,2\n"
+        "y\t(1,000)\t100%\tab\n"
+        "z\t\t-3%\t\n"
+    )
+    monkeypatch.setattr(polars.io.clipboard, "_read_clipboard_string", lambda: text)
+
+    expected = pl.DataFrame(
+        {
+            "name": ["x", "y", "z"],
+            "amount": [1234, -1000, None],
+            "share": [0.125, 1.0, -0.03],
+            "code": ["1,2", "ab", None],
+        }
+    )
+    assert_frame_equal(pl.read_clipboard(parse_numbers=True), expected)
+
+    out = pl.read_clipboard()
+    assert out.schema == pl.Schema(
+        {"name": pl.String, "amount": pl.String, "share": pl.String, "code": pl.String}
+    )
@@ -1046,3 +1046,81 @@ print("OK", end="")
     )
 
     assert out == b"OK"
+
+
+@pytest.mark.write_disk
+def test_hive_filter_narrows_glob_listing(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    for year in [2023, 2024]:
+        for month in ["01", "2"]:
+            path = tmp_path / f"year={year}" / f"month={month}" / "data.parquet"
+            path.parent.mkdir(parents=True)
+            pl.DataFrame({"x": [f"{year}-{month}"]}).write_parquet(path)
+
+    source = tmp_path / "year=*" / "month=*" / "*.parquet"
+    hive_schema = {"year": pl.Int64, "month": pl.Int64}
+    lf = pl.scan_parquet(
+        source,
+        schema={"x": pl.String},
+        hive_partitioning=True,
+        hive_schema=hive_schema,
+    )
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    out = lf.filter(pl.col("year") == 2024).collect()
+    assert "1 patterns matched 2 files" in capfd.readouterr().err
+    assert out.sort("x").to_dict(as_series=False) == {
+        "x": ["2024-01", "2024-2"],
+        "year": [2024, 2024],
+        "month": [1, 2],
+    }
+
+    out = lf.filter(pl.col("year") == 2024, pl.col("month") == 2).collect()
+    assert "1 patterns matched 1 files" in capfd.readouterr().err
+    assert out["x"].to_list() == ["2024-2"]
+
+    # `month=01` is not matched by the narrowed pattern, so all files are listed.
+    out = lf.filter(pl.col("year") == 2023, pl.col("month") == 1).collect()
+    assert "1 patterns matched 0 files" in capfd.readouterr().err
+    assert out["x"].to_list() == ["2023-01"]
+
+    # The narrowed listing is cached for the scan.
+    lf.filter(pl.col("year") == 2024).collect()
+    assert "patterns matched" not in capfd.readouterr().err
+
+    # Alternatives are not pushed into the listing.
+    out = lf.filter((pl.col("year") == 2024) | (pl.col("month") == 1)).collect()
+    assert "patterns matched" not in capfd.readouterr().err
+    assert out.height == 3
+
+    # The schemas would be inferred from the narrowed files only.
+    out = (
+        pl.scan_parquet(source, hive_partitioning=True)
+        .filter(pl.col("year") == 2024)
+        .collect()
+    )
+    assert "patterns matched" not in capfd.readouterr().err
+    assert out.height == 2
+
+    # The row index and the slice count the rows of all files.
+    out = (
+        pl.scan_parquet(
+            source,
+            schema={"x": pl.String},
+            hive_partitioning=True,
+            hive_schema=hive_schema,
+            row_index_name="index",
+            n_rows=3,
+        )
+        .filter(pl.col("year") == 2024)
+        .collect()
+    )
+    assert "patterns matched" not in capfd.readouterr().err
+    assert out.to_dict(as_series=False) == {
+        "index": [2],
+        "x": ["2024-01"],
+        "year": [2024],
+        "month": [1],
+    }
@@ -36,6 +36,29 @@ def test_write_json() -> None:
     assert_frame_equal(result, df)
 
 
+def test_write_json_pretty_sort_keys(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "z": [1, 2],
+            "s": [{"y": 'a"}', "x": [{"q": 1.5, "p": None}]}, None],
+            "e": [[], [3]],
+            "a": ["x", None],
+        }
+    )
+    rows = df.rows(named=True)
+
+    assert df.write_json(pretty=True) == json.dumps(rows, indent=2)
+
+    out = df.write_json(sort_keys=True)
+    assert out == json.dumps(rows, sort_keys=True, separators=(",", ":"))
+
+    path = tmp_path / "pretty.json"
+    df.write_json(path, pretty=True, sort_keys=True)
+    assert path.read_text() == json.dumps(rows, indent=2, sort_keys=True)
+
+    assert df.clear().write_json(pretty=True) == "[]"
+
+
 def test_write_json_categoricals() -> None:
     data = {"column": ["test1", "test2", "test3", "test4"]}
     df = pl.DataFrame(data).with_columns(pl.col("column").cast(pl.Categorical))
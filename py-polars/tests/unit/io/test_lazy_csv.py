@@ -1,15 +1,18 @@
 from __future__ import annotations
 
 import io
+import tarfile
 import tempfile
+import zipfile
 from collections import OrderedDict
+from datetime import date, datetime, time
 from pathlib import Path
 
 import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, ShapeError
+from polars.exceptions import ComputeError, InvalidOperationError, ShapeError
 from polars.testing import assert_frame_equal
 
 
@@ -522,3 +525,70 @@ def test_csv_io_object_utf8_23629() -> None:
         f_str.seek(0)
         df_str = pl.read_csv(f_str)
         assert_frame_equal(df, df_str)
+
+
+@pytest.mark.parametrize("archive", ["zip", "tar", "tar.gz"])
+def test_scan_csv_archive(tmp_path: Path, archive: str) -> None:
+    members = {
+        "data/a.csv": b"x,y\n1,a\n2,b\n",
+        "data/b.csv": b"x,y\n3,c",
+        "README.txt": b"not a csv",
+    }
+    path = tmp_path / f"data.{archive}"
+    if archive == "zip":
+        with zipfile.ZipFile(path, "w", compression=zipfile.ZIP_DEFLATED) as f:
+            for name, content in members.items():
+                f.writestr(name, content)
+    else:
+        with tarfile.open(path, "w:gz" if archive == "tar.gz" else "w") as f:
+            for name, content in members.items():
+                info = tarfile.TarInfo(name)
+                info.size = len(content)
+                f.addfile(info, io.BytesIO(content))
+
+    expected = pl.DataFrame({"x": [1, 2, 3], "y": ["a", "b", "c"]})
+    lf = pl.scan_csv(path, archive_member_glob="data/*.csv")
+    assert_frame_equal(lf.collect(), expected)
+    assert lf.select(pl.len()).collect().item() == 3
+
+    with pytest.raises(ComputeError, match="no archive member matches"):
+        pl.scan_csv(path, archive_member_glob="*.parquet").collect()
+
+
+def test_scan_csv_date_inference(
+    capfd: pytest.CaptureFixture[str], monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    source = (
+        b"mdy,dt,t,s\n"
+        b"01/02/2024,2024-01-02 10:30,10:30:00,01/02/2024\n"
+        b"01/13/2024,2024-01-13 08:00,08:00:00,x\n"
+    )
+    result = pl.scan_csv(source, try_parse_dates=pl.DateInference()).collect()
+    expected = pl.DataFrame(
+        {
+            "mdy": [date(2024, 1, 2), date(2024, 1, 13)],
+            "dt": [datetime(2024, 1, 2, 10, 30), datetime(2024, 1, 13, 8)],
+            "t": [time(10, 30), time(8)],
+            "s": ["01/02/2024", "x"],
+        }
+    )
+    assert_frame_equal(result, expected)
+    err = capfd.readouterr().err
+    assert "inferred format '%m/%d/%Y' (date) for column 'mdy'" in err
+
+
+def test_scan_csv_date_inference_month_names() -> None:
+    month_names = [
+        "Januar", "Februar", "März", "April", "Mai", "Juni",
+        "Juli", "August", "September", "Oktober", "November", "Dezember",
+    ]  # fmt: skip
+    inference = pl.DateInference(
+        ["%d. %B %y"], month_names=month_names, two_digit_year_pivot=50
+    )
+    source = "d\n3. März 68\n24. dezember 05\n".encode()
+    result = pl.scan_csv(source, try_parse_dates=inference).collect()
+    assert result["d"].to_list() == [date(1968, 3, 3), date(2005, 12, 24)]
+
+    with pytest.raises(InvalidOperationError, match="`month_names` must hold 12"):
+        pl.scan_csv(source, try_parse_dates=pl.DateInference(month_names=["Mai"]))
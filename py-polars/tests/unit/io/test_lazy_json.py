@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import json
+import zipfile
 from typing import TYPE_CHECKING
 
 import pytest
@@ -327,3 +329,92 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+def test_scan_ndjson_zip_archive(tmp_path: Path) -> None:
+    path = tmp_path / "data.zip"
+    with zipfile.ZipFile(path, "w", compression=zipfile.ZIP_DEFLATED) as f:
+        f.writestr("2024/01.jsonl", '{"a": 1}\n{"a": 2}')
+        f.writestr("2024/02.jsonl", '{"a": 3}\n')
+        f.writestr("other/03.jsonl", '{"a": 4}\n')
+
+    assert pl.scan_ndjson(path).collect()["a"].to_list() == [1, 2, 3, 4]
+    out = pl.scan_ndjson(path, archive_member_glob="2024/*").collect()
+    assert_frame_equal(out, pl.DataFrame({"a": [1, 2, 3]}))
+
+
+def test_sink_ndjson_json_schema(tmp_path: Path) -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [["x"], None]})
+    lf.sink_ndjson(
+        tmp_path / "out.ndjson",
+        json_schema=tmp_path / "schema" / "out.json",
+        mkdir=True,
+    )
+
+    assert_frame_equal(pl.read_ndjson(tmp_path / "out.ndjson"), lf.collect())
+    with (tmp_path / "schema" / "out.json").open() as f:
+        assert json.load(f) == lf.collect_schema().to_json_schema()
+
+    with pytest.raises(ValueError, match="lazy=True"):
+        lf.sink_ndjson(tmp_path / "lazy.ndjson", json_schema="schema.json", lazy=True)
+
+    # No schema is written if the rows could not be written.
+    lf = pl.LazyFrame({"a": [1, 2]}).select(pl.col("a").str.len_bytes())
+    with pytest.raises(pl.exceptions.PolarsError):
+        lf.sink_ndjson(tmp_path / "err.ndjson", json_schema=tmp_path / "err.json")
+    assert not (tmp_path / "err.json").exists()
+
+
+def test_scan_ndjson_json_schema(tmp_path: Path) -> None:
+    lf = pl.LazyFrame(
+        {"a": [1, 2], "b": [["x"], None]}, schema_overrides={"a": pl.UInt8}
+    )
+    lf.sink_ndjson(tmp_path / "ok.ndjson", json_schema=tmp_path / "schema.json")
+
+    # A schema written by the sink validates the rows it wrote.
+    q = pl.scan_ndjson(tmp_path / "ok.ndjson", json_schema=tmp_path / "schema.json")
+    assert_frame_equal(q.collect(), lf.collect(), check_dtypes=False)
+
+    path = tmp_path / "data.ndjson"
+    path.write_text(
+        '{"a": 1, "b": ["x"]}\n'
+        '{"a": 300, "b": []}\n'
+        '{"a": 3, "b": [1]}\n'
+        "not json\n"
+        '{"a": null, "b": null}\n'
+    )
+    json_schema = lf.collect_schema().to_json_schema()
+    # The schema is given, as it cannot be inferred from the invalid rows.
+    schema = {"a": pl.Int64, "b": pl.List(pl.String)}
+
+    q = pl.scan_ndjson(path, schema=schema, json_schema=json_schema)
+    with pytest.raises(pl.exceptions.ComputeError, match="at '/a': 300 is outside"):
+        q.collect()
+
+    rejects = tmp_path / "rejects.ndjson"
+    q = pl.scan_ndjson(
+        path,
+        schema=schema,
+        json_schema=json_schema,
+        json_schema_rejects=rejects,
+        row_index_name="i",
+    )
+    assert q.collect().to_dict(as_series=False) == {
+        "i": [0, 1],
+        "a": [1, None],
+        "b": [["x"], None],
+    }
+    assert rejects.read_text().splitlines() == [
+        '{"a": 300, "b": []}',
+        '{"a": 3, "b": [1]}',
+        "not json",
+    ]
+    assert q.select(pl.len()).collect().item() == 2
+
+
+def test_scan_ndjson_json_schema_unsupported_keyword(tmp_path: Path) -> None:
+    path = tmp_path / "data.ndjson"
+    path.write_text('{"a": 1}\n')
+    q = pl.scan_ndjson(path, json_schema={"$ref": "#/$defs/row"})
+    with pytest.raises(pl.exceptions.ComputeError, match=r"keyword '\$ref'"):
+        q.collect()
@@ -4,6 +4,7 @@ import io
 import subprocess
 import sys
 from collections import OrderedDict
+from datetime import datetime
 from pathlib import Path
 from threading import Thread
 from typing import TYPE_CHECKING, Any
@@ -12,7 +13,7 @@ import pandas as pd
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ColumnNotFoundError, ComputeError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -1062,3 +1063,176 @@ def test_parquet_prefiltering_inserted_column_23268() -> None:
         ),
         pl.DataFrame(schema={"a": pl.Int8, "b": pl.Int16}),
     )
+
+
+def test_scan_parquet_file_statistics(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    paths = [tmp_path / f"{i}.parquet" for i in range(3)]
+    for i, path in enumerate(paths):
+        pl.DataFrame({"a": [2 * i, 2 * i + 1], "b": ["x", "y"]}).write_parquet(path)
+
+    stats = pl.DataFrame(
+        {
+            "path": [str(p) for p in paths],
+            "num_rows": [2, 2, 2],
+            "min": [{"a": 0}, {"a": 2}, {"a": 4}],
+            "max": [{"a": 1}, {"a": 3}, {"a": None}],
+            "null_count": [{"a": 0}, {"a": 0}, {"a": 0}],
+        }
+    )
+    lf = pl.scan_parquet(paths, file_statistics=stats)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    out = lf.filter(pl.col("a") >= 3).collect()
+    assert "Table statistics allow skipping 1 / 3 files" in capfd.readouterr().err
+    assert_frame_equal(out, pl.DataFrame({"a": [3, 4, 5], "b": ["y", "x", "y"]}))
+
+    # The row counts are taken from the statistics instead of the files.
+    stats = stats.with_columns(num_rows=pl.Series([10, 20, 30]))
+    lf = pl.scan_parquet(paths, file_statistics=stats)
+    assert lf.select(pl.len()).collect().item() == 60
+    assert lf.select(pl.len().alias("n")).collect().to_dict(as_series=False) == {
+        "n": [60]
+    }
+    assert lf.filter(pl.col("a") > 0).select(pl.len()).collect().item() == 5
+
+    # The row count is only used if it is known for all files.
+    stats = stats.with_columns(num_rows=pl.Series([10, None, 30]))
+    lf = pl.scan_parquet(paths, file_statistics=stats)
+    assert lf.select(pl.len()).collect().item() == 6
+
+
+def test_scan_parquet_file_statistics_invalid(tmp_path: Path) -> None:
+    paths = [tmp_path / f"{i}.parquet" for i in range(2)]
+    for path in paths:
+        pl.DataFrame({"a": [1, 2]}).write_parquet(path)
+
+    stats = pl.DataFrame({"num_rows": [2]})
+    with pytest.raises(
+        pl.exceptions.InvalidOperationError,
+        match="table statistics were given for 1 files, but the scan has 2 files",
+    ):
+        pl.scan_parquet(paths, file_statistics=stats).collect()
+
+    stats = pl.DataFrame({"num_rows": [2, 2], "rows": [2, 2]})
+    with pytest.raises(pl.exceptions.ColumnNotFoundError, match="'rows'"):
+        pl.scan_parquet(paths, file_statistics=stats)
+
+
+def test_scan_parquet_file_errors(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch, capfd: pytest.CaptureFixture[str]
+) -> None:
+    paths = [tmp_path / f"{i}.parquet" for i in range(3)]
+    for i, path in enumerate(paths):
+        pl.DataFrame({"a": [i]}).write_parquet(path)
+    paths[1].write_bytes(b"not a parquet file")
+
+    with pytest.raises(ComputeError):
+        pl.scan_parquet(paths).collect()
+
+    file_errors = pl.ScanFileErrors(retries=2, skip=True)
+    lf = pl.scan_parquet(paths, file_errors=file_errors)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    capfd.readouterr()
+    assert_frame_equal(lf.collect(), pl.DataFrame({"a": [0, 2]}))
+    assert "Retry source 1 (2 / 2)" in capfd.readouterr().err
+
+    skipped = file_errors.skipped()
+    assert skipped["path"].to_list() == [str(paths[1])]
+    assert skipped["error"].str.len_chars().item() > 0
+
+    # The skipped files of every execution are reported.
+    lf.with_row_index().collect()
+    assert file_errors.skipped().height == 2
+
+    file_errors.clear()
+    assert file_errors.skipped().height == 0
+
+    # Without `skip`, the file still fails after the retries.
+    with pytest.raises(ComputeError):
+        pl.scan_parquet(paths, file_errors=pl.ScanFileErrors(retries=1)).collect()
+
+    with pytest.raises(ValueError, match="`retries` must be non-negative"):
+        pl.ScanFileErrors(retries=-1)
+
+
+@pytest.mark.parametrize("parallel", ["auto", "prefiltered"])
+def test_scan_parquet_schema_overrides(
+    tmp_path: Path, parallel: ParallelStrategy
+) -> None:
+    path = tmp_path / "data.parquet"
+    df = pl.DataFrame(
+        {
+            "ts": pl.Series([1_000_000, 2_000_000, None]).cast(pl.Datetime("ns")),
+            "name": ["a", "b", "a"],
+            "x": [1, 2, 3],
+        }
+    )
+    df.write_parquet(path, row_group_size=1)
+
+    schema_overrides = {"ts": pl.Datetime("ms"), "name": pl.Categorical()}
+    lf = pl.scan_parquet(path, schema_overrides=schema_overrides, parallel=parallel)
+    expected = df.with_columns(
+        pl.col("ts").cast(pl.Datetime("ms")), pl.col("name").cast(pl.Categorical())
+    )
+
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(
+        lf.filter(pl.col("name") == "a").select("x").collect(),
+        pl.DataFrame({"x": [1, 3]}),
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("ts") > datetime(1970, 1, 1, 0, 0, 0, 1500)).collect(),
+        expected.slice(1, 1),
+    )
+
+    with pytest.raises(ColumnNotFoundError, match="missing"):
+        pl.scan_parquet(path, schema_overrides={"missing": pl.Int8}).collect()
+
+
+def test_scan_parquet_fast_statistics(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3, None, 1, 7, None, 5],
+            "b": ["x", None, "y", "z", "w", None],
+            "c": [1.5, 2.5, None, 0.5, 3.5, 4.5],
+        }
+    )
+    df.head(4).write_parquet(tmp_path / "0.parquet", row_group_size=2)
+    df.tail(2).write_parquet(tmp_path / "1.parquet", statistics=False)
+    lf = pl.scan_parquet(tmp_path / "*.parquet")
+    optimizations = pl.QueryOptFlags(fast_statistics=True)
+
+    q = lf.select(
+        pl.col("a").null_count(),
+        pl.col("a").min().alias("a_min"),
+        pl.col("a").max().alias("a_max"),
+        pl.col("b").null_count().alias("b_nc"),
+    )
+    assert "FAST STATISTICS" not in q.explain()
+    assert "FAST STATISTICS" in q.explain(optimizations=optimizations)
+    expected = df.select(
+        pl.col("a").null_count(),
+        pl.col("a").min().alias("a_min"),
+        pl.col("a").max().alias("a_max"),
+        pl.col("b").null_count().alias("b_nc"),
+    )
+    assert_frame_equal(q.collect(optimizations=optimizations), expected)
+    assert_frame_equal(
+        q.collect(engine="streaming", optimizations=optimizations), expected
+    )
+
+    # Predicates and the min/max of floats are not answered from the statistics.
+    for q in [
+        lf.filter(pl.col("a") > 1).select(pl.col("a").null_count()),
+        lf.select(pl.col("c").min()),
+    ]:
+        assert "FAST STATISTICS" not in q.explain(optimizations=optimizations)
+    q = lf.filter(pl.col("a") > 1).select(pl.col("b").null_count())
+    assert q.collect(optimizations=optimizations).item() == 1
+    q = lf.select(pl.col("c").null_count())
+    assert q.collect(optimizations=optimizations).item() == 1
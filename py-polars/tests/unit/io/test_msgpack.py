@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+import io
+import struct
+from datetime import datetime
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def _str(s: str) -> bytes:
+    return bytes([0xA0 | len(s)]) + s.encode()
+
+
+def _map(*entries: tuple[str, bytes]) -> bytes:
+    return bytes([0x80 | len(entries)]) + b"".join(_str(k) + v for k, v in entries)
+
+
+@pytest.fixture
+def data() -> bytes:
+    return _map(
+        ("a", b"\x01"),
+        ("b", b"\x92\xff\xcb" + struct.pack(">d", 0.5)),
+    ) + _map(
+        ("c", _map(("d", _str("x")))),
+        ("a", b"\xc0"),
+    )
+
+
+def test_read_msgpack(data: bytes) -> None:
+    df = pl.read_msgpack(io.BytesIO(data))
+    expected = pl.DataFrame(
+        {
+            "a": [1, None],
+            "b": [[-1.0, 0.5], None],
+            "c": [None, {"d": "x"}],
+        }
+    )
+    assert_frame_equal(df, expected)
+
+
+def test_read_msgpack_columns_n_rows(data: bytes, tmp_path: Path) -> None:
+    path = tmp_path / "data.msgpack"
+    path.write_bytes(data)
+
+    df = pl.read_msgpack(path, columns=["c", "a"], n_rows=1)
+    expected = pl.DataFrame(
+        {"c": [None], "a": [1]},
+        schema={"c": pl.Null, "a": pl.Int64},
+    )
+    assert_frame_equal(df, expected)
+
+
+def test_read_msgpack_timestamp() -> None:
+    data = _map(("t", b"\xd6\xff" + struct.pack(">I", 1)))
+    df = pl.read_msgpack(io.BytesIO(data))
+    assert df.schema == {"t": pl.Datetime("ns")}
+    assert df.item() == datetime(1970, 1, 1, 0, 0, 1)
+
+
+def test_read_msgpack_not_a_map() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="expected a map"):
+        pl.read_msgpack(io.BytesIO(b"\x01"))
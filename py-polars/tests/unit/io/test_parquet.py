@@ -4,7 +4,7 @@ import decimal
 import functools
 import io
 import warnings
-from datetime import date, datetime, time, timezone
+from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
 from itertools import chain
 from typing import TYPE_CHECKING, Any, Callable, Literal, cast
@@ -345,6 +345,85 @@ def test_row_group_size_saturation() -> None:
     assert_frame_equal(pl.read_parquet(f), df)
 
 
+def test_row_group_bytes() -> None:
+    df = pl.DataFrame({"a": pl.int_range(1000, dtype=pl.Int64, eager=True)})
+    f = io.BytesIO()
+
+    # 8 bytes per row, so 100 rows per row group
+    df.write_parquet(f, row_group_bytes=800)
+    f.seek(0)
+    assert pq.ParquetFile(f).num_row_groups == 10
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    # the smallest of `row_group_size` and `row_group_bytes` wins
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_bytes=800, row_group_size=250)
+    f.seek(0)
+    assert pq.ParquetFile(f).num_row_groups == 10
+
+
+def test_split_row_groups_on() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 3, 18), "6h", eager=True
+            ),
+        }
+    ).with_row_index()
+    f = io.BytesIO()
+
+    df.write_parquet(
+        f, split_row_groups_on="ts", split_row_groups_every=timedelta(days=1)
+    )
+    f.seek(0)
+    md = pq.ParquetFile(f).metadata
+    assert [md.row_group(i).num_rows for i in range(md.num_row_groups)] == [4, 4, 4]
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    # row groups still respect `row_group_size` within a key
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        split_row_groups_on="ts",
+        split_row_groups_every=timedelta(days=1),
+        row_group_size=3,
+    )
+    f.seek(0)
+    md = pq.ParquetFile(f).metadata
+    assert [md.row_group(i).num_rows for i in range(md.num_row_groups)] == [
+        3,
+        1,
+        3,
+        1,
+        3,
+        1,
+    ]
+
+    # without a granularity, every distinct value starts a row group
+    df = pl.DataFrame({"key": ["a", "a", None, "b", "b", "b"]})
+    f = io.BytesIO()
+    df.write_parquet(f, split_row_groups_on="key")
+    f.seek(0)
+    md = pq.ParquetFile(f).metadata
+    assert [md.row_group(i).num_rows for i in range(md.num_row_groups)] == [2, 1, 3]
+
+
+def test_split_row_groups_on_invalid() -> None:
+    df = pl.DataFrame({"a": ["x", "y"]})
+    with pytest.raises(ValueError, match="requires `split_row_groups_on`"):
+        df.write_parquet(io.BytesIO(), split_row_groups_every=1)
+    with pytest.raises(TypeError, match="temporal"):
+        df.write_parquet(
+            io.BytesIO(), split_row_groups_on="a", split_row_groups_every=timedelta(1)
+        )
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.write_parquet(io.BytesIO(), split_row_groups_on="a", split_row_groups_every=2)
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="streaming"):
+        df.lazy().sink_parquet(io.BytesIO(), row_group_bytes=1024, engine="streaming")
+
+
 def test_nested_sliced() -> None:
     for df in [
         pl.Series([[1, 2], [3, 4], [5, 6]]).slice(2, 2).to_frame(),
@@ -3513,3 +3592,137 @@ def test_write_nested_categoricals() -> None:
 
     f.seek(0)
     assert_frame_equal(pl.read_parquet(f), df)
+
+
+def test_read_parquet_row_group_metadata() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, None, 4],
+            "b": ["x", "y", "z", "w"],
+            "c": [[1], [2, 3], [], None],
+        }
+    )
+    f = io.BytesIO()
+    df.write_parquet(f, row_group_size=2, compression="snappy")
+
+    f.seek(0)
+    md = pl.read_parquet_row_group_metadata(f)
+    assert md.columns == [
+        "row_group",
+        "num_rows",
+        "total_byte_size",
+        "compressed_size",
+        "columns",
+        "min",
+        "max",
+        "null_count",
+    ]
+    assert md["row_group"].to_list() == [0, 1]
+    assert md["num_rows"].to_list() == [2, 2]
+
+    assert_frame_equal(
+        md.select(pl.col("min").struct.unnest()),
+        pl.DataFrame(
+            {"a": [1, 4], "b": ["x", "w"], "c": [None, None]},
+            schema={"a": pl.Int64, "b": pl.String, "c": pl.List(pl.Int64)},
+        ),
+    )
+    assert md["max"].struct.field("a").to_list() == [2, 4]
+    assert md["max"].struct.field("b").to_list() == ["y", "z"]
+    assert md["null_count"].struct.field("a").to_list() == [0, 1]
+
+    chunks = md.select("row_group", "columns").explode("columns").unnest("columns")
+    assert chunks.height == 6
+    assert chunks["path"].head(2).to_list() == ["a", "b"]
+    assert chunks["physical_type"].head(3).to_list() == ["INT64", "BYTE_ARRAY", "INT64"]
+    assert chunks["compression"].unique().to_list() == ["SNAPPY"]
+    assert not chunks["has_bloom_filter"].any()
+    assert chunks["encodings"].list.len().min() > 0  # type: ignore[operator]
+
+    f.seek(0)
+    pq_md = pq.ParquetFile(f).metadata
+    for rg, col, size in chunks.select(
+        "row_group", pl.int_range(pl.len()).over("row_group"), "compressed_size"
+    ).iter_rows():
+        assert pq_md.row_group(rg).column(col).total_compressed_size == size
+    assert md["num_rows"].sum() == pq_md.num_rows
+
+
+def test_sort_limit_sorted_heads(tmp_path: Path) -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2024, 1, d) for d in range(1, 13)],
+            "value": range(12),
+        }
+    )
+    for i, part in enumerate(df.iter_slices(4)):
+        # The last file is not actually sorted and must be fully sorted.
+        if i == 2:
+            part = part.reverse()
+        pq.write_table(
+            part.to_arrow(),
+            tmp_path / f"{i}.parquet",
+            row_group_size=2,
+            sorting_columns=[pq.SortingColumn(0)] if i < 2 else None,
+        )
+    lf = pl.scan_parquet(tmp_path / "*.parquet")
+
+    for q in [
+        lf.sort("ts", descending=True).head(3),
+        lf.sort("ts").head(5),
+        lf.sort("ts").slice(2, 3),
+        lf.select("value", "ts").sort("ts", descending=True).head(6),
+        lf.sort("ts", maintain_order=True).head(3),
+        lf.sort("ts", descending=True, maintain_order=True).head(3),
+    ]:
+        expected = q.collect(optimizations=pl.QueryOptFlags.none())
+        assert_frame_equal(q.collect(), expected)
+        assert_frame_equal(q.collect(engine="streaming"), expected)
+
+    # The sorted files are only read up to the limit, the unsorted file is read whole.
+    plan = lf.sort("ts").head(3).explain()
+    assert "UNION" in plan
+    assert plan.count("SLICE: Positive { offset: 0, len: 3 }") == 2
+    plan = lf.sort("ts", descending=True).head(3).explain()
+    assert plan.count("SLICE: Positive { offset: 1, len: 3 }") == 2
+    # The tails of the files hold the last of the equal rows.
+    plan = lf.sort("ts", descending=True, maintain_order=True).head(3).explain()
+    assert "SLICE: Positive" not in plan
+
+    q = lf.filter(pl.col("value") > 2).sort("ts").head(3)
+    assert "SLICE: Positive" not in q.explain()
+
+    (tmp_path / "0.parquet").unlink()
+    df.write_parquet(tmp_path / "0.parquet")
+    q = lf.sort("ts").head(3)
+    assert "SLICE: Positive" not in q.explain()
+
+
+def test_sort_limit_sorted_heads_cast(tmp_path: Path) -> None:
+    df = pl.DataFrame({"k": pl.Series([3, 1, 2], dtype=pl.Int32)})
+    pq.write_table(
+        df.sort("k").to_arrow(),
+        tmp_path / "0.parquet",
+        sorting_columns=[pq.SortingColumn(0)],
+    )
+    pq.write_table(
+        df.to_arrow(),
+        tmp_path / "1.parquet",
+    )
+
+    lf = pl.scan_parquet(tmp_path / "*.parquet")
+    q = lf.sort("k").head(2)
+    assert "SLICE: Positive { offset: 0, len: 2 }" in q.explain()
+    assert_frame_equal(
+        q.collect(), pl.DataFrame({"k": pl.Series([1, 1], dtype=pl.Int32)})
+    )
+
+    # The sort column is cast, so the order of the files is not used.
+    lf = pl.scan_parquet(
+        tmp_path / "*.parquet",
+        schema={"k": pl.Int64},
+        cast_options=pl.ScanCastOptions(integer_cast="upcast"),
+    )
+    q = lf.sort("k").head(2)
+    assert "SLICE: Positive" not in q.explain()
+    assert_frame_equal(q.collect(), pl.DataFrame({"k": [1, 1]}))
@@ -9,6 +9,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from polars._typing import SchemaDict
+
 
 # A simple python source. But this can dispatch into a rust IO source as well.
 def my_source(
@@ -52,3 +54,49 @@ def test_my_source() -> None:
     assert_frame_equal(
         scan_my_source().select("a").collect(), pl.DataFrame({"a": [1, 2, 3]})
     )
+
+
+def test_projected_schema_fn() -> None:
+    calls: list[list[str] | None] = []
+
+    def schema(with_columns: list[str] | None) -> SchemaDict:
+        calls.append(with_columns)
+        full = {"a": pl.Int64(), "b": pl.Int64()}
+        if with_columns is None:
+            return full
+        return {name: full[name] for name in with_columns}
+
+    lf = register_io_source(my_source, schema=schema)
+
+    assert lf.select("a").collect_schema() == pl.Schema({"a": pl.Int64()})
+    assert calls[-1] == ["a"]
+
+    assert_frame_equal(
+        lf.select(pl.col("b") * 2, c="a").collect(),
+        pl.DataFrame({"b": [2, 4, 6], "c": [1, 2, 3]}),
+    )
+    assert calls[-1] == ["b", "a"]
+
+    # Selecting by pattern requires the full schema.
+    assert lf.select(pl.all()).collect_schema().names() == ["a", "b"]
+    assert calls[-1] is None
+    assert lf.filter(pl.col("a") > 1).collect_schema().names() == ["a", "b"]
+    assert calls[-1] is None
+
+
+def test_schema_fn_without_projection() -> None:
+    lf = register_io_source(
+        my_source, schema=lambda: {"a": pl.Int64(), "b": pl.Int64()}
+    )
+    assert_frame_equal(lf.select("b").collect(), pl.DataFrame({"b": [1, 2, 3]}))
+    assert lf.collect_schema().names() == ["a", "b"]
+
+
+def test_schema_fn_with_optional_parameter() -> None:
+    def schema(refresh: bool = False) -> SchemaDict:
+        assert refresh is False
+        return {"a": pl.Int64(), "b": pl.Int64()}
+
+    lf = register_io_source(my_source, schema=schema)
+    assert lf.select("a").collect_schema() == pl.Schema({"a": pl.Int64()})
+    assert lf.collect_schema().names() == ["a", "b"]
@@ -0,0 +1,132 @@
+from __future__ import annotations
+
+import io
+import struct
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def _varint(v: int) -> bytes:
+    out = bytearray()
+    while v >= 0x80:
+        out.append((v & 0x7F) | 0x80)
+        v >>= 7
+    out.append(v)
+    return bytes(out)
+
+
+def _len_field(number: int, value: bytes) -> bytes:
+    return _varint(number << 3 | 2) + _varint(len(value)) + value
+
+
+def _varint_field(number: int, value: int) -> bytes:
+    return _varint(number << 3) + _varint(value)
+
+
+def _field(name: str, number: int, label: int, ty: int, type_name: str = "") -> bytes:
+    out = (
+        _len_field(1, name.encode())
+        + _varint_field(3, number)
+        + _varint_field(4, label)
+        + _varint_field(5, ty)
+    )
+    if type_name:
+        out += _len_field(6, type_name.encode())
+    return out
+
+
+OPTIONAL, REPEATED = 1, 3
+INT64, STRING, MESSAGE, DOUBLE = 3, 9, 11, 1
+
+
+@pytest.fixture
+def descriptor_set() -> bytes:
+    """
+    A descriptor set for the following file.
+
+    syntax = "proto2";
+    package telemetry;
+    message Event {
+      optional string device = 1;
+      repeated int64 readings = 2;
+      map<string, double> metrics = 3;
+    }
+    """
+    entry = (
+        _len_field(1, b"MetricsEntry")
+        + _len_field(2, _field("key", 1, OPTIONAL, STRING))
+        + _len_field(2, _field("value", 2, OPTIONAL, DOUBLE))
+        # MessageOptions.map_entry
+        + _len_field(7, _varint_field(7, 1))
+    )
+    event = (
+        _len_field(1, b"Event")
+        + _len_field(2, _field("device", 1, OPTIONAL, STRING))
+        + _len_field(2, _field("readings", 2, REPEATED, INT64))
+        + _len_field(
+            2,
+            _field("metrics", 3, REPEATED, MESSAGE, ".telemetry.Event.MetricsEntry"),
+        )
+        + _len_field(3, entry)
+    )
+    file = _len_field(2, b"telemetry") + _len_field(4, event)
+    return _len_field(1, file)
+
+
+@pytest.fixture
+def messages() -> bytes:
+    entry = _len_field(1, b"temp") + _varint(2 << 3 | 1) + struct.pack("<d", 1.5)
+    first = (
+        _len_field(1, b"sensor-1")
+        + _len_field(2, _varint(1) + _varint(2))
+        + _len_field(3, entry)
+    )
+    second = _varint_field(2, 3)
+    return b"".join(_varint(len(m)) + m for m in (first, second))
+
+
+def test_read_protobuf(descriptor_set: bytes, messages: bytes) -> None:
+    df = pl.read_protobuf(io.BytesIO(messages), descriptor_set, "telemetry.Event")
+    expected = pl.DataFrame(
+        {
+            "device": ["sensor-1", None],
+            "readings": [[1, 2], [3]],
+            "metrics": [[{"key": "temp", "value": 1.5}], []],
+        },
+        schema={
+            "device": pl.String,
+            "readings": pl.List(pl.Int64),
+            "metrics": pl.List(pl.Struct({"key": pl.String, "value": pl.Float64})),
+        },
+    )
+    assert_frame_equal(df, expected)
+
+    df = pl.read_protobuf(
+        messages, descriptor_set, ".telemetry.Event", columns=["readings"], n_rows=1
+    )
+    assert_frame_equal(df, expected.select("readings").head(1))
+
+
+@pytest.mark.write_disk
+def test_read_protobuf_files(
+    descriptor_set: bytes, messages: bytes, tmp_path: Path
+) -> None:
+    (tmp_path / "events.desc").write_bytes(descriptor_set)
+    (tmp_path / "events.pb").write_bytes(messages)
+
+    df = pl.read_protobuf(
+        tmp_path / "events.pb", tmp_path / "events.desc", "telemetry.Event"
+    )
+    assert df.shape == (2, 3)
+
+
+def test_read_protobuf_unknown_message_type(descriptor_set: bytes) -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="not found"):
+        pl.read_protobuf(b"", descriptor_set, "telemetry.Missing")
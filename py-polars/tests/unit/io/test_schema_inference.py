@@ -0,0 +1,69 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def test_infer_schema_csv() -> None:
+    rows = ["i,f,b,d,s,n"]
+    rows += [
+        f"{i},{i}.5,{i % 2 == 0},2020-01-{i % 28 + 1:02},x{i}," for i in range(999)
+    ]
+    rows.append("oops,1,true,2020-01-01,y,")
+    source = "\n".join(rows).encode()
+
+    inferred = pl.infer_schema(source, format="csv")
+    assert inferred.n_sampled_rows == 1000
+    assert inferred.schema == pl.Schema(
+        {
+            "i": pl.String,
+            "f": pl.Float64,
+            "b": pl.Boolean,
+            "d": pl.Date,
+            "s": pl.String,
+            "n": pl.String,
+        }
+    )
+    assert inferred.notes["i"] == "parsed as Int64 in 99.9% of non-null sampled values"
+    assert inferred.notes["n"] == "all sampled values are null"
+
+    # The recommended schema can be fed back into the readers.
+    df = pl.read_csv(source, schema=inferred.schema)
+    assert df.schema == inferred.schema
+
+    inferred = pl.infer_schema(source, format="csv", sample_size=10)
+    assert inferred.n_sampled_rows == 10
+    assert inferred.schema["i"] == pl.Int64
+
+
+def test_infer_schema_ndjson() -> None:
+    source = b'{"a": 1, "b": "2020-01-01"}\n{"a": 2, "b": "2021-06-01"}\n'
+    inferred = pl.infer_schema(source, format="ndjson")
+    assert inferred.schema == pl.Schema({"a": pl.Int64, "b": pl.Date})
+    assert inferred.notes["a"] == "JSON values are Int64 in all 2 sampled rows"
+
+
+@pytest.mark.write_disk
+def test_infer_schema_parquet_and_format_from_suffix(tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    df.write_parquet(tmp_path / "data.parquet")
+    df.write_csv(tmp_path / "data.csv")
+
+    inferred = pl.infer_schema(tmp_path / "data.parquet")
+    assert inferred.schema == df.schema
+    assert inferred.n_sampled_rows is None
+
+    assert pl.infer_schema(tmp_path / "data.csv").schema == df.schema
+
+
+def test_infer_schema_errors() -> None:
+    with pytest.raises(ValueError, match="pass `format`"):
+        pl.infer_schema(b"a\n1\n")
+    with pytest.raises(ValueError, match="`sample_size`"):
+        pl.infer_schema(b"a\n1\n", format="csv", sample_size=0)
@@ -0,0 +1,187 @@
+from __future__ import annotations
+
+import json
+import threading
+from http.server import BaseHTTPRequestHandler, ThreadingHTTPServer
+from typing import TYPE_CHECKING, Any
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SchemaError
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+    from pathlib import Path
+
+
+class FakeRegistry:
+    """A schema registry that accepts a new schema if it keeps all earlier fields."""
+
+    def __init__(self) -> None:
+        self.versions: dict[str, list[dict[str, Any]]] = {}
+        self.config: dict[str, str] = {}
+        self.requests: list[tuple[str, str]] = []
+        self.url = ""
+
+    def is_compatible(self, subject: str, schema: str) -> bool:
+        versions = self.versions.get(subject, [])
+        if not versions:
+            return True
+        latest = json.loads(versions[-1]["schema"])
+        new = json.loads(schema)
+        if "fields" in latest:
+            old_names = {f["name"] for f in latest["fields"]}
+            return old_names <= {f["name"] for f in new["fields"]}
+        return set(latest["properties"]) <= set(new["properties"])
+
+
+@pytest.fixture
+def registry() -> Iterator[FakeRegistry]:
+    state = FakeRegistry()
+
+    class Handler(BaseHTTPRequestHandler):
+        def log_message(self, *args: Any) -> None:
+            pass
+
+        def _respond(self, status: int, body: dict[str, Any]) -> None:
+            data = json.dumps(body).encode()
+            self.send_response(status)
+            self.send_header("Content-Type", "application/json")
+            self.send_header("Content-Length", str(len(data)))
+            self.end_headers()
+            self.wfile.write(data)
+
+        def _body(self) -> dict[str, Any]:
+            length = int(self.headers["Content-Length"])
+            return json.loads(self.rfile.read(length))  # type: ignore[no-any-return]
+
+        def do_PUT(self) -> None:
+            state.requests.append(("PUT", self.path))
+            subject = self.path.removeprefix("/config/")
+            state.config[subject] = self._body()["compatibility"]
+            self._respond(200, {"compatibility": state.config[subject]})
+
+        def do_POST(self) -> None:
+            state.requests.append(("POST", self.path))
+            body = self._body()
+            parts = self.path.split("?")[0].strip("/").split("/")
+            if parts[0] == "compatibility":
+                subject = parts[2]
+                if subject not in state.versions:
+                    self._respond(404, {"error_code": 40401, "message": "not found"})
+                elif state.is_compatible(subject, body["schema"]):
+                    self._respond(200, {"is_compatible": True, "messages": []})
+                else:
+                    msg = "field removed"
+                    self._respond(200, {"is_compatible": False, "messages": [msg]})
+            else:
+                versions = state.versions.setdefault(parts[1], [])
+                versions.append(body)
+                self._respond(200, {"id": len(versions)})
+
+    server = ThreadingHTTPServer(("127.0.0.1", 0), Handler)
+    state.url = f"http://127.0.0.1:{server.server_address[1]}"
+    thread = threading.Thread(target=server.serve_forever, daemon=True)
+    thread.start()
+    yield state
+    server.shutdown()
+    server.server_close()
+
+
+def test_write_avro_schema_registry(registry: FakeRegistry, tmp_path: Path) -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    options = {
+        "url": registry.url,
+        "subject": "test-value",
+        "compatibility": "BACKWARD",
+    }
+
+    df.write_avro(tmp_path / "a.avro", name="test", schema_registry=options)
+    assert registry.config == {"test-value": "BACKWARD"}
+    [version] = registry.versions["test-value"]
+    assert version["schemaType"] == "AVRO"
+    schema = json.loads(version["schema"])
+    assert schema["name"] == "test"
+    assert [f["name"] for f in schema["fields"]] == ["a", "b"]
+
+    # Adding a field is compatible.
+    df.with_columns(c=1).write_avro(tmp_path / "b.avro", schema_registry=options)
+    assert len(registry.versions["test-value"]) == 2
+
+    # Removing a field is incompatible and fails fast.
+    with pytest.raises(SchemaError, match="incompatible with subject 'test-value'"):
+        df.drop("b").write_avro(tmp_path / "c.avro", schema_registry=options)
+    assert not (tmp_path / "c.avro").exists()
+    assert len(registry.versions["test-value"]) == 2
+
+
+def test_write_avro_schema_registry_failed_write(
+    registry: FakeRegistry, tmp_path: Path
+) -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    options = {"url": registry.url, "subject": "test-value"}
+
+    with pytest.raises(OSError):
+        df.write_avro(tmp_path / "missing" / "a.avro", schema_registry=options)
+    assert registry.versions == {}
+
+
+def test_schema_registry_subject_encoding(
+    registry: FakeRegistry, tmp_path: Path
+) -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    options = {"url": registry.url, "subject": "a b/c?d#e%"}
+
+    df.write_avro(tmp_path / "a.avro", schema_registry=options)
+    subject = "a%20b%2Fc%3Fd%23e%25"
+    assert registry.requests == [
+        ("POST", f"/compatibility/subjects/{subject}/versions/latest?verbose=true"),
+        ("POST", f"/subjects/{subject}/versions"),
+    ]
+
+
+def test_sink_ndjson_schema_registry_validate_only(
+    registry: FakeRegistry, tmp_path: Path
+) -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": ["x", "y"]})
+    options = {"url": registry.url, "subject": "rows", "register": False}
+
+    lf.sink_ndjson(tmp_path / "a.ndjson", schema_registry=options)
+    assert registry.versions == {}
+    assert registry.requests == [
+        ("POST", "/compatibility/subjects/rows/versions/latest?verbose=true")
+    ]
+
+    registry.versions["rows"] = [
+        {"schema": json.dumps(lf.collect_schema().to_json_schema())}
+    ]
+    with pytest.raises(SchemaError, match="field removed"):
+        lf.drop("a").sink_ndjson(tmp_path / "b.ndjson", schema_registry=options)
+    assert not (tmp_path / "b.ndjson").exists()
+
+
+def test_write_ndjson_schema_registry(registry: FakeRegistry) -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    out = df.write_ndjson(schema_registry={"url": registry.url, "subject": "rows"})
+    assert out == '{"a":1}\n{"a":2}\n'
+    [version] = registry.versions["rows"]
+    assert version["schemaType"] == "JSON"
+    assert json.loads(version["schema"]) == df.schema.to_json_schema()
+
+
+def test_schema_registry_invalid_options(registry: FakeRegistry) -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    with pytest.raises(ValueError, match="unknown `schema_registry` keys"):
+        df.write_ndjson(schema_registry={"url": registry.url, "subjects": "rows"})
+    with pytest.raises(ValueError, match="missing the required key 'subject'"):
+        df.write_ndjson(schema_registry={"url": registry.url})
+    with pytest.raises(ValueError, match="`compatibility` must be one of"):
+        df.write_ndjson(
+            schema_registry={
+                "url": registry.url,
+                "subject": "rows",
+                "compatibility": "sideways",
+            }
+        )
+    assert registry.requests == []
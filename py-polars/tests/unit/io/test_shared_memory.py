@@ -0,0 +1,86 @@
+from __future__ import annotations
+
+import pickle
+from concurrent.futures import ProcessPoolExecutor
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "a": [1, 2, 3],
+            "b": ["x", None, "z"],
+            "c": [[1.0], [], None],
+        }
+    )
+
+
+def _read_sum(handle: pl.SharedMemoryFrame) -> int:
+    return handle.read()["a"].sum()
+
+
+def test_write_shared_memory(df: pl.DataFrame) -> None:
+    handle = df.write_shared_memory()
+    try:
+        assert handle.path.exists()
+        assert_frame_equal(handle.read(), df)
+        assert_frame_equal(handle.read(columns=["c", "a"]), df.select("c", "a"))
+
+        restored = pickle.loads(pickle.dumps(handle))
+        assert restored.name == handle.name
+        assert_frame_equal(restored.read(), df)
+    finally:
+        handle.unlink()
+    assert not handle.path.exists()
+
+
+def test_sink_shared_memory(df: pl.DataFrame, tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    df.write_parquet(path)
+
+    q = pl.scan_parquet(path).filter(pl.col("a") > 1)
+    handle = q.sink_shared_memory()
+    try:
+        assert_frame_equal(handle.read(), q.collect())
+    finally:
+        handle.unlink()
+
+
+def test_shared_memory_named(df: pl.DataFrame) -> None:
+    handle = df.write_shared_memory("polars-test-shared-memory-named")
+    try:
+        assert handle.name == "polars-test-shared-memory-named"
+        assert repr(handle) == "SharedMemoryFrame('polars-test-shared-memory-named')"
+        assert_frame_equal(pl.SharedMemoryFrame(handle.name).read(), df)
+
+        with pytest.raises(FileExistsError, match="already exists"):
+            df.write_shared_memory(handle.name)
+        with pytest.raises(FileExistsError, match="already exists"):
+            df.lazy().sink_shared_memory(handle.name)
+    finally:
+        handle.unlink()
+
+
+@pytest.mark.parametrize("name", ["", ".", "..", "a/b"])
+def test_shared_memory_invalid_name(df: pl.DataFrame, name: str) -> None:
+    with pytest.raises(ValueError, match="invalid shared-memory segment name"):
+        df.write_shared_memory(name)
+
+
+@pytest.mark.slow
+def test_shared_memory_multiprocessing(df: pl.DataFrame) -> None:
+    handle = df.write_shared_memory()
+    try:
+        with ProcessPoolExecutor(max_workers=2) as pool:
+            assert list(pool.map(_read_sum, [handle, handle])) == [6, 6]
+    finally:
+        handle.unlink()
@@ -35,6 +35,27 @@ def test_mkdir(tmp_path: Path, scan: Any, sink: Any, engine: EngineType) -> None
     assert_frame_equal(scan(f).collect(), df)
 
 
+@pytest.mark.parametrize(("scan", "sink"), SINKS)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_staged_commit(
+    tmp_path: Path, scan: Any, sink: Any, engine: EngineType
+) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    f = tmp_path / "file"
+
+    sink(df.lazy(), f, staged_commit=True, engine=engine)
+    assert_frame_equal(scan(f).collect(), df)
+    assert [p.name for p in tmp_path.iterdir()] == ["file"]
+
+    # A failing query leaves the previous file in place.
+    lf = pl.LazyFrame({"a": [1, 1000]}).select(pl.col("a").cast(pl.Int8))
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        sink(lf, f, staged_commit=True, engine=engine)
+    assert_frame_equal(scan(f).collect(), df)
+    assert [p.name for p in tmp_path.iterdir()] == ["file"]
+
+
 @pytest.mark.parametrize(("scan", "sink"), SINKS)
 @pytest.mark.parametrize("engine", ["in-memory", "streaming"])
 @pytest.mark.write_disk
@@ -106,3 +127,31 @@ def test_sink_to_file(tmp_path: Path, sink: Any, scan: Any) -> None:
             scan(f).collect(),
             df,
         )
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_sink_multiple(tmp_path: Path, engine: EngineType) -> None:
+    df = pl.DataFrame({"key": ["a", "b", "a"], "value": [1, 2, 3]})
+    df.write_parquet(tmp_path / "source.parquet")
+    lf = pl.scan_parquet(tmp_path / "source.parquet")
+
+    summary = lf.group_by("key").agg(pl.col("value").sum()).sort("key")
+    pl.sink_multiple(
+        [
+            lf.sink_ipc(tmp_path / "raw.ipc", lazy=True),
+            summary.sink_csv(tmp_path / "summary.csv", lazy=True),
+        ],
+        engine=engine,
+    )
+
+    assert_frame_equal(pl.read_ipc(tmp_path / "raw.ipc"), df)
+    assert_frame_equal(
+        pl.read_csv(tmp_path / "summary.csv"),
+        pl.DataFrame({"key": ["a", "b"], "value": [4, 2]}),
+    )
+
+
+def test_sink_multiple_requires_sinks() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="lazy file sinks"):
+        pl.sink_multiple([pl.LazyFrame({"a": [1]})])
@@ -0,0 +1,65 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_collect_partitioned_round_robin() -> None:
+    lf = pl.LazyFrame({"a": range(10), "b": [str(i) for i in range(10)]})
+    shards = lf.collect_partitioned(n_shards=3)
+
+    assert [df["a"].to_list() for df in shards] == [[0, 3, 6, 9], [1, 4, 7], [2, 5, 8]]
+    assert all(df.columns == ["a", "b"] for df in shards)
+
+
+def test_collect_partitioned_by_key() -> None:
+    lf = pl.LazyFrame({"key": [1, 2, None, 1, 3, 2, 4, None], "v": range(8)})
+    shards = lf.collect_partitioned("key", n_shards=4)
+    assert len(shards) == 4
+
+    keys = [set(df["key"]) for df in shards]
+    for i, a in enumerate(keys):
+        for b in keys[i + 1 :]:
+            assert a.isdisjoint(b)
+
+    assert_frame_equal(pl.concat(shards).sort("v"), lf.collect())
+
+    # Sharding is deterministic.
+    again = lf.collect_partitioned(pl.col("key"), n_shards=4)
+    for a_df, b_df in zip(shards, again):
+        assert_frame_equal(a_df, b_df)
+
+
+def test_collect_partitioned_empty_shards() -> None:
+    lf = pl.LazyFrame({"key": [1, 1, 1], "v": [1.0, 2.0, 3.0]})
+    shards = lf.collect_partitioned("key", n_shards=3)
+
+    assert sorted(df.height for df in shards) == [0, 0, 3]
+    assert all(df.schema == lf.collect_schema() for df in shards)
+
+
+def test_collect_partitioned_max_shard_size() -> None:
+    lf = pl.LazyFrame({"a": range(7)})
+    shards = lf.collect_partitioned(max_shard_size=3)
+
+    assert [df["a"].to_list() for df in shards] == [[0, 1, 2], [3, 4, 5], [6]]
+
+
+@pytest.mark.parametrize("n_shards", [0, -1])
+def test_collect_partitioned_invalid_n_shards(n_shards: int) -> None:
+    with pytest.raises(ValueError, match="`n_shards` must be a positive integer"):
+        pl.LazyFrame({"a": [1]}).collect_partitioned(n_shards=n_shards)
+
+
+def test_collect_partitioned_invalid_arguments() -> None:
+    lf = pl.LazyFrame({"a": [1]})
+    with pytest.raises(ValueError, match="exactly one of"):
+        lf.collect_partitioned()
+    with pytest.raises(ValueError, match="exactly one of"):
+        lf.collect_partitioned(n_shards=2, max_shard_size=2)
+    with pytest.raises(ValueError, match="cannot be combined"):
+        lf.collect_partitioned("a", max_shard_size=2)
+    with pytest.raises(ValueError, match="`max_shard_size` must be a positive"):
+        lf.collect_partitioned(max_shard_size=0)
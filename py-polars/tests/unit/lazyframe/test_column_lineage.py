@@ -0,0 +1,85 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import polars as pl
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def lineage_of(
+    lf: pl.LazyFrame,
+) -> dict[str, tuple[set[tuple[str, str]], list[str]]]:
+    return {
+        row["column"]: (
+            {(s["source"], s["column"]) for s in row["sources"]},
+            row["expressions"],
+        )
+        for row in lf.column_lineage().iter_rows(named=True)
+    }
+
+
+def test_column_lineage_select() -> None:
+    lf = pl.LazyFrame({"a": [1, 2], "b": [3, 4], "c": [5, 6]})
+    q = lf.select(
+        "a", (pl.col("a") + pl.col("b")).alias("d"), pl.lit(1).alias("e")
+    ).filter(pl.col("d") > 4)
+
+    result = q.column_lineage()
+    assert result.columns == ["column", "sources", "expressions"]
+    assert result["column"].to_list() == ["a", "d", "e"]
+
+    lineage = lineage_of(q)
+    assert lineage["a"] == ({("DF", "a")}, [])
+    assert lineage["d"][0] == {("DF", "a"), ("DF", "b")}
+    assert len(lineage["d"][1]) == 1
+    assert lineage["e"][0] == set()
+    assert len(lineage["e"][1]) == 1
+
+
+def test_column_lineage_scan_join_group_by(tmp_path: Path) -> None:
+    path = tmp_path / "a.csv"
+    pl.DataFrame({"key": [1, 2], "x": [1.0, 2.0]}).write_csv(path)
+    other = pl.LazyFrame({"key": [1, 2], "x": [3.0, 4.0], "label": ["a", "b"]})
+
+    q = (
+        pl.scan_csv(path)
+        .join(other, on="key", how="left")
+        .with_columns((pl.col("x") * 2).alias("y"))
+        .group_by("label")
+        .agg(pl.col("y").sum(), pl.col("x_right").first())
+    )
+    lineage = lineage_of(q)
+    assert list(lineage) == ["label", "y", "x_right"]
+
+    assert lineage["label"] == ({("DF", "label")}, [])
+
+    sources, expressions = lineage["y"]
+    assert len(sources) == 1
+    source, column = sources.pop()
+    assert source.endswith("a.csv")
+    assert column == "x"
+    assert len(expressions) == 2
+    assert "sum" in expressions[1]
+
+    sources, expressions = lineage["x_right"]
+    assert sources == {("DF", "x")}
+    assert len(expressions) == 1
+
+
+def test_column_lineage_coalesced_key(tmp_path: Path) -> None:
+    path = tmp_path / "right.csv"
+    pl.DataFrame({"k": [2, 3], "b": [1, 2]}).write_csv(path)
+    left = pl.LazyFrame({"k": [1, 2], "a": [1, 2]})
+    right = pl.scan_csv(path)
+
+    lineage = lineage_of(left.join(right, on="k", how="full", coalesce=True))
+    assert list(lineage) == ["k", "a", "b"]
+    assert {source for source, _ in lineage["k"][0]} == {"DF", str(path)}
+    assert lineage["a"][0] == {("DF", "a")}
+
+    lineage = lineage_of(left.join(right, on="k", how="full", coalesce=False))
+    assert list(lineage) == ["k", "a", "k_right", "b"]
+    assert lineage["k"][0] == {("DF", "k")}
+    assert lineage["k_right"][0] == {(str(path), "k")}
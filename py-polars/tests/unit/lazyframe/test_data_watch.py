@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+import re
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+@pytest.fixture
+def lf() -> pl.LazyFrame:
+    lf = pl.LazyFrame({"a": range(20)})
+    return lf.filter(pl.col("a") > 5).with_columns(b=-pl.col("a"))
+
+
+def node_id(lf: pl.LazyFrame, name: str) -> int:
+    plan = lf.explain(node_ids=True)
+    m = re.search(rf"^\s*\[(\d+)\] {name}", plan, re.MULTILINE)
+    assert m is not None, plan
+    return int(m.group(1))
+
+
+def test_explain_node_ids(lf: pl.LazyFrame) -> None:
+    plan = lf.explain(node_ids=True)
+    assert plan.startswith("[0] ")
+    assert re.sub(r"\[\d+\] ", "", plan) == lf.explain()
+
+    with pytest.raises(ValueError, match="node_ids"):
+        lf.explain(node_ids=True, format="tree")
+
+
+def test_data_watch_callback(lf: pl.LazyFrame) -> None:
+    samples: list[pl.DataFrame] = []
+    filter_id = node_id(lf, "FILTER")
+    out = lf.collect(watch={filter_id: samples.append})
+
+    assert_frame_equal(out, lf.collect())
+    assert len(samples) == 1
+    assert_frame_equal(samples[0], pl.DataFrame({"a": range(6, 16)}))
+
+
+def test_data_watch_ipc(lf: pl.LazyFrame, tmp_path: Path) -> None:
+    path = tmp_path / "filter.ipc"
+    filter_id = node_id(lf, "FILTER")
+    out = lf.collect(watch={filter_id: path})
+
+    assert_frame_equal(out, lf.collect())
+    assert_frame_equal(pl.read_ipc(path), pl.DataFrame({"a": range(6, 20)}))
+
+
+def test_data_watch_invalid(lf: pl.LazyFrame) -> None:
+    with pytest.raises(InvalidOperationError, match="no node with id 100"):
+        lf.collect(watch={100: print})
+    with pytest.raises(TypeError, match="expected a path or a callable"):
+        lf.collect(watch={0: 1})  # type: ignore[dict-item]
+    with pytest.raises(ValueError, match="background"):
+        lf.collect(watch={0: print}, background=True)
@@ -48,3 +48,19 @@ def test_engine_selection_eager_quiet(df: pl.LazyFrame, engine: EngineType) -> N
 def test_engine_import_error_raises(df: pl.LazyFrame, engine: EngineType) -> None:
     with pytest.raises(ImportError, match="GPU engine requested"):
         df.collect(engine=engine)
+
+
+def test_gpu_fallback_report() -> None:
+    lf = pl.LazyFrame({"a": [[1, 2], [3]], "b": [1, 2]})
+    q = lf.join(lf.select(pl.col("a").list.sum(), "b"), on="b")
+
+    report = q.gpu_fallback_report()
+    assert not report.supported
+    assert report.operations == [
+        pl.GPUUnsupportedOperation(
+            path=(1,), kind="expression", node="projection", message="list expr"
+        )
+    ]
+    assert repr(report) == (
+        "GPUFallbackReport(\n  [1] expression in projection: list expr\n)"
+    )
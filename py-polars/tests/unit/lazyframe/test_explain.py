@@ -1,8 +1,11 @@
 from __future__ import annotations
 
+import re
+
 import pytest
 
 import polars as pl
+from polars.exceptions import InvalidOperationError
 
 
 def test_lf_explain_format_tree() -> None:
@@ -37,3 +40,19 @@ def test_lf_explain_tree_format_deprecated() -> None:
 
     with pytest.deprecated_call():
         lf.explain(tree_format=True)
+
+
+def test_lf_explain_sample() -> None:
+    lf = pl.LazyFrame({"a": range(100)})
+    plan = lf.filter(pl.col("a") % 4 == 0).select(b=pl.col("a").sum())
+
+    result = plan.explain(sample=0.5)
+
+    rows = dict(re.findall(r"^\s*(\w+).*\[rows: (\d+), time: \d+µs\]$", result, re.M))
+    assert rows == {"SELECT": "1", "FILTER": "25", "DF": "50"}
+    assert re.sub(r"  \[rows: .*\]", "", result) == plan.explain()
+
+    with pytest.raises(ValueError, match="sample"):
+        plan.explain(sample=0.5, format="tree")
+    with pytest.raises(InvalidOperationError, match="fraction"):
+        plan.explain(sample=1.5)
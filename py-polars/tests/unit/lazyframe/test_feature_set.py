@@ -0,0 +1,90 @@
+from __future__ import annotations
+
+import io
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import (
+    ColumnNotFoundError,
+    InvalidOperationError,
+    SchemaError,
+)
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+def test_feature_set_dependency_order() -> None:
+    fs = pl.FeatureSet(
+        "fs",
+        {
+            "c": pl.col("b") + 1,
+            "b": pl.col("a") * 2,
+            "a": pl.col("a").cast(pl.Float64),
+            "d": pl.col("x") + pl.col("c"),
+        },
+        version=3,
+    )
+    lf = pl.LazyFrame({"a": [1, 2], "x": [10.0, 20.0]})
+    result = lf.with_feature_set(fs).collect()
+    expected = pl.DataFrame(
+        {
+            "a": [1.0, 2.0],
+            "x": [10.0, 20.0],
+            "b": [2.0, 4.0],
+            "c": [3.0, 5.0],
+            "d": [13.0, 25.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+    assert_frame_equal(lf.collect().with_feature_set(fs), expected)
+
+
+def test_feature_set_serde(tmp_path: Path) -> None:
+    fs = pl.FeatureSet(
+        "orders",
+        {"total": pl.col("price") * pl.col("qty"), "big": pl.col("total") > 10},
+        version=7,
+        dtypes={"big": pl.Boolean},
+    )
+    path = tmp_path / "orders.features"
+    fs.serialize(path)
+
+    for source in [path, io.BytesIO(fs.serialize())]:
+        result = pl.FeatureSet.deserialize(source)
+        assert result.name == "orders"
+        assert result.version == 7
+        assert list(result.features) == ["total", "big"]
+        assert result.features["big"].meta.eq(fs.features["big"])
+        assert result.dtypes == {"big": pl.Boolean}
+
+    df = pl.DataFrame({"price": [2, 5], "qty": [3, 4]})
+    expected = df.with_columns(total=pl.Series([6, 20]), big=pl.Series([False, True]))
+    assert_frame_equal(df.with_feature_set(result), expected)
+
+
+def test_feature_set_deserialize_invalid() -> None:
+    with pytest.raises(pl.exceptions.ComputeError, match="magic bytes"):
+        pl.FeatureSet.deserialize(io.BytesIO(b"not a feature set"))
+    lf = pl.LazyFrame({"a": [1]})
+    with pytest.raises(pl.exceptions.ComputeError, match="magic bytes"):
+        pl.FeatureSet.deserialize(io.BytesIO(lf.serialize()))
+
+
+def test_feature_set_invalid() -> None:
+    with pytest.raises(InvalidOperationError, match="cycle"):
+        pl.FeatureSet("fs", {"a": pl.col("b"), "b": pl.col("a") + 1})
+    with pytest.raises(ValueError, match="not features"):
+        pl.FeatureSet("fs", {"a": pl.col("x")}, dtypes={"b": pl.Int64})
+
+    lf = pl.LazyFrame({"x": [1, 2]})
+    fs = pl.FeatureSet("fs", {"y": pl.col("x") + pl.col("z")})
+    with pytest.raises(ColumnNotFoundError, match="'z' of feature set 'fs'"):
+        lf.with_feature_set(fs)
+
+    fs = pl.FeatureSet("fs", {"y": pl.col("x") / 2}, dtypes={"y": pl.Int64})
+    with pytest.raises(SchemaError, match="feature 'y' of feature set 'fs' has dtype"):
+        lf.with_feature_set(fs)
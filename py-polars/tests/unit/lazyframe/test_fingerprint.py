@@ -0,0 +1,72 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+
+
+@pytest.fixture
+def lf() -> pl.LazyFrame:
+    return pl.LazyFrame(
+        {"a": [1, 2, 3], "b": [True, False, True], "c": ["x", "y", "z"]}
+    )
+
+
+@pytest.mark.parametrize("optimized", [True, False])
+def test_fingerprint_equal_plans(lf: pl.LazyFrame, optimized: bool) -> None:
+    def query() -> pl.LazyFrame:
+        return lf.filter(pl.col("a") > 1).group_by("c").agg(pl.col("a").sum())
+
+    assert query().fingerprint(optimized=optimized) == query().fingerprint(
+        optimized=optimized
+    )
+    assert query().diff_plan(query(), optimized=optimized) is None
+
+
+@pytest.mark.parametrize("optimized", [True, False])
+def test_fingerprint_commutative(lf: pl.LazyFrame, optimized: bool) -> None:
+    a = pl.col("a") > 1
+    b = pl.col("b")
+    c = pl.col("c") == "x"
+    q1 = lf.filter(a & b & c).select(x=pl.col("a") * 2)
+    q2 = lf.filter(c & (b & a)).select(x=2 * pl.col("a"))
+    assert q1.fingerprint(optimized=optimized) == q2.fingerprint(optimized=optimized)
+    assert q1.diff_plan(q2, optimized=optimized) is None
+
+    # Non-commutative operators keep the order of their operands.
+    q1 = lf.select(x=pl.col("a") - 1)
+    q2 = lf.select(x=1 - pl.col("a"))
+    assert q1.fingerprint(optimized=optimized) != q2.fingerprint(optimized=optimized)
+
+
+def test_fingerprint_cache_ids(lf: pl.LazyFrame) -> None:
+    def query() -> pl.LazyFrame:
+        shared = lf.filter(pl.col("a") > 1)
+        return pl.concat([shared, shared])
+
+    assert query().fingerprint() == query().fingerprint()
+
+
+def test_diff_plan(lf: pl.LazyFrame) -> None:
+    q1 = lf.filter(pl.col("a") > 1).select("a", "c")
+    q2 = lf.filter(pl.col("a") > 2).select("a", "c")
+    assert q1.fingerprint() != q2.fingerprint()
+
+    diff = q1.diff_plan(q2)
+    assert diff is not None
+    removed = [line for line in diff.splitlines() if line.startswith("- ")]
+    added = [line for line in diff.splitlines() if line.startswith("+ ")]
+    assert len(removed) == len(added) == 1
+    assert "1" in removed[0]
+    assert "2" in added[0]
+
+
+def test_fingerprint_same_description() -> None:
+    q1 = pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a") + 1)
+    q2 = pl.LazyFrame({"a": [4, 5, 6]}).select(pl.col("a") + 1)
+    assert q1.explain() == q2.explain()
+    assert q1.fingerprint() != q2.fingerprint()
+
+    diff = q1.diff_plan(q2)
+    assert diff is not None
+    assert not any(line.startswith(("- ", "+ ")) for line in diff.splitlines())
@@ -0,0 +1,61 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_inspect_metrics(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [1, None, 3, 4, None], "b": [1.5, 2.0, None, -1.0, 0.0]})
+    lf, metrics = lf.inspect_metrics(
+        pl.len(),
+        pl.all().null_count().name.suffix("_nulls"),
+        pl.col("a").min().alias("a_min"),
+        pl.col("b").max(),
+        pl.col("a").sum().alias("a_sum"),
+    )
+    out = lf.filter(pl.col("a") > 1).collect(engine=engine)
+
+    assert_frame_equal(out, pl.DataFrame({"a": [3, 4], "b": [None, -1.0]}))
+    expected = pl.DataFrame(
+        {
+            "len": pl.Series([5], dtype=pl.UInt32),
+            "a_nulls": pl.Series([2], dtype=pl.UInt32),
+            "b_nulls": pl.Series([1], dtype=pl.UInt32),
+            "a_min": [1],
+            "b": [2.0],
+            "a_sum": [8],
+        }
+    )
+    assert_frame_equal(metrics.report(), expected)
+
+    # The metrics of every execution are combined.
+    lf.collect(engine=engine)
+    assert metrics.report()["len"].item() == 10
+
+    metrics.reset()
+    assert metrics.report()["len"].item() == 0
+    assert metrics.report()["a_min"].item() is None
+
+
+def test_inspect_metrics_streaming_batches() -> None:
+    lf = pl.LazyFrame({"a": range(10_000)})
+    lf, metrics = lf.inspect_metrics(pl.len(), pl.col("a").max())
+    with pl.Config(streaming_chunk_size=100):
+        lf.collect(engine="streaming")
+
+    assert metrics.report().rows() == [(10_000, 9_999)]
+
+
+def test_inspect_metrics_unsupported() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(InvalidOperationError, match="only supports"):
+        lf.inspect_metrics(pl.col("a").mean())
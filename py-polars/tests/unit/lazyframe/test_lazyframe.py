@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import asyncio
+import os
 from datetime import date, datetime
 from functools import reduce
 from inspect import signature
@@ -22,6 +24,8 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import FLOAT_DTYPES, NUMERIC_DTYPES
 
 if TYPE_CHECKING:
+    from pathlib import Path
+
     from _pytest.capture import CaptureFixture
 
     from polars._typing import PolarsDataType
@@ -899,6 +903,23 @@ def test_head(fruits_cars: pl.DataFrame) -> None:
     assert_frame_equal(fruits_cars.lazy().head(2).collect(), fruits_cars[:2, :])
 
 
+def test_peek() -> None:
+    lf = pl.LazyFrame({"a": range(10_000), "b": [i % 100 for i in range(10_000)]})
+    q = lf.filter(pl.col("a") % 7 == 0)
+    assert_frame_equal(q.peek(3), q.head(3).collect())
+    assert_frame_equal(lf.peek(0), lf.clear().collect())
+
+    # The group-by produces any complete groups.
+    q = lf.group_by("b").agg(pl.col("a").sum(), n=pl.len())
+    result = q.peek(5)
+    assert result.height == 5
+    assert result["b"].n_unique() == 5
+    assert_frame_equal(
+        result.sort("b"),
+        q.collect().filter(pl.col("b").is_in(result["b"].implode())).sort("b"),
+    )
+
+
 def test_tail(fruits_cars: pl.DataFrame) -> None:
     assert_frame_equal(fruits_cars.lazy().tail(2).collect(), fruits_cars[3:, :])
 
@@ -1168,6 +1189,83 @@ def test_lazy_cache_hit(monkeypatch: Any, capfd: Any) -> None:
     assert "CACHE HIT" in err
 
 
+@pytest.mark.may_fail_auto_streaming
+def test_collect_config_verbose(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.delenv("POLARS_VERBOSE", raising=False)
+
+    ldf = pl.LazyFrame({"a": [1, 2, 3], "c": ["x", "y", "z"]}).cache()
+    q = ldf.join(ldf, on="c")
+
+    q.collect(config={"verbose": True})
+    (_, err) = capfd.readouterr()
+    assert "CACHE HIT" in err
+    assert "POLARS_VERBOSE" not in os.environ
+
+    q.collect()
+    (_, err) = capfd.readouterr()
+    assert "CACHE HIT" not in err
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+    q.collect(config={"verbose": False})
+    (_, err) = capfd.readouterr()
+    assert "CACHE HIT" not in err
+
+
+@pytest.mark.may_fail_auto_streaming
+def test_collect_async_config_verbose(monkeypatch: Any, capfd: Any) -> None:
+    monkeypatch.delenv("POLARS_VERBOSE", raising=False)
+
+    ldf = pl.LazyFrame({"a": [1, 2, 3], "c": ["x", "y", "z"]}).cache()
+    q = ldf.join(ldf, on="c")
+
+    # The query runs on a thread of the thread pool, the setting must travel with it.
+    async def collect() -> pl.DataFrame:
+        return await q.collect_async(config={"verbose": True})
+
+    asyncio.run(collect())
+    (_, err) = capfd.readouterr()
+    assert "CACHE HIT" in err
+
+
+def test_collect_config_streaming_chunk_size() -> None:
+    lf = pl.LazyFrame({"a": range(100)}).select(pl.col("a") * 2)
+    expected = pl.DataFrame({"a": range(0, 200, 2)})
+    result = lf.collect(engine="streaming", config={"streaming_chunk_size": 7})
+    assert_frame_equal(result, expected)
+
+
+def test_collect_config_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1]})
+    with pytest.raises(ValueError, match="unsupported `config` option"):
+        lf.collect(config={"tbl_rows": 5})
+    with pytest.raises(ValueError, match="must be >= 1"):
+        lf.collect(config={"streaming_chunk_size": 0})
+
+
+def test_collect_streaming_engine_config(tmp_path: Path) -> None:
+    paths = []
+    for i in range(3):
+        paths.append(tmp_path / f"{i}.parquet")
+        pl.DataFrame({"a": [i, i + 1]}).write_parquet(paths[-1])
+    right = pl.LazyFrame({"a": [1, 2], "b": ["x", "y"]})
+
+    engine = pl.StreamingEngine(
+        scan_readahead=1, join_probe_parallelism=1, sink_writer_threads=1
+    )
+    assert "join_probe_parallelism=1" in repr(engine)
+    q = pl.scan_parquet(paths).join(right, on="a")
+    result = q.collect(engine=engine)
+    assert_frame_equal(result, q.collect(), check_row_order=False)
+
+    q.sink_parquet(tmp_path / "out.parquet", engine=engine)
+    assert_frame_equal(
+        pl.read_parquet(tmp_path / "out.parquet"), result, check_row_order=False
+    )
+
+    with pytest.raises(ValueError, match="must be >= 1"):
+        pl.StreamingEngine(join_probe_parallelism=0)
+
+
 def test_lazy_cache_parallel() -> None:
     df_evaluated = 0
 
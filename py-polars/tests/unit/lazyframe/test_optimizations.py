@@ -1,10 +1,16 @@
+from __future__ import annotations
+
 import itertools
+from typing import TYPE_CHECKING
 
 import pytest
 
 import polars as pl
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from polars._typing import JoinStrategy
+
 
 def test_is_null_followed_by_all() -> None:
     lf = pl.LazyFrame({"group": [0, 0, 0, 1], "val": [6, 0, None, None]})
@@ -385,3 +391,37 @@ def test_fused_correct_name() -> None:
         opts,
     )
     assert_frame_equal(opts, pl.DataFrame({"a": [2, 6, 12]}))
+
+
+@pytest.mark.parametrize("how", ["left", "inner"])
+def test_lookup_joins(how: JoinStrategy) -> None:
+    fact = pl.LazyFrame({"key": [1, 2, None, 3, 1], "value": [1, 2, 3, 4, 5]})
+    dim = pl.DataFrame({"key": [1, 3, None], "value": ["a", "c", "n"]}).lazy()
+
+    q = fact.join(dim, on="key", how=how)
+    optimizations = pl.QueryOptFlags(lookup_joins=True)
+
+    plan = q.explain(optimizations=optimizations)
+    assert "JOIN" not in plan
+    assert "JOIN" in q.explain()
+    assert_frame_equal(
+        q.collect(optimizations=optimizations),
+        q.collect(),
+        check_row_order=False,
+    )
+
+
+def test_lookup_joins_not_applied() -> None:
+    fact = pl.LazyFrame({"key": [1, 2, 3]})
+    optimizations = pl.QueryOptFlags(lookup_joins=True)
+
+    # Duplicate keys.
+    dim = pl.LazyFrame({"key": [1, 1], "value": ["a", "b"]})
+    q = fact.join(dim, on="key", how="left")
+    assert "LEFT JOIN" in q.explain(optimizations=optimizations)
+    assert_frame_equal(q.collect(optimizations=optimizations), q.collect())
+
+    # Right order.
+    dim = pl.LazyFrame({"key": [3, 1], "value": ["c", "a"]})
+    q = fact.join(dim, on="key", how="inner", maintain_order="right")
+    assert "INNER JOIN" in q.explain(optimizations=optimizations)
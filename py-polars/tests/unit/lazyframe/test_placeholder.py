@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
+
+
+@pytest.fixture
+def lf() -> pl.LazyFrame:
+    return pl.LazyFrame({"a": [1, 2, 3, 4], "b": ["x", "y", "x", "y"]})
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_placeholder_filter(lf: pl.LazyFrame, engine: EngineType) -> None:
+    query = lf.filter(
+        pl.col("a") > pl.placeholder("min_a", pl.Int64),
+        pl.col("b") == pl.placeholder("b", pl.String),
+    )
+    assert_frame_equal(
+        query.collect(engine=engine, params={"min_a": 1, "b": "x"}),
+        pl.DataFrame({"a": [3], "b": ["x"]}),
+    )
+    assert_frame_equal(
+        query.collect(engine=engine, params={"min_a": 0, "b": "y"}),
+        pl.DataFrame({"a": [2, 4], "b": ["y", "y"]}),
+    )
+
+
+def test_placeholder_select_cast(lf: pl.LazyFrame) -> None:
+    query = lf.select(pl.col("a") * pl.placeholder("factor", pl.Float64))
+    assert query.collect_schema() == pl.Schema({"a": pl.Float64})
+    assert_frame_equal(
+        query.collect(params={"factor": 2}),
+        pl.DataFrame({"a": [2.0, 4.0, 6.0, 8.0]}),
+    )
+
+    query = lf.select(pl.placeholder("x", pl.Int32))
+    expected = pl.DataFrame({"x": pl.Series([5], dtype=pl.Int32)})
+    assert_frame_equal(query.collect(params={"x": 5}), expected)
+
+
+def test_placeholder_missing_or_unknown(lf: pl.LazyFrame) -> None:
+    query = lf.filter(pl.col("a") > pl.placeholder("min_a", pl.Int64))
+    with pytest.raises(InvalidOperationError, match="not bound"):
+        query.collect()
+    with pytest.raises(InvalidOperationError, match="no value given"):
+        query.collect(params={})
+    with pytest.raises(InvalidOperationError, match="no placeholder 'max_a'"):
+        query.collect(params={"min_a": 1, "max_a": 3})
+
+
+def test_placeholder_background_unsupported(lf: pl.LazyFrame) -> None:
+    query = lf.filter(pl.col("a") > pl.placeholder("min_a", pl.Int64))
+    with pytest.raises(ValueError, match="background"):
+        query.collect(background=True, params={"min_a": 1})
@@ -0,0 +1,63 @@
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+
+if TYPE_CHECKING:
+    from pathlib import Path
+
+
+@pytest.mark.write_disk
+def test_pruned_files_hive(tmp_path: Path) -> None:
+    pl.DataFrame({"x": [0, 1, 1, 2, 4], "y": range(5)}).write_parquet(
+        tmp_path, partition_by="x"
+    )
+    lf = pl.scan_parquet(tmp_path, hive_partitioning=True)
+
+    result = lf.filter(pl.col("x").is_in([1, 4])).pruned_files()
+    assert result.schema == pl.Schema(
+        {
+            "path": pl.String,
+            "estimated_rows": pl.UInt64,
+            "estimated_bytes": pl.UInt64,
+        }
+    )
+    paths = result["path"].to_list()
+    assert len(paths) == 2
+    assert "x=1" in paths[0]
+    assert "x=4" in paths[1]
+    for path, size in zip(paths, result["estimated_bytes"]):
+        assert size == (tmp_path / path).stat().st_size
+
+    assert lf.pruned_files().height == 4
+    assert lf.filter(pl.col("x") > 10).pruned_files().height == 0
+
+
+@pytest.mark.write_disk
+def test_pruned_files_statistics(tmp_path: Path) -> None:
+    paths = [tmp_path / f"{i}.parquet" for i in range(3)]
+    for i, path in enumerate(paths):
+        pl.DataFrame({"a": [2 * i, 2 * i + 1]}).write_parquet(path)
+
+    stats = pl.DataFrame(
+        {
+            "num_rows": [2, None, 2],
+            "min": [{"a": 0}, {"a": 2}, {"a": 4}],
+            "max": [{"a": 1}, {"a": 3}, {"a": 5}],
+        }
+    )
+    lf = pl.scan_parquet(paths, file_statistics=stats)
+
+    result = lf.filter(pl.col("a") >= 2).pruned_files()
+    assert result["path"].to_list() == [str(p) for p in paths[1:]]
+    assert result["estimated_rows"].to_list() == [None, 2]
+    assert result["estimated_bytes"].to_list() == [p.stat().st_size for p in paths[1:]]
+
+
+def test_pruned_files_invalid() -> None:
+    with pytest.raises(InvalidOperationError, match="exactly one file scan, got 0"):
+        pl.LazyFrame({"a": [1]}).pruned_files()
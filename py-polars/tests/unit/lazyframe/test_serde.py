@@ -7,7 +7,7 @@ import pytest
 from hypothesis import example, given
 
 import polars as pl
-from polars.exceptions import ComputeError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal
 from polars.testing.parametric import dataframes
 
@@ -154,3 +154,99 @@ def test_lf_serde_map_batches_on_lazyframe() -> None:
     result = pl.LazyFrame.deserialize(io.BytesIO(ser))
     expected = pl.LazyFrame({"a": [2, 3, 4]})
     assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("format", ["binary", "json"])
+def test_lf_deserialize_reject_python_objects(format: SerializationFormat) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    udf = lf.select(pl.col("a").map_batches(custom_function, return_dtype=pl.Int64))
+
+    ser = lf.serialize(format=format)
+    result = pl.LazyFrame.deserialize(
+        io.BytesIO(ser), format=format, allow_python_objects=False
+    )
+    assert_frame_equal(result, lf)
+
+    ser = udf.serialize(format=format)
+    with pytest.raises(ComputeError, match="deserializing Python objects"):
+        pl.LazyFrame.deserialize(
+            io.BytesIO(ser), format=format, allow_python_objects=False
+        )
+
+
+@pytest.mark.write_disk
+def test_lf_deserialize_allowed_paths(tmp_path: Path) -> None:
+    allowed = tmp_path / "allowed"
+    allowed.mkdir()
+    path = allowed / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3]}).write_parquet(path)
+
+    def deserialize(lf: pl.LazyFrame) -> pl.LazyFrame:
+        return pl.LazyFrame.deserialize(
+            io.BytesIO(lf.serialize()), allowed_paths=[allowed, "s3://bucket/data"]
+        )
+
+    lf = pl.scan_parquet(path)
+    assert_frame_equal(deserialize(lf), lf)
+    assert_frame_equal(deserialize(pl.LazyFrame({"a": [1]})), pl.LazyFrame({"a": [1]}))
+    deserialize(pl.scan_parquet("s3://bucket/data/*.parquet", credential_provider=None))
+
+    for lf in [
+        pl.scan_parquet(tmp_path / "other.parquet"),
+        pl.scan_parquet(allowed / ".." / "other.parquet"),
+        pl.scan_parquet(path).join(pl.scan_csv(tmp_path / "x.csv"), on="a"),
+        pl.scan_parquet("s3://bucket/data-other/x.parquet", credential_provider=None),
+        pl.scan_parquet("s3://bucket/data/../x.parquet", credential_provider=None),
+        pl.LazyFrame({"a": [1]}).sink_parquet(tmp_path / "out.parquet", lazy=True),
+    ]:
+        with pytest.raises(InvalidOperationError, match="outside of the allowed paths"):
+            deserialize(lf)
+
+
+def deserialize_without_user_functions(lf: pl.LazyFrame) -> pl.LazyFrame:
+    return pl.LazyFrame.deserialize(
+        io.BytesIO(lf.serialize()), allow_user_functions=False
+    )
+
+
+def test_lf_deserialize_reject_plugins() -> None:
+    from polars.plugins import register_plugin_function
+
+    expr = register_plugin_function(
+        plugin_path="lib.so", function_name="hello", args=pl.col("a")
+    )
+    lf = pl.LazyFrame({"a": [1], "b": [2]})
+    assert_frame_equal(deserialize_without_user_functions(lf), lf)
+
+    for q in [
+        lf.select(expr),
+        lf.filter(pl.col("b").over(order_by=expr) > 0),
+        lf.select(pl.col("b").cast(pl.dtype_of(expr))),
+        lf.select(pl.int_range(3, dtype=pl.dtype_of(expr))),
+        lf.quantile(expr),
+    ]:
+        with pytest.raises(InvalidOperationError, match="FFI plugins are not allowed"):
+            deserialize_without_user_functions(q)
+
+
+@pytest.mark.filterwarnings("ignore::polars.exceptions.PolarsInefficientMapWarning")
+def test_lf_deserialize_reject_python_udfs() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    udf = lf.with_columns(pl.col("a").map_batches(custom_function) * 2)
+    with pytest.raises(InvalidOperationError, match="anonymous functions"):
+        deserialize_without_user_functions(udf)
+
+    udf = lf.map_batches(lambda df: df)
+    with pytest.raises(InvalidOperationError, match="Python functions"):
+        deserialize_without_user_functions(udf)
+
+
+def test_lf_deserialize_reject_anonymous_functions() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": [4, 5, 6]})
+    fold = lf.select(pl.fold(pl.lit(0), lambda acc, x: acc + x, pl.all()))
+    with pytest.raises(InvalidOperationError, match="fold and reduce functions"):
+        deserialize_without_user_functions(fold)
+
+    rename = lf.select(pl.all().name.map(str.upper))
+    with pytest.raises(InvalidOperationError, match="Python functions"):
+        deserialize_without_user_functions(rename)
@@ -8,7 +8,7 @@ import pytest
 
 import polars as pl
 import polars.selectors as cs
-from polars.exceptions import ComputeError, PolarsError
+from polars.exceptions import ComputeError, InvalidOperationError, PolarsError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
@@ -668,3 +668,89 @@ def test_raise_invalid_types_21835() -> None:
         match=r"cannot compare string with numeric type \(i64\)",
     ):
         df.select(pl.min_horizontal("x", "y"))
+
+
+def test_any_all_horizontal_wide_early_exit() -> None:
+    # Wide enough to not be rewritten to binary expressions.
+    n = 200
+    df = pl.DataFrame(
+        {
+            "first": [True, False, None, False],
+            **{f"c{i}": [False, False, None, False] for i in range(n)},
+            "last": [False, True, False, None],
+        }
+    )
+    out = df.select(any=pl.any_horizontal(pl.all()), all=pl.all_horizontal(pl.all()))
+    assert out.to_dict(as_series=False) == {
+        "any": [True, True, None, None],
+        "all": [False, False, False, False],
+    }
+
+    # The accumulator saturates on the first, scalar, column.
+    out = df.select(pl.any_horizontal(pl.lit(True), *[f"c{i}" for i in range(n)]))
+    assert out.to_series().to_list() == [True] * 4
+
+    # Columns after the saturating one are still validated.
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        df.select(
+            pl.any_horizontal(pl.lit(True), *[f"c{i}" for i in range(n)], pl.lit("x"))
+        )
+
+
+@pytest.mark.parametrize("dtype", [pl.Int8, pl.Int64, pl.UInt32, pl.Float64])
+def test_sum_horizontal_wide_same_dtype(dtype: PolarsDataType) -> None:
+    s = pl.Series(range(5000))
+    df = pl.DataFrame({f"c{i}": s % (i % 50 + 2) for i in range(150)}).cast(dtype)
+    expected = df.select(pl.fold(pl.lit(0, dtype), lambda acc, s: acc + s, pl.all()))
+    assert_frame_equal(
+        df.select(pl.sum_horizontal(pl.all())),
+        expected.rename({"literal": "c0"}),
+    )
+
+
+def test_median_quantile_std_horizontal() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [2, 8, 1, None],
+            "b": [4, 5, None, None],
+            "c": [6, 2, 1, None],
+        }
+    )
+    out = df.select(
+        median=pl.median_horizontal(pl.all()),
+        q=pl.quantile_horizontal(pl.all(), quantile=0.75, interpolation="linear"),
+        q_nearest=pl.quantile_horizontal("a", "b", quantile=1.0),
+        std=pl.std_horizontal(pl.all()),
+        std_ddof0=pl.std_horizontal("a", "b", ddof=0),
+    )
+    expected = pl.DataFrame(
+        {
+            "median": [4.0, 5.0, 1.0, None],
+            "q": [5.0, 6.5, 1.0, None],
+            "q_nearest": [4.0, 8.0, 1.0, None],
+            "std": [2.0, 3.0, 0.0, None],
+            "std_ddof0": [1.0, 1.5, 0.0, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_median_std_horizontal_broadcast_and_dtype() -> None:
+    df = pl.DataFrame({"a": [1.0, 3.0], "b": [5.0, None]}).cast(pl.Float32)
+    out = df.select(
+        pl.median_horizontal(pl.all(), pl.lit(3.0, pl.Float32)),
+        std=pl.std_horizontal(pl.all()),
+    )
+    assert out.schema == pl.Schema({"a": pl.Float32, "std": pl.Float32})
+    assert out.to_dict(as_series=False) == {
+        "a": [3.0, 3.0],
+        "std": [pytest.approx(2.8284271), None],
+    }
+
+
+def test_quantile_std_horizontal_errors() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x", "y"]})
+    with pytest.raises(ComputeError, match="quantile should be between"):
+        df.select(pl.quantile_horizontal("a", quantile=1.5))
+    with pytest.raises(InvalidOperationError, match="expects numeric expressions"):
+        df.select(pl.std_horizontal("a", "b"))
@@ -0,0 +1,58 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_add_checked() -> None:
+    df = pl.DataFrame(
+        {"a": [1, 100, 127, None], "b": [1, 27, 1, 1]},
+        schema={"a": pl.Int8, "b": pl.Int8},
+    )
+    with pytest.warns(UserWarning, match="1 of 4 values overflowed in `add_checked`"):
+        out = df.select(pl.col("a").add_checked(pl.col("b"))).to_series()
+    assert_series_equal(out, pl.Series("a", [2, 127, None, None], dtype=pl.Int8))
+
+
+def test_sub_checked_unsigned() -> None:
+    s = pl.Series("a", [0, 5, 10], dtype=pl.UInt8)
+    with pytest.warns(UserWarning, match="1 of 3 values overflowed in `sub_checked`"):
+        out = s.to_frame().select(pl.col("a").sub_checked(5)).to_series()
+    assert_series_equal(out, pl.Series("a", [None, 0, 5], dtype=pl.UInt8))
+
+
+def test_mul_checked() -> None:
+    s = pl.Series("a", [2, 2**62, -(2**62), 3], dtype=pl.Int64)
+    with pytest.warns(UserWarning, match="1 of 4 values overflowed in `mul_checked`"):
+        out = s.to_frame().select(pl.col("a").mul_checked(2)).to_series()
+    assert_series_equal(out, pl.Series("a", [4, None, -(2**63), 6], dtype=pl.Int64))
+
+
+def test_checked_no_overflow_no_warning() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    out = df.select(
+        add=pl.col("a").add_checked(1),
+        sub=pl.col("a").sub_checked(1),
+        mul=pl.col("a").mul_checked(pl.col("a")),
+    )
+    assert out.to_dict(as_series=False) == {
+        "add": [2, 3, 4],
+        "sub": [0, 1, 2],
+        "mul": [1, 4, 9],
+    }
+
+
+def test_checked_supertype() -> None:
+    df = pl.DataFrame({"a": [127], "b": [1]}, schema={"a": pl.Int8, "b": pl.Int16})
+    out = df.select(pl.col("a").add_checked(pl.col("b")))
+    assert out.schema == pl.Schema({"a": pl.Int16})
+    assert out.item() == 128
+
+
+def test_checked_non_integer_raises() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0]})
+    with pytest.raises(InvalidOperationError, match="only supported for integers"):
+        df.select(pl.col("a").add_checked(1))
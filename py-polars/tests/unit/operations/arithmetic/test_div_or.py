@@ -0,0 +1,54 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_truediv_or() -> None:
+    df = pl.DataFrame({"a": [6, 1, 0, None, -3], "b": [3, 0, 0, 2, None]})
+    out = df.select(
+        null=pl.col("a").truediv_or(pl.col("b")),
+        default=pl.col("a").truediv_or(pl.col("b"), -1),
+        expr=pl.col("a").truediv_or(pl.col("b"), pl.col("a") * 10),
+    )
+    assert out.to_dict(as_series=False) == {
+        "null": [2.0, None, None, None, None],
+        "default": [2.0, -1.0, -1.0, None, None],
+        "expr": [2.0, 10.0, 0.0, None, None],
+    }
+    assert out.schema == pl.Schema(dict.fromkeys(out.columns, pl.Float64))
+
+
+def test_truediv_or_float32() -> None:
+    s = pl.Series("a", [1.0, 3.0], dtype=pl.Float32)
+    other = pl.Series([0.0, -2.0], dtype=pl.Float32)
+    out = s.to_frame().select(pl.col("a").truediv_or(other, 0.0))
+    assert_series_equal(out.to_series(), pl.Series("a", [0.0, -1.5], dtype=pl.Float32))
+
+
+def test_floordiv_or() -> None:
+    df = pl.DataFrame(
+        {"a": [7, -7, 5, None], "b": [2, 2, 0, 0]},
+        schema={"a": pl.Int16, "b": pl.Int16},
+    )
+    out = df.select(pl.col("a").floordiv_or(pl.col("b"), 0)).to_series()
+    assert_series_equal(out, pl.Series("a", [3, -4, 0, None], dtype=pl.Int16))
+
+    s = pl.Series("x", [7.5, -7.5, 1.0])
+    out = s.to_frame().select(pl.col("x").floordiv_or(pl.Series([2.0, 2.0, 0.0])))
+    assert_series_equal(out.to_series(), pl.Series("x", [3.0, -4.0, None]))
+
+
+def test_div_or_broadcast() -> None:
+    df = pl.DataFrame({"b": [0, 4, 0]})
+    out = df.select(pl.lit(8).truediv_or(pl.col("b"), pl.Series([1.0, 2.0, 3.0])))
+    assert out.to_series().to_list() == [1.0, 2.0, 3.0]
+
+
+def test_div_or_invalid() -> None:
+    df = pl.DataFrame({"a": ["x"], "b": [1]})
+    with pytest.raises(InvalidOperationError, match="only supported for numeric"):
+        df.select(pl.col("a").truediv_or(pl.col("b")))
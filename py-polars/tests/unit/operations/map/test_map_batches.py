@@ -1,6 +1,7 @@
 from __future__ import annotations
 
 from functools import reduce
+from typing import TYPE_CHECKING
 
 import numpy as np
 import pytest
@@ -9,6 +10,9 @@ import polars as pl
 from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal
 
+if TYPE_CHECKING:
+    from pathlib import Path
+
 
 def test_map_return_py_object() -> None:
     df = pl.DataFrame({"A": [1, 2, 3], "B": [4, 5, 6]})
@@ -128,3 +132,31 @@ def test_map_batches_collect_schema_17327() -> None:
     )
     expected = pl.Schema({"a": pl.Int64(), "b": pl.List(pl.Int64)})
     assert q.collect_schema() == expected
+
+
+@pytest.mark.write_disk
+def test_map_batches_scan_level(tmp_path: Path) -> None:
+    path = tmp_path / "blobs.parquet"
+    values = list(range(1000))
+    pl.DataFrame({"blob": [v.to_bytes(4, "little") for v in values]}).write_parquet(
+        path, row_group_size=100
+    )
+
+    batch_heights = []
+
+    def decode(df: pl.DataFrame) -> pl.DataFrame:
+        batch_heights.append(df.height)
+        decoded = [int.from_bytes(b, "little") for b in df["blob"]]
+        return df.with_columns(value=pl.Series(decoded, dtype=pl.Int64))
+
+    q = pl.scan_parquet(path).map_batches(
+        decode, schema={"blob": pl.Binary, "value": pl.Int64}, scan_level=True
+    )
+    out = q.collect(engine="streaming")
+    assert out["value"].to_list() == values
+    # The function runs on the batches of the scan instead of on the collected scan.
+    assert len(batch_heights) > 1
+    assert sum(batch_heights) == len(values)
+
+    with pytest.raises(InvalidOperationError, match="directly to a scan"):
+        pl.scan_parquet(path).head(5).map_batches(decode, scan_level=True)
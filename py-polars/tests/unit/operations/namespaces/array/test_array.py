@@ -508,6 +508,29 @@ def test_array_to_struct() -> None:
     ).sum().collect().columns == ["field_0", "field_1", "field_2"]
 
 
+def test_array_to_cols() -> None:
+    df = pl.DataFrame(
+        {"a": [[1, 2, 3], None, [4, 5, None]]}, schema={"a": pl.Array(pl.Int8, 3)}
+    )
+    expected = pl.DataFrame(
+        {"x": [1, None, 4], "y": [2, None, 5], "z": [3, None, None]},
+        schema={"x": pl.Int8, "y": pl.Int8, "z": pl.Int8},
+    )
+    assert_frame_equal(df.select(pl.col("a").arr.to_cols(["x", "y", "z"])), expected)
+    assert_frame_equal(df.to_series().arr.to_cols(["x", "y", "z"]), expected)
+
+    # Sliced arrays.
+    out = df.slice(1).select(pl.col("a").arr.to_cols())
+    assert out.columns == ["field_0", "field_1", "field_2"]
+    assert out.rows() == [(None, None, None), (4, 5, None)]
+
+
+def test_array_to_cols_roundtrip() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0], "b": [3.0, None], "c": [5.0, 6.0]})
+    out = df.select(pl.concat_arr("a", "b", "c").arr.to_cols(df.columns))
+    assert_frame_equal(out, df)
+
+
 def test_array_shift() -> None:
     df = pl.DataFrame(
         {"a": [[1, 2, 3], None, [4, 5, 6], [7, 8, 9]], "n": [None, 1, 1, -2]},
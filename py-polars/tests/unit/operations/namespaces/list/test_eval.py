@@ -6,6 +6,7 @@ import pytest
 
 import polars as pl
 from polars.exceptions import (
+    InvalidOperationError,
     StructFieldNotFoundError,
 )
 from polars.testing import assert_frame_equal, assert_series_equal
@@ -166,3 +167,49 @@ def test_list_eval_in_filter_23300() -> None:
         ).height
         == 1
     )
+
+
+def test_list_agg() -> None:
+    df = pl.DataFrame({"a": [[1, 8, 3], [], None, [4, 5]]})
+    result = df.select(
+        sum=pl.col("a").list.agg(pl.element().sum()),
+        q=pl.col("a").list.agg(pl.element().quantile(0.9)),
+        n_big=pl.col("a").list.agg((pl.element() > 3).sum()),
+    )
+    expected = pl.DataFrame(
+        {
+            "sum": [12, 0, None, 9],
+            "q": [8.0, None, None, 5.0],
+            "n_big": pl.Series([1, 0, None, 2], dtype=pl.UInt32),
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_list_agg_matches_eval() -> None:
+    df = pl.DataFrame({"a": [[1.0, 2.0, None], [3.0], None, [5.0, 7.0, 6.0]]})
+    for expr in [
+        pl.element().mean(),
+        pl.element().null_count(),
+        pl.element().max() - pl.element().min(),
+        pl.element().sort().last(),
+    ]:
+        assert_series_equal(
+            df.select(pl.col("a").list.agg(expr)).to_series(),
+            df.select(pl.col("a").list.eval(expr).list.first()).to_series(),
+        )
+
+
+def test_list_agg_group_by() -> None:
+    df = pl.DataFrame({"g": [1, 1, 2], "a": [[1, 2], [3], [4, 5, 6]]})
+    result = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").list.agg(pl.element().sum())
+    )
+    expected = pl.DataFrame({"g": [1, 2], "a": [[3, 3], [15]]})
+    assert_frame_equal(result, expected)
+
+
+def test_list_agg_non_scalar() -> None:
+    df = pl.DataFrame({"a": [[1, 2]]})
+    with pytest.raises(InvalidOperationError, match="list.agg"):
+        df.select(pl.col("a").list.agg(pl.element() * 2))
@@ -698,6 +698,96 @@ def test_list_to_struct_all_null_12119() -> None:
     assert result == [{"a": None, "b": None, "c": None}]
 
 
+def test_list_to_struct_name_format() -> None:
+    df = pl.DataFrame({"n": [[0, 1, 2], [0, 1]]})
+
+    result = df.select(pl.col("n").list.to_struct(fields="n{}", _eager=True))
+    assert result.rows(named=True) == [
+        {"n": {"n0": 0, "n1": 1, "n2": 2}},
+        {"n": {"n0": 0, "n1": 1, "n2": None}},
+    ]
+
+    q = df.lazy().select(pl.col("n").list.to_struct(fields="x_{}", upper_bound=2))
+    assert q.collect_schema() == {"n": pl.Struct({"x_0": pl.Int64, "x_1": pl.Int64})}
+    assert q.collect().schema == q.collect_schema()
+
+    with pytest.raises(ValueError, match="must contain"):
+        pl.col("n").list.to_struct(fields="n")
+
+
+@pytest.mark.may_fail_auto_streaming
+def test_list_to_struct_infer_sample() -> None:
+    lf = pl.LazyFrame({"n": [[0, 1], [2, 3, 4], [5]]})
+
+    q = lf.select(pl.col("n").list.to_struct("max_width", infer_sample=2))
+    assert q.collect_schema() == {
+        "n": pl.Struct({"field_0": pl.Int64, "field_1": pl.Int64, "field_2": pl.Int64})
+    }
+    assert q.collect().schema == q.collect_schema()
+
+    # Lists beyond the sample are truncated to the inferred width.
+    q = lf.with_columns(pl.col("n").list.to_struct(fields="n{}", infer_sample=1))
+    assert q.collect_schema() == {"n": pl.Struct({"n0": pl.Int64, "n1": pl.Int64})}
+    assert q.collect().get_column("n").to_list() == [
+        {"n0": 0, "n1": 1},
+        {"n0": 2, "n1": 3},
+        {"n0": 5, "n1": None},
+    ]
+
+    q = lf.select(pl.col("n").list.to_struct(infer_sample=1, error_on_overflow=True))
+    with pytest.raises(InvalidOperationError, match="3 elements"):
+        q.collect()
+
+    q = lf.filter(
+        pl.col("n")
+        .list.to_struct("max_width", infer_sample=2)
+        .struct.field("field_2")
+        .is_null()
+    )
+    assert q.collect().get_column("n").to_list() == [[0, 1], [5]]
+
+    q = lf.group_by(pl.lit(0).alias("g")).agg(
+        pl.col("n").list.to_struct("max_width", infer_sample=2)
+    )
+    assert q.collect_schema()["n"] == pl.List(
+        pl.Struct({"field_0": pl.Int64, "field_1": pl.Int64, "field_2": pl.Int64})
+    )
+
+    # Errors while collecting the sample are raised.
+    q = lf.select(pl.col("n").list.get(2).implode().list.to_struct(infer_sample=1))
+    with pytest.raises(ComputeError, match="out of bounds"):
+        q.collect_schema()
+
+
+@pytest.mark.may_fail_auto_streaming
+def test_list_to_struct_error_on_overflow() -> None:
+    df = pl.DataFrame({"n": [[0, 1], [0, 1, 2]]})
+
+    q = df.lazy().select(
+        pl.col("n").list.to_struct(upper_bound=2, error_on_overflow=True)
+    )
+    with pytest.raises(InvalidOperationError, match="3 elements"):
+        q.collect()
+
+    with pytest.raises(InvalidOperationError, match="2 fields"):
+        df.select(
+            pl.col("n").list.to_struct(fields=["a", "b"], error_on_overflow=True)
+        )
+
+    with pytest.raises(InvalidOperationError):
+        df.get_column("n").list.to_struct(error_on_overflow=True)
+
+    result = df.select(
+        pl.col("n").list.to_struct(
+            "max_width", upper_bound=3, error_on_overflow=True, _eager=True
+        )
+    )
+    assert result.rows(named=True) == [
+        {"n": {"field_0": 0, "field_1": 1, "field_2": None}},
+        {"n": {"field_0": 0, "field_1": 1, "field_2": 2}},
+    ]
+
+
 def test_select_from_list_to_struct_11143() -> None:
     ldf = pl.LazyFrame({"some_col": [[1.0, 2.0], [1.5, 3.0]]})
     ldf = ldf.select(
@@ -1112,6 +1202,40 @@ def test_list_filter_result_empty() -> None:
     ]
 
 
+def test_list_filter_mask() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2, 3], [], None, [4, 5], [6, 7]],
+            "mask": [[True, False, True], [], [True], None, [None, True]],
+        }
+    )
+    out = df.select(pl.col("a").list.filter(pl.col("mask")))
+    expected = pl.DataFrame({"a": [[1, 3], [], None, None, [7]]})
+    assert_frame_equal(out, expected)
+
+    s = pl.Series("a", [[1.0, 2.0], [3.0]]).list.filter(
+        pl.Series([[False, True], [True]])
+    )
+    assert_series_equal(s, pl.Series("a", [[2.0], [3.0]]))
+
+
+def test_list_filter_mask_broadcast() -> None:
+    s = pl.Series("a", [["x", "y"], ["z", "w"]])
+    assert s.list.filter(pl.Series([[False, True]])).to_list() == [["y"], ["w"]]
+
+
+def test_list_filter_mask_length_mismatch() -> None:
+    df = pl.DataFrame({"a": [[1, 2], [3]], "mask": [[True, False], [True, False]]})
+    with pytest.raises(pl.exceptions.ShapeError):
+        df.select(pl.col("a").list.filter(pl.col("mask")))
+
+
+def test_list_filter_mask_non_boolean() -> None:
+    df = pl.DataFrame({"a": [[1, 2]], "mask": [[1, 0]]})
+    with pytest.raises(pl.exceptions.ComputeError, match="list of booleans"):
+        df.select(pl.col("a").list.filter(pl.col("mask")))
+
+
 def test_list_filter_null() -> None:
     assert pl.Series(
         [
@@ -0,0 +1,104 @@
+from __future__ import annotations
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def test_str_parse_common_log() -> None:
+    lines = [
+        '127.0.0.1 - frank [10/Oct/2000:13:55:36 -0700] "GET /a.gif HTTP/1.0" 200 2326',
+        '::1 - - [11/Oct/2000:08:00:00 +0000] "POST /login HTTP/1.1" 302 - '
+        '"https://pola.rs/" "curl/8.0"',
+        "not a log line",
+        None,
+    ]
+    out = (
+        pl.Series("log", lines)
+        .to_frame()
+        .select(pl.col("log").str.parse_common_log())
+        .unnest("log")
+    )
+    expected = pl.DataFrame(
+        {
+            "host": ["127.0.0.1", "::1", None, None],
+            "ident": [None, None, None, None],
+            "user": ["frank", None, None, None],
+            "time": [
+                "10/Oct/2000:13:55:36 -0700",
+                "11/Oct/2000:08:00:00 +0000",
+                None,
+                None,
+            ],
+            "method": ["GET", "POST", None, None],
+            "path": ["/a.gif", "/login", None, None],
+            "protocol": ["HTTP/1.0", "HTTP/1.1", None, None],
+            "status": [200, 302, None, None],
+            "size": [2326, None, None, None],
+            "referer": [None, "https://pola.rs/", None, None],
+            "user_agent": [None, "curl/8.0", None, None],
+        },
+        schema={
+            "host": pl.String,
+            "ident": pl.String,
+            "user": pl.String,
+            "time": pl.String,
+            "method": pl.String,
+            "path": pl.String,
+            "protocol": pl.String,
+            "status": pl.UInt16,
+            "size": pl.UInt64,
+            "referer": pl.String,
+            "user_agent": pl.String,
+        },
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_str_parse_common_log_outer_nulls() -> None:
+    s = pl.Series(["not a log line", None])
+    assert s.str.parse_common_log().is_null().to_list() == [True, True]
+
+
+def test_str_parse_user_agent() -> None:
+    agents = [
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 "
+        "(KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36",
+        "Mozilla/5.0 (Macintosh; Intel Mac OS X 14_5) AppleWebKit/605.1.15 "
+        "(KHTML, like Gecko) Version/17.5 Safari/605.1.15",
+        "Mozilla/5.0 (iPad; CPU OS 17_5 like Mac OS X) AppleWebKit/605.1.15 "
+        "(KHTML, like Gecko) CriOS/126.0.6478.54 Mobile/15E148 Safari/604.1",
+        "Mozilla/5.0 (compatible; bingbot/2.0; +http://www.bing.com/bingbot.htm)",
+        "curl/8.0",
+        None,
+    ]
+    out = (
+        pl.Series("ua", agents)
+        .to_frame()
+        .select(pl.col("ua").str.parse_user_agent())
+        .unnest("ua")
+    )
+    expected = pl.DataFrame(
+        {
+            "browser": ["Chrome", "Safari", "Chrome", None, None, None],
+            "browser_version": ["126.0.0.0", "17.5", "126.0.6478.54", None, None, None],
+            "os": ["Windows", "macOS", "iOS", None, None, None],
+            "device": ["desktop", "desktop", "tablet", "bot", "desktop", None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_str_parse_user_agent_schema() -> None:
+    lf = pl.LazyFrame({"ua": ["curl/8.0"]})
+    assert lf.select(pl.col("ua").str.parse_user_agent()).collect_schema() == pl.Schema(
+        {
+            "ua": pl.Struct(
+                {
+                    "browser": pl.String,
+                    "browser_version": pl.String,
+                    "os": pl.String,
+                    "device": pl.String,
+                }
+            )
+        }
+    )
@@ -3,7 +3,7 @@ from __future__ import annotations
 import pytest
 
 import polars as pl
-from polars.exceptions import ShapeError
+from polars.exceptions import InvalidOperationError, ShapeError
 from polars.testing import assert_frame_equal
 
 
@@ -178,3 +178,56 @@ def test_str_zfill_unicode_not_respected() -> None:
 
     expected = pl.LazyFrame({"a": ["0Café", "000345", "東京", None]})
     assert_frame_equal(result, expected)
+
+
+def test_str_pad_display() -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["Café", "東京", "e\u0301", "ｆｕｌｌ", "", None],
+            "width": [6, 6, 3, 4, 2, 6],
+        }
+    )
+
+    result = df.select(
+        start=pl.col("a").str.pad_start_display(6, "."),
+        end=pl.col("a").str.pad_end_display(pl.col("width"), "."),
+    )
+    expected = pl.DataFrame(
+        {
+            "start": ["..Café", "..東京", ".....e\u0301", "ｆｕｌｌ", "......", None],
+            "end": ["Café..", "東京..", "e\u0301..", "ｆｕｌｌ", "..", None],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_str_pad_display_truncate() -> None:
+    s = pl.Series(["Café au lait", "東京都", "abc", None])
+
+    assert s.str.pad_end_display(5, truncate=True).to_list() == [
+        "Café ",
+        "東京 ",
+        "abc  ",
+        None,
+    ]
+    assert s.str.pad_start_display(5, "-", truncate=True).to_list() == [
+        "Café ",
+        "-東京",
+        "--abc",
+        None,
+    ]
+    # Strings that fit are not truncated.
+    assert s.str.pad_end_display(20, truncate=True).str.len_chars().to_list() == [
+        20,
+        17,
+        20,
+        None,
+    ]
+
+
+def test_str_pad_display_invalid_fill_char() -> None:
+    s = pl.Series(["a"])
+    with pytest.raises(InvalidOperationError, match="one column wide"):
+        s.str.pad_end_display(4, "日")
+    with pytest.raises(TypeError, match="expects a `str`"):
+        s.str.pad_end_display(4, 1)  # type: ignore[arg-type]
@@ -1776,6 +1776,73 @@ def test_split_exact_expr() -> None:
     assert_frame_equal(out2, expected2)
 
 
+def test_split_exact_rest() -> None:
+    df = pl.DataFrame({"x": ["a_b_c_d", None, "b", "c_c", ""]})
+
+    out = df.select(pl.col("x").str.split_exact("_", 1, rest=True)).unnest("x")
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a", None, "b", "c", ""],
+            "rest": ["b_c_d", None, None, "c", None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("x").str.split_exact("_", 2, rest=True)).unnest("x")
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a", None, "b", "c", ""],
+            "field_1": ["b", None, None, "c", None],
+            "rest": ["c_d", None, None, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(
+        pl.col("x").str.split_exact("_", 1, inclusive=True, rest=True)
+    ).unnest("x")
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a_", None, "b", "c_", None],
+            "rest": ["b_c_d", None, None, "c", None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.select(pl.col("x").str.split_exact("", 1, rest=True)).unnest("x")
+    expected = pl.DataFrame(
+        {
+            "field_0": ["a", None, "b", "c", None],
+            "rest": ["_b_c_d", None, None, "_c", None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    schema = df.lazy().select(pl.col("x").str.split_exact("_", 1, rest=True)).schema
+    assert schema["x"] == pl.Struct({"field_0": pl.String, "rest": pl.String})
+
+
+def test_split_exact_infer_n() -> None:
+    s = pl.Series("x", ["a,b", "c,d,e", None])
+
+    out = s.str.split_exact(",", None)
+    assert out.struct.fields == ["field_0", "field_1", "field_2"]
+    assert_series_equal(out, s.str.split_exact(",", 2))
+
+    out = s.str.split_exact(",", None, rest=True, infer_schema_length=1)
+    assert out.to_list() == [
+        {"field_0": "a", "rest": "b"},
+        {"field_0": "c", "rest": "d,e"},
+        {"field_0": None, "rest": None},
+    ]
+
+    out = s.str.split_exact("", None)
+    assert out.struct.fields == [f"field_{i}" for i in range(5)]
+
+    with pytest.raises(TypeError, match="`n` can only be inferred"):
+        s.str.split_exact(pl.lit(","), None)
+
+
 def test_splitn() -> None:
     df = pl.DataFrame({"x": ["a_a", None, "b", "c_c_c", ""]})
     out = df.select([pl.col("x").str.splitn("_", 2)]).unnest("x")
@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+from datetime import date, datetime, time
+
+import pytest
+
+import polars as pl
+from polars.exceptions import ComputeError, InvalidOperationError
+from polars.testing import assert_series_equal
+
+
+def test_convert_time_zone_per_row() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [
+                datetime(2024, 1, 1, 12),
+                datetime(2024, 7, 1, 12),
+                datetime(2024, 1, 1),
+                None,
+                datetime(2024, 1, 1),
+            ],
+            "tz": ["Europe/Amsterdam", "Europe/Amsterdam", "Asia/Tokyo", "UTC", None],
+        },
+        schema_overrides={"ts": pl.Datetime("ms", "UTC")},
+    )
+    lf = df.lazy().select(pl.col("ts").dt.convert_time_zone(pl.col("tz")))
+    assert lf.collect_schema() == pl.Schema({"ts": pl.Datetime("ms")})
+
+    expected = pl.Series(
+        "ts",
+        [
+            datetime(2024, 1, 1, 13),
+            datetime(2024, 7, 1, 14),
+            datetime(2024, 1, 1, 9),
+            None,
+            None,
+        ],
+        dtype=pl.Datetime("ms"),
+    )
+    assert_series_equal(lf.collect().to_series(), expected)
+
+
+def test_convert_time_zone_per_row_series() -> None:
+    s = pl.Series([datetime(2024, 3, 1), datetime(2024, 3, 1)])
+    tz = pl.Series(["America/New_York", "Australia/Sydney"])
+    out = s.dt.convert_time_zone(tz)
+    assert out.to_list() == [datetime(2024, 2, 29, 19), datetime(2024, 3, 1, 11)]
+
+
+def test_combine_per_row_time_zone() -> None:
+    df = pl.DataFrame(
+        {
+            "d": [date(2024, 1, 1), date(2024, 7, 1), date(2024, 7, 1)],
+            "t": [time(9), time(9), None],
+            "tz": ["Europe/Amsterdam", "America/New_York", "UTC"],
+        }
+    )
+    lf = df.lazy().select(
+        pl.col("d").dt.combine(pl.col("t"), "ms", time_zone=pl.col("tz"))
+    )
+    assert lf.collect_schema() == pl.Schema({"d": pl.Datetime("ms", "UTC")})
+
+    expected = pl.Series(
+        "d",
+        [datetime(2024, 1, 1, 8), datetime(2024, 7, 1, 13), None],
+        dtype=pl.Datetime("ms"),
+    ).dt.replace_time_zone("UTC")
+    assert_series_equal(lf.collect().to_series(), expected)
+
+
+def test_combine_single_time_zone() -> None:
+    s = pl.Series("d", [datetime(2024, 1, 1), datetime(2024, 7, 1)])
+    out = s.dt.combine(time(9), time_zone="Asia/Tokyo")
+    expected = pl.Series(
+        "d", [datetime(2024, 1, 1), datetime(2024, 7, 1)]
+    ).dt.replace_time_zone("UTC")
+    assert_series_equal(out, expected)
+
+
+def test_time_zone_column_errors() -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [datetime(2024, 3, 31, 2, 30)],
+            "tz": ["Europe/Amsterdam"],
+            "bad": ["Mars/Olympus_Mons"],
+        }
+    )
+    with pytest.raises(ComputeError, match="non-existent"):
+        df.select(pl.col("ts").dt.combine(time(2, 30), time_zone=pl.col("tz")))
+    with pytest.raises(ComputeError, match="unable to parse time zone"):
+        df.select(pl.col("ts").dt.convert_time_zone(pl.col("bad")))
+
+    aware = df.with_columns(pl.col("ts").dt.replace_time_zone("UTC"))
+    with pytest.raises(InvalidOperationError, match="cannot combine datetimes"):
+        aware.select(pl.col("ts").dt.combine(time(1), time_zone=pl.col("tz")))
@@ -0,0 +1,130 @@
+from __future__ import annotations
+
+import io
+from datetime import date, datetime
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError, OutOfBoundsError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_hash_stable() -> None:
+    s = pl.Series("a", ["x", "y", "x", None])
+    result = s.to_frame().select(pl.col("a").anon.hash_stable("salt")).to_series()
+
+    assert result.dtype == pl.UInt64
+    assert result[0] == result[2]
+    assert result[0] != result[1]
+    assert result[3] is None
+
+
+def test_hash_stable_salt_rotation() -> None:
+    df = pl.DataFrame({"a": ["x", "x", "x"], "salt_id": [0, 1, None]})
+    result = df.select(
+        old=pl.col("a").anon.hash_stable(["s0", "s1"]),
+        new=pl.col("a").anon.hash_stable(["s0", "s1"], salt_id=1),
+        per_row=pl.col("a").anon.hash_stable(["s0", "s1"], "salt_id"),
+    )
+
+    assert result["old"][0] != result["new"][0]
+    assert result["per_row"].to_list() == [
+        result["old"][0],
+        result["new"][0],
+        None,
+    ]
+
+
+def test_hash_stable_non_string() -> None:
+    df = pl.DataFrame(
+        {
+            "int": [12, 12],
+            "str": ["12", "12"],
+            "cat": pl.Series(["12", "12"], dtype=pl.Categorical),
+            "date": [date(2024, 1, 1)] * 2,
+            "bool": [True, True],
+        }
+    )
+    result = df.select(pl.all().anon.hash_stable("salt"))
+    assert result.select(pl.all().n_unique()).row(0) == (1, 1, 1, 1, 1)
+    assert result["int"][0] != result["str"][0]
+    assert result["cat"][0] == result["str"][0]
+    # Values are hashed by their physical bytes, not by their string representation.
+    assert (
+        result["date"][0]
+        == df.select(pl.col("date").to_physical().anon.hash_stable("salt")).item(0, 0)
+    )
+    assert result["date"][0] != df.select(
+        pl.col("date").cast(pl.String).anon.hash_stable("salt")
+    ).item(0, 0)
+
+
+def test_hash_stable_salts_redacted() -> None:
+    expr = pl.col("a").anon.hash_stable(["secret-salt"])
+    lf = pl.LazyFrame({"a": ["x"]})
+    assert "secret-salt" not in lf.select(expr).explain()
+    assert "secret-salt" not in repr(expr)
+
+    serialized = expr.meta.serialize()
+    assert b"secret-salt" not in serialized
+    with pytest.raises(InvalidOperationError, match="at least one salt"):
+        lf.select(pl.Expr.deserialize(io.BytesIO(serialized))).collect()
+
+
+def test_hash_stable_salt_id_oob() -> None:
+    df = pl.DataFrame({"a": ["x"]})
+    with pytest.raises(OutOfBoundsError):
+        df.select(pl.col("a").anon.hash_stable(["s0"], salt_id=1))
+
+
+def test_suppress_rare() -> None:
+    df = pl.DataFrame({"a": [1, 2, 1, 3, 1, 2], "g": [0, 0, 0, 1, 1, 1]})
+    result = df.select(pl.col("a").anon.suppress_rare(2))
+    expected = pl.DataFrame({"a": [1, 2, 1, None, 1, 2]})
+    assert_frame_equal(result, expected)
+
+    result = df.select(pl.col("a").anon.suppress_rare(2).over("g"))
+    expected = pl.DataFrame({"a": [1, None, 1, None, None, None]})
+    assert_frame_equal(result, expected)
+
+
+def test_generalize_date() -> None:
+    s = pl.Series("a", [datetime(2024, 5, 17, 13, 45), datetime(2023, 11, 2, 8, 5)])
+    df = s.to_frame()
+
+    assert_series_equal(
+        df.select(pl.col("a").anon.generalize_date("month")).to_series(),
+        pl.Series("a", [datetime(2024, 5, 1), datetime(2023, 11, 1)]),
+    )
+    assert_series_equal(
+        df.select(pl.col("a").anon.generalize_date("year")).to_series(),
+        pl.Series("a", [datetime(2024, 1, 1), datetime(2023, 1, 1)]),
+    )
+    assert_series_equal(
+        df.select(pl.col("a").anon.generalize_date("hour")).to_series(),
+        pl.Series("a", [datetime(2024, 5, 17, 13), datetime(2023, 11, 2, 8)]),
+    )
+    result = df.select(pl.col("a").cast(pl.Date).anon.generalize_date("quarter"))
+    assert_series_equal(
+        result.to_series(),
+        pl.Series("a", [date(2024, 4, 1), date(2023, 10, 1)]),
+    )
+
+    with pytest.raises(InvalidOperationError, match="level"):
+        pl.col("a").anon.generalize_date("decade")  # type: ignore[arg-type]
+
+
+def test_k_anonymity() -> None:
+    df = pl.DataFrame(
+        {
+            "zip": ["1011", "1011", "1011", "2022", "2022"],
+            "gender": ["f", "f", "m", "m", "m"],
+        }
+    )
+    assert df.k_anonymity(["zip", "gender"]) == 1
+    assert df.k_anonymity("zip") == 2
+
+    suppressed = df.with_columns(pl.col("gender").anon.suppress_rare(3))
+    assert suppressed.k_anonymity("gender") == 2
+    assert df.clear().k_anonymity("zip") == 0
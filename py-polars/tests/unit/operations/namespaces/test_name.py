@@ -44,6 +44,35 @@ def test_name_prefix_suffix() -> None:
     )
 
 
+
+def test_name_format() -> None:
+    df = pl.DataFrame(
+        schema={"ColX": pl.Int32, "ColY": pl.String},
+    ).with_columns(pl.all().name.format("{}_{}_x"))
+    assert df.columns == ["ColX", "ColY", "ColX_ColX_x", "ColY_ColY_x"]
+
+
+def test_name_replace() -> None:
+    df = pl.DataFrame(
+        schema={"col_1": pl.Int32, "col_2": pl.String, "x.y": pl.Int8},
+    )
+    assert df.select(pl.all().name.replace(r"_(\d)", "$1")).columns == [
+        "col1",
+        "col2",
+        "x.y",
+    ]
+    assert df.select(pl.all().name.replace(".", "_", literal=True)).columns == [
+        "col_1",
+        "col_2",
+        "x_y",
+    ]
+    assert df.select(pl.all().name.replace(r"[a-z]", "")).columns == [
+        "_1",
+        "_2",
+        ".",
+    ]
+
+
 def test_name_update_all() -> None:
     df = pl.DataFrame(
         schema={
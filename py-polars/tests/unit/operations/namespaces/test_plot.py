@@ -1,6 +1,8 @@
 import altair as alt
+import pytest
 
 import polars as pl
+from polars.testing import assert_frame_equal
 
 
 def test_dataframe_plot() -> None:
@@ -75,3 +77,35 @@ def test_errorbar_19787() -> None:
     assert "tooltip" not in result["encoding"]
     result = df["A"].plot.errorbar().to_dict()
     assert "tooltip" not in result["encoding"]
+
+
+def test_dataframe_plot_downsample_lttb() -> None:
+    df = pl.DataFrame(
+        {
+            "x": [0, 1, 2, 3, 4, 5, 6, 7],
+            "y": [0.0, 1.0, None, 0.0, 8.0, 0.0, 2.0, 1.0],
+            "label": list("abcdefgh"),
+        }
+    )
+    result = df.plot.downsample_lttb("x", "y", 4)
+    expected = pl.DataFrame(
+        {"x": [0, 3, 4, 7], "y": [0.0, 0.0, 8.0, 1.0], "label": list("adeh")}
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.plot.downsample_lttb("x", "y", 2)
+    assert_frame_equal(result, df[[0, 7]])
+    assert_frame_equal(df.plot.downsample_lttb("x", "y", 10), df.drop_nulls())
+
+
+def test_dataframe_plot_bin2d() -> None:
+    df = pl.DataFrame({"x": [0.0, 1.0, 1.0, 4.0, None], "y": [0, 0, 1, 2, 0]})
+    result = df.plot.bin2d("x", "y", bins=(2, 1))
+    expected = pl.DataFrame(
+        {"x": [1.0, 3.0], "y": [1.0, 1.0], "count": [3, 1]},
+        schema_overrides={"count": pl.get_index_type()},
+    )
+    assert_frame_equal(result, expected)
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="positive"):
+        df.plot.bin2d("x", "y", bins=0)
@@ -69,6 +69,25 @@ def test_struct_json_encode_logical_type() -> None:
     }
 
 
+def test_struct_json_encode_escaped_keys() -> None:
+    s = pl.Series([{'a"b': 1, "c\\d": None}])
+    assert s.struct.json_encode().to_list() == ['{"a\\"b":1,"c\\\\d":null}']
+
+
+def test_struct_json_encode_sliced_nested_nulls() -> None:
+    s = pl.Series(
+        [
+            {"l": [{"x": 1}, None]},
+            {"l": [None, {"x": 2}]},
+            {"l": None},
+        ]
+    )
+    assert s.slice(1).struct.json_encode().to_list() == [
+        '{"l":[null,{"x":2}]}',
+        '{"l":null}',
+    ]
+
+
 def test_map_fields() -> None:
     df = pl.DataFrame({"x": {"a": 1, "b": 2}})
     assert df.schema == OrderedDict([("x", pl.Struct({"a": pl.Int64, "b": pl.Int64}))])
@@ -0,0 +1,95 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_url_parse() -> None:
+    df = pl.DataFrame(
+        {
+            "url": [
+                "https://user:pw@pola.rs:8080/docs/api?x=1&y=2#frag",
+                "/relative/path?q",
+                "mailto:someone@example.com",
+                "http://[::1]/",
+                None,
+            ]
+        }
+    )
+    out = df.select(pl.col("url").url.parse()).unnest("url")
+    expected = pl.DataFrame(
+        {
+            "scheme": ["https", None, "mailto", "http", None],
+            "host": ["pola.rs", None, None, "[::1]", None],
+            "path": ["/docs/api", "/relative/path", "someone@example.com", "/", None],
+            "query": ["x=1&y=2", "q", None, None, None],
+            "fragment": ["frag", None, None, None, None],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_url_parse_schema() -> None:
+    lf = pl.LazyFrame({"url": ["https://pola.rs"]})
+    assert lf.select(pl.col("url").url.parse()).collect_schema() == pl.Schema(
+        {
+            "url": pl.Struct(
+                {
+                    "scheme": pl.String,
+                    "host": pl.String,
+                    "path": pl.String,
+                    "query": pl.String,
+                    "fragment": pl.String,
+                }
+            )
+        }
+    )
+
+
+def test_url_query_param() -> None:
+    s = pl.Series(
+        "url",
+        [
+            "https://pola.rs/?a=1&b=hello+world&a=2",
+            "https://pola.rs/?c=%C3%A9",
+            "https://pola.rs/",
+            None,
+        ],
+    )
+    df = s.to_frame().select(
+        a=pl.col("url").url.query_param("a"),
+        b=pl.col("url").url.query_param("b"),
+        c=pl.col("url").url.query_param("c"),
+    )
+    assert df.to_dict(as_series=False) == {
+        "a": ["1", None, None, None],
+        "b": ["hello world", None, None, None],
+        "c": [None, "é", None, None],
+    }
+
+
+def test_url_query_param_invalid_key() -> None:
+    with pytest.raises(TypeError, match="expects a `str`"):
+        pl.col("url").url.query_param(1)  # type: ignore[arg-type]
+
+
+def test_url_encode_decode_roundtrip() -> None:
+    s = pl.Series("s", ["a b&c=d", "café/ü", "-_.~", "", None])
+    encoded = s.to_frame().select(pl.col("s").url.encode()).to_series()
+    assert encoded.to_list() == [
+        "a%20b%26c%3Dd",
+        "caf%C3%A9%2F%C3%BC",
+        "-_.~",
+        "",
+        None,
+    ]
+
+    decoded = encoded.to_frame().select(pl.col("s").url.decode()).to_series()
+    assert_series_equal(decoded, s)
+
+
+def test_url_decode_invalid_utf8() -> None:
+    df = pl.DataFrame({"s": ["%FF", "ok%21"]})
+    assert df.select(pl.col("s").url.decode()).to_series().to_list() == [None, "ok!"]
@@ -9,6 +9,9 @@ import polars as pl
 from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import INTEGER_DTYPES
 
+if typing.TYPE_CHECKING:
+    from polars._typing import EngineType
+
 
 @pytest.mark.parametrize("op", ["and_", "or_"])
 def test_bitwise_integral_schema(op: str) -> None:
@@ -256,6 +259,58 @@ def test_bit_group_by(dtype: pl.DataType) -> None:
     )
 
 
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_bit_group_by_filtered(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 1, 1, 2, 2],
+            "flags": [0b0001, 0b0110, 0b1000, 0b0011, 0b0101],
+            "keep": [True, True, False, True, True],
+        }
+    )
+    out = (
+        lf.group_by("g")
+        .agg(
+            AND=pl.col.flags.filter("keep").bitwise_and(),
+            OR=pl.col.flags.filter("keep").bitwise_or(),
+            XOR=pl.col.flags.bitwise_xor(),
+        )
+        .sort("g")
+        .collect(engine=engine)
+    )
+    expected = pl.DataFrame(
+        {
+            "g": [1, 2],
+            "AND": [0b0000, 0b0001],
+            "OR": [0b0111, 0b0111],
+            "XOR": [0b1111, 0b0110],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_bit_aggregations_over() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "b", "a"],
+            "flags": [0b001, 0b100, 0b010, None, 0b011],
+        }
+    )
+    out = df.select(
+        AND=pl.col.flags.bitwise_and().over("g"),
+        OR=pl.col.flags.bitwise_or().over("g"),
+        XOR=pl.col.flags.bitwise_xor().over("g"),
+    )
+    expected = pl.DataFrame(
+        {
+            "AND": [0b000, 0b100, 0b000, 0b100, 0b000],
+            "OR": [0b011, 0b100, 0b011, 0b100, 0b011],
+            "XOR": [0b000, 0b100, 0b000, 0b100, 0b000],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
 def test_scalar_bitwise_xor() -> None:
     df = pl.select(
         pl.repeat(pl.lit(0x80, pl.UInt8), i).bitwise_xor().alias(f"l{i}")
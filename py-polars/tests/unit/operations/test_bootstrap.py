@@ -0,0 +1,105 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+BOOTSTRAP_DTYPE = pl.Struct(
+    {"mean": pl.Float64, "std": pl.Float64, "lower": pl.Float64, "upper": pl.Float64}
+)
+
+
+def test_bootstrap_constant() -> None:
+    df = pl.DataFrame({"a": [3, 3, 3, 3]})
+    result = df.select(pl.col("a").bootstrap(50, seed=1)).unnest("a")
+    expected = pl.DataFrame(
+        {"mean": [3.0], "std": [0.0], "lower": [3.0], "upper": [3.0]}
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_bootstrap_schema() -> None:
+    lf = pl.LazyFrame({"a": [1.0, 2.0, 3.0]})
+    q = lf.select(pl.col("a").bootstrap(10, "median", seed=0))
+    assert q.collect_schema() == pl.Schema({"a": BOOTSTRAP_DTYPE})
+    assert q.collect().schema == q.collect_schema()
+
+
+@pytest.mark.parametrize("statistic", ["mean", "median", "sum", "min", "max"])
+def test_bootstrap_bounds(statistic: str) -> None:
+    values = [1.0, 4.0, 2.0, 8.0, 5.0, 7.0]
+    df = pl.DataFrame({"a": values})
+    result = df.select(
+        pl.col("a").bootstrap(200, statistic, seed=0)  # type: ignore[arg-type]
+    ).unnest("a")
+    row = result.row(0, named=True)
+
+    lo, hi = min(values), max(values)
+    if statistic == "sum":
+        lo, hi = lo * len(values), hi * len(values)
+    assert lo <= row["lower"] <= row["mean"] <= row["upper"] <= hi
+
+
+def test_bootstrap_seed_deterministic() -> None:
+    df = pl.DataFrame({"a": [1.0, 4.0, 2.0, 8.0, 5.0, 7.0, 3.0]})
+    expr = pl.col("a").bootstrap(100, seed=42)
+    assert_frame_equal(df.select(expr), df.select(expr))
+
+
+def test_bootstrap_group_by() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["x", "x", "x", "y", "y", "y"],
+            "a": [1, 1, 1, 5, 5, None],
+        }
+    )
+    result = (
+        df.group_by("g", maintain_order=True)
+        .agg(pl.col("a").bootstrap(20, seed=0))
+        .unnest("a")
+    )
+    expected = pl.DataFrame(
+        {
+            "g": ["x", "y"],
+            "mean": [1.0, 5.0],
+            "std": [0.0, 0.0],
+            "lower": [1.0, 5.0],
+            "upper": [1.0, 5.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_bootstrap_weighted() -> None:
+    df = pl.DataFrame({"a": [1.0, 100.0], "w": [1.0, 0.0]})
+    result = df.select(pl.col("a").bootstrap(20, weights="w", seed=0)).unnest("a")
+    assert result.row(0) == (1.0, 0.0, 1.0, 1.0)
+
+
+def test_bootstrap_block() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0, 3.0, 4.0]})
+    # A single block covering all values reproduces the input every time.
+    result = df.select(
+        pl.col("a").bootstrap(10, "sum", method="block", block_size=4, seed=0)
+    ).unnest("a")
+    assert result.row(0) == (10.0, 0.0, 10.0, 10.0)
+
+
+def test_bootstrap_empty() -> None:
+    df = pl.DataFrame({"a": [None, None]}, schema={"a": pl.Float64})
+    result = df.select(pl.col("a").bootstrap(10, seed=0)).unnest("a")
+    assert result.row(0) == (None, None, None, None)
+
+
+def test_bootstrap_invalid() -> None:
+    df = pl.DataFrame({"a": [1.0, 2.0], "w": [1.0, 1.0]})
+    with pytest.raises(InvalidOperationError, match="confidence_level"):
+        df.select(pl.col("a").bootstrap(10, confidence_level=1.5))
+    with pytest.raises(InvalidOperationError, match="weights"):
+        df.select(pl.col("a").bootstrap(10, method="block", block_size=1, weights="w"))
+    with pytest.raises(ValueError, match="block_size"):
+        pl.col("a").bootstrap(10, method="block")
+    with pytest.raises(ValueError, match="statistic"):
+        pl.col("a").bootstrap(10, "mode")  # type: ignore[arg-type]
@@ -0,0 +1,101 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "city": ["NY", "SF", "NY", "LA", "NY", "SF", None],
+            "y": [1, 0, 1, 0, 0, 1, 1],
+        }
+    )
+
+
+def test_frequency_encode(df: pl.DataFrame) -> None:
+    result = df.select(
+        freq=pl.col("city").frequency_encode(),
+        frac=pl.col("city").frequency_encode(normalize=True),
+    )
+    expected = pl.DataFrame(
+        {
+            "freq": pl.Series([3, 2, 3, 1, 3, 2, 1], dtype=pl.UInt32),
+            "frac": [3 / 7, 2 / 7, 3 / 7, 1 / 7, 3 / 7, 2 / 7, 1 / 7],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_frequency_encode_group_by(df: pl.DataFrame) -> None:
+    result = df.select(pl.col("city").frequency_encode().over("y"))
+    expected = pl.DataFrame({"city": pl.Series([2, 1, 2, 1, 1, 1, 1], dtype=pl.UInt32)})
+    assert_frame_equal(result, expected)
+
+
+def test_categorical_target_encode_single_fold(df: pl.DataFrame) -> None:
+    result = df.select(
+        pl.col("city").categorical_target_encode("y", folds=1, smoothing=1.0)
+    )
+    prior = 4 / 7
+    ny = (2 + prior) / 4
+    sf = (1 + prior) / 3
+    la = prior / 2
+    null = (1 + prior) / 2
+    expected = pl.DataFrame({"city": [ny, sf, ny, la, ny, sf, null]})
+    assert_frame_equal(result, expected)
+
+    result = df.select(
+        pl.col("city").categorical_target_encode("y", folds=1, smoothing=0.0)
+    )
+    expected = pl.DataFrame({"city": [2 / 3, 0.5, 2 / 3, 0.0, 2 / 3, 0.5, 1.0]})
+    assert_frame_equal(result, expected)
+
+
+def test_categorical_target_encode_folds_no_leakage() -> None:
+    n = 1_000
+    city = pl.Series("city", ["a", "b", "c", "d"] * (n // 4))
+    y = pl.Series("y", [i % 3 == 0 for i in range(n)])
+    encoded = city.categorical_target_encode(y, folds=5, seed=42)
+
+    # The target of a row must not influence its own encoding.
+    flipped = y.clone().scatter(0, not y[0])
+    encoded_flipped = city.categorical_target_encode(flipped, folds=5, seed=42)
+    assert encoded[0] == encoded_flipped[0]
+    assert not encoded.equals(encoded_flipped)
+
+    # Deterministic for the same seed.
+    assert_series_equal(encoded, city.categorical_target_encode(y, folds=5, seed=42))
+    assert not encoded.equals(city.categorical_target_encode(y, folds=5, seed=0))
+
+
+def test_categorical_target_encode_null_target() -> None:
+    s = pl.Series("a", ["x", "x", "y"])
+    result = s.categorical_target_encode(pl.Series([1.0, None, None]), folds=1)
+    assert_series_equal(result, pl.Series("a", [1.0, 1.0, 1.0]))
+
+    result = s.categorical_target_encode(
+        pl.Series([None, None, None], dtype=pl.Float64), folds=1
+    )
+    assert_series_equal(result, pl.Series("a", [None, None, None], dtype=pl.Float64))
+
+
+def test_categorical_target_encode_lazy_schema(df: pl.DataFrame) -> None:
+    lf = df.lazy().select(
+        pl.col("city").categorical_target_encode("y"),
+        freq=pl.col("city").frequency_encode(),
+    )
+    assert lf.collect_schema() == lf.collect().schema
+
+
+def test_categorical_target_encode_invalid(df: pl.DataFrame) -> None:
+    with pytest.raises(InvalidOperationError, match="target"):
+        df.select(pl.col("city").categorical_target_encode("city"))
+    with pytest.raises(InvalidOperationError, match="folds"):
+        df.select(pl.col("city").categorical_target_encode("y", folds=0))
+    with pytest.raises(InvalidOperationError, match="smoothing"):
+        df.select(pl.col("city").categorical_target_encode("y", smoothing=-1.0))
@@ -1,5 +1,8 @@
 from __future__ import annotations
 
+from datetime import date, datetime, timedelta
+from decimal import Decimal as D
+
 import pytest
 
 import polars as pl
@@ -128,3 +131,95 @@ def test_cut_fast_unique_15981(
     assert_series_equal(s_cut.cast(pl.String), expected_labels)
     assert s_cut.n_unique() == s_cut.to_physical().n_unique() == expected_unique
     s_cut.to_frame().group_by(s.name).len()
+
+
+def test_cut_date() -> None:
+    s = pl.Series("a", [date(2024, 1, 1), date(2024, 2, 15), None, date(2024, 4, 1)])
+
+    result = s.cut([date(2024, 1, 31), date(2024, 3, 31)], include_breaks=True)
+
+    expected = pl.DataFrame(
+        {
+            "breakpoint": [date(2024, 1, 31), date(2024, 3, 31), None, None],
+            "category": [
+                "(-inf, 2024-01-31]",
+                "(2024-01-31, 2024-03-31]",
+                None,
+                "(2024-03-31, inf]",
+            ],
+        },
+        schema_overrides={"category": pl.Categorical},
+    ).to_struct("a")
+    assert_series_equal(result, expected, categorical_as_str=True)
+
+
+def test_cut_datetime_ns() -> None:
+    # These differ by less than the precision of a Float64.
+    t = datetime(2024, 1, 1)
+    s = pl.Series("a", [t, t], dtype=pl.Datetime("ns")) + pl.Series(
+        [0, 1], dtype=pl.Duration("ns")
+    )
+
+    result = s.cut([t], labels=["before", "after"], left_closed=True)
+    assert result.cast(pl.String).to_list() == ["after", "after"]
+
+    result = s.cut([t], labels=["before", "after"])
+    assert result.cast(pl.String).to_list() == ["before", "after"]
+
+
+def test_cut_datetime_finer_breaks() -> None:
+    t = datetime(2024, 1, 1)
+    s = pl.Series("a", [t, t + timedelta(milliseconds=1)], dtype=pl.Datetime("ms"))
+    brk = t + timedelta(microseconds=500)
+
+    result = s.cut([brk], include_breaks=True)
+
+    expected = pl.DataFrame(
+        {
+            "breakpoint": pl.Series([brk, None], dtype=pl.Datetime("us")),
+            "category": [
+                "(-inf, 2024-01-01 00:00:00.000500]",
+                "(2024-01-01 00:00:00.000500, inf]",
+            ],
+        },
+        schema_overrides={"category": pl.Categorical},
+    ).to_struct("a")
+    assert_series_equal(result, expected, categorical_as_str=True)
+
+    lf = pl.LazyFrame({"a": s}).select(pl.col("a").cut([brk], include_breaks=True))
+    assert lf.collect_schema()["a"] == lf.collect().schema["a"]
+
+
+def test_cut_decimal() -> None:
+    s = pl.Series("a", [D("0.1"), D("0.10000000000000000001"), D("0.3")])
+
+    lf = pl.LazyFrame({"a": s}).select(
+        pl.col("a").cut(
+            [D("0.1"), D("0.2")], labels=["a", "b", "c"], include_breaks=True
+        )
+    )
+    result = lf.collect().to_series()
+
+    assert result.dtype == lf.collect_schema()["a"]
+    assert result.struct.field("category").cast(pl.String).to_list() == ["a", "b", "c"]
+    assert result.struct.field("breakpoint").to_list() == [D("0.1"), D("0.2"), None]
+
+
+def test_cut_missing_label() -> None:
+    s = pl.Series("a", [-1.0, None, float("nan"), 2.0])
+
+    result = s.cut([0], labels=["neg", "pos"], missing_label="missing")
+    assert result.cast(pl.String).to_list() == ["neg", "missing", "missing", "pos"]
+
+    result = s.cut([0], include_breaks=True, missing_label="missing")
+    assert result.struct.field("category").cast(pl.String).to_list() == [
+        "(-inf, 0]",
+        "missing",
+        "missing",
+        "(0, inf]",
+    ]
+    assert result.struct.field("breakpoint").to_list() == [0.0, None, None, inf]
+
+    dates = pl.Series("a", [None, date(2024, 1, 1)])
+    result = dates.cut([date(2024, 1, 1)], labels=["a", "b"], missing_label="missing")
+    assert result.cast(pl.String).to_list() == ["missing", "a"]
@@ -0,0 +1,48 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal, assert_series_equal
+
+
+def test_downsample_lttb() -> None:
+    start = datetime(2024, 1, 1)
+    df = pl.DataFrame(
+        {
+            "id": ["a"] * 6 + ["b"] * 3,
+            "t": [start + timedelta(hours=i) for i in [*range(6), 0, 1, 2]],
+            "v": [1.0, None, 7.0, 2.0, 3.0, 0.0, 1.0, 2.0, 3.0],
+        }
+    )
+    result = df.select(pl.col("v").downsample_lttb("t", 3).over("id"))
+    expected = pl.DataFrame({"v": [True, False, True, False, False, True] + [True] * 3})
+    assert_frame_equal(result, expected)
+
+    result = df.select(pl.col("v").downsample_lttb("t", 2).over("id"))
+    expected = pl.DataFrame({"v": [True] + [False] * 4 + [True, True, False, True]})
+    assert_frame_equal(result, expected)
+
+
+def test_downsample_minmax() -> None:
+    s = pl.Series("v", [1.0, float("nan"), 5.0, 0.0, 2.0, 2.0])
+    by = pl.Series(range(6))
+
+    result = s.downsample_minmax(by, 1)
+    expected = pl.Series("v", [False, False, True, True, False, False])
+    assert_series_equal(result, expected)
+
+    result = s.downsample_minmax(by, 3)
+    expected = pl.Series("v", [True, False, True, True, True, True])
+    assert_series_equal(result, expected)
+
+    result = s.downsample_minmax(by, 0)
+    assert_series_equal(result, pl.Series("v", [False] * 6))
+
+
+def test_downsample_invalid_dtype() -> None:
+    df = pl.DataFrame({"t": ["a", "b"], "v": [1, 2]})
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="dtype str"):
+        df.select(pl.col("v").downsample_lttb("t", 1))
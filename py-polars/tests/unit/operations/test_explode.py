@@ -458,3 +458,58 @@ def test_explode_17648() -> None:
 def test_explode_struct_nulls() -> None:
     df = pl.DataFrame({"A": [[{"B": 1}], [None], []]})
     assert df.explode("A").to_dict(as_series=False) == {"A": [{"B": 1}, None, None]}
+
+
+def test_explode_keep_index() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [[1, 2], None, [], [3]],
+            "b": [["x", "y"], None, [], ["z"]],
+            "c": [10, 20, 30, 40],
+        }
+    )
+    expected = pl.DataFrame(
+        {
+            "idx": pl.Series([0, 0, 1, 2, 3], dtype=pl.get_index_type()),
+            "a": [1, 2, None, None, 3],
+            "b": ["x", "y", None, None, "z"],
+            "c": [10, 10, 20, 30, 40],
+        }
+    )
+    assert_frame_equal(df.explode("a", "b", keep_index_as="idx"), expected)
+
+    # the index refers to the rows of the input of the explode, also when streaming
+    # or when optimizations would push projections, predicates and slices through it
+    lf = df.lazy().filter(pl.col("c") > 10).explode("a", keep_index_as="idx")
+    q = lf.filter(pl.col("c") < 40).select("idx", "a")
+    expected = pl.DataFrame(
+        {"idx": pl.Series([0, 1], dtype=pl.get_index_type()), "a": [None, None]},
+        schema_overrides={"a": pl.Int64},
+    )
+    assert_frame_equal(q.collect(), expected)
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert lf.head(2).collect()["idx"].to_list() == [0, 1]
+    assert lf.collect_schema() == {
+        "idx": pl.get_index_type(),
+        "a": pl.Int64,
+        "b": pl.List(pl.String),
+        "c": pl.Int64,
+    }
+
+    # re-aggregate the exploded rows
+    result = (
+        df.explode("a", keep_index_as="idx")
+        .group_by("idx", maintain_order=True)
+        .agg(pl.col("a").sum())
+    )
+    assert result["a"].to_list() == [3, 0, 0, 3]
+
+    with pytest.raises(pl.exceptions.DuplicateError):
+        df.explode("a", keep_index_as="c")
+
+
+def test_explode_keep_index_empty() -> None:
+    df = pl.DataFrame({"a": [[1]], "b": [1]}).clear()
+    result = df.explode("a", keep_index_as="idx")
+    assert result.schema == {"idx": pl.get_index_type(), "a": pl.Int64, "b": pl.Int64}
+    assert result.height == 0
@@ -338,3 +338,35 @@ def test_filter_expand_20014() -> None:
         .count("FILTER")
         == 3
     )
+
+
+@pytest.mark.parametrize(
+    "predicate",
+    [
+        # Selective first conjunct.
+        (pl.col("a") < 10) & (pl.col("b") > 0) & pl.col("c").is_not_null(),
+        # Non-selective first conjunct.
+        (pl.col("a") > 10) & (pl.col("b") % 7 == 0),
+        # Null handling in the conjuncts.
+        (pl.col("c") > 50) & (pl.col("b") > 0) & (pl.col("c") < 900),
+        # Broadcasted scalars.
+        pl.lit(True) & (pl.col("a") % 3 == 0) & (pl.col("b") < 0),
+        pl.lit(None, dtype=pl.Boolean) & (pl.col("a") > 0),
+        (pl.col("a") % 2 == 0) & pl.lit(False),
+    ],
+)
+def test_filter_conjuncts(predicate: pl.Expr) -> None:
+    n = 1000
+    df = pl.DataFrame(
+        {
+            "a": range(n),
+            "b": [i * (-1) ** i for i in range(n)],
+            "c": [None if i % 5 == 0 else i for i in range(n)],
+        }
+    )
+    df = pl.concat([df.slice(0, 300), df.slice(300)], rechunk=False)
+
+    expected = df.with_columns(keep=predicate).filter(pl.col("keep")).drop("keep")
+    assert_frame_equal(df.lazy().filter(predicate).collect(), expected)
+    assert_frame_equal(df.filter(predicate), expected)
+
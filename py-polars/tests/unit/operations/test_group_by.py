@@ -377,6 +377,22 @@ def test_group_by_iteration_selector() -> None:
     assert result_first.to_dict(as_series=False) == {"a": ["one", "one"], "b": [1, 3]}
 
 
+def test_group_by_indices() -> None:
+    df = pl.DataFrame({"a": [2, 1, 2, 2, 1], "b": ["x", "y", "x", "z", "y"]})
+    result = df.group_by("a", "b", maintain_order=True).indices()
+    expected = pl.DataFrame(
+        {"a": [2, 1, 2], "b": ["x", "y", "z"], "indices": [[0, 2], [1, 4], [3]]},
+        schema_overrides={"indices": pl.List(pl.get_index_type())},
+    )
+    assert_frame_equal(result, expected)
+
+    result = df.group_by(key=pl.col("a") * 10).indices(name="idx")
+    assert result.sort("key").to_dict(as_series=False) == {
+        "key": [10, 20],
+        "idx": [[1, 4], [0, 2, 3]],
+    }
+
+
 @pytest.mark.parametrize("input", [[pl.col("b").sum()], pl.col("b").sum()])
 def test_group_by_agg_input_types(input: Any) -> None:
     df = pl.LazyFrame({"a": [1, 1, 2, 2], "b": [1, 2, 3, 4]})
@@ -1382,3 +1398,145 @@ def test_group_by_filter_all_22955() -> None:
         ),
         check_row_order=False,
     )
+
+
+def test_group_by_expand_categories() -> None:
+    dtype = pl.Enum(["S", "M", "L"])
+    df = pl.DataFrame(
+        {
+            "size": pl.Series(["L", "S", "L", None], dtype=dtype),
+            "n": [1, 2, 3, 4],
+        }
+    )
+
+    out = df.group_by("size", maintain_order=True, expand_categories=True).agg(
+        pl.col("n").sum().alias("sum"),
+        pl.col("n").mean().alias("mean"),
+        pl.len(),
+    )
+    expected = pl.DataFrame(
+        {
+            "size": pl.Series(["L", "S", None, "M"], dtype=dtype),
+            "sum": [4, 2, 4, 0],
+            "mean": [2.0, 2.0, 4.0, None],
+            "len": pl.Series([2, 1, 1, 0], dtype=get_index_type()),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = df.group_by("size", expand_categories=True).agg(pl.len())
+    assert out.height == 4
+    assert_frame_equal(
+        df.lazy()
+        .group_by("size", expand_categories=True)
+        .agg(pl.len())
+        .collect(engine="streaming"),
+        out,
+        check_row_order=False,
+    )
+
+
+def test_group_by_expand_categories_multiple_keys() -> None:
+    df = pl.DataFrame(
+        {
+            "a": pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"])),
+            "b": [1, 2],
+            "c": [10, 20],
+        }
+    )
+    out = df.group_by("a", "b", maintain_order=True, expand_categories=True).agg(
+        pl.col("c").sum()
+    )
+    expected = pl.DataFrame(
+        {
+            "a": pl.Series(["x", "y", "x", "y"], dtype=pl.Enum(["x", "y"])),
+            "b": [1, 2, 2, 1],
+            "c": [10, 20, 0, 0],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_group_by_expand_categories_categorical() -> None:
+    # Categories from other frames share the global mapping and must not show up.
+    pl.Series(["unrelated"], dtype=pl.Categorical)
+    df = pl.DataFrame(
+        {
+            "a": pl.Series(["x", "y", "x"], dtype=pl.Categorical),
+            "b": pl.Series(["p", "q", "q"], dtype=pl.Enum(["p", "q"])),
+        }
+    )
+
+    out = df.group_by("a", expand_categories=True).agg(pl.len())
+    assert sorted(out["a"].to_list()) == ["x", "y"]
+
+    out = df.group_by("a", "b", maintain_order=True, expand_categories=True).agg(
+        pl.len()
+    )
+    expected = pl.DataFrame(
+        {
+            "a": pl.Series(["x", "y", "x", "y"], dtype=pl.Categorical),
+            "b": pl.Series(["p", "q", "q", "p"], dtype=pl.Enum(["p", "q"])),
+            "len": pl.Series([1, 1, 1, 0], dtype=get_index_type()),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_group_by_expand_categories_filter_not_pushed_down() -> None:
+    lf = pl.LazyFrame(
+        {"a": pl.Series(["x", "y"], dtype=pl.Enum(["x", "y"])), "b": [1, 2]}
+    )
+    out = (
+        lf.group_by("a", maintain_order=True, expand_categories=True)
+        .agg(pl.col("b").sum())
+        .filter(pl.col("a") == "x")
+        .collect()
+    )
+    assert out.to_dict(as_series=False) == {"a": ["x"], "b": [1]}
+
+    # Keys without categories are not expanded.
+    out = lf.group_by("b", expand_categories=True).agg(pl.len()).collect()
+    assert out.height == 2
+
+
+def test_group_by_fused_aggregations() -> None:
+    lf = pl.LazyFrame(
+        {
+            "g": [1, 1, 1, 2, 2, 3],
+            "a": [1, None, 1, 4, 5, None],
+            "b": [True, False, None, True, True, False],
+        }
+    )
+    q = lf.group_by("g", maintain_order=True).agg(
+        pl.col("a").unique().len().alias("n_unique"),
+        pl.col("a").is_not_null().sum().alias("count"),
+        pl.col("a").filter(pl.col("b")).len().alias("filter_len"),
+        pl.col("a").filter(pl.col("b")).count().alias("filter_count"),
+        pl.col("a").filter(pl.col("b")).sum().alias("filter_sum"),
+        pl.col("a").filter(pl.col("a") > 1).max().alias("filter_max"),
+        pl.col("a").filter(pl.col("b")).mean().alias("filter_mean"),
+    )
+
+    plan = q.explain()
+    assert "n_unique()" in plan
+    assert "filter" not in plan
+
+    expected = q.collect(optimizations=pl.QueryOptFlags(simplify_expression=False))
+    assert_frame_equal(q.collect(), expected)
+    assert expected.to_dict(as_series=False) == {
+        "g": [1, 2, 3],
+        "n_unique": [2, 2, 1],
+        "count": [2, 2, 0],
+        "filter_len": [1, 2, 0],
+        "filter_count": [1, 2, 0],
+        "filter_sum": [1, 9, 0],
+        "filter_max": [None, 5, None],
+        "filter_mean": [1.0, 4.5, None],
+    }
+
+    # Filters by a scalar mask keep or drop the whole group.
+    q = lf.group_by("g", maintain_order=True).agg(
+        pl.col("a").filter(pl.lit(True)).len()
+    )
+    assert q.collect()["a"].to_list() == [3, 2, 1]
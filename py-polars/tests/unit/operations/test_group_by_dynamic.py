@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date, datetime, timedelta, timezone
+from datetime import date, datetime, time, timedelta, timezone
 from typing import TYPE_CHECKING, Any
 from zoneinfo import ZoneInfo
 
@@ -1131,3 +1131,70 @@ def test_group_by_dynamic_null_mean_22724() -> None:
         }
     )
     assert_frame_equal(out, expected)
+
+
+def test_group_by_dynamic_trading_calendar() -> None:
+    calendar = pl.TradingCalendar(
+        open=time(9, 30),
+        close=time(16),
+        holidays=[date(2024, 11, 28)],
+        early_closes={date(2024, 11, 29): time(13)},
+    )
+
+    df = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 11, 29, 9, 30),
+                datetime(2024, 11, 29, 10, 15),
+                datetime(2024, 11, 29, 12, 45),
+                datetime(2024, 11, 29, 13, 5),
+                datetime(2024, 12, 2, 9, 29),
+                datetime(2024, 12, 2, 9, 31),
+            ]
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone("America/New_York"))
+    out = df.group_by_dynamic("time", every="2h", calendar=calendar).agg(pl.len())
+    expected = pl.DataFrame(
+        {
+            "time": [
+                datetime(2024, 11, 29, 9, 30),
+                datetime(2024, 11, 29, 11, 30),
+                datetime(2024, 12, 2, 9, 30),
+            ],
+            "len": pl.Series([2, 1, 1], dtype=pl.UInt32),
+        }
+    ).with_columns(pl.col("time").dt.replace_time_zone("America/New_York"))
+    assert_frame_equal(out, expected)
+
+    # Daily windows skip the holiday and the weekend.
+    df = pl.DataFrame(
+        {
+            "date": [
+                date(2024, 11, 27),
+                date(2024, 11, 28),
+                date(2024, 11, 29),
+                date(2024, 11, 30),
+                date(2024, 12, 2),
+            ],
+            "value": [1, 2, 3, 4, 5],
+        }
+    )
+    out = df.group_by_dynamic("date", every="1d", calendar=calendar).agg(
+        pl.col("value").sum()
+    )
+    expected = pl.DataFrame(
+        {
+            "date": [date(2024, 11, 27), date(2024, 11, 29), date(2024, 12, 2)],
+            "value": [1, 3, 5],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    with pytest.raises(InvalidOperationError, match="Date or Datetime"):
+        pl.DataFrame({"idx": [1, 2]}).group_by_dynamic(
+            "idx", every="1i", calendar=calendar
+        ).agg(pl.len())
+    with pytest.raises(InvalidOperationError, match="number of days"):
+        df.group_by_dynamic("date", every="1d12h", calendar=calendar).agg(pl.len())
+    with pytest.raises(ValueError, match="7 days"):
+        pl.TradingCalendar(open=time(9), close=time(17), week_mask=[True])
@@ -681,3 +681,33 @@ def test_boolean_predicate_join_where() -> None:
             categories, pl.col("url").str.starts_with(pl.col("base_url"))
         ).explain()
     )
+
+
+@pytest.mark.parametrize("maintain_order", ["left_right", "right_left"])
+@pytest.mark.parametrize(
+    "predicates",
+    [
+        [pl.col("a") < pl.col("b")],
+        [pl.col("a") < pl.col("b"), pl.col("c") > pl.col("d")],
+        [pl.col("c") == pl.col("d"), pl.col("a") < pl.col("b")],
+    ],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_join_where_maintain_order(
+    maintain_order: Any, predicates: list[pl.Expr], engine: Any
+) -> None:
+    left = pl.LazyFrame({"a": [5, 1, 4, 2, 3, 0], "c": [1, 0, 1, 0, 1, 0]})
+    right = pl.LazyFrame({"b": [3, 6, 1, 5, 2], "d": [0, 1, 0, 1, 1]})
+
+    q = left.join_where(right, *predicates, maintain_order=maintain_order)
+    assert "IEJOIN" not in q.explain()
+
+    by = ["l", "r"] if maintain_order == "left_right" else ["r", "l"]
+    expected = (
+        left.with_row_index("l")
+        .join(right.with_row_index("r"), how="cross")
+        .filter(predicates)
+        .sort(by)
+        .drop("l", "r")
+    )
+    assert_frame_equal(q.collect(engine=engine), expected.collect())
@@ -6,11 +6,11 @@ from typing import TYPE_CHECKING, Any
 import pytest
 
 import polars as pl
-from polars.exceptions import InvalidOperationError
+from polars.exceptions import ComputeError, InvalidOperationError
 from polars.testing import assert_frame_equal, assert_series_equal
 
 if TYPE_CHECKING:
-    from polars._typing import PolarsDataType
+    from polars._typing import EngineType, PolarsDataType
 
 
 def test_is_first_distinct() -> None:
@@ -156,3 +156,52 @@ def test_is_first_last_distinct_all_null(dtypes: PolarsDataType) -> None:
     s = pl.Series([None, None, None], dtype=dtypes)
     assert s.is_first_distinct().to_list() == [True, False, False]
     assert s.is_last_distinct().to_list() == [False, False, True]
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_is_first_last_distinct_columns(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": [1, 1, None, 2, None, 1],
+            "b": ["x", "x", "y", None, "y", "z"],
+        }
+    )
+    result = lf.select(
+        first=pl.is_first_distinct("a", "b"),
+        last=pl.is_last_distinct(pl.col("a", "b")),
+        first_struct=pl.struct("a", "b").is_first_distinct(),
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {
+            "first": [True, False, True, True, False, True],
+            "last": [False, True, False, True, True, True],
+            "first_struct": [True, False, True, True, False, True],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_is_first_last_distinct_expressions(engine: EngineType) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4, 5, 6], "b": [1, 1, 2, 2, 1, 1]})
+    result = lf.filter(pl.is_first_distinct(pl.col("a") % 2, "b")).collect(
+        engine=engine
+    )
+    assert_frame_equal(result, pl.DataFrame({"a": [1, 2, 3, 4], "b": [1, 1, 2, 2]}))
+
+
+def test_is_first_distinct_columns_streaming_batches() -> None:
+    lf = pl.LazyFrame({"a": [i % 7 for i in range(1000)], "b": range(1000)})
+    q = lf.select(pl.is_first_distinct("a", pl.col("b") % 3))
+    with pl.Config(streaming_chunk_size=10):
+        result = q.collect(engine="streaming")
+    assert_frame_equal(result, q.collect(engine="in-memory"))
+    assert result["a"].sum() == 21
+
+
+def test_is_first_last_distinct_columns_invalid() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+    with pytest.raises(ComputeError, match="at least one expression"):
+        df.select(pl.is_first_distinct([]))
+    with pytest.raises(ComputeError, match="at least one expression"):
+        df.select(pl.is_last_distinct([]))
@@ -21,7 +21,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 from tests.unit.conftest import time_func
 
 if TYPE_CHECKING:
-    from polars._typing import JoinStrategy, PolarsDataType
+    from polars._typing import EngineType, JoinStrategy, PolarsDataType
 
 
 def test_semi_anti_join() -> None:
@@ -1518,6 +1518,38 @@ def test_join_numeric_key_upcast_15338(
         )
 
 
+def test_join_nested_key_upcast() -> None:
+    left = pl.DataFrame(
+        {"a": [[1, 2], [3, 4], [5, 6]], "b": [1, 2, 3]},
+        schema={"a": pl.Array(pl.Int64, 2), "b": pl.Int64},
+    )
+    right = pl.DataFrame(
+        {"a": [[3, 4], [5], [1, 2]], "c": [4, 5, 6]},
+        schema={"a": pl.List(pl.Int32), "c": pl.Int64},
+    )
+
+    out = left.join(right, on="a", how="left", maintain_order="left")
+    expected = left.with_columns(c=pl.Series([6, 4, None]))
+    assert_frame_equal(out, expected)
+
+    out = left.join(right, on="a", how="full", coalesce=True).sort(
+        "b", nulls_last=True
+    )
+    assert out.schema["a"] == pl.List(pl.Int64)
+    assert out.to_dict(as_series=False) == {
+        "a": [[1, 2], [3, 4], [5, 6], [5]],
+        "b": [1, 2, 3, None],
+        "c": [6, 4, None, 5],
+    }
+
+    # Arrays of different widths don't have a common type.
+    with pytest.raises(SchemaError, match="datatypes of join keys don't match"):
+        left.join(
+            right.with_columns(pl.col("a").cast(pl.Array(pl.Int64, 1), strict=False)),
+            on="a",
+        )
+
+
 def test_join_numeric_key_upcast_forbid_float_int() -> None:
     ltype = pl.Float64
     rtype = pl.Int128
@@ -1824,6 +1856,37 @@ def test_join_on_nested(dtype: PolarsDataType, data: list[Any]) -> None:
     )
 
 
+@pytest.mark.parametrize(
+    "dtype",
+    [pl.List(pl.Int64), pl.Array(pl.Int64, 2), pl.Struct({"x": pl.Int64})],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_join_on_nested_nulls(dtype: pl.DataType, engine: EngineType) -> None:
+    if isinstance(dtype, pl.Struct):
+        values: list[Any] = [{"x": 1}, None, {"x": None}]
+    else:
+        values = [[1, 2], None, [None, 3]]
+    lhs = pl.LazyFrame(
+        {"a": values, "b": [1, 2, 3]}, schema={"a": dtype, "b": pl.Int64}
+    )
+    rhs = pl.LazyFrame(
+        {"a": values[::-1], "c": [3, 2, 1]}, schema={"a": dtype, "c": pl.Int64}
+    )
+
+    out = lhs.join(rhs, on="a", how="left", maintain_order="left")
+    assert out.collect(engine=engine)["c"].to_list() == [1, None, 3]
+
+    out = lhs.join(rhs, on="a", how="left", nulls_equal=True, maintain_order="left")
+    assert out.collect(engine=engine)["c"].to_list() == [1, 2, 3]
+
+    out = lhs.join(rhs, on="a", how="anti", maintain_order="left")
+    assert out.collect(engine=engine)["b"].to_list() == [2]
+
+    # Multiple keys of which one is nested.
+    out = lhs.join(rhs, left_on=["a", "b"], right_on=["a", "c"], how="inner")
+    assert out.collect(engine=engine).sort("b")["b"].to_list() == [1, 3]
+
+
 def test_empty_join_result_with_array_15474() -> None:
     lhs = pl.DataFrame(
         {
@@ -3764,3 +3827,92 @@ def test_join_asof_by_i128() -> None:
             schema={"a": pl.Int128, "i": pl.Int32, "b": pl.Int128},
         ),
     )
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_join_indicator(engine: EngineType) -> None:
+    left = pl.LazyFrame({"a": [1, 2, 2, 3], "b": ["x", "y", "z", "w"]})
+    right = pl.LazyFrame({"a": [2, 4], "c": [True, False]})
+
+    out = left.join(
+        right, on="a", how="full", indicator=True, maintain_order="left_right"
+    ).collect(engine=engine)
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2, 2, 3, None],
+            "b": ["x", "y", "z", "w", None],
+            "a_right": [None, 2, 2, None, 4],
+            "c": [None, True, True, None, False],
+            "_merge": pl.Series(
+                ["left_only", "both", "both", "left_only", "right_only"],
+                dtype=pl.Categorical,
+            ),
+        }
+    )
+    assert_frame_equal(out, expected)
+
+    out = left.join(
+        right, on="a", how="left", indicator="side", maintain_order="left"
+    ).collect(engine=engine)
+    assert out.columns == ["a", "b", "c", "side"]
+    assert out["side"].cast(pl.String).to_list() == [
+        "left_only",
+        "both",
+        "both",
+        "left_only",
+    ]
+
+
+def test_join_indicator_semi_anti() -> None:
+    left = pl.DataFrame({"a": [1, 1, 2, 3, 3], "b": range(5)})
+    right = pl.DataFrame({"a": [2, 4]})
+
+    anti = left.join(right, on="a", how="anti", indicator=True)
+    assert anti["_merge"].cast(pl.String).to_list() == ["left_only"] * 4
+    semi = left.join(right, on="a", how="semi", indicator=True)
+    assert semi["_merge"].cast(pl.String).to_list() == ["both"]
+
+    # An anti join keeps all duplicates of the left rows without a match, exactly
+    # like the "left_only" rows of a full join.
+    left_only = (
+        left.join(right, on="a", how="full", indicator=True, coalesce=True)
+        .filter(pl.col("_merge") == "left_only")
+        .select(left.columns)
+    )
+    assert_frame_equal(anti.drop("_merge"), left_only, check_row_order=False)
+    assert anti["b"].sort().to_list() == [0, 1, 3, 4]
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_join_nans_equal(engine: EngineType) -> None:
+    nan = float("nan")
+    left = pl.LazyFrame({"x": [1.0, nan, None], "a": [1, 2, 3]})
+    right = pl.LazyFrame({"x": [nan, 1.0], "b": ["p", "q"]})
+
+    def join(how: JoinStrategy, **kwargs: Any) -> pl.DataFrame:
+        out = left.join(right, on="x", how=how, **kwargs).collect(engine=engine)
+        return out.sort("a", nulls_last=True)
+
+    assert join("inner")["a"].to_list() == [1, 2]
+    assert join("inner", nans_equal=False)["a"].to_list() == [1]
+    assert join("semi", nans_equal=False)["a"].to_list() == [1]
+    assert join("anti", nans_equal=False)["a"].to_list() == [2, 3]
+    assert_frame_equal(
+        join("left", nans_equal=False),
+        pl.DataFrame({"x": [1.0, nan, None], "a": [1, 2, 3], "b": ["q", None, None]}),
+    )
+    # The unmatched NaN keys keep their value in the coalesced key column.
+    assert_frame_equal(
+        join("full", nans_equal=False, coalesce=True),
+        pl.DataFrame(
+            {
+                "x": [1.0, nan, None, nan],
+                "a": [1, 2, 3, None],
+                "b": ["q", None, None, "p"],
+            }
+        ),
+        check_row_order=False,
+    )
+
+    with pytest.raises(InvalidOperationError, match="cannot be combined"):
+        join("inner", nans_equal=False, nulls_equal=True)
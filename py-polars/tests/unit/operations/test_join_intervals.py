@@ -0,0 +1,136 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta
+from typing import Any
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+
+def brute_force(
+    left: pl.DataFrame, right: pl.DataFrame, predicate: pl.Expr
+) -> pl.DataFrame:
+    return left.join(right, how="cross").filter(predicate)
+
+
+@pytest.fixture
+def left() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "id": [0, 1, 2, 3, 4],
+            "start": [0, 10, 20, 30, None],
+            "end": [10, 25, 22, 60, 5],
+        }
+    )
+
+
+@pytest.fixture
+def right() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "id": [0, 1, 2, 3],
+            "start": [5, 10, 25, 40],
+            "end": [10, 20, 30, 50],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    ("kwargs", "predicate"),
+    [
+        (
+            {},
+            (pl.col("start") < pl.col("end_right"))
+            & (pl.col("end") > pl.col("start_right")),
+        ),
+        (
+            {"min_overlap": 5},
+            (
+                pl.min_horizontal("end", "end_right")
+                - pl.max_horizontal("start", "start_right")
+            )
+            >= 5,
+        ),
+        (
+            {"min_overlap": 0},
+            (pl.col("start") <= pl.col("end_right"))
+            & (pl.col("end") >= pl.col("start_right")),
+        ),
+        (
+            {"mode": "contains"},
+            (pl.col("start") <= pl.col("start_right"))
+            & (pl.col("end") >= pl.col("end_right")),
+        ),
+        (
+            {"mode": "within"},
+            (pl.col("start") >= pl.col("start_right"))
+            & (pl.col("end") <= pl.col("end_right")),
+        ),
+    ],
+)
+def test_join_intervals(
+    left: pl.DataFrame,
+    right: pl.DataFrame,
+    kwargs: dict[str, Any],
+    predicate: pl.Expr,
+) -> None:
+    result = left.join_intervals(right, on=["start", "end"], **kwargs)
+    expected = brute_force(left, right, predicate)
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_join_intervals_exprs_and_suffix(
+    left: pl.DataFrame, right: pl.DataFrame
+) -> None:
+    right = right.select("id", pl.col("start").alias("lo"), pl.col("end") - 1)
+    result = (
+        left.lazy()
+        .join_intervals(
+            right.lazy(),
+            left_on=["start", "end"],
+            right_on=[pl.col("lo"), pl.col("end") + 1],
+            suffix="_r",
+        )
+        .collect()
+    )
+    expected = brute_force(
+        left,
+        right.rename({"id": "id_r", "end": "end_r"}),
+        (pl.col("start") < pl.col("end_r") + 1) & (pl.col("end") > pl.col("lo")),
+    )
+    assert result.columns == ["id", "start", "end", "id_r", "lo", "end_r"]
+    assert_frame_equal(result, expected, check_row_order=False)
+
+
+def test_join_intervals_datetime() -> None:
+    shifts = pl.DataFrame(
+        {
+            "worker": ["a", "b"],
+            "start": [datetime(2025, 1, 1, 8), datetime(2025, 1, 1, 12)],
+            "end": [datetime(2025, 1, 1, 16), datetime(2025, 1, 1, 20)],
+        }
+    )
+    incidents = pl.DataFrame(
+        {
+            "incident": [1, 2],
+            "start": [datetime(2025, 1, 1, 15), datetime(2025, 1, 1, 7)],
+            "end": [datetime(2025, 1, 1, 18), datetime(2025, 1, 1, 9)],
+        }
+    )
+    result = shifts.join_intervals(
+        incidents, on=["start", "end"], min_overlap=timedelta(hours=2)
+    ).select("worker", "incident")
+    assert_frame_equal(result, pl.DataFrame({"worker": ["b"], "incident": [1]}))
+
+
+def test_join_intervals_invalid(left: pl.DataFrame, right: pl.DataFrame) -> None:
+    with pytest.raises(ValueError, match="either `on`"):
+        left.join_intervals(right, left_on=["start", "end"])
+    with pytest.raises(ValueError, match="cannot use `on`"):
+        left.join_intervals(right, on=["start", "end"], left_on=["start", "end"])
+    with pytest.raises(ValueError, match="start and end"):
+        left.join_intervals(right, on=["start"])
+    with pytest.raises(ValueError, match="`mode` must be one of"):
+        left.join_intervals(right, on=["start", "end"], mode="touch")  # type: ignore[arg-type]
@@ -7,7 +7,7 @@ import pytest
 
 import polars as pl
 import polars.selectors as cs
-from polars.exceptions import ComputeError, DuplicateError
+from polars.exceptions import ComputeError, DuplicateError, InvalidOperationError
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
@@ -621,3 +621,65 @@ def test_pivot_agg_null_methods_23408() -> None:
         {"idx": [0, 1], "a": ["aa", "aa"], "b": ["bb", "xx"], "c": ["xx", "cc"]}
     )
     assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("agg_fn", ["first", "len", "min", "sum", "mean"])
+def test_pivot_lazy_enum(agg_fn: PivotAgg) -> None:
+    df = pl.DataFrame(
+        {
+            "a": ["x", "x", "y", "y", "z"],
+            "b": ["a", "a", "b", "b", "b"],
+            "c": [2, 4, None, 8, 10],
+        },
+        schema_overrides={"a": pl.Enum(["x", "y", "z"])},
+    )
+    expected = df.pivot(
+        index="b", on="a", values="c", aggregate_function=agg_fn, sort_columns=True
+    )
+    lf = df.lazy().pivot(
+        "a", index="b", values="c", aggregate_function=agg_fn, maintain_order=True
+    )
+    assert lf.collect_schema() == expected.schema
+    assert_frame_equal(lf.collect(), expected)
+    assert_frame_equal(lf.collect(engine="streaming"), expected, check_row_order=False)
+
+
+def test_pivot_lazy_enum_unused_category_and_multiple_values() -> None:
+    lf = pl.LazyFrame(
+        {
+            "ix": [1, 1, 2],
+            "col": ["a", "b", "a"],
+            "foo": [0, 1, 2],
+            "bar": [3, 4, 5],
+        },
+        schema_overrides={"col": pl.Enum(["a", "b", "c"])},
+    )
+    out = lf.pivot(
+        "col",
+        index="ix",
+        aggregate_function=pl.element().sum(),
+        maintain_order=True,
+        separator=":",
+    ).collect()
+    expected = pl.DataFrame(
+        {
+            "ix": [1, 2],
+            "foo:a": [0, 2],
+            "foo:b": [1, 0],
+            "foo:c": [0, 0],
+            "bar:a": [3, 5],
+            "bar:b": [4, 0],
+            "bar:c": [0, 0],
+        }
+    )
+    assert_frame_equal(out, expected)
+
+
+def test_pivot_lazy_requires_enum() -> None:
+    lf = pl.LazyFrame({"ix": [1, 2], "col": ["a", "b"], "foo": [0, 1]})
+    with pytest.raises(InvalidOperationError, match="Enum"):
+        lf.pivot("col", index="ix", aggregate_function="first")
+    with pytest.raises(InvalidOperationError, match="column references"):
+        lf.with_columns(pl.col("col").cast(pl.Enum(["a", "b"]))).pivot(
+            "col", index="ix", aggregate_function=pl.col("foo").first()
+        )
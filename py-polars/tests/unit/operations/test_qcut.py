@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from datetime import date
+
 import pytest
 
 import polars as pl
@@ -141,3 +143,30 @@ def test_qcut_over() -> None:
         dtype=pl.Categorical,
     )
     assert_series_equal(out, expected, categorical_as_str=True)
+
+
+def test_qcut_date() -> None:
+    s = pl.Series("a", [date(2024, 1, d) for d in range(1, 6)])
+
+    result = s.qcut([0.5], include_breaks=True)
+
+    expected = pl.DataFrame(
+        {
+            "breakpoint": 3 * [date(2024, 1, 3)] + 2 * [None],
+            "category": 3 * ["(-inf, 2024-01-03]"] + 2 * ["(2024-01-03, inf]"],
+        },
+        schema_overrides={"category": pl.Categorical},
+    ).to_struct("a")
+    assert_series_equal(result, expected, categorical_as_str=True)
+
+
+def test_qcut_missing_label() -> None:
+    s = pl.Series("a", [1, None, 2, 3, 4])
+
+    result = s.qcut(2, labels=["low", "high"], missing_label="missing")
+    assert result.cast(pl.String).to_list() == ["low", "missing", "low", "high", "high"]
+
+    result = pl.Series("a", [None, None], dtype=pl.Int64).qcut(
+        2, missing_label="missing"
+    )
+    assert result.cast(pl.String).to_list() == ["missing", "missing"]
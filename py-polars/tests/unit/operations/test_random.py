@@ -24,6 +24,33 @@ def test_shuffle_group_by_reseed() -> None:
     )  # Fixed seed should be always the same.
 
 
+def test_shuffle_over_seed_by_group_key() -> None:
+    n = 50
+    df = pl.DataFrame({"l": [1, 2, 3] * n, "group": sorted(list(range(n)) * 3)})
+    shuffled = df.with_columns(pl.col("l").shuffle(0xDEADBEEF).over("group"))
+
+    # Groups get their own permutation.
+    assert shuffled.group_by("group").agg("l")["l"].n_unique() > 1
+
+    # The permutation of a group only depends on its key, not on the group order.
+    reversed_df = df.reverse().with_columns(pl.col("l").reverse().over("group"))
+    reshuffled = reversed_df.with_columns(pl.col("l").shuffle(0xDEADBEEF).over("group"))
+    assert_frame_equal(
+        shuffled.group_by("group").agg("l").sort("group"),
+        reshuffled.group_by("group", maintain_order=True).agg("l").sort("group"),
+    )
+
+    sampled = df.with_columns(
+        pl.col("l").sample(fraction=1.0, shuffle=True, seed=1).over("group")
+    )
+    assert_frame_equal(
+        sampled,
+        df.with_columns(
+            pl.col("l").sample(fraction=1.0, shuffle=True, seed=1).over("group")
+        ),
+    )
+
+
 def test_sample_expr() -> None:
     a = pl.Series("a", range(20))
     out = pl.select(
@@ -146,3 +173,49 @@ def test_sample_16232() -> None:
     assert df.select(pl.col("b").list.sample(n=pl.col("a"), seed=0)).to_dict(
         as_series=False
     ) == {"b": [[], [], [1]]}
+
+
+def test_split() -> None:
+    df = pl.DataFrame({"x": range(100), "y": [0, 0, 0, 1] * 25})
+
+    train, val, test = df.split([0.7, 0.2, 0.1], seed=1)
+    assert (train.height, val.height, test.height) == (70, 20, 10)
+    assert_frame_equal(pl.concat([train, val, test]).sort("x"), df)
+
+    # The same seed gives the same split, and the lazy splits are consistent.
+    assert_frame_equal(df.split([0.7, 0.2, 0.1], seed=1)[2], test)
+    lf_splits = pl.collect_all(df.lazy().split([0.5, 0.5]))
+    assert_frame_equal(pl.concat(lf_splits).sort("x"), df)
+
+    train, test = df.split([0.8, 0.2], stratify_by="y", seed=1)
+    assert test["y"].value_counts().sort("y")["count"].to_list() == [15, 5]
+
+    train, test = df.split([0.9, 0.1], shuffle=False)
+    assert test["x"].to_list() == list(range(90, 100))
+
+    with pytest.raises(ValueError, match="sum to 1"):
+        df.split([0.5, 0.4])
+    with pytest.raises(ValueError, match="at least two"):
+        df.split([1.0])
+
+
+def test_k_fold() -> None:
+    df = pl.DataFrame({"x": range(12), "g": [0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5]})
+
+    folds = df.k_fold(3, seed=1)
+    assert len(folds) == 3
+    assert_frame_equal(pl.concat([test for _, test in folds]).sort("x"), df)
+    for train, test in folds:
+        assert test.height == 4
+        assert_frame_equal(pl.concat([train, test]).sort("x"), df)
+
+    folds = df.k_fold(3, group_by="g", seed=1)
+    for train, test in folds:
+        assert test["g"].n_unique() == 2
+        assert set(train["g"]).isdisjoint(set(test["g"]))
+
+    folds = df.lazy().k_fold(4, shuffle=False)
+    assert pl.collect_all([test for _, test in folds])[1]["x"].to_list() == [1, 5, 9]
+
+    with pytest.raises(ValueError, match="at least 2"):
+        df.k_fold(1)
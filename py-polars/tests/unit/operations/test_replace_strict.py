@@ -1,5 +1,6 @@
 from __future__ import annotations
 
+import io
 from typing import Any
 
 import pytest
@@ -419,3 +420,50 @@ def test_replace_strict_nested_mapping_22554() -> None:
         ),
         pl.Series([[42], [13], [37]]),
     )
+
+
+def test_replace_strict_frame_mapping() -> None:
+    df = pl.DataFrame({"a": [1, 2, None, 3, 1]})
+    mapping = pl.DataFrame({"from": [1, 2, 3], "to": ["x", "y", "z"]})
+
+    expected = pl.DataFrame({"a": ["x", "y", None, "z", "x"]})
+    assert_frame_equal(df.select(pl.col("a").replace_strict(mapping)), expected)
+    assert_frame_equal(df.select(pl.col("a").replace_strict(mapping.lazy())), expected)
+
+
+def test_replace_strict_lazy_frame_mapping_default_expr() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3], "b": ["p", "q", "r"]})
+    mapping = pl.LazyFrame({"from": [1, 2], "to": ["x", "y"]}).filter(
+        pl.col("from") < 2
+    )
+
+    result = lf.select(pl.col("a").replace_strict(mapping, default=pl.col("b")))
+
+    expected = pl.DataFrame({"a": ["x", "q", "r"]})
+    assert_frame_equal(result.collect(), expected)
+
+
+def test_replace_strict_frame_mapping_incomplete() -> None:
+    df = pl.DataFrame({"a": [1, 2]})
+    mapping = pl.LazyFrame({"from": [1], "to": [10]})
+
+    with pytest.raises(InvalidOperationError, match="incomplete mapping"):
+        df.select(pl.col("a").replace_strict(mapping))
+
+
+def test_replace_strict_frame_mapping_invalid() -> None:
+    with pytest.raises(pl.exceptions.ShapeError, match="2 columns"):
+        pl.col("a").replace_strict(pl.LazyFrame({"from": [1]}))
+
+    mapping = pl.DataFrame({"from": [1], "to": [10]})
+    with pytest.raises(TypeError, match="must not be given"):
+        pl.col("a").replace_strict(mapping, [20])
+
+
+def test_replace_strict_frame_mapping_serializable() -> None:
+    mapping = pl.LazyFrame({"from": [1, 2], "to": ["x", "y"]})
+    expr = pl.col("a").replace_strict(mapping, default="z")
+
+    expr = pl.Expr.deserialize(io.BytesIO(expr.meta.serialize()))
+    result = pl.DataFrame({"a": [2, 3, 1]}).select(expr)
+    assert_frame_equal(result, pl.DataFrame({"a": ["y", "z", "x"]}))
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date
+from datetime import date, datetime, timedelta
 
 import pytest
 
@@ -177,3 +177,89 @@ def test_shift_fill_value_nonscalar() -> None:
         match="'fill_value' must be scalar value",
     ):
         df.select(pl.col("a").shift(1, fill_value=pl.col("b")))
+
+
+@pytest.mark.parametrize("n", [-3, -1, 0, 1, 2, 5])
+@pytest.mark.parametrize(
+    "fill_value", [pl.col("a").mean(), pl.col("a").first(), pl.lit(-1.0), None]
+)
+def test_shift_fill_value_per_group(n: int, fill_value: pl.Expr | None) -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["x", "y", "x", "z", "y", "x", "y", "x"],
+            "a": [1.0, 2.0, None, 4.0, 5.0, 6.0, 7.0, 8.0],
+        }
+    )
+
+    def expected_group(values: list[float | None]) -> list[float | None]:
+        fill = None
+        if fill_value is not None:
+            fill = pl.DataFrame({"a": values}).select(fill_value).item()
+        return [
+            values[i - n] if 0 <= i - n < len(values) else fill
+            for i in range(len(values))
+        ]
+
+    groups: dict[str, list[float | None]] = {}
+    for g, a in df.iter_rows():
+        groups.setdefault(g, []).append(a)
+    expected_per_group = {g: expected_group(v) for g, v in groups.items()}
+    position = dict.fromkeys(groups, 0)
+    expected = []
+    for g in df["g"]:
+        expected.append(expected_per_group[g][position[g]])
+        position[g] += 1
+
+    shift = pl.col("a").shift(n, fill_value=fill_value)
+    out = df.with_columns(shift.over("g"))
+    assert out["a"].to_list() == expected
+
+    out = df.group_by("g", maintain_order=True).agg(shift)
+    assert out["a"].to_list() == [expected_per_group[g] for g in ["x", "y", "z"]]
+
+
+def test_shift_fill_value_per_group_sorted() -> None:
+    df = pl.DataFrame({"g": [1, 2, 1, 2, 1], "a": [3, 1, 2, 4, 1]})
+    out = df.group_by("g", maintain_order=True).agg(
+        pl.col("a").sort().shift(1, fill_value=pl.col("a").max()),
+        b=pl.col("a").shift(-1, fill_value=pl.col("a").sum()).sort(),
+    )
+    expected = pl.DataFrame(
+        {"g": [1, 2], "a": [[3, 1, 2], [4, 1]], "b": [[1, 2, 6], [4, 5]]}
+    )
+    assert_frame_equal(out, expected)
+
+
+@pytest.mark.parametrize("period", ["1h", timedelta(hours=1)])
+def test_shift_by(period: str | timedelta) -> None:
+    df = pl.DataFrame(
+        {
+            "ts": [
+                datetime(2024, 1, 1, 0),
+                datetime(2024, 1, 1, 1),
+                datetime(2024, 1, 1, 1, 30),
+                datetime(2024, 1, 1, 4),
+            ],
+            "a": [1, 2, 3, 4],
+            "b": ["w", "x", "y", "z"],
+        }
+    )
+    expected = df.with_columns(
+        a=pl.Series([None, 1, 1, 3]), b=pl.Series([None, "w", "w", "y"])
+    )
+    assert_frame_equal(df.shift_by(period, on="ts"), expected)
+    assert_frame_equal(df.lazy().shift_by(period, on="ts").collect(), expected)
+
+    out = df.shift_by("-30m", on="ts")
+    assert out["a"].to_list() == [1, 3, 3, 4]
+
+
+def test_shift_by_date() -> None:
+    df = pl.DataFrame(
+        {
+            "d": [date(2024, 1, 15), date(2024, 2, 15), date(2024, 3, 20)],
+            "a": [1.0, 2.0, 3.0],
+        }
+    )
+    out = df.shift_by("1mo", on="d")
+    assert out["a"].to_list() == [None, 1.0, 2.0]
@@ -842,6 +842,34 @@ def test_arg_sort_by_descending() -> None:
         df.select(pl.arg_sort_by(["a", "b"], descending=[True]))
 
 
+@pytest.mark.parametrize("limit", [0, 1, 3, 7, 100])
+@pytest.mark.parametrize("descending", [False, True])
+@pytest.mark.parametrize("nulls_last", [False, True])
+def test_arg_sort_by_limit(limit: int, descending: bool, nulls_last: bool) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3, None, 1, 3, 2, None, 1, 3, 2, 1],
+            "b": ["x", "y", "x", "x", "y", "x", "y", "x", "x", "y"],
+        }
+    )
+    for by in [["a"], ["a", "b"]]:
+        kwargs = {"descending": descending, "nulls_last": nulls_last}
+        result = df.select(
+            pl.arg_sort_by(by, **kwargs, maintain_order=True, limit=limit)
+        )
+        expected = df.select(pl.arg_sort_by(by, **kwargs, maintain_order=True))
+        assert_frame_equal(result, expected.head(limit))
+
+        grouped = df.group_by(pl.lit(1)).agg(pl.arg_sort_by(by, **kwargs, limit=limit))
+        assert grouped.item(0, 1).len() == min(limit, df.height)
+
+    result = df.select(pl.arg_sort_by("a", descending=descending, limit=limit))
+    assert_frame_equal(
+        df[result.to_series()].select("a"),
+        df.sort("a", descending=descending).head(limit).select("a"),
+    )
+
+
 def test_arg_sort_struct() -> None:
     df = pl.DataFrame(
         {
@@ -1198,3 +1226,27 @@ def test_sort_cat_nulls_last(dtype: PolarsDataType) -> None:
         ),
         pl.Series([None, "b", "a"], dtype=dtype),
     )
+
+
+def test_sort_reuses_with_columns_key() -> None:
+    df = pl.DataFrame({"a": [3, 1, 2], "b": ["x", "yyyyy", "zz"]})
+    key = pl.col("b").str.len_chars() * pl.col("a")
+    expected = pl.DataFrame({"a": [1, 2, 3], "b": ["yyyyy", "zz", "x"]})
+
+    assert_frame_equal(df.sort(key, descending=True), expected)
+
+    lf = df.lazy().with_columns(k=key).sort(key, descending=True)
+    assert 'SORT BY [col("k")]' in lf.explain()
+    assert 'SORT BY [col("k")]' not in lf.explain(optimized=False)
+    assert 'SORT BY [col("k")]' not in lf.explain(
+        optimizations=pl.QueryOptFlags(comm_subexpr_elim=False)
+    )
+    assert_frame_equal(lf.collect(), expected.with_columns(k=pl.Series([5, 4, 3])))
+
+    # The reused column is still dropped afterwards.
+    assert_frame_equal(lf.drop("k").collect(), expected)
+
+    # The key reads `a` after it is overwritten, so it is computed again.
+    lf = df.lazy().with_columns(a=key).sort(key)
+    assert 'SORT BY [col("a")]' not in lf.explain()
+    assert lf.collect()["a"].to_list() == [3, 4, 5]
@@ -0,0 +1,73 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import InvalidOperationError
+from polars.testing import assert_frame_equal
+
+COLOR = pl.Enum(["red", "green", "blue"])
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {"color": ["red", "green", None, "red", "blue"]}, schema={"color": COLOR}
+    )
+
+
+def test_to_dummies_expr(df: pl.DataFrame) -> None:
+    result = df.select(pl.col("color").to_dummies()).unnest("color")
+    expected = pl.DataFrame(
+        {
+            "color_red": [1, 0, 0, 1, 0],
+            "color_green": [0, 1, 0, 0, 0],
+            "color_blue": [0, 0, 0, 0, 1],
+        },
+        schema_overrides={
+            "color_red": pl.UInt8,
+            "color_green": pl.UInt8,
+            "color_blue": pl.UInt8,
+        },
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_to_dummies_expr_drop_first_separator(df: pl.DataFrame) -> None:
+    result = df.select(
+        pl.col("color").to_dummies(separator=":", drop_first=True)
+    ).unnest("color")
+    expected = pl.DataFrame(
+        {"color:green": [0, 1, 0, 0, 0], "color:blue": [0, 0, 0, 0, 1]},
+        schema_overrides={"color:green": pl.UInt8, "color:blue": pl.UInt8},
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_to_dummies_expr_sparse(df: pl.DataFrame) -> None:
+    result = df.select(
+        pl.col("color").to_dummies(sparse=True).alias("all"),
+        pl.col("color").to_dummies(sparse=True, drop_first=True).alias("drop"),
+    )
+    expected = pl.DataFrame(
+        {
+            "all": [[0], [1], [], [0], [2]],
+            "drop": [[], [0], [], [], [1]],
+        },
+        schema={"all": pl.List(pl.UInt32), "drop": pl.List(pl.UInt32)},
+    )
+    assert_frame_equal(result, expected)
+
+
+@pytest.mark.parametrize("sparse", [False, True])
+def test_to_dummies_expr_lazy_schema(df: pl.DataFrame, sparse: bool) -> None:
+    lf = df.lazy().select(pl.col("color").to_dummies(sparse=sparse))
+    assert lf.collect_schema() == lf.collect().schema
+
+
+def test_to_dummies_expr_requires_enum() -> None:
+    df = pl.DataFrame({"color": ["red", "green"]})
+    with pytest.raises(InvalidOperationError, match="Enum"):
+        df.select(pl.col("color").to_dummies())
+    with pytest.raises(InvalidOperationError, match="Enum"):
+        df.lazy().select(pl.col("color").to_dummies()).collect_schema()
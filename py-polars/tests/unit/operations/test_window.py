@@ -698,3 +698,35 @@ def test_window_fold_22493() -> None:
                 .alias("prod"),
             )
         )
+
+
+@pytest.mark.parametrize(
+    "groups",
+    [
+        ["a", "a", "b", "b", "b", "c"],
+        ["c", "c", "a", "b", "b", "b"],
+        ["a", "b", "a", "c", "b", "b"],
+    ],
+)
+def test_over_join_contiguous_groups(groups: list[str]) -> None:
+    df = pl.DataFrame({"g": groups, "x": [1, 2, 3, 4, 5, 6], "s": list("uvwxyz")})
+    out = df.select(
+        lst=pl.col("x").over("g", mapping_strategy="join"),
+        first=pl.col("s").first().over("g", mapping_strategy="join"),
+        rev=pl.col("x").reverse().over("g", mapping_strategy="join"),
+    )
+
+    expected_lst = []
+    expected_first = []
+    for g in groups:
+        members = [i for i, other in enumerate(groups) if other == g]
+        expected_lst.append([i + 1 for i in members])
+        expected_first.append("uvwxyz"[members[0]])
+    expected = pl.DataFrame(
+        {
+            "lst": expected_lst,
+            "first": expected_first,
+            "rev": [lst[::-1] for lst in expected_lst],
+        }
+    )
+    assert_frame_equal(out, expected)
@@ -177,3 +177,142 @@ def test_with_columns_scalar_20981() -> None:
     lf = pl.LazyFrame({"a": [1.0, 2.0, 3.0]})
     assert_frame_equal(lf.with_columns(a=2.0).collect(), expected)
     assert_frame_equal(lf.with_columns(pl.col.a.mean()).collect(), expected)
+
+
+def test_with_columns_fresh_dependencies() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+
+    result = lf.with_columns(
+        c=pl.col("b") * 10,
+        b=pl.col("a") + 1,
+        d=pl.col("c") + pl.col("b"),
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2, 3],
+            "c": [20, 30, 40],
+            "b": [2, 3, 4],
+            "d": [22, 33, 44],
+        }
+    )
+    assert_frame_equal(result.collect(), expected)
+    assert_frame_equal(
+        lf.with_columns_seq(c=pl.col("b") * 10, b=pl.col("a") + 1).collect(),
+        expected.drop("d"),
+    )
+
+
+def test_with_columns_input_takes_precedence() -> None:
+    df = pl.DataFrame({"a": [1, 2, 3]})
+
+    result = df.with_columns(b=pl.col("a") * 2, a=pl.col("a") + 1)
+
+    expected = pl.DataFrame({"a": [2, 3, 4], "b": [2, 4, 6]})
+    assert_frame_equal(result, expected)
+
+
+def test_with_columns_cyclic_dependencies() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="cycle"):
+        lf.with_columns(b=pl.col("c"), c=pl.col("b")).collect()
+
+
+def test_update_where() -> None:
+    df = pl.DataFrame(
+        {"a": [1, 2, None, 4], "b": ["x", "y", "z", "w"], "c": [1.0, 2.0, 3.0, 4.0]}
+    )
+    result = df.update_where(pl.col("a") > 1, pl.col("c") * 10, b=pl.lit("big"))
+    expected = pl.DataFrame(
+        {
+            "a": [1, 2, None, 4],
+            "b": ["x", "big", "z", "big"],
+            "c": [1.0, 20.0, 3.0, 40.0],
+        }
+    )
+    assert_frame_equal(result, expected)
+
+
+def test_update_where_chunks() -> None:
+    lf = pl.concat(
+        [pl.LazyFrame({"a": [1, 2]}), pl.LazyFrame({"a": [3, 4]})], rechunk=False
+    )
+    result = lf.update_where(pl.col("a") >= 3, a=0).collect()
+    assert_frame_equal(result, pl.DataFrame({"a": [1, 2, 0, 0]}))
+
+
+def test_update_where_invalid() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        lf.update_where(pl.col("a") > 1, b=1).collect()
+    with pytest.raises(pl.exceptions.ComputeError, match="output_name"):
+        lf.update_where(pl.col("a") > 1, pl.all() + 1)
+
+
+def test_with_columns_scalar_subquery() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 4]})
+    other = pl.LazyFrame({"b": [5, 5]})
+    total = other.select(pl.col("b").sum()).to_scalar_expr()
+
+    result = lf.with_columns(total, share=pl.col("a") / total).collect()
+    expected = pl.DataFrame(
+        {"a": [1, 2, 3, 4], "b": [10] * 4, "share": [0.1, 0.2, 0.3, 0.4]}
+    )
+    assert_frame_equal(result, expected)
+
+    # No rows give a null.
+    empty = other.filter(pl.col("b") > 5).to_scalar_expr()
+    result = lf.with_columns(b=pl.col("a") + empty).collect()
+    assert result["b"].to_list() == [None] * 4
+
+    with pytest.raises(pl.exceptions.ComputeError, match="at most one row"):
+        lf.with_columns(other.to_scalar_expr()).collect()
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="single column"):
+        lf.with_columns(other.with_columns(c=1).to_scalar_expr()).collect()
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not allowed"):
+        lf.select(total).collect()
+
+
+def test_with_columns_cached() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3, 6]})
+    mean = pl.cached(pl.col("a").mean())
+
+    q = lf.with_columns(b=pl.col("a") - mean, c=pl.col("a") / mean)
+    expected = pl.DataFrame(
+        {"a": [1, 2, 3, 6], "b": [-2.0, -1.0, 0.0, 3.0], "c": [1 / 3, 2 / 3, 1.0, 2.0]}
+    )
+    assert_frame_equal(q.collect(), expected)
+    assert q.explain(optimized=False).count("SCALAR SUBQUERY") == 1
+
+    # Uses on the same input in different parts of the query share a cache.
+    q = pl.concat([lf.with_columns(b=mean), lf.with_columns(b=mean * 2)])
+    assert "CACHE" in q.explain()
+    assert q.collect()["b"].to_list() == [3.0] * 4 + [6.0] * 4
+
+    with pytest.raises(pl.exceptions.ComputeError, match="at most one row"):
+        lf.with_columns(pl.cached(pl.col("a") * 2)).collect()
+
+
+def test_cached_other_nodes() -> None:
+    lf = pl.LazyFrame({"g": [1, 1, 2, 2], "a": [1, 2, 3, 6]})
+    mean = pl.cached(pl.col("a").mean())
+
+    result = lf.select(mean, b=pl.col("a") - mean).collect()
+    expected = pl.DataFrame({"a": [3.0] * 4, "b": [-2.0, -1.0, 0.0, 3.0]})
+    assert_frame_equal(result, expected)
+    assert_frame_equal(lf.select(mean).collect(), pl.DataFrame({"a": [3.0]}))
+
+    result = lf.filter(pl.col("a") > mean).collect()
+    assert_frame_equal(result, pl.DataFrame({"g": [2], "a": [6]}))
+
+    result = lf.group_by("g", maintain_order=True).agg(d=pl.col("a").sum() - mean)
+    expected = pl.DataFrame({"g": [1, 2], "d": [0.0, 6.0]})
+    assert_frame_equal(result.collect(), expected)
+
+    # Uses on the same input in different nodes share a cache.
+    q = pl.concat([lf.filter(pl.col("a") > mean), lf.filter(pl.col("a") < mean)])
+    assert "CACHE" in q.explain()
+    assert q.collect()["a"].to_list() == [6, 1, 2]
+
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="only allowed"):
+        lf.sort(mean).collect()
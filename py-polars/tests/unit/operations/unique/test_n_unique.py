@@ -1,8 +1,15 @@
 from __future__ import annotations
 
+from typing import TYPE_CHECKING
+
 import pytest
 
 import polars as pl
+import polars.selectors as cs
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from polars._typing import EngineType
 
 
 def test_n_unique() -> None:
@@ -30,6 +37,40 @@ def test_n_unique_subsets() -> None:
     )
 
 
+def test_n_unique_subsets_approximate() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1, 1, None, None, 2],
+            "b": ["x", "x", "y", "y", "x"],
+            "c": [1, 2, 3, 3, 1],
+        }
+    )
+    assert df.n_unique(approximate=True) == 4
+    assert df.n_unique(["a", "b"], approximate=True) == 3
+    assert df.n_unique("b", approximate=True) == 2
+    assert df.clear().n_unique() == 0
+    assert df.clear().n_unique(approximate=True) == 0
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_n_unique_lazy(engine: EngineType) -> None:
+    lf = pl.LazyFrame(
+        {
+            "a": [1, 1, None, None, 2],
+            "b": ["x", "x", "y", "y", "x"],
+            "c": [1, 2, 3, 3, 1],
+        }
+    )
+    expected = pl.DataFrame({"n_unique": pl.Series([4], dtype=pl.get_index_type())})
+    assert_frame_equal(lf.n_unique().collect(engine=engine), expected)
+
+    out = lf.n_unique(["a", "b"]).collect(engine=engine)
+    assert out.item() == 3
+    out = lf.n_unique(cs.numeric()).collect(engine=engine)
+    assert out.item() == 4
+    assert lf.clear().n_unique().collect(engine=engine).item() == 0
+
+
 def test_n_unique_null() -> None:
     assert pl.Series([]).n_unique() == 0
     assert pl.Series([None]).n_unique() == 1
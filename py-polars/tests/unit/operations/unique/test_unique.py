@@ -295,3 +295,21 @@ def test_unique_booleans_22753() -> None:
         pl.Series([None, None, True]).head(2).unique(),
         pl.Series([None], dtype=pl.Boolean()),
     )
+
+
+def test_unique_with_duplicates() -> None:
+    df = pl.DataFrame({"a": [3, 1, 2, 1, 3, 3], "b": [1, 2, 3, 4, 5, 6]})
+
+    unique, duplicates = df.unique_with_duplicates("a", maintain_order=True)
+    assert_frame_equal(unique, df.unique("a", keep="none", maintain_order=True))
+    expected = pl.DataFrame(
+        {"a": [3, 1], "count": [3, 2]},
+        schema_overrides={"count": pl.get_index_type()},
+    )
+    assert_frame_equal(duplicates, expected)
+
+    # Without duplicates in all columns.
+    unique, duplicates = df.unique_with_duplicates()
+    assert_frame_equal(unique, df)
+    assert duplicates.columns == ["a", "b", "count"]
+    assert duplicates.is_empty()
@@ -5,7 +5,12 @@ import numpy as np
 import pytest
 
 import polars as pl
-from polars.exceptions import ComputeError, InvalidOperationError, OutOfBoundsError
+from polars.exceptions import (
+    ComputeError,
+    InvalidOperationError,
+    OutOfBoundsError,
+    ShapeError,
+)
 from polars.testing import assert_series_equal
 
 
@@ -122,3 +127,32 @@ def test_scatter_enum() -> None:
 
     with pytest.raises(InvalidOperationError):
         s.scatter(1, 2)
+
+
+def test_scatter_batches() -> None:
+    s = pl.Series("a", [1, 2, 3, 4, 5])
+    result = s.scatter_batches([([3, 0], [30, 0]), (-1, None), ([0, 1], 7)])
+    assert_series_equal(result, pl.Series("a", [7, 7, 3, 30, None]))
+
+    s = pl.Series("a", ["a", "b", "c"])
+    result = s.scatter_batches([([2], ["x"]), ([1, 2], ["y", "z"])])
+    assert_series_equal(result, pl.Series("a", ["a", "y", "z"]))
+
+    s = pl.Series("a", [True, False, True])
+    result = s.scatter_batches([([], []), ([2, 0], False)])
+    assert_series_equal(result, pl.Series("a", [False, False, False]))
+
+
+def test_scatter_batches_invalid() -> None:
+    s = pl.Series("a", [1, 2, 3])
+    with pytest.raises(InvalidOperationError, match="index 1 is set more than once"):
+        s.scatter_batches([([1], [10]), ([2, 1], [20, 30])], on_conflict="raise")
+    with pytest.raises(OutOfBoundsError):
+        s.scatter_batches([([1], [10]), ([3], [20])])
+    with pytest.raises(ShapeError):
+        s.scatter_batches([([0, 1], [10, 20, 30])])
+    with pytest.raises(ValueError, match="on_conflict"):
+        s.scatter_batches([], on_conflict="first")  # type: ignore[arg-type]
+
+    # The series is left unchanged on errors.
+    assert_series_equal(s, pl.Series("a", [1, 2, 3]))
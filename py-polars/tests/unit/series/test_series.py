@@ -36,7 +36,7 @@ from tests.unit.utils.pycapsule_utils import PyCapsuleStreamHolder
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
-    from polars._typing import EpochTimeUnit, PolarsDataType, TimeUnit
+    from polars._typing import EpochTimeUnit, PolarsDataType, RoundMode, TimeUnit
 
 
 def test_cum_agg() -> None:
@@ -995,6 +995,60 @@ def test_round_sig_figs_raises_exc() -> None:
         pl.Series([1.234, 0.1234]).round_sig_figs(digits=0)
 
 
+@pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
+def test_round_modes(dtype: PolarsDataType) -> None:
+    s = pl.Series([-1.25, -0.5, 0.15, 2.5, 2.71], dtype=dtype)
+    assert s.round(1, mode="floor").to_list() == pytest.approx(
+        [-1.3, -0.5, 0.1, 2.5, 2.7]
+    )
+    assert s.round(mode="ceil").to_list() == [-1.0, -0.0, 1.0, 3.0, 3.0]
+    assert s.round(mode="half_away_from_zero").to_list() == [
+        -1.0,
+        -1.0,
+        0.0,
+        3.0,
+        3.0,
+    ]
+
+
+@pytest.mark.parametrize("dtype", [pl.Float32, pl.Float64])
+def test_round_half_away_from_zero_decimals(dtype: PolarsDataType) -> None:
+    # Float32 used to round ties to even when rounding to decimals.
+    s = pl.Series([0.125, -0.125, 0.375], dtype=dtype)
+    assert_series_equal(
+        s.round(2, mode="half_away_from_zero"),
+        pl.Series([0.13, -0.13, 0.38], dtype=dtype),
+    )
+    assert_series_equal(s.round(2), pl.Series([0.12, -0.12, 0.38], dtype=dtype))
+
+
+@pytest.mark.parametrize(
+    ("mode", "expected"),
+    [
+        ("half_to_even", [0.1, -0.1, 0.15, 1.0, 1.05, -0.0]),
+        ("half_away_from_zero", [0.15, -0.15, 0.15, 1.0, 1.05, -0.0]),
+        ("floor", [0.1, -0.15, 0.15, 1.0, 1.0, -0.05]),
+        ("ceil", [0.15, -0.1, 0.15, 1.05, 1.05, -0.0]),
+    ],
+)
+def test_round_to_increment(mode: RoundMode, expected: list[float]) -> None:
+    s = pl.Series([0.125, -0.125, 0.15, 1.01, 1.03, -0.01])
+    assert s.round_to_increment(0.05, mode=mode).to_list() == expected
+    assert_series_equal(
+        s.cast(pl.Float32).round_to_increment(0.05, mode=mode),
+        pl.Series(expected, dtype=pl.Float32),
+    )
+
+
+def test_round_to_increment_raises_exc() -> None:
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="positive"):
+        pl.Series([1.0]).round_to_increment(0.0)
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="float and decimal"):
+        pl.Series([1]).round_to_increment(5)
+    with pytest.raises(pl.exceptions.InvalidOperationError, match="not a multiple"):
+        pl.Series(["1.25"]).cast(pl.Decimal(scale=2)).round_to_increment(0.005)
+
+
 def test_apply_list_out() -> None:
     s = pl.Series("count", [3, 2, 2])
     out = s.map_elements(lambda val: pl.repeat(val, val, eager=True))
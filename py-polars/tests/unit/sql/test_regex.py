@@ -142,3 +142,27 @@ def test_regexp_like_errors() -> None:
             match=r"REGEXP_LIKE expects 2-3 arguments \(found 1\)",
         ):
             ctx.execute("SELECT * FROM df WHERE REGEXP_LIKE(scol)")
+
+
+def test_columns_regex_projection_pushdown(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame(
+        {
+            "id": [1, 2],
+            "price_eu": [1.5, 2.5],
+            "price_us": [2.0, 3.0],
+            "name": ["a", "b"],
+        }
+    ).write_parquet(path)
+
+    with pl.SQLContext(df=pl.scan_parquet(path)) as ctx:
+        query = "SELECT COLUMNS('^price_') FROM df"
+        assert "PROJECT 2/4 COLUMNS" in ctx.execute(query).explain()
+        assert ctx.execute(query).collect().rows() == [(1.5, 2.0), (2.5, 3.0)]
+
+        query = "SELECT MAX(COLUMNS('_us$|^id$')) FROM df"
+        assert "PROJECT 2/4 COLUMNS" in ctx.execute(query).explain()
+        assert ctx.execute(query).collect().rows() == [(2, 3.0)]
+
+        with pytest.raises(SQLSyntaxError, match="COLUMNS expects a valid regex"):
+            ctx.execute("SELECT COLUMNS('price_[') FROM df")
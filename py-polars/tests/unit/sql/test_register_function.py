@@ -0,0 +1,74 @@
+from __future__ import annotations
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def ctx() -> pl.SQLContext[pl.DataFrame]:
+    df = pl.DataFrame({"k": ["x", "y", "x"], "a": [1, 2, 3], "b": [4, 5, 6]})
+    return pl.SQLContext(frame=df, eager=True)
+
+
+def test_register_function_expr_factory(ctx: pl.SQLContext[pl.DataFrame]) -> None:
+    ctx.register_function("Weighted", lambda e, w: e * w)
+    ctx.register_function("total", lambda e: e.sum())
+    assert ctx.functions() == ["total", "weighted"]
+
+    res = ctx.execute(
+        """
+        SELECT k, TOTAL(WEIGHTED(a, b)) AS t
+        FROM frame GROUP BY k ORDER BY k
+        """
+    )
+    assert_frame_equal(res, pl.DataFrame({"k": ["x", "y"], "t": [22, 10]}))
+
+    res = ctx.execute("SELECT weighted(a, 10) AS w FROM frame")
+    assert res.to_dict(as_series=False) == {"w": [10, 20, 30]}
+
+
+def test_register_function_shadows_builtin(ctx: pl.SQLContext[pl.DataFrame]) -> None:
+    ctx.register_function("abs", lambda e: e * -1)
+    assert ctx.execute("SELECT ABS(a) AS a FROM frame")["a"].to_list() == [-1, -2, -3]
+
+    ctx.unregister_function("ABS")
+    assert ctx.functions() == []
+    assert ctx.execute("SELECT ABS(a) AS a FROM frame")["a"].to_list() == [1, 2, 3]
+
+
+def test_register_function_python_udf(ctx: pl.SQLContext[pl.DataFrame]) -> None:
+    ctx.register_function(
+        "ratio",
+        lambda a, b: a / b,
+        return_dtype=pl.Float64,
+    )
+    ctx.register_function(
+        "spread",
+        lambda s: s.max() - s.min(),
+        return_dtype=pl.Int64,
+        returns_scalar=True,
+    )
+    lf = ctx.execute("SELECT ratio(b, a) AS r FROM frame", eager=False)
+    assert lf.collect_schema() == pl.Schema({"r": pl.Float64})
+    assert lf.collect()["r"].to_list() == [4.0, 2.5, 2.0]
+
+    res = ctx.execute(
+        "SELECT k, SPREAD(b) AS s FROM frame GROUP BY k ORDER BY k",
+    )
+    assert res.to_dict(as_series=False) == {"k": ["x", "y"], "s": [2, 0]}
+
+
+def test_register_function_errors(ctx: pl.SQLContext[pl.DataFrame]) -> None:
+    with pytest.raises(ValueError, match="requires a `return_dtype`"):
+        ctx.register_function("f", lambda e: e, returns_scalar=True)
+
+    ctx.register_function("f", lambda e: 1)
+    with pytest.raises(TypeError, match="must return an expression, got 'int'"):
+        ctx.execute("SELECT f(a) FROM frame")
+
+    ctx.unregister_function("f")
+    with pytest.raises(SQLInterfaceError, match="unsupported function 'f'"):
+        ctx.execute("SELECT f(a) FROM frame")
@@ -117,3 +117,50 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_table_policy(test_frame: pl.LazyFrame) -> None:
+    other = pl.LazyFrame({"x": [1, 2, 3, 4], "w": [10, 20, 30, 40]})
+    ctx = pl.SQLContext(frame=test_frame, other=other, eager=True)
+    ctx.register_policy(
+        "frame",
+        row_filter="x >= 2",
+        column_masks={"y": pl.col("y").str.slice(0, 1), "z": "NULL::date"},
+    )
+    expected = pl.DataFrame(
+        {"x": [2, 3], "y": ["b", "c"], "z": [None, None]},
+        schema={"x": pl.UInt8, "y": pl.String, "z": pl.Date},
+    )
+
+    # the policy applies to every read of the table, including aliases and subqueries
+    assert_frame_equal(ctx.execute("SELECT * FROM frame ORDER BY x"), expected)
+    assert_frame_equal(
+        ctx.execute("SELECT f.* FROM (SELECT * FROM frame) AS f ORDER BY f.x"),
+        expected,
+    )
+    res = ctx.execute(
+        "SELECT f.x, o.w FROM other o JOIN frame f ON o.x = f.x ORDER BY f.x"
+    )
+    assert res.to_dict(as_series=False) == {"x": [2, 3], "w": [20, 30]}
+
+    # filters in the query cannot see the hidden rows and values
+    assert ctx.execute("SELECT * FROM frame WHERE x = 1").height == 0
+    assert ctx.execute("SELECT * FROM frame WHERE y = 'bbb'").height == 0
+
+    # the policy is kept if the table is registered again
+    ctx.register("frame", test_frame)
+    assert_frame_equal(ctx.execute("SELECT * FROM frame ORDER BY x"), expected)
+
+    # other tables, and the table after unregistering the policy, are unaffected
+    assert ctx.execute("SELECT * FROM other").height == 4
+    ctx.unregister_policy("frame")
+    assert_frame_equal(ctx.execute("SELECT * FROM frame"), test_frame.collect())
+
+
+def test_table_policy_replace(test_frame: pl.LazyFrame) -> None:
+    ctx = pl.SQLContext(frame=test_frame, eager=True)
+    ctx.register_policy("frame", row_filter=pl.col("x") == 1)
+    ctx.register_policy("frame", column_masks={"x": pl.lit(0, pl.UInt8)})
+
+    res = ctx.execute("SELECT x FROM frame")
+    assert res.to_dict(as_series=False) == {"x": [0, 0, 0]}
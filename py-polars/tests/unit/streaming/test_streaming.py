@@ -380,3 +380,57 @@ def test_i128_sum_reduction() -> None:
         .item()
         == 6
     )
+
+
+@pytest.mark.parametrize("lazy_filter", ["0", "1"])
+def test_streaming_lazy_filter(
+    lazy_filter: str, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_LAZY_FILTER", lazy_filter)
+    df = pl.DataFrame(
+        {
+            "a": [1, 2, 3, 4, 5, 6, None],
+            "b": [6, 5, 4, 3, 2, 1, 0],
+            "s": ["1", "x", "3", "y", "5", "6", "7"],
+        }
+    )
+    lf = df.lazy()
+
+    q = (
+        lf.filter(pl.col("a") > 1)
+        .filter(pl.col("b") > 1)
+        .select(pl.col("a") * 2, c=pl.col("b") + pl.lit(1))
+        .sort("a")
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    # Morsels without any selected rows are dropped.
+    q = lf.filter(pl.col("a") > 10).select(pl.col("a"))
+    assert q.collect(engine="streaming").height == 0
+
+    # The unselected rows would make the strict cast fail.
+    q = lf.filter(~pl.col("s").is_in(["x", "y"])).select(pl.col("s").cast(pl.Int64))
+    assert q.collect(engine="streaming").to_series().to_list() == [1, 3, 5, 6, 7]
+
+    q = lf.filter(~pl.col("s").is_in(["x", "y"])).filter(
+        pl.col("s").cast(pl.Int64) > 3
+    )
+    assert q.collect(engine="streaming")["a"].to_list() == [5, 6, None]
+
+
+def test_streaming_lazy_filter_udf(monkeypatch: pytest.MonkeyPatch) -> None:
+    monkeypatch.setenv("POLARS_STREAMING_LAZY_FILTER", "1")
+    seen: list[int] = []
+
+    def udf(s: pl.Series) -> pl.Series:
+        seen.extend(s.to_list())
+        return s * 2
+
+    q = (
+        pl.LazyFrame({"a": [1, 2, 3, 4]})
+        .filter(pl.col("a") > 2)
+        .select(pl.col("a").map_batches(udf, is_elementwise=True))
+    )
+    assert q.collect(engine="streaming")["a"].to_list() == [6, 8]
+    # UDFs never see the rows the filter removed.
+    assert sorted(seen) == [3, 4]
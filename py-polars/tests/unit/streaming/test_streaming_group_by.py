@@ -14,6 +14,8 @@ from tests.unit.conftest import INTEGER_DTYPES
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import ClosedInterval
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -512,3 +514,69 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+@pytest.mark.parametrize("include_boundaries", [False, True])
+def test_streaming_group_by_dynamic_with_keys(include_boundaries: bool) -> None:
+    n_entities = 50
+    lf = pl.LazyFrame(
+        {
+            "entity": [i % n_entities for i in range(5000)],
+            "sector": [str(i % 3) for i in range(5000)],
+            "time": [i // n_entities for i in range(5000)],
+            "value": range(5000),
+        }
+    )
+    for source in [lf, lf.filter(pl.col("entity") < 0)]:
+        q = source.group_by_dynamic(
+            "time",
+            every="10i",
+            period="15i",
+            group_by=["entity", "sector"],
+            include_boundaries=include_boundaries,
+        ).agg(pl.col("value").sum(), pl.len())
+        assert_frame_equal(q.collect(engine="streaming"), q.collect())
+        assert_frame_equal(
+            q.slice(5, 20).collect(engine="streaming"), q.slice(5, 20).collect()
+        )
+
+
+def test_streaming_group_by_dynamic_with_keys_unsorted() -> None:
+    lf = pl.LazyFrame({"key": [1, 2, 1, 2], "time": [1, 2, 0, 3]})
+    q = lf.group_by_dynamic("time", every="2i", group_by="key").agg(pl.len())
+    with pytest.raises(pl.exceptions.ComputeError, match="not sorted"):
+        q.collect(engine="streaming")
+
+
+@pytest.mark.parametrize(
+    ("every", "period", "offset"),
+    [("10i", "15i", "0i"), ("10i", "4i", "3i"), ("7i", "7i", "-2i")],
+)
+@pytest.mark.parametrize("closed", ["left", "right", "both"])
+def test_streaming_group_by_dynamic_with_keys_incremental(
+    every: str,
+    period: str,
+    offset: str,
+    closed: ClosedInterval,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    # Aggregate the complete windows after every few rows.
+    monkeypatch.setenv("POLARS_DYNAMIC_GROUP_BY_FLUSH_SIZE", "10")
+    n = 5000
+    df = pl.DataFrame(
+        {
+            "entity": [i % 7 for i in range(n)],
+            "time": [i // 20 for i in range(n)],
+            "value": range(n),
+        }
+    )
+    lf = pl.concat([df.slice(i, 250).lazy() for i in range(0, n, 250)])
+    q = lf.group_by_dynamic(
+        "time",
+        every=every,
+        period=period,
+        offset=offset,
+        closed=closed,
+        group_by="entity",
+    ).agg(pl.col("value").sum(), pl.len())
+    assert_frame_equal(q.collect(engine="streaming"), q.collect())
@@ -206,3 +206,49 @@ def test_gevent_collect_async_spawn(
         return result_greenlet.get().get()
 
     _gevent_run(main, raises)
+
+
+async def _aio_collect_all_as_completed(
+    lfs: list[pl.LazyFrame], **kwargs: Any
+) -> list[tuple[int, pl.DataFrame]]:
+    return [item async for item in pl.collect_all_as_completed(lfs, **kwargs)]
+
+
+def test_collect_all_as_completed() -> None:
+    lfs = [pl.LazyFrame({"a": [i]}).select(pl.col("a") * 2) for i in range(5)]
+
+    out = asyncio.run(_aio_collect_all_as_completed(lfs))
+    assert sorted((i, df.item()) for i, df in out) == [(i, 2 * i) for i in range(5)]
+
+    # With a single query at a time, the queries finish in the order of priority.
+    out = asyncio.run(
+        _aio_collect_all_as_completed(lfs, max_concurrency=1, priority=[0, 2, 1, 2, 0])
+    )
+    assert [i for i, _ in out] == [1, 3, 2, 0, 4]
+
+    assert asyncio.run(_aio_collect_all_as_completed([])) == []
+
+
+def test_collect_all_as_completed_raises() -> None:
+    lfs = [pl.LazyFrame({"a": [1]}), pl.LazyFrame({"a": [1]}).select("foo_bar")]
+    with pytest.raises(ColumnNotFoundError):
+        asyncio.run(_aio_collect_all_as_completed(lfs, max_concurrency=1))
+
+    with pytest.raises(ValueError, match="`max_concurrency` must be at least 1"):
+        asyncio.run(_aio_collect_all_as_completed(lfs, max_concurrency=0))
+
+    with pytest.raises(ValueError, match="`priority` must have the same length"):
+        asyncio.run(_aio_collect_all_as_completed(lfs, priority=[1]))
+
+
+def test_collect_all_async_max_concurrency() -> None:
+    lfs = [pl.LazyFrame({"a": [i]}).select(pl.col("a") * 2) for i in range(5)]
+
+    async def main() -> list[pl.DataFrame]:
+        return await pl.collect_all_async(lfs, max_concurrency=2)
+
+    assert [df.item() for df in asyncio.run(main())] == [0, 2, 4, 6, 8]
+
+    with pytest.raises(ValueError, match="not supported together with `gevent=True`"):
+        pl.collect_all_async(lfs, gevent=True, max_concurrency=2)  # type: ignore[call-overload]
+
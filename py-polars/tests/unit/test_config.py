@@ -11,10 +11,13 @@ import polars as pl
 import polars.polars as plr
 from polars._utils.unstable import issue_unstable_warning
 from polars.config import _POLARS_CFG_ENV_VARS
+from polars.testing import assert_series_equal
 
 if TYPE_CHECKING:
     from collections.abc import Iterator
 
+    from polars._typing import EngineType
+
 
 @pytest.fixture(autouse=True)
 def _environ() -> Iterator[None]:
@@ -838,6 +841,89 @@ def test_set_streaming_chunk_size() -> None:
         cfg.set_streaming_chunk_size(0)
 
 
+def test_set_arithmetic_overflow() -> None:
+    s = pl.Series("a", [100, 120, None], dtype=pl.Int8)
+    other = pl.Series("b", [100, 10, 1], dtype=pl.Int8)
+
+    assert_series_equal(s + other, pl.Series("a", [-56, -126, None], dtype=pl.Int8))
+
+    with pl.Config(arithmetic_overflow="promote"):
+        assert_series_equal(
+            s + other, pl.Series("a", [200, 130, None], dtype=pl.Int16)
+        )
+        assert_series_equal(s * 2, pl.Series("a", [200, 240, None], dtype=pl.Int16))
+        # The output dtype does not depend on the data.
+        assert_series_equal(s - other, pl.Series("a", [0, 110, None], dtype=pl.Int16))
+
+        u = pl.Series("u", [1, 2], dtype=pl.UInt8)
+        assert_series_equal(u - 3, pl.Series("u", [-2, -1], dtype=pl.Int16))
+
+        big = pl.Series("u", [60_000], dtype=pl.UInt16)
+        with pytest.raises(pl.exceptions.ComputeError, match="promoted from u16"):
+            big * big
+
+        df = pl.DataFrame({"x": [2**31 - 1, 1]}, schema={"x": pl.Int32})
+        for x in [df, df.slice(1)]:
+            lf = x.lazy().select(pl.col("x") + 1, y=pl.col("x") * pl.col("x"))
+            assert lf.collect_schema() == {"x": pl.Int64, "y": pl.Int64}
+            assert lf.collect().schema == lf.collect_schema()
+
+    with pl.Config(arithmetic_overflow="error"):
+        with pytest.raises(pl.exceptions.ComputeError, match="integer overflow"):
+            s + other
+        with pytest.raises(pl.exceptions.ComputeError, match="integer overflow"):
+            s * 2
+        assert_series_equal(s - other, pl.Series("a", [0, 110, None], dtype=pl.Int8))
+
+    with pl.Config(arithmetic_overflow="wrap"):
+        assert_series_equal(s * 2, pl.Series("a", [-56, -16, None], dtype=pl.Int8))
+
+    with pytest.raises(ValueError, match="invalid `policy`"):
+        pl.Config.set_arithmetic_overflow("saturate")  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_set_float_summation(engine: EngineType) -> None:
+    # Each addition of 1.0 to 1e16 rounds to 1e16 in a naive sum.
+    df = pl.DataFrame({"g": [1] * 1001, "x": [1e16] + [1.0] * 1000})
+
+    with pl.Config(float_summation="kahan"):
+        out = (
+            df.lazy()
+            .group_by("g")
+            .agg(sum=pl.col("x").sum(), mean=pl.col("x").mean())
+            .collect(engine=engine)
+        )
+        assert out["sum"].item() == 1e16 + 1000
+        assert out["mean"].item() == (1e16 + 1000) / 1001
+        assert df.select(pl.col("x").sum().over("g"))["x"][0] == 1e16 + 1000
+        assert df["x"].rolling_sum(1001)[-1] == 1e16 + 1000
+        out = (
+            df.with_row_index("i")
+            .with_columns(pl.col("i").cast(pl.Int64))
+            .rolling("i", period="1001i")
+            .agg(pl.col("x").sum())
+        )
+        assert out["x"][-1] == 1e16 + 1000
+
+        s = pl.Series([0.1] * 10, dtype=pl.Float32)
+        assert s.sum() == 1.0
+
+    with pytest.raises(ValueError, match="invalid `method`"):
+        pl.Config.set_float_summation("pairwise")  # type: ignore[arg-type]
+
+
+def test_set_join_nans_equal() -> None:
+    df = pl.DataFrame({"x": [float("nan"), 1.0]})
+    assert df.join(df, on="x").height == 2
+
+    with pl.Config(join_nans_equal=False):
+        assert df.join(df, on="x").height == 1
+        assert df.join(df, on="x", nans_equal=True).height == 2
+
+    assert df.join(df, on="x").height == 2
+
+
 def test_set_fmt_str_lengths_invalid_length() -> None:
     with pl.Config() as cfg:
         with pytest.raises(ValueError):
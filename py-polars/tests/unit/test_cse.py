@@ -40,7 +40,7 @@ def test_cse_rename_cross_join_5405() -> None:
 
 def test_union_duplicates() -> None:
     n_dfs = 10
-    df_lazy = pl.DataFrame({}).lazy()
+    df_lazy = pl.DataFrame({}).lazy().cache_hint("always")
     lazy_dfs = [df_lazy for _ in range(n_dfs)]
 
     result = len(
@@ -699,7 +699,7 @@ def test_cse_and_schema_update_projection_pd() -> None:
 @pytest.mark.may_fail_auto_streaming
 def test_cse_predicate_self_join(capfd: Any, monkeypatch: Any) -> None:
     monkeypatch.setenv("POLARS_VERBOSE", "1")
-    y = pl.LazyFrame({"a": [1], "b": [2], "y": [3]})
+    y = pl.LazyFrame({"a": [1], "b": [2], "y": [3]}).cache_hint("always")
 
     xf = y.filter(pl.col("y") == 2).select(["a", "b"])
     y_xf = y.join(xf, on=["a", "b"], how="left")
@@ -935,3 +935,41 @@ def test_multiplex_predicate_pushdown() -> None:
         ldf = pl.scan_parquet(tmppath, hive_partitioning=True)
         ldf = ldf.filter(pl.col("a").eq(1)).select("b")
         assert 'SELECTION: [(col("a")) == (1)]' in pl.explain_all([ldf, ldf])
+
+
+def test_cse_cheap_duplicates_not_cached() -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+    q = pl.concat([lf, lf])
+    assert "CACHE" not in q.explain()
+    assert "CACHE" in pl.concat([lf.cache_hint("always")] * 2).explain()
+
+    # Duplicates that do compute something are cached.
+    lf = lf.with_columns(b=pl.col("a") * 2)
+    q = pl.concat([lf, lf])
+    assert "CACHE" in q.explain()
+    assert_frame_equal(
+        q.collect(),
+        pl.DataFrame({"a": [1, 2, 3] * 2, "b": [2, 4, 6] * 2}),
+    )
+
+
+@pytest.mark.write_disk
+def test_cse_cache_hint_never(tmp_path: Path) -> None:
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": [1, 2, 3]}).write_parquet(path)
+
+    lf = pl.scan_parquet(path).with_columns(b=pl.col("a") * 2)
+    assert "CACHE" in pl.concat([lf, lf]).explain()
+
+    lf = lf.cache_hint("never")
+    q = pl.concat([lf, lf])
+    assert "CACHE" not in q.explain()
+    assert_frame_equal(
+        q.collect(),
+        pl.DataFrame({"a": [1, 2, 3] * 2, "b": [2, 4, 6] * 2}),
+    )
+
+
+def test_cache_hint_invalid() -> None:
+    with pytest.raises(ValueError, match="`hint` must be one of"):
+        pl.LazyFrame().cache_hint("sometimes")  # type: ignore[arg-type]
@@ -226,3 +226,47 @@ def test_match_to_schema_float_upcast() -> None:
 
     result = df.lazy().match_to_schema(expected.schema, float_cast="upcast").collect()
     assert_frame_equal(expected, result)
+
+
+def test_match_to_schema_cast() -> None:
+    lf = pl.LazyFrame({"a": ["1", "2", "x"], "b": [1.5, 2.5, 3.5], "c": [1, 2, 3]})
+    schema = {"a": pl.Int64(), "b": pl.Int32(), "c": pl.Int64()}
+
+    with pytest.raises(pl.exceptions.SchemaError):
+        lf.match_to_schema(schema).collect()
+
+    with pytest.raises(pl.exceptions.InvalidOperationError):
+        lf.match_to_schema(schema, cast="strict").collect()
+
+    result = lf.match_to_schema(schema, cast="non-strict").collect()
+    expected = pl.DataFrame(
+        {"a": [1, 2, None], "b": [1, 2, 3], "c": [1, 2, 3]},
+        schema={"a": pl.Int64, "b": pl.Int32, "c": pl.Int64},
+    )
+    assert_frame_equal(result, expected)
+
+    # Per column, columns without a policy only follow the other policies.
+    result = lf.match_to_schema(
+        {"a": pl.String(), "b": pl.Int32(), "c": pl.UInt8()},
+        cast={"b": "strict"},
+        integer_cast="upcast",
+    )
+    with pytest.raises(pl.exceptions.SchemaError):
+        result.collect()
+
+    result = lf.match_to_schema(
+        {"a": pl.String(), "b": pl.Int32(), "c": pl.UInt8()},
+        cast={"b": "strict", "c": "strict"},
+    ).collect()
+    assert result.schema == pl.Schema({"a": pl.String, "b": pl.Int32, "c": pl.UInt8})
+
+    with pytest.raises(ValueError, match="`cast` must be one of"):
+        lf.match_to_schema(schema, cast="upcast")  # type: ignore[arg-type]
+
+
+def test_match_to_schema_missing_columns_message() -> None:
+    lf = pl.LazyFrame({"a": [1]})
+    with pytest.raises(pl.exceptions.SchemaError) as exc:
+        lf.match_to_schema({"a": pl.Int64, "b": pl.Int64, "c": pl.Int64}).collect()
+    assert 'missing columns in `match_to_schema`: "b", "c"' in str(exc.value)
+    assert '"c""' not in str(exc.value)
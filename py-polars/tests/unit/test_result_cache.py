@@ -0,0 +1,131 @@
+from __future__ import annotations
+
+import os
+import time
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+from polars.testing import assert_frame_equal
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+    from pathlib import Path
+
+
+@pytest.fixture(autouse=True)
+def _disable_result_cache() -> Iterator[None]:
+    yield
+    pl.disable_result_cache()
+
+
+def _scan(tmp_path: Path, values: list[int]) -> pl.LazyFrame:
+    # The cache doesn't know when a file changes, which is used to detect cache hits.
+    path = tmp_path / "data.parquet"
+    pl.DataFrame({"a": values}).write_parquet(path)
+    return pl.scan_parquet(path).select(pl.col("a").sum())
+
+
+def test_result_cache(tmp_path: Path) -> None:
+    assert not pl.using_result_cache()
+    pl.enable_result_cache()
+    assert pl.using_result_cache()
+
+    lf = _scan(tmp_path, [1, 2, 3])
+    assert lf.collect().item() == 6
+
+    # Identical plans return the stored result.
+    lf = _scan(tmp_path, [10, 20])
+    assert lf.collect().item() == 6
+    assert lf.collect(engine="in-memory").item() == 6
+
+    # Other plans and settings are collected.
+    assert lf.select(pl.col("a") + 1).collect().item() == 31
+    assert lf.collect(engine="streaming").item() == 30
+    assert lf.collect(optimizations=pl.QueryOptFlags.none()).item() == 30
+
+    pl.clear_result_cache(lf)
+    assert lf.collect().item() == 30
+
+    pl.disable_result_cache()
+    lf = _scan(tmp_path, [1])
+    assert lf.collect().item() == 1
+
+
+def test_result_cache_returns_copy() -> None:
+    pl.enable_result_cache()
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+
+    df = lf.collect()
+    df[0, "a"] = 10
+    assert_frame_equal(lf.collect(), pl.DataFrame({"a": [1, 2, 3]}))
+
+
+def test_result_cache_skips_python_functions() -> None:
+    pl.enable_result_cache()
+    calls = 0
+
+    def udf(s: pl.Series) -> pl.Series:
+        nonlocal calls
+        calls += 1
+        return s
+
+    lf = pl.LazyFrame({"a": [1, 2, 3]}).select(
+        pl.col("a").map_batches(udf, return_dtype=pl.Int64)
+    )
+    lf.collect()
+    n_calls = calls
+    lf.collect()
+    assert calls > n_calls
+
+
+def test_result_cache_ttl(tmp_path: Path) -> None:
+    pl.enable_result_cache(ttl=0)
+    lf = _scan(tmp_path, [1, 2, 3])
+    assert lf.collect().item() == 6
+
+    time.sleep(0.01)
+    lf = _scan(tmp_path, [10, 20])
+    assert lf.collect().item() == 30
+
+    with pytest.raises(ValueError, match="non-negative"):
+        pl.enable_result_cache(ttl=-1)
+
+
+def test_result_cache_directory(tmp_path: Path) -> None:
+    directory = tmp_path / "cache"
+    pl.enable_result_cache(directory=directory, ttl=3600)
+
+    lf = _scan(tmp_path, [1, 2, 3])
+    assert lf.collect().item() == 6
+    assert len(list(directory.glob("*.ipc"))) == 1
+
+    # The stored results are kept on disk.
+    pl.disable_result_cache()
+    pl.enable_result_cache(directory=directory, ttl=3600)
+    lf = _scan(tmp_path, [10, 20])
+    assert lf.collect().item() == 6
+
+    # Expired files are removed.
+    (path,) = directory.glob("*.ipc")
+    os.utime(path, (0, 0))
+    assert lf.collect().item() == 30
+
+    pl.clear_result_cache()
+    assert not list(directory.glob("*.ipc"))
+
+
+def test_result_cache_write_failure_warns(
+    tmp_path: Path, monkeypatch: pytest.MonkeyPatch
+) -> None:
+    pl.enable_result_cache(directory=tmp_path / "cache")
+
+    def write_ipc(*args: object, **kwargs: object) -> None:
+        msg = "No space left on device"
+        raise OSError(msg)
+
+    monkeypatch.setattr(pl.DataFrame, "write_ipc", write_ipc)
+    with pytest.warns(UserWarning, match="No space left on device"):
+        assert _scan(tmp_path, [1, 2, 3]).collect().item() == 6
+    assert not list((tmp_path / "cache").iterdir())
@@ -368,3 +368,44 @@ def test_scalar_agg_schema_20044() -> None:
         .group_by("c")
         .agg(pl.col("d").mean())
     ).schema == pl.Schema([("c", pl.String), ("d", pl.Float64)])
+
+
+def test_schema_to_json_schema() -> None:
+    s = pl.Schema(
+        {
+            "i": pl.Int16(),
+            "u": pl.UInt8(),
+            "f": pl.Float64(),
+            "b": pl.Boolean(),
+            "e": pl.Enum(["a", "b"]),
+            "d": pl.Date(),
+            "a": pl.Array(pl.String, 2),
+            "s": pl.Struct({"x": pl.Null()}),
+        }
+    )
+    assert s.to_json_schema() == {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "type": "object",
+        "properties": {
+            "i": {"type": ["integer", "null"], "minimum": -32768, "maximum": 32767},
+            "u": {"type": ["integer", "null"], "minimum": 0, "maximum": 255},
+            "f": {"type": ["number", "null"]},
+            "b": {"type": ["boolean", "null"]},
+            "e": {"type": ["string", "null"], "enum": ["a", "b", None]},
+            "d": {"type": ["string", "null"], "format": "date"},
+            "a": {
+                "type": ["array", "null"],
+                "items": {"type": ["string", "null"]},
+                "minItems": 2,
+                "maxItems": 2,
+            },
+            "s": {
+                "type": ["object", "null"],
+                "properties": {"x": {"type": "null"}},
+            },
+        },
+        "required": ["i", "u", "f", "b", "e", "d", "a", "s"],
+    }
+
+    with pytest.raises(TypeError, match="cannot convert dtype Binary"):
+        pl.Schema({"x": pl.Binary()}).to_json_schema()
@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import multiprocessing
+import pickle
+from concurrent.futures import ProcessPoolExecutor
+from typing import TYPE_CHECKING
+
+import pytest
+
+import polars as pl
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+
+@pytest.fixture
+def cache() -> Iterator[pl.SharedStringCache]:
+    cache = pl.SharedStringCache()
+    yield cache
+    cache.unlink()
+
+
+def _encode(strings: list[str]) -> list[int]:
+    return pl.Series(strings, dtype=pl.Categorical).to_physical().to_list()
+
+
+def _decode(physical: list[int]) -> list[str]:
+    s = pl.Series(physical, dtype=pl.UInt32).cast(pl.Categorical)
+    return s.cast(pl.String).to_list()
+
+
+def test_shared_string_cache_handle(cache: pl.SharedStringCache) -> None:
+    assert pickle.loads(pickle.dumps(cache)).path == cache.path
+    assert repr(cache) == f"SharedStringCache({cache.name!r})"
+    assert pl.SharedStringCache("cats").name == "cats"
+
+
+@pytest.mark.parametrize("name", ["", ".", "..", "a/b"])
+def test_shared_string_cache_invalid_name(name: str) -> None:
+    with pytest.raises(ValueError, match="invalid shared-memory segment name"):
+        pl.SharedStringCache(name)
+
+
+@pytest.mark.slow
+def test_shared_string_cache_multiprocessing(cache: pl.SharedStringCache) -> None:
+    # Spawned workers start without categorical data, so they can enable the cache.
+    ctx = multiprocessing.get_context("spawn")
+    # The new categories of a column are shared at once, including repeated ones.
+    partitions = [["a", "b"], ["c", "b"], ["d", "a", "c"], ["e", "f", "e", "a"]]
+    with ProcessPoolExecutor(
+        max_workers=2, mp_context=ctx, initializer=cache.enable
+    ) as pool:
+        encoded = list(pool.map(_encode, partitions))
+
+        physical: dict[str, int] = {}
+        for strings, cats in zip(partitions, encoded):
+            for s, cat in zip(strings, cats):
+                assert physical.setdefault(s, cat) == cat
+        assert len(set(physical.values())) == 6
+
+        decoded = list(pool.map(_decode, encoded))
+    assert decoded == partitions